@@ -0,0 +1,57 @@
+use s_e_e_core::{CoreError, ErrorCategory, WorkflowResult};
+
+/// Process exit codes for workflow runs, so scripts and CI can tell failures apart.
+///
+/// | Code | Meaning |
+/// |------|---------|
+/// | 0 | The workflow succeeded |
+/// | 1 | A task failed, or the run hit an internal error |
+/// | 2 | The workflow file or its parameters were invalid |
+/// | 3 | The run was cancelled |
+/// | 4 | A task exceeded its timeout |
+/// | 5 | A preflight check failed, so no task ran |
+/// | 6 | The handler policy does not allow one of the workflow's tasks |
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitCode {
+    Success = 0,
+    TaskFailure = 1,
+    ValidationError = 2,
+    Cancelled = 3,
+    Timeout = 4,
+    PreflightFailed = 5,
+    PolicyDenied = 6,
+}
+
+impl ExitCode {
+    pub fn for_result(result: &WorkflowResult) -> Self {
+        match result.failure_category() {
+            None => ExitCode::Success,
+            Some(category) => Self::for_category(category),
+        }
+    }
+
+    pub fn for_error(error: &CoreError) -> Self {
+        Self::for_category(error.category())
+    }
+
+    pub fn for_category(category: ErrorCategory) -> Self {
+        match category {
+            ErrorCategory::Validation => ExitCode::ValidationError,
+            ErrorCategory::Cancelled => ExitCode::Cancelled,
+            ErrorCategory::Timeout => ExitCode::Timeout,
+            ErrorCategory::Preflight => ExitCode::PreflightFailed,
+            ErrorCategory::Policy => ExitCode::PolicyDenied,
+            ErrorCategory::HandlerFailure { .. }
+            | ErrorCategory::Io
+            | ErrorCategory::NotFound
+            | ErrorCategory::Input
+            | ErrorCategory::Untrusted
+            | ErrorCategory::Storage
+            | ErrorCategory::Internal => ExitCode::TaskFailure,
+        }
+    }
+
+    pub fn exit(self) -> ! {
+        std::process::exit(self as i32)
+    }
+}
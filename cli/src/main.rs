@@ -1,7 +1,28 @@
+mod exit_code;
+mod output;
+mod params;
+
+use chrono::Duration;
 use clap::{Parser, Subcommand};
+use exit_code::ExitCode;
+use output::{Output, OutputMode};
 use s_e_e_core::{
-    execute_workflow_by_id, init_global_store, populate_initial_data, OutputCallback,
+    add_all_example_workflows, add_example_workflow, archive_executions_older_than,
+    clear_directory_cache, clear_task_result_cache, create_test_from_execution,
+    default_archive_dir, dry_run_workflow, execute_workflow_by_id_profiled,
+    execute_workflow_by_id_with_bounds, export_execution_data, export_workflow_bundle,
+    export_workflow_graph, generate_workflow_readme, get_execution_manifest,
+    import_workflow_bundle, init_global_store, init_read_only_store, interrupt_runs, lint_workflow,
+    list_archived_executions, list_example_workflows, list_resource_locks, list_snippets,
+    load_handler_policy, load_log_settings, load_message_triggers, load_workflow_limits,
+    populate_initial_data, refresh_examples, release_resource_lock, rerun_task,
+    restore_archived_execution, run_message_triggers, run_sandboxed, run_workflow_tests,
+    save_snapshot_as_workflow, schema_docs, set_execution_labels, storage_stats, trust_workflow,
+    vacuum_storage, validate_workflow_json, workflow_trust_prompt, CoreError, ExportFormat,
+    LayoutMode, Lifecycle, OutputCallback, RunBounds, SchemaDocsFormat, WorkflowResult,
+    WorkflowTestReport, DEFAULT_SHUTDOWN_TIMEOUT,
 };
+use s_e_e_engine::EngineError;
 use std::fs;
 
 #[derive(Parser, Debug)]
@@ -12,6 +33,62 @@ struct Args {
 
     #[arg(short, long)]
     file: Option<String>,
+
+    /// Workflow parameter value, repeatable
+    #[arg(short, long = "param", value_name = "KEY=VALUE")]
+    param: Vec<String>,
+
+    /// Label stored with the execution, repeatable
+    #[arg(short, long = "label", value_name = "LABEL")]
+    label: Vec<String>,
+
+    /// Start at this task, skipping the tasks before it
+    #[arg(long, value_name = "TASK", conflicts_with_all = ["sandbox", "dry_run"])]
+    from: Option<String>,
+
+    /// Stop after this task, running only it and the tasks leading to it
+    #[arg(long, value_name = "TASK", conflicts_with_all = ["sandbox", "dry_run"])]
+    until: Option<String>,
+
+    /// Take outputs of the tasks skipped by --from from this execution
+    #[arg(long, value_name = "EXECUTION_ID", requires = "from")]
+    context_from: Option<String>,
+
+    /// Fail instead of prompting for missing parameters
+    #[arg(long)]
+    non_interactive: bool,
+
+    /// Print only the final result
+    #[arg(short, long, conflicts_with_all = ["verbose", "json"])]
+    quiet: bool,
+
+    /// Stream every task's full log
+    #[arg(short, long, conflicts_with = "json")]
+    verbose: bool,
+
+    /// Emit machine-readable NDJSON events
+    #[arg(long)]
+    json: bool,
+
+    /// Treat workflow lint warnings as validation errors
+    #[arg(long)]
+    fail_on_warning: bool,
+
+    /// Log what commands would run instead of running them, and save nothing
+    #[arg(long)]
+    sandbox: bool,
+
+    /// List the tasks that would run with their inputs filled in, running none
+    #[arg(long, conflicts_with = "sandbox")]
+    dry_run: bool,
+
+    /// Time each phase of the run and print where the time went
+    #[arg(long, conflicts_with_all = ["sandbox", "dry_run"])]
+    profile_run: bool,
+
+    /// Also write the run's profile to this file as Chrome trace JSON
+    #[arg(long, value_name = "FILE", requires = "profile_run")]
+    profile_trace: Option<String>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -22,6 +99,198 @@ enum Commands {
     #[command(name = "list-system-prompts")]
     ListSystemPrompts,
 
+    #[command(name = "list-examples")]
+    ListExamples,
+
+    /// List task snippets that workflow tasks can reference with "snippet"
+    #[command(name = "list-snippets")]
+    ListSnippets,
+
+    #[command(name = "add-example")]
+    AddExample {
+        #[arg(short, long)]
+        id: Option<String>,
+        /// Add every example that is not in the library yet
+        #[arg(long, conflicts_with = "id")]
+        all: bool,
+    },
+
+    #[command(name = "refresh-examples")]
+    RefreshExamples,
+
+    /// Forget cached task results and directories so cached tasks run again
+    #[command(name = "clear-task-cache")]
+    ClearTaskCache,
+
+    /// Remove stored output that no execution refers to any more
+    #[command(name = "vacuum")]
+    Vacuum,
+
+    /// Move finished executions older than N days into compressed archive files
+    #[command(name = "archive")]
+    Archive {
+        #[arg(long, value_name = "DAYS")]
+        older_than_days: u32,
+    },
+
+    #[command(name = "list-archived")]
+    ListArchived,
+
+    /// Load an archived execution back into the database
+    #[command(name = "restore-archived")]
+    RestoreArchived {
+        #[arg(short, long)]
+        id: String,
+    },
+
+    /// Save the workflow an execution ran as a new workflow definition
+    #[command(name = "save-snapshot")]
+    SaveSnapshot {
+        #[arg(short, long)]
+        execution_id: String,
+        #[arg(short, long)]
+        name: Option<String>,
+    },
+
+    /// Write a workflow as a bundle with a content hash, optionally signed
+    #[command(name = "export-bundle")]
+    ExportBundle {
+        #[arg(short, long)]
+        id: String,
+        #[arg(short, long)]
+        output: String,
+        /// File holding a hex-encoded 32-byte Ed25519 secret key
+        #[arg(long, value_name = "FILE")]
+        signing_key: Option<String>,
+    },
+
+    /// Write a Markdown README documenting a workflow
+    #[command(name = "workflow-readme")]
+    WorkflowReadme {
+        #[arg(short, long)]
+        id: String,
+        /// File to write; prints to stdout when omitted
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+
+    /// Export a workflow's task graph with laid-out node positions as JSON
+    #[command(name = "workflow-graph")]
+    WorkflowGraph {
+        #[arg(short, long)]
+        id: String,
+        /// layered, force_directed or stage_grid; the workflow's saved
+        /// layout when omitted
+        #[arg(short, long)]
+        layout: Option<String>,
+        /// File to write; prints to stdout when omitted
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+
+    /// Verify a workflow bundle and add it to the library
+    #[command(name = "import-bundle")]
+    ImportBundle {
+        #[arg(short, long)]
+        file: String,
+    },
+
+    /// Allow an imported workflow to run as it is now
+    #[command(name = "trust-workflow")]
+    TrustWorkflow {
+        #[arg(short, long)]
+        id: String,
+    },
+
+    /// Replace an execution's labels; pass none to clear them
+    #[command(name = "label-execution")]
+    LabelExecution {
+        #[arg(short, long)]
+        id: String,
+        #[arg(short, long = "label", value_name = "LABEL")]
+        label: Vec<String>,
+    },
+
+    /// Print what an execution ran, where, and with which tool versions, as JSON
+    #[command(name = "execution-manifest")]
+    ExecutionManifest {
+        #[arg(short, long)]
+        id: String,
+    },
+
+    /// Write executions and their task metrics in a time range as CSV or
+    /// Parquet, one file each, for analysis in other tools
+    #[command(name = "export-executions")]
+    ExportExecutions {
+        /// First day or RFC 3339 time to include
+        #[arg(long, value_name = "DATE")]
+        since: String,
+        /// Last day to include, or RFC 3339 time to stop before; now when omitted
+        #[arg(long, value_name = "DATE")]
+        until: Option<String>,
+        /// csv or parquet
+        #[arg(long, default_value = "csv")]
+        format: String,
+        /// Directory to write executions.<format> and tasks.<format> into
+        #[arg(short, long)]
+        output: String,
+    },
+
+    /// Run a failed task of a finished execution again, with the context it
+    /// first ran with, keeping its earlier result as an attempt
+    #[command(name = "rerun-task")]
+    RerunTask {
+        #[arg(short, long)]
+        execution_id: String,
+        #[arg(short, long)]
+        task: String,
+        /// Also run the tasks after it if it succeeds
+        #[arg(long)]
+        downstream: bool,
+    },
+
+    /// Record a finished execution as a test of its workflow: its task
+    /// results are replayed and its final context becomes the expectation
+    #[command(name = "create-test")]
+    CreateTest {
+        #[arg(short, long)]
+        execution_id: String,
+        #[arg(short, long)]
+        name: Option<String>,
+    },
+
+    /// Run a workflow's recorded tests against its current definition,
+    /// without calling any handler
+    #[command(name = "test")]
+    Test { workflow_id: String },
+
+    /// Describe every stored model, its fields and the database tables, for
+    /// tools that read or write the data directly
+    #[command(name = "schema-docs")]
+    SchemaDocs {
+        /// markdown or json
+        #[arg(long, default_value = "markdown")]
+        format: String,
+        /// File to write; prints to stdout when omitted
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+
+    /// Show which tasks hold or are queued for each named resource lock
+    #[command(name = "locks")]
+    Locks,
+
+    /// Drop a ticket from a resource lock queue, e.g. one a stuck run holds
+    #[command(name = "release-lock")]
+    ReleaseLock {
+        #[arg(short, long)]
+        ticket: i64,
+    },
+
+    /// Run workflows with message triggers as messages arrive, until Ctrl-C
+    #[command(name = "listen")]
+    Listen,
+
     #[command(name = "clone-workflow")]
     CloneWorkflow {
         #[arg(short, long)]
@@ -54,7 +323,22 @@ async fn main() {
 
     if let Some(file) = args.file {
         tracing::info!(file = %file, "CLI starting");
-        execute_workflow_from_file(file).await;
+        let output = Output::new(OutputMode::from_flags(args.quiet, args.verbose, args.json));
+        let options = RunOptions {
+            non_interactive: args.non_interactive,
+            fail_on_warning: args.fail_on_warning,
+            sandbox: args.sandbox,
+            dry_run: args.dry_run,
+            profile_run: args.profile_run,
+            profile_trace: args.profile_trace,
+            labels: args.label,
+            bounds: RunBounds {
+                from: args.from,
+                until: args.until,
+                context_from: args.context_from,
+            },
+        };
+        execute_workflow_from_file(file, args.param, options, output).await;
     } else {
         eprintln!("No command or workflow file specified. Use --help for usage.");
         std::process::exit(1);
@@ -67,12 +351,22 @@ async fn handle_command(command: Commands) {
         eprintln!("Failed to initialize database: {}", e);
         std::process::exit(1);
     }
+    if let Err(e) = load_log_settings().await {
+        tracing::warn!(error = %e, "Failed to load log levels, keeping the defaults");
+    }
 
     if let Err(e) = populate_initial_data().await {
         tracing::error!("Failed to populate initial data: {}", e);
         eprintln!("Failed to populate initial data: {}", e);
     }
 
+    if let Err(e) = load_workflow_limits().await {
+        tracing::warn!(error = %e, "Failed to load workflow limits, using defaults");
+    }
+    if let Err(e) = load_handler_policy().await {
+        tracing::warn!(error = %e, "Failed to load handler policy, allowing every handler");
+    }
+
     match command {
         Commands::ListSystemWorkflows => {
             if let Ok(store) = s_e_e_core::get_global_store() {
@@ -109,6 +403,536 @@ async fn handle_command(command: Commands) {
                 }
             }
         }
+        Commands::ListExamples => match list_example_workflows() {
+            Ok(examples) => {
+                println!("Examples ({}):", examples.len());
+                for example in examples {
+                    println!("  - {} ({})", example.name, example.id);
+                    if let Some(desc) = &example.description {
+                        println!("    {}", desc);
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to list examples: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Commands::ListSnippets => match list_snippets().await {
+            Ok(snippets) => {
+                println!("Snippets ({}):", snippets.len());
+                for snippet in snippets {
+                    println!("  - {} ({})", snippet.name, snippet.id);
+                    if let Some(desc) = &snippet.description {
+                        println!("    {}", desc);
+                    }
+                    for parameter in &snippet.parameters {
+                        match &parameter.default {
+                            Some(default) => println!("    with {} = {}", parameter.name, default),
+                            None => println!("    with {} (required)", parameter.name),
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to list snippets: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Commands::AddExample { id, all } => {
+            let result = match (id, all) {
+                (Some(id), _) => add_example_workflow(&id)
+                    .await
+                    .map(|workflow| format!("Added '{}' to your workflows", workflow.name)),
+                (None, true) => add_all_example_workflows()
+                    .await
+                    .map(|added| format!("Added {} example workflow(s)", added)),
+                (None, false) => {
+                    eprintln!("Pass --id <example id> or --all");
+                    std::process::exit(1);
+                }
+            };
+            match result {
+                Ok(message) => println!("{}", message),
+                Err(e) => {
+                    eprintln!("Failed to add example: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::RefreshExamples => match refresh_examples().await {
+            Ok(refresh) => {
+                println!("Updated {} example workflow(s)", refresh.updated.len());
+                for id in &refresh.skipped_edited {
+                    println!("  Skipped {} (edited)", id);
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to refresh examples: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Commands::ClearTaskCache => match clear_task_result_cache().await {
+            Ok(removed) => {
+                println!("Removed {} cached task result(s)", removed);
+                match clear_directory_cache() {
+                    Ok(removed) => println!("Removed {} cached directory archive(s)", removed),
+                    Err(e) => {
+                        eprintln!("Failed to clear directory cache: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to clear task cache: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Commands::Vacuum => match vacuum_storage().await {
+            Ok(vacuum) => {
+                println!(
+                    "Removed {} unreferenced blob(s), {} bytes reclaimed",
+                    vacuum.removed, vacuum.bytes_reclaimed
+                );
+                if let Ok(stats) = storage_stats().await {
+                    println!(
+                        "{} blob(s), {} bytes, {} reference(s) remain",
+                        stats.blobs, stats.bytes, stats.references
+                    );
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to vacuum storage: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Commands::Archive { older_than_days } => {
+            let archived = match default_archive_dir() {
+                Ok(dir) => {
+                    archive_executions_older_than(&dir, Duration::days(older_than_days.into()))
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+            match archived {
+                Ok(archived) => {
+                    let bytes: u64 = archived.iter().map(|entry| entry.size_bytes).sum();
+                    println!(
+                        "Archived {} execution(s), {} bytes written",
+                        archived.len(),
+                        bytes
+                    );
+                }
+                Err(e) => {
+                    eprintln!("Failed to archive executions: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::ListArchived => match list_archived_executions().await {
+            Ok(archived) if archived.is_empty() => println!("No archived executions"),
+            Ok(archived) => {
+                for entry in archived {
+                    println!(
+                        "{}  {}  {}  {}  archived {}",
+                        entry.id,
+                        entry.workflow_name,
+                        entry.status,
+                        entry.created_at.format("%Y-%m-%d %H:%M"),
+                        entry.archived_at.format("%Y-%m-%d")
+                    );
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to list archived executions: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Commands::RestoreArchived { id } => {
+            let restored = match default_archive_dir() {
+                Ok(dir) => restore_archived_execution(&dir, &id).await,
+                Err(e) => Err(e),
+            };
+            match restored {
+                Ok(execution) => println!(
+                    "Restored execution {} ({})",
+                    execution.id, execution.workflow_name
+                ),
+                Err(e) => {
+                    eprintln!("Failed to restore execution: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::SaveSnapshot { execution_id, name } => {
+            match save_snapshot_as_workflow(&execution_id, name.as_deref()).await {
+                Ok(workflow) => println!("Saved workflow '{}' ({})", workflow.name, workflow.id),
+                Err(e) => {
+                    eprintln!("Failed to save snapshot: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::ExportBundle {
+            id,
+            output,
+            signing_key,
+        } => {
+            let key = match signing_key.map(fs::read_to_string).transpose() {
+                Ok(key) => key,
+                Err(e) => {
+                    eprintln!("Failed to read signing key: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            match export_workflow_bundle(&id, key.as_deref()).await {
+                Ok(bundle) => {
+                    if let Err(e) = fs::write(&output, bundle) {
+                        eprintln!("Failed to write '{}': {}", output, e);
+                        std::process::exit(1);
+                    }
+                    let signed = if key.is_some() { "signed " } else { "" };
+                    println!("Wrote {}bundle for '{}' to {}", signed, id, output);
+                }
+                Err(e) => {
+                    eprintln!("Failed to export bundle: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::LabelExecution { id, label } => match set_execution_labels(&id, label).await {
+            Ok(labels) if labels.is_empty() => println!("Cleared labels on {}", id),
+            Ok(labels) => println!("Labeled {}: {}", id, labels.join(", ")),
+            Err(e) => {
+                eprintln!("Failed to label execution: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Commands::WorkflowReadme { id, output } => match generate_workflow_readme(&id).await {
+            Ok(readme) => match output {
+                Some(output) => {
+                    if let Err(e) = fs::write(&output, readme) {
+                        eprintln!("Failed to write '{}': {}", output, e);
+                        std::process::exit(1);
+                    }
+                    println!("Wrote README for '{}' to {}", id, output);
+                }
+                None => print!("{}", readme),
+            },
+            Err(e) => {
+                eprintln!("Failed to generate README: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Commands::WorkflowGraph { id, layout, output } => {
+            let mode = match layout
+                .as_deref()
+                .map(|name| (name, LayoutMode::from_name(name)))
+            {
+                Some((name, None)) => {
+                    eprintln!(
+                        "Unknown layout '{}'; expected one of: {}",
+                        name,
+                        LayoutMode::ALL.map(|mode| mode.as_str()).join(", ")
+                    );
+                    std::process::exit(1);
+                }
+                Some((_, mode)) => mode,
+                None => None,
+            };
+            let graph = match export_workflow_graph(&id, mode).await {
+                Ok(graph) => graph,
+                Err(e) => {
+                    eprintln!("Failed to export graph: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            let json = match serde_json::to_string_pretty(&graph) {
+                Ok(json) => json,
+                Err(e) => {
+                    eprintln!("Failed to serialize graph: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            match output {
+                Some(output) => {
+                    if let Err(e) = fs::write(&output, json) {
+                        eprintln!("Failed to write '{}': {}", output, e);
+                        std::process::exit(1);
+                    }
+                    println!("Wrote graph for '{}' to {}", id, output);
+                }
+                None => println!("{}", json),
+            }
+        }
+        Commands::ExecutionManifest { id } => match get_execution_manifest(&id).await {
+            Ok(Some(manifest)) => match serde_json::to_string_pretty(&manifest) {
+                Ok(json) => println!("{}", json),
+                Err(e) => {
+                    eprintln!("Failed to serialize manifest: {}", e);
+                    std::process::exit(1);
+                }
+            },
+            Ok(None) => {
+                eprintln!(
+                    "Execution {} has no manifest; it ran before they were recorded",
+                    id
+                );
+                std::process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("Failed to load manifest: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Commands::ExportExecutions {
+            since,
+            until,
+            format,
+            output,
+        } => {
+            let Some(format) = ExportFormat::from_name(&format) else {
+                eprintln!(
+                    "Unknown format '{}'; expected one of: {}",
+                    format,
+                    ExportFormat::ALL.map(|format| format.as_str()).join(", ")
+                );
+                std::process::exit(1);
+            };
+            let from = match parse_time(&since, false) {
+                Ok(from) => from,
+                Err(e) => {
+                    eprintln!("Invalid --since: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            let to = match until.as_deref().map(|until| parse_time(until, true)) {
+                Some(Ok(to)) => to,
+                Some(Err(e)) => {
+                    eprintln!("Invalid --until: {}", e);
+                    std::process::exit(1);
+                }
+                None => chrono::Utc::now(),
+            };
+            if let Err(e) = init_read_only_store().await {
+                tracing::warn!(error = %e, "Exporting through the main connection instead");
+            }
+            match export_execution_data(from, to, format, std::path::Path::new(&output)).await {
+                Ok(export) => {
+                    println!(
+                        "Exported {} execution(s) and {} task(s)",
+                        export.executions, export.tasks
+                    );
+                    for file in export.files {
+                        println!("  {}", file.display());
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Failed to export executions: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::SchemaDocs { format, output } => {
+            let Some(format) = SchemaDocsFormat::from_name(&format) else {
+                eprintln!(
+                    "Unknown format '{}'; expected one of: {}",
+                    format,
+                    SchemaDocsFormat::ALL
+                        .map(|format| format.as_str())
+                        .join(", ")
+                );
+                std::process::exit(1);
+            };
+            let docs = match schema_docs().await.and_then(|docs| docs.render(format)) {
+                Ok(docs) => docs,
+                Err(e) => {
+                    eprintln!("Failed to generate schema docs: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            match output {
+                Some(output) => {
+                    if let Err(e) = fs::write(&output, docs) {
+                        eprintln!("Failed to write '{}': {}", output, e);
+                        std::process::exit(1);
+                    }
+                    println!("Wrote schema docs to {}", output);
+                }
+                None => print!("{}", docs),
+            }
+        }
+        Commands::Locks => match list_resource_locks().await {
+            Ok(locks) if locks.is_empty() => println!("No resource locks held"),
+            Ok(locks) => {
+                for lock in locks {
+                    let state = match lock.position {
+                        0 => "held".to_string(),
+                        position => format!("waiting, {} ahead", position),
+                    };
+                    println!(
+                        "{}  {}  {}  execution {} task {}  since {}",
+                        lock.ticket,
+                        lock.resource,
+                        state,
+                        lock.execution_id,
+                        lock.task_id,
+                        lock.requested_at.format("%Y-%m-%d %H:%M:%S")
+                    );
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to list resource locks: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Commands::RerunTask {
+            execution_id,
+            task,
+            downstream,
+        } => {
+            let output = Output::new(OutputMode::Normal);
+            output.message(&format!("Re-running task {} of {}", task, execution_id));
+            let callback: OutputCallback = std::sync::Arc::new(move |event| output.event(&event));
+            let execution = tokio::select! {
+                result = rerun_task(&execution_id, &task, downstream, Some(callback)) => result,
+                _ = tokio::signal::ctrl_c() => {
+                    tracing::warn!("Interrupted, killing running task processes");
+                    interrupt_runs().await;
+                    Err(CoreError::Engine(EngineError::Cancelled))
+                }
+            };
+            finish_run(execution, output);
+        }
+        Commands::CreateTest { execution_id, name } => {
+            match create_test_from_execution(&execution_id, name.as_deref()).await {
+                Ok(test) => println!(
+                    "Recorded test '{}' ({}) for workflow {}",
+                    test.name, test.id, test.workflow_id
+                ),
+                Err(e) => {
+                    eprintln!("Failed to create test: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::Test { workflow_id } => {
+            let reports = match run_workflow_tests(&workflow_id).await {
+                Ok(reports) => reports,
+                Err(e) => {
+                    eprintln!("Failed to run tests: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            if reports.is_empty() {
+                println!(
+                    "Workflow {} has no tests; record one with create-test --execution-id <ID>",
+                    workflow_id
+                );
+                return;
+            }
+            for report in &reports {
+                print_test_report(report);
+            }
+            let failed = reports.iter().filter(|report| !report.passed).count();
+            println!("{} passed, {} failed", reports.len() - failed, failed);
+            if failed > 0 {
+                std::process::exit(1);
+            }
+        }
+        Commands::ReleaseLock { ticket } => match release_resource_lock(ticket).await {
+            Ok(true) => println!("Released lock ticket {}", ticket),
+            Ok(false) => {
+                eprintln!("No lock ticket {}", ticket);
+                std::process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("Failed to release lock: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Commands::ImportBundle { file } => {
+            let text = match fs::read_to_string(&file) {
+                Ok(text) => text,
+                Err(e) => {
+                    eprintln!("Failed to read '{}': {}", file, e);
+                    std::process::exit(1);
+                }
+            };
+            let source = std::path::Path::new(&file)
+                .file_name()
+                .map_or(file.clone(), |name| name.to_string_lossy().into_owned());
+            match import_workflow_bundle(&source, &text).await {
+                Ok(workflow) => {
+                    println!("Imported workflow '{}' ({})", workflow.name, workflow.id);
+                    if let Ok(Some(prompt)) = workflow_trust_prompt(&workflow.id).await {
+                        println!(
+                            "It is unsigned and runs these commands; review them, then use trust-workflow --id {}:",
+                            workflow.id
+                        );
+                        for command in prompt.commands {
+                            println!("  {}", command);
+                        }
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Failed to import bundle: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Commands::TrustWorkflow { id } => {
+            if let Err(e) = trust_workflow(&id).await {
+                eprintln!("Failed to trust workflow: {}", e);
+                std::process::exit(1);
+            }
+            println!("Trusted workflow '{}'", id);
+        }
+        Commands::Listen => {
+            let (triggers, problems) = match load_message_triggers().await {
+                Ok(loaded) => loaded,
+                Err(e) => {
+                    eprintln!("Failed to load message triggers: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            for problem in &problems {
+                eprintln!("{}", problem);
+            }
+            if triggers.is_empty() {
+                eprintln!("No workflow has a usable message trigger");
+                std::process::exit(1);
+            }
+            for trigger in &triggers {
+                println!(
+                    "Listening on '{}' ({} connection '{}') for '{}'",
+                    trigger.source,
+                    trigger.connection.backend,
+                    trigger.connection.name,
+                    trigger.workflow_name
+                );
+            }
+
+            let mut lifecycle = Lifecycle::new();
+            lifecycle.start("message triggers", |shutdown| {
+                run_message_triggers(triggers, shutdown)
+            });
+            let _ = tokio::signal::ctrl_c().await;
+            println!(
+                "Stopping; waiting up to {}s for running workflows to finish (Ctrl-C again to stop now)",
+                DEFAULT_SHUTDOWN_TIMEOUT.as_secs()
+            );
+            let interrupted = tokio::select! {
+                report = lifecycle.shutdown() => report.interrupted,
+                _ = tokio::signal::ctrl_c() => interrupt_runs().await,
+            };
+            for execution_id in interrupted {
+                eprintln!(
+                    "Execution {} did not finish and was saved as failed",
+                    execution_id
+                );
+            }
+        }
         Commands::CloneWorkflow {
             system_id: _,
             name: _,
@@ -126,19 +950,45 @@ async fn handle_command(command: Commands) {
     }
 }
 
-async fn execute_workflow_from_file(file: String) {
+struct RunOptions {
+    non_interactive: bool,
+    fail_on_warning: bool,
+    sandbox: bool,
+    dry_run: bool,
+    profile_run: bool,
+    profile_trace: Option<String>,
+    labels: Vec<String>,
+    bounds: RunBounds,
+}
+
+async fn execute_workflow_from_file(
+    file: String,
+    raw_params: Vec<String>,
+    options: RunOptions,
+    output: Output,
+) {
     if let Err(e) = init_global_store().await {
         tracing::error!(error = %e, "Failed to initialize global store");
-        eprintln!("Failed to initialize database: {}", e);
-        std::process::exit(1);
+        output.error(&format!("Failed to initialize database: {}", e));
+        ExitCode::TaskFailure.exit();
+    }
+    if let Err(e) = load_log_settings().await {
+        tracing::warn!(error = %e, "Failed to load log levels, keeping the defaults");
+    }
+
+    if let Err(e) = load_workflow_limits().await {
+        tracing::warn!(error = %e, "Failed to load workflow limits, using defaults");
+    }
+    if let Err(e) = load_handler_policy().await {
+        tracing::warn!(error = %e, "Failed to load handler policy, allowing every handler");
     }
 
     let workflow_content = match fs::read_to_string(&file) {
         Ok(content) => content,
         Err(e) => {
             tracing::error!(error = %e, file = %file, "Failed to read workflow file");
-            eprintln!("Failed to read workflow file '{}': {}", file, e);
-            std::process::exit(1);
+            output.error(&format!("Failed to read workflow file '{}': {}", file, e));
+            ExitCode::ValidationError.exit();
         }
     };
 
@@ -146,11 +996,76 @@ async fn execute_workflow_from_file(file: String) {
         Ok(json) => json,
         Err(e) => {
             tracing::error!(error = %e, file = %file, "Failed to parse workflow JSON");
-            eprintln!("Failed to parse workflow JSON: {}", e);
-            std::process::exit(1);
+            output.error(&format!("Failed to parse workflow JSON: {}", e));
+            ExitCode::ValidationError.exit();
         }
     };
 
+    if let Err(errors) = validate_workflow_json(&workflow_content) {
+        tracing::error!(error = %errors, file = %file, "Workflow failed validation");
+        for error in &errors.errors {
+            output.validation_error(error);
+        }
+        ExitCode::ValidationError.exit();
+    }
+
+    let warnings = lint_workflow(&workflow_json);
+    for warning in &warnings {
+        output.warning(&warning.to_string());
+    }
+    if options.fail_on_warning && !warnings.is_empty() {
+        output.error(&format!(
+            "{} lint warning(s) and --fail-on-warning is set",
+            warnings.len()
+        ));
+        ExitCode::ValidationError.exit();
+    }
+
+    let params =
+        match params::collect_parameters(&workflow_json, &raw_params, options.non_interactive) {
+            Ok(params) => params,
+            Err(e) => {
+                tracing::error!(error = %e, file = %file, "Invalid workflow parameters");
+                output.error(&e);
+                ExitCode::ValidationError.exit();
+            }
+        };
+
+    if options.dry_run {
+        let name = workflow_json
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unnamed Workflow");
+        output.workflow_started(&format!("{} (dry run)", name));
+        match dry_run_workflow(&workflow_content, params).await {
+            Ok(result) => {
+                output.plan(&result);
+                let code = ExitCode::for_result(&result);
+                if code != ExitCode::Success {
+                    code.exit();
+                }
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Dry run failed");
+                output.execution_error(&e);
+                ExitCode::for_error(&e).exit();
+            }
+        }
+        return;
+    }
+
+    if options.sandbox {
+        let name = workflow_json
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unnamed Workflow");
+        output.workflow_started(&format!("{} (sandbox)", name));
+        let callback: OutputCallback = std::sync::Arc::new(move |event| output.event(&event));
+        let execution = run_sandboxed(&workflow_content, params, Some(callback)).await;
+        finish_run(execution, output);
+        return;
+    }
+
     let workflow_id = workflow_json
         .get("id")
         .and_then(|v| v.as_str())
@@ -180,12 +1095,60 @@ async fn execute_workflow_from_file(file: String) {
 
     if let Err(e) = store.save_workflow(&workflow_definition).await {
         tracing::error!(error = %e, workflow_id = %workflow_id, "Failed to save workflow");
-        eprintln!("Failed to save workflow: {}", e);
-        std::process::exit(1);
+        output.error(&format!("Failed to save workflow: {}", e));
+        ExitCode::TaskFailure.exit();
+    }
+
+    output.workflow_started(&workflow_definition.name);
+    let callback: OutputCallback = std::sync::Arc::new(move |event| output.event(&event));
+    let run = async {
+        if options.profile_run {
+            execute_workflow_by_id_profiled(
+                workflow_id,
+                params,
+                options.labels,
+                options.bounds,
+                Some(callback),
+            )
+            .await
+        } else {
+            execute_workflow_by_id_with_bounds(
+                workflow_id,
+                params,
+                options.labels,
+                options.bounds,
+                Some(callback),
+            )
+            .await
+        }
+    };
+    let execution = tokio::select! {
+        result = run => result,
+        _ = tokio::signal::ctrl_c() => {
+            tracing::warn!("Interrupted, killing running task processes");
+            interrupt_runs().await;
+            Err(CoreError::Engine(EngineError::Cancelled))
+        }
+    };
+
+    if let (Some(path), Ok(result)) = (&options.profile_trace, &execution) {
+        if let Some(profile) = &result.profile {
+            let trace = profile.to_chrome_trace().to_string();
+            match fs::write(path, trace) {
+                Ok(()) => output.message(&format!("Profile trace written to {}", path)),
+                Err(e) => output.warning(&format!(
+                    "Failed to write profile trace to '{}': {}",
+                    path, e
+                )),
+            }
+        }
     }
+    finish_run(execution, output);
+}
 
-    let output: OutputCallback = std::sync::Arc::new(|line| println!("{}", line));
-    match execute_workflow_by_id(workflow_id, Some(output)).await {
+/// Print how a run ended and exit with its code when it did not succeed.
+fn finish_run(execution: Result<WorkflowResult, CoreError>, output: Output) {
+    match execution {
         Ok(result) => {
             tracing::info!(
                 workflow = %result.workflow_name,
@@ -193,17 +1156,69 @@ async fn execute_workflow_from_file(file: String) {
                 task_count = result.tasks.len(),
                 "Workflow completed"
             );
-            println!(
-                "Workflow '{}' completed: success={} tasks={}",
-                result.workflow_name,
-                result.success,
-                result.tasks.len()
-            );
+            output.result(&result);
+            if let Some(profile) = &result.profile {
+                output.profile(profile);
+            }
+            let code = ExitCode::for_result(&result);
+            if code != ExitCode::Success {
+                code.exit();
+            }
         }
         Err(e) => {
-            tracing::error!(error = %e, "Workflow execution failed");
-            eprintln!("Execution failed: {}", e);
-            std::process::exit(1);
+            tracing::error!(error = %e, category = %e.category(), "Workflow execution failed");
+            output.execution_error(&e);
+            ExitCode::for_error(&e).exit();
         }
     }
 }
+
+fn print_test_report(report: &WorkflowTestReport) {
+    let status = if report.passed { "PASS" } else { "FAIL" };
+    println!("{}  {} ({})", status, report.name, report.test_id);
+    if report.actual_success != report.expected_success {
+        let outcome = |success: bool| if success { "succeed" } else { "fail" };
+        println!(
+            "      expected the run to {}, but it did not",
+            outcome(report.expected_success)
+        );
+    }
+    for task in &report.unrecorded_tasks {
+        println!("      task '{}' ran but has no recorded result", task);
+    }
+    let value = |value: &Option<serde_json::Value>| {
+        value
+            .as_ref()
+            .map_or("nothing".to_string(), |value| value.to_string())
+    };
+    for mismatch in &report.mismatches {
+        println!(
+            "      {}: expected {}, got {}",
+            mismatch.path,
+            value(&mismatch.expected),
+            value(&mismatch.actual)
+        );
+    }
+    if report.mismatch_count > report.mismatches.len() {
+        println!(
+            "      ... and {} more differences",
+            report.mismatch_count - report.mismatches.len()
+        );
+    }
+}
+
+/// A `YYYY-MM-DD` day, as its start or, with `end_of_day`, the start of the
+/// next day, or an RFC 3339 time.
+fn parse_time(value: &str, end_of_day: bool) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    if let Ok(day) = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let day = if end_of_day {
+            day + Duration::days(1)
+        } else {
+            day
+        };
+        return Ok(day.and_time(chrono::NaiveTime::MIN).and_utc());
+    }
+    chrono::DateTime::parse_from_rfc3339(value)
+        .map(|time| time.with_timezone(&chrono::Utc))
+        .map_err(|_| format!("'{}' is neither YYYY-MM-DD nor an RFC 3339 time", value))
+}
@@ -0,0 +1,523 @@
+use s_e_e_core::{
+    CoreError, ErrorCategory, OutputEvent, RunProfile, ValidationError, WorkflowResult,
+    VALIDATION_CODES_VERSION,
+};
+use s_e_e_engine::{AuditEntry, AuditStatus, TaskInfo, TaskStatus};
+use serde_json::json;
+use std::io::IsTerminal;
+
+/// How much the CLI prints while running a workflow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Colored status lines and a summary.
+    Normal,
+    /// Only the final result line.
+    Quiet,
+    /// Status lines plus every task's output as it is written.
+    Verbose,
+    /// One JSON object per line (NDJSON) for each event.
+    Json,
+}
+
+impl OutputMode {
+    pub fn from_flags(quiet: bool, verbose: bool, json: bool) -> Self {
+        if json {
+            OutputMode::Json
+        } else if quiet {
+            OutputMode::Quiet
+        } else if verbose {
+            OutputMode::Verbose
+        } else {
+            OutputMode::Normal
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Yellow,
+    Dim,
+    Bold,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+/// Colors for task labels, picked by task id so a task keeps its color for the whole run.
+const TASK_COLORS: [Color; 5] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Blue,
+    Color::Yellow,
+    Color::Green,
+];
+
+impl Color {
+    fn code(self) -> &'static str {
+        match self {
+            Color::Red => "31",
+            Color::Green => "32",
+            Color::Yellow => "33",
+            Color::Dim => "2",
+            Color::Bold => "1",
+            Color::Blue => "34",
+            Color::Magenta => "35",
+            Color::Cyan => "36",
+        }
+    }
+}
+
+/// Formats workflow progress and results for the terminal.
+#[derive(Debug, Clone, Copy)]
+pub struct Output {
+    mode: OutputMode,
+    color: bool,
+}
+
+impl Output {
+    pub fn new(mode: OutputMode) -> Self {
+        let color = std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal();
+        Self { mode, color }
+    }
+
+    /// A progress message from the engine.
+    pub fn message(&self, message: &str) {
+        match self.mode {
+            OutputMode::Quiet => {}
+            OutputMode::Json => emit(json!({ "event": "message", "message": message })),
+            OutputMode::Normal | OutputMode::Verbose => {
+                println!("{} {}", self.paint(Color::Dim, "›"), message)
+            }
+        }
+    }
+
+    /// A line of live output. Task lines are labelled with their task so the output
+    /// of tasks running in parallel stays readable.
+    pub fn event(&self, event: &OutputEvent) {
+        let Some(task_id) = event.task_id.as_deref() else {
+            self.message(&event.line);
+            return;
+        };
+        match self.mode {
+            OutputMode::Quiet | OutputMode::Normal => {}
+            OutputMode::Json => {
+                let mut line = json!({
+                    "event": "output",
+                    "execution_id": event.execution_id,
+                    "sequence": event.sequence,
+                    "task_id": task_id,
+                    "task_name": event.task_name,
+                    "line": event.line,
+                });
+                if let Some(progress) = &event.progress {
+                    line["progress"] = json!(progress);
+                }
+                if let Some(finished) = event.finished {
+                    line["finished"] = json!(finished);
+                }
+                emit(line)
+            }
+            OutputMode::Verbose => {
+                let label = format!("[{}]", event.task_name.as_deref().unwrap_or(task_id));
+                println!("{} {}", self.paint(task_color(task_id), &label), event.line)
+            }
+        }
+    }
+
+    pub fn workflow_started(&self, workflow_name: &str) {
+        match self.mode {
+            OutputMode::Quiet => {}
+            OutputMode::Json => {
+                emit(json!({ "event": "workflow_started", "workflow": workflow_name }))
+            }
+            OutputMode::Normal | OutputMode::Verbose => {
+                println!("{} {}", self.paint(Color::Bold, "Running"), workflow_name)
+            }
+        }
+    }
+
+    /// Print every task's outcome followed by the overall result.
+    pub fn result(&self, result: &WorkflowResult) {
+        match self.mode {
+            OutputMode::Quiet => {}
+            OutputMode::Json => {
+                for transition in task_transitions(result) {
+                    self.task(&transition);
+                }
+            }
+            OutputMode::Normal | OutputMode::Verbose => self.tasks_by_stage(result),
+        }
+
+        match self.mode {
+            OutputMode::Json => emit(json!({
+                "event": "workflow_finished",
+                "workflow": result.workflow_name,
+                "execution_id": result.execution_id,
+                "success": result.success,
+                "tasks": result.tasks.len(),
+                "errors": result.errors,
+                "failure": result.failure_category(),
+                "outputs": result.outputs,
+                "result": result.to_versioned_json(),
+            })),
+            _ => {
+                let (color, label) = if result.success {
+                    (Color::Green, "succeeded")
+                } else {
+                    (Color::Red, "failed")
+                };
+                if self.mode != OutputMode::Quiet {
+                    for error in &result.errors {
+                        eprintln!("  {} {}", self.paint(Color::Red, "error:"), error);
+                    }
+                }
+                println!(
+                    "Workflow '{}' {} ({} tasks, execution {})",
+                    result.workflow_name,
+                    self.paint(color, label),
+                    result.tasks.len(),
+                    result.execution_id
+                );
+                if let Some(workspace) = &result.workspace {
+                    println!("Workspace kept at {}", workspace);
+                }
+                if self.mode != OutputMode::Quiet {
+                    for (name, value) in &result.outputs {
+                        let value = match value {
+                            serde_json::Value::String(text) => text.clone(),
+                            other => other.to_string(),
+                        };
+                        println!(
+                            "  {} {}",
+                            self.paint(Color::Bold, &format!("{}:", name)),
+                            value
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// What a dry run found: each task that would run and what it would do,
+    /// then the tasks it would skip.
+    pub fn plan(&self, result: &WorkflowResult) {
+        let skipped: Vec<&TaskInfo> = result
+            .tasks
+            .iter()
+            .filter(|task| task.status == TaskStatus::Skipped)
+            .collect();
+        match self.mode {
+            OutputMode::Quiet => {}
+            OutputMode::Json => {
+                for task in &result.planned {
+                    emit(json!({
+                        "event": "planned_task",
+                        "task_id": task.task_id,
+                        "name": task.task_name,
+                        "handler": task.handler,
+                        "action": task.action,
+                        "function": task.function,
+                        "unresolved": task.unresolved,
+                    }));
+                }
+                emit(json!({
+                    "event": "dry_run_finished",
+                    "workflow": result.workflow_name,
+                    "success": result.success,
+                    "planned": result.planned.len(),
+                    "skipped": skipped.iter().map(|task| &task.id).collect::<Vec<_>>(),
+                    "errors": result.errors,
+                    "result": result.to_versioned_json(),
+                }));
+                return;
+            }
+            OutputMode::Normal | OutputMode::Verbose => {
+                for task in &result.planned {
+                    println!(
+                        "  {} {} {}",
+                        self.paint(Color::Cyan, "▸"),
+                        task.task_name,
+                        self.paint(Color::Dim, &format!("({})", task.handler))
+                    );
+                    println!("      {}", task.action);
+                    for unresolved in &task.unresolved {
+                        println!(
+                            "      {} {}",
+                            self.paint(Color::Yellow, "undecided:"),
+                            unresolved
+                        );
+                    }
+                }
+                for task in &skipped {
+                    println!(
+                        "  {} {} {}",
+                        self.paint(Color::Dim, "↷"),
+                        task.name,
+                        self.paint(Color::Dim, "(skipped)")
+                    );
+                }
+                for error in &result.errors {
+                    eprintln!("  {} {}", self.paint(Color::Red, "error:"), error);
+                }
+            }
+        }
+        println!(
+            "Dry run of '{}': {} tasks would run, {} skipped, {} errors",
+            result.workflow_name,
+            result.planned.len(),
+            skipped.len(),
+            result.errors.len()
+        );
+    }
+
+    /// Where a profiled run's time went, per phase. Spans of tasks that ran
+    /// in parallel add up, so a phase can total more than the run took.
+    pub fn profile(&self, profile: &RunProfile) {
+        let summary = profile.summary();
+        match self.mode {
+            OutputMode::Quiet => {}
+            OutputMode::Json => emit(json!({
+                "event": "run_profile",
+                "total_us": profile.total_us,
+                "phases": summary,
+            })),
+            OutputMode::Normal | OutputMode::Verbose => {
+                println!(
+                    "{} ({} total)",
+                    self.paint(Color::Bold, "Profile"),
+                    format_micros(profile.total_us)
+                );
+                for phase in &summary {
+                    println!(
+                        "  {:<12} {:>10}  {}",
+                        phase.phase.label(),
+                        format_micros(phase.total_us),
+                        self.paint(
+                            Color::Dim,
+                            &format!(
+                                "{} span(s), longest {}",
+                                phase.count,
+                                format_micros(phase.longest_us)
+                            )
+                        )
+                    );
+                }
+            }
+        }
+    }
+
+    pub fn warning(&self, message: &str) {
+        match self.mode {
+            OutputMode::Quiet => {}
+            OutputMode::Json => emit(json!({ "event": "warning", "message": message })),
+            _ => eprintln!("{} {}", self.paint(Color::Yellow, "warning:"), message),
+        }
+    }
+
+    pub fn error(&self, message: &str) {
+        match self.mode {
+            OutputMode::Json => emit(json!({ "event": "error", "message": message })),
+            _ => eprintln!("{} {}", self.paint(Color::Red, "error:"), message),
+        }
+    }
+
+    /// A workflow validation failure. JSON mode emits the full diagnostic so
+    /// scripts can match on its code and pointer.
+    pub fn validation_error(&self, error: &ValidationError) {
+        match self.mode {
+            OutputMode::Json => emit(json!({
+                "event": "validation_error",
+                "codes_version": VALIDATION_CODES_VERSION,
+                "diagnostic": error,
+            })),
+            _ => self.error(&error.to_string()),
+        }
+    }
+
+    /// A run that ended in an error rather than a result. JSON mode adds the
+    /// error category, and the exit code when a task process failed.
+    pub fn execution_error(&self, error: &CoreError) {
+        let category = error.category();
+        match self.mode {
+            OutputMode::Json => {
+                let mut event = json!({
+                    "event": "error",
+                    "message": format!("Execution failed: {}", error),
+                    "category": category.as_str(),
+                });
+                if let ErrorCategory::HandlerFailure {
+                    exit_code: Some(code),
+                } = category
+                {
+                    event["exit_code"] = json!(code);
+                }
+                emit(event);
+            }
+            _ if category == ErrorCategory::Cancelled => self.error("Execution interrupted"),
+            _ => self.error(&format!("Execution failed: {}", error)),
+        }
+    }
+
+    /// Task outcomes under a header per stage, then any tasks outside a stage.
+    fn tasks_by_stage(&self, result: &WorkflowResult) {
+        let transitions = task_transitions(result);
+        for stage in &result.stages {
+            let color = match stage.status {
+                TaskStatus::Complete => Color::Green,
+                TaskStatus::Failed => Color::Red,
+                TaskStatus::WaitingForInput => Color::Yellow,
+                TaskStatus::Pending | TaskStatus::InProgress | TaskStatus::Skipped => Color::Dim,
+            };
+            println!(
+                "{} {}",
+                self.paint(Color::Bold, &format!("Stage {}", stage.name)),
+                self.paint(color, &format!("({})", stage.status.as_str()))
+            );
+            for transition in transitions
+                .iter()
+                .filter(|transition| stage.task_ids.contains(&transition.task.id))
+            {
+                self.task(transition);
+            }
+        }
+        for transition in transitions.iter().filter(|transition| {
+            !result
+                .stages
+                .iter()
+                .any(|stage| stage.task_ids.contains(&transition.task.id))
+        }) {
+            self.task(transition);
+        }
+    }
+
+    fn task(&self, transition: &TaskTransition) {
+        if self.mode == OutputMode::Json {
+            let mut event = json!({
+                "event": "task",
+                "task_id": transition.task.id,
+                "name": transition.task.name,
+                "status": transition.state.as_str(),
+            });
+            if let Some(entry) = transition.entry {
+                event["timestamp"] = json!(entry.timestamp);
+                event["message"] = json!(entry.message);
+                event["changes_count"] = json!(entry.changes_count);
+            }
+            emit(event);
+            return;
+        }
+
+        let (color, symbol) = match transition.state {
+            TaskState::Succeeded => (Color::Green, "✔"),
+            TaskState::Failed => (Color::Red, "✘"),
+            TaskState::WaitingForInput => (Color::Yellow, "⏸"),
+            TaskState::Skipped => (Color::Dim, "↷"),
+            TaskState::NotRun => (Color::Dim, "·"),
+        };
+        println!(
+            "  {} {} {}",
+            self.paint(color, symbol),
+            transition.task.name,
+            self.paint(Color::Dim, &format!("({})", transition.state.as_str()))
+        );
+    }
+
+    fn paint(&self, color: Color, text: &str) -> String {
+        if self.color {
+            format!("\x1b[{}m{}\x1b[0m", color.code(), text)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TaskState {
+    Succeeded,
+    Failed,
+    WaitingForInput,
+    Skipped,
+    NotRun,
+}
+
+impl TaskState {
+    fn as_str(self) -> &'static str {
+        match self {
+            TaskState::Succeeded => "succeeded",
+            TaskState::Failed => "failed",
+            TaskState::WaitingForInput => "waiting_for_input",
+            TaskState::Skipped => "skipped",
+            TaskState::NotRun => "not_run",
+        }
+    }
+}
+
+struct TaskTransition<'a> {
+    task: &'a TaskInfo,
+    state: TaskState,
+    entry: Option<&'a AuditEntry>,
+}
+
+/// Tasks in the order they finished, followed by those that never ran.
+///
+/// The audit trail is the source of truth for success or failure, since failed
+/// tasks are reported as complete in the task list.
+fn task_transitions(result: &WorkflowResult) -> Vec<TaskTransition<'_>> {
+    let mut transitions: Vec<TaskTransition> = result
+        .audit_trail
+        .iter()
+        .filter_map(|entry| {
+            let task = result.tasks.iter().find(|t| t.id == entry.task_id)?;
+            let state = match entry.status {
+                AuditStatus::Success => TaskState::Succeeded,
+                AuditStatus::Failure => TaskState::Failed,
+            };
+            Some(TaskTransition {
+                task,
+                state,
+                entry: Some(entry),
+            })
+        })
+        .collect();
+
+    for task in &result.tasks {
+        if transitions.iter().any(|t| t.task.id == task.id) {
+            continue;
+        }
+        let state = match task.status {
+            TaskStatus::WaitingForInput => TaskState::WaitingForInput,
+            TaskStatus::Skipped => TaskState::Skipped,
+            _ => TaskState::NotRun,
+        };
+        transitions.push(TaskTransition {
+            task,
+            state,
+            entry: None,
+        });
+    }
+
+    transitions
+}
+
+fn task_color(task_id: &str) -> Color {
+    let hash = task_id.bytes().fold(0usize, |hash, byte| {
+        hash.wrapping_mul(31).wrapping_add(byte as usize)
+    });
+    TASK_COLORS[hash % TASK_COLORS.len()]
+}
+
+fn format_micros(micros: u64) -> String {
+    if micros < 1_000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.1}ms", micros as f64 / 1_000.0)
+    } else {
+        format!("{:.2}s", micros as f64 / 1_000_000.0)
+    }
+}
+
+fn emit(event: serde_json::Value) {
+    println!("{}", event);
+}
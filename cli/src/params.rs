@@ -0,0 +1,189 @@
+use rustyline::completion::{Completer, FilenameCompleter, Pair};
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Behavior, CompletionType, Config, Context, Editor, Helper};
+use s_e_e_core::{missing_parameters, parse_parameter_value, WorkflowParameter, SECRET_MASK};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::{self, BufRead, IsTerminal, Write};
+
+/// Build the parameter values for a run from `--param` flags, prompting on the
+/// terminal for required parameters that were not given.
+pub fn collect_parameters(
+    workflow_json: &Value,
+    raw_params: &[String],
+    non_interactive: bool,
+) -> Result<HashMap<String, Value>, String> {
+    let definitions: Vec<WorkflowParameter> = match workflow_json.get("parameters") {
+        Some(parameters) => serde_json::from_value(parameters.clone())
+            .map_err(|e| format!("Invalid workflow parameters: {}", e))?,
+        None => Vec::new(),
+    };
+
+    let mut values = HashMap::new();
+    for raw in raw_params {
+        let (name, value) = raw
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid --param '{}', expected KEY=VALUE", raw))?;
+        let definition = definitions
+            .iter()
+            .find(|d| d.name == name)
+            .ok_or_else(|| format!("Unknown parameter: {}", name))?;
+        let value = parse_parameter_value(definition, value).map_err(|e| e.to_string())?;
+        values.insert(name.to_string(), value);
+    }
+
+    let interactive = !non_interactive && io::stdin().is_terminal();
+    if !interactive {
+        let missing = missing_parameters(&definitions, &values);
+        if !missing.is_empty() {
+            let names: Vec<&str> = missing.iter().map(|d| d.name.as_str()).collect();
+            return Err(format!(
+                "Missing required parameter(s): {} (pass them with --param KEY=VALUE)",
+                names.join(", ")
+            ));
+        }
+        return Ok(values);
+    }
+
+    let pending: Vec<&WorkflowParameter> = definitions
+        .iter()
+        .filter(|d| d.required && !values.contains_key(&d.name))
+        .collect();
+    if pending.is_empty() {
+        return Ok(values);
+    }
+
+    let stdin = io::stdin();
+    let mut input = stdin.lock();
+    let mut output = io::stderr();
+    let _ = writeln!(output, "This workflow needs the following parameters:");
+    for definition in pending {
+        let value = prompt_parameter(definition, &mut input, &mut output)?;
+        values.insert(definition.name.clone(), value);
+    }
+
+    Ok(values)
+}
+
+/// Ask for one parameter until a valid value (or the default) is entered.
+fn prompt_parameter(
+    definition: &WorkflowParameter,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> Result<Value, String> {
+    loop {
+        let kind = if definition.options.is_empty() {
+            definition.param_type.as_str().to_string()
+        } else {
+            let options: Vec<String> = definition.options.iter().map(display_value).collect();
+            format!("one of {}", options.join(", "))
+        };
+        let mut label = format!("  {} ({})", definition.name, kind);
+        if let Some(description) = &definition.description {
+            label.push_str(&format!(" - {}", description));
+        }
+        match &definition.default {
+            Some(_) if definition.secret => label.push_str(&format!(" [{}]", SECRET_MASK)),
+            Some(default) => label.push_str(&format!(" [{}]", display_value(default))),
+            None => {}
+        }
+        let prompt = format!("{}: ", label);
+
+        let line = if definition.secret {
+            let _ = write!(output, "{}", prompt);
+            let _ = output.flush();
+            rpassword::read_password()
+                .map_err(|e| format!("Failed to read parameter value: {}", e))?
+        } else if definition.param_type.is_path() {
+            read_path(definition, &prompt)?
+        } else {
+            let _ = write!(output, "{}", prompt);
+            let _ = output.flush();
+            let mut line = String::new();
+            let read = input
+                .read_line(&mut line)
+                .map_err(|e| format!("Failed to read parameter value: {}", e))?;
+            if read == 0 {
+                return Err(format!(
+                    "Input closed before a value for '{}' was entered",
+                    definition.name
+                ));
+            }
+            line.trim_end_matches(['\r', '\n']).to_string()
+        };
+        let line = line.as_str();
+        if line.is_empty() {
+            match &definition.default {
+                Some(default) => return Ok(default.clone()),
+                None => {
+                    let _ = writeln!(output, "  A value is required.");
+                    continue;
+                }
+            }
+        }
+
+        match parse_parameter_value(definition, line) {
+            Ok(value) => return Ok(value),
+            Err(e) => {
+                let _ = writeln!(output, "  {}", e);
+            }
+        }
+    }
+}
+
+/// Read a path parameter on the terminal, with Tab completing file and
+/// directory names.
+fn read_path(definition: &WorkflowParameter, prompt: &str) -> Result<String, String> {
+    let config = Config::builder()
+        .behavior(Behavior::PreferTerm)
+        .completion_type(CompletionType::List)
+        .auto_add_history(false)
+        .build();
+    let mut editor = Editor::<PathCompletion, DefaultHistory>::with_config(config)
+        .map_err(|e| format!("Failed to read parameter value: {}", e))?;
+    editor.set_helper(Some(PathCompletion(FilenameCompleter::new())));
+    match editor.readline(prompt) {
+        Ok(line) => Ok(line),
+        Err(ReadlineError::Eof | ReadlineError::Interrupted) => Err(format!(
+            "Input closed before a value for '{}' was entered",
+            definition.name
+        )),
+        Err(e) => Err(format!("Failed to read parameter value: {}", e)),
+    }
+}
+
+struct PathCompletion(FilenameCompleter);
+
+impl Completer for PathCompletion {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        self.0.complete(line, pos, ctx)
+    }
+}
+
+impl Hinter for PathCompletion {
+    type Hint = String;
+}
+
+impl Highlighter for PathCompletion {}
+
+impl Validator for PathCompletion {}
+
+impl Helper for PathCompletion {}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,181 @@
+use s_e_e_core::{WorkflowResult, WORKFLOW_RESULT_SCHEMA_VERSION};
+use serde_json::{json, Value};
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use tempfile::TempDir;
+
+fn cli(home: &Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_s_e_e_cli"));
+    command
+        .env("HOME", home)
+        .env("NO_COLOR", "1")
+        .arg("--non-interactive");
+    command
+}
+
+fn write_workflow(dir: &TempDir, workflow: &Value) -> String {
+    let path = dir.path().join("workflow.json");
+    std::fs::write(&path, workflow.to_string()).unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+fn run(workflow: &Value, extra_args: &[&str]) -> Output {
+    let dir = TempDir::new().unwrap();
+    let file = write_workflow(&dir, workflow);
+    cli(dir.path())
+        .arg("--file")
+        .arg(file)
+        .args(extra_args)
+        .output()
+        .unwrap()
+}
+
+fn command_workflow(command: &str, args: &[&str], process: Option<Value>) -> Value {
+    let mut input = json!({ "command": command, "args": args });
+    if let Some(process) = process {
+        input["process"] = process;
+    }
+    json!({
+        "id": "exit-code-test",
+        "name": "Exit Code Test",
+        "tasks": [{
+            "id": "task1",
+            "name": "Task 1",
+            "function": { "name": "cli_command", "input": input }
+        }]
+    })
+}
+
+#[test]
+fn test_success_exits_zero() {
+    let output = run(&command_workflow("echo", &["hello"], None), &[]);
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn test_task_failure_exits_one() {
+    let output = run(&command_workflow("false", &[], None), &[]);
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_json_result_reports_failed_exit_code() {
+    let output = run(
+        &command_workflow("sh", &["-c", "exit 7"], None),
+        &["--json"],
+    );
+    assert_eq!(output.status.code(), Some(1));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let finished: Value = stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .find(|event| event["event"] == "workflow_finished")
+        .unwrap();
+    assert_eq!(
+        finished["failure"],
+        json!({ "category": "handler_failure", "exit_code": 7 })
+    );
+
+    let result = &finished["result"];
+    assert_eq!(
+        result["schema_version"],
+        json!(WORKFLOW_RESULT_SCHEMA_VERSION)
+    );
+    let result: WorkflowResult = serde_json::from_value(result.clone()).unwrap();
+    assert_eq!(result.exit_codes.get("task1"), Some(&7));
+}
+
+#[test]
+fn test_invalid_workflow_exits_two() {
+    let output = run(&json!({ "id": "exit-code-test" }), &[]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_unparseable_file_exits_two() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("workflow.json");
+    std::fs::write(&path, "{ not json").unwrap();
+
+    let output = cli(dir.path()).arg("--file").arg(&path).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_missing_parameter_exits_two() {
+    let mut workflow = command_workflow("echo", &["hello"], None);
+    workflow["parameters"] = json!([{ "name": "target", "type": "string", "required": true }]);
+
+    let output = run(&workflow, &[]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_timeout_exits_four() {
+    let workflow = command_workflow("sleep", &["5"], Some(json!({ "timeout_ms": 200 })));
+    let output = run(&workflow, &[]);
+    assert_eq!(output.status.code(), Some(4));
+}
+
+#[test]
+fn test_failed_preflight_exits_five() {
+    let mut workflow = command_workflow("echo", &["hello"], None);
+    workflow["preflight"] = json!([{ "check": "env_var", "name": "S_E_E_CLI_UNSET_VAR" }]);
+
+    let output = run(&workflow, &[]);
+    assert_eq!(output.status.code(), Some(5));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("environment variable 'S_E_E_CLI_UNSET_VAR': not set"));
+}
+
+#[test]
+fn test_lint_warnings_only_fail_with_flag() {
+    let mut workflow = command_workflow("echo", &["hello"], None);
+    workflow["parameters"] =
+        json!([{ "name": "target", "type": "string", "required": true, "default": "dev" }]);
+
+    let output = run(&workflow, &[]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("warning:"));
+
+    let output = run(&workflow, &["--fail-on-warning"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_interrupt_exits_three() {
+    use std::io::{BufRead, BufReader};
+
+    let dir = TempDir::new().unwrap();
+    let file = write_workflow(&dir, &command_workflow("sleep", &["30"], None));
+    let mut child = cli(dir.path())
+        .arg("--file")
+        .arg(file)
+        .arg("--json")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let mut line = String::new();
+    while stdout.read_line(&mut line).unwrap() > 0 && !line.contains("workflow_started") {
+        line.clear();
+    }
+    // Give the CLI a moment to install its Ctrl-C handler.
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let status = Command::new("kill")
+        .arg("-INT")
+        .arg(child.id().to_string())
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    assert_eq!(child.wait().unwrap().code(), Some(3));
+
+    let rest: String = stdout.lines().map_while(Result::ok).collect();
+    assert!(rest.contains(r#""category":"cancelled""#));
+}
@@ -0,0 +1,243 @@
+use crate::errors::CoreError;
+use crate::store_singleton::{get_data_dir, get_global_store};
+use chrono::{Duration, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use s_e_e_persistence::{
+    ArchivedExecution, TaskExecution, WorkflowExecution, WorkflowExecutionStatus,
+};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+const ARCHIVE_FORMAT: u32 = 1;
+
+/// Contents of one gzipped archive file.
+#[derive(Serialize, Deserialize)]
+struct ArchiveFile {
+    format: u32,
+    execution: WorkflowExecution,
+    tasks: Vec<TaskExecution>,
+}
+
+/// `$HOME/.s_e_e/archive`, where archived executions are written by default.
+pub fn default_archive_dir() -> Result<PathBuf, CoreError> {
+    Ok(PathBuf::from(get_data_dir()?).join("archive"))
+}
+
+fn io_error(action: &str, path: &Path, e: std::io::Error) -> CoreError {
+    CoreError::Archive(format!("Failed to {} {}: {}", action, path.display(), e))
+}
+
+fn write_archive(path: &Path, archive: &ArchiveFile) -> Result<u64, CoreError> {
+    // Written under a temporary name first so a crash never leaves a truncated
+    // archive behind the real file name.
+    let partial = path.with_extension("partial");
+    let file = File::create(&partial).map_err(|e| io_error("create", &partial, e))?;
+    let mut encoder = GzEncoder::new(BufWriter::new(file), Compression::default());
+    serde_json::to_writer(&mut encoder, archive)
+        .map_err(|e| CoreError::Archive(format!("Serialization error: {}", e)))?;
+    let mut writer = encoder
+        .finish()
+        .map_err(|e| io_error("write", &partial, e))?;
+    writer.flush().map_err(|e| io_error("write", &partial, e))?;
+    writer
+        .get_ref()
+        .sync_all()
+        .map_err(|e| io_error("sync", &partial, e))?;
+    drop(writer);
+
+    std::fs::rename(&partial, path).map_err(|e| io_error("rename", &partial, e))?;
+    let size = std::fs::metadata(path)
+        .map_err(|e| io_error("inspect", path, e))?
+        .len();
+    Ok(size)
+}
+
+fn read_archive(path: &Path) -> Result<ArchiveFile, CoreError> {
+    let file = File::open(path).map_err(|e| io_error("open", path, e))?;
+    let archive: ArchiveFile = serde_json::from_reader(GzDecoder::new(BufReader::new(file)))
+        .map_err(|e| CoreError::Archive(format!("Failed to read {}: {}", path.display(), e)))?;
+    if archive.format != ARCHIVE_FORMAT {
+        return Err(CoreError::Archive(format!(
+            "Unsupported archive format {} in {}",
+            archive.format,
+            path.display()
+        )));
+    }
+    Ok(archive)
+}
+
+/// Move one finished execution and its tasks into `<dir>/<id>.json.gz`.
+///
+/// The file is written and indexed before anything is deleted, so a failure
+/// part way leaves the execution in the database.
+pub async fn archive_execution(
+    dir: &Path,
+    execution_id: &str,
+) -> Result<ArchivedExecution, CoreError> {
+    let store = get_global_store()?;
+
+    let execution = store
+        .get_workflow_execution(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(execution_id.to_string()))?;
+    if !matches!(
+        execution.status,
+        WorkflowExecutionStatus::Complete
+            | WorkflowExecutionStatus::Failed
+            | WorkflowExecutionStatus::Cancelled
+    ) {
+        return Err(CoreError::Archive(format!(
+            "Execution {} is {} and cannot be archived until it finishes",
+            execution_id, execution.status
+        )));
+    }
+    let tasks = store
+        .get_tasks_for_workflow(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    std::fs::create_dir_all(dir).map_err(|e| io_error("create", dir, e))?;
+    let file_name = format!("{}.json.gz", execution_id);
+    let entry = ArchivedExecution {
+        id: execution.id.clone(),
+        workflow_name: execution.workflow_name.clone(),
+        status: execution.status.clone(),
+        created_at: execution.created_at,
+        completed_at: execution.completed_at,
+        task_count: execution.tasks.len().max(tasks.len()),
+        archived_at: Utc::now(),
+        size_bytes: 0,
+        file_name,
+    };
+    let archive = ArchiveFile {
+        format: ARCHIVE_FORMAT,
+        execution,
+        tasks,
+    };
+    let size_bytes = write_archive(&dir.join(&entry.file_name), &archive)?;
+    let entry = ArchivedExecution {
+        size_bytes,
+        ..entry
+    };
+
+    store
+        .save_archived_execution(&entry)
+        .await
+        .map_err(CoreError::Persistence)?;
+    store
+        .delete_workflow_metadata_and_tasks(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    tracing::debug!(
+        execution_id = %execution_id,
+        size_bytes,
+        "Archived workflow execution"
+    );
+    Ok(entry)
+}
+
+/// Archive every complete or failed execution started more than `older_than` ago.
+pub async fn archive_executions_older_than(
+    dir: &Path,
+    older_than: Duration,
+) -> Result<Vec<ArchivedExecution>, CoreError> {
+    let store = get_global_store()?;
+    let ids = store
+        .list_archivable_execution_ids(Utc::now() - older_than)
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    let mut archived = Vec::with_capacity(ids.len());
+    for id in ids {
+        archived.push(archive_execution(dir, &id).await?);
+    }
+
+    if !archived.is_empty() {
+        tracing::info!(count = archived.len(), "Archived old workflow executions");
+    }
+    Ok(archived)
+}
+
+/// Apply the `archive_after_days` setting, archiving into the default directory.
+/// Does nothing when the setting is off.
+pub async fn archive_per_settings() -> Result<Vec<ArchivedExecution>, CoreError> {
+    let store = get_global_store()?;
+    let days = store
+        .load_settings()
+        .await
+        .map_err(CoreError::Persistence)?
+        .and_then(|settings| settings.archive_after_days);
+
+    match days {
+        Some(days) => {
+            archive_executions_older_than(&default_archive_dir()?, Duration::days(days.into()))
+                .await
+        }
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Archived executions, newest run first.
+pub async fn list_archived_executions() -> Result<Vec<ArchivedExecution>, CoreError> {
+    let store = get_global_store()?;
+    store
+        .list_archived_executions()
+        .await
+        .map_err(CoreError::Persistence)
+}
+
+/// Load an archived execution and its tasks back into the database, then remove
+/// the archive file and its index entry.
+pub async fn restore_archived_execution(
+    dir: &Path,
+    execution_id: &str,
+) -> Result<WorkflowExecution, CoreError> {
+    let store = get_global_store()?;
+
+    let entry = store
+        .get_archived_execution(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(execution_id.to_string()))?;
+    if store
+        .get_workflow_execution(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .is_some()
+    {
+        return Err(CoreError::Archive(format!(
+            "Execution {} is already in the database",
+            execution_id
+        )));
+    }
+
+    let path = dir.join(&entry.file_name);
+    let archive = read_archive(&path)?;
+    for task in archive.tasks {
+        store
+            .save_task_execution(task)
+            .await
+            .map_err(CoreError::Persistence)?;
+    }
+    store
+        .save_workflow_execution(archive.execution.clone())
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    store
+        .delete_archived_execution(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?;
+    if let Err(e) = std::fs::remove_file(&path) {
+        tracing::warn!("Failed to remove archive file {}: {}", path.display(), e);
+    }
+
+    tracing::debug!(execution_id = %execution_id, "Restored archived workflow execution");
+    Ok(archive.execution)
+}
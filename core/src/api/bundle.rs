@@ -0,0 +1,284 @@
+//! Workflow bundles: workflow JSON packaged with a content hash and an
+//! optional Ed25519 signature, and the trust check for imported workflows.
+
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use crate::validation;
+use chrono::Utc;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use s_e_e_engine::handlers::get_function_type;
+use s_e_e_engine::handlers::sandbox::{describe_action, SIDE_EFFECT_HANDLERS};
+use s_e_e_engine::EngineTask;
+use s_e_e_persistence::{WorkflowDefinition, WorkflowProvenance};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// A workflow as shared between installs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowBundle {
+    pub format_version: u32,
+    /// The workflow JSON, byte for byte as it will be stored.
+    pub content: String,
+    /// `sha256:<hex>` of `content`.
+    pub content_hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<BundleSignature>,
+}
+
+/// Ed25519 signature over the bundle content, both parts hex encoded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleSignature {
+    pub public_key: String,
+    pub signature: String,
+}
+
+/// Why an imported workflow needs confirmation before it runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrustReason {
+    /// The bundle it came from was not signed.
+    Unsigned,
+    /// It was edited after import, so its signature no longer covers it.
+    Modified,
+}
+
+/// What to show the user before running an imported workflow.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrustPrompt {
+    pub workflow_id: String,
+    pub reason: TrustReason,
+    /// One line per side-effecting task, such as `Would run: rm -rf build`.
+    pub commands: Vec<String>,
+}
+
+pub fn content_hash(content: &str) -> String {
+    format!("sha256:{}", hex::encode(Sha256::digest(content.as_bytes())))
+}
+
+impl WorkflowBundle {
+    pub fn new(content: String) -> Self {
+        Self {
+            format_version: BUNDLE_FORMAT_VERSION,
+            content_hash: content_hash(&content),
+            content,
+            signature: None,
+        }
+    }
+
+    /// Sign with a hex-encoded 32-byte Ed25519 secret key.
+    pub fn signed(mut self, secret_key_hex: &str) -> Result<Self, CoreError> {
+        let secret: [u8; 32] = hex::decode(secret_key_hex.trim())
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| CoreError::Bundle("Signing key must be 32 bytes of hex".to_string()))?;
+        let key = SigningKey::from_bytes(&secret);
+        let signature = key.sign(self.content.as_bytes());
+        self.signature = Some(BundleSignature {
+            public_key: hex::encode(key.verifying_key().to_bytes()),
+            signature: hex::encode(signature.to_bytes()),
+        });
+        Ok(self)
+    }
+
+    /// Check the content against its hash and signature. Returns the hex
+    /// public key of the signer, or `None` for an unsigned bundle.
+    pub fn verify(&self) -> Result<Option<String>, CoreError> {
+        if self.format_version > BUNDLE_FORMAT_VERSION {
+            return Err(CoreError::Bundle(format!(
+                "Bundle format {} is newer than this app supports ({})",
+                self.format_version, BUNDLE_FORMAT_VERSION
+            )));
+        }
+        if content_hash(&self.content) != self.content_hash {
+            return Err(CoreError::Bundle(
+                "Workflow content does not match the bundle's hash".to_string(),
+            ));
+        }
+
+        let Some(signature) = &self.signature else {
+            return Ok(None);
+        };
+        let public_key: [u8; 32] = hex::decode(&signature.public_key)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| CoreError::Bundle("Malformed signer public key".to_string()))?;
+        let signature_bytes: [u8; 64] = hex::decode(&signature.signature)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| CoreError::Bundle("Malformed signature".to_string()))?;
+
+        VerifyingKey::from_bytes(&public_key)
+            .and_then(|key| {
+                key.verify(
+                    self.content.as_bytes(),
+                    &Signature::from_bytes(&signature_bytes),
+                )
+            })
+            .map_err(|_| CoreError::Bundle("Bundle signature is not valid".to_string()))?;
+
+        Ok(Some(signature.public_key.clone()))
+    }
+}
+
+/// Whether `text` looks like a bundle rather than plain workflow JSON.
+pub fn is_workflow_bundle(text: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(text)
+        .is_ok_and(|value| value.get("content_hash").is_some() && value.get("content").is_some())
+}
+
+pub async fn export_workflow_bundle(
+    workflow_id: &str,
+    secret_key_hex: Option<&str>,
+) -> Result<String, CoreError> {
+    let store = get_global_store()?;
+    let workflow = store
+        .get_workflow(workflow_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(workflow_id.to_string()))?;
+
+    let mut bundle = WorkflowBundle::new(workflow.content);
+    if let Some(key) = secret_key_hex {
+        bundle = bundle.signed(key)?;
+    }
+    serde_json::to_string_pretty(&bundle).map_err(|e| CoreError::Bundle(e.to_string()))
+}
+
+/// Verify a bundle and add its workflow to the library, recording where it
+/// came from. `source` is a label such as the file name.
+pub async fn import_workflow_bundle(
+    source: &str,
+    bundle_json: &str,
+) -> Result<WorkflowDefinition, CoreError> {
+    let bundle: WorkflowBundle = serde_json::from_str(bundle_json)
+        .map_err(|e| CoreError::Bundle(format!("Not a workflow bundle: {}", e)))?;
+    let signed_by = bundle.verify()?;
+    validation::validate_workflow_json(&bundle.content)?;
+
+    let json: serde_json::Value = serde_json::from_str(&bundle.content)
+        .map_err(|e| CoreError::Bundle(format!("Invalid workflow JSON: {}", e)))?;
+    let field = |name: &str| json.get(name).and_then(|v| v.as_str()).map(str::to_string);
+    let id = field("id").unwrap_or_default();
+
+    let store = get_global_store()?;
+    if store
+        .get_workflow(&id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .is_some()
+    {
+        return Err(CoreError::Bundle(format!(
+            "A workflow with id '{}' is already in the library",
+            id
+        )));
+    }
+
+    let now = Utc::now();
+    let workflow = WorkflowDefinition {
+        id: id.clone(),
+        name: field("name").unwrap_or_else(|| "Unnamed Workflow".to_string()),
+        description: field("description"),
+        content: bundle.content,
+        is_default: false,
+        is_edited: false,
+        created_at: now,
+        updated_at: now,
+    };
+    store
+        .save_workflow(&workflow)
+        .await
+        .map_err(CoreError::Persistence)?;
+    store
+        .save_workflow_provenance(&WorkflowProvenance {
+            workflow_id: id,
+            source: source.to_string(),
+            content_hash: bundle.content_hash,
+            signed_by,
+            imported_at: now,
+            trusted_hash: None,
+        })
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    tracing::info!(workflow_id = %workflow.id, source = %source, "Imported workflow bundle");
+    Ok(workflow)
+}
+
+fn side_effect_commands(tasks: &[EngineTask], commands: &mut Vec<String>) {
+    for task in tasks {
+        if SIDE_EFFECT_HANDLERS.contains(&get_function_type(task)) {
+            commands.push(describe_action(task));
+        }
+        side_effect_commands(&task.next_tasks, commands);
+    }
+}
+
+/// The confirmation an imported workflow needs before it runs, if any.
+/// Workflows that were not imported, signed ones that are unchanged, ones
+/// the user already trusted at this content and ones that run no commands
+/// need none.
+pub async fn workflow_trust_prompt(workflow_id: &str) -> Result<Option<TrustPrompt>, CoreError> {
+    let store = get_global_store()?;
+    let Some(provenance) = store
+        .get_workflow_provenance(workflow_id)
+        .await
+        .map_err(CoreError::Persistence)?
+    else {
+        return Ok(None);
+    };
+    let workflow = store
+        .get_workflow(workflow_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(workflow_id.to_string()))?;
+
+    let current = content_hash(&workflow.content);
+    if provenance.trusted_hash.as_deref() == Some(current.as_str()) {
+        return Ok(None);
+    }
+    let reason = if current != provenance.content_hash {
+        TrustReason::Modified
+    } else if provenance.signed_by.is_none() {
+        TrustReason::Unsigned
+    } else {
+        return Ok(None);
+    };
+
+    let parsed =
+        s_e_e_engine::parse_workflow(&workflow.content).map_err(|e| CoreError::Engine(e.into()))?;
+    let mut commands = Vec::new();
+    side_effect_commands(&parsed.tasks, &mut commands);
+    if commands.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(TrustPrompt {
+        workflow_id: workflow_id.to_string(),
+        reason,
+        commands,
+    }))
+}
+
+/// Record that the user trusts the imported workflow as it is now. Editing it
+/// again brings the prompt back.
+pub async fn trust_workflow(workflow_id: &str) -> Result<(), CoreError> {
+    let store = get_global_store()?;
+    let mut provenance = store
+        .get_workflow_provenance(workflow_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(workflow_id.to_string()))?;
+    let workflow = store
+        .get_workflow(workflow_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(workflow_id.to_string()))?;
+
+    provenance.trusted_hash = Some(content_hash(&workflow.content));
+    store
+        .save_workflow_provenance(&provenance)
+        .await
+        .map_err(CoreError::Persistence)
+}
@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use s_e_e_engine::{Checkpoint, CheckpointStore};
+use s_e_e_persistence::{ExecutionCheckpoint, Store};
+use std::sync::Arc;
+use tracing::warn;
+
+/// Checkpoints kept in the `execution_checkpoints` table, so a run that was
+/// interrupted can be resumed with [`resume_workflow_execution`]. Storage
+/// errors are logged; the run carries on, only less resumable.
+///
+/// [`resume_workflow_execution`]: crate::api::resume::resume_workflow_execution
+pub struct StoreCheckpoints {
+    store: Arc<Store>,
+}
+
+impl StoreCheckpoints {
+    pub fn new(store: Arc<Store>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl CheckpointStore for StoreCheckpoints {
+    async fn save(&self, checkpoint: &Checkpoint) {
+        let saved = ExecutionCheckpoint {
+            execution_id: checkpoint.execution_id.clone(),
+            completed_tasks: checkpoint.completed_tasks.iter().cloned().collect(),
+            skipped_tasks: checkpoint.skipped_tasks.iter().cloned().collect(),
+            data: checkpoint.data.clone(),
+            scratch: checkpoint.scratch.entries().clone(),
+            saved_at: chrono::Utc::now(),
+        };
+        if let Err(e) = self.store.save_execution_checkpoint(&saved).await {
+            warn!(execution_id = %checkpoint.execution_id, error = %e, "Failed to save checkpoint");
+        }
+    }
+}
+
+/// Drop the checkpoint of a run that has finished. A leftover only means the
+/// execution stays resumable, so errors are logged.
+pub(crate) async fn clear_checkpoint(store: &Store, execution_id: &str) {
+    if let Err(e) = store.delete_execution_checkpoint(execution_id).await {
+        warn!(execution_id, error = %e, "Failed to delete checkpoint");
+    }
+}
@@ -0,0 +1,172 @@
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use chrono::{DateTime, Duration, Utc};
+use s_e_e_persistence::{
+    TaskExecution, TaskExecutionStatus, WorkflowExecution, WorkflowExecutionStatus,
+};
+
+/// A task that takes this much longer than before, and at least
+/// [`SLOWDOWN_MIN`] longer, counts as a regression.
+const SLOWDOWN_RATIO: f64 = 1.5;
+const SLOWDOWN_MIN: Duration = Duration::seconds(1);
+
+/// How one task fared in an execution compared with the run before it. A task
+/// that only ran in one of the two has no status or duration for the other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskComparison {
+    pub task_id: String,
+    pub name: String,
+    pub previous_status: Option<TaskExecutionStatus>,
+    pub current_status: Option<TaskExecutionStatus>,
+    pub previous_duration: Option<Duration>,
+    pub current_duration: Option<Duration>,
+    /// Both runs finished the task and it wrote something different.
+    pub output_changed: bool,
+}
+
+impl TaskComparison {
+    /// Failed now after completing in the previous run, or failed in a task
+    /// the previous run did not have.
+    pub fn newly_failing(&self) -> bool {
+        self.current_status == Some(TaskExecutionStatus::Failed)
+            && self.previous_status != Some(TaskExecutionStatus::Failed)
+    }
+
+    /// Completed now after failing in the previous run.
+    pub fn fixed(&self) -> bool {
+        self.previous_status == Some(TaskExecutionStatus::Failed)
+            && self.current_status == Some(TaskExecutionStatus::Complete)
+    }
+
+    /// Took markedly longer than in the previous run.
+    pub fn slower(&self) -> bool {
+        match (self.previous_duration, self.current_duration) {
+            (Some(previous), Some(current)) => {
+                current - previous >= SLOWDOWN_MIN
+                    && current.num_milliseconds() as f64
+                        > previous.num_milliseconds() as f64 * SLOWDOWN_RATIO
+            }
+            _ => false,
+        }
+    }
+
+    pub fn regressed(&self) -> bool {
+        self.newly_failing() || self.slower()
+    }
+
+    pub fn changed(&self) -> bool {
+        self.previous_status != self.current_status || self.output_changed || self.slower()
+    }
+}
+
+/// An execution set against the most recent earlier run of the same workflow.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionComparison {
+    pub execution_id: String,
+    pub previous_execution_id: String,
+    pub previous_created_at: DateTime<Utc>,
+    pub previous_status: WorkflowExecutionStatus,
+    pub current_status: WorkflowExecutionStatus,
+    pub previous_duration: Option<Duration>,
+    pub current_duration: Option<Duration>,
+    /// Tasks in the order the current run lists them, followed by tasks only
+    /// the previous run had.
+    pub tasks: Vec<TaskComparison>,
+}
+
+impl ExecutionComparison {
+    pub fn regressions(&self) -> impl Iterator<Item = &TaskComparison> {
+        self.tasks.iter().filter(|task| task.regressed())
+    }
+
+    pub fn newly_failing(&self) -> impl Iterator<Item = &TaskComparison> {
+        self.tasks.iter().filter(|task| task.newly_failing())
+    }
+}
+
+fn task_duration(task: &TaskExecution) -> Option<Duration> {
+    task.completed_at
+        .map(|completed_at| completed_at - task.created_at)
+}
+
+fn finished(task: &TaskExecution) -> bool {
+    matches!(
+        task.status,
+        TaskExecutionStatus::Complete | TaskExecutionStatus::Failed
+    )
+}
+
+fn compare_task(
+    previous: Option<&TaskExecution>,
+    current: Option<&TaskExecution>,
+) -> TaskComparison {
+    let named = current.or(previous).expect("a task from at least one run");
+    let output_changed = match (previous, current) {
+        (Some(previous), Some(current)) if finished(previous) && finished(current) => {
+            previous.output != current.output
+        }
+        _ => false,
+    };
+    TaskComparison {
+        task_id: named.id.clone(),
+        name: named.name.clone(),
+        previous_status: previous.map(|task| task.status.clone()),
+        current_status: current.map(|task| task.status.clone()),
+        previous_duration: previous.and_then(task_duration),
+        current_duration: current.and_then(task_duration),
+        output_changed,
+    }
+}
+
+fn find<'a>(execution: &'a WorkflowExecution, task_id: &str) -> Option<&'a TaskExecution> {
+    execution.tasks.iter().find(|task| task.id == task_id)
+}
+
+/// Compare two executions task by task, matching tasks on their id.
+pub fn compare_executions(
+    previous: &WorkflowExecution,
+    current: &WorkflowExecution,
+) -> ExecutionComparison {
+    let mut tasks: Vec<TaskComparison> = current
+        .tasks
+        .iter()
+        .map(|task| compare_task(find(previous, &task.id), Some(task)))
+        .collect();
+    tasks.extend(
+        previous
+            .tasks
+            .iter()
+            .filter(|task| find(current, &task.id).is_none())
+            .map(|task| compare_task(Some(task), None)),
+    );
+
+    ExecutionComparison {
+        execution_id: current.id.clone(),
+        previous_execution_id: previous.id.clone(),
+        previous_created_at: previous.created_at,
+        previous_status: previous.status.clone(),
+        current_status: current.status.clone(),
+        previous_duration: previous.completed_at.map(|at| at - previous.created_at),
+        current_duration: current.completed_at.map(|at| at - current.created_at),
+        tasks,
+    }
+}
+
+/// Compare an execution with the run of the same workflow that preceded it.
+/// `None` when it is the first run.
+pub async fn compare_with_previous_run(
+    execution_id: &str,
+) -> Result<Option<ExecutionComparison>, CoreError> {
+    let store = get_global_store()?;
+    let execution = store
+        .get_workflow_execution(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(execution_id.to_string()))?;
+
+    let previous = store
+        .previous_workflow_execution(&execution)
+        .await
+        .map_err(CoreError::Persistence)?;
+    Ok(previous.map(|previous| compare_executions(&previous, &execution)))
+}
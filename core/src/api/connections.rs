@@ -0,0 +1,70 @@
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::{DbConnection, MessageBackend, MessageConnection};
+use s_e_e_persistence::{
+    AppSettings, DbConnectionSettings, MessageBroker, MessageConnectionSettings,
+};
+
+pub fn db_connection_from_settings(settings: &DbConnectionSettings) -> DbConnection {
+    DbConnection {
+        name: settings.name.trim().to_string(),
+        url: settings.url.trim().to_string(),
+        password_env: password_env(&settings.password_env),
+        allowed_statements: settings
+            .allowed_statements
+            .iter()
+            .map(|keyword| keyword.trim().to_lowercase())
+            .filter(|keyword| !keyword.is_empty())
+            .collect(),
+        max_rows: settings.max_rows,
+    }
+}
+
+pub fn message_connection_from_settings(settings: &MessageConnectionSettings) -> MessageConnection {
+    MessageConnection {
+        name: settings.name.trim().to_string(),
+        backend: match settings.broker {
+            MessageBroker::Kafka => MessageBackend::Kafka,
+            MessageBroker::Nats => MessageBackend::Nats,
+            MessageBroker::Amqp => MessageBackend::Amqp,
+        },
+        url: settings.url.trim().to_string(),
+        password_env: password_env(&settings.password_env),
+    }
+}
+
+fn password_env(var: &Option<String>) -> Option<String> {
+    var.as_deref()
+        .map(str::trim)
+        .filter(|var| !var.is_empty())
+        .map(str::to_string)
+}
+
+async fn load_settings() -> Result<AppSettings, CoreError> {
+    let store = get_global_store()?;
+    Ok(store
+        .load_settings()
+        .await
+        .map_err(CoreError::Persistence)?
+        .unwrap_or_default())
+}
+
+/// The database connections saved in the settings, ready to hand to an engine.
+pub async fn load_db_connections() -> Result<Vec<DbConnection>, CoreError> {
+    Ok(load_settings()
+        .await?
+        .db_connections
+        .iter()
+        .map(db_connection_from_settings)
+        .collect())
+}
+
+/// The message broker connections saved in the settings.
+pub async fn load_message_connections() -> Result<Vec<MessageConnection>, CoreError> {
+    Ok(load_settings()
+        .await?
+        .message_connections
+        .iter()
+        .map(message_connection_from_settings)
+        .collect())
+}
@@ -0,0 +1,78 @@
+//! Rebuilds an execution's context at earlier points from the per-task
+//! changes in its audit trail.
+
+use crate::bridge::audit::audit_change_to_context;
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::diff;
+use s_e_e_persistence::AuditEvent;
+use serde_json::{Map, Value};
+
+/// The context as it stood when a task started.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskContext {
+    pub task_id: String,
+    pub data: Value,
+    /// An earlier task's diff was truncated, so parts of `data` may be missing
+    /// or hold placeholders instead of the real values.
+    pub incomplete: bool,
+    /// Paths the task itself changed once it finished.
+    pub changes_count: usize,
+}
+
+/// Task ids in the order their first audit event was recorded, which is the
+/// order their changes were merged into the context.
+pub fn context_steps(audit_trail: &[AuditEvent]) -> Vec<String> {
+    let mut steps: Vec<String> = Vec::new();
+    for event in audit_trail {
+        if !steps.contains(&event.task_id) {
+            steps.push(event.task_id.clone());
+        }
+    }
+    steps
+}
+
+/// Replays `audit_trail` up to the first event for `task_id`. Returns `None`
+/// when the task has no audit event, i.e. it never ran.
+///
+/// Tasks that ran in parallel are merged one after another, so the context of
+/// a task can include changes from siblings merged ahead of it.
+pub fn context_at(audit_trail: &[AuditEvent], task_id: &str) -> Option<TaskContext> {
+    let position = audit_trail
+        .iter()
+        .position(|event| event.task_id == task_id)?;
+
+    let (data, incomplete) = replay(&audit_trail[..position]);
+    Some(TaskContext {
+        task_id: task_id.to_string(),
+        data,
+        incomplete,
+        changes_count: audit_trail[position].changes_count,
+    })
+}
+
+/// Applies the changes of `events` in order to an empty context. The flag is
+/// set when any of them was truncated.
+pub(crate) fn replay(events: &[AuditEvent]) -> (Value, bool) {
+    let mut data = Value::Object(Map::new());
+    let mut incomplete = false;
+    for event in events {
+        let changes: Vec<_> = event.changes.iter().map(audit_change_to_context).collect();
+        diff::apply(&mut data, &changes);
+        incomplete |= event.changes_truncated;
+    }
+    (data, incomplete)
+}
+
+/// The context of an execution as it was when `task_id` started.
+pub async fn get_context_at(execution_id: &str, task_id: &str) -> Result<TaskContext, CoreError> {
+    let store = get_global_store()?;
+    let execution = store
+        .get_workflow_execution(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(execution_id.to_string()))?;
+
+    context_at(&execution.audit_trail, task_id)
+        .ok_or_else(|| CoreError::TaskNotFound(task_id.to_string()))
+}
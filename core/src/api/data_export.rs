@@ -0,0 +1,433 @@
+//! Flat tables of executions and task metrics for analysis in external BI
+//! tools, written as CSV or Parquet.
+//!
+//! The columns below are a published schema: new columns are only ever added
+//! at the end, and existing ones are never renamed, retyped or reordered.
+
+use crate::errors::CoreError;
+use crate::store_singleton::get_reporting_store;
+use chrono::{DateTime, SecondsFormat, Utc};
+use parquet::basic::Type as PhysicalType;
+use parquet::data_type::{BoolType, ByteArray, ByteArrayType, Int64Type};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::types::Type;
+use s_e_e_persistence::{TaskExecutionStatus, WorkflowExecution};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Separator between an execution's labels in the `labels` column.
+pub const LABEL_SEPARATOR: &str = ";";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Parquet,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 2] = [ExportFormat::Csv, ExportFormat::Parquet];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Parquet => "parquet",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|format| format.as_str() == name)
+    }
+}
+
+/// One row of `executions.<format>`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionRecord {
+    pub execution_id: String,
+    pub workflow_id: Option<String>,
+    pub workflow_name: String,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub duration_ms: Option<i64>,
+    pub task_count: i64,
+    pub failed_task_count: i64,
+    pub cached_task_count: i64,
+    /// Labels joined with [`LABEL_SEPARATOR`].
+    pub labels: String,
+    pub triggered_by: Option<String>,
+}
+
+/// One row of `tasks.<format>`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskRecord {
+    pub execution_id: String,
+    pub task_id: String,
+    pub task_name: String,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub duration_ms: Option<i64>,
+    pub cache_hit: bool,
+    pub output_bytes: Option<i64>,
+    pub failure_reason: Option<String>,
+}
+
+/// Files written by [`export_execution_data`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionDataExport {
+    pub executions: usize,
+    pub tasks: usize,
+    pub files: Vec<PathBuf>,
+}
+
+enum Values {
+    Text(Vec<Option<String>>),
+    Int(Vec<Option<i64>>),
+    Bool(Vec<Option<bool>>),
+    Timestamp(Vec<Option<DateTime<Utc>>>),
+}
+
+impl Values {
+    fn len(&self) -> usize {
+        match self {
+            Values::Text(values) => values.len(),
+            Values::Int(values) => values.len(),
+            Values::Bool(values) => values.len(),
+            Values::Timestamp(values) => values.len(),
+        }
+    }
+
+    fn csv_cell(&self, row: usize) -> String {
+        match self {
+            Values::Text(values) => values[row].clone().unwrap_or_default(),
+            Values::Int(values) => values[row].map(|v| v.to_string()).unwrap_or_default(),
+            Values::Bool(values) => values[row].map(|v| v.to_string()).unwrap_or_default(),
+            Values::Timestamp(values) => values[row]
+                .map(|v| v.to_rfc3339_opts(SecondsFormat::Millis, true))
+                .unwrap_or_default(),
+        }
+    }
+
+    fn parquet_field(&self, name: &str) -> parquet::errors::Result<Type> {
+        use parquet::basic::{ConvertedType, LogicalType, Repetition, TimeUnit};
+        let builder = match self {
+            Values::Text(_) => Type::primitive_type_builder(name, PhysicalType::BYTE_ARRAY)
+                .with_converted_type(ConvertedType::UTF8)
+                .with_logical_type(Some(LogicalType::String)),
+            Values::Int(_) => Type::primitive_type_builder(name, PhysicalType::INT64),
+            Values::Bool(_) => Type::primitive_type_builder(name, PhysicalType::BOOLEAN),
+            Values::Timestamp(_) => Type::primitive_type_builder(name, PhysicalType::INT64)
+                .with_logical_type(Some(LogicalType::Timestamp {
+                    is_adjusted_to_u_t_c: true,
+                    unit: TimeUnit::MILLIS(Default::default()),
+                })),
+        };
+        builder.with_repetition(Repetition::OPTIONAL).build()
+    }
+}
+
+/// Columns in schema order.
+struct Table {
+    name: &'static str,
+    columns: Vec<(&'static str, Values)>,
+}
+
+impl Table {
+    fn rows(&self) -> usize {
+        self.columns.first().map_or(0, |(_, values)| values.len())
+    }
+}
+
+fn execution_table(records: &[ExecutionRecord]) -> Table {
+    let text =
+        |f: fn(&ExecutionRecord) -> Option<String>| Values::Text(records.iter().map(f).collect());
+    let int = |f: fn(&ExecutionRecord) -> Option<i64>| Values::Int(records.iter().map(f).collect());
+    Table {
+        name: "executions",
+        columns: vec![
+            ("execution_id", text(|r| Some(r.execution_id.clone()))),
+            ("workflow_id", text(|r| r.workflow_id.clone())),
+            ("workflow_name", text(|r| Some(r.workflow_name.clone()))),
+            ("status", text(|r| Some(r.status.clone()))),
+            (
+                "created_at",
+                Values::Timestamp(records.iter().map(|r| Some(r.created_at)).collect()),
+            ),
+            (
+                "completed_at",
+                Values::Timestamp(records.iter().map(|r| r.completed_at).collect()),
+            ),
+            ("duration_ms", int(|r| r.duration_ms)),
+            ("task_count", int(|r| Some(r.task_count))),
+            ("failed_task_count", int(|r| Some(r.failed_task_count))),
+            ("cached_task_count", int(|r| Some(r.cached_task_count))),
+            ("labels", text(|r| Some(r.labels.clone()))),
+            ("triggered_by", text(|r| r.triggered_by.clone())),
+        ],
+    }
+}
+
+fn task_table(records: &[TaskRecord]) -> Table {
+    let text = |f: fn(&TaskRecord) -> Option<String>| Values::Text(records.iter().map(f).collect());
+    Table {
+        name: "tasks",
+        columns: vec![
+            ("execution_id", text(|r| Some(r.execution_id.clone()))),
+            ("task_id", text(|r| Some(r.task_id.clone()))),
+            ("task_name", text(|r| Some(r.task_name.clone()))),
+            ("status", text(|r| Some(r.status.clone()))),
+            (
+                "created_at",
+                Values::Timestamp(records.iter().map(|r| Some(r.created_at)).collect()),
+            ),
+            (
+                "completed_at",
+                Values::Timestamp(records.iter().map(|r| r.completed_at).collect()),
+            ),
+            (
+                "duration_ms",
+                Values::Int(records.iter().map(|r| r.duration_ms).collect()),
+            ),
+            (
+                "cache_hit",
+                Values::Bool(records.iter().map(|r| Some(r.cache_hit)).collect()),
+            ),
+            (
+                "output_bytes",
+                Values::Int(records.iter().map(|r| r.output_bytes).collect()),
+            ),
+            ("failure_reason", text(|r| r.failure_reason.clone())),
+        ],
+    }
+}
+
+/// Column names of `executions.<format>`, in order.
+pub fn execution_columns() -> Vec<&'static str> {
+    execution_table(&[])
+        .columns
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect()
+}
+
+/// Column names of `tasks.<format>`, in order.
+pub fn task_columns() -> Vec<&'static str> {
+    task_table(&[])
+        .columns
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect()
+}
+
+fn duration_ms(start: DateTime<Utc>, end: Option<DateTime<Utc>>) -> Option<i64> {
+    end.map(|end| (end - start).num_milliseconds())
+}
+
+fn records_for(execution: &WorkflowExecution) -> (ExecutionRecord, Vec<TaskRecord>) {
+    let tasks: Vec<TaskRecord> = execution
+        .tasks
+        .iter()
+        .map(|task| TaskRecord {
+            execution_id: execution.id.clone(),
+            task_id: task.id.clone(),
+            task_name: task.name.clone(),
+            status: task.status.as_str().to_string(),
+            created_at: task.created_at,
+            completed_at: task.completed_at,
+            duration_ms: duration_ms(task.created_at, task.completed_at),
+            cache_hit: task.cache_hit,
+            output_bytes: task.output_bytes.map(|bytes| bytes as i64),
+            failure_reason: task
+                .failure_reason
+                .map(|reason| reason.as_str().to_string()),
+        })
+        .collect();
+    let record = ExecutionRecord {
+        execution_id: execution.id.clone(),
+        workflow_id: execution.workflow_id.clone(),
+        workflow_name: execution.workflow_name.clone(),
+        status: execution.status.as_str().to_string(),
+        created_at: execution.created_at,
+        completed_at: execution.completed_at,
+        duration_ms: duration_ms(execution.created_at, execution.completed_at),
+        task_count: execution.tasks.len() as i64,
+        failed_task_count: execution
+            .tasks
+            .iter()
+            .filter(|task| task.status == TaskExecutionStatus::Failed)
+            .count() as i64,
+        cached_task_count: execution.tasks.iter().filter(|task| task.cache_hit).count() as i64,
+        labels: execution.labels.join(LABEL_SEPARATOR),
+        triggered_by: execution.triggered_by.clone(),
+    };
+    (record, tasks)
+}
+
+/// Executions started at or after `from` and before `to`, oldest first, with
+/// the tasks of each. Read through the read-only store when one is open.
+pub async fn execution_records(
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<(Vec<ExecutionRecord>, Vec<TaskRecord>), CoreError> {
+    let store = get_reporting_store()?;
+    let executions = store
+        .list_workflow_executions_between(from, to)
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    let mut execution_rows = Vec::new();
+    let mut task_rows = Vec::new();
+    for mut execution in executions {
+        if execution.tasks.is_empty() {
+            execution.tasks = store
+                .get_tasks_for_workflow(&execution.id)
+                .await
+                .map_err(CoreError::Persistence)?;
+        }
+        let (record, tasks) = records_for(&execution);
+        execution_rows.push(record);
+        task_rows.extend(tasks);
+    }
+    Ok((execution_rows, task_rows))
+}
+
+fn export_error(path: &Path, e: impl std::fmt::Display) -> CoreError {
+    CoreError::Export(format!("Failed to write {}: {}", path.display(), e))
+}
+
+fn write_csv(path: &Path, table: &Table) -> Result<(), CoreError> {
+    let mut writer = csv::Writer::from_path(path).map_err(|e| export_error(path, e))?;
+    writer
+        .write_record(table.columns.iter().map(|(name, _)| *name))
+        .map_err(|e| export_error(path, e))?;
+    for row in 0..table.rows() {
+        writer
+            .write_record(table.columns.iter().map(|(_, values)| values.csv_cell(row)))
+            .map_err(|e| export_error(path, e))?;
+    }
+    writer.flush().map_err(|e| export_error(path, e))
+}
+
+fn write_parquet(path: &Path, table: &Table) -> Result<(), CoreError> {
+    fn levels<T>(values: &[Option<T>]) -> Vec<i16> {
+        values
+            .iter()
+            .map(|value| i16::from(value.is_some()))
+            .collect()
+    }
+
+    let fields = table
+        .columns
+        .iter()
+        .map(|(name, values)| values.parquet_field(name).map(Arc::new))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| export_error(path, e))?;
+    let schema = Type::group_type_builder(table.name)
+        .with_fields(fields)
+        .build()
+        .map_err(|e| export_error(path, e))?;
+    let file = File::create(path).map_err(|e| export_error(path, e))?;
+    let mut writer = SerializedFileWriter::new(
+        file,
+        Arc::new(schema),
+        Arc::new(WriterProperties::builder().build()),
+    )
+    .map_err(|e| export_error(path, e))?;
+
+    if table.rows() > 0 {
+        let mut row_group = writer.next_row_group().map_err(|e| export_error(path, e))?;
+        for (_, values) in &table.columns {
+            let mut column = row_group
+                .next_column()
+                .map_err(|e| export_error(path, e))?
+                .ok_or_else(|| export_error(path, "schema has fewer columns than the table"))?;
+            match values {
+                Values::Text(values) => {
+                    let present: Vec<ByteArray> = values
+                        .iter()
+                        .flatten()
+                        .map(|value| ByteArray::from(value.as_str()))
+                        .collect();
+                    column.typed::<ByteArrayType>().write_batch(
+                        &present,
+                        Some(&levels(values)),
+                        None,
+                    )
+                }
+                Values::Int(values) => {
+                    let present: Vec<i64> = values.iter().flatten().copied().collect();
+                    column
+                        .typed::<Int64Type>()
+                        .write_batch(&present, Some(&levels(values)), None)
+                }
+                Values::Bool(values) => {
+                    let present: Vec<bool> = values.iter().flatten().copied().collect();
+                    column
+                        .typed::<BoolType>()
+                        .write_batch(&present, Some(&levels(values)), None)
+                }
+                Values::Timestamp(values) => {
+                    let present: Vec<i64> = values
+                        .iter()
+                        .flatten()
+                        .map(|value| value.timestamp_millis())
+                        .collect();
+                    column
+                        .typed::<Int64Type>()
+                        .write_batch(&present, Some(&levels(values)), None)
+                }
+            }
+            .map_err(|e| export_error(path, e))?;
+            column.close().map_err(|e| export_error(path, e))?;
+        }
+        row_group.close().map_err(|e| export_error(path, e))?;
+    }
+    writer.close().map_err(|e| export_error(path, e))?;
+    Ok(())
+}
+
+/// Write `executions.<format>` and `tasks.<format>` into `dir`, covering
+/// executions started at or after `from` and before `to`. Existing files of
+/// the same name are replaced.
+pub async fn export_execution_data(
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    format: ExportFormat,
+    dir: &Path,
+) -> Result<ExecutionDataExport, CoreError> {
+    if from >= to {
+        return Err(CoreError::InputValidationFailed(format!(
+            "Export range is empty: {} is not before {}",
+            from.to_rfc3339_opts(SecondsFormat::Secs, true),
+            to.to_rfc3339_opts(SecondsFormat::Secs, true)
+        )));
+    }
+    let (executions, tasks) = execution_records(from, to).await?;
+    std::fs::create_dir_all(dir).map_err(|e| export_error(dir, e))?;
+
+    let mut files = Vec::new();
+    for table in [execution_table(&executions), task_table(&tasks)] {
+        let path = dir.join(format!("{}.{}", table.name, format.as_str()));
+        match format {
+            ExportFormat::Csv => write_csv(&path, &table)?,
+            ExportFormat::Parquet => write_parquet(&path, &table)?,
+        }
+        files.push(path);
+    }
+    tracing::info!(
+        executions = executions.len(),
+        tasks = tasks.len(),
+        format = format.as_str(),
+        "Exported execution data"
+    );
+    Ok(ExecutionDataExport {
+        executions: executions.len(),
+        tasks: tasks.len(),
+        files,
+    })
+}
@@ -0,0 +1,110 @@
+//! Copies of saved workflows and prompts, for "Duplicate" in the lists.
+
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_persistence::{Prompt, WorkflowDefinition};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// `"NAME (copy)"`, or `"NAME (copy N)"` with the first N not in `taken`.
+/// Copying a copy starts from the original name.
+pub fn copy_name(name: &str, taken: &HashSet<String>) -> String {
+    let base = strip_copy_suffix(name);
+    std::iter::once(format!("{} (copy)", base))
+        .chain((2..).map(|n| format!("{} (copy {})", base, n)))
+        .find(|candidate| !taken.contains(candidate))
+        .expect("there is always an unused copy number")
+}
+
+fn strip_copy_suffix(name: &str) -> &str {
+    let Some(rest) = name.strip_suffix(')') else {
+        return name;
+    };
+    let Some((base, suffix)) = rest.rsplit_once(" (copy") else {
+        return name;
+    };
+    let numbered = suffix
+        .strip_prefix(' ')
+        .is_some_and(|n| n.parse::<u32>().is_ok());
+    if suffix.is_empty() || numbered {
+        base
+    } else {
+        name
+    }
+}
+
+/// Save a copy of workflow `workflow_id` under a new id and a copy name.
+/// The copy is a custom workflow even when the original is a default one.
+pub async fn duplicate_workflow(workflow_id: &str) -> Result<WorkflowDefinition, CoreError> {
+    let store = get_global_store()?;
+    let workflow = store
+        .get_workflow(workflow_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(workflow_id.to_string()))?;
+    let taken: HashSet<String> = store
+        .list_workflows()
+        .await
+        .map_err(CoreError::Persistence)?
+        .into_iter()
+        .map(|workflow| workflow.name)
+        .collect();
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let name = copy_name(&workflow.name, &taken);
+    let mut content: Value = serde_json::from_str(&workflow.content)
+        .map_err(|e| CoreError::Execution(format!("Invalid workflow JSON: {}", e)))?;
+    if let Value::Object(fields) = &mut content {
+        fields.insert("id".to_string(), Value::from(id.clone()));
+        fields.insert("name".to_string(), Value::from(name.clone()));
+    }
+    let content = serde_json::to_string_pretty(&content)
+        .map_err(|e| CoreError::Execution(format!("Failed to serialize workflow: {}", e)))?;
+
+    let now = chrono::Utc::now();
+    let copy = WorkflowDefinition {
+        id,
+        name,
+        content,
+        is_default: false,
+        is_edited: false,
+        created_at: now,
+        updated_at: now,
+        ..workflow
+    };
+    store
+        .save_workflow(&copy)
+        .await
+        .map_err(CoreError::Persistence)?;
+    Ok(copy)
+}
+
+/// Save a copy of prompt `prompt_id` as `ID-copy`, or `ID-copy-N` if that is
+/// taken, with a copy name.
+pub async fn duplicate_prompt(prompt_id: &str) -> Result<Prompt, CoreError> {
+    let store = get_global_store()?;
+    let prompts = store.list_prompts().await.map_err(CoreError::Persistence)?;
+    let prompt = prompts
+        .iter()
+        .find(|prompt| prompt.id == prompt_id)
+        .cloned()
+        .ok_or_else(|| CoreError::Execution(format!("Prompt not found: {}", prompt_id)))?;
+    let ids: HashSet<&str> = prompts.iter().map(|prompt| prompt.id.as_str()).collect();
+    let names: HashSet<String> = prompts.iter().map(|prompt| prompt.name.clone()).collect();
+
+    let id = std::iter::once(format!("{}-copy", prompt.id))
+        .chain((2..).map(|n| format!("{}-copy-{}", prompt.id, n)))
+        .find(|candidate| !ids.contains(candidate.as_str()))
+        .expect("there is always an unused copy number");
+    let copy = Prompt {
+        id,
+        name: copy_name(&prompt.name, &names),
+        created_at: chrono::Utc::now(),
+        ..prompt
+    };
+    store
+        .save_prompt(&copy)
+        .await
+        .map_err(CoreError::Persistence)?;
+    Ok(copy)
+}
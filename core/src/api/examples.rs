@@ -0,0 +1,276 @@
+use crate::api::line_diff::{diff_lines, DiffLine, DiffLineKind};
+use crate::embedded_data;
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use chrono::Utc;
+use s_e_e_persistence::WorkflowDefinition;
+use tracing::info;
+
+/// A workflow shipped with the app. Examples stay out of the user's library until
+/// they are added explicitly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExampleWorkflow {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// The workflow JSON, as it would be saved to the library.
+    pub content: String,
+}
+
+impl ExampleWorkflow {
+    fn parse(filename: &str, file: &str) -> Result<Self, CoreError> {
+        let file_data: serde_json::Value = serde_json::from_str(file)
+            .map_err(|e| CoreError::Execution(format!("Invalid example {}: {}", filename, e)))?;
+
+        let field = |name: &str| {
+            file_data[name]
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| CoreError::Execution(format!("Missing '{}' in {}", name, filename)))
+        };
+
+        let content = serde_json::to_string(&file_data["content"])
+            .map_err(|e| CoreError::Execution(format!("Invalid example {}: {}", filename, e)))?;
+
+        Ok(Self {
+            id: field("id")?,
+            name: field("name")?,
+            description: field("description").ok(),
+            content,
+        })
+    }
+
+    pub fn to_definition(&self) -> WorkflowDefinition {
+        let now = Utc::now();
+        WorkflowDefinition {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            content: self.content.clone(),
+            is_default: true,
+            is_edited: false,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+pub fn list_example_workflows() -> Result<Vec<ExampleWorkflow>, CoreError> {
+    embedded_data::get_default_workflows()
+        .into_iter()
+        .map(|(filename, file)| ExampleWorkflow::parse(filename, file))
+        .collect()
+}
+
+pub fn get_example_workflow(id: &str) -> Result<ExampleWorkflow, CoreError> {
+    list_example_workflows()?
+        .into_iter()
+        .find(|example| example.id == id)
+        .ok_or_else(|| CoreError::WorkflowNotFound(id.to_string()))
+}
+
+/// Copy an example into the library. Fails if a workflow with the same id is already there.
+pub async fn add_example_workflow(id: &str) -> Result<WorkflowDefinition, CoreError> {
+    let example = get_example_workflow(id)?;
+    let store = get_global_store()?;
+
+    if store
+        .get_workflow(id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .is_some()
+    {
+        return Err(CoreError::Execution(format!(
+            "'{}' is already in your workflows",
+            example.name
+        )));
+    }
+
+    let workflow = example.to_definition();
+    workflow.validate().map_err(CoreError::Persistence)?;
+    store
+        .save_workflow(&workflow)
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    info!(workflow_id = %id, "Added example workflow");
+    Ok(workflow)
+}
+
+/// Copy every example that is not in the library yet. Returns how many were added.
+pub async fn add_all_example_workflows() -> Result<usize, CoreError> {
+    let store = get_global_store()?;
+    let mut added = 0;
+
+    for example in list_example_workflows()? {
+        if store
+            .get_workflow(&example.id)
+            .await
+            .map_err(CoreError::Persistence)?
+            .is_some()
+        {
+            continue;
+        }
+        let workflow = example.to_definition();
+        workflow.validate().map_err(CoreError::Persistence)?;
+        store
+            .save_workflow(&workflow)
+            .await
+            .map_err(CoreError::Persistence)?;
+        added += 1;
+    }
+
+    info!(added, "Added example workflows");
+    Ok(added)
+}
+
+/// Record the first-run choice, adding the examples if the user asked for them.
+pub async fn complete_onboarding(add_examples: bool) -> Result<usize, CoreError> {
+    let added = if add_examples {
+        add_all_example_workflows().await?
+    } else {
+        0
+    };
+
+    let store = get_global_store()?;
+    let mut settings = store
+        .load_settings()
+        .await
+        .map_err(CoreError::Persistence)?
+        .unwrap_or_default();
+    settings.set_onboarding_complete(true);
+    store
+        .save_settings(&settings)
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    Ok(added)
+}
+
+/// What resetting a library workflow to its shipped example would change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefaultResetPreview {
+    pub workflow_id: String,
+    pub name: String,
+    /// The library copy was changed after it was added.
+    pub is_edited: bool,
+    /// Library content on the removed side, shipped content on the added side.
+    pub lines: Vec<DiffLine>,
+}
+
+impl DefaultResetPreview {
+    pub fn has_changes(&self) -> bool {
+        self.lines
+            .iter()
+            .any(|line| line.kind != DiffLineKind::Unchanged)
+    }
+}
+
+/// Result of [`refresh_examples`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExampleRefresh {
+    /// Examples whose library copy was updated to the shipped content.
+    pub updated: Vec<String>,
+    /// Examples left alone because the library copy was edited.
+    pub skipped_edited: Vec<String>,
+}
+
+/// Pretty-prints JSON so both sides of a diff share one layout; anything that
+/// does not parse is diffed as written.
+fn normalize_content(content: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(content)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| content.to_string())
+}
+
+async fn library_copy(id: &str) -> Result<WorkflowDefinition, CoreError> {
+    get_global_store()?
+        .get_workflow(id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(id.to_string()))
+}
+
+/// Diff between a library workflow and the example it came from, for review
+/// before [`reset_default_workflow`] overwrites it.
+pub async fn preview_default_reset(id: &str) -> Result<DefaultResetPreview, CoreError> {
+    let example = get_example_workflow(id)?;
+    let current = library_copy(id).await?;
+
+    Ok(DefaultResetPreview {
+        workflow_id: id.to_string(),
+        name: example.name,
+        is_edited: current.is_edited,
+        lines: diff_lines(
+            &normalize_content(&current.content),
+            &normalize_content(&example.content),
+        ),
+    })
+}
+
+/// Overwrite one library workflow with its shipped example, edited or not.
+/// The creation time is kept so the workflow stays in place in the library.
+pub async fn reset_default_workflow(id: &str) -> Result<WorkflowDefinition, CoreError> {
+    let example = get_example_workflow(id)?;
+    let current = library_copy(id).await?;
+
+    let workflow = WorkflowDefinition {
+        created_at: current.created_at,
+        ..example.to_definition()
+    };
+    workflow.validate().map_err(CoreError::Persistence)?;
+    get_global_store()?
+        .save_workflow(&workflow)
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    info!(workflow_id = %id, was_edited = current.is_edited, "Reset workflow to shipped example");
+    Ok(workflow)
+}
+
+/// Bring unedited library copies of the examples up to the shipped content.
+/// Edited copies are reported and left for [`reset_default_workflow`].
+pub async fn refresh_examples() -> Result<ExampleRefresh, CoreError> {
+    let store = get_global_store()?;
+    let mut refresh = ExampleRefresh::default();
+
+    for example in list_example_workflows()? {
+        let Some(current) = store
+            .get_workflow(&example.id)
+            .await
+            .map_err(CoreError::Persistence)?
+        else {
+            continue;
+        };
+
+        if current.is_edited {
+            refresh.skipped_edited.push(example.id);
+            continue;
+        }
+        if normalize_content(&current.content) == normalize_content(&example.content)
+            && current.name == example.name
+            && current.description == example.description
+        {
+            continue;
+        }
+
+        let workflow = WorkflowDefinition {
+            created_at: current.created_at,
+            ..example.to_definition()
+        };
+        workflow.validate().map_err(CoreError::Persistence)?;
+        store
+            .save_workflow(&workflow)
+            .await
+            .map_err(CoreError::Persistence)?;
+        refresh.updated.push(example.id);
+    }
+
+    info!(
+        updated = refresh.updated.len(),
+        skipped_edited = refresh.skipped_edited.len(),
+        "Refreshed example workflows"
+    );
+    Ok(refresh)
+}
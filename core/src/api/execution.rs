@@ -1,14 +1,36 @@
+use crate::api::bundle::workflow_trust_prompt;
+use crate::api::checkpoints::{clear_checkpoint, StoreCheckpoints};
+use crate::api::connections::{load_db_connections, load_message_connections};
+use crate::api::labels::stored_labels;
+use crate::api::lifecycle::track_run;
+use crate::api::locks::StoreResourceLocks;
+use crate::api::manifest::build_manifest;
+use crate::api::middleware::with_registered_middlewares;
+use crate::api::notifications::{notify_execution, notify_tasks};
+use crate::api::output_journal::OutputJournal;
+use crate::api::run_bounds::apply_run_bounds;
+use crate::api::secrets::Secrets;
+use crate::api::snippets::expand_workflow_snippets;
+use crate::api::task_cache::{directory_cache, StoreTaskResultCache};
+use crate::api::triggers::{execution_outputs, run_dependent_workflows, TriggerSource};
 use crate::bridge::audit::audit_entry_to_event;
-use crate::bridge::execution::workflow_result_to_execution;
-use crate::bridge::workflow::workflow_definition_to_engine;
+use crate::bridge::execution::{link_task_prompts, workflow_result_to_execution};
+use crate::bridge::workflow::{
+    engine_result_to_core_result, profiled_workflow_definition_to_engine,
+};
 use crate::bridge::{OutputCallback, WorkflowResult};
 use crate::errors::CoreError;
 use crate::store_singleton::get_global_store;
-use s_e_e_engine::WorkflowEngine;
+use s_e_e_engine::parameters::resolve_parameters;
+use s_e_e_engine::{OutputEvents, ProfilePhase, Profiler, WorkflowEngine};
 use s_e_e_persistence::{
-    InputRequestStatus, InputType, UserInputRequest, WorkflowExecution, WorkflowExecutionStatus,
+    InputRequestStatus, InputType, NotificationKind, RunBounds, UserInputRequest,
+    WorkflowDefinition, WorkflowExecution, WorkflowExecutionStatus,
 };
 use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
 
 pub async fn delete_workflow_execution(execution_id: &str) -> Result<(), CoreError> {
     tracing::info!("Deleting workflow execution: {}", execution_id);
@@ -105,6 +127,99 @@ pub async fn delete_workflow_execution(execution_id: &str) -> Result<(), CoreErr
 pub async fn execute_workflow_by_id(
     workflow_id: &str,
     callback: Option<OutputCallback>,
+) -> Result<WorkflowResult, CoreError> {
+    execute_workflow_by_id_with_params(workflow_id, HashMap::new(), callback).await
+}
+
+pub async fn execute_workflow_by_id_with_params(
+    workflow_id: &str,
+    params: HashMap<String, Value>,
+    callback: Option<OutputCallback>,
+) -> Result<WorkflowResult, CoreError> {
+    execute_workflow(
+        workflow_id,
+        params,
+        Vec::new(),
+        RunBounds::default(),
+        callback,
+        None,
+        Profiler::default(),
+    )
+    .await
+}
+
+/// [`execute_workflow_by_id_with_params`], storing `labels` with the execution.
+pub async fn execute_workflow_by_id_with_labels(
+    workflow_id: &str,
+    params: HashMap<String, Value>,
+    labels: Vec<String>,
+    callback: Option<OutputCallback>,
+) -> Result<WorkflowResult, CoreError> {
+    execute_workflow(
+        workflow_id,
+        params,
+        labels,
+        RunBounds::default(),
+        callback,
+        None,
+        Profiler::default(),
+    )
+    .await
+}
+
+/// [`execute_workflow_by_id_with_labels`] for only part of the workflow: from
+/// `bounds.from` on, stopping after `bounds.until`. Outputs of skipped tasks
+/// that later tasks read are taken from the `bounds.context_from` execution.
+pub async fn execute_workflow_by_id_with_bounds(
+    workflow_id: &str,
+    params: HashMap<String, Value>,
+    labels: Vec<String>,
+    bounds: RunBounds,
+    callback: Option<OutputCallback>,
+) -> Result<WorkflowResult, CoreError> {
+    execute_workflow(
+        workflow_id,
+        params,
+        labels,
+        bounds,
+        callback,
+        None,
+        Profiler::default(),
+    )
+    .await
+}
+
+/// [`execute_workflow_by_id_with_bounds`], timing each phase of the run:
+/// parsing and validating the workflow, each round's graph build, each task's
+/// dispatch and handler, and the reads and writes to the store. The result's
+/// `profile` holds the timings.
+pub async fn execute_workflow_by_id_profiled(
+    workflow_id: &str,
+    params: HashMap<String, Value>,
+    labels: Vec<String>,
+    bounds: RunBounds,
+    callback: Option<OutputCallback>,
+) -> Result<WorkflowResult, CoreError> {
+    execute_workflow(
+        workflow_id,
+        params,
+        labels,
+        bounds,
+        callback,
+        None,
+        Profiler::new(),
+    )
+    .await
+}
+
+pub(crate) async fn execute_workflow(
+    workflow_id: &str,
+    params: HashMap<String, Value>,
+    labels: Vec<String>,
+    bounds: RunBounds,
+    callback: Option<OutputCallback>,
+    source: Option<TriggerSource>,
+    profiler: Profiler,
 ) -> Result<WorkflowResult, CoreError> {
     tracing::debug!("Executing workflow: {}", workflow_id);
 
@@ -112,13 +227,21 @@ pub async fn execute_workflow_by_id(
     let store = get_global_store()?;
     tracing::debug!("Step 1: Got global store");
     tracing::debug!("Step 1: Loading workflow from DB");
-    let workflow = store
-        .get_workflow(workflow_id)
+    let workflow = profiler
+        .time(
+            ProfilePhase::Persistence,
+            "load workflow",
+            store.get_workflow(workflow_id),
+        )
         .await
         .map_err(CoreError::Persistence)?
         .ok_or_else(|| CoreError::WorkflowNotFound(workflow_id.to_string()))?;
     tracing::debug!("Step 1: Loaded workflow: {}", workflow.name);
 
+    if workflow_trust_prompt(workflow_id).await?.is_some() {
+        return Err(CoreError::UntrustedWorkflow(workflow_id.to_string()));
+    }
+
     tracing::debug!("Step 2: Validating workflow content");
     if workflow.content.is_empty() {
         return Err(CoreError::Execution(
@@ -126,19 +249,36 @@ pub async fn execute_workflow_by_id(
         ));
     }
 
+    let workflow = WorkflowDefinition {
+        content: expand_workflow_snippets(&workflow.content).await?,
+        ..workflow
+    };
+
     tracing::debug!("Step 3: Parsing workflow JSON");
     let workflow_json: serde_json::Value = serde_json::from_str(&workflow.content)
         .map_err(|e| CoreError::Execution(format!("Invalid workflow JSON: {}", e)))?;
     tracing::debug!("Step 3: Parsed workflow JSON successfully");
 
     tracing::debug!("Step 4: Converting to engine workflow");
-    let engine_workflow = workflow_definition_to_engine(&workflow)?;
+    let engine_workflow = profiled_workflow_definition_to_engine(&workflow, &profiler)?;
     tracing::debug!("Step 4: Converted to engine workflow");
+    let (engine_workflow, initial_data) =
+        apply_run_bounds(engine_workflow, &workflow.id, &bounds).await?;
+
+    let upstream_ids = vec![workflow.id.clone(), engine_workflow.id.clone()];
+    let notify_rules = engine_workflow.notify.clone();
+    let parameters: HashMap<String, Value> =
+        resolve_parameters(&engine_workflow.parameters, &params)
+            .map_err(|e| CoreError::Engine(e.into()))?
+            .into_iter()
+            .collect();
+    let secrets = Secrets::new(&engine_workflow.parameters, &parameters);
+    let stored_parameters: HashMap<String, Value> = secrets.mask(&parameters);
 
     let execution_id = uuid::Uuid::new_v4().to_string();
     let now = chrono::Utc::now();
 
-    let initial_execution = WorkflowExecution {
+    let mut initial_execution = WorkflowExecution {
         id: execution_id.clone(),
         workflow_name: workflow.name.clone(),
         workflow_snapshot: workflow_json,
@@ -150,19 +290,62 @@ pub async fn execute_workflow_by_id(
         audit_trail: Vec::new(),
         per_task_logs: std::collections::HashMap::new(),
         errors: Vec::new(),
+        parameters: stored_parameters.clone(),
+        triggered_by: source.as_ref().map(|s| s.execution_id.clone()),
+        workflow_id: Some(workflow.id.clone()),
+        labels: Vec::new(),
+        bounds: (!bounds.is_empty()).then_some(bounds),
+        manifest: Some(
+            build_manifest(&engine_workflow, &workflow.content, &stored_parameters).await,
+        ),
+        sealed_parameters: secrets.seal()?,
     };
+    initial_execution.set_labels(labels);
+
+    let db_connections = load_db_connections().await?;
+    let message_connections = load_message_connections().await?;
 
     tracing::debug!("Step 6: Saving initial execution to DB");
-    store
-        .save_workflow_execution(initial_execution.clone())
+    profiler
+        .time(
+            ProfilePhase::Persistence,
+            "save execution",
+            store.save_workflow_execution(initial_execution.clone()),
+        )
         .await
         .map_err(CoreError::Persistence)?;
     tracing::debug!("Step 6: Saved initial execution");
+    let run = track_run(&execution_id);
 
     tracing::debug!("Step 7: Creating workflow engine");
-    let engine = WorkflowEngine::new();
+    let journal = OutputJournal::start(store.clone(), &execution_id);
+    let output = secrets
+        .guard_output(Some(journal.sink(callback)))
+        .map(|sink| OutputEvents::new(execution_id.clone(), sink));
+    let mut engine = secrets
+        .guard(with_registered_middlewares(WorkflowEngine::new()))
+        .with_result_cache(Arc::new(StoreTaskResultCache::new(store.clone())))
+        .with_checkpoints(Arc::new(StoreCheckpoints::new(store.clone())))
+        .with_directory_cache(directory_cache()?)
+        .with_resource_locks(Arc::new(StoreResourceLocks::new(store.clone())))
+        .with_execution_id(execution_id.clone())
+        .with_db_connections(db_connections)
+        .with_message_connections(message_connections)
+        .with_profiler(profiler.clone());
+    if let Some(output) = &output {
+        engine = engine.with_output(output.clone());
+    }
+    if let Some(data) = initial_data {
+        engine = engine.with_initial_data(data);
+    }
     tracing::debug!("Step 7: Executing workflow through engine");
-    let engine_result = match engine.execute_workflow(engine_workflow).await {
+    let engine_result = run
+        .unless_cancelled(engine.execute_workflow_with_params(engine_workflow, parameters))
+        .await;
+    if run.interrupted() {
+        return Err(CoreError::Engine(s_e_e_engine::EngineError::Cancelled));
+    }
+    let mut engine_result = match engine_result {
         Ok(result) => {
             tracing::debug!("Step 7: Engine execution completed successfully");
             result
@@ -173,17 +356,23 @@ pub async fn execute_workflow_by_id(
             let mut failed_execution = initial_execution.clone();
             failed_execution.status = WorkflowExecutionStatus::Failed;
             failed_execution.completed_at = Some(chrono::Utc::now());
-            failed_execution.errors = vec![e.to_string()];
+            failed_execution.errors = vec![secrets.redact_text(&e.to_string())];
+            failed_execution.labels =
+                stored_labels(&store, &execution_id, &initial_execution.labels).await;
 
             store
                 .save_workflow_execution(failed_execution)
                 .await
                 .map_err(CoreError::Persistence)?;
+            clear_checkpoint(&store, &execution_id).await;
+            journal.discard(&store).await;
 
             return Err(CoreError::Engine(e));
         }
     };
 
+    secrets.redact_result(&mut engine_result);
+
     let has_input_waiting = engine_result
         .tasks
         .iter()
@@ -205,8 +394,12 @@ pub async fn execute_workflow_by_id(
                             request_id = %input_request.id,
                             "Creating input request for task"
                         );
-                        store
-                            .save_input_request(&input_request)
+                        profiler
+                            .time(
+                                ProfilePhase::Persistence,
+                                "save input request",
+                                store.save_input_request(&input_request),
+                            )
                             .await
                             .map_err(CoreError::Persistence)?;
                     }
@@ -222,39 +415,59 @@ pub async fn execute_workflow_by_id(
 
         let mut updated_execution = waiting_execution.clone();
         updated_execution.workflow_snapshot = initial_execution.workflow_snapshot;
+        link_task_prompts(&mut updated_execution);
+        updated_execution.parameters = stored_parameters;
+        updated_execution.sealed_parameters = initial_execution.sealed_parameters.clone();
+        updated_execution.triggered_by = initial_execution.triggered_by.clone();
+        updated_execution.workflow_id = initial_execution.workflow_id.clone();
+        updated_execution.bounds = initial_execution.bounds.clone();
+        updated_execution.manifest = initial_execution.manifest.clone();
+        updated_execution.labels =
+            stored_labels(&store, &execution_id, &initial_execution.labels).await;
 
         updated_execution.status = WorkflowExecutionStatus::WaitingForInput;
         updated_execution.completed_at = None;
 
+        let started = Instant::now();
         for task in &updated_execution.tasks {
             store
                 .save_task_execution(task.clone())
                 .await
                 .map_err(CoreError::Persistence)?;
         }
-
-        store
-            .save_workflow_execution(updated_execution)
+        profiler.record(ProfilePhase::Persistence, "save task executions", started);
+
+        profiler
+            .time(
+                ProfilePhase::Persistence,
+                "save execution",
+                store.save_workflow_execution(updated_execution),
+            )
             .await
             .map_err(CoreError::Persistence)?;
 
-        if let Some(ref callback) = callback {
-            callback("Workflow paused - waiting for user input".to_string());
+        if let Some(ref output) = output {
+            output.message("Workflow paused - waiting for user input");
         }
+        journal.discard(&store).await;
+        notify_tasks(&notify_rules, &engine_result, &execution_id).await;
+        notify_execution(
+            NotificationKind::InputRequested,
+            &initial_execution.workflow_name,
+            &execution_id,
+        )
+        .await;
 
         return Ok(WorkflowResult {
             success: false,
-            workflow_name: engine_result.workflow_name,
-            execution_id,
-            tasks: engine_result.tasks,
-            audit_trail: engine_result.audit_trail,
-            per_task_logs: engine_result.per_task_logs,
             errors: vec!["Waiting for user input".to_string()],
+            profile: profiler.profile(),
+            ..engine_result_to_core_result(engine_result, execution_id)
         });
     }
 
-    if let Some(ref callback) = callback {
-        callback("Workflow execution completed".to_string());
+    if let Some(ref output) = output {
+        output.message("Workflow execution completed");
     }
 
     let _completed_at = chrono::Utc::now();
@@ -265,14 +478,25 @@ pub async fn execute_workflow_by_id(
     );
 
     final_execution.workflow_snapshot = initial_execution.workflow_snapshot;
-
+    link_task_prompts(&mut final_execution);
+    final_execution.parameters = stored_parameters.clone();
+    final_execution.sealed_parameters = initial_execution.sealed_parameters.clone();
+    final_execution.triggered_by = initial_execution.triggered_by.clone();
+    final_execution.workflow_id = initial_execution.workflow_id.clone();
+    final_execution.bounds = initial_execution.bounds.clone();
+    final_execution.manifest = initial_execution.manifest.clone();
+    final_execution.labels = stored_labels(&store, &execution_id, &initial_execution.labels).await;
+
+    let started = Instant::now();
     for task in &final_execution.tasks {
         store
             .save_task_execution(task.clone())
             .await
             .map_err(CoreError::Persistence)?;
     }
+    profiler.record(ProfilePhase::Persistence, "save task executions", started);
 
+    let started = Instant::now();
     for audit_entry in &engine_result.audit_trail {
         let audit_event = audit_entry_to_event(audit_entry)?;
         store
@@ -280,20 +504,35 @@ pub async fn execute_workflow_by_id(
             .await
             .map_err(CoreError::Persistence)?;
     }
-
-    store
-        .save_workflow_execution(final_execution.clone())
+    profiler.record(ProfilePhase::Persistence, "save audit events", started);
+
+    profiler
+        .time(
+            ProfilePhase::Persistence,
+            "save execution",
+            store.save_workflow_execution(final_execution.clone()),
+        )
         .await
         .map_err(CoreError::Persistence)?;
+    profiler
+        .time(
+            ProfilePhase::Persistence,
+            "clear checkpoint",
+            clear_checkpoint(&store, &execution_id),
+        )
+        .await;
+    profiler
+        .time(
+            ProfilePhase::Persistence,
+            "discard output journal",
+            journal.discard(&store),
+        )
+        .await;
+    notify_tasks(&notify_rules, &engine_result, &execution_id).await;
 
     let result = WorkflowResult {
-        success: engine_result.success,
-        workflow_name: engine_result.workflow_name,
-        execution_id,
-        tasks: engine_result.tasks,
-        audit_trail: engine_result.audit_trail,
-        per_task_logs: engine_result.per_task_logs,
-        errors: engine_result.errors,
+        profile: profiler.profile(),
+        ..engine_result_to_core_result(engine_result, execution_id)
     };
 
     tracing::info!(
@@ -301,6 +540,27 @@ pub async fn execute_workflow_by_id(
         result.success,
         result.execution_id
     );
+
+    let kind = match (result.success, &final_execution.triggered_by) {
+        (true, _) => NotificationKind::ExecutionCompleted,
+        (false, Some(_)) => NotificationKind::TriggeredRunFailed,
+        (false, None) => NotificationKind::ExecutionFailed,
+    };
+    notify_execution(kind, &final_execution.workflow_name, &result.execution_id).await;
+
+    if result.success {
+        let chain = match source {
+            Some(source) => source.chain,
+            None => vec![workflow.id.clone()],
+        };
+        let source = TriggerSource {
+            execution_id: result.execution_id.clone(),
+            chain,
+        };
+        let outputs = execution_outputs(&result.audit_trail, &stored_parameters);
+        run_dependent_workflows(&upstream_ids, source, outputs, output).await;
+    }
+
     Ok(result)
 }
 
@@ -336,11 +596,18 @@ fn create_input_request_from_task(
     let function = task_node.get("function")?;
     let function_type = function.get("name").and_then(|v| v.as_str())?;
 
+    let input = function.get("input")?;
+    if function_type == "manual" {
+        return Some(UserInputRequest::manual(
+            task_id,
+            execution_id,
+            input.get("instructions")?.as_str()?,
+        ));
+    }
     if function_type != "user_input" {
         return None;
     }
 
-    let input = function.get("input")?;
     let prompt = input.get("prompt")?.as_str()?.to_string();
     let input_type_str = input
         .get("input_type")
@@ -0,0 +1,157 @@
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_persistence::{TaskExecutionStatus, WorkflowExecution};
+use std::collections::HashMap;
+
+/// How many of a workflow's most recent runs are looked at.
+pub const FLAKINESS_WINDOW: usize = 20;
+
+/// A task has to go from passing to failing or back at least this often to
+/// count as flaky; a single change is a regression or a fix.
+const MIN_FLIPS: usize = 2;
+
+/// A task whose outcome keeps changing between runs of the same workflow
+/// definition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlakyTask {
+    pub workflow_id: Option<String>,
+    pub workflow_name: String,
+    pub task_id: String,
+    pub task_name: String,
+    /// Runs in which the task finished.
+    pub runs: usize,
+    pub failures: usize,
+    /// Times the outcome differed from the run before.
+    pub flips: usize,
+}
+
+impl FlakyTask {
+    /// Share of consecutive runs whose outcome differed, from 0.0 to 1.0.
+    pub fn flip_rate(&self) -> f64 {
+        if self.runs < 2 {
+            return 0.0;
+        }
+        self.flips as f64 / (self.runs - 1) as f64
+    }
+}
+
+fn workflow_key(execution: &WorkflowExecution) -> (Option<&str>, &str) {
+    match execution.workflow_id.as_deref() {
+        Some(id) => (Some(id), ""),
+        None => (None, execution.workflow_name.as_str()),
+    }
+}
+
+/// Flaky tasks among `runs`, which must all have run the same definition.
+fn flaky_tasks_in(runs: &[&WorkflowExecution]) -> Vec<FlakyTask> {
+    let mut outcomes: Vec<(&str, &str, Vec<bool>)> = Vec::new();
+    for execution in runs {
+        for task in &execution.tasks {
+            let passed = match task.status {
+                TaskExecutionStatus::Complete => true,
+                TaskExecutionStatus::Failed => false,
+                _ => continue,
+            };
+            match outcomes.iter_mut().find(|(id, _, _)| *id == task.id) {
+                Some((_, _, history)) => history.push(passed),
+                None => outcomes.push((&task.id, &task.name, vec![passed])),
+            }
+        }
+    }
+
+    let Some(latest) = runs.last() else {
+        return Vec::new();
+    };
+    outcomes
+        .into_iter()
+        .filter_map(|(task_id, task_name, history)| {
+            let flips = history.windows(2).filter(|pair| pair[0] != pair[1]).count();
+            (flips >= MIN_FLIPS).then(|| FlakyTask {
+                workflow_id: latest.workflow_id.clone(),
+                workflow_name: latest.workflow_name.clone(),
+                task_id: task_id.to_string(),
+                task_name: task_name.to_string(),
+                runs: history.len(),
+                failures: history.iter().filter(|passed| !**passed).count(),
+                flips,
+            })
+        })
+        .collect()
+}
+
+/// The last [`FLAKINESS_WINDOW`] runs that share `latest`'s workflow and
+/// definition, oldest first. `executions` must be sorted oldest first.
+fn comparable_runs<'a>(
+    executions: &'a [WorkflowExecution],
+    latest: &WorkflowExecution,
+) -> Vec<&'a WorkflowExecution> {
+    let mut runs: Vec<&WorkflowExecution> = executions
+        .iter()
+        .filter(|execution| {
+            execution.created_at <= latest.created_at
+                && workflow_key(execution) == workflow_key(latest)
+                && execution.workflow_snapshot == latest.workflow_snapshot
+        })
+        .collect();
+    let skip = runs.len().saturating_sub(FLAKINESS_WINDOW);
+    runs.drain(..skip);
+    runs
+}
+
+/// Flaky tasks across all workflows, most flaky first. Each workflow is judged
+/// on the recent runs of its latest definition, so editing a workflow starts
+/// its history over.
+pub fn detect_flaky_tasks(executions: &[WorkflowExecution]) -> Vec<FlakyTask> {
+    let mut sorted: Vec<WorkflowExecution> = executions.to_vec();
+    sorted.sort_by_key(|execution| execution.created_at);
+
+    let mut latest: HashMap<(Option<&str>, &str), &WorkflowExecution> = HashMap::new();
+    for execution in &sorted {
+        latest.insert(workflow_key(execution), execution);
+    }
+
+    let mut flaky: Vec<FlakyTask> = latest
+        .values()
+        .flat_map(|execution| flaky_tasks_in(&comparable_runs(&sorted, execution)))
+        .collect();
+    flaky.sort_by(|a, b| {
+        b.flip_rate()
+            .total_cmp(&a.flip_rate())
+            .then(b.flips.cmp(&a.flips))
+            .then_with(|| a.workflow_name.cmp(&b.workflow_name))
+            .then_with(|| a.task_id.cmp(&b.task_id))
+    });
+    flaky
+}
+
+/// The most flaky tasks across stored executions.
+pub async fn flaky_tasks(limit: usize) -> Result<Vec<FlakyTask>, CoreError> {
+    let store = get_global_store()?;
+    let executions = store
+        .list_workflow_executions()
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    let mut flaky = detect_flaky_tasks(&executions);
+    flaky.truncate(limit);
+    Ok(flaky)
+}
+
+/// Flaky tasks of the workflow definition an execution ran, judged on the
+/// runs up to and including it.
+pub async fn execution_flaky_tasks(execution_id: &str) -> Result<Vec<FlakyTask>, CoreError> {
+    let store = get_global_store()?;
+    let execution = store
+        .get_workflow_execution(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(execution_id.to_string()))?;
+
+    let mut executions = store
+        .list_workflow_executions()
+        .await
+        .map_err(CoreError::Persistence)?;
+    executions.sort_by_key(|execution| execution.created_at);
+
+    Ok(flaky_tasks_in(&comparable_runs(&executions, &execution)))
+}
@@ -0,0 +1,35 @@
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::{set_handler_policy, HandlerPolicy};
+use s_e_e_persistence::HandlerPolicySettings;
+use tracing::debug;
+
+/// The engine policy for the active profile, or `None` when no profile is
+/// active and every handler may run.
+pub fn handler_policy_from_settings(settings: &HandlerPolicySettings) -> Option<HandlerPolicy> {
+    settings.active().map(|profile| HandlerPolicy {
+        profile: profile.name.clone(),
+        allowed: profile.allowed_handlers.clone(),
+        denied: profile.denied_handlers.clone(),
+    })
+}
+
+/// Use the active profile in `settings` for every workflow validated or run
+/// from now on.
+pub fn apply_handler_policy(settings: &HandlerPolicySettings) {
+    debug!(active_profile = ?settings.active_profile, "Applying handler policy");
+    set_handler_policy(handler_policy_from_settings(settings));
+}
+
+/// Apply the handler policy saved in the settings; without saved settings
+/// every handler may run.
+pub async fn load_handler_policy() -> Result<(), CoreError> {
+    let store = get_global_store()?;
+    let settings = store
+        .load_settings()
+        .await
+        .map_err(CoreError::Persistence)?
+        .unwrap_or_default();
+    apply_handler_policy(&settings.handler_policy);
+    Ok(())
+}
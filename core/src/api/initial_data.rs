@@ -4,18 +4,18 @@ use tracing::{error, info};
 use crate::embedded_data;
 use crate::store_singleton;
 
-pub async fn populate_initial_workflows() -> Result<(), String> {
+pub async fn populate_initial_prompts() -> Result<(), String> {
     let store = store_singleton::get_global_store()?;
 
-    let existing_workflows = store.list_workflows().await?;
-    if !existing_workflows.is_empty() {
-        info!("Workflows already exist, skipping initial population");
+    let existing_prompts = store.list_prompts().await?;
+    if !existing_prompts.is_empty() {
+        info!("Prompts already exist, skipping initial population");
         return Ok(());
     }
 
     let mut loaded_count = 0;
 
-    for (filename, content) in embedded_data::get_default_workflows() {
+    for (filename, content) in embedded_data::get_default_prompts() {
         let file_data: serde_json::Value = serde_json::from_str(content).map_err(|e| {
             error!("Failed to parse JSON in {}: {}", filename, e);
             format!("Failed to parse JSON: {}", e)
@@ -29,83 +29,64 @@ pub async fn populate_initial_workflows() -> Result<(), String> {
             .as_str()
             .ok_or_else(|| format!("Missing 'name' field in {}", filename))?
             .to_string();
-        let description = file_data["description"].as_str().map(|s| s.to_string());
-
-        let content = file_data["content"].clone();
-        let content_str = serde_json::to_string(&content).map_err(|e| {
-            error!("Failed to serialize content: {}", e);
-            format!("Failed to serialize content: {}", e)
-        })?;
+        let content_str = file_data["content"]
+            .as_str()
+            .ok_or_else(|| format!("Missing 'content' field in {}", filename))?
+            .to_string();
 
-        let workflow = s_e_e_persistence::WorkflowDefinition {
+        let mut prompt = s_e_e_persistence::Prompt {
             id: id.clone(),
             name,
-            description,
             content: content_str,
-            is_default: true,
-            is_edited: false,
             created_at: Utc::now(),
-            updated_at: Utc::now(),
+            tags: Vec::new(),
         };
+        if let Some(tags) = file_data["tags"].as_array() {
+            prompt.set_tags(tags.iter().filter_map(|tag| tag.as_str()));
+        }
 
-        workflow.validate()?;
+        prompt.validate()?;
 
-        store.save_workflow(&workflow).await?;
+        store.save_prompt(&prompt).await?;
         loaded_count += 1;
 
-        info!("Loaded initial workflow '{}'", id);
+        info!("Loaded initial prompt '{}'", id);
     }
 
-    info!("Loaded {} initial workflows", loaded_count);
+    info!("Loaded {} initial prompts", loaded_count);
     Ok(())
 }
 
-pub async fn populate_initial_prompts() -> Result<(), String> {
+pub async fn populate_initial_snippets() -> Result<(), String> {
     let store = store_singleton::get_global_store()?;
 
-    let existing_prompts = store.list_prompts().await?;
-    if !existing_prompts.is_empty() {
-        info!("Prompts already exist, skipping initial population");
+    if !store.list_snippets().await?.is_empty() {
+        info!("Snippets already exist, skipping initial population");
         return Ok(());
     }
 
-    let mut loaded_count = 0;
-
-    for (filename, content) in embedded_data::get_default_prompts() {
+    for (filename, content) in embedded_data::get_default_snippets() {
         let file_data: serde_json::Value = serde_json::from_str(content).map_err(|e| {
             error!("Failed to parse JSON in {}: {}", filename, e);
             format!("Failed to parse JSON: {}", e)
         })?;
-
-        let id = file_data["id"]
-            .as_str()
-            .ok_or_else(|| format!("Missing 'id' field in {}", filename))?
-            .to_string();
-        let name = file_data["name"]
-            .as_str()
-            .ok_or_else(|| format!("Missing 'name' field in {}", filename))?
-            .to_string();
-        let content_str = file_data["content"]
-            .as_str()
-            .ok_or_else(|| format!("Missing 'content' field in {}", filename))?
-            .to_string();
-
-        let prompt = s_e_e_persistence::Prompt {
-            id: id.clone(),
-            name,
-            content: content_str,
-            created_at: Utc::now(),
-        };
-
-        prompt.validate()?;
-
-        store.save_prompt(&prompt).await?;
-        loaded_count += 1;
-
-        info!("Loaded initial prompt '{}'", id);
+        let mut object = file_data
+            .as_object()
+            .cloned()
+            .ok_or_else(|| format!("{} is not a JSON object", filename))?;
+        let now = serde_json::to_value(Utc::now()).map_err(|e| e.to_string())?;
+        object.insert("created_at".to_string(), now.clone());
+        object.insert("updated_at".to_string(), now);
+
+        let snippet: s_e_e_persistence::TaskSnippet =
+            serde_json::from_value(serde_json::Value::Object(object))
+                .map_err(|e| format!("Invalid snippet in {}: {}", filename, e))?;
+        snippet.validate()?;
+
+        store.save_snippet(&snippet).await?;
+        info!("Loaded initial snippet '{}'", snippet.id);
     }
 
-    info!("Loaded {} initial prompts", loaded_count);
     Ok(())
 }
 
@@ -161,8 +142,9 @@ pub async fn populate_initial_data() -> Result<(), String> {
 
     audit_stuck_workflows().await?;
 
-    populate_initial_workflows().await?;
+    // Example workflows are opt-in: see `complete_onboarding` and `add_example_workflow`.
     populate_initial_prompts().await?;
+    populate_initial_snippets().await?;
 
     info!("✓ Initial data population complete");
     Ok(())
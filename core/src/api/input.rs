@@ -1,5 +1,6 @@
 use crate::errors::CoreError;
 use crate::store_singleton::get_global_store;
+use s_e_e_engine::{ManualConfirmation, ManualOutcome};
 use s_e_e_persistence::{TaskExecution, TaskExecutionStatus, UserInputRequest};
 use tracing::{debug, info};
 
@@ -113,6 +114,57 @@ pub async fn provide_user_input(
     Ok(())
 }
 
+/// Settle a `manual` task on behalf of `confirmed_by` and resume the run.
+/// The confirmation is kept as the task's input and, once the run resumes,
+/// in the audit trail.
+pub async fn confirm_manual_task(
+    execution_id: &str,
+    task_id: &str,
+    outcome: ManualOutcome,
+    confirmed_by: &str,
+    notes: Option<String>,
+) -> Result<(), CoreError> {
+    let confirmed_by = confirmed_by.trim();
+    if confirmed_by.is_empty() {
+        return Err(CoreError::Execution(
+            "Say who is confirming the manual task".to_string(),
+        ));
+    }
+
+    let store = get_global_store()?;
+    let is_manual = store
+        .get_input_request_by_task(task_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .is_some_and(|request| request.is_manual());
+    if !is_manual {
+        return Err(CoreError::Execution(format!(
+            "Task {} is not a manual task",
+            task_id
+        )));
+    }
+
+    let confirmation = ManualConfirmation {
+        outcome,
+        confirmed_by: confirmed_by.to_string(),
+        confirmed_at: chrono::Utc::now(),
+        notes: notes
+            .map(|notes| notes.trim().to_string())
+            .filter(|notes| !notes.is_empty()),
+    };
+    let value = serde_json::to_string(&confirmation)
+        .map_err(|e| CoreError::Execution(format!("Failed to encode confirmation: {}", e)))?;
+
+    provide_user_input(execution_id, task_id, value).await
+}
+
+/// Name to record as the confirmer by default: the local account's user name.
+pub fn local_user_name() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
 pub async fn get_pending_inputs(workflow_id: &str) -> Result<Vec<UserInputRequest>, CoreError> {
     debug!(
         workflow_id = %workflow_id,
@@ -0,0 +1,97 @@
+//! Execution labels and the saved filter presets that use them.
+
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_persistence::{ExecutionFilter, ExecutionFilterPreset, Store};
+use std::sync::Arc;
+
+/// Replace an execution's labels. Labels are trimmed, and blanks and
+/// duplicates dropped; an empty list clears them. Returns the labels kept.
+///
+/// Works on running executions too: the run picks the new labels up when it
+/// saves its result.
+pub async fn set_execution_labels(
+    execution_id: &str,
+    labels: Vec<String>,
+) -> Result<Vec<String>, CoreError> {
+    let store = get_global_store()?;
+    let mut execution = store
+        .get_workflow_execution(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(execution_id.to_string()))?;
+
+    execution.set_labels(labels);
+    let labels = execution.labels.clone();
+    store
+        .save_workflow_execution(execution)
+        .await
+        .map_err(CoreError::Persistence)?;
+    tracing::info!(execution_id = %execution_id, ?labels, "Set execution labels");
+    Ok(labels)
+}
+
+/// Labels as currently stored for an execution, or `fallback` when it can't be
+/// read. Runs save their result over the stored execution, so they take the
+/// labels from here to keep ones added while they ran.
+pub(crate) async fn stored_labels(
+    store: &Arc<Store>,
+    execution_id: &str,
+    fallback: &[String],
+) -> Vec<String> {
+    match store.get_workflow_execution(execution_id).await {
+        Ok(Some(execution)) => execution.labels,
+        _ => fallback.to_vec(),
+    }
+}
+
+/// Every label in use, sorted.
+pub async fn list_execution_labels() -> Result<Vec<String>, CoreError> {
+    let store = get_global_store()?;
+    store
+        .list_execution_labels()
+        .await
+        .map_err(CoreError::Persistence)
+}
+
+pub async fn list_execution_filter_presets() -> Result<Vec<ExecutionFilterPreset>, CoreError> {
+    let store = get_global_store()?;
+    store
+        .list_execution_filter_presets()
+        .await
+        .map_err(CoreError::Persistence)
+}
+
+/// Save `filter` under `name`, replacing a preset of the same name.
+pub async fn save_execution_filter_preset(
+    name: &str,
+    filter: ExecutionFilter,
+) -> Result<ExecutionFilterPreset, CoreError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(CoreError::InputValidationFailed(
+            "Filter preset name cannot be empty".to_string(),
+        ));
+    }
+    if filter.is_empty() {
+        return Err(CoreError::InputValidationFailed(
+            "Filter preset must narrow by status, label or workflow".to_string(),
+        ));
+    }
+
+    let preset = ExecutionFilterPreset::new(name, filter);
+    let store = get_global_store()?;
+    store
+        .save_execution_filter_preset(&preset)
+        .await
+        .map_err(CoreError::Persistence)?;
+    Ok(preset)
+}
+
+pub async fn delete_execution_filter_preset(name: &str) -> Result<(), CoreError> {
+    let store = get_global_store()?;
+    store
+        .delete_execution_filter_preset(name)
+        .await
+        .map_err(CoreError::Persistence)
+}
@@ -0,0 +1,106 @@
+//! Laid-out task graphs for the visual editor and the `workflow-graph` export.
+//!
+//! A workflow definition may carry `metadata.layout`, the layout mode it was
+//! last shown with, and `metadata.node_positions`, boxes the user dragged by
+//! hand. Dragged boxes stay where they were put whatever the layout.
+
+use crate::api::snippets::expand_workflow_snippets;
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::{layout_workflow, EngineTask, LayoutMode, NodePosition};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowGraph {
+    pub mode: LayoutMode,
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphNode {
+    pub id: String,
+    pub name: String,
+    pub x: f64,
+    pub y: f64,
+    /// Placed by hand rather than by the layout.
+    pub pinned: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphEdge {
+    pub source: String,
+    pub target: String,
+}
+
+/// Lay out the workflow in `content` with `mode`, or the mode saved in its
+/// metadata when `None`.
+pub fn layout_workflow_content(
+    content: &str,
+    mode: Option<LayoutMode>,
+) -> Result<WorkflowGraph, CoreError> {
+    let json: Value = serde_json::from_str(content)
+        .map_err(|e| CoreError::InputValidationFailed(e.to_string()))?;
+    let workflow = s_e_e_engine::parse_workflow(content)
+        .map_err(|e| CoreError::Engine(s_e_e_engine::EngineError::Parser(e)))?;
+    let metadata = &json["metadata"];
+    let mode = mode
+        .or_else(|| metadata["layout"].as_str().and_then(LayoutMode::from_name))
+        .unwrap_or_default();
+    let positions = layout_workflow(&workflow, mode);
+
+    fn walk(
+        task: &EngineTask,
+        metadata: &Value,
+        positions: &BTreeMap<String, NodePosition>,
+        graph: &mut WorkflowGraph,
+    ) {
+        let pinned = serde_json::from_value::<NodePosition>(
+            metadata["node_positions"][task.id.as_str()].clone(),
+        )
+        .ok();
+        let position = pinned.unwrap_or(positions[&task.id]);
+        graph.nodes.push(GraphNode {
+            id: task.id.clone(),
+            name: task.name.clone(),
+            x: position.x,
+            y: position.y,
+            pinned: pinned.is_some(),
+        });
+        for next in &task.next_tasks {
+            graph.edges.push(GraphEdge {
+                source: task.id.clone(),
+                target: next.id.clone(),
+            });
+            walk(next, metadata, positions, graph);
+        }
+    }
+
+    let mut graph = WorkflowGraph {
+        mode,
+        nodes: Vec::new(),
+        edges: Vec::new(),
+    };
+    for root in workflow.tasks.iter().filter(|task| task.is_root) {
+        walk(root, metadata, &positions, &mut graph);
+    }
+    Ok(graph)
+}
+
+/// Laid-out graph of a saved workflow, with snippet references expanded.
+pub async fn export_workflow_graph(
+    workflow_id: &str,
+    mode: Option<LayoutMode>,
+) -> Result<WorkflowGraph, CoreError> {
+    let store = get_global_store()?;
+    let workflow = store
+        .get_workflow(workflow_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(workflow_id.to_string()))?;
+
+    let content = expand_workflow_snippets(&workflow.content).await?;
+    layout_workflow_content(&content, mode)
+}
@@ -0,0 +1,297 @@
+//! Orderly startup and shutdown of a process's long-running parts: the
+//! listeners of `see listen`, the app's background maintenance and the
+//! workflow runs in flight.
+//!
+//! Subsystems start in the order they are added and stop in reverse, each
+//! told through its own signal and waited for before the next is told. They
+//! and the runs still going afterwards share one timeout. Whatever has not
+//! finished by then is cancelled: its task processes are killed and a run's
+//! execution is saved as failed, so none is left recorded as running. Its
+//! checkpoint is kept, so it can still be resumed. A single run can be stopped
+//! the same way with [`cancel_workflow_execution`], which saves it as
+//! cancelled and drops its checkpoint instead.
+
+use crate::api::checkpoints::clear_checkpoint;
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::handlers::process::kill_all_process_trees;
+use s_e_e_engine::EngineError;
+use s_e_e_persistence::{TaskExecutionStatus, WorkflowExecutionStatus};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+use std::time::Duration;
+use tokio::sync::{watch, Notify};
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+/// How long [`Lifecycle::shutdown`] waits before cancelling what is left.
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Error saved on an execution that was still running at shutdown.
+pub const INTERRUPTED_BY_SHUTDOWN: &str = "Interrupted by shutdown";
+
+/// Error saved on an execution cancelled with [`cancel_workflow_execution`].
+pub const CANCELLED_BY_USER: &str = "Cancelled";
+
+/// How often shutdown checks whether the runs in flight have finished.
+const RUN_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The background subsystems of a process, stopped together on shutdown.
+pub struct Lifecycle {
+    subsystems: Vec<Subsystem>,
+    timeout: Duration,
+}
+
+struct Subsystem {
+    name: String,
+    shutdown: watch::Sender<bool>,
+    handle: JoinHandle<()>,
+}
+
+/// What [`Lifecycle::shutdown`] had to do.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// Subsystems that stopped when told to, in the order they stopped.
+    pub stopped: Vec<String>,
+    /// Subsystems still running at the timeout, which were cancelled.
+    pub cancelled: Vec<String>,
+    /// Executions still running at the timeout, saved as failed.
+    pub interrupted: Vec<String>,
+}
+
+impl Default for Lifecycle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Lifecycle {
+    pub fn new() -> Self {
+        Self {
+            subsystems: Vec::new(),
+            timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+        }
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Spawn `subsystem` with a signal that turns true when it should stop.
+    pub fn start<F, Fut>(&mut self, name: &str, subsystem: F)
+    where
+        F: FnOnce(watch::Receiver<bool>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let (shutdown, signal) = watch::channel(false);
+        tracing::debug!(subsystem = name, "Starting subsystem");
+        self.subsystems.push(Subsystem {
+            name: name.to_string(),
+            shutdown,
+            handle: tokio::spawn(subsystem(signal)),
+        });
+    }
+
+    /// Stop the subsystems, last started first, then wait for the runs in
+    /// flight. Anything left at the timeout is cancelled.
+    pub async fn shutdown(self) -> ShutdownReport {
+        let deadline = Instant::now() + self.timeout;
+        let mut report = ShutdownReport::default();
+        for mut subsystem in self.subsystems.into_iter().rev() {
+            let _ = subsystem.shutdown.send(true);
+            match tokio::time::timeout_at(deadline, &mut subsystem.handle).await {
+                Ok(_) => {
+                    tracing::debug!(subsystem = %subsystem.name, "Subsystem stopped");
+                    report.stopped.push(subsystem.name);
+                }
+                Err(_) => {
+                    tracing::warn!(subsystem = %subsystem.name, "Subsystem did not stop in time, cancelling");
+                    subsystem.handle.abort();
+                    report.cancelled.push(subsystem.name);
+                }
+            }
+        }
+
+        while runs().values().any(|state| *state == RunState::Running) {
+            if Instant::now() >= deadline {
+                break;
+            }
+            tokio::time::sleep(RUN_POLL_INTERVAL).await;
+        }
+        report.interrupted = interrupt_runs().await;
+        report
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RunState {
+    Running,
+    /// Its future is gone. It may have been dropped, e.g. on Ctrl-C, before
+    /// saving how the run ended.
+    Ended,
+    Interrupted,
+}
+
+/// A workflow run of this process, tracked while its future is alive.
+pub(crate) struct InFlight {
+    execution_id: String,
+    cancel: Arc<Notify>,
+}
+
+impl InFlight {
+    /// Whether shutdown gave up on the run and saved it as failed, in which
+    /// case its own result must not be saved over that.
+    pub(crate) fn interrupted(&self) -> bool {
+        runs().get(&self.execution_id) == Some(&RunState::Interrupted)
+    }
+
+    /// Drive `run` unless [`cancel_workflow_execution`] stops it first. Dropping the engine's
+    /// future kills the processes of its tasks.
+    pub(crate) async fn unless_cancelled<T>(
+        &self,
+        run: impl Future<Output = Result<T, EngineError>>,
+    ) -> Result<T, EngineError> {
+        tokio::select! {
+            result = run => result,
+            _ = self.cancel.notified() => Err(EngineError::Cancelled),
+        }
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        cancels().remove(&self.execution_id);
+        let mut runs = runs();
+        match runs.get(&self.execution_id) {
+            Some(RunState::Running) => {
+                runs.insert(self.execution_id.clone(), RunState::Ended);
+            }
+            Some(RunState::Interrupted) => {
+                runs.remove(&self.execution_id);
+            }
+            _ => {}
+        }
+    }
+}
+
+pub(crate) fn track_run(execution_id: &str) -> InFlight {
+    runs().insert(execution_id.to_string(), RunState::Running);
+    let cancel = Arc::new(Notify::new());
+    cancels().insert(execution_id.to_string(), Arc::clone(&cancel));
+    InFlight {
+        execution_id: execution_id.to_string(),
+        cancel,
+    }
+}
+
+/// Stop a run of this process: its task processes are killed and its
+/// execution is saved as cancelled. False if it is not running here, e.g.
+/// because it was started by another process or has already finished.
+pub async fn cancel_workflow_execution(execution_id: &str) -> Result<bool, CoreError> {
+    let cancel = {
+        let mut runs = runs();
+        if runs.get(execution_id) != Some(&RunState::Running) {
+            return Ok(false);
+        }
+        runs.insert(execution_id.to_string(), RunState::Interrupted);
+        cancels().get(execution_id).cloned()
+    };
+    if let Some(cancel) = cancel {
+        cancel.notify_one();
+    }
+    save_interrupted(
+        execution_id,
+        WorkflowExecutionStatus::Cancelled,
+        CANCELLED_BY_USER,
+    )
+    .await?;
+    let store = get_global_store()?;
+    clear_checkpoint(&store, execution_id).await;
+    Ok(true)
+}
+
+/// Kill the task processes of the runs in flight and save each execution
+/// still recorded as running as failed. Returns the ids of those executions.
+pub async fn interrupt_runs() -> Vec<String> {
+    let ids: Vec<String> = {
+        let mut runs = runs();
+        kill_all_process_trees();
+        let ids = runs.keys().cloned().collect();
+        runs.retain(|_, state| *state == RunState::Running);
+        runs.values_mut()
+            .for_each(|state| *state = RunState::Interrupted);
+        ids
+    };
+
+    let mut interrupted = Vec::new();
+    for id in ids {
+        match save_interrupted(
+            &id,
+            WorkflowExecutionStatus::Failed,
+            INTERRUPTED_BY_SHUTDOWN,
+        )
+        .await
+        {
+            Ok(true) => interrupted.push(id),
+            Ok(false) => {}
+            Err(e) => {
+                tracing::error!(execution_id = %id, error = %e, "Failed to save interrupted execution")
+            }
+        }
+    }
+    interrupted.sort();
+    interrupted
+}
+
+async fn save_interrupted(
+    execution_id: &str,
+    status: WorkflowExecutionStatus,
+    reason: &str,
+) -> Result<bool, CoreError> {
+    let store = get_global_store()?;
+    let Some(mut execution) = store
+        .get_workflow_execution(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?
+    else {
+        return Ok(false);
+    };
+    if execution.status != WorkflowExecutionStatus::Running {
+        return Ok(false);
+    }
+
+    let now = chrono::Utc::now();
+    execution.status = status;
+    execution.completed_at = Some(now);
+    execution.errors.push(reason.to_string());
+    for task in &mut execution.tasks {
+        if task.status == TaskExecutionStatus::InProgress {
+            task.status = TaskExecutionStatus::Failed;
+            task.error = Some(reason.to_string());
+            task.completed_at = Some(now);
+        }
+    }
+    tracing::warn!(execution_id, reason, "Saving interrupted execution");
+    store
+        .save_workflow_execution(execution)
+        .await
+        .map_err(CoreError::Persistence)?;
+    Ok(true)
+}
+
+fn runs() -> MutexGuard<'static, HashMap<String, RunState>> {
+    static RUNS: OnceLock<Mutex<HashMap<String, RunState>>> = OnceLock::new();
+    RUNS.get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+fn cancels() -> MutexGuard<'static, HashMap<String, Arc<Notify>>> {
+    static CANCELS: OnceLock<Mutex<HashMap<String, Arc<Notify>>>> = OnceLock::new();
+    CANCELS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
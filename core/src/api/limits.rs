@@ -0,0 +1,33 @@
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::{set_workflow_limits, WorkflowLimits};
+use s_e_e_persistence::WorkflowLimitSettings;
+use tracing::debug;
+
+pub fn workflow_limits_from_settings(settings: &WorkflowLimitSettings) -> WorkflowLimits {
+    WorkflowLimits {
+        max_tasks: settings.max_tasks,
+        max_depth: settings.max_depth,
+        max_fan_out: settings.max_fan_out,
+        max_parameters: settings.max_parameters,
+        max_document_bytes: settings.max_document_kb.saturating_mul(1024),
+    }
+}
+
+/// Use `settings` for every workflow validated or parsed from now on.
+pub fn apply_workflow_limits(settings: &WorkflowLimitSettings) {
+    debug!(?settings, "Applying workflow limits");
+    set_workflow_limits(workflow_limits_from_settings(settings));
+}
+
+/// Apply the limits saved in the settings, or the defaults if nothing is saved yet.
+pub async fn load_workflow_limits() -> Result<(), CoreError> {
+    let store = get_global_store()?;
+    let settings = store
+        .load_settings()
+        .await
+        .map_err(CoreError::Persistence)?
+        .unwrap_or_default();
+    apply_workflow_limits(&settings.workflow_limits);
+    Ok(())
+}
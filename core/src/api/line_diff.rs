@@ -0,0 +1,111 @@
+/// Largest `before × after` line product diffed line by line. Bigger inputs are
+/// shown as a full removal followed by a full addition.
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    Unchanged,
+    Removed,
+    Added,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub text: String,
+}
+
+impl DiffLine {
+    fn new(kind: DiffLineKind, text: &str) -> Self {
+        Self {
+            kind,
+            text: text.to_string(),
+        }
+    }
+}
+
+/// Line diff that turns `before` into `after`, using the longest common
+/// subsequence of lines. Removals come before additions within a changed block.
+pub fn diff_lines(before: &str, after: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = before.lines().collect();
+    let new: Vec<&str> = after.lines().collect();
+
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let mut lines: Vec<DiffLine> = old[..prefix]
+        .iter()
+        .map(|line| DiffLine::new(DiffLineKind::Unchanged, line))
+        .collect();
+
+    if old_mid.len().saturating_mul(new_mid.len()) > MAX_DIFF_CELLS {
+        lines.extend(
+            old_mid
+                .iter()
+                .map(|line| DiffLine::new(DiffLineKind::Removed, line)),
+        );
+        lines.extend(
+            new_mid
+                .iter()
+                .map(|line| DiffLine::new(DiffLineKind::Added, line)),
+        );
+    } else {
+        lines.extend(lcs_diff(old_mid, new_mid));
+    }
+
+    lines.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|line| DiffLine::new(DiffLineKind::Unchanged, line)),
+    );
+    lines
+}
+
+fn lcs_diff(old: &[&str], new: &[&str]) -> Vec<DiffLine> {
+    // lengths[i][j] is the LCS length of old[i..] and new[j..].
+    let width = new.len() + 1;
+    let mut lengths = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if old[i] == new[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::with_capacity(old.len() + new.len());
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(DiffLine::new(DiffLineKind::Unchanged, old[i]));
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            lines.push(DiffLine::new(DiffLineKind::Removed, old[i]));
+            i += 1;
+        } else {
+            lines.push(DiffLine::new(DiffLineKind::Added, new[j]));
+            j += 1;
+        }
+    }
+    lines.extend(
+        old[i..]
+            .iter()
+            .map(|line| DiffLine::new(DiffLineKind::Removed, line)),
+    );
+    lines.extend(
+        new[j..]
+            .iter()
+            .map(|line| DiffLine::new(DiffLineKind::Added, line)),
+    );
+    lines
+}
@@ -0,0 +1,69 @@
+//! Resource locks shared by every execution through the `resource_locks`
+//! table, so tasks naming the same `lock` wait for each other even when they
+//! run in different workflows or processes.
+
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use async_trait::async_trait;
+use s_e_e_engine::ResourceLocks;
+use s_e_e_persistence::{ResourceLock, Store};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+
+/// A ticket whose owner has not reported in for this long is dropped, so a
+/// crashed run does not hold its resource forever.
+pub const LOCK_STALE_AFTER: Duration = Duration::from_secs(30);
+
+pub struct StoreResourceLocks {
+    store: Arc<Store>,
+}
+
+impl StoreResourceLocks {
+    pub fn new(store: Arc<Store>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ResourceLocks for StoreResourceLocks {
+    async fn join(&self, resource: &str, execution_id: &str, task_id: &str) -> Result<i64, String> {
+        self.store
+            .join_resource_queue(resource, execution_id, task_id)
+            .await
+    }
+
+    async fn position(&self, ticket: i64) -> Result<usize, String> {
+        let stale_before =
+            chrono::Utc::now() - chrono::Duration::from_std(LOCK_STALE_AFTER).unwrap_or_default();
+        self.store
+            .resource_queue_position(ticket, stale_before)
+            .await?
+            .ok_or_else(|| format!("Lock ticket {} was released while queued", ticket))
+    }
+
+    async fn leave(&self, ticket: i64) {
+        if let Err(e) = self.store.leave_resource_queue(ticket).await {
+            warn!(ticket = ticket, error = %e, "Failed to release resource lock");
+        }
+    }
+}
+
+/// Every task holding or waiting for a resource, in queue order per resource.
+pub async fn list_resource_locks() -> Result<Vec<ResourceLock>, CoreError> {
+    let store = get_global_store()?;
+    store
+        .list_resource_locks()
+        .await
+        .map_err(CoreError::Persistence)
+}
+
+/// Drop `ticket` from its queue, e.g. one left behind by a run that is stuck.
+/// Returns whether it was queued.
+pub async fn release_resource_lock(ticket: i64) -> Result<bool, CoreError> {
+    let store = get_global_store()?;
+    store
+        .leave_resource_queue(ticket)
+        .await
+        .map_err(CoreError::Persistence)
+}
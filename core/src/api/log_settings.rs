@@ -0,0 +1,15 @@
+use crate::errors::CoreError;
+use crate::logging::apply_log_settings;
+use crate::store_singleton::get_global_store;
+
+/// Apply the log levels saved in the settings; without saved settings
+/// everything logs at `info`. `SEE_LOG` and `RUST_LOG` still win.
+pub async fn load_log_settings() -> Result<(), CoreError> {
+    let store = get_global_store()?;
+    let settings = store
+        .load_settings()
+        .await
+        .map_err(CoreError::Persistence)?
+        .unwrap_or_default();
+    apply_log_settings(&settings.logging).map_err(CoreError::Execution)
+}
@@ -0,0 +1,57 @@
+//! The reproducibility manifest recorded with every execution.
+
+use crate::api::bundle::content_hash;
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::handlers::get_function_type;
+use s_e_e_engine::{probe_tool_versions, referenced_tools, EngineWorkflow, TaskFunction};
+use s_e_e_persistence::ReproducibilityManifest;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Describe the run about to start: this build and platform, the handlers
+/// and tools `workflow` uses, the hash of `content` and the parameters.
+pub(crate) async fn build_manifest(
+    workflow: &EngineWorkflow,
+    content: &str,
+    parameters: &HashMap<String, Value>,
+) -> ReproducibilityManifest {
+    let handlers = workflow
+        .tasks
+        .iter()
+        .map(|task| {
+            let name = match &task.function {
+                TaskFunction::Custom { name, .. } => name.as_str(),
+                _ => get_function_type(task),
+            };
+            (name.to_string(), s_e_e_engine::VERSION.to_string())
+        })
+        .collect();
+
+    ReproducibilityManifest {
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        handlers,
+        workflow_hash: content_hash(content),
+        parameters: parameters
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect(),
+        tools: probe_tool_versions(&referenced_tools(workflow)).await,
+    }
+}
+
+/// The manifest recorded for an execution. `None` for executions started
+/// before manifests were recorded.
+pub async fn get_execution_manifest(
+    execution_id: &str,
+) -> Result<Option<ReproducibilityManifest>, CoreError> {
+    let store = get_global_store()?;
+    let execution = store
+        .get_workflow_execution(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(execution_id.to_string()))?;
+    Ok(execution.manifest)
+}
@@ -0,0 +1,314 @@
+//! Workflows started by messages taken from a queue, for `see listen`.
+
+use crate::api::connections::load_message_connections;
+use crate::api::execution::execute_workflow_by_id_with_params;
+use crate::api::snippets::expanded_engine_workflow;
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::messaging::{publish, subscribe};
+use s_e_e_engine::{IncomingMessage, MessageConnection, TaskStatus, WorkflowTrigger};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{watch, Semaphore};
+
+/// How long a listener waits before subscribing again after losing the broker.
+pub const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// A workflow's `message` trigger with its connection resolved.
+#[derive(Debug, Clone)]
+pub struct MessageTrigger {
+    pub workflow_id: String,
+    pub workflow_name: String,
+    pub connection: MessageConnection,
+    pub source: String,
+    pub params: BTreeMap<String, String>,
+    /// Parameters the workflow declares.
+    pub parameter_names: Vec<String>,
+    pub max_concurrent: usize,
+    pub max_attempts: u32,
+    pub dead_letter: Option<String>,
+}
+
+/// What became of a message once its workflow ran.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageOutcome {
+    /// The workflow succeeded or is waiting on a person.
+    Acked,
+    /// Every attempt failed; the message went to the dead-letter destination.
+    DeadLettered,
+    /// Every attempt failed and there was nowhere to dead-letter it.
+    Rejected,
+}
+
+/// Every `message` trigger among the saved workflows.
+///
+/// Triggers naming a connection that isn't in the settings are returned as
+/// errors next to the usable ones, so one bad workflow doesn't stop the rest.
+pub async fn load_message_triggers() -> Result<(Vec<MessageTrigger>, Vec<String>), CoreError> {
+    let store = get_global_store()?;
+    let workflows = store
+        .list_workflows()
+        .await
+        .map_err(CoreError::Persistence)?;
+    let connections = load_message_connections().await?;
+
+    let mut triggers = Vec::new();
+    let mut problems = Vec::new();
+    for workflow in workflows {
+        let Ok(engine_workflow) = expanded_engine_workflow(&workflow).await else {
+            continue;
+        };
+        for trigger in &engine_workflow.triggers {
+            let WorkflowTrigger::Message {
+                connection,
+                source,
+                params,
+                max_concurrent,
+                max_attempts,
+                dead_letter,
+            } = trigger
+            else {
+                continue;
+            };
+            let Some(connection) = connections.iter().find(|c| &c.name == connection) else {
+                problems.push(format!(
+                    "Workflow '{}' listens on unknown message connection '{}'",
+                    workflow.name, connection
+                ));
+                continue;
+            };
+            triggers.push(MessageTrigger {
+                workflow_id: workflow.id.clone(),
+                workflow_name: workflow.name.clone(),
+                connection: connection.clone(),
+                source: source.clone(),
+                params: params.clone(),
+                parameter_names: engine_workflow
+                    .parameters
+                    .iter()
+                    .map(|p| p.name.clone())
+                    .collect(),
+                max_concurrent: *max_concurrent,
+                max_attempts: *max_attempts,
+                dead_letter: dead_letter.clone(),
+            });
+        }
+    }
+    Ok((triggers, problems))
+}
+
+/// Workflow parameters taken from a message payload.
+///
+/// The payload is read as JSON, or as a plain string when it isn't JSON.
+/// `mapping` takes each parameter from a JSON pointer into the payload; an
+/// empty mapping takes the payload's top-level fields that share a name with
+/// a declared parameter.
+pub fn message_params(
+    payload: &[u8],
+    mapping: &BTreeMap<String, String>,
+    parameter_names: &[String],
+) -> Result<HashMap<String, Value>, String> {
+    let body: Value = serde_json::from_slice(payload)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(payload).into_owned()));
+
+    if mapping.is_empty() {
+        let Value::Object(fields) = body else {
+            return Ok(HashMap::new());
+        };
+        return Ok(fields
+            .into_iter()
+            .filter(|(name, _)| parameter_names.contains(name))
+            .collect());
+    }
+    mapping
+        .iter()
+        .map(|(name, pointer)| {
+            body.pointer(pointer)
+                .map(|value| (name.clone(), value.clone()))
+                .ok_or_else(|| {
+                    format!(
+                        "payload has nothing at '{}' for parameter '{}'",
+                        pointer, name
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Run the trigger's workflow for one message, up to `max_attempts` times,
+/// then settle the message.
+pub async fn handle_message(
+    trigger: &MessageTrigger,
+    message: IncomingMessage,
+) -> Result<MessageOutcome, CoreError> {
+    let params = match message_params(&message.payload, &trigger.params, &trigger.parameter_names) {
+        Ok(params) => params,
+        Err(e) => return give_up(trigger, message, &e).await,
+    };
+
+    let mut last_error = String::new();
+    for attempt in 1..=trigger.max_attempts {
+        match execute_workflow_by_id_with_params(&trigger.workflow_id, params.clone(), None).await {
+            Ok(result)
+                if result.success
+                    || result
+                        .tasks
+                        .iter()
+                        .any(|t| t.status == TaskStatus::WaitingForInput) =>
+            {
+                message.ack().await.map_err(CoreError::Execution)?;
+                return Ok(MessageOutcome::Acked);
+            }
+            Ok(result) if result.errors.is_empty() => {
+                last_error = format!("execution {} failed", result.execution_id);
+            }
+            Ok(result) => last_error = result.errors.join("; "),
+            Err(e) => last_error = e.to_string(),
+        }
+        tracing::warn!(
+            workflow_id = %trigger.workflow_id,
+            attempt,
+            max_attempts = trigger.max_attempts,
+            error = %last_error,
+            "Message-triggered run failed"
+        );
+    }
+    give_up(trigger, message, &last_error).await
+}
+
+async fn give_up(
+    trigger: &MessageTrigger,
+    message: IncomingMessage,
+    error: &str,
+) -> Result<MessageOutcome, CoreError> {
+    if let Some(dead_letter) = &trigger.dead_letter {
+        let headers = BTreeMap::from([
+            ("x-see-workflow".to_string(), trigger.workflow_id.clone()),
+            ("x-see-error".to_string(), error.to_string()),
+        ]);
+        match publish(
+            &trigger.connection,
+            dead_letter,
+            None,
+            &headers,
+            message.payload.clone(),
+        )
+        .await
+        {
+            Ok(_) => {
+                message.ack().await.map_err(CoreError::Execution)?;
+                return Ok(MessageOutcome::DeadLettered);
+            }
+            Err(e) => tracing::error!(
+                workflow_id = %trigger.workflow_id,
+                dead_letter = %dead_letter,
+                error = %e,
+                "Could not dead-letter message; rejecting it"
+            ),
+        }
+    }
+    message.reject().await.map_err(CoreError::Execution)?;
+    Ok(MessageOutcome::Rejected)
+}
+
+/// Consume messages for every trigger until `shutdown` turns true, then wait
+/// for the runs already started to finish.
+///
+/// Each trigger gets its own consumer group, `see-<workflow id>`, and runs at
+/// most `max_concurrent` of its workflow at once. A lost subscription is
+/// retried after [`RECONNECT_DELAY`].
+pub async fn run_message_triggers(triggers: Vec<MessageTrigger>, shutdown: watch::Receiver<bool>) {
+    let listeners: Vec<_> = triggers
+        .into_iter()
+        .map(|trigger| tokio::spawn(listen(Arc::new(trigger), shutdown.clone())))
+        .collect();
+    for listener in listeners {
+        let _ = listener.await;
+    }
+}
+
+async fn listen(trigger: Arc<MessageTrigger>, mut shutdown: watch::Receiver<bool>) {
+    let slots = Arc::new(Semaphore::new(trigger.max_concurrent));
+    let group = format!("see-{}", trigger.workflow_id);
+
+    'subscribe: while !*shutdown.borrow() {
+        match subscribe(
+            &trigger.connection,
+            &trigger.source,
+            &group,
+            trigger.max_concurrent,
+        )
+        .await
+        {
+            Ok(mut source) => {
+                tracing::info!(
+                    workflow_id = %trigger.workflow_id,
+                    source = %trigger.source,
+                    "Listening for messages"
+                );
+                loop {
+                    let slot = tokio::select! {
+                        slot = Arc::clone(&slots).acquire_owned() => {
+                            slot.expect("listener semaphore is never closed")
+                        }
+                        _ = shutdown.changed() => break 'subscribe,
+                    };
+                    let message = tokio::select! {
+                        message = source.next_message() => message,
+                        _ = shutdown.changed() => break 'subscribe,
+                    };
+                    match message {
+                        Ok(Some(message)) => {
+                            let trigger = Arc::clone(&trigger);
+                            tokio::spawn(async move {
+                                let _slot = slot;
+                                match handle_message(&trigger, message).await {
+                                    Ok(outcome) => tracing::info!(
+                                        workflow_id = %trigger.workflow_id,
+                                        ?outcome,
+                                        "Handled message"
+                                    ),
+                                    Err(e) => tracing::error!(
+                                        workflow_id = %trigger.workflow_id,
+                                        error = %e,
+                                        "Could not settle message"
+                                    ),
+                                }
+                            });
+                        }
+                        Ok(None) => {
+                            tracing::warn!(
+                                workflow_id = %trigger.workflow_id,
+                                "Subscription ended"
+                            );
+                            break;
+                        }
+                        Err(e) => {
+                            tracing::error!(
+                                workflow_id = %trigger.workflow_id,
+                                error = %e,
+                                "Receiving messages failed"
+                            );
+                            break;
+                        }
+                    }
+                }
+            }
+            Err(e) => tracing::error!(
+                workflow_id = %trigger.workflow_id,
+                source = %trigger.source,
+                error = %e,
+                "Could not subscribe"
+            ),
+        }
+        tokio::select! {
+            _ = tokio::time::sleep(RECONNECT_DELAY) => {}
+            _ = shutdown.changed() => break,
+        }
+    }
+
+    // Holding every slot means no run is still in flight.
+    let _ = slots.acquire_many(trigger.max_concurrent as u32).await;
+}
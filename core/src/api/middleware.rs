@@ -0,0 +1,34 @@
+//! Task middlewares applied to every engine this crate builds, so embedders
+//! can add cross-cutting behaviour without building engines themselves.
+
+use s_e_e_engine::{TaskMiddleware, WorkflowEngine};
+use std::sync::{Arc, RwLock};
+
+static TASK_MIDDLEWARES: RwLock<Vec<Arc<dyn TaskMiddleware>>> = RwLock::new(Vec::new());
+
+/// Wrap every task of every later run, in the CLI, GUI and sandbox alike.
+/// Middlewares registered first run outermost.
+pub fn register_task_middleware(middleware: Arc<dyn TaskMiddleware>) {
+    TASK_MIDDLEWARES
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(middleware);
+}
+
+/// Remove every registered middleware.
+pub fn clear_task_middlewares() {
+    TASK_MIDDLEWARES
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .clear();
+}
+
+pub(crate) fn with_registered_middlewares(engine: WorkflowEngine) -> WorkflowEngine {
+    TASK_MIDDLEWARES
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .fold(engine, |engine, middleware| {
+            engine.with_middleware(middleware.clone())
+        })
+}
@@ -1,13 +1,149 @@
+pub mod archive;
+pub mod bundle;
+pub mod checkpoints;
+pub mod comparison;
+pub mod connections;
+pub mod context_history;
+pub mod data_export;
 pub mod defaults;
+pub mod duplicate;
+pub mod examples;
 pub mod execution;
+pub mod flakiness;
+pub mod handler_policy;
 pub mod init;
 pub mod initial_data;
 pub mod input;
+pub mod labels;
+pub mod layout;
+pub mod lifecycle;
+pub mod limits;
+pub mod line_diff;
+pub mod locks;
+pub mod log_settings;
+pub mod manifest;
+pub mod message_triggers;
+pub mod middleware;
+pub mod notifications;
+pub mod output_journal;
+pub mod parameter_form;
+pub mod prompt_render;
+pub mod prompts;
+pub mod readme;
+pub mod rerun;
 pub mod resume;
+pub mod run_bounds;
+pub mod sandbox;
+pub mod schema_docs;
+pub mod secrets;
+pub mod snapshot;
+pub mod snippets;
+pub mod storage;
+pub mod task_cache;
+pub mod trash;
+pub mod triggers;
+pub mod workflow_tests;
 
+pub use archive::{
+    archive_execution, archive_executions_older_than, archive_per_settings, default_archive_dir,
+    list_archived_executions, restore_archived_execution,
+};
+pub use bundle::{
+    content_hash, export_workflow_bundle, import_workflow_bundle, is_workflow_bundle,
+    trust_workflow, workflow_trust_prompt, BundleSignature, TrustPrompt, TrustReason,
+    WorkflowBundle, BUNDLE_FORMAT_VERSION,
+};
+pub use checkpoints::StoreCheckpoints;
+pub use comparison::{
+    compare_executions, compare_with_previous_run, ExecutionComparison, TaskComparison,
+};
+pub use connections::{
+    db_connection_from_settings, load_db_connections, load_message_connections,
+    message_connection_from_settings,
+};
+pub use context_history::{context_at, context_steps, get_context_at, TaskContext};
+pub use data_export::{
+    execution_columns, execution_records, export_execution_data, task_columns, ExecutionDataExport,
+    ExecutionRecord, ExportFormat, TaskRecord, LABEL_SEPARATOR,
+};
 pub use defaults::get_default_workflows;
-pub use execution::{delete_workflow_execution, execute_workflow_by_id};
+pub use duplicate::{copy_name, duplicate_prompt, duplicate_workflow};
+pub use examples::{
+    add_all_example_workflows, add_example_workflow, complete_onboarding, get_example_workflow,
+    list_example_workflows, preview_default_reset, refresh_examples, reset_default_workflow,
+    DefaultResetPreview, ExampleRefresh, ExampleWorkflow,
+};
+pub use execution::{
+    delete_workflow_execution, execute_workflow_by_id, execute_workflow_by_id_profiled,
+    execute_workflow_by_id_with_bounds, execute_workflow_by_id_with_labels,
+    execute_workflow_by_id_with_params,
+};
+pub use flakiness::{
+    detect_flaky_tasks, execution_flaky_tasks, flaky_tasks, FlakyTask, FLAKINESS_WINDOW,
+};
+pub use handler_policy::{apply_handler_policy, handler_policy_from_settings, load_handler_policy};
 pub use init::{init_global_store, init_tracing};
 pub use initial_data::populate_initial_data;
-pub use input::{get_pending_inputs, get_tasks_waiting_for_input, provide_user_input};
+pub use input::{
+    confirm_manual_task, get_pending_inputs, get_tasks_waiting_for_input, local_user_name,
+    provide_user_input,
+};
+pub use labels::{
+    delete_execution_filter_preset, list_execution_filter_presets, list_execution_labels,
+    save_execution_filter_preset, set_execution_labels,
+};
+pub use layout::{
+    export_workflow_graph, layout_workflow_content, GraphEdge, GraphNode, WorkflowGraph,
+};
+pub use lifecycle::{
+    cancel_workflow_execution, interrupt_runs, Lifecycle, ShutdownReport, CANCELLED_BY_USER,
+    DEFAULT_SHUTDOWN_TIMEOUT, INTERRUPTED_BY_SHUTDOWN,
+};
+pub use limits::{apply_workflow_limits, load_workflow_limits, workflow_limits_from_settings};
+pub use line_diff::{diff_lines, DiffLine, DiffLineKind};
+pub use locks::{list_resource_locks, release_resource_lock, StoreResourceLocks, LOCK_STALE_AFTER};
+pub use log_settings::load_log_settings;
+pub use manifest::get_execution_manifest;
+pub use message_triggers::{
+    handle_message, load_message_triggers, message_params, run_message_triggers, MessageOutcome,
+    MessageTrigger, RECONNECT_DELAY,
+};
+pub use middleware::{clear_task_middlewares, register_task_middleware};
+pub use notifications::{
+    list_notifications, mark_all_notifications_read, mark_notification_read, record_notification,
+    unread_notification_count, MAX_NOTIFICATIONS,
+};
+pub use output_journal::get_journaled_output;
+pub use parameter_form::{
+    parameter_input_text, parse_parameter_form, workflow_parameters, ParameterField,
+};
+pub use prompt_render::{
+    included_prompt_ids, render_prompt_content, PromptIncludeError, MAX_INCLUDE_DEPTH,
+};
+pub use prompts::{list_prompt_tags, list_prompts_filtered, preview_prompt, render_prompt};
+pub use readme::{generate_workflow_readme, render_workflow_readme};
+pub use rerun::{rerun_task, task_failed};
 pub use resume::resume_workflow_execution;
+pub use sandbox::{dry_run_workflow, run_sandboxed};
+pub use schema_docs::{
+    schema_docs, FieldDoc, ModelDoc, SchemaDocs, SchemaDocsFormat, TypeDoc, VariantDoc,
+};
+pub use snapshot::{execution_snapshot_status, save_snapshot_as_workflow, SnapshotStatus};
+pub use snippets::{
+    delete_snippet, expand_snippets, expand_workflow_snippets, insert_snippet_reference,
+    list_snippets, save_snippet, uses_snippets, SnippetError,
+};
+pub use storage::{
+    last_storage_maintenance, maintain_storage_if_idle, run_storage_maintenance, storage_stats,
+    vacuum_storage, STORAGE_MAINTENANCE_INTERVAL,
+};
+pub use task_cache::{clear_directory_cache, clear_task_result_cache, StoreTaskResultCache};
+pub use trash::{
+    delete_prompts, delete_workflows, purge_expired_trash, undo_deletion, DeletionBatch,
+    UNDO_GRACE_PERIOD,
+};
+pub use triggers::execution_outputs;
+pub use workflow_tests::{
+    create_test_from_execution, delete_workflow_test, list_workflow_tests, run_workflow_test,
+    run_workflow_tests, ContextMismatch, WorkflowTestReport,
+};
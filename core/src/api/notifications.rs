@@ -0,0 +1,189 @@
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::{AuditStatus, NotifyOn, NotifyRule, WorkflowResult as EngineWorkflowResult};
+use s_e_e_persistence::{Notification, NotificationKind};
+use std::time::Duration;
+
+/// Older notifications are deleted once there are more than this many.
+pub const MAX_NOTIFICATIONS: usize = 200;
+
+/// Store `notification` unless its kind is muted. Returns whether it was stored.
+pub async fn record_notification(notification: Notification) -> Result<bool, CoreError> {
+    let store = get_global_store()?;
+    let settings = store
+        .load_settings()
+        .await
+        .map_err(CoreError::Persistence)?
+        .unwrap_or_default();
+    if !settings.notifies(notification.kind) {
+        return Ok(false);
+    }
+
+    store
+        .save_notification(&notification)
+        .await
+        .map_err(CoreError::Persistence)?;
+    store
+        .prune_notifications(MAX_NOTIFICATIONS)
+        .await
+        .map_err(CoreError::Persistence)?;
+    Ok(true)
+}
+
+/// Record a notification about an execution. A failure to record it is
+/// logged rather than returned, so it never fails the run itself.
+pub(crate) async fn notify_execution(
+    kind: NotificationKind,
+    workflow_name: &str,
+    execution_id: &str,
+) {
+    let (title, message) = match kind {
+        NotificationKind::ExecutionCompleted => (
+            format!("{} completed", workflow_name),
+            "The workflow finished successfully.".to_string(),
+        ),
+        NotificationKind::ExecutionFailed => (
+            format!("{} failed", workflow_name),
+            "One or more tasks failed.".to_string(),
+        ),
+        NotificationKind::TriggeredRunFailed => (
+            format!("{} failed", workflow_name),
+            "A run started by another workflow's trigger failed.".to_string(),
+        ),
+        NotificationKind::InputRequested => (
+            format!("{} needs input", workflow_name),
+            "The workflow is paused until you answer its prompt.".to_string(),
+        ),
+        NotificationKind::TaskAlert => (
+            format!("{} has task alerts", workflow_name),
+            "A task matched one of the workflow's notify rules.".to_string(),
+        ),
+    };
+    let notification = Notification {
+        execution_id: Some(execution_id.to_string()),
+        ..Notification::new(kind, title, message)
+    };
+
+    if let Err(e) = record_notification(notification).await {
+        tracing::warn!(execution_id = %execution_id, error = %e, "Failed to record notification");
+    }
+}
+
+/// Record a notification for each task that ran in `result` and fired one of
+/// the workflow's `notify` rules: one for how it ended, and one for running
+/// long. Like [`notify_execution`], failures are logged, not returned.
+pub(crate) async fn notify_tasks(
+    rules: &[NotifyRule],
+    result: &EngineWorkflowResult,
+    execution_id: &str,
+) {
+    let mut notifications = Vec::new();
+    for entry in &result.audit_trail {
+        let covering: Vec<&NotifyRule> = rules
+            .iter()
+            .filter(|rule| rule.covers(&entry.task_id))
+            .collect();
+        if covering.is_empty() {
+            continue;
+        }
+        let success = entry.status == AuditStatus::Success;
+        let elapsed = Duration::from_millis(
+            result
+                .durations_ms
+                .get(&entry.task_id)
+                .copied()
+                .unwrap_or_default(),
+        );
+        let task_name = result
+            .tasks
+            .iter()
+            .find(|task| task.id == entry.task_id)
+            .map_or(entry.task_id.as_str(), |task| task.name.as_str());
+
+        let ended = covering.iter().any(|rule| {
+            matches!(rule.on, NotifyOn::Failure | NotifyOn::Success) && rule.fires(success, elapsed)
+        });
+        if ended {
+            let (title, message) = if success {
+                (
+                    format!("{} succeeded", task_name),
+                    format!(
+                        "Task {} of {} finished successfully.",
+                        entry.task_id, result.workflow_name
+                    ),
+                )
+            } else {
+                (
+                    format!("{} failed", task_name),
+                    format!("Task {} of {} failed.", entry.task_id, result.workflow_name),
+                )
+            };
+            notifications.push((title, message));
+        }
+
+        let limit = covering
+            .iter()
+            .filter(|rule| rule.fires(success, elapsed))
+            .filter_map(|rule| match rule.on {
+                NotifyOn::Slow {
+                    longer_than_seconds,
+                } => Some(longer_than_seconds),
+                _ => None,
+            })
+            .min();
+        if let Some(limit) = limit {
+            notifications.push((
+                format!("{} ran long", task_name),
+                format!(
+                    "Task {} of {} took {}s, more than the {}s its notify rule allows.",
+                    entry.task_id,
+                    result.workflow_name,
+                    elapsed.as_secs(),
+                    limit
+                ),
+            ));
+        }
+    }
+
+    for (title, message) in notifications {
+        let notification = Notification {
+            execution_id: Some(execution_id.to_string()),
+            ..Notification::new(NotificationKind::TaskAlert, title, message)
+        };
+        if let Err(e) = record_notification(notification).await {
+            tracing::warn!(execution_id = %execution_id, error = %e, "Failed to record task notification");
+        }
+    }
+}
+
+pub async fn list_notifications(limit: usize) -> Result<Vec<Notification>, CoreError> {
+    let store = get_global_store()?;
+    store
+        .list_notifications(limit)
+        .await
+        .map_err(CoreError::Persistence)
+}
+
+pub async fn unread_notification_count() -> Result<usize, CoreError> {
+    let store = get_global_store()?;
+    store
+        .count_unread_notifications()
+        .await
+        .map_err(CoreError::Persistence)
+}
+
+pub async fn mark_notification_read(id: &str) -> Result<(), CoreError> {
+    let store = get_global_store()?;
+    store
+        .mark_notifications_read(Some(id))
+        .await
+        .map_err(CoreError::Persistence)
+}
+
+pub async fn mark_all_notifications_read() -> Result<(), CoreError> {
+    let store = get_global_store()?;
+    store
+        .mark_notifications_read(None)
+        .await
+        .map_err(CoreError::Persistence)
+}
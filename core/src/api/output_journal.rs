@@ -0,0 +1,117 @@
+//! Saving a run's output line by line as it is written, rather than only with
+//! each task's logs when the run ends, so the output of the tasks that were
+//! running survives a crash and can still be read afterwards.
+
+use crate::bridge::OutputCallback;
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::OutputEvent;
+use s_e_e_persistence::{OutputChunk, Store};
+use std::sync::Arc;
+use tokio::sync::{mpsc, oneshot};
+use tracing::warn;
+
+enum JournalMessage {
+    Chunk(OutputChunk),
+    Close(oneshot::Sender<()>),
+}
+
+/// Writes one run's output to the `output_journal` table from a background
+/// task, in batches of whatever has arrived since the last write.
+pub(crate) struct OutputJournal {
+    execution_id: String,
+    sender: mpsc::UnboundedSender<JournalMessage>,
+}
+
+impl OutputJournal {
+    pub(crate) fn start(store: Arc<Store>, execution_id: &str) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(write_chunks(store, receiver));
+        Self {
+            execution_id: execution_id.to_string(),
+            sender,
+        }
+    }
+
+    /// A sink that journals each event, then hands it on to `forward`.
+    pub(crate) fn sink(&self, forward: Option<OutputCallback>) -> OutputCallback {
+        let sender = self.sender.clone();
+        Arc::new(move |event: OutputEvent| {
+            let chunk = OutputChunk {
+                execution_id: event.execution_id.clone(),
+                sequence: event.sequence,
+                task_id: event.task_id.clone(),
+                task_name: event.task_name.clone(),
+                line: event.line.clone(),
+                finished: event.finished,
+            };
+            // Closed once the run's logs are saved; later lines need no journal.
+            let _ = sender.send(JournalMessage::Chunk(chunk));
+            if let Some(forward) = &forward {
+                forward(event);
+            }
+        })
+    }
+
+    /// Write what is still queued, stop journaling and drop the journal. For
+    /// when the run's task logs have been saved with its execution.
+    pub(crate) async fn discard(self, store: &Store) {
+        let (done, written) = oneshot::channel();
+        if self.sender.send(JournalMessage::Close(done)).is_ok() {
+            let _ = written.await;
+        }
+        if let Err(e) = store.delete_output_journal(&self.execution_id).await {
+            warn!(execution_id = %self.execution_id, error = %e, "Failed to delete output journal");
+        }
+    }
+}
+
+async fn write_chunks(store: Arc<Store>, mut receiver: mpsc::UnboundedReceiver<JournalMessage>) {
+    while let Some(message) = receiver.recv().await {
+        let mut chunks = Vec::new();
+        let mut close = None;
+        let mut next = Some(message);
+        while let Some(message) = next.take() {
+            match message {
+                JournalMessage::Chunk(chunk) => chunks.push(chunk),
+                JournalMessage::Close(done) => {
+                    close = Some(done);
+                    break;
+                }
+            }
+            next = receiver.try_recv().ok();
+        }
+        if !chunks.is_empty() {
+            if let Err(e) = store.append_output_chunks(&chunks).await {
+                warn!(error = %e, lines = chunks.len(), "Failed to journal task output");
+            }
+        }
+        if let Some(done) = close {
+            let _ = done.send(());
+            return;
+        }
+    }
+}
+
+/// The output saved for an execution whose run did not get to save its task
+/// logs, e.g. because the process died mid-task, oldest line first. Empty for
+/// runs that finished normally.
+pub async fn get_journaled_output(execution_id: &str) -> Result<Vec<OutputEvent>, CoreError> {
+    let store = get_global_store()?;
+    let chunks = store
+        .get_output_journal(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?;
+    Ok(chunks
+        .into_iter()
+        .map(|chunk| OutputEvent {
+            execution_id: chunk.execution_id,
+            sequence: chunk.sequence,
+            task_id: chunk.task_id,
+            task_name: chunk.task_name,
+            line: chunk.line,
+            progress: None,
+            finished: chunk.finished,
+        })
+        .collect())
+}
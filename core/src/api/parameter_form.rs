@@ -0,0 +1,105 @@
+//! Typed run forms for parameterized workflows: one field per parameter,
+//! checked field by field so every mistake can be shown next to its input.
+
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::parameters::parse_parameter_value;
+use s_e_e_engine::{ParameterType, WorkflowParameter};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// The input a parameter is entered with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParameterField {
+    Text,
+    Number,
+    Checkbox,
+    Select,
+    File,
+    Directory,
+    /// A path pattern, typed or started from a picked directory.
+    Glob,
+    /// Hidden as it is typed, for a `secret` parameter.
+    Secret,
+}
+
+impl ParameterField {
+    pub fn for_parameter(parameter: &WorkflowParameter) -> Self {
+        if !parameter.options.is_empty() {
+            return ParameterField::Select;
+        }
+        match parameter.param_type {
+            ParameterType::Boolean => ParameterField::Checkbox,
+            _ if parameter.secret => ParameterField::Secret,
+            ParameterType::String => ParameterField::Text,
+            ParameterType::Number => ParameterField::Number,
+            ParameterType::File => ParameterField::File,
+            ParameterType::Directory => ParameterField::Directory,
+            ParameterType::Glob => ParameterField::Glob,
+        }
+    }
+}
+
+/// How `value` is written in a form field: strings as they are, anything
+/// else as JSON.
+pub fn parameter_input_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// The parameters a saved workflow asks for, in the order it declares them.
+pub async fn workflow_parameters(workflow_id: &str) -> Result<Vec<WorkflowParameter>, CoreError> {
+    let store = get_global_store()?;
+    let workflow = store
+        .get_workflow(workflow_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(workflow_id.to_string()))?;
+
+    let json: Value = serde_json::from_str(&workflow.content)
+        .map_err(|e| CoreError::InputValidationFailed(e.to_string()))?;
+    match json.get("parameters") {
+        Some(parameters) => serde_json::from_value(parameters.clone())
+            .map_err(|e| CoreError::InputValidationFailed(format!("Invalid parameters: {}", e))),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Turn the text of each form field into typed parameter values.
+///
+/// A blank field leaves the parameter out so its default applies. On failure
+/// the error maps each parameter name to a message for its field.
+pub fn parse_parameter_form(
+    parameters: &[WorkflowParameter],
+    inputs: &HashMap<String, String>,
+) -> Result<HashMap<String, Value>, BTreeMap<String, String>> {
+    let mut values = HashMap::new();
+    let mut errors = BTreeMap::new();
+    for parameter in parameters {
+        let raw = inputs.get(&parameter.name).map(String::as_str);
+        match raw.filter(|raw| !raw.trim().is_empty()) {
+            Some(raw) => match parse_parameter_value(parameter, raw) {
+                Ok(value) => {
+                    values.insert(parameter.name.clone(), value);
+                }
+                Err(e) => {
+                    errors.insert(parameter.name.clone(), e.to_string());
+                }
+            },
+            None if parameter.required && parameter.default.is_none() => {
+                errors.insert(parameter.name.clone(), "A value is required".to_string());
+            }
+            None => {}
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(errors)
+    }
+}
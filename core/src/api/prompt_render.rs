@@ -0,0 +1,100 @@
+/// How many includes may nest inside one another before rendering gives up.
+pub const MAX_INCLUDE_DEPTH: usize = 8;
+
+const INCLUDE_PREFIX: &str = "include:";
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PromptIncludeError {
+    #[error("included prompt '{0}' does not exist")]
+    NotFound(String),
+
+    #[error("prompt includes form a cycle: {}", .0.join(" -> "))]
+    Cycle(Vec<String>),
+
+    #[error("includes are nested more than {} deep: {}", MAX_INCLUDE_DEPTH, .0.join(" -> "))]
+    TooDeep(Vec<String>),
+}
+
+/// Replace every `{{include:prompt-id}}` in `content` with that prompt's rendered
+/// content. `lookup` returns the raw content of a prompt by id.
+///
+/// `root_id` names the prompt being rendered, if it is saved, so a prompt that
+/// includes itself is reported as a cycle. Other `{{...}}` text is left alone.
+pub fn render_prompt_content<F>(
+    content: &str,
+    root_id: Option<&str>,
+    lookup: F,
+) -> Result<String, PromptIncludeError>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut stack: Vec<String> = root_id.map(str::to_string).into_iter().collect();
+    render(content, &mut stack, 0, &lookup)
+}
+
+/// Ids of the prompts `content` includes directly, in order of appearance.
+pub fn included_prompt_ids(content: &str) -> Vec<String> {
+    let mut ids = Vec::new();
+    let mut rest = content;
+    while let Some((_, id, after)) = next_include(rest) {
+        ids.push(id.to_string());
+        rest = after;
+    }
+    ids
+}
+
+fn render<F>(
+    content: &str,
+    stack: &mut Vec<String>,
+    depth: usize,
+    lookup: &F,
+) -> Result<String, PromptIncludeError>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut output = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some((before_len, id, after)) = next_include(rest) {
+        output.push_str(&rest[..before_len]);
+
+        if stack.iter().any(|seen| seen == id) {
+            let mut chain = stack.clone();
+            chain.push(id.to_string());
+            return Err(PromptIncludeError::Cycle(chain));
+        }
+        if depth == MAX_INCLUDE_DEPTH {
+            let mut chain = stack.clone();
+            chain.push(id.to_string());
+            return Err(PromptIncludeError::TooDeep(chain));
+        }
+
+        let included = lookup(id).ok_or_else(|| PromptIncludeError::NotFound(id.to_string()))?;
+        stack.push(id.to_string());
+        output.push_str(&render(&included, stack, depth + 1, lookup)?);
+        stack.pop();
+
+        rest = after;
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
+
+/// Finds the next include directive: the length of the text before it, the
+/// trimmed prompt id, and the text after it.
+fn next_include(text: &str) -> Option<(usize, &str, &str)> {
+    let mut offset = 0;
+    loop {
+        let start = offset + text[offset..].find("{{")?;
+        let end = start + 2 + text[start + 2..].find("}}")?;
+        let inner = text[start + 2..end].trim();
+        if let Some(id) = inner.strip_prefix(INCLUDE_PREFIX) {
+            let id = id.trim();
+            if !id.is_empty() {
+                return Some((start, id, &text[end + 2..]));
+            }
+        }
+        offset = start + 2;
+    }
+}
@@ -0,0 +1,51 @@
+use crate::api::prompt_render::render_prompt_content;
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_persistence::{PromptFilter, PromptWithUsage};
+use std::collections::HashMap;
+
+/// Prompts matching `filter`, with usage counted from task runs that reference
+/// them through `input.prompt_id`.
+pub async fn list_prompts_filtered(
+    filter: &PromptFilter,
+) -> Result<Vec<PromptWithUsage>, CoreError> {
+    get_global_store()?
+        .list_prompts_filtered(filter)
+        .await
+        .map_err(CoreError::Persistence)
+}
+
+pub async fn list_prompt_tags() -> Result<Vec<String>, CoreError> {
+    get_global_store()?
+        .list_prompt_tags()
+        .await
+        .map_err(CoreError::Persistence)
+}
+
+async fn prompt_contents() -> Result<HashMap<String, String>, CoreError> {
+    let prompts = get_global_store()?
+        .list_prompts()
+        .await
+        .map_err(CoreError::Persistence)?;
+    Ok(prompts.into_iter().map(|p| (p.id, p.content)).collect())
+}
+
+/// A saved prompt with its includes expanded.
+pub async fn render_prompt(id: &str) -> Result<String, CoreError> {
+    let contents = prompt_contents().await?;
+    let content = contents
+        .get(id)
+        .ok_or_else(|| CoreError::Execution(format!("Prompt not found: {}", id)))?;
+    Ok(render_prompt_content(content, Some(id), |include| {
+        contents.get(include).cloned()
+    })?)
+}
+
+/// Expand the includes in unsaved editor content against the saved prompts.
+/// `id` is the prompt being edited, if any, so including itself is caught.
+pub async fn preview_prompt(id: Option<&str>, content: &str) -> Result<String, CoreError> {
+    let contents = prompt_contents().await?;
+    Ok(render_prompt_content(content, id, |include| {
+        contents.get(include).cloned()
+    })?)
+}
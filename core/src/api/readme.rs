@@ -0,0 +1,266 @@
+//! Markdown documentation generated from a workflow definition, meant to be
+//! committed next to the definition file.
+
+use crate::api::snippets::expand_workflow_snippets;
+use crate::bridge::workflow::workflow_definition_to_engine;
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::{EngineTask, TaskFunction};
+use s_e_e_persistence::WorkflowDefinition;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// README for a saved workflow. Snippet references are expanded so the task
+/// list shows what actually runs.
+pub async fn generate_workflow_readme(workflow_id: &str) -> Result<String, CoreError> {
+    let store = get_global_store()?;
+    let workflow = store
+        .get_workflow(workflow_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(workflow_id.to_string()))?;
+
+    let content = expand_workflow_snippets(&workflow.content).await?;
+    render_workflow_readme(&WorkflowDefinition {
+        content,
+        ..workflow
+    })
+}
+
+/// README for a workflow definition: its description, parameters, tasks with
+/// their descriptions, what each task leaves in the context, and the task
+/// graph as a Mermaid flowchart.
+pub fn render_workflow_readme(workflow: &WorkflowDefinition) -> Result<String, CoreError> {
+    let engine_workflow = workflow_definition_to_engine(workflow)?;
+    let json: Value = serde_json::from_str(&workflow.content)
+        .map_err(|e| CoreError::InputValidationFailed(e.to_string()))?;
+    let mut descriptions = HashMap::new();
+    collect_descriptions(&json["tasks"], &mut descriptions);
+
+    // The parser lists every task at the top level too; walk from the roots.
+    let roots: Vec<EngineTask> = engine_workflow
+        .tasks
+        .iter()
+        .filter(|task| task.is_root)
+        .cloned()
+        .collect();
+    let mut tasks = Vec::new();
+    flatten(&roots, None, &mut tasks);
+
+    let mut out = String::new();
+    let _ = writeln!(out, "# {}\n", engine_workflow.name);
+    let description = workflow
+        .description
+        .as_deref()
+        .or_else(|| json["description"].as_str())
+        .filter(|d| !d.trim().is_empty());
+    if let Some(description) = description {
+        let _ = writeln!(out, "{}\n", description.trim());
+    }
+    let _ = writeln!(out, "Workflow ID: `{}`\n", engine_workflow.id);
+
+    if !engine_workflow.parameters.is_empty() {
+        out.push_str("## Parameters\n\n");
+        out.push_str("| Name | Type | Required | Default | Description |\n");
+        out.push_str("| --- | --- | --- | --- | --- |\n");
+        for parameter in &engine_workflow.parameters {
+            let _ = writeln!(
+                out,
+                "| `{}` | {} | {} | {} | {} |",
+                parameter.name,
+                parameter.param_type.as_str(),
+                if parameter.required { "yes" } else { "no" },
+                parameter
+                    .default
+                    .as_ref()
+                    .map(|d| format!("`{}`", table_cell(&d.to_string())))
+                    .unwrap_or_default(),
+                parameter
+                    .description
+                    .as_deref()
+                    .map(table_cell)
+                    .unwrap_or_default(),
+            );
+        }
+        out.push('\n');
+    }
+
+    out.push_str("## Tasks\n\n");
+    if tasks.is_empty() {
+        out.push_str("This workflow has no tasks.\n\n");
+    }
+    for (task, parent) in &tasks {
+        let _ = writeln!(out, "### {}\n", task.name);
+        let mut facts = vec![format!("`{}`", task.id), function_name(&task.function)];
+        if let Some(stage) = &task.stage {
+            facts.push(format!("stage `{}`", stage));
+        }
+        if let Some(lock) = &task.lock {
+            facts.push(format!("lock `{}`", lock));
+        }
+        if let Some(parent) = parent {
+            facts.push(format!("after `{}`", parent));
+        }
+        let _ = writeln!(out, "{}\n", facts.join(" · "));
+        if let Some(description) = descriptions.get(task.id.as_str()) {
+            let _ = writeln!(out, "{}\n", description.trim());
+        }
+        let _ = writeln!(out, "{}\n", function_summary(&task.function));
+    }
+
+    if !tasks.is_empty() {
+        out.push_str("## Outputs\n\n");
+        out.push_str("Each task's output is available to later tasks under `data.<task id>`.\n\n");
+        out.push_str("| Task | Reference | Output |\n");
+        out.push_str("| --- | --- | --- |\n");
+        for (task, _) in &tasks {
+            let _ = writeln!(
+                out,
+                "| {} | `data.{}` | {} |",
+                table_cell(&task.name),
+                task.id,
+                function_output(&task.function)
+            );
+        }
+        out.push('\n');
+
+        out.push_str("## Graph\n\n```mermaid\nflowchart TD\n");
+        let nodes: HashMap<&str, usize> = tasks
+            .iter()
+            .enumerate()
+            .map(|(index, (task, _))| (task.id.as_str(), index))
+            .collect();
+        for (index, (task, _)) in tasks.iter().enumerate() {
+            let _ = writeln!(out, "    t{}[\"{}\"]", index, mermaid_label(&task.name));
+        }
+        for (index, (_, parent)) in tasks.iter().enumerate() {
+            if let Some(parent) = parent.and_then(|p| nodes.get(p)) {
+                let _ = writeln!(out, "    t{} --> t{}", parent, index);
+            }
+        }
+        out.push_str("```\n");
+    }
+
+    Ok(out.trim_end().to_string() + "\n")
+}
+
+/// Tasks in document order, each with the id of the task it runs after.
+fn flatten<'a>(
+    tasks: &'a [EngineTask],
+    parent: Option<&'a str>,
+    out: &mut Vec<(&'a EngineTask, Option<&'a str>)>,
+) {
+    for task in tasks {
+        out.push((task, parent));
+        flatten(&task.next_tasks, Some(&task.id), out);
+    }
+}
+
+/// Task `description` fields by task id. The engine doesn't keep them, so
+/// they are read from the JSON.
+fn collect_descriptions<'a>(tasks: &'a Value, out: &mut HashMap<&'a str, &'a str>) {
+    for task in tasks.as_array().map(Vec::as_slice).unwrap_or_default() {
+        if let (Some(id), Some(description)) = (task["id"].as_str(), task["description"].as_str()) {
+            out.insert(id, description);
+        }
+        collect_descriptions(&task["next_tasks"], out);
+    }
+}
+
+fn function_name(function: &TaskFunction) -> String {
+    let name = match function {
+        TaskFunction::CliCommand { .. } => "cli_command",
+        TaskFunction::CursorAgent { .. } => "cursor_agent",
+        TaskFunction::Custom { .. } => "custom",
+        TaskFunction::UserInput { .. } => "user_input",
+        TaskFunction::Manual { .. } => "manual",
+        TaskFunction::DbQuery { .. } => "db_query",
+        TaskFunction::PublishMessage { .. } => "publish_message",
+        TaskFunction::Cache { .. } => "cache",
+    };
+    format!("`{}`", name)
+}
+
+fn function_summary(function: &TaskFunction) -> String {
+    match function {
+        TaskFunction::CliCommand { command, args, .. } => {
+            let line = std::iter::once(command.as_str())
+                .chain(args.iter().map(String::as_str))
+                .collect::<Vec<_>>()
+                .join(" ");
+            format!("Runs:\n\n```sh\n{}\n```", line)
+        }
+        TaskFunction::CursorAgent { prompt, .. } => {
+            format!(
+                "Asks the Cursor agent:\n\n> {}",
+                prompt.trim().replace('\n', "\n> ")
+            )
+        }
+        TaskFunction::Custom { name, .. } => format!("Runs the `{}` handler.", name),
+        TaskFunction::UserInput {
+            prompt,
+            input_type,
+            required,
+            ..
+        } => format!(
+            "Asks for {} {} value: \"{}\"",
+            if *required {
+                "a required"
+            } else {
+                "an optional"
+            },
+            input_type,
+            prompt
+        ),
+        TaskFunction::Manual { instructions } => format!(
+            "Waits for a person to do this and confirm it:\n\n> {}",
+            instructions.trim().replace('\n', "\n> ")
+        ),
+        TaskFunction::DbQuery {
+            connection, query, ..
+        } => format!(
+            "Queries the `{}` connection:\n\n```sql\n{}\n```",
+            connection,
+            query.trim()
+        ),
+        TaskFunction::PublishMessage {
+            connection,
+            destination,
+            ..
+        } => format!(
+            "Publishes a message to `{}` on the `{}` connection.",
+            destination, connection
+        ),
+        TaskFunction::Cache { key, paths } => format!(
+            "Restores {} from the cache key `{}`, saving them there after a successful run if nothing was cached yet.",
+            paths
+                .iter()
+                .map(|path| format!("`{}`", path))
+                .collect::<Vec<_>>()
+                .join(", "),
+            key
+        ),
+    }
+}
+
+fn function_output(function: &TaskFunction) -> &'static str {
+    match function {
+        TaskFunction::CliCommand { .. } => "Standard output of the command",
+        TaskFunction::CursorAgent { .. } => "The agent's response",
+        TaskFunction::Custom { .. } => "Whatever the handler returns",
+        TaskFunction::UserInput { .. } => "The value entered",
+        TaskFunction::Manual { .. } => "Outcome, who confirmed it and their notes",
+        TaskFunction::DbQuery { .. } => "Result rows as JSON objects",
+        TaskFunction::PublishMessage { .. } => "The broker's delivery confirmation",
+        TaskFunction::Cache { .. } => "The key and whether it was a hit",
+    }
+}
+
+fn table_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+fn mermaid_label(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
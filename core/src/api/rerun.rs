@@ -0,0 +1,278 @@
+//! Re-running one failed task of a finished execution in place, from the
+//! context it first ran with. The execution keeps its id; the task keeps its
+//! earlier results as attempts.
+
+use crate::api::connections::{load_db_connections, load_message_connections};
+use crate::api::context_history::context_at;
+use crate::api::lifecycle::track_run;
+use crate::api::locks::StoreResourceLocks;
+use crate::api::middleware::with_registered_middlewares;
+use crate::api::notifications::{notify_execution, notify_tasks};
+use crate::api::output_journal::OutputJournal;
+use crate::api::resume::{create_input_request_from_task, find_task_in_snapshot};
+use crate::api::run_bounds::bounded_for_resume;
+use crate::api::secrets::{unsealed_parameters, Secrets};
+use crate::api::task_cache::{directory_cache, StoreTaskResultCache};
+use crate::bridge::audit::audit_entry_to_event;
+use crate::bridge::execution::workflow_result_to_execution;
+use crate::bridge::workflow::engine_result_to_core_result;
+use crate::bridge::{OutputCallback, WorkflowResult};
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::{OutputEvents, TaskStatus, WorkflowEngine};
+use s_e_e_persistence::{
+    AuditStatus, NotificationKind, TaskExecutionStatus, WorkflowExecution, WorkflowExecutionStatus,
+};
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Whether `task_id` failed the last time it ran in `execution`.
+pub fn task_failed(execution: &WorkflowExecution, task_id: &str) -> bool {
+    let failed_status = execution
+        .tasks
+        .iter()
+        .any(|task| task.id == task_id && task.status == TaskExecutionStatus::Failed);
+    // Runs recorded before failed tasks kept their status only show the
+    // failure in the audit trail.
+    let failed_last = execution
+        .audit_trail
+        .iter()
+        .rev()
+        .find(|event| event.task_id == task_id)
+        .is_some_and(|event| event.status == AuditStatus::Failure);
+    failed_status || failed_last
+}
+
+/// Run the failed task `task_id` of a finished execution again, with the
+/// context it first started with. With `downstream`, the tasks after it run
+/// too if it succeeds this time.
+///
+/// The task's earlier result is kept in its `attempts`, and the execution's
+/// status reflects the new outcome.
+pub async fn rerun_task(
+    execution_id: &str,
+    task_id: &str,
+    downstream: bool,
+    callback: Option<OutputCallback>,
+) -> Result<WorkflowResult, CoreError> {
+    tracing::info!(execution_id, task_id, downstream, "Re-running task");
+
+    let store = get_global_store()?;
+    let mut execution = store
+        .get_workflow_execution(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(execution_id.to_string()))?;
+
+    if !matches!(
+        execution.status,
+        WorkflowExecutionStatus::Complete
+            | WorkflowExecutionStatus::Failed
+            | WorkflowExecutionStatus::Cancelled
+    ) {
+        return Err(CoreError::InputValidationFailed(format!(
+            "Execution {} has not finished, so its tasks cannot be re-run yet",
+            execution_id
+        )));
+    }
+    if !execution.tasks.iter().any(|task| task.id == task_id) {
+        return Err(CoreError::TaskNotFound(task_id.to_string()));
+    }
+    if !task_failed(&execution, task_id) {
+        return Err(CoreError::InputValidationFailed(format!(
+            "Task '{}' did not fail, so there is nothing to re-run",
+            task_id
+        )));
+    }
+
+    let workflow_json = serde_json::to_string(&execution.workflow_snapshot).map_err(|e| {
+        CoreError::Execution(format!("Failed to serialize workflow snapshot: {}", e))
+    })?;
+    let engine_workflow = s_e_e_engine::parse_workflow(&workflow_json)
+        .map_err(|e| CoreError::Engine(s_e_e_engine::EngineError::Parser(e)))?;
+    let engine_workflow = bounded_for_resume(engine_workflow, execution.bounds.as_ref())?;
+    let notify_rules = engine_workflow.notify.clone();
+
+    let context = context_at(&execution.audit_trail, task_id)
+        .map(|context| context.data)
+        .unwrap_or_else(|| Value::Object(Map::new()));
+
+    let parameters = unsealed_parameters(&execution)?;
+    let secrets = Secrets::new(&engine_workflow.parameters, &parameters);
+    let journal = OutputJournal::start(store.clone(), execution_id);
+    let output = secrets
+        .guard_output(Some(journal.sink(callback)))
+        .map(|sink| OutputEvents::new(execution_id, sink));
+    let mut engine = secrets
+        .guard(with_registered_middlewares(WorkflowEngine::new()))
+        .with_result_cache(Arc::new(StoreTaskResultCache::new(store.clone())))
+        .with_directory_cache(directory_cache()?)
+        .with_resource_locks(Arc::new(StoreResourceLocks::new(store.clone())))
+        .with_db_connections(load_db_connections().await?)
+        .with_message_connections(load_message_connections().await?)
+        .with_initial_data(context);
+    if let Some(output) = &output {
+        engine = engine.with_output(output.clone());
+    }
+
+    let started_at = chrono::Utc::now();
+    let run = track_run(execution_id);
+    let mut engine_result = run
+        .unless_cancelled(engine.rerun_task(
+            engine_workflow,
+            execution_id.to_string(),
+            task_id,
+            downstream,
+            parameters,
+        ))
+        .await
+        .map_err(CoreError::Engine)?;
+    if run.interrupted() {
+        return Err(CoreError::Engine(s_e_e_engine::EngineError::Cancelled));
+    }
+    secrets.redact_result(&mut engine_result);
+
+    // Tasks the engine was told were done come back as complete; only those
+    // with a new audit entry, waiting for input or skipped this time actually
+    // ran.
+    let waiting: HashSet<&str> = engine_result
+        .tasks
+        .iter()
+        .filter(|task| task.status == TaskStatus::WaitingForInput)
+        .map(|task| task.id.as_str())
+        .collect();
+    let ran: HashSet<String> = engine_result
+        .audit_trail
+        .iter()
+        .map(|entry| entry.task_id.clone())
+        .chain(waiting.iter().map(|id| id.to_string()))
+        .chain(
+            engine_result
+                .tasks
+                .iter()
+                .filter(|task| task.status == TaskStatus::Skipped)
+                .map(|task| task.id.clone()),
+        )
+        .collect();
+
+    // The engine reports failed tasks as complete; the audit trail has the
+    // outcome, so the kept attempt and the new record say what happened.
+    let failed_before: HashSet<String> = ran
+        .iter()
+        .filter(|id| task_failed(&execution, id))
+        .cloned()
+        .collect();
+    let failed_now: HashSet<&str> = engine_result
+        .audit_trail
+        .iter()
+        .filter(|entry| entry.status == s_e_e_engine::AuditStatus::Failure)
+        .map(|entry| entry.task_id.as_str())
+        .collect();
+
+    let rerun_execution =
+        workflow_result_to_execution(engine_result.clone(), execution_id.to_string(), started_at);
+    for mut rerun in rerun_execution.tasks {
+        if !ran.contains(&rerun.id) {
+            continue;
+        }
+        if failed_now.contains(rerun.id.as_str()) {
+            rerun.status = TaskExecutionStatus::Failed;
+        }
+        // A `for_each` task may now have more items than on its first run.
+        let Some(task) = execution.tasks.iter_mut().find(|task| task.id == rerun.id) else {
+            execution.tasks.push(rerun);
+            continue;
+        };
+        if task.is_finished() {
+            if failed_before.contains(&task.id) {
+                task.status = TaskExecutionStatus::Failed;
+            }
+            task.record_rerun(rerun);
+        } else {
+            let attempts = std::mem::take(&mut task.attempts);
+            let prompt_id = task.prompt_id.take();
+            *task = rerun;
+            task.attempts = attempts;
+            task.prompt_id = prompt_id;
+        }
+    }
+
+    execution.errors.retain(|error| {
+        !ran.iter()
+            .any(|id| error.starts_with(&format!("Task {}:", id)))
+    });
+    execution
+        .errors
+        .extend(engine_result.errors.iter().cloned());
+    for id in &ran {
+        match engine_result.per_task_logs.get(id) {
+            Some(logs) => execution.per_task_logs.insert(id.clone(), logs.clone()),
+            None => execution.per_task_logs.remove(id),
+        };
+    }
+
+    for entry in &engine_result.audit_trail {
+        let event = audit_entry_to_event(entry)?;
+        store
+            .log_audit_event(event.clone())
+            .await
+            .map_err(CoreError::Persistence)?;
+        execution.audit_trail.push(event);
+    }
+
+    for id in &waiting {
+        if let Some(task_node) = find_task_in_snapshot(&execution.workflow_snapshot, id) {
+            if let Some(request) = create_input_request_from_task(task_node, id, execution_id) {
+                store
+                    .save_input_request(&request)
+                    .await
+                    .map_err(CoreError::Persistence)?;
+            }
+        }
+    }
+
+    let now = chrono::Utc::now();
+    execution.status = if !waiting.is_empty() {
+        WorkflowExecutionStatus::WaitingForInput
+    } else if execution.errors.is_empty() {
+        WorkflowExecutionStatus::Complete
+    } else {
+        WorkflowExecutionStatus::Failed
+    };
+    execution.completed_at = waiting.is_empty().then_some(now);
+    execution.timestamp = now;
+
+    for task in &execution.tasks {
+        store
+            .save_task_execution(task.clone())
+            .await
+            .map_err(CoreError::Persistence)?;
+    }
+    let status = execution.status.clone();
+    let errors = execution.errors.clone();
+    store
+        .save_workflow_execution(execution.clone())
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    let kind = match status {
+        WorkflowExecutionStatus::WaitingForInput => NotificationKind::InputRequested,
+        WorkflowExecutionStatus::Complete => NotificationKind::ExecutionCompleted,
+        _ => NotificationKind::ExecutionFailed,
+    };
+    notify_tasks(&notify_rules, &engine_result, execution_id).await;
+    notify_execution(kind, &execution.workflow_name, execution_id).await;
+    if let Some(output) = &output {
+        output.message(&format!("Re-run of task {} finished", task_id));
+    }
+    journal.discard(&store).await;
+
+    tracing::info!(execution_id, task_id, status = ?status, "Task re-run finished");
+
+    Ok(WorkflowResult {
+        success: status == WorkflowExecutionStatus::Complete,
+        errors,
+        ..engine_result_to_core_result(engine_result, execution_id.to_string())
+    })
+}
@@ -1,11 +1,27 @@
-use crate::bridge::audit::audit_entry_to_event;
-use crate::bridge::execution::workflow_result_to_execution;
+use crate::api::checkpoints::{clear_checkpoint, StoreCheckpoints};
+use crate::api::connections::{load_db_connections, load_message_connections};
+use crate::api::labels::stored_labels;
+use crate::api::lifecycle::track_run;
+use crate::api::locks::StoreResourceLocks;
+use crate::api::middleware::with_registered_middlewares;
+use crate::api::notifications::{notify_execution, notify_tasks};
+use crate::api::output_journal::OutputJournal;
+use crate::api::run_bounds::bounded_for_resume;
+use crate::api::secrets::{unsealed_parameters, Secrets};
+use crate::api::task_cache::{directory_cache, StoreTaskResultCache};
+use crate::api::triggers::{execution_outputs, run_dependent_workflows, TriggerSource};
+use crate::bridge::audit::{audit_entry_to_event, audit_event_to_entry};
+use crate::bridge::execution::{link_task_prompts, workflow_result_to_execution};
+use crate::bridge::workflow::engine_result_to_core_result;
 use crate::bridge::{OutputCallback, WorkflowResult};
 use crate::errors::CoreError;
 use crate::store_singleton::get_global_store;
-use s_e_e_engine::WorkflowEngine;
-use s_e_e_persistence::{TaskExecutionStatus, WorkflowExecutionStatus};
+use s_e_e_engine::{loop_task_id, OutputEvents, Scratchpad, WorkflowEngine};
+use s_e_e_persistence::{
+    NotificationKind, TaskExecution, TaskExecutionStatus, WorkflowExecutionStatus,
+};
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 pub async fn resume_workflow_execution(
     execution_id: &str,
@@ -35,10 +51,20 @@ pub async fn resume_workflow_execution(
         "Loaded workflow execution with fresh tasks from database"
     );
 
-    if !matches!(
-        execution.status,
-        WorkflowExecutionStatus::WaitingForInput | WorkflowExecutionStatus::Running
-    ) {
+    let checkpoint = store
+        .get_execution_checkpoint(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    // A failed execution that still has a checkpoint was interrupted, e.g. by
+    // a crash or shutdown, rather than failed by its tasks.
+    let interrupted = execution.status == WorkflowExecutionStatus::Failed && checkpoint.is_some();
+    if !interrupted
+        && !matches!(
+            execution.status,
+            WorkflowExecutionStatus::WaitingForInput | WorkflowExecutionStatus::Running
+        )
+    {
         return Err(CoreError::Execution(format!(
             "Workflow execution {} cannot be resumed from status: {:?}",
             execution_id, execution.status
@@ -51,6 +77,11 @@ pub async fn resume_workflow_execution(
 
     let engine_workflow = s_e_e_engine::parse_workflow(&workflow_json_str)
         .map_err(|e| CoreError::Engine(s_e_e_engine::EngineError::Parser(e)))?;
+    let engine_workflow = bounded_for_resume(engine_workflow, execution.bounds.as_ref())?;
+    let workflow_id = engine_workflow.id.clone();
+    let notify_rules = engine_workflow.notify.clone();
+    let parameters = unsealed_parameters(&execution)?;
+    let secrets = Secrets::new(&engine_workflow.parameters, &parameters);
 
     tracing::debug!(
         execution_id = %execution_id,
@@ -80,7 +111,9 @@ pub async fn resume_workflow_execution(
         );
 
         match task_execution.status {
-            TaskExecutionStatus::Complete | TaskExecutionStatus::Failed => {
+            TaskExecutionStatus::Complete
+            | TaskExecutionStatus::Failed
+            | TaskExecutionStatus::Skipped => {
                 completed_task_ids.insert(task_execution.id.clone());
             }
             TaskExecutionStatus::WaitingForInput => {
@@ -98,6 +131,27 @@ pub async fn resume_workflow_execution(
         }
     }
 
+    let mut skipped_task_ids: HashSet<String> = execution
+        .tasks
+        .iter()
+        .filter(|task| task.status == TaskExecutionStatus::Skipped)
+        .map(|task| task.id.clone())
+        .collect();
+    let mut initial_data = None;
+    let mut initial_scratch = None;
+    if let Some(checkpoint) = checkpoint {
+        tracing::debug!(
+            execution_id = %execution_id,
+            checkpoint_completed = checkpoint.completed_tasks.len(),
+            saved_at = %checkpoint.saved_at,
+            "Resuming from checkpoint"
+        );
+        completed_task_ids.extend(checkpoint.completed_tasks);
+        skipped_task_ids.extend(checkpoint.skipped_tasks);
+        initial_data = Some(checkpoint.data);
+        initial_scratch = Some(Scratchpad::from(checkpoint.scratch));
+    }
+
     tracing::debug!(
         execution_id = %execution_id,
         completed_count = completed_task_ids.len(),
@@ -105,16 +159,48 @@ pub async fn resume_workflow_execution(
         "Built execution state"
     );
 
-    let engine = WorkflowEngine::new();
-    let engine_result = engine
-        .resume_workflow_execution(
+    let journal = OutputJournal::start(store.clone(), execution_id);
+    let output = secrets
+        .guard_output(Some(journal.sink(callback)))
+        .map(|sink| OutputEvents::new(execution_id, sink));
+    let mut engine = secrets
+        .guard(with_registered_middlewares(WorkflowEngine::new()))
+        .with_result_cache(Arc::new(StoreTaskResultCache::new(store.clone())))
+        .with_checkpoints(Arc::new(StoreCheckpoints::new(store.clone())))
+        .with_directory_cache(directory_cache()?)
+        .with_resource_locks(Arc::new(StoreResourceLocks::new(store.clone())))
+        .with_db_connections(load_db_connections().await?)
+        .with_message_connections(load_message_connections().await?);
+    if let Some(output) = &output {
+        engine = engine.with_output(output.clone());
+    }
+    if let Some(data) = initial_data {
+        engine = engine.with_initial_data(data);
+    }
+    if let Some(scratch) = initial_scratch {
+        engine = engine.with_initial_scratch(scratch);
+    }
+    let run = track_run(execution_id);
+    let mut engine_result = run
+        .unless_cancelled(engine.resume_workflow_execution_with_params(
             engine_workflow,
             execution_id.to_string(),
             completed_task_ids,
             task_user_inputs,
-        )
+            parameters,
+        ))
         .await
         .map_err(CoreError::Engine)?;
+    if run.interrupted() {
+        return Err(CoreError::Engine(s_e_e_engine::EngineError::Cancelled));
+    }
+    secrets.redact_result(&mut engine_result);
+    // The engine only knows these as done; keep them skipped.
+    for task_info in &mut engine_result.tasks {
+        if skipped_task_ids.contains(&task_info.id) {
+            task_info.status = s_e_e_engine::TaskStatus::Skipped;
+        }
+    }
 
     tracing::debug!(
         execution_id = %execution_id,
@@ -163,7 +249,19 @@ pub async fn resume_workflow_execution(
         );
 
         let mut updated_execution = waiting_execution.clone();
+        keep_earlier_iterations(&execution.tasks, &mut updated_execution.tasks);
         updated_execution.workflow_snapshot = execution.workflow_snapshot;
+        link_task_prompts(&mut updated_execution);
+        updated_execution.parameters = execution.parameters;
+        updated_execution.sealed_parameters = execution.sealed_parameters;
+        updated_execution.triggered_by = execution.triggered_by;
+        updated_execution.workflow_id = execution.workflow_id;
+        updated_execution.bounds = execution.bounds;
+        updated_execution.manifest = execution.manifest;
+        updated_execution.labels = stored_labels(&store, execution_id, &execution.labels).await;
+        updated_execution
+            .audit_trail
+            .splice(0..0, execution.audit_trail);
         updated_execution.status = WorkflowExecutionStatus::WaitingForInput;
         updated_execution.completed_at = None;
 
@@ -179,21 +277,26 @@ pub async fn resume_workflow_execution(
             .await
             .map_err(CoreError::Persistence)?;
 
-        if let Some(ref callback) = callback {
-            callback("Workflow paused - waiting for user input".to_string());
+        if let Some(ref output) = output {
+            output.message("Workflow paused - waiting for user input");
         }
+        journal.discard(&store).await;
+        notify_tasks(&notify_rules, &engine_result, execution_id).await;
+        notify_execution(
+            NotificationKind::InputRequested,
+            &execution.workflow_name,
+            execution_id,
+        )
+        .await;
 
         return Ok(WorkflowResult {
             success: false,
-            workflow_name: engine_result.workflow_name,
-            execution_id: execution_id.to_string(),
-            tasks: engine_result.tasks,
-            audit_trail: engine_result.audit_trail,
-            per_task_logs: engine_result.per_task_logs,
             errors: vec!["Waiting for user input".to_string()],
+            ..engine_result_to_core_result(engine_result, execution_id.to_string())
         });
     }
 
+    let triggered = execution.triggered_by.is_some();
     let _completed_at = chrono::Utc::now();
     let mut final_execution = workflow_result_to_execution(
         engine_result.clone(),
@@ -201,7 +304,26 @@ pub async fn resume_workflow_execution(
         execution.created_at,
     );
 
+    keep_earlier_iterations(&execution.tasks, &mut final_execution.tasks);
     final_execution.workflow_snapshot = execution.workflow_snapshot;
+    link_task_prompts(&mut final_execution);
+    final_execution.parameters = execution.parameters;
+    final_execution.sealed_parameters = execution.sealed_parameters;
+    final_execution.triggered_by = execution.triggered_by;
+    final_execution.workflow_id = execution.workflow_id;
+    final_execution.bounds = execution.bounds;
+    final_execution.manifest = execution.manifest;
+    final_execution.labels = stored_labels(&store, execution_id, &execution.labels).await;
+    final_execution
+        .audit_trail
+        .splice(0..0, execution.audit_trail);
+
+    let full_audit_trail: Vec<_> = final_execution
+        .audit_trail
+        .iter()
+        .map(audit_event_to_entry)
+        .collect();
+    let outputs = execution_outputs(&full_audit_trail, &final_execution.parameters);
 
     for task in &final_execution.tasks {
         store
@@ -222,20 +344,15 @@ pub async fn resume_workflow_execution(
         .save_workflow_execution(final_execution)
         .await
         .map_err(CoreError::Persistence)?;
+    clear_checkpoint(&store, execution_id).await;
+    journal.discard(&store).await;
+    notify_tasks(&notify_rules, &engine_result, execution_id).await;
 
-    if let Some(ref callback) = callback {
-        callback("Workflow execution completed".to_string());
+    if let Some(ref output) = output {
+        output.message("Workflow execution completed");
     }
 
-    let result = WorkflowResult {
-        success: engine_result.success,
-        workflow_name: engine_result.workflow_name,
-        execution_id: execution_id.to_string(),
-        tasks: engine_result.tasks,
-        audit_trail: engine_result.audit_trail,
-        per_task_logs: engine_result.per_task_logs,
-        errors: engine_result.errors,
-    };
+    let result = engine_result_to_core_result(engine_result, execution_id.to_string());
 
     tracing::info!(
         execution_id = %execution_id,
@@ -243,10 +360,35 @@ pub async fn resume_workflow_execution(
         "Workflow resume execution completed"
     );
 
+    let kind = match (result.success, triggered) {
+        (true, _) => NotificationKind::ExecutionCompleted,
+        (false, true) => NotificationKind::TriggeredRunFailed,
+        (false, false) => NotificationKind::ExecutionFailed,
+    };
+    notify_execution(kind, &result.workflow_name, execution_id).await;
+
+    if result.success {
+        let source = TriggerSource {
+            execution_id: execution_id.to_string(),
+            chain: vec![workflow_id.clone()],
+        };
+        run_dependent_workflows(&[workflow_id], source, outputs, output).await;
+    }
+
     Ok(result)
 }
 
-fn find_task_in_snapshot<'a>(
+/// The engine only reports the `for_each` iterations it ran itself; keep the
+/// records of those that ran before the pause.
+fn keep_earlier_iterations(earlier: &[TaskExecution], tasks: &mut Vec<TaskExecution>) {
+    for task in earlier {
+        if loop_task_id(&task.id) != task.id && !tasks.iter().any(|t| t.id == task.id) {
+            tasks.push(task.clone());
+        }
+    }
+}
+
+pub(crate) fn find_task_in_snapshot<'a>(
     snapshot: &'a serde_json::Value,
     task_id: &str,
 ) -> Option<&'a serde_json::Value> {
@@ -274,7 +416,7 @@ fn find_task_in_snapshot<'a>(
     None
 }
 
-fn create_input_request_from_task(
+pub(crate) fn create_input_request_from_task(
     task_node: &serde_json::Value,
     task_id: &str,
     execution_id: &str,
@@ -282,11 +424,18 @@ fn create_input_request_from_task(
     let function = task_node.get("function")?;
     let function_type = function.get("name").and_then(|v| v.as_str())?;
 
+    let input = function.get("input")?;
+    if function_type == "manual" {
+        return Some(s_e_e_persistence::UserInputRequest::manual(
+            task_id,
+            execution_id,
+            input.get("instructions")?.as_str()?,
+        ));
+    }
     if function_type != "user_input" {
         return None;
     }
 
-    let input = function.get("input")?;
     let prompt = input.get("prompt")?.as_str()?.to_string();
     let input_type_str = input
         .get("input_type")
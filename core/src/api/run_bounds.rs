@@ -0,0 +1,102 @@
+//! Limiting a run to part of its workflow, with outputs of the skipped tasks
+//! optionally carried over from an earlier execution.
+
+use crate::api::context_history::replay;
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::{bound_workflow, EngineWorkflow};
+use s_e_e_persistence::RunBounds;
+use serde_json::Value;
+
+/// `workflow` trimmed to `bounds`, plus the context the run should start with.
+///
+/// Fails when a remaining task reads the output of a skipped one and
+/// `bounds.context_from` does not supply it.
+pub(crate) async fn apply_run_bounds(
+    workflow: EngineWorkflow,
+    workflow_id: &str,
+    bounds: &RunBounds,
+) -> Result<(EngineWorkflow, Option<Value>), CoreError> {
+    if bounds.is_empty() {
+        return Ok((workflow, None));
+    }
+    if bounds.context_from.is_some() && bounds.from.is_none() {
+        return Err(CoreError::InputValidationFailed(
+            "Outputs from an earlier execution can only be used together with a task to start from"
+                .to_string(),
+        ));
+    }
+
+    let bounded = bound_workflow(&workflow, bounds.from.as_deref(), bounds.until.as_deref())
+        .map_err(CoreError::InputValidationFailed)?;
+
+    let Some(source_id) = &bounds.context_from else {
+        if let Some((task_id, needed)) = bounded.requirements.first() {
+            return Err(CoreError::InputValidationFailed(format!(
+                "Task '{}' uses the output of '{}', which this run skips. Start from an earlier task or take the outputs from a previous execution",
+                task_id, needed
+            )));
+        }
+        return Ok((bounded.workflow, None));
+    };
+
+    let store = get_global_store()?;
+    let source = store
+        .get_workflow_execution(source_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(source_id.to_string()))?;
+    if source
+        .workflow_id
+        .as_deref()
+        .is_some_and(|id| id != workflow_id)
+    {
+        return Err(CoreError::InputValidationFailed(format!(
+            "Execution {} ran a different workflow",
+            source_id
+        )));
+    }
+
+    // Only the skipped tasks' outputs; the tasks that run produce their own.
+    let (data, _) = replay(&source.audit_trail);
+    let mut data = match data {
+        Value::Object(data) => data,
+        _ => Default::default(),
+    };
+    data.retain(|task_id, _| bounded.skipped.contains(task_id));
+    if let Some((task_id, needed)) = bounded
+        .requirements
+        .iter()
+        .find(|(_, needed)| !data.contains_key(needed))
+    {
+        return Err(CoreError::InputValidationFailed(format!(
+            "Execution {} has no output from '{}', which task '{}' uses",
+            source_id, needed, task_id
+        )));
+    }
+
+    tracing::debug!(
+        from = ?bounds.from,
+        until = ?bounds.until,
+        context_from = %source_id,
+        carried_over = data.len(),
+        "Bounded run takes skipped outputs from an earlier execution"
+    );
+    Ok((bounded.workflow, Some(Value::Object(data))))
+}
+
+/// `workflow` trimmed to bounds recorded on an execution, for resuming it.
+/// The context is not rebuilt on resume, so nothing is carried over here.
+pub(crate) fn bounded_for_resume(
+    workflow: EngineWorkflow,
+    bounds: Option<&RunBounds>,
+) -> Result<EngineWorkflow, CoreError> {
+    match bounds {
+        Some(bounds) if bounds.from.is_some() || bounds.until.is_some() => {
+            bound_workflow(&workflow, bounds.from.as_deref(), bounds.until.as_deref())
+                .map(|bounded| bounded.workflow)
+                .map_err(CoreError::InputValidationFailed)
+        }
+        _ => Ok(workflow),
+    }
+}
@@ -0,0 +1,89 @@
+use crate::api::middleware::with_registered_middlewares;
+use crate::api::secrets::Secrets;
+use crate::api::snippets::expand_workflow_snippets;
+use crate::bridge::workflow::engine_result_to_core_result;
+use crate::bridge::{OutputCallback, WorkflowResult};
+use crate::errors::CoreError;
+use crate::validation;
+use s_e_e_engine::handlers::HandlerRegistry;
+use s_e_e_engine::parameters::resolve_parameters;
+use s_e_e_engine::{EngineWorkflow, OutputEvents, WorkflowEngine};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Workflow JSON parsed as a run would parse it, with its parameters resolved.
+async fn prepare(
+    content: &str,
+    params: HashMap<String, Value>,
+) -> Result<(EngineWorkflow, HashMap<String, Value>, Secrets), CoreError> {
+    let content = &expand_workflow_snippets(content).await?;
+    validation::validate_workflow_json(content)?;
+    let workflow =
+        s_e_e_engine::parse_workflow(content).map_err(|e| CoreError::Engine(e.into()))?;
+    let parameters: HashMap<String, Value> = resolve_parameters(&workflow.parameters, &params)
+        .map_err(|e| CoreError::Engine(e.into()))?
+        .into_iter()
+        .collect();
+    let secrets = Secrets::new(&workflow.parameters, &parameters);
+    Ok((workflow, parameters, secrets))
+}
+
+/// Run workflow JSON without side effects. Commands are not run; their output
+/// is `[sandbox] Would run: ...` instead. Nothing is saved, the task
+/// result cache is skipped and no dependent workflows are triggered. Meant for
+/// previewing a workflow before adding it to the library.
+pub async fn run_sandboxed(
+    content: &str,
+    params: HashMap<String, Value>,
+    callback: Option<OutputCallback>,
+) -> Result<WorkflowResult, CoreError> {
+    let (workflow, parameters, secrets) = prepare(content, params).await?;
+
+    let execution_id = uuid::Uuid::new_v4().to_string();
+    tracing::info!(
+        execution_id = %execution_id,
+        workflow = %workflow.name,
+        "Running workflow in sandbox"
+    );
+
+    let mut engine = secrets.guard(with_registered_middlewares(WorkflowEngine::with_handlers(
+        HandlerRegistry::sandboxed(),
+    )));
+    if let Some(sink) = secrets.guard_output(callback) {
+        engine = engine.with_output(OutputEvents::new(execution_id.clone(), sink));
+    }
+    let mut result = engine
+        .execute_workflow_with_params(workflow, parameters)
+        .await?;
+    secrets.redact_result(&mut result);
+
+    Ok(engine_result_to_core_result(result, execution_id))
+}
+
+/// Walk workflow JSON without running any task. The result's `planned` lists
+/// each task that would run with its placeholders filled in; tasks whose
+/// condition does not hold come back skipped. Nothing is saved. Placeholders
+/// and conditions that read task output stay undecided, as no task produces
+/// any.
+pub async fn dry_run_workflow(
+    content: &str,
+    params: HashMap<String, Value>,
+) -> Result<WorkflowResult, CoreError> {
+    let (workflow, parameters, secrets) = prepare(content, params).await?;
+
+    let execution_id = uuid::Uuid::new_v4().to_string();
+    tracing::info!(
+        execution_id = %execution_id,
+        workflow = %workflow.name,
+        "Dry-running workflow"
+    );
+
+    let mut result = WorkflowEngine::new()
+        .with_execution_id(execution_id.clone())
+        .with_dry_run()
+        .execute_workflow_with_params(workflow, parameters)
+        .await?;
+    secrets.redact_result(&mut result);
+
+    Ok(engine_result_to_core_result(result, execution_id))
+}
@@ -0,0 +1,394 @@
+//! Reference documentation for the data the store keeps, generated from the
+//! model types and the live database so it cannot drift from either.
+
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_persistence::{persisted_models, TableLayout, SCHEMA_VERSION};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaDocsFormat {
+    #[default]
+    Markdown,
+    Json,
+}
+
+impl SchemaDocsFormat {
+    pub const ALL: [SchemaDocsFormat; 2] = [SchemaDocsFormat::Markdown, SchemaDocsFormat::Json];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SchemaDocsFormat::Markdown => "markdown",
+            SchemaDocsFormat::Json => "json",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|format| format.as_str() == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldDoc {
+    pub name: String,
+    /// Readable type such as `string | null` or `TaskAttempt[]`.
+    pub type_name: String,
+    /// Missing fields fail to load; the others fall back to a default.
+    pub required: bool,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VariantDoc {
+    pub value: String,
+    pub description: Option<String>,
+}
+
+/// A type used inside a stored model: a struct with fields or an enum with
+/// values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TypeDoc {
+    pub name: String,
+    pub description: Option<String>,
+    pub fields: Vec<FieldDoc>,
+    pub variants: Vec<VariantDoc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelDoc {
+    pub name: String,
+    pub table: String,
+    pub row_id: Option<String>,
+    pub blob_paths: Vec<String>,
+    pub description: Option<String>,
+    pub fields: Vec<FieldDoc>,
+    /// Full JSON schema of the stored document.
+    pub schema: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaDocs {
+    /// Schema version this build writes.
+    pub schema_version: i64,
+    /// Schema version recorded in the open database.
+    pub database_version: i64,
+    pub models: Vec<ModelDoc>,
+    /// Types nested in the models, sorted by name.
+    pub types: Vec<TypeDoc>,
+    pub tables: Vec<TableLayout>,
+}
+
+/// Every persisted model with its fields, the types they use, and the
+/// table layout of the open database.
+pub async fn schema_docs() -> Result<SchemaDocs, CoreError> {
+    let store = get_global_store()?;
+    let database_version = store
+        .schema_version()
+        .await
+        .map_err(CoreError::Persistence)?;
+    let tables = store.table_layout().await.map_err(CoreError::Persistence)?;
+
+    let mut models = Vec::new();
+    let mut types = BTreeMap::new();
+    for model in persisted_models() {
+        let schema = serde_json::to_value(&model.schema)
+            .map_err(|e| CoreError::Export(format!("Failed to serialize schema: {}", e)))?;
+        if let Some(definitions) = schema.get("definitions").and_then(Value::as_object) {
+            for (name, definition) in definitions {
+                types
+                    .entry(name.clone())
+                    .or_insert_with(|| type_doc(name, definition));
+            }
+        }
+        models.push(ModelDoc {
+            name: model.name.to_string(),
+            table: model.table.to_string(),
+            row_id: model.row_id.map(String::from),
+            blob_paths: model
+                .blob_paths
+                .iter()
+                .map(|path| path.to_string())
+                .collect(),
+            description: description(&schema),
+            fields: fields(&schema),
+            schema,
+        });
+    }
+
+    Ok(SchemaDocs {
+        schema_version: SCHEMA_VERSION,
+        database_version,
+        models,
+        types: types.into_values().collect(),
+        tables,
+    })
+}
+
+impl SchemaDocs {
+    pub fn render(&self, format: SchemaDocsFormat) -> Result<String, CoreError> {
+        match format {
+            SchemaDocsFormat::Markdown => Ok(self.to_markdown()),
+            SchemaDocsFormat::Json => serde_json::to_string_pretty(self)
+                .map(|json| json + "\n")
+                .map_err(|e| CoreError::Export(e.to_string())),
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        out.push_str("# Data Model\n\n");
+        let _ = writeln!(
+            out,
+            "Schema version {} (open database: {}). Generated by `schema-docs`.\n",
+            self.schema_version, self.database_version
+        );
+
+        out.push_str("## Models\n\n");
+        for model in &self.models {
+            let _ = writeln!(out, "### {}\n", model.name);
+            if let Some(description) = &model.description {
+                let _ = writeln!(out, "{}\n", description);
+            }
+            match &model.row_id {
+                Some(row_id) => {
+                    let _ = writeln!(
+                        out,
+                        "Stored as JSON in `{}.data`, in the row with id `{}`.\n",
+                        model.table, row_id
+                    );
+                }
+                None => {
+                    let _ = writeln!(out, "Stored as JSON in `{}.data`.\n", model.table);
+                }
+            }
+            if !model.blob_paths.is_empty() {
+                let paths: Vec<String> = model
+                    .blob_paths
+                    .iter()
+                    .map(|path| format!("`{}`", path))
+                    .collect();
+                let _ = writeln!(
+                    out,
+                    "Large values at {} are kept in `blobs` and stored as `{{\"$blob\": \"<id>\"}}`.\n",
+                    paths.join(", ")
+                );
+            }
+            write_fields(&mut out, &model.fields);
+        }
+
+        if !self.types.is_empty() {
+            out.push_str("## Types\n\n");
+        }
+        for doc in &self.types {
+            let _ = writeln!(out, "### {}\n", doc.name);
+            if let Some(description) = &doc.description {
+                let _ = writeln!(out, "{}\n", description);
+            }
+            if !doc.variants.is_empty() {
+                out.push_str("| Value | Description |\n");
+                out.push_str("| --- | --- |\n");
+                for variant in &doc.variants {
+                    let _ = writeln!(
+                        out,
+                        "| `{}` | {} |",
+                        variant.value,
+                        variant
+                            .description
+                            .as_deref()
+                            .map(table_cell)
+                            .unwrap_or_default()
+                    );
+                }
+                out.push('\n');
+            }
+            if !doc.fields.is_empty() {
+                write_fields(&mut out, &doc.fields);
+            }
+        }
+
+        out.push_str("## Tables\n\n");
+        for table in &self.tables {
+            let _ = writeln!(out, "### {}\n", table.name);
+            out.push_str("| Column | Type | Not null | Primary key |\n");
+            out.push_str("| --- | --- | --- | --- |\n");
+            for column in &table.columns {
+                let _ = writeln!(
+                    out,
+                    "| `{}` | {} | {} | {} |",
+                    column.name,
+                    column.sql_type,
+                    yes_no(column.not_null),
+                    yes_no(column.primary_key)
+                );
+            }
+            out.push('\n');
+            for index in &table.indexes {
+                let _ = writeln!(out, "- `{}`", index);
+            }
+            if !table.indexes.is_empty() {
+                out.push('\n');
+            }
+        }
+
+        out.trim_end().to_string() + "\n"
+    }
+}
+
+fn write_fields(out: &mut String, fields: &[FieldDoc]) {
+    out.push_str("| Field | Type | Required | Description |\n");
+    out.push_str("| --- | --- | --- | --- |\n");
+    for field in fields {
+        let _ = writeln!(
+            out,
+            "| `{}` | {} | {} | {} |",
+            field.name,
+            table_cell(&field.type_name),
+            yes_no(field.required),
+            field
+                .description
+                .as_deref()
+                .map(table_cell)
+                .unwrap_or_default()
+        );
+    }
+    out.push('\n');
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+fn table_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+fn description(schema: &Value) -> Option<String> {
+    schema
+        .get("description")
+        .and_then(Value::as_str)
+        .map(String::from)
+}
+
+fn fields(schema: &Value) -> Vec<FieldDoc> {
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|names| names.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|properties| {
+            properties
+                .iter()
+                .map(|(name, property)| FieldDoc {
+                    name: name.clone(),
+                    type_name: type_name(property),
+                    required: required.contains(&name.as_str()),
+                    description: description(property),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn type_doc(name: &str, schema: &Value) -> TypeDoc {
+    TypeDoc {
+        name: name.to_string(),
+        description: description(schema),
+        fields: fields(schema),
+        variants: variants(schema),
+    }
+}
+
+/// Values of a string enum, whether listed in `enum` or, when variants have
+/// doc comments, as one `oneOf` branch each.
+fn variants(schema: &Value) -> Vec<VariantDoc> {
+    let values = |schema: &Value, description: Option<String>| -> Vec<VariantDoc> {
+        schema
+            .get("enum")
+            .and_then(Value::as_array)
+            .map(|values| {
+                values
+                    .iter()
+                    .map(|value| VariantDoc {
+                        value: value
+                            .as_str()
+                            .map(String::from)
+                            .unwrap_or(value.to_string()),
+                        description: description.clone(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    match schema.get("oneOf").and_then(Value::as_array) {
+        Some(branches) => branches
+            .iter()
+            .flat_map(|branch| values(branch, description(branch)))
+            .collect(),
+        None => values(schema, None),
+    }
+}
+
+fn type_name(schema: &Value) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return reference
+            .rsplit('/')
+            .next()
+            .unwrap_or(reference)
+            .to_string();
+    }
+    let variants = variants(schema);
+    if !variants.is_empty() {
+        return variants
+            .iter()
+            .map(|variant| format!("\"{}\"", variant.value))
+            .collect::<Vec<_>>()
+            .join(" | ");
+    }
+    for key in ["allOf", "anyOf", "oneOf"] {
+        if let Some(branches) = schema.get(key).and_then(Value::as_array) {
+            return branches
+                .iter()
+                .map(type_name)
+                .collect::<Vec<_>>()
+                .join(" | ");
+        }
+    }
+
+    let types: Vec<&str> = match schema.get("type") {
+        Some(Value::String(name)) => vec![name.as_str()],
+        Some(Value::Array(names)) => names.iter().filter_map(Value::as_str).collect(),
+        _ => return "any".to_string(),
+    };
+    types
+        .into_iter()
+        .map(|name| match name {
+            "array" => format!(
+                "{}[]",
+                schema.get("items").map(type_name).unwrap_or("any".into())
+            ),
+            "object" => match schema.get("additionalProperties") {
+                Some(values) if values.is_object() => {
+                    format!("map<string, {}>", type_name(values))
+                }
+                _ => "object".to_string(),
+            },
+            "string" => match schema.get("format").and_then(Value::as_str) {
+                Some(format) => format!("string ({})", format),
+                None => "string".to_string(),
+            },
+            other => other.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
@@ -0,0 +1,203 @@
+//! Parameters marked `secret`. An execution record never holds their values
+//! in the clear: `parameters` has them masked and `sealed_parameters` has
+//! them encrypted with a key kept in the data directory, so a paused run can
+//! still resume and a failed task re-run. The run's output, logs and errors
+//! have the values redacted.
+
+use crate::bridge::OutputCallback;
+use crate::errors::CoreError;
+use crate::store_singleton::get_data_dir;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use s_e_e_engine::parameters::{secret_values, SECRET_MASK};
+use s_e_e_engine::{RedactMiddleware, WorkflowEngine, WorkflowParameter};
+use s_e_e_persistence::WorkflowExecution;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Length of the AES-GCM nonce stored in front of each sealed value.
+const NONCE_LEN: usize = 12;
+
+/// The secret parameters of one run, with their values.
+#[derive(Clone)]
+pub(crate) struct Secrets {
+    values: Map<String, Value>,
+    redact: Arc<RedactMiddleware>,
+}
+
+impl Secrets {
+    pub(crate) fn new(parameters: &[WorkflowParameter], values: &HashMap<String, Value>) -> Self {
+        Self {
+            values: parameters
+                .iter()
+                .filter(|parameter| parameter.secret)
+                .filter_map(|parameter| {
+                    let value = values.get(&parameter.name)?;
+                    Some((parameter.name.clone(), value.clone()))
+                })
+                .collect(),
+            redact: Arc::new(RedactMiddleware::new(secret_values(parameters, values))),
+        }
+    }
+
+    /// `values` with each secret replaced by [`SECRET_MASK`].
+    pub(crate) fn mask<V: FromIterator<(String, Value)>>(
+        &self,
+        values: &HashMap<String, Value>,
+    ) -> V {
+        values
+            .iter()
+            .map(|(name, value)| {
+                if self.values.contains_key(name) {
+                    (name.clone(), Value::from(SECRET_MASK))
+                } else {
+                    (name.clone(), value.clone())
+                }
+            })
+            .collect()
+    }
+
+    /// The secret values encrypted for `sealed_parameters`, if there are any.
+    pub(crate) fn seal(&self) -> Result<Option<String>, CoreError> {
+        if self.values.is_empty() {
+            return Ok(None);
+        }
+        let plaintext = serde_json::to_vec(&self.values)
+            .map_err(|e| CoreError::Execution(format!("Failed to seal parameters: {}", e)))?;
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let sealed = cipher()?
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|e| CoreError::Execution(format!("Failed to seal parameters: {}", e)))?;
+        Ok(Some(hex::encode([nonce.as_slice(), &sealed].concat())))
+    }
+
+    pub(crate) fn redact_text(&self, text: &str) -> String {
+        self.redact.redact_text(text)
+    }
+
+    /// `engine`, with the secrets redacted from each task's output and error.
+    pub(crate) fn guard(&self, engine: WorkflowEngine) -> WorkflowEngine {
+        if self.values.is_empty() {
+            return engine;
+        }
+        engine.with_middleware(self.redact.clone())
+    }
+
+    /// `callback`, with the secrets redacted from each live line.
+    pub(crate) fn guard_output(&self, callback: Option<OutputCallback>) -> Option<OutputCallback> {
+        let sink = callback?;
+        if self.values.is_empty() {
+            return Some(sink);
+        }
+        let redact = self.redact.clone();
+        Some(Arc::new(move |mut event| {
+            event.line = redact.redact_text(&event.line);
+            sink(event)
+        }))
+    }
+
+    /// Redact the secrets from what handlers logged or reported outside their
+    /// output, such as the command line a task ran.
+    pub(crate) fn redact_result(&self, result: &mut s_e_e_engine::WorkflowResult) {
+        if self.values.is_empty() {
+            return;
+        }
+        let redact = |text: &mut String| *text = self.redact_text(text);
+        result.per_task_logs.values_mut().flatten().for_each(redact);
+        result.errors.iter_mut().for_each(redact);
+        for entry in &mut result.audit_trail {
+            redact(&mut entry.message);
+        }
+        result
+            .environments
+            .values_mut()
+            .flat_map(|environment| environment.values_mut())
+            .for_each(redact);
+        result
+            .outputs
+            .values_mut()
+            .for_each(|value| self.redact.redact_value(value));
+        for task in &mut result.planned {
+            redact(&mut task.action);
+            let Ok(mut function) = serde_json::to_value(&task.function) else {
+                continue;
+            };
+            self.redact.redact_value(&mut function);
+            if let Ok(function) = serde_json::from_value(function) {
+                task.function = function;
+            }
+        }
+    }
+}
+
+/// The parameters `execution` ran with, its secrets decrypted.
+pub(crate) fn unsealed_parameters(
+    execution: &WorkflowExecution,
+) -> Result<HashMap<String, Value>, CoreError> {
+    let mut parameters = execution.parameters.clone();
+    let Some(sealed) = &execution.sealed_parameters else {
+        return Ok(parameters);
+    };
+    let unreadable = |reason: String| {
+        CoreError::Execution(format!(
+            "Secret parameters of execution {} cannot be decrypted: {}",
+            execution.id, reason
+        ))
+    };
+    let bytes = hex::decode(sealed).map_err(|e| unreadable(e.to_string()))?;
+    if bytes.len() < NONCE_LEN {
+        return Err(unreadable("value is too short".to_string()));
+    }
+    let (nonce, sealed) = bytes.split_at(NONCE_LEN);
+    let plaintext = cipher()?
+        .decrypt(Nonce::from_slice(nonce), sealed)
+        .map_err(|_| unreadable("the key has changed".to_string()))?;
+    let secrets: Map<String, Value> =
+        serde_json::from_slice(&plaintext).map_err(|e| unreadable(e.to_string()))?;
+    parameters.extend(secrets);
+    Ok(parameters)
+}
+
+/// `$HOME/.s_e_e/secret.key`, created on first use.
+fn key_path() -> Result<PathBuf, CoreError> {
+    Ok(PathBuf::from(get_data_dir()?).join("secret.key"))
+}
+
+fn cipher() -> Result<Aes256Gcm, CoreError> {
+    let path = key_path()?;
+    let key_error = |e: &dyn std::fmt::Display| {
+        CoreError::Execution(format!("Failed to read key {}: {}", path.display(), e))
+    };
+    let text = match std::fs::read_to_string(&path) {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            create_key(&path)?;
+            std::fs::read_to_string(&path)
+        }
+        read => read,
+    }
+    .map_err(|e| key_error(&e))?;
+    let key = hex::decode(text.trim()).map_err(|e| key_error(&e))?;
+    Aes256Gcm::new_from_slice(&key).map_err(|e| key_error(&e))
+}
+
+/// Write a new random key readable only by the current user, unless another
+/// process has just done so.
+fn create_key(path: &Path) -> Result<(), CoreError> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let key = Aes256Gcm::generate_key(OsRng);
+    match options
+        .open(path)
+        .and_then(|mut file| file.write_all(hex::encode(key).as_bytes()))
+    {
+        Err(e) if e.kind() != std::io::ErrorKind::AlreadyExists => Err(CoreError::Execution(
+            format!("Failed to write key {}: {}", path.display(), e),
+        )),
+        _ => Ok(()),
+    }
+}
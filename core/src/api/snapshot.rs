@@ -0,0 +1,117 @@
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use crate::validation::validate_workflow_json;
+use s_e_e_persistence::{WorkflowDefinition, WorkflowExecution};
+use serde_json::Value;
+
+/// How an execution's workflow snapshot compares with the definition it ran from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotStatus {
+    /// The definition still has the content the execution ran.
+    Current,
+    /// The definition has been edited since the run.
+    Edited,
+    /// The definition has been deleted.
+    Deleted,
+    /// The execution does not record which definition it ran.
+    Unknown,
+}
+
+async fn load_execution(execution_id: &str) -> Result<WorkflowExecution, CoreError> {
+    let store = get_global_store()?;
+    store
+        .get_workflow_execution(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(execution_id.to_string()))
+}
+
+fn has_tasks(snapshot: &Value) -> bool {
+    snapshot
+        .get("tasks")
+        .and_then(Value::as_array)
+        .is_some_and(|tasks| !tasks.is_empty())
+}
+
+/// Whether the definition an execution ran has changed since.
+pub async fn execution_snapshot_status(execution_id: &str) -> Result<SnapshotStatus, CoreError> {
+    let execution = load_execution(execution_id).await?;
+    let Some(workflow_id) = execution.workflow_id.as_deref() else {
+        return Ok(SnapshotStatus::Unknown);
+    };
+
+    let store = get_global_store()?;
+    let Some(definition) = store
+        .get_workflow(workflow_id)
+        .await
+        .map_err(CoreError::Persistence)?
+    else {
+        return Ok(SnapshotStatus::Deleted);
+    };
+
+    let current: Value = serde_json::from_str(&definition.content).unwrap_or(Value::Null);
+    Ok(if current == execution.workflow_snapshot {
+        SnapshotStatus::Current
+    } else {
+        SnapshotStatus::Edited
+    })
+}
+
+/// Save the workflow content an execution ran as a new definition, so behavior
+/// from before later edits can be recovered. `name` defaults to the workflow name
+/// followed by when the execution started.
+pub async fn save_snapshot_as_workflow(
+    execution_id: &str,
+    name: Option<&str>,
+) -> Result<WorkflowDefinition, CoreError> {
+    let execution = load_execution(execution_id).await?;
+    if !has_tasks(&execution.workflow_snapshot) {
+        return Err(CoreError::Execution(format!(
+            "Execution {} has no workflow snapshot to save",
+            execution_id
+        )));
+    }
+
+    let id = format!("custom-workflow-{}", uuid::Uuid::new_v4());
+    let name = match name.map(str::trim) {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => format!(
+            "{} ({})",
+            execution.workflow_name,
+            execution.created_at.format("%Y-%m-%d %H:%M")
+        ),
+    };
+
+    // The copy gets its own id so triggers keyed on the original don't fire for it.
+    let mut content = execution.workflow_snapshot;
+    content["id"] = Value::String(id.clone());
+    content["name"] = Value::String(name.clone());
+    let content = serde_json::to_string_pretty(&content)
+        .map_err(|e| CoreError::Execution(format!("Failed to serialize snapshot: {}", e)))?;
+    validate_workflow_json(&content)?;
+
+    let now = chrono::Utc::now();
+    let workflow = WorkflowDefinition {
+        id,
+        name,
+        description: Some(format!("Saved from execution {}", execution_id)),
+        content,
+        is_default: false,
+        is_edited: false,
+        created_at: now,
+        updated_at: now,
+    };
+
+    let store = get_global_store()?;
+    store
+        .save_workflow(&workflow)
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    tracing::info!(
+        execution_id = %execution_id,
+        workflow_id = %workflow.id,
+        "Saved execution snapshot as new workflow"
+    );
+    Ok(workflow)
+}
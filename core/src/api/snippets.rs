@@ -0,0 +1,282 @@
+//! Task snippets: reusable task fragments that workflows reference by id.
+//!
+//! A task written as `{"id": "checkout", "snippet": "system:checkout-repo",
+//! "with": {"repo": "..."}}` is replaced by the snippet's task when the
+//! workflow runs, with `{{snippet.NAME}}` placeholders filled from `with` and
+//! the parameter defaults. Fields set on the referencing task (`id`, `name`,
+//! `next_tasks`, ...) win over the snippet's.
+
+use crate::bridge::workflow::workflow_definition_to_engine;
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use crate::validation::fixes::{collect_task_ids, unique_id};
+use s_e_e_engine::EngineWorkflow;
+use s_e_e_persistence::{TaskSnippet, WorkflowDefinition};
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SnippetError {
+    #[error("task '{task}' uses snippet '{snippet}', which does not exist")]
+    NotFound { task: String, snippet: String },
+
+    #[error("task '{task}' passes '{parameter}', which snippet '{snippet}' does not take")]
+    UnknownParameter {
+        task: String,
+        snippet: String,
+        parameter: String,
+    },
+
+    #[error("task '{task}' must pass '{parameter}' to snippet '{snippet}'")]
+    MissingParameter {
+        task: String,
+        snippet: String,
+        parameter: String,
+    },
+
+    #[error("task '{0}' needs a snippet id string and a 'with' object")]
+    InvalidReference(String),
+}
+
+/// Whether any task in the workflow JSON references a snippet.
+pub fn uses_snippets(workflow: &Value) -> bool {
+    fn any(tasks: &Value) -> bool {
+        tasks
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .any(|task| task.get("snippet").is_some() || any(&task["next_tasks"]))
+    }
+    any(&workflow["tasks"])
+}
+
+/// Replace every snippet reference in the workflow JSON with the snippet's
+/// task. `lookup` returns a snippet by id.
+pub fn expand_snippets<F>(workflow: &mut Value, lookup: F) -> Result<(), SnippetError>
+where
+    F: Fn(&str) -> Option<TaskSnippet>,
+{
+    expand_tasks(workflow.get_mut("tasks"), &lookup)
+}
+
+fn expand_tasks<F>(tasks: Option<&mut Value>, lookup: &F) -> Result<(), SnippetError>
+where
+    F: Fn(&str) -> Option<TaskSnippet>,
+{
+    let Some(Value::Array(tasks)) = tasks else {
+        return Ok(());
+    };
+    for task in tasks {
+        let Value::Object(task) = task else {
+            continue;
+        };
+        expand_task(task, lookup)?;
+        expand_tasks(task.get_mut("next_tasks"), lookup)?;
+    }
+    Ok(())
+}
+
+fn expand_task<F>(task: &mut Map<String, Value>, lookup: &F) -> Result<(), SnippetError>
+where
+    F: Fn(&str) -> Option<TaskSnippet>,
+{
+    let Some(reference) = task.remove("snippet") else {
+        return Ok(());
+    };
+    let task_id = task
+        .get("id")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let mut with = match task.remove("with") {
+        None => Map::new(),
+        Some(Value::Object(with)) => with,
+        Some(_) => return Err(SnippetError::InvalidReference(task_id)),
+    };
+    let Value::String(snippet_id) = reference else {
+        return Err(SnippetError::InvalidReference(task_id));
+    };
+    let snippet = lookup(&snippet_id).ok_or_else(|| SnippetError::NotFound {
+        task: task_id.clone(),
+        snippet: snippet_id.clone(),
+    })?;
+
+    let mut values = Map::new();
+    for parameter in &snippet.parameters {
+        let value = with
+            .remove(&parameter.name)
+            .or_else(|| parameter.default.clone())
+            .ok_or_else(|| SnippetError::MissingParameter {
+                task: task_id.clone(),
+                snippet: snippet_id.clone(),
+                parameter: parameter.name.clone(),
+            })?;
+        values.insert(parameter.name.clone(), value);
+    }
+    if let Some(parameter) = with.keys().next() {
+        return Err(SnippetError::UnknownParameter {
+            task: task_id,
+            snippet: snippet_id,
+            parameter: parameter.clone(),
+        });
+    }
+
+    let Value::Object(mut expanded) = fill(&snippet.task, &values) else {
+        return Err(SnippetError::InvalidReference(task_id));
+    };
+    expanded.append(task);
+    *task = expanded;
+    Ok(())
+}
+
+fn fill(template: &Value, values: &Map<String, Value>) -> Value {
+    match template {
+        Value::String(text) => fill_string(text, values),
+        Value::Array(items) => Value::Array(items.iter().map(|item| fill(item, values)).collect()),
+        Value::Object(object) => Value::Object(
+            object
+                .iter()
+                .map(|(key, value)| (key.clone(), fill(value, values)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Fills `{{snippet.NAME}}`; other placeholders are left for the run. A string
+/// that is only one placeholder takes the value's type.
+fn fill_string(text: &str, values: &Map<String, Value>) -> Value {
+    let whole = text
+        .strip_prefix("{{")
+        .and_then(|rest| rest.strip_suffix("}}"))
+        .filter(|inner| !inner.contains("{{"))
+        .and_then(|inner| inner.trim().strip_prefix("snippet."))
+        .and_then(|name| values.get(name));
+    if let Some(value) = whole {
+        return value.clone();
+    }
+
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start..].find("}}") else {
+            break;
+        };
+        output.push_str(&rest[..start]);
+        let placeholder = &rest[start..start + len + 2];
+        match placeholder[2..len]
+            .trim()
+            .strip_prefix("snippet.")
+            .and_then(|name| values.get(name))
+        {
+            Some(Value::String(value)) => output.push_str(value),
+            Some(value) => output.push_str(&value.to_string()),
+            None => output.push_str(placeholder),
+        }
+        rest = &rest[start + len + 2..];
+    }
+    output.push_str(rest);
+    Value::String(output)
+}
+
+/// Workflow JSON with its snippet references expanded from the saved
+/// snippets. Content without references comes back unchanged.
+pub async fn expand_workflow_snippets(content: &str) -> Result<String, CoreError> {
+    // Unparseable content is left for validation to report.
+    let Ok(mut workflow) = serde_json::from_str::<Value>(content) else {
+        return Ok(content.to_string());
+    };
+    if !uses_snippets(&workflow) {
+        return Ok(content.to_string());
+    }
+
+    let snippets = list_snippets().await?;
+    expand_snippets(&mut workflow, |id| {
+        snippets.iter().find(|snippet| snippet.id == id).cloned()
+    })?;
+    Ok(workflow.to_string())
+}
+
+/// [`workflow_definition_to_engine`] after expanding snippet references.
+pub(crate) async fn expanded_engine_workflow(
+    workflow: &WorkflowDefinition,
+) -> Result<EngineWorkflow, CoreError> {
+    let content = expand_workflow_snippets(&workflow.content).await?;
+    workflow_definition_to_engine(&WorkflowDefinition {
+        content,
+        ..workflow.clone()
+    })
+}
+
+/// Append a task referencing `snippet` to the workflow JSON, for the editor
+/// palette. Parameters without a default are added to `with` empty, ready to
+/// fill in.
+pub fn insert_snippet_reference(content: &str, snippet: &TaskSnippet) -> Result<String, String> {
+    let mut workflow: Value =
+        serde_json::from_str(content).map_err(|e| format!("Invalid workflow JSON: {}", e))?;
+    let Value::Object(object) = &mut workflow else {
+        return Err("Workflow JSON must be an object".to_string());
+    };
+
+    let mut taken = HashSet::new();
+    collect_task_ids(object.get("tasks").unwrap_or(&Value::Null), &mut taken);
+    let base: String = snippet
+        .id
+        .rsplit(':')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let id = unique_id(if base.is_empty() { "task" } else { &base }, &taken);
+
+    let mut reference = Map::new();
+    reference.insert("id".to_string(), Value::String(id));
+    reference.insert("snippet".to_string(), Value::String(snippet.id.clone()));
+    let with: Map<String, Value> = snippet
+        .parameters
+        .iter()
+        .filter(|parameter| parameter.default.is_none())
+        .map(|parameter| (parameter.name.clone(), Value::String(String::new())))
+        .collect();
+    if !with.is_empty() {
+        reference.insert("with".to_string(), Value::Object(with));
+    }
+
+    match object
+        .entry("tasks")
+        .or_insert_with(|| Value::Array(Vec::new()))
+    {
+        Value::Array(tasks) => tasks.push(Value::Object(reference)),
+        _ => return Err("Workflow 'tasks' must be an array".to_string()),
+    }
+    serde_json::to_string_pretty(&workflow).map_err(|e| e.to_string())
+}
+
+pub async fn list_snippets() -> Result<Vec<TaskSnippet>, CoreError> {
+    let store = get_global_store()?;
+    store.list_snippets().await.map_err(CoreError::Persistence)
+}
+
+/// Validate and save a snippet, stamping its update time.
+pub async fn save_snippet(mut snippet: TaskSnippet) -> Result<TaskSnippet, CoreError> {
+    snippet
+        .validate()
+        .map_err(CoreError::InputValidationFailed)?;
+    snippet.updated_at = chrono::Utc::now();
+    let store = get_global_store()?;
+    store
+        .save_snippet(&snippet)
+        .await
+        .map_err(CoreError::Persistence)?;
+    Ok(snippet)
+}
+
+pub async fn delete_snippet(id: &str) -> Result<(), CoreError> {
+    let store = get_global_store()?;
+    store
+        .delete_snippet(id)
+        .await
+        .map_err(CoreError::Persistence)
+}
@@ -0,0 +1,73 @@
+use crate::api::trash::purge_expired_trash;
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use chrono::{Duration, Utc};
+use s_e_e_persistence::{
+    BlobStats, BlobVacuumStats, ExecutionFilter, MaintenanceReport, WorkflowExecutionStatus,
+};
+
+/// How much deduplicated execution output is stored.
+pub async fn storage_stats() -> Result<BlobStats, CoreError> {
+    let store = get_global_store()?;
+    store.blob_stats().await.map_err(CoreError::Persistence)
+}
+
+/// Reclaim stored output that no execution or task refers to any more.
+pub async fn vacuum_storage() -> Result<BlobVacuumStats, CoreError> {
+    let store = get_global_store()?;
+    let stats = store.vacuum_blobs().await.map_err(CoreError::Persistence)?;
+    tracing::debug!(
+        removed = stats.removed,
+        bytes_reclaimed = stats.bytes_reclaimed,
+        "Vacuumed blob storage"
+    );
+    Ok(stats)
+}
+
+/// How long after one maintenance run the idle check runs another.
+pub const STORAGE_MAINTENANCE_INTERVAL: Duration = Duration::hours(24);
+
+/// Compact the database file and refresh its statistics.
+pub async fn run_storage_maintenance() -> Result<MaintenanceReport, CoreError> {
+    let store = get_global_store()?;
+    purge_expired_trash().await?;
+    let report = store.maintenance().await.map_err(CoreError::Persistence)?;
+    tracing::info!(
+        bytes_before = report.bytes_before,
+        bytes_after = report.bytes_after,
+        full_vacuum = report.full_vacuum,
+        "Storage maintenance finished"
+    );
+    Ok(report)
+}
+
+pub async fn last_storage_maintenance() -> Result<Option<MaintenanceReport>, CoreError> {
+    let store = get_global_store()?;
+    store
+        .last_maintenance()
+        .await
+        .map_err(CoreError::Persistence)
+}
+
+/// Run maintenance when nothing is executing and the last run is older than
+/// [`STORAGE_MAINTENANCE_INTERVAL`]. Returns `None` when it was skipped.
+pub async fn maintain_storage_if_idle() -> Result<Option<MaintenanceReport>, CoreError> {
+    let store = get_global_store()?;
+    let running = store
+        .count_workflow_executions(&ExecutionFilter::with_status(Some(
+            WorkflowExecutionStatus::Running,
+        )))
+        .await
+        .map_err(CoreError::Persistence)?;
+    if running > 0 {
+        return Ok(None);
+    }
+    let last = store
+        .last_maintenance()
+        .await
+        .map_err(CoreError::Persistence)?;
+    if last.is_some_and(|report| Utc::now() - report.completed_at < STORAGE_MAINTENANCE_INTERVAL) {
+        return Ok(None);
+    }
+    run_storage_maintenance().await.map(Some)
+}
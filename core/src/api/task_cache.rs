@@ -0,0 +1,105 @@
+use crate::errors::CoreError;
+use crate::store_singleton::{get_data_dir, get_global_store};
+use async_trait::async_trait;
+use s_e_e_engine::{DirectoryCache, TaskResult, TaskResultCache};
+use s_e_e_persistence::{CachedTaskResult, Store};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+
+/// Task result cache kept in the `task_result_cache` table. Storage errors are
+/// logged and treated as misses, so a broken cache only costs a rerun.
+pub struct StoreTaskResultCache {
+    store: Arc<Store>,
+}
+
+impl StoreTaskResultCache {
+    pub fn new(store: Arc<Store>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl TaskResultCache for StoreTaskResultCache {
+    async fn get(&self, key: &str) -> Option<TaskResult> {
+        let cached = match self.store.get_cached_task_result(key).await {
+            Ok(cached) => cached?,
+            Err(e) => {
+                warn!(cache_key = %key, error = %e, "Failed to read task result cache");
+                return None;
+            }
+        };
+        match serde_json::from_value(cached.result) {
+            Ok(result) => Some(result),
+            Err(e) => {
+                warn!(cache_key = %key, error = %e, "Ignoring unreadable cached task result");
+                None
+            }
+        }
+    }
+
+    async fn put(&self, key: &str, result: &TaskResult, ttl: Duration) {
+        let Ok(ttl) = chrono::Duration::from_std(ttl) else {
+            return;
+        };
+        let value = match serde_json::to_value(result) {
+            Ok(value) => value,
+            Err(e) => {
+                warn!(cache_key = %key, error = %e, "Failed to serialize task result for cache");
+                return;
+            }
+        };
+        let cached = CachedTaskResult::new(key.to_string(), value, ttl);
+        if let Err(e) = self.store.save_cached_task_result(&cached).await {
+            warn!(cache_key = %key, error = %e, "Failed to write task result cache");
+        }
+    }
+}
+
+/// Forget every cached task result, so cached tasks run again. Returns how many
+/// entries were removed.
+pub async fn clear_task_result_cache() -> Result<u64, CoreError> {
+    let store = get_global_store()?;
+    store
+        .clear_task_result_cache()
+        .await
+        .map_err(CoreError::Persistence)
+}
+
+/// `$HOME/.s_e_e/cache`, where `cache` tasks keep their directory archives.
+fn directory_cache_dir() -> Result<PathBuf, CoreError> {
+    Ok(PathBuf::from(get_data_dir()?).join("cache"))
+}
+
+pub(crate) fn directory_cache() -> Result<DirectoryCache, CoreError> {
+    directory_cache_dir().map(DirectoryCache::new)
+}
+
+/// Delete every directory archive saved by `cache` tasks, so their next run
+/// misses. Returns how many archives were removed.
+pub fn clear_directory_cache() -> Result<u64, CoreError> {
+    let dir = directory_cache_dir()?;
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => {
+            return Err(CoreError::Execution(format!(
+                "Failed to read {}: {}",
+                dir.display(),
+                e
+            )))
+        }
+    };
+    let mut removed = 0;
+    for entry in entries {
+        let path = entry
+            .map_err(|e| CoreError::Execution(format!("Failed to read {}: {}", dir.display(), e)))?
+            .path();
+        std::fs::remove_file(&path).map_err(|e| {
+            CoreError::Execution(format!("Failed to remove {}: {}", path.display(), e))
+        })?;
+        removed += 1;
+    }
+    Ok(removed)
+}
@@ -0,0 +1,74 @@
+//! Deleting workflows and prompts in bulk. Deleted items go to the trash and
+//! can be put back with [`undo_deletion`] for [`UNDO_GRACE_PERIOD`]; after
+//! that they are purged.
+
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use chrono::{DateTime, Utc};
+use s_e_e_persistence::TrashKind;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+pub const UNDO_GRACE_PERIOD: Duration = Duration::from_secs(30);
+
+/// One bulk deletion, undone as a whole.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeletionBatch {
+    pub id: String,
+    pub kind: TrashKind,
+    /// Items actually deleted; ids that no longer existed are not counted.
+    pub count: usize,
+    pub deleted_at: DateTime<Utc>,
+}
+
+fn grace_cutoff() -> DateTime<Utc> {
+    Utc::now() - chrono::Duration::from_std(UNDO_GRACE_PERIOD).unwrap_or_default()
+}
+
+async fn delete_batch(kind: TrashKind, ids: &[String]) -> Result<DeletionBatch, CoreError> {
+    let store = get_global_store()?;
+    store
+        .purge_trash(grace_cutoff())
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    let batch = uuid::Uuid::new_v4().to_string();
+    let count = store
+        .move_to_trash(kind, ids, &batch)
+        .await
+        .map_err(CoreError::Persistence)?;
+    tracing::info!(kind = kind.as_str(), count, batch = %batch, "Moved items to the trash");
+    Ok(DeletionBatch {
+        id: batch,
+        kind,
+        count,
+        deleted_at: Utc::now(),
+    })
+}
+
+pub async fn delete_workflows(ids: &[String]) -> Result<DeletionBatch, CoreError> {
+    delete_batch(TrashKind::Workflow, ids).await
+}
+
+pub async fn delete_prompts(ids: &[String]) -> Result<DeletionBatch, CoreError> {
+    delete_batch(TrashKind::Prompt, ids).await
+}
+
+/// Put back the items of a deletion still within the grace period. Returns
+/// the restored ids; an item whose id was reused since stays deleted.
+pub async fn undo_deletion(batch_id: &str) -> Result<Vec<String>, CoreError> {
+    let store = get_global_store()?;
+    store
+        .restore_from_trash(batch_id, grace_cutoff())
+        .await
+        .map_err(CoreError::Persistence)
+}
+
+/// Permanently remove trashed items past the grace period.
+pub async fn purge_expired_trash() -> Result<usize, CoreError> {
+    let store = get_global_store()?;
+    store
+        .purge_trash(grace_cutoff())
+        .await
+        .map_err(CoreError::Persistence)
+}
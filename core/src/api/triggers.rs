@@ -0,0 +1,161 @@
+use crate::api::execution::execute_workflow;
+use crate::api::snippets::expanded_engine_workflow;
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use s_e_e_engine::{diff, AuditEntry, OutputEvents, Profiler, WorkflowTrigger};
+use s_e_e_persistence::RunBounds;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+
+/// The upstream run that started a triggered execution.
+#[derive(Debug, Clone)]
+pub(crate) struct TriggerSource {
+    pub execution_id: String,
+    /// Workflow ids already run in this chain, so cycles stop instead of looping.
+    pub chain: Vec<String>,
+}
+
+/// Values a finished execution hands to the workflows it triggers.
+///
+/// Starts from the execution's parameters and overlays each task's output under
+/// its task id, rebuilt from the context changes in the audit trail.
+pub fn execution_outputs(
+    audit_trail: &[AuditEntry],
+    parameters: &HashMap<String, Value>,
+) -> Map<String, Value> {
+    let mut data = Value::Object(Map::new());
+    for entry in audit_trail {
+        diff::apply(&mut data, &entry.changes);
+    }
+
+    let mut outputs: Map<String, Value> = parameters
+        .iter()
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+    if let Value::Object(data) = data {
+        outputs.extend(data);
+    }
+    outputs
+}
+
+/// Start every workflow with an `after_workflow` trigger on the one that just succeeded.
+///
+/// Each dependent receives the upstream outputs whose names match its declared
+/// parameters. Failures are logged and reported through the callback but never
+/// affect the upstream result.
+pub(crate) fn run_dependent_workflows<'a>(
+    upstream_ids: &'a [String],
+    source: TriggerSource,
+    outputs: Map<String, Value>,
+    output: Option<OutputEvents>,
+) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    Box::pin(async move {
+        let dependents = match find_dependents(upstream_ids).await {
+            Ok(dependents) => dependents,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to look up dependent workflows");
+                return;
+            }
+        };
+
+        for (workflow_id, workflow_name, parameter_names) in dependents {
+            if source.chain.contains(&workflow_id) {
+                tracing::warn!(
+                    workflow_id = %workflow_id,
+                    chain = ?source.chain,
+                    "Skipping trigger that would run a workflow twice in one chain"
+                );
+                continue;
+            }
+
+            let params: HashMap<String, Value> = parameter_names
+                .into_iter()
+                .filter_map(|name| {
+                    let value = outputs.get(&name)?;
+                    Some((name, trim_output(value)))
+                })
+                .collect();
+
+            if let Some(ref output) = output {
+                output.message(&format!("Triggering workflow '{}'", workflow_name));
+            }
+            tracing::info!(
+                workflow_id = %workflow_id,
+                upstream_execution_id = %source.execution_id,
+                "Running triggered workflow"
+            );
+
+            let mut chain = source.chain.clone();
+            chain.push(workflow_id.clone());
+            let dependent_source = TriggerSource {
+                execution_id: source.execution_id.clone(),
+                chain,
+            };
+            if let Err(e) = execute_workflow(
+                &workflow_id,
+                params,
+                Vec::new(),
+                RunBounds::default(),
+                output.as_ref().map(OutputEvents::sink),
+                Some(dependent_source),
+                Profiler::default(),
+            )
+            .await
+            {
+                tracing::error!(workflow_id = %workflow_id, error = %e, "Triggered workflow failed");
+                if let Some(ref output) = output {
+                    output.message(&format!(
+                        "Triggered workflow '{}' failed: {}",
+                        workflow_name, e
+                    ));
+                }
+            }
+        }
+    })
+}
+
+/// Workflows triggered by any of `upstream_ids`, with their declared parameter names.
+async fn find_dependents(
+    upstream_ids: &[String],
+) -> Result<Vec<(String, String, Vec<String>)>, CoreError> {
+    let store = get_global_store()?;
+    let workflows = store
+        .list_workflows()
+        .await
+        .map_err(CoreError::Persistence)?;
+
+    let mut dependents = Vec::new();
+    for workflow in workflows {
+        let Ok(engine_workflow) = expanded_engine_workflow(&workflow).await else {
+            continue;
+        };
+        let triggered = engine_workflow
+            .triggers
+            .iter()
+            .any(|trigger| match trigger {
+                WorkflowTrigger::AfterWorkflow { workflow_id } => {
+                    upstream_ids.contains(workflow_id)
+                }
+                WorkflowTrigger::Message { .. } => false,
+            });
+        if triggered {
+            let parameter_names = engine_workflow
+                .parameters
+                .into_iter()
+                .map(|p| p.name)
+                .collect();
+            dependents.push((workflow.id, workflow.name, parameter_names));
+        }
+    }
+    Ok(dependents)
+}
+
+/// Command output usually ends with a newline that is not part of the value.
+fn trim_output(value: &Value) -> Value {
+    match value {
+        Value::String(s) => Value::String(s.trim_end().to_string()),
+        other => other.clone(),
+    }
+}
@@ -0,0 +1,257 @@
+//! Regression tests recorded from past runs. A test replays the task results
+//! of the run it came from instead of calling handlers, so it checks the
+//! workflow's wiring — templates, ordering, stages — without side effects.
+
+use crate::api::context_history::replay;
+use crate::api::snippets::expand_workflow_snippets;
+use crate::bridge::task::failure_reason_from_persistence;
+use crate::bridge::workflow::workflow_definition_to_engine;
+use crate::errors::CoreError;
+use crate::store_singleton::get_global_store;
+use async_trait::async_trait;
+use s_e_e_engine::handlers::HandlerRegistry;
+use s_e_e_engine::{
+    diff, EngineTask, ExecutionContext, HandlerError, Next, TaskMiddleware, TaskResult,
+    WorkflowEngine,
+};
+use s_e_e_persistence::{
+    RecordedTaskResult, TaskExecutionStatus, WorkflowDefinition, WorkflowExecutionStatus,
+    WorkflowTest,
+};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+/// A path where the context a test ended with differs from the recorded one.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ContextMismatch {
+    /// JSON pointer into the context, e.g. `/build/artifact`.
+    pub path: String,
+    /// `None` when the recorded context has nothing at `path`.
+    pub expected: Option<Value>,
+    /// `None` when the test run left nothing at `path`.
+    pub actual: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WorkflowTestReport {
+    pub test_id: String,
+    pub name: String,
+    pub passed: bool,
+    pub expected_success: bool,
+    pub actual_success: bool,
+    /// Tasks that ran but have no recorded result, usually ones added since
+    /// the test was recorded.
+    pub unrecorded_tasks: Vec<String>,
+    pub mismatches: Vec<ContextMismatch>,
+    /// Number of differing paths, including any past those in `mismatches`.
+    pub mismatch_count: usize,
+}
+
+/// Record the finished execution `execution_id` as a test of the workflow it
+/// ran from. `name` defaults to the workflow name and the run's start time.
+pub async fn create_test_from_execution(
+    execution_id: &str,
+    name: Option<&str>,
+) -> Result<WorkflowTest, CoreError> {
+    let store = get_global_store()?;
+    let execution = store
+        .get_workflow_execution(execution_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(execution_id.to_string()))?;
+
+    let expected_success = match execution.status {
+        WorkflowExecutionStatus::Complete => true,
+        WorkflowExecutionStatus::Failed => false,
+        _ => {
+            return Err(CoreError::InputValidationFailed(format!(
+                "Execution {} has not finished, so it cannot be recorded as a test",
+                execution_id
+            )))
+        }
+    };
+    let Some(workflow_id) = execution.workflow_id.clone() else {
+        return Err(CoreError::InputValidationFailed(format!(
+            "Execution {} does not record which workflow it ran",
+            execution_id
+        )));
+    };
+
+    let (expected_context, _) = replay(&execution.audit_trail);
+    let mut task_results = BTreeMap::new();
+    for task in &execution.tasks {
+        let result = match task.status {
+            TaskExecutionStatus::Complete => RecordedTaskResult {
+                success: true,
+                output: expected_context.get(&task.id).cloned().unwrap_or_default(),
+                error: None,
+                failure_reason: None,
+            },
+            TaskExecutionStatus::Failed => RecordedTaskResult {
+                success: false,
+                output: Value::Null,
+                error: task.error.clone(),
+                failure_reason: task.failure_reason,
+            },
+            _ => continue,
+        };
+        task_results.insert(task.id.clone(), result);
+    }
+
+    let name = match name.map(str::trim).filter(|name| !name.is_empty()) {
+        Some(name) => name.to_string(),
+        None => format!(
+            "{} {}",
+            execution.workflow_name,
+            execution.created_at.format("%Y-%m-%d %H:%M")
+        ),
+    };
+    let test = WorkflowTest {
+        id: uuid::Uuid::new_v4().to_string(),
+        workflow_id,
+        name,
+        source_execution_id: execution.id.clone(),
+        parameters: execution.parameters.clone(),
+        task_results,
+        expected_context,
+        expected_success,
+        created_at: chrono::Utc::now(),
+    };
+    test.validate().map_err(CoreError::InputValidationFailed)?;
+    store
+        .save_workflow_test(&test)
+        .await
+        .map_err(CoreError::Persistence)?;
+    tracing::info!(test_id = %test.id, execution_id, "Recorded workflow test");
+    Ok(test)
+}
+
+pub async fn list_workflow_tests(workflow_id: &str) -> Result<Vec<WorkflowTest>, CoreError> {
+    let store = get_global_store()?;
+    store
+        .list_workflow_tests(workflow_id)
+        .await
+        .map_err(CoreError::Persistence)
+}
+
+pub async fn delete_workflow_test(test_id: &str) -> Result<(), CoreError> {
+    let store = get_global_store()?;
+    store
+        .delete_workflow_test(test_id)
+        .await
+        .map_err(CoreError::Persistence)
+}
+
+/// Serves recorded results in place of the handlers.
+struct RecordedResults {
+    results: BTreeMap<String, RecordedTaskResult>,
+    unrecorded: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl TaskMiddleware for RecordedResults {
+    async fn handle(
+        &self,
+        _context: &mut ExecutionContext,
+        task: &EngineTask,
+        _next: Next<'_>,
+    ) -> Result<TaskResult, HandlerError> {
+        let Some(recorded) = self.results.get(&task.id) else {
+            self.unrecorded
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(task.id.clone());
+            return Ok(TaskResult {
+                success: false,
+                output: Value::Null,
+                error: Some(format!("No recorded result for task '{}'", task.id)),
+                failure_reason: None,
+                output_stats: None,
+                exit_code: None,
+                environment: None,
+            });
+        };
+        Ok(TaskResult {
+            success: recorded.success,
+            output: recorded.output.clone(),
+            error: recorded.error.clone(),
+            failure_reason: recorded.failure_reason.map(failure_reason_from_persistence),
+            output_stats: None,
+            exit_code: None,
+            environment: None,
+        })
+    }
+}
+
+/// Run `test` against its workflow as currently saved. Handlers are never
+/// called; preflight checks and isolated workspaces are skipped.
+pub async fn run_workflow_test(test: &WorkflowTest) -> Result<WorkflowTestReport, CoreError> {
+    let store = get_global_store()?;
+    let workflow = store
+        .get_workflow(&test.workflow_id)
+        .await
+        .map_err(CoreError::Persistence)?
+        .ok_or_else(|| CoreError::WorkflowNotFound(test.workflow_id.clone()))?;
+    let workflow = WorkflowDefinition {
+        content: expand_workflow_snippets(&workflow.content).await?,
+        ..workflow
+    };
+    let mut engine_workflow = workflow_definition_to_engine(&workflow)?;
+    engine_workflow.preflight.clear();
+    engine_workflow.workspace = None;
+
+    let recorded = Arc::new(RecordedResults {
+        results: test.task_results.clone(),
+        unrecorded: Mutex::new(Vec::new()),
+    });
+    let result = WorkflowEngine::with_handlers(HandlerRegistry::sandboxed())
+        .with_middleware(recorded.clone())
+        .execute_workflow_with_params(engine_workflow, test.parameters.clone())
+        .await?;
+
+    let mut actual_context = Value::Object(Map::new());
+    for entry in &result.audit_trail {
+        diff::apply(&mut actual_context, &entry.changes);
+    }
+    let differences = diff::diff(&test.expected_context, &actual_context);
+    let mismatches: Vec<ContextMismatch> = differences
+        .changes
+        .into_iter()
+        .map(|change| ContextMismatch {
+            path: change.path,
+            expected: change.before,
+            actual: change.after,
+        })
+        .collect();
+    let unrecorded_tasks = recorded
+        .unrecorded
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+
+    let passed = result.success == test.expected_success
+        && differences.count == 0
+        && unrecorded_tasks.is_empty();
+    tracing::info!(test_id = %test.id, passed, "Ran workflow test");
+    Ok(WorkflowTestReport {
+        test_id: test.id.clone(),
+        name: test.name.clone(),
+        passed,
+        expected_success: test.expected_success,
+        actual_success: result.success,
+        unrecorded_tasks,
+        mismatches,
+        mismatch_count: differences.count,
+    })
+}
+
+/// Run every test of `workflow_id`, oldest first.
+pub async fn run_workflow_tests(workflow_id: &str) -> Result<Vec<WorkflowTestReport>, CoreError> {
+    let mut reports = Vec::new();
+    for test in list_workflow_tests(workflow_id).await? {
+        reports.push(run_workflow_test(&test).await?);
+    }
+    Ok(reports)
+}
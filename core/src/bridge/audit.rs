@@ -1,7 +1,9 @@
 use crate::errors::CoreError;
-use s_e_e_engine::AuditEntry;
 use s_e_e_engine::AuditStatus as EngineAuditStatus;
-use s_e_e_persistence::{AuditEvent, AuditStatus as PersistenceAuditStatus};
+use s_e_e_engine::{AuditEntry, ChangeOp, ContextChange};
+use s_e_e_persistence::{
+    AuditChange, AuditChangeOp, AuditEvent, AuditStatus as PersistenceAuditStatus,
+};
 
 pub fn audit_entry_to_event(entry: &AuditEntry) -> Result<AuditEvent, CoreError> {
     let timestamp = chrono::DateTime::parse_from_rfc3339(&entry.timestamp)
@@ -20,6 +22,8 @@ pub fn audit_entry_to_event(entry: &AuditEntry) -> Result<AuditEvent, CoreError>
         timestamp,
         changes_count: entry.changes_count,
         message: entry.message.clone(),
+        changes: entry.changes.iter().map(context_change_to_audit).collect(),
+        changes_truncated: entry.changes_truncated,
     })
 }
 
@@ -35,5 +39,33 @@ pub fn audit_event_to_entry(event: &AuditEvent) -> AuditEntry {
         timestamp: event.timestamp.to_rfc3339(),
         changes_count: event.changes_count,
         message: event.message.clone(),
+        changes: event.changes.iter().map(audit_change_to_context).collect(),
+        changes_truncated: event.changes_truncated,
+    }
+}
+
+pub fn context_change_to_audit(change: &ContextChange) -> AuditChange {
+    AuditChange {
+        op: match change.op {
+            ChangeOp::Add => AuditChangeOp::Add,
+            ChangeOp::Remove => AuditChangeOp::Remove,
+            ChangeOp::Replace => AuditChangeOp::Replace,
+        },
+        path: change.path.clone(),
+        before: change.before.clone(),
+        after: change.after.clone(),
+    }
+}
+
+pub fn audit_change_to_context(change: &AuditChange) -> ContextChange {
+    ContextChange {
+        op: match change.op {
+            AuditChangeOp::Add => ChangeOp::Add,
+            AuditChangeOp::Remove => ChangeOp::Remove,
+            AuditChangeOp::Replace => ChangeOp::Replace,
+        },
+        path: change.path.clone(),
+        before: change.before.clone(),
+        after: change.after.clone(),
     }
 }
@@ -1,5 +1,6 @@
 use s_e_e_engine::WorkflowResult as EngineWorkflowResult;
 use s_e_e_persistence::{WorkflowExecution, WorkflowExecutionStatus};
+use std::collections::HashMap;
 
 pub fn workflow_result_to_execution(
     result: EngineWorkflowResult,
@@ -12,14 +13,28 @@ pub fn workflow_result_to_execution(
         .tasks
         .iter()
         .map(|task| {
-            crate::bridge::task::task_info_to_execution(
+            let mut execution = crate::bridge::task::task_info_to_execution(
                 task,
                 &execution_id,
                 &result.per_task_logs,
                 &result.errors,
                 created_at,
                 now,
-            )
+            );
+            execution.failure_reason = result
+                .failure_reasons
+                .get(&task.id)
+                .copied()
+                .map(crate::bridge::task::failure_reason_to_persistence);
+            if let Some(stats) = result.output_stats.get(&task.id) {
+                execution.output_bytes = Some(stats.total_bytes);
+                execution.output_truncated = stats.truncated;
+            }
+            execution.cache_hit = result.cache_hits.contains(&task.id);
+            if let Some(environment) = result.environments.get(&task.id) {
+                execution.environment = environment.clone();
+            }
+            execution
         })
         .collect();
 
@@ -40,8 +55,52 @@ pub fn workflow_result_to_execution(
         completed_at: Some(now),
         tasks: task_executions,
         timestamp: now,
-        audit_trail: Vec::new(),
+        audit_trail: result
+            .audit_trail
+            .iter()
+            .filter_map(|entry| crate::bridge::audit::audit_entry_to_event(entry).ok())
+            .collect(),
         per_task_logs: result.per_task_logs,
         errors: result.errors,
+        parameters: std::collections::HashMap::new(),
+        triggered_by: None,
+        workflow_id: None,
+        labels: Vec::new(),
+        bounds: None,
+        manifest: None,
+        sealed_parameters: None,
+    }
+}
+
+/// Copy `function.input.prompt_id` from each task in the workflow snapshot onto the
+/// matching task execution, linking the run to the library prompt it used.
+pub fn link_task_prompts(execution: &mut WorkflowExecution) {
+    fn collect(tasks: &[serde_json::Value], ids: &mut HashMap<String, String>) {
+        for task in tasks {
+            let task_id = task.get("id").and_then(|v| v.as_str());
+            let prompt_id = task
+                .pointer("/function/input/prompt_id")
+                .and_then(|v| v.as_str());
+            if let (Some(task_id), Some(prompt_id)) = (task_id, prompt_id) {
+                ids.insert(task_id.to_string(), prompt_id.to_string());
+            }
+            if let Some(next) = task.get("next_tasks").and_then(|v| v.as_array()) {
+                collect(next, ids);
+            }
+        }
+    }
+
+    let mut prompt_ids = HashMap::new();
+    if let Some(tasks) = execution
+        .workflow_snapshot
+        .get("tasks")
+        .and_then(|v| v.as_array())
+    {
+        collect(tasks, &mut prompt_ids);
+    }
+    for task in &mut execution.tasks {
+        if let Some(prompt_id) = prompt_ids.get(&task.id) {
+            task.prompt_id = Some(prompt_id.clone());
+        }
     }
 }
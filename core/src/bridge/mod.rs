@@ -5,4 +5,4 @@ pub mod user_input;
 pub mod workflow;
 
 pub use workflow::OutputCallback;
-pub use workflow::WorkflowResult;
+pub use workflow::{WorkflowResult, WORKFLOW_RESULT_SCHEMA_VERSION};
@@ -1,6 +1,8 @@
-use s_e_e_engine::{TaskInfo, TaskStatus as EngineTaskStatus};
-use s_e_e_persistence::{TaskExecution, TaskExecutionStatus as PersistenceTaskExecutionStatus};
-use std::collections::HashMap;
+use s_e_e_engine::{FailureReason, TaskInfo, TaskStatus as EngineTaskStatus};
+use s_e_e_persistence::{
+    TaskExecution, TaskExecutionStatus as PersistenceTaskExecutionStatus, TaskFailureReason,
+};
+use std::collections::{BTreeMap, HashMap};
 
 pub fn task_info_to_execution(
     task: &TaskInfo,
@@ -21,6 +23,7 @@ pub fn task_info_to_execution(
         EngineTaskStatus::Complete => PersistenceTaskExecutionStatus::Complete,
         EngineTaskStatus::Failed => PersistenceTaskExecutionStatus::Failed,
         EngineTaskStatus::WaitingForInput => PersistenceTaskExecutionStatus::WaitingForInput,
+        EngineTaskStatus::Skipped => PersistenceTaskExecutionStatus::Skipped,
     };
 
     let error = if matches!(task.status, EngineTaskStatus::Failed) {
@@ -34,7 +37,9 @@ pub fn task_info_to_execution(
     };
 
     let completed_at = match task.status {
-        EngineTaskStatus::Complete | EngineTaskStatus::Failed => Some(workflow_completed_at),
+        EngineTaskStatus::Complete | EngineTaskStatus::Failed | EngineTaskStatus::Skipped => {
+            Some(workflow_completed_at)
+        }
         EngineTaskStatus::WaitingForInput => None,
         _ => None,
     };
@@ -51,6 +56,12 @@ pub fn task_info_to_execution(
         user_input: None,
         input_request_id: None,
         prompt_id: None,
+        failure_reason: None,
+        output_bytes: None,
+        output_truncated: false,
+        cache_hit: false,
+        environment: BTreeMap::new(),
+        attempts: Vec::new(),
     }
 }
 
@@ -61,6 +72,7 @@ pub fn task_execution_to_info(task: &TaskExecution) -> TaskInfo {
         PersistenceTaskExecutionStatus::Complete => EngineTaskStatus::Complete,
         PersistenceTaskExecutionStatus::Failed => EngineTaskStatus::Failed,
         PersistenceTaskExecutionStatus::WaitingForInput => EngineTaskStatus::WaitingForInput,
+        PersistenceTaskExecutionStatus::Skipped => EngineTaskStatus::Skipped,
     };
 
     TaskInfo {
@@ -69,3 +81,19 @@ pub fn task_execution_to_info(task: &TaskExecution) -> TaskInfo {
         status: engine_status,
     }
 }
+
+pub fn failure_reason_to_persistence(reason: FailureReason) -> TaskFailureReason {
+    match reason {
+        FailureReason::Timeout => TaskFailureReason::Timeout,
+        FailureReason::MemoryLimitExceeded => TaskFailureReason::MemoryLimitExceeded,
+        FailureReason::CpuLimitExceeded => TaskFailureReason::CpuLimitExceeded,
+    }
+}
+
+pub fn failure_reason_from_persistence(reason: TaskFailureReason) -> FailureReason {
+    match reason {
+        TaskFailureReason::Timeout => FailureReason::Timeout,
+        TaskFailureReason::MemoryLimitExceeded => FailureReason::MemoryLimitExceeded,
+        TaskFailureReason::CpuLimitExceeded => FailureReason::CpuLimitExceeded,
+    }
+}
@@ -1,9 +1,43 @@
 use crate::errors::CoreError;
 use crate::validation;
-use s_e_e_engine::{EngineWorkflow, WorkflowResult as EngineWorkflowResult};
+use s_e_e_engine::{
+    EngineWorkflow, ErrorCategory, FailureReason, OutputStats, ProfilePhase, Profiler, RunProfile,
+    WorkflowResult as EngineWorkflowResult,
+};
 use s_e_e_persistence::WorkflowDefinition;
-use std::sync::Arc;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
+/// Version of the JSON shape produced by [`WorkflowResult::to_versioned_json`].
+/// Bump it when a field is renamed, removed or changes meaning; adding a field
+/// does not need a bump.
+pub const WORKFLOW_RESULT_SCHEMA_VERSION: u32 = 1;
+
+/// Outcome of a workflow run.
+///
+/// This is the one result shape shared by the CLI `--json` output and anything
+/// else that reports runs. Serialized, it is an object with these keys:
+///
+/// | Key | Type |
+/// |-----|------|
+/// | `success` | boolean |
+/// | `workflow_name` | string |
+/// | `execution_id` | string |
+/// | `tasks` | array of `{id, name, status}` in workflow order |
+/// | `audit_trail` | array of audit entries in the order they happened |
+/// | `per_task_logs` | object of task id to array of log lines |
+/// | `errors` | array of strings |
+/// | `failure_reasons` | object of task id to `timeout`, `memory_limit_exceeded` or `cpu_limit_exceeded` |
+/// | `exit_codes` | object of task id to integer, for failed tasks that ran a process |
+/// | `output_stats` | object of task id to `{total_bytes, truncated}` |
+/// | `cache_hits` | array of task ids served from the result cache |
+/// | `workspace` | string, only present when an isolated workspace was kept |
+/// | `stages` | array of `{name, status, task_ids}` in stage order, only present when the workflow declares stages |
+/// | `planned` | array of `{task_id, task_name, handler, action, function, unresolved}`, only present for a dry run |
+/// | `profile` | `{total_us, spans}` with spans of `{phase, label, start_us, duration_us}`, only present for a profiled run |
+///
+/// Object keys and `cache_hits` are sorted so the same run always serializes
+/// to the same text. `core/tests/fixtures/workflow_result_v1.json` holds the
+/// reference document.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct WorkflowResult {
     pub success: bool,
@@ -11,18 +45,110 @@ pub struct WorkflowResult {
     pub execution_id: String,
     pub tasks: Vec<s_e_e_engine::TaskInfo>,
     pub audit_trail: Vec<s_e_e_engine::AuditEntry>,
-    pub per_task_logs: std::collections::HashMap<String, Vec<String>>,
+    #[serde(serialize_with = "sorted_map")]
+    pub per_task_logs: HashMap<String, Vec<String>>,
     pub errors: Vec<String>,
+    #[serde(serialize_with = "sorted_map")]
+    pub failure_reasons: HashMap<String, s_e_e_engine::FailureReason>,
+    /// Exit codes of failed tasks that ran a process.
+    #[serde(default, serialize_with = "sorted_map")]
+    pub exit_codes: HashMap<String, i32>,
+    #[serde(default, serialize_with = "sorted_map")]
+    pub output_stats: HashMap<String, OutputStats>,
+    /// Tasks whose result came from the result cache instead of running.
+    #[serde(default, serialize_with = "sorted_set")]
+    pub cache_hits: HashSet<String>,
+    /// Isolated workspace left on disk after a failed or paused run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace: Option<String>,
+    /// Status of each declared stage, in stage order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stages: Vec<s_e_e_engine::StageInfo>,
+    /// The workflow's declared `outputs`, filled in from the final context.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub outputs: BTreeMap<String, serde_json::Value>,
+    /// For a dry run, the tasks it would have run with their inputs filled in.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub planned: Vec<s_e_e_engine::PlannedTask>,
+    /// For a profiled run, how long each phase of it took.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<RunProfile>,
+}
+
+fn sorted_map<S, V>(map: &HashMap<String, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    V: serde::Serialize,
+{
+    serializer.collect_map(map.iter().collect::<BTreeMap<_, _>>())
+}
+
+fn sorted_set<S>(set: &HashSet<String>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_seq(set.iter().collect::<BTreeSet<_>>())
+}
+
+impl WorkflowResult {
+    /// The result as JSON with a top-level `schema_version` key, for output
+    /// that other programs parse.
+    pub fn to_versioned_json(&self) -> serde_json::Value {
+        let mut value = serde_json::to_value(self).unwrap_or_default();
+        if let Some(object) = value.as_object_mut() {
+            object.insert(
+                "schema_version".to_string(),
+                WORKFLOW_RESULT_SCHEMA_VERSION.into(),
+            );
+        }
+        value
+    }
+
+    /// Why the run failed, or `None` when it succeeded. A timeout anywhere
+    /// wins; otherwise the exit code is taken from the first failed task.
+    pub fn failure_category(&self) -> Option<ErrorCategory> {
+        if self.success {
+            return None;
+        }
+        if self
+            .failure_reasons
+            .values()
+            .any(|reason| *reason == FailureReason::Timeout)
+        {
+            return Some(ErrorCategory::Timeout);
+        }
+        let exit_code = self
+            .tasks
+            .iter()
+            .find_map(|task| self.exit_codes.get(&task.id).copied());
+        Some(ErrorCategory::HandlerFailure { exit_code })
+    }
 }
 
-pub type OutputCallback = Arc<dyn Fn(String) + Send + Sync>;
+/// Receives each line of live output, tagged with its task and sequence number.
+pub type OutputCallback = s_e_e_engine::OutputSink;
 
 pub fn workflow_definition_to_engine(
     workflow: &WorkflowDefinition,
 ) -> Result<EngineWorkflow, CoreError> {
-    validation::validate_workflow_json(&workflow.content).map_err(CoreError::Validation)?;
+    profiled_workflow_definition_to_engine(workflow, &Profiler::default())
+}
+
+/// [`workflow_definition_to_engine`], timing validation and parsing in `profiler`.
+pub(crate) fn profiled_workflow_definition_to_engine(
+    workflow: &WorkflowDefinition,
+    profiler: &Profiler,
+) -> Result<EngineWorkflow, CoreError> {
+    profiler
+        .measure(ProfilePhase::Validate, "workflow schema", || {
+            validation::validate_workflow_json(&workflow.content)
+        })
+        .map_err(CoreError::Validation)?;
 
-    let parsed = s_e_e_engine::parse_workflow(&workflow.content)
+    let parsed = profiler
+        .measure(ProfilePhase::Parse, "workflow", || {
+            s_e_e_engine::parse_workflow(&workflow.content)
+        })
         .map_err(|e| CoreError::Engine(s_e_e_engine::EngineError::Parser(e)))?;
 
     Ok(parsed)
@@ -40,5 +166,14 @@ pub fn engine_result_to_core_result(
         audit_trail: result.audit_trail,
         per_task_logs: result.per_task_logs,
         errors: result.errors,
+        failure_reasons: result.failure_reasons,
+        exit_codes: result.exit_codes,
+        output_stats: result.output_stats,
+        cache_hits: result.cache_hits,
+        workspace: result.workspace,
+        stages: result.stages,
+        outputs: result.outputs,
+        planned: result.planned,
+        profile: None,
     }
 }
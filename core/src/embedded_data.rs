@@ -9,6 +9,9 @@ pub const BUG_FIX_PROMPT: &str = include_str!("../initial_data/prompts/bug-fix.j
 pub const CODE_REVIEW_PROMPT: &str = include_str!("../initial_data/prompts/code-review.json");
 pub const DOCUMENTATION_PROMPT: &str = include_str!("../initial_data/prompts/documentation.json");
 
+pub const CHECKOUT_REPO_SNIPPET: &str = include_str!("../initial_data/snippets/checkout-repo.json");
+pub const NOTIFY_SLACK_SNIPPET: &str = include_str!("../initial_data/snippets/notify-slack.json");
+
 pub fn get_default_workflows() -> Vec<(&'static str, &'static str)> {
     vec![
         ("code-review.json", CODE_REVIEW_WORKFLOW),
@@ -25,3 +28,10 @@ pub fn get_default_prompts() -> Vec<(&'static str, &'static str)> {
         ("documentation.json", DOCUMENTATION_PROMPT),
     ]
 }
+
+pub fn get_default_snippets() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("checkout-repo.json", CHECKOUT_REPO_SNIPPET),
+        ("notify-slack.json", NOTIFY_SLACK_SNIPPET),
+    ]
+}
@@ -1,4 +1,7 @@
+use crate::api::prompt_render::PromptIncludeError;
+use crate::api::snippets::SnippetError;
 use crate::validation::ValidationErrors;
+use s_e_e_engine::ErrorCategory;
 
 #[derive(thiserror::Error, Debug)]
 pub enum CoreError {
@@ -34,6 +37,46 @@ pub enum CoreError {
 
     #[error("Validation error: {0}")]
     Validation(#[from] ValidationErrors),
+
+    #[error("Archive error: {0}")]
+    Archive(String),
+
+    #[error("Export error: {0}")]
+    Export(String),
+
+    #[error("Prompt include error: {0}")]
+    PromptInclude(#[from] PromptIncludeError),
+
+    #[error("Snippet error: {0}")]
+    Snippet(#[from] SnippetError),
+
+    #[error("Bundle error: {0}")]
+    Bundle(String),
+
+    #[error("Imported workflow '{0}' must be trusted before it runs")]
+    UntrustedWorkflow(String),
+}
+
+impl CoreError {
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            CoreError::Engine(e) => e.category(),
+            CoreError::Validation(_)
+            | CoreError::PromptInclude(_)
+            | CoreError::Snippet(_)
+            | CoreError::Bundle(_) => ErrorCategory::Validation,
+            CoreError::UntrustedWorkflow(_) => ErrorCategory::Untrusted,
+            CoreError::Persistence(_) => ErrorCategory::Storage,
+            CoreError::WorkflowNotFound(_) | CoreError::TaskNotFound(_) => ErrorCategory::NotFound,
+            CoreError::InvalidInputType(_)
+            | CoreError::InputRequired
+            | CoreError::InputValidationFailed(_)
+            | CoreError::TaskNotWaitingForInput
+            | CoreError::WorkflowWaitingForInput => ErrorCategory::Input,
+            CoreError::Archive(_) | CoreError::Export(_) => ErrorCategory::Io,
+            CoreError::Execution(_) => ErrorCategory::Internal,
+        }
+    }
 }
 
 impl From<String> for CoreError {
@@ -7,24 +7,86 @@ pub mod store_singleton;
 pub mod validation;
 
 pub use s_e_e_persistence::{
-    AppSettings, AuditEvent, AuditStatus, Prompt, Store, TaskExecution, TaskExecutionStatus, Theme,
-    UserInputRequest, WorkflowDefinition, WorkflowExecution, WorkflowExecutionStatus,
-    WorkflowExecutionSummary, WorkflowMetadata,
+    AppSettings, ArchivedExecution, AuditChange, AuditChangeOp, AuditEvent, AuditStatus, BlobStats,
+    BlobVacuumStats, CachedTaskResult, ColumnLayout, DailyExecutionStats, DateStyle,
+    DbConnectionSettings, DisplayTimeZone, ExecutionCheckpoint, ExecutionFilter,
+    ExecutionFilterPreset, HandlerPolicySettings, HandlerProfile, LogLevel, LogSettings,
+    MaintenanceReport, MessageBroker, MessageConnectionSettings, Notification, NotificationKind,
+    OutputChunk, PollingSettings, Prompt, PromptFilter, PromptSort, PromptWithUsage,
+    RecordedTaskResult, ReproducibilityManifest, ResourceLock, RunBounds, SnippetParameter, Store,
+    TableLayout, TaskAttempt, TaskExecution, TaskExecutionStatus, TaskFailureReason, TaskSnippet,
+    Theme, TimestampSettings, TrashKind, UiState, UserInputRequest, ViewportState, WindowState,
+    WorkflowDefinition, WorkflowExecution, WorkflowExecutionStatus, WorkflowExecutionSummary,
+    WorkflowHealth, WorkflowLimitSettings, WorkflowMetadata, WorkflowProvenance, WorkflowTest,
+    SCHEMA_VERSION,
 };
 
-pub use s_e_e_engine::{AuditEntry, EngineWorkflow, TaskInfo};
+pub use s_e_e_engine::handlers::process::kill_all_process_trees;
+pub use s_e_e_engine::parameters::{missing_parameters, parse_parameter_value, SECRET_MASK};
+pub use s_e_e_engine::{
+    loop_task_id, AuditEntry, DbConnection, EngineWorkflow, ErrorCategory, FailureReason,
+    HandlerPolicy, IncomingMessage, LayoutMode, ManualConfirmation, ManualOutcome, MessageBackend,
+    MessageConnection, NodePosition, OutputEvent, OutputEvents, ParameterType, PhaseSummary,
+    PolicyList, PolicyViolation, ProfilePhase, RedactMiddleware, RetryMiddleware, RunProfile,
+    Settle, TaskInfo, TaskMiddleware, TaskProgress, WorkflowLimits, WorkflowParameter,
+};
 
 pub type WorkflowJson = EngineWorkflow;
 
 pub use crate::api::{
-    delete_workflow_execution, execute_workflow_by_id, get_pending_inputs,
-    get_tasks_waiting_for_input, populate_initial_data, provide_user_input,
+    add_all_example_workflows, add_example_workflow, apply_handler_policy, apply_workflow_limits,
+    archive_execution, archive_executions_older_than, archive_per_settings,
+    cancel_workflow_execution, clear_directory_cache, clear_task_middlewares,
+    clear_task_result_cache, compare_executions, compare_with_previous_run, complete_onboarding,
+    confirm_manual_task, content_hash, context_at, context_steps, copy_name,
+    create_test_from_execution, db_connection_from_settings, default_archive_dir,
+    delete_execution_filter_preset, delete_prompts, delete_snippet, delete_workflow_execution,
+    delete_workflow_test, delete_workflows, detect_flaky_tasks, diff_lines, dry_run_workflow,
+    duplicate_prompt, duplicate_workflow, execute_workflow_by_id, execute_workflow_by_id_profiled,
+    execute_workflow_by_id_with_bounds, execute_workflow_by_id_with_labels,
+    execute_workflow_by_id_with_params, execution_columns, execution_flaky_tasks,
+    execution_records, execution_snapshot_status, expand_snippets, expand_workflow_snippets,
+    export_execution_data, export_workflow_bundle, export_workflow_graph, flaky_tasks,
+    generate_workflow_readme, get_context_at, get_example_workflow, get_execution_manifest,
+    get_journaled_output, get_pending_inputs, get_tasks_waiting_for_input, handle_message,
+    handler_policy_from_settings, import_workflow_bundle, included_prompt_ids,
+    insert_snippet_reference, interrupt_runs, is_workflow_bundle, last_storage_maintenance,
+    layout_workflow_content, list_archived_executions, list_example_workflows,
+    list_execution_filter_presets, list_execution_labels, list_notifications, list_prompt_tags,
+    list_prompts_filtered, list_resource_locks, list_snippets, list_workflow_tests,
+    load_db_connections, load_handler_policy, load_log_settings, load_message_connections,
+    load_message_triggers, load_workflow_limits, local_user_name, maintain_storage_if_idle,
+    mark_all_notifications_read, mark_notification_read, message_connection_from_settings,
+    message_params, parameter_input_text, parse_parameter_form, populate_initial_data,
+    preview_default_reset, preview_prompt, provide_user_input, purge_expired_trash,
+    record_notification, refresh_examples, register_task_middleware, release_resource_lock,
+    render_prompt, render_prompt_content, render_workflow_readme, rerun_task,
+    reset_default_workflow, restore_archived_execution, resume_workflow_execution,
+    run_message_triggers, run_sandboxed, run_storage_maintenance, run_workflow_test,
+    run_workflow_tests, save_execution_filter_preset, save_snapshot_as_workflow, save_snippet,
+    schema_docs, set_execution_labels, storage_stats, task_columns, task_failed, trust_workflow,
+    undo_deletion, unread_notification_count, uses_snippets, vacuum_storage,
+    workflow_limits_from_settings, workflow_parameters, workflow_trust_prompt, BundleSignature,
+    ContextMismatch, DefaultResetPreview, DeletionBatch, DiffLine, DiffLineKind, ExampleRefresh,
+    ExampleWorkflow, ExecutionComparison, ExecutionDataExport, ExecutionRecord, ExportFormat,
+    FieldDoc, FlakyTask, GraphEdge, GraphNode, Lifecycle, MessageOutcome, MessageTrigger, ModelDoc,
+    ParameterField, PromptIncludeError, SchemaDocs, SchemaDocsFormat, ShutdownReport,
+    SnapshotStatus, SnippetError, StoreCheckpoints, StoreResourceLocks, StoreTaskResultCache,
+    TaskComparison, TaskContext, TaskRecord, TrustPrompt, TrustReason, TypeDoc, VariantDoc,
+    WorkflowBundle, WorkflowGraph, WorkflowTestReport, BUNDLE_FORMAT_VERSION, CANCELLED_BY_USER,
+    DEFAULT_SHUTDOWN_TIMEOUT, FLAKINESS_WINDOW, INTERRUPTED_BY_SHUTDOWN, LABEL_SEPARATOR,
+    LOCK_STALE_AFTER, MAX_INCLUDE_DEPTH, MAX_NOTIFICATIONS, RECONNECT_DELAY,
+    STORAGE_MAINTENANCE_INTERVAL, UNDO_GRACE_PERIOD,
 };
-pub use crate::bridge::WorkflowResult;
+pub use crate::bridge::{WorkflowResult, WORKFLOW_RESULT_SCHEMA_VERSION};
 pub use crate::errors::CoreError;
-pub use crate::logging::{init_tracing, TracingGuard};
+pub use crate::logging::{
+    apply_log_settings, init_tracing, log_directives, log_env_override, TracingGuard, LOG_ENV_VAR,
+    LOG_MODULES,
+};
 pub use crate::store_singleton::{
-    cleanup_test_db, get_global_store, init_global_store, init_test_store,
+    cleanup_test_db, get_global_store, get_reporting_store, init_global_store,
+    init_read_only_store, init_test_store,
 };
 
 pub use crate::bridge::audit::audit_event_to_entry;
@@ -32,4 +94,7 @@ pub use crate::bridge::task::task_execution_to_info;
 
 pub use crate::bridge::OutputCallback;
 
-pub use crate::validation::{validate_workflow_json, validate_workflow_json_simple};
+pub use crate::validation::{
+    lint_workflow, validate_workflow_json, validate_workflow_json_simple, LintWarning, QuickFix,
+    Severity, ValidationCode, ValidationError, ValidationErrors, VALIDATION_CODES_VERSION,
+};
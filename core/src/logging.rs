@@ -1,11 +1,88 @@
+use s_e_e_persistence::LogSettings;
+use std::sync::OnceLock;
 use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::{reload, EnvFilter, Registry};
 
 pub type TracingGuard = WorkerGuard;
 
+/// Overrides the saved log levels for one launch, e.g.
+/// `SEE_LOG=engine=debug,persistence=warn,info`. Takes precedence over `RUST_LOG`.
+pub const LOG_ENV_VAR: &str = "SEE_LOG";
+
+/// Module names accepted in log settings and `SEE_LOG`, with the tracing
+/// target each one stands for.
+pub const LOG_MODULES: [(&str, &str); 6] = [
+    ("engine", "s_e_e_engine"),
+    ("persistence", "s_e_e_persistence"),
+    ("core", "s_e_e_core"),
+    ("gui", "s_e_e_gui"),
+    ("cli", "s_e_e_cli"),
+    ("query", "s_e_e_dioxus_query"),
+];
+
+static FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+
+/// The tracing target for a module name; other names are taken as targets.
+pub fn log_target(module: &str) -> &str {
+    LOG_MODULES
+        .iter()
+        .find(|(name, _)| *name == module)
+        .map(|(_, target)| *target)
+        .unwrap_or(module)
+}
+
+/// `EnvFilter` directives for `settings`, e.g. `info,s_e_e_engine=debug`.
+pub fn log_directives(settings: &LogSettings) -> String {
+    std::iter::once(settings.level.as_str().to_string())
+        .chain(
+            settings
+                .modules
+                .iter()
+                .map(|(module, level)| format!("{}={}", log_target(module), level.as_str())),
+        )
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// `spec` with module names replaced by their targets, so
+/// `engine=debug,warn` becomes `s_e_e_engine=debug,warn`.
+pub fn expand_log_directives(spec: &str) -> String {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|directive| !directive.is_empty())
+        .map(|directive| match directive.split_once('=') {
+            Some((module, level)) => format!("{}={}", log_target(module.trim()), level.trim()),
+            None if LOG_MODULES.iter().any(|(name, _)| *name == directive) => {
+                log_target(directive).to_string()
+            }
+            None => directive.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Directives from `SEE_LOG` or, failing that, `RUST_LOG`. Either one
+/// replaces the saved log levels.
+pub fn log_env_override() -> Option<String> {
+    let from = |var: &str| {
+        std::env::var(var)
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+    };
+    from(LOG_ENV_VAR)
+        .map(|spec| expand_log_directives(&spec))
+        .or_else(|| from("RUST_LOG"))
+}
+
 pub fn init_tracing(log_file: Option<String>) -> Result<TracingGuard, String> {
-    use tracing_subscriber::{fmt, prelude::*, EnvFilter};
+    use tracing_subscriber::{fmt, prelude::*};
 
-    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let filter = log_env_override()
+        .and_then(|directives| EnvFilter::try_new(directives).ok())
+        .unwrap_or_else(|| EnvFilter::new("info"));
+    // Reloadable so saved log levels can replace the filter once settings load.
+    let (filter, handle) = reload::Layer::new(filter);
+    let _ = FILTER_HANDLE.set(handle);
 
     let registry = tracing_subscriber::registry().with(filter);
 
@@ -19,10 +96,33 @@ pub fn init_tracing(log_file: Option<String>) -> Result<TracingGuard, String> {
             Ok(guard)
         }
         None => {
-            registry.with(fmt::layer()).init();
+            // Console logs go to stderr so they never mix with program output on stdout.
+            registry
+                .with(fmt::layer().with_writer(std::io::stderr))
+                .init();
 
             let (_, guard) = tracing_appender::non_blocking(std::io::stdout());
             Ok(guard)
         }
     }
 }
+
+/// Replace the log filter with the levels in `settings`. Does nothing when
+/// `SEE_LOG` or `RUST_LOG` is set, or before `init_tracing`.
+pub fn apply_log_settings(settings: &LogSettings) -> Result<(), String> {
+    if let Some(directives) = log_env_override() {
+        tracing::debug!(%directives, "Log levels come from the environment; keeping them");
+        return Ok(());
+    }
+    let Some(handle) = FILTER_HANDLE.get() else {
+        return Ok(());
+    };
+    let directives = log_directives(settings);
+    let filter = EnvFilter::try_new(&directives)
+        .map_err(|e| format!("Invalid log levels '{}': {}", directives, e))?;
+    handle
+        .reload(filter)
+        .map_err(|e| format!("Failed to apply log levels: {}", e))?;
+    tracing::debug!(%directives, "Applied log levels");
+    Ok(())
+}
@@ -2,6 +2,7 @@ use s_e_e_persistence::Store;
 use std::sync::{Arc, OnceLock};
 
 static GLOBAL_STORE: OnceLock<Arc<Store>> = OnceLock::new();
+static READ_ONLY_STORE: OnceLock<Arc<Store>> = OnceLock::new();
 
 pub async fn init_global_store() -> Result<(), String> {
     let db_path = get_database_path()?;
@@ -24,23 +25,50 @@ pub fn get_global_store() -> Result<Arc<Store>, String> {
         .ok_or_else(|| "Store not initialized. Call init_global_store() first.".to_string())
 }
 
-fn get_database_path() -> Result<String, String> {
+/// Open read-only connections to the database for reporting, next to the
+/// global store. The database has to exist already.
+pub async fn init_read_only_store() -> Result<(), String> {
+    let db_path = get_database_path()?;
+    let store = Store::open_read_only(&db_path)
+        .await
+        .map_err(|e| format!("Failed to open read-only store: {}", e))?;
+
+    READ_ONLY_STORE
+        .set(Arc::new(store))
+        .map_err(|_| "Read-only store already initialized".to_string())?;
+
+    tracing::debug!("Read-only store initialized successfully");
+    Ok(())
+}
+
+/// The store reports read from: the read-only one if it was opened, else the
+/// global store.
+pub fn get_reporting_store() -> Result<Arc<Store>, String> {
+    match READ_ONLY_STORE.get() {
+        Some(store) => Ok(Arc::clone(store)),
+        None => get_global_store(),
+    }
+}
+
+/// `$HOME/.s_e_e`, created if missing.
+pub(crate) fn get_data_dir() -> Result<String, String> {
     let home_dir =
         std::env::var("HOME").map_err(|_| "HOME environment variable not set".to_string())?;
     let data_dir = format!("{}/.s_e_e", home_dir);
     std::fs::create_dir_all(&data_dir)
         .map_err(|e| format!("Failed to create data directory: {}", e))?;
+    Ok(data_dir)
+}
+
+fn get_database_path() -> Result<String, String> {
+    let data_dir = get_data_dir()?;
     let db_path = format!("{}/data.db", data_dir);
     tracing::debug!("Using database path: {}", db_path);
     Ok(db_path)
 }
 
 fn get_test_database_path() -> Result<String, String> {
-    let home_dir =
-        std::env::var("HOME").map_err(|_| "HOME environment variable not set".to_string())?;
-    let data_dir = format!("{}/.s_e_e", home_dir);
-    std::fs::create_dir_all(&data_dir)
-        .map_err(|e| format!("Failed to create data directory: {}", e))?;
+    let data_dir = get_data_dir()?;
     let db_path = format!("{}/test.db", data_dir);
     tracing::debug!("Using test database path: {}", db_path);
     Ok(db_path)
@@ -0,0 +1,109 @@
+//! Building blocks for the quick fixes attached to validation errors.
+
+use serde_json::Value;
+use std::collections::HashSet;
+use std::ops::Range;
+
+/// Every task id in a task array and its nested `next_tasks`.
+pub fn collect_task_ids(tasks: &Value, ids: &mut HashSet<String>) {
+    for task in tasks.as_array().map(Vec::as_slice).unwrap_or_default() {
+        if let Some(id) = task.get("id").and_then(Value::as_str) {
+            ids.insert(id.to_string());
+        }
+        if let Some(next_tasks) = task.get("next_tasks") {
+            collect_task_ids(next_tasks, ids);
+        }
+    }
+}
+
+/// `base`, or `base_2`, `base_3`, ... whichever is not taken yet.
+pub fn unique_id(base: &str, taken: &HashSet<String>) -> String {
+    if !taken.contains(base) {
+        return base.to_string();
+    }
+    (2..)
+        .map(|n| format!("{}_{}", base, n))
+        .find(|candidate| !taken.contains(candidate))
+        .expect("an unbounded range always yields a free suffix")
+}
+
+/// An id for the object at `pointer`, derived from its `name` when it has one.
+/// Task ids avoid every id already used in the workflow.
+pub fn generated_id(document: &Value, pointer: &str) -> String {
+    let is_task = pointer.starts_with("/tasks/");
+    let name = document
+        .pointer(pointer)
+        .and_then(|object| object.get("name"))
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+
+    let mut base = String::new();
+    for c in name.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            base.push(c.to_ascii_lowercase());
+        } else if !base.ends_with('_') && !base.is_empty() {
+            base.push('_');
+        }
+    }
+    let base = base.trim_end_matches('_');
+    let base = match base {
+        "" if is_task => "task",
+        "" => "workflow",
+        base => base,
+    };
+
+    if !is_task {
+        return base.to_string();
+    }
+    let mut taken = HashSet::new();
+    collect_task_ids(&document["tasks"], &mut taken);
+    unique_id(base, &taken)
+}
+
+/// Byte ranges of `{{...}}` templates written as bare JSON values rather than
+/// inside a string, which makes the document invalid JSON.
+pub fn unquoted_templates(source: &str) -> Vec<Range<usize>> {
+    let bytes = source.as_bytes();
+    let mut templates = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+        } else if byte == b'"' {
+            in_string = true;
+        } else if source[i..].starts_with("{{") {
+            let Some(close) = source[i + 2..].find("}}") else {
+                break;
+            };
+            let end = i + 2 + close + 2;
+            if !source[i + 2..end - 2].contains(['"', '\n']) {
+                templates.push(i..end);
+                i = end;
+                continue;
+            }
+        }
+        i += 1;
+    }
+
+    templates
+}
+
+/// 1-based line and column of a byte offset, for messages about the raw text.
+pub fn line_and_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rfind('\n')
+        .map_or(offset, |newline| offset - newline - 1)
+        + 1;
+    (line, column)
+}
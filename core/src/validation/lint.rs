@@ -0,0 +1,273 @@
+use crate::validation::fixes::collect_task_ids;
+use s_e_e_engine::{NotifyRule, ParameterType, WorkflowParameter};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// A problem that does not stop a workflow from running but is probably a mistake.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LintWarning {
+    pub path: String,
+
+    pub message: String,
+}
+
+impl std::fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Warning at '{}': {}", self.path, self.message)
+    }
+}
+
+/// Check a workflow that already passed schema validation for likely mistakes.
+pub fn lint_workflow(workflow_json: &Value) -> Vec<LintWarning> {
+    let mut warnings = Vec::new();
+
+    let tasks = workflow_json
+        .get("tasks")
+        .and_then(|t| t.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    if tasks.is_empty() {
+        warnings.push(LintWarning {
+            path: "/tasks".to_string(),
+            message: "Workflow has no tasks".to_string(),
+        });
+    }
+
+    let parameters = workflow_json
+        .get("parameters")
+        .and_then(|p| p.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    for (index, parameter) in parameters.iter().enumerate() {
+        let Ok(parameter) = serde_json::from_value::<WorkflowParameter>(parameter.clone()) else {
+            continue;
+        };
+        lint_parameter(&parameter, &format!("/parameters/{}", index), &mut warnings);
+    }
+
+    lint_tasks(tasks, "/tasks", &mut warnings);
+    lint_notify(workflow_json, &mut warnings);
+
+    warnings
+}
+
+/// Notify rules may outlive the tasks they name, but a pattern that matches
+/// nothing is usually a typo.
+fn lint_notify(workflow_json: &Value, warnings: &mut Vec<LintWarning>) {
+    let rules = workflow_json
+        .get("notify")
+        .and_then(|n| n.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let mut task_ids = HashSet::new();
+    if let Some(tasks) = workflow_json.get("tasks") {
+        collect_task_ids(tasks, &mut task_ids);
+    }
+    for (index, rule) in rules.iter().enumerate() {
+        let Ok(rule) = serde_json::from_value::<NotifyRule>(rule.clone()) else {
+            continue;
+        };
+        for (pattern_index, pattern) in rule.tasks.iter().enumerate() {
+            let single = NotifyRule {
+                tasks: vec![pattern.clone()],
+                ..rule.clone()
+            };
+            if !task_ids.iter().any(|id| single.covers(id)) {
+                warnings.push(LintWarning {
+                    path: format!("/notify/{}/tasks/{}", index, pattern_index),
+                    message: format!("Notify rule pattern '{}' matches no task", pattern),
+                });
+            }
+        }
+    }
+}
+
+fn lint_parameter(parameter: &WorkflowParameter, path: &str, warnings: &mut Vec<LintWarning>) {
+    let Some(default) = &parameter.default else {
+        return;
+    };
+
+    if parameter.required {
+        warnings.push(LintWarning {
+            path: path.to_string(),
+            message: format!(
+                "Parameter '{}' is required but has a default, so it is never prompted for",
+                parameter.name
+            ),
+        });
+    }
+
+    let matches_type = match parameter.param_type {
+        ParameterType::String
+        | ParameterType::File
+        | ParameterType::Directory
+        | ParameterType::Glob => default.is_string(),
+        ParameterType::Number => default.is_number(),
+        ParameterType::Boolean => default.is_boolean(),
+    };
+    if !matches_type {
+        warnings.push(LintWarning {
+            path: format!("{}/default", path),
+            message: format!(
+                "Default for parameter '{}' is not a {}",
+                parameter.name,
+                parameter.param_type.as_str()
+            ),
+        });
+    } else if !parameter.options.is_empty() && !parameter.options.contains(default) {
+        warnings.push(LintWarning {
+            path: format!("{}/default", path),
+            message: format!(
+                "Default for parameter '{}' is not one of its options",
+                parameter.name
+            ),
+        });
+    }
+}
+
+fn lint_tasks(tasks: &[Value], path: &str, warnings: &mut Vec<LintWarning>) {
+    for (index, task) in tasks.iter().enumerate() {
+        let task_path = format!("{}/{}", path, index);
+        let task_id = task.get("id").and_then(|v| v.as_str()).unwrap_or_default();
+
+        let process = task
+            .get("function")
+            .filter(|f| f.get("name").and_then(|n| n.as_str()) == Some("cli_command"))
+            .and_then(|f| f.pointer("/input/process"));
+        if let Some(process) = process {
+            if process.get("kill_grace_ms").is_some() && process.get("timeout_ms").is_none() {
+                warnings.push(LintWarning {
+                    path: format!("{}/function/input/process", task_path),
+                    message: format!(
+                        "Task '{}' sets kill_grace_ms without timeout_ms, so the grace period \
+                         only applies when the run is interrupted",
+                        task_id
+                    ),
+                });
+            }
+        }
+
+        if let Some(next_tasks) = task.get("next_tasks").and_then(|t| t.as_array()) {
+            lint_tasks(next_tasks, &format!("{}/next_tasks", task_path), warnings);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_lint_clean_workflow() {
+        let workflow = json!({
+            "id": "test",
+            "name": "Test Workflow",
+            "parameters": [{ "name": "target", "type": "string", "default": "dev" }],
+            "tasks": [{
+                "id": "task1",
+                "name": "Task 1",
+                "function": { "name": "cli_command", "input": { "command": "echo" } }
+            }]
+        });
+
+        assert!(lint_workflow(&workflow).is_empty());
+    }
+
+    #[test]
+    fn test_lint_empty_tasks() {
+        let workflow = json!({ "id": "test", "name": "Test Workflow", "tasks": [] });
+
+        let warnings = lint_workflow(&workflow);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "/tasks");
+    }
+
+    #[test]
+    fn test_lint_parameter_defaults() {
+        let workflow = json!({
+            "id": "test",
+            "name": "Test Workflow",
+            "parameters": [
+                { "name": "count", "type": "number", "required": true, "default": "three" }
+            ],
+            "tasks": [{
+                "id": "task1",
+                "name": "Task 1",
+                "function": { "name": "cli_command", "input": { "command": "echo" } }
+            }]
+        });
+
+        let warnings = lint_workflow(&workflow);
+        let paths: Vec<&str> = warnings.iter().map(|w| w.path.as_str()).collect();
+        assert_eq!(paths, vec!["/parameters/0", "/parameters/0/default"]);
+    }
+
+    #[test]
+    fn test_lint_default_outside_options() {
+        let workflow = json!({
+            "id": "test",
+            "name": "Test Workflow",
+            "parameters": [
+                { "name": "env", "options": ["staging", "prod"], "default": "dev" }
+            ],
+            "tasks": [{
+                "id": "task1",
+                "name": "Task 1",
+                "function": { "name": "cli_command", "input": { "command": "echo" } }
+            }]
+        });
+
+        let warnings = lint_workflow(&workflow);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "/parameters/0/default");
+    }
+
+    #[test]
+    fn test_lint_notify_pattern_matching_no_task() {
+        let workflow = json!({
+            "id": "test",
+            "name": "Test Workflow",
+            "notify": [{ "tasks": ["task*", "deploy"], "on": "failure" }],
+            "tasks": [{
+                "id": "task1",
+                "name": "Task 1",
+                "function": { "name": "cli_command", "input": { "command": "echo" } }
+            }]
+        });
+
+        let warnings = lint_workflow(&workflow);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "/notify/0/tasks/1");
+    }
+
+    #[test]
+    fn test_lint_nested_grace_without_timeout() {
+        let workflow = json!({
+            "id": "test",
+            "name": "Test Workflow",
+            "tasks": [{
+                "id": "task1",
+                "name": "Task 1",
+                "function": { "name": "cli_command", "input": { "command": "echo" } },
+                "next_tasks": [{
+                    "id": "task2",
+                    "name": "Task 2",
+                    "function": {
+                        "name": "cli_command",
+                        "input": { "command": "sleep", "process": { "kill_grace_ms": 100 } }
+                    }
+                }]
+            }]
+        });
+
+        let warnings = lint_workflow(&workflow);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].path,
+            "/tasks/0/next_tasks/0/function/input/process"
+        );
+        assert!(warnings[0].message.contains("task2"));
+    }
+}
@@ -1,7 +1,12 @@
+pub mod fixes;
+pub mod lint;
 pub mod schema;
 pub mod types;
 pub mod validator;
 
+pub use lint::{lint_workflow, LintWarning};
 pub use schema::{get_schema_version, load_workflow_schema};
-pub use types::{ValidationError, ValidationErrors};
+pub use types::{
+    QuickFix, Severity, ValidationCode, ValidationError, ValidationErrors, VALIDATION_CODES_VERSION,
+};
 pub use validator::{validate_workflow_json, validate_workflow_json_simple};
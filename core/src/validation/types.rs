@@ -12,15 +12,188 @@ pub struct WorkflowSchema {
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct TaskSchema {
     pub id: String,
+    /// Snippet references may leave `name` and `function` to the snippet.
+    #[serde(default)]
     pub name: String,
-    #[serde(rename = "function")]
+    #[serde(rename = "function", default)]
     pub _function: Value,
     #[serde(default)]
     pub next_tasks: Vec<TaskSchema>,
 }
 
+/// Version of the [`ValidationCode`] set. Codes are only added within a version;
+/// renaming or changing the meaning of one bumps it.
+pub const VALIDATION_CODES_VERSION: u32 = 1;
+
+/// Stable, machine-readable identifier for a kind of validation failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationCode {
+    /// The document is not valid JSON.
+    InvalidJson,
+    /// An object repeats a key.
+    DuplicateKey,
+    /// The document nests deeper than the parser allows.
+    NestingTooDeep,
+    /// The document exceeds a configured size, task, or depth limit.
+    LimitExceeded,
+    /// A required field is missing.
+    MissingField,
+    /// A value has the wrong JSON type.
+    InvalidType,
+    /// A field is not allowed at this location.
+    UnknownField,
+    /// A value has the right type but is not allowed, such as an empty string.
+    InvalidValue,
+    /// Two tasks share an id.
+    DuplicateTaskId,
+    /// Validation itself failed; not a problem with the document.
+    Internal,
+    /// A `{{...}}` template is written as a bare value instead of inside a string.
+    UnquotedTemplate,
+    /// A task uses a handler the active handler profile does not allow.
+    HandlerNotAllowed,
+}
+
+impl ValidationCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValidationCode::InvalidJson => "invalid_json",
+            ValidationCode::DuplicateKey => "duplicate_key",
+            ValidationCode::NestingTooDeep => "nesting_too_deep",
+            ValidationCode::LimitExceeded => "limit_exceeded",
+            ValidationCode::MissingField => "missing_field",
+            ValidationCode::InvalidType => "invalid_type",
+            ValidationCode::UnknownField => "unknown_field",
+            ValidationCode::InvalidValue => "invalid_value",
+            ValidationCode::DuplicateTaskId => "duplicate_task_id",
+            ValidationCode::Internal => "internal",
+            ValidationCode::UnquotedTemplate => "unquoted_template",
+            ValidationCode::HandlerNotAllowed => "handler_not_allowed",
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// An edit that resolves a validation error, addressed by JSON pointer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum QuickFix {
+    /// Add `value` at `pointer`, whose parent must be an object.
+    Insert { pointer: String, value: Value },
+    /// Replace the value at `pointer`.
+    Replace { pointer: String, value: Value },
+    /// Remove the field or array element at `pointer`.
+    Remove { pointer: String },
+    /// Replace bytes `start..end` of the source text. Used when the document
+    /// does not parse, so there is nothing to point into.
+    Edit {
+        start: usize,
+        end: usize,
+        text: String,
+    },
+}
+
+impl QuickFix {
+    /// Apply the fix to `document`. Returns false, leaving the document
+    /// untouched, when the pointer no longer addresses a suitable location.
+    pub fn apply(&self, document: &mut Value) -> bool {
+        let pointer = match self {
+            QuickFix::Insert { pointer, .. }
+            | QuickFix::Replace { pointer, .. }
+            | QuickFix::Remove { pointer } => pointer,
+            QuickFix::Edit { .. } => return false,
+        };
+        let Some((parent_pointer, last)) = pointer.rsplit_once('/') else {
+            return false;
+        };
+        let key = last.replace("~1", "/").replace("~0", "~");
+        let Some(parent) = document.pointer_mut(parent_pointer) else {
+            return false;
+        };
+
+        match (self, parent) {
+            (QuickFix::Insert { value, .. }, Value::Object(map)) if !map.contains_key(&key) => {
+                map.insert(key, value.clone());
+                true
+            }
+            (QuickFix::Replace { value, .. }, Value::Object(map)) if map.contains_key(&key) => {
+                map.insert(key, value.clone());
+                true
+            }
+            (QuickFix::Remove { .. }, Value::Object(map)) => map.remove(&key).is_some(),
+            (QuickFix::Replace { value, .. }, Value::Array(items)) => {
+                match key.parse::<usize>().ok().and_then(|i| items.get_mut(i)) {
+                    Some(item) => {
+                        *item = value.clone();
+                        true
+                    }
+                    None => false,
+                }
+            }
+            (QuickFix::Remove { .. }, Value::Array(items)) => match key.parse::<usize>() {
+                Ok(index) if index < items.len() => {
+                    items.remove(index);
+                    true
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Apply the fix to workflow source text, returning the new text. Pointer
+    /// fixes reformat the document; `None` means the fix no longer applies.
+    pub fn apply_to_source(&self, source: &str) -> Option<String> {
+        if let QuickFix::Edit { start, end, text } = self {
+            let (start, end) = (*start, *end);
+            if start > end || !source.is_char_boundary(start) || !source.is_char_boundary(end) {
+                return None;
+            }
+            return Some(format!("{}{}{}", &source[..start], text, &source[end..]));
+        }
+
+        let mut document: Value = serde_json::from_str(source).ok()?;
+        if !self.apply(&mut document) {
+            return None;
+        }
+        serde_json::to_string_pretty(&document).ok()
+    }
+}
+
+/// Escape one JSON pointer segment (RFC 6901).
+pub fn pointer_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ValidationError {
+    pub code: ValidationCode,
+
+    pub severity: Severity,
+
+    /// JSON pointer to the offending location; empty for the whole document.
     pub path: String,
 
     pub message: String,
@@ -28,11 +201,23 @@ pub struct ValidationError {
     pub expected: Option<String>,
 
     pub suggestions: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix: Option<QuickFix>,
 }
 
 impl std::fmt::Display for ValidationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Validation error at '{}': {}", self.path, self.message)?;
+        let path = if self.path.is_empty() {
+            "/"
+        } else {
+            &self.path
+        };
+        write!(
+            f,
+            "Validation {} [{}] at '{}': {}",
+            self.severity, self.code, path, self.message
+        )?;
         if let Some(ref expected) = self.expected {
             write!(f, " (expected: {})", expected)?;
         }
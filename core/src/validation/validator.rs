@@ -1,117 +1,383 @@
-use crate::validation::types::{ValidationError, ValidationErrors, WorkflowSchema};
+use crate::validation::fixes::{
+    collect_task_ids, generated_id, line_and_column, unique_id, unquoted_templates,
+};
+use crate::validation::types::{
+    pointer_segment, QuickFix, Severity, ValidationCode, ValidationError, ValidationErrors,
+    WorkflowSchema,
+};
+use jsonschema::error::ValidationErrorKind;
 use jsonschema::JSONSchema;
+use s_e_e_engine::json::parse_document;
+use s_e_e_engine::workflow_limits::{check_document_size, check_workflow_limits};
+use s_e_e_engine::{handler_policy, workflow_limits, HandlerPolicy, ParserError, PolicyViolation};
 use serde_json::Value;
+use std::collections::HashSet;
 
+/// Validate a workflow definition, returning every problem found as a
+/// structured diagnostic.
 pub fn validate_workflow_json(json_str: &str) -> Result<(), ValidationErrors> {
-    let workflow_json: Value = serde_json::from_str(json_str).map_err(|e| ValidationErrors {
-        errors: vec![ValidationError {
-            path: "/".to_string(),
-            message: format!("Invalid JSON: {}", e),
-            expected: Some("valid JSON".to_string()),
-            suggestions: vec![
-                "Check JSON syntax (commas, brackets, quotes)".to_string(),
-                "Ensure all strings are properly quoted".to_string(),
-                "Verify that all brackets and braces are balanced".to_string(),
-            ],
-        }],
+    let limits = workflow_limits();
+    check_document_size(json_str.len(), &limits).map_err(limit_error)?;
+
+    let workflow_json: Value = parse_document(json_str).map_err(|e| {
+        if matches!(e, ParserError::Json(_)) {
+            let templates = unquoted_template_errors(json_str);
+            if !templates.is_empty() {
+                return ValidationErrors { errors: templates };
+            }
+        }
+
+        let (code, expected, suggestions) = match e {
+            ParserError::DuplicateKey(_) => (
+                ValidationCode::DuplicateKey,
+                "each key at most once per object",
+                vec!["Remove or rename the repeated key".to_string()],
+            ),
+            ParserError::NestingTooDeep(_) => (
+                ValidationCode::NestingTooDeep,
+                "a shallower document",
+                vec!["Split long next_tasks chains into separate workflows".to_string()],
+            ),
+            _ => (
+                ValidationCode::InvalidJson,
+                "valid JSON",
+                vec![
+                    "Check JSON syntax (commas, brackets, quotes)".to_string(),
+                    "Ensure all strings are properly quoted".to_string(),
+                    "Verify that all brackets and braces are balanced".to_string(),
+                ],
+            ),
+        };
+        ValidationErrors {
+            errors: vec![ValidationError {
+                expected: Some(expected.to_string()),
+                suggestions,
+                ..diagnostic(code, "", format!("Invalid JSON: {}", e))
+            }],
+        }
     })?;
 
+    // Before the schema pass, which is the slow part on an oversized definition.
+    check_workflow_limits(&workflow_json, &limits).map_err(limit_error)?;
+
     let schema = super::schema::load_workflow_schema();
 
     let compiled = JSONSchema::compile(&schema).map_err(|e| ValidationErrors {
         errors: vec![ValidationError {
-            path: "/".to_string(),
-            message: format!("Failed to compile schema: {}", e),
-            expected: None,
             suggestions: vec!["This is an internal error".to_string()],
+            ..diagnostic(
+                ValidationCode::Internal,
+                "",
+                format!("Failed to compile schema: {}", e),
+            )
         }],
     })?;
 
-    let validation_result = compiled.validate(&workflow_json);
+    if let Err(errors) = compiled.validate(&workflow_json) {
+        return Err(ValidationErrors {
+            errors: errors
+                .flat_map(|error| schema_error(error, &workflow_json))
+                .collect(),
+        });
+    }
 
-    if let Ok(()) = validation_result {
-        let workflow: WorkflowSchema =
-            serde_json::from_value(workflow_json.clone()).map_err(|e| ValidationErrors {
-                errors: vec![ValidationError {
-                    path: "/".to_string(),
-                    message: format!("Failed to deserialize workflow: {}", e),
-                    expected: None,
-                    suggestions: vec![
-                        "Check that the JSON matches the expected structure".to_string()
-                    ],
-                }],
-            })?;
-
-        super::types::validate_no_duplicate_task_ids(&workflow).map_err(|msg| {
-            ValidationErrors {
-                errors: vec![ValidationError {
-                    path: "tasks".to_string(),
-                    message: msg,
-                    expected: None,
-                    suggestions: vec![
-                        "Ensure all task IDs are unique".to_string(),
-                        "Check nested next_tasks arrays for duplicates".to_string(),
-                    ],
-                }],
-            }
-        })?;
-
-        Ok(())
-    } else {
-        let errors: Vec<_> = validation_result.unwrap_err().collect();
-        let validation_errors: Vec<ValidationError> = errors
-            .into_iter()
-            .map(|error| {
-                let instance_path = error.instance_path.to_string();
-
-                let message = if !error.to_string().is_empty() {
-                    error.to_string()
-                } else {
-                    format!("Validation failed at {}", instance_path)
-                };
+    serde_json::from_value::<WorkflowSchema>(workflow_json.clone()).map_err(|e| {
+        ValidationErrors {
+            errors: vec![ValidationError {
+                suggestions: vec!["Check that the JSON matches the expected structure".to_string()],
+                ..diagnostic(
+                    ValidationCode::InvalidValue,
+                    "",
+                    format!("Failed to deserialize workflow: {}", e),
+                )
+            }],
+        }
+    })?;
 
+    let mut errors = Vec::new();
+    let mut seen_ids = HashSet::new();
+    let mut taken_ids = HashSet::new();
+    collect_task_ids(&workflow_json["tasks"], &mut taken_ids);
+    collect_duplicate_task_ids(
+        &workflow_json["tasks"],
+        "/tasks",
+        &mut seen_ids,
+        &mut taken_ids,
+        &mut errors,
+    );
+    if let Some(policy) = handler_policy() {
+        collect_blocked_handlers(&workflow_json["tasks"], "/tasks", &policy, &mut errors);
+    }
+    if !errors.is_empty() {
+        return Err(ValidationErrors { errors });
+    }
 
-                let mut expected = None;
-                let mut suggestions = Vec::new();
-
-
-                let path_lower = instance_path.to_lowercase();
-                if path_lower.contains("function") {
-                    suggestions.push("Ensure 'function' field contains 'name' and 'input' fields".to_string());
-                    if path_lower.contains("cli_command") {
-                        suggestions.push("CLI commands require 'input.command' (string) and optionally 'input.args' (array of strings)".to_string());
-                    } else if path_lower.contains("user_input") {
-                        suggestions.push("User input requires 'input.prompt' (string) and 'input.input_type' (string)".to_string());
-                    } else if path_lower.contains("cursor_agent") {
-                        suggestions.push("Cursor agent requires 'input.prompt' (string)".to_string());
-                    }
-                } else if path_lower.contains("/id") {
-                    suggestions.push("IDs must be unique non-empty strings".to_string());
-                    expected = Some("non-empty string".to_string());
-                } else if path_lower.contains("/name") {
-                    suggestions.push("Names should be descriptive non-empty strings".to_string());
-                    expected = Some("non-empty string".to_string());
-                }
+    Ok(())
+}
 
+fn diagnostic(code: ValidationCode, path: &str, message: String) -> ValidationError {
+    ValidationError {
+        code,
+        severity: Severity::Error,
+        path: path.to_string(),
+        message,
+        expected: None,
+        suggestions: Vec::new(),
+        fix: None,
+    }
+}
+
+/// Map one schema failure to diagnostics. A failure listing several unexpected
+/// fields becomes one diagnostic per field so each can be fixed on its own.
+fn schema_error(error: jsonschema::ValidationError, document: &Value) -> Vec<ValidationError> {
+    let instance_path = error.instance_path.to_string();
+
+    match &error.kind {
+        ValidationErrorKind::Required { property } => {
+            let field = property.as_str().unwrap_or_default();
+            let path = format!("{}/{}", instance_path, pointer_segment(field));
+            let (expected, suggestions) = path_hints(&path);
+            let fix = (field == "id").then(|| QuickFix::Insert {
+                pointer: path.clone(),
+                value: Value::String(generated_id(document, &instance_path)),
+            });
+            vec![ValidationError {
+                expected,
+                suggestions,
+                fix,
+                ..diagnostic(
+                    ValidationCode::MissingField,
+                    &path,
+                    format!("Missing required field '{}'", field),
+                )
+            }]
+        }
+        ValidationErrorKind::AdditionalProperties { unexpected } => unexpected
+            .iter()
+            .map(|field| {
+                let path = format!("{}/{}", instance_path, pointer_segment(field));
                 ValidationError {
-                    path: if instance_path.is_empty() || instance_path == "/" {
-                        "root".to_string()
-                    } else {
-                        instance_path
-                    },
-                    message,
-                    expected,
-                    suggestions,
+                    suggestions: vec![format!("Remove '{}' or check its spelling", field)],
+                    fix: Some(QuickFix::Remove {
+                        pointer: path.clone(),
+                    }),
+                    ..diagnostic(
+                        ValidationCode::UnknownField,
+                        &path,
+                        format!("Unknown field '{}'", field),
+                    )
                 }
             })
-            .collect();
+            .collect(),
+        kind => {
+            let code = match kind {
+                ValidationErrorKind::Type { .. } => ValidationCode::InvalidType,
+                _ => ValidationCode::InvalidValue,
+            };
+            let message = if !error.to_string().is_empty() {
+                error.to_string()
+            } else {
+                format!("Validation failed at {}", instance_path)
+            };
+            let (expected, suggestions) = path_hints(&instance_path);
+            let fix = match instance_path.strip_suffix("/id") {
+                Some(parent) if error.instance.as_str() == Some("") => Some(QuickFix::Replace {
+                    pointer: instance_path.clone(),
+                    value: Value::String(generated_id(document, parent)),
+                }),
+                _ => None,
+            };
+            vec![ValidationError {
+                expected,
+                suggestions,
+                fix,
+                ..diagnostic(code, &instance_path, message)
+            }]
+        }
+    }
+}
+
+fn path_hints(path: &str) -> (Option<String>, Vec<String>) {
+    let mut expected = None;
+    let mut suggestions = Vec::new();
+
+    let path_lower = path.to_lowercase();
+    if path_lower.contains("function") {
+        suggestions.push("Ensure 'function' field contains 'name' and 'input' fields".to_string());
+        if path_lower.contains("cli_command") {
+            suggestions.push("CLI commands require 'input.command' (string) and optionally 'input.args' (array of strings)".to_string());
+        } else if path_lower.contains("user_input") {
+            suggestions.push(
+                "User input requires 'input.prompt' (string) and 'input.input_type' (string)"
+                    .to_string(),
+            );
+        } else if path_lower.contains("publish_message") {
+            suggestions.push(
+                "Publishing requires 'input.connection', 'input.destination' and 'input.payload'"
+                    .to_string(),
+            );
+        } else if path_lower.contains("db_query") {
+            suggestions.push(
+                "Database queries require 'input.connection' and 'input.query' (strings)"
+                    .to_string(),
+            );
+        } else if path_lower.contains("cache") {
+            suggestions.push(
+                "Cache tasks require 'input.key' (string) and 'input.paths' (array of relative paths)"
+                    .to_string(),
+            );
+        } else if path_lower.contains("manual") {
+            suggestions.push("Manual tasks require 'input.instructions' (string)".to_string());
+        } else if path_lower.contains("cursor_agent") {
+            suggestions.push("Cursor agent requires 'input.prompt' (string)".to_string());
+        }
+    } else if path_lower.ends_with("/id") {
+        suggestions.push("IDs must be unique non-empty strings".to_string());
+        expected = Some("non-empty string".to_string());
+    } else if path_lower.ends_with("/name") {
+        suggestions.push("Names should be descriptive non-empty strings".to_string());
+        expected = Some("non-empty string".to_string());
+    }
+
+    (expected, suggestions)
+}
+
+/// Report every task whose id was already used earlier in the tree, pointing
+/// at the repeated `id` field. Each gets a rename that is unique among
+/// `taken_ids`, which grows as renames are handed out.
+fn collect_duplicate_task_ids(
+    tasks: &Value,
+    path: &str,
+    seen_ids: &mut HashSet<String>,
+    taken_ids: &mut HashSet<String>,
+    errors: &mut Vec<ValidationError>,
+) {
+    let Some(tasks) = tasks.as_array() else {
+        return;
+    };
+    for (index, task) in tasks.iter().enumerate() {
+        let task_path = format!("{}/{}", path, index);
+        if let Some(id) = task.get("id").and_then(Value::as_str) {
+            if !seen_ids.insert(id.to_string()) {
+                let id_path = format!("{}/id", task_path);
+                let renamed = unique_id(id, taken_ids);
+                taken_ids.insert(renamed.clone());
+                errors.push(ValidationError {
+                    suggestions: vec![
+                        "Ensure all task IDs are unique".to_string(),
+                        "Check nested next_tasks arrays for duplicates".to_string(),
+                    ],
+                    fix: Some(QuickFix::Replace {
+                        pointer: id_path.clone(),
+                        value: Value::String(renamed),
+                    }),
+                    ..diagnostic(
+                        ValidationCode::DuplicateTaskId,
+                        &id_path,
+                        format!("Duplicate task ID: '{}'", id),
+                    )
+                });
+            }
+        }
+        if let Some(next_tasks) = task.get("next_tasks") {
+            collect_duplicate_task_ids(
+                next_tasks,
+                &format!("{}/next_tasks", task_path),
+                seen_ids,
+                taken_ids,
+                errors,
+            );
+        }
+    }
+}
 
-        Err(ValidationErrors {
-            errors: validation_errors,
+/// Report every task whose handler the policy refuses, pointing at its
+/// `function.name`. Snippet references without a function of their own are
+/// checked once expanded, when the workflow runs.
+fn collect_blocked_handlers(
+    tasks: &Value,
+    path: &str,
+    policy: &HandlerPolicy,
+    errors: &mut Vec<ValidationError>,
+) {
+    let Some(tasks) = tasks.as_array() else {
+        return;
+    };
+    for (index, task) in tasks.iter().enumerate() {
+        let task_path = format!("{}/{}", path, index);
+        let handler = task.pointer("/function/name").and_then(Value::as_str);
+        if let Some(handler) = handler {
+            if let Some(list) = policy.blocking_list(handler) {
+                let violation = PolicyViolation {
+                    task_id: task["id"].as_str().unwrap_or_default().to_string(),
+                    handler: handler.to_string(),
+                    profile: policy.profile.clone(),
+                    list,
+                };
+                errors.push(ValidationError {
+                    suggestions: vec![format!(
+                        "Ask for '{}' to be allowed, or switch handler profiles in settings",
+                        handler
+                    )],
+                    ..diagnostic(
+                        ValidationCode::HandlerNotAllowed,
+                        &format!("{}/function/name", task_path),
+                        violation.to_string(),
+                    )
+                });
+            }
+        }
+        collect_blocked_handlers(
+            &task["next_tasks"],
+            &format!("{}/next_tasks", task_path),
+            policy,
+            errors,
+        );
+    }
+}
+
+/// One diagnostic per bare `{{...}}` template, each fixed by quoting it.
+fn unquoted_template_errors(json_str: &str) -> Vec<ValidationError> {
+    unquoted_templates(json_str)
+        .into_iter()
+        .map(|range| {
+            let template = &json_str[range.clone()];
+            let (line, column) = line_and_column(json_str, range.start);
+            ValidationError {
+                expected: Some("a JSON string".to_string()),
+                suggestions: vec![format!("Write it as \"{}\"", template)],
+                fix: Some(QuickFix::Edit {
+                    start: range.start,
+                    end: range.end,
+                    text: Value::String(template.to_string()).to_string(),
+                }),
+                ..diagnostic(
+                    ValidationCode::UnquotedTemplate,
+                    "",
+                    format!(
+                        "Template {} at line {}, column {} must be inside quotes",
+                        template, line, column
+                    ),
+                )
+            }
         })
+        .collect()
+}
+
+fn limit_error(error: ParserError) -> ValidationErrors {
+    ValidationErrors {
+        errors: vec![ValidationError {
+            suggestions: vec![
+                "Split the workflow into smaller workflows chained with after_workflow triggers"
+                    .to_string(),
+                "Raise the workflow limits in settings if this size is intended".to_string(),
+            ],
+            ..diagnostic(ValidationCode::LimitExceeded, "", error.to_string())
+        }],
     }
 }
 
+/// Validation errors joined into one displayable string.
 pub fn validate_workflow_json_simple(json_str: &str) -> Result<(), String> {
     validate_workflow_json(json_str).map_err(|errors| errors.to_string())
 }
@@ -159,6 +425,20 @@ mod tests {
         assert!(validate_workflow_json(json).is_err());
     }
 
+    #[test]
+    fn test_validate_rejects_duplicate_keys() {
+        let json = r#"{"id": "a", "id": "b", "name": "Test", "tasks": []}"#;
+        let errors = validate_workflow_json(json).unwrap_err();
+        assert!(errors.errors[0].message.contains("Duplicate key"));
+    }
+
+    #[test]
+    fn test_validate_rejects_deep_nesting() {
+        let json = format!("{}{}", "[".repeat(10_000), "]".repeat(10_000));
+        let errors = validate_workflow_json(&json).unwrap_err();
+        assert!(errors.errors[0].message.contains("nested"));
+    }
+
     #[test]
     fn test_validate_duplicate_task_ids() {
         let json = r#"{
@@ -197,10 +477,185 @@ mod tests {
         assert!(result.is_err());
 
         let errors = result.unwrap_err();
-        assert!(errors
+        assert_eq!(errors.errors.len(), 1);
+        assert_eq!(errors.errors[0].code, ValidationCode::DuplicateTaskId);
+        assert_eq!(errors.errors[0].path, "/tasks/0/next_tasks/0/id");
+        assert!(errors.errors[0].message.contains("Duplicate task ID"));
+    }
+
+    #[test]
+    fn test_validate_reports_codes_and_pointers() {
+        let errors = validate_workflow_json("{ invalid json }").unwrap_err();
+        assert_eq!(errors.errors[0].code, ValidationCode::InvalidJson);
+        assert_eq!(errors.errors[0].path, "");
+
+        let json = r#"{"id": "test", "name": "Test", "tasks": [{"id": "a", "name": "A"}]}"#;
+        let errors = validate_workflow_json(json).unwrap_err();
+        let missing = &errors.errors[0];
+        assert_eq!(missing.code, ValidationCode::MissingField);
+        assert_eq!(missing.severity, Severity::Error);
+        assert_eq!(missing.path, "/tasks/0/function");
+
+        let json = r#"{"id": "test", "name": 5, "tasks": []}"#;
+        let errors = validate_workflow_json(json).unwrap_err();
+        assert_eq!(errors.errors[0].code, ValidationCode::InvalidType);
+        assert_eq!(errors.errors[0].path, "/name");
+    }
+
+    #[test]
+    fn test_unknown_fields_offer_removal() {
+        let json = r#"{
+            "id": "test",
+            "name": "Test",
+            "parameters": [
+                {"name": "env", "type": "string", "requried": true, "a/b": 1}
+            ],
+            "tasks": [{
+                "id": "a",
+                "name": "A",
+                "function": {"name": "cli_command", "input": {"command": "echo"}}
+            }]
+        }"#;
+        let errors = validate_workflow_json(json).unwrap_err();
+        let unknown: Vec<_> = errors
+            .errors
+            .iter()
+            .filter(|e| e.code == ValidationCode::UnknownField)
+            .collect();
+        assert_eq!(unknown.len(), 2);
+        assert!(unknown.iter().any(|e| e.path == "/parameters/0/requried"));
+        assert!(unknown.iter().any(|e| e.path == "/parameters/0/a~1b"));
+
+        let mut document: Value = serde_json::from_str(json).unwrap();
+        for error in &unknown {
+            assert!(error.fix.as_ref().unwrap().apply(&mut document));
+        }
+        assert!(validate_workflow_json(&document.to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_duplicate_task_ids_offer_unique_rename() {
+        let task = |id: &str| {
+            serde_json::json!({
+                "id": id,
+                "name": "Step",
+                "function": {"name": "cli_command", "input": {"command": "echo"}}
+            })
+        };
+        let document = serde_json::json!({
+            "id": "test",
+            "name": "Test",
+            "tasks": [task("build"), task("build"), task("build_2"), task("build")]
+        });
+        let mut source = document.to_string();
+
+        let errors = validate_workflow_json(&source).unwrap_err();
+        let renames: Vec<_> = errors
             .errors
             .iter()
-            .any(|e| e.message.contains("Duplicate task ID")));
+            .map(|e| match &e.fix {
+                Some(QuickFix::Replace { value, .. }) => value.as_str().unwrap().to_string(),
+                other => panic!("expected a rename, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(renames, vec!["build_3", "build_4"]);
+
+        for error in &errors.errors {
+            source = error
+                .fix
+                .as_ref()
+                .unwrap()
+                .apply_to_source(&source)
+                .unwrap();
+        }
+        assert!(validate_workflow_json(&source).is_ok());
+    }
+
+    #[test]
+    fn test_missing_or_empty_id_offers_generated_id() {
+        let json = r#"{
+            "id": "test",
+            "name": "Test",
+            "tasks": [
+                {"id": "deploy_app", "name": "Deploy", "function": {"name": "cli_command", "input": {"command": "echo"}}},
+                {"name": "Deploy App!", "function": {"name": "cli_command", "input": {"command": "echo"}}},
+                {"id": "", "name": "", "function": {"name": "cli_command", "input": {"command": "echo"}}}
+            ]
+        }"#;
+        let errors = validate_workflow_json(json).unwrap_err();
+
+        let missing = errors
+            .errors
+            .iter()
+            .find(|e| e.path == "/tasks/1/id")
+            .unwrap();
+        assert_eq!(missing.code, ValidationCode::MissingField);
+        assert_eq!(
+            missing.fix,
+            Some(QuickFix::Insert {
+                pointer: "/tasks/1/id".to_string(),
+                value: Value::from("deploy_app_2"),
+            })
+        );
+
+        let empty = errors
+            .errors
+            .iter()
+            .find(|e| e.path == "/tasks/2/id")
+            .unwrap();
+        assert_eq!(
+            empty.fix,
+            Some(QuickFix::Replace {
+                pointer: "/tasks/2/id".to_string(),
+                value: Value::from("task"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_unquoted_template_offers_quoting() {
+        let json = r#"{
+            "id": "test",
+            "name": "Test",
+            "tasks": [{
+                "id": "a",
+                "name": "Say {{greeting}}",
+                "function": {"name": "cli_command", "input": {"command": {{command}}}}
+            }]
+        }"#;
+        let errors = validate_workflow_json(json).unwrap_err();
+        assert_eq!(errors.errors.len(), 1);
+        let error = &errors.errors[0];
+        assert_eq!(error.code, ValidationCode::UnquotedTemplate);
+        assert!(error.message.contains("{{command}} at line 7"));
+
+        let fixed = error.fix.as_ref().unwrap().apply_to_source(json).unwrap();
+        assert!(fixed.contains(r#""command": "{{command}}""#));
+        assert!(validate_workflow_json(&fixed).is_ok());
+    }
+
+    #[test]
+    fn test_quick_fix_rejects_stale_pointer() {
+        let mut document = serde_json::json!({"tasks": [{"id": "a"}]});
+        let original = document.clone();
+
+        let remove = QuickFix::Remove {
+            pointer: "/tasks/3/id".to_string(),
+        };
+        assert!(!remove.apply(&mut document));
+        let insert = QuickFix::Insert {
+            pointer: "/tasks/0/id".to_string(),
+            value: Value::from("b"),
+        };
+        assert!(!insert.apply(&mut document));
+        assert_eq!(document, original);
+
+        let replace = QuickFix::Replace {
+            pointer: "/tasks/0/id".to_string(),
+            value: Value::from("b"),
+        };
+        assert!(replace.apply(&mut document));
+        assert_eq!(document["tasks"][0]["id"], "b");
     }
 
     #[test]
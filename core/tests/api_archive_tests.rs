@@ -0,0 +1,142 @@
+use chrono::{Duration, Utc};
+use s_e_e_core::{
+    archive_execution, archive_executions_older_than, get_global_store, init_test_store,
+    list_archived_executions, restore_archived_execution, CoreError, TaskExecution,
+    TaskExecutionStatus, WorkflowExecution, WorkflowExecutionStatus,
+};
+use serial_test::serial;
+
+fn save_execution(
+    rt: &tokio::runtime::Runtime,
+    status: WorkflowExecutionStatus,
+    age: Duration,
+) -> WorkflowExecution {
+    let store = get_global_store().unwrap();
+    let id = uuid::Uuid::new_v4().to_string();
+    let created_at = Utc::now() - age;
+    let task = TaskExecution {
+        workflow_id: id.clone(),
+        name: "Build".to_string(),
+        status: TaskExecutionStatus::Complete,
+        output: Some("built".repeat(2000)),
+        created_at,
+        completed_at: Some(created_at),
+        ..Default::default()
+    };
+    let execution = WorkflowExecution {
+        id,
+        workflow_name: "Nightly".to_string(),
+        status,
+        created_at,
+        completed_at: Some(created_at),
+        tasks: vec![task.clone()],
+        ..Default::default()
+    };
+    rt.block_on(store.save_task_execution(task)).unwrap();
+    rt.block_on(store.save_workflow_execution(execution.clone()))
+        .unwrap();
+    execution
+}
+
+#[test]
+#[serial]
+fn test_archive_moves_only_old_finished_executions() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    let dir = tempfile::tempdir().unwrap();
+
+    let old = save_execution(&rt, WorkflowExecutionStatus::Complete, Duration::days(40));
+    let recent = save_execution(&rt, WorkflowExecutionStatus::Failed, Duration::days(2));
+    let waiting = save_execution(
+        &rt,
+        WorkflowExecutionStatus::WaitingForInput,
+        Duration::days(40),
+    );
+
+    let archived = rt
+        .block_on(archive_executions_older_than(
+            dir.path(),
+            Duration::days(30),
+        ))
+        .unwrap();
+    let entry = archived.iter().find(|e| e.id == old.id).unwrap();
+    assert!(archived
+        .iter()
+        .all(|e| e.id != recent.id && e.id != waiting.id));
+    assert_eq!(entry.task_count, 1);
+    assert!(entry.size_bytes > 0);
+    assert!(dir.path().join(&entry.file_name).exists());
+
+    assert!(rt
+        .block_on(store.get_workflow_execution(&old.id))
+        .unwrap()
+        .is_none());
+    assert!(rt
+        .block_on(store.get_tasks_for_workflow(&old.id))
+        .unwrap()
+        .is_empty());
+    for kept in [&recent, &waiting] {
+        assert!(rt
+            .block_on(store.get_workflow_execution(&kept.id))
+            .unwrap()
+            .is_some());
+    }
+
+    let listed = rt.block_on(list_archived_executions()).unwrap();
+    assert!(listed.iter().any(|e| e.id == old.id));
+}
+
+#[test]
+#[serial]
+fn test_restore_archived_execution() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    let dir = tempfile::tempdir().unwrap();
+
+    let execution = save_execution(&rt, WorkflowExecutionStatus::Failed, Duration::days(90));
+    let entry = rt
+        .block_on(archive_execution(dir.path(), &execution.id))
+        .unwrap();
+
+    let restored = rt
+        .block_on(restore_archived_execution(dir.path(), &execution.id))
+        .unwrap();
+    assert_eq!(restored, execution);
+    assert_eq!(
+        rt.block_on(store.get_workflow_execution(&execution.id))
+            .unwrap(),
+        Some(execution.clone())
+    );
+    assert_eq!(
+        rt.block_on(store.get_tasks_for_workflow(&execution.id))
+            .unwrap(),
+        execution.tasks
+    );
+
+    assert!(!dir.path().join(&entry.file_name).exists());
+    let listed = rt.block_on(list_archived_executions()).unwrap();
+    assert!(listed.iter().all(|e| e.id != execution.id));
+
+    let again = rt.block_on(restore_archived_execution(dir.path(), &execution.id));
+    assert!(matches!(again, Err(CoreError::WorkflowNotFound(_))));
+}
+
+#[test]
+#[serial]
+fn test_archive_rejects_unfinished_execution() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    let dir = tempfile::tempdir().unwrap();
+
+    let running = save_execution(&rt, WorkflowExecutionStatus::Running, Duration::days(90));
+    let result = rt.block_on(archive_execution(dir.path(), &running.id));
+    assert!(matches!(result, Err(CoreError::Archive(_))));
+    assert!(rt
+        .block_on(store.get_workflow_execution(&running.id))
+        .unwrap()
+        .is_some());
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+}
@@ -0,0 +1,133 @@
+use s_e_e_core::{
+    execute_workflow_by_id, get_global_store, import_workflow_bundle, init_test_store,
+    is_workflow_bundle, trust_workflow, workflow_trust_prompt, CoreError, TrustReason,
+    WorkflowBundle,
+};
+use serde_json::json;
+use serial_test::serial;
+
+const SECRET_KEY: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+
+fn workflow_content(id: &str) -> String {
+    json!({
+        "id": id,
+        "name": "Shared",
+        "tasks": [{
+            "id": "clean",
+            "name": "Clean",
+            "function": {
+                "name": "cli_command",
+                "input": { "command": "rm", "args": ["-rf", "build"] }
+            }
+        }]
+    })
+    .to_string()
+}
+
+fn unique_id() -> String {
+    format!("shared-{}", uuid::Uuid::new_v4())
+}
+
+#[test]
+fn test_bundle_signature_round_trip() {
+    let bundle = WorkflowBundle::new(workflow_content("shared"))
+        .signed(SECRET_KEY)
+        .unwrap();
+    let signer = bundle.verify().unwrap();
+    assert_eq!(
+        signer,
+        bundle.signature.as_ref().map(|s| s.public_key.clone())
+    );
+
+    let text = serde_json::to_string(&bundle).unwrap();
+    assert!(is_workflow_bundle(&text));
+    assert!(!is_workflow_bundle(&workflow_content("shared")));
+
+    let unsigned = WorkflowBundle::new(workflow_content("shared"));
+    assert_eq!(unsigned.verify().unwrap(), None);
+}
+
+#[test]
+fn test_tampered_bundles_are_rejected() {
+    let bundle = WorkflowBundle::new(workflow_content("shared"))
+        .signed(SECRET_KEY)
+        .unwrap();
+
+    let mut edited = bundle.clone();
+    edited.content = edited.content.replace("build", "/");
+    assert!(matches!(edited.verify(), Err(CoreError::Bundle(_))));
+
+    // Re-hashing the edited content does not help: the signature still fails.
+    edited.content_hash = s_e_e_core::content_hash(&edited.content);
+    assert!(matches!(edited.verify(), Err(CoreError::Bundle(_))));
+
+    assert!(matches!(
+        WorkflowBundle::new(String::new()).signed("not hex"),
+        Err(CoreError::Bundle(_))
+    ));
+}
+
+#[test]
+#[serial]
+fn test_unsigned_import_needs_trust_before_running() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let id = unique_id();
+    let bundle = serde_json::to_string(&WorkflowBundle::new(workflow_content(&id))).unwrap();
+
+    let workflow = rt
+        .block_on(import_workflow_bundle("shared.json", &bundle))
+        .unwrap();
+    assert_eq!(workflow.id, id);
+
+    let store = get_global_store().unwrap();
+    let provenance = rt
+        .block_on(store.get_workflow_provenance(&id))
+        .unwrap()
+        .unwrap();
+    assert_eq!(provenance.source, "shared.json");
+    assert_eq!(provenance.signed_by, None);
+
+    let prompt = rt.block_on(workflow_trust_prompt(&id)).unwrap().unwrap();
+    assert_eq!(prompt.reason, TrustReason::Unsigned);
+    assert_eq!(prompt.commands, vec!["Would run: rm -rf build".to_string()]);
+
+    let run = rt.block_on(execute_workflow_by_id(&id, None));
+    assert!(matches!(run, Err(CoreError::UntrustedWorkflow(_))));
+
+    rt.block_on(trust_workflow(&id)).unwrap();
+    assert_eq!(rt.block_on(workflow_trust_prompt(&id)).unwrap(), None);
+
+    let again = rt.block_on(import_workflow_bundle("shared.json", &bundle));
+    assert!(matches!(again, Err(CoreError::Bundle(_))));
+}
+
+#[test]
+#[serial]
+fn test_signed_import_prompts_only_after_edits() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let id = unique_id();
+    let bundle = WorkflowBundle::new(workflow_content(&id))
+        .signed(SECRET_KEY)
+        .unwrap();
+
+    let mut workflow = rt
+        .block_on(import_workflow_bundle(
+            "signed.json",
+            &serde_json::to_string(&bundle).unwrap(),
+        ))
+        .unwrap();
+    assert_eq!(rt.block_on(workflow_trust_prompt(&id)).unwrap(), None);
+
+    workflow.content = workflow.content.replace("build", "target");
+    let store = get_global_store().unwrap();
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let prompt = rt.block_on(workflow_trust_prompt(&id)).unwrap().unwrap();
+    assert_eq!(prompt.reason, TrustReason::Modified);
+    assert_eq!(
+        prompt.commands,
+        vec!["Would run: rm -rf target".to_string()]
+    );
+}
@@ -0,0 +1,115 @@
+use s_e_e_core::{
+    execute_workflow_by_id, get_global_store, init_test_store, resume_workflow_execution,
+    CoreError, ExecutionCheckpoint, TaskExecutionStatus, WorkflowDefinition, WorkflowExecution,
+    WorkflowExecutionStatus,
+};
+use serde_json::json;
+use serial_test::serial;
+
+/// build → deploy, where deploy echoes what build produced. Build fails if it
+/// runs again, so a resume that repeats it shows.
+fn release_content() -> serde_json::Value {
+    json!({
+        "id": "checkpoint-release",
+        "name": "Checkpoint Release",
+        "tasks": [{
+            "id": "build",
+            "name": "Build",
+            "function": { "name": "cli_command", "input": { "command": "false" } },
+            "next_tasks": [{
+                "id": "deploy",
+                "name": "Deploy",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "echo", "args": ["${data.build}"] }
+                }
+            }]
+        }]
+    })
+}
+
+/// What a run that died after `build` leaves behind once startup has audited
+/// it: a failed execution with no tasks, and the checkpoint.
+fn save_interrupted_run(rt: &tokio::runtime::Runtime, execution_id: &str) {
+    let store = get_global_store().unwrap();
+    let execution = WorkflowExecution {
+        id: execution_id.to_string(),
+        workflow_name: "Checkpoint Release".to_string(),
+        workflow_snapshot: release_content(),
+        status: WorkflowExecutionStatus::Failed,
+        errors: vec!["Workflow was interrupted by application shutdown".to_string()],
+        ..Default::default()
+    };
+    rt.block_on(store.save_workflow_execution(execution))
+        .unwrap();
+    let checkpoint = ExecutionCheckpoint {
+        execution_id: execution_id.to_string(),
+        completed_tasks: vec!["build".to_string()],
+        skipped_tasks: Vec::new(),
+        data: json!({ "build": "v1.2.3" }),
+        scratch: Default::default(),
+        saved_at: chrono::Utc::now(),
+    };
+    rt.block_on(store.save_execution_checkpoint(&checkpoint))
+        .unwrap();
+}
+
+#[test]
+#[serial]
+fn test_interrupted_run_resumes_from_checkpoint() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    save_interrupted_run(&rt, "exec-interrupted");
+
+    let result = rt
+        .block_on(resume_workflow_execution("exec-interrupted", None))
+        .unwrap();
+    assert!(result.success, "{:?}", result.errors);
+
+    let execution = rt
+        .block_on(store.get_workflow_execution("exec-interrupted"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(execution.status, WorkflowExecutionStatus::Complete);
+    assert!(execution.errors.is_empty());
+    let build = execution.tasks.iter().find(|t| t.id == "build").unwrap();
+    assert_eq!(build.status, TaskExecutionStatus::Complete);
+    let deploy = execution.tasks.iter().find(|t| t.id == "deploy").unwrap();
+    assert_eq!(deploy.output.as_deref(), Some("Output: v1.2.3\n"));
+    assert!(rt
+        .block_on(store.get_execution_checkpoint("exec-interrupted"))
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+#[serial]
+fn test_failed_run_without_checkpoint_is_not_resumed() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let workflow = WorkflowDefinition {
+        id: "checkpoint-release".to_string(),
+        name: "Checkpoint Release".to_string(),
+        content: release_content().to_string(),
+        ..Default::default()
+    };
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let first = rt
+        .block_on(execute_workflow_by_id("checkpoint-release", None))
+        .unwrap();
+    assert!(!first.success);
+    assert!(rt
+        .block_on(store.get_execution_checkpoint(&first.execution_id))
+        .unwrap()
+        .is_none());
+
+    let error = rt
+        .block_on(resume_workflow_execution(&first.execution_id, None))
+        .unwrap_err();
+    assert!(matches!(error, CoreError::Execution(_)), "{:?}", error);
+}
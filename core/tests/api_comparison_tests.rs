@@ -0,0 +1,163 @@
+use chrono::{DateTime, Duration, Utc};
+use s_e_e_core::{
+    compare_with_previous_run, get_global_store, init_test_store, CoreError, TaskExecution,
+    TaskExecutionStatus, WorkflowExecution, WorkflowExecutionStatus,
+};
+use serial_test::serial;
+
+fn task(
+    id: &str,
+    status: TaskExecutionStatus,
+    started: DateTime<Utc>,
+    seconds: i64,
+    output: &str,
+) -> TaskExecution {
+    TaskExecution {
+        id: id.to_string(),
+        name: id.to_uppercase(),
+        status,
+        output: Some(output.to_string()),
+        created_at: started,
+        completed_at: Some(started + Duration::seconds(seconds)),
+        ..Default::default()
+    }
+}
+
+fn run(
+    workflow_id: Option<&str>,
+    started: DateTime<Utc>,
+    status: WorkflowExecutionStatus,
+    tasks: Vec<TaskExecution>,
+) -> WorkflowExecution {
+    WorkflowExecution {
+        workflow_name: "Nightly".to_string(),
+        workflow_id: workflow_id.map(str::to_string),
+        status,
+        created_at: started,
+        completed_at: Some(started + Duration::seconds(30)),
+        tasks,
+        ..Default::default()
+    }
+}
+
+#[test]
+#[serial]
+fn test_compare_with_previous_run_highlights_regressions() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+
+    let workflow_id = uuid::Uuid::new_v4().to_string();
+    let now = Utc::now();
+    let older = run(
+        Some(&workflow_id),
+        now - Duration::hours(2),
+        WorkflowExecutionStatus::Failed,
+        vec![task("build", TaskExecutionStatus::Failed, now, 1, "")],
+    );
+    let previous = run(
+        Some(&workflow_id),
+        now - Duration::hours(1),
+        WorkflowExecutionStatus::Complete,
+        vec![
+            task("build", TaskExecutionStatus::Complete, now, 2, "ok"),
+            task("test", TaskExecutionStatus::Complete, now, 10, "42 passed"),
+            task("lint", TaskExecutionStatus::Failed, now, 1, ""),
+            task("retired", TaskExecutionStatus::Complete, now, 1, ""),
+        ],
+    );
+    let other_workflow = run(
+        Some("another-workflow"),
+        now - Duration::minutes(30),
+        WorkflowExecutionStatus::Complete,
+        Vec::new(),
+    );
+    let current = run(
+        Some(&workflow_id),
+        now,
+        WorkflowExecutionStatus::Failed,
+        vec![
+            task("build", TaskExecutionStatus::Complete, now, 10, "ok"),
+            task("test", TaskExecutionStatus::Failed, now, 10, "41 passed"),
+            task("lint", TaskExecutionStatus::Complete, now, 1, ""),
+        ],
+    );
+    for execution in [&older, &previous, &other_workflow, &current] {
+        rt.block_on(store.save_workflow_execution(execution.clone()))
+            .unwrap();
+    }
+
+    let comparison = rt
+        .block_on(compare_with_previous_run(&current.id))
+        .unwrap()
+        .unwrap();
+    assert_eq!(comparison.previous_execution_id, previous.id);
+    assert_eq!(
+        comparison.previous_status,
+        WorkflowExecutionStatus::Complete
+    );
+    assert_eq!(comparison.current_status, WorkflowExecutionStatus::Failed);
+
+    let ids: Vec<_> = comparison
+        .tasks
+        .iter()
+        .map(|t| t.task_id.as_str())
+        .collect();
+    assert_eq!(ids, ["build", "test", "lint", "retired"]);
+
+    let [build, test, lint, retired] = &comparison.tasks[..] else {
+        panic!("expected four tasks");
+    };
+    assert!(build.slower() && !build.newly_failing() && !build.output_changed);
+    assert!(test.newly_failing() && test.output_changed && !test.slower());
+    assert!(lint.fixed() && !lint.regressed());
+    assert_eq!(retired.current_status, None);
+    assert!(!retired.regressed());
+
+    let regressions: Vec<_> = comparison
+        .regressions()
+        .map(|t| t.task_id.as_str())
+        .collect();
+    assert_eq!(regressions, ["build", "test"]);
+    let failing: Vec<_> = comparison
+        .newly_failing()
+        .map(|t| t.task_id.as_str())
+        .collect();
+    assert_eq!(failing, ["test"]);
+
+    let first = rt.block_on(compare_with_previous_run(&older.id)).unwrap();
+    assert!(first.is_none());
+}
+
+#[test]
+#[serial]
+fn test_compare_matches_untracked_runs_by_name() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+
+    let now = Utc::now();
+    let previous = run(
+        None,
+        now - Duration::minutes(5),
+        WorkflowExecutionStatus::Complete,
+        Vec::new(),
+    );
+    let current = run(None, now, WorkflowExecutionStatus::Complete, Vec::new());
+    for execution in [&previous, &current] {
+        rt.block_on(store.save_workflow_execution(execution.clone()))
+            .unwrap();
+    }
+
+    let comparison = rt
+        .block_on(compare_with_previous_run(&current.id))
+        .unwrap()
+        .unwrap();
+    assert_eq!(comparison.previous_execution_id, previous.id);
+    assert!(comparison.tasks.is_empty());
+
+    let missing = rt.block_on(compare_with_previous_run("missing"));
+    assert!(matches!(missing, Err(CoreError::WorkflowNotFound(_))));
+}
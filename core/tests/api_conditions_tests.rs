@@ -0,0 +1,78 @@
+use s_e_e_core::{
+    confirm_manual_task, execute_workflow_by_id, get_global_store, init_test_store, ManualOutcome,
+    TaskExecutionStatus, WorkflowDefinition, WorkflowExecutionStatus,
+};
+use serial_test::serial;
+
+/// A manual review next to a cleanup task whose condition never holds.
+fn conditional_workflow() -> WorkflowDefinition {
+    WorkflowDefinition {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: "Conditional".to_string(),
+        content: r#"{
+            "id": "conditional",
+            "name": "Conditional",
+            "tasks": [
+                {
+                    "id": "review",
+                    "name": "Review",
+                    "function": { "name": "manual", "input": { "instructions": "Approve" } }
+                },
+                {
+                    "id": "cleanup",
+                    "name": "Cleanup",
+                    "when": "data.review.outcome == \"skipped\"",
+                    "function": {
+                        "name": "cli_command",
+                        "input": { "command": "echo", "args": ["cleaned"] }
+                    }
+                }
+            ]
+        }"#
+        .to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+#[serial]
+fn test_skipped_status_is_persisted_and_kept_on_resume() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let workflow = conditional_workflow();
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let execution_id = rt
+        .block_on(execute_workflow_by_id(&workflow.id, None))
+        .unwrap()
+        .execution_id;
+    let status_of = |task_id: &str| {
+        rt.block_on(store.get_workflow_execution(&execution_id))
+            .unwrap()
+            .unwrap()
+            .tasks
+            .into_iter()
+            .find(|task| task.id == task_id)
+            .map(|task| task.status)
+    };
+    assert_eq!(status_of("cleanup"), Some(TaskExecutionStatus::Skipped));
+
+    rt.block_on(confirm_manual_task(
+        &execution_id,
+        "review",
+        ManualOutcome::Done,
+        "alice",
+        None,
+    ))
+    .unwrap();
+
+    let execution = rt
+        .block_on(store.get_workflow_execution(&execution_id))
+        .unwrap()
+        .unwrap();
+    assert_eq!(execution.status, WorkflowExecutionStatus::Complete);
+    assert_eq!(status_of("cleanup"), Some(TaskExecutionStatus::Skipped));
+    assert_eq!(status_of("review"), Some(TaskExecutionStatus::Complete));
+}
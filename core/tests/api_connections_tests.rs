@@ -0,0 +1,115 @@
+use s_e_e_core::{
+    db_connection_from_settings, execute_workflow_by_id, get_global_store, init_test_store,
+    message_connection_from_settings, AppSettings, DbConnectionSettings, MessageBackend,
+    MessageBroker, MessageConnectionSettings, WorkflowDefinition,
+};
+use serial_test::serial;
+
+fn local_connection() -> DbConnectionSettings {
+    DbConnectionSettings {
+        name: "local".to_string(),
+        url: "sqlite::memory:".to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_connection_settings_are_normalized() {
+    let settings = DbConnectionSettings {
+        name: " warehouse ".to_string(),
+        url: "postgres://report@db/sales".to_string(),
+        password_env: Some("  ".to_string()),
+        allowed_statements: vec![" SELECT".to_string(), String::new()],
+        max_rows: Some(20),
+    };
+    let connection = db_connection_from_settings(&settings);
+    assert_eq!(connection.name, "warehouse");
+    assert_eq!(connection.password_env, None);
+    assert_eq!(connection.allowed_statements, vec!["select".to_string()]);
+    assert_eq!(connection.max_rows, Some(20));
+}
+
+#[test]
+fn test_message_connection_settings_map_to_backend() {
+    let settings = MessageConnectionSettings {
+        name: "events".to_string(),
+        broker: MessageBroker::Amqp,
+        url: " amqp://see@mq.internal:5672/%2f ".to_string(),
+        password_env: Some("MQ_PASSWORD".to_string()),
+    };
+    let connection = message_connection_from_settings(&settings);
+    assert_eq!(connection.backend, MessageBackend::Amqp);
+    assert_eq!(connection.url, "amqp://see@mq.internal:5672/%2f");
+    assert_eq!(connection.password_env.as_deref(), Some("MQ_PASSWORD"));
+}
+
+#[test]
+fn test_duplicate_connection_names_are_invalid() {
+    let settings = AppSettings {
+        db_connections: vec![local_connection(), local_connection()],
+        ..Default::default()
+    };
+    assert!(settings.validate().is_err());
+
+    let events = MessageConnectionSettings {
+        name: "events".to_string(),
+        url: "broker:9092".to_string(),
+        ..Default::default()
+    };
+    let settings = AppSettings {
+        message_connections: vec![events.clone(), events],
+        ..Default::default()
+    };
+    assert!(settings.validate().is_err());
+}
+
+#[test]
+#[serial]
+fn test_workflow_queries_connection_from_settings() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let settings = AppSettings {
+        db_connections: vec![local_connection()],
+        ..Default::default()
+    };
+    rt.block_on(store.save_settings(&settings)).unwrap();
+
+    let workflow = WorkflowDefinition {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: "Query".to_string(),
+        content: r#"{
+            "id": "query",
+            "name": "Query",
+            "tasks": [
+                {
+                    "id": "count",
+                    "name": "Count",
+                    "function": {
+                        "name": "db_query",
+                        "input": { "connection": "local", "query": "SELECT 40 + ? AS answer", "params": [2] }
+                    }
+                }
+            ]
+        }"#
+        .to_string(),
+        ..Default::default()
+    };
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let result = rt
+        .block_on(execute_workflow_by_id(&workflow.id, None))
+        .unwrap();
+    assert!(result.success, "{:?}", result.errors);
+    let execution = rt
+        .block_on(store.get_workflow_execution(&result.execution_id))
+        .unwrap()
+        .unwrap();
+    let task = execution
+        .tasks
+        .iter()
+        .find(|task| task.id == "count")
+        .unwrap();
+    assert!(task.output.as_deref().unwrap_or_default().contains("42"));
+}
@@ -0,0 +1,127 @@
+use s_e_e_core::{
+    context_at, context_steps, execute_workflow_by_id, get_context_at, get_global_store,
+    init_test_store, AuditChange, AuditChangeOp, AuditEvent, CoreError, WorkflowDefinition,
+};
+use serde_json::json;
+use serial_test::serial;
+
+fn event(task_id: &str, changes: Vec<AuditChange>) -> AuditEvent {
+    AuditEvent {
+        task_id: task_id.to_string(),
+        message: format!("{} finished", task_id),
+        changes_count: changes.len(),
+        changes,
+        ..Default::default()
+    }
+}
+
+fn set(path: &str, value: serde_json::Value) -> AuditChange {
+    AuditChange {
+        op: AuditChangeOp::Add,
+        path: path.to_string(),
+        before: None,
+        after: Some(value),
+    }
+}
+
+#[test]
+fn test_context_at_replays_earlier_tasks() {
+    let trail = vec![
+        event("fetch", vec![set("/fetch", json!({"rows": 3}))]),
+        event(
+            "transform",
+            vec![
+                AuditChange {
+                    op: AuditChangeOp::Replace,
+                    path: "/fetch/rows".to_string(),
+                    before: Some(json!(3)),
+                    after: Some(json!(4)),
+                },
+                set("/transform", json!("ok")),
+            ],
+        ),
+        event("publish", Vec::new()),
+    ];
+
+    assert_eq!(context_steps(&trail), vec!["fetch", "transform", "publish"]);
+
+    let first = context_at(&trail, "fetch").unwrap();
+    assert_eq!(first.data, json!({}));
+    assert_eq!(first.changes_count, 1);
+
+    let transform = context_at(&trail, "transform").unwrap();
+    assert_eq!(transform.data, json!({"fetch": {"rows": 3}}));
+
+    let publish = context_at(&trail, "publish").unwrap();
+    assert_eq!(
+        publish.data,
+        json!({"fetch": {"rows": 4}, "transform": "ok"})
+    );
+    assert!(!publish.incomplete);
+
+    assert!(context_at(&trail, "never_ran").is_none());
+}
+
+#[test]
+fn test_context_at_flags_truncated_diffs() {
+    let mut truncated = event("fetch", vec![set("/fetch", json!("…"))]);
+    truncated.changes_truncated = true;
+    let trail = vec![truncated, event("publish", Vec::new())];
+
+    assert!(!context_at(&trail, "fetch").unwrap().incomplete);
+    assert!(context_at(&trail, "publish").unwrap().incomplete);
+}
+
+#[test]
+#[serial]
+fn test_get_context_at_for_a_stored_execution() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+
+    let workflow = WorkflowDefinition {
+        id: "two_steps".to_string(),
+        name: "Two steps".to_string(),
+        content: json!({
+            "id": "two_steps",
+            "name": "Two steps",
+            "tasks": [{
+                "id": "first",
+                "name": "First",
+                "function": { "name": "cli_command", "input": { "command": "echo", "args": ["one"] } },
+                "next_tasks": [{
+                    "id": "second",
+                    "name": "Second",
+                    "function": { "name": "cli_command", "input": { "command": "echo", "args": ["two"] } }
+                }]
+            }]
+        })
+        .to_string(),
+        ..Default::default()
+    };
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+    let result = rt
+        .block_on(execute_workflow_by_id("two_steps", None))
+        .unwrap();
+
+    let first = rt
+        .block_on(get_context_at(&result.execution_id, "first"))
+        .unwrap();
+    assert_eq!(first.data, json!({}));
+
+    let second = rt
+        .block_on(get_context_at(&result.execution_id, "second"))
+        .unwrap();
+    assert!(second.data.get("first").is_some());
+    assert!(second.data.get("second").is_none());
+
+    assert!(matches!(
+        rt.block_on(get_context_at(&result.execution_id, "missing")),
+        Err(CoreError::TaskNotFound(_))
+    ));
+    assert!(matches!(
+        rt.block_on(get_context_at("missing", "first")),
+        Err(CoreError::WorkflowNotFound(_))
+    ));
+}
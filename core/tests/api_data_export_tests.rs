@@ -0,0 +1,151 @@
+use chrono::{DateTime, Duration, Utc};
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use s_e_e_core::{
+    execution_columns, export_execution_data, get_global_store, init_test_store, task_columns,
+    CoreError, ExportFormat, TaskExecution, TaskExecutionStatus, TaskFailureReason,
+    WorkflowExecution, WorkflowExecutionStatus,
+};
+use serial_test::serial;
+
+fn at(time: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(time)
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn save_executions(rt: &tokio::runtime::Runtime) {
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    for (id, created_at) in [
+        ("early", "2024-03-03T12:00:00Z"),
+        ("nightly", "2024-03-04T02:00:00Z"),
+        ("late", "2024-03-05T00:00:00Z"),
+    ] {
+        let created_at = at(created_at);
+        let tasks = vec![
+            TaskExecution {
+                id: "build".to_string(),
+                workflow_id: id.to_string(),
+                name: "Build, test".to_string(),
+                status: TaskExecutionStatus::Complete,
+                created_at,
+                completed_at: Some(created_at + Duration::milliseconds(1500)),
+                output_bytes: Some(42),
+                cache_hit: true,
+                ..Default::default()
+            },
+            TaskExecution {
+                id: "deploy".to_string(),
+                workflow_id: id.to_string(),
+                name: "Deploy".to_string(),
+                status: TaskExecutionStatus::Failed,
+                created_at: created_at + Duration::seconds(2),
+                completed_at: Some(created_at + Duration::seconds(5)),
+                failure_reason: Some(TaskFailureReason::Timeout),
+                ..Default::default()
+            },
+        ];
+        rt.block_on(store.save_workflow_execution(WorkflowExecution {
+            id: id.to_string(),
+            workflow_id: Some("release".to_string()),
+            workflow_name: "Release".to_string(),
+            status: WorkflowExecutionStatus::Failed,
+            created_at,
+            completed_at: Some(created_at + Duration::seconds(5)),
+            tasks,
+            labels: vec!["v1.2".to_string(), "hotfix".to_string()],
+            ..Default::default()
+        }))
+        .unwrap();
+    }
+}
+
+#[test]
+#[serial]
+fn test_export_csv_covers_the_range() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    save_executions(&rt);
+    let dir = tempfile::tempdir().unwrap();
+
+    let export = rt
+        .block_on(export_execution_data(
+            at("2024-03-04T00:00:00Z"),
+            at("2024-03-05T00:00:00Z"),
+            ExportFormat::Csv,
+            dir.path(),
+        ))
+        .unwrap();
+
+    assert_eq!((export.executions, export.tasks), (1, 2));
+    let executions = std::fs::read_to_string(dir.path().join("executions.csv")).unwrap();
+    let mut lines = executions.lines();
+    assert_eq!(lines.next().unwrap(), execution_columns().join(","));
+    assert_eq!(
+        lines.next().unwrap(),
+        "nightly,release,Release,failed,2024-03-04T02:00:00.000Z,\
+         2024-03-04T02:00:05.000Z,5000,2,1,1,v1.2;hotfix,"
+    );
+    assert!(lines.next().is_none());
+
+    let tasks = std::fs::read_to_string(dir.path().join("tasks.csv")).unwrap();
+    let lines: Vec<&str> = tasks.lines().collect();
+    assert_eq!(lines[0], task_columns().join(","));
+    assert_eq!(
+        lines[1],
+        "nightly,build,\"Build, test\",complete,2024-03-04T02:00:00.000Z,\
+         2024-03-04T02:00:01.500Z,1500,true,42,"
+    );
+    assert!(lines[2].ends_with(",3000,false,,timeout"));
+}
+
+#[test]
+#[serial]
+fn test_export_parquet_has_the_same_columns() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    save_executions(&rt);
+    let dir = tempfile::tempdir().unwrap();
+
+    rt.block_on(export_execution_data(
+        at("2024-03-01T00:00:00Z"),
+        at("2024-04-01T00:00:00Z"),
+        ExportFormat::Parquet,
+        dir.path(),
+    ))
+    .unwrap();
+
+    for (file, columns, rows) in [
+        ("executions.parquet", execution_columns(), 3),
+        ("tasks.parquet", task_columns(), 6),
+    ] {
+        let reader =
+            SerializedFileReader::new(std::fs::File::open(dir.path().join(file)).unwrap()).unwrap();
+        let metadata = reader.metadata().file_metadata();
+        assert_eq!(metadata.num_rows(), rows);
+        let names: Vec<&str> = metadata
+            .schema_descr()
+            .columns()
+            .iter()
+            .map(|column| column.name())
+            .collect();
+        assert_eq!(names, columns);
+    }
+}
+
+#[test]
+#[serial]
+fn test_export_rejects_an_empty_range() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+
+    let result = rt.block_on(export_execution_data(
+        at("2024-03-05T00:00:00Z"),
+        at("2024-03-04T00:00:00Z"),
+        ExportFormat::Csv,
+        dir.path(),
+    ));
+
+    assert!(matches!(result, Err(CoreError::InputValidationFailed(_))));
+}
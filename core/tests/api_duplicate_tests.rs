@@ -0,0 +1,82 @@
+use s_e_e_core::{
+    copy_name, duplicate_prompt, duplicate_workflow, get_global_store, init_test_store, Prompt,
+    WorkflowDefinition,
+};
+use serial_test::serial;
+use std::collections::HashSet;
+
+#[test]
+fn test_copy_name() {
+    let taken = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+    assert_eq!(copy_name("Build", &HashSet::new()), "Build (copy)");
+    assert_eq!(
+        copy_name("Build", &taken(&["Build (copy)"])),
+        "Build (copy 2)"
+    );
+    assert_eq!(
+        copy_name("Build (copy)", &taken(&["Build (copy)"])),
+        "Build (copy 2)"
+    );
+    assert_eq!(
+        copy_name(
+            "Build (copy 2)",
+            &taken(&["Build (copy)", "Build (copy 2)"])
+        ),
+        "Build (copy 3)"
+    );
+    assert_eq!(
+        copy_name("Build (copycat)", &HashSet::new()),
+        "Build (copycat) (copy)"
+    );
+}
+
+#[test]
+#[serial]
+fn test_duplicate_workflow_and_prompt() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    rt.block_on(store.save_workflow(&WorkflowDefinition {
+        id: "build".to_string(),
+        name: "Build".to_string(),
+        content: r#"{"id":"build","name":"Build","tasks":[]}"#.to_string(),
+        is_default: true,
+        ..Default::default()
+    }))
+    .unwrap();
+    rt.block_on(store.save_prompt(&Prompt {
+        id: "greeting".to_string(),
+        name: "Greeting".to_string(),
+        content: "Hello".to_string(),
+        created_at: chrono::Utc::now(),
+        tags: vec!["intro".to_string()],
+    }))
+    .unwrap();
+
+    let first = rt.block_on(duplicate_workflow("build")).unwrap();
+    let second = rt.block_on(duplicate_workflow("build")).unwrap();
+    assert_eq!(first.name, "Build (copy)");
+    assert_eq!(second.name, "Build (copy 2)");
+    assert_ne!(first.id, second.id);
+    assert!(!first.is_default);
+    let content: serde_json::Value = serde_json::from_str(&first.content).unwrap();
+    assert_eq!(content["id"], first.id.as_str());
+    assert_eq!(content["name"], "Build (copy)");
+    assert_eq!(rt.block_on(store.list_workflows()).unwrap().len(), 3);
+    assert!(rt.block_on(duplicate_workflow("missing")).is_err());
+
+    let first = rt.block_on(duplicate_prompt("greeting")).unwrap();
+    let second = rt.block_on(duplicate_prompt("greeting")).unwrap();
+    assert_eq!(
+        (first.id.as_str(), first.name.as_str()),
+        ("greeting-copy", "Greeting (copy)")
+    );
+    assert_eq!(
+        (second.id.as_str(), second.name.as_str()),
+        ("greeting-copy-2", "Greeting (copy 2)")
+    );
+    assert_eq!(first.content, "Hello");
+    assert_eq!(first.tags, vec!["intro"]);
+    assert!(rt.block_on(duplicate_prompt("missing")).is_err());
+}
@@ -0,0 +1,188 @@
+use s_e_e_core::{
+    add_all_example_workflows, add_example_workflow, complete_onboarding, get_example_workflow,
+    get_global_store, init_test_store, list_example_workflows, preview_default_reset,
+    refresh_examples, reset_default_workflow, CoreError, DiffLineKind, WorkflowDefinition,
+};
+use serial_test::serial;
+
+fn remove_examples(rt: &tokio::runtime::Runtime) {
+    let store = get_global_store().unwrap();
+    for example in list_example_workflows().unwrap() {
+        rt.block_on(store.delete_workflow(&example.id)).unwrap();
+    }
+}
+
+#[test]
+fn test_list_example_workflows() {
+    let examples = list_example_workflows().unwrap();
+
+    assert_eq!(examples.len(), 4);
+    for example in &examples {
+        assert!(example.id.starts_with("system:"));
+        let content: serde_json::Value = serde_json::from_str(&example.content).unwrap();
+        assert!(
+            content.get("tasks").is_some(),
+            "{} has no tasks",
+            example.id
+        );
+    }
+}
+
+#[test]
+fn test_get_example_workflow_not_found() {
+    match get_example_workflow("system:missing") {
+        Err(CoreError::WorkflowNotFound(id)) => assert_eq!(id, "system:missing"),
+        other => panic!("Expected WorkflowNotFound, got: {:?}", other),
+    }
+}
+
+#[test]
+#[serial]
+fn test_add_example_workflow() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    remove_examples(&rt);
+
+    let added = rt
+        .block_on(add_example_workflow("system:code-review"))
+        .unwrap();
+    assert!(added.is_default);
+    assert!(!added.is_edited);
+
+    let store = get_global_store().unwrap();
+    let saved = rt
+        .block_on(store.get_workflow("system:code-review"))
+        .unwrap();
+    assert_eq!(saved.map(|w| w.name), Some(added.name));
+
+    let duplicate = rt.block_on(add_example_workflow("system:code-review"));
+    assert!(matches!(duplicate, Err(CoreError::Execution(_))));
+}
+
+#[test]
+#[serial]
+fn test_add_all_example_workflows_skips_existing() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    remove_examples(&rt);
+
+    rt.block_on(add_example_workflow("system:deploy-app"))
+        .unwrap();
+    let added = rt.block_on(add_all_example_workflows()).unwrap();
+    assert_eq!(added, 3);
+
+    assert_eq!(rt.block_on(add_all_example_workflows()).unwrap(), 0);
+}
+
+#[test]
+#[serial]
+fn test_complete_onboarding() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    remove_examples(&rt);
+
+    let added = rt.block_on(complete_onboarding(false)).unwrap();
+    assert_eq!(added, 0);
+
+    let store = get_global_store().unwrap();
+    let settings = rt.block_on(store.load_settings()).unwrap().unwrap();
+    assert!(settings.onboarding_complete);
+    let workflows = rt.block_on(store.list_workflows()).unwrap();
+    assert!(workflows.iter().all(|w| !w.id.starts_with("system:")));
+}
+
+fn edit_library_copy(
+    rt: &tokio::runtime::Runtime,
+    id: &str,
+    is_edited: bool,
+) -> WorkflowDefinition {
+    let store = get_global_store().unwrap();
+    let mut workflow = rt.block_on(store.get_workflow(id)).unwrap().unwrap();
+    let mut content: serde_json::Value = serde_json::from_str(&workflow.content).unwrap();
+    content["name"] = serde_json::json!("Changed locally");
+    workflow.content = content.to_string();
+    workflow.is_edited = is_edited;
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+    workflow
+}
+
+#[test]
+#[serial]
+fn test_preview_and_reset_default_workflow() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    remove_examples(&rt);
+
+    let added = rt
+        .block_on(add_example_workflow("system:setup-project"))
+        .unwrap();
+    let preview = rt
+        .block_on(preview_default_reset("system:setup-project"))
+        .unwrap();
+    assert!(!preview.has_changes());
+
+    edit_library_copy(&rt, "system:setup-project", true);
+    let preview = rt
+        .block_on(preview_default_reset("system:setup-project"))
+        .unwrap();
+    assert!(preview.is_edited);
+    assert!(preview.has_changes());
+    assert!(preview
+        .lines
+        .iter()
+        .any(|l| l.kind == DiffLineKind::Removed && l.text.contains("Changed locally")));
+
+    let reset = rt
+        .block_on(reset_default_workflow("system:setup-project"))
+        .unwrap();
+    assert!(!reset.is_edited);
+    assert_eq!(reset.content, added.content);
+    assert_eq!(reset.created_at, added.created_at);
+    assert!(!rt
+        .block_on(preview_default_reset("system:setup-project"))
+        .unwrap()
+        .has_changes());
+}
+
+#[test]
+#[serial]
+fn test_reset_default_workflow_requires_library_copy() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    remove_examples(&rt);
+
+    let result = rt.block_on(reset_default_workflow("system:setup-project"));
+    assert!(matches!(result, Err(CoreError::WorkflowNotFound(_))));
+}
+
+#[test]
+#[serial]
+fn test_refresh_examples_skips_edited_copies() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    remove_examples(&rt);
+
+    rt.block_on(add_example_workflow("system:code-review"))
+        .unwrap();
+    rt.block_on(add_example_workflow("system:deploy-app"))
+        .unwrap();
+    edit_library_copy(&rt, "system:code-review", false);
+    let edited = edit_library_copy(&rt, "system:deploy-app", true);
+
+    let refresh = rt.block_on(refresh_examples()).unwrap();
+    assert_eq!(refresh.updated, vec!["system:code-review".to_string()]);
+    assert_eq!(
+        refresh.skipped_edited,
+        vec!["system:deploy-app".to_string()]
+    );
+
+    let store = get_global_store().unwrap();
+    let kept = rt
+        .block_on(store.get_workflow("system:deploy-app"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(kept.content, edited.content);
+
+    let refresh = rt.block_on(refresh_examples()).unwrap();
+    assert!(refresh.updated.is_empty());
+}
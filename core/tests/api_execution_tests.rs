@@ -1,8 +1,10 @@
 use s_e_e_core::{
-    execute_workflow_by_id, get_global_store, init_test_store, CoreError, OutputCallback,
-    WorkflowDefinition,
+    execute_workflow_by_id, execute_workflow_by_id_profiled, execute_workflow_by_id_with_params,
+    get_global_store, init_test_store, CoreError, OutputCallback, OutputEvent, ProfilePhase,
+    RunBounds, WorkflowDefinition,
 };
 use serial_test::serial;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
@@ -55,6 +57,12 @@ fn test_workflow_execution_flow() {
         Ok(workflow_result) => {
             assert_eq!(workflow_result.workflow_name, "Test Workflow");
             assert!(!workflow_result.execution_id.is_empty());
+
+            let execution = rt
+                .block_on(store.get_workflow_execution(&workflow_result.execution_id))
+                .unwrap()
+                .unwrap();
+            assert_eq!(execution.workflow_id, Some(workflow.id.clone()));
         }
         Err(CoreError::Engine(_)) => {}
         Err(other) => {
@@ -95,7 +103,7 @@ fn test_workflow_execution_with_callback() {
     let callback_called = Arc::new(AtomicBool::new(false));
     let callback_called_clone = callback_called.clone();
 
-    let callback: OutputCallback = Arc::new(move |_msg: String| {
+    let callback: OutputCallback = Arc::new(move |_event: OutputEvent| {
         callback_called_clone.store(true, Ordering::SeqCst);
     });
 
@@ -132,3 +140,173 @@ fn test_invalid_workflow_execution() {
         Ok(_) => panic!("Should have failed for invalid JSON"),
     }
 }
+
+#[test]
+#[serial]
+fn test_workflow_execution_with_parameters() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store())
+        .expect("Failed to initialize test store");
+    let store = get_global_store().unwrap();
+
+    let mut workflow = create_test_workflow();
+    workflow.content = r#"{
+        "id": "param-workflow",
+        "name": "Param Workflow",
+        "parameters": [
+            { "name": "target", "type": "string", "required": true },
+            { "name": "count", "type": "number", "default": 2 }
+        ],
+        "tasks": [
+            {
+                "id": "task-1",
+                "name": "Echo",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "echo", "args": ["hi"] }
+                }
+            }
+        ]
+    }"#
+    .to_string();
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let missing = rt.block_on(execute_workflow_by_id(&workflow.id, None));
+    assert!(matches!(
+        missing,
+        Err(CoreError::Engine(s_e_e_engine::EngineError::Parameter(_)))
+    ));
+
+    let mut params = std::collections::HashMap::new();
+    params.insert("target".to_string(), serde_json::json!("prod"));
+    let result = rt
+        .block_on(execute_workflow_by_id_with_params(
+            &workflow.id,
+            params,
+            None,
+        ))
+        .unwrap();
+
+    let execution = rt
+        .block_on(store.get_workflow_execution(&result.execution_id))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        execution.parameters.get("target"),
+        Some(&serde_json::json!("prod"))
+    );
+    assert_eq!(
+        execution.parameters.get("count"),
+        Some(&serde_json::json!(2))
+    );
+}
+
+#[test]
+#[serial]
+fn test_successful_run_triggers_dependent_workflow() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store())
+        .expect("Failed to initialize test store");
+    let store = get_global_store().unwrap();
+
+    let mut upstream = create_test_workflow();
+    upstream.content = r#"{
+        "id": "trigger-upstream",
+        "name": "Build",
+        "tasks": [
+            {
+                "id": "version",
+                "name": "Version",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "echo", "args": ["1.2.3"] }
+                }
+            }
+        ]
+    }"#
+    .to_string();
+    rt.block_on(store.save_workflow(&upstream)).unwrap();
+
+    let mut downstream = create_test_workflow();
+    downstream.name = "Deploy".to_string();
+    downstream.content = r#"{
+        "id": "trigger-downstream",
+        "name": "Deploy",
+        "triggers": [{ "type": "after_workflow", "workflow_id": "trigger-upstream" }],
+        "parameters": [{ "name": "version", "type": "string", "required": true }],
+        "tasks": [
+            {
+                "id": "deploy",
+                "name": "Deploy",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "echo", "args": ["deploying"] }
+                }
+            }
+        ]
+    }"#
+    .to_string();
+    rt.block_on(store.save_workflow(&downstream)).unwrap();
+
+    let result = rt
+        .block_on(execute_workflow_by_id(&upstream.id, None))
+        .unwrap();
+    assert!(result.success);
+
+    let executions = rt.block_on(store.list_workflow_executions()).unwrap();
+    let triggered = executions
+        .iter()
+        .find(|e| e.triggered_by.as_deref() == Some(result.execution_id.as_str()))
+        .expect("dependent workflow should have run");
+    assert_eq!(triggered.workflow_name, "Deploy");
+    assert_eq!(
+        triggered.parameters.get("version"),
+        Some(&serde_json::json!("1.2.3"))
+    );
+
+    rt.block_on(store.delete_workflow(&downstream.id)).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_profiled_run_reports_where_the_time_went() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store())
+        .expect("Failed to initialize test store");
+    let store = get_global_store().unwrap();
+    let workflow = create_test_workflow();
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let result = rt
+        .block_on(execute_workflow_by_id_profiled(
+            &workflow.id,
+            HashMap::new(),
+            Vec::new(),
+            RunBounds::default(),
+            None,
+        ))
+        .unwrap();
+    assert!(result.success, "{:?}", result.errors);
+
+    let profile = result.profile.expect("profiled run should have a profile");
+    let phases: Vec<ProfilePhase> = profile
+        .summary()
+        .iter()
+        .map(|summary| summary.phase)
+        .collect();
+    assert_eq!(phases, ProfilePhase::ALL);
+    let labels: Vec<&str> = profile
+        .spans
+        .iter()
+        .filter(|span| span.phase == ProfilePhase::Persistence)
+        .map(|span| span.label.as_str())
+        .collect();
+    for label in ["load workflow", "save execution", "save audit events"] {
+        assert!(labels.contains(&label), "{:?}", labels);
+    }
+
+    let unprofiled = rt
+        .block_on(execute_workflow_by_id(&workflow.id, None))
+        .unwrap();
+    assert_eq!(unprofiled.profile, None);
+}
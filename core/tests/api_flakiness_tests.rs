@@ -0,0 +1,145 @@
+use chrono::{Duration, Utc};
+use s_e_e_core::{
+    detect_flaky_tasks, execution_flaky_tasks, flaky_tasks, get_global_store, init_test_store,
+    TaskExecution, TaskExecutionStatus, WorkflowExecution, FLAKINESS_WINDOW,
+};
+use serde_json::json;
+use serial_test::serial;
+
+/// Runs of one workflow whose `check` task passed (`true`) or failed in
+/// order, oldest first, next to a `build` task that always passes.
+fn runs(
+    workflow_id: &str,
+    snapshot: serde_json::Value,
+    outcomes: &[bool],
+) -> Vec<WorkflowExecution> {
+    let start = Utc::now() - Duration::days(1);
+    outcomes
+        .iter()
+        .enumerate()
+        .map(|(i, passed)| {
+            let status = if *passed {
+                TaskExecutionStatus::Complete
+            } else {
+                TaskExecutionStatus::Failed
+            };
+            WorkflowExecution {
+                workflow_name: "Nightly".to_string(),
+                workflow_id: Some(workflow_id.to_string()),
+                workflow_snapshot: snapshot.clone(),
+                created_at: start + Duration::minutes(i as i64),
+                tasks: vec![
+                    TaskExecution {
+                        id: "build".to_string(),
+                        name: "Build".to_string(),
+                        status: TaskExecutionStatus::Complete,
+                        ..Default::default()
+                    },
+                    TaskExecution {
+                        id: "check".to_string(),
+                        name: "Check".to_string(),
+                        status,
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_alternating_task_is_flaky() {
+    let executions = runs(
+        "nightly",
+        json!({ "v": 1 }),
+        &[true, false, true, true, false],
+    );
+
+    let flaky = detect_flaky_tasks(&executions);
+
+    assert_eq!(flaky.len(), 1);
+    let task = &flaky[0];
+    assert_eq!(task.task_id, "check");
+    assert_eq!(task.task_name, "Check");
+    assert_eq!(task.workflow_id.as_deref(), Some("nightly"));
+    assert_eq!((task.runs, task.failures, task.flips), (5, 2, 3));
+    assert_eq!(task.flip_rate(), 0.75);
+}
+
+#[test]
+fn test_single_regression_is_not_flaky() {
+    let executions = runs("nightly", json!({ "v": 1 }), &[true, true, false, false]);
+    assert!(detect_flaky_tasks(&executions).is_empty());
+}
+
+#[test]
+fn test_edited_definition_starts_history_over() {
+    let mut executions = runs("nightly", json!({ "v": 1 }), &[true, false, true, false]);
+    let later = runs("nightly", json!({ "v": 2 }), &[true, true]);
+    let offset = executions.len() as i64;
+    executions.extend(later.into_iter().map(|mut execution| {
+        execution.created_at += Duration::minutes(offset);
+        execution
+    }));
+
+    assert!(detect_flaky_tasks(&executions).is_empty());
+}
+
+#[test]
+fn test_only_recent_runs_count() {
+    let mut outcomes = vec![true, false, true, false];
+    outcomes.extend(std::iter::repeat_n(true, FLAKINESS_WINDOW));
+    let executions = runs("nightly", json!({ "v": 1 }), &outcomes);
+
+    assert!(detect_flaky_tasks(&executions).is_empty());
+}
+
+#[test]
+fn test_flakiest_tasks_come_first() {
+    let mut executions = runs(
+        "sometimes",
+        json!({ "v": 1 }),
+        &[true, false, true, true, true],
+    );
+    executions.extend(runs(
+        "always",
+        json!({ "v": 1 }),
+        &[true, false, true, false, true],
+    ));
+
+    let flaky = detect_flaky_tasks(&executions);
+
+    let workflows: Vec<_> = flaky.iter().map(|t| t.workflow_id.as_deref()).collect();
+    assert_eq!(workflows, [Some("always"), Some("sometimes")]);
+}
+
+#[test]
+#[serial]
+fn test_flaky_tasks_from_store() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+
+    let executions = runs("nightly", json!({ "v": 1 }), &[false, true, false, true]);
+    for execution in &executions {
+        rt.block_on(store.save_workflow_execution(execution.clone()))
+            .unwrap();
+    }
+
+    let top = rt.block_on(flaky_tasks(10)).unwrap();
+    assert_eq!(top.len(), 1);
+    assert_eq!(top[0].task_id, "check");
+    assert!(rt.block_on(flaky_tasks(0)).unwrap().is_empty());
+
+    let at_latest = rt
+        .block_on(execution_flaky_tasks(&executions[3].id))
+        .unwrap();
+    assert_eq!(at_latest, top);
+    // Only the runs up to an execution are considered.
+    let at_second = rt
+        .block_on(execution_flaky_tasks(&executions[1].id))
+        .unwrap();
+    assert!(at_second.is_empty());
+}
@@ -0,0 +1,62 @@
+use s_e_e_core::{
+    apply_handler_policy, handler_policy_from_settings, validate_workflow_json,
+    HandlerPolicySettings, ValidationCode,
+};
+use serial_test::serial;
+
+const WORKFLOW: &str = r#"{
+    "id": "deploy",
+    "name": "Deploy",
+    "tasks": [{
+        "id": "confirm",
+        "name": "Confirm",
+        "function": {"name": "user_input", "input": {"prompt": "Ship it?", "input_type": "string"}},
+        "next_tasks": [{
+            "id": "ship",
+            "name": "Ship",
+            "function": {"name": "cli_command", "input": {"command": "echo"}}
+        }]
+    }]
+}"#;
+
+fn restricted() -> HandlerPolicySettings {
+    HandlerPolicySettings {
+        active_profile: Some("restricted".to_string()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_no_active_profile_means_no_policy() {
+    assert_eq!(
+        handler_policy_from_settings(&HandlerPolicySettings::default()),
+        None
+    );
+
+    let policy = handler_policy_from_settings(&restricted()).unwrap();
+    assert_eq!(policy.profile, "restricted");
+    assert!(policy.denied.contains("cli_command"));
+    assert_eq!(policy.allowed, None);
+}
+
+#[test]
+#[serial]
+fn test_validation_flags_blocked_handler() {
+    apply_handler_policy(&restricted());
+    let result = validate_workflow_json(WORKFLOW);
+    apply_handler_policy(&HandlerPolicySettings::default());
+
+    let errors = result.unwrap_err().errors;
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert_eq!(errors[0].code, ValidationCode::HandlerNotAllowed);
+    assert_eq!(errors[0].path, "/tasks/0/next_tasks/0/function/name");
+    assert!(errors[0].message.contains("denied_handlers"));
+    assert!(errors[0].message.contains("'restricted'"));
+}
+
+#[test]
+#[serial]
+fn test_validation_passes_without_policy() {
+    apply_handler_policy(&HandlerPolicySettings::default());
+    assert!(validate_workflow_json(WORKFLOW).is_ok());
+}
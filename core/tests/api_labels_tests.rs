@@ -0,0 +1,124 @@
+use s_e_e_core::{
+    delete_execution_filter_preset, execute_workflow_by_id_with_labels, get_global_store,
+    init_test_store, list_execution_filter_presets, list_execution_labels,
+    save_execution_filter_preset, set_execution_labels, CoreError, ExecutionFilter,
+    WorkflowDefinition, WorkflowExecutionStatus,
+};
+use serde_json::json;
+use serial_test::serial;
+use std::collections::HashMap;
+
+fn echo_workflow() -> WorkflowDefinition {
+    WorkflowDefinition {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: "Echo".to_string(),
+        content: json!({
+            "id": "echo",
+            "name": "Echo",
+            "tasks": [{
+                "id": "say",
+                "name": "Say",
+                "function": { "name": "cli_command", "input": { "command": "echo", "args": ["hi"] } }
+            }]
+        })
+        .to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+#[serial]
+fn test_labels_set_at_run_time_and_afterwards() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let workflow = echo_workflow();
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let result = rt
+        .block_on(execute_workflow_by_id_with_labels(
+            &workflow.id,
+            HashMap::new(),
+            vec!["release-1.4".to_string(), " release-1.4 ".to_string()],
+            None,
+        ))
+        .unwrap();
+    let execution = rt
+        .block_on(store.get_workflow_execution(&result.execution_id))
+        .unwrap()
+        .unwrap();
+    assert_eq!(execution.labels, vec!["release-1.4"]);
+
+    let labels = rt
+        .block_on(set_execution_labels(
+            &result.execution_id,
+            vec!["release-1.4".to_string(), "customer-repro".to_string()],
+        ))
+        .unwrap();
+    assert_eq!(labels, vec!["release-1.4", "customer-repro"]);
+    assert_eq!(
+        rt.block_on(list_execution_labels()).unwrap(),
+        vec!["customer-repro", "release-1.4"]
+    );
+
+    let filter = ExecutionFilter {
+        label: Some("customer-repro".to_string()),
+        workflow_id: Some(workflow.id.clone()),
+        ..Default::default()
+    };
+    let summaries = rt
+        .block_on(store.list_workflow_execution_summaries(&filter, 0, 10))
+        .unwrap();
+    assert_eq!(summaries.len(), 1);
+    assert_eq!(summaries[0].id, result.execution_id);
+
+    assert!(matches!(
+        rt.block_on(set_execution_labels("missing", Vec::new())),
+        Err(CoreError::WorkflowNotFound(_))
+    ));
+}
+
+#[test]
+#[serial]
+fn test_execution_filter_presets() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+
+    let filter = ExecutionFilter {
+        status: Some(WorkflowExecutionStatus::Failed),
+        label: Some("release-1.4".to_string()),
+        workflow_id: None,
+    };
+    let preset = rt
+        .block_on(save_execution_filter_preset(
+            "  Failed release  ",
+            filter.clone(),
+        ))
+        .unwrap();
+    assert_eq!(preset.name, "Failed release");
+
+    assert!(matches!(
+        rt.block_on(save_execution_filter_preset(" ", filter)),
+        Err(CoreError::InputValidationFailed(_))
+    ));
+    assert!(matches!(
+        rt.block_on(save_execution_filter_preset(
+            "Everything",
+            ExecutionFilter::default()
+        )),
+        Err(CoreError::InputValidationFailed(_))
+    ));
+
+    let presets = rt.block_on(list_execution_filter_presets()).unwrap();
+    assert_eq!(presets, vec![preset]);
+
+    rt.block_on(delete_execution_filter_preset("Failed release"))
+        .unwrap();
+    assert!(rt
+        .block_on(list_execution_filter_presets())
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,95 @@
+use s_e_e_core::{
+    export_workflow_graph, get_global_store, init_test_store, layout_workflow_content, CoreError,
+    GraphEdge, LayoutMode, WorkflowDefinition,
+};
+use serde_json::json;
+use serial_test::serial;
+
+fn pipeline(metadata: serde_json::Value) -> String {
+    json!({
+        "id": "pipeline",
+        "name": "Pipeline",
+        "tasks": [{
+            "id": "build",
+            "name": "Build",
+            "function": { "name": "cli_command", "input": { "command": "make" } },
+            "next_tasks": [{
+                "id": "test",
+                "name": "Test",
+                "function": { "name": "cli_command", "input": { "command": "make", "args": ["test"] } }
+            }]
+        }],
+        "metadata": metadata
+    })
+    .to_string()
+}
+
+#[test]
+fn test_layout_uses_saved_mode_and_keeps_dragged_nodes() {
+    let content = pipeline(json!({
+        "layout": "stage_grid",
+        "node_positions": { "test": { "x": 900.0, "y": 40.0 }, "__start__": { "x": 0.0, "y": 0.0 } }
+    }));
+
+    let graph = layout_workflow_content(&content, None).unwrap();
+    assert_eq!(graph.mode, LayoutMode::StageGrid);
+    assert_eq!(
+        graph.edges,
+        vec![GraphEdge {
+            source: "build".to_string(),
+            target: "test".to_string()
+        }]
+    );
+    let ids: Vec<_> = graph.nodes.iter().map(|node| node.id.as_str()).collect();
+    assert_eq!(ids, ["build", "test"]);
+    let test = &graph.nodes[1];
+    assert!(test.pinned);
+    assert_eq!((test.x, test.y), (900.0, 40.0));
+    assert!(!graph.nodes[0].pinned);
+
+    let layered = layout_workflow_content(&content, Some(LayoutMode::Layered)).unwrap();
+    assert_eq!(layered.mode, LayoutMode::Layered);
+    assert_eq!((layered.nodes[1].x, layered.nodes[1].y), (900.0, 40.0));
+}
+
+#[test]
+fn test_layout_defaults_to_layered_and_rejects_invalid_workflows() {
+    let graph = layout_workflow_content(&pipeline(json!(null)), None).unwrap();
+    assert_eq!(graph.mode, LayoutMode::Layered);
+    assert!(graph.nodes.iter().all(|node| !node.pinned));
+    assert!(graph.nodes[1].y > graph.nodes[0].y);
+
+    assert!(layout_workflow_content("{", None).is_err());
+    assert!(
+        layout_workflow_content(r#"{"id":"x","name":"x","tasks":[{"id":"t"}]}"#, None).is_err()
+    );
+}
+
+#[test]
+#[serial]
+fn test_export_workflow_graph() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let workflow = WorkflowDefinition {
+        id: "pipeline".to_string(),
+        name: "Pipeline".to_string(),
+        content: pipeline(json!({ "layout": "force_directed" })),
+        ..Default::default()
+    };
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let graph = rt
+        .block_on(export_workflow_graph("pipeline", None))
+        .unwrap();
+    assert_eq!(graph.mode, LayoutMode::ForceDirected);
+    assert_eq!(
+        graph,
+        layout_workflow_content(&workflow.content, None).unwrap()
+    );
+    assert!(matches!(
+        rt.block_on(export_workflow_graph("missing", None)),
+        Err(CoreError::WorkflowNotFound(_))
+    ));
+}
@@ -0,0 +1,168 @@
+use s_e_e_core::{
+    cancel_workflow_execution, execute_workflow_by_id, get_global_store, init_test_store,
+    CoreError, Lifecycle, TaskExecutionStatus, WorkflowDefinition, WorkflowExecutionStatus,
+    CANCELLED_BY_USER, INTERRUPTED_BY_SHUTDOWN,
+};
+use s_e_e_engine::EngineError;
+use serial_test::serial;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+fn sleeping_workflow() -> WorkflowDefinition {
+    WorkflowDefinition {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: "Sleeper".to_string(),
+        content: r#"{
+            "id": "sleeper",
+            "name": "Sleeper",
+            "tasks": [{
+                "id": "sleep",
+                "name": "Sleep",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "sleep", "args": ["30"] }
+                }
+            }]
+        }"#
+        .to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_subsystems_stop_in_reverse_order() {
+    let stopped = Arc::new(Mutex::new(Vec::new()));
+    let mut lifecycle = Lifecycle::new();
+    for name in ["store", "listener"] {
+        let stopped = stopped.clone();
+        lifecycle.start(name, move |mut shutdown| async move {
+            let _ = shutdown.changed().await;
+            stopped.lock().unwrap().push(name);
+        });
+    }
+
+    let report = lifecycle.shutdown().await;
+    assert_eq!(report.stopped, vec!["listener", "store"]);
+    assert!(report.cancelled.is_empty());
+    assert_eq!(*stopped.lock().unwrap(), vec!["listener", "store"]);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_subsystem_that_does_not_stop_is_cancelled() {
+    let mut lifecycle = Lifecycle::new().with_timeout(Duration::from_millis(100));
+    lifecycle.start("stubborn", |_| async {
+        tokio::time::sleep(Duration::from_secs(60)).await;
+    });
+    lifecycle.start("polite", |mut shutdown| async move {
+        let _ = shutdown.changed().await;
+    });
+
+    let started = Instant::now();
+    let report = lifecycle.shutdown().await;
+    assert_eq!(report.stopped, vec!["polite"]);
+    assert_eq!(report.cancelled, vec!["stubborn"]);
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_run_still_going_at_timeout_is_saved_as_failed() {
+    init_test_store().await.unwrap();
+    let store = get_global_store().unwrap();
+    store.clear_all_data().await.unwrap();
+    let workflow = sleeping_workflow();
+    store.save_workflow(&workflow).await.unwrap();
+
+    let run = tokio::spawn({
+        let workflow_id = workflow.id.clone();
+        async move { execute_workflow_by_id(&workflow_id, None).await }
+    });
+    let execution_id = loop {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let executions = store.list_workflow_executions().await.unwrap();
+        if let Some(execution) = executions.into_iter().next() {
+            break execution.id;
+        }
+    };
+
+    let started = Instant::now();
+    let report = Lifecycle::new()
+        .with_timeout(Duration::from_millis(300))
+        .shutdown()
+        .await;
+    assert_eq!(report.interrupted, vec![execution_id.clone()]);
+
+    let result = tokio::time::timeout(Duration::from_secs(10), run)
+        .await
+        .expect("the run should end once its processes are killed")
+        .unwrap();
+    assert!(matches!(
+        result,
+        Err(CoreError::Engine(EngineError::Cancelled))
+    ));
+    assert!(started.elapsed() < Duration::from_secs(10));
+
+    let execution = store
+        .get_workflow_execution(&execution_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(execution.status, WorkflowExecutionStatus::Failed);
+    assert!(execution.completed_at.is_some());
+    assert_eq!(execution.errors, vec![INTERRUPTED_BY_SHUTDOWN]);
+    assert!(execution
+        .tasks
+        .iter()
+        .all(|task| task.status != TaskExecutionStatus::InProgress));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[serial]
+async fn test_cancelled_run_stops_and_is_saved_as_cancelled() {
+    init_test_store().await.unwrap();
+    let store = get_global_store().unwrap();
+    store.clear_all_data().await.unwrap();
+    let workflow = sleeping_workflow();
+    store.save_workflow(&workflow).await.unwrap();
+
+    assert!(!cancel_workflow_execution("no-such-run").await.unwrap());
+
+    let run = tokio::spawn({
+        let workflow_id = workflow.id.clone();
+        async move { execute_workflow_by_id(&workflow_id, None).await }
+    });
+    let execution_id = loop {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let executions = store.list_workflow_executions().await.unwrap();
+        if let Some(execution) = executions.into_iter().next() {
+            break execution.id;
+        }
+    };
+
+    let started = Instant::now();
+    assert!(cancel_workflow_execution(&execution_id).await.unwrap());
+    let result = tokio::time::timeout(Duration::from_secs(10), run)
+        .await
+        .expect("the run should end once cancelled")
+        .unwrap();
+    assert!(matches!(
+        result,
+        Err(CoreError::Engine(EngineError::Cancelled))
+    ));
+    assert!(started.elapsed() < Duration::from_secs(10));
+    assert!(!cancel_workflow_execution(&execution_id).await.unwrap());
+
+    let execution = store
+        .get_workflow_execution(&execution_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(execution.status, WorkflowExecutionStatus::Cancelled);
+    assert_eq!(execution.errors, vec![CANCELLED_BY_USER]);
+    assert!(execution
+        .tasks
+        .iter()
+        .all(|task| task.status != TaskExecutionStatus::InProgress));
+}
@@ -0,0 +1,21 @@
+use s_e_e_core::{workflow_limits_from_settings, WorkflowLimitSettings, WorkflowLimits};
+
+#[test]
+fn test_default_limit_settings_match_engine_defaults() {
+    assert_eq!(
+        workflow_limits_from_settings(&WorkflowLimitSettings::default()),
+        WorkflowLimits::default()
+    );
+}
+
+#[test]
+fn test_document_size_converted_from_kilobytes() {
+    let settings = WorkflowLimitSettings {
+        max_document_kb: 2,
+        ..Default::default()
+    };
+    assert_eq!(
+        workflow_limits_from_settings(&settings).max_document_bytes,
+        2048
+    );
+}
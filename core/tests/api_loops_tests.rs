@@ -0,0 +1,68 @@
+use s_e_e_core::{
+    execute_workflow_by_id, get_global_store, init_test_store, TaskExecutionStatus,
+    WorkflowDefinition, WorkflowExecutionStatus,
+};
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn test_iterations_are_persisted_as_tasks() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let workflow = WorkflowDefinition {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: "Greet".to_string(),
+        content: r#"{
+            "id": "greet",
+            "name": "Greet",
+            "tasks": [{
+                "id": "names",
+                "name": "Names",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "printf", "args": ["ada\\ngrace\\n"] }
+                },
+                "next_tasks": [{
+                    "id": "hello",
+                    "name": "Hello",
+                    "for_each": "data.names",
+                    "function": {
+                        "name": "cli_command",
+                        "input": { "command": "echo", "args": ["hello {{item}}"] }
+                    }
+                }]
+            }]
+        }"#
+        .to_string(),
+        ..Default::default()
+    };
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let execution_id = rt
+        .block_on(execute_workflow_by_id(&workflow.id, None))
+        .unwrap()
+        .execution_id;
+    let execution = rt
+        .block_on(store.get_workflow_execution(&execution_id))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(execution.status, WorkflowExecutionStatus::Complete);
+    let mut ids: Vec<&str> = execution
+        .tasks
+        .iter()
+        .map(|task| task.id.as_str())
+        .collect();
+    ids.sort();
+    assert_eq!(ids, ["hello", "hello#0", "hello#1", "names"]);
+    let second = execution.tasks.iter().find(|task| task.id == "hello#1");
+    let second = second.unwrap();
+    assert_eq!(second.status, TaskExecutionStatus::Complete);
+    assert!(second
+        .output
+        .as_deref()
+        .unwrap_or("")
+        .contains("hello grace"));
+}
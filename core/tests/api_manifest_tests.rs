@@ -0,0 +1,59 @@
+use s_e_e_core::{
+    content_hash, execute_workflow_by_id_with_params, get_execution_manifest, get_global_store,
+    init_test_store, CoreError, WorkflowDefinition,
+};
+use serde_json::json;
+use serial_test::serial;
+use std::collections::HashMap;
+
+#[test]
+#[serial]
+fn test_execution_records_manifest() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+
+    let content = json!({
+        "id": "versions",
+        "name": "Versions",
+        "parameters": [{ "name": "target", "default": "debug" }],
+        "tasks": [{
+            "id": "build",
+            "name": "Build",
+            "function": { "name": "cli_command", "input": { "command": "echo", "args": ["cargo build"] } }
+        }]
+    })
+    .to_string();
+    rt.block_on(store.save_workflow(&WorkflowDefinition {
+        id: "versions".to_string(),
+        name: "Versions".to_string(),
+        content: content.clone(),
+        ..Default::default()
+    }))
+    .unwrap();
+
+    let result = rt
+        .block_on(execute_workflow_by_id_with_params(
+            "versions",
+            HashMap::from([("target".to_string(), json!("release"))]),
+            None,
+        ))
+        .unwrap();
+    let manifest = rt
+        .block_on(get_execution_manifest(&result.execution_id))
+        .unwrap()
+        .expect("manifest is recorded");
+
+    assert_eq!(manifest.app_version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(manifest.os, std::env::consts::OS);
+    assert_eq!(manifest.workflow_hash, content_hash(&content));
+    assert_eq!(manifest.parameters["target"], json!("release"));
+    assert!(manifest.handlers.contains_key("cli_command"));
+    assert!(manifest.tools["cargo"].starts_with("cargo "));
+
+    assert!(matches!(
+        rt.block_on(get_execution_manifest("missing")),
+        Err(CoreError::WorkflowNotFound(_))
+    ));
+}
@@ -0,0 +1,204 @@
+use s_e_e_core::{
+    confirm_manual_task, execute_workflow_by_id, get_global_store, get_pending_inputs,
+    init_test_store, provide_user_input, ManualOutcome, TaskExecutionStatus, WorkflowDefinition,
+    WorkflowExecutionStatus,
+};
+use serial_test::serial;
+
+fn review_workflow() -> WorkflowDefinition {
+    WorkflowDefinition {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: "Release".to_string(),
+        content: r#"{
+            "id": "release",
+            "name": "Release",
+            "tasks": [
+                {
+                    "id": "review",
+                    "name": "Review the PR",
+                    "function": {
+                        "name": "manual",
+                        "input": { "instructions": "Review and approve the release PR" }
+                    },
+                    "next_tasks": [
+                        {
+                            "id": "ship",
+                            "name": "Ship",
+                            "function": {
+                                "name": "cli_command",
+                                "input": { "command": "echo", "args": ["shipped"] }
+                            }
+                        }
+                    ]
+                }
+            ]
+        }"#
+        .to_string(),
+        ..Default::default()
+    }
+}
+
+fn task_status(
+    rt: &tokio::runtime::Runtime,
+    execution_id: &str,
+    task_id: &str,
+) -> Option<TaskExecutionStatus> {
+    let store = get_global_store().unwrap();
+    let execution = rt
+        .block_on(store.get_workflow_execution(execution_id))
+        .unwrap()
+        .unwrap();
+    execution
+        .tasks
+        .into_iter()
+        .find(|task| task.id == task_id)
+        .map(|task| task.status)
+}
+
+#[test]
+#[serial]
+fn test_manual_task_waits_for_confirmation() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let workflow = review_workflow();
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let result = rt
+        .block_on(execute_workflow_by_id(&workflow.id, None))
+        .unwrap();
+    let execution_id = result.execution_id;
+
+    let requests = rt.block_on(get_pending_inputs(&execution_id)).unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].is_manual());
+    assert_eq!(requests[0].prompt_text, "Review and approve the release PR");
+    assert_eq!(
+        task_status(&rt, &execution_id, "review"),
+        Some(TaskExecutionStatus::WaitingForInput)
+    );
+    assert_ne!(
+        task_status(&rt, &execution_id, "ship"),
+        Some(TaskExecutionStatus::Complete)
+    );
+
+    let no_one = rt.block_on(confirm_manual_task(
+        &execution_id,
+        "review",
+        ManualOutcome::Done,
+        "  ",
+        None,
+    ));
+    assert!(no_one.is_err());
+
+    rt.block_on(confirm_manual_task(
+        &execution_id,
+        "review",
+        ManualOutcome::Done,
+        "alice",
+        Some("Approved after a second look".to_string()),
+    ))
+    .unwrap();
+
+    let execution = rt
+        .block_on(store.get_workflow_execution(&execution_id))
+        .unwrap()
+        .unwrap();
+    assert_eq!(execution.status, WorkflowExecutionStatus::Complete);
+    assert_eq!(
+        task_status(&rt, &execution_id, "ship"),
+        Some(TaskExecutionStatus::Complete)
+    );
+    let confirmation = execution
+        .audit_trail
+        .iter()
+        .find(|event| event.task_id == "review")
+        .expect("confirmation recorded in the audit trail");
+    assert!(confirmation.message.contains("Confirmed done"));
+    assert!(confirmation.message.contains("by alice"));
+    assert!(confirmation
+        .message
+        .contains("Approved after a second look"));
+}
+
+#[test]
+#[serial]
+fn test_skipped_manual_task_lets_dependents_run() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let workflow = review_workflow();
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let execution_id = rt
+        .block_on(execute_workflow_by_id(&workflow.id, None))
+        .unwrap()
+        .execution_id;
+    rt.block_on(confirm_manual_task(
+        &execution_id,
+        "review",
+        ManualOutcome::Skipped,
+        "bob",
+        None,
+    ))
+    .unwrap();
+
+    let execution = rt
+        .block_on(store.get_workflow_execution(&execution_id))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        task_status(&rt, &execution_id, "ship"),
+        Some(TaskExecutionStatus::Complete)
+    );
+    assert!(execution
+        .audit_trail
+        .iter()
+        .any(|event| event.task_id == "review" && event.message.starts_with("Skipped")));
+}
+
+#[test]
+#[serial]
+fn test_only_manual_tasks_can_be_confirmed() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let workflow = WorkflowDefinition {
+        content: r#"{
+            "id": "ask",
+            "name": "Ask",
+            "tasks": [
+                {
+                    "id": "name",
+                    "name": "Name",
+                    "function": {
+                        "name": "user_input",
+                        "input": { "prompt": "Name?", "input_type": "string" }
+                    }
+                }
+            ]
+        }"#
+        .to_string(),
+        ..review_workflow()
+    };
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let execution_id = rt
+        .block_on(execute_workflow_by_id(&workflow.id, None))
+        .unwrap()
+        .execution_id;
+    let result = rt.block_on(confirm_manual_task(
+        &execution_id,
+        "name",
+        ManualOutcome::Done,
+        "alice",
+        None,
+    ));
+    assert!(result.is_err());
+
+    rt.block_on(provide_user_input(&execution_id, "name", "Ada".to_string()))
+        .unwrap();
+}
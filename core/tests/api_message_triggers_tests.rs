@@ -0,0 +1,206 @@
+use async_trait::async_trait;
+use s_e_e_core::{
+    get_global_store, handle_message, init_test_store, load_message_triggers, message_params,
+    AppSettings, IncomingMessage, MessageBackend, MessageBroker, MessageConnection,
+    MessageConnectionSettings, MessageOutcome, MessageTrigger, Settle, WorkflowDefinition,
+};
+use serde_json::json;
+use serial_test::serial;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+/// Records how the message was settled instead of talking to a broker.
+struct Recorder(Arc<Mutex<Vec<&'static str>>>);
+
+#[async_trait]
+impl Settle for Recorder {
+    async fn ack(self: Box<Self>) -> Result<(), String> {
+        self.0.lock().unwrap().push("ack");
+        Ok(())
+    }
+
+    async fn reject(self: Box<Self>) -> Result<(), String> {
+        self.0.lock().unwrap().push("reject");
+        Ok(())
+    }
+}
+
+fn message(payload: &str) -> (IncomingMessage, Arc<Mutex<Vec<&'static str>>>) {
+    let settled = Arc::new(Mutex::new(Vec::new()));
+    let message = IncomingMessage::new(
+        payload.as_bytes().to_vec(),
+        Box::new(Recorder(Arc::clone(&settled))),
+    );
+    (message, settled)
+}
+
+fn release_workflow(command: &str, trigger_connection: &str) -> WorkflowDefinition {
+    let id = uuid::Uuid::new_v4().to_string();
+    let content = json!({
+        "id": id,
+        "name": "Release",
+        "parameters": [{ "name": "version", "required": true }],
+        "triggers": [{
+            "type": "message",
+            "connection": trigger_connection,
+            "source": "releases",
+            "max_attempts": 2
+        }],
+        "tasks": [{
+            "id": "ship",
+            "name": "Ship",
+            "function": {
+                "name": "cli_command",
+                "input": { "command": command, "args": [] }
+            }
+        }]
+    });
+    WorkflowDefinition {
+        id,
+        name: "Release".to_string(),
+        content: content.to_string(),
+        ..Default::default()
+    }
+}
+
+fn trigger(workflow: &WorkflowDefinition) -> MessageTrigger {
+    MessageTrigger {
+        workflow_id: workflow.id.clone(),
+        workflow_name: workflow.name.clone(),
+        connection: MessageConnection {
+            name: "events".to_string(),
+            backend: MessageBackend::Nats,
+            url: "nats://127.0.0.1:4222".to_string(),
+            password_env: None,
+        },
+        source: "releases".to_string(),
+        params: BTreeMap::new(),
+        parameter_names: vec!["version".to_string()],
+        max_concurrent: 1,
+        max_attempts: 2,
+        dead_letter: None,
+    }
+}
+
+#[test]
+fn test_message_params() {
+    let names = vec!["version".to_string(), "channel".to_string()];
+    let payload = br#"{"version": "1.4.0", "build": {"id": 42}, "other": true}"#;
+
+    let params = message_params(payload, &BTreeMap::new(), &names).unwrap();
+    assert_eq!(params.len(), 1);
+    assert_eq!(params["version"], json!("1.4.0"));
+
+    let mapping = BTreeMap::from([
+        ("version".to_string(), "/version".to_string()),
+        ("build".to_string(), "/build/id".to_string()),
+    ]);
+    let params = message_params(payload, &mapping, &names).unwrap();
+    assert_eq!(params["build"], json!(42));
+
+    let missing = BTreeMap::from([("channel".to_string(), "/channel".to_string())]);
+    assert!(message_params(payload, &missing, &names).is_err());
+
+    let whole = BTreeMap::from([("version".to_string(), String::new())]);
+    let params = message_params(b"1.4.0-rc1", &whole, &names).unwrap();
+    assert_eq!(params["version"], json!("1.4.0-rc1"));
+}
+
+#[test]
+#[serial]
+fn test_load_message_triggers_resolves_connections() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let settings = AppSettings {
+        message_connections: vec![MessageConnectionSettings {
+            name: "events".to_string(),
+            broker: MessageBroker::Nats,
+            url: "nats://127.0.0.1:4222".to_string(),
+            password_env: None,
+        }],
+        ..Default::default()
+    };
+    rt.block_on(store.save_settings(&settings)).unwrap();
+    let listening = release_workflow("true", "events");
+    rt.block_on(store.save_workflow(&listening)).unwrap();
+    rt.block_on(store.save_workflow(&release_workflow("true", "elsewhere")))
+        .unwrap();
+
+    let (triggers, problems) = rt.block_on(load_message_triggers()).unwrap();
+    assert_eq!(triggers.len(), 1);
+    assert_eq!(triggers[0].workflow_id, listening.id);
+    assert_eq!(triggers[0].connection.backend, MessageBackend::Nats);
+    assert_eq!(triggers[0].max_concurrent, 1);
+    assert_eq!(triggers[0].max_attempts, 2);
+    assert_eq!(triggers[0].parameter_names, vec!["version".to_string()]);
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].contains("'elsewhere'"));
+}
+
+#[test]
+#[serial]
+fn test_successful_run_acks_message() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let workflow = release_workflow("true", "events");
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let (incoming, settled) = message(r#"{"version": "1.4.0"}"#);
+    let outcome = rt
+        .block_on(handle_message(&trigger(&workflow), incoming))
+        .unwrap();
+
+    assert_eq!(outcome, MessageOutcome::Acked);
+    assert_eq!(*settled.lock().unwrap(), vec!["ack"]);
+    let executions = rt.block_on(store.list_workflow_executions()).unwrap();
+    assert_eq!(executions.len(), 1);
+    assert_eq!(executions[0].parameters["version"], json!("1.4.0"));
+}
+
+#[test]
+#[serial]
+fn test_failing_run_is_retried_then_rejected() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let workflow = release_workflow("false", "events");
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let (incoming, settled) = message(r#"{"version": "1.4.0"}"#);
+    let outcome = rt
+        .block_on(handle_message(&trigger(&workflow), incoming))
+        .unwrap();
+
+    assert_eq!(outcome, MessageOutcome::Rejected);
+    assert_eq!(*settled.lock().unwrap(), vec!["reject"]);
+    let executions = rt.block_on(store.list_workflow_executions()).unwrap();
+    assert_eq!(executions.len(), 2);
+}
+
+#[test]
+#[serial]
+fn test_unmappable_message_is_rejected_without_running() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let workflow = release_workflow("true", "events");
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let mut trigger = trigger(&workflow);
+    trigger.params = BTreeMap::from([("version".to_string(), "/release/version".to_string())]);
+    let (incoming, settled) = message(r#"{"version": "1.4.0"}"#);
+    let outcome = rt.block_on(handle_message(&trigger, incoming)).unwrap();
+
+    assert_eq!(outcome, MessageOutcome::Rejected);
+    assert_eq!(*settled.lock().unwrap(), vec!["reject"]);
+    assert!(rt
+        .block_on(store.list_workflow_executions())
+        .unwrap()
+        .is_empty());
+}
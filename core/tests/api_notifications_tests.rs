@@ -0,0 +1,182 @@
+use s_e_e_core::{
+    execute_workflow_by_id, get_global_store, init_test_store, list_notifications,
+    mark_all_notifications_read, mark_notification_read, unread_notification_count, AppSettings,
+    NotificationKind, WorkflowDefinition,
+};
+use serde_json::json;
+use serial_test::serial;
+
+fn workflow(command: &str) -> WorkflowDefinition {
+    let id = uuid::Uuid::new_v4().to_string();
+    let content = json!({
+        "id": id,
+        "name": "Deploy",
+        "tasks": [{
+            "id": "ship",
+            "name": "Ship",
+            "function": {
+                "name": "cli_command",
+                "input": { "command": command, "args": [] }
+            }
+        }]
+    });
+    WorkflowDefinition {
+        id,
+        name: "Deploy".to_string(),
+        description: None,
+        content: content.to_string(),
+        is_default: false,
+        is_edited: false,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    }
+}
+
+#[test]
+#[serial]
+fn test_finished_runs_are_notified_with_a_link() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let passing = workflow("true");
+    let failing = workflow("false");
+    rt.block_on(store.save_workflow(&passing)).unwrap();
+    rt.block_on(store.save_workflow(&failing)).unwrap();
+
+    let passed = rt
+        .block_on(execute_workflow_by_id(&passing.id, None))
+        .unwrap();
+    let failed = rt
+        .block_on(execute_workflow_by_id(&failing.id, None))
+        .unwrap();
+
+    let notifications = rt.block_on(list_notifications(10)).unwrap();
+    assert_eq!(notifications.len(), 2);
+    assert_eq!(notifications[0].kind, NotificationKind::ExecutionFailed);
+    assert_eq!(notifications[0].execution_id, Some(failed.execution_id));
+    assert_eq!(notifications[1].kind, NotificationKind::ExecutionCompleted);
+    assert_eq!(notifications[1].execution_id, Some(passed.execution_id));
+    assert_eq!(rt.block_on(unread_notification_count()).unwrap(), 2);
+
+    rt.block_on(mark_notification_read(&notifications[0].id))
+        .unwrap();
+    assert_eq!(rt.block_on(unread_notification_count()).unwrap(), 1);
+    rt.block_on(mark_all_notifications_read()).unwrap();
+    assert_eq!(rt.block_on(unread_notification_count()).unwrap(), 0);
+}
+
+#[test]
+#[serial]
+fn test_muted_kinds_are_not_recorded() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let mut settings = AppSettings::default();
+    settings
+        .muted_notifications
+        .insert(NotificationKind::ExecutionCompleted);
+    rt.block_on(store.save_settings(&settings)).unwrap();
+
+    let passing = workflow("true");
+    rt.block_on(store.save_workflow(&passing)).unwrap();
+    rt.block_on(execute_workflow_by_id(&passing.id, None))
+        .unwrap();
+
+    assert!(rt.block_on(list_notifications(10)).unwrap().is_empty());
+}
+
+fn release_workflow(notify: serde_json::Value) -> WorkflowDefinition {
+    let id = uuid::Uuid::new_v4().to_string();
+    let content = json!({
+        "id": id,
+        "name": "Release",
+        "notify": notify,
+        "tasks": [{
+            "id": "build",
+            "name": "Build",
+            "function": {
+                "name": "cli_command",
+                "input": { "command": "sleep", "args": ["1.2"] }
+            },
+            "next_tasks": [{
+                "id": "deploy-eu",
+                "name": "Deploy EU",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "false", "args": [] }
+                }
+            }]
+        }]
+    });
+    WorkflowDefinition {
+        id,
+        name: "Release".to_string(),
+        description: None,
+        content: content.to_string(),
+        is_default: false,
+        is_edited: false,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    }
+}
+
+#[test]
+#[serial]
+fn test_notify_rules_alert_about_matching_tasks() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let release = release_workflow(json!([
+        { "tasks": ["deploy-*"], "on": "failure" },
+        { "tasks": ["deploy-*"], "on": "success" },
+        { "tasks": ["build"], "on": "failure" },
+        { "tasks": ["build"], "on": "slow", "longer_than_seconds": 1 }
+    ]));
+    rt.block_on(store.save_workflow(&release)).unwrap();
+
+    let result = rt
+        .block_on(execute_workflow_by_id(&release.id, None))
+        .unwrap();
+
+    let alerts: Vec<String> = rt
+        .block_on(list_notifications(10))
+        .unwrap()
+        .into_iter()
+        .filter(|n| n.kind == NotificationKind::TaskAlert)
+        .inspect(|n| assert_eq!(n.execution_id.as_ref(), Some(&result.execution_id)))
+        .map(|n| n.title)
+        .collect();
+    assert_eq!(alerts.len(), 2, "{:?}", alerts);
+    assert!(alerts.contains(&"Build ran long".to_string()));
+    assert!(alerts.contains(&"Deploy EU failed".to_string()));
+}
+
+#[test]
+#[serial]
+fn test_task_alerts_can_be_muted() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let mut settings = AppSettings::default();
+    settings
+        .muted_notifications
+        .insert(NotificationKind::TaskAlert);
+    rt.block_on(store.save_settings(&settings)).unwrap();
+    let release = release_workflow(json!([{ "tasks": ["deploy-eu"], "on": "failure" }]));
+    rt.block_on(store.save_workflow(&release)).unwrap();
+
+    rt.block_on(execute_workflow_by_id(&release.id, None))
+        .unwrap();
+
+    let kinds: Vec<NotificationKind> = rt
+        .block_on(list_notifications(10))
+        .unwrap()
+        .into_iter()
+        .map(|n| n.kind)
+        .collect();
+    assert_eq!(kinds, vec![NotificationKind::ExecutionFailed]);
+}
@@ -0,0 +1,89 @@
+use s_e_e_core::{
+    execute_workflow_by_id, get_global_store, get_journaled_output, init_test_store, OutputChunk,
+    OutputEvent, WorkflowDefinition,
+};
+use serde_json::json;
+use serial_test::serial;
+use std::sync::{Arc, Mutex};
+
+fn chunk(sequence: u64, task_id: Option<&str>, line: &str) -> OutputChunk {
+    OutputChunk {
+        execution_id: "exec-crashed".to_string(),
+        sequence,
+        task_id: task_id.map(str::to_string),
+        task_name: task_id.map(|id| format!("Task {}", id)),
+        line: line.to_string(),
+        finished: None,
+    }
+}
+
+#[test]
+#[serial]
+fn test_journaled_output_of_interrupted_run() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    rt.block_on(store.append_output_chunks(&[
+        chunk(0, Some("build"), "compiling"),
+        chunk(1, Some("build"), "linking"),
+    ]))
+    .unwrap();
+
+    let output = rt.block_on(get_journaled_output("exec-crashed")).unwrap();
+    let lines: Vec<&str> = output.iter().map(|event| event.line.as_str()).collect();
+    assert_eq!(lines, ["compiling", "linking"]);
+    assert_eq!(output[1].sequence, 1);
+    assert_eq!(output[1].task_id.as_deref(), Some("build"));
+    assert_eq!(output[1].task_name.as_deref(), Some("Task build"));
+    assert!(rt
+        .block_on(get_journaled_output("exec-other"))
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+#[serial]
+fn test_finished_run_leaves_no_journal() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let workflow = WorkflowDefinition {
+        id: "journal-echo".to_string(),
+        name: "Journal Echo".to_string(),
+        content: json!({
+            "id": "journal-echo",
+            "name": "Journal Echo",
+            "tasks": [{
+                "id": "greet",
+                "name": "Greet",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "echo", "args": ["hello"] }
+                }
+            }]
+        })
+        .to_string(),
+        ..Default::default()
+    };
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let seen: Arc<Mutex<Vec<OutputEvent>>> = Arc::default();
+    let sink = seen.clone();
+    let result = rt
+        .block_on(execute_workflow_by_id(
+            "journal-echo",
+            Some(Arc::new(move |event| sink.lock().unwrap().push(event))),
+        ))
+        .unwrap();
+    assert!(result.success, "{:?}", result.errors);
+
+    // The callback still gets every line the journal took.
+    let seen = seen.lock().unwrap();
+    assert!(seen.iter().any(|event| event.line == "hello"));
+    assert!(rt
+        .block_on(get_journaled_output(&result.execution_id))
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,115 @@
+use s_e_e_core::{
+    get_global_store, init_test_store, parse_parameter_form, workflow_parameters, ParameterField,
+    ParameterType, WorkflowDefinition,
+};
+use serde_json::json;
+use serial_test::serial;
+use std::collections::HashMap;
+
+const WORKFLOW: &str = r#"{
+    "id": "deploy",
+    "name": "Deploy",
+    "parameters": [
+        {"name": "env", "options": ["staging", "prod"], "required": true},
+        {"name": "replicas", "type": "number", "default": 2},
+        {"name": "dry_run", "type": "boolean", "default": false},
+        {"name": "manifest", "type": "file"},
+        {"name": "note"},
+        {"name": "token", "secret": true},
+        {"name": "out_dir", "type": "directory"},
+        {"name": "inputs", "type": "glob"}
+    ],
+    "tasks": [{
+        "id": "ship",
+        "name": "Ship",
+        "function": {"name": "cli_command", "input": {"command": "echo"}}
+    }]
+}"#;
+
+fn inputs(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+#[serial]
+fn test_workflow_parameters_pick_fields() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    rt.block_on(store.save_workflow(&WorkflowDefinition {
+        id: "deploy".to_string(),
+        name: "Deploy".to_string(),
+        content: WORKFLOW.to_string(),
+        ..Default::default()
+    }))
+    .unwrap();
+
+    let parameters = rt.block_on(workflow_parameters("deploy")).unwrap();
+
+    let fields: Vec<ParameterField> = parameters
+        .iter()
+        .map(ParameterField::for_parameter)
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            ParameterField::Select,
+            ParameterField::Number,
+            ParameterField::Checkbox,
+            ParameterField::File,
+            ParameterField::Text,
+            ParameterField::Secret,
+            ParameterField::Directory,
+            ParameterField::Glob,
+        ]
+    );
+    assert_eq!(parameters[3].param_type, ParameterType::File);
+}
+
+#[test]
+fn test_parameter_form_reports_each_field() {
+    let parameters = serde_json::from_value::<Vec<_>>(
+        serde_json::from_str::<serde_json::Value>(WORKFLOW).unwrap()["parameters"].clone(),
+    )
+    .unwrap();
+
+    let errors = parse_parameter_form(&parameters, &inputs(&[("replicas", "many")])).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors["env"], "A value is required");
+    assert!(errors["replicas"].contains("expected number"));
+
+    let errors = parse_parameter_form(&parameters, &inputs(&[("env", "dev")])).unwrap_err();
+    assert!(errors["env"].contains("one of staging, prod"));
+}
+
+#[test]
+fn test_parameter_form_leaves_blank_fields_to_defaults() {
+    let parameters = serde_json::from_value::<Vec<_>>(
+        serde_json::from_str::<serde_json::Value>(WORKFLOW).unwrap()["parameters"].clone(),
+    )
+    .unwrap();
+
+    let values = parse_parameter_form(
+        &parameters,
+        &inputs(&[
+            ("env", "prod"),
+            ("replicas", " "),
+            ("dry_run", "true"),
+            ("manifest", "/srv/deploy.yaml"),
+        ]),
+    )
+    .unwrap();
+
+    assert_eq!(
+        values,
+        HashMap::from([
+            ("env".to_string(), json!("prod")),
+            ("dry_run".to_string(), json!(true)),
+            ("manifest".to_string(), json!("/srv/deploy.yaml")),
+        ])
+    );
+}
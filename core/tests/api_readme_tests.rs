@@ -0,0 +1,106 @@
+use s_e_e_core::{
+    generate_workflow_readme, get_global_store, init_test_store, render_workflow_readme,
+    save_snippet, CoreError, TaskSnippet, WorkflowDefinition,
+};
+use serde_json::json;
+use serial_test::serial;
+
+fn release_workflow() -> WorkflowDefinition {
+    let content = json!({
+        "id": "release",
+        "name": "Release",
+        "description": "Tags and announces a release.",
+        "parameters": [
+            { "name": "version", "required": true, "description": "Version | tag to cut" },
+            { "name": "dry_run", "type": "boolean", "default": false }
+        ],
+        "tasks": [{
+            "id": "tag",
+            "name": "Tag \"release\"",
+            "description": "Creates the git tag.",
+            "function": {
+                "name": "cli_command",
+                "input": { "command": "git", "args": ["tag", "{{param.version}}"] }
+            },
+            "next_tasks": [{
+                "id": "approve",
+                "name": "Approve",
+                "function": { "name": "manual", "input": { "instructions": "Check the notes" } }
+            }]
+        }]
+    });
+    WorkflowDefinition {
+        id: "release".to_string(),
+        name: "Release".to_string(),
+        content: content.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_render_workflow_readme() {
+    let readme = render_workflow_readme(&release_workflow()).unwrap();
+
+    assert!(readme.starts_with("# Release\n\nTags and announces a release.\n"));
+    assert!(readme.contains("| `version` | string | yes |  | Version \\| tag to cut |"));
+    assert!(readme.contains("| `dry_run` | boolean | no | `false` |  |"));
+    assert!(readme.contains("### Approve\n\n`approve` · `manual` · after `tag`"));
+    assert!(
+        readme.contains("Creates the git tag.\n\nRuns:\n\n```sh\ngit tag {{param.version}}\n```")
+    );
+    assert!(readme.contains("| Approve | `data.approve` |"));
+    assert!(readme.contains(
+        "```mermaid\nflowchart TD\n    t0[\"Tag #quot;release#quot;\"]\n    t1[\"Approve\"]\n    t0 --> t1\n```"
+    ));
+}
+
+#[test]
+fn test_render_workflow_readme_rejects_invalid_definition() {
+    let workflow = WorkflowDefinition {
+        content: "{\"id\": \"broken\"}".to_string(),
+        ..release_workflow()
+    };
+    assert!(matches!(
+        render_workflow_readme(&workflow),
+        Err(CoreError::Validation(_))
+    ));
+}
+
+#[test]
+#[serial]
+fn test_generate_workflow_readme_expands_snippets() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+
+    rt.block_on(save_snippet(TaskSnippet {
+        id: "greet".to_string(),
+        name: "Greet".to_string(),
+        task: json!({
+            "name": "Say hello",
+            "function": { "name": "cli_command", "input": { "command": "echo", "args": ["hello"] } }
+        }),
+        ..Default::default()
+    }))
+    .unwrap();
+    let workflow = WorkflowDefinition {
+        id: "greeting".to_string(),
+        name: "Greeting".to_string(),
+        content: json!({
+            "id": "greeting",
+            "name": "Greeting",
+            "tasks": [{ "id": "hello", "snippet": "greet" }]
+        })
+        .to_string(),
+        ..Default::default()
+    };
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let readme = rt.block_on(generate_workflow_readme("greeting")).unwrap();
+    assert!(readme.contains("### Say hello\n\n`hello` · `cli_command`"));
+    assert!(matches!(
+        rt.block_on(generate_workflow_readme("missing")),
+        Err(CoreError::WorkflowNotFound(_))
+    ));
+}
@@ -0,0 +1,153 @@
+use s_e_e_core::{
+    execute_workflow_by_id, get_global_store, init_test_store, rerun_task, task_failed, CoreError,
+    TaskExecutionStatus, WorkflowDefinition, WorkflowExecution, WorkflowExecutionStatus,
+};
+use serde_json::json;
+use serial_test::serial;
+use std::path::Path;
+
+/// build → test → deploy, where test passes once `marker` exists.
+fn release_workflow(marker: &Path) -> WorkflowDefinition {
+    let command = |id: &str, command: &str, args: Vec<String>| {
+        json!({
+            "id": id,
+            "name": id,
+            "function": { "name": "cli_command", "input": { "command": command, "args": args } }
+        })
+    };
+    let mut build = command("build", "echo", vec!["built".to_string()]);
+    let mut test = command(
+        "test",
+        "test",
+        vec!["-f".to_string(), marker.display().to_string()],
+    );
+    test["next_tasks"] = json!([command("deploy", "echo", vec!["deployed".to_string()])]);
+    build["next_tasks"] = json!([test]);
+    WorkflowDefinition {
+        id: "rerun-release".to_string(),
+        name: "Rerun Release".to_string(),
+        content: json!({ "id": "rerun-release", "name": "Rerun Release", "tasks": [build] })
+            .to_string(),
+        ..Default::default()
+    }
+}
+
+fn stored(execution_id: &str, rt: &tokio::runtime::Runtime) -> WorkflowExecution {
+    let store = get_global_store().unwrap();
+    rt.block_on(store.get_workflow_execution(execution_id))
+        .unwrap()
+        .unwrap()
+}
+
+fn task_status(execution: &WorkflowExecution, task_id: &str) -> TaskExecutionStatus {
+    execution
+        .tasks
+        .iter()
+        .find(|task| task.id == task_id)
+        .map(|task| task.status.clone())
+        .unwrap()
+}
+
+#[test]
+#[serial]
+fn test_rerun_failed_task_in_place() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let marker = dir.path().join("tests-pass");
+    rt.block_on(store.save_workflow(&release_workflow(&marker)))
+        .unwrap();
+
+    let first = rt
+        .block_on(execute_workflow_by_id("rerun-release", None))
+        .unwrap();
+    assert!(!first.success);
+    let execution_id = first.execution_id;
+    let execution = stored(&execution_id, &rt);
+    assert_eq!(execution.status, WorkflowExecutionStatus::Failed);
+    assert!(task_failed(&execution, "test"));
+    // Tasks after a failed one still run on a first run.
+    assert_eq!(
+        task_status(&execution, "deploy"),
+        TaskExecutionStatus::Complete
+    );
+    let audit_events = execution.audit_trail.len();
+
+    // Still failing: the task gains an attempt and deploy is left alone.
+    let again = rt
+        .block_on(rerun_task(&execution_id, "test", true, None))
+        .unwrap();
+    assert!(!again.success);
+    let execution = stored(&execution_id, &rt);
+    let test = execution.tasks.iter().find(|t| t.id == "test").unwrap();
+    assert_eq!(test.attempts.len(), 1);
+    assert_eq!(test.attempts[0].status, TaskExecutionStatus::Failed);
+    let deploy = execution.tasks.iter().find(|t| t.id == "deploy").unwrap();
+    assert!(deploy.attempts.is_empty());
+    assert_eq!(execution.audit_trail.len(), audit_events + 1);
+
+    std::fs::write(&marker, "").unwrap();
+    let fixed = rt
+        .block_on(rerun_task(&execution_id, "test", true, None))
+        .unwrap();
+    assert!(fixed.success);
+    assert_eq!(fixed.execution_id, execution_id);
+
+    let execution = stored(&execution_id, &rt);
+    assert_eq!(execution.status, WorkflowExecutionStatus::Complete);
+    assert!(execution.errors.is_empty());
+    let test = execution.tasks.iter().find(|t| t.id == "test").unwrap();
+    assert_eq!(test.status, TaskExecutionStatus::Complete);
+    assert_eq!(test.attempt_number(), 3);
+    let deploy = execution.tasks.iter().find(|t| t.id == "deploy").unwrap();
+    assert_eq!(deploy.status, TaskExecutionStatus::Complete);
+    assert_eq!(deploy.attempts.len(), 1);
+    assert_eq!(deploy.output.as_deref(), Some("Output: deployed\n"));
+    let build = execution.tasks.iter().find(|t| t.id == "build").unwrap();
+    assert!(build.attempts.is_empty());
+}
+
+#[test]
+#[serial]
+fn test_rerun_only_failed_tasks_of_finished_runs() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let marker = dir.path().join("tests-pass");
+    rt.block_on(store.save_workflow(&release_workflow(&marker)))
+        .unwrap();
+    let first = rt
+        .block_on(execute_workflow_by_id("rerun-release", None))
+        .unwrap();
+
+    assert!(matches!(
+        rt.block_on(rerun_task(&first.execution_id, "build", false, None)),
+        Err(CoreError::InputValidationFailed(_))
+    ));
+    assert!(matches!(
+        rt.block_on(rerun_task(&first.execution_id, "missing", false, None)),
+        Err(CoreError::TaskNotFound(_))
+    ));
+    assert!(matches!(
+        rt.block_on(rerun_task("no-such-execution", "test", false, None)),
+        Err(CoreError::WorkflowNotFound(_))
+    ));
+
+    // Without downstream, only the task itself runs.
+    std::fs::write(&marker, "").unwrap();
+    let fixed = rt
+        .block_on(rerun_task(&first.execution_id, "test", false, None))
+        .unwrap();
+    assert!(fixed.success);
+    let execution = stored(&first.execution_id, &rt);
+    assert_eq!(
+        task_status(&execution, "test"),
+        TaskExecutionStatus::Complete
+    );
+    let deploy = execution.tasks.iter().find(|t| t.id == "deploy").unwrap();
+    assert!(deploy.attempts.is_empty());
+}
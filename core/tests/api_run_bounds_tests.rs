@@ -0,0 +1,125 @@
+use s_e_e_core::{
+    execute_workflow_by_id, execute_workflow_by_id_with_bounds, get_global_store, init_test_store,
+    CoreError, RunBounds, WorkflowDefinition,
+};
+use serde_json::json;
+use serial_test::serial;
+use std::collections::HashMap;
+
+/// build → test → deploy, where deploy reads build's output.
+fn release_workflow() -> WorkflowDefinition {
+    let echo = |id: &str, arg: &str| {
+        json!({
+            "id": id,
+            "name": id,
+            "function": { "name": "cli_command", "input": { "command": "echo", "args": [arg] } }
+        })
+    };
+    let mut build = echo("build", "built");
+    let mut test = echo("test", "tested");
+    test["next_tasks"] = json!([echo("deploy", "{{data.build}}")]);
+    build["next_tasks"] = json!([test]);
+    WorkflowDefinition {
+        id: "release".to_string(),
+        name: "Release".to_string(),
+        content: json!({ "id": "release", "name": "Release", "tasks": [build] }).to_string(),
+        ..Default::default()
+    }
+}
+
+fn task_ids(execution_id: &str, rt: &tokio::runtime::Runtime) -> Vec<String> {
+    let store = get_global_store().unwrap();
+    let execution = rt
+        .block_on(store.get_workflow_execution(execution_id))
+        .unwrap()
+        .unwrap();
+    let mut ids: Vec<String> = execution.tasks.into_iter().map(|t| t.id).collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+#[serial]
+fn test_bounded_runs() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    rt.block_on(store.save_workflow(&release_workflow()))
+        .unwrap();
+    let run = |bounds: RunBounds| {
+        rt.block_on(execute_workflow_by_id_with_bounds(
+            "release",
+            HashMap::new(),
+            Vec::new(),
+            bounds,
+            None,
+        ))
+    };
+
+    let until_test = run(RunBounds {
+        until: Some("test".to_string()),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(task_ids(&until_test.execution_id, &rt), ["build", "test"]);
+
+    let missing_build = run(RunBounds {
+        from: Some("test".to_string()),
+        ..Default::default()
+    });
+    assert!(matches!(
+        missing_build,
+        Err(CoreError::InputValidationFailed(message)) if message.contains("'build'")
+    ));
+
+    let full = rt
+        .block_on(execute_workflow_by_id("release", None))
+        .unwrap();
+    let bounds = RunBounds {
+        from: Some("test".to_string()),
+        context_from: Some(full.execution_id.clone()),
+        ..Default::default()
+    };
+    let resumed = run(bounds.clone()).unwrap();
+    assert!(resumed.success);
+    assert_eq!(task_ids(&resumed.execution_id, &rt), ["deploy", "test"]);
+    let stored = rt
+        .block_on(store.get_workflow_execution(&resumed.execution_id))
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.bounds, Some(bounds));
+
+    let chained = run(RunBounds {
+        from: Some("test".to_string()),
+        context_from: Some(until_test.execution_id.clone()),
+        ..Default::default()
+    });
+    assert!(chained.is_ok());
+    // A run that started at test never ran build, so it cannot supply build's output.
+    let no_output = run(RunBounds {
+        from: Some("test".to_string()),
+        context_from: Some(resumed.execution_id.clone()),
+        ..Default::default()
+    });
+    assert!(matches!(
+        no_output,
+        Err(CoreError::InputValidationFailed(message)) if message.contains("no output from 'build'")
+    ));
+
+    assert!(matches!(
+        run(RunBounds {
+            from: Some("deploy".to_string()),
+            until: Some("build".to_string()),
+            ..Default::default()
+        }),
+        Err(CoreError::InputValidationFailed(_))
+    ));
+    assert!(matches!(
+        run(RunBounds {
+            context_from: Some(full.execution_id),
+            ..Default::default()
+        }),
+        Err(CoreError::InputValidationFailed(_))
+    ));
+}
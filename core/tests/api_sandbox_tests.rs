@@ -0,0 +1,117 @@
+use s_e_e_core::{dry_run_workflow, get_global_store, init_test_store, run_sandboxed};
+use serde_json::json;
+use serial_test::serial;
+use std::collections::HashMap;
+
+#[test]
+#[serial]
+fn test_sandbox_run_records_commands_and_saves_nothing() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let dir = tempfile::TempDir::new().unwrap();
+    let marker = dir.path().join("created");
+
+    let workflow = json!({
+        "id": "sandbox-preview",
+        "name": "Sandbox Preview",
+        "tasks": [{
+            "id": "write",
+            "name": "Write file",
+            "function": {
+                "name": "cli_command",
+                "input": {
+                    "command": "touch",
+                    "args": [marker.to_string_lossy()]
+                }
+            },
+            "next_tasks": [{
+                "id": "note",
+                "name": "Note",
+                "function": { "name": "custom", "input": { "name": "note", "input": "done" } }
+            }]
+        }]
+    });
+
+    let result = rt
+        .block_on(run_sandboxed(&workflow.to_string(), HashMap::new(), None))
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    assert!(!marker.exists());
+    let logs = &result.per_task_logs["write"];
+    assert!(
+        logs.iter().any(
+            |line| line.starts_with("Output: [sandbox] Would run: touch ")
+                && line.ends_with("/created")
+        ),
+        "{:?}",
+        logs
+    );
+    assert!(result.per_task_logs.contains_key("note"));
+
+    let store = get_global_store().unwrap();
+    let saved = rt
+        .block_on(store.get_workflow_execution(&result.execution_id))
+        .unwrap();
+    assert!(saved.is_none());
+}
+
+#[test]
+#[serial]
+fn test_sandbox_run_rejects_invalid_workflows() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+
+    let result = rt.block_on(run_sandboxed(r#"{"id": "x"}"#, HashMap::new(), None));
+    assert!(matches!(result, Err(s_e_e_core::CoreError::Validation(_))));
+}
+
+#[test]
+#[serial]
+fn test_dry_run_plans_tasks_and_hides_secrets() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let dir = tempfile::TempDir::new().unwrap();
+    let marker = dir.path().join("created");
+
+    let workflow = json!({
+        "id": "dry-run-preview",
+        "name": "Dry Run Preview",
+        "parameters": [{ "name": "token", "type": "string", "secret": true }],
+        "tasks": [{
+            "id": "login",
+            "name": "Login",
+            "function": {
+                "name": "cli_command",
+                "input": { "command": "login", "args": ["${param.token}"] }
+            },
+            "next_tasks": [{
+                "id": "write",
+                "name": "Write file",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "touch", "args": [marker.to_string_lossy()] }
+                }
+            }]
+        }]
+    });
+    let params = HashMap::from([("token".to_string(), json!("hunter2"))]);
+
+    let result = rt
+        .block_on(dry_run_workflow(&workflow.to_string(), params))
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    assert!(!marker.exists());
+    let planned: Vec<&str> = result.planned.iter().map(|t| t.task_id.as_str()).collect();
+    assert_eq!(planned, ["login", "write"]);
+    let login = serde_json::to_string(&result.planned[0]).unwrap();
+    assert!(!login.contains("hunter2"), "{}", login);
+    assert!(login.contains("[redacted]"), "{}", login);
+
+    let store = get_global_store().unwrap();
+    let saved = rt
+        .block_on(store.get_workflow_execution(&result.execution_id))
+        .unwrap();
+    assert!(saved.is_none());
+}
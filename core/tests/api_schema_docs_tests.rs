@@ -0,0 +1,86 @@
+use s_e_e_core::{init_test_store, schema_docs, SchemaDocs, SchemaDocsFormat, SCHEMA_VERSION};
+use serial_test::serial;
+
+fn docs() -> SchemaDocs {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    rt.block_on(schema_docs()).unwrap()
+}
+
+#[test]
+#[serial]
+fn test_schema_docs_describe_models_and_tables() {
+    let docs = docs();
+    assert_eq!(docs.schema_version, SCHEMA_VERSION);
+    assert_eq!(docs.database_version, SCHEMA_VERSION);
+
+    let task = docs
+        .models
+        .iter()
+        .find(|model| model.name == "TaskExecution")
+        .unwrap();
+    assert_eq!(task.table, "task_executions");
+    let field = |name: &str| task.fields.iter().find(|field| field.name == name).unwrap();
+    assert_eq!(field("status").type_name, "TaskExecutionStatus");
+    assert!(field("status").required);
+    assert_eq!(field("completed_at").type_name, "string (date-time) | null");
+    assert_eq!(field("attempts").type_name, "TaskAttempt[]");
+    assert_eq!(field("environment").type_name, "map<string, string>");
+    assert_eq!(
+        field("failure_reason").type_name,
+        "TaskFailureReason | null"
+    );
+    assert!(!field("cache_hit").required);
+    assert!(field("cache_hit").description.is_some());
+
+    let status = docs
+        .types
+        .iter()
+        .find(|doc| doc.name == "TaskExecutionStatus")
+        .unwrap();
+    assert!(status
+        .variants
+        .iter()
+        .any(|variant| variant.value == "in_progress"));
+    let date_style = docs
+        .types
+        .iter()
+        .find(|doc| doc.name == "DateStyle")
+        .unwrap();
+    assert_eq!(date_style.variants[0].value, "iso");
+    assert!(date_style.variants[0].description.is_some());
+
+    let settings = docs
+        .models
+        .iter()
+        .find(|model| model.name == "AppSettings")
+        .unwrap();
+    assert_eq!(settings.row_id.as_deref(), Some("app_settings"));
+    assert!(docs
+        .tables
+        .iter()
+        .any(|table| table.name == "resource_locks"));
+}
+
+#[test]
+#[serial]
+fn test_schema_docs_render() {
+    let docs = docs();
+
+    let markdown = docs.render(SchemaDocsFormat::Markdown).unwrap();
+    assert!(markdown.starts_with("# Data Model\n"));
+    assert!(markdown.contains("### WorkflowExecution\n"));
+    assert!(markdown.contains("Stored as JSON in `settings.data`, in the row with id `ui_state`."));
+    assert!(markdown.contains("| `completed_at` | string (date-time) \\| null | no |"));
+    assert!(markdown.contains("`{\"$blob\": \"<id>\"}`"));
+    assert!(markdown.contains("### workflow_executions\n"));
+
+    let json = docs.render(SchemaDocsFormat::Json).unwrap();
+    let parsed: SchemaDocs = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, docs);
+    assert_eq!(
+        SchemaDocsFormat::from_name("json"),
+        Some(SchemaDocsFormat::Json)
+    );
+    assert_eq!(SchemaDocsFormat::from_name("yaml"), None);
+}
@@ -0,0 +1,122 @@
+use s_e_e_core::{
+    confirm_manual_task, execute_workflow_by_id_with_params, get_global_store, init_test_store,
+    ManualOutcome, OutputEvent, TaskExecutionStatus, WorkflowDefinition, WorkflowExecutionStatus,
+    SECRET_MASK,
+};
+use serde_json::json;
+use serial_test::serial;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Prints the token, waits for a review, then deploys only if the token
+/// made it through the pause.
+fn secret_workflow() -> WorkflowDefinition {
+    WorkflowDefinition {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: "Secret".to_string(),
+        content: r#"{
+            "id": "secret",
+            "name": "Secret",
+            "parameters": [
+                { "name": "token", "secret": true, "required": true },
+                { "name": "env", "default": "staging" }
+            ],
+            "tasks": [{
+                "id": "login",
+                "name": "Login",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "echo", "args": ["logging in with hunter2"] }
+                },
+                "next_tasks": [{
+                    "id": "review",
+                    "name": "Review",
+                    "function": { "name": "manual", "input": { "instructions": "Approve" } },
+                    "next_tasks": [{
+                        "id": "deploy",
+                        "name": "Deploy",
+                        "when": "param.token == \"hunter2\"",
+                        "function": {
+                            "name": "cli_command",
+                            "input": { "command": "echo", "args": ["deployed"] }
+                        }
+                    }]
+                }]
+            }]
+        }"#
+        .to_string(),
+        ..Default::default()
+    }
+}
+
+/// What the run recorded, leaving out the definition, which has the token
+/// in it for the test's sake.
+fn recorded(execution: &s_e_e_core::WorkflowExecution) -> String {
+    let mut execution = execution.clone();
+    execution.workflow_snapshot = json!({});
+    serde_json::to_string(&execution).unwrap()
+}
+
+#[test]
+#[serial]
+fn test_secret_parameters_are_masked_and_kept_for_resume() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let workflow = secret_workflow();
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let lines = Arc::new(Mutex::new(Vec::<String>::new()));
+    let sink = lines.clone();
+    let callback: s_e_e_core::OutputCallback =
+        Arc::new(move |event: OutputEvent| sink.lock().unwrap().push(event.line));
+    let execution_id = rt
+        .block_on(execute_workflow_by_id_with_params(
+            &workflow.id,
+            HashMap::from([("token".to_string(), json!("hunter2"))]),
+            Some(callback),
+        ))
+        .unwrap()
+        .execution_id;
+
+    let execution = rt
+        .block_on(store.get_workflow_execution(&execution_id))
+        .unwrap()
+        .unwrap();
+    assert_eq!(execution.status, WorkflowExecutionStatus::WaitingForInput);
+    assert_eq!(execution.parameters["token"], json!(SECRET_MASK));
+    assert_eq!(execution.parameters["env"], json!("staging"));
+    assert_eq!(
+        execution.manifest.as_ref().unwrap().parameters["token"],
+        json!(SECRET_MASK)
+    );
+    assert!(execution.sealed_parameters.is_some());
+    let stored = recorded(&execution);
+    assert!(!stored.contains("hunter2"), "{}", stored);
+    assert!(stored.contains("logging in with [redacted]"));
+    assert!(lines
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|line| !line.contains("hunter2")));
+
+    rt.block_on(confirm_manual_task(
+        &execution_id,
+        "review",
+        ManualOutcome::Done,
+        "alice",
+        None,
+    ))
+    .unwrap();
+
+    let execution = rt
+        .block_on(store.get_workflow_execution(&execution_id))
+        .unwrap()
+        .unwrap();
+    assert_eq!(execution.status, WorkflowExecutionStatus::Complete);
+    let deploy = execution.tasks.iter().find(|task| task.id == "deploy");
+    assert_eq!(deploy.unwrap().status, TaskExecutionStatus::Complete);
+    assert_eq!(execution.parameters["token"], json!(SECRET_MASK));
+    assert!(!recorded(&execution).contains("hunter2"));
+}
@@ -0,0 +1,131 @@
+use s_e_e_core::{
+    execution_snapshot_status, get_global_store, init_test_store, save_snapshot_as_workflow,
+    CoreError, SnapshotStatus, WorkflowDefinition, WorkflowExecution, WorkflowExecutionStatus,
+};
+use serde_json::json;
+use serial_test::serial;
+
+fn workflow_content(id: &str, command: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "name": "Deploy",
+        "tasks": [{
+            "id": "ship",
+            "name": "Ship",
+            "function": {
+                "name": "cli_command",
+                "input": { "command": command, "args": [] }
+            }
+        }]
+    })
+}
+
+/// A saved definition and a finished execution that ran its current content.
+fn save_run(rt: &tokio::runtime::Runtime) -> (WorkflowDefinition, WorkflowExecution) {
+    let store = get_global_store().unwrap();
+    let id = uuid::Uuid::new_v4().to_string();
+    let content = workflow_content(&id, "echo");
+    let workflow = WorkflowDefinition {
+        id: id.clone(),
+        name: "Deploy".to_string(),
+        description: None,
+        content: content.to_string(),
+        is_default: false,
+        is_edited: false,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    };
+    let execution = WorkflowExecution {
+        workflow_name: "Deploy".to_string(),
+        workflow_snapshot: content,
+        workflow_id: Some(id),
+        status: WorkflowExecutionStatus::Complete,
+        ..Default::default()
+    };
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+    rt.block_on(store.save_workflow_execution(execution.clone()))
+        .unwrap();
+    (workflow, execution)
+}
+
+#[test]
+#[serial]
+fn test_snapshot_status_tracks_definition_changes() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    let (mut workflow, execution) = save_run(&rt);
+
+    let status = rt.block_on(execution_snapshot_status(&execution.id));
+    assert_eq!(status.unwrap(), SnapshotStatus::Current);
+
+    workflow.content = workflow_content(&workflow.id, "false").to_string();
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+    let status = rt.block_on(execution_snapshot_status(&execution.id));
+    assert_eq!(status.unwrap(), SnapshotStatus::Edited);
+
+    rt.block_on(store.delete_workflow(&workflow.id)).unwrap();
+    let status = rt.block_on(execution_snapshot_status(&execution.id));
+    assert_eq!(status.unwrap(), SnapshotStatus::Deleted);
+
+    let untracked = WorkflowExecution {
+        workflow_id: None,
+        ..execution.clone()
+    };
+    rt.block_on(store.save_workflow_execution(untracked))
+        .unwrap();
+    let status = rt.block_on(execution_snapshot_status(&execution.id));
+    assert_eq!(status.unwrap(), SnapshotStatus::Unknown);
+}
+
+#[test]
+#[serial]
+fn test_save_snapshot_as_workflow() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    let (mut workflow, execution) = save_run(&rt);
+    workflow.content = workflow_content(&workflow.id, "false").to_string();
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let saved = rt
+        .block_on(save_snapshot_as_workflow(&execution.id, None))
+        .unwrap();
+    assert_ne!(saved.id, workflow.id);
+    assert!(saved.name.starts_with("Deploy ("));
+    assert!(!saved.is_default);
+
+    let content: serde_json::Value = serde_json::from_str(&saved.content).unwrap();
+    assert_eq!(content["id"], json!(saved.id));
+    assert_eq!(content["name"], json!(saved.name));
+    assert_eq!(content["tasks"], execution.workflow_snapshot["tasks"]);
+
+    let stored = rt.block_on(store.get_workflow(&saved.id)).unwrap();
+    assert_eq!(stored, Some(saved));
+
+    let named = rt
+        .block_on(save_snapshot_as_workflow(&execution.id, Some("Deploy v1")))
+        .unwrap();
+    assert_eq!(named.name, "Deploy v1");
+}
+
+#[test]
+#[serial]
+fn test_save_snapshot_requires_snapshot() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+
+    let execution = WorkflowExecution {
+        workflow_snapshot: json!({ "tasks": [] }),
+        ..Default::default()
+    };
+    rt.block_on(store.save_workflow_execution(execution.clone()))
+        .unwrap();
+
+    let result = rt.block_on(save_snapshot_as_workflow(&execution.id, None));
+    assert!(matches!(result, Err(CoreError::Execution(_))));
+
+    let missing = rt.block_on(save_snapshot_as_workflow("missing", None));
+    assert!(matches!(missing, Err(CoreError::WorkflowNotFound(_))));
+}
@@ -0,0 +1,237 @@
+use s_e_e_core::{
+    execute_workflow_by_id, expand_snippets, get_global_store, init_test_store,
+    insert_snippet_reference, list_snippets, populate_initial_data, save_snippet,
+    validate_workflow_json, SnippetError, SnippetParameter, TaskSnippet, WorkflowDefinition,
+};
+use serde_json::{json, Value};
+use serial_test::serial;
+
+fn echo_snippet() -> TaskSnippet {
+    TaskSnippet {
+        id: "echo".to_string(),
+        name: "Echo".to_string(),
+        parameters: vec![
+            SnippetParameter {
+                name: "text".to_string(),
+                default: None,
+                description: None,
+            },
+            SnippetParameter {
+                name: "times".to_string(),
+                default: Some(json!(1)),
+                description: None,
+            },
+        ],
+        task: json!({
+            "name": "Echo {{snippet.text}}",
+            "function": {
+                "name": "cli_command",
+                "input": {
+                    "command": "echo",
+                    "args": ["{{ snippet.text }} for {{param.who}}"],
+                    "repeat": "{{snippet.times}}"
+                }
+            }
+        }),
+        ..Default::default()
+    }
+}
+
+fn expand(workflow: &mut Value) -> Result<(), SnippetError> {
+    let snippet = echo_snippet();
+    expand_snippets(workflow, |id| (id == snippet.id).then(|| snippet.clone()))
+}
+
+#[test]
+fn test_expand_snippets() {
+    let mut workflow = json!({
+        "id": "w",
+        "name": "W",
+        "tasks": [{
+            "id": "greet",
+            "snippet": "echo",
+            "with": { "text": "hello" },
+            "next_tasks": [{
+                "id": "again",
+                "name": "Again",
+                "snippet": "echo",
+                "with": { "text": "bye", "times": 2 }
+            }]
+        }]
+    });
+    expand(&mut workflow).unwrap();
+
+    assert_eq!(
+        workflow["tasks"][0],
+        json!({
+            "id": "greet",
+            "name": "Echo hello",
+            "function": {
+                "name": "cli_command",
+                "input": {
+                    "command": "echo",
+                    "args": ["hello for {{param.who}}"],
+                    "repeat": 1
+                }
+            },
+            "next_tasks": [{
+                "id": "again",
+                "name": "Again",
+                "function": {
+                    "name": "cli_command",
+                    "input": {
+                        "command": "echo",
+                        "args": ["bye for {{param.who}}"],
+                        "repeat": 2
+                    }
+                }
+            }]
+        })
+    );
+}
+
+#[test]
+fn test_expand_snippets_reports_bad_references() {
+    let reference = |task: Value| json!({ "id": "w", "name": "W", "tasks": [task] });
+
+    let mut missing = reference(json!({ "id": "t", "snippet": "nope" }));
+    assert!(matches!(
+        expand(&mut missing),
+        Err(SnippetError::NotFound { snippet, .. }) if snippet == "nope"
+    ));
+
+    let mut no_text = reference(json!({ "id": "t", "snippet": "echo" }));
+    assert!(matches!(
+        expand(&mut no_text),
+        Err(SnippetError::MissingParameter { parameter, .. }) if parameter == "text"
+    ));
+
+    let mut extra = reference(json!({
+        "id": "t",
+        "snippet": "echo",
+        "with": { "text": "hi", "volume": 11 }
+    }));
+    assert!(matches!(
+        expand(&mut extra),
+        Err(SnippetError::UnknownParameter { parameter, .. }) if parameter == "volume"
+    ));
+}
+
+#[test]
+fn test_snippet_references_pass_validation() {
+    let workflow = json!({
+        "id": "w",
+        "name": "W",
+        "tasks": [{ "id": "greet", "snippet": "echo", "with": { "text": "hello" } }]
+    });
+    assert!(validate_workflow_json(&workflow.to_string()).is_ok());
+
+    let both = json!({
+        "id": "w",
+        "name": "W",
+        "tasks": [{
+            "id": "greet",
+            "snippet": "echo",
+            "function": { "name": "cli_command", "input": { "command": "echo" } }
+        }]
+    });
+    assert!(validate_workflow_json(&both.to_string()).is_err());
+
+    let neither = json!({ "id": "w", "name": "W", "tasks": [{ "id": "greet", "name": "Greet" }] });
+    assert!(validate_workflow_json(&neither.to_string()).is_err());
+}
+
+#[test]
+fn test_insert_snippet_reference() {
+    let content = json!({
+        "id": "w",
+        "name": "W",
+        "tasks": [{ "id": "echo", "snippet": "echo", "with": { "text": "hi" } }]
+    })
+    .to_string();
+
+    let inserted: Value =
+        serde_json::from_str(&insert_snippet_reference(&content, &echo_snippet()).unwrap())
+            .unwrap();
+    assert_eq!(
+        inserted["tasks"][1],
+        json!({ "id": "echo_2", "snippet": "echo", "with": { "text": "" } })
+    );
+
+    let system = TaskSnippet {
+        id: "system:checkout-repo".to_string(),
+        parameters: Vec::new(),
+        ..echo_snippet()
+    };
+    let inserted: Value = serde_json::from_str(
+        &insert_snippet_reference(r#"{"id": "w", "name": "W"}"#, &system).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        inserted["tasks"],
+        json!([{ "id": "checkout_repo", "snippet": "system:checkout-repo" }])
+    );
+}
+
+#[test]
+#[serial]
+fn test_workflow_runs_saved_snippet() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+
+    let mut snippet = echo_snippet();
+    snippet.task["function"]["input"] = json!({ "command": "echo", "args": ["{{snippet.text}}"] });
+    rt.block_on(save_snippet(snippet.clone())).unwrap();
+    let workflow = WorkflowDefinition {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: "Greet".to_string(),
+        content: json!({
+            "id": "greet",
+            "name": "Greet",
+            "tasks": [{ "id": "hello", "snippet": "echo", "with": { "text": "from a snippet" } }]
+        })
+        .to_string(),
+        ..Default::default()
+    };
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let run = |rt: &tokio::runtime::Runtime| {
+        let result = rt
+            .block_on(execute_workflow_by_id(&workflow.id, None))
+            .unwrap();
+        assert!(result.success, "{:?}", result.errors);
+        let execution = rt
+            .block_on(store.get_workflow_execution(&result.execution_id))
+            .unwrap()
+            .unwrap();
+        // The execution keeps the expanded definition it ran.
+        assert_eq!(
+            execution.workflow_snapshot["tasks"][0]["name"],
+            "Echo from a snippet"
+        );
+        execution.tasks[0].output.clone().unwrap_or_default()
+    };
+    assert!(run(&rt).contains("from a snippet"));
+
+    // Editing the snippet changes every workflow that uses it.
+    snippet.task["function"]["input"]["args"] = json!(["updated {{snippet.text}}"]);
+    rt.block_on(save_snippet(snippet)).unwrap();
+    assert!(run(&rt).contains("updated from a snippet"));
+}
+
+#[test]
+#[serial]
+fn test_default_snippets_are_populated() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+
+    rt.block_on(populate_initial_data()).unwrap();
+    let snippets = rt.block_on(list_snippets()).unwrap();
+    let ids: Vec<&str> = snippets.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(ids, vec!["system:checkout-repo", "system:notify-slack"]);
+    assert!(snippets.iter().all(|s| s.validate().is_ok()));
+}
@@ -0,0 +1,45 @@
+use s_e_e_core::{
+    get_global_store, init_test_store, last_storage_maintenance, maintain_storage_if_idle,
+    run_storage_maintenance, WorkflowExecution, WorkflowExecutionStatus,
+};
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn test_idle_maintenance_runs_once_per_interval() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+
+    let first = rt.block_on(maintain_storage_if_idle()).unwrap();
+    assert!(first.is_some());
+    assert_eq!(rt.block_on(last_storage_maintenance()).unwrap(), first);
+
+    assert_eq!(rt.block_on(maintain_storage_if_idle()).unwrap(), None);
+
+    // A manual run is never skipped.
+    let manual = rt.block_on(run_storage_maintenance()).unwrap();
+    assert_eq!(
+        rt.block_on(last_storage_maintenance()).unwrap(),
+        Some(manual)
+    );
+}
+
+#[test]
+#[serial]
+fn test_idle_maintenance_waits_for_running_executions() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    let running = WorkflowExecution {
+        workflow_name: "Long Build".to_string(),
+        status: WorkflowExecutionStatus::Running,
+        ..Default::default()
+    };
+    rt.block_on(store.save_workflow_execution(running)).unwrap();
+
+    assert_eq!(rt.block_on(maintain_storage_if_idle()).unwrap(), None);
+    assert_eq!(rt.block_on(last_storage_maintenance()).unwrap(), None);
+}
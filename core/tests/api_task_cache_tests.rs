@@ -0,0 +1,69 @@
+use s_e_e_core::{
+    clear_task_result_cache, execute_workflow_by_id, get_global_store, init_test_store,
+    TaskExecution, WorkflowDefinition,
+};
+use serial_test::serial;
+
+fn cached_workflow() -> WorkflowDefinition {
+    WorkflowDefinition {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: "Cached Workflow".to_string(),
+        description: None,
+        content: r#"{
+            "id": "cached-workflow",
+            "name": "Cached Workflow",
+            "tasks": [
+                {
+                    "id": "stamp",
+                    "name": "Stamp",
+                    "cache": { "ttl_seconds": 3600 },
+                    "function": {
+                        "name": "cli_command",
+                        "input": { "command": "echo", "args": ["cached output"] }
+                    }
+                }
+            ]
+        }"#
+        .to_string(),
+        is_default: false,
+        is_edited: false,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    }
+}
+
+fn run_task(rt: &tokio::runtime::Runtime, workflow_id: &str) -> TaskExecution {
+    let result = rt
+        .block_on(execute_workflow_by_id(workflow_id, None))
+        .unwrap();
+    assert!(result.success, "{:?}", result.errors);
+
+    let store = get_global_store().unwrap();
+    let execution = rt
+        .block_on(store.get_workflow_execution(&result.execution_id))
+        .unwrap()
+        .unwrap();
+    execution.tasks.into_iter().next().unwrap()
+}
+
+#[test]
+#[serial]
+fn test_cached_task_is_marked_on_rerun() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    rt.block_on(clear_task_result_cache()).unwrap();
+
+    let store = get_global_store().unwrap();
+    let workflow = cached_workflow();
+    rt.block_on(store.save_workflow(&workflow)).unwrap();
+
+    let first = run_task(&rt, &workflow.id);
+    assert!(!first.cache_hit);
+
+    let second = run_task(&rt, &workflow.id);
+    assert!(second.cache_hit);
+    assert_eq!(second.output, first.output);
+
+    assert_eq!(rt.block_on(clear_task_result_cache()).unwrap(), 1);
+    assert!(!run_task(&rt, &workflow.id).cache_hit);
+}
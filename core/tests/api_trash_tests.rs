@@ -0,0 +1,51 @@
+use s_e_e_core::{
+    delete_prompts, delete_workflows, get_global_store, init_test_store, undo_deletion, Prompt,
+    TrashKind, WorkflowDefinition,
+};
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn test_bulk_delete_and_undo() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    for id in ["build", "deploy"] {
+        rt.block_on(store.save_workflow(&WorkflowDefinition {
+            id: id.to_string(),
+            name: id.to_string(),
+            content: format!(r#"{{"id":"{}","name":"{}","tasks":[]}}"#, id, id),
+            ..Default::default()
+        }))
+        .unwrap();
+    }
+    rt.block_on(store.save_prompt(&Prompt {
+        id: "greeting".to_string(),
+        name: "Greeting".to_string(),
+        content: "Hello".to_string(),
+        created_at: chrono::Utc::now(),
+        tags: Vec::new(),
+    }))
+    .unwrap();
+
+    let ids = vec!["build".to_string(), "deploy".to_string()];
+    let workflows = rt.block_on(delete_workflows(&ids)).unwrap();
+    assert_eq!(workflows.kind, TrashKind::Workflow);
+    assert_eq!(workflows.count, 2);
+    assert!(rt.block_on(store.list_workflows()).unwrap().is_empty());
+
+    let prompts = rt
+        .block_on(delete_prompts(&["greeting".to_string()]))
+        .unwrap();
+    assert_eq!(prompts.count, 1);
+    assert_ne!(prompts.id, workflows.id);
+
+    assert_eq!(rt.block_on(undo_deletion(&workflows.id)).unwrap(), ids);
+    assert_eq!(rt.block_on(store.list_workflows()).unwrap().len(), 2);
+    assert!(rt.block_on(store.list_prompts()).unwrap().is_empty());
+    assert!(rt
+        .block_on(undo_deletion(&workflows.id))
+        .unwrap()
+        .is_empty());
+}
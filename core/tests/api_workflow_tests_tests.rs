@@ -0,0 +1,135 @@
+use s_e_e_core::{
+    create_test_from_execution, delete_workflow_test, execute_workflow_by_id, get_global_store,
+    init_test_store, list_workflow_tests, run_workflow_tests, CoreError, WorkflowDefinition,
+};
+use serde_json::{json, Value};
+use serial_test::serial;
+
+/// greet → shout, where shout echoes greet's output.
+fn greeting_workflow() -> WorkflowDefinition {
+    let content = json!({
+        "id": "greeting",
+        "name": "Greeting",
+        "tasks": [{
+            "id": "greet",
+            "name": "Greet",
+            "function": { "name": "cli_command", "input": { "command": "echo", "args": ["hello"] } },
+            "next_tasks": [{
+                "id": "shout",
+                "name": "Shout",
+                "function": { "name": "cli_command", "input": { "command": "echo", "args": ["{{data.greet}}!"] } }
+            }]
+        }]
+    });
+    WorkflowDefinition {
+        id: "greeting".to_string(),
+        name: "Greeting".to_string(),
+        content: content.to_string(),
+        ..Default::default()
+    }
+}
+
+fn setup(rt: &tokio::runtime::Runtime) -> String {
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+    rt.block_on(store.clear_all_data()).unwrap();
+    rt.block_on(store.save_workflow(&greeting_workflow()))
+        .unwrap();
+    let result = rt
+        .block_on(execute_workflow_by_id("greeting", None))
+        .unwrap();
+    assert!(result.success);
+    result.execution_id
+}
+
+#[test]
+#[serial]
+fn test_recorded_test_passes_until_the_workflow_changes() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let execution_id = setup(&rt);
+
+    let test = rt
+        .block_on(create_test_from_execution(
+            &execution_id,
+            Some("happy path"),
+        ))
+        .unwrap();
+    assert_eq!(test.workflow_id, "greeting");
+    assert!(test.expected_success);
+    assert_eq!(test.task_results.len(), 2);
+    assert_eq!(
+        test.expected_context["greet"],
+        test.task_results["greet"].output
+    );
+    assert_ne!(test.expected_context["greet"], Value::Null);
+
+    let reports = rt.block_on(run_workflow_tests("greeting")).unwrap();
+    assert_eq!(reports.len(), 1);
+    assert!(reports[0].passed, "{:?}", reports[0]);
+    assert_eq!(reports[0].name, "happy path");
+
+    // Removing a task changes the context the run ends with.
+    let store = get_global_store().unwrap();
+    let content = json!({
+        "id": "greeting",
+        "name": "Greeting",
+        "tasks": [{
+            "id": "greet",
+            "name": "Greet",
+            "function": { "name": "cli_command", "input": { "command": "echo", "args": ["hello"] } }
+        }]
+    });
+    rt.block_on(store.save_workflow(&WorkflowDefinition {
+        content: content.to_string(),
+        ..greeting_workflow()
+    }))
+    .unwrap();
+    let reports = rt.block_on(run_workflow_tests("greeting")).unwrap();
+    assert!(!reports[0].passed);
+    assert_eq!(reports[0].mismatch_count, 1);
+    assert_eq!(reports[0].mismatches[0].path, "/shout");
+    assert_eq!(reports[0].mismatches[0].actual, None);
+
+    rt.block_on(delete_workflow_test(&test.id)).unwrap();
+    assert!(rt
+        .block_on(list_workflow_tests("greeting"))
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+#[serial]
+fn test_tasks_added_after_recording_fail_the_test() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let execution_id = setup(&rt);
+    rt.block_on(create_test_from_execution(&execution_id, None))
+        .unwrap();
+
+    let store = get_global_store().unwrap();
+    let mut content: Value = serde_json::from_str(&greeting_workflow().content).unwrap();
+    content["tasks"][0]["next_tasks"][0]["next_tasks"] = json!([{
+        "id": "notify",
+        "name": "Notify",
+        "function": { "name": "cli_command", "input": { "command": "echo", "args": ["sent"] } }
+    }]);
+    rt.block_on(store.save_workflow(&WorkflowDefinition {
+        content: content.to_string(),
+        ..greeting_workflow()
+    }))
+    .unwrap();
+
+    let reports = rt.block_on(run_workflow_tests("greeting")).unwrap();
+    assert!(!reports[0].passed);
+    assert_eq!(reports[0].unrecorded_tasks, vec!["notify".to_string()]);
+    assert!(!reports[0].actual_success);
+}
+
+#[test]
+#[serial]
+fn test_unknown_execution_cannot_be_recorded() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+
+    let result = rt.block_on(create_test_from_execution("missing", None));
+    assert!(matches!(result, Err(CoreError::WorkflowNotFound(_))));
+}
@@ -18,6 +18,8 @@ fn test_audit_status_conversion() {
             status: engine_status,
             timestamp: "2024-01-15T10:30:45Z".to_string(),
             changes_count: 0,
+            changes: Vec::new(),
+            changes_truncated: false,
             message: "Test".to_string(),
         };
 
@@ -41,6 +43,8 @@ fn test_timestamp_parsing() {
             status: EngineAuditStatus::Success,
             timestamp: timestamp_str.to_string(),
             changes_count: 0,
+            changes: Vec::new(),
+            changes_truncated: false,
             message: "Test".to_string(),
         };
 
@@ -69,6 +73,8 @@ fn test_invalid_timestamp_parsing() {
             status: EngineAuditStatus::Success,
             timestamp: timestamp_str.to_string(),
             changes_count: 0,
+            changes: Vec::new(),
+            changes_truncated: false,
             message: "Test".to_string(),
         };
 
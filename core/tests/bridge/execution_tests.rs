@@ -1,5 +1,3 @@
-
-
 use core::bridge::*;
 use s_e_e_engine::WorkflowResult as EngineWorkflowResult;
 
@@ -12,10 +10,14 @@ fn test_engine_result_to_core_result() {
         audit_trail: vec![],
         per_task_logs: std::collections::HashMap::new(),
         errors: vec![],
+        failure_reasons: std::collections::HashMap::new(),
+        exit_codes: std::collections::HashMap::new(),
+        output_stats: std::collections::HashMap::new(),
     };
 
     let execution_id = "exec-123".to_string();
-    let core_result = core::bridge::workflow::engine_result_to_core_result(engine_result, execution_id.clone());
+    let core_result =
+        core::bridge::workflow::engine_result_to_core_result(engine_result, execution_id.clone());
 
     assert!(core_result.success);
     assert_eq!(core_result.workflow_name, "Test Workflow");
@@ -27,25 +29,23 @@ fn test_engine_result_to_core_result() {
 
 #[test]
 fn test_engine_result_to_core_result_with_data() {
-    use engine::{TaskInfo, TaskStatus, AuditEntry, AuditStatus};
+    use engine::{AuditEntry, AuditStatus, TaskInfo, TaskStatus};
 
-    let tasks = vec![
-        TaskInfo {
-            id: "task-1".to_string(),
-            name: "Test Task".to_string(),
-            status: TaskStatus::Complete,
-        }
-    ];
+    let tasks = vec![TaskInfo {
+        id: "task-1".to_string(),
+        name: "Test Task".to_string(),
+        status: TaskStatus::Complete,
+    }];
 
-    let audit_trail = vec![
-        AuditEntry {
-            task_id: "task-1".to_string(),
-            status: AuditStatus::Success,
-            timestamp: "2024-01-15T10:30:45Z".to_string(),
-            changes_count: 5,
-            message: "Task completed".to_string(),
-        }
-    ];
+    let audit_trail = vec![AuditEntry {
+        task_id: "task-1".to_string(),
+        status: AuditStatus::Success,
+        timestamp: "2024-01-15T10:30:45Z".to_string(),
+        changes_count: 5,
+        changes: Vec::new(),
+        changes_truncated: false,
+        message: "Task completed".to_string(),
+    }];
 
     let mut per_task_logs = std::collections::HashMap::new();
     per_task_logs.insert("task-1".to_string(), vec!["output line 1".to_string()]);
@@ -59,10 +59,14 @@ fn test_engine_result_to_core_result_with_data() {
         audit_trail,
         per_task_logs,
         errors,
+        failure_reasons: std::collections::HashMap::new(),
+        exit_codes: std::collections::HashMap::new(),
+        output_stats: std::collections::HashMap::new(),
     };
 
     let execution_id = "exec-456".to_string();
-    let core_result = core::bridge::workflow::engine_result_to_core_result(engine_result, execution_id.clone());
+    let core_result =
+        core::bridge::workflow::engine_result_to_core_result(engine_result, execution_id.clone());
 
     assert!(!core_result.success);
     assert_eq!(core_result.workflow_name, "Failed Workflow");
@@ -13,6 +13,10 @@ fn test_workflow_result_creation() {
         audit_trail: vec![],
         per_task_logs: std::collections::HashMap::new(),
         errors: vec![],
+        failure_reasons: std::collections::HashMap::new(),
+        exit_codes: std::collections::HashMap::new(),
+        output_stats: std::collections::HashMap::new(),
+        cache_hits: std::collections::HashSet::new(),
     };
 
     assert!(result.success);
@@ -16,6 +16,8 @@ fn test_audit_status_conversion() {
             status: engine_status,
             timestamp: "2024-01-15T10:30:45Z".to_string(),
             changes_count: 0,
+            changes: Vec::new(),
+            changes_truncated: false,
             message: "Test".to_string(),
         };
 
@@ -39,6 +41,8 @@ fn test_timestamp_parsing() {
             status: EngineAuditStatus::Success,
             timestamp: timestamp_str.to_string(),
             changes_count: 0,
+            changes: Vec::new(),
+            changes_truncated: false,
             message: "Test".to_string(),
         };
 
@@ -71,6 +75,8 @@ fn test_invalid_timestamp_parsing() {
             status: EngineAuditStatus::Success,
             timestamp: timestamp_str.to_string(),
             changes_count: 0,
+            changes: Vec::new(),
+            changes_truncated: false,
             message: "Test".to_string(),
         };
 
@@ -89,3 +95,40 @@ fn test_invalid_timestamp_parsing() {
         }
     }
 }
+
+#[test]
+fn test_audit_changes_round_trip() {
+    use s_e_e_engine::{ChangeOp, ContextChange};
+
+    let entry = AuditEntry {
+        task_id: "build".to_string(),
+        status: EngineAuditStatus::Success,
+        timestamp: "2024-01-15T10:30:45Z".to_string(),
+        changes_count: 2,
+        message: "Completed task: Build".to_string(),
+        changes: vec![
+            ContextChange {
+                op: ChangeOp::Add,
+                path: "/build".to_string(),
+                before: None,
+                after: Some(serde_json::json!({"status": "ok"})),
+            },
+            ContextChange {
+                op: ChangeOp::Remove,
+                path: "/stale".to_string(),
+                before: Some(serde_json::json!(1)),
+                after: None,
+            },
+        ],
+        changes_truncated: true,
+    };
+
+    let event = audit::audit_entry_to_event(&entry).unwrap();
+    assert_eq!(event.changes.len(), 2);
+    assert_eq!(event.changes[0].op, s_e_e_persistence::AuditChangeOp::Add);
+    assert!(event.changes_truncated);
+
+    let back = audit::audit_event_to_entry(&event);
+    assert_eq!(back.changes, entry.changes);
+    assert!(back.changes_truncated);
+}
@@ -10,6 +10,16 @@ fn test_engine_result_to_core_result() {
         audit_trail: vec![],
         per_task_logs: std::collections::HashMap::new(),
         errors: vec![],
+        failure_reasons: std::collections::HashMap::new(),
+        exit_codes: std::collections::HashMap::new(),
+        output_stats: std::collections::HashMap::new(),
+        cache_hits: std::collections::HashSet::new(),
+        environments: std::collections::HashMap::new(),
+        workspace: None,
+        stages: Vec::new(),
+        outputs: Default::default(),
+        planned: Vec::new(),
+        durations_ms: std::collections::HashMap::new(),
     };
 
     let execution_id = "exec-123".to_string();
@@ -38,6 +48,8 @@ fn test_engine_result_to_core_result_with_data() {
         status: AuditStatus::Success,
         timestamp: "2024-01-15T10:30:45Z".to_string(),
         changes_count: 5,
+        changes: Vec::new(),
+        changes_truncated: false,
         message: "Task completed".to_string(),
     }];
 
@@ -53,6 +65,16 @@ fn test_engine_result_to_core_result_with_data() {
         audit_trail,
         per_task_logs,
         errors,
+        failure_reasons: std::collections::HashMap::new(),
+        exit_codes: std::collections::HashMap::new(),
+        output_stats: std::collections::HashMap::new(),
+        cache_hits: std::collections::HashSet::new(),
+        environments: std::collections::HashMap::new(),
+        workspace: None,
+        stages: Vec::new(),
+        outputs: Default::default(),
+        planned: Vec::new(),
+        durations_ms: std::collections::HashMap::new(),
     };
 
     let execution_id = "exec-456".to_string();
@@ -66,3 +88,244 @@ fn test_engine_result_to_core_result_with_data() {
     assert_eq!(core_result.errors.len(), 1);
     assert_eq!(core_result.errors[0], "Some error");
 }
+
+#[test]
+fn test_failure_reasons_recorded_on_task_executions() {
+    use s_e_e_engine::{FailureReason, TaskInfo, TaskStatus};
+    use s_e_e_persistence::TaskFailureReason;
+
+    let tasks = vec![
+        TaskInfo {
+            id: "slow".to_string(),
+            name: "Slow Task".to_string(),
+            status: TaskStatus::Failed,
+        },
+        TaskInfo {
+            id: "fast".to_string(),
+            name: "Fast Task".to_string(),
+            status: TaskStatus::Complete,
+        },
+    ];
+
+    let mut failure_reasons = std::collections::HashMap::new();
+    failure_reasons.insert("slow".to_string(), FailureReason::MemoryLimitExceeded);
+
+    let engine_result = EngineWorkflowResult {
+        success: false,
+        workflow_name: "Limited Workflow".to_string(),
+        tasks,
+        audit_trail: vec![],
+        per_task_logs: std::collections::HashMap::new(),
+        errors: vec!["Command exceeded its memory limit".to_string()],
+        failure_reasons,
+        exit_codes: std::collections::HashMap::new(),
+        output_stats: std::collections::HashMap::new(),
+        cache_hits: std::collections::HashSet::new(),
+        environments: std::collections::HashMap::new(),
+        workspace: None,
+        stages: Vec::new(),
+        outputs: Default::default(),
+        planned: Vec::new(),
+        durations_ms: std::collections::HashMap::new(),
+    };
+
+    let execution = execution::workflow_result_to_execution(
+        engine_result,
+        "exec-789".to_string(),
+        chrono::Utc::now(),
+    );
+
+    let slow = execution.tasks.iter().find(|t| t.id == "slow").unwrap();
+    let fast = execution.tasks.iter().find(|t| t.id == "fast").unwrap();
+    assert_eq!(
+        slow.failure_reason,
+        Some(TaskFailureReason::MemoryLimitExceeded)
+    );
+    assert_eq!(fast.failure_reason, None);
+}
+
+#[test]
+fn test_output_stats_recorded_on_task_executions() {
+    use s_e_e_engine::{OutputStats, TaskInfo, TaskStatus};
+
+    let tasks = vec![TaskInfo {
+        id: "noisy".to_string(),
+        name: "Noisy Task".to_string(),
+        status: TaskStatus::Complete,
+    }];
+
+    let mut output_stats = std::collections::HashMap::new();
+    output_stats.insert(
+        "noisy".to_string(),
+        OutputStats {
+            total_bytes: 5_000_000,
+            truncated: true,
+        },
+    );
+
+    let engine_result = EngineWorkflowResult {
+        success: true,
+        workflow_name: "Noisy Workflow".to_string(),
+        tasks,
+        audit_trail: vec![],
+        per_task_logs: std::collections::HashMap::new(),
+        errors: vec![],
+        failure_reasons: std::collections::HashMap::new(),
+        exit_codes: std::collections::HashMap::new(),
+        output_stats,
+        cache_hits: std::collections::HashSet::new(),
+        environments: std::collections::HashMap::new(),
+        workspace: None,
+        stages: Vec::new(),
+        outputs: Default::default(),
+        planned: Vec::new(),
+        durations_ms: std::collections::HashMap::new(),
+    };
+
+    let execution = execution::workflow_result_to_execution(
+        engine_result,
+        "exec-noisy".to_string(),
+        chrono::Utc::now(),
+    );
+
+    let noisy = &execution.tasks[0];
+    assert_eq!(noisy.output_bytes, Some(5_000_000));
+    assert!(noisy.output_truncated);
+}
+
+#[test]
+fn test_cache_hits_recorded_on_task_executions() {
+    use s_e_e_engine::{TaskInfo, TaskStatus};
+
+    let task = |id: &str| TaskInfo {
+        id: id.to_string(),
+        name: id.to_string(),
+        status: TaskStatus::Complete,
+    };
+    let engine_result = EngineWorkflowResult {
+        success: true,
+        workflow_name: "Cached Workflow".to_string(),
+        tasks: vec![task("cached"), task("fresh")],
+        audit_trail: vec![],
+        per_task_logs: std::collections::HashMap::new(),
+        errors: vec![],
+        failure_reasons: std::collections::HashMap::new(),
+        exit_codes: std::collections::HashMap::new(),
+        output_stats: std::collections::HashMap::new(),
+        cache_hits: ["cached".to_string()].into_iter().collect(),
+        environments: std::collections::HashMap::new(),
+        workspace: None,
+        stages: Vec::new(),
+        outputs: Default::default(),
+        planned: Vec::new(),
+        durations_ms: std::collections::HashMap::new(),
+    };
+
+    let execution = execution::workflow_result_to_execution(
+        engine_result,
+        "exec-cached".to_string(),
+        chrono::Utc::now(),
+    );
+
+    assert!(execution.tasks[0].cache_hit);
+    assert!(!execution.tasks[1].cache_hit);
+}
+
+#[test]
+fn test_environments_recorded_on_task_executions() {
+    use s_e_e_engine::{TaskInfo, TaskStatus};
+
+    let task = |id: &str| TaskInfo {
+        id: id.to_string(),
+        name: id.to_string(),
+        status: TaskStatus::Complete,
+    };
+    let environment: std::collections::BTreeMap<String, String> =
+        [("HOME".to_string(), "/home/runner".to_string())].into();
+    let engine_result = EngineWorkflowResult {
+        success: true,
+        workflow_name: "Env Workflow".to_string(),
+        tasks: vec![task("command"), task("input")],
+        audit_trail: vec![],
+        per_task_logs: std::collections::HashMap::new(),
+        errors: vec![],
+        failure_reasons: std::collections::HashMap::new(),
+        exit_codes: std::collections::HashMap::new(),
+        output_stats: std::collections::HashMap::new(),
+        cache_hits: std::collections::HashSet::new(),
+        environments: [("command".to_string(), environment.clone())].into(),
+        workspace: None,
+        stages: Vec::new(),
+        outputs: Default::default(),
+        planned: Vec::new(),
+        durations_ms: std::collections::HashMap::new(),
+    };
+
+    let execution = execution::workflow_result_to_execution(
+        engine_result,
+        "exec-env".to_string(),
+        chrono::Utc::now(),
+    );
+
+    assert_eq!(execution.tasks[0].environment, environment);
+    assert!(execution.tasks[1].environment.is_empty());
+}
+
+#[test]
+fn test_link_task_prompts_from_snapshot() {
+    use s_e_e_engine::{TaskInfo, TaskStatus};
+
+    let task = |id: &str| TaskInfo {
+        id: id.to_string(),
+        name: id.to_string(),
+        status: TaskStatus::Complete,
+    };
+    let engine_result = EngineWorkflowResult {
+        success: true,
+        workflow_name: "Agents".to_string(),
+        tasks: vec![task("plain"), task("agent"), task("nested")],
+        audit_trail: vec![],
+        per_task_logs: std::collections::HashMap::new(),
+        errors: vec![],
+        failure_reasons: std::collections::HashMap::new(),
+        exit_codes: std::collections::HashMap::new(),
+        output_stats: std::collections::HashMap::new(),
+        cache_hits: std::collections::HashSet::new(),
+        environments: std::collections::HashMap::new(),
+        workspace: None,
+        stages: Vec::new(),
+        outputs: Default::default(),
+        planned: Vec::new(),
+        durations_ms: std::collections::HashMap::new(),
+    };
+
+    let mut execution = execution::workflow_result_to_execution(
+        engine_result,
+        "exec-agents".to_string(),
+        chrono::Utc::now(),
+    );
+    execution.workflow_snapshot = serde_json::json!({
+        "tasks": [
+            { "id": "plain", "function": { "name": "cli_command", "input": { "command": "true" } } },
+            {
+                "id": "agent",
+                "function": { "name": "cursor_agent", "input": { "prompt": "Review", "prompt_id": "system:code-review" } },
+                "next_tasks": [
+                    { "id": "nested", "function": { "name": "cursor_agent", "input": { "prompt": "Fix", "prompt_id": "system:bug-fix" } } }
+                ]
+            }
+        ]
+    });
+
+    execution::link_task_prompts(&mut execution);
+
+    let prompt_ids: Vec<Option<&str>> = execution
+        .tasks
+        .iter()
+        .map(|t| t.prompt_id.as_deref())
+        .collect();
+    assert_eq!(
+        prompt_ids,
+        vec![None, Some("system:code-review"), Some("system:bug-fix")]
+    );
+}
@@ -17,6 +17,7 @@ fn test_task_status_conversion() {
             EngineTaskStatus::WaitingForInput,
             TaskExecutionStatus::WaitingForInput,
         ),
+        (EngineTaskStatus::Skipped, TaskExecutionStatus::Skipped),
     ];
 
     for (engine_status, expected_persistence_status) in test_cases {
@@ -1,4 +1,4 @@
-use s_e_e_core::{bridge::*, CoreError};
+use s_e_e_core::{bridge::*, CoreError, ErrorCategory, FailureReason, OutputEvent, OutputEvents};
 use s_e_e_persistence::WorkflowDefinition;
 
 #[test]
@@ -11,6 +11,15 @@ fn test_workflow_result_creation() {
         audit_trail: vec![],
         per_task_logs: std::collections::HashMap::new(),
         errors: vec![],
+        failure_reasons: std::collections::HashMap::new(),
+        exit_codes: std::collections::HashMap::new(),
+        output_stats: std::collections::HashMap::new(),
+        cache_hits: std::collections::HashSet::new(),
+        workspace: None,
+        stages: Vec::new(),
+        outputs: Default::default(),
+        planned: Vec::new(),
+        profile: None,
     };
 
     assert!(result.success);
@@ -18,13 +27,52 @@ fn test_workflow_result_creation() {
     assert_eq!(result.execution_id, "exec-123");
 }
 
+#[test]
+fn test_workflow_result_failure_category() {
+    let task = |id: &str| s_e_e_core::TaskInfo {
+        id: id.to_string(),
+        name: id.to_string(),
+        status: s_e_e_engine::TaskStatus::Failed,
+    };
+    let mut result = WorkflowResult {
+        success: false,
+        workflow_name: "Test Workflow".to_string(),
+        execution_id: "exec-123".to_string(),
+        tasks: vec![task("build"), task("deploy")],
+        audit_trail: vec![],
+        per_task_logs: std::collections::HashMap::new(),
+        errors: vec![],
+        failure_reasons: std::collections::HashMap::new(),
+        exit_codes: [("deploy".to_string(), 3), ("build".to_string(), 2)].into(),
+        output_stats: std::collections::HashMap::new(),
+        cache_hits: std::collections::HashSet::new(),
+        workspace: None,
+        stages: Vec::new(),
+        outputs: Default::default(),
+        planned: Vec::new(),
+        profile: None,
+    };
+    assert_eq!(
+        result.failure_category(),
+        Some(ErrorCategory::HandlerFailure { exit_code: Some(2) })
+    );
+
+    result
+        .failure_reasons
+        .insert("deploy".to_string(), FailureReason::Timeout);
+    assert_eq!(result.failure_category(), Some(ErrorCategory::Timeout));
+
+    result.success = true;
+    assert_eq!(result.failure_category(), None);
+}
+
 #[test]
 fn test_output_callback_type() {
-    let callback: OutputCallback = std::sync::Arc::new(|msg: String| {
-        assert_eq!(msg, "test");
+    let callback: OutputCallback = std::sync::Arc::new(|event: OutputEvent| {
+        assert_eq!(event.line, "test");
     });
 
-    callback("test".to_string());
+    OutputEvents::new("exec-123", callback).message("test");
 }
 
 #[test]
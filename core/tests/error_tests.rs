@@ -1,5 +1,5 @@
-use s_e_e_core::CoreError;
-use s_e_e_engine::{EngineError, HandlerError, ParserError};
+use s_e_e_core::{CoreError, ErrorCategory};
+use s_e_e_engine::{EngineError, HandlerError, ParameterError, ParserError};
 
 #[test]
 fn test_core_error_engine_conversion() {
@@ -117,3 +117,59 @@ fn test_core_error_debug() {
     assert!(debug_msg.contains("WorkflowNotFound"));
     assert!(debug_msg.contains("test-workflow"));
 }
+
+#[test]
+fn test_core_error_categories() {
+    let cases = [
+        (
+            CoreError::Engine(ParserError::MissingField("id".to_string()).into()),
+            ErrorCategory::Validation,
+        ),
+        (
+            CoreError::Engine(ParameterError::Missing("target".to_string()).into()),
+            ErrorCategory::Validation,
+        ),
+        (
+            CoreError::Engine(HandlerError::ExecutionFailed("boom".to_string()).into()),
+            ErrorCategory::HandlerFailure { exit_code: None },
+        ),
+        (
+            CoreError::Engine(EngineError::Cancelled),
+            ErrorCategory::Cancelled,
+        ),
+        (
+            CoreError::Engine(std::io::Error::other("disk").into()),
+            ErrorCategory::Io,
+        ),
+        (
+            CoreError::WorkflowNotFound("wf".to_string()),
+            ErrorCategory::NotFound,
+        ),
+        (CoreError::InputRequired, ErrorCategory::Input),
+        (
+            CoreError::Persistence("locked".to_string()),
+            ErrorCategory::Storage,
+        ),
+        (
+            CoreError::Execution("bad state".to_string()),
+            ErrorCategory::Internal,
+        ),
+    ];
+
+    for (error, expected) in cases {
+        assert_eq!(error.category(), expected, "category of {:?}", error);
+    }
+}
+
+#[test]
+fn test_error_category_serialization() {
+    let category = ErrorCategory::HandlerFailure { exit_code: Some(2) };
+    assert_eq!(
+        serde_json::to_value(category).unwrap(),
+        serde_json::json!({ "category": "handler_failure", "exit_code": 2 })
+    );
+    assert_eq!(
+        serde_json::to_value(ErrorCategory::Timeout).unwrap(),
+        serde_json::json!({ "category": "timeout" })
+    );
+}
@@ -7,12 +7,13 @@ fn test_output_callback() {
     let call_count = Arc::new(AtomicUsize::new(0));
     let call_count_clone = call_count.clone();
 
-    let callback: OutputCallback = Arc::new(move |msg: String| {
+    let callback: OutputCallback = Arc::new(move |event: OutputEvent| {
         call_count_clone.fetch_add(1, Ordering::SeqCst);
-        assert_eq!(msg, "test message");
+        assert_eq!(event.line, "test message");
+        assert_eq!(event.task_id.as_deref(), Some("task-1"));
     });
 
-    callback("test message".to_string());
+    OutputEvents::new("exec-1", callback).task_line("task-1", "Task 1", "test message");
     assert_eq!(call_count.load(Ordering::SeqCst), 1);
 }
 
@@ -122,6 +123,8 @@ fn test_embedded_data_population() {
 
         let store = get_global_store().unwrap();
 
+        // Examples are opt-in, so they only land in the library when asked for.
+        rt.block_on(add_all_example_workflows()).unwrap();
         let workflows = rt.block_on(store.list_workflows()).unwrap();
         assert!(
             workflows.len() >= 4,
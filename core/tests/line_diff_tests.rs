@@ -0,0 +1,40 @@
+use s_e_e_core::{diff_lines, DiffLineKind};
+
+fn render(before: &str, after: &str) -> Vec<String> {
+    diff_lines(before, after)
+        .into_iter()
+        .map(|line| {
+            let marker = match line.kind {
+                DiffLineKind::Unchanged => ' ',
+                DiffLineKind::Removed => '-',
+                DiffLineKind::Added => '+',
+            };
+            format!("{}{}", marker, line.text)
+        })
+        .collect()
+}
+
+#[test]
+fn test_identical_text_is_unchanged() {
+    assert_eq!(render("a\nb", "a\nb"), vec![" a", " b"]);
+}
+
+#[test]
+fn test_changed_line_in_the_middle() {
+    assert_eq!(render("a\nb\nc", "a\nx\nc"), vec![" a", "-b", "+x", " c"]);
+}
+
+#[test]
+fn test_insertions_and_removals() {
+    assert_eq!(
+        render("a\nb\nc\nd", "b\nc\ne\nd"),
+        vec!["-a", " b", " c", "+e", " d"]
+    );
+}
+
+#[test]
+fn test_empty_sides() {
+    assert_eq!(render("", "a"), vec!["+a"]);
+    assert_eq!(render("a", ""), vec!["-a"]);
+    assert!(render("", "").is_empty());
+}
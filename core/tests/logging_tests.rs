@@ -0,0 +1,82 @@
+use s_e_e_core::logging::expand_log_directives;
+use s_e_e_core::{
+    apply_log_settings, init_tracing, log_directives, log_env_override, LogLevel, LogSettings,
+    LOG_ENV_VAR,
+};
+use serial_test::serial;
+use tracing::Level;
+
+fn settings(level: LogLevel, modules: &[(&str, LogLevel)]) -> LogSettings {
+    LogSettings {
+        level,
+        modules: modules
+            .iter()
+            .map(|(module, level)| (module.to_string(), *level))
+            .collect(),
+    }
+}
+
+#[test]
+fn test_log_directives_use_crate_targets() {
+    assert_eq!(log_directives(&LogSettings::default()), "info");
+    assert_eq!(
+        log_directives(&settings(
+            LogLevel::Warn,
+            &[
+                ("engine", LogLevel::Debug),
+                ("gui", LogLevel::Info),
+                ("hyper::client", LogLevel::Error),
+            ]
+        )),
+        "warn,s_e_e_engine=debug,s_e_e_gui=info,hyper::client=error"
+    );
+}
+
+#[test]
+fn test_expand_log_directives() {
+    assert_eq!(
+        expand_log_directives("engine=debug, persistence=warn,info"),
+        "s_e_e_engine=debug,s_e_e_persistence=warn,info"
+    );
+    assert_eq!(expand_log_directives("query"), "s_e_e_dioxus_query");
+    assert_eq!(
+        expand_log_directives("s_e_e_core=trace,,debug"),
+        "s_e_e_core=trace,debug"
+    );
+}
+
+#[test]
+#[serial]
+fn test_see_log_overrides_rust_log() {
+    std::env::set_var("RUST_LOG", "warn");
+    std::env::set_var(LOG_ENV_VAR, "engine=trace");
+    assert_eq!(log_env_override().as_deref(), Some("s_e_e_engine=trace"));
+
+    std::env::remove_var(LOG_ENV_VAR);
+    assert_eq!(log_env_override().as_deref(), Some("warn"));
+
+    std::env::remove_var("RUST_LOG");
+    assert_eq!(log_env_override(), None);
+}
+
+#[test]
+#[serial]
+fn test_apply_log_settings_rebuilds_filter() {
+    std::env::remove_var(LOG_ENV_VAR);
+    std::env::remove_var("RUST_LOG");
+    let _guard = init_tracing(None).unwrap();
+    assert!(!tracing::enabled!(target: "s_e_e_engine", Level::DEBUG));
+
+    apply_log_settings(&settings(LogLevel::Info, &[("engine", LogLevel::Debug)])).unwrap();
+    assert!(tracing::enabled!(target: "s_e_e_engine", Level::DEBUG));
+    assert!(!tracing::enabled!(target: "s_e_e_persistence", Level::DEBUG));
+
+    // The environment wins over saved levels.
+    std::env::set_var(LOG_ENV_VAR, "warn");
+    apply_log_settings(&LogSettings::default()).unwrap();
+    assert!(tracing::enabled!(target: "s_e_e_engine", Level::DEBUG));
+    std::env::remove_var(LOG_ENV_VAR);
+
+    apply_log_settings(&LogSettings::default()).unwrap();
+    assert!(!tracing::enabled!(target: "s_e_e_engine", Level::DEBUG));
+}
@@ -0,0 +1,152 @@
+use s_e_e_core::{
+    get_global_store, included_prompt_ids, init_test_store, preview_prompt, render_prompt,
+    render_prompt_content, CoreError, Prompt, PromptIncludeError, MAX_INCLUDE_DEPTH,
+};
+use serial_test::serial;
+use std::collections::HashMap;
+
+fn library(prompts: &[(&str, &str)]) -> HashMap<String, String> {
+    prompts
+        .iter()
+        .map(|(id, content)| (id.to_string(), content.to_string()))
+        .collect()
+}
+
+fn render(
+    content: &str,
+    root: Option<&str>,
+    prompts: &HashMap<String, String>,
+) -> Result<String, PromptIncludeError> {
+    render_prompt_content(content, root, |id| prompts.get(id).cloned())
+}
+
+#[test]
+fn test_includes_are_expanded_recursively() {
+    let prompts = library(&[
+        ("preamble", "You are careful. {{ include: tone }}"),
+        ("tone", "Be brief."),
+        ("suffix", "Reply in markdown."),
+    ]);
+
+    let rendered = render(
+        "{{include:preamble}}\nReview this.\n{{include:suffix}}",
+        None,
+        &prompts,
+    )
+    .unwrap();
+    assert_eq!(
+        rendered,
+        "You are careful. Be brief.\nReview this.\nReply in markdown."
+    );
+}
+
+#[test]
+fn test_other_braces_are_left_alone() {
+    let prompts = library(&[]);
+    let content = "Use {{name}} and {{ include: }} as written.";
+    assert_eq!(render(content, None, &prompts).unwrap(), content);
+}
+
+#[test]
+fn test_missing_include_is_reported() {
+    let prompts = library(&[]);
+    assert_eq!(
+        render("{{include:missing}}", None, &prompts),
+        Err(PromptIncludeError::NotFound("missing".to_string()))
+    );
+}
+
+#[test]
+fn test_cycles_are_detected() {
+    let prompts = library(&[("a", "{{include:b}}"), ("b", "{{include:a}}")]);
+    assert_eq!(
+        render("{{include:b}}", Some("a"), &prompts),
+        Err(PromptIncludeError::Cycle(vec![
+            "a".to_string(),
+            "b".to_string(),
+            "a".to_string()
+        ]))
+    );
+
+    assert!(matches!(
+        render("{{include:self}}", Some("self"), &prompts),
+        Err(PromptIncludeError::Cycle(_))
+    ));
+}
+
+#[test]
+fn test_the_same_prompt_may_be_included_twice() {
+    let prompts = library(&[("rule", "---")]);
+    assert_eq!(
+        render("{{include:rule}}x{{include:rule}}", None, &prompts).unwrap(),
+        "---x---"
+    );
+}
+
+#[test]
+fn test_depth_limit() {
+    let chain: Vec<(String, String)> = (0..=MAX_INCLUDE_DEPTH)
+        .map(|i| (format!("p{}", i), format!("{{{{include:p{}}}}}", i + 1)))
+        .chain(std::iter::once((
+            format!("p{}", MAX_INCLUDE_DEPTH + 1),
+            "end".to_string(),
+        )))
+        .collect();
+    let prompts: HashMap<String, String> = chain.into_iter().collect();
+
+    // p1 through the last prompt is one include too many; p2 is exactly the limit.
+    assert!(matches!(
+        render("{{include:p1}}", None, &prompts),
+        Err(PromptIncludeError::TooDeep(_))
+    ));
+    assert_eq!(render("{{include:p2}}", None, &prompts).unwrap(), "end");
+}
+
+#[test]
+fn test_included_prompt_ids() {
+    assert_eq!(
+        included_prompt_ids("{{include:a}} {{x}} {{ include: b }}"),
+        vec!["a".to_string(), "b".to_string()]
+    );
+}
+
+#[test]
+#[serial]
+fn test_render_and_preview_saved_prompts() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(init_test_store()).unwrap();
+    let store = get_global_store().unwrap();
+
+    for (id, content) in [
+        ("render-test:shared", "Shared preamble."),
+        (
+            "render-test:task",
+            "{{include:render-test:shared}} Do the task.",
+        ),
+    ] {
+        let prompt = Prompt {
+            id: id.to_string(),
+            name: id.to_string(),
+            content: content.to_string(),
+            ..Prompt::default()
+        };
+        rt.block_on(store.save_prompt(&prompt)).unwrap();
+    }
+
+    assert_eq!(
+        rt.block_on(render_prompt("render-test:task")).unwrap(),
+        "Shared preamble. Do the task."
+    );
+    assert_eq!(
+        rt.block_on(preview_prompt(None, "{{include:render-test:task}}!"))
+            .unwrap(),
+        "Shared preamble. Do the task.!"
+    );
+    assert!(matches!(
+        rt.block_on(preview_prompt(
+            Some("render-test:shared"),
+            "{{include:render-test:task}}"
+        )),
+        Err(CoreError::PromptInclude(PromptIncludeError::Cycle(_)))
+    ));
+}
@@ -0,0 +1,138 @@
+//! Guards the JSON shape of `WorkflowResult`. If a change here is intended,
+//! bump `WORKFLOW_RESULT_SCHEMA_VERSION` when it renames or removes a field,
+//! then regenerate the fixture with `UPDATE_GOLDEN=1 cargo test`.
+
+use s_e_e_core::{
+    AuditEntry, FailureReason, TaskInfo, WorkflowResult, WORKFLOW_RESULT_SCHEMA_VERSION,
+};
+use s_e_e_engine::{AuditStatus, ChangeOp, ContextChange, OutputStats, TaskStatus};
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+fn golden_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/workflow_result_v1.json")
+}
+
+fn task(id: &str, name: &str, status: TaskStatus) -> TaskInfo {
+    TaskInfo {
+        id: id.to_string(),
+        name: name.to_string(),
+        status,
+    }
+}
+
+fn sample_result() -> WorkflowResult {
+    WorkflowResult {
+        success: false,
+        workflow_name: "Release".to_string(),
+        execution_id: "exec-0001".to_string(),
+        tasks: vec![
+            task("build", "Build", TaskStatus::Complete),
+            task("test", "Test", TaskStatus::Failed),
+            task("deploy", "Deploy", TaskStatus::Pending),
+        ],
+        audit_trail: vec![
+            AuditEntry {
+                task_id: "build".to_string(),
+                status: AuditStatus::Success,
+                timestamp: "2026-01-02T03:04:05+00:00".to_string(),
+                changes_count: 1,
+                message: "Completed task: Build".to_string(),
+                changes: vec![ContextChange {
+                    op: ChangeOp::Add,
+                    path: "/build/artifact".to_string(),
+                    before: None,
+                    after: Some(json!("dist/app.tar.gz")),
+                }],
+                changes_truncated: false,
+            },
+            AuditEntry {
+                task_id: "test".to_string(),
+                status: AuditStatus::Failure,
+                timestamp: "2026-01-02T03:04:09+00:00".to_string(),
+                changes_count: 0,
+                message: "Failed task: Test - 2 tests failed".to_string(),
+                changes: vec![],
+                changes_truncated: false,
+            },
+        ],
+        per_task_logs: HashMap::from([
+            ("test".to_string(), vec!["running 12 tests".to_string()]),
+            ("build".to_string(), vec!["Output: built".to_string()]),
+        ]),
+        errors: vec!["Task test: 2 tests failed".to_string()],
+        failure_reasons: HashMap::from([("test".to_string(), FailureReason::Timeout)]),
+        exit_codes: HashMap::from([("test".to_string(), 101)]),
+        output_stats: HashMap::from([
+            (
+                "test".to_string(),
+                OutputStats {
+                    total_bytes: 4096,
+                    truncated: true,
+                },
+            ),
+            (
+                "build".to_string(),
+                OutputStats {
+                    total_bytes: 12,
+                    truncated: false,
+                },
+            ),
+        ]),
+        cache_hits: HashSet::from(["lint".to_string(), "build".to_string()]),
+        workspace: None,
+        stages: Vec::new(),
+        outputs: Default::default(),
+        planned: Vec::new(),
+        profile: None,
+    }
+}
+
+#[test]
+fn test_workflow_result_matches_golden_file() {
+    let actual = serde_json::to_string_pretty(&sample_result().to_versioned_json()).unwrap() + "\n";
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::write(golden_path(), &actual).unwrap();
+    }
+
+    let expected = std::fs::read_to_string(golden_path()).unwrap();
+    assert_eq!(
+        actual, expected,
+        "WorkflowResult JSON no longer matches the golden file"
+    );
+}
+
+#[test]
+fn test_golden_file_deserializes_to_the_same_result() {
+    let golden: Value =
+        serde_json::from_str(&std::fs::read_to_string(golden_path()).unwrap()).unwrap();
+    assert_eq!(
+        golden["schema_version"],
+        json!(WORKFLOW_RESULT_SCHEMA_VERSION)
+    );
+
+    let parsed: WorkflowResult = serde_json::from_value(golden).unwrap();
+    assert_eq!(parsed, sample_result());
+}
+
+#[test]
+fn test_serialization_is_deterministic() {
+    let first = serde_json::to_string(&sample_result()).unwrap();
+    for _ in 0..10 {
+        assert_eq!(serde_json::to_string(&sample_result()).unwrap(), first);
+    }
+}
+
+#[test]
+fn test_results_without_newer_fields_still_parse() {
+    let mut document = sample_result().to_versioned_json();
+    for key in ["exit_codes", "output_stats", "cache_hits"] {
+        document.as_object_mut().unwrap().remove(key);
+    }
+
+    let parsed: WorkflowResult = serde_json::from_value(document).unwrap();
+    assert!(parsed.exit_codes.is_empty());
+    assert!(parsed.cache_hits.is_empty());
+}
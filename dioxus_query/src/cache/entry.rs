@@ -6,6 +6,8 @@ use tokio::task::JoinHandle;
 pub trait CacheEntry: Send + Sync {
     fn as_any(&self) -> &dyn Any;
     fn type_id(&self) -> TypeId;
+    /// Name of the cached value's type, for diagnostics.
+    fn type_name(&self) -> &'static str;
     fn fetched_at(&self) -> Option<Instant>;
     fn last_accessed(&self) -> Instant;
     fn is_fetching(&self) -> bool;
@@ -67,6 +69,10 @@ impl<T: Clone + Send + Sync + 'static> CacheEntry for TypedCacheEntry<T> {
         TypeId::of::<T>()
     }
 
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+
     fn fetched_at(&self) -> Option<Instant> {
         Some(self.fetched_at)
     }
@@ -0,0 +1,146 @@
+//! Bookkeeping behind the query devtools: which queries are mounted, how
+//! their fetches went, and what the cache holds.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::cache::storage::QUERY_CACHE;
+use crate::query_key::QueryKey;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueryStatus {
+    /// Mounted but not fetched yet.
+    Idle,
+    Fetching,
+    Success,
+    Error,
+}
+
+/// What one mounted query has been doing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryActivity {
+    pub key: QueryKey,
+    /// Components currently using the query.
+    pub observers: usize,
+    pub status: QueryStatus,
+    pub fetch_count: u32,
+    /// How long the last finished fetch took, retries included.
+    pub last_fetch_duration: Option<Duration>,
+    pub last_fetched_at: Option<Instant>,
+    pub last_error: Option<String>,
+    fetch_started_at: Option<Instant>,
+}
+
+impl QueryActivity {
+    fn new(key: QueryKey) -> Self {
+        Self {
+            key,
+            observers: 0,
+            status: QueryStatus::Idle,
+            fetch_count: 0,
+            last_fetch_duration: None,
+            last_fetched_at: None,
+            last_error: None,
+            fetch_started_at: None,
+        }
+    }
+}
+
+/// Activity of the mounted queries. A query is forgotten once its last
+/// observer unmounts.
+#[derive(Debug, Default)]
+pub struct ActivityLog {
+    queries: HashMap<QueryKey, QueryActivity>,
+}
+
+impl ActivityLog {
+    pub fn mounted(&mut self, key: &QueryKey) {
+        self.queries
+            .entry(key.clone())
+            .or_insert_with(|| QueryActivity::new(key.clone()))
+            .observers += 1;
+    }
+
+    pub fn unmounted(&mut self, key: &QueryKey) {
+        if let Some(activity) = self.queries.get_mut(key) {
+            activity.observers = activity.observers.saturating_sub(1);
+            if activity.observers == 0 {
+                self.queries.remove(key);
+            }
+        }
+    }
+
+    pub fn fetch_started(&mut self, key: &QueryKey, at: Instant) {
+        if let Some(activity) = self.queries.get_mut(key) {
+            activity.status = QueryStatus::Fetching;
+            activity.fetch_started_at = Some(at);
+        }
+    }
+
+    pub fn fetch_finished(&mut self, key: &QueryKey, at: Instant, error: Option<String>) {
+        if let Some(activity) = self.queries.get_mut(key) {
+            activity.status = if error.is_some() {
+                QueryStatus::Error
+            } else {
+                QueryStatus::Success
+            };
+            activity.fetch_count += 1;
+            activity.last_fetch_duration = activity
+                .fetch_started_at
+                .take()
+                .map(|started| at.saturating_duration_since(started));
+            activity.last_fetched_at = Some(at);
+            activity.last_error = error;
+        }
+    }
+
+    /// Every mounted query, sorted by key.
+    pub fn snapshot(&self) -> Vec<QueryActivity> {
+        let mut queries: Vec<QueryActivity> = self.queries.values().cloned().collect();
+        queries.sort_by(|a, b| a.key.as_str().cmp(b.key.as_str()));
+        queries
+    }
+}
+
+static ACTIVITY: OnceLock<Mutex<ActivityLog>> = OnceLock::new();
+
+pub(crate) fn record<R>(update: impl FnOnce(&mut ActivityLog) -> R) -> R {
+    let log = ACTIVITY.get_or_init(Default::default);
+    let mut log = log.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    update(&mut log)
+}
+
+/// Every mounted query and how its fetches went, sorted by key.
+pub fn query_activity() -> Vec<QueryActivity> {
+    record(|log| log.snapshot())
+}
+
+/// One entry of the query cache.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CachedQuery {
+    pub key: QueryKey,
+    pub type_name: &'static str,
+    /// Time since the value was fetched.
+    pub age: Option<Duration>,
+    pub is_fetching: bool,
+    pub cache_time_ms: Option<u64>,
+}
+
+/// Every entry of the query cache, sorted by key. Needs the Dioxus runtime.
+pub fn cache_entries() -> Vec<CachedQuery> {
+    let cache = QUERY_CACHE();
+    let map = cache.borrow();
+    let mut entries: Vec<CachedQuery> = map
+        .iter()
+        .map(|(key, entry)| CachedQuery {
+            key: key.clone(),
+            type_name: entry.type_name(),
+            age: entry.fetched_at().map(|fetched_at| fetched_at.elapsed()),
+            is_fetching: entry.is_fetching(),
+            cache_time_ms: entry.cache_time(),
+        })
+        .collect();
+    entries.sort_by(|a, b| a.key.as_str().cmp(b.key.as_str()));
+    entries
+}
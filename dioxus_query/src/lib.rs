@@ -1,8 +1,10 @@
 pub mod cache;
+pub mod devtools;
 pub mod invalidate;
 pub mod mutation;
 pub mod query;
 pub mod query_key;
+pub mod refresh;
 pub mod state;
 pub mod utils;
 
@@ -13,6 +15,9 @@ pub mod prelude {
     pub use crate::mutation::use_mutation;
     pub use crate::query::use_query;
     pub use crate::query_key::QueryKey;
+    pub use crate::refresh::{
+        is_window_focused, refresh_policy, set_refresh_policy, set_window_focused, RefreshPolicy,
+    };
     pub use crate::state::{MutationCallbacks, MutationState, QueryOptions, QueryState};
     pub use crate::utils::get_cache_stats;
 }
@@ -1,13 +1,15 @@
 use dioxus::prelude::*;
 use futures::Future;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tracing::{debug, error, info, instrument, trace, warn};
 
 use crate::cache::cleanup::cleanup_stale_entries_sync;
 use crate::cache::storage::QUERY_CACHE;
 use crate::cache::{get_typed_value, mark_fetch_complete, start_cleanup_task, TypedCacheEntry};
+use crate::devtools;
 use crate::query_key::QueryKey;
+use crate::refresh::{subscribe_refresh_policy, subscribe_window_focus};
 use crate::state::{QueryOptions, QueryState};
 
 #[instrument(skip(fetcher, options), fields(key = %key))]
@@ -43,6 +45,11 @@ where
         cleanup_stale_entries_sync(&mut cache_map);
     });
 
+    let mount_key = key.clone();
+    use_hook(move || devtools::record(|log| log.mounted(&mount_key)));
+    let drop_key = key.clone();
+    use_drop(move || devtools::record(|log| log.unmounted(&drop_key)));
+
     let key_clone = key.clone();
 
     // Check cache on mount
@@ -164,6 +171,7 @@ where
                 }
             }
 
+            devtools::record(|log| log.fetch_started(&key, Instant::now()));
             state.write().is_fetching = true;
             debug!(key = %key, "Query state: is_fetching = true");
 
@@ -182,6 +190,7 @@ where
                 match fetcher().await {
                     Ok(data) => {
                         info!(key = %key, attempt = attempts, "Fetch successful");
+                        devtools::record(|log| log.fetch_finished(&key, Instant::now(), None));
 
                         // Update cache with typed value (no serialization!)
                         // Store with the query's cache_time option
@@ -239,6 +248,10 @@ where
                             debug!(key = %key, "Removed failed cache entry to prevent memory leak");
                         }
 
+                        devtools::record(|log| {
+                            log.fetch_finished(&key, Instant::now(), Some(err.clone()))
+                        });
+
                         let error_msg = err.clone();
                         let new_state = QueryState {
                             data: None,
@@ -266,24 +279,59 @@ where
         }
     });
 
-    if let Some(interval) = options.refetch_interval {
+    if options.refetch_interval.is_some()
+        || options.refresh_group.is_some()
+        || options.refetch_on_window_focus
+    {
         info!(
             key = %key,
-            interval_ms = interval,
-            "Setting up refetch interval"
+            interval_ms = ?options.refetch_interval,
+            group = ?options.refresh_group,
+            "Setting up background refresh"
         );
 
         let fetch_for_interval = fetch;
         let interval_key_str = key.as_str().to_string();
+        let group = options.refresh_group;
+        let fallback_interval = options.refetch_interval;
+        let refetch_on_focus = options.refetch_on_window_focus;
         use_future(move || {
             let fetch = fetch_for_interval;
             let key_str = interval_key_str.clone();
             async move {
-                let mut interval_stream = tokio::time::interval(Duration::from_millis(interval));
+                let mut policy = subscribe_refresh_policy();
+                let mut focus = subscribe_window_focus();
                 loop {
-                    interval_stream.tick().await;
-                    trace!(key = %key_str, "Refetch interval triggered");
-                    fetch(());
+                    let (interval, paused) = {
+                        let policy = policy.borrow_and_update();
+                        let paused = policy.pause_when_unfocused && !*focus.borrow();
+                        (policy.interval_for(group, fallback_interval), paused)
+                    };
+                    let tick = async move {
+                        match interval {
+                            Some(ms) if !paused => {
+                                tokio::time::sleep(Duration::from_millis(ms)).await
+                            }
+                            _ => std::future::pending::<()>().await,
+                        }
+                    };
+
+                    tokio::select! {
+                        _ = tick => {
+                            trace!(key = %key_str, "Refetch interval triggered");
+                            fetch(());
+                        }
+                        Ok(()) = policy.changed() => {
+                            trace!(key = %key_str, "Refresh policy changed");
+                        }
+                        Ok(()) = focus.changed() => {
+                            let focused = *focus.borrow_and_update();
+                            if focused && (refetch_on_focus || interval.is_some()) {
+                                debug!(key = %key_str, "Window focused - refetching");
+                                fetch(());
+                            }
+                        }
+                    }
                 }
             }
         });
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use tokio::sync::watch;
+use tracing::debug;
+
+/// App-wide rules for background refetching.
+///
+/// Queries opt in to a named group through `QueryOptions::refresh_group`; the
+/// group's interval here overrides the query's own `refetch_interval`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RefreshPolicy {
+    pub intervals: HashMap<String, u64>,
+
+    /// Skip interval refetches while the window is unfocused or minimized.
+    pub pause_when_unfocused: bool,
+}
+
+impl Default for RefreshPolicy {
+    fn default() -> Self {
+        Self {
+            intervals: HashMap::new(),
+            pause_when_unfocused: true,
+        }
+    }
+}
+
+impl RefreshPolicy {
+    pub fn with_interval(mut self, group: &str, interval_ms: u64) -> Self {
+        self.intervals.insert(group.to_string(), interval_ms);
+        self
+    }
+
+    /// The interval a query should poll at, if any.
+    pub fn interval_for(&self, group: Option<&str>, fallback: Option<u64>) -> Option<u64> {
+        group
+            .and_then(|group| self.intervals.get(group).copied())
+            .or(fallback)
+            .filter(|interval| *interval > 0)
+    }
+}
+
+static REFRESH_POLICY: OnceLock<watch::Sender<RefreshPolicy>> = OnceLock::new();
+static WINDOW_FOCUS: OnceLock<watch::Sender<bool>> = OnceLock::new();
+
+fn policy_sender() -> &'static watch::Sender<RefreshPolicy> {
+    REFRESH_POLICY.get_or_init(|| watch::channel(RefreshPolicy::default()).0)
+}
+
+fn focus_sender() -> &'static watch::Sender<bool> {
+    WINDOW_FOCUS.get_or_init(|| watch::channel(true).0)
+}
+
+/// Replace the refresh policy. Running interval loops restart their timers with it.
+pub fn set_refresh_policy(policy: RefreshPolicy) {
+    policy_sender().send_if_modified(|current| {
+        if *current == policy {
+            return false;
+        }
+        debug!(?policy, "Updating refresh policy");
+        *current = policy;
+        true
+    });
+}
+
+pub fn refresh_policy() -> RefreshPolicy {
+    policy_sender().borrow().clone()
+}
+
+/// Report window focus changes from the app shell.
+pub fn set_window_focused(focused: bool) {
+    focus_sender().send_if_modified(|current| {
+        if *current == focused {
+            return false;
+        }
+        debug!(focused, "Window focus changed");
+        *current = focused;
+        true
+    });
+}
+
+pub fn is_window_focused() -> bool {
+    *focus_sender().borrow()
+}
+
+pub(crate) fn subscribe_refresh_policy() -> watch::Receiver<RefreshPolicy> {
+    policy_sender().subscribe()
+}
+
+pub(crate) fn subscribe_window_focus() -> watch::Receiver<bool> {
+    focus_sender().subscribe()
+}
@@ -50,6 +50,9 @@ pub struct QueryOptions {
 
     pub refetch_interval: Option<u64>,
 
+    /// Named group whose interval in the global `RefreshPolicy` overrides `refetch_interval`.
+    pub refresh_group: Option<&'static str>,
+
     pub retry: Option<u8>,
 
     pub retry_delay: Option<u64>,
@@ -67,6 +70,7 @@ impl Default for QueryOptions {
             stale_time: Some(0),
             cache_time: Some(300_000),
             refetch_interval: None,
+            refresh_group: None,
             retry: Some(3),
             retry_delay: Some(1000),
             refetch_on_mount: true,
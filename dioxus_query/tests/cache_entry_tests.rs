@@ -101,3 +101,14 @@ fn test_fetched_at() {
     let elapsed = fetched_at.unwrap().elapsed();
     assert!(elapsed < Duration::from_secs(1));
 }
+
+#[test]
+fn test_type_name_names_the_cached_value() {
+    let entry = TypedCacheEntry::new(Arc::new(vec![String::from("a")]));
+    let entry_trait: &dyn CacheEntry = &entry;
+
+    assert_eq!(
+        entry_trait.type_name(),
+        "alloc::vec::Vec<alloc::string::String>"
+    );
+}
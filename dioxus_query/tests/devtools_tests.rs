@@ -0,0 +1,84 @@
+use s_e_e_dioxus_query::devtools::{ActivityLog, QueryStatus};
+use s_e_e_dioxus_query::prelude::QueryKey;
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_mounted_queries_are_listed_by_key() {
+    let mut log = ActivityLog::default();
+    let workflows = QueryKey::new(&["workflows", "list"]);
+    let executions = QueryKey::new(&["executions", "list"]);
+
+    log.mounted(&workflows);
+    log.mounted(&executions);
+    log.mounted(&executions);
+
+    let snapshot = log.snapshot();
+    assert_eq!(snapshot.len(), 2);
+    assert_eq!(snapshot[0].key, executions);
+    assert_eq!(snapshot[0].observers, 2);
+    assert_eq!(snapshot[0].status, QueryStatus::Idle);
+    assert_eq!(snapshot[1].key, workflows);
+}
+
+#[test]
+fn test_query_is_forgotten_after_last_observer_unmounts() {
+    let mut log = ActivityLog::default();
+    let key = QueryKey::new(&["executions", "list"]);
+
+    log.mounted(&key);
+    log.mounted(&key);
+    log.unmounted(&key);
+    assert_eq!(log.snapshot()[0].observers, 1);
+
+    log.unmounted(&key);
+    assert!(log.snapshot().is_empty());
+}
+
+#[test]
+fn test_fetches_record_duration_and_outcome() {
+    let mut log = ActivityLog::default();
+    let key = QueryKey::new(&["settings"]);
+    let start = Instant::now();
+    log.mounted(&key);
+
+    log.fetch_started(&key, start);
+    assert_eq!(log.snapshot()[0].status, QueryStatus::Fetching);
+
+    log.fetch_finished(&key, start + Duration::from_millis(40), None);
+    let activity = &log.snapshot()[0];
+    assert_eq!(activity.status, QueryStatus::Success);
+    assert_eq!(activity.fetch_count, 1);
+    assert_eq!(
+        activity.last_fetch_duration,
+        Some(Duration::from_millis(40))
+    );
+
+    log.fetch_started(&key, start + Duration::from_millis(100));
+    log.fetch_finished(
+        &key,
+        start + Duration::from_millis(150),
+        Some("Database not available".to_string()),
+    );
+    let activity = &log.snapshot()[0];
+    assert_eq!(activity.status, QueryStatus::Error);
+    assert_eq!(activity.fetch_count, 2);
+    assert_eq!(
+        activity.last_fetch_duration,
+        Some(Duration::from_millis(50))
+    );
+    assert_eq!(
+        activity.last_error.as_deref(),
+        Some("Database not available")
+    );
+}
+
+#[test]
+fn test_fetches_of_unmounted_queries_are_ignored() {
+    let mut log = ActivityLog::default();
+    let key = QueryKey::new(&["settings"]);
+
+    log.fetch_started(&key, Instant::now());
+    log.fetch_finished(&key, Instant::now(), None);
+
+    assert!(log.snapshot().is_empty());
+}
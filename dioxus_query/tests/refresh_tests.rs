@@ -0,0 +1,43 @@
+use s_e_e_dioxus_query::prelude::{
+    is_window_focused, refresh_policy, set_refresh_policy, set_window_focused, RefreshPolicy,
+};
+
+#[test]
+fn test_interval_for_prefers_group() {
+    let policy = RefreshPolicy::default().with_interval("executions", 5000);
+
+    assert_eq!(
+        policy.interval_for(Some("executions"), Some(1000)),
+        Some(5000)
+    );
+    assert_eq!(
+        policy.interval_for(Some("calendar"), Some(1000)),
+        Some(1000)
+    );
+    assert_eq!(policy.interval_for(None, Some(1000)), Some(1000));
+    assert_eq!(policy.interval_for(None, None), None);
+}
+
+#[test]
+fn test_zero_interval_disables_polling() {
+    let policy = RefreshPolicy::default().with_interval("executions", 0);
+
+    assert_eq!(policy.interval_for(Some("executions"), Some(1000)), None);
+}
+
+#[test]
+fn test_global_policy_and_focus() {
+    let policy = RefreshPolicy {
+        pause_when_unfocused: false,
+        ..Default::default()
+    }
+    .with_interval("calendar", 60_000);
+    set_refresh_policy(policy.clone());
+    assert_eq!(refresh_policy(), policy);
+
+    assert!(is_window_focused());
+    set_window_focused(false);
+    assert!(!is_window_focused());
+    set_window_focused(true);
+    assert!(is_window_focused());
+}
@@ -0,0 +1,10 @@
+#![no_main]
+
+// Run from `engine/` with `cargo +nightly fuzz run parse_workflow`.
+
+use libfuzzer_sys::fuzz_target;
+use s_e_e_engine::parse_workflow_bytes;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = parse_workflow_bytes(data);
+});
@@ -0,0 +1,174 @@
+#![no_main]
+
+//! Builds workflow-shaped JSON so the fuzzer spends its time past the JSON
+//! syntax checks, inside task, function, parameter and trigger parsing.
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use s_e_e_engine::parser::parse_workflow_from_value;
+use serde_json::{json, Map, Value};
+
+#[derive(Arbitrary, Debug)]
+struct Workflow {
+    id: Option<Field>,
+    name: Option<Field>,
+    tasks: Option<Vec<Task>>,
+    parameters: Option<Vec<(Field, Field)>>,
+    triggers: Option<Vec<Field>>,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Task {
+    id: Option<Field>,
+    name: Option<Field>,
+    function: Option<Function>,
+    next_tasks: Option<Vec<Task>>,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Function {
+    name: Option<FunctionName>,
+    input: Option<Vec<(InputKey, Field)>>,
+}
+
+#[derive(Arbitrary, Debug)]
+enum FunctionName {
+    CliCommand,
+    CursorAgent,
+    UserInput,
+    Other(String),
+}
+
+#[derive(Arbitrary, Debug)]
+enum InputKey {
+    Command,
+    Args,
+    Prompt,
+    InputType,
+    Required,
+    Default,
+    Process,
+    Other(String),
+}
+
+#[derive(Arbitrary, Debug)]
+enum Field {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+    List(Vec<Field>),
+}
+
+impl Field {
+    fn to_json(&self) -> Value {
+        match self {
+            Field::Null => Value::Null,
+            Field::Bool(b) => json!(b),
+            Field::Int(i) => json!(i),
+            Field::Float(f) => json!(f),
+            Field::Text(s) => json!(s),
+            Field::List(items) => Value::Array(items.iter().map(Field::to_json).collect()),
+        }
+    }
+}
+
+fn insert(map: &mut Map<String, Value>, key: &str, value: Option<Value>) {
+    if let Some(value) = value {
+        map.insert(key.to_string(), value);
+    }
+}
+
+impl Task {
+    fn to_json(&self) -> Value {
+        let mut map = Map::new();
+        insert(&mut map, "id", self.id.as_ref().map(Field::to_json));
+        insert(&mut map, "name", self.name.as_ref().map(Field::to_json));
+        insert(
+            &mut map,
+            "function",
+            self.function.as_ref().map(Function::to_json),
+        );
+        insert(
+            &mut map,
+            "next_tasks",
+            self.next_tasks
+                .as_ref()
+                .map(|tasks| Value::Array(tasks.iter().map(Task::to_json).collect())),
+        );
+        Value::Object(map)
+    }
+}
+
+impl Function {
+    fn to_json(&self) -> Value {
+        let mut map = Map::new();
+        let name = self.name.as_ref().map(|name| match name {
+            FunctionName::CliCommand => json!("cli_command"),
+            FunctionName::CursorAgent => json!("cursor_agent"),
+            FunctionName::UserInput => json!("user_input"),
+            FunctionName::Other(name) => json!(name),
+        });
+        insert(&mut map, "name", name);
+        let input = self.input.as_ref().map(|entries| {
+            let mut input = Map::new();
+            for (key, value) in entries {
+                let key = match key {
+                    InputKey::Command => "command",
+                    InputKey::Args => "args",
+                    InputKey::Prompt => "prompt",
+                    InputKey::InputType => "input_type",
+                    InputKey::Required => "required",
+                    InputKey::Default => "default",
+                    InputKey::Process => "process",
+                    InputKey::Other(key) => key,
+                };
+                input.insert(key.to_string(), value.to_json());
+            }
+            Value::Object(input)
+        });
+        insert(&mut map, "input", input);
+        Value::Object(map)
+    }
+}
+
+impl Workflow {
+    fn to_json(&self) -> Value {
+        let mut map = Map::new();
+        insert(&mut map, "id", self.id.as_ref().map(Field::to_json));
+        insert(&mut map, "name", self.name.as_ref().map(Field::to_json));
+        insert(
+            &mut map,
+            "tasks",
+            self.tasks
+                .as_ref()
+                .map(|tasks| Value::Array(tasks.iter().map(Task::to_json).collect())),
+        );
+        insert(
+            &mut map,
+            "parameters",
+            self.parameters.as_ref().map(|parameters| {
+                parameters
+                    .iter()
+                    .map(|(name, kind)| json!({"name": name.to_json(), "type": kind.to_json()}))
+                    .collect()
+            }),
+        );
+        insert(
+            &mut map,
+            "triggers",
+            self.triggers.as_ref().map(|triggers| {
+                triggers
+                    .iter()
+                    .map(|id| json!({"type": "after_workflow", "workflow_id": id.to_json()}))
+                    .collect()
+            }),
+        );
+        Value::Object(map)
+    }
+}
+
+fuzz_target!(|workflow: Workflow| {
+    let _ = parse_workflow_from_value(&workflow.to_json());
+});
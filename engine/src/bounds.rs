@@ -0,0 +1,187 @@
+//! Running part of a workflow: start at one task, stop after another, or both.
+
+use crate::condition::Condition;
+use crate::interpolation::referenced_tasks;
+use crate::types::{EngineTask, EngineWorkflow};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// A workflow trimmed to the tasks between `from` and `until`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundedWorkflow {
+    pub workflow: EngineWorkflow,
+    /// Tasks of the full workflow that this run leaves out.
+    pub skipped: Vec<String>,
+    /// Outputs of skipped tasks that the remaining tasks read, as
+    /// `(task id, skipped task id)`. A run needs them from somewhere else.
+    pub requirements: Vec<(String, String)>,
+}
+
+/// Trim `workflow` so that `from` is the only root and nothing runs after
+/// `until`.
+///
+/// `from` drops every task that is not `from` or downstream of it. `until`
+/// keeps only `until` and the tasks leading to it, so branches that do not
+/// lead there are dropped as well. Either may be left out.
+pub fn bound_workflow(
+    workflow: &EngineWorkflow,
+    from: Option<&str>,
+    until: Option<&str>,
+) -> Result<BoundedWorkflow, String> {
+    let mut roots: Vec<EngineTask> = workflow
+        .tasks
+        .iter()
+        .filter(|task| task.is_root)
+        .cloned()
+        .collect();
+
+    if let Some(from) = from {
+        let start = find_task(&roots, from)
+            .ok_or_else(|| format!("workflow has no task '{}' to start from", from))?;
+        roots = vec![start.clone()];
+    }
+
+    if let Some(until) = until {
+        if find_task(&roots, until).is_none() {
+            return Err(match (from, find_task(&workflow.tasks, until)) {
+                (Some(from), Some(_)) => {
+                    format!("task '{}' does not run after '{}'", until, from)
+                }
+                _ => format!("workflow has no task '{}' to stop after", until),
+            });
+        }
+        roots = roots
+            .into_iter()
+            .filter_map(|task| keep_path_to(task, until))
+            .collect();
+    }
+
+    let mut tasks = Vec::new();
+    for root in &mut roots {
+        root.is_root = true;
+        flatten(root, &mut tasks);
+    }
+
+    let kept: HashSet<&str> = tasks.iter().map(|task| task.id.as_str()).collect();
+    let skipped: Vec<String> = workflow
+        .tasks
+        .iter()
+        .filter(|task| !kept.contains(task.id.as_str()))
+        .map(|task| task.id.clone())
+        .collect();
+    let mut requirements = Vec::new();
+    for task in &tasks {
+        for needed in data_references(task) {
+            if skipped.contains(&needed)
+                && !requirements.contains(&(task.id.clone(), needed.clone()))
+            {
+                requirements.push((task.id.clone(), needed));
+            }
+        }
+    }
+
+    Ok(BoundedWorkflow {
+        workflow: EngineWorkflow {
+            tasks,
+            ..workflow.clone()
+        },
+        skipped,
+        requirements,
+    })
+}
+
+/// Ids of the tasks whose output `task` reads through `{{data.TASK_ID...}}`
+/// or `${data.TASK_ID...}` placeholders in its configuration, its `when`
+/// condition or its `for_each` path.
+pub fn data_references(task: &EngineTask) -> Vec<String> {
+    let mut ids = Vec::new();
+    if let Ok(function) = serde_json::to_value(&task.function) {
+        collect_references(&function, &mut ids);
+        for id in referenced_tasks(&function) {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+    if let Some(condition) = task
+        .when
+        .as_deref()
+        .and_then(|when| Condition::parse(when).ok())
+    {
+        for id in condition.data_references() {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+    if let Some(id) = task
+        .for_each
+        .as_deref()
+        .and_then(|path| path.strip_prefix("data."))
+        .and_then(|path| path.split('.').next())
+    {
+        if !ids.iter().any(|known| known == id) {
+            ids.push(id.to_string());
+        }
+    }
+    ids
+}
+
+fn collect_references(value: &Value, ids: &mut Vec<String>) {
+    match value {
+        Value::String(text) => {
+            let mut rest = text.as_str();
+            while let Some(start) = rest.find("{{") {
+                let Some(end) = rest[start..].find("}}") else {
+                    break;
+                };
+                let path = rest[start + 2..start + end].trim();
+                if let Some(id) = path.strip_prefix("data.").and_then(|p| p.split('.').next()) {
+                    if !id.is_empty() && !ids.iter().any(|known| known == id) {
+                        ids.push(id.to_string());
+                    }
+                }
+                rest = &rest[start + end + 2..];
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|item| collect_references(item, ids)),
+        Value::Object(object) => object
+            .values()
+            .for_each(|item| collect_references(item, ids)),
+        _ => {}
+    }
+}
+
+fn find_task<'a>(tasks: &'a [EngineTask], id: &str) -> Option<&'a EngineTask> {
+    tasks.iter().find_map(|task| {
+        if task.id == id {
+            Some(task)
+        } else {
+            find_task(&task.next_tasks, id)
+        }
+    })
+}
+
+/// `task` cut down to the branch that reaches `until`, with nothing after it.
+fn keep_path_to(mut task: EngineTask, until: &str) -> Option<EngineTask> {
+    if task.id == until {
+        task.next_tasks.clear();
+        return Some(task);
+    }
+    task.next_tasks = std::mem::take(&mut task.next_tasks)
+        .into_iter()
+        .filter_map(|next| keep_path_to(next, until))
+        .collect();
+    (!task.next_tasks.is_empty()).then_some(task)
+}
+
+/// Every task of the tree under `task` in the flat form the parser produces:
+/// children before their parent, only roots marked as such.
+fn flatten(task: &EngineTask, out: &mut Vec<EngineTask>) {
+    for next in &task.next_tasks {
+        let mut next = next.clone();
+        next.is_root = false;
+        flatten(&next, out);
+    }
+    out.push(task.clone());
+}
@@ -0,0 +1,43 @@
+use crate::types::*;
+use async_trait::async_trait;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Storage for the results of tasks that opt into caching with `cache`.
+#[async_trait]
+pub trait TaskResultCache: Send + Sync {
+    /// The stored result for `key`, unless there is none or it has expired.
+    async fn get(&self, key: &str) -> Option<TaskResult>;
+
+    async fn put(&self, key: &str, result: &TaskResult, ttl: Duration);
+}
+
+/// Cache key for a task: a SHA-256 of its function config and the workflow
+/// parameters it ran with. Task ids and names are left out, so identical tasks
+/// in different workflows share results.
+pub fn cache_key(task: &EngineTask, params: &serde_json::Map<String, Value>) -> String {
+    let config = serde_json::json!({
+        "function": task.function,
+        "params": params,
+    });
+    let digest = Sha256::digest(config.to_string().as_bytes());
+    digest
+        .iter()
+        .fold(String::with_capacity(64), |mut key, byte| {
+            let _ = write!(key, "{:02x}", byte);
+            key
+        })
+}
+
+/// Whether a finished result may be stored. Failures and tasks that paused for
+/// input are always run again.
+pub fn is_cacheable(result: &TaskResult) -> bool {
+    let waiting = result
+        .output
+        .get("waiting_for_input")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    result.success && !waiting
+}
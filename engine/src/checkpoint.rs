@@ -0,0 +1,48 @@
+use crate::scratch::Scratchpad;
+use crate::types::ExecutionContext;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashSet};
+
+/// How far a run got: what a resumed run needs to carry on after the last
+/// task that finished instead of starting over.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub execution_id: String,
+    /// Tasks that succeeded or were skipped. Failed tasks are left out, so a
+    /// resumed run tries them again.
+    pub completed_tasks: BTreeSet<String>,
+    /// The tasks in `completed_tasks` that were skipped.
+    pub skipped_tasks: BTreeSet<String>,
+    /// The context data at that point: initial data and task outputs.
+    pub data: Value,
+    /// The handlers' scratchpad at that point.
+    #[serde(default)]
+    pub scratch: Scratchpad,
+}
+
+impl Checkpoint {
+    pub fn new(
+        context: &ExecutionContext,
+        completed: &HashSet<String>,
+        failed: &HashSet<String>,
+        skipped: &HashSet<String>,
+    ) -> Self {
+        Self {
+            execution_id: context.execution_id.clone(),
+            completed_tasks: completed.difference(failed).cloned().collect(),
+            skipped_tasks: skipped.iter().cloned().collect(),
+            data: context.data.clone(),
+            scratch: context.scratch.clone(),
+        }
+    }
+}
+
+/// Where a run saves its [`Checkpoint`] after every round of tasks.
+#[async_trait]
+pub trait CheckpointStore: Send + Sync {
+    /// Replace the execution's checkpoint. Failing to save must not fail the
+    /// run, so implementations report their own errors.
+    async fn save(&self, checkpoint: &Checkpoint);
+}
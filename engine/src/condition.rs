@@ -0,0 +1,352 @@
+//! `when` conditions that decide at run time whether a task runs.
+//!
+//! A condition compares values from the execution context, e.g.
+//! `data.build.status == "ok" && param.env != "dev"`. Supported are
+//! `data.TASK_ID.field` and `param.NAME` paths, string, number, boolean and
+//! `null` literals, `== != < <= > >=`, `&& || !` and parentheses. A path
+//! that leads nowhere is `null`, so a condition on a task that has not
+//! produced the field is simply false.
+
+use serde_json::{Map, Value};
+use std::cmp::Ordering;
+
+/// A parsed `when` expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    expr: Expr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Literal(Value),
+    Path(Vec<String>),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Compare(Box<Expr>, CompareOp, Box<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Literal(Value),
+    Path(Vec<String>),
+    Compare(CompareOp),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+impl Condition {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let tokens = tokenize(source)?;
+        if tokens.is_empty() {
+            return Err("condition is empty".to_string());
+        }
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.or()?;
+        if parser.pos < parser.tokens.len() {
+            return Err(format!(
+                "unexpected {} in condition",
+                describe(&parser.tokens[parser.pos])
+            ));
+        }
+        Ok(Self { expr })
+    }
+
+    /// Whether the condition holds for these parameters and task outputs.
+    pub fn evaluate(&self, params: &Map<String, Value>, data: &Value) -> bool {
+        truthy(&self.expr.value(params, data))
+    }
+
+    /// Ids of the tasks whose output the condition reads.
+    pub fn data_references(&self) -> Vec<String> {
+        let mut ids = Vec::new();
+        self.expr.collect_references(&mut ids);
+        ids
+    }
+}
+
+impl Expr {
+    fn value(&self, params: &Map<String, Value>, data: &Value) -> Value {
+        match self {
+            Expr::Literal(value) => value.clone(),
+            Expr::Path(path) => lookup(path, params, data),
+            Expr::Not(inner) => Value::Bool(!truthy(&inner.value(params, data))),
+            Expr::And(left, right) => {
+                Value::Bool(truthy(&left.value(params, data)) && truthy(&right.value(params, data)))
+            }
+            Expr::Or(left, right) => {
+                Value::Bool(truthy(&left.value(params, data)) || truthy(&right.value(params, data)))
+            }
+            Expr::Compare(left, op, right) => {
+                let (left, right) = (left.value(params, data), right.value(params, data));
+                Value::Bool(match op {
+                    CompareOp::Eq => equal(&left, &right),
+                    CompareOp::Ne => !equal(&left, &right),
+                    CompareOp::Lt => order(&left, &right) == Some(Ordering::Less),
+                    CompareOp::Le => {
+                        matches!(order(&left, &right), Some(Ordering::Less | Ordering::Equal))
+                    }
+                    CompareOp::Gt => order(&left, &right) == Some(Ordering::Greater),
+                    CompareOp::Ge => matches!(
+                        order(&left, &right),
+                        Some(Ordering::Greater | Ordering::Equal)
+                    ),
+                })
+            }
+        }
+    }
+
+    fn collect_references(&self, ids: &mut Vec<String>) {
+        match self {
+            Expr::Literal(_) => {}
+            Expr::Path(path) => {
+                if path[0] == "data" && !ids.contains(&path[1]) {
+                    ids.push(path[1].clone());
+                }
+            }
+            Expr::Not(inner) => inner.collect_references(ids),
+            Expr::And(left, right) | Expr::Or(left, right) | Expr::Compare(left, _, right) => {
+                left.collect_references(ids);
+                right.collect_references(ids);
+            }
+        }
+    }
+}
+
+/// Value at a `data.` or `param.` path such as `data.list.files`, `null` if
+/// there is none.
+pub(crate) fn path_value(
+    path: &str,
+    params: &Map<String, Value>,
+    data: &Value,
+) -> Result<Value, String> {
+    let path = path.trim();
+    if let Some(c) = path
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')))
+    {
+        return Err(format!("unexpected character '{}' in path '{}'", c, path));
+    }
+    match word_token(path)? {
+        Token::Path(path) => Ok(lookup(&path, params, data)),
+        _ => Err(format!("'{}' is not a data. or param. path", path)),
+    }
+}
+
+fn lookup(path: &[String], params: &Map<String, Value>, data: &Value) -> Value {
+    let root = match path[0].as_str() {
+        "param" => params.get(&path[1]),
+        _ => data.get(&path[1]),
+    };
+    path[2..]
+        .iter()
+        .fold(root, |value, field| match value? {
+            Value::Array(items) => field.parse::<usize>().ok().and_then(|i| items.get(i)),
+            value => value.get(field),
+        })
+        .cloned()
+        .unwrap_or(Value::Null)
+}
+
+fn truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(value) => *value,
+        Value::Number(number) => number.as_f64().is_some_and(|n| n != 0.0),
+        Value::String(text) => !text.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(object) => !object.is_empty(),
+    }
+}
+
+/// JSON equality, except that `1` and `1.0` are the same number.
+fn equal(left: &Value, right: &Value) -> bool {
+    match (left.as_f64(), right.as_f64()) {
+        (Some(left), Some(right)) => left == right,
+        _ => left == right,
+    }
+}
+
+/// Numbers and strings order among themselves; nothing else orders.
+fn order(left: &Value, right: &Value) -> Option<Ordering> {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => left.as_f64()?.partial_cmp(&right.as_f64()?),
+        (Value::String(left), Value::String(right)) => Some(left.cmp(right)),
+        _ => None,
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Literal(value) => format!("value {}", value),
+        Token::Path(path) => format!("'{}'", path.join(".")),
+        Token::Compare(_) => "comparison".to_string(),
+        Token::And => "'&&'".to_string(),
+        Token::Or => "'||'".to_string(),
+        Token::Not => "'!'".to_string(),
+        Token::Open => "'('".to_string(),
+        Token::Close => "')'".to_string(),
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let (token, len) = match (c, next) {
+            (c, _) if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            ('=', Some('=')) => (Token::Compare(CompareOp::Eq), 2),
+            ('!', Some('=')) => (Token::Compare(CompareOp::Ne), 2),
+            ('<', Some('=')) => (Token::Compare(CompareOp::Le), 2),
+            ('>', Some('=')) => (Token::Compare(CompareOp::Ge), 2),
+            ('<', _) => (Token::Compare(CompareOp::Lt), 1),
+            ('>', _) => (Token::Compare(CompareOp::Gt), 1),
+            ('&', Some('&')) => (Token::And, 2),
+            ('|', Some('|')) => (Token::Or, 2),
+            ('!', _) => (Token::Not, 1),
+            ('(', _) => (Token::Open, 1),
+            (')', _) => (Token::Close, 1),
+            ('"' | '\'', _) => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|&ch| ch == c)
+                    .ok_or_else(|| "unterminated string in condition".to_string())?;
+                let text: String = chars[i + 1..i + 1 + end].iter().collect();
+                (Token::Literal(Value::String(text)), end + 2)
+            }
+            (c, _) if c.is_ascii_digit() || c == '-' => {
+                let len = chars[i + 1..]
+                    .iter()
+                    .take_while(|ch| ch.is_ascii_digit() || **ch == '.')
+                    .count()
+                    + 1;
+                let text: String = chars[i..i + len].iter().collect();
+                let number = serde_json::from_str::<serde_json::Number>(&text)
+                    .map_err(|_| format!("invalid number '{}' in condition", text))?;
+                (Token::Literal(Value::Number(number)), len)
+            }
+            (c, _) if c.is_ascii_alphabetic() => {
+                let len = chars[i..]
+                    .iter()
+                    .take_while(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.'))
+                    .count();
+                let word: String = chars[i..i + len].iter().collect();
+                (word_token(&word)?, len)
+            }
+            (c, _) => return Err(format!("unexpected character '{}' in condition", c)),
+        };
+        tokens.push(token);
+        i += len;
+    }
+    Ok(tokens)
+}
+
+fn word_token(word: &str) -> Result<Token, String> {
+    if word.is_empty() {
+        return Err("path is empty".to_string());
+    }
+    match word {
+        "true" => return Ok(Token::Literal(Value::Bool(true))),
+        "false" => return Ok(Token::Literal(Value::Bool(false))),
+        "null" => return Ok(Token::Literal(Value::Null)),
+        _ => {}
+    }
+    let path: Vec<String> = word.split('.').map(str::to_string).collect();
+    if !matches!(path[0].as_str(), "data" | "param") {
+        return Err(format!(
+            "'{}' in condition must start with 'data.' or 'param.'",
+            word
+        ));
+    }
+    if path.len() < 2 || path.iter().any(String::is_empty) {
+        return Err(format!("incomplete path '{}' in condition", word));
+    }
+    Ok(Token::Path(path))
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        let found = self.tokens.get(self.pos) == Some(token);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn or(&mut self) -> Result<Expr, String> {
+        let mut expr = self.and()?;
+        while self.eat(&Token::Or) {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, String> {
+        let mut expr = self.unary()?;
+        while self.eat(&Token::And) {
+            expr = Expr::And(Box::new(expr), Box::new(self.unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        if self.eat(&Token::Not) {
+            return Ok(Expr::Not(Box::new(self.unary()?)));
+        }
+        let left = self.operand()?;
+        match self.tokens.get(self.pos) {
+            Some(Token::Compare(op)) => {
+                let op = *op;
+                self.pos += 1;
+                Ok(Expr::Compare(Box::new(left), op, Box::new(self.operand()?)))
+            }
+            _ => Ok(left),
+        }
+    }
+
+    fn operand(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Literal(value)) => Ok(Expr::Literal(value)),
+            Some(Token::Path(path)) => Ok(Expr::Path(path)),
+            Some(Token::Open) => {
+                let expr = self.or()?;
+                if !self.eat(&Token::Close) {
+                    return Err("missing ')' in condition".to_string());
+                }
+                Ok(expr)
+            }
+            Some(token) => Err(format!("unexpected {} in condition", describe(&token))),
+            None => Err("condition ends too early".to_string()),
+        }
+    }
+}
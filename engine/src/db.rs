@@ -0,0 +1,149 @@
+//! Named database connections for `db_query` tasks and the checks run on a
+//! query before it is sent.
+//!
+//! Workflows refer to a connection by name only, so the address and the
+//! password stay out of workflow files. The password is never stored: a
+//! connection names the environment variable that holds it.
+
+use serde::{Deserialize, Serialize};
+
+/// Rows kept from a query when neither the task nor the connection sets a cap.
+pub const DEFAULT_MAX_ROWS: usize = 1000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DbConnection {
+    pub name: String,
+    /// `postgres://`, `mysql://` or `sqlite:` URL, without the password.
+    pub url: String,
+    /// Environment variable holding the password, added to `url` on connect.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_env: Option<String>,
+    /// Statement keywords allowed on this connection, e.g. `["select", "with"]`.
+    /// Empty allows anything; otherwise only a single statement starting with
+    /// one of them is run.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_statements: Vec<String>,
+    /// Most rows any query on this connection returns, whatever the task asks for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_rows: Option<usize>,
+}
+
+impl DbConnection {
+    /// The URL to connect with, password included.
+    pub fn connect_url(&self) -> Result<String, String> {
+        let Some(var) = &self.password_env else {
+            return Ok(self.url.clone());
+        };
+        let password = std::env::var(var).map_err(|_| {
+            format!(
+                "connection '{}' reads its password from {}, which is not set",
+                self.name, var
+            )
+        })?;
+        with_password(&self.url, &password)
+            .ok_or_else(|| format!("connection '{}' has no user name in its URL", self.name))
+    }
+
+    /// Rows to keep for a task asking for `requested`.
+    pub fn row_cap(&self, requested: Option<usize>) -> usize {
+        let requested = requested.unwrap_or(DEFAULT_MAX_ROWS);
+        self.max_rows.map_or(requested, |cap| requested.min(cap))
+    }
+}
+
+/// `scheme://user@host/db` with the password added after the user name.
+pub(crate) fn with_password(url: &str, password: &str) -> Option<String> {
+    let (scheme, rest) = url.split_once("://")?;
+    let authority_end = rest.find('/').unwrap_or(rest.len());
+    let (user, host) = rest[..authority_end].rsplit_once('@')?;
+    let user = user.split(':').next().unwrap_or(user);
+    Some(format!(
+        "{}://{}:{}@{}{}",
+        scheme,
+        user,
+        percent_encode(password),
+        host,
+        &rest[authority_end..]
+    ))
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Check `query` against a connection's statement allowlist: a single
+/// statement whose first keyword is in `allowed`. An empty list allows
+/// anything.
+pub fn check_statement(query: &str, allowed: &[String]) -> Result<(), String> {
+    if allowed.is_empty() {
+        return Ok(());
+    }
+
+    let code = strip_comments_and_literals(query);
+    let body = code.trim().trim_end_matches(';');
+    if body.contains(';') {
+        return Err("only a single statement is allowed on this connection".to_string());
+    }
+    let keyword = body
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .find(|word| !word.is_empty())
+        .unwrap_or_default()
+        .to_lowercase();
+    if allowed.iter().any(|a| a.eq_ignore_ascii_case(&keyword)) {
+        Ok(())
+    } else {
+        Err(format!(
+            "'{}' statements are not allowed on this connection (allowed: {})",
+            keyword,
+            allowed.join(", ")
+        ))
+    }
+}
+
+/// The query with comments removed and quoted text blanked, so neither can
+/// hide a `;` or a keyword.
+fn strip_comments_and_literals(query: &str) -> String {
+    let mut out = String::with_capacity(query.len());
+    let mut chars = query.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '-' if chars.peek() == Some(&'-') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+                out.push(' ');
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut last = '\0';
+                for c in chars.by_ref() {
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    last = c;
+                }
+                out.push(' ');
+            }
+            '\'' | '"' | '`' => {
+                for inner in chars.by_ref() {
+                    if inner == c {
+                        break;
+                    }
+                }
+                out.push_str(" '' ");
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
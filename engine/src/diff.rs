@@ -0,0 +1,158 @@
+use crate::types::{ChangeOp, ContextChange};
+use serde_json::Value;
+
+/// Most changes kept for a single task; anything past this is only counted.
+pub const MAX_CHANGES: usize = 200;
+
+/// Largest serialized value kept in a change before it is replaced by a placeholder.
+pub const MAX_VALUE_BYTES: usize = 16 * 1024;
+
+/// Changes between two context snapshots, capped to keep audit events small.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContextDiff {
+    pub changes: Vec<ContextChange>,
+    /// Number of changed paths, including ones dropped by the cap.
+    pub count: usize,
+    /// Set when changes were dropped or values were replaced by placeholders.
+    pub truncated: bool,
+    /// Top-level keys that changed, all of them even when changes were dropped.
+    pub keys: Vec<String>,
+}
+
+/// Compute the changes that turn `before` into `after`.
+///
+/// Objects are compared key by key; any other differing value (arrays included)
+/// is reported as a single replace at its path.
+pub fn diff(before: &Value, after: &Value) -> ContextDiff {
+    let mut diff = ContextDiff::default();
+    diff_at(&mut diff, String::new(), before, after);
+    if let (Value::Object(old), Value::Object(new)) = (before, after) {
+        diff.keys = old
+            .keys()
+            .chain(new.keys().filter(|key| !old.contains_key(*key)))
+            .filter(|key| old.get(*key) != new.get(*key))
+            .cloned()
+            .collect();
+    }
+    diff
+}
+
+/// Apply changes produced by [`diff`] to `target`, in order.
+pub fn apply(target: &mut Value, changes: &[ContextChange]) {
+    for change in changes {
+        match change.op {
+            ChangeOp::Remove => {
+                remove_at(target, &change.path);
+            }
+            ChangeOp::Add | ChangeOp::Replace => {
+                set_at(
+                    target,
+                    &change.path,
+                    change.after.clone().unwrap_or(Value::Null),
+                );
+            }
+        }
+    }
+}
+
+/// Escape a key for use as a JSON pointer segment (RFC 6901).
+pub fn escape_pointer_segment(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn unescape_pointer_segment(segment: &str) -> String {
+    segment.replace("~1", "/").replace("~0", "~")
+}
+
+fn diff_at(diff: &mut ContextDiff, path: String, before: &Value, after: &Value) {
+    if before == after {
+        return;
+    }
+
+    match (before, after) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                let child = format!("{}/{}", path, escape_pointer_segment(key));
+                match new.get(key) {
+                    Some(new_value) => diff_at(diff, child, old_value, new_value),
+                    None => push(diff, ChangeOp::Remove, child, Some(old_value), None),
+                }
+            }
+            for (key, new_value) in new {
+                if !old.contains_key(key) {
+                    let child = format!("{}/{}", path, escape_pointer_segment(key));
+                    push(diff, ChangeOp::Add, child, None, Some(new_value));
+                }
+            }
+        }
+        _ => push(diff, ChangeOp::Replace, path, Some(before), Some(after)),
+    }
+}
+
+fn push(
+    diff: &mut ContextDiff,
+    op: ChangeOp,
+    path: String,
+    before: Option<&Value>,
+    after: Option<&Value>,
+) {
+    diff.count += 1;
+    if diff.changes.len() >= MAX_CHANGES {
+        diff.truncated = true;
+        return;
+    }
+
+    let mut capped = |value: Option<&Value>| {
+        value.map(|value| {
+            let size = serde_json::to_string(value).map(|s| s.len()).unwrap_or(0);
+            if size > MAX_VALUE_BYTES {
+                diff.truncated = true;
+                Value::String(format!("<{} bytes omitted>", size))
+            } else {
+                value.clone()
+            }
+        })
+    };
+    let before = capped(before);
+    let after = capped(after);
+
+    diff.changes.push(ContextChange {
+        op,
+        path,
+        before,
+        after,
+    });
+}
+
+fn set_at(target: &mut Value, path: &str, value: Value) {
+    if path.is_empty() {
+        *target = value;
+        return;
+    }
+
+    let mut current = target;
+    let mut segments = path.split('/').skip(1).peekable();
+    while let Some(segment) = segments.next() {
+        let key = unescape_pointer_segment(segment);
+        if !current.is_object() {
+            *current = Value::Object(serde_json::Map::new());
+        }
+        let Value::Object(map) = current else {
+            unreachable!("value was just made an object");
+        };
+        if segments.peek().is_none() {
+            map.insert(key, value);
+            return;
+        }
+        current = map.entry(key).or_insert(Value::Null);
+    }
+}
+
+fn remove_at(target: &mut Value, path: &str) {
+    let Some((parent, last)) = path.rsplit_once('/') else {
+        return;
+    };
+    if let Some(Value::Object(map)) = target.pointer_mut(parent) {
+        map.remove(&unescape_pointer_segment(last));
+    }
+}
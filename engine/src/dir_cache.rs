@@ -0,0 +1,274 @@
+//! Directories saved by `cache` tasks, such as `node_modules` or `target`, and
+//! restored into later runs.
+//!
+//! Each key is one gzipped archive named after the SHA-256 of the key. The
+//! archive is a flat list of entries: directories, regular files with their
+//! permission bits, and symlinks, each with a path relative to the run's
+//! working directory.
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use sha2::{Digest, Sha256};
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+const MAGIC: &[u8] = b"s_e_e-dir-cache 1\n";
+const DIR: u8 = b'd';
+const FILE: u8 = b'f';
+const SYMLINK: u8 = b'l';
+const END: u8 = b'e';
+
+/// Archives of cached directories, kept in one directory on disk.
+#[derive(Debug, Clone)]
+pub struct DirectoryCache {
+    dir: PathBuf,
+}
+
+impl DirectoryCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn archive_path(&self, key: &str) -> PathBuf {
+        let digest = Sha256::digest(key.as_bytes());
+        let name = digest
+            .iter()
+            .fold(String::with_capacity(64), |mut name, byte| {
+                let _ = write!(name, "{:02x}", byte);
+                name
+            });
+        self.dir.join(format!("{}.gz", name))
+    }
+
+    /// Unpack the archive saved under `key` into `root`, overwriting files
+    /// that are already there. Returns the number of file bytes restored, or
+    /// `None` when nothing is saved under `key`.
+    pub async fn restore(&self, key: &str, root: &Path) -> io::Result<Option<u64>> {
+        let archive = self.archive_path(key);
+        let root = root.to_path_buf();
+        blocking(move || {
+            let file = match File::open(&archive) {
+                Ok(file) => file,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(e) => return Err(e),
+            };
+            unpack(&mut GzDecoder::new(BufReader::new(file)), &root).map(Some)
+        })
+        .await
+    }
+
+    /// Pack `paths`, relative to `root`, into the archive for `key`, replacing
+    /// any earlier one. Paths that do not exist are left out. Returns the
+    /// number of file bytes saved.
+    pub async fn save(&self, key: &str, root: &Path, paths: &[String]) -> io::Result<u64> {
+        let archive = self.archive_path(key);
+        let dir = self.dir.clone();
+        let root = root.to_path_buf();
+        let paths = paths.to_vec();
+        blocking(move || {
+            fs::create_dir_all(&dir)?;
+            // Written under a temporary name so a run that saves the same key
+            // at the same time, or a crash, never leaves a half archive behind.
+            let partial = archive.with_extension(format!("{}.partial", uuid::Uuid::new_v4()));
+            let result = (|| -> io::Result<u64> {
+                let mut encoder =
+                    GzEncoder::new(BufWriter::new(File::create(&partial)?), Compression::fast());
+                encoder.write_all(MAGIC)?;
+                let mut bytes = 0;
+                for path in &paths {
+                    bytes += pack(&mut encoder, root.as_path(), Path::new(path))?;
+                }
+                encoder.write_all(&[END])?;
+                encoder.finish()?.into_inner()?.sync_all()?;
+                Ok(bytes)
+            })();
+            match result {
+                Ok(bytes) => {
+                    fs::rename(&partial, &archive)?;
+                    Ok(bytes)
+                }
+                Err(e) => {
+                    let _ = fs::remove_file(&partial);
+                    Err(e)
+                }
+            }
+        })
+        .await
+    }
+}
+
+/// Whether `path` stays inside the directory it is relative to.
+pub fn is_contained(path: &str) -> bool {
+    !path.is_empty()
+        && Path::new(path)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+}
+
+async fn blocking<T: Send + 'static>(
+    work: impl FnOnce() -> io::Result<T> + Send + 'static,
+) -> io::Result<T> {
+    tokio::task::spawn_blocking(work)
+        .await
+        .map_err(io::Error::other)?
+}
+
+fn pack(out: &mut impl Write, root: &Path, relative: &Path) -> io::Result<u64> {
+    let full = root.join(relative);
+    let metadata = match fs::symlink_metadata(&full) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e),
+    };
+    let name = relative.to_string_lossy().replace('\\', "/");
+
+    if metadata.file_type().is_symlink() {
+        out.write_all(&[SYMLINK])?;
+        write_text(out, &name)?;
+        write_text(out, &fs::read_link(&full)?.to_string_lossy())?;
+        Ok(0)
+    } else if metadata.is_dir() {
+        out.write_all(&[DIR])?;
+        write_text(out, &name)?;
+        let mut entries = fs::read_dir(&full)?
+            .map(|entry| entry.map(|entry| entry.file_name()))
+            .collect::<io::Result<Vec<_>>>()?;
+        entries.sort();
+        let mut bytes = 0;
+        for entry in entries {
+            bytes += pack(out, root, &relative.join(entry))?;
+        }
+        Ok(bytes)
+    } else {
+        out.write_all(&[FILE])?;
+        write_text(out, &name)?;
+        out.write_all(&mode(&metadata).to_le_bytes())?;
+        out.write_all(&metadata.len().to_le_bytes())?;
+        let copied = io::copy(&mut File::open(&full)?.take(metadata.len()), out)?;
+        if copied != metadata.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("{} changed while it was being cached", full.display()),
+            ));
+        }
+        Ok(copied)
+    }
+}
+
+fn unpack(input: &mut impl Read, root: &Path) -> io::Result<u64> {
+    let mut magic = vec![0; MAGIC.len()];
+    input.read_exact(&mut magic)?;
+    if magic != MAGIC {
+        return Err(invalid("not a directory cache archive".to_string()));
+    }
+
+    let mut bytes = 0;
+    loop {
+        let mut tag = [0];
+        input.read_exact(&mut tag)?;
+        if tag[0] == END {
+            return Ok(bytes);
+        }
+        let name = read_text(input)?;
+        if !is_contained(&name) {
+            return Err(invalid(format!("archive entry '{}' leaves the root", name)));
+        }
+        let target = root.join(&name);
+        match tag[0] {
+            DIR => fs::create_dir_all(&target)?,
+            FILE => {
+                let mode = u32::from_le_bytes(read_array(input)?);
+                let len = u64::from_le_bytes(read_array(input)?);
+                if let Some(parent) = target.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                remove_existing(&target)?;
+                let mut file = File::create(&target)?;
+                if io::copy(&mut input.by_ref().take(len), &mut file)? != len {
+                    return Err(io::ErrorKind::UnexpectedEof.into());
+                }
+                bytes += len;
+                set_mode(&target, mode)?;
+            }
+            SYMLINK => {
+                let link = read_text(input)?;
+                if let Some(parent) = target.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                remove_existing(&target)?;
+                symlink(&link, &target)?;
+            }
+            other => return Err(invalid(format!("unknown archive entry type {}", other))),
+        }
+    }
+}
+
+fn remove_existing(path: &Path) -> io::Result<()> {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.is_dir() => fs::remove_dir_all(path),
+        Ok(_) => fs::remove_file(path),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+fn write_text(out: &mut impl Write, text: &str) -> io::Result<()> {
+    out.write_all(&(text.len() as u32).to_le_bytes())?;
+    out.write_all(text.as_bytes())
+}
+
+fn read_text(input: &mut impl Read) -> io::Result<String> {
+    let len = u32::from_le_bytes(read_array(input)?);
+    let mut text = Vec::new();
+    input.by_ref().take(len as u64).read_to_end(&mut text)?;
+    if text.len() != len as usize {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    String::from_utf8(text).map_err(|e| invalid(e.to_string()))
+}
+
+fn read_array<const N: usize>(input: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    input.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(unix)]
+fn mode(metadata: &fs::Metadata) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode()
+}
+
+#[cfg(not(unix))]
+fn mode(_metadata: &fs::Metadata) -> u32 {
+    0o644
+}
+
+#[cfg(unix)]
+fn set_mode(path: &Path, mode: u32) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(mode))
+}
+
+#[cfg(not(unix))]
+fn set_mode(_path: &Path, _mode: u32) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(unix)]
+fn symlink(link: &str, target: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(link, target)
+}
+
+// Creating symlinks needs extra privileges on Windows, so they are left out.
+#[cfg(not(unix))]
+fn symlink(_link: &str, _target: &Path) -> io::Result<()> {
+    Ok(())
+}
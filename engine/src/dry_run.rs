@@ -0,0 +1,35 @@
+//! Dry runs: walking a workflow the way a run would, filling in each task's
+//! placeholders and deciding its `when` condition, without calling a handler.
+//! No task produces output, so placeholders and conditions that read task
+//! output cannot be decided; they are kept as written and reported.
+
+use crate::condition::Condition;
+use crate::types::TaskFunction;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A task a dry run reached, as it would have been handed to its handler.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlannedTask {
+    pub task_id: String,
+    pub task_name: String,
+    /// The handler that would run it.
+    pub handler: String,
+    /// What it would do, e.g. the command line it would run.
+    pub action: String,
+    /// The task's function with its placeholders filled in.
+    pub function: TaskFunction,
+    /// Placeholders left as written and `when` conditions assumed to hold,
+    /// because they read output of tasks that did not run.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unresolved: Vec<String>,
+}
+
+/// Whether `condition` reads the output of a task that has produced none, so
+/// a dry run cannot tell whether it holds.
+pub(crate) fn reads_missing_output(condition: &Condition, data: &Value) -> bool {
+    condition
+        .data_references()
+        .iter()
+        .any(|id| data.get(id).is_none())
+}
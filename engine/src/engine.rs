@@ -1,33 +1,230 @@
+use crate::cache::{cache_key, is_cacheable, TaskResultCache};
+use crate::checkpoint::{Checkpoint, CheckpointStore};
+use crate::condition::Condition;
+use crate::db::DbConnection;
+use crate::diff::{self, ContextDiff};
+use crate::dir_cache::DirectoryCache;
+use crate::dry_run::{reads_missing_output, PlannedTask};
 use crate::errors::*;
-use crate::handlers::{get_function_type, HandlerRegistry};
+use crate::events::OutputEvents;
+use crate::handler_policy::handler_policy;
+use crate::handlers::sandbox::describe_action;
+use crate::handlers::{cache, get_function_type, manual, HandlerRegistry};
+use crate::interpolation::{interpolate_task, preview_task};
+use crate::locks::{self, MemoryResourceLocks, ResourceLocks};
+use crate::loops::{expand_loops, loop_task_id};
+use crate::messaging::MessageConnection;
+use crate::middleware::{Next, TaskMiddleware};
+use crate::outputs::map_outputs;
+use crate::parameters::resolve_parameters;
+use crate::preflight::run_preflight;
+use crate::profile::{ProfilePhase, Profiler};
+use crate::scratch::Scratchpad;
+use crate::speculation::Speculation;
+use crate::stages::{is_held_back, open_stage, stage_rollups};
 use crate::types::*;
+use crate::workspace::{finish_workspace, prepare_workspace};
+use serde_json::Value;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tracing::{debug, error, info, instrument, trace, warn};
 
 pub struct WorkflowEngine {
     handlers: Arc<HandlerRegistry>,
+    output: Option<OutputEvents>,
+    result_cache: Option<Arc<dyn TaskResultCache>>,
+    checkpoints: Option<Arc<dyn CheckpointStore>>,
+    execution_id: Option<String>,
+    db_connections: Arc<Vec<DbConnection>>,
+    message_connections: Arc<Vec<MessageConnection>>,
+    initial_data: Option<Value>,
+    initial_scratch: Option<Scratchpad>,
+    directory_cache: Option<DirectoryCache>,
+    middlewares: Vec<Arc<dyn TaskMiddleware>>,
+    resource_locks: Arc<dyn ResourceLocks>,
+    dry_run: bool,
+    profiler: Profiler,
 }
 
 impl WorkflowEngine {
     pub fn new() -> Self {
         Self {
             handlers: Arc::new(HandlerRegistry::new()),
+            output: None,
+            result_cache: None,
+            checkpoints: None,
+            execution_id: None,
+            db_connections: Arc::new(Vec::new()),
+            message_connections: Arc::new(Vec::new()),
+            initial_data: None,
+            initial_scratch: None,
+            directory_cache: None,
+            middlewares: Vec::new(),
+            resource_locks: Arc::new(MemoryResourceLocks::new()),
+            dry_run: false,
+            profiler: Profiler::default(),
         }
     }
 
+    pub fn with_handlers(handlers: HandlerRegistry) -> Self {
+        Self {
+            handlers: Arc::new(handlers),
+            output: None,
+            result_cache: None,
+            checkpoints: None,
+            execution_id: None,
+            db_connections: Arc::new(Vec::new()),
+            message_connections: Arc::new(Vec::new()),
+            initial_data: None,
+            initial_scratch: None,
+            directory_cache: None,
+            middlewares: Vec::new(),
+            resource_locks: Arc::new(MemoryResourceLocks::new()),
+            dry_run: false,
+            profiler: Profiler::default(),
+        }
+    }
+
+    /// Stream task output to `output` while workflows run.
+    pub fn with_output(mut self, output: OutputEvents) -> Self {
+        self.output = Some(output);
+        self
+    }
+
+    /// Reuse stored results for tasks that set `cache`, and store new ones.
+    pub fn with_result_cache(mut self, cache: Arc<dyn TaskResultCache>) -> Self {
+        self.result_cache = Some(cache);
+        self
+    }
+
+    /// Save a [`Checkpoint`] to `checkpoints` after every round of tasks, so
+    /// an interrupted run can be resumed from it.
+    pub fn with_checkpoints(mut self, checkpoints: Arc<dyn CheckpointStore>) -> Self {
+        self.checkpoints = Some(checkpoints);
+        self
+    }
+
+    /// Wrap every handler call in `middleware`. Middlewares added earlier run
+    /// further out, seeing the task before and the result after later ones.
+    pub fn with_middleware(mut self, middleware: Arc<dyn TaskMiddleware>) -> Self {
+        self.middlewares.push(middleware);
+        self
+    }
+
+    /// Coordinate tasks that set `lock` through `locks` instead of a queue
+    /// private to this engine.
+    pub fn with_resource_locks(mut self, locks: Arc<dyn ResourceLocks>) -> Self {
+        self.resource_locks = locks;
+        self
+    }
+
+    /// Where `cache` tasks restore directories from and save them to.
+    pub fn with_directory_cache(mut self, cache: DirectoryCache) -> Self {
+        self.directory_cache = Some(cache);
+        self
+    }
+
+    /// Connections `db_query` tasks can refer to by name.
+    pub fn with_db_connections(mut self, connections: Vec<DbConnection>) -> Self {
+        self.db_connections = Arc::new(connections);
+        self
+    }
+
+    /// Broker connections `publish_message` tasks can refer to by name.
+    pub fn with_message_connections(mut self, connections: Vec<MessageConnection>) -> Self {
+        self.message_connections = Arc::new(connections);
+        self
+    }
+
+    /// Run under the caller's execution id instead of a generated one, so
+    /// per-execution state such as the workspace can be found again on resume.
+    pub fn with_execution_id(mut self, execution_id: impl Into<String>) -> Self {
+        self.execution_id = Some(execution_id.into());
+        self
+    }
+
+    /// Start the context with these task outputs, e.g. ones carried over from
+    /// an earlier run for tasks this run skips.
+    pub fn with_initial_data(mut self, data: Value) -> Self {
+        self.initial_data = Some(data);
+        self
+    }
+
+    /// Start the handlers' scratchpad with these values, e.g. the ones saved
+    /// in the checkpoint of the run being resumed.
+    pub fn with_initial_scratch(mut self, scratch: Scratchpad) -> Self {
+        self.initial_scratch = Some(scratch);
+        self
+    }
+
+    /// Walk the workflow without calling any handler: each task that would
+    /// run has its placeholders filled in and is listed in the result's
+    /// `planned` instead. No preflight checks run, no workspace is made and
+    /// nothing is cached or checkpointed. Only [`Self::execute_workflow`] and
+    /// [`Self::execute_workflow_with_params`] do dry runs.
+    pub fn with_dry_run(mut self) -> Self {
+        self.dry_run = true;
+        self
+    }
+
+    /// Record how long each round's graph build, each task's dispatch and
+    /// handler and each checkpoint save take in `profiler`.
+    pub fn with_profiler(mut self, profiler: Profiler) -> Self {
+        self.profiler = profiler;
+        self
+    }
+
+    async fn save_checkpoint(
+        &self,
+        context: &ExecutionContext,
+        completed: &HashSet<String>,
+        failed: &HashSet<String>,
+        skipped: &HashSet<String>,
+    ) {
+        if self.dry_run {
+            return;
+        }
+        if let Some(checkpoints) = &self.checkpoints {
+            let checkpoint = Checkpoint::new(context, completed, failed, skipped);
+            self.profiler
+                .time(
+                    ProfilePhase::Persistence,
+                    "save checkpoint",
+                    checkpoints.save(&checkpoint),
+                )
+                .await;
+        }
+    }
+
+    fn handler_key(&self, task: &EngineTask) -> String {
+        if let TaskFunction::Custom { name, .. } = &task.function {
+            if self.handlers.has_handler(name) {
+                return name.clone();
+            }
+        }
+        get_function_type(task).to_string()
+    }
+
     fn get_ready_tasks_from_tree(
         &self,
-        root_tasks: &[EngineTask],
+        workflow: &EngineWorkflow,
         completed_tasks: &HashSet<String>,
+        failed_tasks: &HashSet<String>,
         waiting_for_input: &HashSet<String>,
     ) -> Vec<EngineTask> {
         let mut ready_tasks = Vec::new();
 
+        struct Gate<'a> {
+            stages: &'a [String],
+            open: Option<usize>,
+        }
+
         fn collect_ready_tasks(
             tasks: &[EngineTask],
             completed_tasks: &HashSet<String>,
             waiting_for_input: &HashSet<String>,
+            gate: &Gate,
             ready_tasks: &mut Vec<EngineTask>,
         ) {
             for task in tasks {
@@ -36,6 +233,7 @@ impl WorkflowEngine {
                         &task.next_tasks,
                         completed_tasks,
                         waiting_for_input,
+                        gate,
                         ready_tasks,
                     );
                     continue;
@@ -46,17 +244,36 @@ impl WorkflowEngine {
                     continue;
                 }
 
+                if is_held_back(gate.stages, task, gate.open) {
+                    trace!("Task {} held back until earlier stages finish", task.id);
+                    continue;
+                }
+
                 ready_tasks.push(task.clone());
             }
         }
 
-        let root_only_tasks: Vec<EngineTask> =
-            root_tasks.iter().filter(|t| t.is_root).cloned().collect();
+        let root_only_tasks: Vec<EngineTask> = workflow
+            .tasks
+            .iter()
+            .filter(|t| t.is_root)
+            .cloned()
+            .collect();
+        let gate = Gate {
+            stages: &workflow.stages,
+            open: open_stage(
+                &workflow.stages,
+                &workflow.tasks,
+                completed_tasks,
+                failed_tasks,
+            ),
+        };
 
         collect_ready_tasks(
             &root_only_tasks,
             completed_tasks,
             waiting_for_input,
+            &gate,
             &mut ready_tasks,
         );
 
@@ -69,12 +286,28 @@ impl WorkflowEngine {
         ready_tasks
     }
 
-    #[instrument(skip(self), fields(workflow_id = %workflow.id, workflow_name = %workflow.name, task_count = workflow.tasks.len()))]
     pub async fn execute_workflow(
         &self,
         workflow: EngineWorkflow,
     ) -> Result<WorkflowResult, EngineError> {
-        let execution_id = uuid::Uuid::new_v4().to_string();
+        self.execute_workflow_with_params(workflow, HashMap::new())
+            .await
+    }
+
+    /// Run a workflow with values for its declared parameters. Missing optional
+    /// parameters fall back to their defaults.
+    #[instrument(skip(self, params), fields(workflow_id = %workflow.id, workflow_name = %workflow.name, task_count = workflow.tasks.len()))]
+    pub async fn execute_workflow_with_params(
+        &self,
+        workflow: EngineWorkflow,
+        params: HashMap<String, Value>,
+    ) -> Result<WorkflowResult, EngineError> {
+        check_handler_policy(&workflow)?;
+        let params = resolve_parameters(&workflow.parameters, &params)?;
+        let execution_id = self
+            .execution_id
+            .clone()
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
 
         info!(
             execution_id = %execution_id,
@@ -89,8 +322,34 @@ impl WorkflowEngine {
             workflow.tasks.iter().map(|t| &t.id).collect::<Vec<_>>()
         );
 
+        if !workflow.preflight.is_empty() && !self.dry_run {
+            let report = run_preflight(&workflow.preflight).await;
+            if !report.passed() {
+                warn!(
+                    execution_id = %execution_id,
+                    failed_checks = report.failures().count(),
+                    "Preflight checks failed, not starting any task"
+                );
+                return Err(EngineError::Preflight(report));
+            }
+        }
+
         debug!(execution_id = %execution_id, "Creating execution context");
         let mut context = ExecutionContext::new(execution_id.clone(), workflow.name.clone());
+        context.output = self.output.clone();
+        context.db_connections = Arc::clone(&self.db_connections);
+        context.message_connections = Arc::clone(&self.message_connections);
+        context.directory_cache = self.directory_cache.clone();
+        context.params = params;
+        if let Some(data) = &self.initial_data {
+            context.data = data.clone();
+        }
+        if let Some(scratch) = &self.initial_scratch {
+            context.scratch = scratch.clone();
+        }
+        if let Some(options) = workflow.workspace.as_ref().filter(|_| !self.dry_run) {
+            context.working_dir = Some(prepare_workspace(&execution_id, options)?);
+        }
 
         debug!(execution_id = %execution_id, "Adding tasks to execution context");
         for task in &workflow.tasks {
@@ -107,8 +366,18 @@ impl WorkflowEngine {
         debug!(execution_id = %execution_id, "Initializing execution state");
         let mut completed_tasks = HashSet::new();
         let mut waiting_for_input = HashSet::new();
+        let mut failed_tasks = HashSet::new();
+        let mut skipped_tasks = HashSet::new();
+        let mut loop_iterations: HashMap<String, Vec<EngineTask>> = HashMap::new();
         let mut audit_trail = Vec::new();
         let mut errors = Vec::new();
+        let mut failure_reasons = HashMap::new();
+        let mut exit_codes = HashMap::new();
+        let mut output_stats = HashMap::new();
+        let mut cache_hits = HashSet::new();
+        let mut environments = HashMap::new();
+        let mut planned = Vec::new();
+        let speculation = Speculation::new(&workflow.tasks);
         let mut execution_round = 0;
 
         trace!(
@@ -128,11 +397,32 @@ impl WorkflowEngine {
             );
 
             trace!(execution_id = %execution_id, "Determining ready tasks");
+            let graph_started = Instant::now();
             let ready_tasks = self.get_ready_tasks_from_tree(
-                &workflow.tasks,
+                &workflow,
                 &completed_tasks,
+                &failed_tasks,
                 &waiting_for_input,
             );
+            let skipped_before = skipped_tasks.len();
+            let ready_tasks = skip_unmet_conditions(
+                ready_tasks,
+                &mut context,
+                &mut skipped_tasks,
+                &mut completed_tasks,
+                self.dry_run,
+            );
+            speculation.settle(&completed_tasks, &mut context);
+            self.profiler.record(
+                ProfilePhase::GraphBuild,
+                format!("round {}", execution_round),
+                graph_started,
+            );
+            if ready_tasks.is_empty() && skipped_tasks.len() > skipped_before {
+                continue;
+            }
+            speculation.start(&ready_tasks, &mut context);
+            let (ready_tasks, loops) = expand_loops(ready_tasks, &mut context);
 
             trace!(
                 execution_id = %execution_id,
@@ -144,7 +434,7 @@ impl WorkflowEngine {
                 ready_tasks.len()
             );
 
-            if ready_tasks.is_empty() {
+            if ready_tasks.is_empty() && loops.is_empty() {
                 if waiting_for_input.is_empty() {
                     debug!(
                         execution_id = %execution_id,
@@ -171,7 +461,11 @@ impl WorkflowEngine {
                 ready_count = ready_tasks.len(),
                 "Executing ready tasks in parallel"
             );
-            let results = self.execute_round(ready_tasks, &mut context).await?;
+            let results = if self.dry_run {
+                self.plan_round(ready_tasks, &mut context, &mut planned)
+            } else {
+                self.execute_round(ready_tasks, &mut context).await?
+            };
 
             debug!(
                 execution_id = %execution_id,
@@ -180,14 +474,26 @@ impl WorkflowEngine {
                 "Processing task execution results"
             );
 
-            for (task, result) in results {
+            for (task, result, changes, cache_hit) in results {
                 trace!(
                     execution_id = %execution_id,
                     task_id = %task.id,
                     task_name = %task.name,
                     success = result.success,
+                    cache_hit = cache_hit,
                     "Processing task result"
                 );
+                speculation.record(&task.id, &changes.keys, &mut context);
+
+                if cache_hit {
+                    cache_hits.insert(task.id.clone());
+                } else if let Some(environment) = result.environment {
+                    environments.insert(task.id.clone(), environment);
+                }
+
+                if let Some(stats) = result.output_stats {
+                    output_stats.insert(task.id.clone(), stats);
+                }
 
                 if let Some(waiting) = result.output.get("waiting_for_input") {
                     if waiting.as_bool().unwrap_or(false) {
@@ -219,8 +525,10 @@ impl WorkflowEngine {
                         task_id: task.id.clone(),
                         status: AuditStatus::Success,
                         timestamp: chrono::Utc::now().to_rfc3339(),
-                        changes_count: 1,
+                        changes_count: changes.count,
                         message: format!("Completed task: {}", task.name),
+                        changes: changes.changes,
+                        changes_truncated: changes.truncated,
                     });
 
                     completed_tasks.insert(task.id.clone());
@@ -250,12 +558,21 @@ impl WorkflowEngine {
                         task_id: task.id.clone(),
                         status: AuditStatus::Failure,
                         timestamp: chrono::Utc::now().to_rfc3339(),
-                        changes_count: 0,
+                        changes_count: changes.count,
                         message: format!("Failed task: {} - {}", task.name, error_msg),
+                        changes: changes.changes,
+                        changes_truncated: changes.truncated,
                     });
 
                     errors.push(format!("Task {}: {}", task.id, error_msg));
+                    if let Some(reason) = result.failure_reason {
+                        failure_reasons.insert(task.id.clone(), reason);
+                    }
+                    if let Some(code) = result.exit_code {
+                        exit_codes.insert(task.id.clone(), code);
+                    }
 
+                    failed_tasks.insert(task.id.clone());
                     completed_tasks.insert(task.id.clone());
 
                     trace!(
@@ -266,12 +583,27 @@ impl WorkflowEngine {
                 }
             }
 
+            for run in loops {
+                let task_id = run.task_id().to_string();
+                let iterations = run.finish(
+                    &mut context,
+                    &mut completed_tasks,
+                    &mut failed_tasks,
+                    &mut errors,
+                    &mut audit_trail,
+                );
+                speculation.record(&task_id, &[], &mut context);
+                loop_iterations.insert(task_id, iterations);
+            }
+
             debug!(
                 execution_id = %execution_id,
                 completed_count = completed_tasks.len(),
                 "📊 Progress: {} completed",
                 completed_tasks.len()
             );
+            self.save_checkpoint(&context, &completed_tasks, &failed_tasks, &skipped_tasks)
+                .await;
         }
 
         let success = errors.is_empty();
@@ -284,14 +616,24 @@ impl WorkflowEngine {
             "🏁 Workflow execution finished"
         );
 
+        if success && waiting_for_input.is_empty() && !self.dry_run {
+            cache::save_missed_caches(&mut context, &workflow.tasks).await;
+        }
+        let workspace = settle_workspace(&context, success, !waiting_for_input.is_empty());
+
         let tasks = workflow
             .tasks
             .iter()
+            .flat_map(|t| {
+                std::iter::once(t).chain(loop_iterations.get(&t.id).into_iter().flatten())
+            })
             .map(|t| TaskInfo {
                 id: t.id.clone(),
                 name: t.name.clone(),
 
-                status: if completed_tasks.contains(&t.id) {
+                status: if skipped_tasks.contains(&t.id) {
+                    TaskStatus::Skipped
+                } else if completed_tasks.contains(&t.id) {
                     TaskStatus::Complete
                 } else if waiting_for_input.contains(&t.id) {
                     TaskStatus::WaitingForInput
@@ -302,6 +644,15 @@ impl WorkflowEngine {
                 },
             })
             .collect();
+        let stages = stage_rollups(
+            &workflow.stages,
+            &workflow.tasks,
+            &completed_tasks,
+            &failed_tasks,
+            &waiting_for_input,
+        );
+
+        let outputs = map_outputs(&workflow.outputs, &context.params, &context.data);
 
         Ok(WorkflowResult {
             success,
@@ -310,26 +661,99 @@ impl WorkflowEngine {
             audit_trail,
             per_task_logs: context.per_task_logs,
             errors,
+            failure_reasons,
+            exit_codes,
+            output_stats,
+            cache_hits,
+            environments,
+            workspace,
+            stages,
+            outputs,
+            planned,
+            durations_ms: duration_millis(&context.task_durations),
         })
     }
 
+    /// A dry run's round: list each task as it would run instead of running
+    /// it. Planned tasks succeed without output.
+    fn plan_round(
+        &self,
+        ready_tasks: Vec<EngineTask>,
+        context: &mut ExecutionContext,
+        planned: &mut Vec<PlannedTask>,
+    ) -> Vec<(EngineTask, TaskResult, ContextDiff, bool)> {
+        let mut outcomes = Vec::with_capacity(ready_tasks.len());
+        for task in ready_tasks {
+            let (success, error) = match preview_task(&task, &context.params, &context.data) {
+                Ok((resolved, mut unresolved)) => {
+                    let undecided = task
+                        .when
+                        .as_deref()
+                        .filter(|when| {
+                            Condition::parse(when).is_ok_and(|condition| {
+                                reads_missing_output(&condition, &context.data)
+                            })
+                        })
+                        .map(|when| format!("when {}", when));
+                    unresolved.splice(0..0, undecided);
+                    let action = describe_action(&resolved);
+                    info!(
+                        execution_id = %context.execution_id,
+                        task_id = %task.id,
+                        action = %action,
+                        "Dry run: planned task"
+                    );
+                    context.log_task(task.id.clone(), format!("[dry run] {}", action));
+                    planned.push(PlannedTask {
+                        task_id: task.id.clone(),
+                        task_name: task.name.clone(),
+                        handler: self.handler_key(&task),
+                        action,
+                        function: resolved.function,
+                        unresolved,
+                    });
+                    (true, None)
+                }
+                Err(e) => {
+                    let error = format!("Could not fill in placeholder {}", e);
+                    context.log_task(task.id.clone(), format!("Error: {}", error));
+                    (false, Some(error))
+                }
+            };
+            context.report_finished(&task.id, success);
+            let result = TaskResult {
+                success,
+                output: Value::Null,
+                error,
+                failure_reason: None,
+                output_stats: None,
+                exit_code: None,
+                environment: None,
+            };
+            outcomes.push((task, result, ContextDiff::default(), false));
+        }
+        outcomes
+    }
+
     #[instrument(skip(self, context), fields(ready_count = ready_tasks.len()))]
     async fn execute_round(
         &self,
         ready_tasks: Vec<EngineTask>,
         context: &mut ExecutionContext,
-    ) -> Result<Vec<(EngineTask, TaskResult)>, EngineError> {
+    ) -> Result<Vec<(EngineTask, TaskResult, ContextDiff, bool)>, EngineError> {
         debug!(
             execution_id = %context.execution_id,
             ready_count = ready_tasks.len(),
             "Starting parallel execution of ready tasks"
         );
 
+        let round_data = context.data.clone();
+        let round_scratch = context.scratch.clone();
         let mut handles = Vec::new();
 
         for task in ready_tasks {
             let task_id = task.id.clone();
-            let function_type = get_function_type(&task);
+            let function_type = self.handler_key(&task);
 
             trace!(
                 execution_id = %context.execution_id,
@@ -357,74 +781,212 @@ impl WorkflowEngine {
 
             let task_clone = task.clone();
             let mut context_clone = context.clone();
-            let function_type_clone = function_type.to_string();
+            let function_type_clone = function_type.clone();
             let handlers_clone = Arc::clone(&self.handlers);
+            let cache_clone = self.result_cache.clone();
+            let middlewares = self.middlewares.clone();
+            let resource_locks = Arc::clone(&self.resource_locks);
+            let profiler = self.profiler.clone();
 
-            let handle = tokio::spawn(async move {
-                trace!(
-                    execution_id = %context_clone.execution_id,
-                    task_id = %task_clone.id,
-                    function_type = %function_type_clone,
-                    "Starting task execution in async context"
-                );
+            let handle = tokio::spawn(timed(dispatched(
+                profiler.clone(),
+                task_id.clone(),
+                async move {
+                    trace!(
+                        execution_id = %context_clone.execution_id,
+                        task_id = %task_clone.id,
+                        function_type = %function_type_clone,
+                        "Starting task execution in async context"
+                    );
 
-                let handler = match handlers_clone.get_handler(&function_type_clone) {
-                    Some(h) => h,
-                    None => {
-                        warn!(
-                            execution_id = %context_clone.execution_id,
-                            task_id = %task_clone.id,
-                            function_type = %function_type_clone,
-                            "No handler found for function type"
-                        );
-                        return (
-                            task_clone,
-                            TaskResult {
-                                success: false,
-                                output: serde_json::Value::Null,
-                                error: Some(format!(
-                                    "No handler found for function type: {}",
-                                    function_type_clone
-                                )),
-                            },
-                        );
-                    }
-                };
+                    // Filled in here, so the cache key covers the values used.
+                    let task_clone = match interpolate_task(
+                        &task_clone,
+                        &context_clone.params,
+                        &context_clone.data,
+                    ) {
+                        Ok(task) => task,
+                        Err(e) => {
+                            warn!(
+                                execution_id = %context_clone.execution_id,
+                                task_id = %task_clone.id,
+                                error = %e,
+                                "Failed to fill in task placeholders"
+                            );
+                            return (
+                                task_clone,
+                                TaskResult {
+                                    success: false,
+                                    output: serde_json::Value::Null,
+                                    error: Some(format!("Could not fill in placeholder {}", e)),
+                                    failure_reason: None,
+                                    output_stats: None,
+                                    exit_code: None,
+                                    environment: None,
+                                },
+                                context_clone,
+                                false,
+                            );
+                        }
+                    };
+
+                    let cached = match (&cache_clone, task_clone.cache) {
+                        (Some(cache), Some(policy)) => {
+                            let key = cache_key(&task_clone, &context_clone.params);
+                            if let Some(result) = cache.get(&key).await {
+                                debug!(
+                                    execution_id = %context_clone.execution_id,
+                                    task_id = %task_clone.id,
+                                    cache_key = %key,
+                                    "Using cached task result"
+                                );
+                                context_clone.emit_task_line(&task_clone.id, "Using cached result");
+                                return (task_clone, result, context_clone, true);
+                            }
+                            Some((Arc::clone(cache), key, policy))
+                        }
+                        _ => None,
+                    };
+
+                    let handler = match handlers_clone.get_handler(&function_type_clone) {
+                        Some(h) => h,
+                        None => {
+                            warn!(
+                                execution_id = %context_clone.execution_id,
+                                task_id = %task_clone.id,
+                                function_type = %function_type_clone,
+                                "No handler found for function type"
+                            );
+                            return (
+                                task_clone,
+                                TaskResult {
+                                    success: false,
+                                    output: serde_json::Value::Null,
+                                    error: Some(format!(
+                                        "No handler found for function type: {}",
+                                        function_type_clone
+                                    )),
+                                    failure_reason: None,
+                                    output_stats: None,
+                                    exit_code: None,
+                                    environment: None,
+                                },
+                                context_clone,
+                                false,
+                            );
+                        }
+                    };
 
-                debug!(
-                    execution_id = %context_clone.execution_id,
-                    task_id = %task_clone.id,
-                    "Calling task handler"
-                );
+                    debug!(
+                        execution_id = %context_clone.execution_id,
+                        task_id = %task_clone.id,
+                        "Calling task handler"
+                    );
 
-                match handler.execute(&mut context_clone, &task_clone).await {
-                    Ok(result) => {
-                        trace!(
-                            execution_id = %context_clone.execution_id,
-                            task_id = %task_clone.id,
-                            success = result.success,
-                            "Task handler completed successfully"
-                        );
-                        (task_clone, result)
+                    let held = match &task_clone.lock {
+                        Some(lock) => {
+                            match locks::acquire(
+                                &resource_locks,
+                                lock,
+                                &context_clone,
+                                &task_clone.id,
+                            )
+                            .await
+                            {
+                                Ok(held) => Some(held),
+                                Err(e) => {
+                                    warn!(
+                                        execution_id = %context_clone.execution_id,
+                                        task_id = %task_clone.id,
+                                        error = %e,
+                                        "Failed to acquire resource lock"
+                                    );
+                                    return (
+                                        task_clone,
+                                        TaskResult {
+                                            success: false,
+                                            output: serde_json::Value::Null,
+                                            error: Some(format!("Could not acquire lock: {}", e)),
+                                            failure_reason: None,
+                                            output_stats: None,
+                                            exit_code: None,
+                                            environment: None,
+                                        },
+                                        context_clone,
+                                        false,
+                                    );
+                                }
+                            }
+                        }
+                        None => None,
+                    };
+
+                    let run = profiler.time(ProfilePhase::Handler, task_clone.id.clone(), async {
+                        Next::new(&middlewares, handler)
+                            .run(&mut context_clone, &task_clone)
+                            .await
+                            .map_err(EngineError::from)
+                    });
+                    // Dropping the handler future on timeout kills any process tree it spawned.
+                    let outcome = match task_clone.timeout_seconds {
+                        Some(seconds) => tokio::time::timeout(Duration::from_secs(seconds), run)
+                            .await
+                            .unwrap_or_else(|_| {
+                                Err(EngineError::TaskTimeout {
+                                    task_id: task_clone.id.clone(),
+                                    seconds,
+                                })
+                            }),
+                        None => run.await,
+                    };
+                    if let Some(held) = held {
+                        held.release().await;
                     }
-                    Err(e) => {
-                        error!(
-                            execution_id = %context_clone.execution_id,
-                            task_id = %task_clone.id,
-                            error = %e,
-                            "Task handler failed"
-                        );
-                        (
-                            task_clone,
-                            TaskResult {
-                                success: false,
-                                output: serde_json::Value::Null,
-                                error: Some(e.to_string()),
-                            },
-                        )
+
+                    match outcome {
+                        Ok(result) => {
+                            trace!(
+                                execution_id = %context_clone.execution_id,
+                                task_id = %task_clone.id,
+                                success = result.success,
+                                "Task handler completed successfully"
+                            );
+                            if let Some((cache, key, policy)) = cached {
+                                if is_cacheable(&result) {
+                                    cache
+                                        .put(&key, &result, Duration::from_secs(policy.ttl_seconds))
+                                        .await;
+                                }
+                            }
+                            (task_clone, result, context_clone, false)
+                        }
+                        Err(e) => {
+                            error!(
+                                execution_id = %context_clone.execution_id,
+                                task_id = %task_clone.id,
+                                error = %e,
+                                "Task handler failed"
+                            );
+                            let failure_reason = matches!(e, EngineError::TaskTimeout { .. })
+                                .then_some(FailureReason::Timeout);
+                            (
+                                task_clone,
+                                TaskResult {
+                                    success: false,
+                                    output: serde_json::Value::Null,
+                                    error: Some(e.to_string()),
+                                    failure_reason,
+                                    output_stats: None,
+                                    exit_code: None,
+                                    environment: None,
+                                },
+                                context_clone,
+                                false,
+                            )
+                        }
                     }
-                }
-            });
+                },
+            )));
 
             handles.push(handle);
         }
@@ -435,6 +997,7 @@ impl WorkflowEngine {
             "Waiting for all parallel tasks to complete"
         );
 
+        let _abort = AbortOnDrop(handles.iter().map(|h| h.abort_handle()).collect());
         let mut results = Vec::new();
         for (i, handle) in handles.into_iter().enumerate() {
             trace!(
@@ -444,13 +1007,23 @@ impl WorkflowEngine {
             );
 
             match handle.await {
-                Ok(result) => {
+                Ok((result, elapsed)) => {
                     trace!(
                         execution_id = %context.execution_id,
                         task_id = %result.0.id,
                         success = result.1.success,
+                        elapsed_ms = elapsed.as_millis() as u64,
                         "Task handle completed successfully"
                     );
+                    let task_id = loop_task_id(&result.0.id);
+                    if task_id != result.0.id {
+                        let longest = context
+                            .task_durations
+                            .entry(task_id.to_string())
+                            .or_default();
+                        *longest = (*longest).max(elapsed);
+                    }
+                    context.task_durations.insert(result.0.id.clone(), elapsed);
                     results.push(result);
                 }
                 Err(e) => {
@@ -474,7 +1047,29 @@ impl WorkflowEngine {
             "Merging context updates from parallel execution"
         );
 
-        for (task, task_result) in &results {
+        let mut outcomes = Vec::with_capacity(results.len());
+        for (task, task_result, task_context, cache_hit) in results {
+            let before = context.data.clone();
+            merge_task_data(&mut context.data, &round_data, &task_context.data);
+            context.scratch.merge(&round_scratch, &task_context.scratch);
+            let waiting = task_result
+                .output
+                .get("waiting_for_input")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            if task_result.success && !waiting && !task_result.output.is_null() {
+                if let Value::Object(data) = &mut context.data {
+                    data.insert(task.id.clone(), task_result.output.clone());
+                }
+            }
+            let changes = diff::diff(&before, &context.data);
+            trace!(
+                execution_id = %context.execution_id,
+                task_id = %task.id,
+                changes_count = changes.count,
+                "Recorded context changes"
+            );
+
             let mut logs = Vec::new();
 
             if let Some(output_str) = task_result.output.as_str() {
@@ -492,18 +1087,22 @@ impl WorkflowEngine {
             if !logs.is_empty() {
                 context.per_task_logs.insert(task.id.clone(), logs);
             }
+            if !waiting {
+                context.report_finished(&task.id, task_result.success);
+            }
+
+            outcomes.push((task, task_result, changes, cache_hit));
         }
 
         debug!(
             execution_id = %context.execution_id,
-            final_result_count = results.len(),
+            final_result_count = outcomes.len(),
             "Parallel execution round completed"
         );
 
-        Ok(results)
+        Ok(outcomes)
     }
 
-    #[instrument(skip(self), fields(execution_id = %execution_id))]
     pub async fn resume_workflow_execution(
         &self,
         workflow: EngineWorkflow,
@@ -511,6 +1110,47 @@ impl WorkflowEngine {
         completed_task_ids: HashSet<String>,
         task_user_inputs: HashMap<String, String>,
     ) -> Result<WorkflowResult, EngineError> {
+        self.resume_workflow_execution_with_params(
+            workflow,
+            execution_id,
+            completed_task_ids,
+            task_user_inputs,
+            HashMap::new(),
+        )
+        .await
+    }
+
+    #[instrument(skip(self, params), fields(execution_id = %execution_id))]
+    pub async fn resume_workflow_execution_with_params(
+        &self,
+        workflow: EngineWorkflow,
+        execution_id: String,
+        completed_task_ids: HashSet<String>,
+        task_user_inputs: HashMap<String, String>,
+        params: HashMap<String, Value>,
+    ) -> Result<WorkflowResult, EngineError> {
+        self.resume_with_data(
+            workflow,
+            execution_id,
+            completed_task_ids,
+            task_user_inputs,
+            params,
+            self.initial_data.clone(),
+        )
+        .await
+    }
+
+    async fn resume_with_data(
+        &self,
+        workflow: EngineWorkflow,
+        execution_id: String,
+        completed_task_ids: HashSet<String>,
+        task_user_inputs: HashMap<String, String>,
+        params: HashMap<String, Value>,
+        data: Option<Value>,
+    ) -> Result<WorkflowResult, EngineError> {
+        check_handler_policy(&workflow)?;
+        let params = resolve_parameters(&workflow.parameters, &params)?;
         info!(
             execution_id = %execution_id,
             workflow_name = %workflow.name,
@@ -520,6 +1160,20 @@ impl WorkflowEngine {
 
         debug!(execution_id = %execution_id, "Creating execution context");
         let mut context = ExecutionContext::new(execution_id.clone(), workflow.name.clone());
+        context.output = self.output.clone();
+        context.db_connections = Arc::clone(&self.db_connections);
+        context.message_connections = Arc::clone(&self.message_connections);
+        context.directory_cache = self.directory_cache.clone();
+        context.params = params;
+        if let Some(data) = data {
+            context.data = data;
+        }
+        if let Some(scratch) = &self.initial_scratch {
+            context.scratch = scratch.clone();
+        }
+        if let Some(options) = &workflow.workspace {
+            context.working_dir = Some(prepare_workspace(&execution_id, options)?);
+        }
 
         debug!(execution_id = %execution_id, "Adding tasks to execution context");
         for task in &workflow.tasks {
@@ -533,18 +1187,59 @@ impl WorkflowEngine {
         );
         let mut completed_tasks = completed_task_ids;
 
+        let mut audit_trail = Vec::new();
         for (task_id, input_value) in &task_user_inputs {
             completed_tasks.insert(task_id.clone());
 
+            if let Some(task) = context
+                .tasks
+                .get(task_id)
+                .filter(|task| matches!(task.function, TaskFunction::Manual { .. }))
+            {
+                match serde_json::from_str::<ManualConfirmation>(input_value) {
+                    Ok(confirmation) => {
+                        let entry = manual::confirmation_audit_entry(task, &confirmation);
+                        context.log_task(task_id.clone(), entry.message.clone());
+                        audit_trail.push(entry);
+                        if let (Value::Object(data), Ok(value)) =
+                            (&mut context.data, serde_json::to_value(&confirmation))
+                        {
+                            data.insert(task_id.clone(), value);
+                        }
+                        continue;
+                    }
+                    Err(e) => warn!(
+                        execution_id = %execution_id,
+                        task_id = %task_id,
+                        error = %e,
+                        "Manual task input is not a confirmation, storing it as text"
+                    ),
+                }
+            }
+
             context.log_task(
                 task_id.clone(),
                 format!("User input provided: {}", input_value),
             );
+            if let Value::Object(data) = &mut context.data {
+                data.insert(task_id.clone(), Value::String(input_value.clone()));
+            }
+        }
+        let speculation = Speculation::new(&workflow.tasks);
+        for task_id in task_user_inputs.keys() {
+            speculation.record(task_id, &[], &mut context);
         }
 
         let mut waiting_for_input = HashSet::new();
-        let mut audit_trail = Vec::new();
+        let mut failed_tasks = HashSet::new();
+        let mut skipped_tasks = HashSet::new();
+        let mut loop_iterations: HashMap<String, Vec<EngineTask>> = HashMap::new();
         let mut errors = Vec::new();
+        let mut failure_reasons = HashMap::new();
+        let mut exit_codes = HashMap::new();
+        let mut output_stats = HashMap::new();
+        let mut cache_hits = HashSet::new();
+        let mut environments = HashMap::new();
         let mut execution_round = 0;
 
         loop {
@@ -558,11 +1253,32 @@ impl WorkflowEngine {
             );
 
             trace!(execution_id = %execution_id, "Determining ready tasks");
+            let graph_started = Instant::now();
             let ready_tasks = self.get_ready_tasks_from_tree(
-                &workflow.tasks,
+                &workflow,
                 &completed_tasks,
+                &failed_tasks,
                 &waiting_for_input,
             );
+            let skipped_before = skipped_tasks.len();
+            let ready_tasks = skip_unmet_conditions(
+                ready_tasks,
+                &mut context,
+                &mut skipped_tasks,
+                &mut completed_tasks,
+                false,
+            );
+            speculation.settle(&completed_tasks, &mut context);
+            self.profiler.record(
+                ProfilePhase::GraphBuild,
+                format!("round {}", execution_round),
+                graph_started,
+            );
+            if ready_tasks.is_empty() && skipped_tasks.len() > skipped_before {
+                continue;
+            }
+            speculation.start(&ready_tasks, &mut context);
+            let (ready_tasks, loops) = expand_loops(ready_tasks, &mut context);
 
             trace!(
                 execution_id = %execution_id,
@@ -574,7 +1290,7 @@ impl WorkflowEngine {
                 ready_tasks.len()
             );
 
-            if ready_tasks.is_empty() {
+            if ready_tasks.is_empty() && loops.is_empty() {
                 if waiting_for_input.is_empty() {
                     debug!(
                         execution_id = %execution_id,
@@ -610,14 +1326,26 @@ impl WorkflowEngine {
                 "Processing task execution results"
             );
 
-            for (task, result) in results {
+            for (task, result, changes, cache_hit) in results {
                 trace!(
                     execution_id = %execution_id,
                     task_id = %task.id,
                     task_name = %task.name,
                     success = result.success,
+                    cache_hit = cache_hit,
                     "Processing task result"
                 );
+                speculation.record(&task.id, &changes.keys, &mut context);
+
+                if cache_hit {
+                    cache_hits.insert(task.id.clone());
+                } else if let Some(environment) = result.environment {
+                    environments.insert(task.id.clone(), environment);
+                }
+
+                if let Some(stats) = result.output_stats {
+                    output_stats.insert(task.id.clone(), stats);
+                }
 
                 if let Some(waiting) = result.output.get("waiting_for_input") {
                     if waiting.as_bool().unwrap_or(false) {
@@ -644,8 +1372,10 @@ impl WorkflowEngine {
                         task_id: task.id.clone(),
                         status: AuditStatus::Success,
                         timestamp: chrono::Utc::now().to_rfc3339(),
-                        changes_count: 1,
+                        changes_count: changes.count,
                         message: format!("Completed task: {}", task.name),
+                        changes: changes.changes,
+                        changes_truncated: changes.truncated,
                     });
 
                     completed_tasks.insert(task.id.clone());
@@ -663,21 +1393,45 @@ impl WorkflowEngine {
                         task_id: task.id.clone(),
                         status: AuditStatus::Failure,
                         timestamp: chrono::Utc::now().to_rfc3339(),
-                        changes_count: 0,
+                        changes_count: changes.count,
                         message: format!("Failed task: {} - {}", task.name, error_msg),
+                        changes: changes.changes,
+                        changes_truncated: changes.truncated,
                     });
 
                     errors.push(format!("Task {}: {}", task.id, error_msg));
+                    if let Some(reason) = result.failure_reason {
+                        failure_reasons.insert(task.id.clone(), reason);
+                    }
+                    if let Some(code) = result.exit_code {
+                        exit_codes.insert(task.id.clone(), code);
+                    }
+                    failed_tasks.insert(task.id.clone());
                     completed_tasks.insert(task.id.clone());
                 }
             }
 
+            for run in loops {
+                let task_id = run.task_id().to_string();
+                let iterations = run.finish(
+                    &mut context,
+                    &mut completed_tasks,
+                    &mut failed_tasks,
+                    &mut errors,
+                    &mut audit_trail,
+                );
+                speculation.record(&task_id, &[], &mut context);
+                loop_iterations.insert(task_id, iterations);
+            }
+
             debug!(
                 execution_id = %execution_id,
                 completed_count = completed_tasks.len(),
                 "📊 Progress: {} completed",
                 completed_tasks.len()
             );
+            self.save_checkpoint(&context, &completed_tasks, &failed_tasks, &skipped_tasks)
+                .await;
         }
 
         let success = errors.is_empty();
@@ -690,13 +1444,23 @@ impl WorkflowEngine {
             "🏁 Workflow resume execution finished"
         );
 
+        if success && waiting_for_input.is_empty() {
+            cache::save_missed_caches(&mut context, &workflow.tasks).await;
+        }
+        let workspace = settle_workspace(&context, success, !waiting_for_input.is_empty());
+
         let tasks = workflow
             .tasks
             .iter()
+            .flat_map(|t| {
+                std::iter::once(t).chain(loop_iterations.get(&t.id).into_iter().flatten())
+            })
             .map(|t| TaskInfo {
                 id: t.id.clone(),
                 name: t.name.clone(),
-                status: if completed_tasks.contains(&t.id) {
+                status: if skipped_tasks.contains(&t.id) {
+                    TaskStatus::Skipped
+                } else if completed_tasks.contains(&t.id) {
                     TaskStatus::Complete
                 } else if waiting_for_input.contains(&t.id) {
                     TaskStatus::WaitingForInput
@@ -707,6 +1471,15 @@ impl WorkflowEngine {
                 },
             })
             .collect();
+        let stages = stage_rollups(
+            &workflow.stages,
+            &workflow.tasks,
+            &completed_tasks,
+            &failed_tasks,
+            &waiting_for_input,
+        );
+
+        let outputs = map_outputs(&workflow.outputs, &context.params, &context.data);
 
         Ok(WorkflowResult {
             success,
@@ -715,8 +1488,118 @@ impl WorkflowEngine {
             audit_trail,
             per_task_logs: context.per_task_logs,
             errors,
+            failure_reasons,
+            exit_codes,
+            output_stats,
+            cache_hits,
+            environments,
+            workspace,
+            stages,
+            outputs,
+            planned: Vec::new(),
+            durations_ms: duration_millis(&context.task_durations),
         })
     }
+
+    /// Run `task_id` of a finished execution again. The context starts from
+    /// the engine's initial data, which should be the context the task first
+    /// ran with. Every other task counts as done, except with `downstream`,
+    /// where the tasks after it run again too once it has succeeded.
+    #[instrument(skip(self, workflow, params), fields(execution_id = %execution_id))]
+    pub async fn rerun_task(
+        &self,
+        workflow: EngineWorkflow,
+        execution_id: String,
+        task_id: &str,
+        downstream: bool,
+        params: HashMap<String, Value>,
+    ) -> Result<WorkflowResult, EngineError> {
+        let task = workflow
+            .tasks
+            .iter()
+            .find(|task| task.id == task_id)
+            .ok_or_else(|| {
+                EngineError::Execution(format!("Task '{}' is not in the workflow", task_id))
+            })?;
+        let mut after = HashSet::new();
+        fn collect(tasks: &[EngineTask], ids: &mut HashSet<String>) {
+            for task in tasks {
+                ids.insert(task.id.clone());
+                collect(&task.next_tasks, ids);
+            }
+        }
+        collect(&task.next_tasks, &mut after);
+        let ids = workflow.tasks.iter().map(|task| task.id.clone());
+        let rerun_done: HashSet<String> = ids.clone().filter(|id| id != task_id).collect();
+        let downstream_done: HashSet<String> = ids.filter(|id| !after.contains(id)).collect();
+
+        info!(
+            execution_id = %execution_id,
+            task_id = %task_id,
+            downstream = downstream,
+            "🔁 Re-running task"
+        );
+        let mut result = self
+            .resume_with_data(
+                workflow.clone(),
+                execution_id.clone(),
+                rerun_done,
+                HashMap::new(),
+                params.clone(),
+                self.initial_data.clone(),
+            )
+            .await?;
+        let waiting = result
+            .tasks
+            .iter()
+            .any(|task| task.status == TaskStatus::WaitingForInput);
+        if !downstream || after.is_empty() || !result.success || waiting {
+            return Ok(result);
+        }
+
+        // The tasks after it run on top of what the re-run changed. Ready
+        // tasks run even when their parent failed, hence the second pass.
+        let mut data = self
+            .initial_data
+            .clone()
+            .unwrap_or_else(|| Value::Object(Default::default()));
+        for entry in &result.audit_trail {
+            diff::apply(&mut data, &entry.changes);
+        }
+        let rest = self
+            .resume_with_data(
+                workflow,
+                execution_id,
+                downstream_done,
+                HashMap::new(),
+                params,
+                Some(data),
+            )
+            .await?;
+        result.success = rest.success;
+        // The second pass only knows the re-run task as done, so keep its
+        // first-pass record.
+        result
+            .tasks
+            .retain(|task| !after.contains(loop_task_id(&task.id)));
+        result.tasks.extend(
+            rest.tasks
+                .into_iter()
+                .filter(|task| after.contains(loop_task_id(&task.id))),
+        );
+        result.audit_trail.extend(rest.audit_trail);
+        result.per_task_logs.extend(rest.per_task_logs);
+        result.errors.extend(rest.errors);
+        result.failure_reasons.extend(rest.failure_reasons);
+        result.exit_codes.extend(rest.exit_codes);
+        result.output_stats.extend(rest.output_stats);
+        result.durations_ms.extend(rest.durations_ms);
+        result.cache_hits.extend(rest.cache_hits);
+        result.environments.extend(rest.environments);
+        result.workspace = rest.workspace;
+        result.stages = rest.stages;
+        Ok(result)
+    }
 }
 
 impl Default for WorkflowEngine {
@@ -724,3 +1607,156 @@ impl Default for WorkflowEngine {
         Self::new()
     }
 }
+
+/// Aborts the tasks of a round when the run is dropped before they finish,
+/// e.g. because it was cancelled, so their processes are killed with it.
+struct AbortOnDrop(Vec<tokio::task::AbortHandle>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.iter().for_each(|handle| handle.abort());
+    }
+}
+
+/// Refuse the whole run up front when any task's handler is not allowed, so a
+/// blocked task never leaves the tasks before it half done.
+fn check_handler_policy(workflow: &EngineWorkflow) -> Result<(), EngineError> {
+    let Some(policy) = handler_policy() else {
+        return Ok(());
+    };
+    policy.check_tasks(&workflow.tasks).map_err(|violation| {
+        warn!(
+            task_id = %violation.task_id,
+            handler = %violation.handler,
+            profile = %violation.profile,
+            "Handler policy blocks the workflow"
+        );
+        EngineError::Policy(violation)
+    })
+}
+
+/// Take the ready tasks whose `when` condition does not hold out of
+/// `ready_tasks`, marking them and every task after them as skipped. A skipped
+/// task counts as completed, so the tasks after it are never picked up. In a
+/// `dry_run`, conditions reading output no task produced are taken to hold.
+fn skip_unmet_conditions(
+    ready_tasks: Vec<EngineTask>,
+    context: &mut ExecutionContext,
+    skipped: &mut HashSet<String>,
+    completed: &mut HashSet<String>,
+    dry_run: bool,
+) -> Vec<EngineTask> {
+    fn skip_after(
+        tasks: &[EngineTask],
+        parent: &str,
+        context: &mut ExecutionContext,
+        skipped: &mut HashSet<String>,
+        completed: &mut HashSet<String>,
+    ) {
+        for task in tasks {
+            context.log_task(
+                task.id.clone(),
+                format!("Skipped: task '{}' was skipped", parent),
+            );
+            skipped.insert(task.id.clone());
+            completed.insert(task.id.clone());
+            skip_after(&task.next_tasks, &task.id, context, skipped, completed);
+        }
+    }
+
+    let mut runnable = Vec::new();
+    for task in ready_tasks {
+        let Some(when) = &task.when else {
+            runnable.push(task);
+            continue;
+        };
+        let reason = match Condition::parse(when) {
+            Ok(condition)
+                if condition.evaluate(&context.params, &context.data)
+                    || (dry_run && reads_missing_output(&condition, &context.data)) =>
+            {
+                runnable.push(task);
+                continue;
+            }
+            Ok(_) => format!("Skipped: condition `{}` does not hold", when),
+            Err(e) => {
+                warn!(
+                    execution_id = %context.execution_id,
+                    task_id = %task.id,
+                    error = %e,
+                    "Task condition is invalid, skipping the task"
+                );
+                format!("Skipped: condition `{}` is invalid: {}", when, e)
+            }
+        };
+        info!(
+            execution_id = %context.execution_id,
+            task_id = %task.id,
+            task_name = %task.name,
+            "⏭️  Skipping task: {}",
+            task.name
+        );
+        context.log_task(task.id.clone(), reason);
+        skipped.insert(task.id.clone());
+        completed.insert(task.id.clone());
+        skip_after(&task.next_tasks, &task.id, context, skipped, completed);
+    }
+    runnable
+}
+
+/// Run a task's dispatch, recording it in `profiler`. Takes the profiler by
+/// value so the future can be spawned.
+async fn dispatched<T>(
+    profiler: Profiler,
+    task_id: String,
+    task: impl std::future::Future<Output = T>,
+) -> T {
+    profiler.time(ProfilePhase::Dispatch, task_id, task).await
+}
+
+/// Run `task`, measuring how long it took.
+async fn timed<T>(task: impl std::future::Future<Output = T>) -> (T, Duration) {
+    let started = Instant::now();
+    let output = task.await;
+    (output, started.elapsed())
+}
+
+fn duration_millis(durations: &HashMap<String, Duration>) -> HashMap<String, u64> {
+    durations
+        .iter()
+        .map(|(task_id, duration)| (task_id.clone(), duration.as_millis() as u64))
+        .collect()
+}
+
+/// Fold the top-level data keys a task changed in its own context copy back into
+/// the shared context, so parallel tasks in a round do not overwrite each other.
+fn merge_task_data(target: &mut Value, base: &Value, task_data: &Value) {
+    let (Value::Object(target), Value::Object(base), Value::Object(task_data)) =
+        (target, base, task_data)
+    else {
+        return;
+    };
+
+    for (key, value) in task_data {
+        if base.get(key) != Some(value) {
+            target.insert(key.clone(), value.clone());
+        }
+    }
+    for key in base.keys() {
+        if !task_data.contains_key(key) {
+            target.remove(key);
+        }
+    }
+}
+
+/// Clean up the run's isolated workspace, if it has one, and report the path
+/// when it stays: after a failure, or while the run waits for input.
+fn settle_workspace(context: &ExecutionContext, success: bool, paused: bool) -> Option<String> {
+    let dir = context.working_dir.as_deref()?;
+    let kept = if paused {
+        Some(dir.to_path_buf())
+    } else {
+        finish_workspace(dir, success)
+    };
+    kept.map(|dir| dir.display().to_string())
+}
@@ -1,3 +1,6 @@
+use crate::handler_policy::PolicyViolation;
+use crate::preflight::PreflightReport;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -8,11 +11,100 @@ pub enum EngineError {
     #[error("Handler error: {0}")]
     Handler(#[from] HandlerError),
 
+    #[error("Parameter error: {0}")]
+    Parameter(#[from] ParameterError),
+
     #[error("Execution error: {0}")]
     Execution(String),
 
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
+
+    #[error("Workflow execution was cancelled")]
+    Cancelled,
+
+    #[error("Preflight checks failed: {0}")]
+    Preflight(PreflightReport),
+
+    #[error("Blocked by handler policy: {0}")]
+    Policy(PolicyViolation),
+
+    #[error("Task {task_id} timed out after {seconds} s")]
+    TaskTimeout { task_id: String, seconds: u64 },
+}
+
+/// What kind of failure an error is, so callers can pick an exit code, banner
+/// or notification without matching on messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "category", rename_all = "snake_case")]
+pub enum ErrorCategory {
+    /// The workflow, its parameters or a task configuration is invalid.
+    Validation,
+    /// A task failed. `exit_code` is set when the task ran a process.
+    HandlerFailure {
+        exit_code: Option<i32>,
+    },
+    Timeout,
+    Cancelled,
+    Io,
+    NotFound,
+    /// Input for a waiting task was missing or invalid.
+    Input,
+    /// An imported workflow needs the user's confirmation before it runs.
+    Untrusted,
+    /// The environment failed one of the workflow's preflight checks.
+    Preflight,
+    /// The handler policy in the settings does not allow one of the tasks.
+    Policy,
+    Storage,
+    Internal,
+}
+
+impl ErrorCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCategory::Validation => "validation",
+            ErrorCategory::HandlerFailure { .. } => "handler_failure",
+            ErrorCategory::Timeout => "timeout",
+            ErrorCategory::Cancelled => "cancelled",
+            ErrorCategory::Io => "io",
+            ErrorCategory::NotFound => "not_found",
+            ErrorCategory::Input => "input",
+            ErrorCategory::Untrusted => "untrusted",
+            ErrorCategory::Preflight => "preflight",
+            ErrorCategory::Policy => "policy",
+            ErrorCategory::Storage => "storage",
+            ErrorCategory::Internal => "internal",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl EngineError {
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            EngineError::Parser(_)
+            | EngineError::Parameter(_)
+            | EngineError::Handler(HandlerError::HandlerNotFound(_))
+            | EngineError::Handler(HandlerError::InvalidConfiguration(_)) => {
+                ErrorCategory::Validation
+            }
+            EngineError::Handler(HandlerError::ExecutionFailed(_)) => {
+                ErrorCategory::HandlerFailure { exit_code: None }
+            }
+            EngineError::Execution(_) => ErrorCategory::Internal,
+            EngineError::Io(_) => ErrorCategory::Io,
+            EngineError::Cancelled => ErrorCategory::Cancelled,
+            EngineError::Preflight(_) => ErrorCategory::Preflight,
+            EngineError::Policy(_) => ErrorCategory::Policy,
+            EngineError::TaskTimeout { .. } => ErrorCategory::Timeout,
+        }
+    }
 }
 
 #[derive(Error, Debug)]
@@ -25,6 +117,18 @@ pub enum ParserError {
 
     #[error("Invalid task structure: {0}")]
     InvalidTask(String),
+
+    #[error("Duplicate key in workflow JSON: {0}")]
+    DuplicateKey(String),
+
+    #[error("Workflow JSON is nested more than {0} levels deep")]
+    NestingTooDeep(usize),
+
+    #[error("Workflow is not valid UTF-8 (first invalid byte at offset {position})")]
+    InvalidUtf8 { position: usize },
+
+    #[error("Workflow exceeds the limit of {max} {what}")]
+    LimitExceeded { what: &'static str, max: usize },
 }
 
 #[derive(Error, Debug)]
@@ -38,3 +142,26 @@ pub enum HandlerError {
     #[error("Invalid task configuration: {0}")]
     InvalidConfiguration(String),
 }
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ParameterError {
+    #[error("Missing required parameter: {0}")]
+    Missing(String),
+
+    #[error("Unknown parameter: {0}")]
+    Unknown(String),
+
+    #[error("Invalid value for parameter '{name}': expected {expected}, got '{value}'")]
+    InvalidValue {
+        name: String,
+        expected: String,
+        value: String,
+    },
+
+    #[error("Invalid path for parameter '{name}': '{path}' {reason}")]
+    InvalidPath {
+        name: String,
+        path: String,
+        reason: String,
+    },
+}
@@ -0,0 +1,146 @@
+use crate::progress::{parse_progress_line, TaskProgress};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// One line of live output from a running workflow.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputEvent {
+    pub execution_id: String,
+    /// Position in the stream, strictly increasing across every task of a run.
+    pub sequence: u64,
+    /// The task that wrote the line, or `None` for messages about the workflow itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_name: Option<String>,
+    pub line: String,
+    /// Progress the line reports, for a progress bar next to the task.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress: Option<TaskProgress>,
+    /// Set on the line a task ends with: whether it succeeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished: Option<bool>,
+}
+
+pub type OutputSink = Arc<dyn Fn(OutputEvent) + Send + Sync>;
+
+/// Numbers one execution's output events and hands them to an `OutputSink` in order.
+///
+/// Clones share the counter, so tasks running in parallel can each hold one and
+/// the sink still receives a single stream in sequence order.
+#[derive(Clone)]
+pub struct OutputEvents {
+    execution_id: String,
+    sink: OutputSink,
+    next_sequence: Arc<Mutex<u64>>,
+}
+
+impl OutputEvents {
+    pub fn new(execution_id: impl Into<String>, sink: OutputSink) -> Self {
+        Self {
+            execution_id: execution_id.into(),
+            sink,
+            next_sequence: Arc::new(Mutex::new(0)),
+        }
+    }
+
+    pub fn execution_id(&self) -> &str {
+        &self.execution_id
+    }
+
+    pub fn sink(&self) -> OutputSink {
+        Arc::clone(&self.sink)
+    }
+
+    pub fn task_line(&self, task_id: &str, task_name: &str, line: &str) {
+        self.emit(
+            Some(task_id.to_string()),
+            Some(task_name.to_string()),
+            line,
+            None,
+        );
+    }
+
+    /// A line a command printed, with the progress it shows when it is in a
+    /// known progress format.
+    pub fn command_line(&self, task_id: &str, task_name: &str, line: &str) {
+        self.emit(
+            Some(task_id.to_string()),
+            Some(task_name.to_string()),
+            line,
+            parse_progress_line(line),
+        );
+    }
+
+    /// Progress a handler reports directly rather than through its output.
+    pub fn task_progress(&self, task_id: &str, task_name: &str, progress: TaskProgress) {
+        self.emit(
+            Some(task_id.to_string()),
+            Some(task_name.to_string()),
+            &progress.to_string(),
+            Some(progress),
+        );
+    }
+
+    /// The last line of a task, saying how it ended.
+    pub fn task_finished(&self, task_id: &str, task_name: &str, success: bool) {
+        let line = if success { "Finished" } else { "Failed" };
+        self.emit_event(
+            Some(task_id.to_string()),
+            Some(task_name.to_string()),
+            line,
+            None,
+            Some(success),
+        );
+    }
+
+    pub fn message(&self, line: &str) {
+        self.emit(None, None, line, None);
+    }
+
+    fn emit(
+        &self,
+        task_id: Option<String>,
+        task_name: Option<String>,
+        line: &str,
+        progress: Option<TaskProgress>,
+    ) {
+        self.emit_event(task_id, task_name, line, progress, None);
+    }
+
+    fn emit_event(
+        &self,
+        task_id: Option<String>,
+        task_name: Option<String>,
+        line: &str,
+        progress: Option<TaskProgress>,
+        finished: Option<bool>,
+    ) {
+        // The sink runs under the lock so events can never reach it out of order.
+        let mut next_sequence = self
+            .next_sequence
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let sequence = *next_sequence;
+        *next_sequence += 1;
+        (self.sink)(OutputEvent {
+            execution_id: self.execution_id.clone(),
+            sequence,
+            task_id,
+            task_name,
+            line: line.to_string(),
+            progress,
+            finished,
+        });
+    }
+}
+
+impl fmt::Debug for OutputEvents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OutputEvents")
+            .field("execution_id", &self.execution_id)
+            .field("next_sequence", &self.next_sequence)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,139 @@
+//! Which task handlers may run, for locked-down machines where e.g. shell
+//! commands must never be started.
+//!
+//! A task is named by its handler, the `function.name` in the definition.
+//! Custom handlers also answer to `custom`, so listing `custom` covers all
+//! of them at once.
+
+use crate::handlers::get_function_type;
+use crate::types::{EngineTask, TaskFunction};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::sync::{OnceLock, RwLock};
+
+/// Handlers built into the engine; any other `function.name` is a custom one.
+const BUILT_IN_HANDLERS: [&str; 7] = [
+    "cli_command",
+    "cursor_agent",
+    "user_input",
+    "manual",
+    "db_query",
+    "publish_message",
+    "cache",
+];
+
+/// Handlers the active settings profile allows or denies.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandlerPolicy {
+    /// Settings profile the lists come from, named in policy errors.
+    pub profile: String,
+    /// Only these handlers may run. `None` allows any handler not denied.
+    pub allowed: Option<BTreeSet<String>>,
+    /// These handlers never run, even when also allowed.
+    pub denied: BTreeSet<String>,
+}
+
+/// The list that stopped a task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyList {
+    /// The handler is not in the profile's allow list.
+    Allowed,
+    /// The handler is in the profile's deny list.
+    Denied,
+}
+
+impl PolicyList {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PolicyList::Allowed => "allowed_handlers",
+            PolicyList::Denied => "denied_handlers",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyViolation {
+    pub task_id: String,
+    pub handler: String,
+    pub profile: String,
+    pub list: PolicyList,
+}
+
+impl std::fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.list {
+            PolicyList::Allowed => write!(
+                f,
+                "Task '{}' uses handler '{}', which is not in {} of handler profile '{}'",
+                self.task_id,
+                self.handler,
+                self.list.as_str(),
+                self.profile
+            ),
+            PolicyList::Denied => write!(
+                f,
+                "Task '{}' uses handler '{}', which is in {} of handler profile '{}'",
+                self.task_id,
+                self.handler,
+                self.list.as_str(),
+                self.profile
+            ),
+        }
+    }
+}
+
+impl HandlerPolicy {
+    /// Why `handler` may not run, if it may not.
+    pub fn blocking_list(&self, handler: &str) -> Option<PolicyList> {
+        let custom = !BUILT_IN_HANDLERS.contains(&handler);
+        let names = [Some(handler), custom.then_some("custom")];
+        let mut names = names.into_iter().flatten();
+        if names.clone().any(|name| self.denied.contains(name)) {
+            return Some(PolicyList::Denied);
+        }
+        match &self.allowed {
+            Some(allowed) if !names.any(|name| allowed.contains(name)) => Some(PolicyList::Allowed),
+            _ => None,
+        }
+    }
+
+    /// The first task in `tasks` or their `next_tasks` that this policy refuses.
+    pub fn check_tasks(&self, tasks: &[EngineTask]) -> Result<(), PolicyViolation> {
+        for task in tasks {
+            let handler = match &task.function {
+                TaskFunction::Custom { name, .. } => name.as_str(),
+                _ => get_function_type(task),
+            };
+            if let Some(list) = self.blocking_list(handler) {
+                return Err(PolicyViolation {
+                    task_id: task.id.clone(),
+                    handler: handler.to_string(),
+                    profile: self.profile.clone(),
+                    list,
+                });
+            }
+            self.check_tasks(&task.next_tasks)?;
+        }
+        Ok(())
+    }
+}
+
+static POLICY: OnceLock<RwLock<Option<HandlerPolicy>>> = OnceLock::new();
+
+fn policy_lock() -> &'static RwLock<Option<HandlerPolicy>> {
+    POLICY.get_or_init(|| RwLock::new(None))
+}
+
+/// Replace the policy every engine checks before running a workflow, for the
+/// rest of the process. `None` lets every handler run.
+pub fn set_handler_policy(policy: Option<HandlerPolicy>) {
+    *policy_lock().write().unwrap_or_else(|e| e.into_inner()) = policy;
+}
+
+pub fn handler_policy() -> Option<HandlerPolicy> {
+    policy_lock()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
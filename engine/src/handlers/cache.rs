@@ -0,0 +1,145 @@
+use crate::errors::*;
+use crate::messaging::render_template;
+use crate::types::*;
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+use tracing::{debug, instrument, warn};
+
+pub struct CacheHandler;
+
+#[async_trait]
+impl super::TaskHandler for CacheHandler {
+    #[instrument(skip(self, context), fields(task_id = %task.id, task_name = %task.name))]
+    async fn execute(
+        &self,
+        context: &mut ExecutionContext,
+        task: &EngineTask,
+    ) -> Result<TaskResult, HandlerError> {
+        let TaskFunction::Cache { key, paths } = &task.function else {
+            return Err(HandlerError::InvalidConfiguration(
+                "Expected Cache function".to_string(),
+            ));
+        };
+
+        let key = match render_template(&Value::String(key.clone()), &context.params, &context.data)
+            .map_err(|e| HandlerError::InvalidConfiguration(format!("Cache key: {}", e)))?
+        {
+            Value::String(key) => key,
+            other => other.to_string(),
+        };
+
+        let restored = match &context.directory_cache {
+            Some(cache) => cache.restore(&key, &root_dir(context)?).await,
+            None => Ok(None),
+        };
+        let hit = match restored {
+            Ok(Some(bytes)) => {
+                context.log_task(
+                    task.id.clone(),
+                    format!("Restored cache '{}' ({} bytes)", key, bytes),
+                );
+                true
+            }
+            Ok(None) if context.directory_cache.is_none() => {
+                context.log_task(
+                    task.id.clone(),
+                    "No directory cache is configured, nothing restored".to_string(),
+                );
+                false
+            }
+            Ok(None) => {
+                context.log_task(
+                    task.id.clone(),
+                    format!(
+                        "Nothing cached under '{}' yet; it is saved if the run succeeds",
+                        key
+                    ),
+                );
+                false
+            }
+            // A broken archive only costs the time to rebuild what it held.
+            Err(e) => {
+                warn!(
+                    execution_id = %context.execution_id,
+                    task_id = %task.id,
+                    cache_key = %key,
+                    error = %e,
+                    "Failed to restore directory cache"
+                );
+                context.log_task(
+                    task.id.clone(),
+                    format!("Could not restore cache '{}': {}", key, e),
+                );
+                false
+            }
+        };
+        debug!(
+            execution_id = %context.execution_id,
+            task_id = %task.id,
+            cache_key = %key,
+            hit = hit,
+            "Directory cache lookup finished"
+        );
+
+        Ok(TaskResult {
+            success: true,
+            output: json!({ "key": key, "paths": paths, "hit": hit }),
+            error: None,
+            failure_reason: None,
+            output_stats: None,
+            exit_code: None,
+            environment: None,
+        })
+    }
+}
+
+/// Save the directories of every `cache` task in `tasks` that missed during
+/// this run. Called once a run has succeeded; failures are logged on the task
+/// and otherwise ignored.
+pub(crate) async fn save_missed_caches(context: &mut ExecutionContext, tasks: &[EngineTask]) {
+    let Some(cache) = context.directory_cache.clone() else {
+        return;
+    };
+    for task in tasks {
+        let TaskFunction::Cache { paths, .. } = &task.function else {
+            continue;
+        };
+        let output = &context.data[&task.id];
+        let (Some(false), Some(key)) = (output["hit"].as_bool(), output["key"].as_str()) else {
+            continue;
+        };
+        let key = key.to_string();
+
+        let saved = match root_dir(context) {
+            Ok(root) => cache
+                .save(&key, &root, paths)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        let message = match saved {
+            Ok(bytes) => format!("Saved cache '{}' ({} bytes)", key, bytes),
+            Err(e) => {
+                warn!(
+                    execution_id = %context.execution_id,
+                    task_id = %task.id,
+                    cache_key = %key,
+                    error = %e,
+                    "Failed to save directory cache"
+                );
+                format!("Could not save cache '{}': {}", key, e)
+            }
+        };
+        context.log_task(task.id.clone(), message);
+    }
+}
+
+fn root_dir(context: &ExecutionContext) -> Result<PathBuf, HandlerError> {
+    match &context.working_dir {
+        Some(dir) => Ok(dir.clone()),
+        None => std::env::current_dir().map_err(|e| {
+            HandlerError::ExecutionFailed(format!("Cannot find the working directory: {}", e))
+        }),
+    }
+}
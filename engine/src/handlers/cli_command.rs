@@ -1,8 +1,14 @@
+use super::environment::environment_snapshot;
+use super::output::{CappedOutput, LineForwarder, DEFAULT_MAX_OUTPUT_BYTES};
+use super::process::ProcessTree;
 use crate::errors::*;
 use crate::types::*;
 use async_trait::async_trait;
 use serde_json::Value;
-use tokio::process::Command;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::task::JoinHandle;
 use tracing::{debug, error, instrument, trace, warn};
 
 pub struct CliCommandHandler;
@@ -21,7 +27,12 @@ impl super::TaskHandler for CliCommandHandler {
             "Starting CLI command execution"
         );
 
-        let TaskFunction::CliCommand { command, args } = &task.function else {
+        let TaskFunction::CliCommand {
+            command,
+            args,
+            process,
+        } = &task.function
+        else {
             error!(
                 execution_id = %context.execution_id,
                 task_id = %task.id,
@@ -53,39 +64,111 @@ impl super::TaskHandler for CliCommandHandler {
             "Spawning command process"
         );
 
-        let output = Command::new(command)
-            .args(args)
-            .output()
+        let mut cmd = super::shell::build_command(command, args);
+        if let Some(dir) = &context.working_dir {
+            cmd.current_dir(dir);
+        }
+        cmd.stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let environment = environment_snapshot(&cmd);
+
+        let (mut child, mut tree) = ProcessTree::spawn(&mut cmd, process).map_err(|e| {
+            error!(
+                execution_id = %context.execution_id,
+                task_id = %task.id,
+                command = %command,
+                error = %e,
+                "Failed to spawn command process"
+            );
+            HandlerError::ExecutionFailed(format!("Failed to execute command: {}", e))
+        })?;
+
+        let max_output_bytes = process.max_output_bytes.unwrap_or(DEFAULT_MAX_OUTPUT_BYTES);
+        let forwarder = || {
+            context
+                .output
+                .clone()
+                .map(|output| LineForwarder::new(output, &task.id, &task.name))
+        };
+        let stdout_reader = read_pipe(child.stdout.take(), max_output_bytes, forwarder());
+        let stderr_reader = read_pipe(child.stderr.take(), max_output_bytes, forwarder());
+
+        let timed_out = match process.timeout_ms {
+            Some(ms) => tokio::time::timeout(Duration::from_millis(ms), child.wait())
+                .await
+                .is_err(),
+            None => false,
+        };
+
+        let status = if timed_out {
+            warn!(
+                execution_id = %context.execution_id,
+                task_id = %task.id,
+                timeout_ms = ?process.timeout_ms,
+                "Command timed out, terminating process tree"
+            );
+            tree.terminate(&mut child).await
+        } else {
+            child.wait().await
+        }
+        .map_err(|e| HandlerError::ExecutionFailed(format!("Failed to wait for command: {}", e)))?;
+        let failure_reason = if timed_out {
+            Some(FailureReason::Timeout)
+        } else if !status.success() {
+            tree.breach(&status)
+        } else {
+            None
+        };
+        tree.finish();
+
+        let stdout = stdout_reader
+            .await
+            .unwrap_or_else(|_| CappedOutput::new(max_output_bytes));
+        let stderr = stderr_reader
             .await
-            .map_err(|e| {
-                error!(
-                    execution_id = %context.execution_id,
-                    task_id = %task.id,
-                    command = %command,
-                    error = %e,
-                    "Failed to spawn command process"
-                );
-                HandlerError::ExecutionFailed(format!("Failed to execute command: {}", e))
-            })?;
-
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let stderr = String::from_utf8_lossy(&output.stderr);
+            .unwrap_or_else(|_| CappedOutput::new(max_output_bytes));
+        let output_stats = OutputStats {
+            total_bytes: stdout.total_bytes() + stderr.total_bytes(),
+            truncated: stdout.is_truncated() || stderr.is_truncated(),
+        };
+        if output_stats.truncated {
+            warn!(
+                execution_id = %context.execution_id,
+                task_id = %task.id,
+                total_bytes = output_stats.total_bytes,
+                max_output_bytes = max_output_bytes,
+                "Command output exceeded its quota, keeping head and tail"
+            );
+        }
+        let stdout = stdout.into_text();
+        let stderr = stderr.into_text();
+        let exit_code = super::shell::exit_code(&status);
 
         trace!(
             execution_id = %context.execution_id,
             task_id = %task.id,
             stdout_length = stdout.len(),
             stderr_length = stderr.len(),
-            exit_code = ?output.status.code(),
+            exit_code = ?exit_code,
             "Command execution completed"
         );
 
-        context.log_task(task.id.clone(), format!("Output: {}", stdout));
+        context.record_task_log(task.id.clone(), format!("Output: {}", stdout));
         if !stderr.is_empty() {
-            context.log_task(task.id.clone(), format!("Error: {}", stderr));
+            context.record_task_log(task.id.clone(), format!("Error: {}", stderr));
         }
 
-        let success = output.status.success();
+        let success = status.success() && !timed_out;
+
+        if let Some(reason) = failure_reason {
+            warn!(
+                execution_id = %context.execution_id,
+                task_id = %task.id,
+                reason = ?reason,
+                "Command stopped by a process limit"
+            );
+        }
 
         if success {
             debug!(
@@ -98,24 +181,43 @@ impl super::TaskHandler for CliCommandHandler {
             warn!(
                 execution_id = %context.execution_id,
                 task_id = %task.id,
-                exit_code = ?output.status.code(),
+                exit_code = ?exit_code,
                 stderr = %stderr,
                 "Command failed"
             );
             context.log_task(
                 task.id.clone(),
-                format!("Command failed with exit code: {:?}", output.status.code()),
+                format!("Command failed with exit code: {:?}", exit_code),
             );
         }
 
         let result = TaskResult {
             success,
-            output: Value::String(stdout.to_string()),
+            output: Value::String(stdout),
             error: if success {
                 None
             } else {
-                Some(stderr.to_string())
+                match failure_reason {
+                    Some(FailureReason::Timeout) => Some(format!(
+                        "Command timed out after {} ms",
+                        process.timeout_ms.unwrap_or_default()
+                    )),
+                    Some(FailureReason::MemoryLimitExceeded) => {
+                        Some("Command exceeded its memory limit".to_string())
+                    }
+                    Some(FailureReason::CpuLimitExceeded) => {
+                        Some("Command exceeded its CPU time limit".to_string())
+                    }
+                    None if stderr.trim().is_empty() => {
+                        Some(format!("Command failed with exit code: {:?}", exit_code))
+                    }
+                    None => Some(stderr),
+                }
             },
+            failure_reason,
+            output_stats: Some(output_stats),
+            exit_code,
+            environment: Some(environment),
         };
 
         debug!(
@@ -128,3 +230,34 @@ impl super::TaskHandler for CliCommandHandler {
         Ok(result)
     }
 }
+
+/// Drains `pipe` to the end so the child never blocks on a full pipe, keeping at most
+/// `max_bytes` of what it wrote and streaming every line through `forwarder`.
+fn read_pipe<R>(
+    pipe: Option<R>,
+    max_bytes: u64,
+    mut forwarder: Option<LineForwarder>,
+) -> JoinHandle<CappedOutput>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut output = CappedOutput::new(max_bytes);
+        if let Some(mut pipe) = pipe {
+            let mut chunk = [0u8; 8192];
+            while let Ok(n) = pipe.read(&mut chunk).await {
+                if n == 0 {
+                    break;
+                }
+                output.push(&chunk[..n]);
+                if let Some(forwarder) = forwarder.as_mut() {
+                    forwarder.push(&chunk[..n]);
+                }
+            }
+        }
+        if let Some(forwarder) = forwarder {
+            forwarder.finish();
+        }
+        output
+    })
+}
@@ -75,6 +75,10 @@ impl super::TaskHandler for CursorAgentHandler {
             success: true,
             output: Value::String(simulated_response),
             error: None,
+            failure_reason: None,
+            output_stats: None,
+            exit_code: None,
+            environment: None,
         };
 
         debug!(
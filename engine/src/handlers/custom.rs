@@ -74,6 +74,10 @@ impl super::TaskHandler for CustomHandler {
             success: true,
             output: Value::String(output),
             error: None,
+            failure_reason: None,
+            output_stats: None,
+            exit_code: None,
+            environment: None,
         };
 
         debug!(
@@ -0,0 +1,198 @@
+use crate::db::check_statement;
+use crate::errors::*;
+use crate::types::*;
+use async_trait::async_trait;
+use futures_util::TryStreamExt;
+use serde_json::{Map, Value};
+use sqlx::any::{AnyArguments, AnyRow};
+use sqlx::query::Query;
+use sqlx::{Any, AnyConnection, Column, Connection, Either, Executor, Row, TypeInfo, ValueRef};
+use std::sync::Once;
+use tracing::{debug, instrument};
+
+pub struct DbQueryHandler;
+
+#[async_trait]
+impl super::TaskHandler for DbQueryHandler {
+    #[instrument(skip(self, context), fields(task_id = %task.id, task_name = %task.name))]
+    async fn execute(
+        &self,
+        context: &mut ExecutionContext,
+        task: &EngineTask,
+    ) -> Result<TaskResult, HandlerError> {
+        let TaskFunction::DbQuery {
+            connection,
+            query,
+            params,
+            max_rows,
+        } = &task.function
+        else {
+            return Err(HandlerError::InvalidConfiguration(
+                "Expected DbQuery function".to_string(),
+            ));
+        };
+
+        let profile = context
+            .db_connections
+            .iter()
+            .find(|profile| &profile.name == connection)
+            .cloned()
+            .ok_or_else(|| {
+                HandlerError::InvalidConfiguration(format!(
+                    "Unknown database connection '{}'",
+                    connection
+                ))
+            })?;
+        check_statement(query, &profile.allowed_statements)
+            .map_err(HandlerError::InvalidConfiguration)?;
+        let params = params
+            .iter()
+            .map(|param| resolve_param(param, &context.params))
+            .collect::<Result<Vec<_>, _>>()?;
+        let cap = profile.row_cap(*max_rows);
+
+        debug!(
+            execution_id = %context.execution_id,
+            task_id = %task.id,
+            connection = %connection,
+            param_count = params.len(),
+            max_rows = cap,
+            "Running database query"
+        );
+        context.log_task(
+            task.id.clone(),
+            format!("Querying '{}' (up to {} rows)", connection, cap),
+        );
+
+        install_drivers();
+        let url = profile
+            .connect_url()
+            .map_err(HandlerError::InvalidConfiguration)?;
+        let mut conn = AnyConnection::connect(&url).await.map_err(|e| {
+            HandlerError::ExecutionFailed(format!("Could not connect to '{}': {}", connection, e))
+        })?;
+
+        let mut rows = Vec::new();
+        let mut truncated = false;
+        let mut rows_affected = 0;
+        {
+            let mut results = conn.fetch_many(bind_params(sqlx::query(query), &params));
+            while let Some(item) = results
+                .try_next()
+                .await
+                .map_err(|e| HandlerError::ExecutionFailed(format!("Query failed: {}", e)))?
+            {
+                match item {
+                    Either::Left(done) => rows_affected += done.rows_affected(),
+                    Either::Right(_) if rows.len() == cap => {
+                        truncated = true;
+                        break;
+                    }
+                    Either::Right(row) => rows.push(row_to_json(&row)?),
+                }
+            }
+        }
+        let _ = conn.close().await;
+
+        context.log_task(
+            task.id.clone(),
+            format!(
+                "Returned {} row(s){}",
+                rows.len(),
+                if truncated { ", more were cut off" } else { "" }
+            ),
+        );
+
+        Ok(TaskResult {
+            success: true,
+            output: serde_json::json!({
+                "row_count": rows.len(),
+                "truncated": truncated,
+                "rows_affected": rows_affected,
+                "rows": rows,
+            }),
+            error: None,
+            failure_reason: None,
+            output_stats: None,
+            exit_code: None,
+            environment: None,
+        })
+    }
+}
+
+fn install_drivers() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(sqlx::any::install_default_drivers);
+}
+
+/// A literal parameter, or `{"param": "name"}` for a workflow parameter.
+fn resolve_param(
+    param: &Value,
+    workflow_params: &Map<String, Value>,
+) -> Result<Value, HandlerError> {
+    let Some(name) = param
+        .as_object()
+        .filter(|object| object.len() == 1)
+        .and_then(|object| object.get("param"))
+        .and_then(Value::as_str)
+    else {
+        return Ok(param.clone());
+    };
+    workflow_params.get(name).cloned().ok_or_else(|| {
+        HandlerError::InvalidConfiguration(format!("Unknown workflow parameter '{}'", name))
+    })
+}
+
+fn bind_params<'q>(
+    mut query: Query<'q, Any, AnyArguments<'q>>,
+    params: &[Value],
+) -> Query<'q, Any, AnyArguments<'q>> {
+    for param in params {
+        query = match param {
+            Value::Null => query.bind(None::<String>),
+            Value::Bool(value) => query.bind(*value),
+            Value::Number(number) => match number.as_i64() {
+                Some(value) => query.bind(value),
+                None => query.bind(number.as_f64().unwrap_or_default()),
+            },
+            Value::String(value) => query.bind(value.clone()),
+            other => query.bind(other.to_string()),
+        };
+    }
+    query
+}
+
+fn row_to_json(row: &AnyRow) -> Result<Value, HandlerError> {
+    let decode_error =
+        |e: sqlx::Error| HandlerError::ExecutionFailed(format!("Could not read row: {}", e));
+    let mut object = Map::new();
+    for (index, column) in row.columns().iter().enumerate() {
+        let raw = row.try_get_raw(index).map_err(decode_error)?;
+        let value = if raw.is_null() {
+            Value::Null
+        } else {
+            // The Any driver maps every column to one of these.
+            match raw.type_info().name() {
+                "BOOLEAN" => Value::from(row.try_get::<bool, _>(index).map_err(decode_error)?),
+                "SMALLINT" => Value::from(row.try_get::<i16, _>(index).map_err(decode_error)?),
+                "INTEGER" => Value::from(row.try_get::<i32, _>(index).map_err(decode_error)?),
+                "BIGINT" => Value::from(row.try_get::<i64, _>(index).map_err(decode_error)?),
+                "REAL" => Value::from(row.try_get::<f32, _>(index).map_err(decode_error)?),
+                "DOUBLE" => Value::from(row.try_get::<f64, _>(index).map_err(decode_error)?),
+                "BLOB" => {
+                    let bytes = row.try_get::<Vec<u8>, _>(index).map_err(decode_error)?;
+                    Value::from(
+                        bytes
+                            .iter()
+                            .map(|b| format!("{:02x}", b))
+                            .collect::<String>(),
+                    )
+                }
+                "NULL" => Value::Null,
+                _ => Value::from(row.try_get::<String, _>(index).map_err(decode_error)?),
+            }
+        };
+        object.insert(column.name().to_string(), value);
+    }
+    Ok(Value::Object(object))
+}
@@ -0,0 +1,210 @@
+use crate::types::{FailureReason, ProcessOptions};
+use std::process::ExitStatus;
+use tokio::process::Command;
+
+/// CPU and memory limits for a spawned command on Unix.
+///
+/// Linux uses a cgroup v2 child group when the current cgroup allows it and falls back to
+/// rlimits otherwise; other Unix systems only get niceness and rlimits.
+pub struct ResourceLimits {
+    #[cfg(unix)]
+    cpu_seconds: Option<u64>,
+    #[cfg(target_os = "linux")]
+    cgroup: Option<cgroup::Cgroup>,
+}
+
+impl ResourceLimits {
+    #[cfg(unix)]
+    pub fn apply(command: &mut Command, options: &ProcessOptions) -> Self {
+        let nice = options.nice;
+        let cpu_seconds = options.max_cpu_seconds;
+        let memory_bytes = options
+            .max_memory_mb
+            .map(|mb| mb.saturating_mul(1024 * 1024));
+
+        #[cfg(target_os = "linux")]
+        let cgroup = if memory_bytes.is_some() || options.max_cpu_percent.is_some() {
+            cgroup::Cgroup::create(memory_bytes, options.max_cpu_percent)
+                .map_err(|e| {
+                    tracing::warn!(error = %e, "cgroup v2 limits unavailable, using rlimits");
+                })
+                .ok()
+        } else {
+            None
+        };
+        #[cfg(target_os = "linux")]
+        let join_path = cgroup.as_ref().map(|cgroup| cgroup.procs_path());
+        #[cfg(target_os = "linux")]
+        let rlimit_memory = if cgroup.is_some() { None } else { memory_bytes };
+        #[cfg(target_os = "linux")]
+        let cpu_percent_enforced = cgroup.is_some();
+
+        #[cfg(not(target_os = "linux"))]
+        let rlimit_memory = memory_bytes;
+        #[cfg(not(target_os = "linux"))]
+        let cpu_percent_enforced = false;
+
+        if options.max_cpu_percent.is_some() && !cpu_percent_enforced {
+            tracing::warn!("max_cpu_percent needs cgroup v2 on this platform and was not applied");
+        }
+
+        #[cfg(target_os = "linux")]
+        let joins_cgroup = join_path.is_some();
+        #[cfg(not(target_os = "linux"))]
+        let joins_cgroup = false;
+
+        let needs_hook =
+            joins_cgroup || nice.is_some() || cpu_seconds.is_some() || rlimit_memory.is_some();
+
+        if needs_hook {
+            // SAFETY: the hook runs between fork and exec, so it only performs
+            // async-signal-safe syscalls and does not allocate.
+            unsafe {
+                command.pre_exec(move || {
+                    #[cfg(target_os = "linux")]
+                    if let Some(path) = &join_path {
+                        cgroup::join(path);
+                    }
+                    if let Some(nice) = nice {
+                        libc::setpriority(libc::PRIO_PROCESS, 0, nice);
+                    }
+                    if let Some(seconds) = cpu_seconds {
+                        let limit = libc::rlimit {
+                            rlim_cur: seconds as libc::rlim_t,
+                            rlim_max: seconds.saturating_add(1) as libc::rlim_t,
+                        };
+                        if libc::setrlimit(libc::RLIMIT_CPU, &limit) != 0 {
+                            return Err(std::io::Error::last_os_error());
+                        }
+                    }
+                    if let Some(bytes) = rlimit_memory {
+                        let limit = libc::rlimit {
+                            rlim_cur: bytes as libc::rlim_t,
+                            rlim_max: bytes as libc::rlim_t,
+                        };
+                        if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                            return Err(std::io::Error::last_os_error());
+                        }
+                    }
+                    Ok(())
+                });
+            }
+        }
+
+        Self {
+            cpu_seconds,
+            #[cfg(target_os = "linux")]
+            cgroup,
+        }
+    }
+
+    #[cfg(not(unix))]
+    pub fn apply(_command: &mut Command, _options: &ProcessOptions) -> Self {
+        Self {}
+    }
+
+    /// Work out whether the command was stopped for exceeding one of its limits.
+    pub fn breach(&self, status: &ExitStatus) -> Option<FailureReason> {
+        #[cfg(target_os = "linux")]
+        if self
+            .cgroup
+            .as_ref()
+            .is_some_and(|cgroup| cgroup.oom_killed())
+        {
+            return Some(FailureReason::MemoryLimitExceeded);
+        }
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            if self.cpu_seconds.is_some() && status.signal() == Some(libc::SIGXCPU) {
+                return Some(FailureReason::CpuLimitExceeded);
+            }
+        }
+
+        let _ = status;
+        None
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod cgroup {
+    use std::ffi::{CStr, CString};
+    use std::fs;
+    use std::io;
+    use std::path::{Path, PathBuf};
+
+    const CPU_PERIOD_US: u64 = 100_000;
+
+    pub struct Cgroup {
+        path: PathBuf,
+    }
+
+    impl Cgroup {
+        pub fn create(memory_bytes: Option<u64>, cpu_percent: Option<u32>) -> io::Result<Self> {
+            let own = fs::read_to_string("/proc/self/cgroup")?;
+            let relative = own
+                .lines()
+                .find_map(|line| line.strip_prefix("0::"))
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::Unsupported, "cgroup v2 not mounted")
+                })?;
+
+            let path = Path::new("/sys/fs/cgroup")
+                .join(relative.trim_start_matches('/'))
+                .join(format!("see-task-{}", uuid::Uuid::new_v4()));
+            fs::create_dir(&path)?;
+            let cgroup = Self { path };
+
+            if let Some(bytes) = memory_bytes {
+                cgroup.write("memory.max", &bytes.to_string())?;
+                let _ = cgroup.write("memory.swap.max", "0");
+            }
+            if let Some(percent) = cpu_percent {
+                let quota = u64::from(percent.max(1)) * CPU_PERIOD_US / 100;
+                cgroup.write("cpu.max", &format!("{} {}", quota, CPU_PERIOD_US))?;
+            }
+
+            Ok(cgroup)
+        }
+
+        pub fn procs_path(&self) -> CString {
+            use std::os::unix::ffi::OsStrExt;
+            CString::new(self.path.join("cgroup.procs").as_os_str().as_bytes()).unwrap_or_default()
+        }
+
+        pub fn oom_killed(&self) -> bool {
+            fs::read_to_string(self.path.join("memory.events"))
+                .ok()
+                .and_then(|events| {
+                    events
+                        .lines()
+                        .find_map(|line| line.strip_prefix("oom_kill "))
+                        .and_then(|count| count.trim().parse::<u64>().ok())
+                })
+                .is_some_and(|count| count > 0)
+        }
+
+        fn write(&self, file: &str, value: &str) -> io::Result<()> {
+            fs::write(self.path.join(file), value)
+        }
+    }
+
+    impl Drop for Cgroup {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir(&self.path);
+        }
+    }
+
+    /// Move the calling process into the cgroup. Only used from a `pre_exec` hook.
+    pub fn join(procs_path: &CStr) {
+        // SAFETY: plain open/write/close on a valid NUL-terminated path.
+        unsafe {
+            let fd = libc::open(procs_path.as_ptr(), libc::O_WRONLY | libc::O_CLOEXEC);
+            if fd >= 0 {
+                libc::write(fd, b"0".as_ptr().cast(), 1);
+                libc::close(fd);
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::errors::*;
+use crate::types::*;
+use async_trait::async_trait;
+use tracing::{debug, error, instrument};
+
+pub struct ManualHandler;
+
+#[async_trait]
+impl super::TaskHandler for ManualHandler {
+    #[instrument(skip(self, context), fields(task_id = %task.id, task_name = %task.name))]
+    async fn execute(
+        &self,
+        context: &mut ExecutionContext,
+        task: &EngineTask,
+    ) -> Result<TaskResult, HandlerError> {
+        let TaskFunction::Manual { instructions } = &task.function else {
+            error!(
+                execution_id = %context.execution_id,
+                task_id = %task.id,
+                "Invalid function type - expected Manual"
+            );
+            return Err(HandlerError::InvalidConfiguration(
+                "Expected Manual function".to_string(),
+            ));
+        };
+
+        debug!(
+            execution_id = %context.execution_id,
+            task_id = %task.id,
+            "Manual task waiting for confirmation"
+        );
+
+        context.update_task_status(task.id.clone(), TaskStatus::WaitingForInput);
+        context.log_task(
+            task.id.clone(),
+            format!("Waiting for someone to confirm: {}", instructions),
+        );
+
+        Ok(TaskResult {
+            success: true,
+            output: serde_json::json!({
+                "waiting_for_input": true,
+                "manual": true,
+                "instructions": instructions,
+            }),
+            error: None,
+            failure_reason: None,
+            output_stats: None,
+            exit_code: None,
+            environment: None,
+        })
+    }
+}
+
+/// The audit entry recording who settled a manual task, how and when.
+pub(crate) fn confirmation_audit_entry(
+    task: &EngineTask,
+    confirmation: &ManualConfirmation,
+) -> AuditEntry {
+    let outcome = match confirmation.outcome {
+        ManualOutcome::Done => "Confirmed done",
+        ManualOutcome::Skipped => "Skipped",
+    };
+    let mut message = format!(
+        "{} manual task: {} by {}",
+        outcome, task.name, confirmation.confirmed_by
+    );
+    if let Some(notes) = confirmation.notes.as_deref().filter(|n| !n.is_empty()) {
+        message.push_str(&format!(" - {}", notes));
+    }
+    AuditEntry {
+        task_id: task.id.clone(),
+        status: AuditStatus::Success,
+        timestamp: confirmation.confirmed_at.to_rfc3339(),
+        changes_count: 0,
+        message,
+        changes: Vec::new(),
+        changes_truncated: false,
+    }
+}
@@ -43,6 +43,21 @@ impl HandlerRegistry {
             Box::new(user_input::UserInputHandler),
         );
 
+        trace!("Registering database query handler");
+        handlers.insert("db_query".to_string(), Box::new(db_query::DbQueryHandler));
+
+        trace!("Registering message publish handler");
+        handlers.insert(
+            "publish_message".to_string(),
+            Box::new(publish_message::PublishMessageHandler),
+        );
+
+        trace!("Registering directory cache handler");
+        handlers.insert("cache".to_string(), Box::new(cache::CacheHandler));
+
+        trace!("Registering manual handler");
+        handlers.insert("manual".to_string(), Box::new(manual::ManualHandler));
+
         debug!(
             registered_handlers = handlers.len(),
             handler_types = ?handlers.keys().collect::<Vec<_>>(),
@@ -52,6 +67,16 @@ impl HandlerRegistry {
         Self { handlers }
     }
 
+    /// Handlers for a sandbox run: every handler in [`sandbox::SIDE_EFFECT_HANDLERS`]
+    /// is replaced by a [`sandbox::RecordingHandler`], the rest run normally.
+    pub fn sandboxed() -> Self {
+        let mut registry = Self::new();
+        for name in sandbox::SIDE_EFFECT_HANDLERS {
+            registry.register_handler(name.to_string(), Box::new(sandbox::RecordingHandler));
+        }
+        registry
+    }
+
     pub fn get_handler(&self, function_type: &str) -> Option<&dyn TaskHandler> {
         trace!(function_type = %function_type, "Looking up handler");
         let result = self.handlers.get(function_type).map(|h| h.as_ref());
@@ -68,6 +93,10 @@ impl HandlerRegistry {
         result
     }
 
+    pub fn has_handler(&self, function_type: &str) -> bool {
+        self.handlers.contains_key(function_type)
+    }
+
     pub fn register_handler(&mut self, name: String, handler: Box<dyn TaskHandler>) {
         debug!(handler_name = %name, "Registering new handler");
         self.handlers.insert(name, handler);
@@ -90,6 +119,10 @@ pub fn get_function_type(task: &EngineTask) -> &'static str {
         TaskFunction::CursorAgent { .. } => "cursor_agent",
         TaskFunction::Custom { .. } => "custom",
         TaskFunction::UserInput { .. } => "user_input",
+        TaskFunction::Manual { .. } => "manual",
+        TaskFunction::DbQuery { .. } => "db_query",
+        TaskFunction::PublishMessage { .. } => "publish_message",
+        TaskFunction::Cache { .. } => "cache",
     };
 
     trace!(
@@ -102,7 +135,17 @@ pub fn get_function_type(task: &EngineTask) -> &'static str {
 }
 
 // Export individual handlers
+pub mod cache;
 pub mod cli_command;
 pub mod cursor_agent;
 pub mod custom;
+pub mod db_query;
+pub mod environment;
+pub mod limits;
+pub mod manual;
+pub mod output;
+pub mod process;
+pub mod publish_message;
+pub mod sandbox;
+pub mod shell;
 pub mod user_input;
@@ -0,0 +1,148 @@
+use crate::events::OutputEvents;
+use std::collections::VecDeque;
+
+/// Bytes kept from each output stream when a task does not set `max_output_bytes`.
+pub const DEFAULT_MAX_OUTPUT_BYTES: u64 = 1024 * 1024;
+
+/// Longest partial line held back while waiting for its newline.
+const MAX_PENDING_LINE_BYTES: usize = 8 * 1024;
+
+/// One output stream held to a byte budget.
+///
+/// Half of the budget keeps the start of the stream and the other half a rolling
+/// tail, so the first lines and the final error both survive a noisy command.
+#[derive(Debug)]
+pub struct CappedOutput {
+    head: Vec<u8>,
+    tail: VecDeque<u8>,
+    head_limit: usize,
+    tail_limit: usize,
+    total: u64,
+}
+
+impl CappedOutput {
+    pub fn new(max_bytes: u64) -> Self {
+        let max_bytes = usize::try_from(max_bytes).unwrap_or(usize::MAX);
+        let head_limit = max_bytes / 2;
+        Self {
+            head: Vec::new(),
+            tail: VecDeque::new(),
+            head_limit,
+            tail_limit: max_bytes - head_limit,
+            total: 0,
+        }
+    }
+
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.total = self.total.saturating_add(bytes.len() as u64);
+
+        let take = (self.head_limit - self.head.len()).min(bytes.len());
+        self.head.extend_from_slice(&bytes[..take]);
+        let rest = &bytes[take..];
+        if rest.is_empty() {
+            return;
+        }
+
+        if rest.len() >= self.tail_limit {
+            self.tail.clear();
+            self.tail.extend(&rest[rest.len() - self.tail_limit..]);
+        } else {
+            let overflow = (self.tail.len() + rest.len()).saturating_sub(self.tail_limit);
+            self.tail.drain(..overflow);
+            self.tail.extend(rest);
+        }
+    }
+
+    /// Bytes written to the stream, including any that were dropped.
+    pub fn total_bytes(&self) -> u64 {
+        self.total
+    }
+
+    pub fn is_truncated(&self) -> bool {
+        self.omitted() > 0
+    }
+
+    fn omitted(&self) -> u64 {
+        self.total - (self.head.len() + self.tail.len()) as u64
+    }
+
+    /// The kept bytes as text, with a marker where the middle was dropped.
+    pub fn into_text(self) -> String {
+        let omitted = self.omitted();
+        let tail = Vec::from(self.tail);
+        if omitted == 0 {
+            let mut bytes = self.head;
+            bytes.extend_from_slice(&tail);
+            return String::from_utf8_lossy(&bytes).into_owned();
+        }
+
+        // The cut points can fall inside a multi-byte character; drop the partial
+        // bytes rather than render replacement characters next to the marker.
+        let head_end = match std::str::from_utf8(&self.head) {
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            _ => self.head.len(),
+        };
+        let tail_start = tail
+            .iter()
+            .take(3)
+            .take_while(|b| (**b & 0b1100_0000) == 0b1000_0000)
+            .count();
+
+        let mut text = String::from_utf8_lossy(&self.head[..head_end]).into_owned();
+        text.push_str(&elision_marker(omitted));
+        text.push_str(&String::from_utf8_lossy(&tail[tail_start..]));
+        text
+    }
+}
+
+pub fn elision_marker(omitted_bytes: u64) -> String {
+    format!("\n… [{} bytes omitted] …\n", omitted_bytes)
+}
+
+/// Splits a command's output into lines and streams each one as it arrives.
+#[derive(Debug)]
+pub struct LineForwarder {
+    output: OutputEvents,
+    task_id: String,
+    task_name: String,
+    pending: Vec<u8>,
+}
+
+impl LineForwarder {
+    pub fn new(output: OutputEvents, task_id: &str, task_name: &str) -> Self {
+        Self {
+            output,
+            task_id: task_id.to_string(),
+            task_name: task_name.to_string(),
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            if byte == b'\n' {
+                self.flush();
+            } else {
+                self.pending.push(byte);
+                if self.pending.len() >= MAX_PENDING_LINE_BYTES {
+                    self.flush();
+                }
+            }
+        }
+    }
+
+    /// Sends whatever is left after the last newline.
+    pub fn finish(mut self) {
+        if !self.pending.is_empty() {
+            self.flush();
+        }
+    }
+
+    fn flush(&mut self) {
+        let line = String::from_utf8_lossy(&self.pending);
+        let line = line.strip_suffix('\r').unwrap_or(&line);
+        self.output
+            .command_line(&self.task_id, &self.task_name, line);
+        self.pending.clear();
+    }
+}
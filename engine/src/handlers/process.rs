@@ -0,0 +1,371 @@
+use super::limits::ResourceLimits;
+use crate::types::{FailureReason, ProcessOptions};
+use std::io;
+use std::process::ExitStatus;
+use std::time::Duration;
+use tokio::process::{Child, Command};
+use tracing::{debug, warn};
+
+pub const DEFAULT_KILL_GRACE: Duration = Duration::from_secs(5);
+
+/// Owns the process group (Unix) or job object (Windows) of a spawned command so
+/// that the whole tree is torn down when the task finishes, times out or is dropped.
+pub struct ProcessTree {
+    grace: Duration,
+    finished: bool,
+    limits: ResourceLimits,
+    #[cfg(unix)]
+    pgid: Option<i32>,
+    #[cfg(windows)]
+    job: Option<windows_job::Job>,
+}
+
+impl ProcessTree {
+    pub fn spawn(command: &mut Command, options: &ProcessOptions) -> io::Result<(Child, Self)> {
+        let grace = options
+            .kill_grace_ms
+            .map(Duration::from_millis)
+            .unwrap_or(DEFAULT_KILL_GRACE);
+
+        #[cfg(unix)]
+        command.process_group(0);
+        let limits = ResourceLimits::apply(command, options);
+
+        let child = command.spawn()?;
+
+        #[cfg(unix)]
+        let tree = {
+            let pgid = child.id().map(|pid| pid as i32);
+            if let Some(pgid) = pgid {
+                live_groups().insert(pgid);
+            }
+            Self {
+                grace,
+                finished: false,
+                limits,
+                pgid,
+            }
+        };
+
+        #[cfg(windows)]
+        let tree = Self {
+            grace,
+            finished: false,
+            limits,
+            job: windows_job::Job::assign(&child, options)
+                .map_err(|e| warn!(error = %e, "Failed to attach process to job object"))
+                .ok(),
+        };
+
+        #[cfg(not(any(unix, windows)))]
+        let tree = Self {
+            grace,
+            finished: false,
+            limits,
+        };
+
+        Ok((child, tree))
+    }
+
+    /// Ask the tree to stop, escalating to a hard kill once the grace period is over.
+    pub async fn terminate(&mut self, child: &mut Child) -> io::Result<ExitStatus> {
+        debug!(
+            grace_ms = self.grace.as_millis() as u64,
+            "Terminating process tree"
+        );
+
+        #[cfg(unix)]
+        {
+            self.signal(libc::SIGTERM);
+            if let Ok(status) = tokio::time::timeout(self.grace, child.wait()).await {
+                self.finish();
+                return status;
+            }
+            warn!("Process tree ignored SIGTERM, sending SIGKILL");
+        }
+
+        #[cfg(windows)]
+        if let Some(job) = &self.job {
+            job.terminate();
+        }
+
+        let _ = child.start_kill();
+        let status = child.wait().await;
+        self.finish();
+        status
+    }
+
+    /// Kill anything the command left running after it exited.
+    pub fn finish(&mut self) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+
+        #[cfg(unix)]
+        {
+            self.signal(libc::SIGKILL);
+            if let Some(pgid) = self.pgid {
+                live_groups().remove(&pgid);
+            }
+        }
+
+        #[cfg(windows)]
+        if let Some(job) = &self.job {
+            job.terminate();
+        }
+    }
+
+    /// Resource limit the command ran into, if any.
+    pub fn breach(&self, status: &ExitStatus) -> Option<FailureReason> {
+        #[cfg(windows)]
+        if let Some(reason) = self.job.as_ref().and_then(|job| job.breach()) {
+            return Some(reason);
+        }
+
+        self.limits.breach(status)
+    }
+
+    #[cfg(unix)]
+    fn signal(&self, signal: i32) {
+        if let Some(pgid) = self.pgid {
+            signal_group(pgid, signal);
+        }
+    }
+}
+
+impl Drop for ProcessTree {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+
+        #[cfg(unix)]
+        if let Some(pgid) = self.pgid {
+            debug!(pgid, "Process tree dropped while running, terminating");
+            signal_group(pgid, libc::SIGTERM);
+            let grace = self.grace;
+            std::thread::spawn(move || {
+                std::thread::sleep(grace);
+                signal_group(pgid, libc::SIGKILL);
+                live_groups().remove(&pgid);
+            });
+            self.finished = true;
+            return;
+        }
+
+        self.finish();
+    }
+}
+
+/// Hard-kill every process tree that is still running, e.g. when the host process is interrupted.
+pub fn kill_all_process_trees() {
+    #[cfg(unix)]
+    {
+        let groups: Vec<i32> = live_groups().drain().collect();
+        for pgid in groups {
+            signal_group(pgid, libc::SIGKILL);
+        }
+    }
+}
+
+#[cfg(unix)]
+fn signal_group(pgid: i32, signal: i32) {
+    // SAFETY: kill(2) with a negative pid only signals the given process group.
+    unsafe {
+        libc::kill(-pgid, signal);
+    }
+}
+
+#[cfg(unix)]
+fn live_groups() -> std::sync::MutexGuard<'static, std::collections::HashSet<i32>> {
+    use std::collections::HashSet;
+    use std::sync::{Mutex, OnceLock};
+
+    static LIVE_GROUPS: OnceLock<Mutex<HashSet<i32>>> = OnceLock::new();
+    LIVE_GROUPS
+        .get_or_init(|| Mutex::new(HashSet::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(windows)]
+mod windows_job {
+    use crate::types::{FailureReason, ProcessOptions};
+    use std::ffi::c_void;
+    use std::io;
+    use tokio::process::Child;
+    use windows_sys::Win32::Foundation::{CloseHandle, HANDLE};
+    use windows_sys::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, JobObjectBasicAccountingInformation,
+        JobObjectCpuRateControlInformation, JobObjectExtendedLimitInformation,
+        QueryInformationJobObject, SetInformationJobObject, TerminateJobObject,
+        JOBOBJECT_BASIC_ACCOUNTING_INFORMATION, JOBOBJECT_CPU_RATE_CONTROL_INFORMATION,
+        JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JOB_OBJECT_CPU_RATE_CONTROL_ENABLE,
+        JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP, JOB_OBJECT_LIMIT_JOB_MEMORY,
+        JOB_OBJECT_LIMIT_JOB_TIME, JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+        JOB_OBJECT_LIMIT_PRIORITY_CLASS,
+    };
+    use windows_sys::Win32::System::Threading::{
+        ABOVE_NORMAL_PRIORITY_CLASS, BELOW_NORMAL_PRIORITY_CLASS, IDLE_PRIORITY_CLASS,
+        NORMAL_PRIORITY_CLASS,
+    };
+
+    /// Job times are measured in 100ns ticks.
+    const TICKS_PER_SECOND: i64 = 10_000_000;
+
+    /// Job object configured to kill every member once its last handle closes,
+    /// carrying the task's memory, CPU and priority limits.
+    pub struct Job {
+        handle: HANDLE,
+        memory_limit: Option<usize>,
+        cpu_time_limit: Option<i64>,
+    }
+
+    // SAFETY: job object handles may be used from any thread.
+    unsafe impl Send for Job {}
+    unsafe impl Sync for Job {}
+
+    impl Job {
+        pub fn assign(child: &Child, options: &ProcessOptions) -> io::Result<Self> {
+            let process = child
+                .raw_handle()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "process already exited"))?;
+
+            // SAFETY: all pointers passed below are valid for the duration of each call.
+            unsafe {
+                let handle = CreateJobObjectW(std::ptr::null(), std::ptr::null());
+                if handle.is_null() {
+                    return Err(io::Error::last_os_error());
+                }
+                let mut job = Job {
+                    handle,
+                    memory_limit: None,
+                    cpu_time_limit: None,
+                };
+
+                let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+                info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+
+                if let Some(mb) = options.max_memory_mb {
+                    let bytes =
+                        usize::try_from(mb.saturating_mul(1024 * 1024)).unwrap_or(usize::MAX);
+                    info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_JOB_MEMORY;
+                    info.JobMemoryLimit = bytes;
+                    job.memory_limit = Some(bytes);
+                }
+                if let Some(seconds) = options.max_cpu_seconds {
+                    let ticks = i64::try_from(seconds)
+                        .unwrap_or(i64::MAX / TICKS_PER_SECOND)
+                        .saturating_mul(TICKS_PER_SECOND);
+                    info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_JOB_TIME;
+                    info.BasicLimitInformation.PerJobUserTimeLimit = ticks;
+                    job.cpu_time_limit = Some(ticks);
+                }
+                if let Some(nice) = options.nice {
+                    info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_PRIORITY_CLASS;
+                    info.BasicLimitInformation.PriorityClass = match nice {
+                        n if n >= 10 => IDLE_PRIORITY_CLASS,
+                        n if n > 0 => BELOW_NORMAL_PRIORITY_CLASS,
+                        n if n < 0 => ABOVE_NORMAL_PRIORITY_CLASS,
+                        _ => NORMAL_PRIORITY_CLASS,
+                    };
+                }
+
+                if SetInformationJobObject(
+                    job.handle,
+                    JobObjectExtendedLimitInformation,
+                    &info as *const _ as *const c_void,
+                    std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                ) == 0
+                {
+                    return Err(io::Error::last_os_error());
+                }
+
+                if let Some(percent) = options.max_cpu_percent {
+                    // CpuRate is a share of the whole machine in 1/100ths of a percent,
+                    // while max_cpu_percent counts 100 as one full core.
+                    let cores = std::thread::available_parallelism()
+                        .map(|n| n.get() as u32)
+                        .unwrap_or(1);
+                    let mut rate: JOBOBJECT_CPU_RATE_CONTROL_INFORMATION = std::mem::zeroed();
+                    rate.ControlFlags =
+                        JOB_OBJECT_CPU_RATE_CONTROL_ENABLE | JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP;
+                    rate.Anonymous.CpuRate = (percent.saturating_mul(100) / cores).clamp(1, 10_000);
+                    if SetInformationJobObject(
+                        job.handle,
+                        JobObjectCpuRateControlInformation,
+                        &rate as *const _ as *const c_void,
+                        std::mem::size_of::<JOBOBJECT_CPU_RATE_CONTROL_INFORMATION>() as u32,
+                    ) == 0
+                    {
+                        tracing::warn!(
+                            error = %io::Error::last_os_error(),
+                            "Failed to apply CPU rate limit"
+                        );
+                    }
+                }
+
+                if AssignProcessToJobObject(job.handle, process as HANDLE) == 0 {
+                    return Err(io::Error::last_os_error());
+                }
+
+                Ok(job)
+            }
+        }
+
+        pub fn breach(&self) -> Option<FailureReason> {
+            // SAFETY: the handle is owned by this struct and the buffers match the
+            // information classes being queried.
+            unsafe {
+                if let Some(limit) = self.memory_limit {
+                    let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+                    if QueryInformationJobObject(
+                        self.handle,
+                        JobObjectExtendedLimitInformation,
+                        &mut info as *mut _ as *mut c_void,
+                        std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                        std::ptr::null_mut(),
+                    ) != 0
+                        && info.PeakJobMemoryUsed >= limit
+                    {
+                        return Some(FailureReason::MemoryLimitExceeded);
+                    }
+                }
+
+                if let Some(limit) = self.cpu_time_limit {
+                    let mut info: JOBOBJECT_BASIC_ACCOUNTING_INFORMATION = std::mem::zeroed();
+                    if QueryInformationJobObject(
+                        self.handle,
+                        JobObjectBasicAccountingInformation,
+                        &mut info as *mut _ as *mut c_void,
+                        std::mem::size_of::<JOBOBJECT_BASIC_ACCOUNTING_INFORMATION>() as u32,
+                        std::ptr::null_mut(),
+                    ) != 0
+                        && info.TotalUserTime >= limit
+                    {
+                        return Some(FailureReason::CpuLimitExceeded);
+                    }
+                }
+            }
+
+            None
+        }
+
+        pub fn terminate(&self) {
+            // SAFETY: the handle is owned by this struct and still open.
+            unsafe {
+                TerminateJobObject(self.handle, 1);
+            }
+        }
+    }
+
+    impl Drop for Job {
+        fn drop(&mut self) {
+            // SAFETY: the handle is owned by this struct and closed exactly once.
+            unsafe {
+                CloseHandle(self.handle);
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::errors::*;
+use crate::messaging::{publish, render_template};
+use crate::types::*;
+use async_trait::async_trait;
+use serde_json::Value;
+use tracing::{debug, instrument};
+
+pub struct PublishMessageHandler;
+
+#[async_trait]
+impl super::TaskHandler for PublishMessageHandler {
+    #[instrument(skip(self, context), fields(task_id = %task.id, task_name = %task.name))]
+    async fn execute(
+        &self,
+        context: &mut ExecutionContext,
+        task: &EngineTask,
+    ) -> Result<TaskResult, HandlerError> {
+        let TaskFunction::PublishMessage {
+            connection,
+            destination,
+            payload,
+            key,
+            headers,
+        } = &task.function
+        else {
+            return Err(HandlerError::InvalidConfiguration(
+                "Expected PublishMessage function".to_string(),
+            ));
+        };
+
+        let profile = context
+            .message_connections
+            .iter()
+            .find(|profile| &profile.name == connection)
+            .cloned()
+            .ok_or_else(|| {
+                HandlerError::InvalidConfiguration(format!(
+                    "Unknown message connection '{}'",
+                    connection
+                ))
+            })?;
+
+        let template_error =
+            |e: String| HandlerError::InvalidConfiguration(format!("Payload template: {}", e));
+        let payload =
+            render_template(payload, &context.params, &context.data).map_err(template_error)?;
+        let key = match key {
+            Some(key) => {
+                match render_template(&Value::String(key.clone()), &context.params, &context.data)
+                    .map_err(template_error)?
+                {
+                    Value::String(key) => Some(key),
+                    other => Some(other.to_string()),
+                }
+            }
+            None => None,
+        };
+        // Text payloads go out as-is; anything else as JSON.
+        let bytes = match &payload {
+            Value::String(text) => text.clone().into_bytes(),
+            other => serde_json::to_vec(other).map_err(|e| {
+                HandlerError::ExecutionFailed(format!("Could not encode payload: {}", e))
+            })?,
+        };
+        let payload_bytes = bytes.len();
+
+        debug!(
+            execution_id = %context.execution_id,
+            task_id = %task.id,
+            connection = %connection,
+            backend = %profile.backend,
+            destination = %destination,
+            payload_bytes,
+            "Publishing message"
+        );
+        context.log_task(
+            task.id.clone(),
+            format!(
+                "Publishing {} bytes to '{}' on {} connection '{}'",
+                payload_bytes, destination, profile.backend, connection
+            ),
+        );
+
+        let confirmation = publish(&profile, destination, key.as_deref(), headers, bytes)
+            .await
+            .map_err(HandlerError::ExecutionFailed)?;
+
+        context.log_task(
+            task.id.clone(),
+            format!("Delivery confirmed: {}", confirmation),
+        );
+
+        Ok(TaskResult {
+            success: true,
+            output: serde_json::json!({
+                "backend": profile.backend.as_str(),
+                "destination": destination,
+                "key": key,
+                "payload_bytes": payload_bytes,
+                "delivered": true,
+                "confirmation": confirmation,
+            }),
+            error: None,
+            failure_reason: None,
+            output_stats: None,
+            exit_code: None,
+            environment: None,
+        })
+    }
+}
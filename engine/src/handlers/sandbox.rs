@@ -0,0 +1,94 @@
+use crate::errors::*;
+use crate::types::*;
+use async_trait::async_trait;
+use serde_json::Value;
+use tracing::{debug, instrument};
+
+/// Handlers whose tasks change things outside the engine. A sandbox run
+/// records these instead of running them.
+pub const SIDE_EFFECT_HANDLERS: &[&str] = &["cli_command", "db_query", "publish_message"];
+
+/// Stands in for a side-effecting handler: succeeds with a description of what
+/// the task would have done as its output, so later tasks still run.
+pub struct RecordingHandler;
+
+/// One line describing what a task would do if it ran for real.
+pub fn describe_action(task: &EngineTask) -> String {
+    match &task.function {
+        TaskFunction::CliCommand {
+            command,
+            args,
+            process,
+        } => {
+            let mut line = std::iter::once(command.as_str())
+                .chain(args.iter().map(String::as_str))
+                .map(quote_arg)
+                .collect::<Vec<_>>()
+                .join(" ");
+            if let Some(timeout_ms) = process.timeout_ms {
+                line.push_str(&format!(" (timeout {} ms)", timeout_ms));
+            }
+            format!("Would run: {}", line)
+        }
+        TaskFunction::CursorAgent { prompt, .. } => format!(
+            "Would send a {} character prompt to the Cursor agent",
+            prompt.chars().count()
+        ),
+        TaskFunction::Custom { name, .. } => format!("Would call custom function '{}'", name),
+        TaskFunction::UserInput { prompt, .. } => format!("Would ask for input: {}", prompt),
+        TaskFunction::DbQuery {
+            connection, query, ..
+        } => format!("Would query '{}': {}", connection, query),
+        TaskFunction::PublishMessage {
+            connection,
+            destination,
+            ..
+        } => format!(
+            "Would publish a message to '{}' on '{}'",
+            destination, connection
+        ),
+        TaskFunction::Manual { instructions } => {
+            format!("Would wait for someone to confirm: {}", instructions)
+        }
+        TaskFunction::Cache { key, paths } => {
+            format!("Would restore {} from cache '{}'", paths.join(", "), key)
+        }
+    }
+}
+
+fn quote_arg(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains(|c: char| c.is_whitespace() || "'\"\\$`".contains(c)) {
+        return arg.to_string();
+    }
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+#[async_trait]
+impl super::TaskHandler for RecordingHandler {
+    #[instrument(skip(self, context), fields(task_id = %task.id, task_name = %task.name))]
+    async fn execute(
+        &self,
+        context: &mut ExecutionContext,
+        task: &EngineTask,
+    ) -> Result<TaskResult, HandlerError> {
+        let action = describe_action(task);
+        debug!(
+            execution_id = %context.execution_id,
+            task_id = %task.id,
+            action = %action,
+            "Recorded sandboxed task instead of running it"
+        );
+        let output = format!("[sandbox] {}", action);
+        context.log_task(task.id.clone(), output.clone());
+
+        Ok(TaskResult {
+            success: true,
+            output: Value::String(output),
+            error: None,
+            failure_reason: None,
+            output_stats: None,
+            exit_code: None,
+            environment: None,
+        })
+    }
+}
@@ -0,0 +1,115 @@
+use std::path::{MAIN_SEPARATOR, MAIN_SEPARATOR_STR};
+use std::process::ExitStatus;
+use tokio::process::Command;
+
+/// Commands that only exist as `cmd.exe` builtins on Windows.
+const CMD_BUILTINS: &[&str] = &[
+    "assoc", "call", "cd", "chdir", "cls", "copy", "date", "del", "dir", "echo", "erase", "ftype",
+    "md", "mkdir", "mklink", "move", "path", "pause", "rd", "ren", "rename", "rmdir", "set",
+    "start", "time", "type", "ver", "vol",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Launcher {
+    Direct,
+    Cmd,
+    PowerShell,
+}
+
+/// Decide how a command has to be launched on Windows.
+pub fn windows_launcher(command: &str) -> Launcher {
+    let lower = command.to_ascii_lowercase();
+    if lower.ends_with(".ps1") {
+        Launcher::PowerShell
+    } else if lower.ends_with(".bat")
+        || lower.ends_with(".cmd")
+        || CMD_BUILTINS.contains(&lower.as_str())
+    {
+        Launcher::Cmd
+    } else {
+        Launcher::Direct
+    }
+}
+
+/// Rewrite forward slashes in a program path to the platform separator.
+pub fn to_native_path(path: &str) -> String {
+    if MAIN_SEPARATOR == '/' {
+        path.to_string()
+    } else {
+        path.replace('/', MAIN_SEPARATOR_STR)
+    }
+}
+
+/// Quote a single argument for a `cmd.exe /S /C` command line.
+pub fn quote_cmd_arg(arg: &str) -> String {
+    let needs_quotes = arg.is_empty()
+        || arg
+            .chars()
+            .any(|c| c.is_whitespace() || "&|<>^()\",;=!%".contains(c));
+
+    if needs_quotes {
+        format!("\"{}\"", arg.replace('"', "\"\""))
+    } else {
+        arg.to_string()
+    }
+}
+
+pub fn build_command(command: &str, args: &[String]) -> Command {
+    #[cfg(windows)]
+    {
+        let program = to_native_path(command);
+        match windows_launcher(command) {
+            Launcher::Cmd => {
+                let line = std::iter::once(quote_cmd_arg(&program))
+                    .chain(args.iter().map(|a| quote_cmd_arg(a)))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let mut cmd = Command::new("cmd.exe");
+                cmd.args(["/D", "/S", "/C"])
+                    .raw_arg(format!("\"{}\"", line));
+                cmd
+            }
+            Launcher::PowerShell => {
+                let mut cmd = Command::new("powershell.exe");
+                cmd.args([
+                    "-NoProfile",
+                    "-NonInteractive",
+                    "-ExecutionPolicy",
+                    "Bypass",
+                    "-File",
+                ])
+                .arg(program)
+                .args(args);
+                cmd
+            }
+            Launcher::Direct => {
+                let mut cmd = Command::new(program);
+                cmd.args(args);
+                cmd
+            }
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        let mut cmd = Command::new(command);
+        cmd.args(args);
+        cmd
+    }
+}
+
+/// Exit code of a finished process, mapping Unix signals to the shell's `128 + signal`.
+pub fn exit_code(status: &ExitStatus) -> Option<i32> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        status
+            .code()
+            .or_else(|| status.signal().map(|signal| 128 + signal))
+    }
+
+    #[cfg(not(unix))]
+    {
+        status.code()
+    }
+}
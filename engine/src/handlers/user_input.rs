@@ -80,6 +80,10 @@ impl super::TaskHandler for UserInputHandler {
                 "default": default.clone(),
             }),
             error: None,
+            failure_reason: None,
+            output_stats: None,
+            exit_code: None,
+            environment: None,
         };
 
         debug!(
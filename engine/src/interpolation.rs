@@ -0,0 +1,305 @@
+//! `${data.TASK_ID.field}` and `${param.NAME}` placeholders in the command
+//! and arguments of `cli_command` tasks, the prompt of `cursor_agent` tasks
+//! and the input of `custom` tasks, filled in from the execution context just
+//! before the task runs.
+//!
+//! Only `${data.` and `${param.` start a placeholder, so shell variables such
+//! as `${HOME}` pass through untouched; `$${` writes a literal `${`. A
+//! placeholder that is a whole string in a custom input is replaced by the
+//! value itself, keeping numbers and objects typed. Everywhere else values are
+//! written as text, strings without their quotes.
+
+use crate::types::{EngineTask, TaskFunction};
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+
+#[derive(Debug, PartialEq)]
+enum Segment<'a> {
+    Text(&'a str),
+    /// The dotted path inside `${...}`, starting with `data` or `param`.
+    Placeholder(Vec<&'a str>),
+}
+
+/// Split `text` into literal text and placeholders.
+fn segments(text: &str) -> Result<Vec<Segment<'_>>, String> {
+    let mut segments = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        if rest[..start].ends_with('$') {
+            segments.push(Segment::Text(&rest[..start - 1]));
+            segments.push(Segment::Text("${"));
+            rest = &rest[start + 2..];
+            continue;
+        }
+        let inner = &rest[start + 2..];
+        if !(inner.starts_with("data.") || inner.starts_with("param.")) {
+            segments.push(Segment::Text(&rest[..start + 2]));
+            rest = inner;
+            continue;
+        }
+        let end = inner
+            .find('}')
+            .ok_or_else(|| format!("'${{{}' is missing its closing '}}'", inner))?;
+        segments.push(Segment::Text(&rest[..start]));
+        segments.push(Segment::Placeholder(parse_path(&inner[..end])?));
+        rest = &inner[end + 1..];
+    }
+    segments.push(Segment::Text(rest));
+    segments.retain(|segment| *segment != Segment::Text(""));
+    Ok(segments)
+}
+
+fn parse_path(path: &str) -> Result<Vec<&str>, String> {
+    let parts: Vec<&str> = path.split('.').collect();
+    if let Some(part) = parts.iter().find(|part| {
+        part.is_empty()
+            || !part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+    }) {
+        return Err(if part.is_empty() {
+            format!("'${{{}}}' has an empty field", path)
+        } else {
+            format!("'${{{}}}' has an invalid field '{}'", path, part)
+        });
+    }
+    if parts[0] == "param" && parts.len() > 2 {
+        return Err(format!(
+            "'${{{}}}' has fields after the parameter name, but parameters have none",
+            path
+        ));
+    }
+    Ok(parts)
+}
+
+/// Check the syntax of every placeholder in the strings inside `value`.
+pub fn check_placeholders(value: &Value) -> Result<(), String> {
+    match value {
+        Value::String(text) => segments(text).map(|_| ()),
+        Value::Array(items) => items.iter().try_for_each(check_placeholders),
+        Value::Object(object) => object.values().try_for_each(check_placeholders),
+        _ => Ok(()),
+    }
+}
+
+/// Check the placeholders in the inputs of a task that fills them in.
+pub fn check_task_placeholders(function: &TaskFunction) -> Result<(), String> {
+    match function {
+        TaskFunction::CliCommand { command, args, .. } => std::iter::once(command)
+            .chain(args)
+            .try_for_each(|text| segments(text).map(|_| ())),
+        TaskFunction::CursorAgent { prompt, .. } => segments(prompt).map(|_| ()),
+        TaskFunction::Custom { input, .. } => check_placeholders(input),
+        _ => Ok(()),
+    }
+}
+
+/// Ids of the tasks whose output the strings inside `value` read through
+/// `${data.TASK_ID...}` placeholders.
+pub(crate) fn referenced_tasks(value: &Value) -> Vec<String> {
+    references(value, "data")
+}
+
+/// Names of the parameters the strings inside `value` read through
+/// `${param.NAME}` placeholders.
+pub(crate) fn referenced_params(value: &Value) -> Vec<String> {
+    references(value, "param")
+}
+
+fn references(value: &Value, root: &str) -> Vec<String> {
+    match value {
+        Value::String(text) => segments(text)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|segment| match segment {
+                Segment::Placeholder(path) if path[0] == root => Some(path[1].to_string()),
+                _ => None,
+            })
+            .collect(),
+        Value::Array(items) => items
+            .iter()
+            .flat_map(|item| references(item, root))
+            .collect(),
+        Value::Object(object) => object
+            .values()
+            .flat_map(|item| references(item, root))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn lookup<'a>(
+    path: &[&str],
+    params: &'a Map<String, Value>,
+    data: &'a Value,
+) -> Result<&'a Value, String> {
+    let shown = || format!("${{{}}}", path.join("."));
+    let root = match path[0] {
+        "param" => params
+            .get(path[1])
+            .ok_or_else(|| format!("{}: unknown workflow parameter '{}'", shown(), path[1]))?,
+        _ => data
+            .get(path[1])
+            .ok_or_else(|| format!("{}: no output from task '{}' yet", shown(), path[1]))?,
+    };
+    path[2..].iter().try_fold(root, |value, field| {
+        match value {
+            Value::Array(items) => field.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => value.get(*field),
+        }
+        .ok_or_else(|| format!("{}: no field '{}'", shown(), field))
+    })
+}
+
+/// Fills in placeholders from one set of parameters and task outputs.
+struct Filler<'a> {
+    params: &'a Map<String, Value>,
+    data: &'a Value,
+    /// Set for a preview: placeholders reading output no task has produced
+    /// are kept as written and listed here instead of failing.
+    unresolved: Option<RefCell<Vec<String>>>,
+}
+
+impl<'a> Filler<'a> {
+    fn new(params: &'a Map<String, Value>, data: &'a Value) -> Self {
+        Self {
+            params,
+            data,
+            unresolved: None,
+        }
+    }
+
+    /// The value `path` names, or `None` when it is kept as written.
+    fn lookup(&self, path: &[&str]) -> Result<Option<&'a Value>, String> {
+        match (&self.unresolved, path[0]) {
+            (Some(unresolved), "data") if self.data.get(path[1]).is_none() => {
+                unresolved
+                    .borrow_mut()
+                    .push(format!("${{{}}}", path.join(".")));
+                Ok(None)
+            }
+            _ => lookup(path, self.params, self.data).map(Some),
+        }
+    }
+
+    fn string(&self, text: &str) -> Result<String, String> {
+        segments(text)?.into_iter().try_fold(
+            String::with_capacity(text.len()),
+            |mut output, segment| {
+                match segment {
+                    Segment::Text(text) => output.push_str(text),
+                    Segment::Placeholder(path) => match self.lookup(&path)? {
+                        Some(Value::String(value)) => output.push_str(value),
+                        Some(value) => output.push_str(&value.to_string()),
+                        None => output.push_str(&format!("${{{}}}", path.join("."))),
+                    },
+                }
+                Ok(output)
+            },
+        )
+    }
+
+    fn value(&self, value: &Value) -> Result<Value, String> {
+        match value {
+            Value::String(text) => match segments(text)?.as_slice() {
+                [Segment::Placeholder(path)] => Ok(self
+                    .lookup(path)?
+                    .cloned()
+                    .unwrap_or_else(|| Value::String(text.clone()))),
+                _ => self.string(text).map(Value::String),
+            },
+            Value::Array(items) => items
+                .iter()
+                .map(|item| self.value(item))
+                .collect::<Result<_, _>>()
+                .map(Value::Array),
+            Value::Object(object) => object
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), self.value(value)?)))
+                .collect::<Result<_, String>>()
+                .map(Value::Object),
+            other => Ok(other.clone()),
+        }
+    }
+
+    fn task(&self, task: &EngineTask) -> Result<EngineTask, String> {
+        let text = |text: &String| self.string(text);
+        let function = match &task.function {
+            TaskFunction::CliCommand {
+                command,
+                args,
+                process,
+            } => TaskFunction::CliCommand {
+                command: text(command)?,
+                args: args.iter().map(text).collect::<Result<_, _>>()?,
+                process: process.clone(),
+            },
+            TaskFunction::CursorAgent { prompt, config } => {
+                let prompt = text(prompt)?;
+                let mut config = config.clone();
+                if let Some(fields) = config.as_object_mut() {
+                    fields.insert("prompt".to_string(), Value::from(prompt.clone()));
+                }
+                TaskFunction::CursorAgent { prompt, config }
+            }
+            TaskFunction::Custom { name, input } => TaskFunction::Custom {
+                name: name.clone(),
+                input: self.value(input)?,
+            },
+            _ => return Ok(task.clone()),
+        };
+        Ok(EngineTask {
+            function,
+            ..task.clone()
+        })
+    }
+}
+
+/// `text` with its placeholders filled in.
+pub fn interpolate_string(
+    text: &str,
+    params: &Map<String, Value>,
+    data: &Value,
+) -> Result<String, String> {
+    Filler::new(params, data).string(text)
+}
+
+/// `value` with the placeholders in its strings filled in. A string that is a
+/// single placeholder becomes the value it names.
+pub fn interpolate_value(
+    value: &Value,
+    params: &Map<String, Value>,
+    data: &Value,
+) -> Result<Value, String> {
+    Filler::new(params, data).value(value)
+}
+
+/// `task` with the placeholders in its inputs filled in from `params` and the
+/// outputs in `data`.
+pub fn interpolate_task(
+    task: &EngineTask,
+    params: &Map<String, Value>,
+    data: &Value,
+) -> Result<EngineTask, String> {
+    Filler::new(params, data).task(task)
+}
+
+/// [`interpolate_task`] for a task that is only previewed: placeholders
+/// reading the output of a task that has produced none are left as written
+/// and returned alongside, instead of failing.
+pub(crate) fn preview_task(
+    task: &EngineTask,
+    params: &Map<String, Value>,
+    data: &Value,
+) -> Result<(EngineTask, Vec<String>), String> {
+    let filler = Filler {
+        unresolved: Some(RefCell::default()),
+        ..Filler::new(params, data)
+    };
+    let task = filler.task(task)?;
+    let unresolved = filler
+        .unresolved
+        .map(RefCell::into_inner)
+        .unwrap_or_default();
+    Ok((task, unresolved))
+}
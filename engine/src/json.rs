@@ -0,0 +1,132 @@
+//! Strict JSON reading for workflow documents.
+//!
+//! `serde_json::from_str` keeps the last of two identical keys and reports a
+//! deeply nested document as an opaque recursion error. Workflows come from
+//! uploads and sync directories, so both cases are rejected here with their
+//! own `ParserError` variants instead.
+
+use crate::errors::ParserError;
+use serde::de::{DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+use std::fmt;
+
+/// Deepest array/object nesting accepted in a workflow document.
+///
+/// A task chain needs two levels per step (`next_tasks` array and task
+/// object), so this still allows chains of about thirty tasks.
+pub const MAX_NESTING_DEPTH: usize = 64;
+
+/// Parse a JSON document, rejecting duplicate object keys and excessive nesting.
+pub fn parse_document(json: &str) -> Result<Value, ParserError> {
+    let violation = RefCell::new(None);
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let result = StrictValue {
+        depth: 0,
+        violation: &violation,
+    }
+    .deserialize(&mut deserializer)
+    .and_then(|value| deserializer.end().map(|()| value));
+
+    match (result, violation.into_inner()) {
+        (_, Some(violation)) => Err(violation),
+        (Ok(value), None) => Ok(value),
+        (Err(e), None) => Err(ParserError::Json(e)),
+    }
+}
+
+/// Parse raw bytes, reporting where the UTF-8 goes wrong before any JSON parsing.
+pub fn parse_document_bytes(bytes: &[u8]) -> Result<Value, ParserError> {
+    let json = std::str::from_utf8(bytes).map_err(|e| ParserError::InvalidUtf8 {
+        position: e.valid_up_to(),
+    })?;
+    parse_document(json)
+}
+
+#[derive(Clone, Copy)]
+struct StrictValue<'a> {
+    depth: usize,
+    violation: &'a RefCell<Option<ParserError>>,
+}
+
+impl StrictValue<'_> {
+    fn reject<E: serde::de::Error>(&self, violation: ParserError) -> E {
+        let error = E::custom(&violation);
+        *self.violation.borrow_mut() = Some(violation);
+        error
+    }
+
+    fn nested<E: serde::de::Error>(self) -> Result<Self, E> {
+        let depth = self.depth + 1;
+        if depth > MAX_NESTING_DEPTH {
+            return Err(self.reject(ParserError::NestingTooDeep(MAX_NESTING_DEPTH)));
+        }
+        Ok(Self { depth, ..self })
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for StrictValue<'_> {
+    type Value = Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for StrictValue<'_> {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any JSON value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Value, E> {
+        Ok(Value::Bool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Value, E> {
+        Ok(Value::from(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Value, E> {
+        Ok(Value::from(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Value, E> {
+        Ok(Value::from(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Value, E> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Value, E> {
+        Ok(Value::String(v))
+    }
+
+    fn visit_unit<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let element = self.nested()?;
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element_seed(element)? {
+            values.push(value);
+        }
+        Ok(Value::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let entry = self.nested()?;
+        let mut values = Map::new();
+        while let Some(key) = map.next_key::<String>()? {
+            if values.contains_key(&key) {
+                return Err(self.reject(ParserError::DuplicateKey(key)));
+            }
+            let value = map.next_value_seed(entry)?;
+            values.insert(key, value);
+        }
+        Ok(Value::Object(values))
+    }
+}
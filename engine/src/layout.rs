@@ -0,0 +1,238 @@
+//! Node positions for drawing a workflow's task graph. The visual editor and
+//! the CLI graph export both use these, so a workflow looks the same in each.
+//!
+//! Positions are the top-left corner of each task's box, in the editor's
+//! canvas units, with the whole graph starting at (0, 0).
+
+use crate::types::{EngineTask, EngineWorkflow};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+pub const LAYOUT_NODE_WIDTH: f64 = 250.0;
+pub const LAYOUT_NODE_HEIGHT: f64 = 80.0;
+/// Space between boxes side by side.
+const NODE_GAP: f64 = 50.0;
+/// Space between a task and the tasks after it, or between grid rows.
+const RANK_GAP: f64 = 150.0;
+const FORCE_ITERATIONS: usize = 300;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LayoutMode {
+    /// Each task one row below the task it runs after, parents centered over
+    /// their children.
+    #[default]
+    Layered,
+    /// Linked tasks pulled together and unrelated ones pushed apart, starting
+    /// from the layered layout so the result is always the same.
+    ForceDirected,
+    /// One column per stage in declared order, tasks in document order down
+    /// each column. Tasks without a stage go in their parent's column.
+    StageGrid,
+}
+
+impl LayoutMode {
+    pub const ALL: [LayoutMode; 3] = [
+        LayoutMode::Layered,
+        LayoutMode::ForceDirected,
+        LayoutMode::StageGrid,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LayoutMode::Layered => "layered",
+            LayoutMode::ForceDirected => "force_directed",
+            LayoutMode::StageGrid => "stage_grid",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|mode| mode.as_str() == name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NodePosition {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Position of every task in `workflow`, keyed by task id.
+pub fn layout_workflow(
+    workflow: &EngineWorkflow,
+    mode: LayoutMode,
+) -> BTreeMap<String, NodePosition> {
+    // The parser lists every task at the top level too; walk from the roots.
+    let roots: Vec<&EngineTask> = workflow.tasks.iter().filter(|task| task.is_root).collect();
+    match mode {
+        LayoutMode::Layered => layered(&roots),
+        LayoutMode::ForceDirected => force_directed(&roots),
+        LayoutMode::StageGrid => stage_grid(&roots, &workflow.stages),
+    }
+}
+
+fn layered(roots: &[&EngineTask]) -> BTreeMap<String, NodePosition> {
+    // Leaves take the next free column; parents sit midway over their
+    // first and last child.
+    fn place(
+        task: &EngineTask,
+        depth: usize,
+        next_column: &mut f64,
+        out: &mut BTreeMap<String, NodePosition>,
+    ) -> f64 {
+        let column = if task.next_tasks.is_empty() {
+            let column = *next_column;
+            *next_column += 1.0;
+            column
+        } else {
+            let columns: Vec<f64> = task
+                .next_tasks
+                .iter()
+                .map(|next| place(next, depth + 1, next_column, out))
+                .collect();
+            (columns[0] + columns[columns.len() - 1]) / 2.0
+        };
+        out.insert(
+            task.id.clone(),
+            NodePosition {
+                x: column * (LAYOUT_NODE_WIDTH + NODE_GAP),
+                y: depth as f64 * (LAYOUT_NODE_HEIGHT + RANK_GAP),
+            },
+        );
+        column
+    }
+
+    let mut out = BTreeMap::new();
+    let mut next_column = 0.0;
+    for root in roots {
+        place(root, 0, &mut next_column, &mut out);
+    }
+    out
+}
+
+fn force_directed(roots: &[&EngineTask]) -> BTreeMap<String, NodePosition> {
+    fn collect_edges<'a>(task: &'a EngineTask, edges: &mut Vec<(&'a str, &'a str)>) {
+        for next in &task.next_tasks {
+            edges.push((&task.id, &next.id));
+            collect_edges(next, edges);
+        }
+    }
+
+    let start = layered(roots);
+    let ids: Vec<&String> = start.keys().collect();
+    let index: HashMap<&str, usize> = ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (id.as_str(), i))
+        .collect();
+    let mut edges = Vec::new();
+    for root in roots {
+        collect_edges(root, &mut edges);
+    }
+    let edges: Vec<(usize, usize)> = edges
+        .into_iter()
+        .map(|(from, to)| (index[from], index[to]))
+        .collect();
+    let mut positions: Vec<(f64, f64)> = start.values().map(|p| (p.x, p.y)).collect();
+
+    // Fruchterman-Reingold with linear cooling.
+    let ideal = LAYOUT_NODE_WIDTH + NODE_GAP;
+    for iteration in 0..FORCE_ITERATIONS {
+        let temperature = ideal * (1.0 - iteration as f64 / FORCE_ITERATIONS as f64);
+        let mut shift = vec![(0.0, 0.0); positions.len()];
+        for i in 0..positions.len() {
+            for j in (i + 1)..positions.len() {
+                let (dx, dy) = (
+                    positions[i].0 - positions[j].0,
+                    positions[i].1 - positions[j].1,
+                );
+                let distance = dx.hypot(dy).max(1.0);
+                let force = ideal * ideal / distance;
+                let (fx, fy) = (dx / distance * force, dy / distance * force);
+                shift[i].0 += fx;
+                shift[i].1 += fy;
+                shift[j].0 -= fx;
+                shift[j].1 -= fy;
+            }
+        }
+        for &(from, to) in &edges {
+            let (dx, dy) = (
+                positions[from].0 - positions[to].0,
+                positions[from].1 - positions[to].1,
+            );
+            let distance = dx.hypot(dy).max(1.0);
+            let force = distance * distance / ideal;
+            let (fx, fy) = (dx / distance * force, dy / distance * force);
+            shift[from].0 -= fx;
+            shift[from].1 -= fy;
+            shift[to].0 += fx;
+            shift[to].1 += fy;
+        }
+        for (position, (sx, sy)) in positions.iter_mut().zip(shift) {
+            let length = sx.hypot(sy);
+            if length > 0.0 {
+                let step = length.min(temperature) / length;
+                position.0 += sx * step;
+                position.1 += sy * step;
+            }
+        }
+    }
+
+    let min_x = positions.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+    let min_y = positions.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+    ids.into_iter()
+        .zip(positions)
+        .map(|(id, (x, y))| {
+            (
+                id.clone(),
+                NodePosition {
+                    x: (x - min_x).round(),
+                    y: (y - min_y).round(),
+                },
+            )
+        })
+        .collect()
+}
+
+fn stage_grid(roots: &[&EngineTask], stages: &[String]) -> BTreeMap<String, NodePosition> {
+    // Column 0 holds tasks with no stage of their own or above them, and is
+    // dropped when there are none.
+    fn assign<'a>(
+        task: &'a EngineTask,
+        stages: &[String],
+        inherited: usize,
+        out: &mut Vec<(&'a str, usize)>,
+    ) {
+        let column = task
+            .stage
+            .as_deref()
+            .and_then(|stage| stages.iter().position(|name| name == stage))
+            .map(|index| index + 1)
+            .unwrap_or(inherited);
+        out.push((&task.id, column));
+        for next in &task.next_tasks {
+            assign(next, stages, column, out);
+        }
+    }
+
+    let mut columns = Vec::new();
+    for root in roots {
+        assign(root, stages, 0, &mut columns);
+    }
+    let offset = usize::from(columns.iter().all(|(_, column)| *column > 0));
+    let mut rows = vec![0usize; stages.len() + 1];
+    columns
+        .into_iter()
+        .map(|(id, column)| {
+            let row = rows[column];
+            rows[column] += 1;
+            (
+                id.to_string(),
+                NodePosition {
+                    x: (column - offset) as f64 * (LAYOUT_NODE_WIDTH + NODE_GAP),
+                    y: row as f64 * (LAYOUT_NODE_HEIGHT + RANK_GAP),
+                },
+            )
+        })
+        .collect()
+}
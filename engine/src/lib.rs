@@ -1,16 +1,82 @@
+pub mod bounds;
+pub mod cache;
+pub mod checkpoint;
+pub mod condition;
+pub mod db;
+pub mod diff;
+pub mod dir_cache;
+pub mod dry_run;
 pub mod engine;
 pub mod errors;
+pub mod events;
+pub mod handler_policy;
 pub mod handlers;
+pub mod interpolation;
+pub mod json;
+pub mod layout;
+pub mod locks;
+pub mod loops;
+pub mod messaging;
+pub mod middleware;
+pub mod notify;
+pub mod outputs;
+pub mod parameters;
 pub mod parser;
+pub mod preflight;
+pub mod profile;
+pub mod progress;
+pub mod scratch;
+pub mod speculation;
+pub mod stages;
 pub mod types;
+pub mod workflow_limits;
+pub mod workspace;
 
 #[cfg(test)]
 mod tests;
 
+pub use bounds::{bound_workflow, data_references, BoundedWorkflow};
+pub use cache::{cache_key, TaskResultCache};
+pub use checkpoint::{Checkpoint, CheckpointStore};
+pub use condition::Condition;
+pub use db::{check_statement, DbConnection, DEFAULT_MAX_ROWS};
+pub use dir_cache::DirectoryCache;
+pub use dry_run::PlannedTask;
 pub use engine::WorkflowEngine;
 pub use errors::*;
-pub use parser::parse_workflow;
+pub use events::{OutputEvent, OutputEvents, OutputSink};
+pub use handler_policy::{
+    handler_policy, set_handler_policy, HandlerPolicy, PolicyList, PolicyViolation,
+};
+pub use interpolation::{
+    check_placeholders, interpolate_string, interpolate_task, interpolate_value,
+};
+pub use layout::{
+    layout_workflow, LayoutMode, NodePosition, LAYOUT_NODE_HEIGHT, LAYOUT_NODE_WIDTH,
+};
+pub use locks::{MemoryResourceLocks, ResourceLocks, LOCK_POLL_INTERVAL};
+pub use loops::{iteration_id, loop_task_id};
+pub use messaging::{
+    render_template, IncomingMessage, MessageBackend, MessageConnection, MessageSource, Settle,
+};
+pub use middleware::{Next, RedactMiddleware, RetryMiddleware, TaskMiddleware};
+pub use notify::{validate_notify, NotifyOn, NotifyRule};
+pub use outputs::{map_outputs, validate_outputs};
+pub use parser::{parse_workflow, parse_workflow_bytes, parse_workflow_with_limits};
+pub use preflight::{
+    probe_tool_versions, referenced_tools, run_preflight, PreflightCheck, PreflightReport,
+    PreflightResult,
+};
+pub use profile::{PhaseSummary, ProfilePhase, ProfileSpan, Profiler, RunProfile};
+pub use progress::{parse_progress_line, TaskProgress};
+pub use scratch::Scratchpad;
+pub use speculation::{validate_speculation, BRANCHES_KEY};
+pub use stages::validate_stages;
 pub use types::*;
+pub use workflow_limits::{set_workflow_limits, workflow_limits, WorkflowLimits};
+
+/// Version of the engine and the task handlers built into it.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub async fn execute_workflow_from_json(json: &str) -> Result<WorkflowResult, EngineError> {
     let workflow = parse_workflow(json)?;
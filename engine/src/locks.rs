@@ -0,0 +1,166 @@
+//! Named resource locks that serialize tasks across executions, e.g. every
+//! task declaring `"lock": "deploy-prod"`, whichever workflow it belongs to.
+//!
+//! Each task wanting a resource takes a ticket; tickets are served in order
+//! and the oldest one for a resource holds it.
+
+use crate::messaging::render_template;
+use crate::types::ExecutionContext;
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::debug;
+
+/// How often a waiting task checks its place in the queue, and how often a
+/// holder reports that it is still alive.
+pub const LOCK_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Queue of tickets per resource, shared by every execution that should
+/// coordinate.
+#[async_trait]
+pub trait ResourceLocks: Send + Sync {
+    /// Take a ticket at the back of `resource`'s queue.
+    async fn join(&self, resource: &str, execution_id: &str, task_id: &str) -> Result<i64, String>;
+
+    /// How many tickets are ahead of `ticket`; 0 means it holds the resource.
+    /// Each call also marks the ticket as still in use.
+    async fn position(&self, ticket: i64) -> Result<usize, String>;
+
+    async fn leave(&self, ticket: i64);
+}
+
+/// Resource locks kept in memory, coordinating only the engines that share
+/// the same instance.
+#[derive(Default)]
+pub struct MemoryResourceLocks {
+    tickets: Mutex<(i64, BTreeMap<i64, String>)>,
+}
+
+impl MemoryResourceLocks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ResourceLocks for MemoryResourceLocks {
+    async fn join(
+        &self,
+        resource: &str,
+        _execution_id: &str,
+        _task_id: &str,
+    ) -> Result<i64, String> {
+        let mut tickets = self.tickets.lock().unwrap_or_else(|e| e.into_inner());
+        tickets.0 += 1;
+        let ticket = tickets.0;
+        tickets.1.insert(ticket, resource.to_string());
+        Ok(ticket)
+    }
+
+    async fn position(&self, ticket: i64) -> Result<usize, String> {
+        let tickets = self.tickets.lock().unwrap_or_else(|e| e.into_inner());
+        let resource = tickets
+            .1
+            .get(&ticket)
+            .ok_or_else(|| format!("Lock ticket {} is no longer queued", ticket))?;
+        Ok(tickets
+            .1
+            .range(..ticket)
+            .filter(|(_, other)| *other == resource)
+            .count())
+    }
+
+    async fn leave(&self, ticket: i64) {
+        let mut tickets = self.tickets.lock().unwrap_or_else(|e| e.into_inner());
+        tickets.1.remove(&ticket);
+    }
+}
+
+/// A resource the current task holds, kept alive until [`HeldLock::release`].
+pub(crate) struct HeldLock {
+    locks: Arc<dyn ResourceLocks>,
+    ticket: i64,
+    heartbeat: JoinHandle<()>,
+}
+
+impl HeldLock {
+    pub(crate) async fn release(self) {
+        self.heartbeat.abort();
+        self.locks.leave(self.ticket).await;
+    }
+}
+
+/// Wait until the task holds the resource named by `lock`, a template over
+/// the parameters and context, streaming its place in the queue whenever it
+/// changes.
+pub(crate) async fn acquire(
+    locks: &Arc<dyn ResourceLocks>,
+    lock: &str,
+    context: &ExecutionContext,
+    task_id: &str,
+) -> Result<HeldLock, String> {
+    let resource = match render_template(
+        &Value::String(lock.to_string()),
+        &context.params,
+        &context.data,
+    )
+    .map_err(|e| format!("Lock name: {}", e))?
+    {
+        Value::String(resource) => resource,
+        other => other.to_string(),
+    };
+    let resource = resource.as_str();
+    let ticket = locks.join(resource, &context.execution_id, task_id).await?;
+    let mut last_position = None;
+    loop {
+        let position = match locks.position(ticket).await {
+            Ok(position) => position,
+            Err(e) => {
+                locks.leave(ticket).await;
+                return Err(e);
+            }
+        };
+        if position == 0 {
+            break;
+        }
+        if last_position != Some(position) {
+            debug!(
+                execution_id = %context.execution_id,
+                task_id = %task_id,
+                resource = %resource,
+                position = position,
+                "Waiting for resource lock"
+            );
+            context.emit_task_line(
+                task_id,
+                &format!(
+                    "Waiting for lock '{}' ({} ahead in queue)",
+                    resource, position
+                ),
+            );
+            last_position = Some(position);
+        }
+        tokio::time::sleep(LOCK_POLL_INTERVAL).await;
+    }
+    if last_position.is_some() {
+        context.emit_task_line(task_id, &format!("Acquired lock '{}'", resource));
+    }
+
+    let heartbeat = {
+        let locks = Arc::clone(locks);
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(LOCK_POLL_INTERVAL).await;
+                let _ = locks.position(ticket).await;
+            }
+        })
+    };
+    Ok(HeldLock {
+        locks: Arc::clone(locks),
+        ticket,
+        heartbeat,
+    })
+}
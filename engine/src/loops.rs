@@ -0,0 +1,253 @@
+//! `for_each` tasks: one run of the task per item of an array in the context.
+//!
+//! A string, such as the output of a command that lists files, is looped
+//! over line by line, skipping blank lines. Each item gets its own iteration task, `TASK_ID#INDEX`, with `{{item}}`,
+//! `{{item.FIELD}}` and `{{index}}` in its inputs filled in. Iterations run
+//! in parallel; once all have finished, the task's output is the list of
+//! their outputs and the tasks after it run once.
+
+use crate::condition::path_value;
+use crate::diff;
+use crate::types::*;
+use serde_json::Value;
+use std::collections::HashSet;
+use tracing::info;
+
+/// Id of one iteration of a `for_each` task, e.g. `convert#2`.
+pub fn iteration_id(task_id: &str, index: usize) -> String {
+    format!("{}#{}", task_id, index)
+}
+
+/// The task an id belongs to: the loop task for an iteration, else the id itself.
+pub fn loop_task_id(id: &str) -> &str {
+    id.rsplit_once('#')
+        .filter(|(_, index)| index.parse::<usize>().is_ok())
+        .map_or(id, |(task_id, _)| task_id)
+}
+
+/// A `for_each` task whose iterations run in the current round.
+pub(crate) struct LoopRun {
+    task: EngineTask,
+    iterations: Vec<EngineTask>,
+    error: Option<String>,
+}
+
+/// Replace the `for_each` tasks among `ready_tasks` by their iterations.
+pub(crate) fn expand_loops(
+    ready_tasks: Vec<EngineTask>,
+    context: &mut ExecutionContext,
+) -> (Vec<EngineTask>, Vec<LoopRun>) {
+    let mut runnable = Vec::new();
+    let mut loops = Vec::new();
+    for task in ready_tasks {
+        let Some(for_each) = task.for_each.clone() else {
+            runnable.push(task);
+            continue;
+        };
+        let iterations = path_value(&for_each, &context.params, &context.data)
+            .and_then(|items| match items {
+                Value::Array(items) => Ok(items),
+                Value::String(text) => Ok(text
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(Value::from)
+                    .collect()),
+                Value::Null => Err(format!("for_each {} has no value", for_each)),
+                _ => Err(format!("for_each {} is not an array", for_each)),
+            })
+            .and_then(|items| {
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| iteration_task(&task, index, item))
+                    .collect::<Result<Vec<_>, _>>()
+            });
+        let run = match iterations {
+            Ok(iterations) => {
+                info!(
+                    execution_id = %context.execution_id,
+                    task_id = %task.id,
+                    iterations = iterations.len(),
+                    "🔁 Expanding for_each task"
+                );
+                context.log_task(
+                    task.id.clone(),
+                    format!("Running {} iterations over {}", iterations.len(), for_each),
+                );
+                runnable.extend(iterations.iter().cloned());
+                LoopRun {
+                    task,
+                    iterations,
+                    error: None,
+                }
+            }
+            Err(e) => LoopRun {
+                task,
+                iterations: Vec::new(),
+                error: Some(e),
+            },
+        };
+        loops.push(run);
+    }
+    (runnable, loops)
+}
+
+impl LoopRun {
+    pub(crate) fn task_id(&self) -> &str {
+        &self.task.id
+    }
+
+    /// Settle the loop task once its iterations have finished: its output is
+    /// the list of their outputs, and it fails if any of them did. Returns
+    /// the iterations that ran.
+    pub(crate) fn finish(
+        self,
+        context: &mut ExecutionContext,
+        completed: &mut HashSet<String>,
+        failed: &mut HashSet<String>,
+        errors: &mut Vec<String>,
+        audit_trail: &mut Vec<AuditEntry>,
+    ) -> Vec<EngineTask> {
+        let failures = self
+            .iterations
+            .iter()
+            .filter(|iteration| failed.contains(&iteration.id))
+            .count();
+        let error = self.error.or_else(|| {
+            (failures > 0).then(|| {
+                format!(
+                    "{} of {} iterations failed",
+                    failures,
+                    self.iterations.len()
+                )
+            })
+        });
+
+        let before = context.data.clone();
+        let (status, message) = match &error {
+            None => {
+                let outputs = self
+                    .iterations
+                    .iter()
+                    .map(|iteration| {
+                        context
+                            .data
+                            .get(&iteration.id)
+                            .cloned()
+                            .unwrap_or(Value::Null)
+                    })
+                    .collect();
+                if let Value::Object(data) = &mut context.data {
+                    data.insert(self.task.id.clone(), Value::Array(outputs));
+                }
+                (
+                    AuditStatus::Success,
+                    format!("Completed task: {}", self.task.name),
+                )
+            }
+            Some(error) => {
+                context.log_task(self.task.id.clone(), format!("Error: {}", error));
+                errors.push(format!("Task {}: {}", self.task.id, error));
+                failed.insert(self.task.id.clone());
+                (
+                    AuditStatus::Failure,
+                    format!("Failed task: {} - {}", self.task.name, error),
+                )
+            }
+        };
+        let changes = diff::diff(&before, &context.data);
+        audit_trail.push(AuditEntry {
+            task_id: self.task.id.clone(),
+            status,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            changes_count: changes.count,
+            message,
+            changes: changes.changes,
+            changes_truncated: changes.truncated,
+        });
+        completed.insert(self.task.id);
+        self.iterations
+    }
+}
+
+/// A copy of `task` that runs once, for `item`.
+fn iteration_task(task: &EngineTask, index: usize, item: &Value) -> Result<EngineTask, String> {
+    let function = serde_json::to_value(&task.function).map_err(|e| e.to_string())?;
+    let function = serde_json::from_value(fill_item(&function, index, item)?)
+        .map_err(|e| format!("iteration {} has invalid inputs: {}", index, e))?;
+    Ok(EngineTask {
+        id: iteration_id(&task.id, index),
+        name: format!("{} [{}]", task.name, index),
+        function,
+        next_tasks: Vec::new(),
+        is_root: false,
+        when: None,
+        for_each: None,
+        speculative: None,
+        ..task.clone()
+    })
+}
+
+fn fill_item(value: &Value, index: usize, item: &Value) -> Result<Value, String> {
+    Ok(match value {
+        Value::String(text) => Value::String(fill_string(text, index, item)?),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|value| fill_item(value, index, item))
+                .collect::<Result<_, _>>()?,
+        ),
+        Value::Object(object) => Value::Object(
+            object
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), fill_item(value, index, item)?)))
+                .collect::<Result<_, String>>()?,
+        ),
+        other => other.clone(),
+    })
+}
+
+/// Fill in the loop placeholders of `text`, leaving any other placeholder
+/// for the handler.
+fn fill_string(text: &str, index: usize, item: &Value) -> Result<String, String> {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}") else {
+            break;
+        };
+        let placeholder = &rest[start..start + end + 2];
+        let path = placeholder[2..placeholder.len() - 2].trim();
+        let value = match path {
+            "index" => Some(Value::from(index)),
+            "item" => Some(item.clone()),
+            _ => match path.strip_prefix("item.") {
+                Some(fields) => Some(
+                    item_field(item, fields)
+                        .ok_or_else(|| format!("item {} has no field '{}'", index, fields))?,
+                ),
+                None => None,
+            },
+        };
+        output.push_str(&rest[..start]);
+        match value {
+            Some(Value::String(value)) => output.push_str(&value),
+            Some(value) => output.push_str(&value.to_string()),
+            None => output.push_str(placeholder),
+        }
+        rest = &rest[start + end + 2..];
+    }
+    output.push_str(rest);
+    Ok(output)
+}
+
+fn item_field(item: &Value, fields: &str) -> Option<Value> {
+    fields
+        .split('.')
+        .try_fold(item, |value, field| match value {
+            Value::Array(items) => field.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => value.get(field),
+        })
+        .cloned()
+}
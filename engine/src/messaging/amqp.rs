@@ -0,0 +1,132 @@
+use super::{IncomingMessage, MessageConnection, MessageSource, Settle};
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use lapin::acker::Acker;
+use lapin::options::{
+    BasicAckOptions, BasicConsumeOptions, BasicNackOptions, BasicPublishOptions, BasicQosOptions,
+    ConfirmSelectOptions,
+};
+use lapin::types::{AMQPValue, FieldTable};
+use lapin::{BasicProperties, Connection, ConnectionProperties, Consumer};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+pub(super) async fn publish(
+    connection: &MessageConnection,
+    exchange: &str,
+    routing_key: Option<&str>,
+    headers: &BTreeMap<String, String>,
+    payload: Vec<u8>,
+) -> Result<Value, String> {
+    let amqp_error = |e: lapin::Error| format!("AMQP publish failed: {}", e);
+    let conn = Connection::connect(&connection.connect_url()?, ConnectionProperties::default())
+        .await
+        .map_err(|e| format!("Could not connect to the AMQP broker: {}", e))?;
+    let channel = conn.create_channel().await.map_err(amqp_error)?;
+    channel
+        .confirm_select(ConfirmSelectOptions::default())
+        .await
+        .map_err(amqp_error)?;
+
+    let mut table = FieldTable::default();
+    for (key, value) in headers {
+        table.insert(
+            key.clone().into(),
+            AMQPValue::LongString(value.clone().into()),
+        );
+    }
+    let confirmation = channel
+        .basic_publish(
+            exchange,
+            routing_key.unwrap_or_default(),
+            BasicPublishOptions::default(),
+            &payload,
+            BasicProperties::default().with_headers(table),
+        )
+        .await
+        .map_err(amqp_error)?
+        .await
+        .map_err(amqp_error)?;
+    let _ = conn.close(200, "OK").await;
+
+    if !confirmation.is_ack() {
+        return Err("the AMQP broker rejected the message".to_string());
+    }
+    Ok(serde_json::json!({ "acked": true }))
+}
+
+pub(super) async fn subscribe(
+    connection: &MessageConnection,
+    queue: &str,
+    group: &str,
+    prefetch: usize,
+) -> Result<Box<dyn MessageSource>, String> {
+    let amqp_error = |e: lapin::Error| format!("Could not consume from '{}': {}", queue, e);
+    let conn = Connection::connect(&connection.connect_url()?, ConnectionProperties::default())
+        .await
+        .map_err(|e| format!("Could not connect to the AMQP broker: {}", e))?;
+    let channel = conn.create_channel().await.map_err(amqp_error)?;
+    channel
+        .basic_qos(
+            prefetch.min(u16::MAX as usize) as u16,
+            BasicQosOptions::default(),
+        )
+        .await
+        .map_err(amqp_error)?;
+    let consumer = channel
+        .basic_consume(
+            queue,
+            group,
+            BasicConsumeOptions::default(),
+            FieldTable::default(),
+        )
+        .await
+        .map_err(amqp_error)?;
+    Ok(Box::new(AmqpSource {
+        _connection: conn,
+        consumer,
+    }))
+}
+
+struct AmqpSource {
+    // Closing the connection would end the consumer.
+    _connection: Connection,
+    consumer: Consumer,
+}
+
+#[async_trait]
+impl MessageSource for AmqpSource {
+    async fn next_message(&mut self) -> Result<Option<IncomingMessage>, String> {
+        let Some(delivery) = self.consumer.next().await else {
+            return Ok(None);
+        };
+        let delivery = delivery.map_err(|e| format!("AMQP receive failed: {}", e))?;
+        Ok(Some(IncomingMessage::new(
+            delivery.data,
+            Box::new(AmqpAcker(delivery.acker)),
+        )))
+    }
+}
+
+struct AmqpAcker(Acker);
+
+#[async_trait]
+impl Settle for AmqpAcker {
+    async fn ack(self: Box<Self>) -> Result<(), String> {
+        self.0
+            .ack(BasicAckOptions::default())
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// Not requeued, so a queue with a dead-letter exchange moves it there.
+    async fn reject(self: Box<Self>) -> Result<(), String> {
+        self.0
+            .nack(BasicNackOptions {
+                requeue: false,
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,135 @@
+use super::{IncomingMessage, MessageConnection, MessageSource, Settle};
+use async_trait::async_trait;
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+use rdkafka::message::{Header, Message, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use rdkafka::{Offset, TopicPartitionList};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+const SEND_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn client_config(connection: &MessageConnection) -> Result<ClientConfig, String> {
+    let (servers, credentials) = connection.kafka_servers()?;
+    let mut config = ClientConfig::new();
+    config.set("bootstrap.servers", &servers);
+    if let Some((user, password)) = credentials {
+        config
+            .set("security.protocol", "SASL_SSL")
+            .set("sasl.mechanisms", "PLAIN")
+            .set("sasl.username", user)
+            .set("sasl.password", password);
+    }
+    Ok(config)
+}
+
+pub(super) async fn publish(
+    connection: &MessageConnection,
+    topic: &str,
+    key: Option<&str>,
+    headers: &BTreeMap<String, String>,
+    payload: Vec<u8>,
+) -> Result<Value, String> {
+    let producer: FutureProducer = client_config(connection)?
+        .set("message.timeout.ms", SEND_TIMEOUT.as_millis().to_string())
+        .create()
+        .map_err(|e| format!("Could not create Kafka producer: {}", e))?;
+
+    let headers = headers
+        .iter()
+        .fold(OwnedHeaders::new(), |all, (key, value)| {
+            all.insert(Header {
+                key,
+                value: Some(value),
+            })
+        });
+    let mut record = FutureRecord::<str, [u8]>::to(topic)
+        .payload(&payload)
+        .headers(headers);
+    if let Some(key) = key {
+        record = record.key(key);
+    }
+    let (partition, offset) = producer
+        .send(record, SEND_TIMEOUT)
+        .await
+        .map_err(|(e, _)| format!("Kafka did not accept the message: {}", e))?;
+    Ok(serde_json::json!({ "partition": partition, "offset": offset }))
+}
+
+pub(super) async fn subscribe(
+    connection: &MessageConnection,
+    topic: &str,
+    group: &str,
+) -> Result<Box<dyn MessageSource>, String> {
+    let consumer: StreamConsumer = client_config(connection)?
+        .set("group.id", group)
+        .set("enable.auto.commit", "false")
+        .set("auto.offset.reset", "earliest")
+        .create()
+        .map_err(|e| format!("Could not create Kafka consumer: {}", e))?;
+    consumer
+        .subscribe(&[topic])
+        .map_err(|e| format!("Could not subscribe to '{}': {}", topic, e))?;
+    Ok(Box::new(KafkaSource {
+        consumer: Arc::new(consumer),
+    }))
+}
+
+struct KafkaSource {
+    consumer: Arc<StreamConsumer>,
+}
+
+#[async_trait]
+impl MessageSource for KafkaSource {
+    async fn next_message(&mut self) -> Result<Option<IncomingMessage>, String> {
+        let message = self
+            .consumer
+            .recv()
+            .await
+            .map_err(|e| format!("Kafka receive failed: {}", e))?;
+        let mut next = TopicPartitionList::new();
+        next.add_partition_offset(
+            message.topic(),
+            message.partition(),
+            Offset::Offset(message.offset() + 1),
+        )
+        .map_err(|e| e.to_string())?;
+        let payload = message.payload().unwrap_or_default().to_vec();
+        Ok(Some(IncomingMessage::new(
+            payload,
+            Box::new(KafkaOffset {
+                consumer: Arc::clone(&self.consumer),
+                next,
+            }),
+        )))
+    }
+}
+
+/// Kafka can't drop or requeue a single message, so both ways of settling
+/// commit the offset past it.
+struct KafkaOffset {
+    consumer: Arc<StreamConsumer>,
+    next: TopicPartitionList,
+}
+
+impl KafkaOffset {
+    fn commit(&self) -> Result<(), String> {
+        self.consumer
+            .commit(&self.next, CommitMode::Async)
+            .map_err(|e| format!("Could not commit Kafka offset: {}", e))
+    }
+}
+
+#[async_trait]
+impl Settle for KafkaOffset {
+    async fn ack(self: Box<Self>) -> Result<(), String> {
+        self.commit()
+    }
+
+    async fn reject(self: Box<Self>) -> Result<(), String> {
+        self.commit()
+    }
+}
@@ -0,0 +1,277 @@
+//! Named message broker connections for `publish_message` tasks and message
+//! triggers, payload templating, and the broker clients.
+//!
+//! Each broker client sits behind a cargo feature (`kafka`, `nats`, `amqp`) so
+//! builds that don't use messaging don't carry the client libraries. Using a
+//! broker whose feature is off fails with a message saying so.
+
+#[cfg(feature = "amqp")]
+mod amqp;
+#[cfg(feature = "kafka")]
+mod kafka;
+#[cfg(feature = "nats")]
+mod nats;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageBackend {
+    Kafka,
+    Nats,
+    Amqp,
+}
+
+impl MessageBackend {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageBackend::Kafka => "kafka",
+            MessageBackend::Nats => "nats",
+            MessageBackend::Amqp => "amqp",
+        }
+    }
+
+    /// Whether this build includes the client for the broker.
+    pub fn is_available(&self) -> bool {
+        match self {
+            MessageBackend::Kafka => cfg!(feature = "kafka"),
+            MessageBackend::Nats => cfg!(feature = "nats"),
+            MessageBackend::Amqp => cfg!(feature = "amqp"),
+        }
+    }
+}
+
+impl std::fmt::Display for MessageBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageConnection {
+    pub name: String,
+    pub backend: MessageBackend,
+    /// `nats://user@host:4222`, `amqp://user@host:5672/vhost`, or for Kafka the
+    /// bootstrap servers, `user@broker1:9092,broker2:9092` when using SASL.
+    pub url: String,
+    /// Environment variable holding the password, read when publishing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_env: Option<String>,
+}
+
+impl MessageConnection {
+    fn password(&self) -> Result<Option<String>, String> {
+        let Some(var) = &self.password_env else {
+            return Ok(None);
+        };
+        std::env::var(var).map(Some).map_err(|_| {
+            format!(
+                "connection '{}' reads its password from {}, which is not set",
+                self.name, var
+            )
+        })
+    }
+
+    /// The NATS or AMQP URL to connect with, password included.
+    pub fn connect_url(&self) -> Result<String, String> {
+        match self.password()? {
+            None => Ok(self.url.clone()),
+            Some(password) => crate::db::with_password(&self.url, &password)
+                .ok_or_else(|| format!("connection '{}' has no user name in its URL", self.name)),
+        }
+    }
+
+    /// Kafka bootstrap servers, and the SASL user name and password if set.
+    pub fn kafka_servers(&self) -> Result<(String, Option<(String, String)>), String> {
+        let password = self.password()?;
+        match (self.url.split_once('@'), password) {
+            (Some((user, servers)), Some(password)) => {
+                Ok((servers.to_string(), Some((user.to_string(), password))))
+            }
+            (None, Some(_)) => Err(format!(
+                "connection '{}' has a password but no user name; write the servers as user@host:port",
+                self.name
+            )),
+            (Some((_, servers)), None) => Ok((servers.to_string(), None)),
+            (None, None) => Ok((self.url.clone(), None)),
+        }
+    }
+}
+
+/// Fill in `{{param.NAME}}` and `{{data.TASK_ID.field}}` placeholders in every
+/// string inside `template`. A string that is nothing but one placeholder is
+/// replaced by the value itself, so numbers and objects keep their type.
+pub fn render_template(
+    template: &Value,
+    params: &Map<String, Value>,
+    data: &Value,
+) -> Result<Value, String> {
+    match template {
+        Value::String(text) => render_string(text, params, data),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| render_template(item, params, data))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        Value::Object(object) => object
+            .iter()
+            .map(|(key, value)| Ok((key.clone(), render_template(value, params, data)?)))
+            .collect::<Result<Map<_, _>, String>>()
+            .map(Value::Object),
+        other => Ok(other.clone()),
+    }
+}
+
+fn render_string(text: &str, params: &Map<String, Value>, data: &Value) -> Result<Value, String> {
+    if let Some(path) = text
+        .strip_prefix("{{")
+        .and_then(|rest| rest.strip_suffix("}}"))
+        .filter(|path| !path.contains("{{"))
+    {
+        return lookup(path.trim(), params, data).cloned();
+    }
+
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}") else {
+            break;
+        };
+        output.push_str(&rest[..start]);
+        match lookup(rest[start + 2..start + end].trim(), params, data)? {
+            Value::String(value) => output.push_str(value),
+            value => output.push_str(&value.to_string()),
+        }
+        rest = &rest[start + end + 2..];
+    }
+    output.push_str(rest);
+    Ok(Value::String(output))
+}
+
+fn lookup<'a>(
+    path: &str,
+    params: &'a Map<String, Value>,
+    data: &'a Value,
+) -> Result<&'a Value, String> {
+    let mut parts = path.split('.');
+    let value = match parts.next() {
+        Some("param") => {
+            let name = parts.next().unwrap_or_default();
+            params
+                .get(name)
+                .ok_or_else(|| format!("unknown workflow parameter '{}'", name))?
+        }
+        Some("data") => {
+            let task_id = parts.next().unwrap_or_default();
+            data.get(task_id)
+                .ok_or_else(|| format!("no output from task '{}' yet", task_id))?
+        }
+        _ => return Err(format!("unknown placeholder '{{{{{}}}}}'", path)),
+    };
+    parts.try_fold(value, |value, field| {
+        match value {
+            Value::Array(items) => field.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => value.get(field),
+        }
+        .ok_or_else(|| format!("'{}' has no field '{}'", path, field))
+    })
+}
+
+/// Publish `payload` and wait for the broker to confirm it, returning what the
+/// broker reported. `key` is the Kafka message key or the AMQP routing key;
+/// NATS has no use for it.
+#[cfg_attr(
+    not(all(feature = "kafka", feature = "nats", feature = "amqp")),
+    allow(unused_variables)
+)]
+pub async fn publish(
+    connection: &MessageConnection,
+    destination: &str,
+    key: Option<&str>,
+    headers: &BTreeMap<String, String>,
+    payload: Vec<u8>,
+) -> Result<Value, String> {
+    match connection.backend {
+        #[cfg(feature = "kafka")]
+        MessageBackend::Kafka => {
+            kafka::publish(connection, destination, key, headers, payload).await
+        }
+        #[cfg(feature = "nats")]
+        MessageBackend::Nats => nats::publish(connection, destination, headers, payload).await,
+        #[cfg(feature = "amqp")]
+        MessageBackend::Amqp => amqp::publish(connection, destination, key, headers, payload).await,
+        #[allow(unreachable_patterns)]
+        backend => Err(format!(
+            "this build has no {} support; rebuild with the '{}' feature",
+            backend, backend
+        )),
+    }
+}
+
+/// How a consumer settles a message it took from a queue.
+#[async_trait]
+pub trait Settle: Send {
+    /// The message was handled and must not be delivered again.
+    async fn ack(self: Box<Self>) -> Result<(), String>;
+    /// The message can't be handled. It is dropped, or moved to the broker's
+    /// own dead-letter queue where one is set up.
+    async fn reject(self: Box<Self>) -> Result<(), String>;
+}
+
+/// A message taken from a queue. It stays unsettled until `ack` or `reject`.
+pub struct IncomingMessage {
+    pub payload: Vec<u8>,
+    settle: Box<dyn Settle>,
+}
+
+impl IncomingMessage {
+    pub fn new(payload: Vec<u8>, settle: Box<dyn Settle>) -> Self {
+        Self { payload, settle }
+    }
+
+    pub async fn ack(self) -> Result<(), String> {
+        self.settle.ack().await
+    }
+
+    pub async fn reject(self) -> Result<(), String> {
+        self.settle.reject().await
+    }
+}
+
+#[async_trait]
+pub trait MessageSource: Send {
+    /// Waits for the next message. `Ok(None)` means the subscription ended.
+    async fn next_message(&mut self) -> Result<Option<IncomingMessage>, String>;
+}
+
+/// Start consuming `source`: a Kafka topic, a NATS JetStream stream, or an
+/// AMQP queue. `group` names the consumer group or durable consumer, so
+/// several listeners share the work and a restarted one picks up where it
+/// stopped. At most `prefetch` messages are handed out unsettled.
+#[cfg_attr(
+    not(all(feature = "kafka", feature = "nats", feature = "amqp")),
+    allow(unused_variables)
+)]
+pub async fn subscribe(
+    connection: &MessageConnection,
+    source: &str,
+    group: &str,
+    prefetch: usize,
+) -> Result<Box<dyn MessageSource>, String> {
+    match connection.backend {
+        #[cfg(feature = "kafka")]
+        MessageBackend::Kafka => kafka::subscribe(connection, source, group).await,
+        #[cfg(feature = "nats")]
+        MessageBackend::Nats => nats::subscribe(connection, source, group, prefetch).await,
+        #[cfg(feature = "amqp")]
+        MessageBackend::Amqp => amqp::subscribe(connection, source, group, prefetch).await,
+        #[allow(unreachable_patterns)]
+        backend => Err(format!(
+            "this build has no {} support; rebuild with the '{}' feature",
+            backend, backend
+        )),
+    }
+}
@@ -0,0 +1,104 @@
+use super::{IncomingMessage, MessageConnection, MessageSource, Settle};
+use async_nats::jetstream::consumer::{pull, AckPolicy};
+use async_nats::jetstream::message::Acker;
+use async_nats::jetstream::AckKind;
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+pub(super) async fn publish(
+    connection: &MessageConnection,
+    subject: &str,
+    headers: &BTreeMap<String, String>,
+    payload: Vec<u8>,
+) -> Result<Value, String> {
+    let client = async_nats::connect(connection.connect_url()?)
+        .await
+        .map_err(|e| format!("Could not connect to NATS: {}", e))?;
+    let mut header_map = async_nats::HeaderMap::new();
+    for (key, value) in headers {
+        header_map.insert(key.as_str(), value.as_str());
+    }
+    client
+        .publish_with_headers(subject.to_string(), header_map, payload.into())
+        .await
+        .map_err(|e| format!("NATS publish failed: {}", e))?;
+    // Core NATS has no per-message acks; a completed flush means the
+    // server has the message.
+    client
+        .flush()
+        .await
+        .map_err(|e| format!("NATS did not confirm the message: {}", e))?;
+    Ok(serde_json::json!({ "flushed": true }))
+}
+
+/// Consume a JetStream stream through a durable pull consumer named `group`,
+/// created on first use.
+pub(super) async fn subscribe(
+    connection: &MessageConnection,
+    stream: &str,
+    group: &str,
+    prefetch: usize,
+) -> Result<Box<dyn MessageSource>, String> {
+    let client = async_nats::connect(connection.connect_url()?)
+        .await
+        .map_err(|e| format!("Could not connect to NATS: {}", e))?;
+    let consumer = async_nats::jetstream::new(client)
+        .get_stream(stream)
+        .await
+        .map_err(|e| format!("Could not open JetStream stream '{}': {}", stream, e))?
+        .get_or_create_consumer(
+            group,
+            pull::Config {
+                durable_name: Some(group.to_string()),
+                ack_policy: AckPolicy::Explicit,
+                max_ack_pending: prefetch as i64,
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(|e| format!("Could not create consumer '{}': {}", group, e))?;
+    let messages = consumer
+        .messages()
+        .await
+        .map_err(|e| format!("Could not consume from '{}': {}", stream, e))?;
+    Ok(Box::new(NatsSource { messages }))
+}
+
+struct NatsSource {
+    messages: pull::Stream,
+}
+
+#[async_trait]
+impl MessageSource for NatsSource {
+    async fn next_message(&mut self) -> Result<Option<IncomingMessage>, String> {
+        let Some(message) = self.messages.next().await else {
+            return Ok(None);
+        };
+        let (message, acker) = message
+            .map_err(|e| format!("NATS receive failed: {}", e))?
+            .split();
+        Ok(Some(IncomingMessage::new(
+            message.payload.to_vec(),
+            Box::new(NatsAcker(acker)),
+        )))
+    }
+}
+
+struct NatsAcker(Acker);
+
+#[async_trait]
+impl Settle for NatsAcker {
+    async fn ack(self: Box<Self>) -> Result<(), String> {
+        self.0.ack().await.map_err(|e| e.to_string())
+    }
+
+    /// Terminates delivery; JetStream won't redeliver the message.
+    async fn reject(self: Box<Self>) -> Result<(), String> {
+        self.0
+            .ack_with(AckKind::Term)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,165 @@
+//! Middleware wrapped around every task handler call, for concerns that apply
+//! to tasks of any type instead of living in each handler.
+//!
+//! Middlewares run in the order they were added to the engine: the first one
+//! sees the task first and the result last. Results served from the task
+//! result cache never reach the chain.
+
+use crate::errors::HandlerError;
+use crate::handlers::environment::REDACTED;
+use crate::handlers::TaskHandler;
+use crate::types::{EngineTask, ExecutionContext, TaskResult};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::debug;
+
+#[async_trait]
+pub trait TaskMiddleware: Send + Sync {
+    /// Handle `task`, usually by calling `next.run` and looking at or changing
+    /// what it returns. Not calling `next` skips the handler altogether.
+    async fn handle(
+        &self,
+        context: &mut ExecutionContext,
+        task: &EngineTask,
+        next: Next<'_>,
+    ) -> Result<TaskResult, HandlerError>;
+}
+
+/// The rest of the chain after the current middleware, ending in the handler.
+pub struct Next<'a> {
+    middlewares: &'a [Arc<dyn TaskMiddleware>],
+    handler: &'a dyn TaskHandler,
+}
+
+impl<'a> Next<'a> {
+    pub fn new(middlewares: &'a [Arc<dyn TaskMiddleware>], handler: &'a dyn TaskHandler) -> Self {
+        Self {
+            middlewares,
+            handler,
+        }
+    }
+
+    pub async fn run(
+        self,
+        context: &mut ExecutionContext,
+        task: &EngineTask,
+    ) -> Result<TaskResult, HandlerError> {
+        match self.middlewares.split_first() {
+            Some((middleware, rest)) => {
+                middleware
+                    .handle(context, task, Next::new(rest, self.handler))
+                    .await
+            }
+            None => self.handler.execute(context, task).await,
+        }
+    }
+
+    /// Copy of the remaining chain, so a middleware can run it more than once.
+    pub fn duplicate(&self) -> Next<'a> {
+        Next::new(self.middlewares, self.handler)
+    }
+}
+
+/// Run a failed task again, up to `max_attempts` runs in all, waiting `delay`
+/// between runs. Tasks that paused for input are not retried.
+pub struct RetryMiddleware {
+    pub max_attempts: u32,
+    pub delay: Duration,
+}
+
+#[async_trait]
+impl TaskMiddleware for RetryMiddleware {
+    async fn handle(
+        &self,
+        context: &mut ExecutionContext,
+        task: &EngineTask,
+        next: Next<'_>,
+    ) -> Result<TaskResult, HandlerError> {
+        let mut attempt = 1;
+        loop {
+            let result = next.duplicate().run(context, task).await;
+            let failed = match &result {
+                Ok(result) => !result.success,
+                Err(_) => true,
+            };
+            if !failed || attempt >= self.max_attempts {
+                return result;
+            }
+            debug!(
+                execution_id = %context.execution_id,
+                task_id = %task.id,
+                attempt = attempt,
+                max_attempts = self.max_attempts,
+                "Task failed, retrying"
+            );
+            context.log_task(
+                task.id.clone(),
+                format!(
+                    "Attempt {} of {} failed, retrying",
+                    attempt, self.max_attempts
+                ),
+            );
+            tokio::time::sleep(self.delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Replace every occurrence of the given values in a task's output and error
+/// with `[redacted]`, e.g. tokens a command might echo. Lines already streamed
+/// live while the task ran are not covered.
+pub struct RedactMiddleware {
+    values: Vec<String>,
+}
+
+impl RedactMiddleware {
+    /// Empty values are ignored; they would match everywhere.
+    pub fn new(values: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            values: values
+                .into_iter()
+                .filter(|value| !value.is_empty())
+                .collect(),
+        }
+    }
+
+    pub fn redact_text(&self, text: &str) -> String {
+        self.values.iter().fold(text.to_string(), |text, value| {
+            text.replace(value, REDACTED)
+        })
+    }
+
+    pub fn redact_value(&self, value: &mut Value) {
+        match value {
+            Value::String(text) => *text = self.redact_text(text),
+            Value::Array(items) => items.iter_mut().for_each(|item| self.redact_value(item)),
+            Value::Object(object) => object.values_mut().for_each(|item| self.redact_value(item)),
+            _ => {}
+        }
+    }
+}
+
+#[async_trait]
+impl TaskMiddleware for RedactMiddleware {
+    async fn handle(
+        &self,
+        context: &mut ExecutionContext,
+        task: &EngineTask,
+        next: Next<'_>,
+    ) -> Result<TaskResult, HandlerError> {
+        let mut result = next.run(context, task).await.map_err(|e| match e {
+            HandlerError::ExecutionFailed(message) => {
+                HandlerError::ExecutionFailed(self.redact_text(&message))
+            }
+            HandlerError::HandlerNotFound(message) => HandlerError::HandlerNotFound(message),
+            HandlerError::InvalidConfiguration(message) => {
+                HandlerError::InvalidConfiguration(self.redact_text(&message))
+            }
+        })?;
+        self.redact_value(&mut result.output);
+        result.error = result.error.map(|error| self.redact_text(&error));
+        Ok(result)
+    }
+}
@@ -0,0 +1,77 @@
+//! Workflow `notify` rules: which tasks someone wants to hear about, and
+//! when, e.g. only when the deploy step fails. The engine parses and matches
+//! them; what a match turns into is up to the app running the workflow.
+
+use glob::Pattern;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// When a task a [`NotifyRule`] covers is worth a notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "on", rename_all = "snake_case")]
+pub enum NotifyOn {
+    Failure,
+    Success,
+    /// The task ran longer than this, whether or not it succeeded.
+    Slow {
+        longer_than_seconds: u64,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotifyRule {
+    /// Task ids the rule covers. `*` and `?` match as in file globs, so
+    /// `deploy-*` covers every task whose id starts with `deploy-`.
+    pub tasks: Vec<String>,
+    #[serde(flatten)]
+    pub on: NotifyOn,
+}
+
+impl NotifyRule {
+    pub fn covers(&self, task_id: &str) -> bool {
+        self.tasks
+            .iter()
+            .any(|pattern| Pattern::new(pattern).is_ok_and(|pattern| pattern.matches(task_id)))
+    }
+
+    /// Whether a task it covers that ended this way fires the rule.
+    pub fn fires(&self, success: bool, duration: Duration) -> bool {
+        match self.on {
+            NotifyOn::Failure => !success,
+            NotifyOn::Success => success,
+            NotifyOn::Slow {
+                longer_than_seconds,
+            } => duration > Duration::from_secs(longer_than_seconds),
+        }
+    }
+}
+
+/// Check that every rule names at least one task, its patterns are valid
+/// globs and a `slow` threshold is at least a second. Patterns that match no
+/// task are allowed, so removing a task does not break the workflow.
+pub fn validate_notify(rules: &[NotifyRule]) -> Result<(), String> {
+    for (index, rule) in rules.iter().enumerate() {
+        if rule.tasks.is_empty() {
+            return Err(format!("rule {} names no tasks", index));
+        }
+        for pattern in &rule.tasks {
+            if pattern.trim().is_empty() {
+                return Err(format!("rule {} has an empty task pattern", index));
+            }
+            Pattern::new(pattern)
+                .map_err(|e| format!("rule {} pattern '{}' is invalid: {}", index, pattern, e))?;
+        }
+        if matches!(
+            rule.on,
+            NotifyOn::Slow {
+                longer_than_seconds: 0
+            }
+        ) {
+            return Err(format!(
+                "rule {} longer_than_seconds must be a positive integer",
+                index
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! The `outputs` of a workflow: named values picked from the context once the
+//! run ends, such as
+//! `"outputs": { "version": "${data.build.version}", "target": "${param.env}" }`.
+//! They come back in `WorkflowResult::outputs`, so callers get a few stable
+//! values instead of reading them out of task outputs.
+//!
+//! Each value is filled in like a `custom` task input: a value that is a single
+//! placeholder keeps its type, anything else becomes text. One that cannot be
+//! filled in, e.g. because its task failed or was skipped, comes back as `null`.
+
+use crate::interpolation::{
+    check_placeholders, interpolate_value, referenced_params, referenced_tasks,
+};
+use crate::types::{EngineTask, WorkflowParameter};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use tracing::debug;
+
+/// Check output names and placeholders, and that each placeholder names a task
+/// or parameter of the workflow.
+pub fn validate_outputs(
+    outputs: &BTreeMap<String, Value>,
+    tasks: &[EngineTask],
+    parameters: &[WorkflowParameter],
+) -> Result<(), String> {
+    for (name, value) in outputs {
+        if name.trim().is_empty() {
+            return Err("output names must not be empty".to_string());
+        }
+        check_placeholders(value).map_err(|e| format!("output '{}': {}", name, e))?;
+        if let Some(task) = referenced_tasks(value)
+            .into_iter()
+            .find(|id| !tasks.iter().any(|task| task.id == *id))
+        {
+            return Err(format!("output '{}' reads unknown task '{}'", name, task));
+        }
+        if let Some(param) = referenced_params(value)
+            .into_iter()
+            .find(|param| !parameters.iter().any(|p| p.name == *param))
+        {
+            return Err(format!(
+                "output '{}' reads unknown parameter '{}'",
+                name, param
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The declared `outputs`, filled in from `params` and the final `data`.
+pub fn map_outputs(
+    outputs: &BTreeMap<String, Value>,
+    params: &Map<String, Value>,
+    data: &Value,
+) -> BTreeMap<String, Value> {
+    outputs
+        .iter()
+        .map(|(name, value)| {
+            let value = interpolate_value(value, params, data).unwrap_or_else(|e| {
+                debug!(output = %name, error = %e, "Output left empty");
+                Value::Null
+            });
+            (name.clone(), value)
+        })
+        .collect()
+}
@@ -0,0 +1,246 @@
+use crate::errors::ParameterError;
+use crate::types::{ParameterType, WorkflowParameter};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+
+/// Stands in for the value of a `secret` parameter wherever it would show.
+pub const SECRET_MASK: &str = "********";
+
+/// Convert a raw string (from a command line or text field) into a typed parameter value.
+pub fn parse_parameter_value(
+    parameter: &WorkflowParameter,
+    raw: &str,
+) -> Result<Value, ParameterError> {
+    let invalid = || ParameterError::InvalidValue {
+        name: parameter.name.clone(),
+        expected: parameter.param_type.as_str().to_string(),
+        value: shown_value(parameter, raw.to_string()),
+    };
+
+    let value = match parameter.param_type {
+        ParameterType::String
+        | ParameterType::File
+        | ParameterType::Directory
+        | ParameterType::Glob => Value::String(raw.to_string()),
+        ParameterType::Number => {
+            let trimmed = raw.trim();
+            match trimmed.parse::<i64>() {
+                Ok(integer) => Value::from(integer),
+                Err(_) => trimmed
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(serde_json::Number::from_f64)
+                    .map(Value::Number)
+                    .ok_or_else(invalid)?,
+            }
+        }
+        ParameterType::Boolean => match raw.trim().to_ascii_lowercase().as_str() {
+            "true" | "yes" | "y" | "1" => Value::Bool(true),
+            "false" | "no" | "n" | "0" => Value::Bool(false),
+            _ => return Err(invalid()),
+        },
+    };
+    check_options(parameter, value)
+}
+
+/// Refuse a value that is not one of the parameter's `options`, when it has any.
+fn check_options(parameter: &WorkflowParameter, value: Value) -> Result<Value, ParameterError> {
+    if parameter.options.is_empty() || parameter.options.contains(&value) {
+        return Ok(value);
+    }
+    let options: Vec<String> = parameter
+        .options
+        .iter()
+        .map(|option| match option {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })
+        .collect();
+    Err(ParameterError::InvalidValue {
+        name: parameter.name.clone(),
+        expected: format!("one of {}", options.join(", ")),
+        value: shown_value(
+            parameter,
+            match value {
+                Value::String(s) => s,
+                other => other.to_string(),
+            },
+        ),
+    })
+}
+
+/// How a value appears in an error: as it is, or masked for a secret.
+fn shown_value(parameter: &WorkflowParameter, value: String) -> String {
+    if parameter.secret {
+        SECRET_MASK.to_string()
+    } else {
+        value
+    }
+}
+
+/// Text of each given secret parameter value, as it would appear in a
+/// command or in output, for redacting.
+pub fn secret_values(
+    parameters: &[WorkflowParameter],
+    values: &HashMap<String, Value>,
+) -> Vec<String> {
+    parameters
+        .iter()
+        .filter(|parameter| parameter.secret)
+        .filter_map(|parameter| values.get(&parameter.name))
+        .map(|value| match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })
+        .collect()
+}
+
+/// Required parameters that have neither a provided value nor a default.
+pub fn missing_parameters<'a>(
+    parameters: &'a [WorkflowParameter],
+    provided: &HashMap<String, Value>,
+) -> Vec<&'a WorkflowParameter> {
+    parameters
+        .iter()
+        .filter(|p| p.required && p.default.is_none() && !provided.contains_key(&p.name))
+        .collect()
+}
+
+/// Check provided values against the workflow's parameters and fill in defaults.
+///
+/// String values given for number or boolean parameters are coerced, so callers can
+/// pass raw user input straight through. Path parameters are checked against the
+/// file system and made absolute, see [`resolve_path`].
+pub fn resolve_parameters(
+    parameters: &[WorkflowParameter],
+    provided: &HashMap<String, Value>,
+) -> Result<Map<String, Value>, ParameterError> {
+    if let Some(unknown) = provided
+        .keys()
+        .find(|name| !parameters.iter().any(|p| &p.name == *name))
+    {
+        return Err(ParameterError::Unknown(unknown.clone()));
+    }
+
+    let mut resolved = Map::new();
+    for parameter in parameters {
+        let value = match provided.get(&parameter.name) {
+            Some(value) => coerce(parameter, value)?,
+            None => match &parameter.default {
+                Some(default) => default.clone(),
+                None if parameter.required => {
+                    return Err(ParameterError::Missing(parameter.name.clone()))
+                }
+                None => continue,
+            },
+        };
+        let value = if parameter.param_type.is_path() {
+            resolve_path(parameter, value)?
+        } else {
+            value
+        };
+        resolved.insert(parameter.name.clone(), value);
+    }
+
+    Ok(resolved)
+}
+
+fn coerce(parameter: &WorkflowParameter, value: &Value) -> Result<Value, ParameterError> {
+    match (parameter.param_type, value) {
+        (
+            ParameterType::String
+            | ParameterType::File
+            | ParameterType::Directory
+            | ParameterType::Glob,
+            Value::String(_),
+        )
+        // A glob already expanded, e.g. when a paused run resumes.
+        | (ParameterType::Glob, Value::Array(_))
+        | (ParameterType::Number, Value::Number(_))
+        | (ParameterType::Boolean, Value::Bool(_)) => check_options(parameter, value.clone()),
+        (_, Value::String(raw)) => parse_parameter_value(parameter, raw),
+        _ => Err(ParameterError::InvalidValue {
+            name: parameter.name.clone(),
+            expected: parameter.param_type.as_str().to_string(),
+            value: shown_value(parameter, value.to_string()),
+        }),
+    }
+}
+
+/// Check that what a `file`, `directory` or `glob` value names exists and can
+/// be read, and turn it into absolute paths: one for a file or directory, the
+/// sorted list of matches for a glob. Relative paths are taken from the
+/// current directory.
+fn resolve_path(parameter: &WorkflowParameter, value: Value) -> Result<Value, ParameterError> {
+    match (parameter.param_type, value) {
+        (ParameterType::Glob, Value::String(pattern)) => expand_glob(parameter, &pattern),
+        (ParameterType::Glob, Value::Array(paths)) => paths
+            .into_iter()
+            .map(|path| match path {
+                Value::String(path) => readable_path(parameter, &path).map(Value::String),
+                other => Err(invalid_path(parameter, &other.to_string(), "is not a path")),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        (_, Value::String(path)) => readable_path(parameter, &path).map(Value::String),
+        (_, other) => Err(invalid_path(parameter, &other.to_string(), "is not a path")),
+    }
+}
+
+fn expand_glob(parameter: &WorkflowParameter, pattern: &str) -> Result<Value, ParameterError> {
+    let entries = glob::glob(pattern).map_err(|e| {
+        invalid_path(
+            parameter,
+            pattern,
+            &format!("is not a valid pattern: {}", e.msg),
+        )
+    })?;
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|e| {
+            invalid_path(
+                parameter,
+                &e.path().to_string_lossy(),
+                &format!("cannot be read: {}", e.error()),
+            )
+        })?;
+        paths.push(readable_path(parameter, &path.to_string_lossy())?);
+    }
+    if paths.is_empty() {
+        return Err(invalid_path(parameter, pattern, "matches no paths"));
+    }
+    paths.sort();
+    paths.dedup();
+    Ok(Value::Array(paths.into_iter().map(Value::String).collect()))
+}
+
+/// The canonical absolute form of `path`, once it is known to be the kind of
+/// entry the parameter asks for and readable by this process.
+fn readable_path(parameter: &WorkflowParameter, path: &str) -> Result<String, ParameterError> {
+    let canonical = dunce::canonicalize(path).map_err(|e| match e.kind() {
+        ErrorKind::NotFound => invalid_path(parameter, path, "does not exist"),
+        _ => invalid_path(parameter, path, &format!("cannot be resolved: {}", e)),
+    })?;
+    let readable = match (parameter.param_type, canonical.is_dir()) {
+        (ParameterType::File, true) => {
+            return Err(invalid_path(parameter, path, "is a directory, not a file"))
+        }
+        (ParameterType::Directory, false) => {
+            return Err(invalid_path(parameter, path, "is not a directory"))
+        }
+        (_, true) => fs::read_dir(&canonical).map(|_| ()),
+        (_, false) => fs::File::open(&canonical).map(|_| ()),
+    };
+    readable.map_err(|e| invalid_path(parameter, path, &format!("cannot be read: {}", e)))?;
+    Ok(canonical.to_string_lossy().into_owned())
+}
+
+fn invalid_path(parameter: &WorkflowParameter, path: &str, reason: &str) -> ParameterError {
+    ParameterError::InvalidPath {
+        name: parameter.name.clone(),
+        path: shown_value(parameter, path.to_string()),
+        reason: reason.to_string(),
+    }
+}
@@ -1,19 +1,58 @@
+use crate::condition::{path_value, Condition};
+use crate::dir_cache::is_contained;
 use crate::errors::*;
+use crate::interpolation::check_task_placeholders;
+use crate::json::{parse_document, parse_document_bytes};
+use crate::notify::{validate_notify, NotifyRule};
+use crate::outputs::validate_outputs;
+use crate::preflight::PreflightCheck;
+use crate::speculation::validate_speculation;
+use crate::stages::validate_stages;
 use crate::types::*;
+use crate::workflow_limits::{
+    check_document_size, check_workflow_limits, workflow_limits, WorkflowLimits,
+};
 use serde_json::Value;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use tracing::{debug, error, instrument, trace, warn};
 
-#[instrument]
 pub fn parse_workflow(json: &str) -> Result<EngineWorkflow, ParserError> {
+    parse_workflow_with_limits(json, &workflow_limits())
+}
+
+/// Like `parse_workflow`, but checked against `limits` instead of the process-wide ones.
+#[instrument(skip(json, limits), fields(len = json.len()))]
+pub fn parse_workflow_with_limits(
+    json: &str,
+    limits: &WorkflowLimits,
+) -> Result<EngineWorkflow, ParserError> {
     debug!("Starting workflow JSON parsing");
-    let workflow_json: Value = serde_json::from_str(json)?;
+    check_document_size(json.len(), limits)?;
+    let workflow_json = parse_document(json)?;
     trace!("JSON parsed successfully, delegating to value parser");
-    parse_workflow_from_value(&workflow_json)
+    parse_value(&workflow_json, limits)
+}
+
+/// Parse a workflow straight from file or upload bytes.
+#[instrument(skip(bytes), fields(len = bytes.len()))]
+pub fn parse_workflow_bytes(bytes: &[u8]) -> Result<EngineWorkflow, ParserError> {
+    let limits = workflow_limits();
+    check_document_size(bytes.len(), &limits)?;
+    let workflow_json = parse_document_bytes(bytes)?;
+    parse_value(&workflow_json, &limits)
 }
 
 #[instrument(skip(workflow_json))]
 pub fn parse_workflow_from_value(workflow_json: &Value) -> Result<EngineWorkflow, ParserError> {
+    parse_value(workflow_json, &workflow_limits())
+}
+
+fn parse_value(
+    workflow_json: &Value,
+    limits: &WorkflowLimits,
+) -> Result<EngineWorkflow, ParserError> {
+    check_workflow_limits(workflow_json, limits)?;
+
     let id = workflow_json
         .get("id")
         .and_then(|v| v.as_str())
@@ -70,13 +109,181 @@ pub fn parse_workflow_from_value(workflow_json: &Value) -> Result<EngineWorkflow
         "Workflow parsing completed successfully"
     );
 
+    let parameters = parse_parameters(workflow_json)?;
+    let triggers = parse_triggers(workflow_json, &id)?;
+    let workspace = parse_workspace(workflow_json)?;
+    let preflight = parse_preflight(workflow_json)?;
+    let stages = parse_stages(workflow_json)?;
+    validate_stages(&stages, &all_tasks)
+        .map_err(|e| ParserError::InvalidTask(format!("Invalid stages: {}", e)))?;
+    let outputs = parse_outputs(workflow_json)?;
+    validate_outputs(&outputs, &all_tasks, &parameters)
+        .map_err(|e| ParserError::InvalidTask(format!("Invalid outputs: {}", e)))?;
+    let notify = parse_notify(workflow_json)?;
+    validate_speculation(&all_tasks)
+        .map_err(|e| ParserError::InvalidTask(format!("Invalid speculative branch: {}", e)))?;
+
     Ok(EngineWorkflow {
         id,
         name,
         tasks: all_tasks,
+        parameters,
+        triggers,
+        workspace,
+        preflight,
+        stages,
+        outputs,
+        notify,
     })
 }
 
+fn parse_outputs(workflow_json: &Value) -> Result<BTreeMap<String, Value>, ParserError> {
+    let Some(outputs_json) = workflow_json.get("outputs") else {
+        return Ok(BTreeMap::new());
+    };
+    let outputs: BTreeMap<String, Value> = serde_json::from_value(outputs_json.clone())
+        .map_err(|e| ParserError::InvalidTask(format!("Invalid outputs: {}", e)))?;
+    debug!(output_count = outputs.len(), "Parsed outputs");
+    Ok(outputs)
+}
+
+fn parse_notify(workflow_json: &Value) -> Result<Vec<NotifyRule>, ParserError> {
+    let Some(notify_json) = workflow_json.get("notify") else {
+        return Ok(Vec::new());
+    };
+    let rules: Vec<NotifyRule> = serde_json::from_value(notify_json.clone())
+        .map_err(|e| ParserError::InvalidTask(format!("Invalid notify rules: {}", e)))?;
+    validate_notify(&rules)
+        .map_err(|e| ParserError::InvalidTask(format!("Invalid notify rules: {}", e)))?;
+    debug!(rule_count = rules.len(), "Parsed notify rules");
+    Ok(rules)
+}
+
+fn parse_stages(workflow_json: &Value) -> Result<Vec<String>, ParserError> {
+    let Some(stages_json) = workflow_json.get("stages") else {
+        return Ok(Vec::new());
+    };
+    let stages: Vec<String> = serde_json::from_value(stages_json.clone())
+        .map_err(|e| ParserError::InvalidTask(format!("Invalid stages: {}", e)))?;
+    debug!(stages = ?stages, "Parsed stages");
+    Ok(stages)
+}
+
+fn parse_preflight(workflow_json: &Value) -> Result<Vec<PreflightCheck>, ParserError> {
+    let Some(preflight_json) = workflow_json.get("preflight") else {
+        return Ok(Vec::new());
+    };
+    let checks: Vec<PreflightCheck> = serde_json::from_value(preflight_json.clone())
+        .map_err(|e| ParserError::InvalidTask(format!("Invalid preflight checks: {}", e)))?;
+    for check in &checks {
+        check
+            .validate()
+            .map_err(|e| ParserError::InvalidTask(format!("Invalid preflight check: {}", e)))?;
+    }
+    debug!(check_count = checks.len(), "Parsed preflight checks");
+    Ok(checks)
+}
+
+fn parse_workspace(workflow_json: &Value) -> Result<Option<WorkspaceOptions>, ParserError> {
+    let isolated = match workflow_json.get("isolated_workspace") {
+        None => false,
+        Some(value) => value.as_bool().ok_or_else(|| {
+            ParserError::InvalidTask("isolated_workspace must be true or false".to_string())
+        })?,
+    };
+    let template = match workflow_json.get("workspace_template") {
+        None => None,
+        Some(Value::String(path)) if !path.is_empty() => Some(path.clone()),
+        Some(_) => {
+            return Err(ParserError::InvalidTask(
+                "workspace_template must be a non-empty path".to_string(),
+            ))
+        }
+    };
+
+    if !isolated {
+        if template.is_some() {
+            return Err(ParserError::InvalidTask(
+                "workspace_template requires isolated_workspace to be true".to_string(),
+            ));
+        }
+        return Ok(None);
+    }
+    Ok(Some(WorkspaceOptions { template }))
+}
+
+fn parse_triggers(
+    workflow_json: &Value,
+    workflow_id: &str,
+) -> Result<Vec<WorkflowTrigger>, ParserError> {
+    let Some(triggers_json) = workflow_json.get("triggers") else {
+        return Ok(Vec::new());
+    };
+
+    let triggers: Vec<WorkflowTrigger> = serde_json::from_value(triggers_json.clone())
+        .map_err(|e| ParserError::InvalidTask(format!("Invalid triggers: {}", e)))?;
+
+    let triggers_itself = triggers.iter().any(|trigger| {
+        matches!(trigger, WorkflowTrigger::AfterWorkflow { workflow_id: upstream } if upstream == workflow_id)
+    });
+    if triggers_itself {
+        return Err(ParserError::InvalidTask(format!(
+            "Workflow '{}' cannot trigger itself",
+            workflow_id
+        )));
+    }
+    for trigger in &triggers {
+        if let WorkflowTrigger::Message {
+            connection,
+            source,
+            max_concurrent,
+            max_attempts,
+            ..
+        } = trigger
+        {
+            if connection.trim().is_empty() || source.trim().is_empty() {
+                return Err(ParserError::MissingField(
+                    "message trigger connection and source".to_string(),
+                ));
+            }
+            if *max_concurrent == 0 || *max_attempts == 0 {
+                return Err(ParserError::InvalidTask(
+                    "message trigger max_concurrent and max_attempts must be at least 1"
+                        .to_string(),
+                ));
+            }
+        }
+    }
+
+    debug!(trigger_count = triggers.len(), "Parsed workflow triggers");
+    Ok(triggers)
+}
+
+fn parse_parameters(workflow_json: &Value) -> Result<Vec<WorkflowParameter>, ParserError> {
+    let Some(parameters_json) = workflow_json.get("parameters") else {
+        return Ok(Vec::new());
+    };
+
+    let parameters: Vec<WorkflowParameter> = serde_json::from_value(parameters_json.clone())
+        .map_err(|e| ParserError::InvalidTask(format!("Invalid parameters: {}", e)))?;
+
+    let mut names = HashSet::new();
+    for parameter in &parameters {
+        if !names.insert(parameter.name.as_str()) {
+            return Err(ParserError::InvalidTask(format!(
+                "Duplicate parameter name: {}",
+                parameter.name
+            )));
+        }
+    }
+
+    debug!(
+        parameter_count = parameters.len(),
+        "Parsed workflow parameters"
+    );
+    Ok(parameters)
+}
+
 #[instrument(skip(all_tasks, task_ids), fields(parent_id = ?parent_id))]
 fn parse_task_recursive(
     task_json: &Value,
@@ -115,6 +322,105 @@ fn parse_task_recursive(
 
     trace!(task_id = %task_id, "Parsing task function");
     let function = parse_task_function(task_json)?;
+    check_task_placeholders(&function).map_err(|e| {
+        ParserError::InvalidTask(format!(
+            "Task {} has an invalid placeholder: {}",
+            task_id, e
+        ))
+    })?;
+    let cache = parse_task_cache(task_json, &function)?;
+    let stage = match task_json.get("stage") {
+        None | Some(Value::Null) => None,
+        Some(Value::String(stage)) => Some(stage.clone()),
+        Some(_) => {
+            return Err(ParserError::InvalidTask(format!(
+                "Task {} stage must be a string",
+                task_id
+            )))
+        }
+    };
+
+    let lock = match task_json.get("lock") {
+        None | Some(Value::Null) => None,
+        Some(Value::String(lock)) if !lock.is_empty() => Some(lock.clone()),
+        Some(_) => {
+            return Err(ParserError::InvalidTask(format!(
+                "Task {} lock must be a non-empty string",
+                task_id
+            )))
+        }
+    };
+
+    let timeout_seconds = match task_json.get("timeout_seconds") {
+        None | Some(Value::Null) => None,
+        Some(value) => match value.as_u64() {
+            Some(seconds) if seconds > 0 => Some(seconds),
+            _ => {
+                return Err(ParserError::InvalidTask(format!(
+                    "Task {} timeout_seconds must be a positive integer",
+                    task_id
+                )))
+            }
+        },
+    };
+
+    let when = match task_json.get("when") {
+        None | Some(Value::Null) => None,
+        Some(Value::String(when)) => {
+            Condition::parse(when).map_err(|e| {
+                ParserError::InvalidTask(format!("Task {} when is invalid: {}", task_id, e))
+            })?;
+            Some(when.clone())
+        }
+        Some(_) => {
+            return Err(ParserError::InvalidTask(format!(
+                "Task {} when must be a string",
+                task_id
+            )))
+        }
+    };
+
+    let for_each = match task_json.get("for_each") {
+        None | Some(Value::Null) => None,
+        Some(Value::String(for_each)) => {
+            path_value(for_each, &Default::default(), &Value::Null).map_err(|e| {
+                ParserError::InvalidTask(format!("Task {} for_each is invalid: {}", task_id, e))
+            })?;
+            if matches!(
+                function,
+                TaskFunction::UserInput { .. } | TaskFunction::Manual { .. }
+            ) {
+                return Err(ParserError::InvalidTask(format!(
+                    "Task {} waits for a person and cannot use for_each",
+                    task_id
+                )));
+            }
+            Some(for_each.trim().to_string())
+        }
+        Some(_) => {
+            return Err(ParserError::InvalidTask(format!(
+                "Task {} for_each must be a string",
+                task_id
+            )))
+        }
+    };
+
+    let speculative = match task_json.get("speculative") {
+        None | Some(Value::Null) => None,
+        Some(speculative_json) => {
+            let speculative: Speculative = serde_json::from_value(speculative_json.clone())
+                .map_err(|e| {
+                    ParserError::InvalidTask(format!(
+                        "Task {} speculative is invalid: {}",
+                        task_id, e
+                    ))
+                })?;
+            Condition::parse(&speculative.commit_when).map_err(|e| {
+                ParserError::InvalidTask(format!("Task {} commit_when is invalid: {}", task_id, e))
+            })?;
+            Some(speculative)
+        }
+    };
 
     let mut next_tasks = Vec::new();
     if let Some(next_tasks_array) = task_json.get("next_tasks").and_then(|v| v.as_array()) {
@@ -152,6 +458,13 @@ fn parse_task_recursive(
         next_tasks,
         status: TaskStatus::Pending,
         is_root: parent_id.is_none(),
+        cache,
+        stage,
+        lock,
+        timeout_seconds,
+        when,
+        for_each,
+        speculative,
     };
 
     trace!(
@@ -179,6 +492,51 @@ fn parse_task_recursive(
     Ok(task)
 }
 
+fn parse_task_cache(
+    task_json: &Value,
+    function: &TaskFunction,
+) -> Result<Option<TaskCache>, ParserError> {
+    let Some(cache_json) = task_json.get("cache") else {
+        return Ok(None);
+    };
+    let cache = match cache_json {
+        Value::Bool(false) => return Ok(None),
+        Value::Bool(true) => TaskCache {
+            ttl_seconds: TaskCache::DEFAULT_TTL_SECONDS,
+        },
+        _ => serde_json::from_value(cache_json.clone())
+            .map_err(|e| ParserError::InvalidTask(format!("Invalid cache options: {}", e)))?,
+    };
+
+    if cache.ttl_seconds == 0 {
+        return Err(ParserError::InvalidTask(
+            "cache.ttl_seconds must be greater than zero".to_string(),
+        ));
+    }
+    if matches!(function, TaskFunction::UserInput { .. }) {
+        return Err(ParserError::InvalidTask(
+            "user_input tasks cannot be cached".to_string(),
+        ));
+    }
+    if matches!(function, TaskFunction::Manual { .. }) {
+        return Err(ParserError::InvalidTask(
+            "manual tasks cannot be cached".to_string(),
+        ));
+    }
+    if matches!(function, TaskFunction::PublishMessage { .. }) {
+        return Err(ParserError::InvalidTask(
+            "publish_message tasks cannot be cached".to_string(),
+        ));
+    }
+    if matches!(function, TaskFunction::Cache { .. }) {
+        return Err(ParserError::InvalidTask(
+            "cache tasks restore directories and cannot have their result cached".to_string(),
+        ));
+    }
+
+    Ok(Some(cache))
+}
+
 #[instrument(skip(task_json), fields(task_id = ?task_json.get("id").and_then(|v| v.as_str())))]
 fn parse_task_function(task_json: &Value) -> Result<TaskFunction, ParserError> {
     trace!("Starting task function parsing");
@@ -238,6 +596,14 @@ fn parse_task_function(task_json: &Value) -> Result<TaskFunction, ParserError> {
                     Vec::new()
                 });
 
+            let process = match input.get("process") {
+                Some(value) => serde_json::from_value(value.clone()).map_err(|e| {
+                    error!(command = %command, error = %e, "Invalid process options");
+                    ParserError::InvalidTask(format!("Invalid process options: {}", e))
+                })?,
+                None => ProcessOptions::default(),
+            };
+
             debug!(
                 function_type = %function_type,
                 command = %command,
@@ -245,7 +611,11 @@ fn parse_task_function(task_json: &Value) -> Result<TaskFunction, ParserError> {
                 "Successfully parsed CLI command function"
             );
 
-            Ok(TaskFunction::CliCommand { command, args })
+            Ok(TaskFunction::CliCommand {
+                command,
+                args,
+                process,
+            })
         }
         "cursor_agent" => {
             trace!("Parsing Cursor agent function");
@@ -325,6 +695,172 @@ fn parse_task_function(task_json: &Value) -> Result<TaskFunction, ParserError> {
                 default,
             })
         }
+        "db_query" => {
+            let input = function.get("input").ok_or_else(|| {
+                error!(function_type = %function_type, "Missing input field for database query");
+                ParserError::MissingField("function.input".to_string())
+            })?;
+            let required = |field: &str| {
+                input
+                    .get(field)
+                    .and_then(|v| v.as_str())
+                    .filter(|value| !value.trim().is_empty())
+                    .map(str::to_string)
+                    .ok_or_else(|| ParserError::MissingField(format!("function.input.{}", field)))
+            };
+            let connection = required("connection")?;
+            let query = required("query")?;
+
+            let params = match input.get("params") {
+                None | Some(Value::Null) => Vec::new(),
+                Some(Value::Array(params)) => params.clone(),
+                Some(_) => {
+                    return Err(ParserError::InvalidTask(
+                        "function.input.params must be an array".to_string(),
+                    ))
+                }
+            };
+            let max_rows = match input.get("max_rows") {
+                None | Some(Value::Null) => None,
+                Some(value) => Some(value.as_u64().filter(|rows| *rows > 0).ok_or_else(|| {
+                    ParserError::InvalidTask(
+                        "function.input.max_rows must be a positive integer".to_string(),
+                    )
+                })? as usize),
+            };
+
+            debug!(
+                function_type = %function_type,
+                connection = %connection,
+                param_count = params.len(),
+                "Successfully parsed database query function"
+            );
+
+            Ok(TaskFunction::DbQuery {
+                connection,
+                query,
+                params,
+                max_rows,
+            })
+        }
+        "manual" => {
+            let instructions = function
+                .get("input")
+                .and_then(|input| input.get("instructions"))
+                .and_then(|v| v.as_str())
+                .filter(|instructions| !instructions.trim().is_empty())
+                .ok_or_else(|| {
+                    error!(function_type = %function_type, "Missing instructions field");
+                    ParserError::MissingField("function.input.instructions".to_string())
+                })?
+                .to_string();
+
+            debug!(
+                function_type = %function_type,
+                instructions_length = instructions.len(),
+                "Successfully parsed manual function"
+            );
+
+            Ok(TaskFunction::Manual { instructions })
+        }
+        "publish_message" => {
+            let input = function.get("input").ok_or_else(|| {
+                error!(function_type = %function_type, "Missing input field for message publish");
+                ParserError::MissingField("function.input".to_string())
+            })?;
+            let required = |field: &str| {
+                input
+                    .get(field)
+                    .and_then(|v| v.as_str())
+                    .filter(|value| !value.trim().is_empty())
+                    .map(str::to_string)
+                    .ok_or_else(|| ParserError::MissingField(format!("function.input.{}", field)))
+            };
+            let connection = required("connection")?;
+            let destination = required("destination")?;
+            let payload = input
+                .get("payload")
+                .cloned()
+                .ok_or_else(|| ParserError::MissingField("function.input.payload".to_string()))?;
+
+            let key = match input.get("key") {
+                None | Some(Value::Null) => None,
+                Some(Value::String(key)) => Some(key.clone()),
+                Some(_) => {
+                    return Err(ParserError::InvalidTask(
+                        "function.input.key must be a string".to_string(),
+                    ))
+                }
+            };
+            let headers = match input.get("headers") {
+                None | Some(Value::Null) => BTreeMap::new(),
+                Some(Value::Object(headers)) => headers
+                    .iter()
+                    .map(|(name, value)| match value {
+                        Value::String(value) => Ok((name.clone(), value.clone())),
+                        _ => Err(ParserError::InvalidTask(format!(
+                            "function.input.headers.{} must be a string",
+                            name
+                        ))),
+                    })
+                    .collect::<Result<_, _>>()?,
+                Some(_) => {
+                    return Err(ParserError::InvalidTask(
+                        "function.input.headers must be an object".to_string(),
+                    ))
+                }
+            };
+
+            debug!(
+                function_type = %function_type,
+                connection = %connection,
+                destination = %destination,
+                header_count = headers.len(),
+                "Successfully parsed message publish function"
+            );
+
+            Ok(TaskFunction::PublishMessage {
+                connection,
+                destination,
+                payload,
+                key,
+                headers,
+            })
+        }
+        "cache" => {
+            let input = function.get("input").ok_or_else(|| {
+                error!(function_type = %function_type, "Missing input field for cache");
+                ParserError::MissingField("function.input".to_string())
+            })?;
+            let key = input
+                .get("key")
+                .and_then(|v| v.as_str())
+                .filter(|key| !key.trim().is_empty())
+                .ok_or_else(|| ParserError::MissingField("function.input.key".to_string()))?
+                .to_string();
+            let paths = input
+                .get("paths")
+                .and_then(|v| v.as_array())
+                .filter(|paths| !paths.is_empty())
+                .ok_or_else(|| ParserError::MissingField("function.input.paths".to_string()))?
+                .iter()
+                .map(|path| match path.as_str() {
+                    Some(path) if is_contained(path) => Ok(path.to_string()),
+                    _ => Err(ParserError::InvalidTask(format!(
+                        "function.input.paths entries must be relative paths inside the working directory, got {}",
+                        path
+                    ))),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            debug!(
+                function_type = %function_type,
+                path_count = paths.len(),
+                "Successfully parsed cache function"
+            );
+
+            Ok(TaskFunction::Cache { key, paths })
+        }
         _ => {
             trace!(
                 function_type = %function_type,
@@ -0,0 +1,418 @@
+//! Checks a workflow declares under `preflight`, run before its first task so a
+//! missing tool or unreachable service fails the run up front.
+
+use crate::types::{EngineWorkflow, TaskFunction};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+use tracing::debug;
+
+const DEFAULT_URL_TIMEOUT_MS: u64 = 5000;
+const TOOL_VERSION_TIMEOUT_MS: u64 = 5000;
+
+/// Tools whose versions are worth recording when a workflow calls them, with
+/// the arguments that make each print its version. Only these are probed:
+/// running an arbitrary command with `--version` could do real work.
+const VERSIONED_TOOLS: &[(&str, &[&str])] = &[
+    ("cargo", &["--version"]),
+    ("docker", &["--version"]),
+    ("git", &["--version"]),
+    ("go", &["version"]),
+    ("java", &["-version"]),
+    ("node", &["--version"]),
+    ("npm", &["--version"]),
+    ("python", &["--version"]),
+    ("python3", &["--version"]),
+    ("rustc", &["--version"]),
+];
+
+/// One condition the environment has to meet before a workflow starts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "check", rename_all = "snake_case")]
+pub enum PreflightCheck {
+    /// An executable called `name` is on `PATH`, or at `name` if it is a path.
+    Binary { name: String },
+    /// The filesystem holding `path` has at least `min_mb` megabytes free.
+    DiskSpace {
+        #[serde(default = "default_disk_path")]
+        path: String,
+        min_mb: u64,
+    },
+    /// The environment variable `name` is set.
+    EnvVar { name: String },
+    /// A TCP connection to the host of an http(s) `url` succeeds in time.
+    Url {
+        url: String,
+        #[serde(default = "default_url_timeout_ms")]
+        timeout_ms: u64,
+    },
+}
+
+fn default_disk_path() -> String {
+    ".".to_string()
+}
+
+fn default_url_timeout_ms() -> u64 {
+    DEFAULT_URL_TIMEOUT_MS
+}
+
+impl PreflightCheck {
+    /// Reject checks that could never pass, so mistakes surface when the
+    /// workflow is loaded rather than on every run.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            PreflightCheck::Binary { name } | PreflightCheck::EnvVar { name } => {
+                if name.trim().is_empty() {
+                    return Err(format!("{} check needs a name", self.kind()));
+                }
+            }
+            PreflightCheck::DiskSpace { path, .. } => {
+                if path.is_empty() {
+                    return Err("disk_space check needs a path".to_string());
+                }
+            }
+            PreflightCheck::Url { url, timeout_ms } => {
+                url_address(url)?;
+                if *timeout_ms == 0 {
+                    return Err("url check timeout_ms must be greater than 0".to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            PreflightCheck::Binary { .. } => "binary",
+            PreflightCheck::DiskSpace { .. } => "disk_space",
+            PreflightCheck::EnvVar { .. } => "env_var",
+            PreflightCheck::Url { .. } => "url",
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            PreflightCheck::Binary { name } => format!("binary '{}' on PATH", name),
+            PreflightCheck::DiskSpace { path, min_mb } => {
+                format!("{} MB free at '{}'", min_mb, path)
+            }
+            PreflightCheck::EnvVar { name } => format!("environment variable '{}'", name),
+            PreflightCheck::Url { url, .. } => format!("'{}' reachable", url),
+        }
+    }
+
+    async fn run(&self) -> PreflightResult {
+        let outcome = match self {
+            PreflightCheck::Binary { name } => check_binary(name),
+            PreflightCheck::DiskSpace { path, min_mb } => check_disk_space(path, *min_mb),
+            PreflightCheck::EnvVar { name } => check_env_var(name),
+            PreflightCheck::Url { url, timeout_ms } => check_url(url, *timeout_ms).await,
+        };
+        let (passed, detail) = match outcome {
+            Ok(detail) => (true, detail),
+            Err(detail) => (false, detail),
+        };
+        PreflightResult {
+            check: self.clone(),
+            passed,
+            detail,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreflightResult {
+    pub check: PreflightCheck,
+    pub passed: bool,
+    pub detail: String,
+}
+
+/// The outcome of every preflight check of one run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreflightReport {
+    pub results: Vec<PreflightResult>,
+}
+
+impl PreflightReport {
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(|result| result.passed)
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &PreflightResult> {
+        self.results.iter().filter(|result| !result.passed)
+    }
+}
+
+impl fmt::Display for PreflightReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} preflight checks failed",
+            self.failures().count(),
+            self.results.len()
+        )?;
+        for result in self.failures() {
+            write!(f, "\n  - {}: {}", result.check.describe(), result.detail)?;
+        }
+        Ok(())
+    }
+}
+
+/// Run every check, even after one fails, so the report lists all problems.
+pub async fn run_preflight(checks: &[PreflightCheck]) -> PreflightReport {
+    let mut results = Vec::with_capacity(checks.len());
+    for check in checks {
+        let result = check.run().await;
+        debug!(
+            check = %check.describe(),
+            passed = result.passed,
+            detail = %result.detail,
+            "Preflight check finished"
+        );
+        results.push(result);
+    }
+    PreflightReport { results }
+}
+
+/// Known tools that `workflow` mentions in a command, its arguments, or a
+/// `binary` preflight check, so `sh -c "cargo build"` counts as using cargo.
+pub fn referenced_tools(workflow: &EngineWorkflow) -> Vec<&'static str> {
+    let mut words = Vec::new();
+    for task in &workflow.tasks {
+        if let TaskFunction::CliCommand { command, args, .. } = &task.function {
+            words.push(command.as_str());
+            words.extend(args.iter().map(String::as_str));
+        }
+    }
+    for check in &workflow.preflight {
+        if let PreflightCheck::Binary { name } = check {
+            words.push(name.as_str());
+        }
+    }
+
+    VERSIONED_TOOLS
+        .iter()
+        .map(|(tool, _)| *tool)
+        .filter(|tool| {
+            words.iter().any(|text| {
+                text.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+                    .any(|word| word == *tool)
+            })
+        })
+        .collect()
+}
+
+/// The first line each tool prints for its version, or why there is none.
+/// Tools run concurrently and each gets a few seconds to answer.
+pub async fn probe_tool_versions(tools: &[&str]) -> BTreeMap<String, String> {
+    let probes = tools.iter().map(|tool| async move {
+        let args = VERSIONED_TOOLS
+            .iter()
+            .find(|(name, _)| name == tool)
+            .map_or(&["--version"][..], |(_, args)| *args);
+        (tool.to_string(), tool_version(tool, args).await)
+    });
+    futures_util::future::join_all(probes)
+        .await
+        .into_iter()
+        .collect()
+}
+
+async fn tool_version(tool: &str, args: &[&str]) -> String {
+    if find_on_path(tool).is_none() {
+        return "not found on PATH".to_string();
+    }
+    let output = tokio::process::Command::new(tool)
+        .args(args)
+        .stdin(Stdio::null())
+        .kill_on_drop(true)
+        .output();
+    match tokio::time::timeout(Duration::from_millis(TOOL_VERSION_TIMEOUT_MS), output).await {
+        // Some tools, like java, print their version to stderr.
+        Ok(Ok(output)) => [output.stdout, output.stderr]
+            .iter()
+            .filter_map(|bytes| {
+                String::from_utf8_lossy(bytes)
+                    .lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .map(str::to_string)
+            })
+            .next()
+            .unwrap_or_else(|| "no version output".to_string()),
+        Ok(Err(e)) => format!("failed to run: {}", e),
+        Err(_) => format!("no answer within {} ms", TOOL_VERSION_TIMEOUT_MS),
+    }
+}
+
+fn check_binary(name: &str) -> Result<String, String> {
+    let path = Path::new(name);
+    if path.components().count() > 1 {
+        return if is_executable(path) {
+            Ok(format!("found at {}", path.display()))
+        } else {
+            Err("not found or not executable".to_string())
+        };
+    }
+    find_on_path(name)
+        .map(|found| format!("found at {}", found.display()))
+        .ok_or_else(|| "not found on PATH".to_string())
+}
+
+fn find_on_path(name: &str) -> Option<PathBuf> {
+    let dirs = std::env::var_os("PATH")?;
+    std::env::split_paths(&dirs).find_map(|dir| {
+        executable_names(name)
+            .into_iter()
+            .map(|candidate| dir.join(candidate))
+            .find(|candidate| is_executable(candidate))
+    })
+}
+
+#[cfg(windows)]
+fn executable_names(name: &str) -> Vec<String> {
+    let mut names = vec![name.to_string()];
+    if Path::new(name).extension().is_none() {
+        let extensions =
+            std::env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string());
+        names.extend(
+            extensions
+                .split(';')
+                .filter(|ext| !ext.is_empty())
+                .map(|ext| format!("{}{}", name, ext)),
+        );
+    }
+    names
+}
+
+#[cfg(not(windows))]
+fn executable_names(name: &str) -> Vec<String> {
+    vec![name.to_string()]
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path)
+        .map(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+fn check_disk_space(path: &str, min_mb: u64) -> Result<String, String> {
+    let available = available_bytes(Path::new(path))
+        .map_err(|e| format!("could not read free space: {}", e))?;
+    let available_mb = available / (1024 * 1024);
+    if available_mb >= min_mb {
+        Ok(format!("{} MB free", available_mb))
+    } else {
+        Err(format!("only {} MB free", available_mb))
+    }
+}
+
+#[cfg(unix)]
+fn available_bytes(path: &Path) -> std::io::Result<u64> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = std::ffi::CString::new(path.as_os_str().as_bytes())
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+    let mut stats: libc::statvfs = unsafe { std::mem::zeroed() };
+    // SAFETY: `c_path` is a valid NUL-terminated string and `stats` is a
+    // writable statvfs that outlives the call.
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stats) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    #[allow(clippy::unnecessary_cast)]
+    Ok(stats.f_bavail as u64 * stats.f_frsize as u64)
+}
+
+#[cfg(windows)]
+fn available_bytes(path: &Path) -> std::io::Result<u64> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows_sys::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+    let wide: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+    let mut available = 0u64;
+    // SAFETY: `wide` is NUL-terminated and the out pointer is valid for the
+    // call; the other outputs are optional and passed as null.
+    let ok = unsafe {
+        GetDiskFreeSpaceExW(
+            wide.as_ptr(),
+            &mut available,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    if ok == 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(available)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn available_bytes(_path: &Path) -> std::io::Result<u64> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "not supported on this platform",
+    ))
+}
+
+fn check_env_var(name: &str) -> Result<String, String> {
+    match std::env::var_os(name) {
+        Some(_) => Ok("set".to_string()),
+        None => Err("not set".to_string()),
+    }
+}
+
+async fn check_url(url: &str, timeout_ms: u64) -> Result<String, String> {
+    let (host, port) = url_address(url)?;
+    let connect = tokio::net::TcpStream::connect((host.as_str(), port));
+    match tokio::time::timeout(Duration::from_millis(timeout_ms), connect).await {
+        Ok(Ok(_)) => Ok(format!("connected to {}:{}", host, port)),
+        Ok(Err(e)) => Err(format!("could not connect to {}:{}: {}", host, port, e)),
+        Err(_) => Err(format!("no connection within {} ms", timeout_ms)),
+    }
+}
+
+/// Host and port to connect to for an http(s) URL.
+fn url_address(url: &str) -> Result<(String, u16), String> {
+    let (scheme, rest) = url
+        .split_once("://")
+        .ok_or_else(|| format!("'{}' is not a URL", url))?;
+    let default_port = match scheme.to_ascii_lowercase().as_str() {
+        "http" => 80,
+        "https" => 443,
+        other => return Err(format!("unsupported URL scheme '{}'", other)),
+    };
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let authority = authority.rsplit('@').next().unwrap_or_default();
+
+    let (host, port) = if let Some(bracketed) = authority.strip_prefix('[') {
+        let (host, after) = bracketed
+            .split_once(']')
+            .ok_or_else(|| format!("'{}' has an unterminated IPv6 host", url))?;
+        (host, after.strip_prefix(':'))
+    } else {
+        match authority.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (authority, None),
+        }
+    };
+    if host.is_empty() {
+        return Err(format!("'{}' has no host", url));
+    }
+    let port = match port {
+        Some(port) => port
+            .parse()
+            .map_err(|_| format!("'{}' has an invalid port", url))?,
+        None => default_port,
+    };
+    Ok((host.to_string(), port))
+}
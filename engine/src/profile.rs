@@ -0,0 +1,224 @@
+//! Run profiles: how long each phase of a run took, from parsing the workflow
+//! to each task's dispatch and handler to the writes to the store, so a slow
+//! run can be pinned on its handlers, the store or the engine itself.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Part of a run a [`ProfileSpan`] was spent in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfilePhase {
+    /// Reading the workflow document into tasks.
+    Parse,
+    /// Checking the workflow against the schema.
+    Validate,
+    /// Working out which tasks are ready in a round.
+    GraphBuild,
+    /// Running one task: filling in its inputs, the cache lookup, waiting
+    /// for its lock and its handler.
+    Dispatch,
+    /// The task's handler, middlewares included. Part of its dispatch.
+    Handler,
+    /// Reads and writes to the store.
+    Persistence,
+}
+
+impl ProfilePhase {
+    pub const ALL: [ProfilePhase; 6] = [
+        ProfilePhase::Parse,
+        ProfilePhase::Validate,
+        ProfilePhase::GraphBuild,
+        ProfilePhase::Dispatch,
+        ProfilePhase::Handler,
+        ProfilePhase::Persistence,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ProfilePhase::Parse => "parse",
+            ProfilePhase::Validate => "validate",
+            ProfilePhase::GraphBuild => "graph build",
+            ProfilePhase::Dispatch => "dispatch",
+            ProfilePhase::Handler => "handler",
+            ProfilePhase::Persistence => "persistence",
+        }
+    }
+}
+
+/// One timed piece of work, in microseconds since the profile started.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileSpan {
+    pub phase: ProfilePhase,
+    /// What was timed: the task id for dispatch and handler spans, else
+    /// e.g. `save execution`.
+    pub label: String,
+    pub start_us: u64,
+    pub duration_us: u64,
+}
+
+/// Time spent in one phase over a whole run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseSummary {
+    pub phase: ProfilePhase,
+    pub count: usize,
+    /// Summed over its spans, so tasks that ran in parallel can add up to
+    /// more than the run took.
+    pub total_us: u64,
+    pub longest_us: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunProfile {
+    /// Time from the start of the profile to when it was taken.
+    pub total_us: u64,
+    pub spans: Vec<ProfileSpan>,
+}
+
+impl RunProfile {
+    /// Time spent per phase, for the phases that have spans.
+    pub fn summary(&self) -> Vec<PhaseSummary> {
+        ProfilePhase::ALL
+            .into_iter()
+            .filter_map(|phase| {
+                let spans: Vec<&ProfileSpan> = self
+                    .spans
+                    .iter()
+                    .filter(|span| span.phase == phase)
+                    .collect();
+                (!spans.is_empty()).then(|| PhaseSummary {
+                    phase,
+                    count: spans.len(),
+                    total_us: spans.iter().map(|span| span.duration_us).sum(),
+                    longest_us: spans.iter().map(|span| span.duration_us).max().unwrap_or(0),
+                })
+            })
+            .collect()
+    }
+
+    /// The spans in Chrome's trace event format, for `chrome://tracing` or
+    /// Perfetto. The run's own phases and the store share one track; each
+    /// task gets a track of its own.
+    pub fn to_chrome_trace(&self) -> Value {
+        let mut task_tracks: HashMap<&str, usize> = HashMap::new();
+        let mut events: Vec<Value> = self
+            .spans
+            .iter()
+            .map(|span| {
+                let track = match span.phase {
+                    ProfilePhase::Dispatch | ProfilePhase::Handler => {
+                        let next = task_tracks.len() + 2;
+                        *task_tracks.entry(span.label.as_str()).or_insert(next)
+                    }
+                    _ => 1,
+                };
+                json!({
+                    "name": span.label,
+                    "cat": span.phase.label(),
+                    "ph": "X",
+                    "ts": span.start_us,
+                    "dur": span.duration_us,
+                    "pid": 1,
+                    "tid": track,
+                })
+            })
+            .collect();
+        events.push(json!({
+            "name": "thread_name", "ph": "M", "pid": 1, "tid": 1, "args": { "name": "run" }
+        }));
+        for (task_id, track) in task_tracks {
+            events.push(json!({
+                "name": "thread_name", "ph": "M", "pid": 1, "tid": track, "args": { "name": task_id }
+            }));
+        }
+        json!({ "traceEvents": events, "displayTimeUnit": "ms" })
+    }
+}
+
+struct ProfileState {
+    started: Instant,
+    spans: Vec<ProfileSpan>,
+}
+
+/// Collects the spans of one run. A default profiler records nothing, so
+/// code can time its phases unconditionally.
+#[derive(Clone, Default)]
+pub struct Profiler {
+    state: Option<Arc<Mutex<ProfileState>>>,
+}
+
+impl Profiler {
+    /// A profiler that records, starting the clock now.
+    pub fn new() -> Self {
+        Self {
+            state: Some(Arc::new(Mutex::new(ProfileState {
+                started: Instant::now(),
+                spans: Vec::new(),
+            }))),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.state.is_some()
+    }
+
+    /// Record a span of `phase` from `started` until now.
+    pub fn record(&self, phase: ProfilePhase, label: impl Into<String>, started: Instant) {
+        let Some(state) = &self.state else {
+            return;
+        };
+        let duration = started.elapsed();
+        let Ok(mut state) = state.lock() else {
+            return;
+        };
+        let start = started.saturating_duration_since(state.started);
+        state.spans.push(ProfileSpan {
+            phase,
+            label: label.into(),
+            start_us: start.as_micros() as u64,
+            duration_us: duration.as_micros() as u64,
+        });
+    }
+
+    /// Run `work`, recording it as a span of `phase`.
+    pub fn measure<T>(
+        &self,
+        phase: ProfilePhase,
+        label: impl Into<String>,
+        work: impl FnOnce() -> T,
+    ) -> T {
+        let started = Instant::now();
+        let output = work();
+        self.record(phase, label, started);
+        output
+    }
+
+    /// Await `work`, recording it as a span of `phase`.
+    pub async fn time<F: Future>(
+        &self,
+        phase: ProfilePhase,
+        label: impl Into<String>,
+        work: F,
+    ) -> F::Output {
+        let started = Instant::now();
+        let output = work.await;
+        self.record(phase, label, started);
+        output
+    }
+
+    /// The spans recorded so far, oldest first. `None` for a profiler that
+    /// does not record.
+    pub fn profile(&self) -> Option<RunProfile> {
+        let state = self.state.as_ref()?.lock().ok()?;
+        let mut spans = state.spans.clone();
+        spans.sort_by_key(|span| span.start_us);
+        Some(RunProfile {
+            total_us: state.started.elapsed().as_micros() as u64,
+            spans,
+        })
+    }
+}
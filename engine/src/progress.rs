@@ -0,0 +1,97 @@
+//! Progress that running tasks report: how far along they are and what they
+//! are doing. It rides along with live output and is not stored with the run.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Longest status text kept from a progress line.
+const MAX_STATUS_CHARS: usize = 120;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskProgress {
+    /// Whole percent done, 0 to 100.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub percent: Option<u8>,
+    /// What the task is doing now, such as "Receiving objects".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+impl TaskProgress {
+    pub fn percent(percent: u8) -> Self {
+        Self {
+            percent: Some(percent.min(100)),
+            status: None,
+        }
+    }
+
+    pub fn with_status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+}
+
+impl fmt::Display for TaskProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.percent, &self.status) {
+            (Some(percent), Some(status)) => write!(f, "[{}%] {}", percent, status),
+            (Some(percent), None) => write!(f, "[{}%]", percent),
+            (None, Some(status)) => write!(f, "{}", status),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Progress in a line of command output, for the formats common tools print:
+/// a step counter at the start such as `[3/10] Building` or `Step 3/10 : RUN`,
+/// or a percentage such as `Receiving objects:  45% (450/1000)`.
+pub fn parse_progress_line(line: &str) -> Option<TaskProgress> {
+    let line = line.trim();
+    step_counter(line).or_else(|| percentage(line))
+}
+
+fn step_counter(line: &str) -> Option<TaskProgress> {
+    let (counter, rest) = if let Some(rest) = line.strip_prefix('[') {
+        rest.split_once(']')?
+    } else if let Some(rest) = line.strip_prefix('(') {
+        rest.split_once(')')?
+    } else {
+        let rest = line.strip_prefix("Step ")?;
+        rest.split_once(char::is_whitespace).unwrap_or((rest, ""))
+    };
+    let (done, total) = counter.trim().split_once('/')?;
+    let done: u64 = done.trim().parse().ok()?;
+    let total: u64 = total.trim().parse().ok()?;
+    if total == 0 || done > total {
+        return None;
+    }
+    let status = rest.trim_start_matches([' ', ':']).trim();
+    Some(TaskProgress {
+        percent: Some((done * 100 / total) as u8),
+        status: status_text(status),
+    })
+}
+
+fn percentage(line: &str) -> Option<TaskProgress> {
+    let sign = line.find('%')?;
+    let before = &line[..sign];
+    let start = before
+        .rfind(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .map_or(0, |i| i + 1);
+    if start > 0 && !before[..start].ends_with([' ', '(', '[', ':', '|']) {
+        return None;
+    }
+    let percent: f64 = before[start..].parse().ok()?;
+    if !(0.0..=100.0).contains(&percent) {
+        return None;
+    }
+    let status = before[..start].trim_end_matches([' ', '(', '[', ':', '|', '-']);
+    Some(TaskProgress {
+        percent: Some(percent.floor() as u8),
+        status: status_text(status.trim()),
+    })
+}
+
+fn status_text(text: &str) -> Option<String> {
+    (!text.is_empty()).then(|| text.chars().take(MAX_STATUS_CHARS).collect())
+}
@@ -0,0 +1,67 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Values handlers keep for the rest of a run that are not task output, such
+/// as temp file paths and cursors. They live apart from the context data, so
+/// they never show up in the result, the audit trail's changes or exports,
+/// and are saved with the run's checkpoint so a resumed run finds them again.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Scratchpad {
+    entries: BTreeMap<String, Value>,
+}
+
+impl Scratchpad {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.entries.get(key)
+    }
+
+    /// The value under `key` read as `T`. `None` when there is none or it has
+    /// another shape.
+    pub fn get_as<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        T::deserialize(self.entries.get(key)?).ok()
+    }
+
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<Value>) {
+        self.entries.insert(key.into(), value.into());
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        self.entries.remove(key)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn entries(&self) -> &BTreeMap<String, Value> {
+        &self.entries
+    }
+
+    /// Apply the changes one task of a round made: what `task` set or removed
+    /// compared to `base`, the scratchpad the round started with.
+    pub(crate) fn merge(&mut self, base: &Scratchpad, task: &Scratchpad) {
+        for (key, value) in &task.entries {
+            if base.entries.get(key) != Some(value) {
+                self.entries.insert(key.clone(), value.clone());
+            }
+        }
+        for key in base.entries.keys() {
+            if !task.entries.contains_key(key) {
+                self.entries.remove(key);
+            }
+        }
+    }
+}
+
+impl From<BTreeMap<String, Value>> for Scratchpad {
+    fn from(entries: BTreeMap<String, Value>) -> Self {
+        Self { entries }
+    }
+}
@@ -0,0 +1,231 @@
+//! Speculative branches: a task marked `speculative` and every task after it
+//! run against the shared context as usual, but what they change is only kept
+//! if the branch's `commit_when` condition holds once all of them have
+//! finished. Otherwise each top-level data key the branch touched goes back to
+//! the value it had when the branch started, and keys it added are removed.
+//!
+//! Ending a branch with a `manual` task and committing on its outcome, e.g.
+//! `data.review.outcome == "done"`, leaves the decision to a person. Branches
+//! that have started and not settled are kept in the scratchpad under
+//! [`BRANCHES_KEY`], so a run paused inside one settles it after resuming.
+
+use crate::condition::Condition;
+use crate::loops::loop_task_id;
+use crate::types::{EngineTask, ExecutionContext};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use tracing::{info, warn};
+
+/// Scratchpad key the branches that have not settled yet are saved under.
+pub const BRANCHES_KEY: &str = "speculative_branches";
+
+/// A started branch, saved under the id of the task it starts at.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct OpenBranch {
+    /// The context data when the branch started.
+    fork: Value,
+    /// Top-level data keys the branch's tasks changed so far.
+    touched: BTreeSet<String>,
+}
+
+struct Branch {
+    task_id: String,
+    commit_when: String,
+    /// The speculative task and every task after it.
+    members: HashSet<String>,
+}
+
+/// The speculative branches of one workflow.
+pub(crate) struct Speculation {
+    branches: Vec<Branch>,
+}
+
+impl Speculation {
+    pub(crate) fn new(tasks: &[EngineTask]) -> Self {
+        fn collect(task: &EngineTask, members: &mut HashSet<String>) {
+            members.insert(task.id.clone());
+            for next in &task.next_tasks {
+                collect(next, members);
+            }
+        }
+
+        let branches = tasks
+            .iter()
+            .filter_map(|task| {
+                let speculative = task.speculative.as_ref()?;
+                let mut members = HashSet::new();
+                collect(task, &mut members);
+                Some(Branch {
+                    task_id: task.id.clone(),
+                    commit_when: speculative.commit_when.clone(),
+                    members,
+                })
+            })
+            .collect();
+        Self { branches }
+    }
+
+    /// Fork the context for the branches whose first task is about to run.
+    pub(crate) fn start(&self, ready_tasks: &[EngineTask], context: &mut ExecutionContext) {
+        if self.branches.is_empty() {
+            return;
+        }
+        let mut open = open_branches(context);
+        let mut started = false;
+        for task in ready_tasks.iter().filter(|task| task.speculative.is_some()) {
+            if open.contains_key(&task.id) {
+                continue;
+            }
+            info!(
+                execution_id = %context.execution_id,
+                task_id = %task.id,
+                "🔀 Starting speculative branch"
+            );
+            open.insert(
+                task.id.clone(),
+                OpenBranch {
+                    fork: context.data.clone(),
+                    touched: BTreeSet::new(),
+                },
+            );
+            started = true;
+        }
+        if started {
+            save_branches(context, &open);
+        }
+    }
+
+    /// Note the top-level data keys a task changed, when it is part of a
+    /// branch that has not settled. Its own output key always counts.
+    pub(crate) fn record(&self, task_id: &str, keys: &[String], context: &mut ExecutionContext) {
+        let task_id = loop_task_id(task_id);
+        let Some(branch) = self
+            .branches
+            .iter()
+            .find(|branch| branch.members.contains(task_id))
+        else {
+            return;
+        };
+        let mut open = open_branches(context);
+        let Some(started) = open.get_mut(&branch.task_id) else {
+            return;
+        };
+        started.touched.insert(task_id.to_string());
+        started.touched.extend(keys.iter().cloned());
+        save_branches(context, &open);
+    }
+
+    /// Commit or roll back every started branch whose tasks have all
+    /// finished. Failed and skipped tasks count as finished; a task waiting
+    /// for input keeps its branch open.
+    pub(crate) fn settle(&self, completed: &HashSet<String>, context: &mut ExecutionContext) {
+        if self.branches.is_empty() {
+            return;
+        }
+        let mut open = open_branches(context);
+        let mut settled = false;
+        for branch in &self.branches {
+            if !branch.members.iter().all(|id| completed.contains(id)) {
+                continue;
+            }
+            let Some(started) = open.remove(&branch.task_id) else {
+                continue;
+            };
+            settled = true;
+
+            let commit = match Condition::parse(&branch.commit_when) {
+                Ok(condition) => condition.evaluate(&context.params, &context.data),
+                Err(e) => {
+                    warn!(
+                        execution_id = %context.execution_id,
+                        task_id = %branch.task_id,
+                        error = %e,
+                        "Speculative branch condition is invalid, rolling the branch back"
+                    );
+                    false
+                }
+            };
+            let message = if commit {
+                format!(
+                    "Speculative branch committed: `{}` holds",
+                    branch.commit_when
+                )
+            } else {
+                let restored = roll_back(&mut context.data, &started);
+                format!(
+                    "Speculative branch rolled back: `{}` does not hold, restored {} context key(s)",
+                    branch.commit_when, restored
+                )
+            };
+            info!(
+                execution_id = %context.execution_id,
+                task_id = %branch.task_id,
+                committed = commit,
+                "🔀 Settled speculative branch"
+            );
+            context.log_task(branch.task_id.clone(), message);
+        }
+        if settled {
+            save_branches(context, &open);
+        }
+    }
+}
+
+/// Check that no speculative task is inside another one's branch.
+pub fn validate_speculation(tasks: &[EngineTask]) -> Result<(), String> {
+    fn check(task: &EngineTask, branch: Option<&str>) -> Result<(), String> {
+        let branch = match (branch, &task.speculative) {
+            (Some(outer), Some(_)) => {
+                return Err(format!(
+                    "task '{}' is inside the speculative branch of '{}'",
+                    task.id, outer
+                ))
+            }
+            (None, Some(_)) => Some(task.id.as_str()),
+            (branch, None) => branch,
+        };
+        task.next_tasks
+            .iter()
+            .try_for_each(|next| check(next, branch))
+    }
+
+    tasks
+        .iter()
+        .filter(|task| task.is_root)
+        .try_for_each(|task| check(task, None))
+}
+
+fn open_branches(context: &ExecutionContext) -> BTreeMap<String, OpenBranch> {
+    context.scratch.get_as(BRANCHES_KEY).unwrap_or_default()
+}
+
+fn save_branches(context: &mut ExecutionContext, open: &BTreeMap<String, OpenBranch>) {
+    if open.is_empty() {
+        context.scratch.remove(BRANCHES_KEY);
+        return;
+    }
+    match serde_json::to_value(open) {
+        Ok(value) => context.scratch.set(BRANCHES_KEY, value),
+        Err(e) => warn!(error = %e, "Failed to save speculative branches"),
+    }
+}
+
+/// Put each key the branch touched back the way it was at the fork. Returns
+/// how many keys that was.
+fn roll_back(data: &mut Value, branch: &OpenBranch) -> usize {
+    let Value::Object(data) = data else {
+        return 0;
+    };
+    for key in &branch.touched {
+        match branch.fork.get(key) {
+            Some(value) => {
+                data.insert(key.clone(), value.clone());
+            }
+            None => {
+                data.remove(key);
+            }
+        }
+    }
+    branch.touched.len()
+}
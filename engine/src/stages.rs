@@ -0,0 +1,127 @@
+//! Named stages that split a workflow into phases, e.g. build → test → deploy.
+//! A stage starts only once every task of the stages before it has succeeded,
+//! whatever the `next_tasks` links say.
+
+use crate::types::{EngineTask, StageInfo, TaskStatus};
+use std::collections::HashSet;
+
+/// Check that stage names are unique and that every task names a declared
+/// stage no earlier than the stage of any task it follows. A task placed in
+/// an earlier stage than its parent could never start.
+pub fn validate_stages(stages: &[String], tasks: &[EngineTask]) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for stage in stages {
+        if stage.trim().is_empty() {
+            return Err("stage names must not be empty".to_string());
+        }
+        if !seen.insert(stage.as_str()) {
+            return Err(format!("stage '{}' is declared twice", stage));
+        }
+    }
+
+    fn check(
+        stages: &[String],
+        task: &EngineTask,
+        parent: Option<(&str, usize)>,
+    ) -> Result<(), String> {
+        let index = task
+            .stage
+            .as_deref()
+            .map(|stage| {
+                stage_index(stages, stage)
+                    .ok_or_else(|| format!("task '{}' uses undeclared stage '{}'", task.id, stage))
+            })
+            .transpose()?;
+        if let (Some(index), Some((parent_stage, parent_index))) = (index, parent) {
+            if index < parent_index {
+                return Err(format!(
+                    "task '{}' is in stage '{}' but follows a task in the later stage '{}'",
+                    task.id, stages[index], parent_stage
+                ));
+            }
+        }
+        let parent = match (index, parent) {
+            (Some(index), _) => Some((stages[index].as_str(), index)),
+            (None, parent) => parent,
+        };
+        task.next_tasks
+            .iter()
+            .try_for_each(|next| check(stages, next, parent))
+    }
+
+    tasks
+        .iter()
+        .filter(|task| task.is_root)
+        .try_for_each(|task| check(stages, task, None))
+}
+
+fn stage_index(stages: &[String], stage: &str) -> Option<usize> {
+    stages.iter().position(|name| name == stage)
+}
+
+fn stage_tasks<'a>(
+    tasks: &'a [EngineTask],
+    stage: &'a str,
+) -> impl Iterator<Item = &'a EngineTask> {
+    tasks
+        .iter()
+        .filter(move |task| task.stage.as_deref() == Some(stage))
+}
+
+/// Index of the earliest stage that has not fully succeeded yet. Tasks of
+/// later stages must wait. `None` once every stage has passed.
+pub(crate) fn open_stage(
+    stages: &[String],
+    tasks: &[EngineTask],
+    completed: &HashSet<String>,
+    failed: &HashSet<String>,
+) -> Option<usize> {
+    stages.iter().position(|stage| {
+        !stage_tasks(tasks, stage)
+            .all(|task| completed.contains(&task.id) && !failed.contains(&task.id))
+    })
+}
+
+/// Whether `task` has to wait for an earlier stage.
+pub(crate) fn is_held_back(stages: &[String], task: &EngineTask, open: Option<usize>) -> bool {
+    let index = task
+        .stage
+        .as_deref()
+        .and_then(|stage| stage_index(stages, stage));
+    matches!((index, open), (Some(index), Some(open)) if index > open)
+}
+
+/// Each stage's status: failed if any task failed, waiting if any task waits
+/// for input, complete once all tasks finished, in progress while some have.
+pub(crate) fn stage_rollups(
+    stages: &[String],
+    tasks: &[EngineTask],
+    completed: &HashSet<String>,
+    failed: &HashSet<String>,
+    waiting_for_input: &HashSet<String>,
+) -> Vec<StageInfo> {
+    stages
+        .iter()
+        .map(|stage| {
+            let task_ids: Vec<String> = stage_tasks(tasks, stage)
+                .map(|task| task.id.clone())
+                .collect();
+            let status = if task_ids.iter().any(|id| failed.contains(id)) {
+                TaskStatus::Failed
+            } else if task_ids.iter().any(|id| waiting_for_input.contains(id)) {
+                TaskStatus::WaitingForInput
+            } else if task_ids.iter().all(|id| completed.contains(id)) {
+                TaskStatus::Complete
+            } else if task_ids.iter().any(|id| completed.contains(id)) {
+                TaskStatus::InProgress
+            } else {
+                TaskStatus::Pending
+            };
+            StageInfo {
+                name: stage.clone(),
+                status,
+                task_ids,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,132 @@
+use crate::*;
+use serde_json::{json, Value};
+
+fn echo(id: &str, text: &str, next: Vec<Value>) -> Value {
+    json!({
+        "id": id,
+        "name": id,
+        "function": { "name": "cli_command", "input": { "command": "echo", "args": [text] } },
+        "next_tasks": next,
+    })
+}
+
+/// build → test → deploy, with build → lint as a second branch.
+fn pipeline() -> EngineWorkflow {
+    let json = json!({
+        "id": "pipeline",
+        "name": "Pipeline",
+        "tasks": [echo(
+            "build",
+            "built",
+            vec![
+                echo("test", "tested", vec![echo("deploy", "{{data.build}}", vec![])]),
+                echo("lint", "linted", vec![]),
+            ],
+        )],
+    });
+    parse_workflow(&json.to_string()).unwrap()
+}
+
+fn ids(workflow: &EngineWorkflow) -> Vec<&str> {
+    let mut ids: Vec<&str> = workflow.tasks.iter().map(|t| t.id.as_str()).collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn test_bound_from_drops_upstream_and_finds_requirements() {
+    let bounded = bound_workflow(&pipeline(), Some("test"), None).unwrap();
+
+    assert_eq!(ids(&bounded.workflow), ["deploy", "test"]);
+    let roots: Vec<&str> = bounded
+        .workflow
+        .tasks
+        .iter()
+        .filter(|t| t.is_root)
+        .map(|t| t.id.as_str())
+        .collect();
+    assert_eq!(roots, ["test"]);
+    assert_eq!(bounded.skipped, ["lint", "build"]);
+    assert_eq!(
+        bounded.requirements,
+        [("deploy".to_string(), "build".to_string())]
+    );
+}
+
+#[test]
+fn test_bound_until_keeps_only_the_path_to_it() {
+    let bounded = bound_workflow(&pipeline(), None, Some("test")).unwrap();
+
+    assert_eq!(ids(&bounded.workflow), ["build", "test"]);
+    let build = bounded
+        .workflow
+        .tasks
+        .iter()
+        .find(|t| t.id == "build")
+        .unwrap();
+    assert_eq!(build.next_tasks.len(), 1);
+    assert!(build.next_tasks[0].next_tasks.is_empty());
+    assert!(bounded.requirements.is_empty());
+}
+
+#[test]
+fn test_bound_rejects_unknown_or_unreachable_tasks() {
+    let workflow = pipeline();
+    assert!(bound_workflow(&workflow, Some("missing"), None)
+        .unwrap_err()
+        .contains("no task 'missing'"));
+    assert!(bound_workflow(&workflow, Some("lint"), Some("deploy"))
+        .unwrap_err()
+        .contains("'deploy' does not run after 'lint'"));
+}
+
+#[test]
+fn test_data_references() {
+    let workflow = pipeline();
+    let deploy = workflow.tasks.iter().find(|t| t.id == "deploy").unwrap();
+    assert_eq!(data_references(deploy), ["build"]);
+}
+
+#[tokio::test]
+async fn test_initial_data_reaches_tasks() {
+    let json = json!({
+        "id": "publish",
+        "name": "Publish",
+        "tasks": [{
+            "id": "announce",
+            "name": "Announce",
+            "function": {
+                "name": "publish_message",
+                "input": { "connection": "events", "destination": "releases", "payload": "{{data.build}}" }
+            }
+        }]
+    });
+    let workflow = parse_workflow(&json.to_string()).unwrap();
+    let connections = vec![MessageConnection {
+        name: "events".to_string(),
+        backend: MessageBackend::Nats,
+        url: "nats://127.0.0.1:1".to_string(),
+        password_env: None,
+    }];
+
+    let without = WorkflowEngine::new()
+        .with_message_connections(connections.clone())
+        .execute_workflow(workflow.clone())
+        .await
+        .unwrap();
+    assert!(without
+        .errors
+        .iter()
+        .any(|e| e.contains("no output from task 'build'")));
+
+    let with = WorkflowEngine::new()
+        .with_message_connections(connections)
+        .with_initial_data(json!({ "build": "built" }))
+        .execute_workflow(workflow)
+        .await
+        .unwrap();
+    assert!(!with
+        .errors
+        .iter()
+        .any(|e| e.contains("no output from task 'build'")));
+}
@@ -0,0 +1,213 @@
+use crate::cache::*;
+use crate::engine::*;
+use crate::errors::*;
+use crate::handlers::{HandlerRegistry, TaskHandler};
+use crate::parser::*;
+use crate::types::*;
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Default)]
+struct MemoryCache {
+    entries: Mutex<HashMap<String, (TaskResult, Duration)>>,
+}
+
+#[async_trait]
+impl TaskResultCache for MemoryCache {
+    async fn get(&self, key: &str) -> Option<TaskResult> {
+        let entries = self.entries.lock().unwrap();
+        entries.get(key).map(|(result, _)| result.clone())
+    }
+
+    async fn put(&self, key: &str, result: &TaskResult, ttl: Duration) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.insert(key.to_string(), (result.clone(), ttl));
+    }
+}
+
+/// Counts its runs and fails when the input asks it to.
+struct CountingHandler {
+    runs: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl TaskHandler for CountingHandler {
+    async fn execute(
+        &self,
+        _context: &mut ExecutionContext,
+        task: &EngineTask,
+    ) -> Result<TaskResult, HandlerError> {
+        let run = self.runs.fetch_add(1, Ordering::SeqCst) + 1;
+        let fail = match &task.function {
+            TaskFunction::Custom { input, .. } => {
+                input.get("fail").and_then(Value::as_bool).unwrap_or(false)
+            }
+            _ => false,
+        };
+        Ok(TaskResult {
+            success: !fail,
+            output: json!({ "run": run }),
+            error: fail.then(|| "asked to fail".to_string()),
+            failure_reason: None,
+            output_stats: None,
+            exit_code: None,
+            environment: None,
+        })
+    }
+}
+
+fn counting_engine(cache: Arc<MemoryCache>) -> (WorkflowEngine, Arc<AtomicUsize>) {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let mut handlers = HandlerRegistry::new();
+    handlers.register_handler(
+        "count".to_string(),
+        Box::new(CountingHandler { runs: runs.clone() }),
+    );
+    let engine = WorkflowEngine::with_handlers(handlers).with_result_cache(cache);
+    (engine, runs)
+}
+
+fn cached_workflow(input: Value) -> EngineWorkflow {
+    parse_workflow(
+        &json!({
+            "id": "cached",
+            "name": "Cached",
+            "tasks": [{
+                "id": "fetch",
+                "name": "Fetch",
+                "cache": { "ttl_seconds": 60 },
+                "function": { "name": "count", "input": input }
+            }]
+        })
+        .to_string(),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_second_run_uses_cached_result() {
+    let cache = Arc::new(MemoryCache::default());
+    let (engine, runs) = counting_engine(cache.clone());
+
+    let first = engine
+        .execute_workflow(cached_workflow(json!({})))
+        .await
+        .unwrap();
+    assert!(first.success);
+    assert!(first.cache_hits.is_empty());
+
+    let second = engine
+        .execute_workflow(cached_workflow(json!({})))
+        .await
+        .unwrap();
+    assert!(second.success);
+    assert!(second.cache_hits.contains("fetch"));
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        second.audit_trail[0].changes[0].after,
+        Some(json!({ "run": 1 }))
+    );
+
+    let entries = cache.entries.lock().unwrap();
+    assert_eq!(entries.values().next().unwrap().1, Duration::from_secs(60));
+}
+
+#[tokio::test]
+async fn test_failed_results_are_not_cached() {
+    let cache = Arc::new(MemoryCache::default());
+    let (engine, runs) = counting_engine(cache.clone());
+
+    for _ in 0..2 {
+        let result = engine
+            .execute_workflow(cached_workflow(json!({ "fail": true })))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.cache_hits.is_empty());
+    }
+    assert_eq!(runs.load(Ordering::SeqCst), 2);
+    assert!(cache.entries.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_tasks_without_cache_always_run() {
+    let cache = Arc::new(MemoryCache::default());
+    let (engine, runs) = counting_engine(cache.clone());
+    let mut workflow = cached_workflow(json!({}));
+    workflow.tasks[0].cache = None;
+
+    for _ in 0..2 {
+        engine.execute_workflow(workflow.clone()).await.unwrap();
+    }
+    assert_eq!(runs.load(Ordering::SeqCst), 2);
+    assert!(cache.entries.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_cache_key_depends_on_config_and_params() {
+    let workflow = cached_workflow(json!({ "url": "https://example.com" }));
+    let mut renamed = workflow.tasks[0].clone();
+    renamed.id = "other".to_string();
+    renamed.name = "Other".to_string();
+    let changed = cached_workflow(json!({ "url": "https://example.org" }));
+
+    let mut params = serde_json::Map::new();
+    let key = cache_key(&workflow.tasks[0], &params);
+    assert_eq!(key.len(), 64);
+    assert_eq!(key, cache_key(&renamed, &params));
+    assert_ne!(key, cache_key(&changed.tasks[0], &params));
+
+    params.insert("env".to_string(), json!("prod"));
+    assert_ne!(key, cache_key(&workflow.tasks[0], &params));
+}
+
+#[test]
+fn test_parse_task_cache() {
+    let workflow = cached_workflow(json!({}));
+    assert_eq!(workflow.tasks[0].cache, Some(TaskCache { ttl_seconds: 60 }));
+
+    let task = |cache: Value, function: Value| {
+        json!({
+            "id": "wf",
+            "name": "Workflow",
+            "tasks": [{ "id": "t", "name": "T", "cache": cache, "function": function }]
+        })
+        .to_string()
+    };
+    let echo = json!({ "name": "cli_command", "input": { "command": "echo", "args": [] } });
+    let input = json!({
+        "name": "user_input",
+        "input": { "prompt": "Name?", "input_type": "string", "required": true }
+    });
+
+    assert!(matches!(
+        parse_workflow(&task(json!({ "ttl_seconds": 0 }), echo.clone())),
+        Err(ParserError::InvalidTask(msg)) if msg.contains("ttl_seconds")
+    ));
+    assert!(matches!(
+        parse_workflow(&task(json!({}), echo.clone())),
+        Err(ParserError::InvalidTask(msg)) if msg.contains("Invalid cache options")
+    ));
+    assert!(matches!(
+        parse_workflow(&task(json!({ "ttl_seconds": 60 }), input.clone())),
+        Err(ParserError::InvalidTask(msg)) if msg.contains("user_input")
+    ));
+
+    let cache_of =
+        |cache: Value| parse_workflow(&task(cache, echo.clone())).unwrap().tasks[0].cache;
+    assert_eq!(
+        cache_of(json!(true)),
+        Some(TaskCache {
+            ttl_seconds: TaskCache::DEFAULT_TTL_SECONDS
+        })
+    );
+    assert_eq!(cache_of(json!(false)), None);
+    assert!(matches!(
+        parse_workflow(&task(json!(true), input)),
+        Err(ParserError::InvalidTask(msg)) if msg.contains("user_input")
+    ));
+}
@@ -0,0 +1,143 @@
+use crate::*;
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct CollectingCheckpoints {
+    saved: Mutex<Vec<Checkpoint>>,
+}
+
+impl CollectingCheckpoints {
+    fn last(&self) -> Checkpoint {
+        self.saved.lock().unwrap().last().cloned().unwrap()
+    }
+}
+
+#[async_trait]
+impl CheckpointStore for CollectingCheckpoints {
+    async fn save(&self, checkpoint: &Checkpoint) {
+        self.saved.lock().unwrap().push(checkpoint.clone());
+    }
+}
+
+/// build → test → deploy, where test passes once `marker` exists.
+fn release_workflow(marker: &Path) -> EngineWorkflow {
+    let json = serde_json::json!({
+        "id": "release",
+        "name": "Release",
+        "tasks": [{
+            "id": "build",
+            "name": "Build",
+            "function": { "name": "cli_command", "input": { "command": "echo", "args": ["built"] } },
+            "next_tasks": [{
+                "id": "test",
+                "name": "Test",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "test", "args": ["-f", marker.to_str().unwrap()] }
+                },
+                "next_tasks": [{
+                    "id": "deploy",
+                    "name": "Deploy",
+                    "function": {
+                        "name": "cli_command",
+                        "input": { "command": "echo", "args": ["deployed"] }
+                    }
+                }]
+            }]
+        }]
+    });
+    parse_workflow(&json.to_string()).unwrap()
+}
+
+fn ran(result: &WorkflowResult) -> Vec<&str> {
+    result
+        .audit_trail
+        .iter()
+        .map(|entry| entry.task_id.as_str())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_checkpoint_saved_after_each_round() {
+    let marker = std::env::temp_dir().join(format!("see-pass-{}", uuid::Uuid::new_v4()));
+    std::fs::write(&marker, "").unwrap();
+    let checkpoints = Arc::new(CollectingCheckpoints::default());
+    let result = WorkflowEngine::new()
+        .with_checkpoints(checkpoints.clone())
+        .with_execution_id("exec-checkpoint")
+        .execute_workflow(release_workflow(&marker))
+        .await
+        .unwrap();
+    std::fs::remove_file(&marker).unwrap();
+    assert!(result.success, "{:?}", result.errors);
+
+    let saved = checkpoints.saved.lock().unwrap();
+    let completed: Vec<Vec<&str>> = saved
+        .iter()
+        .map(|c| c.completed_tasks.iter().map(String::as_str).collect())
+        .collect();
+    assert_eq!(
+        completed,
+        vec![
+            vec!["build"],
+            vec!["build", "test"],
+            vec!["build", "deploy", "test"]
+        ]
+    );
+    assert!(saved.iter().all(|c| c.execution_id == "exec-checkpoint"));
+    assert!(saved[0].data.get("build").is_some());
+}
+
+#[tokio::test]
+async fn test_checkpoint_leaves_out_failed_tasks() {
+    let marker = std::env::temp_dir().join(format!("see-missing-{}", uuid::Uuid::new_v4()));
+    let checkpoints = Arc::new(CollectingCheckpoints::default());
+    let result = WorkflowEngine::new()
+        .with_checkpoints(checkpoints.clone())
+        .execute_workflow(release_workflow(&marker))
+        .await
+        .unwrap();
+    assert!(!result.success);
+
+    let checkpoint = checkpoints.last();
+    assert_eq!(
+        checkpoint.completed_tasks.into_iter().collect::<Vec<_>>(),
+        vec!["build", "deploy"]
+    );
+    assert!(checkpoint.data.get("test").is_none());
+}
+
+#[tokio::test]
+async fn test_resume_from_checkpoint_runs_only_failed_tasks() {
+    let marker = std::env::temp_dir().join(format!("see-pass-{}", uuid::Uuid::new_v4()));
+    let checkpoints = Arc::new(CollectingCheckpoints::default());
+    WorkflowEngine::new()
+        .with_checkpoints(checkpoints.clone())
+        .execute_workflow(release_workflow(&marker))
+        .await
+        .unwrap();
+    let checkpoint = checkpoints.last();
+
+    std::fs::write(&marker, "").unwrap();
+    let result = WorkflowEngine::new()
+        .with_initial_data(checkpoint.data.clone())
+        .resume_workflow_execution(
+            release_workflow(&marker),
+            checkpoint.execution_id.clone(),
+            checkpoint
+                .completed_tasks
+                .iter()
+                .cloned()
+                .collect::<HashSet<_>>(),
+            HashMap::new(),
+        )
+        .await
+        .unwrap();
+    std::fs::remove_file(&marker).unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    assert_eq!(ran(&result), vec!["test"]);
+}
@@ -0,0 +1,189 @@
+use crate::*;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+fn holds(source: &str, params: Value, data: Value) -> bool {
+    let params: Map<String, Value> = serde_json::from_value(params).unwrap();
+    Condition::parse(source).unwrap().evaluate(&params, &data)
+}
+
+fn echo_task(id: &str, when: Option<&str>, next: Vec<Value>) -> Value {
+    let mut task = json!({
+        "id": id,
+        "name": id,
+        "function": { "name": "cli_command", "input": { "command": "echo", "args": [id] } },
+        "next_tasks": next,
+    });
+    if let Some(when) = when {
+        task["when"] = json!(when);
+    }
+    task
+}
+
+fn release_workflow() -> EngineWorkflow {
+    let json = json!({
+        "id": "release",
+        "name": "Release",
+        "parameters": [{ "name": "env", "type": "string", "required": true }],
+        "tasks": [echo_task(
+            "build",
+            None,
+            vec![
+                echo_task("test", None, vec![]),
+                echo_task(
+                    "deploy",
+                    Some("param.env == \"prod\""),
+                    vec![echo_task("notify", None, vec![])],
+                ),
+            ],
+        )],
+    });
+    parse_workflow(&json.to_string()).unwrap()
+}
+
+fn statuses(result: &WorkflowResult) -> HashMap<String, TaskStatus> {
+    result
+        .tasks
+        .iter()
+        .map(|task| (task.id.clone(), task.status.clone()))
+        .collect()
+}
+
+#[test]
+fn test_condition_comparisons() {
+    let data = json!({ "build": { "status": "ok", "warnings": 3, "files": ["a", "b"] } });
+    let params = json!({ "env": "prod", "dry_run": false });
+
+    assert!(holds(
+        "data.build.status == \"ok\"",
+        params.clone(),
+        data.clone()
+    ));
+    assert!(holds(
+        "data.build.status != 'failed'",
+        params.clone(),
+        data.clone()
+    ));
+    assert!(holds(
+        "data.build.warnings < 5",
+        params.clone(),
+        data.clone()
+    ));
+    assert!(holds(
+        "data.build.warnings >= 3.0",
+        params.clone(),
+        data.clone()
+    ));
+    assert!(holds(
+        "data.build.files.1 == \"b\"",
+        params.clone(),
+        data.clone()
+    ));
+    assert!(!holds(
+        "data.build.warnings > \"2\"",
+        params.clone(),
+        data.clone()
+    ));
+    assert!(holds(
+        "param.env == \"prod\" && !param.dry_run",
+        params.clone(),
+        data.clone()
+    ));
+    assert!(holds(
+        "param.env == \"dev\" || (data.build && data.build.warnings == 3)",
+        params.clone(),
+        data.clone()
+    ));
+}
+
+#[test]
+fn test_condition_missing_values_are_null() {
+    let data = json!({ "build": { "status": "ok" } });
+
+    assert!(!holds("data.deploy.url", json!({}), data.clone()));
+    assert!(holds("data.deploy.url == null", json!({}), data.clone()));
+    assert!(!holds("param.env == \"prod\"", json!({}), data.clone()));
+    assert!(!holds("data.build.status.code > 1", json!({}), data));
+}
+
+#[test]
+fn test_condition_parse_errors() {
+    for source in [
+        "",
+        "data.build ==",
+        "build.status == \"ok\"",
+        "data.build == \"ok",
+        "(data.build",
+        "data. == 1",
+        "data.build = 1",
+        "data.build data.test",
+    ] {
+        assert!(Condition::parse(source).is_err(), "{:?} parsed", source);
+    }
+}
+
+#[test]
+fn test_condition_data_references() {
+    let condition =
+        Condition::parse("data.build.status == \"ok\" && data.lint || data.build.x").unwrap();
+    assert_eq!(condition.data_references(), vec!["build", "lint"]);
+
+    let workflow = release_workflow();
+    let mut task = workflow.tasks[0].clone();
+    task.when = Some("data.setup.ready".to_string());
+    assert!(data_references(&task).contains(&"setup".to_string()));
+}
+
+#[test]
+fn test_parse_rejects_invalid_when() {
+    let mut json = json!({
+        "id": "w",
+        "name": "W",
+        "tasks": [echo_task("build", Some("data.build.status === 1"), vec![])],
+    });
+    let err = parse_workflow(&json.to_string()).unwrap_err();
+    assert!(err.to_string().contains("build when is invalid"), "{}", err);
+
+    json["tasks"][0]["when"] = json!(true);
+    assert!(parse_workflow(&json.to_string()).is_err());
+}
+
+#[tokio::test]
+async fn test_unmet_condition_skips_the_task_and_what_follows() {
+    let result = WorkflowEngine::new()
+        .execute_workflow_with_params(
+            release_workflow(),
+            HashMap::from([("env".to_string(), json!("staging"))]),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    let statuses = statuses(&result);
+    assert_eq!(statuses["build"], TaskStatus::Complete);
+    assert_eq!(statuses["test"], TaskStatus::Complete);
+    assert_eq!(statuses["deploy"], TaskStatus::Skipped);
+    assert_eq!(statuses["notify"], TaskStatus::Skipped);
+    assert!(result
+        .audit_trail
+        .iter()
+        .all(|entry| entry.task_id != "deploy" && entry.task_id != "notify"));
+    assert!(result.per_task_logs["deploy"][0].contains("does not hold"));
+    assert!(result.per_task_logs["notify"][0].contains("'deploy' was skipped"));
+}
+
+#[tokio::test]
+async fn test_met_condition_runs_the_task() {
+    let result = WorkflowEngine::new()
+        .execute_workflow_with_params(
+            release_workflow(),
+            HashMap::from([("env".to_string(), json!("prod"))]),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    assert!(statuses(&result)
+        .values()
+        .all(|status| *status == TaskStatus::Complete));
+}
@@ -0,0 +1,125 @@
+use crate::diff::*;
+use crate::engine::*;
+use crate::parser::*;
+use crate::types::*;
+use serde_json::json;
+
+#[test]
+fn test_diff_identical_values() {
+    let value = json!({"a": 1, "b": {"c": [1, 2]}});
+    let diff = diff(&value, &value);
+
+    assert!(diff.changes.is_empty());
+    assert_eq!(diff.count, 0);
+    assert!(!diff.truncated);
+}
+
+#[test]
+fn test_diff_nested_objects() {
+    let before = json!({"build": {"status": "pending", "log": "x"}, "gone": true});
+    let after = json!({"build": {"status": "ok", "log": "x"}, "deploy": 1});
+    let diff = diff(&before, &after);
+
+    assert_eq!(diff.count, 3);
+    assert!(diff.changes.contains(&ContextChange {
+        op: ChangeOp::Replace,
+        path: "/build/status".to_string(),
+        before: Some(json!("pending")),
+        after: Some(json!("ok")),
+    }));
+    assert!(diff.changes.contains(&ContextChange {
+        op: ChangeOp::Remove,
+        path: "/gone".to_string(),
+        before: Some(json!(true)),
+        after: None,
+    }));
+    assert!(diff.changes.contains(&ContextChange {
+        op: ChangeOp::Add,
+        path: "/deploy".to_string(),
+        before: None,
+        after: Some(json!(1)),
+    }));
+}
+
+#[test]
+fn test_diff_escapes_pointer_segments() {
+    let diff = diff(&json!({}), &json!({"a/b~c": 1}));
+
+    assert_eq!(diff.changes[0].path, "/a~1b~0c");
+}
+
+#[test]
+fn test_diff_caps_change_count() {
+    let after: serde_json::Map<String, serde_json::Value> = (0..MAX_CHANGES + 10)
+        .map(|i| (format!("key{}", i), json!(i)))
+        .collect();
+    let diff = diff(&json!({}), &serde_json::Value::Object(after));
+
+    assert_eq!(diff.changes.len(), MAX_CHANGES);
+    assert_eq!(diff.count, MAX_CHANGES + 10);
+    assert!(diff.truncated);
+}
+
+#[test]
+fn test_diff_caps_value_size() {
+    let big = "x".repeat(MAX_VALUE_BYTES + 1);
+    let diff = diff(&json!({}), &json!({"log": big}));
+
+    assert!(diff.truncated);
+    let after = diff.changes[0].after.as_ref().unwrap().as_str().unwrap();
+    assert!(after.ends_with("bytes omitted>"));
+}
+
+#[test]
+fn test_apply_reproduces_after() {
+    let before = json!({"a": {"b": 1, "c": 2}, "d": "old"});
+    let after = json!({"a": {"b": 3}, "d": "new", "e/f": [1]});
+    let diff = diff(&before, &after);
+
+    let mut rebuilt = before.clone();
+    apply(&mut rebuilt, &diff.changes);
+    assert_eq!(rebuilt, after);
+}
+
+#[tokio::test]
+async fn test_audit_trail_records_task_output_changes() {
+    let json = r#"
+    {
+        "id": "diff",
+        "name": "Diff Workflow",
+        "tasks": [
+            {
+                "id": "greet",
+                "name": "Greet",
+                "function": {
+                    "name": "cli_command",
+                    "input": {
+                        "command": "echo",
+                        "args": ["hello"]
+                    }
+                }
+            }
+        ]
+    }
+    "#;
+
+    let workflow = parse_workflow(json).unwrap();
+    let result = WorkflowEngine::new()
+        .execute_workflow(workflow)
+        .await
+        .unwrap();
+
+    let entry = &result.audit_trail[0];
+    assert_eq!(entry.changes_count, 1);
+    assert_eq!(entry.changes[0].op, ChangeOp::Add);
+    assert_eq!(entry.changes[0].path, "/greet");
+    assert_eq!(
+        entry.changes[0]
+            .after
+            .as_ref()
+            .and_then(|v| v.as_str())
+            .map(str::trim),
+        Some("hello")
+    );
+    assert!(!entry.changes_truncated);
+}
@@ -0,0 +1,144 @@
+use crate::*;
+use serde_json::json;
+use std::path::PathBuf;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("s_e_e-{}-{}", name, uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_save_and_restore_roundtrip() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let cache = DirectoryCache::new(temp_dir("dir-cache"));
+    let source = temp_dir("cache-source");
+    std::fs::create_dir_all(source.join("deps/nested/empty")).unwrap();
+    std::fs::write(source.join("deps/nested/lib.txt"), "library").unwrap();
+    std::fs::write(source.join("deps/run.sh"), "#!/bin/sh\n").unwrap();
+    std::fs::set_permissions(
+        source.join("deps/run.sh"),
+        std::fs::Permissions::from_mode(0o755),
+    )
+    .unwrap();
+    std::os::unix::fs::symlink("nested/lib.txt", source.join("deps/link")).unwrap();
+
+    assert_eq!(cache.restore("deps-v1", &source).await.unwrap(), None);
+    let saved = cache
+        .save(
+            "deps-v1",
+            &source,
+            &["deps".to_string(), "missing".to_string()],
+        )
+        .await
+        .unwrap();
+    assert_eq!(saved, 17);
+
+    let target = temp_dir("cache-target");
+    std::fs::create_dir_all(target.join("deps")).unwrap();
+    std::fs::write(target.join("deps/run.sh"), "stale").unwrap();
+    let restored = cache.restore("deps-v1", &target).await.unwrap();
+
+    assert_eq!(restored, Some(17));
+    assert_eq!(
+        std::fs::read_to_string(target.join("deps/link")).unwrap(),
+        "library"
+    );
+    assert_eq!(
+        std::fs::read_to_string(target.join("deps/run.sh")).unwrap(),
+        "#!/bin/sh\n"
+    );
+    let mode = std::fs::metadata(target.join("deps/run.sh"))
+        .unwrap()
+        .permissions()
+        .mode();
+    assert_eq!(mode & 0o777, 0o755);
+    assert!(target.join("deps/nested/empty").is_dir());
+    assert!(!target.join("missing").exists());
+    assert_eq!(cache.restore("deps-v2", &target).await.unwrap(), None);
+
+    for dir in [source, target] {
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
+
+#[test]
+fn test_cache_paths_must_stay_inside_the_working_directory() {
+    let workflow = |paths: serde_json::Value| {
+        json!({
+            "id": "cached",
+            "name": "Cached",
+            "tasks": [{
+                "id": "deps",
+                "name": "Deps",
+                "function": { "name": "cache", "input": { "key": "deps", "paths": paths } }
+            }]
+        })
+        .to_string()
+    };
+
+    assert!(parse_workflow(&workflow(json!(["node_modules", "./target"]))).is_ok());
+    for paths in [
+        json!([]),
+        json!(["../outside"]),
+        json!(["/etc"]),
+        json!([1]),
+    ] {
+        assert!(
+            parse_workflow(&workflow(paths.clone())).is_err(),
+            "{}",
+            paths
+        );
+    }
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_cache_task_restores_what_a_successful_run_saved() {
+    let cache = DirectoryCache::new(temp_dir("dir-cache"));
+    let key = format!("deps-{}", uuid::Uuid::new_v4());
+    let json = json!({
+        "id": "build",
+        "name": "Build",
+        "isolated_workspace": true,
+        "parameters": [{ "name": "key", "required": true }],
+        "tasks": [{
+            "id": "deps",
+            "name": "Deps",
+            "function": { "name": "cache", "input": { "key": "{{param.key}}", "paths": ["deps"] } },
+            "next_tasks": [{
+                "id": "install",
+                "name": "Install",
+                "function": {
+                    "name": "cli_command",
+                    "input": {
+                        "command": "sh",
+                        "args": ["-c", "test -f deps/ok || { mkdir deps && echo installed > deps/ok; }; cat deps/ok"]
+                    }
+                }
+            }]
+        }]
+    });
+    let workflow = parse_workflow(&json.to_string()).unwrap();
+    let engine = WorkflowEngine::new().with_directory_cache(cache);
+    let params: std::collections::HashMap<_, _> =
+        [("key".to_string(), json!(key))].into_iter().collect();
+    let run = || engine.execute_workflow_with_params(workflow.clone(), params.clone());
+
+    let first = run().await.unwrap();
+    assert!(first.success, "{:?}", first.errors);
+    let logs = first.per_task_logs["deps"].join("\n");
+    assert!(logs.contains("\"hit\":false"));
+    assert!(logs.contains(&format!("Saved cache '{}'", key)));
+
+    let second = run().await.unwrap();
+    assert!(second.success, "{:?}", second.errors);
+    let logs = second.per_task_logs["deps"].join("\n");
+    assert!(logs.contains("\"hit\":true"));
+    assert!(!logs.contains("Saved cache"));
+    assert!(second.per_task_logs["install"]
+        .join("\n")
+        .contains("installed"));
+}
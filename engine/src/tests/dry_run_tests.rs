@@ -0,0 +1,179 @@
+use crate::handlers::{HandlerRegistry, TaskHandler};
+use crate::*;
+use async_trait::async_trait;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Counts its calls, so a test can tell whether a handler ran.
+struct CountingHandler(Arc<AtomicUsize>);
+
+#[async_trait]
+impl TaskHandler for CountingHandler {
+    async fn execute(
+        &self,
+        _context: &mut ExecutionContext,
+        _task: &EngineTask,
+    ) -> Result<TaskResult, HandlerError> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        Ok(TaskResult {
+            success: true,
+            output: json!({ "ok": true }),
+            error: None,
+            failure_reason: None,
+            output_stats: None,
+            exit_code: None,
+            environment: None,
+        })
+    }
+}
+
+/// build → deploy (reads build's output), notify (only in dev) and
+/// announce (only when build says ok).
+fn release_workflow(marker: &str) -> EngineWorkflow {
+    let json = json!({
+        "id": "release",
+        "name": "Release",
+        "parameters": [{ "name": "env", "type": "string", "required": true }],
+        "tasks": [{
+            "id": "build",
+            "name": "Build",
+            "function": {
+                "name": "cli_command",
+                "input": { "command": "touch", "args": [marker] }
+            },
+            "next_tasks": [{
+                "id": "deploy",
+                "name": "Deploy",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "deploy", "args": ["${param.env}", "${data.build.version}"] }
+                }
+            }, {
+                "id": "notify",
+                "name": "Notify",
+                "when": "param.env == \"dev\"",
+                "function": { "name": "count", "input": {} }
+            }, {
+                "id": "announce",
+                "name": "Announce",
+                "when": "data.build.ok == true",
+                "function": { "name": "count", "input": { "env": "${param.env}" } }
+            }]
+        }]
+    });
+    parse_workflow(&json.to_string()).unwrap()
+}
+
+fn prod() -> HashMap<String, serde_json::Value> {
+    HashMap::from([("env".to_string(), json!("prod"))])
+}
+
+#[tokio::test]
+async fn test_dry_run_calls_no_handler() {
+    let marker = std::env::temp_dir().join(format!("dry-run-{}", uuid::Uuid::new_v4()));
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut handlers = HandlerRegistry::new();
+    handlers.register_handler(
+        "count".to_string(),
+        Box::new(CountingHandler(calls.clone())),
+    );
+
+    let result = WorkflowEngine::with_handlers(handlers)
+        .with_dry_run()
+        .execute_workflow_with_params(release_workflow(marker.to_str().unwrap()), prod())
+        .await
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    assert!(!marker.exists());
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    assert!(result
+        .audit_trail
+        .iter()
+        .all(|entry| entry.changes_count == 0));
+}
+
+#[tokio::test]
+async fn test_dry_run_plans_tasks_with_filled_in_inputs() {
+    let result = WorkflowEngine::new()
+        .with_dry_run()
+        .execute_workflow_with_params(release_workflow("/tmp/never-touched"), prod())
+        .await
+        .unwrap();
+
+    let planned: Vec<&str> = result.planned.iter().map(|t| t.task_id.as_str()).collect();
+    assert_eq!(planned, ["build", "deploy", "announce"]);
+
+    let build = &result.planned[0];
+    assert_eq!(build.handler, "cli_command");
+    assert_eq!(build.action, "Would run: touch /tmp/never-touched");
+    assert!(build.unresolved.is_empty());
+
+    let deploy = &result.planned[1];
+    assert_eq!(
+        deploy.action,
+        "Would run: deploy prod '${data.build.version}'"
+    );
+    assert_eq!(deploy.unresolved, ["${data.build.version}"]);
+
+    let announce = &result.planned[2];
+    assert_eq!(announce.unresolved, ["when data.build.ok == true"]);
+    match &announce.function {
+        TaskFunction::Custom { input, .. } => assert_eq!(input, &json!({ "env": "prod" })),
+        other => panic!("unexpected function {:?}", other),
+    }
+
+    let notify = result.tasks.iter().find(|t| t.id == "notify").unwrap();
+    assert_eq!(notify.status, TaskStatus::Skipped);
+    assert_eq!(
+        result.per_task_logs["build"],
+        ["[dry run] Would run: touch /tmp/never-touched"]
+    );
+}
+
+#[tokio::test]
+async fn test_dry_run_fails_tasks_with_bad_placeholders() {
+    let json = json!({
+        "id": "broken",
+        "name": "Broken",
+        "tasks": [{
+            "id": "greet",
+            "name": "Greet",
+            "function": {
+                "name": "cli_command",
+                "input": { "command": "echo", "args": ["${param.missing}"] }
+            }
+        }]
+    });
+    let result = WorkflowEngine::new()
+        .with_dry_run()
+        .execute_workflow(parse_workflow(&json.to_string()).unwrap())
+        .await
+        .unwrap();
+
+    assert!(!result.success);
+    assert!(result.planned.is_empty());
+    assert!(result.errors[0].contains("unknown workflow parameter 'missing'"));
+}
+
+#[tokio::test]
+async fn test_real_run_plans_nothing() {
+    let json = json!({
+        "id": "echo",
+        "name": "Echo",
+        "tasks": [{
+            "id": "greet",
+            "name": "Greet",
+            "function": { "name": "cli_command", "input": { "command": "echo", "args": ["hi"] } }
+        }]
+    });
+    let result = WorkflowEngine::new()
+        .execute_workflow(parse_workflow(&json.to_string()).unwrap())
+        .await
+        .unwrap();
+
+    assert!(result.success);
+    assert!(result.planned.is_empty());
+}
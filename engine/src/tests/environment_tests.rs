@@ -0,0 +1,84 @@
+use crate::handlers::cli_command::CliCommandHandler;
+use crate::handlers::environment::{environment_snapshot, REDACTED};
+use crate::handlers::shell::build_command;
+use crate::handlers::TaskHandler;
+use crate::types::*;
+
+#[test]
+fn test_snapshot_includes_command_overrides() {
+    let mut cmd = build_command("echo", &[]);
+    cmd.env("S_E_E_SNAPSHOT_VAR", "visible");
+    cmd.env_remove("PATH");
+
+    let snapshot = environment_snapshot(&cmd);
+
+    assert_eq!(
+        snapshot.get("S_E_E_SNAPSHOT_VAR").map(String::as_str),
+        Some("visible")
+    );
+    assert!(!snapshot.contains_key("PATH"));
+}
+
+#[test]
+fn test_snapshot_redacts_credentials() {
+    let mut cmd = build_command("echo", &[]);
+    cmd.env("GITHUB_TOKEN", "ghp_abc")
+        .env("AWS_SECRET_ACCESS_KEY", "abc")
+        .env("DB_PASSWORD", "hunter2")
+        .env("OPENAI_API_KEY", "sk-abc")
+        .env(
+            "DATABASE_URL",
+            "postgres://app:hunter2@db:5432/app?sslmode=require",
+        )
+        .env("MIRROR_URL", "https://mirror.example.com/repo")
+        .env("EDITOR", "vim");
+
+    let snapshot = environment_snapshot(&cmd);
+
+    for name in [
+        "GITHUB_TOKEN",
+        "AWS_SECRET_ACCESS_KEY",
+        "DB_PASSWORD",
+        "OPENAI_API_KEY",
+    ] {
+        assert_eq!(snapshot[name], REDACTED, "{} was not redacted", name);
+    }
+    assert_eq!(
+        snapshot["DATABASE_URL"],
+        "postgres://app:[redacted]@db:5432/app?sslmode=require"
+    );
+    assert_eq!(snapshot["MIRROR_URL"], "https://mirror.example.com/repo");
+    assert_eq!(snapshot["EDITOR"], "vim");
+}
+
+#[tokio::test]
+async fn test_cli_command_records_environment() {
+    let mut context = ExecutionContext::new("test".to_string(), "test_workflow".to_string());
+    let task = EngineTask {
+        id: "env_task".to_string(),
+        name: "Env Task".to_string(),
+        function: TaskFunction::CliCommand {
+            command: "echo".to_string(),
+            args: vec!["hello".to_string()],
+            process: ProcessOptions::default(),
+        },
+        next_tasks: Vec::new(),
+        status: TaskStatus::Pending,
+        is_root: true,
+        cache: None,
+        stage: None,
+        lock: None,
+        timeout_seconds: None,
+        when: None,
+        for_each: None,
+        speculative: None,
+    };
+
+    let result = CliCommandHandler
+        .execute(&mut context, &task)
+        .await
+        .unwrap();
+
+    let environment = result.environment.unwrap();
+    assert_eq!(environment.get("PATH"), std::env::var("PATH").ok().as_ref());
+}
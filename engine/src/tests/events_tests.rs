@@ -0,0 +1,95 @@
+use crate::engine::WorkflowEngine;
+use crate::events::{OutputEvent, OutputEvents, OutputSink};
+use crate::parser::parse_workflow;
+use std::sync::{Arc, Mutex};
+
+fn collecting_sink() -> (OutputSink, Arc<Mutex<Vec<OutputEvent>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let collected = Arc::clone(&events);
+    let sink: OutputSink = Arc::new(move |event| collected.lock().unwrap().push(event));
+    (sink, events)
+}
+
+#[test]
+fn test_clones_share_sequence_numbers() {
+    let (sink, events) = collecting_sink();
+    let output = OutputEvents::new("exec-1", sink);
+    let clone = output.clone();
+
+    output.message("starting");
+    clone.task_line("a", "Task A", "from a");
+    output.task_line("b", "Task B", "from b");
+
+    let events = events.lock().unwrap();
+    let sequences: Vec<u64> = events.iter().map(|e| e.sequence).collect();
+    assert_eq!(sequences, vec![0, 1, 2]);
+    assert!(events.iter().all(|e| e.execution_id == "exec-1"));
+    assert_eq!(events[0].task_id, None);
+    assert_eq!(events[1].task_id.as_deref(), Some("a"));
+    assert_eq!(events[2].task_name.as_deref(), Some("Task B"));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_parallel_task_output_is_tagged_and_ordered() {
+    let json = r#"
+    {
+        "id": "parallel",
+        "name": "Parallel Output",
+        "tasks": [
+            {
+                "id": "left",
+                "name": "Left",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "sh", "args": ["-c", "for i in 1 2 3; do echo left $i; done"] }
+                }
+            },
+            {
+                "id": "right",
+                "name": "Right",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "sh", "args": ["-c", "for i in 1 2 3; do echo right $i; done"] }
+                }
+            }
+        ]
+    }
+    "#;
+
+    let (sink, events) = collecting_sink();
+    let engine = WorkflowEngine::new().with_output(OutputEvents::new("exec-parallel", sink));
+    let result = engine
+        .execute_workflow(parse_workflow(json).unwrap())
+        .await
+        .unwrap();
+    assert!(result.success);
+
+    let events = events.lock().unwrap();
+    let sequences: Vec<u64> = events.iter().map(|e| e.sequence).collect();
+    assert_eq!(sequences, (0..events.len() as u64).collect::<Vec<_>>());
+
+    for (task_id, task_name) in [("left", "Left"), ("right", "Right")] {
+        let lines: Vec<&str> = events
+            .iter()
+            .filter(|e| e.task_id.as_deref() == Some(task_id))
+            .inspect(|e| assert_eq!(e.task_name.as_deref(), Some(task_name)))
+            .map(|e| e.line.as_str())
+            .filter(|line| line.starts_with(task_id))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                format!("{} 1", task_id),
+                format!("{} 2", task_id),
+                format!("{} 3", task_id)
+            ]
+        );
+        let last = events
+            .iter()
+            .rfind(|e| e.task_id.as_deref() == Some(task_id))
+            .unwrap();
+        assert_eq!(last.finished, Some(true));
+    }
+    assert_eq!(events.iter().filter(|e| e.finished.is_some()).count(), 2);
+}
@@ -11,11 +11,26 @@ async fn test_per_task_logs_captured() {
             function: TaskFunction::CliCommand {
                 command: "echo".to_string(),
                 args: vec!["Hello World".to_string()],
+                process: ProcessOptions::default(),
             },
             next_tasks: vec![],
             status: TaskStatus::Pending,
             is_root: true,
+            cache: None,
+            stage: None,
+            lock: None,
+            timeout_seconds: None,
+            when: None,
+            for_each: None,
+            speculative: None,
         }],
+        parameters: Vec::new(),
+        triggers: Vec::new(),
+        workspace: None,
+        preflight: Vec::new(),
+        stages: Vec::new(),
+        outputs: Default::default(),
+        notify: Vec::new(),
     };
 
     let engine = WorkflowEngine::new();
@@ -39,10 +54,18 @@ async fn test_parallel_tasks_separate_logs() {
                 function: TaskFunction::CliCommand {
                     command: "echo".to_string(),
                     args: vec!["Task1Output".to_string()],
+                    process: ProcessOptions::default(),
                 },
                 next_tasks: vec![],
                 status: TaskStatus::Pending,
                 is_root: true,
+                cache: None,
+                stage: None,
+                lock: None,
+                timeout_seconds: None,
+                when: None,
+                for_each: None,
+                speculative: None,
             },
             EngineTask {
                 id: "task2".to_string(),
@@ -50,12 +73,27 @@ async fn test_parallel_tasks_separate_logs() {
                 function: TaskFunction::CliCommand {
                     command: "echo".to_string(),
                     args: vec!["Task2Output".to_string()],
+                    process: ProcessOptions::default(),
                 },
                 next_tasks: vec![],
                 status: TaskStatus::Pending,
                 is_root: true,
+                cache: None,
+                stage: None,
+                lock: None,
+                timeout_seconds: None,
+                when: None,
+                for_each: None,
+                speculative: None,
             },
         ],
+        parameters: Vec::new(),
+        triggers: Vec::new(),
+        workspace: None,
+        preflight: Vec::new(),
+        stages: Vec::new(),
+        outputs: Default::default(),
+        notify: Vec::new(),
     };
 
     let engine = WorkflowEngine::new();
@@ -81,11 +119,26 @@ async fn test_error_capture_in_logs() {
             function: TaskFunction::CliCommand {
                 command: "nonexistent_command_xyz".to_string(),
                 args: vec![],
+                process: ProcessOptions::default(),
             },
             next_tasks: vec![],
             status: TaskStatus::Pending,
             is_root: true,
+            cache: None,
+            stage: None,
+            lock: None,
+            timeout_seconds: None,
+            when: None,
+            for_each: None,
+            speculative: None,
         }],
+        parameters: Vec::new(),
+        triggers: Vec::new(),
+        workspace: None,
+        preflight: Vec::new(),
+        stages: Vec::new(),
+        outputs: Default::default(),
+        notify: Vec::new(),
     };
 
     let engine = WorkflowEngine::new();
@@ -95,3 +148,74 @@ async fn test_error_capture_in_logs() {
     let logs = &result.per_task_logs["failing_task"];
     assert!(logs.iter().any(|log| log.contains("Error:")));
 }
+
+fn workspace_workflow(script: &str, template: Option<&std::path::Path>) -> EngineWorkflow {
+    let mut json = serde_json::json!({
+        "id": "isolated",
+        "name": "Isolated",
+        "isolated_workspace": true,
+        "tasks": [{
+            "id": "work",
+            "name": "Work",
+            "function": {
+                "name": "cli_command",
+                "input": { "command": "sh", "args": ["-c", script] }
+            }
+        }]
+    });
+    if let Some(template) = template {
+        json["workspace_template"] = serde_json::json!(template.to_str().unwrap());
+    }
+    parse_workflow(&json.to_string()).unwrap()
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_isolated_workspace_is_seeded_and_removed_on_success() {
+    let template = std::env::temp_dir().join(format!("see-template-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(template.join("nested")).unwrap();
+    std::fs::write(template.join("nested/seed.txt"), "seeded").unwrap();
+
+    let execution_id = uuid::Uuid::new_v4().to_string();
+    let engine = WorkflowEngine::new().with_execution_id(execution_id.clone());
+    let workflow = workspace_workflow("cat nested/seed.txt && pwd", Some(&template));
+    let result = engine.execute_workflow(workflow).await.unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    let logs = result.per_task_logs["work"].join("\n");
+    assert!(logs.contains("seeded"));
+    assert!(logs.contains(&execution_id));
+    assert_eq!(result.workspace, None);
+    assert!(!crate::workspace::workspace_path(&execution_id).exists());
+    assert!(template.join("nested/seed.txt").exists());
+
+    std::fs::remove_dir_all(template).unwrap();
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_isolated_workspace_is_kept_on_failure() {
+    let engine = WorkflowEngine::new();
+    let workflow = workspace_workflow("echo partial > out.txt; exit 3", None);
+    let result = engine.execute_workflow(workflow).await.unwrap();
+
+    assert!(!result.success);
+    let workspace = std::path::PathBuf::from(result.workspace.expect("workspace is kept"));
+    assert_eq!(
+        std::fs::read_to_string(workspace.join("out.txt")).unwrap(),
+        "partial\n"
+    );
+
+    std::fs::remove_dir_all(workspace).unwrap();
+}
+
+#[tokio::test]
+async fn test_missing_workspace_template_fails_the_run() {
+    let engine = WorkflowEngine::new();
+    let missing = std::env::temp_dir().join(format!("see-missing-{}", uuid::Uuid::new_v4()));
+    let workflow = workspace_workflow("true", Some(&missing));
+
+    let result = engine.execute_workflow(workflow).await;
+
+    assert!(matches!(result, Err(EngineError::Execution(message)) if message.contains("template")));
+}
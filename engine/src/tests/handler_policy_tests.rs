@@ -0,0 +1,105 @@
+use crate::*;
+use std::collections::BTreeSet;
+
+fn names(handlers: &[&str]) -> BTreeSet<String> {
+    handlers.iter().map(|name| name.to_string()).collect()
+}
+
+fn policy(allowed: Option<&[&str]>, denied: &[&str]) -> HandlerPolicy {
+    HandlerPolicy {
+        profile: "restricted".to_string(),
+        allowed: allowed.map(names),
+        denied: names(denied),
+    }
+}
+
+fn workflow(root_handler: &str, next_handler: &str) -> EngineWorkflow {
+    let json = format!(
+        r#"{{
+            "id": "policy",
+            "name": "Policy",
+            "tasks": [{{
+                "id": "first",
+                "name": "First",
+                "function": {{"name": "{}", "input": {{"command": "true"}}}},
+                "next_tasks": [{{
+                    "id": "second",
+                    "name": "Second",
+                    "function": {{"name": "{}", "input": {{"command": "true"}}}}
+                }}]
+            }}]
+        }}"#,
+        root_handler, next_handler
+    );
+    parse_workflow(&json).unwrap()
+}
+
+#[test]
+fn test_denied_handler_is_blocked() {
+    let policy = policy(None, &["cli_command", "docker"]);
+    assert_eq!(
+        policy.blocking_list("cli_command"),
+        Some(PolicyList::Denied)
+    );
+    assert_eq!(policy.blocking_list("docker"), Some(PolicyList::Denied));
+    assert_eq!(policy.blocking_list("db_query"), None);
+}
+
+#[test]
+fn test_allow_list_blocks_everything_else() {
+    let policy = policy(Some(&["db_query", "cli_command"]), &["cli_command"]);
+    assert_eq!(policy.blocking_list("db_query"), None);
+    assert_eq!(policy.blocking_list("cache"), Some(PolicyList::Allowed));
+    // Deny wins over allow.
+    assert_eq!(
+        policy.blocking_list("cli_command"),
+        Some(PolicyList::Denied)
+    );
+}
+
+#[test]
+fn test_custom_covers_every_custom_handler() {
+    let denied = policy(None, &["custom"]);
+    assert_eq!(denied.blocking_list("terraform"), Some(PolicyList::Denied));
+    assert_eq!(denied.blocking_list("cli_command"), None);
+
+    let allowed = policy(Some(&["custom"]), &[]);
+    assert_eq!(allowed.blocking_list("terraform"), None);
+    assert_eq!(
+        allowed.blocking_list("cli_command"),
+        Some(PolicyList::Allowed)
+    );
+}
+
+#[test]
+fn test_check_tasks_finds_nested_task() {
+    let policy = policy(None, &["cli_command"]);
+    let violation = policy
+        .check_tasks(&workflow("lint", "cli_command").tasks)
+        .unwrap_err();
+
+    assert_eq!(violation.task_id, "second");
+    assert_eq!(violation.handler, "cli_command");
+    assert_eq!(violation.list, PolicyList::Denied);
+    let message = violation.to_string();
+    assert!(message.contains("denied_handlers"), "{}", message);
+    assert!(message.contains("'restricted'"), "{}", message);
+}
+
+#[tokio::test]
+async fn test_engine_refuses_blocked_workflow() {
+    // Deny a handler no other test uses, since the policy is process-wide.
+    set_handler_policy(Some(policy(None, &["policy_test_forbidden"])));
+    let result = WorkflowEngine::new()
+        .execute_workflow(workflow("cli_command", "policy_test_forbidden"))
+        .await;
+    set_handler_policy(None);
+
+    let error = result.unwrap_err();
+    assert_eq!(error.category(), ErrorCategory::Policy);
+    assert!(
+        matches!(&error, EngineError::Policy(violation) if violation.task_id == "second"),
+        "{:?}",
+        error
+    );
+}
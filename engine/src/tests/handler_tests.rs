@@ -14,6 +14,13 @@ fn create_test_task(function: TaskFunction) -> EngineTask {
         next_tasks: Vec::new(),
         status: TaskStatus::Pending,
         is_root: true,
+        cache: None,
+        stage: None,
+        lock: None,
+        timeout_seconds: None,
+        when: None,
+        for_each: None,
+        speculative: None,
     }
 }
 
@@ -25,6 +32,7 @@ async fn test_cli_command_handler() {
     let task = create_test_task(TaskFunction::CliCommand {
         command: "echo".to_string(),
         args: vec!["hello".to_string()],
+        process: ProcessOptions::default(),
     });
 
     let result = handler.execute(&mut context, &task).await.unwrap();
@@ -40,6 +48,7 @@ async fn test_cli_command_handler() {
     assert!(logs.iter().any(|log| log.contains("hello")));
 }
 
+#[cfg(unix)]
 #[tokio::test]
 async fn test_cli_command_handler_error() {
     let handler = CliCommandHandler;
@@ -48,6 +57,7 @@ async fn test_cli_command_handler_error() {
     let task = create_test_task(TaskFunction::CliCommand {
         command: "nonexistent_command_xyz".to_string(),
         args: vec![],
+        process: ProcessOptions::default(),
     });
 
     let result = handler.execute(&mut context, &task).await;
@@ -75,6 +85,7 @@ async fn test_cursor_agent_invalid_function_type() {
     let task = create_test_task(TaskFunction::CliCommand {
         command: "echo".to_string(),
         args: vec!["hello".to_string()],
+        process: ProcessOptions::default(),
     });
 
     let result = handler.execute(&mut context, &task).await;
@@ -144,6 +155,7 @@ async fn test_custom_handler_invalid_function() {
     let task = create_test_task(TaskFunction::CliCommand {
         command: "echo".to_string(),
         args: vec!["hello".to_string()],
+        process: ProcessOptions::default(),
     });
 
     let result = handler.execute(&mut context, &task).await;
@@ -237,6 +249,7 @@ async fn test_user_input_handler_invalid_function() {
     let task = create_test_task(TaskFunction::CliCommand {
         command: "echo".to_string(),
         args: vec!["hello".to_string()],
+        process: ProcessOptions::default(),
     });
 
     let result = handler.execute(&mut context, &task).await;
@@ -249,3 +262,67 @@ async fn test_user_input_handler_invalid_function() {
         _ => panic!("Expected InvalidConfiguration error"),
     }
 }
+
+#[tokio::test]
+async fn test_sandboxed_registry_records_commands() {
+    let registry = HandlerRegistry::sandboxed();
+    let mut context = ExecutionContext::new("test".to_string(), "test_workflow".to_string());
+    let marker = std::env::temp_dir().join(format!("see-sandbox-{}", uuid::Uuid::new_v4()));
+
+    let task = create_test_task(TaskFunction::CliCommand {
+        command: "touch".to_string(),
+        args: vec![marker.to_string_lossy().into_owned()],
+        process: ProcessOptions::default(),
+    });
+    let handler = registry.get_handler("cli_command").unwrap();
+    let result = handler.execute(&mut context, &task).await.unwrap();
+
+    assert!(result.success);
+    assert!(!marker.exists());
+    let output = result.output.as_str().unwrap();
+    assert!(
+        output.starts_with("[sandbox] Would run: touch "),
+        "{output}"
+    );
+    assert!(context.per_task_logs["test_task"].contains(&output.to_string()));
+}
+
+#[tokio::test]
+async fn test_sandboxed_registry_keeps_pure_handlers() {
+    let registry = HandlerRegistry::sandboxed();
+    let mut context = ExecutionContext::new("test".to_string(), "test_workflow".to_string());
+
+    let task = create_test_task(TaskFunction::Custom {
+        name: "transform".to_string(),
+        input: Value::String("data".to_string()),
+    });
+    let result = registry
+        .get_handler("custom")
+        .unwrap()
+        .execute(&mut context, &task)
+        .await
+        .unwrap();
+
+    assert!(result.output.as_str().unwrap().contains("transform"));
+}
+
+#[test]
+fn test_describe_action_quotes_arguments() {
+    let task = create_test_task(TaskFunction::CliCommand {
+        command: "echo".to_string(),
+        args: vec![
+            "hello world".to_string(),
+            "it's".to_string(),
+            "plain".to_string(),
+        ],
+        process: ProcessOptions {
+            timeout_ms: Some(500),
+            ..Default::default()
+        },
+    });
+
+    assert_eq!(
+        crate::handlers::sandbox::describe_action(&task),
+        r#"Would run: echo 'hello world' 'it'\''s' plain (timeout 500 ms)"#
+    );
+}
@@ -0,0 +1,207 @@
+use crate::*;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+fn params() -> Map<String, Value> {
+    serde_json::from_value(json!({ "env": "prod", "replicas": 3 })).unwrap()
+}
+
+fn data() -> Value {
+    json!({ "build": { "version": "1.2.3", "artifacts": ["app.tar", "app.sig"], "size": 42 } })
+}
+
+#[test]
+fn test_interpolate_string() {
+    let fill = |text: &str| interpolate_string(text, &params(), &data());
+
+    assert_eq!(
+        fill("deploy ${data.build.version} to ${param.env}").unwrap(),
+        "deploy 1.2.3 to prod"
+    );
+    assert_eq!(fill("${data.build.artifacts.1}").unwrap(), "app.sig");
+    assert_eq!(fill("x${param.replicas}").unwrap(), "x3");
+    assert_eq!(
+        fill("${data.build.artifacts}").unwrap(),
+        r#"["app.tar","app.sig"]"#
+    );
+    assert_eq!(
+        fill("echo ${HOME} $${data.build}").unwrap(),
+        "echo ${HOME} ${data.build}"
+    );
+    assert_eq!(fill("no placeholders").unwrap(), "no placeholders");
+
+    let error = fill("${data.test.report}").unwrap_err();
+    assert!(error.contains("no output from task 'test'"), "{}", error);
+    assert!(fill("${data.build.missing}")
+        .unwrap_err()
+        .contains("no field 'missing'"));
+    assert!(fill("${param.region}")
+        .unwrap_err()
+        .contains("unknown workflow parameter"));
+}
+
+#[test]
+fn test_whole_placeholder_keeps_its_type() {
+    let input = json!({
+        "size": "${data.build.size}",
+        "files": ["${data.build.artifacts}"],
+        "label": "v${data.build.version}",
+        "count": 1,
+    });
+    assert_eq!(
+        interpolate_value(&input, &params(), &data()).unwrap(),
+        json!({
+            "size": 42,
+            "files": [["app.tar", "app.sig"]],
+            "label": "v1.2.3",
+            "count": 1,
+        })
+    );
+}
+
+#[test]
+fn test_placeholder_syntax_errors() {
+    for text in [
+        "${data.build",
+        "${data.}",
+        "${data.build..version}",
+        "${param.env.name}",
+        "${data.build version}",
+    ] {
+        assert!(
+            check_placeholders(&json!(text)).is_err(),
+            "{} should be rejected",
+            text
+        );
+    }
+    assert!(check_placeholders(&json!(["${HOME}", "$${data.", { "a": "${param.env}" }])).is_ok());
+}
+
+fn workflow(args: Value) -> String {
+    json!({
+        "id": "deploy",
+        "name": "Deploy",
+        "parameters": [{ "name": "env", "type": "string", "required": true }],
+        "tasks": [{
+            "id": "version",
+            "name": "Version",
+            "function": { "name": "cli_command", "input": { "command": "echo", "args": ["1.2.3"] } },
+            "next_tasks": [{
+                "id": "deploy",
+                "name": "Deploy",
+                "function": { "name": "cli_command", "input": { "command": "echo", "args": args } },
+            }],
+        }],
+    })
+    .to_string()
+}
+
+#[test]
+fn test_invalid_placeholder_fails_validation() {
+    let error = parse_workflow(&workflow(json!(["${data.version"]))).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("Task deploy has an invalid placeholder"),
+        "{}",
+        error
+    );
+    assert_eq!(
+        EngineError::from(error).category(),
+        ErrorCategory::Validation
+    );
+}
+
+#[test]
+fn test_placeholders_count_as_data_references() {
+    let workflow = parse_workflow(&workflow(json!(["${data.version}", "${HOME}"]))).unwrap();
+    let deploy = workflow.tasks.iter().find(|t| t.id == "deploy").unwrap();
+    assert_eq!(data_references(deploy), ["version"]);
+}
+
+#[tokio::test]
+async fn test_placeholders_are_filled_in_before_the_task_runs() {
+    let workflow = parse_workflow(&workflow(json!([
+        "to ${param.env}",
+        "version ${data.version}"
+    ])))
+    .unwrap();
+    let result = WorkflowEngine::new()
+        .execute_workflow_with_params(
+            workflow,
+            HashMap::from([("env".to_string(), json!("staging"))]),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    assert!(
+        result.per_task_logs["deploy"]
+            .iter()
+            .any(|line| line.contains("to staging version 1.2.3")),
+        "{:?}",
+        result.per_task_logs["deploy"]
+    );
+}
+
+#[tokio::test]
+async fn test_missing_value_fails_the_task() {
+    let workflow = parse_workflow(&workflow(json!(["${data.version.tag}"]))).unwrap();
+    let result = WorkflowEngine::new()
+        .execute_workflow_with_params(
+            workflow,
+            HashMap::from([("env".to_string(), json!("staging"))]),
+        )
+        .await
+        .unwrap();
+
+    assert!(!result.success);
+    assert!(
+        result
+            .errors
+            .iter()
+            .any(|error| error.contains("${data.version.tag}: no field 'tag'")),
+        "{:?}",
+        result.errors
+    );
+}
+
+#[test]
+fn test_preview_keeps_output_placeholders() {
+    let task = EngineTask {
+        function: TaskFunction::Custom {
+            name: "deploy".to_string(),
+            input: json!({
+                "version": "${data.build.version}",
+                "target": "${data.release.target}",
+                "env": "${param.env}"
+            }),
+        },
+        ..parse_workflow(&workflow(json!([]))).unwrap().tasks[0].clone()
+    };
+
+    let (preview, unresolved) =
+        crate::interpolation::preview_task(&task, &params(), &data()).unwrap();
+    match preview.function {
+        TaskFunction::Custom { input, .. } => assert_eq!(
+            input,
+            json!({
+                "version": "1.2.3",
+                "target": "${data.release.target}",
+                "env": "prod"
+            })
+        ),
+        other => panic!("unexpected function {:?}", other),
+    }
+    assert_eq!(unresolved, ["${data.release.target}"]);
+
+    let missing_field = TaskFunction::Custom {
+        name: "deploy".to_string(),
+        input: json!("${data.build.tag}"),
+    };
+    let task = EngineTask {
+        function: missing_field,
+        ..task
+    };
+    assert!(crate::interpolation::preview_task(&task, &params(), &data()).is_err());
+}
@@ -0,0 +1,119 @@
+use crate::*;
+use serde_json::json;
+
+fn task(id: &str, stage: Option<&str>, next: Vec<serde_json::Value>) -> serde_json::Value {
+    json!({
+        "id": id,
+        "name": id,
+        "stage": stage,
+        "function": { "name": "cli_command", "input": { "command": "true" } },
+        "next_tasks": next
+    })
+}
+
+fn workflow(stages: &[&str], tasks: Vec<serde_json::Value>) -> EngineWorkflow {
+    parse_workflow(
+        &json!({ "id": "graph", "name": "Graph", "stages": stages, "tasks": tasks }).to_string(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_layered_centers_parents_over_their_children() {
+    let workflow = workflow(
+        &[],
+        vec![
+            task(
+                "build",
+                None,
+                vec![task("test", None, vec![]), task("lint", None, vec![])],
+            ),
+            task("docs", None, vec![]),
+        ],
+    );
+    let positions = layout_workflow(&workflow, LayoutMode::Layered);
+    let column = LAYOUT_NODE_WIDTH + 50.0;
+    let row = LAYOUT_NODE_HEIGHT + 150.0;
+
+    assert_eq!(positions.len(), 4);
+    assert_eq!(positions["test"], NodePosition { x: 0.0, y: row });
+    assert_eq!(positions["lint"], NodePosition { x: column, y: row });
+    assert_eq!(
+        positions["build"],
+        NodePosition {
+            x: column / 2.0,
+            y: 0.0
+        }
+    );
+    assert_eq!(
+        positions["docs"],
+        NodePosition {
+            x: 2.0 * column,
+            y: 0.0
+        }
+    );
+}
+
+#[test]
+fn test_stage_grid_puts_each_stage_in_its_own_column() {
+    let workflow = workflow(
+        &["build", "deploy"],
+        vec![task(
+            "compile",
+            Some("build"),
+            vec![
+                task("package", None, vec![]),
+                task("ship", Some("deploy"), vec![]),
+            ],
+        )],
+    );
+    let positions = layout_workflow(&workflow, LayoutMode::StageGrid);
+    let row = LAYOUT_NODE_HEIGHT + 150.0;
+
+    assert_eq!(positions["compile"], NodePosition { x: 0.0, y: 0.0 });
+    assert_eq!(positions["package"], NodePosition { x: 0.0, y: row });
+    assert_eq!(positions["ship"].x, LAYOUT_NODE_WIDTH + 50.0);
+    assert_eq!(positions["ship"].y, 0.0);
+}
+
+#[test]
+fn test_force_directed_is_deterministic_and_keeps_nodes_apart() {
+    let workflow = workflow(
+        &[],
+        vec![task(
+            "a",
+            None,
+            vec![
+                task("b", None, vec![task("d", None, vec![])]),
+                task("c", None, vec![]),
+            ],
+        )],
+    );
+    let first = layout_workflow(&workflow, LayoutMode::ForceDirected);
+    let second = layout_workflow(&workflow, LayoutMode::ForceDirected);
+
+    assert_eq!(first, second);
+    assert_eq!(
+        first.values().map(|p| p.x).fold(f64::INFINITY, f64::min),
+        0.0
+    );
+    assert_eq!(
+        first.values().map(|p| p.y).fold(f64::INFINITY, f64::min),
+        0.0
+    );
+    let points: Vec<_> = first.values().collect();
+    for (i, a) in points.iter().enumerate() {
+        for b in &points[i + 1..] {
+            assert!((a.x - b.x).hypot(a.y - b.y) > LAYOUT_NODE_HEIGHT);
+        }
+    }
+}
+
+#[test]
+fn test_layout_mode_names_round_trip() {
+    for mode in LayoutMode::ALL {
+        assert_eq!(LayoutMode::from_name(mode.as_str()), Some(mode));
+        assert_eq!(serde_json::to_value(mode).unwrap(), json!(mode.as_str()));
+    }
+    assert_eq!(LayoutMode::from_name("circular"), None);
+}
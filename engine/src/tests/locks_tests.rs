@@ -0,0 +1,112 @@
+use crate::*;
+use serde_json::json;
+use std::sync::Arc;
+
+fn exclusive_task(id: &str, lock: Option<&str>, busy: &std::path::Path) -> serde_json::Value {
+    // Fails if another holder of the same directory is inside the critical section.
+    let script = format!("mkdir {0} || exit 1; sleep 0.3; rmdir {0}", busy.display());
+    json!({
+        "id": id,
+        "name": id,
+        "lock": lock,
+        "function": {
+            "name": "cli_command",
+            "input": { "command": "sh", "args": ["-c", script] }
+        }
+    })
+}
+
+fn busy_dir() -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("s_e_e-lock-{}", uuid::Uuid::new_v4()))
+}
+
+#[tokio::test]
+async fn test_memory_locks_serve_tickets_in_order_per_resource() {
+    let locks = MemoryResourceLocks::new();
+    let first = locks.join("deploy", "e1", "t").await.unwrap();
+    let other = locks.join("backup", "e1", "t").await.unwrap();
+    let second = locks.join("deploy", "e2", "t").await.unwrap();
+
+    assert_eq!(locks.position(first).await.unwrap(), 0);
+    assert_eq!(locks.position(other).await.unwrap(), 0);
+    assert_eq!(locks.position(second).await.unwrap(), 1);
+
+    locks.leave(first).await;
+    assert_eq!(locks.position(second).await.unwrap(), 0);
+    assert!(locks.position(first).await.is_err());
+}
+
+#[test]
+fn test_lock_must_be_a_non_empty_string() {
+    let workflow = |lock: serde_json::Value| {
+        json!({
+            "id": "locked",
+            "name": "Locked",
+            "tasks": [{ "id": "a", "name": "A", "lock": lock, "function": { "name": "noop" } }]
+        })
+        .to_string()
+    };
+
+    let parsed = parse_workflow(&workflow(json!("deploy-{{param.env}}"))).unwrap();
+    assert_eq!(
+        parsed.tasks[0].lock.as_deref(),
+        Some("deploy-{{param.env}}")
+    );
+    for lock in [json!(""), json!(3)] {
+        assert!(parse_workflow(&workflow(lock.clone())).is_err(), "{}", lock);
+    }
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_parallel_tasks_with_the_same_lock_take_turns() {
+    let busy = busy_dir();
+    let json = json!({
+        "id": "locked",
+        "name": "Locked",
+        "tasks": [
+            exclusive_task("a", Some("deploy"), &busy),
+            exclusive_task("b", Some("deploy"), &busy),
+        ]
+    });
+    let workflow = parse_workflow(&json.to_string()).unwrap();
+
+    let result = WorkflowEngine::new()
+        .execute_workflow(workflow)
+        .await
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_executions_sharing_locks_serialize_at_the_locked_task() {
+    let busy = busy_dir();
+    let workflow = |lock: Option<&str>| {
+        let json = json!({
+            "id": "deploy",
+            "name": "Deploy",
+            "tasks": [exclusive_task("deploy", lock, &busy)]
+        });
+        parse_workflow(&json.to_string()).unwrap()
+    };
+
+    let locks: Arc<dyn ResourceLocks> = Arc::new(MemoryResourceLocks::new());
+    let (first, second) = (
+        WorkflowEngine::new().with_resource_locks(Arc::clone(&locks)),
+        WorkflowEngine::new().with_resource_locks(Arc::clone(&locks)),
+    );
+    let (a, b) = tokio::join!(
+        first.execute_workflow(workflow(Some("deploy-prod"))),
+        second.execute_workflow(workflow(Some("deploy-prod"))),
+    );
+    assert!(a.unwrap().success);
+    assert!(b.unwrap().success);
+
+    let (a, b) = tokio::join!(
+        first.execute_workflow(workflow(None)),
+        second.execute_workflow(workflow(None)),
+    );
+    assert!(!(a.unwrap().success && b.unwrap().success));
+}
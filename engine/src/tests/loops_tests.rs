@@ -0,0 +1,206 @@
+use crate::*;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+fn loop_workflow(for_each: &str, args: Vec<&str>) -> Value {
+    json!({
+        "id": "fan-out",
+        "name": "Fan out",
+        "parameters": [{ "name": "files", "type": "string" }],
+        "tasks": [{
+            "id": "convert",
+            "name": "Convert",
+            "for_each": for_each,
+            "function": { "name": "cli_command", "input": { "command": "sh", "args": args } },
+            "next_tasks": [{
+                "id": "summary",
+                "name": "Summary",
+                "function": { "name": "cli_command", "input": { "command": "echo", "args": ["done"] } }
+            }]
+        }]
+    })
+}
+
+async fn run(workflow: Value, data: Value) -> WorkflowResult {
+    let workflow = parse_workflow(&workflow.to_string()).unwrap();
+    WorkflowEngine::new()
+        .with_initial_data(data)
+        .execute_workflow_with_params(workflow, HashMap::new())
+        .await
+        .unwrap()
+}
+
+/// The context the run ended with, rebuilt from the audit trail.
+fn final_context(result: &WorkflowResult, initial: Value) -> Value {
+    let mut data = initial;
+    for entry in &result.audit_trail {
+        diff::apply(&mut data, &entry.changes);
+    }
+    data
+}
+
+#[tokio::test]
+async fn test_for_each_runs_once_per_item() {
+    let initial = json!({ "list": { "files": ["a.md", "b.md", "c.md"] } });
+    let result = run(
+        loop_workflow(
+            "data.list.files",
+            vec!["-c", "printf '%s:%s' {{index}} {{item}}"],
+        ),
+        initial.clone(),
+    )
+    .await;
+
+    assert!(result.success, "{:?}", result.errors);
+    let mut ids: Vec<&str> = result.tasks.iter().map(|task| task.id.as_str()).collect();
+    ids.sort();
+    assert_eq!(
+        ids,
+        ["convert", "convert#0", "convert#1", "convert#2", "summary"]
+    );
+    let iteration = result.tasks.iter().find(|task| task.id == "convert#1");
+    assert_eq!(iteration.unwrap().name, "Convert [1]");
+
+    let context = final_context(&result, initial);
+    let outputs = context["convert"].as_array().unwrap();
+    assert_eq!(outputs.len(), 3);
+    assert!(outputs[1].to_string().contains("1:b.md"), "{:?}", outputs);
+    assert!(result.per_task_logs["convert"][0].contains("Running 3 iterations"));
+
+    // Iterations finish before the task, and the task before what follows it.
+    let position = |id: &str| {
+        result
+            .audit_trail
+            .iter()
+            .position(|entry| entry.task_id == id)
+            .unwrap()
+    };
+    assert!(position("convert#2") < position("convert"));
+    assert!(position("convert") < position("summary"));
+}
+
+#[tokio::test]
+async fn test_for_each_item_fields() {
+    let initial = json!({ "targets": [{ "host": "web-1" }, { "host": "web-2" }] });
+    let result = run(
+        loop_workflow("data.targets", vec!["-c", "echo {{item.host}}"]),
+        initial.clone(),
+    )
+    .await;
+
+    assert!(result.success, "{:?}", result.errors);
+    let context = final_context(&result, initial);
+    assert!(context["convert"][1].to_string().contains("web-2"));
+}
+
+#[tokio::test]
+async fn test_for_each_over_lines() {
+    let workflow = json!({
+        "id": "lines",
+        "name": "Lines",
+        "tasks": [{
+            "id": "list",
+            "name": "List",
+            "function": { "name": "cli_command", "input": { "command": "printf", "args": ["a.md\\n\\nb.md\\n"] } },
+            "next_tasks": [{
+                "id": "convert",
+                "name": "Convert",
+                "for_each": "data.list",
+                "function": { "name": "cli_command", "input": { "command": "echo", "args": ["{{item}}"] } }
+            }]
+        }]
+    });
+    let result = run(workflow, json!({})).await;
+
+    assert!(result.success, "{:?}", result.errors);
+    let context = final_context(&result, json!({}));
+    assert_eq!(context["convert"].as_array().unwrap().len(), 2);
+    assert!(context["convert"][1].to_string().contains("b.md"));
+}
+
+#[tokio::test]
+async fn test_failed_iteration_fails_the_task() {
+    let result = run(
+        loop_workflow("data.items", vec!["-c", "test {{item}} != bad"]),
+        json!({ "items": ["good", "bad"] }),
+    )
+    .await;
+
+    assert!(!result.success);
+    assert!(result
+        .errors
+        .iter()
+        .any(|error| error == "Task convert: 1 of 2 iterations failed"));
+    assert!(result
+        .errors
+        .iter()
+        .any(|error| error.starts_with("Task convert#1:")));
+}
+
+#[tokio::test]
+async fn test_for_each_needs_an_array() {
+    let result = run(
+        loop_workflow("data.items", vec!["-c", "true"]),
+        json!({ "items": 3 }),
+    )
+    .await;
+    assert!(!result.success);
+    assert!(result.errors[0].contains("is not an array"), "{:?}", result);
+
+    let result = run(
+        loop_workflow("data.items", vec!["-c", "true"]),
+        json!({ "items": [] }),
+    )
+    .await;
+    assert!(result.success, "{:?}", result.errors);
+    assert_eq!(result.tasks.len(), 2);
+}
+
+#[test]
+fn test_parse_for_each() {
+    let convert = |workflow: &EngineWorkflow| {
+        workflow
+            .tasks
+            .iter()
+            .find(|task| task.id == "convert")
+            .cloned()
+            .unwrap()
+    };
+    let workflow = loop_workflow(" param.files ", vec!["{{item}}"]);
+    let parsed = parse_workflow(&workflow.to_string()).unwrap();
+    assert_eq!(convert(&parsed).for_each.as_deref(), Some("param.files"));
+    assert_eq!(data_references(&convert(&parsed)), Vec::<String>::new());
+
+    let workflow = loop_workflow("data.list.files", vec!["{{item}}"]);
+    let parsed = parse_workflow(&workflow.to_string()).unwrap();
+    assert_eq!(data_references(&convert(&parsed)), vec!["list"]);
+
+    for invalid in ["files", "data.list files", "data."] {
+        let workflow = loop_workflow(invalid, vec![]);
+        assert!(
+            parse_workflow(&workflow.to_string()).is_err(),
+            "{:?} parsed",
+            invalid
+        );
+    }
+
+    let manual = json!({
+        "id": "w",
+        "name": "W",
+        "tasks": [{
+            "id": "review",
+            "name": "Review",
+            "for_each": "data.prs",
+            "function": { "name": "manual", "input": { "instructions": "Review" } }
+        }]
+    });
+    assert!(parse_workflow(&manual.to_string()).is_err());
+}
+
+#[test]
+fn test_loop_task_id() {
+    assert_eq!(iteration_id("convert", 3), "convert#3");
+    assert_eq!(loop_task_id("convert#3"), "convert");
+    assert_eq!(loop_task_id("convert"), "convert");
+    assert_eq!(loop_task_id("issue#tracker"), "issue#tracker");
+}
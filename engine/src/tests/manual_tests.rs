@@ -0,0 +1,126 @@
+use crate::*;
+use chrono::{TimeZone, Utc};
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+
+fn review_workflow() -> EngineWorkflow {
+    let json = json!({
+        "id": "release",
+        "name": "Release",
+        "tasks": [{
+            "id": "review",
+            "name": "Review",
+            "function": {
+                "name": "manual",
+                "input": { "instructions": "Review the release PR" }
+            },
+            "next_tasks": [{
+                "id": "ship",
+                "name": "Ship",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "echo", "args": ["shipped"] }
+                }
+            }]
+        }]
+    });
+    parse_workflow(&json.to_string()).unwrap()
+}
+
+#[test]
+fn test_parse_manual_task() {
+    let workflow = review_workflow();
+    let review = workflow
+        .tasks
+        .iter()
+        .find(|task| task.id == "review")
+        .unwrap();
+    assert_eq!(
+        review.function,
+        TaskFunction::Manual {
+            instructions: "Review the release PR".to_string()
+        }
+    );
+
+    for input in [json!({}), json!({ "instructions": "  " })] {
+        let json = json!({
+            "id": "w",
+            "name": "W",
+            "tasks": [{ "id": "t", "name": "T", "function": { "name": "manual", "input": input } }]
+        });
+        assert!(matches!(
+            parse_workflow(&json.to_string()),
+            Err(ParserError::MissingField(_))
+        ));
+    }
+
+    let cached = json!({
+        "id": "w",
+        "name": "W",
+        "tasks": [{
+            "id": "t",
+            "name": "T",
+            "cache": { "ttl_seconds": 60 },
+            "function": { "name": "manual", "input": { "instructions": "Do it" } }
+        }]
+    });
+    assert!(matches!(
+        parse_workflow(&cached.to_string()),
+        Err(ParserError::InvalidTask(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_manual_task_blocks_dependents() {
+    let result = WorkflowEngine::new()
+        .execute_workflow(review_workflow())
+        .await
+        .unwrap();
+
+    let status = |id: &str| {
+        result
+            .tasks
+            .iter()
+            .find(|task| task.id == id)
+            .map(|task| task.status.clone())
+    };
+    assert_eq!(status("review"), Some(TaskStatus::WaitingForInput));
+    assert_ne!(status("ship"), Some(TaskStatus::Complete));
+}
+
+#[tokio::test]
+async fn test_resume_records_confirmation() {
+    let confirmation = ManualConfirmation {
+        outcome: ManualOutcome::Done,
+        confirmed_by: "alice".to_string(),
+        confirmed_at: Utc.with_ymd_and_hms(2026, 3, 1, 9, 30, 0).unwrap(),
+        notes: Some("LGTM".to_string()),
+    };
+    let inputs = HashMap::from([(
+        "review".to_string(),
+        serde_json::to_string(&confirmation).unwrap(),
+    )]);
+
+    let result = WorkflowEngine::new()
+        .resume_workflow_execution(
+            review_workflow(),
+            "exec-1".to_string(),
+            HashSet::new(),
+            inputs,
+        )
+        .await
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    let entry = &result.audit_trail[0];
+    assert_eq!(entry.task_id, "review");
+    assert_eq!(entry.timestamp, "2026-03-01T09:30:00+00:00");
+    assert_eq!(
+        entry.message,
+        "Confirmed done manual task: Review by alice - LGTM"
+    );
+    assert!(result
+        .audit_trail
+        .iter()
+        .any(|entry| entry.task_id == "ship" && entry.status == AuditStatus::Success));
+}
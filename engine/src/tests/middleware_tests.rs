@@ -0,0 +1,170 @@
+use crate::*;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+fn command_workflow(script: &str) -> EngineWorkflow {
+    let json = json!({
+        "id": "wrapped",
+        "name": "Wrapped",
+        "tasks": [{
+            "id": "run",
+            "name": "Run",
+            "function": {
+                "name": "cli_command",
+                "input": { "command": "sh", "args": ["-c", script] }
+            }
+        }]
+    });
+    parse_workflow(&json.to_string()).unwrap()
+}
+
+struct Recording {
+    name: &'static str,
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl TaskMiddleware for Recording {
+    async fn handle(
+        &self,
+        context: &mut ExecutionContext,
+        task: &EngineTask,
+        next: Next<'_>,
+    ) -> Result<TaskResult, HandlerError> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("{} before {}", self.name, task.id));
+        let result = next.run(context, task).await;
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("{} after {}", self.name, task.id));
+        result
+    }
+}
+
+struct ShortCircuit;
+
+#[async_trait]
+impl TaskMiddleware for ShortCircuit {
+    async fn handle(
+        &self,
+        _context: &mut ExecutionContext,
+        _task: &EngineTask,
+        _next: Next<'_>,
+    ) -> Result<TaskResult, HandlerError> {
+        Ok(TaskResult {
+            success: true,
+            output: json!("skipped"),
+            error: None,
+            failure_reason: None,
+            output_stats: None,
+            exit_code: None,
+            environment: None,
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_middlewares_run_in_registration_order() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let engine = WorkflowEngine::new()
+        .with_middleware(Arc::new(Recording {
+            name: "outer",
+            calls: calls.clone(),
+        }))
+        .with_middleware(Arc::new(Recording {
+            name: "inner",
+            calls: calls.clone(),
+        }));
+
+    let result = engine
+        .execute_workflow(command_workflow("echo hi"))
+        .await
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    assert_eq!(
+        *calls.lock().unwrap(),
+        vec![
+            "outer before run",
+            "inner before run",
+            "inner after run",
+            "outer after run",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_middleware_can_skip_the_handler() {
+    let engine = WorkflowEngine::new().with_middleware(Arc::new(ShortCircuit));
+
+    let result = engine
+        .execute_workflow(command_workflow("exit 3"))
+        .await
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    assert!(result.per_task_logs["run"]
+        .join("\n")
+        .contains("Output: skipped"));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_retry_middleware_reruns_failed_task() {
+    let marker = std::env::temp_dir().join(format!("s_e_e-retry-{}", uuid::Uuid::new_v4()));
+    let script = format!(
+        "test -f {0} || {{ touch {0}; exit 1; }}; echo recovered",
+        marker.display()
+    );
+    let retry = |max_attempts| {
+        WorkflowEngine::new().with_middleware(Arc::new(RetryMiddleware {
+            max_attempts,
+            delay: Duration::from_millis(10),
+        }))
+    };
+
+    let once = retry(1)
+        .execute_workflow(command_workflow(&script))
+        .await
+        .unwrap();
+    assert!(!once.success);
+    std::fs::remove_file(&marker).unwrap();
+
+    let twice = retry(2)
+        .execute_workflow(command_workflow(&script))
+        .await
+        .unwrap();
+    assert!(twice.success, "{:?}", twice.errors);
+    assert!(twice.per_task_logs["run"].join("\n").contains("recovered"));
+    std::fs::remove_file(&marker).unwrap();
+}
+
+#[tokio::test]
+async fn test_redact_middleware_hides_values_in_output_and_errors() {
+    let engine = WorkflowEngine::new().with_middleware(Arc::new(RedactMiddleware::new([
+        "hunter2".to_string(),
+        String::new(),
+    ])));
+
+    let passed = engine
+        .execute_workflow(command_workflow("echo token=hunter2"))
+        .await
+        .unwrap();
+    assert!(passed.success, "{:?}", passed.errors);
+    let logs = passed.per_task_logs["run"].join("\n");
+    assert!(!logs.contains("hunter2"), "{}", logs);
+    assert!(logs.contains("token=[redacted]"), "{}", logs);
+
+    let failed = engine
+        .execute_workflow(command_workflow("echo hunter2 >&2; exit 1"))
+        .await
+        .unwrap();
+    assert!(!failed.success);
+    assert!(failed.errors.iter().all(|e| !e.contains("hunter2")));
+    assert!(!failed.per_task_logs["run"].join("\n").contains("hunter2"));
+}
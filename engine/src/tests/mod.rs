@@ -1,5 +1,38 @@
+pub mod bounds_tests;
+pub mod cache_tests;
+pub mod checkpoint_tests;
+pub mod condition_tests;
+pub mod db_query_tests;
+pub mod diff_tests;
+pub mod dir_cache_tests;
+pub mod dry_run_tests;
 pub mod engine_tests;
+pub mod environment_tests;
+pub mod events_tests;
 pub mod execution_tests;
+pub mod handler_policy_tests;
 pub mod handler_tests;
 pub mod integration;
+pub mod interpolation_tests;
+pub mod layout_tests;
+pub mod locks_tests;
+pub mod loops_tests;
+pub mod manual_tests;
+pub mod middleware_tests;
+pub mod notify_tests;
+pub mod output_tests;
+pub mod outputs_tests;
+pub mod parameters_tests;
+pub mod parser_fuzz_tests;
 pub mod parser_tests;
+pub mod preflight_tests;
+pub mod process_tests;
+pub mod profile_tests;
+pub mod progress_tests;
+pub mod publish_message_tests;
+pub mod rerun_tests;
+pub mod scratch_tests;
+pub mod shell_tests;
+pub mod speculative_tests;
+pub mod stages_tests;
+pub mod workflow_limits_tests;
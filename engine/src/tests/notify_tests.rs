@@ -0,0 +1,109 @@
+use crate::*;
+use serde_json::json;
+use std::time::Duration;
+
+fn workflow_with_notify(notify: serde_json::Value) -> Result<EngineWorkflow, ParserError> {
+    let json = json!({
+        "id": "release",
+        "name": "Release",
+        "notify": notify,
+        "tasks": [{
+            "id": "build",
+            "name": "Build",
+            "function": {
+                "name": "cli_command",
+                "input": { "command": "true", "args": [] }
+            },
+            "next_tasks": [{
+                "id": "deploy-eu",
+                "name": "Deploy EU",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "false", "args": [] }
+                }
+            }]
+        }]
+    });
+    parse_workflow(&json.to_string())
+}
+
+#[test]
+fn test_notify_rules_parse() {
+    let workflow = workflow_with_notify(json!([
+        { "tasks": ["deploy-*"], "on": "failure" },
+        { "tasks": ["build"], "on": "slow", "longer_than_seconds": 600 }
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        workflow.notify,
+        vec![
+            NotifyRule {
+                tasks: vec!["deploy-*".to_string()],
+                on: NotifyOn::Failure,
+            },
+            NotifyRule {
+                tasks: vec!["build".to_string()],
+                on: NotifyOn::Slow {
+                    longer_than_seconds: 600
+                },
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_notify_rules_are_rejected() {
+    for notify in [
+        json!([{ "tasks": [], "on": "failure" }]),
+        json!([{ "tasks": ["deploy-["], "on": "failure" }]),
+        json!([{ "tasks": ["build"], "on": "slow", "longer_than_seconds": 0 }]),
+        json!([{ "tasks": ["build"], "on": "slow" }]),
+        json!([{ "tasks": ["build"], "on": "retry" }]),
+    ] {
+        let error = workflow_with_notify(notify.clone()).unwrap_err();
+        assert!(
+            error.to_string().contains("Invalid notify rules"),
+            "{}: {}",
+            notify,
+            error
+        );
+    }
+}
+
+#[test]
+fn test_rules_cover_matching_ids_and_fire_on_their_event() {
+    let failure = NotifyRule {
+        tasks: vec!["deploy-*".to_string(), "build".to_string()],
+        on: NotifyOn::Failure,
+    };
+    assert!(failure.covers("deploy-eu"));
+    assert!(failure.covers("build"));
+    assert!(!failure.covers("build-docs"));
+    assert!(failure.fires(false, Duration::ZERO));
+    assert!(!failure.fires(true, Duration::ZERO));
+
+    let slow = NotifyRule {
+        tasks: vec!["build".to_string()],
+        on: NotifyOn::Slow {
+            longer_than_seconds: 60,
+        },
+    };
+    assert!(slow.fires(true, Duration::from_secs(61)));
+    assert!(slow.fires(false, Duration::from_secs(61)));
+    assert!(!slow.fires(false, Duration::from_secs(60)));
+}
+
+#[tokio::test]
+async fn test_run_records_how_long_each_task_took() {
+    let workflow = workflow_with_notify(json!([])).unwrap();
+
+    let result = WorkflowEngine::new()
+        .execute_workflow(workflow)
+        .await
+        .unwrap();
+
+    let mut timed: Vec<&String> = result.durations_ms.keys().collect();
+    timed.sort();
+    assert_eq!(timed, ["build", "deploy-eu"]);
+}
@@ -0,0 +1,132 @@
+use crate::handlers::cli_command::CliCommandHandler;
+use crate::handlers::output::{elision_marker, CappedOutput};
+use crate::handlers::TaskHandler;
+use crate::types::*;
+
+#[test]
+fn test_output_within_quota_is_kept_whole() {
+    let mut output = CappedOutput::new(16);
+    output.push(b"hello ");
+    output.push(b"world");
+
+    assert!(!output.is_truncated());
+    assert_eq!(output.total_bytes(), 11);
+    assert_eq!(output.into_text(), "hello world");
+}
+
+#[test]
+fn test_output_over_quota_keeps_head_and_tail() {
+    let mut output = CappedOutput::new(8);
+    for chunk in [b"abcd".as_slice(), b"efgh", b"ijkl", b"mnop"] {
+        output.push(chunk);
+    }
+
+    assert!(output.is_truncated());
+    assert_eq!(output.total_bytes(), 16);
+    assert_eq!(output.into_text(), format!("abcd{}mnop", elision_marker(8)));
+}
+
+#[test]
+fn test_single_large_write_keeps_head_and_tail() {
+    let mut output = CappedOutput::new(4);
+    output.push(b"0123456789");
+
+    assert_eq!(output.total_bytes(), 10);
+    assert_eq!(output.into_text(), format!("01{}89", elision_marker(6)));
+}
+
+#[test]
+fn test_truncation_does_not_split_characters() {
+    let mut output = CappedOutput::new(6);
+    output.push("aaéé-ééaa".as_bytes());
+
+    let text = output.into_text();
+    assert!(!text.contains('\u{FFFD}'), "{text:?}");
+    assert!(text.starts_with("aa"));
+    assert!(text.ends_with("aa"));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_cli_output_respects_per_task_quota() {
+    let mut context = ExecutionContext::new("test".to_string(), "test_workflow".to_string());
+    let task = EngineTask {
+        id: "verbose".to_string(),
+        name: "Verbose".to_string(),
+        function: TaskFunction::CliCommand {
+            command: "sh".to_string(),
+            args: vec![
+                "-c".to_string(),
+                "echo first; seq 1 20000; echo last".to_string(),
+            ],
+            process: ProcessOptions {
+                max_output_bytes: Some(64),
+                ..Default::default()
+            },
+        },
+        next_tasks: Vec::new(),
+        status: TaskStatus::Pending,
+        is_root: true,
+        cache: None,
+        stage: None,
+        lock: None,
+        timeout_seconds: None,
+        when: None,
+        for_each: None,
+        speculative: None,
+    };
+
+    let result = CliCommandHandler
+        .execute(&mut context, &task)
+        .await
+        .unwrap();
+
+    assert!(result.success);
+    let stats = result.output_stats.unwrap();
+    assert!(stats.truncated);
+    assert!(stats.total_bytes > 100_000);
+
+    let output = result.output.as_str().unwrap();
+    assert!(output.starts_with("first\n"));
+    assert!(output.ends_with("last\n"));
+    assert!(output.contains("bytes omitted"));
+    assert!(output.len() < 200);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_cli_output_under_default_quota_is_not_truncated() {
+    let mut context = ExecutionContext::new("test".to_string(), "test_workflow".to_string());
+    let task = EngineTask {
+        id: "quiet".to_string(),
+        name: "Quiet".to_string(),
+        function: TaskFunction::CliCommand {
+            command: "echo".to_string(),
+            args: vec!["hello".to_string()],
+            process: ProcessOptions::default(),
+        },
+        next_tasks: Vec::new(),
+        status: TaskStatus::Pending,
+        is_root: true,
+        cache: None,
+        stage: None,
+        lock: None,
+        timeout_seconds: None,
+        when: None,
+        for_each: None,
+        speculative: None,
+    };
+
+    let result = CliCommandHandler
+        .execute(&mut context, &task)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.output_stats,
+        Some(OutputStats {
+            total_bytes: 6,
+            truncated: false,
+        })
+    );
+}
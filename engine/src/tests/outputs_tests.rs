@@ -0,0 +1,97 @@
+use crate::*;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+fn outputs(value: Value) -> BTreeMap<String, Value> {
+    serde_json::from_value(value).unwrap()
+}
+
+fn workflow(outputs: Value) -> String {
+    json!({
+        "id": "release",
+        "name": "Release",
+        "parameters": [{ "name": "env", "type": "string", "required": true }],
+        "tasks": [{
+            "id": "version",
+            "name": "Version",
+            "function": { "name": "cli_command", "input": { "command": "echo", "args": ["1.2.3"] } },
+        }],
+        "outputs": outputs,
+    })
+    .to_string()
+}
+
+#[test]
+fn test_map_outputs() {
+    let params: Map<String, Value> = serde_json::from_value(json!({ "env": "prod" })).unwrap();
+    let data = json!({ "build": { "version": "1.2.3", "size": 42 } });
+    let mapped = map_outputs(
+        &outputs(json!({
+            "size": "${data.build.size}",
+            "label": "${param.env}-${data.build.version}",
+            "missing": "${data.build.checksum}",
+            "fixed": true,
+        })),
+        &params,
+        &data,
+    );
+
+    assert_eq!(
+        mapped,
+        outputs(json!({
+            "size": 42,
+            "label": "prod-1.2.3",
+            "missing": null,
+            "fixed": true,
+        }))
+    );
+}
+
+#[test]
+fn test_outputs_are_validated() {
+    for (outputs, expected) in [
+        (
+            json!({ "v": "${data.build}" }),
+            "output 'v' reads unknown task 'build'",
+        ),
+        (
+            json!({ "v": "${param.region}" }),
+            "output 'v' reads unknown parameter 'region'",
+        ),
+        (json!({ "v": "${data.version" }), "output 'v':"),
+        (
+            json!({ " ": "${data.version}" }),
+            "output names must not be empty",
+        ),
+        (json!(["${data.version}"]), "Invalid outputs"),
+    ] {
+        let error = parse_workflow(&workflow(outputs)).unwrap_err().to_string();
+        assert!(error.contains(expected), "{}", error);
+    }
+}
+
+#[tokio::test]
+async fn test_outputs_are_filled_in_after_the_run() {
+    let workflow = parse_workflow(&workflow(json!({
+        "version": "${data.version}",
+        "target": "${param.env}",
+    })))
+    .unwrap();
+    let result = WorkflowEngine::new()
+        .execute_workflow_with_params(
+            workflow,
+            HashMap::from([("env".to_string(), json!("staging"))]),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    assert_eq!(result.outputs["target"], json!("staging"));
+    assert!(
+        result.outputs["version"]
+            .as_str()
+            .is_some_and(|version| version.contains("1.2.3")),
+        "{:?}",
+        result.outputs
+    );
+}
@@ -0,0 +1,322 @@
+use crate::engine::*;
+use crate::errors::*;
+use crate::handlers::{HandlerRegistry, TaskHandler};
+use crate::parameters::*;
+use crate::parser::*;
+use crate::types::*;
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+fn parameter(name: &str, param_type: ParameterType) -> WorkflowParameter {
+    WorkflowParameter {
+        name: name.to_string(),
+        param_type,
+        required: false,
+        default: None,
+        description: None,
+        options: Vec::new(),
+        secret: false,
+    }
+}
+
+const PARAM_WORKFLOW: &str = r#"
+{
+    "id": "params",
+    "name": "Parameter Workflow",
+    "parameters": [
+        { "name": "target", "type": "string", "required": true },
+        { "name": "retries", "type": "number", "default": 3 },
+        { "name": "verbose", "type": "boolean" }
+    ],
+    "tasks": [
+        {
+            "id": "task1",
+            "name": "Task 1",
+            "function": {
+                "name": "echo_params",
+                "input": {}
+            }
+        }
+    ]
+}
+"#;
+
+struct EchoParamsHandler;
+
+#[async_trait]
+impl TaskHandler for EchoParamsHandler {
+    async fn execute(
+        &self,
+        context: &mut ExecutionContext,
+        _task: &EngineTask,
+    ) -> Result<TaskResult, HandlerError> {
+        Ok(TaskResult {
+            success: true,
+            output: Value::Object(context.params.clone()),
+            error: None,
+            failure_reason: None,
+            output_stats: None,
+            exit_code: None,
+            environment: None,
+        })
+    }
+}
+
+#[test]
+fn test_parse_workflow_parameters() {
+    let workflow = parse_workflow(PARAM_WORKFLOW).unwrap();
+
+    assert_eq!(workflow.parameters.len(), 3);
+    assert_eq!(workflow.parameters[0].name, "target");
+    assert!(workflow.parameters[0].required);
+    assert_eq!(workflow.parameters[1].param_type, ParameterType::Number);
+    assert_eq!(workflow.parameters[1].default, Some(json!(3)));
+    assert_eq!(workflow.parameters[2].param_type, ParameterType::Boolean);
+}
+
+#[test]
+fn test_parse_duplicate_parameter_names() {
+    let json = r#"
+    {
+        "id": "params",
+        "name": "Parameter Workflow",
+        "parameters": [{ "name": "a" }, { "name": "a" }],
+        "tasks": []
+    }
+    "#;
+
+    let result = parse_workflow(json);
+    assert!(
+        matches!(result, Err(ParserError::InvalidTask(msg)) if msg.contains("Duplicate parameter"))
+    );
+}
+
+#[test]
+fn test_parse_parameter_value_types() {
+    let number = parameter("n", ParameterType::Number);
+    assert_eq!(parse_parameter_value(&number, "42").unwrap(), json!(42));
+    assert_eq!(parse_parameter_value(&number, "1.5").unwrap(), json!(1.5));
+    assert!(matches!(
+        parse_parameter_value(&number, "many"),
+        Err(ParameterError::InvalidValue { .. })
+    ));
+
+    let flag = parameter("f", ParameterType::Boolean);
+    assert_eq!(parse_parameter_value(&flag, "yes").unwrap(), json!(true));
+    assert_eq!(parse_parameter_value(&flag, "False").unwrap(), json!(false));
+    assert!(parse_parameter_value(&flag, "maybe").is_err());
+
+    let text = parameter("s", ParameterType::String);
+    assert_eq!(parse_parameter_value(&text, " 7 ").unwrap(), json!(" 7 "));
+
+    let file = parameter("path", ParameterType::File);
+    assert_eq!(
+        parse_parameter_value(&file, "/tmp/input.csv").unwrap(),
+        json!("/tmp/input.csv")
+    );
+}
+
+#[test]
+fn test_parameter_options_limit_values() {
+    let env = WorkflowParameter {
+        options: vec![json!("staging"), json!("prod")],
+        ..parameter("env", ParameterType::String)
+    };
+    assert_eq!(parse_parameter_value(&env, "prod").unwrap(), json!("prod"));
+    let error = parse_parameter_value(&env, "dev").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid value for parameter 'env': expected one of staging, prod, got 'dev'"
+    );
+
+    let replicas = WorkflowParameter {
+        options: vec![json!(1), json!(3)],
+        ..parameter("replicas", ParameterType::Number)
+    };
+    let provided = HashMap::from([("replicas".to_string(), json!(2))]);
+    assert!(resolve_parameters(std::slice::from_ref(&replicas), &provided).is_err());
+    let provided = HashMap::from([("replicas".to_string(), json!("3"))]);
+    assert_eq!(
+        resolve_parameters(&[replicas], &provided).unwrap()["replicas"],
+        json!(3)
+    );
+}
+
+#[test]
+fn test_secret_parameters_are_masked_in_errors() {
+    let token = WorkflowParameter {
+        secret: true,
+        options: vec![json!("s3cr3t")],
+        ..parameter("token", ParameterType::String)
+    };
+    let error = parse_parameter_value(&token, "hunter2").unwrap_err();
+    assert!(!error.to_string().contains("hunter2"), "{}", error);
+    assert!(error.to_string().contains(SECRET_MASK));
+
+    let values = HashMap::from([
+        ("token".to_string(), json!("s3cr3t")),
+        ("env".to_string(), json!("prod")),
+    ]);
+    let env = parameter("env", ParameterType::String);
+    assert_eq!(secret_values(&[token, env], &values), vec!["s3cr3t"]);
+}
+
+#[test]
+fn test_resolve_parameters_applies_defaults_and_coerces() {
+    let workflow = parse_workflow(PARAM_WORKFLOW).unwrap();
+    let mut provided = HashMap::new();
+    provided.insert("target".to_string(), json!("prod"));
+    provided.insert("verbose".to_string(), json!("true"));
+
+    let resolved = resolve_parameters(&workflow.parameters, &provided).unwrap();
+
+    assert_eq!(resolved.get("target"), Some(&json!("prod")));
+    assert_eq!(resolved.get("retries"), Some(&json!(3)));
+    assert_eq!(resolved.get("verbose"), Some(&json!(true)));
+}
+
+#[test]
+fn test_resolve_parameters_errors() {
+    let workflow = parse_workflow(PARAM_WORKFLOW).unwrap();
+
+    assert_eq!(
+        resolve_parameters(&workflow.parameters, &HashMap::new()),
+        Err(ParameterError::Missing("target".to_string()))
+    );
+    assert_eq!(
+        missing_parameters(&workflow.parameters, &HashMap::new())
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["target"]
+    );
+
+    let mut provided = HashMap::new();
+    provided.insert("target".to_string(), json!("prod"));
+    provided.insert("extra".to_string(), json!(1));
+    assert_eq!(
+        resolve_parameters(&workflow.parameters, &provided),
+        Err(ParameterError::Unknown("extra".to_string()))
+    );
+
+    provided.remove("extra");
+    provided.insert("retries".to_string(), json!(true));
+    assert!(matches!(
+        resolve_parameters(&workflow.parameters, &provided),
+        Err(ParameterError::InvalidValue { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_execute_workflow_with_params() {
+    let mut handlers = HandlerRegistry::new();
+    handlers.register_handler("echo_params".to_string(), Box::new(EchoParamsHandler));
+    let engine = WorkflowEngine::with_handlers(handlers);
+
+    let mut params = HashMap::new();
+    params.insert("target".to_string(), json!("staging"));
+    let workflow = parse_workflow(PARAM_WORKFLOW).unwrap();
+    let result = engine
+        .execute_workflow_with_params(workflow, params)
+        .await
+        .unwrap();
+
+    assert!(result.success);
+    let output = result.audit_trail[0].changes[0].after.clone().unwrap();
+    assert_eq!(output, json!({"target": "staging", "retries": 3}));
+}
+
+#[tokio::test]
+async fn test_execute_workflow_missing_required_parameter() {
+    let workflow = parse_workflow(PARAM_WORKFLOW).unwrap();
+    let result = WorkflowEngine::new().execute_workflow(workflow).await;
+
+    assert!(matches!(
+        result,
+        Err(EngineError::Parameter(ParameterError::Missing(name))) if name == "target"
+    ));
+}
+
+fn temp_dir() -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("s_e_e-params-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn resolve_one(parameter: &WorkflowParameter, value: Value) -> Result<Value, ParameterError> {
+    let provided = HashMap::from([(parameter.name.clone(), value)]);
+    resolve_parameters(std::slice::from_ref(parameter), &provided)
+        .map(|resolved| resolved[&parameter.name].clone())
+}
+
+#[test]
+fn test_path_parameters_are_checked_and_made_absolute() {
+    let dir = temp_dir();
+    std::fs::write(dir.join("a.csv"), "a").unwrap();
+    std::fs::write(dir.join("b.csv"), "b").unwrap();
+    std::fs::create_dir(dir.join("nested")).unwrap();
+    let canonical = dunce::canonicalize(&dir).unwrap();
+    let absolute = |name: &str| json!(canonical.join(name).to_string_lossy());
+
+    let file = parameter("input", ParameterType::File);
+    let nested_file = format!("{}/nested/../a.csv", dir.display());
+    assert_eq!(
+        resolve_one(&file, json!(nested_file)).unwrap(),
+        absolute("a.csv")
+    );
+
+    let directory = parameter("out", ParameterType::Directory);
+    assert_eq!(
+        resolve_one(&directory, json!(dir.join("nested").to_string_lossy())).unwrap(),
+        absolute("nested")
+    );
+
+    let glob = parameter("inputs", ParameterType::Glob);
+    let pattern = format!("{}/*.csv", dir.display());
+    let expanded = resolve_one(&glob, json!(pattern)).unwrap();
+    assert_eq!(expanded, json!([absolute("a.csv"), absolute("b.csv")]));
+    assert_eq!(resolve_one(&glob, expanded.clone()).unwrap(), expanded);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_invalid_path_parameters() {
+    let dir = temp_dir();
+    std::fs::write(dir.join("a.csv"), "a").unwrap();
+    let path = |name: &str| json!(dir.join(name).to_string_lossy());
+
+    for (param_type, value, reason) in [
+        (ParameterType::File, path("missing.csv"), "does not exist"),
+        (ParameterType::File, path(""), "is a directory, not a file"),
+        (
+            ParameterType::Directory,
+            path("a.csv"),
+            "is not a directory",
+        ),
+        (ParameterType::Glob, path("*.json"), "matches no paths"),
+        (
+            ParameterType::Glob,
+            path("[*.csv"),
+            "is not a valid pattern",
+        ),
+    ] {
+        let error = resolve_one(&parameter("input", param_type), value).unwrap_err();
+        assert!(
+            matches!(&error, ParameterError::InvalidPath { reason: r, .. } if r.contains(reason)),
+            "{:?}: {}",
+            param_type,
+            error
+        );
+    }
+
+    let secret = WorkflowParameter {
+        secret: true,
+        ..parameter("key", ParameterType::File)
+    };
+    let error = resolve_one(&secret, path("id_rsa")).unwrap_err();
+    assert!(error.to_string().contains(SECRET_MASK), "{}", error);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
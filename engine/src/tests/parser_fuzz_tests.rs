@@ -0,0 +1,162 @@
+//! Structured random inputs for the parser: malformed documents must come back
+//! as `ParserError`s, never as panics.
+
+use crate::json::{parse_document, parse_document_bytes};
+use crate::parser::*;
+use proptest::collection::{hash_map, vec};
+use proptest::prelude::*;
+use serde_json::{json, Value};
+
+fn json_value() -> impl Strategy<Value = Value> {
+    let leaf = prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::Bool),
+        any::<i64>().prop_map(Value::from),
+        any::<f64>()
+            .prop_filter("JSON has no NaN or infinity", |f| f.is_finite())
+            .prop_map(Value::from),
+        ".{0,12}".prop_map(Value::String),
+    ];
+    leaf.prop_recursive(4, 32, 4, |inner| {
+        prop_oneof![
+            vec(inner.clone(), 0..4).prop_map(Value::Array),
+            hash_map(".{0,6}", inner, 0..4)
+                .prop_map(|map| Value::Object(map.into_iter().collect())),
+        ]
+    })
+}
+
+/// Either a plausible value for a workflow field or arbitrary JSON in its place.
+fn field(plausible: impl Strategy<Value = Value> + 'static) -> BoxedStrategy<Option<Value>> {
+    prop_oneof![
+        2 => Just(None),
+        5 => plausible.prop_map(Some),
+        1 => json_value().prop_map(Some),
+    ]
+    .boxed()
+}
+
+fn object(fields: Vec<(&'static str, Option<Value>)>) -> Value {
+    Value::Object(
+        fields
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key.to_string(), value)))
+            .collect(),
+    )
+}
+
+fn function() -> impl Strategy<Value = Value> {
+    let name = prop_oneof![
+        Just(json!("cli_command")),
+        Just(json!("cursor_agent")),
+        Just(json!("user_input")),
+        "[a-z_]{1,10}".prop_map(Value::String),
+    ];
+    let input = (
+        field("[a-z]{1,8}".prop_map(Value::String)),
+        field(vec(".{0,6}".prop_map(Value::String), 0..3).prop_map(Value::Array)),
+        field(".{0,20}".prop_map(Value::String)),
+        field(prop_oneof![Just(json!("string")), Just(json!("number"))]),
+        field(any::<bool>().prop_map(Value::Bool)),
+        field(Just(json!({"timeout_secs": 5}))),
+    )
+        .prop_map(|(command, args, prompt, input_type, required, process)| {
+            object(vec![
+                ("command", command),
+                ("args", args),
+                ("prompt", prompt),
+                ("input_type", input_type),
+                ("required", required),
+                ("process", process),
+            ])
+        });
+    (field(name), field(input))
+        .prop_map(|(name, input)| object(vec![("name", name), ("input", input)]))
+}
+
+fn task() -> impl Strategy<Value = Value> {
+    let leaf = (
+        field("t[0-9]{1,2}".prop_map(Value::String)),
+        field(".{0,10}".prop_map(Value::String)),
+        field(function()),
+    )
+        .prop_map(|(id, name, function)| {
+            object(vec![("id", id), ("name", name), ("function", function)])
+        });
+    leaf.prop_recursive(3, 16, 3, |inner| {
+        (inner.clone(), vec(inner, 0..3)).prop_map(|(mut task, next_tasks)| {
+            if let Value::Object(map) = &mut task {
+                map.insert("next_tasks".to_string(), Value::Array(next_tasks));
+            }
+            task
+        })
+    })
+}
+
+fn workflow() -> impl Strategy<Value = Value> {
+    let parameters = vec(
+        (
+            "[a-z]{1,4}",
+            prop_oneof![Just("string"), Just("number"), Just("bogus")],
+        )
+            .prop_map(|(name, kind)| json!({"name": name, "type": kind})),
+        0..3,
+    )
+    .prop_map(Value::Array);
+    let triggers = vec(
+        "[a-z]{1,4}".prop_map(|id| json!({"type": "after_workflow", "workflow_id": id})),
+        0..2,
+    )
+    .prop_map(Value::Array);
+    (
+        field("[a-z]{1,4}".prop_map(Value::String)),
+        field(".{0,10}".prop_map(Value::String)),
+        field(vec(task(), 0..4).prop_map(Value::Array)),
+        field(parameters),
+        field(triggers),
+    )
+        .prop_map(|(id, name, tasks, parameters, triggers)| {
+            object(vec![
+                ("id", id),
+                ("name", name),
+                ("tasks", tasks),
+                ("parameters", parameters),
+                ("triggers", triggers),
+            ])
+        })
+}
+
+proptest! {
+    #[test]
+    fn test_parse_workflow_never_panics_on_text(input in ".{0,200}") {
+        let _ = parse_workflow(&input);
+    }
+
+    #[test]
+    fn test_parse_workflow_bytes_never_panics(bytes in vec(any::<u8>(), 0..200)) {
+        let _ = parse_workflow_bytes(&bytes);
+    }
+
+    #[test]
+    fn test_parse_workflow_never_panics_on_workflow_shapes(workflow in workflow()) {
+        let _ = parse_workflow_from_value(&workflow);
+        let _ = parse_workflow(&workflow.to_string());
+    }
+
+    #[test]
+    fn test_truncated_workflow_is_an_error(workflow in workflow(), cut in any::<prop::sample::Index>()) {
+        let text = workflow.to_string();
+        let end = cut.index(text.len());
+        prop_assume!(text.is_char_boundary(end));
+        prop_assert!(parse_workflow_bytes(&text.as_bytes()[..end]).is_err());
+    }
+
+    #[test]
+    fn test_parse_document_matches_serde_json(value in json_value()) {
+        // Compare against serde_json's own reading: float parsing is not exact.
+        let text = value.to_string();
+        let expected: Value = serde_json::from_str(&text).unwrap();
+        prop_assert_eq!(parse_document(&text).unwrap(), expected.clone());
+        prop_assert_eq!(parse_document_bytes(text.as_bytes()).unwrap(), expected);
+    }
+}
@@ -1,5 +1,7 @@
 use crate::errors::*;
+use crate::json::MAX_NESTING_DEPTH;
 use crate::parser::*;
+use crate::types::WorkspaceOptions;
 
 #[test]
 fn test_parse_simple_workflow() {
@@ -286,3 +288,196 @@ fn test_parse_circular_dependency_parent_child() {
 
     assert_eq!(workflow.tasks.len(), 2);
 }
+
+#[test]
+fn test_parse_workflow_triggers() {
+    let json = r#"
+    {
+        "id": "deploy",
+        "name": "Deploy",
+        "triggers": [{ "type": "after_workflow", "workflow_id": "build" }],
+        "tasks": []
+    }
+    "#;
+    let workflow = parse_workflow(json).unwrap();
+
+    assert_eq!(
+        workflow.triggers,
+        vec![crate::types::WorkflowTrigger::AfterWorkflow {
+            workflow_id: "build".to_string()
+        }]
+    );
+}
+
+#[test]
+fn test_parse_workflow_rejects_self_trigger() {
+    let json = r#"
+    {
+        "id": "deploy",
+        "name": "Deploy",
+        "triggers": [{ "type": "after_workflow", "workflow_id": "deploy" }],
+        "tasks": []
+    }
+    "#;
+    let result = parse_workflow(json);
+
+    assert!(matches!(result, Err(ParserError::InvalidTask(msg)) if msg.contains("trigger itself")));
+}
+
+#[test]
+fn test_parse_message_trigger() {
+    let json = r#"
+    {
+        "id": "release",
+        "name": "Release",
+        "triggers": [{
+            "type": "message",
+            "connection": "events",
+            "source": "releases",
+            "params": { "version": "/version" },
+            "dead_letter": "releases.failed"
+        }],
+        "tasks": []
+    }
+    "#;
+    let workflow = parse_workflow(json).unwrap();
+
+    assert_eq!(
+        workflow.triggers,
+        vec![crate::types::WorkflowTrigger::Message {
+            connection: "events".to_string(),
+            source: "releases".to_string(),
+            params: [("version".to_string(), "/version".to_string())].into(),
+            max_concurrent: 1,
+            max_attempts: 3,
+            dead_letter: Some("releases.failed".to_string()),
+        }]
+    );
+
+    let no_source = json.replace(r#""source": "releases","#, r#""source": "","#);
+    assert!(matches!(
+        parse_workflow(&no_source),
+        Err(ParserError::MissingField(_))
+    ));
+    let no_attempts = json.replace(
+        r#""source": "releases","#,
+        r#""source": "releases", "max_attempts": 0,"#,
+    );
+    assert!(matches!(
+        parse_workflow(&no_attempts),
+        Err(ParserError::InvalidTask(_))
+    ));
+}
+
+#[test]
+fn test_parse_workflow_rejects_duplicate_keys() {
+    let json = r#"{"id": "a", "name": "A", "id": "b", "tasks": []}"#;
+    let result = parse_workflow(json);
+
+    assert!(matches!(result, Err(ParserError::DuplicateKey(key)) if key == "id"));
+}
+
+#[test]
+fn test_parse_workflow_rejects_duplicate_keys_in_task_input() {
+    let json = r#"
+    {
+        "id": "dup",
+        "name": "Dup",
+        "tasks": [{
+            "id": "t",
+            "function": {
+                "name": "cli_command",
+                "input": { "command": "echo", "command": "rm" }
+            }
+        }]
+    }
+    "#;
+    let result = parse_workflow(json);
+
+    assert!(matches!(result, Err(ParserError::DuplicateKey(key)) if key == "command"));
+}
+
+#[test]
+fn test_parse_workflow_rejects_huge_nesting() {
+    let depth = 100_000;
+    let json = format!(
+        r#"{{"id": "deep", "name": "Deep", "tasks": [], "extra": {}{}}}"#,
+        "[".repeat(depth),
+        "]".repeat(depth)
+    );
+    let result = parse_workflow(&json);
+
+    assert!(matches!(
+        result,
+        Err(ParserError::NestingTooDeep(MAX_NESTING_DEPTH))
+    ));
+}
+
+#[test]
+fn test_parse_workflow_accepts_long_task_chain_within_limit() {
+    let mut task = serde_json::json!({"id": "t0", "function": {"name": "noop"}});
+    for i in 1..25 {
+        task = serde_json::json!({
+            "id": format!("t{}", i),
+            "function": {"name": "noop"},
+            "next_tasks": [task],
+        });
+    }
+    let json = serde_json::json!({"id": "chain", "name": "Chain", "tasks": [task]}).to_string();
+
+    let workflow = parse_workflow(&json).unwrap();
+    assert_eq!(workflow.tasks.len(), 25);
+}
+
+#[test]
+fn test_parse_workflow_bytes_rejects_invalid_utf8() {
+    let mut bytes = br#"{"id": "x", "name": ""#.to_vec();
+    let position = bytes.len();
+    bytes.extend_from_slice(&[0xff, 0xfe]);
+    bytes.extend_from_slice(br#"", "tasks": []}"#);
+
+    let result = parse_workflow_bytes(&bytes);
+
+    assert!(matches!(result, Err(ParserError::InvalidUtf8 { position: p }) if p == position));
+}
+
+#[test]
+fn test_parse_workflow_rejects_lone_surrogate_escape() {
+    let json = r#"{"id": "x", "name": "\ud800", "tasks": []}"#;
+    let result = parse_workflow(json);
+
+    assert!(matches!(result, Err(ParserError::Json(_))));
+}
+
+#[test]
+fn test_parse_workflow_rejects_trailing_content() {
+    let json = r#"{"id": "x", "name": "x", "tasks": []} {"id": "y"}"#;
+    let result = parse_workflow(json);
+
+    assert!(matches!(result, Err(ParserError::Json(_))));
+}
+
+#[test]
+fn test_parse_isolated_workspace() {
+    let json = r#"{"id": "x", "name": "x", "tasks": [], "isolated_workspace": true, "workspace_template": "fixtures"}"#;
+    let workflow = parse_workflow(json).unwrap();
+    assert_eq!(
+        workflow.workspace,
+        Some(WorkspaceOptions {
+            template: Some("fixtures".to_string())
+        })
+    );
+
+    let json = r#"{"id": "x", "name": "x", "tasks": []}"#;
+    assert_eq!(parse_workflow(json).unwrap().workspace, None);
+}
+
+#[test]
+fn test_parse_workspace_template_requires_isolation() {
+    let json = r#"{"id": "x", "name": "x", "tasks": [], "workspace_template": "fixtures"}"#;
+    let result = parse_workflow(json);
+
+    assert!(
+        matches!(result, Err(ParserError::InvalidTask(message)) if message.contains("isolated_workspace"))
+    );
+}
@@ -0,0 +1,189 @@
+use crate::*;
+
+fn workflow_with_preflight(preflight: &str, marker: &std::path::Path) -> EngineWorkflow {
+    let json = format!(
+        r#"{{
+            "id": "preflight",
+            "name": "Preflight",
+            "preflight": {},
+            "tasks": [{{
+                "id": "touch",
+                "name": "Touch",
+                "function": {{
+                    "name": "cli_command",
+                    "input": {{"command": "touch", "args": ["{}"]}}
+                }}
+            }}]
+        }}"#,
+        preflight,
+        marker.display()
+    );
+    parse_workflow(&json).unwrap()
+}
+
+#[test]
+fn test_parse_preflight_checks() {
+    let json = r#"{"id": "x", "name": "x", "tasks": [], "preflight": [
+        {"check": "binary", "name": "git"},
+        {"check": "disk_space", "min_mb": 100},
+        {"check": "env_var", "name": "API_TOKEN"},
+        {"check": "url", "url": "https://example.com/health"}
+    ]}"#;
+    let workflow = parse_workflow(json).unwrap();
+    assert_eq!(
+        workflow.preflight,
+        vec![
+            PreflightCheck::Binary {
+                name: "git".to_string()
+            },
+            PreflightCheck::DiskSpace {
+                path: ".".to_string(),
+                min_mb: 100
+            },
+            PreflightCheck::EnvVar {
+                name: "API_TOKEN".to_string()
+            },
+            PreflightCheck::Url {
+                url: "https://example.com/health".to_string(),
+                timeout_ms: 5000
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parse_rejects_invalid_preflight_checks() {
+    for preflight in [
+        r#"[{"check": "ping", "host": "example.com"}]"#,
+        r#"[{"check": "env_var", "name": ""}]"#,
+        r#"[{"check": "url", "url": "ftp://example.com"}]"#,
+        r#"[{"check": "url", "url": "example.com"}]"#,
+        r#"[{"check": "url", "url": "http://:8080"}]"#,
+        r#"[{"check": "url", "url": "http://localhost:http"}]"#,
+        r#"[{"check": "disk_space"}]"#,
+    ] {
+        let json = format!(
+            r#"{{"id": "x", "name": "x", "tasks": [], "preflight": {}}}"#,
+            preflight
+        );
+        assert!(
+            matches!(parse_workflow(&json), Err(ParserError::InvalidTask(message)) if message.contains("preflight")),
+            "accepted {}",
+            preflight
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_preflight_reports_every_failed_check() {
+    let checks = vec![
+        PreflightCheck::Binary {
+            name: "sh".to_string(),
+        },
+        PreflightCheck::Binary {
+            name: "s_e_e_no_such_binary".to_string(),
+        },
+        PreflightCheck::EnvVar {
+            name: "S_E_E_PREFLIGHT_UNSET_VAR".to_string(),
+        },
+        PreflightCheck::DiskSpace {
+            path: ".".to_string(),
+            min_mb: u64::MAX,
+        },
+    ];
+
+    let report = run_preflight(&checks).await;
+
+    assert!(!report.passed());
+    assert!(report.results[0].passed, "{}", report.results[0].detail);
+    let failed: Vec<_> = report.failures().map(|result| &result.check).collect();
+    assert_eq!(failed, checks[1..].iter().collect::<Vec<_>>());
+    let text = report.to_string();
+    assert!(text.starts_with("3 of 4 preflight checks failed"));
+    assert!(text.contains("binary 's_e_e_no_such_binary' on PATH: not found on PATH"));
+    assert!(text.contains("environment variable 'S_E_E_PREFLIGHT_UNSET_VAR': not set"));
+}
+
+#[tokio::test]
+async fn test_url_check_connects_to_listener() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let check = PreflightCheck::Url {
+        url: format!("http://127.0.0.1:{}/health", port),
+        timeout_ms: 2000,
+    };
+
+    let report = run_preflight(&[check]).await;
+    assert!(report.passed(), "{}", report);
+
+    drop(listener);
+    let closed = PreflightCheck::Url {
+        url: format!("http://127.0.0.1:{}/health", port),
+        timeout_ms: 2000,
+    };
+    assert!(!run_preflight(&[closed]).await.passed());
+}
+
+#[tokio::test]
+async fn test_failed_preflight_runs_no_task() {
+    let dir = std::env::temp_dir().join(format!("s_e_e-preflight-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let marker = dir.join("ran");
+    let workflow = workflow_with_preflight(
+        r#"[{"check": "env_var", "name": "S_E_E_PREFLIGHT_UNSET_VAR"}]"#,
+        &marker,
+    );
+
+    let error = WorkflowEngine::new()
+        .execute_workflow(workflow)
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.category(), ErrorCategory::Preflight);
+    assert!(matches!(&error, EngineError::Preflight(report) if report.failures().count() == 1));
+    assert!(!marker.exists());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_passing_preflight_runs_tasks() {
+    let dir = std::env::temp_dir().join(format!("s_e_e-preflight-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let marker = dir.join("ran");
+    let workflow = workflow_with_preflight(
+        r#"[{"check": "binary", "name": "touch"}, {"check": "disk_space", "path": "/", "min_mb": 1}]"#,
+        &marker,
+    );
+
+    let result = WorkflowEngine::new()
+        .execute_workflow(workflow)
+        .await
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    assert!(marker.exists());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_referenced_tools() {
+    let json = r#"{"id": "x", "name": "x",
+        "preflight": [{"check": "binary", "name": "docker"}],
+        "tasks": [
+            {"id": "build", "name": "Build", "function": {"name": "cli_command",
+                "input": {"command": "sh", "args": ["-c", "cargo build && /usr/bin/git status"]}}},
+            {"id": "watch", "name": "Watch", "function": {"name": "cli_command",
+                "input": {"command": "cargo-watch", "args": ["nodes"]}}}
+        ]}"#;
+    let workflow = parse_workflow(json).unwrap();
+
+    assert_eq!(referenced_tools(&workflow), ["cargo", "docker", "git"]);
+}
+
+#[tokio::test]
+async fn test_probe_tool_versions() {
+    // Tests run under cargo, so it is on PATH.
+    let versions = probe_tool_versions(&["cargo"]).await;
+
+    assert!(versions["cargo"].starts_with("cargo "), "{:?}", versions);
+}
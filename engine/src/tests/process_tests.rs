@@ -0,0 +1,351 @@
+use crate::handlers::cli_command::CliCommandHandler;
+use crate::handlers::TaskHandler;
+use crate::parser::parse_workflow;
+use crate::types::*;
+use std::time::{Duration, Instant};
+
+fn cli_task(command: &str, args: &[&str], process: ProcessOptions) -> EngineTask {
+    EngineTask {
+        id: "process_task".to_string(),
+        name: "Process Task".to_string(),
+        function: TaskFunction::CliCommand {
+            command: command.to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+            process,
+        },
+        next_tasks: Vec::new(),
+        status: TaskStatus::Pending,
+        is_root: true,
+        cache: None,
+        stage: None,
+        lock: None,
+        timeout_seconds: None,
+        when: None,
+        for_each: None,
+        speculative: None,
+    }
+}
+
+#[cfg(unix)]
+fn is_running(pid: i32) -> bool {
+    match std::fs::read_to_string(format!("/proc/{}/stat", pid)) {
+        Ok(stat) => !stat.contains(") Z "),
+        Err(_) => unsafe { libc::kill(pid, 0) == 0 },
+    }
+}
+
+#[cfg(unix)]
+async fn wait_until_gone(pid: i32) -> bool {
+    for _ in 0..50 {
+        if !is_running(pid) {
+            return true;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    false
+}
+
+#[test]
+fn test_parse_process_options() {
+    let json = r#"
+    {
+        "id": "proc",
+        "name": "Process Workflow",
+        "tasks": [
+            {
+                "id": "task1",
+                "name": "Task 1",
+                "function": {
+                    "name": "cli_command",
+                    "input": {
+                        "command": "npm",
+                        "args": ["run", "dev"],
+                        "process": { "timeout_ms": 1000, "kill_grace_ms": 250 }
+                    }
+                }
+            }
+        ]
+    }
+    "#;
+
+    let workflow = parse_workflow(json).unwrap();
+    let TaskFunction::CliCommand { process, .. } = &workflow.tasks[0].function else {
+        panic!("Expected CliCommand");
+    };
+    assert_eq!(process.timeout_ms, Some(1000));
+    assert_eq!(process.kill_grace_ms, Some(250));
+}
+
+#[test]
+fn test_parse_resource_limits() {
+    let json = r#"
+    {
+        "id": "proc",
+        "name": "Process Workflow",
+        "tasks": [
+            {
+                "id": "task1",
+                "name": "Task 1",
+                "function": {
+                    "name": "cli_command",
+                    "input": {
+                        "command": "make",
+                        "args": [],
+                        "process": {
+                            "nice": 10,
+                            "max_memory_mb": 512,
+                            "max_cpu_percent": 50,
+                            "max_cpu_seconds": 60
+                        }
+                    }
+                }
+            }
+        ]
+    }
+    "#;
+
+    let workflow = parse_workflow(json).unwrap();
+    let TaskFunction::CliCommand { process, .. } = &workflow.tasks[0].function else {
+        panic!("Expected CliCommand");
+    };
+    assert_eq!(process.nice, Some(10));
+    assert_eq!(process.max_memory_mb, Some(512));
+    assert_eq!(process.max_cpu_percent, Some(50));
+    assert_eq!(process.max_cpu_seconds, Some(60));
+}
+
+#[test]
+fn test_parse_invalid_process_options() {
+    let json = r#"
+    {
+        "id": "proc",
+        "name": "Process Workflow",
+        "tasks": [
+            {
+                "id": "task1",
+                "name": "Task 1",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "ls", "process": { "timeout_ms": "soon" } }
+                }
+            }
+        ]
+    }
+    "#;
+
+    assert!(parse_workflow(json).is_err());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_timeout_kills_grandchildren() {
+    let mut context = ExecutionContext::new("test".to_string(), "test_workflow".to_string());
+    let task = cli_task(
+        "sh",
+        &["-c", "sleep 30 & echo $!; wait"],
+        ProcessOptions {
+            timeout_ms: Some(200),
+            kill_grace_ms: Some(100),
+            ..Default::default()
+        },
+    );
+
+    let result = CliCommandHandler
+        .execute(&mut context, &task)
+        .await
+        .unwrap();
+
+    assert!(!result.success);
+    assert_eq!(
+        result.error.as_deref(),
+        Some("Command timed out after 200 ms")
+    );
+    assert_eq!(result.failure_reason, Some(FailureReason::Timeout));
+    let pid: i32 = result.output.as_str().unwrap().trim().parse().unwrap();
+    assert!(wait_until_gone(pid).await);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_background_process_killed_after_exit() {
+    let mut context = ExecutionContext::new("test".to_string(), "test_workflow".to_string());
+    let task = cli_task(
+        "sh",
+        &["-c", "sleep 30 & echo $!"],
+        ProcessOptions::default(),
+    );
+
+    let started = Instant::now();
+    let result = CliCommandHandler
+        .execute(&mut context, &task)
+        .await
+        .unwrap();
+
+    assert!(result.success);
+    assert!(started.elapsed() < Duration::from_secs(10));
+    let pid: i32 = result.output.as_str().unwrap().trim().parse().unwrap();
+    assert!(wait_until_gone(pid).await);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_sigterm_escalates_to_sigkill() {
+    let mut context = ExecutionContext::new("test".to_string(), "test_workflow".to_string());
+    let task = cli_task(
+        "sh",
+        &["-c", "trap '' TERM; while true; do sleep 0.05; done"],
+        ProcessOptions {
+            timeout_ms: Some(100),
+            kill_grace_ms: Some(200),
+            ..Default::default()
+        },
+    );
+
+    let started = Instant::now();
+    let result = CliCommandHandler
+        .execute(&mut context, &task)
+        .await
+        .unwrap();
+
+    assert!(!result.success);
+    assert!(started.elapsed() >= Duration::from_millis(300));
+    assert!(started.elapsed() < Duration::from_secs(10));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_cpu_time_limit_is_reported() {
+    let mut context = ExecutionContext::new("test".to_string(), "test_workflow".to_string());
+    let task = cli_task(
+        "sh",
+        &["-c", "while :; do :; done"],
+        ProcessOptions {
+            timeout_ms: Some(30_000),
+            max_cpu_seconds: Some(1),
+            ..Default::default()
+        },
+    );
+
+    let result = CliCommandHandler
+        .execute(&mut context, &task)
+        .await
+        .unwrap();
+
+    assert!(!result.success);
+    assert_eq!(result.failure_reason, Some(FailureReason::CpuLimitExceeded));
+    assert_eq!(
+        result.error.as_deref(),
+        Some("Command exceeded its CPU time limit")
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_nice_applies_to_command() {
+    let mut context = ExecutionContext::new("test".to_string(), "test_workflow".to_string());
+    let task = cli_task(
+        "sh",
+        &[
+            "-c",
+            "cut -d ' ' -f 19 /proc/self/stat 2>/dev/null || ps -o nice= -p $$",
+        ],
+        ProcessOptions {
+            nice: Some(5),
+            ..Default::default()
+        },
+    );
+
+    let result = CliCommandHandler
+        .execute(&mut context, &task)
+        .await
+        .unwrap();
+
+    assert!(result.success);
+    assert_eq!(result.failure_reason, None);
+    let nice: i32 = result.output.as_str().unwrap().trim().parse().unwrap();
+    assert!(nice >= 5);
+}
+
+#[test]
+fn test_parse_task_timeout_seconds() {
+    let workflow = |timeout: serde_json::Value| {
+        serde_json::json!({
+            "id": "timeouts",
+            "name": "Timeouts",
+            "tasks": [{
+                "id": "task1",
+                "name": "Task 1",
+                "timeout_seconds": timeout,
+                "function": { "name": "cli_command", "input": { "command": "ls" } }
+            }]
+        })
+        .to_string()
+    };
+
+    let parsed = parse_workflow(&workflow(serde_json::json!(30))).unwrap();
+    assert_eq!(parsed.tasks[0].timeout_seconds, Some(30));
+    assert_eq!(
+        parse_workflow(&workflow(serde_json::Value::Null))
+            .unwrap()
+            .tasks[0]
+            .timeout_seconds,
+        None
+    );
+    assert!(parse_workflow(&workflow(serde_json::json!(0))).is_err());
+    assert!(parse_workflow(&workflow(serde_json::json!("soon"))).is_err());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_task_timeout_fails_task_and_kills_process() {
+    let pid_file = std::env::temp_dir().join(format!("s_e_e-timeout-{}", uuid::Uuid::new_v4()));
+    let script = format!("sleep 30 & echo $! > {}; wait", pid_file.display());
+    let json = serde_json::json!({
+        "id": "timeouts",
+        "name": "Timeouts",
+        "tasks": [{
+            "id": "process_task",
+            "name": "Process Task",
+            "timeout_seconds": 1,
+            "function": {
+                "name": "cli_command",
+                "input": { "command": "sh", "args": ["-c", script] }
+            }
+        }]
+    });
+    let workflow = parse_workflow(&json.to_string()).unwrap();
+
+    let started = Instant::now();
+    let result = crate::WorkflowEngine::new()
+        .execute_workflow(workflow)
+        .await
+        .unwrap();
+
+    assert!(started.elapsed() < Duration::from_secs(10));
+    assert!(!result.success);
+    assert_eq!(
+        result.failure_reasons.get("process_task"),
+        Some(&FailureReason::Timeout)
+    );
+    assert!(result
+        .errors
+        .iter()
+        .any(|error| error.contains("Task process_task timed out after 1 s")));
+    let pid: i32 = std::fs::read_to_string(&pid_file)
+        .unwrap()
+        .trim()
+        .parse()
+        .unwrap();
+    let _ = std::fs::remove_file(&pid_file);
+    assert!(wait_until_gone(pid).await);
+}
+
+#[test]
+fn test_task_timeout_error_category() {
+    let error = crate::errors::EngineError::TaskTimeout {
+        task_id: "build".to_string(),
+        seconds: 5,
+    };
+    assert_eq!(error.category(), crate::errors::ErrorCategory::Timeout);
+    assert_eq!(error.to_string(), "Task build timed out after 5 s");
+}
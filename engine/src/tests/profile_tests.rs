@@ -0,0 +1,155 @@
+use crate::*;
+use serde_json::json;
+
+fn build_workflow() -> EngineWorkflow {
+    let json = json!({
+        "id": "build",
+        "name": "Build",
+        "tasks": [{
+            "id": "compile",
+            "name": "Compile",
+            "function": { "name": "cli_command", "input": { "command": "echo", "args": ["ok"] } },
+            "next_tasks": [{
+                "id": "package",
+                "name": "Package",
+                "function": { "name": "cli_command", "input": { "command": "echo", "args": ["ok"] } }
+            }]
+        }]
+    });
+    parse_workflow(&json.to_string()).unwrap()
+}
+
+fn spans(profile: &RunProfile, phase: ProfilePhase) -> Vec<&ProfileSpan> {
+    profile
+        .spans
+        .iter()
+        .filter(|span| span.phase == phase)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_profiled_run_times_each_task_and_round() {
+    let profiler = Profiler::new();
+    let result = WorkflowEngine::new()
+        .with_profiler(profiler.clone())
+        .execute_workflow(build_workflow())
+        .await
+        .unwrap();
+    assert!(result.success, "{:?}", result.errors);
+
+    let profile = profiler.profile().unwrap();
+    let dispatched: Vec<&str> = spans(&profile, ProfilePhase::Dispatch)
+        .iter()
+        .map(|span| span.label.as_str())
+        .collect();
+    assert_eq!(dispatched, ["compile", "package"]);
+    for handler in spans(&profile, ProfilePhase::Handler) {
+        let dispatch = spans(&profile, ProfilePhase::Dispatch)
+            .into_iter()
+            .find(|span| span.label == handler.label)
+            .unwrap();
+        assert!(handler.start_us >= dispatch.start_us);
+        assert!(handler.duration_us <= dispatch.duration_us);
+    }
+    // One round per task, and the last one that finds nothing left to run.
+    assert_eq!(spans(&profile, ProfilePhase::GraphBuild).len(), 3);
+    assert!(profile.total_us >= profile.spans.last().unwrap().start_us);
+}
+
+#[tokio::test]
+async fn test_default_profiler_records_nothing() {
+    let profiler = Profiler::default();
+    WorkflowEngine::new()
+        .with_profiler(profiler.clone())
+        .execute_workflow(build_workflow())
+        .await
+        .unwrap();
+
+    assert!(!profiler.is_enabled());
+    assert_eq!(profiler.profile(), None);
+}
+
+fn span(phase: ProfilePhase, label: &str, start_us: u64, duration_us: u64) -> ProfileSpan {
+    ProfileSpan {
+        phase,
+        label: label.to_string(),
+        start_us,
+        duration_us,
+    }
+}
+
+#[test]
+fn test_summary_totals_each_phase_in_order() {
+    let profile = RunProfile {
+        total_us: 1_000,
+        spans: vec![
+            span(ProfilePhase::Persistence, "save execution", 0, 40),
+            span(ProfilePhase::Dispatch, "compile", 50, 300),
+            span(ProfilePhase::Dispatch, "package", 50, 500),
+            span(ProfilePhase::Parse, "workflow", 0, 10),
+        ],
+    };
+
+    assert_eq!(
+        profile.summary(),
+        vec![
+            PhaseSummary {
+                phase: ProfilePhase::Parse,
+                count: 1,
+                total_us: 10,
+                longest_us: 10,
+            },
+            PhaseSummary {
+                phase: ProfilePhase::Dispatch,
+                count: 2,
+                total_us: 800,
+                longest_us: 500,
+            },
+            PhaseSummary {
+                phase: ProfilePhase::Persistence,
+                count: 1,
+                total_us: 40,
+                longest_us: 40,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_chrome_trace_puts_each_task_on_its_own_track() {
+    let profile = RunProfile {
+        total_us: 1_000,
+        spans: vec![
+            span(ProfilePhase::GraphBuild, "round 1", 0, 5),
+            span(ProfilePhase::Dispatch, "compile", 10, 300),
+            span(ProfilePhase::Handler, "compile", 12, 290),
+            span(ProfilePhase::Dispatch, "package", 10, 500),
+        ],
+    };
+
+    let trace = profile.to_chrome_trace();
+    let events = trace["traceEvents"].as_array().unwrap();
+    let complete: Vec<(&str, &str, u64)> = events
+        .iter()
+        .filter(|event| event["ph"] == "X")
+        .map(|event| {
+            (
+                event["name"].as_str().unwrap(),
+                event["cat"].as_str().unwrap(),
+                event["tid"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        complete,
+        [
+            ("round 1", "graph build", 1),
+            ("compile", "dispatch", 2),
+            ("compile", "handler", 2),
+            ("package", "dispatch", 3),
+        ]
+    );
+    assert_eq!(events[1]["ts"], 10);
+    assert_eq!(events[1]["dur"], 300);
+    assert_eq!(events.iter().filter(|event| event["ph"] == "M").count(), 3);
+}
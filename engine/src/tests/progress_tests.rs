@@ -0,0 +1,124 @@
+use crate::engine::WorkflowEngine;
+use crate::events::{OutputEvent, OutputEvents, OutputSink};
+use crate::parser::parse_workflow;
+use crate::progress::{parse_progress_line, TaskProgress};
+use std::sync::{Arc, Mutex};
+
+fn collecting_sink() -> (OutputSink, Arc<Mutex<Vec<OutputEvent>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let collected = Arc::clone(&events);
+    let sink: OutputSink = Arc::new(move |event| collected.lock().unwrap().push(event));
+    (sink, events)
+}
+
+#[test]
+fn test_parse_step_counters() {
+    assert_eq!(
+        parse_progress_line("[3/10] Building s_e_e_engine"),
+        Some(TaskProgress::percent(30).with_status("Building s_e_e_engine"))
+    );
+    assert_eq!(
+        parse_progress_line("Step 2/4 : RUN make"),
+        Some(TaskProgress::percent(50).with_status("RUN make"))
+    );
+    assert_eq!(
+        parse_progress_line("(1/1)"),
+        Some(TaskProgress::percent(100))
+    );
+    assert_eq!(parse_progress_line("[5/3] too far"), None);
+    assert_eq!(parse_progress_line("[0/0] nothing"), None);
+}
+
+#[test]
+fn test_parse_percentages() {
+    assert_eq!(
+        parse_progress_line("Receiving objects:  45% (450/1000)"),
+        Some(TaskProgress::percent(45).with_status("Receiving objects"))
+    );
+    assert_eq!(
+        parse_progress_line("  12.5% done"),
+        Some(TaskProgress::percent(12))
+    );
+    assert_eq!(parse_progress_line("GET /search?q=url%20encoded"), None);
+    assert_eq!(parse_progress_line("load 150%"), None);
+    assert_eq!(parse_progress_line("[INFO] starting"), None);
+}
+
+#[test]
+fn test_progress_display() {
+    assert_eq!(
+        TaskProgress::percent(45)
+            .with_status("Compiling")
+            .to_string(),
+        "[45%] Compiling"
+    );
+    assert_eq!(TaskProgress::percent(200).to_string(), "[100%]");
+    assert_eq!(
+        TaskProgress::default().with_status("Waiting").to_string(),
+        "Waiting"
+    );
+}
+
+#[test]
+fn test_events_carry_progress() {
+    let (sink, events) = collecting_sink();
+    let output = OutputEvents::new("exec-progress", sink);
+
+    output.command_line("build", "Build", "[1/4] Fetching");
+    output.command_line("build", "Build", "plain output");
+    output.task_progress("build", "Build", TaskProgress::percent(75));
+    output.task_line("build", "Build", "[2/4] not parsed");
+
+    let events = events.lock().unwrap();
+    assert_eq!(
+        events[0].progress,
+        Some(TaskProgress::percent(25).with_status("Fetching"))
+    );
+    assert_eq!(events[1].progress, None);
+    assert_eq!(events[2].line, "[75%]");
+    assert_eq!(events[2].progress, Some(TaskProgress::percent(75)));
+    assert_eq!(events[3].progress, None);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_command_output_reports_progress() {
+    let json = r#"
+    {
+        "id": "progress",
+        "name": "Progress Output",
+        "tasks": [
+            {
+                "id": "steps",
+                "name": "Steps",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "sh", "args": ["-c", "echo '[1/2] one'; echo '[2/2] two'"] }
+                }
+            }
+        ]
+    }
+    "#;
+
+    let (sink, events) = collecting_sink();
+    let engine = WorkflowEngine::new().with_output(OutputEvents::new("exec-steps", sink));
+    let result = engine
+        .execute_workflow(parse_workflow(json).unwrap())
+        .await
+        .unwrap();
+    assert!(result.success);
+
+    let events = events.lock().unwrap();
+    let progress: Vec<TaskProgress> = events
+        .iter()
+        .filter(|e| e.task_id.as_deref() == Some("steps"))
+        .filter_map(|e| e.progress.clone())
+        .collect();
+    assert_eq!(
+        progress,
+        vec![
+            TaskProgress::percent(50).with_status("one"),
+            TaskProgress::percent(100).with_status("two"),
+        ]
+    );
+}
@@ -0,0 +1,176 @@
+use crate::handlers::{publish_message::PublishMessageHandler, TaskHandler};
+use crate::types::*;
+use crate::*;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+fn publish_workflow(input: Value) -> Value {
+    json!({
+        "id": "w",
+        "name": "W",
+        "tasks": [{
+            "id": "announce",
+            "name": "Announce",
+            "function": { "name": "publish_message", "input": input }
+        }]
+    })
+}
+
+fn publish_task(connection: &str) -> EngineTask {
+    EngineTask {
+        id: "announce".to_string(),
+        name: "Announce".to_string(),
+        function: TaskFunction::PublishMessage {
+            connection: connection.to_string(),
+            destination: "releases".to_string(),
+            payload: json!({ "version": "{{param.version}}" }),
+            key: None,
+            headers: BTreeMap::new(),
+        },
+        next_tasks: Vec::new(),
+        status: TaskStatus::Pending,
+        is_root: true,
+        cache: None,
+        stage: None,
+        lock: None,
+        timeout_seconds: None,
+        when: None,
+        for_each: None,
+        speculative: None,
+    }
+}
+
+#[test]
+fn test_parse_publish_message_task() {
+    let json = publish_workflow(json!({
+        "connection": "events",
+        "destination": "releases",
+        "key": "{{param.version}}",
+        "headers": { "source": "see" },
+        "payload": { "version": "{{param.version}}" }
+    }));
+    let workflow = parse_workflow(&json.to_string()).unwrap();
+    assert_eq!(
+        workflow.tasks[0].function,
+        TaskFunction::PublishMessage {
+            connection: "events".to_string(),
+            destination: "releases".to_string(),
+            payload: json!({ "version": "{{param.version}}" }),
+            key: Some("{{param.version}}".to_string()),
+            headers: BTreeMap::from([("source".to_string(), "see".to_string())]),
+        }
+    );
+
+    for input in [
+        json!({ "destination": "releases", "payload": "hi" }),
+        json!({ "connection": "events", "payload": "hi" }),
+        json!({ "connection": "events", "destination": "releases" }),
+    ] {
+        assert!(matches!(
+            parse_workflow(&publish_workflow(input).to_string()),
+            Err(ParserError::MissingField(_))
+        ));
+    }
+
+    let bad_headers = publish_workflow(json!({
+        "connection": "events",
+        "destination": "releases",
+        "payload": "hi",
+        "headers": { "retries": 3 }
+    }));
+    assert!(matches!(
+        parse_workflow(&bad_headers.to_string()),
+        Err(ParserError::InvalidTask(_))
+    ));
+}
+
+#[test]
+fn test_render_template() {
+    let params = Map::from_iter([
+        ("version".to_string(), json!("1.4.0")),
+        ("count".to_string(), json!(3)),
+    ]);
+    let data = json!({ "build": { "artifacts": [{ "name": "see.tar.gz" }] } });
+
+    let rendered = render_template(
+        &json!({
+            "count": "{{param.count}}",
+            "title": "Release {{ param.version }} ({{param.count}} files)",
+            "first": "{{data.build.artifacts.0.name}}",
+            "tags": ["{{param.version}}", true]
+        }),
+        &params,
+        &data,
+    )
+    .unwrap();
+    assert_eq!(
+        rendered,
+        json!({
+            "count": 3,
+            "title": "Release 1.4.0 (3 files)",
+            "first": "see.tar.gz",
+            "tags": ["1.4.0", true]
+        })
+    );
+
+    assert!(render_template(&json!("{{param.missing}}"), &params, &data).is_err());
+    assert!(render_template(&json!("{{data.deploy}}"), &params, &data).is_err());
+    assert!(render_template(&json!("{{env.HOME}}"), &params, &data).is_err());
+}
+
+#[test]
+fn test_kafka_servers_with_credentials() {
+    std::env::set_var("SEE_TEST_KAFKA_PASSWORD", "secret");
+    let connection = MessageConnection {
+        name: "events".to_string(),
+        backend: MessageBackend::Kafka,
+        url: "svc-see@broker1:9092,broker2:9092".to_string(),
+        password_env: Some("SEE_TEST_KAFKA_PASSWORD".to_string()),
+    };
+    assert_eq!(
+        connection.kafka_servers().unwrap(),
+        (
+            "broker1:9092,broker2:9092".to_string(),
+            Some(("svc-see".to_string(), "secret".to_string()))
+        )
+    );
+
+    let anonymous = MessageConnection {
+        url: "broker1:9092".to_string(),
+        ..connection.clone()
+    };
+    assert!(anonymous.kafka_servers().is_err());
+}
+
+#[tokio::test]
+async fn test_unknown_connection_is_rejected() {
+    let mut context = ExecutionContext::new("exec".to_string(), "workflow".to_string());
+    let result = PublishMessageHandler
+        .execute(&mut context, &publish_task("events"))
+        .await;
+    assert!(matches!(
+        result,
+        Err(HandlerError::InvalidConfiguration(message)) if message.contains("'events'")
+    ));
+}
+
+#[cfg(not(feature = "nats"))]
+#[tokio::test]
+async fn test_broker_without_feature_fails_task() {
+    let mut context = ExecutionContext::new("exec".to_string(), "workflow".to_string());
+    context.message_connections = std::sync::Arc::new(vec![MessageConnection {
+        name: "events".to_string(),
+        backend: MessageBackend::Nats,
+        url: "nats://127.0.0.1:4222".to_string(),
+        password_env: None,
+    }]);
+    context.params.insert("version".to_string(), json!("1.4.0"));
+
+    let result = PublishMessageHandler
+        .execute(&mut context, &publish_task("events"))
+        .await;
+    assert!(matches!(
+        result,
+        Err(HandlerError::ExecutionFailed(message)) if message.contains("'nats' feature")
+    ));
+}
@@ -0,0 +1,127 @@
+use crate::*;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// build → test → deploy, where test passes once `marker` exists.
+fn release_workflow(marker: &Path) -> EngineWorkflow {
+    let json = serde_json::json!({
+        "id": "release",
+        "name": "Release",
+        "tasks": [{
+            "id": "build",
+            "name": "Build",
+            "function": { "name": "cli_command", "input": { "command": "echo", "args": ["built"] } },
+            "next_tasks": [{
+                "id": "test",
+                "name": "Test",
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "test", "args": ["-f", marker.to_str().unwrap()] }
+                },
+                "next_tasks": [{
+                    "id": "deploy",
+                    "name": "Deploy",
+                    "function": {
+                        "name": "cli_command",
+                        "input": { "command": "echo", "args": ["deployed"] }
+                    }
+                }]
+            }]
+        }]
+    });
+    parse_workflow(&json.to_string()).unwrap()
+}
+
+fn status(result: &WorkflowResult, task_id: &str) -> TaskStatus {
+    result
+        .tasks
+        .iter()
+        .find(|task| task.id == task_id)
+        .map(|task| task.status.clone())
+        .unwrap()
+}
+
+fn ran(result: &WorkflowResult) -> Vec<&str> {
+    result
+        .audit_trail
+        .iter()
+        .map(|entry| entry.task_id.as_str())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_rerun_runs_only_the_task() {
+    let marker = std::env::temp_dir().join(format!("see-pass-{}", uuid::Uuid::new_v4()));
+    std::fs::write(&marker, "").unwrap();
+
+    let result = WorkflowEngine::new()
+        .with_initial_data(serde_json::json!({ "build": "built" }))
+        .rerun_task(
+            release_workflow(&marker),
+            "exec".to_string(),
+            "test",
+            false,
+            HashMap::new(),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    assert_eq!(ran(&result), vec!["test"]);
+
+    std::fs::remove_file(marker).unwrap();
+}
+
+#[tokio::test]
+async fn test_rerun_downstream_waits_for_the_task_to_succeed() {
+    let marker = std::env::temp_dir().join(format!("see-pass-{}", uuid::Uuid::new_v4()));
+    let engine = WorkflowEngine::new().with_initial_data(serde_json::json!({ "build": "built" }));
+
+    let failing = engine
+        .rerun_task(
+            release_workflow(&marker),
+            "exec".to_string(),
+            "test",
+            true,
+            HashMap::new(),
+        )
+        .await
+        .unwrap();
+    assert!(!failing.success);
+    assert_eq!(ran(&failing), vec!["test"]);
+    assert_eq!(failing.audit_trail[0].status, AuditStatus::Failure);
+
+    std::fs::write(&marker, "").unwrap();
+    let passing = engine
+        .rerun_task(
+            release_workflow(&marker),
+            "exec".to_string(),
+            "test",
+            true,
+            HashMap::new(),
+        )
+        .await
+        .unwrap();
+    assert!(passing.success, "{:?}", passing.errors);
+    assert_eq!(ran(&passing), vec!["test", "deploy"]);
+    assert_eq!(status(&passing, "test"), TaskStatus::Complete);
+    assert_eq!(status(&passing, "deploy"), TaskStatus::Complete);
+
+    std::fs::remove_file(marker).unwrap();
+}
+
+#[tokio::test]
+async fn test_rerun_unknown_task_is_an_error() {
+    let marker = std::env::temp_dir().join("see-unused");
+    let result = WorkflowEngine::new()
+        .rerun_task(
+            release_workflow(&marker),
+            "exec".to_string(),
+            "missing",
+            false,
+            HashMap::new(),
+        )
+        .await;
+
+    assert!(matches!(result, Err(EngineError::Execution(_))));
+}
@@ -0,0 +1,145 @@
+use crate::handlers::{HandlerRegistry, TaskHandler};
+use crate::*;
+use async_trait::async_trait;
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+/// Reads a page at the scratchpad's cursor and moves the cursor on, leaving
+/// a marker under its own task id as well.
+struct PagingHandler;
+
+#[async_trait]
+impl TaskHandler for PagingHandler {
+    async fn execute(
+        &self,
+        context: &mut ExecutionContext,
+        task: &EngineTask,
+    ) -> Result<TaskResult, HandlerError> {
+        let cursor = context.scratch.get_as::<u64>("cursor").unwrap_or(0);
+        context.scratch.set("cursor", cursor + 1);
+        context.scratch.set(task.id.clone(), true);
+        Ok(TaskResult {
+            success: true,
+            output: json!({ "page": cursor }),
+            error: None,
+            failure_reason: None,
+            output_stats: None,
+            exit_code: None,
+            environment: None,
+        })
+    }
+}
+
+#[derive(Default)]
+struct CollectingCheckpoints {
+    saved: Mutex<Vec<Checkpoint>>,
+}
+
+#[async_trait]
+impl CheckpointStore for CollectingCheckpoints {
+    async fn save(&self, checkpoint: &Checkpoint) {
+        self.saved.lock().unwrap().push(checkpoint.clone());
+    }
+}
+
+fn paging_engine() -> WorkflowEngine {
+    let mut handlers = HandlerRegistry::new();
+    handlers.register_handler("page".to_string(), Box::new(PagingHandler));
+    WorkflowEngine::with_handlers(handlers)
+}
+
+fn page(id: &str, next_tasks: serde_json::Value) -> serde_json::Value {
+    json!({
+        "id": id,
+        "name": id,
+        "function": { "name": "page", "input": {} },
+        "next_tasks": next_tasks
+    })
+}
+
+fn workflow(tasks: serde_json::Value) -> EngineWorkflow {
+    parse_workflow(&json!({ "id": "paging", "name": "Paging", "tasks": tasks }).to_string())
+        .unwrap()
+}
+
+fn pages(result: &WorkflowResult) -> Vec<String> {
+    result
+        .per_task_logs
+        .values()
+        .flatten()
+        .filter(|line| line.contains("page"))
+        .cloned()
+        .collect()
+}
+
+#[tokio::test]
+async fn test_scratchpad_carries_over_but_stays_out_of_audit() {
+    let checkpoints = Arc::new(CollectingCheckpoints::default());
+    let result = paging_engine()
+        .with_checkpoints(checkpoints.clone())
+        .execute_workflow(workflow(json!([page(
+            "first",
+            json!([page("second", json!([]))])
+        )])))
+        .await
+        .unwrap();
+    assert!(result.success, "{:?}", result.errors);
+
+    let checkpoint = checkpoints.saved.lock().unwrap().last().cloned().unwrap();
+    assert_eq!(checkpoint.data["second"], json!({ "page": 1 }));
+    assert_eq!(checkpoint.scratch.get_as::<u64>("cursor"), Some(2));
+    assert!(checkpoint.data.get("cursor").is_none());
+    let audit = serde_json::to_string(&result.audit_trail).unwrap();
+    assert!(!audit.contains("cursor"), "{}", audit);
+}
+
+#[tokio::test]
+async fn test_parallel_tasks_keep_each_others_scratch_entries() {
+    let checkpoints = Arc::new(CollectingCheckpoints::default());
+    paging_engine()
+        .with_checkpoints(checkpoints.clone())
+        .execute_workflow(workflow(json!([
+            page("left", json!([])),
+            page("right", json!([]))
+        ])))
+        .await
+        .unwrap();
+
+    let checkpoint = checkpoints.saved.lock().unwrap().last().cloned().unwrap();
+    assert_eq!(checkpoint.scratch.get("left"), Some(&json!(true)));
+    assert_eq!(checkpoint.scratch.get("right"), Some(&json!(true)));
+}
+
+#[tokio::test]
+async fn test_resume_starts_from_saved_scratchpad() {
+    let mut scratch = Scratchpad::new();
+    scratch.set("cursor", 5);
+    let result = paging_engine()
+        .with_initial_scratch(scratch)
+        .resume_workflow_execution(
+            workflow(json!([page("first", json!([page("second", json!([]))]))])),
+            "exec-paging".to_string(),
+            HashSet::from(["first".to_string()]),
+            HashMap::new(),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    assert_eq!(pages(&result), vec![r#"Output: {"page":5}"#.to_string()]);
+}
+
+#[test]
+fn test_scratchpad_remove_and_typed_reads() {
+    let mut scratch = Scratchpad::new();
+    assert!(scratch.is_empty());
+    scratch.set("temp_file", "/tmp/upload.part");
+    assert_eq!(
+        scratch.get_as::<String>("temp_file").as_deref(),
+        Some("/tmp/upload.part")
+    );
+    assert_eq!(scratch.get_as::<u64>("temp_file"), None);
+    assert_eq!(scratch.remove("temp_file"), Some(json!("/tmp/upload.part")));
+    assert!(scratch.is_empty());
+}
@@ -0,0 +1,124 @@
+use crate::handlers::cli_command::CliCommandHandler;
+use crate::handlers::shell::{quote_cmd_arg, to_native_path, windows_launcher, Launcher};
+use crate::handlers::TaskHandler;
+use crate::types::*;
+
+fn cli_task(command: &str, args: &[&str]) -> EngineTask {
+    EngineTask {
+        id: "shell_task".to_string(),
+        name: "Shell Task".to_string(),
+        function: TaskFunction::CliCommand {
+            command: command.to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+            process: ProcessOptions::default(),
+        },
+        next_tasks: Vec::new(),
+        status: TaskStatus::Pending,
+        is_root: true,
+        cache: None,
+        stage: None,
+        lock: None,
+        timeout_seconds: None,
+        when: None,
+        for_each: None,
+        speculative: None,
+    }
+}
+
+#[test]
+fn test_windows_launcher_selection() {
+    assert_eq!(windows_launcher("echo"), Launcher::Cmd);
+    assert_eq!(windows_launcher("DIR"), Launcher::Cmd);
+    assert_eq!(windows_launcher("scripts/build.bat"), Launcher::Cmd);
+    assert_eq!(windows_launcher("setup.CMD"), Launcher::Cmd);
+    assert_eq!(windows_launcher("scripts/deploy.ps1"), Launcher::PowerShell);
+    assert_eq!(windows_launcher("git"), Launcher::Direct);
+    assert_eq!(windows_launcher("python3"), Launcher::Direct);
+}
+
+#[test]
+fn test_quote_cmd_arg() {
+    assert_eq!(quote_cmd_arg("hello"), "hello");
+    assert_eq!(quote_cmd_arg(""), "\"\"");
+    assert_eq!(quote_cmd_arg("hello world"), "\"hello world\"");
+    assert_eq!(quote_cmd_arg("a&b"), "\"a&b\"");
+    assert_eq!(quote_cmd_arg("say \"hi\""), "\"say \"\"hi\"\"\"");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_to_native_path_unix() {
+    assert_eq!(to_native_path("scripts/build.sh"), "scripts/build.sh");
+}
+
+#[cfg(windows)]
+#[test]
+fn test_to_native_path_windows() {
+    assert_eq!(to_native_path("scripts/build.bat"), "scripts\\build.bat");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_cli_command_reports_exit_code() {
+    let mut context = ExecutionContext::new("test".to_string(), "test_workflow".to_string());
+    let task = cli_task("sh", &["-c", "exit 3"]);
+
+    let result = CliCommandHandler
+        .execute(&mut context, &task)
+        .await
+        .unwrap();
+
+    assert!(!result.success);
+    assert_eq!(
+        result.error.as_deref(),
+        Some("Command failed with exit code: Some(3)")
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_cli_command_maps_signal_to_exit_code() {
+    let mut context = ExecutionContext::new("test".to_string(), "test_workflow".to_string());
+    let task = cli_task("sh", &["-c", "kill -TERM $$"]);
+
+    let result = CliCommandHandler
+        .execute(&mut context, &task)
+        .await
+        .unwrap();
+
+    assert!(!result.success);
+    assert!(result.error.unwrap().contains("Some(143)"));
+}
+
+#[cfg(windows)]
+#[tokio::test]
+async fn test_cli_command_runs_cmd_builtin() {
+    let mut context = ExecutionContext::new("test".to_string(), "test_workflow".to_string());
+    let task = cli_task("echo", &["hello world"]);
+
+    let result = CliCommandHandler
+        .execute(&mut context, &task)
+        .await
+        .unwrap();
+
+    assert!(result.success);
+    assert!(result.output.as_str().unwrap().contains("hello world"));
+}
+
+#[cfg(windows)]
+#[tokio::test]
+async fn test_cli_command_reports_exit_code() {
+    let mut context = ExecutionContext::new("test".to_string(), "test_workflow".to_string());
+    let task = cli_task("cmd.exe", &["/C", "exit 3"]);
+
+    let result = CliCommandHandler
+        .execute(&mut context, &task)
+        .await
+        .unwrap();
+
+    assert!(!result.success);
+    assert_eq!(
+        result.error.as_deref(),
+        Some("Command failed with exit code: Some(3)")
+    );
+}
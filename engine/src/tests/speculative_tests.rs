@@ -0,0 +1,239 @@
+use crate::*;
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct CollectingCheckpoints {
+    saved: Mutex<Vec<Checkpoint>>,
+}
+
+#[async_trait]
+impl CheckpointStore for CollectingCheckpoints {
+    async fn save(&self, checkpoint: &Checkpoint) {
+        self.saved.lock().unwrap().push(checkpoint.clone());
+    }
+}
+
+fn echo(id: &str, text: &str) -> Value {
+    json!({
+        "id": id,
+        "name": id,
+        "function": { "name": "cli_command", "input": { "command": "echo", "args": [text] } }
+    })
+}
+
+/// migrate → check, speculative from migrate on, then a `report` task outside
+/// the branch. `check` fails when `check_command` is `false`.
+fn migration_workflow(check_command: &str) -> EngineWorkflow {
+    let json = json!({
+        "id": "migration",
+        "name": "Migration",
+        "tasks": [
+            {
+                "id": "migrate",
+                "name": "Migrate",
+                "speculative": { "commit_when": "data.check != null" },
+                "function": {
+                    "name": "cli_command",
+                    "input": { "command": "echo", "args": ["schema v2"] }
+                },
+                "next_tasks": [{
+                    "id": "check",
+                    "name": "Check",
+                    "function": {
+                        "name": "cli_command",
+                        "input": { "command": check_command, "args": [] }
+                    }
+                }]
+            },
+            echo("report", "report")
+        ],
+        "outputs": {
+            "migrate": "${data.migrate}",
+            "report": "${data.report}"
+        }
+    });
+    parse_workflow(&json.to_string()).unwrap()
+}
+
+async fn run(workflow: EngineWorkflow) -> WorkflowResult {
+    WorkflowEngine::new()
+        .with_initial_data(json!({ "schema": "v1" }))
+        .execute_workflow(workflow)
+        .await
+        .unwrap()
+}
+
+#[test]
+fn test_parse_speculative_task() {
+    let workflow = migration_workflow("true");
+    let migrate = workflow
+        .tasks
+        .iter()
+        .find(|task| task.id == "migrate")
+        .unwrap();
+    assert_eq!(
+        migrate.speculative,
+        Some(Speculative {
+            commit_when: "data.check != null".to_string()
+        })
+    );
+
+    for (speculative, expected) in [
+        (json!({}), "Task t speculative is invalid"),
+        (
+            json!({ "commit_when": "data.x ==" }),
+            "Task t commit_when is invalid",
+        ),
+    ] {
+        let json = json!({
+            "id": "w",
+            "name": "W",
+            "tasks": [{
+                "id": "t",
+                "name": "T",
+                "speculative": speculative,
+                "function": { "name": "cli_command", "input": { "command": "true" } }
+            }]
+        });
+        let error = parse_workflow(&json.to_string()).unwrap_err();
+        assert!(error.to_string().contains(expected), "{}", error);
+    }
+}
+
+#[test]
+fn test_speculative_branches_do_not_nest() {
+    let mut inner = echo("inner", "inner");
+    inner["speculative"] = json!({ "commit_when": "true" });
+    let mut outer = echo("outer", "outer");
+    outer["speculative"] = json!({ "commit_when": "true" });
+    outer["next_tasks"] = json!([inner]);
+    let json = json!({ "id": "w", "name": "W", "tasks": [outer] });
+
+    let error = parse_workflow(&json.to_string()).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("task 'inner' is inside the speculative branch of 'outer'"),
+        "{}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn test_branch_is_committed_when_condition_holds() {
+    let result = run(migration_workflow("echo")).await;
+
+    assert!(result.success, "{:?}", result.errors);
+    assert!(!result.outputs["migrate"].is_null());
+    assert!(result.per_task_logs["migrate"]
+        .contains(&"Speculative branch committed: `data.check != null` holds".to_string()));
+}
+
+#[tokio::test]
+async fn test_branch_is_rolled_back_when_condition_fails() {
+    let result = run(migration_workflow("false")).await;
+
+    assert!(!result.success);
+    assert_eq!(result.outputs["migrate"], Value::Null);
+    assert!(!result.outputs["report"].is_null());
+    assert!(result.per_task_logs["migrate"].iter().any(|line| line
+        .starts_with("Speculative branch rolled back: `data.check != null` does not hold")));
+}
+
+#[tokio::test]
+async fn test_rollback_restores_values_from_before_the_branch() {
+    let mut schema = echo("schema", "v2");
+    schema["speculative"] = json!({ "commit_when": "false" });
+    let json = json!({
+        "id": "w",
+        "name": "W",
+        "tasks": [schema],
+        "outputs": { "schema": "${data.schema}" }
+    });
+
+    let result = run(parse_workflow(&json.to_string()).unwrap()).await;
+
+    assert!(result.success, "{:?}", result.errors);
+    assert_eq!(result.outputs["schema"], json!("v1"));
+}
+
+/// canary → approve (manual), committed when a person confirms.
+fn canary_workflow() -> EngineWorkflow {
+    let json = json!({
+        "id": "canary",
+        "name": "Canary",
+        "tasks": [{
+            "id": "canary",
+            "name": "Deploy canary",
+            "speculative": { "commit_when": "data.approve.outcome == \"done\"" },
+            "function": {
+                "name": "cli_command",
+                "input": { "command": "echo", "args": ["canary up"] }
+            },
+            "next_tasks": [{
+                "id": "approve",
+                "name": "Approve",
+                "function": { "name": "manual", "input": { "instructions": "Check the canary" } }
+            }]
+        }],
+        "outputs": { "canary": "${data.canary}" }
+    });
+    parse_workflow(&json.to_string()).unwrap()
+}
+
+async fn confirm_canary(outcome: ManualOutcome) -> WorkflowResult {
+    let checkpoints = Arc::new(CollectingCheckpoints::default());
+    let paused = WorkflowEngine::new()
+        .with_checkpoints(checkpoints.clone())
+        .execute_workflow(canary_workflow())
+        .await
+        .unwrap();
+    assert!(paused
+        .tasks
+        .iter()
+        .any(|task| task.id == "approve" && task.status == TaskStatus::WaitingForInput));
+    let checkpoint = checkpoints.saved.lock().unwrap().last().cloned().unwrap();
+    assert!(checkpoint.scratch.get(BRANCHES_KEY).is_some());
+
+    let confirmation = ManualConfirmation {
+        outcome,
+        confirmed_by: "alice".to_string(),
+        confirmed_at: Utc::now(),
+        notes: None,
+    };
+    let inputs = HashMap::from([(
+        "approve".to_string(),
+        serde_json::to_string(&confirmation).unwrap(),
+    )]);
+    let completed: HashSet<String> = checkpoint.completed_tasks.into_iter().collect();
+    WorkflowEngine::new()
+        .with_initial_data(checkpoint.data)
+        .with_initial_scratch(checkpoint.scratch)
+        .resume_workflow_execution(
+            canary_workflow(),
+            "exec-canary".to_string(),
+            completed,
+            inputs,
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_confirmed_branch_is_committed_after_resume() {
+    let result = confirm_canary(ManualOutcome::Done).await;
+
+    assert!(result.success, "{:?}", result.errors);
+    assert!(!result.outputs["canary"].is_null());
+}
+
+#[tokio::test]
+async fn test_skipped_confirmation_rolls_the_branch_back() {
+    let result = confirm_canary(ManualOutcome::Skipped).await;
+
+    assert_eq!(result.outputs["canary"], Value::Null);
+}
@@ -0,0 +1,189 @@
+use crate::*;
+use serde_json::{json, Value};
+
+fn shell_task(id: &str, stage: Option<&str>, script: &str) -> Value {
+    let mut task = json!({
+        "id": id,
+        "name": id,
+        "function": {
+            "name": "cli_command",
+            "input": { "command": "sh", "args": ["-c", script] }
+        }
+    });
+    if let Some(stage) = stage {
+        task["stage"] = json!(stage);
+    }
+    task
+}
+
+fn staged_workflow(stages: &[&str], tasks: Vec<Value>) -> Result<EngineWorkflow, ParserError> {
+    let json = json!({
+        "id": "staged",
+        "name": "Staged",
+        "stages": stages,
+        "tasks": tasks,
+    });
+    parse_workflow(&json.to_string())
+}
+
+fn temp_dir() -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("s_e_e-stages-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_parse_stages() {
+    let workflow = staged_workflow(
+        &["build", "test"],
+        vec![
+            shell_task("compile", Some("build"), "true"),
+            shell_task("unstaged", None, "true"),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(workflow.stages, ["build", "test"]);
+    let stage_of = |id: &str| {
+        workflow
+            .tasks
+            .iter()
+            .find(|task| task.id == id)
+            .and_then(|task| task.stage.clone())
+    };
+    assert_eq!(stage_of("compile").as_deref(), Some("build"));
+    assert_eq!(stage_of("unstaged"), None);
+}
+
+#[test]
+fn test_parse_rejects_invalid_stages() {
+    let mut follows_later_stage = shell_task("deploy", Some("deploy"), "true");
+    follows_later_stage["next_tasks"] = json!([shell_task("compile", Some("build"), "true")]);
+    let mut nested_under_unstaged = shell_task("setup", None, "true");
+    nested_under_unstaged["next_tasks"] = json!([follows_later_stage.clone()]);
+    let mut numeric_stage = shell_task("compile", None, "true");
+    numeric_stage["stage"] = json!(1);
+
+    for (stages, task) in [
+        (vec!["build"], shell_task("compile", Some("test"), "true")),
+        (vec!["build", "build"], shell_task("compile", None, "true")),
+        (vec![""], shell_task("compile", None, "true")),
+        (vec!["build", "deploy"], follows_later_stage),
+        (vec!["build", "deploy"], nested_under_unstaged),
+        (vec!["build"], numeric_stage),
+    ] {
+        let result = staged_workflow(&stages, vec![task.clone()]);
+        assert!(
+            matches!(result, Err(ParserError::InvalidTask(_))),
+            "accepted {} with stages {:?}",
+            task,
+            stages
+        );
+    }
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_stage_waits_for_earlier_stage() {
+    let dir = temp_dir();
+    let marker = dir.join("built");
+    let workflow = staged_workflow(
+        &["build", "test"],
+        vec![
+            shell_task(
+                "compile",
+                Some("build"),
+                &format!("sleep 0.3 && touch {}", marker.display()),
+            ),
+            shell_task(
+                "check",
+                Some("test"),
+                &format!("test -f {}", marker.display()),
+            ),
+        ],
+    )
+    .unwrap();
+
+    let result = WorkflowEngine::new()
+        .execute_workflow(workflow)
+        .await
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    assert_eq!(
+        result.stages,
+        vec![
+            StageInfo {
+                name: "build".to_string(),
+                status: TaskStatus::Complete,
+                task_ids: vec!["compile".to_string()],
+            },
+            StageInfo {
+                name: "test".to_string(),
+                status: TaskStatus::Complete,
+                task_ids: vec!["check".to_string()],
+            },
+        ]
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_failed_stage_stops_later_stages() {
+    let dir = temp_dir();
+    let marker = dir.join("deployed");
+    let workflow = staged_workflow(
+        &["test", "deploy"],
+        vec![
+            shell_task("unit", Some("test"), "true"),
+            shell_task("lint", Some("test"), "exit 1"),
+            shell_task(
+                "ship",
+                Some("deploy"),
+                &format!("touch {}", marker.display()),
+            ),
+            shell_task("report", None, "true"),
+        ],
+    )
+    .unwrap();
+
+    let result = WorkflowEngine::new()
+        .execute_workflow(workflow)
+        .await
+        .unwrap();
+
+    assert!(!result.success);
+    assert!(!marker.exists());
+    let statuses: Vec<_> = result
+        .stages
+        .iter()
+        .map(|stage| (stage.name.as_str(), stage.status.clone()))
+        .collect();
+    assert_eq!(
+        statuses,
+        [
+            ("test", TaskStatus::Failed),
+            ("deploy", TaskStatus::Pending)
+        ]
+    );
+    // Tasks outside any stage are not held back.
+    assert!(result
+        .audit_trail
+        .iter()
+        .any(|entry| entry.task_id == "report" && entry.status == AuditStatus::Success));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_workflow_without_stages_reports_none() {
+    let workflow = staged_workflow(&[], vec![shell_task("only", None, "true")]).unwrap();
+
+    let result = WorkflowEngine::new()
+        .execute_workflow(workflow)
+        .await
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.errors);
+    assert!(result.stages.is_empty());
+}
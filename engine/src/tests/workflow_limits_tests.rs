@@ -0,0 +1,138 @@
+use crate::errors::ParserError;
+use crate::parser::parse_workflow_with_limits;
+use crate::workflow_limits::{check_workflow_limits, WorkflowLimits};
+use serde_json::{json, Value};
+
+fn task(id: &str, next_tasks: Vec<Value>) -> Value {
+    json!({"id": id, "function": {"name": "noop"}, "next_tasks": next_tasks})
+}
+
+fn workflow(tasks: Vec<Value>) -> Value {
+    json!({"id": "limits", "name": "Limits", "tasks": tasks})
+}
+
+fn chain(length: usize) -> Value {
+    (1..length).fold(task("t0", vec![]), |next, i| {
+        task(&format!("t{}", i), vec![next])
+    })
+}
+
+fn exceeded(result: Result<(), ParserError>) -> Option<&'static str> {
+    match result {
+        Err(ParserError::LimitExceeded { what, .. }) => Some(what),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_default_limits_accept_ordinary_workflow() {
+    let tasks = (0..10).map(|i| task(&format!("t{}", i), vec![])).collect();
+    assert!(check_workflow_limits(&workflow(tasks), &WorkflowLimits::default()).is_ok());
+}
+
+#[test]
+fn test_task_count_limit_counts_nested_tasks() {
+    let limits = WorkflowLimits {
+        max_tasks: 3,
+        ..Default::default()
+    };
+    let nested = workflow(vec![task("a", vec![task("b", vec![]), task("c", vec![])])]);
+    assert!(check_workflow_limits(&nested, &limits).is_ok());
+
+    let too_many = workflow(vec![
+        task("a", vec![task("b", vec![]), task("c", vec![])]),
+        task("d", vec![]),
+    ]);
+    assert_eq!(
+        exceeded(check_workflow_limits(&too_many, &limits)),
+        Some("tasks")
+    );
+}
+
+#[test]
+fn test_depth_limit() {
+    let limits = WorkflowLimits {
+        max_depth: 4,
+        ..Default::default()
+    };
+    assert!(check_workflow_limits(&workflow(vec![chain(4)]), &limits).is_ok());
+    assert_eq!(
+        exceeded(check_workflow_limits(&workflow(vec![chain(5)]), &limits)),
+        Some("levels of next_tasks")
+    );
+}
+
+#[test]
+fn test_fan_out_limit() {
+    let limits = WorkflowLimits {
+        max_fan_out: 2,
+        ..Default::default()
+    };
+    let fan_out = |n: usize| {
+        workflow(vec![task(
+            "root",
+            (0..n).map(|i| task(&format!("t{}", i), vec![])).collect(),
+        )])
+    };
+    assert!(check_workflow_limits(&fan_out(2), &limits).is_ok());
+    assert_eq!(
+        exceeded(check_workflow_limits(&fan_out(3), &limits)),
+        Some("next_tasks on one task")
+    );
+}
+
+#[test]
+fn test_parameter_limit() {
+    let limits = WorkflowLimits {
+        max_parameters: 1,
+        ..Default::default()
+    };
+    let mut definition = workflow(vec![]);
+    definition["parameters"] = json!([
+        {"name": "a", "type": "string"},
+        {"name": "b", "type": "string"}
+    ]);
+    assert_eq!(
+        exceeded(check_workflow_limits(&definition, &limits)),
+        Some("parameters")
+    );
+}
+
+#[test]
+fn test_document_size_checked_before_parsing() {
+    let limits = WorkflowLimits {
+        max_document_bytes: 16,
+        ..Default::default()
+    };
+    // Not even valid JSON: the size check must come first.
+    let result = parse_workflow_with_limits(&"x".repeat(17), &limits);
+    assert!(matches!(
+        result,
+        Err(ParserError::LimitExceeded {
+            what: "bytes",
+            max: 16
+        })
+    ));
+}
+
+#[test]
+fn test_large_matrix_rejected_by_default() {
+    let tasks: Vec<Value> = (0..50)
+        .map(|i| {
+            task(
+                &format!("row{}", i),
+                (0..1000)
+                    .map(|j| task(&format!("cell{}-{}", i, j), vec![]))
+                    .collect(),
+            )
+        })
+        .collect();
+    let json = workflow(tasks).to_string();
+    let limits = WorkflowLimits {
+        max_document_bytes: usize::MAX,
+        ..Default::default()
+    };
+
+    let result = parse_workflow_with_limits(&json, &limits);
+    assert!(matches!(result, Err(ParserError::LimitExceeded { .. })));
+}
@@ -1,6 +1,19 @@
+use crate::db::DbConnection;
+use crate::dir_cache::DirectoryCache;
+use crate::dry_run::PlannedTask;
+use crate::events::OutputEvents;
+use crate::messaging::MessageConnection;
+use crate::notify::NotifyRule;
+use crate::preflight::PreflightCheck;
+use crate::progress::TaskProgress;
+use crate::scratch::Scratchpad;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EngineTask {
@@ -13,13 +26,63 @@ pub struct EngineTask {
     pub status: TaskStatus,
     #[serde(default)]
     pub is_root: bool,
+    /// Reuse an earlier successful result for the same config instead of running again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<TaskCache>,
+    /// Stage the task belongs to, one of the workflow's `stages`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage: Option<String>,
+    /// Named resource only one task may use at a time, across executions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lock: Option<String>,
+    /// Fail the task if its handler runs longer than this, stopping any
+    /// process it started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<u64>,
+    /// Condition on the execution context, see [`crate::Condition`]. When it
+    /// does not hold, the task and every task after it are skipped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub when: Option<String>,
+    /// `data.` or `param.` path of an array; the task runs once per item,
+    /// see [`crate::loops`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub for_each: Option<String>,
+    /// Run the task and every task after it as a branch whose context
+    /// changes are only kept if a condition holds, see [`crate::speculation`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speculative: Option<Speculative>,
+}
+
+/// Result caching for a task whose output depends only on its config and the
+/// workflow parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskCache {
+    pub ttl_seconds: u64,
+}
+
+impl TaskCache {
+    /// How long results stay valid for a task declaring `"cache": true`.
+    pub const DEFAULT_TTL_SECONDS: u64 = 24 * 60 * 60;
+}
+
+/// When a speculative branch keeps what it changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Speculative {
+    /// Condition on the execution context, see [`crate::Condition`], checked
+    /// once every task in the branch has finished.
+    pub commit_when: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "name", content = "input")]
 pub enum TaskFunction {
     #[serde(rename = "cli_command")]
-    CliCommand { command: String, args: Vec<String> },
+    CliCommand {
+        command: String,
+        args: Vec<String>,
+        #[serde(default)]
+        process: ProcessOptions,
+    },
     #[serde(rename = "cursor_agent")]
     CursorAgent { prompt: String, config: Value },
     #[serde(rename = "custom")]
@@ -31,6 +94,77 @@ pub enum TaskFunction {
         required: bool,
         default: Option<Value>,
     },
+    /// A step a person performs outside the engine, e.g. reviewing a PR. The
+    /// task waits until someone confirms it done or skipped.
+    #[serde(rename = "manual")]
+    Manual { instructions: String },
+    /// A parameterized query against a named connection. Result rows are
+    /// stored in the context as JSON objects.
+    #[serde(rename = "db_query")]
+    DbQuery {
+        connection: String,
+        query: String,
+        #[serde(default)]
+        params: Vec<Value>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_rows: Option<usize>,
+    },
+    /// Publishes a message to a topic, subject or exchange on a named broker
+    /// connection. `{{...}}` placeholders in the payload and key are filled in
+    /// from workflow parameters and earlier task outputs.
+    #[serde(rename = "publish_message")]
+    PublishMessage {
+        connection: String,
+        destination: String,
+        payload: Value,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        key: Option<String>,
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        headers: BTreeMap<String, String>,
+    },
+    /// Restores `paths`, relative to the working directory, from what an
+    /// earlier run saved under `key`. On a miss they are saved under `key`
+    /// once the run succeeds. `{{...}}` placeholders in the key are filled in.
+    #[serde(rename = "cache")]
+    Cache { key: String, paths: Vec<String> },
+}
+
+/// How a person settled a `manual` task. Passed back to the engine as the
+/// task's input, serialized as JSON, when the run resumes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManualConfirmation {
+    pub outcome: ManualOutcome,
+    pub confirmed_by: String,
+    pub confirmed_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ManualOutcome {
+    Done,
+    Skipped,
+}
+
+/// Lifecycle controls for the process tree spawned by a `cli_command` task.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProcessOptions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kill_grace_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_memory_mb: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_cpu_percent: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_cpu_seconds: Option<u64>,
+    /// Bytes kept from each of stdout and stderr; the middle of longer output is elided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_output_bytes: Option<u64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -38,6 +172,138 @@ pub struct EngineWorkflow {
     pub id: String,
     pub name: String,
     pub tasks: Vec<EngineTask>,
+    #[serde(default)]
+    pub parameters: Vec<WorkflowParameter>,
+    #[serde(default)]
+    pub triggers: Vec<WorkflowTrigger>,
+    /// Set by `isolated_workspace`: every run gets its own working directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace: Option<WorkspaceOptions>,
+    /// Checked before the first task runs; any failure aborts the run.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub preflight: Vec<PreflightCheck>,
+    /// Stage names in the order they run. No task of a stage starts before
+    /// every task of the stages before it has succeeded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stages: Vec<String>,
+    /// Named values picked from the context when the run ends, see
+    /// [`crate::outputs`].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub outputs: BTreeMap<String, Value>,
+    /// Which tasks to notify about and when, see [`crate::notify`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notify: Vec<NotifyRule>,
+}
+
+/// How an isolated workspace is prepared.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceOptions {
+    /// Directory whose contents are copied into each new workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+/// Something that starts a workflow without a user asking for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum WorkflowTrigger {
+    /// Run after every successful execution of another workflow.
+    #[serde(rename = "after_workflow")]
+    AfterWorkflow { workflow_id: String },
+    /// Run once per message taken from a queue by `see listen`.
+    #[serde(rename = "message")]
+    Message {
+        /// Name of a message broker connection from the settings.
+        connection: String,
+        /// Kafka topic, NATS JetStream stream, or AMQP queue to consume.
+        source: String,
+        /// Workflow parameter name to a JSON pointer into the payload. When
+        /// empty, top-level payload fields fill parameters of the same name.
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        params: BTreeMap<String, String>,
+        /// Messages handled at the same time.
+        #[serde(default = "default_max_concurrent")]
+        max_concurrent: usize,
+        /// Runs of the workflow per message before giving up on it.
+        #[serde(default = "default_max_attempts")]
+        max_attempts: u32,
+        /// Where messages that still fail after `max_attempts` are published.
+        /// Without one they are rejected back to the broker.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        dead_letter: Option<String>,
+    },
+}
+
+fn default_max_concurrent() -> usize {
+    1
+}
+
+fn default_max_attempts() -> u32 {
+    3
+}
+
+/// A value the workflow expects from whoever runs it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowParameter {
+    pub name: String,
+    #[serde(rename = "type", default)]
+    pub param_type: ParameterType,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The only values accepted, offered as a list to pick from.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<Value>,
+    /// Entered hidden and masked wherever the run is shown or stored.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub secret: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ParameterType {
+    #[serde(rename = "string")]
+    #[default]
+    String,
+    #[serde(rename = "number")]
+    Number,
+    #[serde(rename = "boolean")]
+    Boolean,
+    /// A file on this machine that can be read, passed to tasks as its
+    /// absolute path.
+    #[serde(rename = "file")]
+    File,
+    /// A directory on this machine that can be listed, passed to tasks as its
+    /// absolute path.
+    #[serde(rename = "directory")]
+    Directory,
+    /// A pattern such as `data/*.csv`, passed to tasks as the sorted absolute
+    /// paths it matches.
+    #[serde(rename = "glob")]
+    Glob,
+}
+
+impl ParameterType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParameterType::String => "string",
+            ParameterType::Number => "number",
+            ParameterType::Boolean => "boolean",
+            ParameterType::File => "file",
+            ParameterType::Directory => "directory",
+            ParameterType::Glob => "glob",
+        }
+    }
+
+    /// Whether values of this type name paths, checked when a run starts.
+    pub fn is_path(&self) -> bool {
+        matches!(
+            self,
+            ParameterType::File | ParameterType::Directory | ParameterType::Glob
+        )
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -45,6 +311,34 @@ pub struct TaskResult {
     pub success: bool,
     pub output: Value,
     pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<FailureReason>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_stats: Option<OutputStats>,
+    /// Exit status of the process the task ran, if it ran one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    /// Environment the task's process started with, secrets redacted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<BTreeMap<String, String>>,
+}
+
+/// How much a task wrote, counted before output quotas were applied.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputStats {
+    pub total_bytes: u64,
+    pub truncated: bool,
+}
+
+/// Failure causes that are more specific than a plain error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FailureReason {
+    #[serde(rename = "timeout")]
+    Timeout,
+    #[serde(rename = "memory_limit_exceeded")]
+    MemoryLimitExceeded,
+    #[serde(rename = "cpu_limit_exceeded")]
+    CpuLimitExceeded,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
@@ -60,6 +354,10 @@ pub enum TaskStatus {
     Failed,
     #[serde(rename = "waiting_for_input")]
     WaitingForInput,
+    /// Not run because its `when` condition, or that of a task before it,
+    /// did not hold.
+    #[serde(rename = "skipped")]
+    Skipped,
 }
 
 impl TaskStatus {
@@ -70,6 +368,7 @@ impl TaskStatus {
             TaskStatus::Complete => "complete",
             TaskStatus::Failed => "failed",
             TaskStatus::WaitingForInput => "waiting_for_input",
+            TaskStatus::Skipped => "skipped",
         }
     }
 }
@@ -83,6 +382,45 @@ pub struct WorkflowResult {
     pub audit_trail: Vec<AuditEntry>,
     pub per_task_logs: HashMap<String, Vec<String>>,
     pub errors: Vec<String>,
+    #[serde(default)]
+    pub failure_reasons: HashMap<String, FailureReason>,
+    /// Exit codes of failed tasks that ran a process.
+    #[serde(default)]
+    pub exit_codes: HashMap<String, i32>,
+    #[serde(default)]
+    pub output_stats: HashMap<String, OutputStats>,
+    /// Tasks whose result came from the result cache instead of running.
+    #[serde(default)]
+    pub cache_hits: HashSet<String>,
+    /// Environment each process-running task started with, secrets redacted.
+    #[serde(default)]
+    pub environments: HashMap<String, BTreeMap<String, String>>,
+    /// Isolated workspace left on disk, either because the run failed or
+    /// because it is waiting for input. Successful runs remove theirs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace: Option<String>,
+    /// Status of each stage, in stage order. Empty when the workflow has none.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stages: Vec<StageInfo>,
+    /// The workflow's declared `outputs`, filled in from the final context.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub outputs: BTreeMap<String, Value>,
+    /// The tasks a dry run would have run, in the order it reached them.
+    /// Empty for real runs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub planned: Vec<PlannedTask>,
+    /// Milliseconds each task that ran in this run took, handler and lock
+    /// wait included. A `for_each` task counts its longest iteration.
+    #[serde(default)]
+    pub durations_ms: HashMap<String, u64>,
+}
+
+/// How far a stage got, rolled up from the statuses of its tasks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageInfo {
+    pub name: String,
+    pub status: TaskStatus,
+    pub task_ids: Vec<String>,
 }
 
 /// Task information for workflow results
@@ -101,6 +439,32 @@ pub struct AuditEntry {
     pub timestamp: String,
     pub changes_count: usize,
     pub message: String,
+    /// Context changes made by the task, capped by size.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<ContextChange>,
+    #[serde(default)]
+    pub changes_truncated: bool,
+}
+
+/// A single change to the execution context, addressed by JSON pointer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContextChange {
+    pub op: ChangeOp,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChangeOp {
+    #[serde(rename = "add")]
+    Add,
+    #[serde(rename = "remove")]
+    Remove,
+    #[serde(rename = "replace")]
+    Replace,
 }
 
 /// Audit status for tracking task state changes
@@ -128,6 +492,26 @@ pub struct ExecutionContext {
     pub output_logs: Vec<String>,
     pub per_task_logs: HashMap<String, Vec<String>>,
     pub tasks: HashMap<String, EngineTask>,
+    /// Workflow data shared between tasks. Each successful task's output is stored
+    /// under its task id.
+    pub data: Value,
+    /// Transient values handlers keep between tasks, outside the audited data.
+    pub scratch: Scratchpad,
+    /// Resolved workflow parameters for this run.
+    pub params: serde_json::Map<String, Value>,
+    /// Live output stream, when someone is listening.
+    pub output: Option<OutputEvents>,
+    /// Directory tasks run in, when the workflow uses an isolated workspace.
+    pub working_dir: Option<PathBuf>,
+    /// Connections `db_query` tasks can use, by name.
+    pub db_connections: Arc<Vec<DbConnection>>,
+    /// Broker connections `publish_message` tasks can use, by name.
+    pub message_connections: Arc<Vec<MessageConnection>>,
+    /// Where `cache` tasks restore and save directories. Without one they
+    /// always miss.
+    pub directory_cache: Option<DirectoryCache>,
+    /// How long each task that has finished so far took to run.
+    pub task_durations: HashMap<String, Duration>,
 }
 
 impl ExecutionContext {
@@ -138,6 +522,15 @@ impl ExecutionContext {
             output_logs: Vec::new(),
             per_task_logs: HashMap::new(),
             tasks: HashMap::new(),
+            data: Value::Object(serde_json::Map::new()),
+            scratch: Scratchpad::new(),
+            params: serde_json::Map::new(),
+            output: None,
+            working_dir: None,
+            db_connections: Arc::new(Vec::new()),
+            message_connections: Arc::new(Vec::new()),
+            directory_cache: None,
+            task_durations: HashMap::new(),
         }
     }
 
@@ -146,9 +539,51 @@ impl ExecutionContext {
     }
 
     pub fn log_task(&mut self, task_id: String, message: String) {
+        self.emit_task_line(&task_id, &message);
+        self.record_task_log(task_id, message);
+    }
+
+    /// Adds to the task's log without streaming it, for output that was already
+    /// streamed line by line as it was produced.
+    pub fn record_task_log(&mut self, task_id: String, message: String) {
         self.per_task_logs.entry(task_id).or_default().push(message);
     }
 
+    pub fn emit_task_line(&self, task_id: &str, line: &str) {
+        if let Some(output) = &self.output {
+            let task_name = self
+                .tasks
+                .get(task_id)
+                .map(|task| task.name.as_str())
+                .unwrap_or(task_id);
+            output.task_line(task_id, task_name, line);
+        }
+    }
+
+    /// Show how far along a task is in the live output. Nothing is logged.
+    pub fn report_progress(&self, task_id: &str, progress: TaskProgress) {
+        if let Some(output) = &self.output {
+            let task_name = self
+                .tasks
+                .get(task_id)
+                .map(|task| task.name.as_str())
+                .unwrap_or(task_id);
+            output.task_progress(task_id, task_name, progress);
+        }
+    }
+
+    /// Mark the end of a task in the live output. Nothing is logged.
+    pub fn report_finished(&self, task_id: &str, success: bool) {
+        if let Some(output) = &self.output {
+            let task_name = self
+                .tasks
+                .get(task_id)
+                .map(|task| task.name.as_str())
+                .unwrap_or(task_id);
+            output.task_finished(task_id, task_name, success);
+        }
+    }
+
     pub fn update_task_status(&mut self, task_id: String, status: TaskStatus) {
         if let Some(task) = self.tasks.get_mut(&task_id) {
             task.status = status;
@@ -0,0 +1,98 @@
+//! Size and complexity limits checked before a workflow is parsed into tasks.
+//!
+//! A definition with tens of thousands of tasks parses fine but then stalls
+//! everything that walks it, from the engine to the GUI's visual editor, so
+//! oversized definitions are turned away up front.
+
+use crate::errors::ParserError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::{OnceLock, RwLock};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkflowLimits {
+    /// Tasks in the whole definition, counting every `next_tasks` entry.
+    pub max_tasks: usize,
+    /// Length of the longest `next_tasks` chain, root task included.
+    pub max_depth: usize,
+    /// Entries in a single task's `next_tasks`, all of which start together.
+    pub max_fan_out: usize,
+    /// Declared parameters.
+    pub max_parameters: usize,
+    /// Size of the JSON text.
+    pub max_document_bytes: usize,
+}
+
+impl Default for WorkflowLimits {
+    fn default() -> Self {
+        Self {
+            max_tasks: 1000,
+            max_depth: 30,
+            max_fan_out: 100,
+            max_parameters: 100,
+            max_document_bytes: 1024 * 1024,
+        }
+    }
+}
+
+static LIMITS: OnceLock<RwLock<WorkflowLimits>> = OnceLock::new();
+
+fn limits_lock() -> &'static RwLock<WorkflowLimits> {
+    LIMITS.get_or_init(|| RwLock::new(WorkflowLimits::default()))
+}
+
+/// Replace the limits used by `parse_workflow` and friends for the rest of the process.
+pub fn set_workflow_limits(limits: WorkflowLimits) {
+    *limits_lock().write().unwrap_or_else(|e| e.into_inner()) = limits;
+}
+
+pub fn workflow_limits() -> WorkflowLimits {
+    *limits_lock().read().unwrap_or_else(|e| e.into_inner())
+}
+
+pub fn check_document_size(len: usize, limits: &WorkflowLimits) -> Result<(), ParserError> {
+    ensure_within("bytes", len, limits.max_document_bytes)
+}
+
+/// Check a workflow document against `limits` without building any tasks.
+///
+/// Fields with the wrong shape are skipped here; the parser reports those.
+pub fn check_workflow_limits(
+    workflow_json: &Value,
+    limits: &WorkflowLimits,
+) -> Result<(), ParserError> {
+    if let Some(parameters) = workflow_json.get("parameters").and_then(Value::as_array) {
+        ensure_within("parameters", parameters.len(), limits.max_parameters)?;
+    }
+
+    let Some(roots) = workflow_json.get("tasks").and_then(Value::as_array) else {
+        return Ok(());
+    };
+
+    let mut task_count = 0;
+    let mut pending: Vec<(&Value, usize)> = roots.iter().map(|task| (task, 1)).collect();
+    while let Some((task, depth)) = pending.pop() {
+        task_count += 1;
+        ensure_within("tasks", task_count, limits.max_tasks)?;
+        ensure_within("levels of next_tasks", depth, limits.max_depth)?;
+
+        if let Some(next_tasks) = task.get("next_tasks").and_then(Value::as_array) {
+            ensure_within(
+                "next_tasks on one task",
+                next_tasks.len(),
+                limits.max_fan_out,
+            )?;
+            pending.extend(next_tasks.iter().map(|next| (next, depth + 1)));
+        }
+    }
+
+    Ok(())
+}
+
+fn ensure_within(what: &'static str, actual: usize, max: usize) -> Result<(), ParserError> {
+    if actual > max {
+        return Err(ParserError::LimitExceeded { what, max });
+    }
+    Ok(())
+}
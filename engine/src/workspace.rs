@@ -0,0 +1,69 @@
+//! Per-execution working directories for workflows that set `isolated_workspace`.
+
+use crate::errors::EngineError;
+use crate::types::WorkspaceOptions;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+
+/// Parent directory of every workspace, under the system temp directory.
+pub const WORKSPACES_DIR: &str = "s_e_e-workspaces";
+
+/// Where the workspace for `execution_id` lives. The path only depends on the
+/// execution id, so a resumed run finds the files its first half left behind.
+pub fn workspace_path(execution_id: &str) -> PathBuf {
+    std::env::temp_dir().join(WORKSPACES_DIR).join(execution_id)
+}
+
+/// Create the workspace for `execution_id`, seeding it from the template when
+/// it is new. An existing workspace is reused as is.
+pub fn prepare_workspace(
+    execution_id: &str,
+    options: &WorkspaceOptions,
+) -> Result<PathBuf, EngineError> {
+    let path = workspace_path(execution_id);
+    if path.is_dir() {
+        debug!(execution_id = %execution_id, path = %path.display(), "Reusing workspace");
+        return Ok(path);
+    }
+
+    fs::create_dir_all(&path)?;
+    if let Some(template) = &options.template {
+        if let Err(e) = copy_dir(Path::new(template), &path) {
+            let _ = fs::remove_dir_all(&path);
+            return Err(EngineError::Execution(format!(
+                "Failed to copy workspace template '{}': {}",
+                template, e
+            )));
+        }
+    }
+    debug!(execution_id = %execution_id, path = %path.display(), "Created workspace");
+    Ok(path)
+}
+
+/// Remove the workspace after a successful run. A failed run keeps it so its
+/// files can be inspected; the returned path says where.
+pub fn finish_workspace(path: &Path, success: bool) -> Option<PathBuf> {
+    if !success {
+        return Some(path.to_path_buf());
+    }
+    if let Err(e) = fs::remove_dir_all(path) {
+        warn!(path = %path.display(), error = %e, "Failed to remove workspace");
+    }
+    None
+}
+
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            fs::create_dir_all(&target)?;
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use dioxus::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Focus outline for custom interactive elements, visible only for keyboard focus.
+pub const FOCUS_RING: &str = "focus-visible:outline focus-visible:outline-2 focus-visible:outline-offset-2 focus-visible:outline-blue-600 dark:focus-visible:outline-blue-400";
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A DOM id that stays the same for the lifetime of the component, for ARIA references.
+pub fn use_unique_id(prefix: &str) -> String {
+    let prefix = prefix.to_string();
+    use_hook(move || format!("{}-{}", prefix, NEXT_ID.fetch_add(1, Ordering::Relaxed)))
+}
+
+/// Keep Tab and Shift+Tab inside the element with `container_id` while `active`.
+///
+/// Focus moves to the first focusable child when the trap starts and returns to
+/// whatever had it before when the trap ends or the component unmounts.
+pub fn use_focus_trap(container_id: String, active: bool) {
+    let trap_id = container_id.clone();
+    use_effect(use_reactive((&active,), move |(active,)| {
+        document::eval(&focus_trap_script(&trap_id, active));
+    }));
+    use_drop(move || {
+        document::eval(&focus_trap_script(&container_id, false));
+    });
+}
+
+/// Move keyboard focus to the element with `id`, if it exists.
+pub fn focus_element(id: &str) {
+    document::eval(&format!(
+        "document.getElementById({})?.focus();",
+        serde_json::to_string(id).unwrap_or_default()
+    ));
+}
+
+fn focus_trap_script(container_id: &str, active: bool) -> String {
+    FOCUS_TRAP_SCRIPT
+        .replace(
+            "__CONTAINER_ID__",
+            &serde_json::to_string(container_id).unwrap_or_default(),
+        )
+        .replace("__ACTIVE__", if active { "true" } else { "false" })
+}
+
+const FOCUS_TRAP_SCRIPT: &str = r#"
+(() => {
+    const id = __CONTAINER_ID__;
+    const traps = (window.__focusTraps = window.__focusTraps || {});
+    const existing = traps[id];
+    if (!__ACTIVE__) {
+        if (existing) {
+            document.removeEventListener("keydown", existing.handler, true);
+            delete traps[id];
+            if (existing.previous && document.contains(existing.previous)) {
+                existing.previous.focus();
+            }
+        }
+        return;
+    }
+    if (existing) {
+        return;
+    }
+    const selector = 'a[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex="-1"])';
+    const focusable = (container) => Array.from(container.querySelectorAll(selector));
+    const handler = (event) => {
+        const container = document.getElementById(id);
+        if (!container || event.key !== "Tab") {
+            return;
+        }
+        const items = focusable(container);
+        if (items.length === 0) {
+            event.preventDefault();
+            return;
+        }
+        const first = items[0];
+        const last = items[items.length - 1];
+        const current = document.activeElement;
+        if (!container.contains(current)) {
+            event.preventDefault();
+            first.focus();
+        } else if (event.shiftKey && current === first) {
+            event.preventDefault();
+            last.focus();
+        } else if (!event.shiftKey && current === last) {
+            event.preventDefault();
+            first.focus();
+        }
+    };
+    traps[id] = { handler, previous: document.activeElement };
+    document.addEventListener("keydown", handler, true);
+    requestAnimationFrame(() => {
+        const container = document.getElementById(id);
+        const target = container && (focusable(container)[0] || container);
+        if (target) {
+            target.focus();
+        }
+    });
+})();
+"#;
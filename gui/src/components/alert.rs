@@ -42,14 +42,17 @@ pub fn Alert(props: AlertProps) -> Element {
 
     rsx! {
         div {
+            role: "alert",
             class: format!("rounded-md {} border {} p-4 mb-6", bg_color, border_color),
             div { class: "flex items-center justify-between",
                 div { class: "flex items-center gap-3",
-                    Icon {
-                        name: icon_name.to_string(),
-                        class: Some(format!("w-5 h-5 {} flex-shrink-0", icon_color)),
-                        size: None,
-                        variant: Some("outline".to_string()),
+                    span { "aria-hidden": "true",
+                        Icon {
+                            name: icon_name.to_string(),
+                            class: Some(format!("w-5 h-5 {} flex-shrink-0", icon_color)),
+                            size: None,
+                            variant: Some("outline".to_string()),
+                        }
                     }
                     div {
                         if let Some(ref alert_title) = title {
@@ -60,9 +63,9 @@ pub fn Alert(props: AlertProps) -> Element {
                         }
                         p {
                             class: if title.is_some() {
-                                format!("text-sm {} mt-1", text_color)
+                                format!("text-sm whitespace-pre-line {} mt-1", text_color)
                             } else {
-                                format!("text-sm {}", text_color)
+                                format!("text-sm whitespace-pre-line {}", text_color)
                             },
                             "{message}"
                         }
@@ -75,6 +78,7 @@ pub fn Alert(props: AlertProps) -> Element {
                     if dismissible.unwrap_or(false) {
                         button {
                             r#type: "button",
+                            "aria-label": "Dismiss",
                             class: format!("inline-flex rounded-md cursor-pointer {} hover:opacity-75 focus:outline focus:outline-2 focus:outline-offset-2", text_color),
                             onclick: move |_| {
                                 if let Some(handler) = on_dismiss {
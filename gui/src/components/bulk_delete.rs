@@ -0,0 +1,185 @@
+use super::{
+    ConfirmDialog, IconButton, IconButtonSize, IconButtonVariant, Notification, NotificationData,
+    NotificationType,
+};
+use crate::services::TrashService;
+use dioxus::prelude::*;
+use s_e_e_core::{DeletionBatch, TrashKind, UNDO_GRACE_PERIOD};
+use s_e_e_dioxus_query::prelude::invalidate_queries_by_prefix;
+use std::collections::BTreeSet;
+
+fn noun(kind: TrashKind, count: usize) -> &'static str {
+    match (kind, count) {
+        (TrashKind::Workflow, 1) => "workflow",
+        (TrashKind::Workflow, _) => "workflows",
+        (TrashKind::Prompt, 1) => "prompt",
+        (TrashKind::Prompt, _) => "prompts",
+    }
+}
+
+fn query_prefix(kind: TrashKind) -> &'static str {
+    match kind {
+        TrashKind::Workflow => "workflows:",
+        TrashKind::Prompt => "prompts:",
+    }
+}
+
+/// Actions for the items checked on a list page: delete them after asking,
+/// then offer to undo for as long as the trash keeps them. Setting
+/// `confirming` asks to delete the selection, e.g. from a context menu.
+#[component]
+pub fn BulkDeleteBar(
+    kind: TrashKind,
+    selected: Signal<BTreeSet<String>>,
+    /// Every item on the page, for "Select all".
+    all_ids: Vec<String>,
+    confirming: Signal<bool>,
+) -> Element {
+    let mut deleting = use_signal(|| false);
+    let mut last_batch = use_signal(|| None::<DeletionBatch>);
+    let mut toast = use_signal(|| NotificationData {
+        r#type: NotificationType::Success,
+        title: String::new(),
+        message: String::new(),
+        show: false,
+    });
+
+    let count = selected.read().len();
+    let grace_seconds = UNDO_GRACE_PERIOD.as_secs();
+
+    let delete = move |_| {
+        confirming.set(false);
+        deleting.set(true);
+        let ids: Vec<String> = selected.peek().iter().cloned().collect();
+        spawn(async move {
+            let result = TrashService::delete(kind, ids).await;
+            deleting.set(false);
+            let batch = match result {
+                Ok(batch) => batch,
+                Err(e) => {
+                    toast.set(NotificationData {
+                        r#type: NotificationType::Error,
+                        title: "Delete failed".to_string(),
+                        message: e.to_string(),
+                        show: true,
+                    });
+                    return;
+                }
+            };
+            selected.write().clear();
+            invalidate_queries_by_prefix(query_prefix(kind));
+            toast.set(NotificationData {
+                r#type: NotificationType::Success,
+                title: format!("Deleted {} {}", batch.count, noun(kind, batch.count)),
+                message: format!("You can undo this for {} seconds.", grace_seconds),
+                show: true,
+            });
+            let batch_id = batch.id.clone();
+            last_batch.set(Some(batch));
+
+            tokio::time::sleep(UNDO_GRACE_PERIOD).await;
+            if last_batch.peek().as_ref().map(|b| b.id.as_str()) == Some(batch_id.as_str()) {
+                last_batch.set(None);
+                toast.write().show = false;
+            }
+        });
+    };
+
+    let undo = move |_| {
+        let Some(batch) = last_batch.take() else {
+            return;
+        };
+        spawn(async move {
+            match TrashService::undo(&batch.id).await {
+                Ok(restored) => {
+                    invalidate_queries_by_prefix(query_prefix(kind));
+                    if restored.len() == batch.count {
+                        toast.write().show = false;
+                    } else {
+                        toast.set(NotificationData {
+                            r#type: NotificationType::Error,
+                            title: format!(
+                                "Restored {} of {} {}",
+                                restored.len(),
+                                batch.count,
+                                noun(kind, batch.count)
+                            ),
+                            message:
+                                "The rest were past the undo period or their ids are in use again."
+                                    .to_string(),
+                            show: true,
+                        });
+                    }
+                }
+                Err(e) => toast.set(NotificationData {
+                    r#type: NotificationType::Error,
+                    title: "Undo failed".to_string(),
+                    message: e.to_string(),
+                    show: true,
+                }),
+            }
+        });
+    };
+
+    rsx! {
+        if count > 0 {
+            div { class: "flex items-center justify-between gap-3 rounded-lg border border-zinc-200 bg-zinc-50 px-4 py-2 dark:border-zinc-700 dark:bg-zinc-800",
+                span { class: "text-sm font-medium text-zinc-700 dark:text-zinc-300",
+                    "{count} selected"
+                }
+                div { class: "flex items-center gap-2",
+                    if count < all_ids.len() {
+                        IconButton {
+                            variant: IconButtonVariant::Ghost,
+                            size: IconButtonSize::Small,
+                            onclick: move |_| {
+                                selected.set(all_ids.iter().cloned().collect());
+                            },
+                            "Select all"
+                        }
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Ghost,
+                        size: IconButtonSize::Small,
+                        onclick: move |_| selected.write().clear(),
+                        "Clear"
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Danger,
+                        size: IconButtonSize::Small,
+                        disabled: Some(deleting()),
+                        loading: Some(deleting()),
+                        onclick: move |_| confirming.set(true),
+                        icon: Some("trash".to_string()),
+                        icon_variant: "outline".to_string(),
+                        "Delete"
+                    }
+                }
+            }
+        }
+
+        ConfirmDialog {
+            show: confirming(),
+            title: format!("Delete {} {}?", count, noun(kind, count)),
+            message: format!(
+                "You can undo this for {} seconds; after that they are gone for good.",
+                grace_seconds
+            ),
+            confirm_text: "Delete".to_string(),
+            cancel_text: "Cancel".to_string(),
+            on_confirm: delete,
+            on_cancel: move |_| confirming.set(false),
+            details: selected.read().iter().cloned().collect(),
+        }
+
+        Notification {
+            notification: toast,
+            on_close: move |_| {
+                last_batch.set(None);
+                toast.write().show = false;
+            },
+            action: last_batch.read().is_some().then(|| "Undo".to_string()),
+            on_action: undo,
+        }
+    }
+}
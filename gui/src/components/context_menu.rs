@@ -0,0 +1,201 @@
+use super::a11y::{focus_element, use_focus_trap, use_unique_id, FOCUS_RING};
+use crate::icons::Icon;
+use dioxus::prelude::*;
+use dioxus_desktop::use_window;
+
+/// Width of the menu, matching its `w-56` class.
+const MENU_WIDTH: f64 = 224.0;
+/// Height of one item, matching its padding and line height.
+const ITEM_HEIGHT: f64 = 36.0;
+/// Height of the heading shown above the items of a bulk menu.
+const TITLE_HEIGHT: f64 = 32.0;
+/// The menu's vertical padding.
+const MENU_PADDING: f64 = 8.0;
+/// Space kept between the menu and the edges of the window.
+const EDGE_MARGIN: f64 = 8.0;
+
+/// Where a context menu was asked for, in window coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MenuAnchor {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// One action in a [`ContextMenu`].
+#[derive(Clone, PartialEq)]
+pub struct ContextMenuItem {
+    pub label: String,
+    pub icon: Option<String>,
+    /// Shown in red, for actions such as delete.
+    pub danger: bool,
+    pub disabled: bool,
+    pub on_select: Callback<()>,
+}
+
+impl ContextMenuItem {
+    pub fn new(label: impl Into<String>, on_select: impl FnMut(()) + 'static) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            danger: false,
+            disabled: false,
+            on_select: Callback::new(on_select),
+        }
+    }
+
+    pub fn icon(mut self, name: &str) -> Self {
+        self.icon = Some(name.to_string());
+        self
+    }
+
+    pub fn danger(mut self) -> Self {
+        self.danger = true;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// `anchor`, moved as little as needed for a `width` by `height` menu to fit
+/// inside a `window_width` by `window_height` window.
+pub fn clamp_menu_position(
+    anchor: MenuAnchor,
+    width: f64,
+    height: f64,
+    window_width: f64,
+    window_height: f64,
+) -> MenuAnchor {
+    let clamp = |position: f64, size: f64, bound: f64| {
+        position.min(bound - size - EDGE_MARGIN).max(EDGE_MARGIN)
+    };
+    MenuAnchor {
+        x: clamp(anchor.x, width, window_width),
+        y: clamp(anchor.y, height, window_height),
+    }
+}
+
+/// Index of the enabled item `step` places from `from`, wrapping around.
+fn next_enabled(items: &[ContextMenuItem], from: usize, step: isize) -> Option<usize> {
+    let len = items.len() as isize;
+    (1..=len)
+        .map(|offset| (from as isize + step * offset).rem_euclid(len) as usize)
+        .find(|&index| !items[index].disabled)
+}
+
+/// A menu of actions opened at `anchor`, e.g. by right-clicking a list row.
+///
+/// Focus moves into the menu when it opens and back when it closes. Arrow
+/// keys, Home and End move between items, Enter or Space picks one, and
+/// Escape, a click outside or another right-click closes it.
+#[component]
+pub fn ContextMenu(
+    anchor: Signal<Option<MenuAnchor>>,
+    items: Vec<ContextMenuItem>,
+    /// Heading above the items, such as "3 workflows selected".
+    #[props(default)]
+    title: Option<String>,
+) -> Element {
+    let menu_id = use_unique_id("context-menu");
+    let window = use_window();
+    let mut active = use_signal(|| 0usize);
+    let is_open = anchor().is_some();
+    use_focus_trap(menu_id.clone(), is_open);
+
+    let Some(requested) = anchor() else {
+        return rsx! {};
+    };
+
+    let height = items.len() as f64 * ITEM_HEIGHT
+        + if title.is_some() { TITLE_HEIGHT } else { 0.0 }
+        + MENU_PADDING;
+    let size = window.inner_size().to_logical::<f64>(window.scale_factor());
+    let position = clamp_menu_position(requested, MENU_WIDTH, height, size.width, size.height);
+    let item_id = {
+        let menu_id = menu_id.clone();
+        move |index: usize| format!("{}-item-{}", menu_id, index)
+    };
+    let key_items = items.clone();
+    let key_item_id = item_id.clone();
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-40",
+            "aria-hidden": "true",
+            onclick: move |_| anchor.set(None),
+            oncontextmenu: move |evt| {
+                evt.prevent_default();
+                anchor.set(None);
+            },
+        }
+        div {
+            id: "{menu_id}",
+            role: "menu",
+            "aria-label": title.clone().unwrap_or_else(|| "Actions".to_string()),
+            tabindex: "-1",
+            class: "fixed z-50 w-56 rounded-lg bg-white py-1 shadow-lg ring-1 ring-black/5 focus:outline-none dark:bg-zinc-800 dark:ring-white/10",
+            style: "left: {position.x}px; top: {position.y}px;",
+            oncontextmenu: move |evt| evt.prevent_default(),
+            onkeydown: move |evt: KeyboardEvent| {
+                let target = match evt.key() {
+                    Key::Escape | Key::Tab => {
+                        evt.prevent_default();
+                        anchor.set(None);
+                        return;
+                    }
+                    Key::ArrowDown => next_enabled(&key_items, active(), 1),
+                    Key::ArrowUp => next_enabled(&key_items, active(), -1),
+                    Key::Home => next_enabled(&key_items, key_items.len().saturating_sub(1), 1),
+                    Key::End => next_enabled(&key_items, 0, -1),
+                    _ => return,
+                };
+                evt.prevent_default();
+                if let Some(index) = target {
+                    active.set(index);
+                    focus_element(&key_item_id(index));
+                }
+            },
+            if let Some(title) = title {
+                div { class: "px-3 py-2 text-xs font-semibold text-zinc-500 dark:text-zinc-400",
+                    "{title}"
+                }
+            }
+            for (index, item) in items.into_iter().enumerate() {
+                button {
+                    key: "{index}",
+                    id: item_id(index),
+                    r#type: "button",
+                    role: "menuitem",
+                    disabled: item.disabled,
+                    class: format!(
+                        "flex w-full items-center gap-2 px-3 py-2 text-left text-sm disabled:cursor-not-allowed disabled:opacity-50 {} {}",
+                        if item.danger {
+                            "text-red-600 hover:bg-red-50 focus:bg-red-50 dark:text-red-400 dark:hover:bg-red-900/20 dark:focus:bg-red-900/20"
+                        } else {
+                            "text-zinc-700 hover:bg-zinc-100 focus:bg-zinc-100 dark:text-zinc-200 dark:hover:bg-zinc-700 dark:focus:bg-zinc-700"
+                        },
+                        FOCUS_RING
+                    ),
+                    onfocus: move |_| active.set(index),
+                    onclick: move |_| {
+                        anchor.set(None);
+                        item.on_select.call(());
+                    },
+                    if let Some(icon) = item.icon.clone() {
+                        span { "aria-hidden": "true",
+                            Icon {
+                                name: icon,
+                                class: Some("size-4".to_string()),
+                                size: None,
+                                variant: Some("outline".to_string()),
+                            }
+                        }
+                    }
+                    "{item.label}"
+                }
+            }
+        }
+    }
+}
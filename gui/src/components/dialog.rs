@@ -1,3 +1,4 @@
+use super::a11y::{use_focus_trap, use_unique_id};
 use super::{IconButton, IconButtonSize, IconButtonVariant};
 use dioxus::prelude::*;
 
@@ -10,7 +11,13 @@ pub fn ConfirmDialog(
     cancel_text: String,
     on_confirm: EventHandler<()>,
     on_cancel: EventHandler<()>,
+    /// Extra lines listed under the message, such as the commands a run would execute.
+    #[props(default)]
+    details: Vec<String>,
 ) -> Element {
+    let dialog_id = use_unique_id("confirm-dialog");
+    use_focus_trap(dialog_id.clone(), show);
+
     if !show {
         return rsx! { div {} };
     }
@@ -18,14 +25,33 @@ pub fn ConfirmDialog(
     rsx! {
         div {
             class: "fixed inset-0 z-50 flex items-center justify-center",
+            onkeydown: move |evt: KeyboardEvent| {
+                if evt.key() == Key::Escape {
+                    on_cancel.call(());
+                }
+            },
             div {
                 class: "absolute inset-0 bg-black/50",
+                "aria-hidden": "true",
                 onclick: move |_| on_cancel.call(())
             }
             div {
-                class: "relative bg-white dark:bg-zinc-800 rounded-xl shadow-xl p-6 max-w-md w-full mx-4 z-10",
-                h3 { class: "text-lg font-semibold text-zinc-900 dark:text-white mb-2", "{title}" }
-                p { class: "text-zinc-600 dark:text-zinc-400 mb-6", "{message}" }
+                id: "{dialog_id}",
+                role: "alertdialog",
+                "aria-modal": "true",
+                "aria-labelledby": "{dialog_id}-title",
+                "aria-describedby": "{dialog_id}-message",
+                tabindex: "-1",
+                class: "relative bg-white dark:bg-zinc-800 rounded-xl shadow-xl p-6 max-w-md w-full mx-4 z-10 focus:outline-none",
+                h3 { id: "{dialog_id}-title", class: "text-lg font-semibold text-zinc-900 dark:text-white mb-2", "{title}" }
+                p { id: "{dialog_id}-message", class: "text-zinc-600 dark:text-zinc-400 mb-6", "{message}" }
+                if !details.is_empty() {
+                    ul { class: "mb-6 max-h-48 overflow-auto space-y-1 rounded-lg bg-zinc-50 dark:bg-zinc-900 p-3",
+                        for line in details.iter() {
+                            li { class: "font-mono text-xs text-zinc-700 dark:text-zinc-300 break-all", "{line}" }
+                        }
+                    }
+                }
                 div { class: "flex gap-3 justify-end",
                     IconButton {
                         variant: IconButtonVariant::Secondary,
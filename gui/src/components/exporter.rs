@@ -0,0 +1,78 @@
+use super::{Notification, NotificationData, NotificationType};
+use crate::services::{ExportKind, ExportService};
+use dioxus::prelude::*;
+
+/// Exports workflows, prompts or executions to files the user picks and
+/// reports how it went; render [`ExportNotification`] with the exporter.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Exporter {
+    toast: Signal<NotificationData>,
+}
+
+pub fn use_exporter() -> Exporter {
+    Exporter {
+        toast: use_signal(|| NotificationData {
+            r#type: NotificationType::Success,
+            title: String::new(),
+            message: String::new(),
+            show: false,
+        }),
+    }
+}
+
+impl Exporter {
+    pub fn export(self, kind: ExportKind, ids: Vec<String>) {
+        let mut toast = self.toast;
+        spawn(async move {
+            let mut files = Vec::with_capacity(ids.len());
+            for id in &ids {
+                match ExportService::fetch(kind, id).await {
+                    Ok(file) => files.push(file),
+                    Err(e) => {
+                        toast.set(NotificationData {
+                            r#type: NotificationType::Error,
+                            title: "Export failed".to_string(),
+                            message: e.to_string(),
+                            show: true,
+                        });
+                        return;
+                    }
+                }
+            }
+
+            match ExportService::save(files) {
+                Ok(Some(paths)) => toast.set(NotificationData {
+                    r#type: NotificationType::Success,
+                    title: format!("Exported {} {}", paths.len(), kind.noun(paths.len())),
+                    message: match paths.as_slice() {
+                        [path] => path.display().to_string(),
+                        _ => paths
+                            .first()
+                            .and_then(|path| path.parent())
+                            .map(|folder| folder.display().to_string())
+                            .unwrap_or_default(),
+                    },
+                    show: true,
+                }),
+                Ok(None) => {}
+                Err(e) => toast.set(NotificationData {
+                    r#type: NotificationType::Error,
+                    title: "Export failed".to_string(),
+                    message: e.to_string(),
+                    show: true,
+                }),
+            }
+        });
+    }
+}
+
+#[component]
+pub fn ExportNotification(exporter: Exporter) -> Element {
+    let mut toast = exporter.toast;
+    rsx! {
+        Notification {
+            notification: toast,
+            on_close: move |_| toast.write().show = false,
+        }
+    }
+}
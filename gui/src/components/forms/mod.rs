@@ -1,8 +1,10 @@
+pub mod parameter_form;
 pub mod text_input;
 pub mod textarea_input;
 pub mod user_input_form;
 pub mod validation_message;
 
+pub use parameter_form::ParameterFormDialog;
 pub use text_input::TextInput;
 pub use textarea_input::TextareaInput;
 pub use validation_message::ValidationMessage;
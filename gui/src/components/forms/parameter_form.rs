@@ -0,0 +1,283 @@
+use crate::components::a11y::{use_focus_trap, use_unique_id};
+use crate::components::{IconButton, IconButtonSize, IconButtonVariant};
+use dioxus::prelude::*;
+use rfd::FileDialog;
+use s_e_e_core::{parameter_input_text, parse_parameter_form, ParameterField, WorkflowParameter};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+const INPUT_CLASS: &str = "block w-full rounded-md border-0 py-1.5 px-3 text-zinc-900 dark:text-white shadow-sm ring-1 ring-inset ring-zinc-300 dark:ring-zinc-600 focus:ring-2 focus:ring-inset focus:ring-blue-600 dark:bg-zinc-700 sm:text-sm sm:leading-6";
+
+/// Asks for a workflow's parameters before it runs, with a field suited to
+/// each parameter's type and the defaults filled in.
+#[component]
+pub fn ParameterFormDialog(
+    workflow_name: String,
+    parameters: Vec<WorkflowParameter>,
+    on_submit: EventHandler<HashMap<String, Value>>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let dialog_id = use_unique_id("parameter-dialog");
+    use_focus_trap(dialog_id.clone(), true);
+
+    let mut inputs = use_signal({
+        let parameters = parameters.clone();
+        move || {
+            parameters
+                .iter()
+                .map(|parameter| {
+                    let text = match (&parameter.default, parameter.param_type) {
+                        // A blank secret field uses the default without showing it.
+                        (Some(_), _) if parameter.secret => String::new(),
+                        (Some(default), _) => parameter_input_text(default),
+                        (None, s_e_e_core::ParameterType::Boolean) => "false".to_string(),
+                        (None, _) => String::new(),
+                    };
+                    (parameter.name.clone(), text)
+                })
+                .collect::<HashMap<String, String>>()
+        }
+    });
+    let mut errors = use_signal(BTreeMap::<String, String>::new);
+    let definitions = use_signal(|| parameters.clone());
+
+    let submit = move |_| match parse_parameter_form(&definitions.read(), &inputs.read()) {
+        Ok(values) => on_submit.call(values),
+        Err(field_errors) => errors.set(field_errors),
+    };
+
+    // Once a submit has failed, keep the messages in step with each edit.
+    let mut set_input = move |name: String, value: String| {
+        inputs.write().insert(name, value);
+        if !errors.peek().is_empty() {
+            let field_errors = parse_parameter_form(&definitions.read(), &inputs.read())
+                .err()
+                .unwrap_or_default();
+            errors.set(field_errors);
+        }
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-50 flex items-center justify-center",
+            onkeydown: move |evt: KeyboardEvent| {
+                if evt.key() == Key::Escape {
+                    on_cancel.call(());
+                }
+            },
+            div {
+                class: "absolute inset-0 bg-black/50",
+                "aria-hidden": "true",
+                onclick: move |_| on_cancel.call(())
+            }
+            div {
+                id: "{dialog_id}",
+                role: "dialog",
+                "aria-modal": "true",
+                "aria-labelledby": "{dialog_id}-title",
+                tabindex: "-1",
+                class: "relative bg-white dark:bg-zinc-800 rounded-xl shadow-xl p-6 max-w-lg w-full mx-4 z-10 focus:outline-none",
+                h3 { id: "{dialog_id}-title", class: "text-lg font-semibold text-zinc-900 dark:text-white mb-4",
+                    "Run {workflow_name}"
+                }
+                div { class: "space-y-4 max-h-[60vh] overflow-auto mb-6",
+                    for parameter in parameters.iter().cloned() {
+                        {
+                            let name = parameter.name.clone();
+                            let field_id = format!("{}-{}", dialog_id, name);
+                            let value = inputs.read().get(&name).cloned().unwrap_or_default();
+                            let error = errors.read().get(&name).cloned();
+                            let required = parameter.required && parameter.default.is_none();
+                            rsx! {
+                                div { key: "{name}",
+                                    label {
+                                        r#for: "{field_id}",
+                                        class: "block text-sm font-medium text-zinc-900 dark:text-white mb-1",
+                                        "{name}"
+                                        if required {
+                                            span { class: "text-red-600 dark:text-red-400 ml-1", "*" }
+                                        }
+                                    }
+                                    ParameterInput {
+                                        id: field_id.clone(),
+                                        parameter: parameter.clone(),
+                                        value,
+                                        on_change: move |value: String| set_input(name.clone(), value),
+                                    }
+                                    if let Some(description) = &parameter.description {
+                                        p { class: "mt-1 text-xs text-zinc-500 dark:text-zinc-400", "{description}" }
+                                    }
+                                    if let Some(error) = error {
+                                        p { class: "mt-1 text-sm text-red-600 dark:text-red-400", "{error}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                div { class: "flex gap-3 justify-end",
+                    IconButton {
+                        variant: IconButtonVariant::Secondary,
+                        size: IconButtonSize::Medium,
+                        onclick: move |_| on_cancel.call(()),
+                        icon: Some("x".to_string()),
+                        icon_variant: "outline".to_string(),
+                        "Cancel"
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Primary,
+                        size: IconButtonSize::Medium,
+                        onclick: submit,
+                        icon: Some("play".to_string()),
+                        icon_variant: "outline".to_string(),
+                        "Run"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ParameterInput(
+    id: String,
+    parameter: WorkflowParameter,
+    value: String,
+    on_change: EventHandler<String>,
+) -> Element {
+    match ParameterField::for_parameter(&parameter) {
+        ParameterField::Text => rsx! {
+            input {
+                id: "{id}",
+                r#type: "text",
+                class: INPUT_CLASS,
+                value: "{value}",
+                oninput: move |evt| on_change.call(evt.value()),
+            }
+        },
+        ParameterField::Number => rsx! {
+            input {
+                id: "{id}",
+                r#type: "number",
+                step: "any",
+                class: INPUT_CLASS,
+                value: "{value}",
+                oninput: move |evt| on_change.call(evt.value()),
+            }
+        },
+        ParameterField::Checkbox => rsx! {
+            input {
+                id: "{id}",
+                r#type: "checkbox",
+                class: "h-4 w-4 rounded border-zinc-300 text-blue-600 focus:ring-blue-600",
+                checked: value == "true",
+                onchange: move |evt| on_change.call(evt.checked().to_string()),
+            }
+        },
+        ParameterField::Secret => rsx! {
+            input {
+                id: "{id}",
+                r#type: "password",
+                autocomplete: "off",
+                class: INPUT_CLASS,
+                placeholder: if parameter.default.is_some() { "Leave blank for the default" } else { "" },
+                value: "{value}",
+                oninput: move |evt| on_change.call(evt.value()),
+            }
+        },
+        ParameterField::Select => {
+            let blank_allowed = !parameter.required || parameter.default.is_some();
+            rsx! {
+                select {
+                    id: "{id}",
+                    class: INPUT_CLASS,
+                    onchange: move |evt| on_change.call(evt.value()),
+                    if blank_allowed || value.is_empty() {
+                        option { value: "", selected: value.is_empty(), "" }
+                    }
+                    for option in parameter.options.iter().map(parameter_input_text) {
+                        option {
+                            key: "{option}",
+                            value: "{option}",
+                            selected: option == value,
+                            "{option}"
+                        }
+                    }
+                }
+            }
+        }
+        ParameterField::File => rsx! {
+            div { class: "flex gap-2",
+                input {
+                    id: "{id}",
+                    r#type: "text",
+                    class: INPUT_CLASS,
+                    placeholder: "/path/to/file",
+                    value: "{value}",
+                    oninput: move |evt| on_change.call(evt.value()),
+                }
+                IconButton {
+                    variant: IconButtonVariant::Secondary,
+                    size: IconButtonSize::Small,
+                    onclick: move |_| {
+                        if let Some(path) = FileDialog::new().pick_file() {
+                            on_change.call(path.display().to_string());
+                        }
+                    },
+                    icon: Some("upload".to_string()),
+                    icon_variant: "outline".to_string(),
+                    "Browse"
+                }
+            }
+        },
+        ParameterField::Directory => rsx! {
+            div { class: "flex gap-2",
+                input {
+                    id: "{id}",
+                    r#type: "text",
+                    class: INPUT_CLASS,
+                    placeholder: "/path/to/directory",
+                    value: "{value}",
+                    oninput: move |evt| on_change.call(evt.value()),
+                }
+                IconButton {
+                    variant: IconButtonVariant::Secondary,
+                    size: IconButtonSize::Small,
+                    onclick: move |_| {
+                        if let Some(path) = FileDialog::new().pick_folder() {
+                            on_change.call(path.display().to_string());
+                        }
+                    },
+                    icon: Some("upload".to_string()),
+                    icon_variant: "outline".to_string(),
+                    "Browse"
+                }
+            }
+        },
+        ParameterField::Glob => rsx! {
+            div { class: "flex gap-2",
+                input {
+                    id: "{id}",
+                    r#type: "text",
+                    class: INPUT_CLASS,
+                    placeholder: "/path/to/files/*.csv",
+                    value: "{value}",
+                    oninput: move |evt| on_change.call(evt.value()),
+                }
+                IconButton {
+                    variant: IconButtonVariant::Secondary,
+                    size: IconButtonSize::Small,
+                    onclick: move |_| {
+                        // Start the pattern from the picked directory.
+                        if let Some(path) = FileDialog::new().pick_folder() {
+                            on_change.call(path.join("*").display().to_string());
+                        }
+                    },
+                    icon: Some("upload".to_string()),
+                    icon_variant: "outline".to_string(),
+                    "Browse"
+                }
+            }
+        },
+    }
+}
@@ -27,6 +27,7 @@ pub fn IconButton(
     #[props(default)] icon: Option<String>,
     #[props(default = "outline".to_string())] icon_variant: String,
     #[props(default = "left".to_string())] icon_position: String,
+    #[props(default)] aria_label: Option<String>,
     children: Element,
 ) -> Element {
     let disabled = disabled.unwrap_or(false);
@@ -36,10 +37,10 @@ pub fn IconButton(
     let base_classes = "inline-flex items-center justify-center font-medium transition-colors focus-visible:outline focus-visible:outline-2 focus-visible:outline-offset-2 disabled:opacity-50 disabled:cursor-not-allowed";
 
     let variant_classes = match variant {
-        IconButtonVariant::Primary => "bg-blue-600 text-white hover:bg-blue-500 focus-visible:outline-blue-600",
-        IconButtonVariant::Secondary => "bg-zinc-100 text-zinc-900 hover:bg-zinc-200 dark:bg-zinc-800 dark:text-zinc-100 dark:hover:bg-zinc-700 focus-visible:outline-zinc-600",
-        IconButtonVariant::Danger => "bg-red-600 text-white hover:bg-red-500 focus-visible:outline-red-600",
-        IconButtonVariant::Ghost => "text-zinc-600 hover:text-zinc-900 hover:bg-zinc-100 dark:text-zinc-400 dark:hover:text-white dark:hover:bg-zinc-800 focus-visible:outline-zinc-600",
+        IconButtonVariant::Primary => "bg-blue-600 text-white hover:bg-blue-500 focus-visible:outline-blue-600 dark:focus-visible:outline-blue-400",
+        IconButtonVariant::Secondary => "bg-zinc-100 text-zinc-900 hover:bg-zinc-200 dark:bg-zinc-800 dark:text-zinc-100 dark:hover:bg-zinc-700 focus-visible:outline-zinc-600 dark:focus-visible:outline-zinc-300",
+        IconButtonVariant::Danger => "bg-red-600 text-white hover:bg-red-500 focus-visible:outline-red-600 dark:focus-visible:outline-red-400",
+        IconButtonVariant::Ghost => "text-zinc-600 hover:text-zinc-900 hover:bg-zinc-100 dark:text-zinc-400 dark:hover:text-white dark:hover:bg-zinc-800 focus-visible:outline-zinc-600 dark:focus-visible:outline-zinc-300",
     };
 
     let size_classes = match size {
@@ -60,6 +61,8 @@ pub fn IconButton(
         button {
             class: combined_classes,
             disabled: is_disabled,
+            "aria-label": aria_label,
+            "aria-busy": if loading { "true" } else { "false" },
             onclick: move |_| {
                 if !is_disabled {
                     if let Some(handler) = onclick {
@@ -1,5 +1,11 @@
+use crate::components::a11y::FOCUS_RING;
+use crate::components::context_menu::MenuAnchor;
 use crate::icons::Icon;
 use dioxus::prelude::*;
+use std::rc::Rc;
+
+/// How far into the row a menu opened from the keyboard appears.
+const KEYBOARD_MENU_INSET: f64 = 24.0;
 
 #[component]
 pub fn List(children: Element) -> Element {
@@ -26,6 +32,14 @@ pub struct ListItemProps {
     pub show_chevron: bool,
     #[props(default)]
     pub onclick: Option<EventHandler<()>>,
+    /// Shows a checkbox in this state, for picking items to act on together.
+    #[props(default)]
+    pub selected: Option<bool>,
+    #[props(default)]
+    pub on_select: Option<EventHandler<()>>,
+    /// Right-click, Shift+F10 or the Menu key on the row, with where to open the menu.
+    #[props(default)]
+    pub on_context_menu: Option<EventHandler<MenuAnchor>>,
 }
 
 #[component]
@@ -38,24 +52,87 @@ pub fn ListItem(props: ListItemProps) -> Element {
         right_content,
         show_chevron,
         onclick,
+        selected,
+        on_select,
+        on_context_menu,
     } = props;
+    let mut element = use_signal(|| None::<Rc<MountedData>>);
 
-    let list_item_classes = if onclick.is_some() {
-        "relative flex justify-between gap-x-6 px-4 py-5 hover:bg-gray-50 sm:px-6 dark:hover:bg-white/[0.025] cursor-pointer"
+    let is_clickable = onclick.is_some();
+    let is_focusable = is_clickable || on_context_menu.is_some();
+    let list_item_classes = if is_clickable {
+        format!("relative flex justify-between gap-x-6 px-4 py-5 hover:bg-gray-50 sm:px-6 dark:hover:bg-white/[0.025] cursor-pointer {}", FOCUS_RING)
     } else {
-        "relative flex justify-between gap-x-6 px-4 py-5 hover:bg-gray-50 sm:px-6 dark:hover:bg-white/[0.025]"
+        "relative flex justify-between gap-x-6 px-4 py-5 hover:bg-gray-50 sm:px-6 dark:hover:bg-white/[0.025]".to_string()
     };
 
     rsx! {
         li {
             class: list_item_classes,
+            tabindex: if is_focusable { Some("0") } else { None },
+            "aria-haspopup": if on_context_menu.is_some() { Some("menu") } else { None },
+            onmounted: move |evt| element.set(Some(evt.data())),
+            oncontextmenu: move |evt| {
+                if let Some(handler) = on_context_menu {
+                    evt.prevent_default();
+                    let point = evt.client_coordinates();
+                    handler.call(MenuAnchor { x: point.x, y: point.y });
+                }
+            },
             onclick: move |_| {
                 if let Some(handler) = onclick {
                     handler.call(());
                 }
             },
+            onkeydown: move |evt: KeyboardEvent| {
+                let opens_menu = evt.key() == Key::ContextMenu
+                    || (evt.key() == Key::F10 && evt.modifiers().contains(Modifiers::SHIFT));
+                if opens_menu {
+                    if let Some(handler) = on_context_menu {
+                        evt.prevent_default();
+                        let element = element.peek().clone();
+                        spawn(async move {
+                            let mut anchor = MenuAnchor::default();
+                            if let Some(element) = element {
+                                if let Ok(rect) = element.get_client_rect().await {
+                                    anchor = MenuAnchor {
+                                        x: rect.origin.x + KEYBOARD_MENU_INSET,
+                                        y: rect.origin.y + rect.size.height / 2.0,
+                                    };
+                                }
+                            }
+                            handler.call(anchor);
+                        });
+                    }
+                    return;
+                }
+                let activates = matches!(evt.key(), Key::Enter)
+                    || evt.key() == Key::Character(" ".to_string());
+                if activates {
+                    if let Some(handler) = onclick {
+                        evt.prevent_default();
+                        handler.call(());
+                    }
+                }
+            },
             div { class: "flex min-w-0 gap-x-4",
-                div { class: "size-12 flex-none rounded-full bg-gray-50 dark:bg-gray-800 dark:outline dark:outline-1 dark:-outline-offset-1 dark:outline-white/10 flex items-center justify-center",
+                if let Some(selected) = selected {
+                    input {
+                        r#type: "checkbox",
+                        class: "size-4 self-center cursor-pointer rounded border-gray-300 text-blue-600 dark:border-white/20 dark:bg-gray-800",
+                        "aria-label": "Select",
+                        checked: selected,
+                        onclick: move |evt| {
+                            evt.stop_propagation();
+                            if let Some(handler) = on_select {
+                                handler.call(());
+                            }
+                        },
+                        // Space toggles the box; it must not also open the item.
+                        onkeydown: move |evt| evt.stop_propagation(),
+                    }
+                }
+                div { "aria-hidden": "true", class: "size-12 flex-none rounded-full bg-gray-50 dark:bg-gray-800 dark:outline dark:outline-1 dark:-outline-offset-1 dark:outline-white/10 flex items-center justify-center",
                     Icon {
                         name: icon_name,
                         class: Some("size-6 text-gray-400 dark:text-gray-500".to_string()),
@@ -81,11 +158,13 @@ pub fn ListItem(props: ListItemProps) -> Element {
                     }
                 }
                 if show_chevron {
-                    Icon {
+                    span { "aria-hidden": "true",
+                        Icon {
                         name: "chevron_right".to_string(),
-                        class: Some("size-5 flex-none text-gray-400 dark:text-gray-500".to_string()),
-                        size: None,
-                        variant: Some("outline".to_string()),
+                            class: Some("size-5 flex-none text-gray-400 dark:text-gray-500".to_string()),
+                            size: None,
+                            variant: Some("outline".to_string()),
+                        }
                     }
                 }
             }
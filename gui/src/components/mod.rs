@@ -1,16 +1,37 @@
+pub mod a11y;
 pub mod alert;
 pub mod badge;
+pub mod bulk_delete;
+pub mod context_menu;
 pub mod dialog;
+pub mod exporter;
 pub mod forms;
 pub mod icon_button;
 pub mod layout;
 pub mod notification;
+pub mod query_devtools;
 pub mod slideout;
+pub mod split_pane;
+pub mod tabs;
+pub mod task_logs;
+pub mod timestamp;
+pub mod virtual_list;
+pub mod workflow_runner;
 
 pub use alert::{Alert, AlertType};
 pub use badge::{Badge, BadgeButton, BadgeColor};
+pub use bulk_delete::BulkDeleteBar;
+pub use context_menu::{ContextMenu, ContextMenuItem, MenuAnchor};
 pub use dialog::ConfirmDialog;
-pub use forms::{TextInput, TextareaInput, ValidationMessage};
+pub use exporter::{use_exporter, ExportNotification, Exporter};
+pub use forms::{ParameterFormDialog, TextInput, TextareaInput, ValidationMessage};
 pub use icon_button::{IconButton, IconButtonSize, IconButtonVariant};
 pub use layout::{EmptyState, List, PageHeader, SectionCard};
 pub use notification::{Notification, NotificationData, NotificationType};
+pub use query_devtools::QueryDevtools;
+pub use split_pane::{dragged_split_ratio, SplitPane};
+pub use tabs::{tab_id, tab_panel_id, Tabs};
+pub use task_logs::{format_bytes, TaskLogs};
+pub use timestamp::{format_absolute, format_relative, Timestamp};
+pub use virtual_list::{visible_range, VirtualList};
+pub use workflow_runner::{use_workflow_runner, WorkflowRunDialogs, WorkflowRunner};
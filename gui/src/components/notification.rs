@@ -16,7 +16,14 @@ pub struct NotificationData {
 }
 
 #[component]
-pub fn Notification(notification: Signal<NotificationData>, on_close: EventHandler<()>) -> Element {
+pub fn Notification(
+    notification: Signal<NotificationData>,
+    on_close: EventHandler<()>,
+    /// Label of a button under the message, such as "Undo".
+    #[props(default)]
+    action: Option<String>,
+    #[props(default)] on_action: Option<EventHandler<()>>,
+) -> Element {
     let notification_data = notification();
 
     if !notification_data.show {
@@ -36,6 +43,7 @@ pub fn Notification(notification: Signal<NotificationData>, on_close: EventHandl
     rsx! {
         div {
             "aria-live": "assertive",
+            role: if notification_data.r#type == NotificationType::Error { "alert" } else { "status" },
             class: "pointer-events-none fixed inset-0 flex items-end px-4 py-6 sm:items-start sm:p-6 z-50",
             div {
                 class: "flex w-full flex-col items-center space-y-4 sm:items-end",
@@ -58,6 +66,16 @@ pub fn Notification(notification: Signal<NotificationData>, on_close: EventHandl
                                 p { class: "mt-1 text-sm text-gray-500 dark:text-gray-400",
                                     {notification_data.message}
                                 }
+                                if let (Some(action), Some(on_action)) = (action, on_action) {
+                                    div { class: "mt-3",
+                                        button {
+                                            r#type: "button",
+                                            class: "rounded-md text-sm font-medium cursor-pointer text-blue-600 hover:text-blue-500 focus:outline focus:outline-2 focus:outline-offset-2 focus:outline-indigo-600 dark:text-blue-400 dark:hover:text-blue-300",
+                                            onclick: move |_| on_action.call(()),
+                                            "{action}"
+                                        }
+                                    }
+                                }
                             }
                             div { class: "ml-4 flex shrink-0",
                                 button {
@@ -0,0 +1,213 @@
+use crate::components::{Badge, BadgeColor, IconButton, IconButtonSize, IconButtonVariant};
+use dioxus::prelude::*;
+use s_e_e_dioxus_query::devtools::{cache_entries, query_activity, QueryStatus};
+use s_e_e_dioxus_query::prelude::{invalidate_all_queries, invalidate_query, QueryKey};
+use std::time::Duration;
+
+/// Ctrl+Shift+Q (Cmd+Shift+Q on macOS) toggles the overlay.
+const TOGGLE_SCRIPT: &str = r#"
+if (window.seeQueryDevtoolsListener) {
+    document.removeEventListener('keydown', window.seeQueryDevtoolsListener);
+}
+window.seeQueryDevtoolsListener = function(event) {
+    if ((event.ctrlKey || event.metaKey) && event.shiftKey && event.key.toLowerCase() === 'q') {
+        event.preventDefault();
+        dioxus.send(true);
+    }
+};
+document.addEventListener('keydown', window.seeQueryDevtoolsListener);
+await new Promise(() => {});
+"#;
+
+const TH_CLASS: &str = "px-2 py-1 text-left font-medium text-zinc-500 dark:text-zinc-400";
+const TD_CLASS: &str = "px-2 py-1 align-top text-zinc-900 dark:text-zinc-100";
+
+fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_secs(1) {
+        format!("{} ms", duration.as_millis())
+    } else {
+        format!("{:.1} s", duration.as_secs_f64())
+    }
+}
+
+fn status_badge(status: QueryStatus) -> (BadgeColor, &'static str) {
+    match status {
+        QueryStatus::Idle => (BadgeColor::Zinc, "idle"),
+        QueryStatus::Fetching => (BadgeColor::Blue, "fetching"),
+        QueryStatus::Success => (BadgeColor::Emerald, "success"),
+        QueryStatus::Error => (BadgeColor::Red, "error"),
+    }
+}
+
+/// Lists the mounted queries and the query cache, refreshed every second
+/// while open, with buttons to invalidate entries. Only mounted in debug
+/// builds.
+#[component]
+pub fn QueryDevtools() -> Element {
+    let mut open = use_signal(|| false);
+    let mut tick = use_signal(|| 0u64);
+
+    use_future(move || async move {
+        let mut eval = document::eval(TOGGLE_SCRIPT);
+        while eval.recv::<bool>().await.is_ok() {
+            let is_open = *open.peek();
+            open.set(!is_open);
+        }
+    });
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            if *open.peek() {
+                *tick.write() += 1;
+            }
+        }
+    });
+
+    if !open() {
+        return rsx! {};
+    }
+    // Read so the overlay re-renders on every tick.
+    let _ = tick();
+    let activity = query_activity();
+    let cache = cache_entries();
+    let active_count = activity.len();
+    let cache_count = cache.len();
+
+    rsx! {
+        div {
+            class: "fixed bottom-4 right-4 z-50 w-[44rem] max-w-[calc(100vw-2rem)] max-h-[70vh] overflow-auto rounded-lg bg-white dark:bg-zinc-900 shadow-xl ring-1 ring-zinc-950/10 dark:ring-white/10 p-4 text-xs",
+            role: "dialog",
+            "aria-label": "Query devtools",
+            div { class: "flex items-center justify-between mb-3",
+                h2 { class: "text-sm font-semibold text-zinc-900 dark:text-white",
+                    "Query devtools"
+                }
+                div { class: "flex items-center gap-2",
+                    IconButton {
+                        variant: IconButtonVariant::Secondary,
+                        size: IconButtonSize::Small,
+                        onclick: move |_| {
+                            invalidate_all_queries();
+                            *tick.write() += 1;
+                        },
+                        icon: Some("arrow_path".to_string()),
+                        icon_variant: "outline".to_string(),
+                        "Invalidate all"
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Ghost,
+                        size: IconButtonSize::Small,
+                        onclick: move |_| open.set(false),
+                        aria_label: Some("Close query devtools".to_string()),
+                        icon: Some("x".to_string()),
+                        icon_variant: "outline".to_string(),
+                        ""
+                    }
+                }
+            }
+
+            h3 { class: "font-semibold text-zinc-700 dark:text-zinc-300 mb-1",
+                "Active queries ({active_count})"
+            }
+            table { class: "w-full mb-4",
+                thead {
+                    tr {
+                        th { class: TH_CLASS, "Key" }
+                        th { class: TH_CLASS, "Observers" }
+                        th { class: TH_CLASS, "State" }
+                        th { class: TH_CLASS, "Fetches" }
+                        th { class: TH_CLASS, "Last fetch" }
+                        th { class: TH_CLASS, "Fetched" }
+                    }
+                }
+                tbody {
+                    for query in activity {
+                        {
+                            let (color, label) = status_badge(query.status);
+                            let duration = query
+                                .last_fetch_duration
+                                .map(format_duration)
+                                .unwrap_or_else(|| "-".to_string());
+                            let fetched = query
+                                .last_fetched_at
+                                .map(|at| format!("{} ago", format_duration(at.elapsed())))
+                                .unwrap_or_else(|| "-".to_string());
+                            rsx! {
+                                tr { key: "{query.key}",
+                                    td { class: "{TD_CLASS} font-mono break-all",
+                                        "{query.key}"
+                                        if let Some(error) = &query.last_error {
+                                            div { class: "text-red-600 dark:text-red-400", "{error}" }
+                                        }
+                                    }
+                                    td { class: TD_CLASS, "{query.observers}" }
+                                    td { class: TD_CLASS, Badge { color, "{label}" } }
+                                    td { class: TD_CLASS, "{query.fetch_count}" }
+                                    td { class: TD_CLASS, "{duration}" }
+                                    td { class: TD_CLASS, "{fetched}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            h3 { class: "font-semibold text-zinc-700 dark:text-zinc-300 mb-1",
+                "Cache ({cache_count})"
+            }
+            table { class: "w-full",
+                thead {
+                    tr {
+                        th { class: TH_CLASS, "Key" }
+                        th { class: TH_CLASS, "Type" }
+                        th { class: TH_CLASS, "Age" }
+                        th { class: TH_CLASS, "Expires after" }
+                        th { class: TH_CLASS, "" }
+                    }
+                }
+                tbody {
+                    for entry in cache {
+                        {
+                            let age = entry
+                                .age
+                                .map(format_duration)
+                                .unwrap_or_else(|| "-".to_string());
+                            let expires = entry
+                                .cache_time_ms
+                                .map(|ms| format_duration(Duration::from_millis(ms)))
+                                .unwrap_or_else(|| "-".to_string());
+                            let key: QueryKey = entry.key.clone();
+                            rsx! {
+                                tr { key: "{entry.key}",
+                                    td { class: "{TD_CLASS} font-mono break-all",
+                                        "{entry.key}"
+                                        if entry.is_fetching {
+                                            " "
+                                            Badge { color: BadgeColor::Blue, "fetching" }
+                                        }
+                                    }
+                                    td { class: "{TD_CLASS} font-mono break-all", "{entry.type_name}" }
+                                    td { class: TD_CLASS, "{age}" }
+                                    td { class: TD_CLASS, "{expires}" }
+                                    td { class: TD_CLASS,
+                                        IconButton {
+                                            variant: IconButtonVariant::Ghost,
+                                            size: IconButtonSize::Small,
+                                            onclick: move |_| {
+                                                invalidate_query(&key);
+                                                *tick.write() += 1;
+                                            },
+                                            icon: Some("trash".to_string()),
+                                            icon_variant: "outline".to_string(),
+                                            "Invalidate"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
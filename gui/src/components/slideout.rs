@@ -1,3 +1,4 @@
+use crate::components::a11y::{use_focus_trap, use_unique_id};
 use crate::components::{IconButton, IconButtonSize, IconButtonVariant};
 use dioxus::prelude::*;
 
@@ -12,6 +13,9 @@ pub fn Slideout(
     footer: Option<Element>,
     show_close_button: Option<bool>,
 ) -> Element {
+    let panel_id = use_unique_id("slideout");
+    use_focus_trap(panel_id.clone(), is_open);
+
     if !is_open {
         return rsx! { div {} };
     }
@@ -21,10 +25,20 @@ pub fn Slideout(
         div {
             class: format!("fixed inset-0 z-50 cursor-pointer {}", backdrop_class),
             onclick: move |_| on_close.call(()),
+            onkeydown: move |evt: KeyboardEvent| {
+                if evt.key() == Key::Escape {
+                    on_close.call(());
+                }
+            },
 
 
             div {
-                class: "fixed inset-y-0 right-0 z-50 w-3/4 transform transition-transform duration-500 ease-in-out sm:duration-700",
+                id: "{panel_id}",
+                role: "dialog",
+                "aria-modal": "true",
+                "aria-labelledby": "{panel_id}-title",
+                tabindex: "-1",
+                class: "fixed inset-y-0 right-0 z-50 w-3/4 transform transition-transform duration-500 ease-in-out sm:duration-700 cursor-auto focus:outline-none",
                 onclick: move |e| e.stop_propagation(),
 
                 div {
@@ -37,6 +51,7 @@ pub fn Slideout(
 
                         div {
                             h2 {
+                                id: "{panel_id}-title",
                                 class: "text-lg font-semibold text-gray-900 dark:text-white",
                                 "{title}"
                             }
@@ -57,6 +72,7 @@ pub fn Slideout(
                                 class: Some("text-gray-400 hover:text-gray-500 dark:hover:text-white".to_string()),
                                 icon: Some("x".to_string()),
                                 icon_variant: "outline".to_string(),
+                                aria_label: Some("Close".to_string()),
                                 ""
                             }
                         }
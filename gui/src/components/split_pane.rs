@@ -0,0 +1,107 @@
+use super::a11y::FOCUS_RING;
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+/// Neither pane can be dragged narrower than this share of the width.
+pub const MIN_SPLIT_RATIO: f64 = 0.2;
+pub const MAX_SPLIT_RATIO: f64 = 0.8;
+
+/// How far one arrow key press moves the divider.
+const KEYBOARD_STEP: f64 = 0.05;
+
+/// Left pane share after dragging the divider `delta_px` across a container
+/// `width_px` wide, starting from `start_ratio`.
+pub fn dragged_split_ratio(start_ratio: f64, delta_px: f64, width_px: f64) -> f64 {
+    if width_px <= 0.0 {
+        return start_ratio.clamp(MIN_SPLIT_RATIO, MAX_SPLIT_RATIO);
+    }
+    (start_ratio + delta_px / width_px).clamp(MIN_SPLIT_RATIO, MAX_SPLIT_RATIO)
+}
+
+/// Pointer position, ratio and container width when a drag started.
+#[derive(Clone, Copy)]
+struct DragStart {
+    x: f64,
+    ratio: f64,
+    width: f64,
+}
+
+/// Two side-by-side panes with a draggable divider. `ratio` is the left pane's
+/// share of the width; callers own it so they can persist it.
+#[component]
+pub fn SplitPane(
+    ratio: Signal<f64>,
+    left: Element,
+    right: Element,
+    #[props(default)] label: Option<String>,
+) -> Element {
+    let mut container = use_signal(|| None::<Rc<MountedData>>);
+    let mut drag = use_signal(|| None::<DragStart>);
+
+    let current = ratio().clamp(MIN_SPLIT_RATIO, MAX_SPLIT_RATIO);
+    let left_percent = current * 100.0;
+    let dragging = drag().is_some();
+
+    rsx! {
+        div {
+            class: if dragging { "flex w-full min-h-0 select-none cursor-col-resize" } else { "flex w-full min-h-0" },
+            onmounted: move |evt| container.set(Some(evt.data())),
+            onmousemove: move |evt| {
+                let Some(start) = drag() else { return };
+                let x = evt.client_coordinates().x;
+                ratio.set(dragged_split_ratio(start.ratio, x - start.x, start.width));
+            },
+            onmouseup: move |_| drag.set(None),
+            onmouseleave: move |_| drag.set(None),
+
+            div {
+                class: "min-w-0 overflow-auto",
+                style: "flex: 0 0 {left_percent}%;",
+                {left}
+            }
+
+            div {
+                role: "separator",
+                tabindex: "0",
+                "aria-orientation": "vertical",
+                "aria-label": label.unwrap_or_else(|| "Resize panes".to_string()),
+                "aria-valuemin": "{MIN_SPLIT_RATIO * 100.0}",
+                "aria-valuemax": "{MAX_SPLIT_RATIO * 100.0}",
+                "aria-valuenow": "{left_percent.round()}",
+                class: format!(
+                    "mx-2 w-1.5 shrink-0 cursor-col-resize rounded-full bg-zinc-200 hover:bg-blue-400 dark:bg-zinc-700 dark:hover:bg-blue-500 {} {}",
+                    if dragging { "bg-blue-400 dark:bg-blue-500" } else { "" },
+                    FOCUS_RING
+                ),
+                onmousedown: move |evt| {
+                    evt.prevent_default();
+                    let Some(element) = container() else { return };
+                    let x = evt.client_coordinates().x;
+                    let start_ratio = current;
+                    spawn(async move {
+                        if let Ok(rect) = element.get_client_rect().await {
+                            drag.set(Some(DragStart {
+                                x,
+                                ratio: start_ratio,
+                                width: rect.size.width,
+                            }));
+                        }
+                    });
+                },
+                onkeydown: move |evt: KeyboardEvent| {
+                    let step = match evt.key() {
+                        Key::ArrowLeft => -KEYBOARD_STEP,
+                        Key::ArrowRight => KEYBOARD_STEP,
+                        _ => return,
+                    };
+                    evt.prevent_default();
+                    ratio.set((current + step).clamp(MIN_SPLIT_RATIO, MAX_SPLIT_RATIO));
+                },
+            }
+
+            div { class: "min-w-0 flex-1 overflow-auto",
+                {right}
+            }
+        }
+    }
+}
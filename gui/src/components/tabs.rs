@@ -0,0 +1,83 @@
+use super::a11y::{focus_element, FOCUS_RING};
+use dioxus::prelude::*;
+
+/// DOM id of the tab button for `tab`, used by the panel's `aria-labelledby`.
+pub fn tab_id(id_prefix: &str, tab: &str) -> String {
+    format!(
+        "{}-tab-{}",
+        id_prefix,
+        tab.to_lowercase().replace(char::is_whitespace, "-")
+    )
+}
+
+/// DOM id of the panel the tabs control.
+pub fn tab_panel_id(id_prefix: &str) -> String {
+    format!("{}-panel", id_prefix)
+}
+
+/// Underlined tab strip with the WAI-ARIA tabs pattern.
+///
+/// Only the selected tab is in the Tab order; arrow keys, Home and End move
+/// between tabs and select them.
+#[component]
+pub fn Tabs(
+    id_prefix: String,
+    tabs: Vec<String>,
+    selected: String,
+    on_change: EventHandler<String>,
+    #[props(default)] label: Option<String>,
+) -> Element {
+    let selected_index = tabs.iter().position(|t| *t == selected).unwrap_or(0);
+    let tab_count = tabs.len();
+
+    rsx! {
+        div { class: "border-b border-zinc-200 dark:border-zinc-700",
+            div {
+                class: "flex space-x-8",
+                role: "tablist",
+                "aria-label": label.unwrap_or_default(),
+                for (index, tab) in tabs.iter().cloned().enumerate() {
+                    button {
+                        key: "{tab}",
+                        id: tab_id(&id_prefix, &tab),
+                        r#type: "button",
+                        role: "tab",
+                        "aria-selected": if index == selected_index { "true" } else { "false" },
+                        "aria-controls": tab_panel_id(&id_prefix),
+                        tabindex: if index == selected_index { "0" } else { "-1" },
+                        class: format!(
+                            "py-2 px-1 border-b-2 font-medium text-sm cursor-pointer {} {}",
+                            if index == selected_index {
+                                "border-blue-500 text-blue-600 dark:text-blue-400"
+                            } else {
+                                "border-transparent text-zinc-500 hover:text-zinc-700 hover:border-zinc-300 dark:text-zinc-400 dark:hover:text-zinc-300"
+                            },
+                            FOCUS_RING
+                        ),
+                        onclick: {
+                            let tab = tab.clone();
+                            move |_| on_change.call(tab.clone())
+                        },
+                        onkeydown: {
+                            let tabs = tabs.clone();
+                            let id_prefix = id_prefix.clone();
+                            move |evt: KeyboardEvent| {
+                                let next = match evt.key() {
+                                    Key::ArrowRight => (index + 1) % tab_count,
+                                    Key::ArrowLeft => (index + tab_count - 1) % tab_count,
+                                    Key::Home => 0,
+                                    Key::End => tab_count - 1,
+                                    _ => return,
+                                };
+                                evt.prevent_default();
+                                on_change.call(tabs[next].clone());
+                                focus_element(&tab_id(&id_prefix, &tabs[next]));
+                            }
+                        },
+                        "{tab}"
+                    }
+                }
+            }
+        }
+    }
+}
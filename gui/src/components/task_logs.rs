@@ -0,0 +1,75 @@
+use super::virtual_list::VirtualList;
+use dioxus::prelude::*;
+
+const LINE_HEIGHT: f64 = 20.0;
+const VIEWPORT_HEIGHT: f64 = 480.0;
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Task output as numbered lines, rendered through `VirtualList` so long logs stay fast.
+///
+/// Lines do not wrap, because every row must have the same height; long lines
+/// scroll horizontally instead. When `truncated` is set a banner explains that the
+/// middle of the output was dropped by the task's output quota.
+#[component]
+pub fn TaskLogs(
+    output: String,
+    #[props(default)] total_bytes: Option<u64>,
+    #[props(default)] truncated: bool,
+) -> Element {
+    let lines = use_memo(use_reactive((&output,), |(output,)| {
+        output.lines().map(str::to_string).collect::<Vec<_>>()
+    }));
+    let line_count = lines.read().len();
+    let number_width = line_count.to_string().len();
+
+    rsx! {
+        if truncated {
+            div {
+                class: "mb-3 rounded-lg border border-amber-200 bg-amber-50 px-4 py-2 text-sm text-amber-800 dark:border-amber-800 dark:bg-amber-950 dark:text-amber-200",
+                role: "status",
+                if let Some(total) = total_bytes {
+                    "Output truncated: the task wrote {format_bytes(total)}. Only the beginning and end are kept."
+                } else {
+                    "Output truncated: only the beginning and end are kept."
+                }
+            }
+        }
+        div { class: "bg-white dark:bg-zinc-900 rounded-lg border border-zinc-200 dark:border-zinc-700 overflow-x-auto",
+            VirtualList {
+                item_count: line_count,
+                row_height: LINE_HEIGHT,
+                viewport_height: VIEWPORT_HEIGHT,
+                overscan: 20,
+                label: "Task output".to_string(),
+                class: "font-mono text-sm text-zinc-900 dark:text-zinc-100 px-4".to_string(),
+                render_row: move |index: usize| {
+                    let line = lines.read().get(index).cloned().unwrap_or_default();
+                    rsx! {
+                        div { class: "flex gap-4 whitespace-pre leading-5",
+                            span {
+                                class: "select-none text-right text-zinc-400 dark:text-zinc-500",
+                                style: "min-width: {number_width}ch;",
+                                "{index + 1}"
+                            }
+                            span { "{line}" }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use chrono::{DateTime, Local, Utc};
+use dioxus::prelude::*;
+use s_e_e_core::{DateStyle, DisplayTimeZone, TimestampSettings};
+
+/// Anything older than this is shown as a date instead of "N days ago".
+const RELATIVE_LIMIT_SECS: i64 = 7 * 86_400;
+
+/// The exact time in the user's chosen zone and date layout.
+pub fn format_absolute(timestamp: DateTime<Utc>, settings: &TimestampSettings) -> String {
+    let pattern = match settings.date_style {
+        DateStyle::Iso => "%Y-%m-%d %H:%M:%S",
+        DateStyle::UnitedStates => "%m/%d/%Y %-I:%M:%S %p",
+        DateStyle::European => "%d.%m.%Y %H:%M:%S",
+    };
+    match settings.time_zone {
+        DisplayTimeZone::Local => timestamp
+            .with_timezone(&Local)
+            .format(&format!("{} (UTC%:z)", pattern))
+            .to_string(),
+        DisplayTimeZone::Utc => timestamp.format(&format!("{} UTC", pattern)).to_string(),
+    }
+}
+
+fn format_date(timestamp: DateTime<Utc>, settings: &TimestampSettings) -> String {
+    let pattern = match settings.date_style {
+        DateStyle::Iso => "%Y-%m-%d",
+        DateStyle::UnitedStates => "%m/%d/%Y",
+        DateStyle::European => "%d.%m.%Y",
+    };
+    match settings.time_zone {
+        DisplayTimeZone::Local => timestamp.with_timezone(&Local).format(pattern).to_string(),
+        DisplayTimeZone::Utc => timestamp.format(pattern).to_string(),
+    }
+}
+
+/// "just now", "3 min ago", "in 2 hr", falling back to the date after a week.
+pub fn format_relative(
+    timestamp: DateTime<Utc>,
+    now: DateTime<Utc>,
+    settings: &TimestampSettings,
+) -> String {
+    let seconds = (now - timestamp).num_seconds();
+    let elapsed = seconds.abs();
+
+    let amount = if elapsed < 45 {
+        return "just now".to_string();
+    } else if elapsed < 3_600 {
+        format!("{} min", (elapsed / 60).max(1))
+    } else if elapsed < 86_400 {
+        format!("{} hr", elapsed / 3_600)
+    } else if elapsed < RELATIVE_LIMIT_SECS {
+        match elapsed / 86_400 {
+            1 => "1 day".to_string(),
+            days => format!("{} days", days),
+        }
+    } else {
+        return format_date(timestamp, settings);
+    };
+
+    if seconds < 0 {
+        format!("in {}", amount)
+    } else {
+        format!("{} ago", amount)
+    }
+}
+
+/// A timestamp shown relative to now, with the exact time as a tooltip.
+///
+/// Reads the display preferences provided at the app root, falling back to
+/// local time in ISO layout.
+#[component]
+pub fn Timestamp(
+    value: DateTime<Utc>,
+    #[props(default)] absolute: bool,
+    #[props(default)] class: Option<String>,
+) -> Element {
+    let settings = try_use_context::<Signal<TimestampSettings>>()
+        .map(|settings| settings())
+        .unwrap_or_default();
+
+    let exact = format_absolute(value, &settings);
+    let text = if absolute {
+        exact.clone()
+    } else {
+        format_relative(value, Utc::now(), &settings)
+    };
+
+    rsx! {
+        time {
+            datetime: value.to_rfc3339(),
+            title: exact,
+            class: class.unwrap_or_default(),
+            "{text}"
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use dioxus::prelude::*;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// Rows to render for a scroll position: the ones in view plus `overscan` on each side.
+pub fn visible_range(
+    scroll_top: f64,
+    viewport_height: f64,
+    row_height: f64,
+    item_count: usize,
+    overscan: usize,
+) -> Range<usize> {
+    if item_count == 0 || row_height <= 0.0 {
+        return 0..0;
+    }
+    let first = (scroll_top.max(0.0) / row_height).floor() as usize;
+    let in_view = (viewport_height / row_height).ceil() as usize + 1;
+    let start = first.saturating_sub(overscan).min(item_count);
+    let end = (first + in_view + overscan).min(item_count);
+    start..end
+}
+
+/// A scrolling list that only mounts the rows near the viewport.
+///
+/// Every row is `row_height` pixels tall, which keeps the DOM at a few dozen
+/// nodes however long the list grows. `on_near_end` fires as the user scrolls
+/// close to the last row, so callers can load the next page.
+#[component]
+pub fn VirtualList(
+    item_count: usize,
+    row_height: f64,
+    viewport_height: f64,
+    render_row: Callback<usize, Element>,
+    #[props(default = 6)] overscan: usize,
+    #[props(default)] on_near_end: Option<EventHandler<()>>,
+    #[props(default)] class: Option<String>,
+    #[props(default)] label: Option<String>,
+) -> Element {
+    let mut scroll_top = use_signal(|| 0.0);
+    let mut viewport = use_signal(|| None::<Rc<MountedData>>);
+
+    let range = visible_range(
+        scroll_top(),
+        viewport_height,
+        row_height,
+        item_count,
+        overscan,
+    );
+    let offset = range.start as f64 * row_height;
+    let total_height = item_count as f64 * row_height;
+
+    rsx! {
+        div {
+            class: "overflow-y-auto",
+            style: "max-height: {viewport_height}px;",
+            role: "list",
+            "aria-label": label.unwrap_or_default(),
+            onmounted: move |evt| viewport.set(Some(evt.data())),
+            onscroll: move |_| {
+                let Some(element) = viewport() else { return };
+                spawn(async move {
+                    let Ok(position) = element.get_scroll_offset().await else {
+                        return;
+                    };
+                    scroll_top.set(position.y);
+                    let range = visible_range(position.y, viewport_height, row_height, item_count, overscan);
+                    if range.end + overscan >= item_count {
+                        if let Some(handler) = on_near_end {
+                            handler.call(());
+                        }
+                    }
+                });
+            },
+            div {
+                class: "relative",
+                style: "height: {total_height}px;",
+                div {
+                    class: class.unwrap_or_default(),
+                    style: "transform: translateY({offset}px);",
+                    for index in range {
+                        div {
+                            key: "{index}",
+                            role: "listitem",
+                            "aria-posinset": "{index + 1}",
+                            "aria-setsize": "{item_count}",
+                            class: "overflow-hidden",
+                            style: "height: {row_height}px;",
+                            {render_row.call(index)}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
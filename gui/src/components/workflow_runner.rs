@@ -0,0 +1,125 @@
+use super::{ConfirmDialog, ParameterFormDialog};
+use crate::queries::use_execute_workflow_mutation;
+use crate::services::WorkflowService;
+use dioxus::prelude::*;
+use s_e_e_core::{TrustPrompt, TrustReason, WorkflowParameter, WorkflowResult};
+use s_e_e_dioxus_query::prelude::MutationState;
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Clone, PartialEq)]
+struct PendingTrust {
+    prompt: TrustPrompt,
+    workflow_name: String,
+}
+
+#[derive(Clone, PartialEq)]
+struct PendingParameters {
+    workflow_id: String,
+    workflow_name: String,
+    parameters: Vec<WorkflowParameter>,
+}
+
+/// Starts workflow runs from anywhere in the app. Imported workflows that are
+/// unsigned or were edited since import are confirmed before their first
+/// run, and workflows with parameters ask for them first; render
+/// [`WorkflowRunDialogs`] with the runner for those steps.
+#[derive(Clone, Copy, PartialEq)]
+pub struct WorkflowRunner {
+    /// The latest run started through this runner.
+    pub state: Signal<MutationState<WorkflowResult>>,
+    execute: Callback<(String, HashMap<String, Value>)>,
+    pending_trust: Signal<Option<PendingTrust>>,
+    pending_parameters: Signal<Option<PendingParameters>>,
+}
+
+pub fn use_workflow_runner() -> WorkflowRunner {
+    let (state, execute_fn) = use_execute_workflow_mutation();
+    WorkflowRunner {
+        state,
+        execute: use_callback(move |args| execute_fn(args)),
+        pending_trust: use_signal(|| None),
+        pending_parameters: use_signal(|| None),
+    }
+}
+
+impl WorkflowRunner {
+    pub fn run(self, workflow_id: String, workflow_name: String) {
+        let mut pending_trust = self.pending_trust;
+        spawn(async move {
+            match WorkflowService::trust_prompt(&workflow_id).await {
+                Ok(Some(prompt)) => pending_trust.set(Some(PendingTrust {
+                    prompt,
+                    workflow_name,
+                })),
+                _ => self.start(workflow_id, workflow_name).await,
+            }
+        });
+    }
+
+    async fn start(self, workflow_id: String, workflow_name: String) {
+        let mut pending_parameters = self.pending_parameters;
+        match WorkflowService::parameters(&workflow_id).await {
+            Ok(parameters) if !parameters.is_empty() => {
+                pending_parameters.set(Some(PendingParameters {
+                    workflow_id,
+                    workflow_name,
+                    parameters,
+                }))
+            }
+            _ => self.execute.call((workflow_id, HashMap::new())),
+        }
+    }
+}
+
+/// The trust prompt and parameter form a [`WorkflowRunner`] may need.
+#[component]
+pub fn WorkflowRunDialogs(runner: WorkflowRunner) -> Element {
+    let mut pending_trust = runner.pending_trust;
+    let mut pending_parameters = runner.pending_parameters;
+
+    let confirm_trust = move |_: ()| {
+        let Some(pending) = pending_trust() else {
+            return;
+        };
+        pending_trust.set(None);
+        spawn(async move {
+            if let Err(e) = WorkflowService::trust(&pending.prompt.workflow_id).await {
+                tracing::error!("[WorkflowRunDialogs] Failed to trust workflow: {}", e);
+                return;
+            }
+            runner
+                .start(pending.prompt.workflow_id, pending.workflow_name)
+                .await;
+        });
+    };
+
+    rsx! {
+        ConfirmDialog {
+            show: pending_trust().is_some(),
+            title: "Run imported workflow?".to_string(),
+            message: match pending_trust().map(|pending| pending.prompt.reason) {
+                Some(TrustReason::Modified) => "This workflow was changed after it was imported, so its signature no longer applies. It will run these commands:".to_string(),
+                _ => "This workflow was imported without a signature. It will run these commands:".to_string(),
+            },
+            confirm_text: "Trust and run".to_string(),
+            cancel_text: "Cancel".to_string(),
+            details: pending_trust().map(|pending| pending.prompt.commands).unwrap_or_default(),
+            on_confirm: confirm_trust,
+            on_cancel: move |_| pending_trust.set(None),
+        }
+
+        if let Some(pending) = pending_parameters() {
+            ParameterFormDialog {
+                key: "{pending.workflow_id}",
+                workflow_name: pending.workflow_name.clone(),
+                parameters: pending.parameters.clone(),
+                on_submit: move |params: HashMap<String, Value>| {
+                    pending_parameters.set(None);
+                    runner.execute.call((pending.workflow_id.clone(), params));
+                },
+                on_cancel: move |_| pending_parameters.set(None),
+            }
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use super::hooks::use_theme;
+use super::hooks::{
+    use_handler_policy, use_lifecycle, use_log_settings, use_provide_timestamp_settings,
+    use_provide_ui_state, use_refresh_policy, use_theme_mode, use_workflow_limits,
+};
 use super::router::Route;
 use dioxus::prelude::*;
 use dioxus_desktop::use_window;
@@ -43,34 +46,14 @@ pub fn App() -> Element {
 
 #[component]
 fn AppContent() -> Element {
-    let theme = use_theme();
-
-    let theme_class = use_memo(move || {
-        let theme_value = theme();
-        tracing::debug!("[AppContent] use_theme returned: {:?}", theme_value);
-        let class = match theme_value {
-            s_e_e_core::Theme::Light => {
-                tracing::debug!("[AppContent] Theme is Light, applying light class");
-                "light"
-            }
-            s_e_e_core::Theme::Dark => {
-                tracing::debug!("[AppContent] Theme is Dark, applying dark class");
-                "dark"
-            }
-            s_e_e_core::Theme::System => {
-                let detected = if matches!(dark_light::detect(), dark_light::Mode::Dark) {
-                    tracing::debug!("[AppContent] Theme is System, detected dark mode");
-                    "dark"
-                } else {
-                    tracing::debug!("[AppContent] Theme is System, detected light mode");
-                    "light"
-                };
-                detected
-            }
-        };
-        tracing::trace!("[AppContent] Theme class: {}", class);
-        class
-    });
+    let theme_class = use_theme_mode();
+    use_refresh_policy();
+    use_provide_timestamp_settings();
+    use_provide_ui_state();
+    use_workflow_limits();
+    use_handler_policy();
+    use_log_settings();
+    use_lifecycle();
 
     rsx! {
         div {
@@ -1,3 +1,20 @@
+pub mod use_handler_policy;
+pub mod use_lifecycle;
+pub mod use_log_settings;
+pub mod use_refresh_policy;
 pub mod use_theme;
+pub mod use_timestamp_settings;
+pub mod use_ui_state;
+pub mod use_workflow_limits;
 
-pub use use_theme::use_theme;
+pub use use_handler_policy::use_handler_policy;
+pub use use_lifecycle::use_lifecycle;
+pub use use_log_settings::use_log_settings;
+pub use use_refresh_policy::use_refresh_policy;
+pub use use_theme::{use_theme, use_theme_mode};
+pub use use_timestamp_settings::use_provide_timestamp_settings;
+pub use use_ui_state::{
+    use_persisted_filter, use_persisted_pane_ratio, use_provide_ui_state, use_remember_route,
+    use_ui_state,
+};
+pub use use_workflow_limits::use_workflow_limits;
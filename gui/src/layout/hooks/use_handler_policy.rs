@@ -0,0 +1,18 @@
+use crate::queries::use_settings_query;
+use dioxus::prelude::*;
+use s_e_e_core::apply_handler_policy;
+
+/// Apply the saved handler profile to validation and runs app-wide.
+pub fn use_handler_policy() {
+    let (settings_state, _refetch) = use_settings_query();
+
+    let loaded = settings_state
+        .data
+        .as_ref()
+        .map(|settings| settings.handler_policy.clone());
+    use_effect(use_reactive((&loaded,), move |(loaded,)| {
+        if let Some(policy) = loaded {
+            apply_handler_policy(&policy);
+        }
+    }));
+}
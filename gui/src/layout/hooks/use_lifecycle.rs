@@ -0,0 +1,71 @@
+use dioxus::prelude::*;
+use dioxus_desktop::tao::event::{Event, WindowEvent};
+use dioxus_desktop::use_wry_event_handler;
+use s_e_e_core::Lifecycle;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// How often the app checks whether storage maintenance is due.
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// Runs started from the window are driven by it, so they cannot finish once
+/// it closes; this only gives maintenance time to wrap up before they are
+/// saved as failed.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Start the app's background work, and stop it when the window closes.
+pub fn use_lifecycle() {
+    let lifecycle = use_hook(|| {
+        let mut lifecycle = Lifecycle::new().with_timeout(SHUTDOWN_TIMEOUT);
+        lifecycle.start("storage maintenance", storage_maintenance);
+        Rc::new(RefCell::new(Some(lifecycle)))
+    });
+
+    use_wry_event_handler(move |event, _| {
+        let Event::WindowEvent {
+            event: WindowEvent::CloseRequested,
+            ..
+        } = event
+        else {
+            return;
+        };
+        let Some(lifecycle) = lifecycle.borrow_mut().take() else {
+            return;
+        };
+        // The event loop is on its way out, so shut down on a runtime of our own.
+        let report = std::thread::spawn(move || {
+            tokio::runtime::Runtime::new().map(|rt| rt.block_on(lifecycle.shutdown()))
+        })
+        .join();
+        match report {
+            Ok(Ok(report)) if !report.interrupted.is_empty() => tracing::warn!(
+                executions = ?report.interrupted,
+                "Saved executions interrupted by closing the app as failed"
+            ),
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => tracing::error!("Failed to shut down background work: {}", e),
+            Err(_) => tracing::error!("Shutting down background work panicked"),
+        }
+    });
+}
+
+/// Run storage maintenance in the background once it is due and no workflow
+/// is running. The first check waits a full interval so startup stays quick.
+async fn storage_maintenance(mut shutdown: watch::Receiver<bool>) {
+    loop {
+        tokio::select! {
+            _ = tokio::time::sleep(IDLE_CHECK_INTERVAL) => {}
+            _ = shutdown.changed() => return,
+        }
+        match s_e_e_core::maintain_storage_if_idle().await {
+            Ok(Some(report)) => tracing::info!(
+                reclaimed_bytes = report.reclaimed_bytes(),
+                "Ran scheduled storage maintenance"
+            ),
+            Ok(None) => {}
+            Err(e) => tracing::warn!("Scheduled storage maintenance failed: {}", e),
+        }
+    }
+}
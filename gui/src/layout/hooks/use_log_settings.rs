@@ -0,0 +1,20 @@
+use crate::queries::use_settings_query;
+use dioxus::prelude::*;
+use s_e_e_core::apply_log_settings;
+
+/// Rebuild the log filter whenever the saved log levels change.
+pub fn use_log_settings() {
+    let (settings_state, _refetch) = use_settings_query();
+
+    let loaded = settings_state
+        .data
+        .as_ref()
+        .map(|settings| settings.logging.clone());
+    use_effect(use_reactive((&loaded,), move |(loaded,)| {
+        if let Some(logging) = loaded {
+            if let Err(e) = apply_log_settings(&logging) {
+                tracing::warn!(error = %e, "Failed to apply log levels");
+            }
+        }
+    }));
+}
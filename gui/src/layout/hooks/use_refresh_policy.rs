@@ -0,0 +1,26 @@
+use crate::queries::{refresh_policy_from, use_settings_query};
+use dioxus_desktop::tao::event::{Event, WindowEvent};
+use dioxus_desktop::use_wry_event_handler;
+use s_e_e_dioxus_query::prelude::{set_refresh_policy, set_window_focused};
+
+/// Keep the query layer's polling in step with saved settings and window focus.
+///
+/// Minimizing the window also takes focus away, so one event covers both cases.
+pub fn use_refresh_policy() {
+    let (settings_state, _refetch) = use_settings_query();
+
+    // Setting an unchanged policy is a no-op, so applying it on every render is cheap.
+    if let Some(settings) = &settings_state.data {
+        set_refresh_policy(refresh_policy_from(&settings.polling));
+    }
+
+    use_wry_event_handler(|event, _| {
+        if let Event::WindowEvent {
+            event: WindowEvent::Focused(focused),
+            ..
+        } = event
+        {
+            set_window_focused(*focused);
+        }
+    });
+}
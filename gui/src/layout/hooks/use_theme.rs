@@ -25,3 +25,23 @@ pub fn use_theme() -> Memo<Theme> {
         theme
     })
 }
+
+/// `"dark"` or `"light"`, with `Theme::System` resolved against the OS.
+pub fn use_theme_mode() -> Memo<&'static str> {
+    let theme = use_theme();
+    use_memo(move || theme_mode(&theme()))
+}
+
+pub fn theme_mode(theme: &Theme) -> &'static str {
+    match theme {
+        Theme::Light => "light",
+        Theme::Dark => "dark",
+        Theme::System => {
+            if matches!(dark_light::detect(), dark_light::Mode::Dark) {
+                "dark"
+            } else {
+                "light"
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::queries::use_settings_query;
+use dioxus::prelude::*;
+use s_e_e_core::TimestampSettings;
+
+/// Share the saved timestamp preferences with every `Timestamp` below the app root.
+pub fn use_provide_timestamp_settings() {
+    let (settings_state, _refetch) = use_settings_query();
+    let mut timestamps = use_context_provider(|| Signal::new(TimestampSettings::default()));
+
+    let loaded = settings_state
+        .data
+        .as_ref()
+        .map(|settings| settings.timestamps.clone());
+    use_effect(use_reactive((&loaded,), move |(loaded,)| {
+        if let Some(loaded) = loaded {
+            tracing::debug!("[use_provide_timestamp_settings] Applying {:?}", loaded);
+            timestamps.set(loaded);
+        }
+    }));
+}
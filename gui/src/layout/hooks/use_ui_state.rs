@@ -0,0 +1,155 @@
+use crate::layout::router::Route;
+use crate::services::UiStateService;
+use dioxus::prelude::*;
+use dioxus_desktop::tao::event::{Event, WindowEvent};
+use dioxus_desktop::{use_window, use_wry_event_handler};
+use dioxus_router::prelude::{use_navigator, use_route};
+use s_e_e_core::{UiState, WindowState};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::time::Duration;
+
+/// Window drags and typing in a filter change the state many times a second.
+const SAVE_DELAY: Duration = Duration::from_millis(500);
+
+/// Share the UI state loaded at startup with everything below the app root,
+/// follow the window's size and position, and save changes once they settle.
+pub fn use_provide_ui_state() {
+    let mut state =
+        use_context_provider(|| Signal::new(try_consume_context::<UiState>().unwrap_or_default()));
+    let mut save_generation = use_signal(|| 0u64);
+
+    use_effect(move || {
+        let snapshot = state();
+        let generation = *save_generation.peek() + 1;
+        save_generation.set(generation);
+        spawn(async move {
+            tokio::time::sleep(SAVE_DELAY).await;
+            if *save_generation.peek() != generation {
+                return;
+            }
+            if let Err(e) = UiStateService::save_ui_state(snapshot).await {
+                tracing::warn!("[use_provide_ui_state] Failed to save UI state: {}", e);
+            }
+        });
+    });
+
+    let window = use_window();
+    use_wry_event_handler(move |event, _| {
+        let Event::WindowEvent {
+            event: WindowEvent::Resized(_) | WindowEvent::Moved(_),
+            ..
+        } = event
+        else {
+            return;
+        };
+
+        let maximized = window.is_maximized();
+        let previous = state.peek().window;
+        // A maximized window keeps the geometry it had before, so un-maximizing
+        // after a restart goes back to the user's chosen size.
+        let geometry = match previous {
+            Some(previous) if maximized => WindowState {
+                maximized,
+                ..previous
+            },
+            _ => {
+                let scale = window.scale_factor();
+                let size = window.inner_size().to_logical::<u32>(scale);
+                let Ok(position) = window.outer_position() else {
+                    return;
+                };
+                let position = position.to_logical::<i32>(scale);
+                WindowState {
+                    width: size.width,
+                    height: size.height,
+                    x: position.x,
+                    y: position.y,
+                    maximized,
+                }
+            }
+        };
+        if previous != Some(geometry) {
+            state.write().window = Some(geometry);
+        }
+    });
+}
+
+pub fn use_ui_state() -> Signal<UiState> {
+    use_context()
+}
+
+/// Reopen the page that was showing when the app last closed, then keep
+/// recording the current page.
+pub fn use_remember_route() {
+    let mut ui_state = use_ui_state();
+    let navigator = use_navigator();
+    let route = use_route::<Route>();
+
+    let saved_route = use_hook(|| {
+        ui_state
+            .peek()
+            .last_route
+            .as_deref()
+            .and_then(|path| path.parse::<Route>().ok())
+    });
+    use_effect(move || {
+        if let Some(saved_route) = saved_route.clone() {
+            navigator.replace(saved_route);
+        }
+    });
+
+    use_effect(use_reactive((&route,), move |(route,)| {
+        if matches!(route, Route::PageNotFound { .. }) {
+            return;
+        }
+        let path = route.to_string();
+        if ui_state.peek().last_route.as_deref() != Some(path.as_str()) {
+            ui_state.write().last_route = Some(path);
+        }
+    }));
+}
+
+/// A page's filter selection, seeded from the saved UI state under `page` and
+/// written back as it changes.
+pub fn use_persisted_filter<T>(page: &'static str, default: impl FnOnce() -> T) -> Signal<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    let mut ui_state = use_ui_state();
+    let filter = use_signal(|| {
+        ui_state
+            .peek()
+            .filters
+            .get(page)
+            .and_then(|saved| serde_json::from_value(saved.clone()).ok())
+            .unwrap_or_else(default)
+    });
+
+    use_effect(move || {
+        let Ok(value) = serde_json::to_value(&*filter.read()) else {
+            return;
+        };
+        if ui_state.peek().filters.get(page) != Some(&value) {
+            ui_state.write().filters.insert(page.to_string(), value);
+        }
+    });
+
+    filter
+}
+
+/// A split pane's divider position, seeded from the saved UI state under
+/// `pane` and written back as it moves.
+pub fn use_persisted_pane_ratio(pane: &'static str, default: f64) -> Signal<f64> {
+    let mut ui_state = use_ui_state();
+    let ratio = use_signal(|| ui_state.peek().panes.get(pane).copied().unwrap_or(default));
+
+    use_effect(move || {
+        let value = ratio();
+        if ui_state.peek().panes.get(pane) != Some(&value) {
+            ui_state.write().panes.insert(pane.to_string(), value);
+        }
+    });
+
+    ratio
+}
@@ -0,0 +1,18 @@
+use crate::queries::use_settings_query;
+use dioxus::prelude::*;
+use s_e_e_core::apply_workflow_limits;
+
+/// Apply the saved workflow size limits to validation and parsing app-wide.
+pub fn use_workflow_limits() {
+    let (settings_state, _refetch) = use_settings_query();
+
+    let loaded = settings_state
+        .data
+        .as_ref()
+        .map(|settings| settings.workflow_limits.clone());
+    use_effect(use_reactive((&loaded,), move |(loaded,)| {
+        if let Some(limits) = loaded {
+            apply_workflow_limits(&limits);
+        }
+    }));
+}
@@ -1,5 +1,6 @@
 pub mod app;
 pub mod hooks;
+pub mod notification_center;
 pub mod router;
 pub mod sidebar;
 
@@ -0,0 +1,145 @@
+use super::router::Route;
+use crate::components::Timestamp;
+use crate::icons::Icon;
+use crate::queries::{use_mark_notifications_read_mutation, use_notifications_query};
+use dioxus::prelude::*;
+use dioxus_router::prelude::use_navigator;
+use s_e_e_core::{Notification, NotificationKind};
+
+/// Page a notification opens.
+fn notification_route(notification: &Notification) -> Route {
+    match &notification.execution_id {
+        Some(id) => Route::WorkflowDetailsPage { id: id.clone() },
+        None => Route::ExecutionListPage {},
+    }
+}
+
+fn kind_dot_class(kind: NotificationKind) -> &'static str {
+    match kind {
+        NotificationKind::ExecutionCompleted => "bg-emerald-500",
+        NotificationKind::ExecutionFailed | NotificationKind::TriggeredRunFailed => "bg-red-500",
+        NotificationKind::InputRequested => "bg-amber-500",
+        NotificationKind::TaskAlert => "bg-blue-500",
+    }
+}
+
+/// Bell button for the sidebar with an unread count and a panel listing recent
+/// notifications. Opening one marks it read and follows its link.
+#[component]
+pub fn NotificationCenter(collapsed: bool) -> Element {
+    let (notifications_state, _refetch) = use_notifications_query();
+    let (_mark_state, mark_read) = use_mark_notifications_read_mutation();
+    let mark_read = std::rc::Rc::new(mark_read);
+    let navigator = use_navigator();
+    let mut open = use_signal(|| false);
+
+    let notifications = notifications_state.data.clone().unwrap_or_default();
+    let unread = notifications.iter().filter(|n| !n.read).count();
+    let label = match unread {
+        0 => "Notifications".to_string(),
+        n => format!("Notifications, {} unread", n),
+    };
+
+    rsx! {
+        div { class: "relative",
+            button {
+                r#type: "button",
+                class: "relative flex w-full items-center gap-2 rounded-lg px-2 py-2 text-left text-sm font-medium text-zinc-900 hover:bg-zinc-950/5 dark:text-white dark:hover:bg-white/5 focus-visible:outline focus-visible:outline-2 focus-visible:outline-offset-2 focus-visible:outline-blue-600 dark:focus-visible:outline-blue-400",
+                "aria-label": "{label}",
+                "aria-expanded": "{open()}",
+                "aria-haspopup": "dialog",
+                onclick: move |_| open.set(!open()),
+                Icon {
+                    name: "bell".to_string(),
+                    class: Some("shrink-0 text-zinc-500 dark:text-zinc-400".to_string()),
+                    size: Some("w-4 h-4".to_string()),
+                    variant: Some("outline".to_string()),
+                }
+                if !collapsed {
+                    span { class: "truncate", "Notifications" }
+                }
+                if unread > 0 {
+                    span {
+                        class: if collapsed { "absolute right-0.5 top-0.5 min-w-4 rounded-full bg-red-600 px-1 text-center text-[10px] font-semibold text-white" } else { "ml-auto min-w-5 rounded-full bg-red-600 px-1.5 text-center text-xs font-semibold text-white" },
+                        "aria-hidden": "true",
+                        if unread > 99 { "99+" } else { "{unread}" }
+                    }
+                }
+            }
+
+            if open() {
+                div {
+                    class: "fixed inset-0 z-40",
+                    "aria-hidden": "true",
+                    onclick: move |_| open.set(false),
+                }
+                div {
+                    role: "dialog",
+                    "aria-label": "Notifications",
+                    class: "absolute bottom-0 left-full z-50 ml-2 w-80 rounded-lg bg-white shadow-lg ring-1 ring-zinc-950/10 dark:bg-zinc-800 dark:ring-white/10",
+                    onkeydown: move |evt: KeyboardEvent| {
+                        if evt.key() == Key::Escape {
+                            open.set(false);
+                        }
+                    },
+                    div { class: "flex items-center justify-between border-b border-zinc-950/5 px-4 py-3 dark:border-white/5",
+                        h2 { class: "text-sm font-semibold text-zinc-900 dark:text-white", "Notifications" }
+                        if unread > 0 {
+                            button {
+                                r#type: "button",
+                                class: "text-xs font-medium text-blue-600 hover:text-blue-500 dark:text-blue-400",
+                                onclick: {
+                                    let mark_read = mark_read.clone();
+                                    move |_| mark_read(None)
+                                },
+                                "Mark all read"
+                            }
+                        }
+                    }
+                    if notifications.is_empty() {
+                        p { class: "px-4 py-6 text-center text-sm text-zinc-500 dark:text-zinc-400",
+                            "No notifications yet."
+                        }
+                    } else {
+                        ul { class: "max-h-96 divide-y divide-zinc-950/5 overflow-y-auto dark:divide-white/5",
+                            for notification in notifications.iter() {
+                                {
+                                    let target = notification_route(notification);
+                                    let id = notification.id.clone();
+                                    let was_read = notification.read;
+                                    let mark_read = mark_read.clone();
+                                    rsx! {
+                                        li { key: "{notification.id}",
+                                            button {
+                                                r#type: "button",
+                                                class: "flex w-full items-start gap-3 px-4 py-3 text-left hover:bg-zinc-50 dark:hover:bg-zinc-700/50",
+                                                onclick: move |_| {
+                                                    if !was_read {
+                                                        mark_read(Some(id.clone()));
+                                                    }
+                                                    open.set(false);
+                                                    navigator.push(target.clone());
+                                                },
+                                                span { class: format!("mt-1.5 size-2 shrink-0 rounded-full {}", kind_dot_class(notification.kind)) }
+                                                div { class: "min-w-0 flex-1",
+                                                    p { class: if notification.read { "truncate text-sm text-zinc-600 dark:text-zinc-300" } else { "truncate text-sm font-semibold text-zinc-900 dark:text-white" },
+                                                        "{notification.title}"
+                                                    }
+                                                    p { class: "text-xs text-zinc-500 dark:text-zinc-400", "{notification.message}" }
+                                                    Timestamp {
+                                                        value: notification.created_at,
+                                                        class: Some("text-xs text-zinc-400 dark:text-zinc-500".to_string()),
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
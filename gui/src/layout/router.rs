@@ -1,12 +1,13 @@
-use crate::components::{IconButton, IconButtonSize, IconButtonVariant};
+use crate::components::{IconButton, IconButtonSize, IconButtonVariant, QueryDevtools};
 use crate::pages::{
-    ExecutionListPage, HomePage, SettingsPage, UserPromptEditPage, UserPromptEditPageNew,
+    ExecutionCalendarPage, ExecutionDayPage, ExecutionListPage, HomePage, SettingsPage, UserPromptEditPage, UserPromptEditPageNew,
     UserPromptsListPage, WorkflowDetailsPage, WorkflowDetailsTaskDetailsPage, WorkflowEditPage,
     WorkflowEditPageNew, WorkflowJsonEditPage, WorkflowsListPage,
 };
 use dioxus::prelude::*;
 use dioxus_router::prelude::{Link, Outlet, Routable};
 
+use super::hooks::{use_remember_route, use_ui_state};
 use super::sidebar::Sidebar;
 
 #[derive(Routable, Clone, PartialEq)]
@@ -25,6 +26,10 @@ pub enum Route {
         WorkflowJsonEditPage { id: String },
         #[route("/executions/list")]
         ExecutionListPage {},
+        #[route("/executions/calendar")]
+        ExecutionCalendarPage {},
+        #[route("/executions/calendar/:date")]
+        ExecutionDayPage { date: String },
         #[route("/executions/details/:id")]
         WorkflowDetailsPage { id: String },
         #[route("/executions/details/:execution_id/task/:task_id")]
@@ -45,11 +50,20 @@ pub enum Route {
 #[component]
 pub fn AppLayout() -> Element {
     let mut show_sidebar = use_signal(|| false);
+    let mut ui_state = use_ui_state();
+    let collapsed = ui_state.read().sidebar_collapsed;
+    use_remember_route();
 
     rsx! {
         div { class: "relative isolate flex h-screen w-full bg-white max-sm:flex-col sm:bg-zinc-100 dark:bg-zinc-900 dark:sm:bg-zinc-950",
-            div { class: "fixed inset-y-0 left-0 w-48 max-sm:hidden",
-                Sidebar {}
+            div { class: if collapsed { "fixed inset-y-0 left-0 w-14 max-sm:hidden" } else { "fixed inset-y-0 left-0 w-48 max-sm:hidden" },
+                Sidebar {
+                    collapsed,
+                    on_toggle: move |_| {
+                        let mut state = ui_state.write();
+                        state.sidebar_collapsed = !state.sidebar_collapsed;
+                    },
+                }
             }
 
             header { class: "flex items-center px-4 sm:hidden",
@@ -86,19 +100,23 @@ pub fn AppLayout() -> Element {
                                     ""
                                 }
                             }
-                            Sidebar {}
+                            Sidebar { collapsed: false }
                         }
                     }
                 }
             }
 
-            main { class: "flex flex-1 flex-col pb-2 sm:min-w-0 sm:pt-2 sm:pr-2 sm:pl-48 min-h-0",
+            main { class: if collapsed { "flex flex-1 flex-col pb-2 sm:min-w-0 sm:pt-2 sm:pr-2 sm:pl-14 min-h-0" } else { "flex flex-1 flex-col pb-2 sm:min-w-0 sm:pt-2 sm:pr-2 sm:pl-48 min-h-0" },
                 div { class: "flex-1 p-6 sm:rounded-lg sm:bg-white sm:p-10 sm:shadow-xs sm:ring-1 sm:ring-zinc-950/5 dark:sm:bg-zinc-900 dark:sm:ring-white/10 overflow-y-auto",
                     div { class: "mx-auto max-w-6xl",
                         Outlet::<Route> {}
                     }
                 }
             }
+
+            if cfg!(debug_assertions) {
+                QueryDevtools {}
+            }
         }
     }
 }
@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use dioxus_desktop::tao::dpi::{LogicalPosition, LogicalSize};
 use dioxus_desktop::{Config, WindowBuilder};
 
 mod components;
@@ -11,10 +12,12 @@ mod services {
     pub mod execution;
     pub mod prompt;
     pub mod settings;
+    pub mod ui_state;
     pub mod workflow;
 
     pub use database::clear_database;
     pub use settings::SettingsService;
+    pub use ui_state::UiStateService;
 }
 
 fn main() {
@@ -71,6 +74,15 @@ fn main() {
         eprintln!("Failed to initialize persistence layer: {}", e);
         std::process::exit(1);
     }
+    if let Err(e) = rt.block_on(s_e_e_core::archive_per_settings()) {
+        tracing::warn!("Failed to archive old executions: {}", e);
+    }
+    let ui_state = rt
+        .block_on(services::UiStateService::fetch_ui_state())
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to load UI state, starting fresh: {}", e);
+            s_e_e_core::UiState::default()
+        });
     drop(rt);
     tracing::debug!("Persistence layer initialized successfully");
 
@@ -82,13 +94,18 @@ fn main() {
     let icon = dioxus_desktop::tao::window::Icon::from_rgba(icon.into_raw(), width, height)
         .expect("Failed to create icon");
 
+    let mut window = WindowBuilder::new()
+        .with_title("Speculative Execution Engine")
+        .with_window_icon(Some(icon));
+    if let Some(geometry) = ui_state.window {
+        window = window
+            .with_inner_size(LogicalSize::new(geometry.width, geometry.height))
+            .with_position(LogicalPosition::new(geometry.x, geometry.y))
+            .with_maximized(geometry.maximized);
+    }
+
     LaunchBuilder::desktop()
-        .with_cfg(
-            Config::new().with_window(
-                WindowBuilder::new()
-                    .with_title("Speculative Execution Engine")
-                    .with_window_icon(Some(icon)),
-            ),
-        )
+        .with_cfg(Config::new().with_window(window))
+        .with_context(ui_state)
         .launch(layout::App);
 }
@@ -0,0 +1,131 @@
+use crate::layout::router::Route;
+use chrono::{Datelike, Duration, NaiveDate};
+use dioxus::prelude::*;
+use dioxus_router::prelude::use_navigator;
+use s_e_e_core::DailyExecutionStats;
+use std::collections::HashMap;
+
+/// Columns of Sunday-first weeks ending with the week that contains `end`.
+///
+/// Days after `end` in the final week are `None`.
+pub fn calendar_weeks(end: NaiveDate, weeks: usize) -> Vec<Vec<Option<NaiveDate>>> {
+    let offset = end.weekday().num_days_from_sunday() as i64;
+    let start = end - Duration::days(offset + 7 * (weeks.saturating_sub(1) as i64));
+
+    (0..weeks)
+        .map(|week| {
+            (0..7)
+                .map(|day| {
+                    let date = start + Duration::days((week * 7 + day) as i64);
+                    (date <= end).then_some(date)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[component]
+pub fn ExecutionHeatmap(stats: Vec<DailyExecutionStats>, end: NaiveDate, weeks: usize) -> Element {
+    let by_day: HashMap<NaiveDate, DailyExecutionStats> =
+        stats.into_iter().map(|s| (s.date, s)).collect();
+    let columns = calendar_weeks(end, weeks);
+
+    rsx! {
+        div { class: "space-y-3",
+            div { class: "overflow-x-auto",
+                div { class: "inline-flex flex-col gap-1",
+                    div { class: "flex gap-1 h-4",
+                        for (index, week) in columns.iter().enumerate() {
+                            span {
+                                key: "{index}",
+                                class: "w-3 text-[10px] leading-4 text-zinc-500 dark:text-zinc-400 overflow-visible whitespace-nowrap",
+                                {month_label(week)}
+                            }
+                        }
+                    }
+                    div { class: "flex gap-1",
+                        for (index, week) in columns.into_iter().enumerate() {
+                            div { key: "{index}", class: "flex flex-col gap-1",
+                                for (day_index, day) in week.into_iter().enumerate() {
+                                    if let Some(date) = day {
+                                        DayCell {
+                                            key: "{date}",
+                                            date,
+                                            stats: by_day.get(&date).cloned(),
+                                        }
+                                    } else {
+                                        span { key: "empty-{day_index}", class: "w-3 h-3" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            HeatmapLegend {}
+        }
+    }
+}
+
+#[component]
+fn DayCell(date: NaiveDate, stats: Option<DailyExecutionStats>) -> Element {
+    let navigator = use_navigator();
+    let title = match &stats {
+        Some(s) => format!("{}: {} executions, {} failed", date, s.total, s.failed),
+        None => format!("{}: no executions", date),
+    };
+    let color = cell_color(stats.as_ref());
+
+    rsx! {
+        button {
+            class: "w-3 h-3 rounded-sm {color} disabled:cursor-default enabled:hover:ring-2 enabled:hover:ring-zinc-400 dark:enabled:hover:ring-zinc-500",
+            title: "{title}",
+            disabled: stats.is_none(),
+            onclick: move |_| {
+                navigator.push(Route::ExecutionDayPage { date: date.to_string() });
+            },
+        }
+    }
+}
+
+#[component]
+fn HeatmapLegend() -> Element {
+    let entries = [
+        ("bg-zinc-100 dark:bg-zinc-800", "No runs"),
+        ("bg-emerald-500", "All passed"),
+        ("bg-amber-300 dark:bg-amber-400", "Under 25% failed"),
+        ("bg-orange-400", "Under 50% failed"),
+        ("bg-red-500", "50% or more failed"),
+    ];
+
+    rsx! {
+        div { class: "flex flex-wrap items-center gap-4 text-xs text-zinc-500 dark:text-zinc-400",
+            for (color, label) in entries {
+                div { key: "{label}", class: "flex items-center gap-1.5",
+                    span { class: "w-3 h-3 rounded-sm {color}" }
+                    "{label}"
+                }
+            }
+        }
+    }
+}
+
+fn cell_color(stats: Option<&DailyExecutionStats>) -> &'static str {
+    let Some(stats) = stats else {
+        return "bg-zinc-100 dark:bg-zinc-800";
+    };
+    match stats.failure_rate() {
+        rate if rate == 0.0 => "bg-emerald-500",
+        rate if rate < 0.25 => "bg-amber-300 dark:bg-amber-400",
+        rate if rate < 0.5 => "bg-orange-400",
+        _ => "bg-red-500",
+    }
+}
+
+/// Month name above the first week that starts in a new month.
+fn month_label(week: &[Option<NaiveDate>]) -> String {
+    match week.first().copied().flatten() {
+        Some(first) if first.day() <= 7 => first.format("%b").to_string(),
+        _ => String::new(),
+    }
+}
@@ -0,0 +1,3 @@
+pub mod heatmap;
+
+pub use heatmap::{calendar_weeks, ExecutionHeatmap};
@@ -0,0 +1,62 @@
+use crate::components::{Alert, AlertType, EmptyState, List, PageHeader, SectionCard};
+use crate::icons::Icon;
+use crate::layout::router::Route;
+use crate::pages::executions::list::components::ExecutionItem;
+use crate::queries::use_executions_on_day_query;
+use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
+
+#[component]
+pub fn ExecutionDayPage(date: String) -> Element {
+    let (day_state, _) = use_executions_on_day_query(date.clone());
+    let executions = day_state.data.clone().unwrap_or_default();
+
+    rsx! {
+        div { class: "space-y-8",
+            PageHeader {
+                title: format!("Executions on {}", date),
+                description: "All executions started on this day (UTC)".to_string(),
+                actions: Some(rsx! {
+                    Link {
+                        to: Route::ExecutionCalendarPage {},
+                        class: "inline-flex items-center gap-x-1.5 rounded-md bg-zinc-100 dark:bg-zinc-800 px-3 py-2 text-sm font-semibold text-zinc-900 dark:text-zinc-100 shadow-sm hover:bg-zinc-200 dark:hover:bg-zinc-700",
+                        Icon {
+                            name: "arrow_left".to_string(),
+                            class: Some("-ml-0.5 h-4 w-4".to_string()),
+                            size: None,
+                            variant: Some("outline".to_string()),
+                        }
+                        "Calendar"
+                    }
+                }),
+            }
+
+            if day_state.is_error {
+                Alert {
+                    alert_type: AlertType::Error,
+                    title: Some("Failed to load executions".to_string()),
+                    message: day_state.error.clone().unwrap_or_default(),
+                    dismissible: None,
+                    on_dismiss: None,
+                    actions: None,
+                }
+            } else {
+                SectionCard {
+                    title: Some(format!("{} executions", executions.len())),
+                    children: rsx! {
+                        if executions.is_empty() {
+                            EmptyState { message: "No executions on this day.".to_string() }
+                        } else {
+                            List {
+                                for execution in executions.iter() {
+                                    ExecutionItem { key: "{execution.id}", execution: execution.clone() }
+                                }
+                            }
+                        }
+                    },
+                    padding: None,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod components;
+pub mod day_page;
+pub mod page;
+
+pub use day_page::ExecutionDayPage;
+pub use page::ExecutionCalendarPage;
@@ -0,0 +1,63 @@
+use crate::components::{Alert, AlertType, PageHeader, SectionCard};
+use crate::icons::Icon;
+use crate::layout::router::Route;
+use crate::queries::use_execution_calendar_query;
+use chrono::Utc;
+use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
+
+use super::components::{calendar_weeks, ExecutionHeatmap};
+
+const WEEKS: usize = 53;
+
+#[component]
+pub fn ExecutionCalendarPage() -> Element {
+    let today = Utc::now().date_naive();
+    let since = calendar_weeks(today, WEEKS)[0][0].unwrap_or(today);
+    let (calendar_state, _) = use_execution_calendar_query(since);
+
+    let stats = calendar_state.data.clone().unwrap_or_default();
+    let total: usize = stats.iter().map(|s| s.total).sum();
+    let failed: usize = stats.iter().map(|s| s.failed).sum();
+
+    rsx! {
+        div { class: "space-y-8",
+            PageHeader {
+                title: "Execution Calendar".to_string(),
+                description: "Executions per day, colored by failure rate. Click a day to see its runs.".to_string(),
+                actions: Some(rsx! {
+                    Link {
+                        to: Route::ExecutionListPage {},
+                        class: "inline-flex items-center gap-x-1.5 rounded-md bg-zinc-100 dark:bg-zinc-800 px-3 py-2 text-sm font-semibold text-zinc-900 dark:text-zinc-100 shadow-sm hover:bg-zinc-200 dark:hover:bg-zinc-700",
+                        Icon {
+                            name: "arrow_left".to_string(),
+                            class: Some("-ml-0.5 h-4 w-4".to_string()),
+                            size: None,
+                            variant: Some("outline".to_string()),
+                        }
+                        "Executions"
+                    }
+                }),
+            }
+
+            if calendar_state.is_error {
+                Alert {
+                    alert_type: AlertType::Error,
+                    title: Some("Failed to load execution calendar".to_string()),
+                    message: calendar_state.error.clone().unwrap_or_default(),
+                    dismissible: None,
+                    on_dismiss: None,
+                    actions: None,
+                }
+            } else {
+                SectionCard {
+                    title: Some(format!("{} executions, {} failed, in the last year", total, failed)),
+                    children: rsx! {
+                        ExecutionHeatmap { stats, end: today, weeks: WEEKS }
+                    },
+                    padding: None,
+                }
+            }
+        }
+    }
+}
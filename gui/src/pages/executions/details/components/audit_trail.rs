@@ -0,0 +1,122 @@
+use crate::components::{Badge, BadgeColor, EmptyState, SectionCard, Timestamp};
+use crate::icons::Icon;
+use dioxus::prelude::*;
+use s_e_e_core::{AuditChange, AuditChangeOp, AuditEvent, AuditStatus};
+
+#[component]
+pub fn AuditTrail(events: Vec<AuditEvent>) -> Element {
+    rsx! {
+        SectionCard {
+            title: Some("Audit Trail".to_string()),
+            children: rsx! {
+                if events.is_empty() {
+                    EmptyState {
+                        message: "No audit events recorded".to_string(),
+                    }
+                } else {
+                    div { class: "divide-y divide-zinc-200 dark:divide-zinc-700",
+                        for event in events.iter() {
+                            AuditTrailItem { key: "{event.id}", event: event.clone() }
+                        }
+                    }
+                }
+            },
+            padding: None,
+        }
+    }
+}
+
+#[component]
+fn AuditTrailItem(event: AuditEvent) -> Element {
+    let mut expanded = use_signal(|| false);
+    let has_changes = !event.changes.is_empty();
+
+    let status_color = match event.status {
+        AuditStatus::Success => BadgeColor::Emerald,
+        AuditStatus::Failure => BadgeColor::Red,
+    };
+    let chevron_class = if expanded() {
+        "rotate-90 transition-transform"
+    } else {
+        "transition-transform"
+    };
+
+    rsx! {
+        div { class: "py-3",
+            button {
+                class: "flex w-full items-center gap-3 text-left disabled:cursor-default",
+                disabled: !has_changes,
+                onclick: move |_| expanded.toggle(),
+                if has_changes {
+                    Icon {
+                        name: "chevron_right".to_string(),
+                        class: Some(chevron_class.to_string()),
+                        size: Some("w-4 h-4".to_string()),
+                        variant: Some("outline".to_string()),
+                    }
+                } else {
+                    span { class: "w-4 h-4" }
+                }
+                Badge { color: status_color, "{event.status}" }
+                div { class: "flex-1 min-w-0",
+                    p { class: "text-sm text-zinc-900 dark:text-white truncate", "{event.message}" }
+                    p { class: "text-xs text-zinc-500 dark:text-zinc-400",
+                        "{event.task_id} · "
+                        Timestamp { value: event.timestamp, absolute: true }
+                    }
+                }
+                span { class: "text-xs text-zinc-500 dark:text-zinc-400 whitespace-nowrap",
+                    "{event.changes_count} changes"
+                }
+            }
+
+            if expanded() {
+                div { class: "mt-3 ml-7 space-y-2",
+                    for (index, change) in event.changes.iter().enumerate() {
+                        ChangeRow { key: "{index}", change: change.clone() }
+                    }
+                    if event.changes_truncated {
+                        p { class: "text-xs text-amber-600 dark:text-amber-400",
+                            "Diff truncated: {event.changes_count} changes in total, large values omitted"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ChangeRow(change: AuditChange) -> Element {
+    let (color, sign) = match change.op {
+        AuditChangeOp::Add => (BadgeColor::Emerald, "+"),
+        AuditChangeOp::Remove => (BadgeColor::Red, "-"),
+        AuditChangeOp::Replace => (BadgeColor::Amber, "~"),
+    };
+    let op = change.op.as_str();
+    let before = change.before.as_ref().map(format_value);
+    let after = change.after.as_ref().map(format_value);
+
+    rsx! {
+        div { class: "rounded-lg border border-zinc-200 dark:border-zinc-700 overflow-hidden",
+            div { class: "flex items-center gap-2 px-3 py-1.5 bg-zinc-50 dark:bg-zinc-800",
+                Badge { color: color, "{sign} {op}" }
+                code { class: "text-xs font-mono text-zinc-700 dark:text-zinc-300", "{change.path}" }
+            }
+            if let Some(before) = before {
+                pre { class: "px-3 py-1 text-xs font-mono whitespace-pre-wrap bg-red-50 text-red-800 dark:bg-red-500/10 dark:text-red-300",
+                    "- {before}"
+                }
+            }
+            if let Some(after) = after {
+                pre { class: "px-3 py-1 text-xs font-mono whitespace-pre-wrap bg-emerald-50 text-emerald-800 dark:bg-emerald-500/10 dark:text-emerald-300",
+                    "+ {after}"
+                }
+            }
+        }
+    }
+}
+
+fn format_value(value: &serde_json::Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string())
+}
@@ -0,0 +1,104 @@
+use crate::components::{Badge, BadgeColor, EmptyState, SectionCard};
+use crate::queries::use_context_at_query;
+use dioxus::prelude::*;
+use s_e_e_core::{context_steps, AuditEvent, TaskExecution};
+
+/// Steps through the run task by task, showing the context each one started
+/// with.
+#[component]
+pub fn ContextTimeline(
+    execution_id: String,
+    audit_trail: Vec<AuditEvent>,
+    tasks: Vec<TaskExecution>,
+) -> Element {
+    let steps = context_steps(&audit_trail);
+    let mut position = use_signal(|| 0usize);
+
+    rsx! {
+        SectionCard {
+            title: Some("Context Over Time".to_string()),
+            children: rsx! {
+                if steps.is_empty() {
+                    EmptyState {
+                        message: "No task has finished yet".to_string(),
+                    }
+                } else {
+                    {
+                        let index = position().min(steps.len() - 1);
+                        let task_id = steps[index].clone();
+                        let task_name = tasks
+                            .iter()
+                            .find(|task| task.id == task_id)
+                            .map(|task| task.name.clone())
+                            .unwrap_or_else(|| task_id.clone());
+                        let last = steps.len() - 1;
+                        rsx! {
+                            div { class: "space-y-4",
+                                div { class: "flex items-center gap-3",
+                                    input {
+                                        r#type: "range",
+                                        min: "0",
+                                        max: "{last}",
+                                        step: "1",
+                                        value: "{index}",
+                                        disabled: last == 0,
+                                        "aria-label": "Task",
+                                        "aria-valuetext": "{task_name}",
+                                        oninput: move |evt| {
+                                            if let Ok(value) = evt.value().parse::<usize>() {
+                                                position.set(value);
+                                            }
+                                        },
+                                        class: "flex-1 accent-blue-600",
+                                    }
+                                    span { class: "text-xs text-zinc-500 dark:text-zinc-400 whitespace-nowrap",
+                                        "{index + 1} of {steps.len()}"
+                                    }
+                                }
+                                TaskContextView {
+                                    key: "{task_id}",
+                                    execution_id: execution_id.clone(),
+                                    task_id: task_id.clone(),
+                                    task_name,
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            padding: Some("p-6".to_string()),
+        }
+    }
+}
+
+#[component]
+fn TaskContextView(execution_id: String, task_id: String, task_name: String) -> Element {
+    let (context_state, _refetch) = use_context_at_query(execution_id, task_id.clone());
+
+    rsx! {
+        div { class: "space-y-3",
+            div { class: "flex flex-wrap items-center gap-2",
+                p { class: "text-sm text-zinc-900 dark:text-white",
+                    "Before "
+                    span { class: "font-medium", "{task_name}" }
+                    " started"
+                }
+                if let Some(context) = context_state.data.as_ref() {
+                    Badge { color: BadgeColor::Zinc, "then changed {context.changes_count} paths" }
+                    if context.incomplete {
+                        Badge { color: BadgeColor::Amber, "Partial: an earlier diff was truncated" }
+                    }
+                }
+            }
+            if let Some(error) = context_state.error.clone() {
+                p { class: "text-sm text-red-600 dark:text-red-400", "{error}" }
+            } else if let Some(context) = context_state.data.as_ref() {
+                pre { class: "max-h-96 overflow-auto rounded-lg bg-zinc-50 p-4 text-xs font-mono text-zinc-800 dark:bg-zinc-900 dark:text-zinc-200",
+                    {serde_json::to_string_pretty(&context.data).unwrap_or_default()}
+                }
+            } else {
+                p { class: "text-sm text-zinc-500 dark:text-zinc-400", "Rebuilding context..." }
+            }
+        }
+    }
+}
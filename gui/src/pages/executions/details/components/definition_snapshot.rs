@@ -0,0 +1,83 @@
+use crate::components::{
+    Alert, AlertType, Badge, BadgeColor, IconButton, IconButtonSize, IconButtonVariant, SectionCard,
+};
+use crate::layout::router::Route;
+use crate::queries::{use_save_snapshot_mutation, use_snapshot_status_query};
+use dioxus::prelude::*;
+use dioxus_router::prelude::use_navigator;
+use s_e_e_core::SnapshotStatus;
+use serde_json::Value;
+
+/// The workflow JSON as it was when the execution ran, with a way to save it as
+/// a new workflow after the original has been edited.
+#[component]
+pub fn DefinitionSnapshot(execution_id: String, snapshot: Value) -> Element {
+    let navigator = use_navigator();
+    let mut expanded = use_signal(|| false);
+    let (status_state, _refetch) = use_snapshot_status_query(execution_id.clone());
+    let (save_state, save_fn) = use_save_snapshot_mutation();
+
+    use_effect(move || {
+        if let Some(workflow) = save_state.read().data.clone() {
+            navigator.push(Route::WorkflowEditPage { id: workflow.id });
+        }
+    });
+
+    let status = match status_state.data {
+        Some(SnapshotStatus::Current) => Some((BadgeColor::Emerald, "Unchanged since this run")),
+        Some(SnapshotStatus::Edited) => Some((BadgeColor::Amber, "Edited since this run")),
+        Some(SnapshotStatus::Deleted) => Some((BadgeColor::Red, "Workflow deleted")),
+        Some(SnapshotStatus::Unknown) | None => None,
+    };
+    let json = serde_json::to_string_pretty(&snapshot).unwrap_or_default();
+    let saving = save_state.read().is_loading;
+
+    rsx! {
+        SectionCard {
+            title: Some("Definition at Time of Run".to_string()),
+            children: rsx! {
+                div { class: "space-y-4",
+                    div { class: "flex flex-wrap items-center gap-3",
+                        if let Some((color, text)) = status {
+                            Badge { color, {text} }
+                        }
+                        div { class: "flex-1" }
+                        IconButton {
+                            variant: IconButtonVariant::Secondary,
+                            size: IconButtonSize::Small,
+                            onclick: move |_| expanded.toggle(),
+                            icon: Some("code_bracket".to_string()),
+                            icon_variant: "outline".to_string(),
+                            if expanded() { "Hide JSON" } else { "Show JSON" }
+                        }
+                        IconButton {
+                            variant: IconButtonVariant::Primary,
+                            size: IconButtonSize::Small,
+                            loading: Some(saving),
+                            onclick: move |_| save_fn(execution_id.clone()),
+                            icon: Some("save".to_string()),
+                            icon_variant: "outline".to_string(),
+                            "Save this version as new workflow"
+                        }
+                    }
+                    if let Some(error) = save_state.read().error.clone() {
+                        Alert {
+                            alert_type: AlertType::Error,
+                            title: Some("Could not save workflow".to_string()),
+                            message: error,
+                            dismissible: None,
+                            on_dismiss: None,
+                            actions: None,
+                        }
+                    }
+                    if expanded() {
+                        pre { class: "max-h-96 overflow-auto rounded-lg bg-zinc-50 p-4 text-xs font-mono text-zinc-800 dark:bg-zinc-900 dark:text-zinc-200",
+                            "{json}"
+                        }
+                    }
+                }
+            },
+            padding: Some("p-6".to_string()),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::components::{Badge, BadgeColor, IconButton, IconButtonSize, IconButtonVariant};
+use crate::queries::use_set_execution_labels_mutation;
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+/// The execution's labels, with a field to add one and a button on each to
+/// remove it.
+#[component]
+pub fn ExecutionLabels(execution_id: String, labels: Vec<String>) -> Element {
+    let (set_state, set_labels) = use_set_execution_labels_mutation();
+    let set_labels = Rc::new(set_labels);
+    let mut new_label = use_signal(String::new);
+
+    let add_labels = labels.clone();
+    let add_id = execution_id.clone();
+    let add = {
+        let set_labels = Rc::clone(&set_labels);
+        move || {
+            let label = new_label().trim().to_string();
+            if label.is_empty() {
+                return;
+            }
+            let mut labels = add_labels.clone();
+            labels.push(label);
+            set_labels((add_id.clone(), labels));
+            new_label.set(String::new());
+        }
+    };
+    let mut add_on_enter = add.clone();
+    let mut add_on_click = add;
+
+    rsx! {
+        div { class: "flex flex-wrap items-center gap-2",
+            for label in labels.iter().cloned() {
+                {
+                    let remaining: Vec<String> = labels.iter().filter(|l| **l != label).cloned().collect();
+                    let execution_id = execution_id.clone();
+                    let set_labels = Rc::clone(&set_labels);
+                    rsx! {
+                        span { key: "{label}", class: "inline-flex items-center gap-1",
+                            Badge { color: BadgeColor::Blue, "{label}" }
+                            IconButton {
+                                variant: IconButtonVariant::Ghost,
+                                size: IconButtonSize::Small,
+                                onclick: move |_| set_labels((execution_id.clone(), remaining.clone())),
+                                icon: Some("x".to_string()),
+                                icon_variant: "outline".to_string(),
+                                ""
+                            }
+                        }
+                    }
+                }
+            }
+            input {
+                r#type: "text",
+                placeholder: "Add label",
+                "aria-label": "Add label",
+                value: new_label(),
+                oninput: move |evt| new_label.set(evt.value()),
+                onkeydown: move |evt| {
+                    if evt.key() == Key::Enter {
+                        add_on_enter();
+                    }
+                },
+                class: "rounded-lg border border-zinc-300 dark:border-zinc-600 bg-white dark:bg-zinc-800 px-2 py-1 text-sm text-zinc-950 dark:text-white"
+            }
+            IconButton {
+                variant: IconButtonVariant::Secondary,
+                size: IconButtonSize::Small,
+                disabled: Some(new_label().trim().is_empty()),
+                onclick: move |_| add_on_click(),
+                icon: Some("plus".to_string()),
+                icon_variant: "outline".to_string(),
+                "Label"
+            }
+            if let Some(error) = set_state.read().error.clone() {
+                span { class: "text-sm text-red-600 dark:text-red-400", "{error}" }
+            }
+        }
+    }
+}
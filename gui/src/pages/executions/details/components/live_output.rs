@@ -0,0 +1,89 @@
+use crate::components::{SectionCard, TaskLogs};
+use crate::queries::use_live_output_query;
+use dioxus::prelude::*;
+use s_e_e_core::TaskProgress;
+
+/// Output streamed while the execution runs, one collapsible section per task.
+///
+/// Only executions started from this window have a live stream. Others show
+/// the output journaled for a run that stopped before saving its task logs,
+/// and nothing once it has.
+#[component]
+pub fn LiveOutput(execution_id: String) -> Element {
+    let (output_state, _refetch) = use_live_output_query(execution_id);
+    let output = output_state.data.clone().unwrap_or_default();
+    let groups = output.groups;
+
+    if groups.is_empty() {
+        return rsx! {};
+    }
+
+    let title = if output.from_journal {
+        "Saved Output"
+    } else {
+        "Live Output"
+    };
+
+    rsx! {
+        SectionCard {
+            title: Some(title.to_string()),
+            padding: Some("p-4".to_string()),
+            children: rsx! {
+                if output.from_journal {
+                    p { class: "mb-3 text-sm text-zinc-500 dark:text-zinc-400",
+                        "The run stopped before it saved its task logs. This is the output written up to then."
+                    }
+                }
+                div { class: "space-y-3",
+                    for group in groups {
+                        details {
+                            key: "{group.task_id.clone().unwrap_or_default()}",
+                            class: "group rounded-lg border border-zinc-200 dark:border-zinc-700",
+                            open: true,
+                            summary { class: "flex cursor-pointer items-center justify-between px-4 py-2 text-sm font-medium text-zinc-900 dark:text-zinc-100",
+                                span { "{group.label}" }
+                                span { class: "text-xs text-zinc-500 dark:text-zinc-400",
+                                    "{group.lines.len()} lines"
+                                }
+                            }
+                            if let Some(progress) = &group.progress {
+                                TaskProgressBar { progress: progress.clone() }
+                            }
+                            div { class: "border-t border-zinc-200 dark:border-zinc-700 p-2",
+                                TaskLogs { output: group.lines.join("\n") }
+                            }
+                        }
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// How far along a task says it is. Without a percentage the bar pulses.
+#[component]
+fn TaskProgressBar(progress: TaskProgress) -> Element {
+    let (width, pulse) = match progress.percent {
+        Some(percent) => (format!("{}%", percent), ""),
+        None => ("100%".to_string(), " animate-pulse"),
+    };
+
+    rsx! {
+        div { class: "px-4 pb-2 space-y-1",
+            div {
+                class: "h-1.5 w-full overflow-hidden rounded-full bg-zinc-200 dark:bg-zinc-700",
+                role: "progressbar",
+                "aria-valuemin": "0",
+                "aria-valuemax": "100",
+                "aria-valuenow": progress.percent.map(|p| p.to_string()).unwrap_or_default(),
+                div { class: "h-full rounded-full bg-blue-600 transition-all{pulse}", style: "width: {width}" }
+            }
+            div { class: "flex justify-between text-xs text-zinc-500 dark:text-zinc-400",
+                span { class: "truncate", {progress.status.clone().unwrap_or_default()} }
+                if let Some(percent) = progress.percent {
+                    span { "{percent}%" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::components::{IconButton, IconButtonSize, IconButtonVariant, SectionCard};
+use dioxus::prelude::*;
+use s_e_e_core::ReproducibilityManifest;
+
+/// Platform, versions, workflow hash and parameters recorded when the run
+/// started, with a button to copy them all as JSON.
+#[component]
+pub fn ReproducibilityManifestView(manifest: ReproducibilityManifest) -> Element {
+    let mut copied = use_signal(|| false);
+    let json = serde_json::to_string_pretty(&manifest).unwrap_or_default();
+    let platform = format!("{} / {}", manifest.os, manifest.arch);
+
+    rsx! {
+        SectionCard {
+            title: Some("Environment".to_string()),
+            children: rsx! {
+                div { class: "space-y-4",
+                    div { class: "flex items-center justify-between gap-3",
+                        p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                            "What this run used, for reproducing it later."
+                        }
+                        IconButton {
+                            variant: IconButtonVariant::Secondary,
+                            size: IconButtonSize::Small,
+                            onclick: move |_| {
+                                let text = serde_json::to_string(&json).unwrap_or_default();
+                                document::eval(&format!("navigator.clipboard.writeText({});", text));
+                                copied.set(true);
+                            },
+                            icon: Some("copy".to_string()),
+                            icon_variant: "outline".to_string(),
+                            if copied() { "Copied" } else { "Copy JSON" }
+                        }
+                    }
+                    dl { class: "grid grid-cols-[auto_1fr] gap-x-6 gap-y-2 text-sm",
+                        ManifestRow { label: "App version", value: manifest.app_version.clone() }
+                        ManifestRow { label: "Platform", value: platform }
+                        ManifestRow { label: "Workflow hash", value: manifest.workflow_hash.clone() }
+                        for (name, version) in manifest.handlers.iter() {
+                            ManifestRow {
+                                key: "handler-{name}",
+                                label: format!("Handler {}", name),
+                                value: version.clone(),
+                            }
+                        }
+                        for (tool, version) in manifest.tools.iter() {
+                            ManifestRow {
+                                key: "tool-{tool}",
+                                label: tool.clone(),
+                                value: version.clone(),
+                            }
+                        }
+                        for (name, value) in manifest.parameters.iter() {
+                            ManifestRow {
+                                key: "param-{name}",
+                                label: format!("Parameter {}", name),
+                                value: value.to_string(),
+                            }
+                        }
+                    }
+                }
+            },
+            padding: Some("p-6".to_string()),
+        }
+    }
+}
+
+#[component]
+fn ManifestRow(label: String, value: String) -> Element {
+    rsx! {
+        dt { class: "font-medium text-zinc-900 dark:text-white", "{label}" }
+        dd { class: "font-mono text-zinc-700 dark:text-zinc-300 break-all", "{value}" }
+    }
+}
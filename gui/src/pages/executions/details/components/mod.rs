@@ -1,5 +1,24 @@
+pub mod audit_trail;
+pub mod context_timeline;
+pub mod definition_snapshot;
+pub mod labels;
+pub mod live_output;
+pub mod manifest;
+pub mod previous_run;
+pub mod provenance;
+pub mod stages;
 pub mod task_box;
 pub mod task_preprocessing;
+pub mod timing;
 pub mod workflow_flow;
 
+pub use audit_trail::AuditTrail;
+pub use context_timeline::ContextTimeline;
+pub use definition_snapshot::DefinitionSnapshot;
+pub use labels::ExecutionLabels;
+pub use live_output::LiveOutput;
+pub use manifest::ReproducibilityManifestView;
+pub use previous_run::PreviousRunComparison;
+pub use provenance::TriggeredByLink;
+pub use timing::ExecutionTiming;
 pub use workflow_flow::WorkflowFlowGraph;
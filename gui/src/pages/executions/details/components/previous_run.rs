@@ -0,0 +1,128 @@
+use super::timing::format_duration;
+use crate::components::{Badge, BadgeColor, EmptyState, SectionCard, Timestamp};
+use crate::layout::router::Route;
+use crate::queries::use_previous_run_comparison_query;
+use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
+use s_e_e_core::{ExecutionComparison, TaskComparison, TaskExecutionStatus};
+
+/// This execution set against the previous run of the same workflow, with
+/// newly failing and markedly slower tasks called out.
+#[component]
+pub fn PreviousRunComparison(execution_id: String) -> Element {
+    let (state, _refetch) = use_previous_run_comparison_query(execution_id);
+
+    rsx! {
+        SectionCard {
+            title: Some("Compared with Previous Run".to_string()),
+            children: rsx! {
+                if state.is_loading {
+                    p { class: "text-sm text-zinc-500 dark:text-zinc-400", "Comparing..." }
+                } else if let Some(error) = state.error.clone() {
+                    p { class: "text-sm text-red-600 dark:text-red-400", "{error}" }
+                } else if let Some(comparison) = state.data.clone().flatten() {
+                    ComparisonDetails { comparison }
+                } else {
+                    EmptyState {
+                        message: "This is the first run of this workflow".to_string(),
+                    }
+                }
+            },
+            padding: Some("p-6".to_string()),
+        }
+    }
+}
+
+#[component]
+fn ComparisonDetails(comparison: ExecutionComparison) -> Element {
+    let regressions = comparison.regressions().count();
+    let newly_failing = comparison.newly_failing().count();
+    let changed: Vec<TaskComparison> = comparison
+        .tasks
+        .iter()
+        .filter(|task| task.changed())
+        .cloned()
+        .collect();
+
+    rsx! {
+        div { class: "space-y-4",
+            div { class: "flex flex-wrap items-center gap-x-6 gap-y-1 text-sm text-zinc-600 dark:text-zinc-400",
+                span {
+                    "Previous run "
+                    Link {
+                        to: Route::WorkflowDetailsPage { id: comparison.previous_execution_id.clone() },
+                        class: "font-mono text-blue-600 hover:underline dark:text-blue-400",
+                        "{comparison.previous_execution_id}"
+                    }
+                    " started "
+                    Timestamp { value: comparison.previous_created_at }
+                }
+                span { "Status {comparison.previous_status} → {comparison.current_status}" }
+                span {
+                    "Duration {duration_text(comparison.previous_duration)} → {duration_text(comparison.current_duration)}"
+                }
+            }
+
+            if regressions > 0 {
+                div { class: "rounded-lg border border-red-200 bg-red-50 px-4 py-3 text-sm text-red-800 dark:border-red-800 dark:bg-red-900/20 dark:text-red-200",
+                    "{regressions} task(s) regressed since the previous run"
+                    if newly_failing > 0 {
+                        ", {newly_failing} newly failing"
+                    }
+                }
+            }
+
+            if changed.is_empty() {
+                p { class: "text-sm text-zinc-500 dark:text-zinc-400",
+                    "No task changed status, output or duration noticeably."
+                }
+            } else {
+                div { class: "divide-y divide-zinc-200 dark:divide-zinc-700",
+                    for task in changed {
+                        TaskComparisonRow { key: "{task.task_id}", task: task.clone() }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TaskComparisonRow(task: TaskComparison) -> Element {
+    let row_class = if task.regressed() {
+        "flex flex-wrap items-center gap-3 py-2 text-sm bg-red-50/60 dark:bg-red-900/10"
+    } else {
+        "flex flex-wrap items-center gap-3 py-2 text-sm"
+    };
+
+    rsx! {
+        div { class: row_class,
+            span { class: "min-w-0 flex-1 truncate text-zinc-900 dark:text-white", "{task.name}" }
+            if task.newly_failing() {
+                Badge { color: BadgeColor::Red, "Newly failing" }
+            } else if task.fixed() {
+                Badge { color: BadgeColor::Emerald, "Fixed" }
+            }
+            if task.slower() {
+                Badge { color: BadgeColor::Amber, "Slower" }
+            }
+            if task.output_changed {
+                Badge { color: BadgeColor::Blue, "Output changed" }
+            }
+            span { class: "text-xs text-zinc-500 dark:text-zinc-400 whitespace-nowrap",
+                "{status_text(task.previous_status.as_ref())} → {status_text(task.current_status.as_ref())}"
+            }
+            span { class: "text-xs text-zinc-500 dark:text-zinc-400 whitespace-nowrap",
+                "{duration_text(task.previous_duration)} → {duration_text(task.current_duration)}"
+            }
+        }
+    }
+}
+
+fn status_text(status: Option<&TaskExecutionStatus>) -> &'static str {
+    status.map_or("not run", TaskExecutionStatus::as_str)
+}
+
+fn duration_text(duration: Option<chrono::Duration>) -> String {
+    duration.map_or_else(|| "–".to_string(), format_duration)
+}
@@ -0,0 +1,25 @@
+use crate::icons::Icon;
+use crate::layout::router::Route;
+use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
+
+/// Link back to the upstream execution whose success started this one.
+#[component]
+pub fn TriggeredByLink(execution_id: String) -> Element {
+    rsx! {
+        div { class: "flex items-center gap-2 rounded-lg border border-zinc-200 bg-white px-4 py-3 text-sm text-zinc-600 dark:border-zinc-700 dark:bg-zinc-800 dark:text-zinc-400",
+            Icon {
+                name: "play".to_string(),
+                class: Some("text-zinc-400 dark:text-zinc-500".to_string()),
+                size: Some("w-4 h-4".to_string()),
+                variant: Some("outline".to_string()),
+            }
+            span { "Triggered by a successful run of execution" }
+            Link {
+                to: Route::WorkflowDetailsPage { id: execution_id.clone() },
+                class: "font-mono text-blue-600 hover:underline dark:text-blue-400",
+                "{execution_id}"
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::components::{Badge, BadgeColor};
+use dioxus::prelude::*;
+use s_e_e_core::{TaskExecution, TaskExecutionStatus};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+struct StageProgress {
+    name: String,
+    total: usize,
+    done: usize,
+    status: TaskExecutionStatus,
+}
+
+/// The workflow's stages in order, each with how many of its tasks are done
+/// and a status rolled up from them. Renders nothing for unstaged workflows.
+#[component]
+pub fn StageHeaders(snapshot: Value, tasks: Vec<TaskExecution>) -> Element {
+    let stages = stage_progress(&snapshot, &tasks);
+    if stages.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        ol { class: "flex flex-wrap items-center gap-2 mb-4", "aria-label": "Stages",
+            for (index, stage) in stages.into_iter().enumerate() {
+                li { key: "{stage.name}", class: "flex items-center gap-2",
+                    if index > 0 {
+                        span { class: "text-zinc-400 dark:text-zinc-500", "aria-hidden": "true", "→" }
+                    }
+                    span { class: "text-sm font-medium text-zinc-900 dark:text-white", "{stage.name}" }
+                    Badge { color: stage_color(&stage.status), "{stage.done}/{stage.total} · {stage.status.as_str()}" }
+                }
+            }
+        }
+    }
+}
+
+fn stage_progress(snapshot: &Value, tasks: &[TaskExecution]) -> Vec<StageProgress> {
+    let Some(stages) = snapshot.get("stages").and_then(|v| v.as_array()) else {
+        return Vec::new();
+    };
+
+    let mut task_stages = Vec::new();
+    if let Some(roots) = snapshot.get("tasks").and_then(|v| v.as_array()) {
+        collect_task_stages(roots, &mut task_stages);
+    }
+    let statuses: HashMap<&str, &TaskExecutionStatus> = tasks
+        .iter()
+        .map(|task| (task.id.as_str(), &task.status))
+        .collect();
+
+    stages
+        .iter()
+        .filter_map(|stage| stage.as_str())
+        .map(|name| {
+            let members: Vec<&TaskExecutionStatus> = task_stages
+                .iter()
+                .filter(|(_, stage)| stage == name)
+                .map(|(id, _)| {
+                    statuses
+                        .get(id.as_str())
+                        .copied()
+                        .unwrap_or(&TaskExecutionStatus::Pending)
+                })
+                .collect();
+            let done = members
+                .iter()
+                .filter(|status| {
+                    matches!(
+                        status,
+                        TaskExecutionStatus::Complete | TaskExecutionStatus::Skipped
+                    )
+                })
+                .count();
+            let status = if members
+                .iter()
+                .any(|status| matches!(status, TaskExecutionStatus::Failed))
+            {
+                TaskExecutionStatus::Failed
+            } else if members
+                .iter()
+                .any(|status| matches!(status, TaskExecutionStatus::WaitingForInput))
+            {
+                TaskExecutionStatus::WaitingForInput
+            } else if done == members.len() {
+                TaskExecutionStatus::Complete
+            } else if members
+                .iter()
+                .any(|status| !matches!(status, TaskExecutionStatus::Pending))
+            {
+                TaskExecutionStatus::InProgress
+            } else {
+                TaskExecutionStatus::Pending
+            };
+            StageProgress {
+                name: name.to_string(),
+                total: members.len(),
+                done,
+                status,
+            }
+        })
+        .collect()
+}
+
+fn collect_task_stages(tasks: &[Value], out: &mut Vec<(String, String)>) {
+    for task in tasks {
+        if let (Some(id), Some(stage)) = (
+            task.get("id").and_then(|v| v.as_str()),
+            task.get("stage").and_then(|v| v.as_str()),
+        ) {
+            out.push((id.to_string(), stage.to_string()));
+        }
+        if let Some(next) = task.get("next_tasks").and_then(|v| v.as_array()) {
+            collect_task_stages(next, out);
+        }
+    }
+}
+
+fn stage_color(status: &TaskExecutionStatus) -> BadgeColor {
+    match status {
+        TaskExecutionStatus::Complete => BadgeColor::Emerald,
+        TaskExecutionStatus::Failed => BadgeColor::Red,
+        TaskExecutionStatus::WaitingForInput => BadgeColor::Amber,
+        TaskExecutionStatus::InProgress => BadgeColor::Blue,
+        TaskExecutionStatus::Pending | TaskExecutionStatus::Skipped => BadgeColor::Zinc,
+    }
+}
@@ -8,12 +8,20 @@ use dioxus_router::prelude::use_navigator;
 pub struct TaskBoxProps {
     pub task: RenderableTask,
     pub execution_id: String,
+    /// Tasks whose outcome keeps flipping between runs of this definition.
+    #[props(default)]
+    pub flaky_task_ids: Vec<String>,
 }
 
 #[component]
 pub fn TaskBox(props: TaskBoxProps) -> Element {
-    let TaskBoxProps { task, execution_id } = props;
+    let TaskBoxProps {
+        task,
+        execution_id,
+        flaky_task_ids,
+    } = props;
     let navigator = use_navigator();
+    let is_flaky = flaky_task_ids.contains(&task.id);
 
     let is_clickable = task.has_execution_data;
     let cursor_class = if is_clickable {
@@ -62,7 +70,26 @@ pub fn TaskBox(props: TaskBoxProps) -> Element {
                     div { class: "flex flex-1 items-center justify-between truncate rounded-r-md border-b border-r border-t border-gray-200 bg-white dark:border-white/10 dark:bg-gray-800/50",
                         div { class: "flex-1 truncate px-4 py-2 text-sm",
                             div { class: "font-medium text-gray-900 dark:text-white truncate", "{task.name}" }
-                            p { class: "text-gray-500 dark:text-gray-400 text-xs truncate", "{task.function_name}" }
+                            p { class: "text-gray-500 dark:text-gray-400 text-xs truncate",
+                                "{task.function_name}"
+                                if task.cache_hit {
+                                    span { class: "ml-2 rounded bg-sky-100 px-1.5 py-0.5 text-sky-700 dark:bg-sky-500/10 dark:text-sky-400", "cached" }
+                                }
+                                if task.iterations > 0 {
+                                    span {
+                                        class: "ml-2 rounded bg-zinc-100 px-1.5 py-0.5 text-zinc-700 dark:bg-zinc-700 dark:text-zinc-300",
+                                        title: "Runs once per item",
+                                        "×{task.iterations}"
+                                    }
+                                }
+                                if is_flaky {
+                                    span {
+                                        class: "ml-2 rounded bg-amber-100 px-1.5 py-0.5 text-amber-700 dark:bg-amber-500/10 dark:text-amber-400",
+                                        title: "Passes and fails intermittently across recent runs",
+                                        "flaky"
+                                    }
+                                }
+                            }
                         }
                     }
                 }
@@ -74,6 +101,7 @@ pub fn TaskBox(props: TaskBoxProps) -> Element {
                         TaskBox {
                             task: child.clone(),
                             execution_id: execution_id.clone(),
+                            flaky_task_ids: flaky_task_ids.clone(),
                         }
                     }
                 }
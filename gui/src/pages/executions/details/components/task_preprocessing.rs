@@ -1,4 +1,4 @@
-use s_e_e_core::TaskExecution;
+use s_e_e_core::{loop_task_id, TaskExecution};
 use serde_json::Value;
 use std::collections::HashMap;
 
@@ -13,6 +13,9 @@ pub struct RenderableTask {
     pub status_color: &'static str,
     pub children: Vec<RenderableTask>,
     pub has_execution_data: bool,
+    pub cache_hit: bool,
+    /// Iterations recorded for a `for_each` task.
+    pub iterations: usize,
 }
 
 pub fn build_renderable_task(
@@ -64,6 +67,11 @@ pub fn build_renderable_task(
         status_color,
         children,
         has_execution_data,
+        cache_hit: task_map.get(task_id).is_some_and(|t| t.cache_hit),
+        iterations: task_map
+            .keys()
+            .filter(|id| id.as_str() != task_id && loop_task_id(id) == task_id)
+            .count(),
     })
 }
 
@@ -72,6 +80,10 @@ pub fn get_function_style(function_name: &str) -> (&'static str, &'static str) {
         "cli_command" => ("terminal", "bg-blue-600 dark:bg-blue-700"),
         "cursor_agent" => ("cursor", "bg-purple-600 dark:bg-purple-700"),
         "user_input" => ("bars_3", "bg-amber-600 dark:bg-amber-700"),
+        "manual" => ("check_circle", "bg-teal-600 dark:bg-teal-700"),
+        "db_query" => ("code_bracket", "bg-emerald-600 dark:bg-emerald-700"),
+        "publish_message" => ("arrow_path", "bg-indigo-600 dark:bg-indigo-700"),
+        "cache" => ("save", "bg-cyan-600 dark:bg-cyan-700"),
         _ => ("code_bracket", "bg-gray-600 dark:bg-gray-700"),
     }
 }
@@ -98,6 +110,10 @@ pub fn get_status_style(status: &str) -> (&'static str, &'static str) {
             "pause",
             "bg-amber-100 dark:bg-amber-900/30 text-amber-700 dark:text-amber-300",
         ),
+        "skipped" => (
+            "chevron_right",
+            "bg-zinc-100 dark:bg-zinc-800 text-zinc-500 dark:text-zinc-400 line-through",
+        ),
         "pending" => (
             "bars_3",
             "bg-zinc-100 dark:bg-zinc-800 text-zinc-500 dark:text-zinc-400 opacity-50",
@@ -1,4 +1,4 @@
-use crate::components::Slideout;
+use crate::components::{Slideout, TaskLogs};
 use crate::queries::use_task_details_query;
 use dioxus::prelude::*;
 
@@ -125,6 +125,13 @@ pub fn TaskSlideout(props: TaskSlideoutProps) -> Element {
                                     onclick: move |_| selected_tab.set("Output".to_string()),
                                     "Output"
                                 }
+                                if !task.environment.is_empty() {
+                                    button {
+                                        class: is_active("Environment"),
+                                        onclick: move |_| selected_tab.set("Environment".to_string()),
+                                        "Environment"
+                                    }
+                                }
                                 button {
                                     class: is_active("User Input"),
                                     onclick: move |_| selected_tab.set("User Input".to_string()),
@@ -147,6 +154,12 @@ pub fn TaskSlideout(props: TaskSlideoutProps) -> Element {
                                             span { class: "text-sm font-medium text-zinc-600 dark:text-zinc-400", "Status:" }
                                             span { class: "text-sm text-zinc-900 dark:text-zinc-100", "{task.status:?}" }
                                         }
+                                        if task.cache_hit {
+                                            div { class: "flex justify-between",
+                                                span { class: "text-sm font-medium text-zinc-600 dark:text-zinc-400", "Result:" }
+                                                span { class: "text-sm text-sky-700 dark:text-sky-400", "Reused from cache" }
+                                            }
+                                        }
                                         div { class: "flex justify-between",
                                             span { class: "text-sm font-medium text-zinc-600 dark:text-zinc-400", "ID:" }
                                             span { class: "text-sm text-zinc-900 dark:text-zinc-100 font-mono", "{task.id}" }
@@ -173,10 +186,10 @@ pub fn TaskSlideout(props: TaskSlideoutProps) -> Element {
                                 div { class: "bg-zinc-50 dark:bg-zinc-800 rounded-xl p-6",
                                     h4 { class: "text-base font-semibold text-zinc-950 dark:text-white mb-4", "Output" }
                                     if let Some(output) = task.output.as_ref() {
-                                        div { class: "bg-white dark:bg-zinc-900 rounded-lg p-4 border border-zinc-200 dark:border-zinc-700",
-                                            pre { class: "text-sm text-zinc-900 dark:text-zinc-100 whitespace-pre-wrap font-mono overflow-x-auto",
-                                                "{output}"
-                                            }
+                                        TaskLogs {
+                                            output: output.clone(),
+                                            total_bytes: task.output_bytes,
+                                            truncated: task.output_truncated,
                                         }
                                     } else {
                                         div { class: "text-center text-zinc-500 dark:text-zinc-400 text-sm py-8",
@@ -184,6 +197,21 @@ pub fn TaskSlideout(props: TaskSlideoutProps) -> Element {
                                         }
                                     }
                                 }
+                            } else if selected_tab() == "Environment" {
+                                div { class: "bg-zinc-50 dark:bg-zinc-800 rounded-xl p-6",
+                                    h4 { class: "text-base font-semibold text-zinc-950 dark:text-white mb-1", "Environment" }
+                                    p { class: "text-xs text-zinc-500 dark:text-zinc-400 mb-4",
+                                        "Credential-like values are redacted."
+                                    }
+                                    dl { class: "divide-y divide-zinc-200 dark:divide-zinc-700",
+                                        for (name, value) in task.environment.iter() {
+                                            div { key: "{name}", class: "flex gap-4 py-1.5",
+                                                dt { class: "w-1/3 shrink-0 text-xs font-mono font-medium text-zinc-600 dark:text-zinc-400 break-all", "{name}" }
+                                                dd { class: "text-xs font-mono text-zinc-900 dark:text-zinc-100 break-all", "{value}" }
+                                            }
+                                        }
+                                    }
+                                }
                             } else if selected_tab() == "User Input" {
                                 if let Some(req) = input_request() {
                                     div { class: "bg-zinc-50 dark:bg-zinc-800 rounded-xl p-6",
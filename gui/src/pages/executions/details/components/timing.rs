@@ -0,0 +1,36 @@
+use crate::components::Timestamp;
+use chrono::{DateTime, Utc};
+use dioxus::prelude::*;
+
+/// When the execution started and, once it has, when it finished.
+#[component]
+pub fn ExecutionTiming(created_at: DateTime<Utc>, completed_at: Option<DateTime<Utc>>) -> Element {
+    rsx! {
+        div { class: "flex flex-wrap items-center gap-x-6 gap-y-1 text-sm text-zinc-600 dark:text-zinc-400",
+            span {
+                "Started "
+                Timestamp { value: created_at, class: Some("text-zinc-900 dark:text-zinc-100".to_string()) }
+            }
+            if let Some(completed_at) = completed_at {
+                span {
+                    "Finished "
+                    Timestamp { value: completed_at, class: Some("text-zinc-900 dark:text-zinc-100".to_string()) }
+                    " · took {format_duration(completed_at - created_at)}"
+                }
+            } else {
+                span { "Still running" }
+            }
+        }
+    }
+}
+
+pub(super) fn format_duration(duration: chrono::Duration) -> String {
+    let seconds = duration.num_seconds().max(0);
+    if seconds < 60 {
+        format!("{}s", seconds)
+    } else if seconds < 3_600 {
+        format!("{}m {}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}h {}m", seconds / 3_600, (seconds % 3_600) / 60)
+    }
+}
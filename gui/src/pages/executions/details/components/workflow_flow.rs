@@ -3,6 +3,7 @@ use s_e_e_core::{TaskExecution, WorkflowExecutionStatus};
 use serde_json::Value;
 use std::collections::HashMap;
 
+use super::stages::StageHeaders;
 use super::task_box::TaskBox;
 use super::task_preprocessing::build_renderable_task;
 
@@ -12,6 +13,7 @@ pub fn WorkflowFlowGraph(
     tasks: Vec<TaskExecution>,
     execution_id: String,
     workflow_status: WorkflowExecutionStatus,
+    #[props(default)] flaky_task_ids: Vec<String>,
 ) -> Element {
     let task_map: HashMap<String, &TaskExecution> =
         tasks.iter().map(|t| (t.id.clone(), t)).collect();
@@ -68,11 +70,13 @@ pub fn WorkflowFlowGraph(
     rsx! {
         div { class: "bg-white dark:bg-zinc-900 rounded-lg shadow-sm ring-1 ring-zinc-950/5 dark:ring-white/10 overflow-x-auto p-4",
             h3 { class: "text-base font-semibold text-zinc-950 dark:text-white mb-4", "Workflow Flow" }
+            StageHeaders { snapshot: snapshot.clone(), tasks: tasks.clone() }
             div { class: "grid gap-4 py-4", style: format!("grid-template-columns: repeat({}, minmax(120px, 1fr)); min-width: max(100%, {}px)", renderable_tasks.len(), renderable_tasks.len() * 140),
                 for task in renderable_tasks.iter() {
                     TaskBox {
                         task: task.clone(),
                         execution_id: execution_id.clone(),
+                        flaky_task_ids: flaky_task_ids.clone(),
                     }
                 }
             }
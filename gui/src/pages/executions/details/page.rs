@@ -1,18 +1,45 @@
-use crate::components::{IconButton, IconButtonSize, IconButtonVariant, PageHeader};
+use crate::components::{
+    tab_id, tab_panel_id, Alert, AlertType, IconButton, IconButtonSize, IconButtonVariant,
+    PageHeader, SplitPane, Tabs,
+};
+use crate::layout::hooks::use_persisted_pane_ratio;
 use crate::layout::router::Route;
-use crate::pages::executions::details::components::WorkflowFlowGraph;
+use crate::pages::executions::details::components::{
+    AuditTrail, ContextTimeline, DefinitionSnapshot, ExecutionLabels, ExecutionTiming, LiveOutput,
+    PreviousRunComparison, ReproducibilityManifestView, TriggeredByLink, WorkflowFlowGraph,
+};
 use crate::pages::executions::list::components::ExecutionDeleteDialog;
-use crate::queries::{use_delete_execution_mutation, use_workflow_execution_query};
+use crate::queries::{
+    use_create_workflow_test_mutation, use_delete_execution_mutation,
+    use_execution_flaky_tasks_query, use_workflow_execution_query,
+};
 use dioxus::prelude::*;
-use dioxus_router::prelude::use_navigator;
+use dioxus_router::prelude::{use_navigator, Link};
+use s_e_e_core::WorkflowExecutionStatus;
+
+const DETAILS_TABS_ID: &str = "execution-details";
+const LOGS_TAB: &str = "Logs";
+const CONTEXT_TAB: &str = "Context";
+const AUDIT_TAB: &str = "Audit";
 
 #[component]
 pub fn WorkflowDetailsPage(id: String) -> Element {
     let navigator = use_navigator();
+    let split_ratio = use_persisted_pane_ratio("execution_details", 0.5);
+    let mut selected_tab = use_signal(|| LOGS_TAB.to_string());
     let mut show_delete_dialog = use_signal(|| false);
+    let mut show_comparison = use_signal(|| false);
     let (delete_state, delete_fn) = use_delete_execution_mutation();
+    let (create_test_state, create_test_fn) = use_create_workflow_test_mutation();
 
     let (exec_state, _refetch) = use_workflow_execution_query(id.clone());
+    let (flaky_state, _refetch_flaky) = use_execution_flaky_tasks_query(id.clone());
+    let flaky_task_ids: Vec<String> = flaky_state
+        .data
+        .iter()
+        .flatten()
+        .map(|task| task.task_id.clone())
+        .collect();
 
     use_effect(move || {
         if delete_state.read().is_success {
@@ -38,6 +65,18 @@ pub fn WorkflowDetailsPage(id: String) -> Element {
         (None, None)
     };
 
+    // Only finished runs of a saved workflow can be recorded as a test.
+    let can_create_test = execution.as_ref().is_some_and(|exec| {
+        exec.workflow_id.is_some()
+            && matches!(
+                exec.status,
+                WorkflowExecutionStatus::Complete
+                    | WorkflowExecutionStatus::Failed
+                    | WorkflowExecutionStatus::Cancelled
+            )
+    });
+    let create_test_id = id.clone();
+
     rsx! {
         div { class: "space-y-6",
             if let Some(exec) = execution.as_ref() {
@@ -45,6 +84,25 @@ pub fn WorkflowDetailsPage(id: String) -> Element {
                     title: exec.workflow_name.clone(),
                     description: format!("Execution ID: {}", exec.id),
                     actions: Some(rsx! {
+                        IconButton {
+                            variant: IconButtonVariant::Secondary,
+                            size: IconButtonSize::Medium,
+                            onclick: move |_| show_comparison.toggle(),
+                            icon: Some("executions".to_string()),
+                            icon_variant: "outline".to_string(),
+                            if show_comparison() { "Hide comparison" } else { "Compare with previous run" }
+                        }
+                        if can_create_test {
+                            IconButton {
+                                variant: IconButtonVariant::Secondary,
+                                size: IconButtonSize::Medium,
+                                loading: Some(create_test_state.read().is_loading),
+                                onclick: move |_| create_test_fn(create_test_id.clone()),
+                                icon: Some("check_circle".to_string()),
+                                icon_variant: "outline".to_string(),
+                                "Create test"
+                            }
+                        }
                         IconButton {
                             variant: IconButtonVariant::Danger,
                             size: IconButtonSize::Medium,
@@ -58,11 +116,101 @@ pub fn WorkflowDetailsPage(id: String) -> Element {
                     }),
                 }
 
-                WorkflowFlowGraph {
-                    snapshot: exec.workflow_snapshot.clone(),
-                    tasks: exec.tasks.clone(),
+                if let Some(error) = create_test_state.read().error.clone() {
+                    Alert {
+                        alert_type: AlertType::Error,
+                        title: Some("Could not create test".to_string()),
+                        message: error,
+                        dismissible: None,
+                        on_dismiss: None,
+                        actions: None,
+                    }
+                }
+                if let Some(test) = create_test_state.read().data.clone() {
+                    p { class: "text-sm text-emerald-700 dark:text-emerald-400",
+                        "Recorded test \"{test.name}\". "
+                        Link {
+                            to: Route::WorkflowEditPage { id: test.workflow_id.clone() },
+                            class: "font-medium hover:underline",
+                            "Open the workflow"
+                        }
+                        " and choose the Test tab to run it."
+                    }
+                }
+
+                ExecutionTiming {
+                    created_at: exec.created_at,
+                    completed_at: exec.completed_at,
+                }
+
+                ExecutionLabels {
                     execution_id: exec.id.clone(),
-                    workflow_status: exec.status.clone()
+                    labels: exec.labels.clone(),
+                }
+
+                if let Some(upstream_id) = exec.triggered_by.clone() {
+                    TriggeredByLink { execution_id: upstream_id }
+                }
+
+                if show_comparison() {
+                    PreviousRunComparison { execution_id: exec.id.clone() }
+                }
+
+                SplitPane {
+                    ratio: split_ratio,
+                    label: Some("Resize task graph and details".to_string()),
+                    left: rsx! {
+                        WorkflowFlowGraph {
+                            snapshot: exec.workflow_snapshot.clone(),
+                            tasks: exec.tasks.clone(),
+                            execution_id: exec.id.clone(),
+                            workflow_status: exec.status.clone(),
+                            flaky_task_ids: flaky_task_ids.clone(),
+                        }
+                    },
+                    right: rsx! {
+                        Tabs {
+                            id_prefix: DETAILS_TABS_ID.to_string(),
+                            tabs: vec![LOGS_TAB.to_string(), CONTEXT_TAB.to_string(), AUDIT_TAB.to_string()],
+                            selected: selected_tab(),
+                            on_change: move |tab| selected_tab.set(tab),
+                            label: Some("Execution details".to_string()),
+                        }
+                        div {
+                            id: tab_panel_id(DETAILS_TABS_ID),
+                            role: "tabpanel",
+                            "aria-labelledby": tab_id(DETAILS_TABS_ID, &selected_tab()),
+                            class: "mt-4 space-y-6",
+                            match selected_tab().as_str() {
+                                CONTEXT_TAB => rsx! {
+                                    ContextTimeline {
+                                        execution_id: exec.id.clone(),
+                                        audit_trail: exec.audit_trail.clone(),
+                                        tasks: exec.tasks.clone(),
+                                    }
+                                    if exec.workflow_snapshot.get("tasks").and_then(|t| t.as_array()).is_some_and(|t| !t.is_empty()) {
+                                        DefinitionSnapshot {
+                                            execution_id: exec.id.clone(),
+                                            snapshot: exec.workflow_snapshot.clone(),
+                                        }
+                                    } else {
+                                        p { class: "text-sm text-zinc-500 dark:text-zinc-400",
+                                            "This execution did not record the workflow definition it ran."
+                                        }
+                                    }
+                                    if let Some(manifest) = exec.manifest.clone() {
+                                        ReproducibilityManifestView { manifest }
+                                    }
+                                },
+                                AUDIT_TAB => rsx! {
+                                    AuditTrail { events: exec.audit_trail.clone() }
+                                },
+                                _ => rsx! {
+                                    LiveOutput { execution_id: exec.id.clone() }
+                                },
+                            }
+                        }
+                    },
                 }
 
                 if let (Some(exec_id), Some(workflow_name)) = (execution_id, workflow_name) {
@@ -1,4 +1,4 @@
-use crate::components::SectionCard;
+use crate::components::{SectionCard, Timestamp};
 use dioxus::prelude::*;
 use s_e_e_core::TaskExecution;
 
@@ -17,6 +17,12 @@ pub fn TaskDetailsInfoTab(task: TaskExecution) -> Element {
                         span { class: "text-sm font-medium text-zinc-600 dark:text-zinc-400", "Status:" }
                         span { class: "text-sm text-zinc-900 dark:text-zinc-100", "{task.status:?}" }
                     }
+                    if !task.attempts.is_empty() {
+                        div { class: "flex justify-between",
+                            span { class: "text-sm font-medium text-zinc-600 dark:text-zinc-400", "Attempt:" }
+                            span { class: "text-sm text-zinc-900 dark:text-zinc-100", "{task.attempt_number()}" }
+                        }
+                    }
                     div { class: "flex justify-between",
                         span { class: "text-sm font-medium text-zinc-600 dark:text-zinc-400", "ID:" }
                         span { class: "text-sm text-zinc-900 dark:text-zinc-100 font-mono", "{task.id}" }
@@ -29,12 +35,44 @@ pub fn TaskDetailsInfoTab(task: TaskExecution) -> Element {
                     }
                     div { class: "flex justify-between",
                         span { class: "text-sm font-medium text-zinc-600 dark:text-zinc-400", "Created At:" }
-                        span { class: "text-sm text-zinc-900 dark:text-zinc-100", "{task.created_at}" }
+                        Timestamp {
+                            value: task.created_at,
+                            absolute: true,
+                            class: Some("text-sm text-zinc-900 dark:text-zinc-100".to_string()),
+                        }
                     }
-                    if let Some(completed_at) = task.completed_at.as_ref() {
+                    if let Some(completed_at) = task.completed_at {
                         div { class: "flex justify-between",
                             span { class: "text-sm font-medium text-zinc-600 dark:text-zinc-400", "Completed At:" }
-                            span { class: "text-sm text-zinc-900 dark:text-zinc-100", "{completed_at}" }
+                            Timestamp {
+                                value: completed_at,
+                                absolute: true,
+                                class: Some("text-sm text-zinc-900 dark:text-zinc-100".to_string()),
+                            }
+                        }
+                    }
+                    if !task.attempts.is_empty() {
+                        div { class: "pt-3 border-t border-zinc-200 dark:border-zinc-700 space-y-2",
+                            h4 { class: "text-sm font-medium text-zinc-600 dark:text-zinc-400", "Previous attempts" }
+                            for (index, attempt) in task.attempts.iter().enumerate() {
+                                div {
+                                    key: "{index}",
+                                    class: "rounded-lg bg-zinc-50 dark:bg-zinc-900 p-3 space-y-1",
+                                    div { class: "flex justify-between text-sm",
+                                        span { class: "text-zinc-900 dark:text-zinc-100", "Attempt {index + 1}: {attempt.status:?}" }
+                                        if let Some(completed_at) = attempt.completed_at {
+                                            Timestamp {
+                                                value: completed_at,
+                                                absolute: true,
+                                                class: Some("text-zinc-500 dark:text-zinc-400".to_string()),
+                                            }
+                                        }
+                                    }
+                                    if let Some(error) = attempt.error.as_ref() {
+                                        p { class: "text-sm text-red-600 dark:text-red-400", "{error}" }
+                                    }
+                                }
+                            }
                         }
                     }
                 }
@@ -0,0 +1,36 @@
+use crate::components::{EmptyState, SectionCard};
+use dioxus::prelude::*;
+use s_e_e_core::TaskExecution;
+
+#[component]
+pub fn TaskDetailsEnvironmentTab(task: TaskExecution) -> Element {
+    rsx! {
+        SectionCard {
+            title: Some("Environment".to_string()),
+            children: rsx! {
+                if task.environment.is_empty() {
+                    EmptyState {
+                        message: "No environment was recorded for this task".to_string(),
+                    }
+                } else {
+                    p { class: "text-xs text-zinc-500 dark:text-zinc-400 mb-3",
+                        "Variables the task's process started with. Values that look like credentials are redacted."
+                    }
+                    dl { class: "divide-y divide-zinc-200 dark:divide-zinc-700",
+                        for (name, value) in task.environment.iter() {
+                            div { key: "{name}", class: "flex gap-4 py-1.5",
+                                dt { class: "w-1/3 shrink-0 text-xs font-mono font-medium text-zinc-600 dark:text-zinc-400 break-all",
+                                    "{name}"
+                                }
+                                dd { class: "text-xs font-mono text-zinc-900 dark:text-zinc-100 break-all",
+                                    "{value}"
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            padding: None,
+        }
+    }
+}
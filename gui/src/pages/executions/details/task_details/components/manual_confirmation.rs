@@ -0,0 +1,121 @@
+use crate::components::{
+    IconButton, IconButtonSize, IconButtonVariant, SectionCard, TextInput, TextareaInput,
+};
+use crate::layout::router::Route;
+use dioxus::prelude::*;
+use dioxus_router::prelude::use_navigator;
+use s_e_e_core::{ManualOutcome, UserInputRequest};
+
+/// Confirm a pending manual step as done or skipped, with who did it and
+/// optional notes. Returns to the execution once the run has resumed.
+#[component]
+pub fn ManualConfirmationForm(request: UserInputRequest) -> Element {
+    let navigator = use_navigator();
+    let mut confirmed_by = use_signal(s_e_e_core::local_user_name);
+    let mut notes = use_signal(String::new);
+    let mut error_message = use_signal(|| None::<String>);
+    let mut is_submitting = use_signal(|| false);
+
+    let execution_id = request.workflow_execution_id.clone();
+    let task_id = request.task_execution_id.clone();
+    let submit = move |outcome: ManualOutcome| {
+        if confirmed_by().trim().is_empty() {
+            error_message.set(Some("Enter who is confirming this step".to_string()));
+            return;
+        }
+        error_message.set(None);
+        is_submitting.set(true);
+
+        let execution_id = execution_id.clone();
+        let task_id = task_id.clone();
+        let confirmer = confirmed_by();
+        let notes = Some(notes()).filter(|notes| !notes.trim().is_empty());
+        spawn(async move {
+            match s_e_e_core::confirm_manual_task(
+                &execution_id,
+                &task_id,
+                outcome,
+                &confirmer,
+                notes,
+            )
+            .await
+            {
+                Ok(()) => {
+                    navigator.push(Route::WorkflowDetailsPage { id: execution_id });
+                }
+                Err(e) => {
+                    error_message.set(Some(format!("Failed to confirm step: {}", e)));
+                }
+            }
+            is_submitting.set(false);
+        });
+    };
+
+    rsx! {
+        SectionCard {
+            title: Some("Manual Step".to_string()),
+            children: rsx! {
+                div { class: "space-y-4",
+                    div { class: "text-sm text-zinc-900 dark:text-zinc-100 bg-white dark:bg-zinc-900 rounded-lg p-3 border border-zinc-200 dark:border-zinc-700 whitespace-pre-wrap",
+                        "{request.prompt_text}"
+                    }
+
+                    if let Some(error_msg) = error_message() {
+                        div { class: "bg-red-50 dark:bg-red-900/20 border border-red-200 dark:border-red-800 rounded-lg p-3",
+                            p { class: "text-sm text-red-700 dark:text-red-300", {error_msg} }
+                        }
+                    }
+
+                    TextInput {
+                        label: "Confirmed by".to_string(),
+                        value: confirmed_by,
+                        oninput: EventHandler::new(move |value| {
+                            confirmed_by.set(value);
+                            error_message.set(None);
+                        }),
+                        placeholder: None,
+                        help_text: None,
+                        required: Some(true),
+                        disabled: Some(is_submitting()),
+                    }
+
+                    TextareaInput {
+                        label: "Notes".to_string(),
+                        value: notes,
+                        oninput: EventHandler::new(move |value| notes.set(value)),
+                        placeholder: Some("Optional, e.g. a link to the review".to_string()),
+                        help_text: None,
+                        rows: Some(3),
+                        disabled: Some(is_submitting()),
+                    }
+
+                    div { class: "flex justify-end gap-3 pt-4",
+                        IconButton {
+                            variant: IconButtonVariant::Secondary,
+                            size: IconButtonSize::Medium,
+                            disabled: Some(is_submitting()),
+                            onclick: {
+                                let mut submit = submit.clone();
+                                move |_| submit(ManualOutcome::Skipped)
+                            },
+                            "Skip"
+                        }
+                        IconButton {
+                            variant: IconButtonVariant::Primary,
+                            size: IconButtonSize::Medium,
+                            disabled: Some(is_submitting()),
+                            loading: Some(is_submitting()),
+                            onclick: {
+                                let mut submit = submit.clone();
+                                move |_| submit(ManualOutcome::Done)
+                            },
+                            icon: Some("check_circle".to_string()),
+                            "Mark done"
+                        }
+                    }
+                }
+            },
+            padding: None,
+        }
+    }
+}
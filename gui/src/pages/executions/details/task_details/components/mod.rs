@@ -1,11 +1,17 @@
 pub mod details_tab;
+pub mod environment_tab;
 pub mod header;
+pub mod manual_confirmation;
 pub mod output_tab;
+pub mod rerun;
 pub mod tabs;
 pub mod user_input_tab;
 
 pub use details_tab::TaskDetailsInfoTab;
+pub use environment_tab::TaskDetailsEnvironmentTab;
 pub use header::TaskDetailsHeader;
+pub use manual_confirmation::ManualConfirmationForm;
 pub use output_tab::TaskDetailsOutputTab;
-pub use tabs::TaskDetailsTabs;
+pub use rerun::TaskRerunCard;
+pub use tabs::{TaskDetailsTabs, TASK_DETAILS_TABS_ID};
 pub use user_input_tab::TaskDetailsUserInputTab;
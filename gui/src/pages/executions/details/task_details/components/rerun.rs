@@ -0,0 +1,75 @@
+use crate::components::{
+    Alert, AlertType, IconButton, IconButtonSize, IconButtonVariant, SectionCard,
+};
+use crate::layout::router::Route;
+use crate::queries::use_rerun_task_mutation;
+use dioxus::prelude::*;
+use dioxus_router::prelude::use_navigator;
+
+const CHECKBOX_CLASS: &str =
+    "h-4 w-4 rounded border-zinc-300 dark:border-zinc-600 text-blue-600 focus:ring-blue-600";
+
+/// Runs a failed task again within its execution, from the context it first
+/// ran with. Returns to the execution once the re-run has finished.
+#[component]
+pub fn TaskRerunCard(execution_id: String, task_id: String) -> Element {
+    let navigator = use_navigator();
+    let (rerun_state, rerun) = use_rerun_task_mutation();
+    let mut downstream = use_signal(|| true);
+
+    use_effect(move || {
+        if let Some(result) = rerun_state.read().data.clone() {
+            navigator.push(Route::WorkflowDetailsPage {
+                id: result.execution_id,
+            });
+        }
+    });
+
+    let running = rerun_state.read().is_loading;
+
+    rsx! {
+        SectionCard {
+            title: Some("Re-run Task".to_string()),
+            children: rsx! {
+                div { class: "space-y-4",
+                    p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                        "Runs this task again in the same execution, with the context it started with the first time. Its earlier result stays listed as a previous attempt."
+                    }
+                    label { class: "flex items-center gap-3 text-sm text-zinc-900 dark:text-white",
+                        input {
+                            r#type: "checkbox",
+                            checked: downstream(),
+                            class: CHECKBOX_CLASS,
+                            disabled: running,
+                            onchange: move |evt| downstream.set(evt.checked()),
+                        }
+                        "Also run the tasks after it if it succeeds"
+                    }
+                    if let Some(error) = rerun_state.read().error.clone() {
+                        Alert {
+                            alert_type: AlertType::Error,
+                            title: Some("Could not re-run".to_string()),
+                            message: error,
+                            dismissible: None,
+                            on_dismiss: None,
+                            actions: None,
+                        }
+                    }
+                    div { class: "flex justify-end",
+                        IconButton {
+                            variant: IconButtonVariant::Primary,
+                            size: IconButtonSize::Medium,
+                            disabled: Some(running),
+                            loading: Some(running),
+                            onclick: move |_| rerun((execution_id.clone(), task_id.clone(), downstream())),
+                            icon: if running { None } else { Some("arrow_path".to_string()) },
+                            icon_variant: "outline".to_string(),
+                            if running { "Running..." } else { "Re-run task" }
+                        }
+                    }
+                }
+            },
+            padding: None,
+        }
+    }
+}
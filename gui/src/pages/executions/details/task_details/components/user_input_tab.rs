@@ -1,3 +1,4 @@
+use super::ManualConfirmationForm;
 use crate::components::{
     Badge, BadgeColor, IconButton, IconButtonSize, IconButtonVariant, SectionCard, TextInput,
 };
@@ -83,6 +84,10 @@ pub fn TaskDetailsUserInputTab(input_request: Option<s_e_e_core::UserInputReques
                     padding: None,
                 }
             }
+        } else if req.is_manual() {
+            rsx! {
+                ManualConfirmationForm { request: req.clone() }
+            }
         } else {
             let task_id = req.task_execution_id.clone();
             let execution_id = req.workflow_execution_id.clone();
@@ -1,14 +1,16 @@
-use crate::components::EmptyState;
+use crate::components::{tab_id, tab_panel_id, EmptyState};
 use crate::pages::executions::details::task_details::components::{
-    TaskDetailsHeader, TaskDetailsInfoTab, TaskDetailsOutputTab, TaskDetailsTabs,
-    TaskDetailsUserInputTab,
+    TaskDetailsEnvironmentTab, TaskDetailsHeader, TaskDetailsInfoTab, TaskDetailsOutputTab,
+    TaskDetailsTabs, TaskDetailsUserInputTab, TaskRerunCard, TASK_DETAILS_TABS_ID,
 };
-use crate::queries::use_task_details_query;
+use crate::queries::{use_task_details_query, use_workflow_execution_query};
 use dioxus::prelude::*;
+use s_e_e_core::WorkflowExecutionStatus;
 
 #[component]
 pub fn TaskDetailsPage(execution_id: String, task_id: String) -> Element {
     let (task_state, refetch) = use_task_details_query(execution_id.clone(), task_id.clone());
+    let (execution_state, _refetch_execution) = use_workflow_execution_query(execution_id.clone());
 
     let task_id_for_invalidation = task_id.clone();
     use_effect(move || {
@@ -69,6 +71,14 @@ pub fn TaskDetailsPage(execution_id: String, task_id: String) -> Element {
     });
 
     let show_user_input = input_request().is_some();
+    let can_rerun = execution_state.data.as_ref().is_some_and(|execution| {
+        matches!(
+            execution.status,
+            WorkflowExecutionStatus::Complete
+                | WorkflowExecutionStatus::Failed
+                | WorkflowExecutionStatus::Cancelled
+        ) && s_e_e_core::task_failed(execution, &task_id)
+    });
 
     let task_name = task
         .as_ref()
@@ -84,19 +94,32 @@ pub fn TaskDetailsPage(execution_id: String, task_id: String) -> Element {
 
             if let Some(task) = task.as_ref() {
                 div { class: "space-y-6",
+                    if can_rerun {
+                        TaskRerunCard {
+                            execution_id: execution_id.clone(),
+                            task_id: task_id.clone(),
+                        }
+                    }
                     TaskDetailsTabs {
                         selected_tab,
                         on_tab_change: EventHandler::new(move |tab_name| {
                             selected_tab.set(tab_name);
                         }),
                         show_user_input,
+                        show_environment: !task.environment.is_empty(),
                     }
 
-                    div { class: "mt-6",
+                    div {
+                        id: tab_panel_id(TASK_DETAILS_TABS_ID),
+                        role: "tabpanel",
+                        "aria-labelledby": tab_id(TASK_DETAILS_TABS_ID, &selected_tab()),
+                        class: "mt-6",
                         if selected_tab() == "Details" {
                             TaskDetailsInfoTab { task: task.clone() }
                         } else if selected_tab() == "Output" {
                             TaskDetailsOutputTab { task: task.clone() }
+                        } else if selected_tab() == "Environment" {
+                            TaskDetailsEnvironmentTab { task: task.clone() }
                         } else if selected_tab() == "User Input" && show_user_input {
                             TaskDetailsUserInputTab { input_request: input_request() }
                         }
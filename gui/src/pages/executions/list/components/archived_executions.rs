@@ -0,0 +1,127 @@
+use crate::components::layout::ListItem;
+use crate::components::{
+    format_bytes, Alert, AlertType, Badge, BadgeColor, EmptyState, IconButton, IconButtonSize,
+    IconButtonVariant, List, Timestamp,
+};
+use crate::queries::{use_archived_executions_query, use_restore_archived_mutation};
+use dioxus::prelude::*;
+use s_e_e_core::{ArchivedExecution, WorkflowExecutionStatus};
+
+/// Executions moved to archive files, each with a button that loads it back.
+#[component]
+pub fn ArchivedExecutions() -> Element {
+    let (archived_state, _refetch) = use_archived_executions_query();
+    let (restore_state, restore_fn) = use_restore_archived_mutation();
+
+    if archived_state.is_error {
+        return rsx! {
+            Alert {
+                alert_type: AlertType::Error,
+                title: Some("Failed to load archived executions".to_string()),
+                message: archived_state
+                    .error
+                    .clone()
+                    .unwrap_or_else(|| "Unknown error".to_string()),
+                dismissible: None,
+                on_dismiss: None,
+                actions: None,
+            }
+        };
+    }
+
+    let archived = archived_state.data.clone().unwrap_or_default();
+    if archived.is_empty() {
+        if archived_state.is_loading {
+            return rsx! {};
+        }
+        return rsx! {
+            EmptyState { message: "No archived executions.".to_string() }
+        };
+    }
+
+    let restoring = restore_state().is_loading;
+    let restore_fn = std::rc::Rc::new(restore_fn);
+
+    rsx! {
+        div { class: "space-y-4",
+            if let Some(error) = restore_state().error {
+                Alert {
+                    alert_type: AlertType::Error,
+                    title: Some("Restore failed".to_string()),
+                    message: error,
+                    dismissible: None,
+                    on_dismiss: None,
+                    actions: None,
+                }
+            }
+            List {
+                for entry in archived {
+                    ArchivedExecutionItem {
+                        key: "{entry.id}",
+                        entry: entry.clone(),
+                        restoring,
+                        on_restore: {
+                            let restore_fn = restore_fn.clone();
+                            move |id: String| restore_fn(id)
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ArchivedExecutionItem(
+    entry: ArchivedExecution,
+    restoring: bool,
+    on_restore: EventHandler<String>,
+) -> Element {
+    let (badge_color, status_text) = match entry.status {
+        WorkflowExecutionStatus::Failed => (BadgeColor::Red, "Failed"),
+        WorkflowExecutionStatus::Cancelled => (BadgeColor::Zinc, "Cancelled"),
+        _ => (BadgeColor::Emerald, "Success"),
+    };
+    let size = format_bytes(entry.size_bytes);
+    let id = entry.id.clone();
+
+    rsx! {
+        ListItem {
+            icon_name: "workflows".to_string(),
+            icon_variant: Some("outline".to_string()),
+            title: rsx! {
+                {entry.workflow_name.clone()}
+            },
+            subtitle: Some(rsx! {
+                div { class: "flex flex-col gap-1",
+                    div { class: "text-sm text-gray-500 dark:text-gray-400",
+                        "Executed "
+                        Timestamp { value: entry.created_at }
+                    }
+                    div { class: "text-xs text-gray-500 dark:text-gray-400",
+                        "{entry.task_count} tasks · {size} archived "
+                        Timestamp { value: entry.archived_at }
+                    }
+                }
+            }),
+            right_content: Some(rsx! {
+                div { class: "flex items-center gap-2",
+                    Badge {
+                        color: badge_color,
+                        {status_text}
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Secondary,
+                        size: IconButtonSize::Small,
+                        disabled: Some(restoring),
+                        onclick: Some(EventHandler::new(move |_| on_restore.call(id.clone()))),
+                        icon: Some("arrow_path".to_string()),
+                        icon_variant: "outline".to_string(),
+                        "Restore"
+                    }
+                }
+            }),
+            show_chevron: false,
+        }
+    }
+}
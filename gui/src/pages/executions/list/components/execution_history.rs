@@ -0,0 +1,212 @@
+use super::{ExecutionItem, ExecutionSelectionBar};
+use crate::components::{
+    use_exporter, use_workflow_runner, Alert, AlertType, ContextMenu, ContextMenuItem, EmptyState,
+    ExportNotification, MenuAnchor, VirtualList, WorkflowRunDialogs,
+};
+use crate::layout::router::Route;
+use crate::queries::use_execution_page_query;
+use crate::services::execution::ExecutionService;
+use crate::services::ExportKind;
+use dioxus::prelude::*;
+use dioxus_router::prelude::use_navigator;
+use s_e_e_core::ExecutionFilter;
+use std::collections::BTreeSet;
+
+const PAGE_SIZE: usize = 100;
+/// Height of one `ExecutionItem` row, including its divider.
+const ROW_HEIGHT: f64 = 113.0;
+const VIEWPORT_HEIGHT: f64 = 720.0;
+
+/// Execution history for one filter, loaded a page at a time as the user scrolls.
+///
+/// Render with a `key` per filter so switching filters starts again from the first page.
+#[component]
+pub fn ExecutionHistory(filter: ExecutionFilter) -> Element {
+    let mut limit = use_signal(|| PAGE_SIZE);
+    let (page_state, refetch) = use_execution_page_query(filter, limit);
+    let mut selected = use_signal(BTreeSet::<String>::new);
+    let mut confirming_delete = use_signal(|| false);
+    let navigator = use_navigator();
+    let runner = use_workflow_runner();
+    let exporter = use_exporter();
+    let mut menu_anchor = use_signal(|| None::<MenuAnchor>);
+    let mut menu_target = use_signal(|| None::<String>);
+    let mut action_error = use_signal(|| None::<String>);
+
+    if page_state.is_error {
+        return rsx! {
+            Alert {
+                alert_type: AlertType::Error,
+                title: Some("Failed to load executions".to_string()),
+                message: page_state
+                    .error
+                    .clone()
+                    .unwrap_or_else(|| "Unknown error".to_string()),
+                dismissible: None,
+                on_dismiss: None,
+                actions: None,
+            }
+        };
+    }
+
+    let page = page_state.data.clone().unwrap_or_default();
+    if page.executions.is_empty() {
+        if page_state.is_loading {
+            return rsx! {};
+        }
+        return rsx! {
+            EmptyState { message: "No executions match this filter.".to_string() }
+        };
+    }
+
+    let loaded = page.executions.len();
+    let has_more = loaded < page.total;
+    let is_fetching = page_state.is_fetching;
+    let total = page.total;
+    let executions = page.executions;
+    let all_ids: Vec<String> = executions
+        .iter()
+        .map(|execution| execution.id.clone())
+        .collect();
+
+    // As on the workflows page, a row in a multi-selection gets the bulk actions.
+    let selection_count = selected.read().len();
+    let menu_on_selection =
+        menu_target().is_some_and(|id| selection_count > 1 && selected.read().contains(&id));
+    let menu_items = match menu_target() {
+        None => Vec::new(),
+        Some(_) if menu_on_selection => vec![
+            ContextMenuItem::new(format!("Export {} executions", selection_count), move |_| {
+                exporter.export(
+                    ExportKind::Execution,
+                    selected.peek().iter().cloned().collect(),
+                )
+            })
+            .icon("save"),
+            ContextMenuItem::new("Clear selection", move |_| selected.write().clear()).icon("x"),
+            ContextMenuItem::new(format!("Delete {} executions", selection_count), move |_| {
+                confirming_delete.set(true)
+            })
+            .icon("trash")
+            .danger(),
+        ],
+        Some(id) => vec![
+            ContextMenuItem::new("Open", {
+                let id = id.clone();
+                move |_| {
+                    navigator.push(Route::WorkflowDetailsPage { id: id.clone() });
+                }
+            })
+            .icon("chevron_right"),
+            ContextMenuItem::new("Run again", {
+                let id = id.clone();
+                move |_| {
+                    let id = id.clone();
+                    spawn(async move {
+                        match ExecutionService::fetch_workflow_execution(&id).await {
+                            Ok(execution) => match execution.workflow_id {
+                                Some(workflow_id) => runner.run(workflow_id, execution.workflow_name),
+                                None => action_error.set(Some(format!(
+                                    "'{}' was not started from a saved workflow, so it cannot be run again.",
+                                    execution.workflow_name
+                                ))),
+                            },
+                            Err(e) => action_error.set(Some(e.to_string())),
+                        }
+                    });
+                }
+            })
+            .icon("play"),
+            ContextMenuItem::new("Export", {
+                let id = id.clone();
+                move |_| exporter.export(ExportKind::Execution, vec![id.clone()])
+            })
+            .icon("save"),
+            ContextMenuItem::new("Delete", move |_| {
+                selected.set(BTreeSet::from([id.clone()]));
+                confirming_delete.set(true);
+            })
+            .icon("trash")
+            .danger(),
+        ],
+    };
+
+    rsx! {
+        WorkflowRunDialogs { runner }
+        ExportNotification { exporter }
+        ContextMenu {
+            anchor: menu_anchor,
+            items: menu_items,
+            title: menu_on_selection.then(|| format!("{} executions selected", selection_count)),
+        }
+        if let Some(error) = runner.state.read().error.clone() {
+            Alert {
+                alert_type: AlertType::Error,
+                title: Some("Workflow run failed".to_string()),
+                message: error,
+                dismissible: None,
+                on_dismiss: None,
+                actions: None,
+            }
+        }
+        if let Some(error) = action_error() {
+            Alert {
+                alert_type: AlertType::Error,
+                title: None,
+                message: error,
+                dismissible: Some(true),
+                on_dismiss: move |_| action_error.set(None),
+                actions: None,
+            }
+        }
+        ExecutionSelectionBar {
+            selected,
+            all_ids,
+            confirming: confirming_delete,
+            exporter,
+        }
+        div { class: "overflow-hidden bg-white shadow-sm outline outline-1 outline-gray-900/5 sm:rounded-xl dark:divide-white/5 dark:bg-gray-800/50 dark:shadow-none dark:outline-white/10 dark:sm:-outline-offset-1",
+            VirtualList {
+                item_count: loaded,
+                row_height: ROW_HEIGHT,
+                viewport_height: VIEWPORT_HEIGHT,
+                label: "Executions".to_string(),
+                class: "divide-y divide-gray-100 dark:divide-white/5".to_string(),
+                render_row: move |index: usize| {
+                    let execution = executions[index].clone();
+                    let select_id = execution.id.clone();
+                    let menu_id = execution.id.clone();
+                    rsx! {
+                        ExecutionItem {
+                            selected: Some(selected.read().contains(&execution.id)),
+                            on_select: move |_| {
+                                let mut selected = selected.write();
+                                if !selected.remove(&select_id) {
+                                    selected.insert(select_id.clone());
+                                }
+                            },
+                            on_context_menu: move |anchor| {
+                                menu_target.set(Some(menu_id.clone()));
+                                menu_anchor.set(Some(anchor));
+                            },
+                            execution,
+                        }
+                    }
+                },
+                on_near_end: move |_| {
+                    if has_more && !is_fetching && limit() <= loaded {
+                        *limit.write() += PAGE_SIZE;
+                        refetch();
+                    }
+                },
+            }
+        }
+        p { class: "mt-2 text-xs text-zinc-500 dark:text-zinc-400",
+            if has_more {
+                "Showing {loaded} of {total} executions"
+            } else {
+                "{total} executions"
+            }
+        }
+    }
+}
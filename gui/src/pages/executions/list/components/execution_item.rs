@@ -1,5 +1,7 @@
 use crate::components::layout::ListItem;
-use crate::components::{Badge, BadgeColor, IconButton, IconButtonSize, IconButtonVariant};
+use crate::components::{
+    Badge, BadgeColor, IconButton, IconButtonSize, IconButtonVariant, MenuAnchor, Timestamp,
+};
 use crate::layout::router::Route;
 use crate::pages::executions::list::components::ExecutionDeleteDialog;
 use crate::queries::use_delete_execution_mutation;
@@ -8,7 +10,14 @@ use dioxus_router::prelude::use_navigator;
 use s_e_e_core::{WorkflowExecutionStatus, WorkflowExecutionSummary};
 
 #[component]
-pub fn ExecutionItem(execution: WorkflowExecutionSummary) -> Element {
+pub fn ExecutionItem(
+    execution: WorkflowExecutionSummary,
+    /// Shows a checkbox when set, as in the execution history.
+    #[props(default)]
+    selected: Option<bool>,
+    #[props(default)] on_select: Option<EventHandler<()>>,
+    #[props(default)] on_context_menu: Option<EventHandler<MenuAnchor>>,
+) -> Element {
     let navigator = use_navigator();
     let mut show_delete_dialog = use_signal(|| false);
     let (_delete_state, delete_fn) = use_delete_execution_mutation();
@@ -19,6 +28,7 @@ pub fn ExecutionItem(execution: WorkflowExecutionSummary) -> Element {
         WorkflowExecutionStatus::Failed => BadgeColor::Red,
         WorkflowExecutionStatus::Running => BadgeColor::Blue,
         WorkflowExecutionStatus::Pending => BadgeColor::Zinc,
+        WorkflowExecutionStatus::Cancelled => BadgeColor::Zinc,
     };
 
     let status_text = match execution.status {
@@ -27,6 +37,7 @@ pub fn ExecutionItem(execution: WorkflowExecutionSummary) -> Element {
         WorkflowExecutionStatus::Failed => "Failed",
         WorkflowExecutionStatus::Running => "Running",
         WorkflowExecutionStatus::Pending => "Pending",
+        WorkflowExecutionStatus::Cancelled => "Cancelled",
     };
 
     let execution_id_for_nav = execution.id.clone();
@@ -38,6 +49,9 @@ pub fn ExecutionItem(execution: WorkflowExecutionSummary) -> Element {
     rsx! {
         div {
             ListItem {
+                selected,
+                on_select,
+                on_context_menu,
                 icon_name: "workflows".to_string(),
                 icon_variant: Some("outline".to_string()),
                 title: rsx! {
@@ -46,10 +60,14 @@ pub fn ExecutionItem(execution: WorkflowExecutionSummary) -> Element {
                 subtitle: Some(rsx! {
                     div { class: "flex flex-col gap-1",
                         div { class: "text-sm text-gray-500 dark:text-gray-400",
-                            "Executed: {execution.timestamp}"
+                            "Executed "
+                            Timestamp { value: execution.timestamp }
                         }
-                        div { class: "text-xs text-gray-500 dark:text-gray-400",
+                        div { class: "flex items-center gap-2 text-xs text-gray-500 dark:text-gray-400",
                             "{execution.task_count} tasks completed"
+                            for label in execution.labels.iter() {
+                                Badge { key: "{label}", color: BadgeColor::Zinc, "{label}" }
+                            }
                         }
                     }
                 }),
@@ -0,0 +1,132 @@
+use crate::components::{IconButton, IconButtonSize, IconButtonVariant};
+use crate::queries::{
+    use_delete_filter_preset_mutation, use_execution_labels_query, use_filter_presets_query,
+    use_save_filter_preset_mutation, use_workflows_query,
+};
+use dioxus::prelude::*;
+use s_e_e_core::ExecutionFilter;
+
+const SELECT_CLASS: &str = "rounded-lg border border-zinc-300 dark:border-zinc-600 bg-white dark:bg-zinc-800 px-3 py-2 text-sm text-zinc-950 dark:text-white";
+
+/// Label and workflow pickers for the execution history, plus saved presets of
+/// the whole filter.
+#[component]
+pub fn ExecutionFilterBar(filter: Signal<ExecutionFilter>) -> Element {
+    let (labels_state, _) = use_execution_labels_query();
+    let (workflows_state, _) = use_workflows_query();
+    let (presets_state, _) = use_filter_presets_query();
+    let (save_state, save_preset) = use_save_filter_preset_mutation();
+    let (_delete_state, delete_preset) = use_delete_filter_preset_mutation();
+    let mut preset_name = use_signal(String::new);
+
+    let labels = labels_state.data.clone().unwrap_or_default();
+    let workflows = workflows_state.data.clone().unwrap_or_default();
+    let presets = presets_state.data.clone().unwrap_or_default();
+    let active_preset = presets
+        .iter()
+        .find(|preset| preset.filter == filter())
+        .map(|preset| preset.name.clone());
+    let can_save = !filter().is_empty() && !preset_name().trim().is_empty();
+
+    rsx! {
+        div { class: "flex items-center gap-3 flex-wrap",
+            select {
+                "aria-label": "Filter by label",
+                value: filter().label.unwrap_or_default(),
+                onchange: move |evt| {
+                    let value = evt.value();
+                    filter.write().label = (!value.is_empty()).then_some(value);
+                },
+                class: SELECT_CLASS,
+                option { value: "", "Any label" }
+                for label in labels {
+                    option {
+                        key: "{label}",
+                        value: "{label}",
+                        selected: filter().label.as_ref() == Some(&label),
+                        "{label}"
+                    }
+                }
+            }
+
+            select {
+                "aria-label": "Filter by workflow",
+                value: filter().workflow_id.unwrap_or_default(),
+                onchange: move |evt| {
+                    let value = evt.value();
+                    filter.write().workflow_id = (!value.is_empty()).then_some(value);
+                },
+                class: SELECT_CLASS,
+                option { value: "", "Any workflow" }
+                for workflow in workflows {
+                    option {
+                        key: "{workflow.id}",
+                        value: "{workflow.id}",
+                        selected: filter().workflow_id.as_ref() == Some(&workflow.id),
+                        "{workflow.name}"
+                    }
+                }
+            }
+
+            select {
+                "aria-label": "Saved filters",
+                value: active_preset.clone().unwrap_or_default(),
+                onchange: {
+                    let presets = presets.clone();
+                    move |evt: Event<FormData>| {
+                        let value = evt.value();
+                        if let Some(preset) = presets.iter().find(|p| p.name == value) {
+                            filter.set(preset.filter.clone());
+                        }
+                    }
+                },
+                class: SELECT_CLASS,
+                option { value: "", "Saved filters" }
+                for preset in presets.iter() {
+                    option {
+                        key: "{preset.name}",
+                        value: "{preset.name}",
+                        selected: active_preset.as_ref() == Some(&preset.name),
+                        "{preset.name}"
+                    }
+                }
+            }
+
+            if let Some(name) = active_preset {
+                IconButton {
+                    variant: IconButtonVariant::Ghost,
+                    size: IconButtonSize::Small,
+                    onclick: move |_| delete_preset(name.clone()),
+                    icon: Some("trash".to_string()),
+                    icon_variant: "outline".to_string(),
+                    "Delete preset"
+                }
+            } else {
+                input {
+                    r#type: "text",
+                    placeholder: "Preset name",
+                    "aria-label": "Preset name",
+                    value: preset_name(),
+                    oninput: move |evt| preset_name.set(evt.value()),
+                    class: "rounded-lg border border-zinc-300 dark:border-zinc-600 bg-white dark:bg-zinc-800 px-3 py-2 text-sm text-zinc-950 dark:text-white"
+                }
+                IconButton {
+                    variant: IconButtonVariant::Secondary,
+                    size: IconButtonSize::Small,
+                    disabled: Some(!can_save),
+                    onclick: move |_| {
+                        save_preset((preset_name().trim().to_string(), filter()));
+                        preset_name.set(String::new());
+                    },
+                    icon: Some("save".to_string()),
+                    icon_variant: "outline".to_string(),
+                    "Save filter"
+                }
+            }
+
+            if let Some(error) = save_state.read().error.clone() {
+                p { class: "text-sm text-red-600 dark:text-red-400", "{error}" }
+            }
+        }
+    }
+}
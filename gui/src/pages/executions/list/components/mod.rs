@@ -1,7 +1,15 @@
+pub mod archived_executions;
 pub mod delete_dialog;
+pub mod execution_history;
 pub mod execution_item;
+pub mod filter_bar;
 pub mod running_workflow_item;
+pub mod selection_bar;
 
+pub use archived_executions::ArchivedExecutions;
 pub use delete_dialog::ExecutionDeleteDialog;
+pub use execution_history::ExecutionHistory;
 pub use execution_item::ExecutionItem;
+pub use filter_bar::ExecutionFilterBar;
 pub use running_workflow_item::RunningWorkflowItem;
+pub use selection_bar::ExecutionSelectionBar;
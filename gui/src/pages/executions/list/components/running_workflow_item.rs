@@ -1,5 +1,5 @@
 use crate::components::layout::ListItem;
-use crate::components::{Badge, BadgeColor};
+use crate::components::{Badge, BadgeColor, Timestamp};
 use crate::icons::Icon;
 use crate::layout::router::Route;
 use dioxus::prelude::*;
@@ -20,7 +20,8 @@ pub fn RunningWorkflowItem(workflow: WorkflowMetadata) -> Element {
             subtitle: Some(rsx! {
                 div { class: "flex flex-col gap-1",
                     div { class: "text-sm text-gray-500 dark:text-gray-400",
-                        "Started: {workflow.start_timestamp}"
+                        "Started "
+                        Timestamp { value: workflow.start_timestamp }
                     }
                     div { class: "text-xs text-gray-500 dark:text-gray-400",
                         "{workflow.task_ids.len()} tasks"
@@ -0,0 +1,105 @@
+use crate::components::{ConfirmDialog, Exporter, IconButton, IconButtonSize, IconButtonVariant};
+use crate::services::execution::ExecutionService;
+use crate::services::ExportKind;
+use dioxus::prelude::*;
+use s_e_e_dioxus_query::prelude::invalidate_queries_by_prefix;
+use std::collections::BTreeSet;
+
+/// Actions for the executions checked in the history. Executions are not
+/// kept in the trash, so deleting them asks first and cannot be undone.
+/// Setting `confirming` asks to delete the selection, e.g. from a context menu.
+#[component]
+pub fn ExecutionSelectionBar(
+    selected: Signal<BTreeSet<String>>,
+    /// Every execution loaded so far, for "Select all".
+    all_ids: Vec<String>,
+    confirming: Signal<bool>,
+    exporter: Exporter,
+) -> Element {
+    let mut deleting = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let count = selected.read().len();
+
+    let delete = move |_| {
+        confirming.set(false);
+        deleting.set(true);
+        let ids: Vec<String> = selected.peek().iter().cloned().collect();
+        spawn(async move {
+            let mut failed = Vec::new();
+            for id in ids {
+                match ExecutionService::delete_workflow_execution(&id).await {
+                    Ok(()) => {
+                        selected.write().remove(&id);
+                    }
+                    Err(e) => failed.push(e.to_string()),
+                }
+            }
+            deleting.set(false);
+            error.set(failed.into_iter().next());
+            invalidate_queries_by_prefix("executions:");
+        });
+    };
+
+    rsx! {
+        if count > 0 {
+            div { class: "mb-3 flex items-center justify-between gap-3 rounded-lg border border-zinc-200 bg-zinc-50 px-4 py-2 dark:border-zinc-700 dark:bg-zinc-800",
+                span { class: "text-sm font-medium text-zinc-700 dark:text-zinc-300",
+                    "{count} selected"
+                }
+                div { class: "flex items-center gap-2",
+                    if count < all_ids.len() {
+                        IconButton {
+                            variant: IconButtonVariant::Ghost,
+                            size: IconButtonSize::Small,
+                            onclick: move |_| {
+                                selected.set(all_ids.iter().cloned().collect());
+                            },
+                            "Select all"
+                        }
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Ghost,
+                        size: IconButtonSize::Small,
+                        onclick: move |_| selected.write().clear(),
+                        "Clear"
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Secondary,
+                        size: IconButtonSize::Small,
+                        onclick: move |_| {
+                            exporter
+                                .export(ExportKind::Execution, selected.peek().iter().cloned().collect())
+                        },
+                        icon: Some("save".to_string()),
+                        icon_variant: "outline".to_string(),
+                        "Export"
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Danger,
+                        size: IconButtonSize::Small,
+                        disabled: Some(deleting()),
+                        loading: Some(deleting()),
+                        onclick: move |_| confirming.set(true),
+                        icon: Some("trash".to_string()),
+                        icon_variant: "outline".to_string(),
+                        "Delete"
+                    }
+                }
+            }
+        }
+
+        if let Some(message) = error() {
+            p { role: "alert", class: "mb-3 text-sm text-red-600 dark:text-red-400", "{message}" }
+        }
+
+        ConfirmDialog {
+            show: confirming(),
+            title: format!("Delete {} {}?", count, ExportKind::Execution.noun(count)),
+            message: "This permanently deletes them with their tasks and logs. It cannot be undone.".to_string(),
+            confirm_text: "Delete".to_string(),
+            cancel_text: "Cancel".to_string(),
+            on_confirm: delete,
+            on_cancel: move |_| confirming.set(false),
+        }
+    }
+}
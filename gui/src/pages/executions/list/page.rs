@@ -1,25 +1,25 @@
 use crate::components::{
     Alert, AlertType, BadgeButton, BadgeColor, EmptyState, List, PageHeader, SectionCard,
 };
+use crate::icons::Icon;
+use crate::layout::hooks::use_persisted_filter;
+use crate::layout::router::Route;
 use dioxus::prelude::*;
-use s_e_e_core::WorkflowExecutionStatus;
+use dioxus_router::prelude::Link;
+use s_e_e_core::{ExecutionFilter, WorkflowExecutionStatus};
 
-use super::components::{ExecutionItem, RunningWorkflowItem};
-use super::hooks::use_execution_list;
+use super::components::{
+    ArchivedExecutions, ExecutionFilterBar, ExecutionHistory, RunningWorkflowItem,
+};
+use super::hooks::use_running_workflows;
 
 #[component]
 pub fn ExecutionListPage() -> Element {
     tracing::trace!("rendering execution list page");
 
-    let (executions_result, running_result) = use_execution_list();
-
-    if executions_result.is_err() || running_result.is_err() {
-        let error_msg = executions_result.err().unwrap_or_else(|| {
-            running_result
-                .err()
-                .unwrap_or_else(|| "Unknown error".to_string())
-        });
+    let running_result = use_running_workflows();
 
+    if let Err(error_msg) = &running_result {
         return rsx! {
             div { class: "space-y-8",
                 PageHeader {
@@ -30,7 +30,7 @@ pub fn ExecutionListPage() -> Element {
                 Alert {
                     alert_type: AlertType::Error,
                     title: Some("Failed to load executions".to_string()),
-                    message: error_msg,
+                    message: error_msg.clone(),
                     dismissible: None,
                     on_dismiss: None,
                     actions: None,
@@ -39,17 +39,31 @@ pub fn ExecutionListPage() -> Element {
         };
     }
 
-    let workflow_executions = executions_result.unwrap();
     let running_workflows = running_result.unwrap();
 
-    let mut active_filter = use_signal(|| None::<WorkflowExecutionStatus>);
+    let mut filter = use_persisted_filter("executions_filter", ExecutionFilter::default);
+    let active_status = move || filter.read().status.clone();
+    let mut set_status =
+        move |status: Option<WorkflowExecutionStatus>| filter.write().status = status;
 
     rsx! {
         div { class: "space-y-8",
             PageHeader {
                 title: "Executions".to_string(),
                 description: "View and manage your workflow executions".to_string(),
-                actions: None,
+                actions: Some(rsx! {
+                    Link {
+                        to: Route::ExecutionCalendarPage {},
+                        class: "inline-flex items-center gap-x-1.5 rounded-md bg-zinc-100 dark:bg-zinc-800 px-3 py-2 text-sm font-semibold text-zinc-900 dark:text-zinc-100 shadow-sm hover:bg-zinc-200 dark:hover:bg-zinc-700",
+                        Icon {
+                            name: "executions".to_string(),
+                            class: Some("-ml-0.5 h-4 w-4".to_string()),
+                            size: None,
+                            variant: Some("outline".to_string()),
+                        }
+                        "Calendar"
+                    }
+                }),
             }
 
             SectionCard {
@@ -61,46 +75,56 @@ pub fn ExecutionListPage() -> Element {
 
                             BadgeButton {
                                 color: BadgeColor::Zinc,
-                                active: active_filter().is_none(),
-                                onclick: move |_| active_filter.set(None),
+                                active: active_status().is_none(),
+                                onclick: move |_| set_status(None),
                                 "All"
                             }
                             BadgeButton {
                                 color: BadgeColor::Amber,
-                                active: active_filter() == Some(WorkflowExecutionStatus::WaitingForInput),
-                                onclick: move |_| active_filter.set(Some(WorkflowExecutionStatus::WaitingForInput)),
+                                active: active_status() == Some(WorkflowExecutionStatus::WaitingForInput),
+                                onclick: move |_| set_status(Some(WorkflowExecutionStatus::WaitingForInput)),
                                 "Waiting for Input"
                             }
                             BadgeButton {
                                 color: BadgeColor::Emerald,
-                                active: active_filter() == Some(WorkflowExecutionStatus::Complete),
-                                onclick: move |_| active_filter.set(Some(WorkflowExecutionStatus::Complete)),
+                                active: active_status() == Some(WorkflowExecutionStatus::Complete),
+                                onclick: move |_| set_status(Some(WorkflowExecutionStatus::Complete)),
                                 "Complete"
                             }
                             BadgeButton {
                                 color: BadgeColor::Blue,
-                                active: active_filter() == Some(WorkflowExecutionStatus::Running),
-                                onclick: move |_| active_filter.set(Some(WorkflowExecutionStatus::Running)),
+                                active: active_status() == Some(WorkflowExecutionStatus::Running),
+                                onclick: move |_| set_status(Some(WorkflowExecutionStatus::Running)),
                                 "Running"
                             }
                             BadgeButton {
                                 color: BadgeColor::Red,
-                                active: active_filter() == Some(WorkflowExecutionStatus::Failed),
-                                onclick: move |_| active_filter.set(Some(WorkflowExecutionStatus::Failed)),
+                                active: active_status() == Some(WorkflowExecutionStatus::Failed),
+                                onclick: move |_| set_status(Some(WorkflowExecutionStatus::Failed)),
                                 "Failed"
                             }
                             BadgeButton {
                                 color: BadgeColor::Zinc,
-                                active: active_filter() == Some(WorkflowExecutionStatus::Pending),
-                                onclick: move |_| active_filter.set(Some(WorkflowExecutionStatus::Pending)),
+                                active: active_status() == Some(WorkflowExecutionStatus::Cancelled),
+                                onclick: move |_| set_status(Some(WorkflowExecutionStatus::Cancelled)),
+                                "Cancelled"
+                            }
+                            BadgeButton {
+                                color: BadgeColor::Zinc,
+                                active: active_status() == Some(WorkflowExecutionStatus::Pending),
+                                onclick: move |_| set_status(Some(WorkflowExecutionStatus::Pending)),
                                 "Pending"
                             }
                         }
 
+                        if active_status() != Some(WorkflowExecutionStatus::Running) {
+                            ExecutionFilterBar { filter }
+                        }
+
 
                         {{
 
-                            if active_filter() == Some(WorkflowExecutionStatus::Running) {
+                            if active_status() == Some(WorkflowExecutionStatus::Running) {
                                 rsx! {
                                     if running_workflows.is_empty() {
                                         EmptyState { message: "No running workflows.".to_string() }
@@ -113,25 +137,10 @@ pub fn ExecutionListPage() -> Element {
                                     }
                                 }
                             } else {
-                                let mut items: Vec<_> = match active_filter() {
-                                    Some(status) => workflow_executions
-                                        .iter()
-                                        .filter(|exec| exec.status == status)
-                                        .cloned()
-                                        .collect(),
-                                    None => workflow_executions.to_vec(),
-                                };
-                                items.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                                let filter = filter();
+                                let filter_key = serde_json::to_string(&filter).unwrap_or_default();
                                 rsx! {
-                                    if items.is_empty() {
-                                        EmptyState { message: "No executions with this status.".to_string() }
-                                    } else {
-                                        List {
-                                            for execution in items.iter() {
-                                                ExecutionItem { execution: execution.clone() }
-                                            }
-                                        }
-                                    }
+                                    ExecutionHistory { key: "{filter_key}", filter }
                                 }
                             }
                         }}
@@ -139,6 +148,14 @@ pub fn ExecutionListPage() -> Element {
                 },
                 padding: None,
             }
+
+            SectionCard {
+                title: Some("Archived".to_string()),
+                children: rsx! {
+                    ArchivedExecutions {}
+                },
+                padding: None,
+            }
         }
     }
 }
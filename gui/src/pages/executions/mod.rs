@@ -1,6 +1,8 @@
+pub mod calendar;
 pub mod details;
 pub mod list;
 
+pub use calendar::{ExecutionCalendarPage, ExecutionDayPage};
 pub use details::task_details::TaskDetailsPage as WorkflowDetailsTaskDetailsPage;
 pub use details::WorkflowDetailsPage;
 pub use list::ExecutionListPage;
@@ -1,5 +1,5 @@
 use crate::components::layout::ListItem;
-use crate::components::{Badge, BadgeColor};
+use crate::components::{Badge, BadgeColor, Timestamp};
 use crate::layout::router::Route;
 use dioxus::prelude::*;
 use dioxus_router::prelude::use_navigator;
@@ -15,6 +15,7 @@ pub fn ExecutionListItem(execution: WorkflowExecutionSummary) -> Element {
         WorkflowExecutionStatus::Failed => BadgeColor::Red,
         WorkflowExecutionStatus::Running => BadgeColor::Blue,
         WorkflowExecutionStatus::Pending => BadgeColor::Zinc,
+        WorkflowExecutionStatus::Cancelled => BadgeColor::Zinc,
     };
 
     let status_text = match execution.status {
@@ -23,6 +24,7 @@ pub fn ExecutionListItem(execution: WorkflowExecutionSummary) -> Element {
         WorkflowExecutionStatus::Failed => "Failed",
         WorkflowExecutionStatus::Running => "Running",
         WorkflowExecutionStatus::Pending => "Pending",
+        WorkflowExecutionStatus::Cancelled => "Cancelled",
     };
 
     rsx! {
@@ -33,7 +35,7 @@ pub fn ExecutionListItem(execution: WorkflowExecutionSummary) -> Element {
                 {execution.workflow_name.clone()}
             },
             subtitle: Some(rsx! {
-                {execution.timestamp.to_string()}
+                Timestamp { value: execution.timestamp }
             }),
             right_content: Some(rsx! {
                 Badge {
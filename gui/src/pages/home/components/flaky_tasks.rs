@@ -0,0 +1,63 @@
+use crate::components::layout::ListItem;
+use crate::components::{Badge, BadgeColor, List, SectionCard};
+use crate::layout::router::Route;
+use crate::queries::use_flaky_tasks_query;
+use dioxus::prelude::*;
+use dioxus_router::prelude::use_navigator;
+use s_e_e_core::FlakyTask;
+
+const TOP_FLAKY_TASKS: usize = 5;
+
+/// The tasks that pass and fail intermittently, the likeliest to need retries.
+/// Hidden while there are none.
+#[component]
+pub fn FlakyTasksCard() -> Element {
+    let (state, _refetch) = use_flaky_tasks_query(TOP_FLAKY_TASKS);
+    let tasks = state.data.clone().unwrap_or_default();
+    if tasks.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        SectionCard {
+            title: Some("Flaky Tasks".to_string()),
+            children: rsx! {
+                List {
+                    for task in tasks {
+                        FlakyTaskItem { key: "{task.workflow_name}-{task.task_id}", task: task.clone() }
+                    }
+                }
+            },
+            padding: None,
+        }
+    }
+}
+
+#[component]
+fn FlakyTaskItem(task: FlakyTask) -> Element {
+    let navigator = use_navigator();
+    let percent = (task.flip_rate() * 100.0).round();
+    let workflow_id = task.workflow_id.clone();
+
+    rsx! {
+        ListItem {
+            icon_name: "exclamation_circle".to_string(),
+            icon_variant: Some("outline".to_string()),
+            title: rsx! {
+                "{task.task_name}"
+            },
+            subtitle: Some(rsx! {
+                "{task.workflow_name} · failed {task.failures} of {task.runs} recent runs"
+            }),
+            right_content: Some(rsx! {
+                Badge { color: BadgeColor::Amber, "Flips {percent}% of runs" }
+            }),
+            show_chevron: workflow_id.is_some(),
+            onclick: move |_| {
+                if let Some(id) = workflow_id.clone() {
+                    navigator.push(Route::WorkflowEditPage { id });
+                }
+            },
+        }
+    }
+}
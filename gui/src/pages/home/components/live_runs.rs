@@ -0,0 +1,183 @@
+use crate::components::{
+    Alert, AlertType, ConfirmDialog, IconButton, IconButtonSize, IconButtonVariant, SectionCard,
+};
+use crate::layout::router::Route;
+use crate::queries::{use_cancel_run_mutation, use_live_runs_query};
+use crate::services::execution::LiveRun;
+use chrono::{DateTime, Utc};
+use dioxus::prelude::*;
+use dioxus_router::prelude::use_navigator;
+use std::time::Duration;
+
+/// "0:42", "12:05" or "1:02:03".
+fn format_elapsed(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    let (hours, minutes, seconds) = (seconds / 3_600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+/// The runs still going, each with the task it is on, how long it has been
+/// running and how many of its tasks are done. Hidden while nothing runs.
+#[component]
+pub fn LiveRunsCard() -> Element {
+    let (state, _refetch) = use_live_runs_query();
+    let (cancel_state, cancel) = use_cancel_run_mutation();
+    let mut confirming = use_signal(|| None::<LiveRun>);
+    let mut now = use_signal(Utc::now);
+
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            now.set(Utc::now());
+        }
+    });
+
+    let runs = state.data.clone().unwrap_or_default();
+    if runs.is_empty() {
+        return rsx! {};
+    }
+
+    let cancelling = cancel_state.read().is_loading;
+    let not_here = cancel_state.read().data == Some(false);
+    let cancel_error = cancel_state.read().error.clone();
+    let title = match confirming.read().as_ref() {
+        Some(run) => format!("Cancel {}?", run.workflow_name),
+        None => String::new(),
+    };
+
+    rsx! {
+        SectionCard {
+            title: Some("Running Now".to_string()),
+            children: rsx! {
+                div { class: "space-y-3",
+                    if not_here {
+                        p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                            "That run was started outside this window, such as from the command line, and can only be stopped there."
+                        }
+                    }
+                    if let Some(error) = cancel_error {
+                        Alert {
+                            alert_type: AlertType::Error,
+                            title: Some("Could not cancel the run".to_string()),
+                            message: error,
+                            dismissible: None,
+                            on_dismiss: None,
+                            actions: None,
+                        }
+                    }
+                    div { class: "grid gap-3 sm:grid-cols-2",
+                        for run in runs {
+                            LiveRunItem {
+                                key: "{run.id}",
+                                run: run.clone(),
+                                now: now(),
+                                cancelling,
+                                on_cancel: move |run| confirming.set(Some(run)),
+                            }
+                        }
+                    }
+                }
+            },
+            padding: Some("p-4".to_string()),
+        }
+        ConfirmDialog {
+            show: confirming.read().is_some(),
+            title,
+            message: "Its running tasks are stopped and the execution is saved as cancelled.".to_string(),
+            confirm_text: "Cancel Run".to_string(),
+            cancel_text: "Keep Running".to_string(),
+            on_confirm: move |_| {
+                if let Some(run) = confirming.take() {
+                    cancel(run.id);
+                }
+            },
+            on_cancel: move |_| confirming.set(None),
+        }
+    }
+}
+
+#[component]
+fn LiveRunItem(
+    run: LiveRun,
+    now: DateTime<Utc>,
+    cancelling: bool,
+    on_cancel: EventHandler<LiveRun>,
+) -> Element {
+    let navigator = use_navigator();
+    let elapsed = format_elapsed((now - run.started_at).num_seconds());
+    let width = format!("{:.0}%", run.fraction() * 100.0);
+    let current = run
+        .status
+        .current_task
+        .clone()
+        .unwrap_or_else(|| "Starting...".to_string());
+    let task_status = run
+        .status
+        .progress
+        .as_ref()
+        .map(|progress| progress.to_string());
+    let done = run.status.finished_tasks.min(run.total_tasks);
+    let open_id = run.id.clone();
+    let cancel_run = run.clone();
+
+    rsx! {
+        div { class: "rounded-lg border border-zinc-200 dark:border-zinc-700 p-4 space-y-3",
+            div { class: "flex items-start justify-between gap-3",
+                div { class: "min-w-0",
+                    div { class: "truncate text-sm font-medium text-zinc-900 dark:text-white",
+                        "{run.workflow_name}"
+                    }
+                    div { class: "truncate text-xs text-zinc-500 dark:text-zinc-400",
+                        "{current}"
+                        if let Some(task_status) = task_status {
+                            " · {task_status}"
+                        }
+                    }
+                }
+                span { class: "shrink-0 font-mono text-xs text-zinc-500 dark:text-zinc-400",
+                    "{elapsed}"
+                }
+            }
+            div { class: "space-y-1",
+                div {
+                    class: "h-1.5 w-full overflow-hidden rounded-full bg-zinc-200 dark:bg-zinc-700",
+                    role: "progressbar",
+                    "aria-valuemin": "0",
+                    "aria-valuemax": "{run.total_tasks}",
+                    "aria-valuenow": "{done}",
+                    div { class: "h-full rounded-full bg-blue-600 transition-all", style: "width: {width}" }
+                }
+                if run.total_tasks > 0 {
+                    div { class: "text-xs text-zinc-500 dark:text-zinc-400",
+                        "{done} of {run.total_tasks} tasks done"
+                    }
+                }
+            }
+            div { class: "flex justify-end gap-2",
+                IconButton {
+                    variant: IconButtonVariant::Secondary,
+                    size: IconButtonSize::Small,
+                    disabled: Some(cancelling),
+                    onclick: move |_| on_cancel.call(cancel_run.clone()),
+                    icon: Some("stop".to_string()),
+                    icon_variant: "outline".to_string(),
+                    "Cancel"
+                }
+                IconButton {
+                    variant: IconButtonVariant::Primary,
+                    size: IconButtonSize::Small,
+                    onclick: move |_| {
+                        navigator.push(Route::WorkflowDetailsPage { id: open_id.clone() });
+                    },
+                    icon: Some("chevron_right".to_string()),
+                    icon_variant: "outline".to_string(),
+                    "Open"
+                }
+            }
+        }
+    }
+}
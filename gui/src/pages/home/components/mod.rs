@@ -1,3 +1,9 @@
 pub mod execution_list_item;
+pub mod flaky_tasks;
+pub mod live_runs;
+pub mod onboarding_card;
 
 pub use execution_list_item::ExecutionListItem;
+pub use flaky_tasks::FlakyTasksCard;
+pub use live_runs::LiveRunsCard;
+pub use onboarding_card::OnboardingCard;
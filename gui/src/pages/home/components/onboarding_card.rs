@@ -0,0 +1,52 @@
+use crate::components::{IconButton, IconButtonSize, IconButtonVariant, SectionCard};
+use crate::queries::use_complete_onboarding_mutation;
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+/// First-run prompt asking whether to start with the bundled examples.
+#[component]
+pub fn OnboardingCard() -> Element {
+    let (state, complete_fn) = use_complete_onboarding_mutation();
+    let complete_fn = Rc::new(complete_fn);
+    let is_saving = state.read().is_loading;
+    let error = state.read().error.clone();
+
+    let add_examples = complete_fn.clone();
+    let start_empty = complete_fn;
+
+    rsx! {
+        SectionCard {
+            title: Some("Get started".to_string()),
+            children: rsx! {
+                div { class: "space-y-4",
+                    p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                        "Your library is empty. Add the example workflows to see how tasks, parallel steps and user input fit together, or start from a blank slate. Examples stay available under Workflows either way."
+                    }
+                    if let Some(e) = error.as_deref() {
+                        p { class: "text-sm text-red-700 dark:text-red-300", "{e}" }
+                    }
+                    div { class: "flex items-center gap-3",
+                        IconButton {
+                            variant: IconButtonVariant::Primary,
+                            size: IconButtonSize::Medium,
+                            disabled: Some(is_saving),
+                            loading: Some(is_saving),
+                            onclick: move |_| add_examples(true),
+                            icon: if is_saving { None } else { Some("plus".to_string()) },
+                            icon_variant: "outline".to_string(),
+                            "Add examples"
+                        }
+                        IconButton {
+                            variant: IconButtonVariant::Secondary,
+                            size: IconButtonSize::Medium,
+                            disabled: Some(is_saving),
+                            onclick: move |_| start_empty(false),
+                            "Start empty"
+                        }
+                    }
+                }
+            },
+            padding: None,
+        }
+    }
+}
@@ -1,18 +1,19 @@
 use crate::components::layout::ListItem as LayoutListItem;
-use crate::components::{BadgeButton, BadgeColor, EmptyState, List, PageHeader, SectionCard};
-use crate::queries::{
-    use_execute_workflow_mutation, use_workflow_executions_query, use_workflows_query,
+use crate::components::{
+    use_workflow_runner, Alert, AlertType, BadgeButton, BadgeColor, EmptyState, List, PageHeader,
+    SectionCard, WorkflowRunDialogs,
 };
+use crate::queries::{use_settings_query, use_workflow_executions_query, use_workflows_query};
 use dioxus::prelude::*;
 use s_e_e_core::WorkflowExecutionStatus;
 
-use super::components::ExecutionListItem;
+use super::components::{ExecutionListItem, FlakyTasksCard, LiveRunsCard, OnboardingCard};
 
 #[component]
 pub fn HomePage() -> Element {
     let (workflows_state, _refetch_workflows) = use_workflows_query();
     let (executions_state, _refetch_executions) = use_workflow_executions_query();
-    let (_exec_mutation_state, execute_fn) = use_execute_workflow_mutation();
+    let (settings_state, _refetch_settings) = use_settings_query();
 
     let workflows = if workflows_state.is_loading {
         return rsx! {
@@ -71,6 +72,13 @@ pub fn HomePage() -> Element {
     };
 
     let mut active_filter = use_signal(|| None::<WorkflowExecutionStatus>);
+    let runner = use_workflow_runner();
+
+    let show_onboarding = workflows.is_empty()
+        && settings_state
+            .data
+            .as_ref()
+            .is_some_and(|settings| !settings.onboarding_complete);
 
     rsx! {
         div { class: "space-y-8",
@@ -80,6 +88,24 @@ pub fn HomePage() -> Element {
                 actions: None,
             }
 
+            if show_onboarding {
+                OnboardingCard {}
+            }
+
+            WorkflowRunDialogs { runner }
+
+            if let Some(error) = runner.state.read().error.clone() {
+                Alert {
+                    alert_type: AlertType::Error,
+                    title: Some("Workflow run failed".to_string()),
+                    message: error,
+                    dismissible: None,
+                    on_dismiss: None,
+                    actions: None,
+                }
+            }
+
+            LiveRunsCard {}
 
             SectionCard {
                 title: Some("Recent Executions".to_string()),
@@ -133,6 +159,12 @@ pub fn HomePage() -> Element {
                                         onclick: move |_| active_filter.set(Some(WorkflowExecutionStatus::Failed)),
                                         "Failed"
                                     }
+                                    BadgeButton {
+                                        color: BadgeColor::Zinc,
+                                        active: active_filter() == Some(WorkflowExecutionStatus::Cancelled),
+                                        onclick: move |_| active_filter.set(Some(WorkflowExecutionStatus::Cancelled)),
+                                        "Cancelled"
+                                    }
                                     BadgeButton {
                                         color: BadgeColor::Zinc,
                                         active: active_filter() == Some(WorkflowExecutionStatus::Pending),
@@ -157,6 +189,7 @@ pub fn HomePage() -> Element {
                 padding: None,
             }
 
+            FlakyTasksCard {}
 
             div { class: "space-y-4",
                 h2 { class: "text-lg font-semibold text-zinc-900 dark:text-white", "Execute Workflows" }
@@ -172,7 +205,7 @@ pub fn HomePage() -> Element {
                           for workflow in workflows.iter().take(6) {
                               {
                                   let workflow_id = workflow.id.clone();
-                                  let execute_fn = execute_fn.clone();
+                                  let workflow_name = workflow.get_name().to_string();
                                   rsx! {
                                       LayoutListItem {
                                           icon_name: "play".to_string(),
@@ -205,7 +238,7 @@ pub fn HomePage() -> Element {
                                           }),
                                           onclick: move |_| {
                                               tracing::debug!("[HomePage] Clicked workflow: {}", workflow_id);
-                                              execute_fn(workflow_id.clone());
+                                              runner.run(workflow_id.clone(), workflow_name.clone());
                                               tracing::debug!("[HomePage] Execution started");
                                           },
                                       }
@@ -4,7 +4,10 @@ pub mod prompts;
 pub mod settings;
 pub mod workflows;
 
-pub use executions::{ExecutionListPage, WorkflowDetailsPage, WorkflowDetailsTaskDetailsPage};
+pub use executions::{
+    ExecutionCalendarPage, ExecutionDayPage, ExecutionListPage, WorkflowDetailsPage,
+    WorkflowDetailsTaskDetailsPage,
+};
 pub use home::HomePage;
 pub use prompts::{UserPromptEditPage, UserPromptEditPageNew, UserPromptsListPage};
 pub use settings::SettingsPage;
@@ -0,0 +1,50 @@
+use crate::components::SectionCard;
+use crate::services::prompt::UserPromptService;
+use dioxus::prelude::*;
+
+/// The prompt as it will be sent, with every `{{include:...}}` expanded.
+/// Hidden until the content includes another prompt.
+#[component]
+pub fn CompositionPreview(prompt_id: Option<String>, content: Signal<String>) -> Element {
+    let mut rendered = use_signal(|| None::<Result<String, String>>);
+
+    use_effect(move || {
+        let text = content();
+        let id = prompt_id.clone();
+        if s_e_e_core::included_prompt_ids(&text).is_empty() {
+            rendered.set(None);
+            return;
+        }
+        spawn(async move {
+            let result = UserPromptService::preview_composition(id, text)
+                .await
+                .map_err(|e| e.to_string());
+            rendered.set(Some(result));
+        });
+    });
+
+    let Some(result) = rendered() else {
+        return rsx! {};
+    };
+
+    rsx! {
+        SectionCard {
+            title: Some("Composition Preview".to_string()),
+            children: rsx! {
+                match result {
+                    Ok(text) => rsx! {
+                        pre { class: "max-h-96 overflow-auto whitespace-pre-wrap rounded-md bg-zinc-50 dark:bg-zinc-900 p-3 text-sm text-zinc-800 dark:text-zinc-200",
+                            "{text}"
+                        }
+                    },
+                    Err(e) => rsx! {
+                        div { class: "p-4 bg-red-50 dark:bg-red-900/20 rounded-lg border border-red-200 dark:border-red-700",
+                            p { class: "text-sm text-red-700 dark:text-red-300", "{e}" }
+                        }
+                    },
+                }
+            },
+            padding: None,
+        }
+    }
+}
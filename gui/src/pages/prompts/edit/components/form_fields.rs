@@ -6,6 +6,7 @@ pub fn PromptFormFields(
     prompt_id: Signal<String>,
     name: Signal<String>,
     content: Signal<String>,
+    tags: Signal<String>,
     validation_error: Signal<String>,
     is_new: bool,
 ) -> Element {
@@ -34,12 +35,22 @@ pub fn PromptFormFields(
                         disabled: None,
                     }
 
+                    TextInput {
+                        label: "Tags".to_string(),
+                        value: tags,
+                        oninput: move |value| tags.set(value),
+                        placeholder: Some("e.g., code, review".to_string()),
+                        help_text: Some("Comma-separated labels for filtering the prompt list".to_string()),
+                        required: Some(false),
+                        disabled: None,
+                    }
+
                     TextareaInput {
                         label: "Prompt Content".to_string(),
                         value: content,
                         oninput: move |value| content.set(value),
                         placeholder: Some("Enter the prompt template content...".to_string()),
-                        help_text: Some("The actual prompt text that will be sent to the AI model".to_string()),
+                        help_text: Some("The actual prompt text that will be sent to the AI model. Use {{include:prompt-id}} to insert another prompt.".to_string()),
                         rows: Some(15),
                         disabled: None,
                     }
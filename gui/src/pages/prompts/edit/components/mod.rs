@@ -1,7 +1,9 @@
+pub mod composition_preview;
 pub mod delete_dialog;
 pub mod form_fields;
 pub mod form_header;
 
+pub use composition_preview::CompositionPreview;
 pub use delete_dialog::PromptDeleteDialog;
 pub use form_fields::PromptFormFields;
 pub use form_header::PromptFormHeader;
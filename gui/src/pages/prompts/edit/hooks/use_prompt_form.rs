@@ -5,6 +5,8 @@ pub struct PromptFormState {
     pub prompt_id: Signal<String>,
     pub name: Signal<String>,
     pub content: Signal<String>,
+    /// Comma-separated, as typed.
+    pub tags: Signal<String>,
     pub validation_error: Signal<String>,
 }
 
@@ -12,6 +14,7 @@ pub fn use_prompt_form(id: String, loaded_prompt: Option<Prompt>) -> PromptFormS
     let mut prompt_id = use_signal(String::new);
     let mut name = use_signal(String::new);
     let mut content = use_signal(String::new);
+    let mut tags = use_signal(String::new);
     let validation_error = use_signal(String::new);
 
     let mut is_loaded = use_signal(|| false);
@@ -21,6 +24,7 @@ pub fn use_prompt_form(id: String, loaded_prompt: Option<Prompt>) -> PromptFormS
                 prompt_id.set(prompt.id.clone());
                 name.set(prompt.name.clone());
                 content.set(prompt.content.clone());
+                tags.set(prompt.tags.join(", "));
                 is_loaded.set(true);
             }
         }
@@ -30,6 +34,7 @@ pub fn use_prompt_form(id: String, loaded_prompt: Option<Prompt>) -> PromptFormS
         prompt_id,
         name,
         content,
+        tags,
         validation_error,
     }
 }
@@ -4,7 +4,9 @@ use crate::queries::prompt_queries::use_prompt_query;
 use dioxus::prelude::*;
 use dioxus_router::prelude::use_navigator;
 
-use super::components::{PromptDeleteDialog, PromptFormFields, PromptFormHeader};
+use super::components::{
+    CompositionPreview, PromptDeleteDialog, PromptFormFields, PromptFormHeader,
+};
 use super::hooks::{use_notification_state, use_prompt_form, use_prompt_mutations};
 use super::validation::{create_prompt_from_fields, validate_prompt_fields};
 
@@ -45,6 +47,7 @@ pub fn UserPromptEditPage(id: String) -> Element {
     let mutations = use_prompt_mutations();
     let mut notification = use_notification_state();
     let mut show_delete_dialog = use_signal(|| false);
+    let preview_id = (!is_new).then(|| id.clone());
 
     use_effect(move || {
         let delete_state = mutations.delete_state.read();
@@ -76,6 +79,7 @@ pub fn UserPromptEditPage(id: String) -> Element {
                         form_state.prompt_id.read().to_string(),
                         form_state.name.read().to_string(),
                         form_state.content.read().to_string(),
+                        &form_state.tags.read(),
                     );
 
 
@@ -100,10 +104,16 @@ pub fn UserPromptEditPage(id: String) -> Element {
                 prompt_id: form_state.prompt_id,
                 name: form_state.name,
                 content: form_state.content,
+                tags: form_state.tags,
                 validation_error: form_state.validation_error,
                 is_new,
             }
 
+            CompositionPreview {
+                prompt_id: preview_id,
+                content: form_state.content,
+            }
+
             Notification {
                 notification,
                 on_close: move |_| {
@@ -13,13 +13,21 @@ pub fn validate_prompt_fields(prompt_id: &str, name: &str, content: &str) -> Res
     Ok(())
 }
 
-pub fn create_prompt_from_fields(prompt_id: String, name: String, content: String) -> Prompt {
+pub fn create_prompt_from_fields(
+    prompt_id: String,
+    name: String,
+    content: String,
+    tags: &str,
+) -> Prompt {
     let now = chrono::Utc::now();
     let content_str = content.trim().to_string();
-    Prompt {
+    let mut prompt = Prompt {
         id: prompt_id.trim().to_string(),
         name: name.trim().to_string(),
         content: content_str,
         created_at: now,
-    }
+        tags: Vec::new(),
+    };
+    prompt.set_tags(tags.split(','));
+    prompt
 }
@@ -0,0 +1,82 @@
+use crate::components::{BadgeButton, BadgeColor};
+use dioxus::prelude::*;
+use s_e_e_core::PromptSort;
+
+fn sort_label(sort: PromptSort) -> &'static str {
+    match sort {
+        PromptSort::Name => "Name",
+        PromptSort::Newest => "Newest",
+        PromptSort::RecentlyUsed => "Recently used",
+        PromptSort::MostUsed => "Most used",
+    }
+}
+
+/// Search box, sort order and tag toggles for the prompt list.
+#[component]
+pub fn PromptFilterBar(
+    search: Signal<String>,
+    selected_tags: Signal<Vec<String>>,
+    sort: Signal<PromptSort>,
+    available_tags: Vec<String>,
+) -> Element {
+    rsx! {
+        div { class: "space-y-3",
+            div { class: "flex items-center gap-3",
+                input {
+                    r#type: "search",
+                    placeholder: "Search name or content...",
+                    "aria-label": "Search prompts",
+                    value: search(),
+                    oninput: move |evt| search.set(evt.value()),
+                    class: "block w-full px-3 py-2 text-sm text-zinc-950 dark:text-white bg-white dark:bg-zinc-800 border border-zinc-300 dark:border-zinc-600 rounded-lg focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent"
+                }
+                select {
+                    "aria-label": "Sort prompts",
+                    value: sort().as_str(),
+                    onchange: move |evt| {
+                        let value = evt.value();
+                        if let Some(next) = PromptSort::ALL.into_iter().find(|s| s.as_str() == value) {
+                            sort.set(next);
+                        }
+                    },
+                    class: "rounded-lg border border-zinc-300 dark:border-zinc-600 bg-white dark:bg-zinc-800 px-3 py-2 text-sm text-zinc-950 dark:text-white",
+                    for option in PromptSort::ALL {
+                        option { value: option.as_str(), selected: option == sort(), {sort_label(option)} }
+                    }
+                }
+            }
+
+            if !available_tags.is_empty() {
+                div { class: "flex items-center gap-2 flex-wrap",
+                    BadgeButton {
+                        color: BadgeColor::Zinc,
+                        active: selected_tags.read().is_empty(),
+                        onclick: move |_| selected_tags.set(Vec::new()),
+                        "All tags"
+                    }
+                    for tag in available_tags {
+                        {
+                            let active = selected_tags.read().contains(&tag);
+                            rsx! {
+                                BadgeButton {
+                                    key: "{tag}",
+                                    color: BadgeColor::Blue,
+                                    active,
+                                    onclick: move |_| {
+                                        let mut tags = selected_tags.write();
+                                        if let Some(index) = tags.iter().position(|t| *t == tag) {
+                                            tags.remove(index);
+                                        } else {
+                                            tags.push(tag.clone());
+                                        }
+                                    },
+                                    "{tag}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use super::PromptList;
+use crate::components::SectionCard;
+use crate::pages::prompts::list::hooks::use_prompts_list;
+use dioxus::prelude::*;
+use s_e_e_core::PromptFilter;
+
+/// The prompt list for one filter. Render with a `key` per filter so a change
+/// starts a fresh query.
+#[component]
+pub fn FilteredPrompts(filter: PromptFilter, on_prompt_click: EventHandler<String>) -> Element {
+    let is_filtered = filter
+        != PromptFilter {
+            sort: filter.sort,
+            ..PromptFilter::default()
+        };
+
+    match use_prompts_list(filter) {
+        Ok(prompts) => rsx! {
+            PromptList { prompts, is_filtered, on_prompt_click }
+        },
+        Err(e) => rsx! {
+            SectionCard {
+                title: Some("Error".to_string()),
+                children: rsx! {
+                    div { class: "text-red-600 dark:text-red-400", "{e}" }
+                },
+                padding: None,
+            }
+        },
+    }
+}
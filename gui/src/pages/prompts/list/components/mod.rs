@@ -1,5 +1,9 @@
+pub mod filter_bar;
+pub mod filtered_prompts;
 pub mod list_header;
 pub mod prompt_list;
 
+pub use filter_bar::PromptFilterBar;
+pub use filtered_prompts::FilteredPrompts;
 pub use list_header::PromptsListHeader;
 pub use prompt_list::PromptList;
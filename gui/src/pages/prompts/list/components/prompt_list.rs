@@ -1,17 +1,117 @@
 use crate::components::layout::{List, ListItem};
-use crate::components::{EmptyState, SectionCard};
+use crate::components::{
+    use_exporter, Alert, AlertType, BulkDeleteBar, ContextMenu, ContextMenuItem, EmptyState,
+    ExportNotification, MenuAnchor, SectionCard, Timestamp,
+};
+use crate::services::{ExportKind, UserPromptService};
 use dioxus::prelude::*;
-use s_e_e_core::Prompt;
+use s_e_e_core::{PromptWithUsage, TrashKind};
+use s_e_e_dioxus_query::prelude::invalidate_queries_by_prefix;
+use std::collections::BTreeSet;
 
 #[component]
-pub fn PromptList(prompts: Vec<Prompt>, on_prompt_click: EventHandler<String>) -> Element {
+pub fn PromptList(
+    prompts: Vec<PromptWithUsage>,
+    is_filtered: bool,
+    on_prompt_click: EventHandler<String>,
+) -> Element {
+    let mut selected = use_signal(BTreeSet::<String>::new);
+    let mut confirming_delete = use_signal(|| false);
+    let all_ids: Vec<String> = prompts.iter().map(|item| item.prompt.id.clone()).collect();
+    let exporter = use_exporter();
+    let mut menu_anchor = use_signal(|| None::<MenuAnchor>);
+    let mut menu_target = use_signal(|| None::<String>);
+    let mut action_error = use_signal(|| None::<String>);
+
+    // As on the workflows page, a row in a multi-selection gets the bulk actions.
+    let selection_count = selected.read().len();
+    let menu_on_selection =
+        menu_target().is_some_and(|id| selection_count > 1 && selected.read().contains(&id));
+    let menu_items = match menu_target() {
+        None => Vec::new(),
+        Some(_) if menu_on_selection => vec![
+            ContextMenuItem::new(format!("Export {} prompts", selection_count), move |_| {
+                exporter.export(
+                    ExportKind::Prompt,
+                    selected.peek().iter().cloned().collect(),
+                )
+            })
+            .icon("save"),
+            ContextMenuItem::new("Clear selection", move |_| selected.write().clear()).icon("x"),
+            ContextMenuItem::new(format!("Delete {} prompts", selection_count), move |_| {
+                confirming_delete.set(true)
+            })
+            .icon("trash")
+            .danger(),
+        ],
+        Some(id) => vec![
+            ContextMenuItem::new("Edit", {
+                let id = id.clone();
+                move |_| on_prompt_click.call(id.clone())
+            })
+            .icon("code_bracket"),
+            ContextMenuItem::new("Duplicate", {
+                let id = id.clone();
+                move |_| {
+                    let id = id.clone();
+                    spawn(async move {
+                        match UserPromptService::duplicate_prompt(&id).await {
+                            Ok(_) => invalidate_queries_by_prefix("prompts:"),
+                            Err(e) => action_error.set(Some(e.to_string())),
+                        }
+                    });
+                }
+            })
+            .icon("copy"),
+            ContextMenuItem::new("Export", {
+                let id = id.clone();
+                move |_| exporter.export(ExportKind::Prompt, vec![id.clone()])
+            })
+            .icon("save"),
+            ContextMenuItem::new("Delete", move |_| {
+                selected.set(BTreeSet::from([id.clone()]));
+                confirming_delete.set(true);
+            })
+            .icon("trash")
+            .danger(),
+        ],
+    };
+
     rsx! {
+        ExportNotification { exporter }
+        ContextMenu {
+            anchor: menu_anchor,
+            items: menu_items,
+            title: menu_on_selection.then(|| format!("{} prompts selected", selection_count)),
+        }
+        if let Some(error) = action_error() {
+            Alert {
+                alert_type: AlertType::Error,
+                title: None,
+                message: error,
+                dismissible: Some(true),
+                on_dismiss: move |_| action_error.set(None),
+                actions: None,
+            }
+        }
+
+        // Kept outside the list so the Undo toast survives deleting every prompt.
+        BulkDeleteBar {
+            kind: TrashKind::Prompt,
+            selected,
+            all_ids,
+            confirming: confirming_delete,
+        }
         if prompts.is_empty() {
             SectionCard {
                 title: Some("Prompts".to_string()),
                 children: rsx! {
                     EmptyState {
-                        message: "No prompts yet. Create your first prompt to get started.".to_string(),
+                        message: if is_filtered {
+                            "No prompts match these filters.".to_string()
+                        } else {
+                            "No prompts yet. Create your first prompt to get started.".to_string()
+                        },
                     }
                 },
                 padding: None,
@@ -21,20 +121,48 @@ pub fn PromptList(prompts: Vec<Prompt>, on_prompt_click: EventHandler<String>) -
                 title: Some("Prompts".to_string()),
                 children: rsx! {
                     List {
-                        for prompt in prompts {
-                            {let prompt_id = prompt.id.clone();
+                        for item in prompts {
+                            {let prompt_id = item.prompt.id.clone();
+                            let select_id = item.prompt.id.clone();
+                            let menu_id = item.prompt.id.clone();
                             rsx! {
                                 ListItem {
+                                    selected: Some(selected.read().contains(&item.prompt.id)),
+                                    on_select: move |_| {
+                                        let mut selected = selected.write();
+                                        if !selected.remove(&select_id) {
+                                            selected.insert(select_id.clone());
+                                        }
+                                    },
                                     icon_name: "prompts".to_string(),
                                     icon_variant: Some("outline".to_string()),
                                     title: rsx! {
-                                        {prompt.name.clone()}
+                                        {item.prompt.name.clone()}
                                     },
-                                    subtitle: None,
-                                    right_content: None,
+                                    subtitle: (!item.prompt.tags.is_empty()).then(|| rsx! {
+                                        div { class: "flex items-center gap-1 flex-wrap",
+                                            for tag in item.prompt.tags.iter() {
+                                                span { key: "{tag}", class: "inline-flex items-center rounded-md bg-blue-50 dark:bg-blue-900/20 px-2 py-0.5 text-xs font-medium text-blue-700 dark:text-blue-300",
+                                                    "{tag}"
+                                                }
+                                            }
+                                        }
+                                    }),
+                                    right_content: Some(rsx! {
+                                        div { class: "text-right text-xs text-zinc-500 dark:text-zinc-400",
+                                            p { "Used {item.use_count} times" }
+                                            if let Some(last_used_at) = item.last_used_at {
+                                                p { "Last used " Timestamp { value: last_used_at } }
+                                            }
+                                        }
+                                    }),
                                     onclick: move |_| {
                                         on_prompt_click.call(prompt_id.clone());
                                     },
+                                    on_context_menu: move |anchor| {
+                                        menu_target.set(Some(menu_id.clone()));
+                                        menu_anchor.set(Some(anchor));
+                                    },
                                 }
                             }}
                         }
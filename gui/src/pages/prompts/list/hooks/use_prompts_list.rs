@@ -1,8 +1,8 @@
-use crate::queries::prompt_queries::use_prompts_query;
-use s_e_e_core::Prompt;
+use crate::queries::prompt_queries::use_prompts_filtered_query;
+use s_e_e_core::{PromptFilter, PromptWithUsage};
 
-pub fn use_prompts_list() -> Result<Vec<Prompt>, String> {
-    let (state, _refetch) = use_prompts_query();
+pub fn use_prompts_list(filter: PromptFilter) -> Result<Vec<PromptWithUsage>, String> {
+    let (state, _refetch) = use_prompts_filtered_query(filter);
 
     if state.is_loading {
         return Err("Loading prompts...".to_string());
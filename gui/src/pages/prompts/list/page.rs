@@ -1,41 +1,50 @@
-use crate::components::SectionCard;
+use crate::layout::hooks::use_persisted_filter;
 use crate::layout::router::Route;
+use crate::queries::prompt_queries::use_prompt_tags_query;
 use dioxus::prelude::*;
 use dioxus_router::prelude::use_navigator;
+use s_e_e_core::{PromptFilter, PromptSort};
 
-use crate::pages::prompts::list::components::{PromptList, PromptsListHeader};
-use crate::pages::prompts::list::hooks::use_prompts_list;
+use crate::pages::prompts::list::components::{
+    FilteredPrompts, PromptFilterBar, PromptsListHeader,
+};
 
 #[component]
 pub fn UserPromptsListPage() -> Element {
     let navigator = use_navigator();
+    let (tags_state, _refetch_tags) = use_prompt_tags_query();
 
-    let prompts = match use_prompts_list() {
-        Ok(p) => p,
-        Err(e) => {
-            return rsx! {
-                div { class: "space-y-8",
-                    PromptsListHeader {}
-                    SectionCard {
-                        title: Some("Error".to_string()),
-                        children: rsx! {
-                            div { class: "text-red-600 dark:text-red-400",
-                                "Failed to load prompts: {e}"
-                            }
-                        },
-                        padding: None,
-                    }
-                }
-            };
-        }
+    let search = use_persisted_filter("prompts_search", String::new);
+    let selected_tags = use_persisted_filter("prompts_tags", Vec::<String>::new);
+    let sort = use_persisted_filter("prompts_sort", PromptSort::default);
+
+    let search_text = search().trim().to_string();
+    let filter = PromptFilter {
+        search: (!search_text.is_empty()).then_some(search_text),
+        tags: selected_tags(),
+        sort: sort(),
     };
+    let filter_key = format!(
+        "{}|{}|{}",
+        filter.sort.as_str(),
+        filter.tags.join(","),
+        filter.search.as_deref().unwrap_or_default()
+    );
 
     rsx! {
         div { class: "space-y-8",
             PromptsListHeader {}
 
-            PromptList {
-                prompts,
+            PromptFilterBar {
+                search,
+                selected_tags,
+                sort,
+                available_tags: tags_state.data.clone().unwrap_or_default(),
+            }
+
+            FilteredPrompts {
+                key: "{filter_key}",
+                filter,
                 on_prompt_click: move |prompt_id: String| {
                     navigator.push(Route::UserPromptEditPage { id: prompt_id });
                 },
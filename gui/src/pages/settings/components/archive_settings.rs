@@ -0,0 +1,51 @@
+use dioxus::prelude::*;
+
+const INPUT_CLASS: &str = "block w-32 rounded-md border-0 py-1.5 px-3 text-zinc-900 dark:text-white shadow-sm ring-1 ring-inset ring-zinc-300 dark:ring-zinc-600 focus:ring-2 focus:ring-inset focus:ring-blue-600 dark:bg-zinc-700 sm:text-sm sm:leading-6";
+
+#[derive(Debug, PartialEq, Clone, Props)]
+pub struct ArchiveSettingsFormProps {
+    pub archive_after_days: Signal<Option<u32>>,
+    pub on_change: EventHandler<Option<u32>>,
+}
+
+/// Archive threshold in days. An empty field turns archiving off.
+#[component]
+pub fn ArchiveSettingsForm(props: ArchiveSettingsFormProps) -> Element {
+    let on_change = props.on_change;
+    let value = props
+        .archive_after_days
+        .read()
+        .map(|days| days.to_string())
+        .unwrap_or_default();
+
+    rsx! {
+        div { class: "space-y-6",
+            p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                "Finished executions older than this are moved to compressed files in the archive folder when the app starts. They stay listed under Archived on the Executions page and can be restored at any time. Leave empty to keep everything in the database."
+            }
+            div {
+                label { class: "block text-sm font-medium text-zinc-900 dark:text-white mb-2",
+                    "Archive after (days)"
+                }
+                input {
+                    r#type: "number",
+                    min: "1",
+                    step: "1",
+                    placeholder: "Never",
+                    value: "{value}",
+                    class: INPUT_CLASS,
+                    onchange: move |evt| {
+                        let text = evt.value();
+                        if text.trim().is_empty() {
+                            on_change.call(None);
+                        } else if let Ok(days) = text.trim().parse::<u32>() {
+                            if days > 0 {
+                                on_change.call(Some(days));
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
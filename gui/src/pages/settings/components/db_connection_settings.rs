@@ -0,0 +1,148 @@
+use crate::components::{IconButton, IconButtonSize, IconButtonVariant};
+use dioxus::prelude::*;
+use s_e_e_core::{AppSettings, DbConnectionSettings};
+
+const INPUT_CLASS: &str = "block w-full rounded-md border-0 py-1.5 px-3 text-zinc-900 dark:text-white shadow-sm ring-1 ring-inset ring-zinc-300 dark:ring-zinc-600 focus:ring-2 focus:ring-inset focus:ring-blue-600 dark:bg-zinc-700 sm:text-sm sm:leading-6";
+
+#[derive(Debug, PartialEq, Clone, Props)]
+pub struct DbConnectionSettingsFormProps {
+    pub connections: Signal<Vec<DbConnectionSettings>>,
+    pub on_change: EventHandler<Vec<DbConnectionSettings>>,
+}
+
+/// Named databases for `db_query` tasks. Passwords are not entered here, only
+/// the environment variable they are read from.
+#[component]
+pub fn DbConnectionSettingsForm(props: DbConnectionSettingsFormProps) -> Element {
+    let connections = props.connections;
+    let on_change = props.on_change;
+    let current = connections.read().clone();
+    let problem = AppSettings {
+        db_connections: current.clone(),
+        ..Default::default()
+    }
+    .validate()
+    .err();
+
+    let update =
+        move |index: usize, apply: fn(&mut DbConnectionSettings, String), value: String| {
+            let mut updated = connections.read().clone();
+            if let Some(connection) = updated.get_mut(index) {
+                apply(connection, value);
+                on_change.call(updated);
+            }
+        };
+
+    rsx! {
+        div { class: "space-y-6",
+            p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                "Databases that db_query tasks refer to by name. Passwords are never saved: name the environment variable that holds it and it is read when a query runs. Allowed statements, e.g. select, with, limit a connection to a single statement starting with one of them."
+            }
+            if let Some(problem) = problem {
+                p { class: "text-sm text-red-600 dark:text-red-400", "{problem}" }
+            }
+            for (index, connection) in current.into_iter().enumerate() {
+                div {
+                    key: "{index}",
+                    class: "grid grid-cols-1 gap-4 sm:grid-cols-2 rounded-lg border border-zinc-200 dark:border-zinc-700 p-4",
+                    ConnectionInput {
+                        label: "Name",
+                        value: connection.name.clone(),
+                        placeholder: "warehouse",
+                        on_change: move |value| update(index, |c, v| c.name = v, value),
+                    }
+                    ConnectionInput {
+                        label: "URL (without password)",
+                        value: connection.url.clone(),
+                        placeholder: "postgres://report@db.internal/sales",
+                        on_change: move |value| update(index, |c, v| c.url = v, value),
+                    }
+                    ConnectionInput {
+                        label: "Password environment variable",
+                        value: connection.password_env.clone().unwrap_or_default(),
+                        placeholder: "WAREHOUSE_PASSWORD",
+                        on_change: move |value| update(
+                            index,
+                            |c, v| c.password_env = Some(v).filter(|v| !v.trim().is_empty()),
+                            value,
+                        ),
+                    }
+                    ConnectionInput {
+                        label: "Allowed statements",
+                        value: connection.allowed_statements.join(", "),
+                        placeholder: "Any",
+                        on_change: move |value| update(
+                            index,
+                            |c, v| {
+                                c.allowed_statements = v
+                                    .split(',')
+                                    .map(|keyword| keyword.trim().to_lowercase())
+                                    .filter(|keyword| !keyword.is_empty())
+                                    .collect()
+                            },
+                            value,
+                        ),
+                    }
+                    ConnectionInput {
+                        label: "Row cap",
+                        value: connection.max_rows.map(|rows| rows.to_string()).unwrap_or_default(),
+                        placeholder: "1000",
+                        on_change: move |value| update(
+                            index,
+                            |c, v| c.max_rows = v.trim().parse().ok().filter(|rows| *rows > 0),
+                            value,
+                        ),
+                    }
+                    div { class: "flex items-end justify-end",
+                        IconButton {
+                            variant: IconButtonVariant::Danger,
+                            size: IconButtonSize::Small,
+                            onclick: move |_| {
+                                let mut updated = connections.read().clone();
+                                updated.remove(index);
+                                on_change.call(updated);
+                            },
+                            icon: Some("trash".to_string()),
+                            icon_variant: "outline".to_string(),
+                            "Remove"
+                        }
+                    }
+                }
+            }
+            IconButton {
+                variant: IconButtonVariant::Secondary,
+                size: IconButtonSize::Medium,
+                onclick: move |_| {
+                    let mut updated = connections.read().clone();
+                    updated.push(DbConnectionSettings {
+                        allowed_statements: vec!["select".to_string(), "with".to_string()],
+                        ..Default::default()
+                    });
+                    on_change.call(updated);
+                },
+                "Add connection"
+            }
+        }
+    }
+}
+
+#[component]
+pub(super) fn ConnectionInput(
+    label: &'static str,
+    value: String,
+    placeholder: &'static str,
+    on_change: EventHandler<String>,
+) -> Element {
+    rsx! {
+        div {
+            label { class: "block text-sm font-medium text-zinc-900 dark:text-white mb-2", {label} }
+            input {
+                r#type: "text",
+                value: "{value}",
+                placeholder: "{placeholder}",
+                class: INPUT_CLASS,
+                onchange: move |evt| on_change.call(evt.value()),
+            }
+        }
+    }
+}
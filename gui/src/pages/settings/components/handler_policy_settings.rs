@@ -0,0 +1,155 @@
+use super::db_connection_settings::ConnectionInput;
+use crate::components::{IconButton, IconButtonSize, IconButtonVariant};
+use dioxus::prelude::*;
+use s_e_e_core::{AppSettings, HandlerPolicySettings, HandlerProfile};
+use std::collections::BTreeSet;
+
+const SELECT_CLASS: &str = "block w-full rounded-md border-0 py-1.5 pl-3 pr-10 text-zinc-900 dark:text-white shadow-sm ring-1 ring-inset ring-zinc-300 dark:ring-zinc-600 focus:ring-2 focus:ring-inset focus:ring-blue-600 dark:bg-zinc-700 sm:text-sm sm:leading-6";
+
+fn join_handlers(handlers: &BTreeSet<String>) -> String {
+    handlers.iter().cloned().collect::<Vec<_>>().join(", ")
+}
+
+fn split_handlers(value: &str) -> BTreeSet<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Clone, Props)]
+pub struct HandlerPolicySettingsFormProps {
+    pub policy: Signal<HandlerPolicySettings>,
+    pub on_change: EventHandler<HandlerPolicySettings>,
+}
+
+/// Handler profiles and the one that decides which tasks may run.
+#[component]
+pub fn HandlerPolicySettingsForm(props: HandlerPolicySettingsFormProps) -> Element {
+    let policy = props.policy;
+    let on_change = props.on_change;
+    let current = policy.read().clone();
+    let problem = AppSettings {
+        handler_policy: current.clone(),
+        ..Default::default()
+    }
+    .validate()
+    .err();
+
+    let update = move |index: usize, apply: fn(&mut HandlerProfile, String), value: String| {
+        let mut updated = policy.read().clone();
+        let Some(profile) = updated.profiles.get_mut(index) else {
+            return;
+        };
+        let old_name = profile.name.clone();
+        apply(profile, value);
+        let new_name = profile.name.clone();
+        if updated.active_profile.as_deref() == Some(old_name.as_str()) {
+            updated.active_profile = Some(new_name);
+        }
+        on_change.call(updated);
+    };
+
+    rsx! {
+        div { class: "space-y-6",
+            p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                "Limit which task handlers may run, by function name such as cli_command or db_query; \"custom\" covers every custom handler. Workflows using a blocked handler are flagged when saved and refused when run."
+            }
+            if let Some(problem) = problem {
+                p { class: "text-sm text-red-600 dark:text-red-400", "{problem}" }
+            }
+            div {
+                label {
+                    r#for: "handler-active-profile",
+                    class: "block text-sm font-medium text-zinc-900 dark:text-white mb-2",
+                    "Active profile"
+                }
+                select {
+                    id: "handler-active-profile",
+                    class: SELECT_CLASS,
+                    onchange: move |evt| {
+                        let mut updated = policy.read().clone();
+                        updated.active_profile = Some(evt.value()).filter(|name| !name.is_empty());
+                        on_change.call(updated);
+                    },
+                    option { value: "", selected: current.active_profile.is_none(), "None (every handler may run)" }
+                    for profile in current.profiles.iter() {
+                        option {
+                            key: "{profile.name}",
+                            value: "{profile.name}",
+                            selected: current.active_profile.as_deref() == Some(profile.name.as_str()),
+                            "{profile.name}"
+                        }
+                    }
+                }
+            }
+            for (index, profile) in current.profiles.clone().into_iter().enumerate() {
+                div {
+                    key: "{index}",
+                    class: "grid grid-cols-1 gap-4 sm:grid-cols-3 rounded-lg border border-zinc-200 dark:border-zinc-700 p-4",
+                    ConnectionInput {
+                        label: "Name",
+                        value: profile.name.clone(),
+                        placeholder: "restricted",
+                        on_change: move |value| update(index, |p, v| p.name = v, value),
+                    }
+                    ConnectionInput {
+                        label: "Denied handlers",
+                        value: join_handlers(&profile.denied_handlers),
+                        placeholder: "cli_command, docker",
+                        on_change: move |value| update(
+                            index,
+                            |p, v| p.denied_handlers = split_handlers(&v),
+                            value,
+                        ),
+                    }
+                    ConnectionInput {
+                        label: "Allowed handlers (empty allows all)",
+                        value: profile.allowed_handlers.as_ref().map(join_handlers).unwrap_or_default(),
+                        placeholder: "db_query, publish_message",
+                        on_change: move |value| update(
+                            index,
+                            |p, v| {
+                                p.allowed_handlers = Some(split_handlers(&v)).filter(|names| !names.is_empty())
+                            },
+                            value,
+                        ),
+                    }
+                    div { class: "flex items-end justify-end sm:col-span-3",
+                        IconButton {
+                            variant: IconButtonVariant::Danger,
+                            size: IconButtonSize::Small,
+                            onclick: move |_| {
+                                let mut updated = policy.read().clone();
+                                let removed = updated.profiles.remove(index);
+                                if updated.active_profile.as_deref() == Some(removed.name.as_str()) {
+                                    updated.active_profile = None;
+                                }
+                                on_change.call(updated);
+                            },
+                            icon: Some("trash".to_string()),
+                            icon_variant: "outline".to_string(),
+                            "Remove"
+                        }
+                    }
+                }
+            }
+            IconButton {
+                variant: IconButtonVariant::Secondary,
+                size: IconButtonSize::Medium,
+                onclick: move |_| {
+                    let mut updated = policy.read().clone();
+                    updated.profiles.push(HandlerProfile {
+                        name: format!("profile-{}", updated.profiles.len() + 1),
+                        allowed_handlers: None,
+                        denied_handlers: BTreeSet::new(),
+                    });
+                    on_change.call(updated);
+                },
+                "Add profile"
+            }
+        }
+    }
+}
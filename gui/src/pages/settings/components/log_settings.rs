@@ -0,0 +1,124 @@
+use dioxus::prelude::*;
+use s_e_e_core::{log_env_override, LogLevel, LogSettings, LOG_ENV_VAR, LOG_MODULES};
+
+const SELECT_CLASS: &str = "block w-full rounded-md border-0 py-1.5 pl-3 pr-10 text-zinc-900 dark:text-white shadow-sm ring-1 ring-inset ring-zinc-300 dark:ring-zinc-600 focus:ring-2 focus:ring-inset focus:ring-blue-600 dark:bg-zinc-700 sm:text-sm sm:leading-6";
+
+#[derive(Debug, PartialEq, Clone, Props)]
+pub struct LogSettingsFormProps {
+    pub logging: Signal<LogSettings>,
+    pub on_change: EventHandler<LogSettings>,
+}
+
+/// The default log level and overrides for single modules.
+#[component]
+pub fn LogSettingsForm(props: LogSettingsFormProps) -> Element {
+    let logging = props.logging;
+    let on_change = props.on_change;
+    let current = logging.read().clone();
+    let env_override = log_env_override();
+    // Other tracing targets saved in the settings stay editable too.
+    let modules: Vec<String> = LOG_MODULES
+        .iter()
+        .map(|(name, _)| name.to_string())
+        .chain(
+            current
+                .modules
+                .keys()
+                .filter(|name| !LOG_MODULES.iter().any(|(known, _)| known == name))
+                .cloned(),
+        )
+        .collect();
+
+    rsx! {
+        div { class: "space-y-6",
+            p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                "How much the app logs. Modules without a level of their own use the default. Changes apply right away."
+            }
+            if let Some(directives) = env_override {
+                p { class: "text-sm text-amber-700 dark:text-amber-400",
+                    "{LOG_ENV_VAR} or RUST_LOG is set ({directives}), so these levels take effect on the next launch without it."
+                }
+            }
+            div { class: "grid grid-cols-1 gap-4 sm:grid-cols-3",
+                div {
+                    label {
+                        r#for: "log-level-default",
+                        class: "block text-sm font-medium text-zinc-900 dark:text-white mb-2",
+                        "Default"
+                    }
+                    select {
+                        id: "log-level-default",
+                        class: SELECT_CLASS,
+                        onchange: move |evt| {
+                            if let Ok(level) = evt.value().parse::<LogLevel>() {
+                                let mut updated = logging.read().clone();
+                                updated.level = level;
+                                on_change.call(updated);
+                            }
+                        },
+                        for level in LogLevel::ALL {
+                            option {
+                                key: "{level.as_str()}",
+                                value: level.as_str(),
+                                selected: current.level == level,
+                                {level.label()}
+                            }
+                        }
+                    }
+                }
+                for module in modules {
+                    ModuleLevelSelect {
+                        key: "{module}",
+                        module: module.clone(),
+                        level: current.modules.get(&module).copied(),
+                        on_change: move |level: Option<LogLevel>| {
+                            let mut updated = logging.read().clone();
+                            match level {
+                                Some(level) => {
+                                    updated.modules.insert(module.clone(), level);
+                                }
+                                None => {
+                                    updated.modules.remove(&module);
+                                }
+                            }
+                            on_change.call(updated);
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ModuleLevelSelect(
+    module: String,
+    level: Option<LogLevel>,
+    on_change: EventHandler<Option<LogLevel>>,
+) -> Element {
+    let id = format!("log-level-{}", module.replace(':', "-"));
+
+    rsx! {
+        div {
+            label {
+                r#for: "{id}",
+                class: "block text-sm font-medium text-zinc-900 dark:text-white mb-2 capitalize",
+                "{module}"
+            }
+            select {
+                id: "{id}",
+                class: SELECT_CLASS,
+                onchange: move |evt| on_change.call(evt.value().parse::<LogLevel>().ok()),
+                option { value: "", selected: level.is_none(), "Default" }
+                for option_level in LogLevel::ALL {
+                    option {
+                        key: "{option_level.as_str()}",
+                        value: option_level.as_str(),
+                        selected: level == Some(option_level),
+                        {option_level.label()}
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use super::db_connection_settings::ConnectionInput;
+use crate::components::{IconButton, IconButtonSize, IconButtonVariant};
+use dioxus::prelude::*;
+use s_e_e_core::{AppSettings, MessageBroker, MessageConnectionSettings};
+
+const SELECT_CLASS: &str = "block w-full rounded-md border-0 py-1.5 pl-3 pr-10 text-zinc-900 dark:text-white shadow-sm ring-1 ring-inset ring-zinc-300 dark:ring-zinc-600 focus:ring-2 focus:ring-inset focus:ring-blue-600 dark:bg-zinc-700 sm:text-sm sm:leading-6";
+
+#[derive(Debug, PartialEq, Clone, Props)]
+pub struct MessageConnectionSettingsFormProps {
+    pub connections: Signal<Vec<MessageConnectionSettings>>,
+    pub on_change: EventHandler<Vec<MessageConnectionSettings>>,
+}
+
+/// Named Kafka, NATS and AMQP brokers for `publish_message` tasks.
+#[component]
+pub fn MessageConnectionSettingsForm(props: MessageConnectionSettingsFormProps) -> Element {
+    let connections = props.connections;
+    let on_change = props.on_change;
+    let current = connections.read().clone();
+    let problem = AppSettings {
+        message_connections: current.clone(),
+        ..Default::default()
+    }
+    .validate()
+    .err();
+
+    let update =
+        move |index: usize, apply: fn(&mut MessageConnectionSettings, String), value: String| {
+            let mut updated = connections.read().clone();
+            if let Some(connection) = updated.get_mut(index) {
+                apply(connection, value);
+                on_change.call(updated);
+            }
+        };
+
+    rsx! {
+        div { class: "space-y-6",
+            p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                "Brokers that publish_message tasks refer to by name. For Kafka, enter the bootstrap servers, with user@ in front when the broker uses SASL. The password is read from the named environment variable when a message is sent."
+            }
+            if let Some(problem) = problem {
+                p { class: "text-sm text-red-600 dark:text-red-400", "{problem}" }
+            }
+            for (index, connection) in current.into_iter().enumerate() {
+                div {
+                    key: "{index}",
+                    class: "grid grid-cols-1 gap-4 sm:grid-cols-2 rounded-lg border border-zinc-200 dark:border-zinc-700 p-4",
+                    ConnectionInput {
+                        label: "Name",
+                        value: connection.name.clone(),
+                        placeholder: "events",
+                        on_change: move |value| update(index, |c, v| c.name = v, value),
+                    }
+                    div {
+                        label { class: "block text-sm font-medium text-zinc-900 dark:text-white mb-2",
+                            "Broker"
+                        }
+                        select {
+                            class: SELECT_CLASS,
+                            onchange: move |evt| {
+                                let broker = MessageBroker::ALL
+                                    .into_iter()
+                                    .find(|broker| broker.as_str() == evt.value())
+                                    .unwrap_or_default();
+                                let mut updated = connections.read().clone();
+                                if let Some(connection) = updated.get_mut(index) {
+                                    connection.broker = broker;
+                                    on_change.call(updated);
+                                }
+                            },
+                            for broker in MessageBroker::ALL {
+                                option {
+                                    key: "{broker.as_str()}",
+                                    value: broker.as_str(),
+                                    selected: connection.broker == broker,
+                                    "{broker.label()}"
+                                }
+                            }
+                        }
+                        if !s_e_e_core::message_connection_from_settings(&connection).backend.is_available() {
+                            p { class: "mt-1 text-xs text-amber-600 dark:text-amber-400",
+                                "Not included in this build; rebuild with the '{connection.broker.as_str()}' feature."
+                            }
+                        }
+                    }
+                    ConnectionInput {
+                        label: "URL (without password)",
+                        value: connection.url.clone(),
+                        placeholder: "nats://see@nats.internal:4222",
+                        on_change: move |value| update(index, |c, v| c.url = v, value),
+                    }
+                    ConnectionInput {
+                        label: "Password environment variable",
+                        value: connection.password_env.clone().unwrap_or_default(),
+                        placeholder: "BROKER_PASSWORD",
+                        on_change: move |value| update(
+                            index,
+                            |c, v| c.password_env = Some(v).filter(|v| !v.trim().is_empty()),
+                            value,
+                        ),
+                    }
+                    div { class: "flex items-end justify-end sm:col-span-2",
+                        IconButton {
+                            variant: IconButtonVariant::Danger,
+                            size: IconButtonSize::Small,
+                            onclick: move |_| {
+                                let mut updated = connections.read().clone();
+                                updated.remove(index);
+                                on_change.call(updated);
+                            },
+                            icon: Some("trash".to_string()),
+                            icon_variant: "outline".to_string(),
+                            "Remove"
+                        }
+                    }
+                }
+            }
+            IconButton {
+                variant: IconButtonVariant::Secondary,
+                size: IconButtonSize::Medium,
+                onclick: move |_| {
+                    let mut updated = connections.read().clone();
+                    updated.push(MessageConnectionSettings::default());
+                    on_change.call(updated);
+                },
+                "Add broker"
+            }
+        }
+    }
+}
@@ -1,5 +1,25 @@
+pub mod archive_settings;
+pub mod db_connection_settings;
+pub mod handler_policy_settings;
+pub mod log_settings;
+pub mod message_connection_settings;
+pub mod notification_settings;
+pub mod polling_settings;
 pub mod settings_form;
+pub mod storage_settings;
 pub mod theme_switcher;
+pub mod timestamp_settings;
+pub mod workflow_limit_settings;
 
+pub use archive_settings::ArchiveSettingsForm;
+pub use db_connection_settings::DbConnectionSettingsForm;
+pub use handler_policy_settings::HandlerPolicySettingsForm;
+pub use log_settings::LogSettingsForm;
+pub use message_connection_settings::MessageConnectionSettingsForm;
+pub use notification_settings::{NotificationPreferences, NotificationSettingsForm};
+pub use polling_settings::PollingSettingsForm;
 pub use settings_form::SettingsForm;
+pub use storage_settings::StorageSettings;
 pub use theme_switcher::ThemeSwitcher;
+pub use timestamp_settings::TimestampSettingsForm;
+pub use workflow_limit_settings::WorkflowLimitSettingsForm;
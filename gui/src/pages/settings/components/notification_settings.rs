@@ -0,0 +1,68 @@
+use dioxus::prelude::*;
+use s_e_e_core::NotificationKind;
+use std::collections::BTreeSet;
+
+const CHECKBOX_CLASS: &str =
+    "h-4 w-4 rounded border-zinc-300 dark:border-zinc-600 text-blue-600 focus:ring-blue-600";
+
+/// Whether notifications are on at all, and which kinds are muted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationPreferences {
+    pub enabled: bool,
+    pub muted: BTreeSet<NotificationKind>,
+}
+
+#[derive(Debug, PartialEq, Clone, Props)]
+pub struct NotificationSettingsFormProps {
+    pub preferences: Signal<NotificationPreferences>,
+    pub on_change: EventHandler<NotificationPreferences>,
+}
+
+#[component]
+pub fn NotificationSettingsForm(props: NotificationSettingsFormProps) -> Element {
+    let preferences = props.preferences;
+    let on_change = props.on_change;
+    let current = preferences.read().clone();
+
+    rsx! {
+        div { class: "space-y-4",
+            label { class: "flex items-center gap-3 text-sm font-medium text-zinc-900 dark:text-white",
+                input {
+                    r#type: "checkbox",
+                    checked: current.enabled,
+                    class: CHECKBOX_CLASS,
+                    onchange: move |evt| {
+                        let mut updated = preferences.read().clone();
+                        updated.enabled = evt.checked();
+                        on_change.call(updated);
+                    },
+                }
+                "Show notifications in the notification center"
+            }
+            fieldset { class: "space-y-3 pl-7", disabled: !current.enabled,
+                legend { class: "sr-only", "Notification types" }
+                for kind in NotificationKind::ALL {
+                    label {
+                        key: "{kind.label()}",
+                        class: if current.enabled { "flex items-center gap-3 text-sm text-zinc-900 dark:text-white" } else { "flex items-center gap-3 text-sm text-zinc-400 dark:text-zinc-500" },
+                        input {
+                            r#type: "checkbox",
+                            checked: !current.muted.contains(&kind),
+                            class: CHECKBOX_CLASS,
+                            onchange: move |evt| {
+                                let mut updated = preferences.read().clone();
+                                if evt.checked() {
+                                    updated.muted.remove(&kind);
+                                } else {
+                                    updated.muted.insert(kind);
+                                }
+                                on_change.call(updated);
+                            },
+                        }
+                        "{kind.label()}"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use dioxus::prelude::*;
+use s_e_e_core::PollingSettings;
+
+const INPUT_CLASS: &str = "block w-32 rounded-md border-0 py-1.5 px-3 text-zinc-900 dark:text-white shadow-sm ring-1 ring-inset ring-zinc-300 dark:ring-zinc-600 focus:ring-2 focus:ring-inset focus:ring-blue-600 dark:bg-zinc-700 sm:text-sm sm:leading-6";
+
+#[derive(Debug, PartialEq, Clone, Props)]
+pub struct PollingSettingsFormProps {
+    pub polling: Signal<PollingSettings>,
+    pub on_change: EventHandler<PollingSettings>,
+}
+
+#[component]
+pub fn PollingSettingsForm(props: PollingSettingsFormProps) -> Element {
+    let polling = props.polling;
+    let on_change = props.on_change;
+    let current = polling.read().clone();
+
+    rsx! {
+        div { class: "space-y-6",
+            p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                "How often live pages refresh, in seconds. Set an interval to 0 to turn polling off."
+            }
+            div { class: "grid grid-cols-1 gap-4 sm:grid-cols-3",
+                IntervalInput {
+                    label: "Executions",
+                    value_ms: current.executions_ms,
+                    on_change: move |ms| {
+                        let mut updated = polling.read().clone();
+                        updated.executions_ms = ms;
+                        on_change.call(updated);
+                    },
+                }
+                IntervalInput {
+                    label: "Execution details",
+                    value_ms: current.execution_details_ms,
+                    on_change: move |ms| {
+                        let mut updated = polling.read().clone();
+                        updated.execution_details_ms = ms;
+                        on_change.call(updated);
+                    },
+                }
+                IntervalInput {
+                    label: "Calendar",
+                    value_ms: current.calendar_ms,
+                    on_change: move |ms| {
+                        let mut updated = polling.read().clone();
+                        updated.calendar_ms = ms;
+                        on_change.call(updated);
+                    },
+                }
+            }
+            label { class: "flex items-center gap-3 text-sm text-zinc-900 dark:text-white",
+                input {
+                    r#type: "checkbox",
+                    checked: current.pause_when_unfocused,
+                    class: "h-4 w-4 rounded border-zinc-300 dark:border-zinc-600 text-blue-600 focus:ring-blue-600",
+                    onchange: move |evt| {
+                        let mut updated = polling.read().clone();
+                        updated.pause_when_unfocused = evt.checked();
+                        on_change.call(updated);
+                    },
+                }
+                "Pause refreshing while the window is in the background"
+            }
+        }
+    }
+}
+
+#[component]
+fn IntervalInput(label: &'static str, value_ms: u64, on_change: EventHandler<u64>) -> Element {
+    let seconds = value_ms as f64 / 1000.0;
+
+    rsx! {
+        div {
+            label { class: "block text-sm font-medium text-zinc-900 dark:text-white mb-2", {label} }
+            input {
+                r#type: "number",
+                min: "0",
+                step: "0.5",
+                value: "{seconds}",
+                class: INPUT_CLASS,
+                onchange: move |evt| {
+                    if let Ok(seconds) = evt.value().parse::<f64>() {
+                        if seconds >= 0.0 {
+                            on_change.call((seconds * 1000.0).round() as u64);
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
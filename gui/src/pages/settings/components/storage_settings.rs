@@ -0,0 +1,53 @@
+use crate::components::{format_bytes, IconButton, IconButtonSize, IconButtonVariant, Timestamp};
+use crate::queries::{use_last_maintenance_query, use_storage_maintenance_mutation};
+use dioxus::prelude::*;
+
+/// Last maintenance run and a button to start one now.
+#[component]
+pub fn StorageSettings() -> Element {
+    let (last_state, _refetch) = use_last_maintenance_query();
+    let (maintenance_state, run_maintenance) = use_storage_maintenance_mutation();
+
+    let running = maintenance_state.read().is_loading;
+    let error = maintenance_state.read().error.clone();
+    let last = maintenance_state
+        .read()
+        .data
+        .clone()
+        .or_else(|| last_state.data.clone().flatten());
+
+    rsx! {
+        div { class: "space-y-6",
+            p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                "Deleting executions frees space inside the database file without shrinking it. Maintenance gives that space back to the disk and refreshes query statistics. It also runs on its own about once a day while no workflow is running."
+            }
+            if let Some(report) = last {
+                div { class: "text-sm text-zinc-900 dark:text-zinc-100 space-y-1",
+                    div { class: "flex gap-2",
+                        span { class: "font-medium text-zinc-600 dark:text-zinc-400", "Last run:" }
+                        Timestamp { value: report.completed_at }
+                    }
+                    div { class: "flex gap-2",
+                        span { class: "font-medium text-zinc-600 dark:text-zinc-400", "Reclaimed:" }
+                        span {
+                            "{format_bytes(report.reclaimed_bytes())} (database now {format_bytes(report.bytes_after)})"
+                        }
+                    }
+                }
+            } else {
+                p { class: "text-sm text-zinc-500 dark:text-zinc-400", "Maintenance has not run yet." }
+            }
+            if let Some(error) = error {
+                p { class: "text-sm text-red-600 dark:text-red-400", "{error}" }
+            }
+            IconButton {
+                variant: IconButtonVariant::Secondary,
+                size: IconButtonSize::Medium,
+                loading: Some(running),
+                onclick: move |_| run_maintenance(()),
+                icon: Some("arrow_path".to_string()),
+                "Run maintenance now"
+            }
+        }
+    }
+}
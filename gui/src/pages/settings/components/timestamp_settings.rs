@@ -0,0 +1,76 @@
+use crate::components::format_absolute;
+use chrono::Utc;
+use dioxus::prelude::*;
+use s_e_e_core::{DateStyle, DisplayTimeZone, TimestampSettings};
+
+const SELECT_CLASS: &str = "block w-full rounded-md border-0 py-1.5 pl-3 pr-10 text-zinc-900 dark:text-white shadow-sm ring-1 ring-inset ring-zinc-300 dark:ring-zinc-600 focus:ring-2 focus:ring-inset focus:ring-blue-600 dark:bg-zinc-700 sm:text-sm sm:leading-6";
+
+#[derive(Debug, PartialEq, Clone, Props)]
+pub struct TimestampSettingsFormProps {
+    pub timestamps: Signal<TimestampSettings>,
+    pub on_change: EventHandler<TimestampSettings>,
+}
+
+#[component]
+pub fn TimestampSettingsForm(props: TimestampSettingsFormProps) -> Element {
+    let timestamps = props.timestamps;
+    let on_change = props.on_change;
+    let current = timestamps.read().clone();
+    let preview = format_absolute(Utc::now(), &current);
+
+    rsx! {
+        div { class: "space-y-6",
+            div { class: "grid grid-cols-1 gap-4 sm:grid-cols-2",
+                div {
+                    label {
+                        r#for: "timestamp-time-zone",
+                        class: "block text-sm font-medium text-zinc-900 dark:text-white mb-2",
+                        "Time zone"
+                    }
+                    select {
+                        id: "timestamp-time-zone",
+                        class: SELECT_CLASS,
+                        onchange: move |evt| {
+                            let time_zone = match evt.value().as_str() {
+                                "utc" => DisplayTimeZone::Utc,
+                                _ => DisplayTimeZone::Local,
+                            };
+                            let mut updated = timestamps.read().clone();
+                            updated.time_zone = time_zone;
+                            on_change.call(updated);
+                        },
+                        option { value: "local", selected: current.time_zone == DisplayTimeZone::Local, "Local time" }
+                        option { value: "utc", selected: current.time_zone == DisplayTimeZone::Utc, "UTC" }
+                    }
+                }
+                div {
+                    label {
+                        r#for: "timestamp-date-style",
+                        class: "block text-sm font-medium text-zinc-900 dark:text-white mb-2",
+                        "Date format"
+                    }
+                    select {
+                        id: "timestamp-date-style",
+                        class: SELECT_CLASS,
+                        onchange: move |evt| {
+                            let date_style = match evt.value().as_str() {
+                                "us" => DateStyle::UnitedStates,
+                                "european" => DateStyle::European,
+                                _ => DateStyle::Iso,
+                            };
+                            let mut updated = timestamps.read().clone();
+                            updated.date_style = date_style;
+                            on_change.call(updated);
+                        },
+                        option { value: "iso", selected: current.date_style == DateStyle::Iso, "ISO (2024-05-01 14:03)" }
+                        option { value: "us", selected: current.date_style == DateStyle::UnitedStates, "US (05/01/2024 2:03 PM)" }
+                        option { value: "european", selected: current.date_style == DateStyle::European, "European (01.05.2024 14:03)" }
+                    }
+                }
+            }
+            p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                "Recent times are shown relative to now, like \"3 min ago\". Hover one to see the exact time, currently {preview}."
+            }
+        }
+    }
+}
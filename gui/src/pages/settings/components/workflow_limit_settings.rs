@@ -0,0 +1,81 @@
+use dioxus::prelude::*;
+use s_e_e_core::WorkflowLimitSettings;
+
+const INPUT_CLASS: &str = "block w-32 rounded-md border-0 py-1.5 px-3 text-zinc-900 dark:text-white shadow-sm ring-1 ring-inset ring-zinc-300 dark:ring-zinc-600 focus:ring-2 focus:ring-inset focus:ring-blue-600 dark:bg-zinc-700 sm:text-sm sm:leading-6";
+
+#[derive(Debug, PartialEq, Clone, Props)]
+pub struct WorkflowLimitSettingsFormProps {
+    pub limits: Signal<WorkflowLimitSettings>,
+    pub on_change: EventHandler<WorkflowLimitSettings>,
+}
+
+#[component]
+pub fn WorkflowLimitSettingsForm(props: WorkflowLimitSettingsFormProps) -> Element {
+    let limits = props.limits;
+    let on_change = props.on_change;
+    let current = limits.read().clone();
+
+    let update = move |apply: fn(&mut WorkflowLimitSettings, usize), value: usize| {
+        let mut updated = limits.read().clone();
+        apply(&mut updated, value);
+        on_change.call(updated);
+    };
+
+    rsx! {
+        div { class: "space-y-6",
+            p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                "Workflows larger than these limits are rejected when they are imported, saved or run."
+            }
+            div { class: "grid grid-cols-1 gap-4 sm:grid-cols-3",
+                LimitInput {
+                    label: "Tasks",
+                    value: current.max_tasks,
+                    on_change: move |value| update(|l, v| l.max_tasks = v, value),
+                }
+                LimitInput {
+                    label: "Task chain depth",
+                    value: current.max_depth,
+                    on_change: move |value| update(|l, v| l.max_depth = v, value),
+                }
+                LimitInput {
+                    label: "Next tasks per task",
+                    value: current.max_fan_out,
+                    on_change: move |value| update(|l, v| l.max_fan_out = v, value),
+                }
+                LimitInput {
+                    label: "Parameters",
+                    value: current.max_parameters,
+                    on_change: move |value| update(|l, v| l.max_parameters = v, value),
+                }
+                LimitInput {
+                    label: "File size (KB)",
+                    value: current.max_document_kb,
+                    on_change: move |value| update(|l, v| l.max_document_kb = v, value),
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn LimitInput(label: &'static str, value: usize, on_change: EventHandler<usize>) -> Element {
+    rsx! {
+        div {
+            label { class: "block text-sm font-medium text-zinc-900 dark:text-white mb-2", {label} }
+            input {
+                r#type: "number",
+                min: "1",
+                step: "1",
+                value: "{value}",
+                class: INPUT_CLASS,
+                onchange: move |evt| {
+                    if let Ok(value) = evt.value().parse::<usize>() {
+                        if value > 0 {
+                            on_change.call(value);
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
@@ -2,11 +2,19 @@ use crate::components::{
     ConfirmDialog, IconButton, IconButtonSize, IconButtonVariant, Notification, NotificationData,
     NotificationType, PageHeader,
 };
-use crate::pages::settings::components::SettingsForm;
+use crate::pages::settings::components::{
+    ArchiveSettingsForm, DbConnectionSettingsForm, HandlerPolicySettingsForm, LogSettingsForm,
+    MessageConnectionSettingsForm, NotificationPreferences, NotificationSettingsForm,
+    PollingSettingsForm, SettingsForm, StorageSettings, TimestampSettingsForm,
+    WorkflowLimitSettingsForm,
+};
 use crate::pages::settings::hooks::{use_settings_mutation, use_settings_query};
 use crate::services::clear_database;
 use dioxus::prelude::*;
-use s_e_e_core::AppSettings;
+use s_e_e_core::{
+    AppSettings, DbConnectionSettings, HandlerPolicySettings, LogSettings,
+    MessageConnectionSettings, PollingSettings, TimestampSettings, WorkflowLimitSettings,
+};
 
 #[component]
 pub fn SettingsPage() -> Element {
@@ -42,11 +50,52 @@ pub fn SettingsPage() -> Element {
     let mutations = use_settings_mutation();
 
     let mut theme = use_signal(|| loaded_settings.theme.clone());
+    let mut polling = use_signal(|| loaded_settings.polling.clone());
+    let mut timestamps = use_signal(|| loaded_settings.timestamps.clone());
+    let mut workflow_limits = use_signal(|| loaded_settings.workflow_limits.clone());
+    let mut archive_after_days = use_signal(|| loaded_settings.archive_after_days);
+    let mut db_connections = use_signal(|| loaded_settings.db_connections.clone());
+    let mut message_connections = use_signal(|| loaded_settings.message_connections.clone());
+    let mut handler_policy = use_signal(|| loaded_settings.handler_policy.clone());
+    let mut logging = use_signal(|| loaded_settings.logging.clone());
+    let notification_preferences_of = |settings: &AppSettings| NotificationPreferences {
+        enabled: settings.notifications,
+        muted: settings.muted_notifications.clone(),
+    };
+    let mut notification_preferences = use_signal(|| notification_preferences_of(&loaded_settings));
 
-    use_effect(move || {
-        theme.set(loaded_settings.theme.clone());
+    use_effect({
+        let loaded_settings = loaded_settings.clone();
+        move || {
+            theme.set(loaded_settings.theme.clone());
+            polling.set(loaded_settings.polling.clone());
+            timestamps.set(loaded_settings.timestamps.clone());
+            workflow_limits.set(loaded_settings.workflow_limits.clone());
+            archive_after_days.set(loaded_settings.archive_after_days);
+            db_connections.set(loaded_settings.db_connections.clone());
+            message_connections.set(loaded_settings.message_connections.clone());
+            handler_policy.set(loaded_settings.handler_policy.clone());
+            logging.set(loaded_settings.logging.clone());
+            notification_preferences.set(notification_preferences_of(&loaded_settings));
+        }
     });
 
+    // The loaded settings with every edit made on this page applied.
+    let current_settings = move || AppSettings {
+        theme: theme(),
+        polling: polling(),
+        timestamps: timestamps(),
+        workflow_limits: workflow_limits(),
+        archive_after_days: archive_after_days(),
+        db_connections: db_connections(),
+        message_connections: message_connections(),
+        handler_policy: handler_policy(),
+        logging: logging(),
+        notifications: notification_preferences().enabled,
+        muted_notifications: notification_preferences().muted,
+        ..loaded_settings.clone()
+    };
+
     let save_settings = {
         let notification = notification;
         let mutate_fn = mutations.mutate_fn.clone();
@@ -64,6 +113,53 @@ pub fn SettingsPage() -> Element {
         }
     };
 
+    let (
+        save_theme,
+        save_polling,
+        save_timestamps,
+        save_limits,
+        save_archive,
+        save_db_connections,
+        save_message_connections,
+        save_handler_policy,
+        save_logging,
+        save_notifications,
+    ) = (
+        save_settings.clone(),
+        save_settings.clone(),
+        save_settings.clone(),
+        save_settings.clone(),
+        save_settings.clone(),
+        save_settings.clone(),
+        save_settings.clone(),
+        save_settings.clone(),
+        save_settings.clone(),
+        save_settings,
+    );
+    let (
+        settings_for_theme,
+        settings_for_polling,
+        settings_for_timestamps,
+        settings_for_limits,
+        settings_for_archive,
+        settings_for_db_connections,
+        settings_for_message_connections,
+        settings_for_handler_policy,
+        settings_for_logging,
+        settings_for_notifications,
+    ) = (
+        current_settings.clone(),
+        current_settings.clone(),
+        current_settings.clone(),
+        current_settings.clone(),
+        current_settings.clone(),
+        current_settings.clone(),
+        current_settings.clone(),
+        current_settings.clone(),
+        current_settings.clone(),
+        current_settings,
+    );
+
     let clear_database_handler = {
         let mut show_dialog = show_confirm_dialog;
         let mut notification = notification;
@@ -122,19 +218,127 @@ pub fn SettingsPage() -> Element {
                     theme,
                     on_theme_change: move |new_theme: s_e_e_core::Theme| {
                         tracing::info!("[SettingsPage] Theme changed to {:?}", new_theme);
-                        theme.set(new_theme.clone());
-                        let settings = AppSettings {
-                            theme: new_theme,
-                            auto_save: loaded_settings.auto_save,
-                            notifications: loaded_settings.notifications,
-                            default_workflow: loaded_settings.default_workflow.clone(),
-                        };
+                        theme.set(new_theme);
+                        let settings = settings_for_theme();
                         tracing::debug!("[SettingsPage] Calling save_settings with theme: {:?}", settings.theme);
-                        save_settings(settings);
+                        save_theme(settings);
+                    },
+                }
+            }
+
+            div { class: "bg-white dark:bg-zinc-800 rounded-xl border border-zinc-200 dark:border-zinc-700 p-8 shadow-sm",
+                h3 { class: "text-base font-semibold text-zinc-900 dark:text-white mb-6", "Live Updates" }
+                PollingSettingsForm {
+                    polling,
+                    on_change: move |new_polling: PollingSettings| {
+                        tracing::info!("[SettingsPage] Polling settings changed to {:?}", new_polling);
+                        polling.set(new_polling);
+                        save_polling(settings_for_polling());
+                    },
+                }
+            }
+
+            div { class: "bg-white dark:bg-zinc-800 rounded-xl border border-zinc-200 dark:border-zinc-700 p-8 shadow-sm",
+                h3 { class: "text-base font-semibold text-zinc-900 dark:text-white mb-6", "Notifications" }
+                NotificationSettingsForm {
+                    preferences: notification_preferences,
+                    on_change: move |preferences: NotificationPreferences| {
+                        tracing::info!("[SettingsPage] Notification settings changed to {:?}", preferences);
+                        notification_preferences.set(preferences);
+                        save_notifications(settings_for_notifications());
+                    },
+                }
+            }
+
+            div { class: "bg-white dark:bg-zinc-800 rounded-xl border border-zinc-200 dark:border-zinc-700 p-8 shadow-sm",
+                h3 { class: "text-base font-semibold text-zinc-900 dark:text-white mb-6", "Date & Time" }
+                TimestampSettingsForm {
+                    timestamps,
+                    on_change: move |new_timestamps: TimestampSettings| {
+                        tracing::info!("[SettingsPage] Timestamp settings changed to {:?}", new_timestamps);
+                        timestamps.set(new_timestamps);
+                        save_timestamps(settings_for_timestamps());
                     },
                 }
             }
 
+            div { class: "bg-white dark:bg-zinc-800 rounded-xl border border-zinc-200 dark:border-zinc-700 p-8 shadow-sm",
+                h3 { class: "text-base font-semibold text-zinc-900 dark:text-white mb-6", "Workflow Limits" }
+                WorkflowLimitSettingsForm {
+                    limits: workflow_limits,
+                    on_change: move |new_limits: WorkflowLimitSettings| {
+                        tracing::info!("[SettingsPage] Workflow limits changed to {:?}", new_limits);
+                        workflow_limits.set(new_limits);
+                        save_limits(settings_for_limits());
+                    },
+                }
+            }
+
+            div { class: "bg-white dark:bg-zinc-800 rounded-xl border border-zinc-200 dark:border-zinc-700 p-8 shadow-sm",
+                h3 { class: "text-base font-semibold text-zinc-900 dark:text-white mb-6", "Archiving" }
+                ArchiveSettingsForm {
+                    archive_after_days,
+                    on_change: move |days: Option<u32>| {
+                        tracing::info!("[SettingsPage] Archive threshold changed to {:?}", days);
+                        archive_after_days.set(days);
+                        save_archive(settings_for_archive());
+                    },
+                }
+            }
+
+            div { class: "bg-white dark:bg-zinc-800 rounded-xl border border-zinc-200 dark:border-zinc-700 p-8 shadow-sm",
+                h3 { class: "text-base font-semibold text-zinc-900 dark:text-white mb-6", "Database Connections" }
+                DbConnectionSettingsForm {
+                    connections: db_connections,
+                    on_change: move |connections: Vec<DbConnectionSettings>| {
+                        tracing::info!("[SettingsPage] Database connections changed ({} defined)", connections.len());
+                        db_connections.set(connections);
+                        save_db_connections(settings_for_db_connections());
+                    },
+                }
+            }
+
+            div { class: "bg-white dark:bg-zinc-800 rounded-xl border border-zinc-200 dark:border-zinc-700 p-8 shadow-sm",
+                h3 { class: "text-base font-semibold text-zinc-900 dark:text-white mb-6", "Message Brokers" }
+                MessageConnectionSettingsForm {
+                    connections: message_connections,
+                    on_change: move |connections: Vec<MessageConnectionSettings>| {
+                        tracing::info!("[SettingsPage] Message brokers changed ({} defined)", connections.len());
+                        message_connections.set(connections);
+                        save_message_connections(settings_for_message_connections());
+                    },
+                }
+            }
+
+            div { class: "bg-white dark:bg-zinc-800 rounded-xl border border-zinc-200 dark:border-zinc-700 p-8 shadow-sm",
+                h3 { class: "text-base font-semibold text-zinc-900 dark:text-white mb-6", "Handler Policy" }
+                HandlerPolicySettingsForm {
+                    policy: handler_policy,
+                    on_change: move |new_policy: HandlerPolicySettings| {
+                        tracing::info!("[SettingsPage] Handler policy changed to {:?}", new_policy.active_profile);
+                        handler_policy.set(new_policy);
+                        save_handler_policy(settings_for_handler_policy());
+                    },
+                }
+            }
+
+            div { class: "bg-white dark:bg-zinc-800 rounded-xl border border-zinc-200 dark:border-zinc-700 p-8 shadow-sm",
+                h3 { class: "text-base font-semibold text-zinc-900 dark:text-white mb-6", "Logging" }
+                LogSettingsForm {
+                    logging,
+                    on_change: move |new_logging: LogSettings| {
+                        tracing::info!("[SettingsPage] Log levels changed to {:?}", new_logging);
+                        logging.set(new_logging);
+                        save_logging(settings_for_logging());
+                    },
+                }
+            }
+
+            div { class: "bg-white dark:bg-zinc-800 rounded-xl border border-zinc-200 dark:border-zinc-700 p-8 shadow-sm",
+                h3 { class: "text-base font-semibold text-zinc-900 dark:text-white mb-6", "Storage" }
+                StorageSettings {}
+            }
+
             ConfirmDialog {
                 show: show_confirm_dialog(),
                 title: "Clear All Data?".to_string(),
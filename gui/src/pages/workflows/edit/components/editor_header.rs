@@ -1,7 +1,9 @@
+use super::RunPartDialog;
 use crate::components::{IconButton, IconButtonSize, IconButtonVariant};
 use crate::layout::router::Route;
 use dioxus::prelude::*;
 use dioxus_router::prelude::use_navigator;
+use s_e_e_core::generate_workflow_readme;
 
 #[derive(Props, PartialEq, Clone)]
 pub struct EditorHeaderProps {
@@ -10,6 +12,9 @@ pub struct EditorHeaderProps {
     pub is_saving: bool,
     pub has_unsaved_changes: Signal<bool>,
     pub on_save: EventHandler<()>,
+    /// Set for workflows that came from an example; shows "Reset to default".
+    #[props(default)]
+    pub on_reset: Option<EventHandler<()>>,
 }
 
 #[component]
@@ -20,9 +25,31 @@ pub fn EditorHeader(props: EditorHeaderProps) -> Element {
         is_saving,
         has_unsaved_changes,
         on_save,
+        on_reset,
     } = props;
 
     let navigator = use_navigator();
+    let mut readme_status = use_signal(|| None::<&'static str>);
+    let mut show_run_part = use_signal(|| false);
+    let readme_workflow_id = workflow_id.clone();
+    let run_part_workflow_id = workflow_id.clone();
+
+    let copy_readme = move |_| {
+        let id = readme_workflow_id.clone();
+        spawn(async move {
+            match generate_workflow_readme(&id).await {
+                Ok(readme) => {
+                    let text = serde_json::to_string(&readme).unwrap_or_default();
+                    document::eval(&format!("navigator.clipboard.writeText({});", text));
+                    readme_status.set(Some("Copied"));
+                }
+                Err(e) => {
+                    tracing::error!("Failed to generate README for {}: {}", id, e);
+                    readme_status.set(Some("Copy failed"));
+                }
+            }
+        });
+    };
 
     rsx! {
         div { class: "flex items-center justify-between",
@@ -50,6 +77,18 @@ pub fn EditorHeader(props: EditorHeaderProps) -> Element {
             }
             div { class: "flex items-center gap-3",
 
+                if let Some(on_reset) = on_reset {
+                    IconButton {
+                        variant: IconButtonVariant::Secondary,
+                        size: IconButtonSize::Medium,
+                        disabled: Some(is_saving),
+                        onclick: move |_| on_reset.call(()),
+                        icon: Some("arrow_path".to_string()),
+                        icon_variant: "outline".to_string(),
+                        "Reset to default"
+                    }
+                }
+
                 if !is_new {
                     IconButton {
                         variant: IconButtonVariant::Secondary,
@@ -61,6 +100,30 @@ pub fn EditorHeader(props: EditorHeaderProps) -> Element {
                         icon_variant: "outline".to_string(),
                         "Edit in JSON"
                     }
+
+                    IconButton {
+                        variant: IconButtonVariant::Secondary,
+                        size: IconButtonSize::Medium,
+                        onclick: copy_readme,
+                        icon: Some("copy".to_string()),
+                        icon_variant: "outline".to_string(),
+                        {readme_status().unwrap_or("Copy README")}
+                    }
+
+                    IconButton {
+                        variant: IconButtonVariant::Secondary,
+                        size: IconButtonSize::Medium,
+                        onclick: move |_| show_run_part.set(true),
+                        icon: Some("play".to_string()),
+                        icon_variant: "outline".to_string(),
+                        "Run part"
+                    }
+                    if show_run_part() {
+                        RunPartDialog {
+                            workflow_id: run_part_workflow_id.clone(),
+                            on_close: move |_| show_run_part.set(false),
+                        }
+                    }
                 }
 
                 IconButton {
@@ -1,11 +1,13 @@
+use super::ValidationDiagnostics;
 use crate::components::forms::TextareaInput;
 use dioxus::prelude::*;
+use s_e_e_core::{QuickFix, ValidationError};
 
 #[derive(Props, PartialEq, Clone)]
 pub struct JsonEditorProps {
     pub content: Signal<String>,
     pub workflow_name: Signal<String>,
-    pub validation_error: Signal<String>,
+    pub diagnostics: Memo<Vec<ValidationError>>,
     pub on_content_change: EventHandler<String>,
     pub is_readonly: Option<bool>,
 }
@@ -15,7 +17,7 @@ pub fn JsonEditor(props: JsonEditorProps) -> Element {
     let JsonEditorProps {
         content,
         workflow_name: _,
-        validation_error,
+        diagnostics,
         on_content_change,
         is_readonly,
     } = props;
@@ -39,6 +41,12 @@ pub fn JsonEditor(props: JsonEditorProps) -> Element {
         }
     });
 
+    // Fixes patch the content signal; the page re-validates from it.
+    let apply_fix = move |fix: QuickFix| match fix.apply_to_source(&content()) {
+        Some(fixed) => on_content_change.call(fixed),
+        None => tracing::warn!("Quick fix no longer applies to the current content"),
+    };
+
     rsx! {
         div { class: "bg-white dark:bg-zinc-800 rounded-xl border border-zinc-200 dark:border-zinc-700 p-8 shadow-sm",
             div { class: "space-y-6",
@@ -51,9 +59,14 @@ pub fn JsonEditor(props: JsonEditorProps) -> Element {
                     disabled: Some(readonly),
                 }
 
-                if !validation_error().is_empty() {
-                    div { class: "mt-2 text-sm text-red-600 dark:text-red-400",
-                        {validation_error()}
+                if !diagnostics().is_empty() {
+                    ValidationDiagnostics {
+                        diagnostics: diagnostics(),
+                        on_fix: move |fix| {
+                            if !readonly {
+                                apply_fix(fix);
+                            }
+                        },
                     }
                 }
             }
@@ -1,7 +1,17 @@
 pub mod editor_header;
 pub mod json_editor;
+pub mod reset_dialog;
+pub mod run_part_dialog;
+pub mod snippet_palette;
+pub mod validation_diagnostics;
 pub mod visual_editor;
+pub mod workflow_tests;
 
 pub use editor_header::EditorHeader;
 pub use json_editor::JsonEditor;
+pub use reset_dialog::ResetToDefaultDialog;
+pub use run_part_dialog::RunPartDialog;
+pub use snippet_palette::SnippetPalette;
+pub use validation_diagnostics::ValidationDiagnostics;
 pub use visual_editor::VisualEditor;
+pub use workflow_tests::WorkflowTestsPanel;
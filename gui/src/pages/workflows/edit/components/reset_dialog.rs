@@ -0,0 +1,100 @@
+use crate::components::a11y::{use_focus_trap, use_unique_id};
+use crate::components::{IconButton, IconButtonSize, IconButtonVariant};
+use dioxus::prelude::*;
+use s_e_e_core::{DefaultResetPreview, DiffLineKind};
+
+/// Shows what "Reset to default" would overwrite before it happens.
+#[component]
+pub fn ResetToDefaultDialog(
+    preview: Option<DefaultResetPreview>,
+    has_unsaved_changes: bool,
+    is_resetting: bool,
+    on_confirm: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let dialog_id = use_unique_id("reset-dialog");
+    use_focus_trap(dialog_id.clone(), preview.is_some());
+
+    let Some(preview) = preview else {
+        return rsx! { div {} };
+    };
+    let has_changes = preview.has_changes();
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-50 flex items-center justify-center",
+            onkeydown: move |evt: KeyboardEvent| {
+                if evt.key() == Key::Escape {
+                    on_cancel.call(());
+                }
+            },
+            div {
+                class: "absolute inset-0 bg-black/50",
+                "aria-hidden": "true",
+                onclick: move |_| on_cancel.call(())
+            }
+            div {
+                id: "{dialog_id}",
+                role: "alertdialog",
+                "aria-modal": "true",
+                "aria-labelledby": "{dialog_id}-title",
+                tabindex: "-1",
+                class: "relative bg-white dark:bg-zinc-800 rounded-xl shadow-xl p-6 max-w-3xl w-full mx-4 z-10 space-y-4 focus:outline-none",
+                h3 { id: "{dialog_id}-title", class: "text-lg font-semibold text-zinc-900 dark:text-white",
+                    "Reset '{preview.name}' to default?"
+                }
+                if has_changes {
+                    p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                        "Removed lines are your saved version; added lines are the version shipped with the app."
+                        if preview.is_edited { " Your edits will be lost." }
+                    }
+                    div { class: "max-h-96 overflow-auto rounded-md border border-zinc-200 dark:border-zinc-700 font-mono text-xs",
+                        for (index, line) in preview.lines.iter().enumerate() {
+                            {
+                                let (marker, class) = match line.kind {
+                                    DiffLineKind::Unchanged => (" ", "text-zinc-600 dark:text-zinc-400"),
+                                    DiffLineKind::Removed => ("-", "bg-red-50 text-red-800 dark:bg-red-900/30 dark:text-red-300"),
+                                    DiffLineKind::Added => ("+", "bg-green-50 text-green-800 dark:bg-green-900/30 dark:text-green-300"),
+                                };
+                                rsx! {
+                                    div { key: "{index}", class: "whitespace-pre px-3 {class}",
+                                        "{marker} {line.text}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                } else {
+                    p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                        "The saved workflow already matches the shipped version."
+                    }
+                }
+                if has_unsaved_changes {
+                    p { class: "text-sm text-amber-700 dark:text-amber-300",
+                        "Unsaved changes in the editor will be discarded as well."
+                    }
+                }
+                div { class: "flex gap-3 justify-end",
+                    IconButton {
+                        variant: IconButtonVariant::Secondary,
+                        size: IconButtonSize::Medium,
+                        onclick: move |_| on_cancel.call(()),
+                        icon: Some("x".to_string()),
+                        icon_variant: "outline".to_string(),
+                        "Cancel"
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Danger,
+                        size: IconButtonSize::Medium,
+                        disabled: Some(is_resetting || (!has_changes && !has_unsaved_changes)),
+                        loading: Some(is_resetting),
+                        onclick: move |_| on_confirm.call(()),
+                        icon: if is_resetting { None } else { Some("check_circle".to_string()) },
+                        icon_variant: "outline".to_string(),
+                        if is_resetting { "Resetting..." } else { "Reset" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use crate::components::a11y::{use_focus_trap, use_unique_id};
+use crate::components::{Alert, AlertType, IconButton, IconButtonSize, IconButtonVariant};
+use crate::layout::router::Route;
+use crate::queries::{
+    use_execute_workflow_part_mutation, use_execution_page_query, use_workflow_query,
+};
+use dioxus::prelude::*;
+use dioxus_router::prelude::use_navigator;
+use s_e_e_core::{ExecutionFilter, RunBounds};
+use serde_json::Value;
+
+const SELECT_CLASS: &str = "block w-full rounded-md border-0 py-1.5 pl-3 pr-10 text-zinc-900 dark:text-white shadow-sm ring-1 ring-inset ring-zinc-300 dark:ring-zinc-600 focus:ring-2 focus:ring-inset focus:ring-blue-600 dark:bg-zinc-700 sm:text-sm sm:leading-6";
+
+/// Runs the saved workflow from one task and/or up to another, optionally
+/// reusing an earlier run's outputs for the tasks it skips.
+#[component]
+pub fn RunPartDialog(workflow_id: String, on_close: EventHandler<()>) -> Element {
+    let dialog_id = use_unique_id("run-part-dialog");
+    use_focus_trap(dialog_id.clone(), true);
+    let navigator = use_navigator();
+
+    let (workflow_state, _refetch) = use_workflow_query(workflow_id.clone());
+    let (executions_state, _refetch_executions) = use_execution_page_query(
+        ExecutionFilter {
+            workflow_id: Some(workflow_id.clone()),
+            ..Default::default()
+        },
+        use_signal(|| 20),
+    );
+    let (run_state, run_part) = use_execute_workflow_part_mutation();
+    let mut from = use_signal(String::new);
+    let mut until = use_signal(String::new);
+    let mut context_from = use_signal(String::new);
+
+    use_effect(move || {
+        if let Some(result) = run_state.read().data.clone() {
+            navigator.push(Route::WorkflowDetailsPage {
+                id: result.execution_id,
+            });
+        }
+    });
+
+    let tasks = workflow_state
+        .data
+        .flatten()
+        .and_then(|workflow| serde_json::from_str::<Value>(&workflow.content).ok())
+        .map(|json| task_options(&json["tasks"]))
+        .unwrap_or_default();
+    let executions = executions_state
+        .data
+        .map(|page| page.executions)
+        .unwrap_or_default();
+    let running = run_state.read().is_loading;
+    let some = |value: String| (!value.is_empty()).then_some(value);
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-50 flex items-center justify-center",
+            onkeydown: move |evt: KeyboardEvent| {
+                if evt.key() == Key::Escape {
+                    on_close.call(());
+                }
+            },
+            div {
+                class: "absolute inset-0 bg-black/50",
+                "aria-hidden": "true",
+                onclick: move |_| on_close.call(())
+            }
+            div {
+                id: "{dialog_id}",
+                role: "dialog",
+                "aria-modal": "true",
+                "aria-labelledby": "{dialog_id}-title",
+                tabindex: "-1",
+                class: "relative bg-white dark:bg-zinc-800 rounded-xl shadow-xl p-6 max-w-lg w-full mx-4 z-10 space-y-4 focus:outline-none",
+                h3 { id: "{dialog_id}-title", class: "text-lg font-semibold text-zinc-900 dark:text-white",
+                    "Run part of the workflow"
+                }
+                p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                    "Runs the saved version. Tasks before the start task are skipped, and nothing runs after the stop task except what leads to it."
+                }
+
+                label { class: "block text-sm font-medium text-zinc-900 dark:text-white",
+                    "Start at"
+                    select {
+                        class: SELECT_CLASS,
+                        value: from(),
+                        onchange: move |evt| from.set(evt.value()),
+                        option { value: "", "The beginning" }
+                        for (id, name) in tasks.iter() {
+                            option { key: "{id}", value: "{id}", selected: from() == *id, "{name}" }
+                        }
+                    }
+                }
+                label { class: "block text-sm font-medium text-zinc-900 dark:text-white",
+                    "Stop after"
+                    select {
+                        class: SELECT_CLASS,
+                        value: until(),
+                        onchange: move |evt| until.set(evt.value()),
+                        option { value: "", "The end" }
+                        for (id, name) in tasks.iter() {
+                            option { key: "{id}", value: "{id}", selected: until() == *id, "{name}" }
+                        }
+                    }
+                }
+                if !from().is_empty() {
+                    label { class: "block text-sm font-medium text-zinc-900 dark:text-white",
+                        "Outputs of skipped tasks from"
+                        select {
+                            class: SELECT_CLASS,
+                            value: context_from(),
+                            onchange: move |evt| context_from.set(evt.value()),
+                            option { value: "", "Nowhere" }
+                            for execution in executions.iter() {
+                                option {
+                                    key: "{execution.id}",
+                                    value: "{execution.id}",
+                                    selected: context_from() == execution.id,
+                                    {format!("{} · {}", execution.created_at.format("%Y-%m-%d %H:%M"), execution.status)}
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if let Some(error) = run_state.read().error.clone() {
+                    Alert {
+                        alert_type: AlertType::Error,
+                        title: Some("Could not run".to_string()),
+                        message: error,
+                        dismissible: None,
+                        on_dismiss: None,
+                        actions: None,
+                    }
+                }
+
+                div { class: "flex gap-3 justify-end",
+                    IconButton {
+                        variant: IconButtonVariant::Secondary,
+                        size: IconButtonSize::Medium,
+                        onclick: move |_| on_close.call(()),
+                        icon: Some("x".to_string()),
+                        icon_variant: "outline".to_string(),
+                        "Cancel"
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Primary,
+                        size: IconButtonSize::Medium,
+                        disabled: Some(running),
+                        loading: Some(running),
+                        onclick: move |_| {
+                            let start = some(from());
+                            let bounds = RunBounds {
+                                context_from: start.as_ref().and_then(|_| some(context_from())),
+                                from: start,
+                                until: some(until()),
+                            };
+                            run_part((workflow_id.clone(), bounds));
+                        },
+                        icon: if running { None } else { Some("play".to_string()) },
+                        icon_variant: "outline".to_string(),
+                        if running { "Running..." } else { "Run" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `(id, name)` of every task in document order.
+fn task_options(tasks: &Value) -> Vec<(String, String)> {
+    let mut options = Vec::new();
+    for task in tasks.as_array().map(Vec::as_slice).unwrap_or_default() {
+        if let Some(id) = task["id"].as_str() {
+            let name = task["name"].as_str().unwrap_or(id);
+            options.push((id.to_string(), name.to_string()));
+        }
+        options.extend(task_options(&task["next_tasks"]));
+    }
+    options
+}
@@ -0,0 +1,74 @@
+use crate::components::{IconButton, IconButtonSize, IconButtonVariant};
+use crate::queries::use_snippets_query;
+use dioxus::prelude::*;
+use s_e_e_core::insert_snippet_reference;
+
+#[derive(Props, PartialEq, Clone)]
+pub struct SnippetPaletteProps {
+    pub content: Signal<String>,
+    pub on_content_change: EventHandler<String>,
+}
+
+/// Saved task snippets, each of which can be appended to the workflow as a
+/// reference.
+#[component]
+pub fn SnippetPalette(props: SnippetPaletteProps) -> Element {
+    let SnippetPaletteProps {
+        content,
+        on_content_change,
+    } = props;
+    let (snippets_state, _refetch) = use_snippets_query();
+    let mut insert_error = use_signal(|| None::<String>);
+
+    let snippets = snippets_state.data.clone().unwrap_or_default();
+
+    rsx! {
+        div { class: "rounded-lg border border-zinc-200 dark:border-zinc-700 p-4 space-y-3",
+            h2 { class: "text-sm font-semibold text-zinc-900 dark:text-white", "Snippets" }
+
+            if snippets_state.is_error {
+                p { class: "text-sm text-red-600 dark:text-red-400",
+                    "Could not load snippets: {snippets_state.error.clone().unwrap_or_default()}"
+                }
+            } else if snippets.is_empty() {
+                p { class: "text-sm text-zinc-500 dark:text-zinc-400", "No snippets saved" }
+            }
+
+            if let Some(error) = insert_error() {
+                p { class: "text-sm text-red-600 dark:text-red-400", "{error}" }
+            }
+
+            ul { class: "space-y-2",
+                for snippet in snippets {
+                    li {
+                        key: "{snippet.id}",
+                        class: "flex items-start justify-between gap-3 text-sm",
+                        div {
+                            p { class: "font-medium text-zinc-900 dark:text-zinc-100", "{snippet.name}" }
+                            code { class: "text-xs text-zinc-500 dark:text-zinc-400", "{snippet.id}" }
+                            if let Some(description) = &snippet.description {
+                                p { class: "text-xs text-zinc-500 dark:text-zinc-400", "{description}" }
+                            }
+                        }
+                        IconButton {
+                            variant: IconButtonVariant::Ghost,
+                            size: IconButtonSize::Small,
+                            onclick: move |_| {
+                                match insert_snippet_reference(&content(), &snippet) {
+                                    Ok(updated) => {
+                                        insert_error.set(None);
+                                        on_content_change.call(updated);
+                                    }
+                                    Err(e) => insert_error.set(Some(e)),
+                                }
+                            },
+                            icon: Some("plus".to_string()),
+                            icon_variant: "outline".to_string(),
+                            "Insert"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::components::{Badge, BadgeButton, BadgeColor};
+use dioxus::prelude::*;
+use s_e_e_core::{QuickFix, Severity, ValidationCode, ValidationError};
+
+/// Button label for the quick fix offered for a diagnostic, if any.
+fn fix_label(error: &ValidationError) -> Option<&'static str> {
+    let fix = error.fix.as_ref()?;
+    Some(match (error.code, fix) {
+        (ValidationCode::UnknownField, _) => "Remove field",
+        (ValidationCode::DuplicateTaskId, _) => "Rename task",
+        (ValidationCode::UnquotedTemplate, _) => "Wrap in quotes",
+        (_, QuickFix::Insert { .. } | QuickFix::Replace { .. }) if error.path.ends_with("/id") => {
+            "Generate id"
+        }
+        (_, QuickFix::Insert { .. }) => "Add field",
+        (_, QuickFix::Replace { .. } | QuickFix::Edit { .. }) => "Replace value",
+        (_, QuickFix::Remove { .. }) => "Remove",
+    })
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct ValidationDiagnosticsProps {
+    pub diagnostics: Vec<ValidationError>,
+    pub on_fix: EventHandler<QuickFix>,
+}
+
+#[component]
+pub fn ValidationDiagnostics(props: ValidationDiagnosticsProps) -> Element {
+    let ValidationDiagnosticsProps {
+        diagnostics,
+        on_fix,
+    } = props;
+
+    rsx! {
+        ul { class: "mt-2 space-y-2",
+            for (index, diagnostic) in diagnostics.into_iter().enumerate() {
+                li {
+                    key: "{index}",
+                    class: "flex items-start justify-between gap-3 rounded-lg border border-zinc-200 dark:border-zinc-700 px-3 py-2 text-sm",
+                    div { class: "space-y-1",
+                        div { class: "flex items-center gap-2",
+                            Badge {
+                                color: match diagnostic.severity {
+                                    Severity::Error => BadgeColor::Red,
+                                    Severity::Warning => BadgeColor::Amber,
+                                },
+                                "{diagnostic.code}"
+                            }
+                            code { class: "text-xs text-zinc-500 dark:text-zinc-400",
+                                if diagnostic.path.is_empty() { "/" } else { "{diagnostic.path}" }
+                            }
+                        }
+                        p { class: "text-zinc-900 dark:text-zinc-100", "{diagnostic.message}" }
+                        for suggestion in diagnostic.suggestions.iter() {
+                            p { class: "text-xs text-zinc-500 dark:text-zinc-400", "{suggestion}" }
+                        }
+                    }
+                    if let (Some(label), Some(fix)) = (fix_label(&diagnostic), diagnostic.fix.clone()) {
+                        BadgeButton {
+                            color: BadgeColor::Blue,
+                            onclick: move |_| on_fix.call(fix.clone()),
+                            "{label}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,11 +1,97 @@
+use crate::components::{IconButton, IconButtonSize, IconButtonVariant};
+use crate::layout::hooks::{use_theme_mode, use_ui_state};
+use crate::services::file_stem;
+use base64::prelude::{Engine, BASE64_STANDARD};
 use dioxus::prelude::*;
+use rfd::FileDialog;
+use s_e_e_core::{layout_workflow_content, LayoutMode, ViewportState};
+use serde::Deserialize;
+use serde_json::json;
+use std::path::PathBuf;
 
-use super::super::{load_workflow_script, MESSAGE_LISTENER_SCRIPT};
+use super::super::{
+    export_image_script, initial_theme_script, layout_payload, load_workflow_script,
+    set_theme_script, view_command_script, EDITOR_EVENTS_SCRIPT, MESSAGE_LISTENER_SCRIPT,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum ImageFormat {
+    Svg,
+    Png,
+}
+
+impl ImageFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Svg => "svg",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+/// Messages from the editor that the page acts on while it is open.
+#[derive(Deserialize)]
+#[serde(tag = "type", content = "payload", rename_all = "SCREAMING_SNAKE_CASE")]
+enum EditorEvent {
+    ViewportChanged {
+        viewport: ViewportState,
+    },
+    LayoutRequested {
+        mode: LayoutMode,
+        workflow: serde_json::Value,
+    },
+}
+
+#[derive(Deserialize)]
+struct ExportReply {
+    data: Option<String>,
+    error: Option<String>,
+}
+
+/// Has the editor render the whole graph and saves it where the user picks.
+/// `Ok(None)` when they cancel the dialog.
+async fn export_image(format: ImageFormat, workflow_name: &str) -> Result<Option<PathBuf>, String> {
+    let mut eval = document::eval(&export_image_script(format.extension()));
+    let reply: ExportReply = eval
+        .recv()
+        .await
+        .map_err(|e| format!("Export failed: {}", e))?;
+    let data = match (reply.data, reply.error) {
+        (Some(data), _) => data,
+        (None, error) => {
+            return Err(format!(
+                "Export failed: {}",
+                error.unwrap_or_else(|| "no image returned".to_string())
+            ))
+        }
+    };
+    let bytes = match format {
+        ImageFormat::Svg => data.into_bytes(),
+        ImageFormat::Png => BASE64_STANDARD
+            .decode(data)
+            .map_err(|e| format!("Export failed: {}", e))?,
+    };
+
+    let stem = file_stem(workflow_name, "workflow");
+    let Some(path) = FileDialog::new()
+        .add_filter(format.extension().to_uppercase(), &[format.extension()])
+        .set_file_name(format!("{}.{}", stem, format.extension()))
+        .set_title("Export Workflow Diagram")
+        .save_file()
+    else {
+        return Ok(None);
+    };
+    std::fs::write(&path, bytes)
+        .map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+    Ok(Some(path))
+}
 
 #[derive(Props, PartialEq, Clone)]
 pub struct VisualEditorProps {
     pub workflow_json_str: Memo<Option<String>>,
     pub edited_workflow_name: Signal<String>,
+    /// Saved workflows remember where the canvas was left; new ones do not.
+    pub workflow_id: Option<String>,
 }
 
 #[component]
@@ -13,28 +99,159 @@ pub fn VisualEditor(props: VisualEditorProps) -> Element {
     let VisualEditorProps {
         workflow_json_str,
         edited_workflow_name,
+        workflow_id,
     } = props;
 
+    // The iframe starts with the theme current at load; later changes are
+    // posted to it so it switches without reloading and losing edits.
+    let theme_mode = use_theme_mode();
+    use_effect(move || {
+        document::eval(&set_theme_script(theme_mode()));
+    });
+
+    let mut ui_state = use_ui_state();
+    let saved_viewport = workflow_id
+        .as_ref()
+        .and_then(|id| ui_state.peek().viewports.get(id).copied());
+    let show_minimap = !ui_state.read().hide_minimap;
+    let mut status = use_signal(|| None::<String>);
+    use_future(move || {
+        let workflow_id = workflow_id.clone();
+        async move {
+            let mut eval = document::eval(EDITOR_EVENTS_SCRIPT);
+            while let Ok(event) = eval.recv::<EditorEvent>().await {
+                match event {
+                    EditorEvent::ViewportChanged { viewport } => {
+                        let Some(workflow_id) = &workflow_id else {
+                            continue;
+                        };
+                        if ui_state.peek().viewports.get(workflow_id) != Some(&viewport) {
+                            ui_state
+                                .write()
+                                .viewports
+                                .insert(workflow_id.clone(), viewport);
+                        }
+                    }
+                    EditorEvent::LayoutRequested { mode, workflow } => {
+                        match layout_workflow_content(&workflow.to_string(), Some(mode)) {
+                            Ok(graph) => {
+                                document::eval(&view_command_script(
+                                    "APPLY_LAYOUT",
+                                    json!({ "layout": layout_payload(&graph) }),
+                                ));
+                            }
+                            Err(e) => status.set(Some(format!("Layout failed: {}", e))),
+                        }
+                    }
+                }
+            }
+        }
+    });
+    let view_command = |command: &'static str| {
+        move |_: ()| {
+            document::eval(&view_command_script(command, json!({})));
+        }
+    };
+
+    let mut exporting = use_signal(|| false);
+    let mut export = move |format: ImageFormat| {
+        exporting.set(true);
+        status.set(None);
+        let workflow_name = edited_workflow_name.peek().clone();
+        spawn(async move {
+            match export_image(format, &workflow_name).await {
+                Ok(Some(path)) => status.set(Some(format!("Saved {}", path.display()))),
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::error!("Failed to export workflow diagram: {}", e);
+                    status.set(Some(e));
+                }
+            }
+            exporting.set(false);
+        });
+    };
+
     rsx! {
 
         script {
             dangerous_inner_html: MESSAGE_LISTENER_SCRIPT.to_string()
         }
 
-        div { class: "bg-white dark:bg-zinc-800 rounded-xl border border-zinc-200 dark:border-zinc-700 shadow-sm",
-            div { class: "relative h-[calc(100vh-100px)]",
+        div { class: "flex flex-col h-[calc(100vh-100px)] bg-white dark:bg-zinc-800 rounded-xl border border-zinc-200 dark:border-zinc-700 shadow-sm",
+            if workflow_json_str().is_some() {
+                div { class: "flex items-center justify-end gap-2 px-4 py-2 border-b border-zinc-200 dark:border-zinc-700",
+                    if let Some(status) = status() {
+                        span { class: "mr-auto truncate text-sm text-zinc-600 dark:text-zinc-400", "{status}" }
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Ghost,
+                        size: IconButtonSize::Small,
+                        onclick: view_command("ZOOM_OUT"),
+                        aria_label: Some("Zoom out".to_string()),
+                        "−"
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Ghost,
+                        size: IconButtonSize::Small,
+                        onclick: view_command("ZOOM_IN"),
+                        aria_label: Some("Zoom in".to_string()),
+                        "+"
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Ghost,
+                        size: IconButtonSize::Small,
+                        onclick: view_command("FIT_VIEW"),
+                        "Fit"
+                    }
+                    IconButton {
+                        variant: if show_minimap { IconButtonVariant::Secondary } else { IconButtonVariant::Ghost },
+                        size: IconButtonSize::Small,
+                        onclick: move |_| {
+                            let visible = !show_minimap;
+                            ui_state.write().hide_minimap = !visible;
+                            document::eval(&view_command_script("SET_MINIMAP", json!({ "minimap": visible })));
+                        },
+                        "Minimap"
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Secondary,
+                        size: IconButtonSize::Small,
+                        disabled: Some(exporting()),
+                        onclick: move |_| export(ImageFormat::Svg),
+                        icon: Some("save".to_string()),
+                        icon_variant: "outline".to_string(),
+                        "Export SVG"
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Secondary,
+                        size: IconButtonSize::Small,
+                        disabled: Some(exporting()),
+                        onclick: move |_| export(ImageFormat::Png),
+                        icon: Some("save".to_string()),
+                        icon_variant: "outline".to_string(),
+                        "Export PNG"
+                    }
+                }
+            }
+            div { class: "relative flex-1 min-h-0",
 
                 if let Some(json_str) = workflow_json_str() {
 
                     script {
-                        dangerous_inner_html: load_workflow_script(&json_str, &edited_workflow_name())
+                        dangerous_inner_html: load_workflow_script(
+                            &json_str,
+                            &edited_workflow_name(),
+                            layout_workflow_content(&json_str, None).ok().as_ref(),
+                            saved_viewport,
+                            !ui_state.peek().hide_minimap,
+                        )
                     }
 
                     iframe {
                         id: "workflow-editor-iframe",
                         srcdoc: format!(
                             r#"<!DOCTYPE html>
-<html lang="en">
+<html lang="en" class="{}">
   <head>
     <meta charset="UTF-8" />
     <meta name="viewport" content="width=device-width, initial-scale=1.0" />
@@ -42,8 +259,9 @@ pub fn VisualEditor(props: VisualEditorProps) -> Element {
     <link rel="stylesheet" href="{}" />
     <link rel="stylesheet" href="{}" />
     <script>
-      // Set mode before React app loads
+      // Set mode and theme before React app loads
       window.WORKFLOW_MODE = 'editor';
+      {}
     </script>
   </head>
   <body>
@@ -51,8 +269,10 @@ pub fn VisualEditor(props: VisualEditorProps) -> Element {
     <script type="module" src="{}"></script>
   </body>
 </html>"#,
+                            theme_mode.peek(),
                             asset!("/assets/workflow-visualizer/index.css"),
                             asset!("/assets/tailwind.css"),
+                            initial_theme_script(&theme_mode.peek()),
                             asset!("/assets/workflow-visualizer/index.js")
                         ),
                         class: "w-full h-full border-0 rounded-b-xl min-h-0",
@@ -0,0 +1,176 @@
+use crate::components::{
+    Alert, AlertType, Badge, BadgeColor, IconButton, IconButtonSize, IconButtonVariant,
+    SectionCard, Timestamp,
+};
+use crate::layout::router::Route;
+use crate::queries::{
+    use_delete_workflow_test_mutation, use_run_workflow_tests_mutation, use_workflow_tests_query,
+};
+use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
+use s_e_e_core::{ContextMismatch, WorkflowTestReport};
+use serde_json::Value;
+use std::rc::Rc;
+
+fn mismatch_value(value: &Option<Value>) -> String {
+    value
+        .as_ref()
+        .map_or("nothing".to_string(), |value| value.to_string())
+}
+
+fn describe_mismatch(mismatch: &ContextMismatch) -> String {
+    format!(
+        "{}: expected {}, got {}",
+        mismatch.path,
+        mismatch_value(&mismatch.expected),
+        mismatch_value(&mismatch.actual)
+    )
+}
+
+/// Why a test failed, one line per problem.
+fn failure_lines(report: &WorkflowTestReport) -> Vec<String> {
+    let mut lines = Vec::new();
+    if report.actual_success != report.expected_success {
+        lines.push(if report.expected_success {
+            "The recorded run succeeded, but this one failed".to_string()
+        } else {
+            "The recorded run failed, but this one succeeded".to_string()
+        });
+    }
+    for task in &report.unrecorded_tasks {
+        lines.push(format!("Task '{}' ran but has no recorded result", task));
+    }
+    lines.extend(report.mismatches.iter().map(describe_mismatch));
+    if report.mismatch_count > report.mismatches.len() {
+        lines.push(format!(
+            "... and {} more differences",
+            report.mismatch_count - report.mismatches.len()
+        ));
+    }
+    lines
+}
+
+/// Tests recorded from past runs of the workflow, with a button that replays
+/// them against the saved definition.
+#[component]
+pub fn WorkflowTestsPanel(workflow_id: String, has_unsaved_changes: bool) -> Element {
+    let (tests_state, _refetch) = use_workflow_tests_query(workflow_id.clone());
+    let (run_state, run_fn) = use_run_workflow_tests_mutation();
+    let (delete_state, delete_fn) = use_delete_workflow_test_mutation();
+    let delete_fn = Rc::new(delete_fn);
+
+    let tests = tests_state.data.clone().unwrap_or_default();
+    let reports = run_state.read().data.clone().unwrap_or_default();
+    let running = run_state.read().is_loading;
+    let passed = reports.iter().filter(|report| report.passed).count();
+    let failed = reports.len() - passed;
+
+    rsx! {
+        SectionCard {
+            title: Some("Tests".to_string()),
+            children: rsx! {
+                div { class: "space-y-4",
+                    div { class: "flex flex-wrap items-center gap-3",
+                        p { class: "flex-1 text-sm text-zinc-600 dark:text-zinc-400",
+                            "Each test replays the task results of a past run and checks that the workflow still ends with the same context. No task actually runs."
+                        }
+                        if !reports.is_empty() {
+                            Badge {
+                                color: if failed == 0 { BadgeColor::Emerald } else { BadgeColor::Red },
+                                "{passed} passed, {failed} failed"
+                            }
+                        }
+                        IconButton {
+                            variant: IconButtonVariant::Primary,
+                            size: IconButtonSize::Small,
+                            loading: Some(running),
+                            disabled: Some(tests.is_empty()),
+                            onclick: {
+                                let workflow_id = workflow_id.clone();
+                                move |_| run_fn(workflow_id.clone())
+                            },
+                            icon: Some("play".to_string()),
+                            icon_variant: "outline".to_string(),
+                            "Run tests"
+                        }
+                    }
+                    if has_unsaved_changes {
+                        p { class: "text-sm text-amber-700 dark:text-amber-400",
+                            "Tests run against the saved workflow. Save to test your changes."
+                        }
+                    }
+                    for error in [run_state.read().error.clone(), delete_state.read().error.clone()].into_iter().flatten() {
+                        Alert {
+                            alert_type: AlertType::Error,
+                            title: None,
+                            message: error,
+                            dismissible: None,
+                            on_dismiss: None,
+                            actions: None,
+                        }
+                    }
+                    if tests_state.is_loading {
+                        p { class: "text-sm text-zinc-500 dark:text-zinc-400", "Loading tests..." }
+                    } else if tests.is_empty() {
+                        p { class: "text-sm text-zinc-500 dark:text-zinc-400",
+                            "No tests yet. Open a finished run of this workflow and choose \"Create test\" to record one."
+                        }
+                    } else {
+                        ul { class: "divide-y divide-zinc-200 dark:divide-zinc-700",
+                            for test in tests {
+                                {
+                                    let report = reports.iter().find(|report| report.test_id == test.id).cloned();
+                                    let test_id = test.id.clone();
+                                    let delete_fn = delete_fn.clone();
+                                    rsx! {
+                                        li { key: "{test.id}", class: "py-3 space-y-2",
+                                            div { class: "flex flex-wrap items-center gap-3",
+                                                span { class: "text-sm font-medium text-zinc-900 dark:text-white", "{test.name}" }
+                                                match &report {
+                                                    Some(report) if report.passed => rsx! {
+                                                        Badge { color: BadgeColor::Emerald, "passed" }
+                                                    },
+                                                    Some(_) => rsx! {
+                                                        Badge { color: BadgeColor::Red, "failed" }
+                                                    },
+                                                    None => rsx! {},
+                                                }
+                                                span { class: "text-xs text-zinc-500 dark:text-zinc-400",
+                                                    "Recorded "
+                                                    Timestamp { value: test.created_at }
+                                                    " from "
+                                                    Link {
+                                                        to: Route::WorkflowDetailsPage { id: test.source_execution_id.clone() },
+                                                        class: "font-mono text-blue-600 hover:underline dark:text-blue-400",
+                                                        "{test.source_execution_id}"
+                                                    }
+                                                }
+                                                div { class: "flex-1" }
+                                                IconButton {
+                                                    variant: IconButtonVariant::Ghost,
+                                                    size: IconButtonSize::Small,
+                                                    onclick: move |_| delete_fn(test_id.clone()),
+                                                    icon: Some("trash".to_string()),
+                                                    icon_variant: "outline".to_string(),
+                                                    "Delete"
+                                                }
+                                            }
+                                            if let Some(report) = report.filter(|report| !report.passed) {
+                                                ul { class: "space-y-1 text-xs font-mono text-red-700 dark:text-red-300",
+                                                    for line in failure_lines(&report) {
+                                                        li { "{line}" }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            padding: Some("p-6".to_string()),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use s_e_e_core::{ViewportState, WorkflowGraph};
+
 pub const MESSAGE_LISTENER_SCRIPT: &str = r#"
 window.addEventListener('message', function(event) {
     if (event.data && event.data.type === 'NODE_CLICKED') {
@@ -29,7 +31,16 @@ window.addEventListener('message', function(event) {
 });
 "#;
 
-pub fn load_workflow_script(workflow_json: &str, workflow_name: &str) -> String {
+/// Sends the workflow to the editor with its nodes placed per `layout`,
+/// opening the canvas at `viewport` when one was saved for it and fitting the
+/// whole graph otherwise.
+pub fn load_workflow_script(
+    workflow_json: &str,
+    workflow_name: &str,
+    layout: Option<&WorkflowGraph>,
+    viewport: Option<ViewportState>,
+    minimap: bool,
+) -> String {
     format!(
         r#"
         setTimeout(function() {{
@@ -41,7 +52,10 @@ pub fn load_workflow_script(workflow_json: &str, workflow_name: &str) -> String
                         type: 'LOAD_WORKFLOW',
                         payload: {{
                             workflow: workflowData,
-                            workflowName: '{}'
+                            workflowName: '{}',
+                            layout: {},
+                            viewport: {},
+                            minimap: {}
                         }}
                     }}, '*');
                 }} else {{
@@ -52,6 +66,136 @@ pub fn load_workflow_script(workflow_json: &str, workflow_name: &str) -> String
             }}
         }}, 500);
         "#,
-        workflow_json, workflow_name
+        workflow_json,
+        workflow_name,
+        layout
+            .map(layout_payload)
+            .unwrap_or(serde_json::Value::Null),
+        serde_json::to_string(&viewport).unwrap_or_else(|_| "null".to_string()),
+        minimap
+    )
+}
+
+/// The editor's `LayoutPayload`: the mode and each task's position.
+pub fn layout_payload(graph: &WorkflowGraph) -> serde_json::Value {
+    let positions: serde_json::Map<String, serde_json::Value> = graph
+        .nodes
+        .iter()
+        .map(|node| {
+            (
+                node.id.clone(),
+                serde_json::json!({ "x": node.x, "y": node.y }),
+            )
+        })
+        .collect();
+    serde_json::json!({ "mode": graph.mode, "positions": positions })
+}
+
+/// Posts a view command such as `FIT_VIEW`, `ZOOM_IN`, `ZOOM_OUT`,
+/// `SET_MINIMAP` or `APPLY_LAYOUT` to the editor.
+pub fn view_command_script(command: &str, payload: serde_json::Value) -> String {
+    format!(
+        r#"
+        const iframe = document.getElementById('workflow-editor-iframe');
+        if (iframe && iframe.contentWindow) {{
+            iframe.contentWindow.postMessage({{
+                type: '{}',
+                payload: {}
+            }}, '*');
+        }}
+        "#,
+        command, payload
+    )
+}
+
+/// Sends every `VIEWPORT_CHANGED` and `LAYOUT_REQUESTED` message from the
+/// editor back to Dioxus, replacing the listener left by a previously opened
+/// editor.
+pub const EDITOR_EVENTS_SCRIPT: &str = r#"
+if (window.seeEditorListener) {
+    window.removeEventListener('message', window.seeEditorListener);
+}
+window.seeEditorListener = function(event) {
+    if (event.data && event.data.payload
+        && (event.data.type === 'VIEWPORT_CHANGED' || event.data.type === 'LAYOUT_REQUESTED')) {
+        dioxus.send(event.data);
+    }
+};
+window.addEventListener('message', window.seeEditorListener);
+await new Promise(() => {});
+"#;
+
+/// Theme the embedded editor should use: its mode plus CSS variables matching
+/// the app's palette, which it sets on its document.
+pub fn theme_payload(mode: &str) -> serde_json::Value {
+    let (accent, surface, text, border) = if mode == "dark" {
+        ("#3b82f6", "#27272a", "#ffffff", "#3f3f46")
+    } else {
+        ("#2563eb", "#ffffff", "#09090b", "#e4e4e7")
+    };
+    serde_json::json!({
+        "mode": mode,
+        "variables": {
+            "--see-accent": accent,
+            "--see-surface": surface,
+            "--see-text": text,
+            "--see-border": border,
+            "--xy-background-color": surface,
+        }
+    })
+}
+
+/// Sets the editor's theme before it loads, so it never flashes the wrong one.
+pub fn initial_theme_script(mode: &str) -> String {
+    format!("window.WORKFLOW_THEME = {};", theme_payload(mode))
+}
+
+/// Switches the already loaded editor to `mode`.
+pub fn set_theme_script(mode: &str) -> String {
+    format!(
+        r#"
+        const iframe = document.getElementById('workflow-editor-iframe');
+        if (iframe && iframe.contentWindow) {{
+            iframe.contentWindow.postMessage({{
+                type: 'SET_THEME',
+                payload: {{ theme: {} }}
+            }}, '*');
+        }}
+        "#,
+        theme_payload(mode)
+    )
+}
+
+/// Asks the editor to render its graph as `format` ("svg" or "png") and sends
+/// back its `IMAGE_EXPORTED` payload: `data`, or `error` if it could not.
+pub fn export_image_script(format: &str) -> String {
+    format!(
+        r#"
+        const iframe = document.getElementById('workflow-editor-iframe');
+        const reply = await new Promise((resolve) => {{
+            if (!iframe || !iframe.contentWindow) {{
+                resolve({{ error: 'The visual editor is not loaded' }});
+                return;
+            }}
+            const timer = setTimeout(() => {{
+                window.removeEventListener('message', listener);
+                resolve({{ error: 'The visual editor did not respond' }});
+            }}, 15000);
+            function listener(event) {{
+                if (event.data && event.data.type === 'IMAGE_EXPORTED') {{
+                    clearTimeout(timer);
+                    window.removeEventListener('message', listener);
+                    resolve(event.data.payload || {{}});
+                }}
+            }}
+            window.addEventListener('message', listener);
+            iframe.contentWindow.postMessage({{
+                type: 'EXPORT_IMAGE',
+                payload: {{ format: '{}' }}
+            }}, '*');
+        }});
+        dioxus.send(reply);
+        "#,
+        format
     )
 }
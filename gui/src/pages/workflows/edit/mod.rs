@@ -2,6 +2,11 @@ pub mod components;
 pub mod javascript_templates;
 pub mod page;
 
-pub use components::{EditorHeader, JsonEditor, VisualEditor};
-pub use javascript_templates::{load_workflow_script, MESSAGE_LISTENER_SCRIPT};
+pub use components::{
+    EditorHeader, JsonEditor, ResetToDefaultDialog, SnippetPalette, VisualEditor,
+};
+pub use javascript_templates::{
+    export_image_script, initial_theme_script, layout_payload, load_workflow_script,
+    set_theme_script, view_command_script, EDITOR_EVENTS_SCRIPT, MESSAGE_LISTENER_SCRIPT,
+};
 pub use page::{WorkflowEditPage, WorkflowEditPageNew};
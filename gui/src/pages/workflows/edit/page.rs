@@ -1,9 +1,17 @@
-use crate::queries::{use_create_workflow_mutation, use_workflow_query};
+use crate::components::{tab_id, tab_panel_id, Tabs};
+use crate::queries::{
+    use_create_workflow_mutation, use_reset_workflow_mutation, use_workflow_query,
+};
+use crate::services::workflow::WorkflowService;
 use dioxus::prelude::*;
-use s_e_e_core::WorkflowDefinition;
+use s_e_e_core::{DefaultResetPreview, WorkflowDefinition};
 use s_e_e_engine::parse_workflow;
 
-use super::{EditorHeader, VisualEditor};
+use super::{EditorHeader, ResetToDefaultDialog, VisualEditor, WorkflowTestsPanel};
+
+const EDIT_TABS_ID: &str = "workflow-edit";
+const EDITOR_TAB: &str = "Editor";
+const TEST_TAB: &str = "Test";
 
 #[component]
 pub fn WorkflowEditPage(id: String) -> Element {
@@ -33,6 +41,7 @@ pub fn WorkflowEditPage(id: String) -> Element {
         None
     };
 
+    let is_default = loaded_workflow.as_ref().is_some_and(|w| w.is_default);
     let mut content = use_signal(String::new);
     let mut edited_workflow_name = use_signal(String::new);
     let mut original_content = use_signal(String::new);
@@ -104,7 +113,7 @@ pub fn WorkflowEditPage(id: String) -> Element {
             name: name_str,
             description: None,
             content: content_str.clone(),
-            is_default: false,
+            is_default,
             is_edited: !is_new,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
@@ -121,6 +130,37 @@ pub fn WorkflowEditPage(id: String) -> Element {
         create_fn(json_str);
     };
 
+    let can_reset = !is_new && WorkflowService::is_example(&id);
+    let mut reset_preview = use_signal(|| None::<DefaultResetPreview>);
+    let mut reset_error = use_signal(|| None::<String>);
+    let (reset_state, reset_fn) = use_reset_workflow_mutation();
+
+    use_effect(move || {
+        if let Some(workflow) = reset_state.read().data.clone() {
+            content.set(workflow.content.clone());
+            edited_workflow_name.set(workflow.name.clone());
+            original_content.set(workflow.content);
+            original_name.set(workflow.name);
+            reset_preview.set(None);
+        }
+    });
+
+    let preview_id = id.clone();
+    let open_reset = move |_| {
+        let id = preview_id.clone();
+        reset_error.set(None);
+        spawn(async move {
+            match WorkflowService::preview_reset(&id).await {
+                Ok(preview) => reset_preview.set(Some(preview)),
+                Err(e) => reset_error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let reset_id = id.clone();
+    let mut selected_tab = use_signal(|| EDITOR_TAB.to_string());
+    let tests_workflow_id = id.clone();
+
     rsx! {
         div { class: "space-y-8",
             EditorHeader {
@@ -129,12 +169,53 @@ pub fn WorkflowEditPage(id: String) -> Element {
                 is_saving: *is_saving.read(),
                 has_unsaved_changes,
                 on_save: move |_| save_workflow(),
+                on_reset: can_reset.then(|| EventHandler::new(open_reset)),
+            }
+
+            if let Some(e) = reset_error() {
+                div { class: "p-4 bg-red-50 dark:bg-red-900/20 rounded-lg border border-red-200 dark:border-red-700",
+                    p { class: "text-sm text-red-700 dark:text-red-300", "{e}" }
+                }
+            }
+            if let Some(e) = reset_state.read().error.clone() {
+                div { class: "p-4 bg-red-50 dark:bg-red-900/20 rounded-lg border border-red-200 dark:border-red-700",
+                    p { class: "text-sm text-red-700 dark:text-red-300", "{e}" }
+                }
             }
 
+            ResetToDefaultDialog {
+                preview: reset_preview(),
+                has_unsaved_changes: has_unsaved_changes(),
+                is_resetting: reset_state.read().is_loading,
+                on_confirm: move |_| reset_fn(reset_id.clone()),
+                on_cancel: move |_| reset_preview.set(None),
+            }
 
-            VisualEditor {
-                workflow_json_str,
-                edited_workflow_name,
+            if !is_new {
+                Tabs {
+                    id_prefix: EDIT_TABS_ID.to_string(),
+                    tabs: vec![EDITOR_TAB.to_string(), TEST_TAB.to_string()],
+                    selected: selected_tab(),
+                    on_change: move |tab| selected_tab.set(tab),
+                    label: Some("Workflow editor".to_string()),
+                }
+            }
+            div {
+                id: tab_panel_id(EDIT_TABS_ID),
+                role: "tabpanel",
+                "aria-labelledby": tab_id(EDIT_TABS_ID, &selected_tab()),
+                if !is_new && selected_tab() == TEST_TAB {
+                    WorkflowTestsPanel {
+                        workflow_id: tests_workflow_id,
+                        has_unsaved_changes: has_unsaved_changes(),
+                    }
+                } else {
+                    VisualEditor {
+                        workflow_json_str,
+                        edited_workflow_name,
+                        workflow_id: (!is_new).then(|| id.clone()),
+                    }
+                }
             }
         }
     }
@@ -1,11 +1,11 @@
 use crate::components::{
     IconButton, IconButtonSize, IconButtonVariant, Notification, NotificationData, NotificationType,
 };
-use crate::pages::workflows::edit::JsonEditor;
+use crate::pages::workflows::edit::{JsonEditor, SnippetPalette};
 use crate::queries::{use_create_workflow_mutation, use_workflow_query};
 use dioxus::prelude::*;
 use dioxus_router::prelude::use_navigator;
-use s_e_e_core::WorkflowDefinition;
+use s_e_e_core::{validate_workflow_json, WorkflowDefinition};
 
 #[component]
 pub fn WorkflowJsonEditPage(id: String) -> Element {
@@ -36,9 +36,19 @@ pub fn WorkflowJsonEditPage(id: String) -> Element {
         None
     };
 
+    let is_default = loaded_workflow.as_ref().is_some_and(|w| w.is_default);
     let mut content = use_signal(String::new);
     let workflow_name = use_signal(String::new);
-    let validation_error = use_signal(String::new);
+    let diagnostics = use_memo(move || {
+        let content_str = content();
+        if content_str.is_empty() {
+            return Vec::new();
+        }
+        validate_workflow_json(&content_str)
+            .err()
+            .map(|errors| errors.errors)
+            .unwrap_or_default()
+    });
     let mut has_unsaved_changes = use_signal(|| false);
     let mut original_content = use_signal(String::new);
 
@@ -90,6 +100,17 @@ pub fn WorkflowJsonEditPage(id: String) -> Element {
             }
         };
 
+        let problems = diagnostics.read().len();
+        if problems > 0 {
+            notification.set(NotificationData {
+                r#type: NotificationType::Error,
+                title: "Validation Error".to_string(),
+                message: format!("Fix the {} problem(s) listed below the editor", problems),
+                show: true,
+            });
+            return;
+        }
+
         let workflow_name_str = json_value
             .get("name")
             .and_then(|v| v.as_str())
@@ -107,7 +128,7 @@ pub fn WorkflowJsonEditPage(id: String) -> Element {
             name: workflow_name_str,
             description: None,
             content: content_str.clone(),
-            is_default: false,
+            is_default,
             is_edited: !is_new,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
@@ -196,10 +217,15 @@ pub fn WorkflowJsonEditPage(id: String) -> Element {
             JsonEditor {
                 content,
                 workflow_name,
-                validation_error,
+                diagnostics,
                 on_content_change: move |value| content.set(value),
                 is_readonly: None,
             }
+
+            SnippetPalette {
+                content,
+                on_content_change: move |value| content.set(value),
+            }
         }
     }
 }
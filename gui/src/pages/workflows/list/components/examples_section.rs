@@ -0,0 +1,190 @@
+use crate::components::layout::{List, ListItem};
+use crate::components::{EmptyState, IconButton, IconButtonSize, IconButtonVariant, SectionCard};
+use crate::queries::{
+    use_add_example_mutation, use_example_workflows_query, use_refresh_examples_mutation,
+    use_sandbox_run_mutation,
+};
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+/// Example workflows shipped with the app. They are read-only here and only
+/// reach the library through "Add to my workflows".
+#[component]
+pub fn ExamplesSection(library_ids: Vec<String>) -> Element {
+    let (examples_state, _refetch) = use_example_workflows_query();
+    let (add_state, add_fn) = use_add_example_mutation();
+    let add_fn = Rc::new(add_fn);
+    let (refresh_state, refresh_fn) = use_refresh_examples_mutation();
+    let (sandbox_state, sandbox_fn) = use_sandbox_run_mutation();
+    let sandbox_fn = Rc::new(sandbox_fn);
+    let mut selected = use_signal(|| None::<String>);
+
+    let examples = examples_state.data.clone().unwrap_or_default();
+    let selected_example =
+        selected().and_then(|id| examples.iter().find(|example| example.id == id).cloned());
+    let is_adding = add_state.read().is_loading;
+    let add_error = add_state.read().error.clone();
+    let is_sandboxing = sandbox_state.read().is_loading;
+    let sandbox_error = sandbox_state.read().error.clone();
+    let sandbox_result = sandbox_state.read().data.clone();
+    let is_refreshing = refresh_state.read().is_loading;
+    let refresh_message = match (&refresh_state.read().data, &refresh_state.read().error) {
+        (_, Some(e)) => Some(e.clone()),
+        (Some(refresh), None) if refresh.skipped_edited.is_empty() => Some(format!(
+            "Updated {} example workflow(s).",
+            refresh.updated.len()
+        )),
+        (Some(refresh), None) => Some(format!(
+            "Updated {} example workflow(s). Skipped {} you have edited; use Reset to default on those.",
+            refresh.updated.len(),
+            refresh.skipped_edited.len()
+        )),
+        (None, None) => None,
+    };
+    let has_added_examples = examples
+        .iter()
+        .any(|example| library_ids.contains(&example.id));
+
+    rsx! {
+        SectionCard {
+            title: Some("Examples".to_string()),
+            children: rsx! {
+                if let Some(e) = examples_state.error.as_deref() {
+                    div { class: "text-red-600 dark:text-red-400", "Failed to load examples: {e}" }
+                } else if examples.is_empty() {
+                    EmptyState { message: "No examples available.".to_string() }
+                } else {
+                    div { class: "space-y-4",
+                        if has_added_examples {
+                            div { class: "flex items-center justify-between gap-4",
+                                p { class: "text-sm text-zinc-600 dark:text-zinc-400",
+                                    {refresh_message.unwrap_or_else(|| "Bring added examples up to date with this version of the app. Examples you have edited are left alone.".to_string())}
+                                }
+                                IconButton {
+                                    variant: IconButtonVariant::Secondary,
+                                    size: IconButtonSize::Medium,
+                                    disabled: Some(is_refreshing),
+                                    loading: Some(is_refreshing),
+                                    onclick: move |_| refresh_fn(()),
+                                    icon: if is_refreshing { None } else { Some("arrow_path".to_string()) },
+                                    icon_variant: "outline".to_string(),
+                                    "Refresh examples"
+                                }
+                            }
+                        }
+                        List {
+                            for example in examples.iter() {
+                                {
+                                    let example_id = example.id.clone();
+                                    let is_selected = selected().as_deref() == Some(example.id.as_str());
+                                    let in_library = library_ids.contains(&example.id);
+                                    rsx! {
+                                        ListItem {
+                                            icon_name: "workflows".to_string(),
+                                            icon_variant: Some("outline".to_string()),
+                                            title: rsx! {
+                                                {example.name.clone()}
+                                            },
+                                            subtitle: example.description.clone().map(|description| rsx! {
+                                                span { class: "text-sm text-zinc-500 dark:text-zinc-400", "{description}" }
+                                            }),
+                                            right_content: Some(rsx! {
+                                                if in_library {
+                                                    span { class: "inline-flex items-center rounded-md bg-green-50 dark:bg-green-900/20 px-2 py-1 text-xs font-medium text-green-700 dark:text-green-300 ring-1 ring-inset ring-green-600/10",
+                                                        "Added"
+                                                    }
+                                                } else if is_selected {
+                                                    span { class: "inline-flex items-center rounded-md bg-blue-50 dark:bg-blue-900/20 px-2 py-1 text-xs font-medium text-blue-700 dark:text-blue-300 ring-1 ring-inset ring-blue-700/10",
+                                                        "Previewing"
+                                                    }
+                                                }
+                                            }),
+                                            onclick: move |_| {
+                                                if is_selected {
+                                                    selected.set(None);
+                                                } else {
+                                                    selected.set(Some(example_id.clone()));
+                                                }
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        if let Some(example) = selected_example {
+                            {
+                                let in_library = library_ids.contains(&example.id);
+                                let preview = serde_json::from_str::<serde_json::Value>(&example.content)
+                                    .ok()
+                                    .and_then(|value| serde_json::to_string_pretty(&value).ok())
+                                    .unwrap_or_else(|| example.content.clone());
+                                let example_id = example.id.clone();
+                                let example_content = example.content.clone();
+                                let add_fn = add_fn.clone();
+                                let sandbox_fn = sandbox_fn.clone();
+                                let sandbox_result = sandbox_result
+                                    .clone()
+                                    .filter(|result| result.workflow_name == example.name);
+                                rsx! {
+                                    div { class: "space-y-3 rounded-lg border border-zinc-200 dark:border-zinc-700 p-4",
+                                        div { class: "flex items-center justify-between",
+                                            h3 { class: "text-sm font-semibold text-zinc-900 dark:text-white", "{example.name}" }
+                                            div { class: "flex items-center gap-2",
+                                                IconButton {
+                                                    variant: IconButtonVariant::Secondary,
+                                                    size: IconButtonSize::Medium,
+                                                    disabled: Some(is_sandboxing),
+                                                    loading: Some(is_sandboxing),
+                                                    onclick: move |_| sandbox_fn(example_content.clone()),
+                                                    icon: if is_sandboxing { None } else { Some("play".to_string()) },
+                                                    icon_variant: "outline".to_string(),
+                                                    if is_sandboxing { "Running..." } else { "Try in sandbox" }
+                                                }
+                                                IconButton {
+                                                    variant: IconButtonVariant::Primary,
+                                                    size: IconButtonSize::Medium,
+                                                    disabled: Some(in_library || is_adding),
+                                                    loading: Some(is_adding),
+                                                    onclick: move |_| add_fn(example_id.clone()),
+                                                    icon: if in_library || is_adding { None } else { Some("plus".to_string()) },
+                                                    icon_variant: "outline".to_string(),
+                                                    if in_library { "Added" } else if is_adding { "Adding..." } else { "Add to my workflows" }
+                                                }
+                                            }
+                                        }
+                                        if let Some(e) = add_error.as_deref() {
+                                            p { class: "text-sm text-red-700 dark:text-red-300", "{e}" }
+                                        }
+                                        if let Some(e) = sandbox_error.as_deref() {
+                                            p { class: "text-sm text-red-700 dark:text-red-300", "{e}" }
+                                        }
+                                        if let Some(result) = sandbox_result {
+                                            div { class: "space-y-2 rounded-md border border-amber-200 dark:border-amber-800 bg-amber-50 dark:bg-amber-900/20 p-3 text-sm",
+                                                p { class: "text-amber-800 dark:text-amber-200",
+                                                    "Sandbox run: commands were not run and nothing was saved."
+                                                }
+                                                for task in result.tasks.iter() {
+                                                    div { key: "{task.id}",
+                                                        p { class: "font-medium text-zinc-900 dark:text-white", "{task.name}" }
+                                                        for line in result.per_task_logs.get(&task.id).cloned().unwrap_or_default() {
+                                                            p { class: "font-mono text-xs text-zinc-600 dark:text-zinc-400", "{line}" }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        pre { class: "max-h-96 overflow-auto rounded-md bg-zinc-50 dark:bg-zinc-900 p-3 text-xs font-mono text-zinc-800 dark:text-zinc-200",
+                                            "{preview}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            padding: None,
+        }
+    }
+}
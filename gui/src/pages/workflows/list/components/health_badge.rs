@@ -0,0 +1,114 @@
+use crate::components::{Badge, BadgeColor, Timestamp};
+use crate::layout::hooks::use_ui_state;
+use crate::layout::router::Route;
+use dioxus::prelude::*;
+use dioxus_router::prelude::use_navigator;
+use s_e_e_core::{ExecutionFilter, WorkflowExecutionStatus, WorkflowHealth};
+
+fn status_color(status: &WorkflowExecutionStatus) -> BadgeColor {
+    match status {
+        WorkflowExecutionStatus::WaitingForInput => BadgeColor::Amber,
+        WorkflowExecutionStatus::Complete => BadgeColor::Emerald,
+        WorkflowExecutionStatus::Failed => BadgeColor::Red,
+        WorkflowExecutionStatus::Running => BadgeColor::Blue,
+        WorkflowExecutionStatus::Pending => BadgeColor::Zinc,
+        WorkflowExecutionStatus::Cancelled => BadgeColor::Zinc,
+    }
+}
+
+fn status_text(status: &WorkflowExecutionStatus) -> &'static str {
+    match status {
+        WorkflowExecutionStatus::WaitingForInput => "Waiting for Input",
+        WorkflowExecutionStatus::Complete => "Success",
+        WorkflowExecutionStatus::Failed => "Failed",
+        WorkflowExecutionStatus::Running => "Running",
+        WorkflowExecutionStatus::Pending => "Pending",
+        WorkflowExecutionStatus::Cancelled => "Cancelled",
+    }
+}
+
+fn bar_class(status: &WorkflowExecutionStatus) -> &'static str {
+    match status {
+        WorkflowExecutionStatus::Complete => "h-4 w-1 rounded-sm bg-emerald-500",
+        WorkflowExecutionStatus::Failed => "h-4 w-1 rounded-sm bg-red-500",
+        WorkflowExecutionStatus::Running => "h-3 w-1 rounded-sm bg-blue-500",
+        WorkflowExecutionStatus::WaitingForInput => "h-3 w-1 rounded-sm bg-amber-400",
+        WorkflowExecutionStatus::Pending => "h-2 w-1 rounded-sm bg-zinc-400",
+        WorkflowExecutionStatus::Cancelled => "h-2 w-1 rounded-sm bg-zinc-400",
+    }
+}
+
+/// The last run's result and time, with one bar per recent run. Clicking it
+/// opens the execution history filtered to the workflow.
+#[component]
+pub fn WorkflowHealthBadge(workflow_id: String, health: Option<WorkflowHealth>) -> Element {
+    let navigator = use_navigator();
+    let mut ui_state = use_ui_state();
+
+    let Some(health) = health else {
+        return rsx! {
+            Badge { color: BadgeColor::Zinc, "Never run" }
+        };
+    };
+    let Some(last_status) = health.last_status().cloned() else {
+        return rsx! {};
+    };
+    let summary = match health.success_rate() {
+        Some(rate) => format!(
+            "{}% of the last {} runs succeeded",
+            (rate * 100.0).round(),
+            health.recent.len()
+        ),
+        None => "No recent run has finished".to_string(),
+    };
+
+    let open_history = move || {
+        let filter = ExecutionFilter {
+            workflow_id: Some(workflow_id.clone()),
+            ..Default::default()
+        };
+        // The executions page reads its filter from the saved UI state.
+        if let Ok(value) = serde_json::to_value(filter) {
+            ui_state
+                .write()
+                .filters
+                .insert("executions_filter".to_string(), value);
+        }
+        navigator.push(Route::ExecutionListPage {});
+    };
+    let mut open_on_click = open_history.clone();
+    let mut open_on_key = open_history;
+
+    rsx! {
+        button {
+            r#type: "button",
+            title: "{summary}",
+            "aria-label": "Last run {status_text(&last_status)}. {summary}. Show execution history",
+            class: "flex items-center gap-3 rounded-md px-2 py-1 hover:bg-zinc-100 dark:hover:bg-zinc-800",
+            onclick: move |evt| {
+                evt.stop_propagation();
+                open_on_click();
+            },
+            onkeydown: move |evt: KeyboardEvent| {
+                // Enter and Space open the history, not the workflow behind it.
+                evt.stop_propagation();
+                if evt.key() == Key::Enter || evt.key() == Key::Character(" ".to_string()) {
+                    evt.prevent_default();
+                    open_on_key();
+                }
+            },
+            div { class: "flex items-end gap-0.5 h-4", "aria-hidden": "true",
+                for (index, status) in health.recent.iter().enumerate() {
+                    span { key: "{index}", class: bar_class(status) }
+                }
+            }
+            div { class: "flex flex-col items-end",
+                Badge { color: status_color(&last_status), {status_text(&last_status)} }
+                Timestamp {
+                    value: health.last_run_at,
+                    class: Some("text-xs text-zinc-500 dark:text-zinc-400".to_string()),
+                }
+            }
+        }
+    }
+}
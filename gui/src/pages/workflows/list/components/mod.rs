@@ -0,0 +1,5 @@
+pub mod examples_section;
+pub mod health_badge;
+
+pub use examples_section::ExamplesSection;
+pub use health_badge::WorkflowHealthBadge;
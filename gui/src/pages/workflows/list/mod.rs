@@ -1,3 +1,4 @@
+pub mod components;
 pub mod hooks;
 pub mod page;
 
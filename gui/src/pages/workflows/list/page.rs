@@ -1,16 +1,28 @@
 use crate::components::layout::{List, ListItem};
 use crate::components::{
-    EmptyState, IconButton, IconButtonSize, IconButtonVariant, PageHeader, SectionCard,
+    use_exporter, use_workflow_runner, Alert, AlertType, BulkDeleteBar, ContextMenu,
+    ContextMenuItem, EmptyState, ExportNotification, IconButton, IconButtonSize, IconButtonVariant,
+    MenuAnchor, PageHeader, SectionCard, WorkflowRunDialogs,
 };
 use crate::icons::Icon;
 use crate::layout::router::Route;
-use crate::services::workflow::read_and_parse_workflow_file;
+use crate::queries::{use_import_bundle_mutation, use_workflow_health_query};
+use crate::services::workflow::{is_bundle_file, read_and_parse_workflow_file};
+use crate::services::{ExportKind, WorkflowService};
 use dioxus::prelude::*;
 use dioxus_router::prelude::{use_navigator, Link};
 use rfd::FileDialog;
+use s_e_e_core::{TrashKind, WorkflowHealth};
+use s_e_e_dioxus_query::prelude::invalidate_queries_by_prefix;
+use std::collections::{BTreeSet, HashMap};
+use std::rc::Rc;
 
+use super::components::{ExamplesSection, WorkflowHealthBadge};
 use super::hooks::{use_upload_workflow, use_workflows_list};
 
+/// How many recent runs the health sparkline covers.
+const HEALTH_RUNS: usize = 10;
+
 #[component]
 pub fn WorkflowsListPage() -> Element {
     let workflows = match use_workflows_list() {
@@ -37,8 +49,96 @@ pub fn WorkflowsListPage() -> Element {
         }
     };
 
+    let (health_state, _refetch_health) = use_workflow_health_query(HEALTH_RUNS);
+    let health: HashMap<String, WorkflowHealth> = health_state
+        .data
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|health| (health.workflow_id.clone(), health))
+        .collect();
+
+    let library_ids: Vec<String> = workflows.iter().map(|w| w.id.clone()).collect();
+    let mut selected = use_signal(BTreeSet::<String>::new);
+    let mut confirming_delete = use_signal(|| false);
     let navigator = use_navigator();
+    let runner = use_workflow_runner();
+    let exporter = use_exporter();
+    let mut menu_anchor = use_signal(|| None::<MenuAnchor>);
+    let mut menu_target = use_signal(|| None::<String>);
+    let mut action_error = use_signal(|| None::<String>);
+
+    // Right-clicking a row that is part of a multi-selection acts on the
+    // whole selection; any other row gets its own actions.
+    let selection_count = selected.read().len();
+    let menu_on_selection =
+        menu_target().is_some_and(|id| selection_count > 1 && selected.read().contains(&id));
+    let menu_items = match menu_target() {
+        None => Vec::new(),
+        Some(_) if menu_on_selection => vec![
+            ContextMenuItem::new(format!("Export {} workflows", selection_count), move |_| {
+                exporter.export(
+                    ExportKind::Workflow,
+                    selected.peek().iter().cloned().collect(),
+                )
+            })
+            .icon("save"),
+            ContextMenuItem::new("Clear selection", move |_| selected.write().clear()).icon("x"),
+            ContextMenuItem::new(format!("Delete {} workflows", selection_count), move |_| {
+                confirming_delete.set(true)
+            })
+            .icon("trash")
+            .danger(),
+        ],
+        Some(id) => {
+            let name = workflows
+                .iter()
+                .find(|workflow| workflow.id == id)
+                .map(|workflow| workflow.get_name().to_string())
+                .unwrap_or_else(|| id.clone());
+            vec![
+                ContextMenuItem::new("Run", {
+                    let id = id.clone();
+                    move |_| runner.run(id.clone(), name.clone())
+                })
+                .icon("play"),
+                ContextMenuItem::new("Edit", {
+                    let id = id.clone();
+                    move |_| {
+                        navigator.push(Route::WorkflowEditPage { id: id.clone() });
+                    }
+                })
+                .icon("code_bracket"),
+                ContextMenuItem::new("Duplicate", {
+                    let id = id.clone();
+                    move |_| {
+                        let id = id.clone();
+                        spawn(async move {
+                            match WorkflowService::duplicate_workflow(&id).await {
+                                Ok(_) => invalidate_queries_by_prefix("workflows:"),
+                                Err(e) => action_error.set(Some(e.to_string())),
+                            }
+                        });
+                    }
+                })
+                .icon("copy"),
+                ContextMenuItem::new("Export", {
+                    let id = id.clone();
+                    move |_| exporter.export(ExportKind::Workflow, vec![id.clone()])
+                })
+                .icon("save"),
+                ContextMenuItem::new("Delete", move |_| {
+                    selected.set(BTreeSet::from([id.clone()]));
+                    confirming_delete.set(true);
+                })
+                .icon("trash")
+                .danger(),
+            ]
+        }
+    };
     let upload_state = use_upload_workflow();
+    let (import_state, import_fn) = use_import_bundle_mutation();
+    let import_fn = Rc::new(import_fn);
 
     let mut workflow_file = use_signal(String::new);
     let mut is_picking_file = use_signal(|| false);
@@ -71,8 +171,15 @@ pub fn WorkflowsListPage() -> Element {
         let mut workflow_file_clone = workflow_file;
         let mut error_message_clone = error_message;
         let upload_fn = upload_state.upload_fn.clone();
+        let import_fn = import_fn.clone();
 
         spawn(async move {
+            if is_bundle_file(&workflow_file_clone()) {
+                import_fn(workflow_file_clone());
+                workflow_file_clone.set(String::new());
+                return;
+            }
+
             match read_and_parse_workflow_file(workflow_file_clone().clone()) {
                 Ok(workflow) => {
                     let json_str = match serde_json::to_string(&workflow) {
@@ -146,6 +253,12 @@ pub fn WorkflowsListPage() -> Element {
                             }
                         }
 
+                        if let Some(error) = import_state.read().error.clone() {
+                            div { class: "p-4 bg-red-50 dark:bg-red-900/20 rounded-lg border border-red-200 dark:border-red-700",
+                                p { class: "text-sm text-red-700 dark:text-red-300", "{error}" }
+                            }
+                        }
+
                         if !error_message().is_empty() {
                             div { class: "p-4 bg-red-50 dark:bg-red-900/20 rounded-lg border border-red-200 dark:border-red-700",
                                 p { class: "text-sm text-red-700 dark:text-red-300", "{error_message()}" }
@@ -153,7 +266,8 @@ pub fn WorkflowsListPage() -> Element {
                         }
 
                         {{
-                            let is_saving = upload_state.state.read().is_loading;
+                            let is_saving = upload_state.state.read().is_loading
+                                || import_state.read().is_loading;
                             rsx! {
                                 IconButton {
                                     variant: IconButtonVariant::Primary,
@@ -174,12 +288,48 @@ pub fn WorkflowsListPage() -> Element {
             }
 
 
+            WorkflowRunDialogs { runner }
+            ExportNotification { exporter }
+            ContextMenu {
+                anchor: menu_anchor,
+                items: menu_items,
+                title: menu_on_selection.then(|| format!("{} workflows selected", selection_count)),
+            }
+
+            if let Some(error) = runner.state.read().error.clone() {
+                Alert {
+                    alert_type: AlertType::Error,
+                    title: Some("Workflow run failed".to_string()),
+                    message: error,
+                    dismissible: None,
+                    on_dismiss: None,
+                    actions: None,
+                }
+            }
+            if let Some(error) = action_error() {
+                Alert {
+                    alert_type: AlertType::Error,
+                    title: None,
+                    message: error,
+                    dismissible: Some(true),
+                    on_dismiss: move |_| action_error.set(None),
+                    actions: None,
+                }
+            }
+
+            // Kept outside the list so the Undo toast survives deleting every workflow.
+            BulkDeleteBar {
+                kind: TrashKind::Workflow,
+                selected,
+                all_ids: library_ids.clone(),
+                confirming: confirming_delete,
+            }
             if workflows.is_empty() {
                 SectionCard {
                     title: Some("Workflows".to_string()),
                     children: rsx! {
                         EmptyState {
-                            message: "No workflows yet. Create your first workflow to get started.".to_string(),
+                            message: "No workflows yet. Create one, upload a file, or add an example below.".to_string(),
                         }
                     },
                     padding: None,
@@ -191,8 +341,17 @@ pub fn WorkflowsListPage() -> Element {
                         List {
                             for workflow in workflows.iter() {
                                 {let workflow_id = workflow.id.clone();
+                                let select_id = workflow.id.clone();
+                                let menu_id = workflow.id.clone();
                                 rsx! {
                                     ListItem {
+                                        selected: Some(selected.read().contains(&workflow.id)),
+                                        on_select: move |_| {
+                                            let mut selected = selected.write();
+                                            if !selected.remove(&select_id) {
+                                                selected.insert(select_id.clone());
+                                            }
+                                        },
                                         icon_name: "workflows".to_string(),
                                         icon_variant: Some("outline".to_string()),
                                         title: rsx! {
@@ -210,19 +369,29 @@ pub fn WorkflowsListPage() -> Element {
                                             }
                                         }),
                                         right_content: Some(rsx! {
-                                            if workflow.is_default && workflow.is_edited {
-                                                span { class: "inline-flex items-center rounded-md bg-yellow-50 dark:bg-yellow-900/20 px-2 py-1 text-xs font-medium text-yellow-700 dark:text-yellow-300 ring-1 ring-inset ring-yellow-600/10",
-                                                    "Modified"
+                                            div { class: "flex items-center gap-3",
+                                                WorkflowHealthBadge {
+                                                    workflow_id: workflow.id.clone(),
+                                                    health: health.get(&workflow.id).cloned(),
                                                 }
-                                            } else {
-                                                span { class: "inline-flex items-center rounded-md bg-green-50 dark:bg-green-900/20 px-2 py-1 text-xs font-medium text-green-700 dark:text-green-300 ring-1 ring-inset ring-green-600/10",
-                                                    "Active"
+                                                if workflow.is_default && workflow.is_edited {
+                                                    span { class: "inline-flex items-center rounded-md bg-yellow-50 dark:bg-yellow-900/20 px-2 py-1 text-xs font-medium text-yellow-700 dark:text-yellow-300 ring-1 ring-inset ring-yellow-600/10",
+                                                        "Modified"
+                                                    }
+                                                } else {
+                                                    span { class: "inline-flex items-center rounded-md bg-green-50 dark:bg-green-900/20 px-2 py-1 text-xs font-medium text-green-700 dark:text-green-300 ring-1 ring-inset ring-green-600/10",
+                                                        "Active"
+                                                    }
                                                 }
                                             }
                                         }),
                                         onclick: move |_| {
                                             navigator.push(Route::WorkflowEditPage { id: workflow_id.clone() });
                                         },
+                                        on_context_menu: move |anchor| {
+                                            menu_target.set(Some(menu_id.clone()));
+                                            menu_anchor.set(Some(anchor));
+                                        },
                                     }
                                 }}
                             }
@@ -231,6 +400,9 @@ pub fn WorkflowsListPage() -> Element {
                     padding: None,
                 }
             }
+
+
+            ExamplesSection { library_ids }
         }
     }
 }
@@ -1,8 +1,22 @@
-use crate::services::execution::ExecutionService;
-use dioxus::prelude::Signal;
-use s_e_e_core::{TaskExecution, WorkflowExecution, WorkflowExecutionSummary, WorkflowMetadata};
+use super::refresh_groups;
+use crate::services::execution::{ExecutionPage, ExecutionService, LiveRun};
+use crate::services::{ExecutionOutput, LiveOutputService};
+use chrono::NaiveDate;
+use dioxus::prelude::{use_future, Signal};
+use s_e_e_core::{
+    ArchivedExecution, DailyExecutionStats, ExecutionComparison, ExecutionFilter,
+    ExecutionFilterPreset, FlakyTask, SnapshotStatus, TaskContext, TaskExecution,
+    WorkflowExecution, WorkflowExecutionStatus, WorkflowExecutionSummary, WorkflowHealth,
+    WorkflowMetadata,
+};
 use s_e_e_dioxus_query::prelude::*;
 use std::rc::Rc;
+use std::time::Duration;
+
+/// Running executions shown as live cards.
+const MAX_LIVE_RUNS: usize = 10;
+/// Shortest time between refreshes of the live cards while output streams in.
+const LIVE_RUN_THROTTLE: Duration = Duration::from_millis(250);
 
 pub fn use_workflow_executions_query() -> (QueryState<Vec<WorkflowExecutionSummary>>, impl Fn()) {
     let key = QueryKey::new(&["executions", "list"]);
@@ -17,6 +31,192 @@ pub fn use_workflow_executions_query() -> (QueryState<Vec<WorkflowExecutionSumma
         stale_time: Some(0),
         cache_time: Some(60_000),
         refetch_interval: Some(1000),
+        refresh_group: Some(refresh_groups::EXECUTIONS),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+/// Executions matching `filter`, newest first, up to however many `limit` currently allows.
+///
+/// The key only depends on `filter`: raise `limit` and call the returned refetch
+/// to load more rows into the same entry.
+pub fn use_execution_page_query(
+    filter: ExecutionFilter,
+    limit: Signal<usize>,
+) -> (QueryState<ExecutionPage>, impl Fn()) {
+    let status_key = filter
+        .status
+        .as_ref()
+        .map(WorkflowExecutionStatus::as_str)
+        .unwrap_or("all");
+    let label_key = filter.label.clone().unwrap_or_default();
+    let workflow_key = filter.workflow_id.clone().unwrap_or_default();
+    let key = QueryKey::new(&["executions", "page", status_key, &label_key, &workflow_key]);
+
+    let fetcher = move || {
+        let filter = filter.clone();
+        async move {
+            ExecutionService::fetch_execution_page(filter, limit())
+                .await
+                .map_err(|e| e.to_string())
+        }
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(0),
+        cache_time: Some(60_000),
+        refetch_interval: Some(1000),
+        refresh_group: Some(refresh_groups::EXECUTIONS),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+pub fn use_execution_labels_query() -> (QueryState<Vec<String>>, impl Fn()) {
+    let key = QueryKey::new(&["executions", "labels"]);
+
+    let fetcher = move || async move {
+        ExecutionService::fetch_execution_labels()
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(5_000),
+        cache_time: Some(60_000),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+/// Replace an execution's labels: `(execution id, labels)`.
+pub fn use_set_execution_labels_mutation() -> (
+    Signal<MutationState<Vec<String>>>,
+    impl Fn((String, Vec<String>)),
+) {
+    let mutation_fn = move |(execution_id, labels): (String, Vec<String>)| async move {
+        ExecutionService::set_execution_labels(&execution_id, labels)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: Some(Rc::new(|| {
+            invalidate_queries_by_prefix("executions:");
+        })),
+        invalidate_keys: vec![QueryKey::new(&["executions", "labels"])],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
+pub fn use_filter_presets_query() -> (QueryState<Vec<ExecutionFilterPreset>>, impl Fn()) {
+    let key = QueryKey::new(&["filter_presets", "list"]);
+
+    let fetcher = move || async move {
+        ExecutionService::fetch_filter_presets()
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(30_000),
+        cache_time: Some(300_000),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+/// Save a filter under a name: `(name, filter)`.
+pub fn use_save_filter_preset_mutation() -> (
+    Signal<MutationState<ExecutionFilterPreset>>,
+    impl Fn((String, ExecutionFilter)),
+) {
+    let mutation_fn = move |(name, filter): (String, ExecutionFilter)| async move {
+        ExecutionService::save_filter_preset(&name, filter)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: None,
+        invalidate_keys: vec![QueryKey::new(&["filter_presets", "list"])],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
+pub fn use_delete_filter_preset_mutation() -> (Signal<MutationState<()>>, impl Fn(String)) {
+    let mutation_fn = move |name: String| async move {
+        ExecutionService::delete_filter_preset(&name)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: None,
+        invalidate_keys: vec![QueryKey::new(&["filter_presets", "list"])],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
+pub fn use_execution_calendar_query(
+    since: NaiveDate,
+) -> (QueryState<Vec<DailyExecutionStats>>, impl Fn()) {
+    let since_key = since.to_string();
+    let key = QueryKey::new(&["executions", "calendar", &since_key]);
+
+    let fetcher = move || async move {
+        ExecutionService::fetch_daily_stats(since)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(30_000),
+        cache_time: Some(300_000),
+        refetch_interval: Some(30_000),
+        refresh_group: Some(refresh_groups::CALENDAR),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+pub fn use_executions_on_day_query(
+    date: String,
+) -> (QueryState<Vec<WorkflowExecutionSummary>>, impl Fn()) {
+    let key = QueryKey::new(&["executions", "day", &date]);
+
+    let fetcher = move || {
+        let date = date.clone();
+        async move {
+            ExecutionService::fetch_executions_on(&date)
+                .await
+                .map_err(|e| e.to_string())
+        }
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(0),
+        cache_time: Some(60_000),
+        refetch_interval: Some(2000),
+        refresh_group: Some(refresh_groups::EXECUTIONS),
         ..Default::default()
     };
 
@@ -36,12 +236,69 @@ pub fn use_running_workflows_query() -> (QueryState<Vec<WorkflowMetadata>>, impl
         stale_time: Some(0),
         cache_time: Some(60_000),
         refetch_interval: Some(1000),
+        refresh_group: Some(refresh_groups::EXECUTIONS),
         ..Default::default()
     };
 
     use_query(key, fetcher, options)
 }
 
+/// Executions still running, refreshed as soon as a run started from this
+/// window writes output and polled for the rest.
+pub fn use_live_runs_query() -> (QueryState<Vec<LiveRun>>, impl Fn()) {
+    let key = QueryKey::new(&["executions", "live_runs"]);
+
+    let updated_key = key.clone();
+    use_future(move || {
+        let key = updated_key.clone();
+        async move {
+            let mut updates = LiveOutputService::subscribe();
+            while updates.changed().await.is_ok() {
+                invalidate_query(&key);
+                tokio::time::sleep(LIVE_RUN_THROTTLE).await;
+            }
+        }
+    });
+
+    let fetcher = move || async move {
+        ExecutionService::fetch_live_runs(MAX_LIVE_RUNS)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(0),
+        cache_time: Some(60_000),
+        refetch_interval: Some(1000),
+        refresh_group: Some(refresh_groups::EXECUTIONS),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+/// Stop a run started from this window. Resolves to false if it was not
+/// running here.
+pub fn use_cancel_run_mutation() -> (Signal<MutationState<bool>>, impl Fn(String)) {
+    let mutation_fn = move |execution_id: String| async move {
+        ExecutionService::cancel_run(&execution_id)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: Some(Rc::new(|| {
+            invalidate_queries_by_prefix("executions:");
+        })),
+        invalidate_keys: vec![],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
 pub fn use_workflow_execution_query(
     execution_id: String,
 ) -> (QueryState<WorkflowExecution>, impl Fn()) {
@@ -61,6 +318,44 @@ pub fn use_workflow_execution_query(
         stale_time: Some(5_000),
         cache_time: Some(300_000),
         refetch_interval: Some(2000),
+        refresh_group: Some(refresh_groups::EXECUTION_DETAILS),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+/// Output streamed by an execution started from this window, grouped by task.
+/// Without any, the output journaled for a run that stopped before saving its
+/// task logs.
+pub fn use_live_output_query(execution_id: String) -> (QueryState<ExecutionOutput>, impl Fn()) {
+    let key = QueryKey::new(&["executions", "live_output", &execution_id]);
+
+    let fetcher = move || {
+        let execution_id = execution_id.clone();
+        async move {
+            let events = LiveOutputService::events_for(&execution_id);
+            if !events.is_empty() {
+                return Ok(ExecutionOutput {
+                    groups: LiveOutputService::group_by_task(&events),
+                    from_journal: false,
+                });
+            }
+            let journaled = ExecutionService::fetch_journaled_output(&execution_id)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok::<_, String>(ExecutionOutput {
+                groups: LiveOutputService::group_by_task(&journaled),
+                from_journal: !journaled.is_empty(),
+            })
+        }
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(0),
+        cache_time: Some(60_000),
+        refetch_interval: Some(1000),
+        refresh_group: Some(refresh_groups::EXECUTION_DETAILS),
         ..Default::default()
     };
 
@@ -113,3 +408,182 @@ pub fn use_delete_execution_mutation() -> (Signal<MutationState<()>>, impl Fn(St
 
     use_mutation(mutation_fn, callbacks)
 }
+
+/// The context as it was when `task_id` started. A finished task's context never
+/// changes, so it is cached for as long as the entry lives.
+pub fn use_context_at_query(
+    execution_id: String,
+    task_id: String,
+) -> (QueryState<TaskContext>, impl Fn()) {
+    let key = QueryKey::new(&["executions", "context_at", &execution_id, &task_id]);
+
+    let fetcher = move || {
+        let execution_id = execution_id.clone();
+        let task_id = task_id.clone();
+        async move {
+            ExecutionService::fetch_context_at(&execution_id, &task_id)
+                .await
+                .map_err(|e| e.to_string())
+        }
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(300_000),
+        cache_time: Some(300_000),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+/// Whether the workflow an execution ran has been edited or deleted since.
+pub fn use_snapshot_status_query(execution_id: String) -> (QueryState<SnapshotStatus>, impl Fn()) {
+    let key = QueryKey::new(&["executions", "snapshot_status", &execution_id]);
+
+    let fetcher = move || {
+        let id = execution_id.clone();
+        async move {
+            ExecutionService::fetch_snapshot_status(&id)
+                .await
+                .map_err(|e| e.to_string())
+        }
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(30_000),
+        cache_time: Some(300_000),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+/// The tasks whose outcome flips most often between runs, most flaky first.
+pub fn use_flaky_tasks_query(limit: usize) -> (QueryState<Vec<FlakyTask>>, impl Fn()) {
+    let key = QueryKey::new(&["executions", "flaky_tasks", &limit.to_string()]);
+
+    let fetcher = move || async move {
+        ExecutionService::fetch_flaky_tasks(limit)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(60_000),
+        cache_time: Some(300_000),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+/// How the latest `runs` executions of each workflow definition went.
+pub fn use_workflow_health_query(runs: usize) -> (QueryState<Vec<WorkflowHealth>>, impl Fn()) {
+    let key = QueryKey::new(&["executions", "workflow_health", &runs.to_string()]);
+
+    let fetcher = move || async move {
+        ExecutionService::fetch_workflow_health(runs)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(30_000),
+        cache_time: Some(300_000),
+        refetch_interval: Some(30_000),
+        refresh_group: Some(refresh_groups::EXECUTIONS),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+/// Flaky tasks of the workflow definition an execution ran.
+pub fn use_execution_flaky_tasks_query(
+    execution_id: String,
+) -> (QueryState<Vec<FlakyTask>>, impl Fn()) {
+    let key = QueryKey::new(&["executions", "flaky_tasks", "execution", &execution_id]);
+
+    let fetcher = move || {
+        let id = execution_id.clone();
+        async move {
+            ExecutionService::fetch_execution_flaky_tasks(&id)
+                .await
+                .map_err(|e| e.to_string())
+        }
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(60_000),
+        cache_time: Some(300_000),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+/// An execution compared with the previous run of the same workflow; `None`
+/// when there is no earlier run.
+pub fn use_previous_run_comparison_query(
+    execution_id: String,
+) -> (QueryState<Option<ExecutionComparison>>, impl Fn()) {
+    let key = QueryKey::new(&["executions", "previous_comparison", &execution_id]);
+
+    let fetcher = move || {
+        let id = execution_id.clone();
+        async move {
+            ExecutionService::compare_with_previous_run(&id)
+                .await
+                .map_err(|e| e.to_string())
+        }
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(30_000),
+        cache_time: Some(300_000),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+pub fn use_archived_executions_query() -> (QueryState<Vec<ArchivedExecution>>, impl Fn()) {
+    let key = QueryKey::new(&["executions", "archived"]);
+
+    let fetcher = move || async move {
+        ExecutionService::fetch_archived_executions()
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(30_000),
+        cache_time: Some(300_000),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+/// Load an archived execution back into the database. Every execution query is
+/// refetched afterwards, so the run moves from the archive list to the history.
+pub fn use_restore_archived_mutation() -> (Signal<MutationState<WorkflowExecution>>, impl Fn(String))
+{
+    let mutation_fn = move |execution_id: String| async move {
+        ExecutionService::restore_archived_execution(&execution_id)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: Some(Rc::new(|| {
+            invalidate_queries_by_prefix("executions:");
+        })),
+        invalidate_keys: vec![QueryKey::new(&["executions", "archived"])],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
@@ -1,10 +1,16 @@
 pub mod execution_queries;
+pub mod notification_queries;
 pub mod prompt_queries;
+pub mod refresh_groups;
 pub mod settings_queries;
+pub mod storage_queries;
 pub mod workflow_queries;
 
 pub use execution_queries::*;
+pub use notification_queries::*;
 #[allow(unused_imports)]
 pub use prompt_queries::*;
+pub use refresh_groups::refresh_policy_from;
 pub use settings_queries::*;
+pub use storage_queries::*;
 pub use workflow_queries::*;
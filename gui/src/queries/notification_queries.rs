@@ -0,0 +1,50 @@
+use super::refresh_groups;
+use crate::services::NotificationService;
+use dioxus::prelude::Signal;
+use s_e_e_core::Notification;
+use s_e_e_dioxus_query::prelude::*;
+use std::rc::Rc;
+
+/// Newest notifications first. Polls with the executions group, since runs
+/// finishing is what creates them.
+pub fn use_notifications_query() -> (QueryState<Vec<Notification>>, impl Fn()) {
+    let key = QueryKey::new(&["notifications", "list"]);
+
+    let fetcher = move || async move {
+        NotificationService::fetch_notifications()
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(0),
+        cache_time: Some(60_000),
+        refetch_interval: Some(2000),
+        refresh_group: Some(refresh_groups::EXECUTIONS),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+/// Marks one notification read, or all of them when called with `None`.
+pub fn use_mark_notifications_read_mutation() -> (Signal<MutationState<()>>, impl Fn(Option<String>))
+{
+    let mutation_fn = move |id: Option<String>| async move {
+        NotificationService::mark_read(id.as_deref())
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: Some(Rc::new(|| {
+            invalidate_queries_by_prefix("notifications:");
+        })),
+        invalidate_keys: vec![QueryKey::new(&["notifications", "list"])],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
@@ -1,6 +1,6 @@
 use crate::services::prompt::UserPromptService;
 use dioxus::prelude::Signal;
-use s_e_e_core::Prompt;
+use s_e_e_core::{Prompt, PromptFilter, PromptWithUsage};
 use s_e_e_dioxus_query::prelude::*;
 use std::rc::Rc;
 
@@ -22,6 +22,51 @@ pub fn use_prompts_query() -> (QueryState<Vec<Prompt>>, impl Fn()) {
     use_query(key, fetcher, options)
 }
 
+/// Prompts matching `filter`. The key covers the whole filter, so render the
+/// consumer with a `key` per filter to pick up a new one.
+pub fn use_prompts_filtered_query(
+    filter: PromptFilter,
+) -> (QueryState<Vec<PromptWithUsage>>, impl Fn()) {
+    let search = filter.search.clone().unwrap_or_default();
+    let tags = filter.tags.join(",");
+    let key = QueryKey::new(&["prompts", "filtered", filter.sort.as_str(), &tags, &search]);
+
+    let fetcher = move || {
+        let filter = filter.clone();
+        async move {
+            UserPromptService::fetch_prompts_filtered(&filter)
+                .await
+                .map_err(|e| e.to_string())
+        }
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(30_000),
+        cache_time: Some(300_000),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+pub fn use_prompt_tags_query() -> (QueryState<Vec<String>>, impl Fn()) {
+    let key = QueryKey::new(&["prompts", "tags"]);
+
+    let fetcher = move || async move {
+        UserPromptService::fetch_prompt_tags()
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(30_000),
+        cache_time: Some(300_000),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
 pub fn use_prompt_query(id: String) -> (QueryState<Option<Prompt>>, impl Fn()) {
     let key = QueryKey::new(&["prompts", "detail", &id]);
 
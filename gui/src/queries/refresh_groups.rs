@@ -0,0 +1,18 @@
+//! Refresh groups let the settings page tune polling per kind of query.
+
+use s_e_e_core::PollingSettings;
+use s_e_e_dioxus_query::prelude::RefreshPolicy;
+
+pub const EXECUTIONS: &str = "executions";
+pub const EXECUTION_DETAILS: &str = "execution_details";
+pub const CALENDAR: &str = "calendar";
+
+pub fn refresh_policy_from(polling: &PollingSettings) -> RefreshPolicy {
+    RefreshPolicy {
+        pause_when_unfocused: polling.pause_when_unfocused,
+        ..Default::default()
+    }
+    .with_interval(EXECUTIONS, polling.executions_ms)
+    .with_interval(EXECUTION_DETAILS, polling.execution_details_ms)
+    .with_interval(CALENDAR, polling.calendar_ms)
+}
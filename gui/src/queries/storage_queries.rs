@@ -0,0 +1,41 @@
+use crate::services::StorageService;
+use dioxus::prelude::Signal;
+use s_e_e_core::MaintenanceReport;
+use s_e_e_dioxus_query::prelude::*;
+
+pub fn use_last_maintenance_query() -> (QueryState<Option<MaintenanceReport>>, impl Fn()) {
+    let key = QueryKey::new(&["storage", "maintenance"]);
+
+    let fetcher = move || async move {
+        StorageService::fetch_last_maintenance()
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(60_000),
+        cache_time: Some(300_000),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+pub fn use_storage_maintenance_mutation() -> (Signal<MutationState<MaintenanceReport>>, impl Fn(()))
+{
+    let mutation_fn = move |_: ()| async move {
+        StorageService::run_maintenance()
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: None,
+        invalidate_keys: vec![QueryKey::new(&["storage", "maintenance"])],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
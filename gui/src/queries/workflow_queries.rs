@@ -1,7 +1,14 @@
 use crate::services::workflow::WorkflowService;
+use crate::services::LiveOutputService;
 use dioxus::prelude::Signal;
-use s_e_e_core::{WorkflowDefinition, WorkflowResult};
+use s_e_e_core::{
+    CoreError, ErrorCategory, ExampleRefresh, ExampleWorkflow, RunBounds, TaskSnippet,
+    WorkflowDefinition, WorkflowResult, WorkflowTest, WorkflowTestReport,
+};
 use s_e_e_dioxus_query::prelude::*;
+use s_e_e_engine::EngineError;
+use serde_json::Value;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 pub fn use_workflows_query() -> (QueryState<Vec<WorkflowDefinition>>, impl Fn()) {
@@ -22,6 +29,24 @@ pub fn use_workflows_query() -> (QueryState<Vec<WorkflowDefinition>>, impl Fn())
     use_query(key, fetcher, options)
 }
 
+pub fn use_snippets_query() -> (QueryState<Vec<TaskSnippet>>, impl Fn()) {
+    let key = QueryKey::new(&["snippets", "list"]);
+
+    let fetcher = move || async move {
+        WorkflowService::fetch_snippets()
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(30_000),
+        cache_time: Some(300_000),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
 pub fn use_workflow_query(id: String) -> (QueryState<Option<WorkflowDefinition>>, impl Fn()) {
     let key = QueryKey::new(&["workflows", "detail", &id]);
 
@@ -67,21 +92,204 @@ pub fn use_create_workflow_mutation() -> (Signal<MutationState<()>>, impl Fn(Str
     use_mutation(mutation_fn, callbacks)
 }
 
+pub fn use_example_workflows_query() -> (QueryState<Vec<ExampleWorkflow>>, impl Fn()) {
+    let key = QueryKey::new(&["examples", "list"]);
+
+    // Examples are compiled into the binary and only change with an upgrade.
+    let fetcher =
+        move || async move { WorkflowService::fetch_examples().map_err(|e| e.to_string()) };
+
+    let options = QueryOptions {
+        stale_time: Some(300_000),
+        cache_time: Some(300_000),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+pub fn use_add_example_mutation() -> (Signal<MutationState<WorkflowDefinition>>, impl Fn(String)) {
+    let mutation_fn = move |id: String| async move {
+        WorkflowService::add_example(&id)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: Some(Rc::new(|| {
+            invalidate_queries_by_prefix("workflows:");
+        })),
+        invalidate_keys: vec![QueryKey::new(&["workflows", "list"])],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
+/// Imports a bundle file by path.
+pub fn use_import_bundle_mutation() -> (Signal<MutationState<WorkflowDefinition>>, impl Fn(String))
+{
+    let mutation_fn = move |path: String| async move {
+        WorkflowService::import_bundle_file(&path)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: Some(Rc::new(|| {
+            invalidate_queries_by_prefix("workflows:");
+        })),
+        invalidate_keys: vec![QueryKey::new(&["workflows", "list"])],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
+/// Runs workflow JSON in the sandbox. Nothing is stored, so nothing is invalidated.
+pub fn use_sandbox_run_mutation() -> (Signal<MutationState<WorkflowResult>>, impl Fn(String)) {
+    let mutation_fn = move |content: String| async move {
+        WorkflowService::run_sandboxed(&content)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: None,
+        invalidate_keys: vec![],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
+/// Records the first-run choice; `true` also adds every example to the library.
+pub fn use_complete_onboarding_mutation() -> (Signal<MutationState<usize>>, impl Fn(bool)) {
+    let mutation_fn = move |add_examples: bool| async move {
+        WorkflowService::complete_onboarding(add_examples)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: Some(Rc::new(|| {
+            invalidate_queries_by_prefix("workflows:");
+            invalidate_query(&QueryKey::new(&["settings"]));
+        })),
+        invalidate_keys: vec![
+            QueryKey::new(&["workflows", "list"]),
+            QueryKey::new(&["settings"]),
+        ],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
+pub fn use_reset_workflow_mutation() -> (Signal<MutationState<WorkflowDefinition>>, impl Fn(String))
+{
+    let mutation_fn = move |id: String| async move {
+        WorkflowService::reset_to_default(&id)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: Some(Rc::new(|| {
+            invalidate_queries_by_prefix("workflows:");
+        })),
+        invalidate_keys: vec![QueryKey::new(&["workflows", "list"])],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
+pub fn use_refresh_examples_mutation() -> (Signal<MutationState<ExampleRefresh>>, impl Fn(())) {
+    let mutation_fn = move |_: ()| async move {
+        WorkflowService::refresh_examples()
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: Some(Rc::new(|| {
+            invalidate_queries_by_prefix("workflows:");
+        })),
+        invalidate_keys: vec![QueryKey::new(&["workflows", "list"])],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
 pub type ExecuteWorkflowMutationResult = (
     Signal<MutationState<WorkflowResult>>,
-    std::rc::Rc<dyn Fn(String)>,
+    std::rc::Rc<dyn Fn((String, HashMap<String, Value>))>,
 );
 
+/// Banner text for a run that ended in an error, worded by what went wrong.
+fn execution_failure_message(error: &CoreError) -> String {
+    match error.category() {
+        ErrorCategory::Validation => format!("The workflow is invalid: {}", error),
+        ErrorCategory::Cancelled => "The run was cancelled.".to_string(),
+        ErrorCategory::Timeout => format!("The run timed out: {}", error),
+        ErrorCategory::HandlerFailure {
+            exit_code: Some(code),
+        } => format!("A task exited with code {}: {}", code, error),
+        ErrorCategory::NotFound => error.to_string(),
+        ErrorCategory::Untrusted => {
+            "This imported workflow has to be reviewed and trusted before it runs.".to_string()
+        }
+        ErrorCategory::Storage => format!("Could not read or write the database: {}", error),
+        ErrorCategory::Preflight => match error {
+            CoreError::Engine(EngineError::Preflight(report)) => {
+                format!("No task was started because {}", report)
+            }
+            _ => error.to_string(),
+        },
+        ErrorCategory::Policy => match error {
+            CoreError::Engine(EngineError::Policy(violation)) => format!(
+                "No task was started because {}. Change the handler profile in Settings to run it.",
+                violation
+            ),
+            _ => error.to_string(),
+        },
+        ErrorCategory::HandlerFailure { exit_code: None }
+        | ErrorCategory::Io
+        | ErrorCategory::Input
+        | ErrorCategory::Internal => format!("Workflow execution failed: {}", error),
+    }
+}
+
 pub fn use_execute_workflow_mutation() -> ExecuteWorkflowMutationResult {
-    let mutation_fn = move |workflow_id: String| async move {
+    let mutation_fn = move |(workflow_id, params): (String, HashMap<String, Value>)| async move {
         tracing::debug!(
             "[ExecuteWorkflowMutation] Starting workflow execution for ID: {}",
             workflow_id
         );
 
-        use s_e_e_core::execute_workflow_by_id;
+        use s_e_e_core::execute_workflow_by_id_with_params;
 
-        match execute_workflow_by_id(&workflow_id, None).await {
+        match execute_workflow_by_id_with_params(
+            &workflow_id,
+            params,
+            Some(LiveOutputService::callback()),
+        )
+        .await
+        {
             Ok(result) => {
                 tracing::info!(
                     "[ExecuteWorkflowMutation] Workflow executed successfully: {}",
@@ -91,10 +299,11 @@ pub fn use_execute_workflow_mutation() -> ExecuteWorkflowMutationResult {
             }
             Err(e) => {
                 tracing::error!(
+                    category = %e.category(),
                     "[ExecuteWorkflowMutation] Workflow execution failed: {:?}",
                     e
                 );
-                Err(format!("Workflow execution failed: {:?}", e))
+                Err(execution_failure_message(&e))
             }
         }
     };
@@ -110,3 +319,163 @@ pub fn use_execute_workflow_mutation() -> ExecuteWorkflowMutationResult {
     let (state, mutate_fn) = use_mutation(mutation_fn, callbacks);
     (state, std::rc::Rc::new(mutate_fn))
 }
+
+/// Re-run a failed task of a finished execution in place:
+/// `(execution id, task id, also run the tasks after it)`.
+pub fn use_rerun_task_mutation() -> (
+    Signal<MutationState<WorkflowResult>>,
+    impl Fn((String, String, bool)),
+) {
+    let mutation_fn = move |(execution_id, task_id, downstream): (String, String, bool)| async move {
+        s_e_e_core::rerun_task(
+            &execution_id,
+            &task_id,
+            downstream,
+            Some(LiveOutputService::callback()),
+        )
+        .await
+        .map_err(|e| execution_failure_message(&e))
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: Some(Rc::new(|| {
+            invalidate_queries_by_prefix("executions:");
+            invalidate_queries_by_prefix("tasks:");
+        })),
+        invalidate_keys: vec![],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
+/// Run part of a workflow, as set out by the [`RunBounds`].
+pub fn use_execute_workflow_part_mutation() -> (
+    Signal<MutationState<WorkflowResult>>,
+    impl Fn((String, RunBounds)),
+) {
+    let mutation_fn = move |(workflow_id, bounds): (String, RunBounds)| async move {
+        s_e_e_core::execute_workflow_by_id_with_bounds(
+            &workflow_id,
+            Default::default(),
+            Vec::new(),
+            bounds,
+            Some(LiveOutputService::callback()),
+        )
+        .await
+        .map_err(|e| execution_failure_message(&e))
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: Some(Rc::new(|| {
+            invalidate_queries_by_prefix("executions:");
+        })),
+        invalidate_keys: vec![],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
+/// Save the workflow an execution ran as a new definition.
+pub fn use_save_snapshot_mutation() -> (Signal<MutationState<WorkflowDefinition>>, impl Fn(String))
+{
+    let mutation_fn = move |execution_id: String| async move {
+        WorkflowService::save_execution_snapshot(&execution_id)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: Some(Rc::new(|| {
+            invalidate_queries_by_prefix("workflows:");
+        })),
+        invalidate_keys: vec![QueryKey::new(&["workflows", "list"])],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
+pub fn use_workflow_tests_query(workflow_id: String) -> (QueryState<Vec<WorkflowTest>>, impl Fn()) {
+    let key = QueryKey::new(&["workflows", "tests", &workflow_id]);
+
+    let fetcher = move || {
+        let workflow_id = workflow_id.clone();
+        async move {
+            WorkflowService::fetch_workflow_tests(&workflow_id)
+                .await
+                .map_err(|e| e.to_string())
+        }
+    };
+
+    let options = QueryOptions {
+        stale_time: Some(30_000),
+        cache_time: Some(300_000),
+        ..Default::default()
+    };
+
+    use_query(key, fetcher, options)
+}
+
+/// Record a finished execution as a test of its workflow.
+pub fn use_create_workflow_test_mutation() -> (Signal<MutationState<WorkflowTest>>, impl Fn(String))
+{
+    let mutation_fn = move |execution_id: String| async move {
+        WorkflowService::create_test_from_execution(&execution_id)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: Some(Rc::new(|| {
+            invalidate_queries_by_prefix("workflows:tests");
+        })),
+        invalidate_keys: vec![],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
+pub fn use_delete_workflow_test_mutation() -> (Signal<MutationState<()>>, impl Fn(String)) {
+    let mutation_fn = move |test_id: String| async move {
+        WorkflowService::delete_workflow_test(&test_id)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    let callbacks = MutationCallbacks {
+        on_success: None,
+        on_error: None,
+        on_settled: Some(Rc::new(|| {
+            invalidate_queries_by_prefix("workflows:tests");
+        })),
+        invalidate_keys: vec![],
+        optimistic_update: None,
+    };
+
+    use_mutation(mutation_fn, callbacks)
+}
+
+/// Run every recorded test of a workflow against its saved definition.
+pub fn use_run_workflow_tests_mutation() -> (
+    Signal<MutationState<Vec<WorkflowTestReport>>>,
+    impl Fn(String),
+) {
+    let mutation_fn = move |workflow_id: String| async move {
+        WorkflowService::run_workflow_tests(&workflow_id)
+            .await
+            .map_err(|e| e.to_string())
+    };
+
+    use_mutation(mutation_fn, MutationCallbacks::default())
+}
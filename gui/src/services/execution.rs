@@ -1,4 +1,11 @@
-use s_e_e_core::{TaskExecution, WorkflowExecution, WorkflowExecutionSummary, WorkflowMetadata};
+use super::live_output::{LiveOutputService, LiveRunStatus};
+use chrono::{DateTime, NaiveDate, Utc};
+use s_e_e_core::{
+    ArchivedExecution, DailyExecutionStats, ExecutionComparison, ExecutionFilter,
+    ExecutionFilterPreset, FlakyTask, OutputEvent, SnapshotStatus, TaskContext, TaskExecution,
+    WorkflowExecution, WorkflowExecutionStatus, WorkflowExecutionSummary, WorkflowHealth,
+    WorkflowMetadata,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum ExecutionError {
@@ -14,6 +21,78 @@ pub enum ExecutionError {
     FetchTaskDetailsFailed(String),
     #[error("Failed to delete workflow execution: {0}")]
     DeleteExecutionFailed(String),
+    #[error("Failed to fetch execution calendar: {0}")]
+    FetchCalendarFailed(String),
+    #[error("Invalid date: {0}")]
+    InvalidDate(String),
+    #[error("Failed to fetch archived executions: {0}")]
+    FetchArchivedFailed(String),
+    #[error("Failed to restore archived execution: {0}")]
+    RestoreArchivedFailed(String),
+    #[error("Failed to compare with the current workflow: {0}")]
+    FetchSnapshotStatusFailed(String),
+    #[error("Failed to compare with the previous run: {0}")]
+    CompareWithPreviousFailed(String),
+    #[error("Failed to fetch flaky tasks: {0}")]
+    FetchFlakyTasksFailed(String),
+    #[error("Failed to update labels: {0}")]
+    UpdateLabelsFailed(String),
+    #[error("Failed to fetch filter presets: {0}")]
+    FetchFilterPresetsFailed(String),
+    #[error("Failed to save filter preset: {0}")]
+    SaveFilterPresetFailed(String),
+    #[error("Failed to rebuild task context: {0}")]
+    FetchContextFailed(String),
+    #[error("Failed to fetch workflow health: {0}")]
+    FetchHealthFailed(String),
+    #[error("Failed to cancel run: {0}")]
+    CancelFailed(String),
+    #[error("Failed to fetch saved output: {0}")]
+    FetchJournaledOutputFailed(String),
+}
+
+/// The newest executions matching a filter, plus how many match in total.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ExecutionPage {
+    pub executions: Vec<WorkflowExecutionSummary>,
+    pub total: usize,
+}
+
+/// A running execution with what its live output says about it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiveRun {
+    pub id: String,
+    pub workflow_name: String,
+    pub started_at: DateTime<Utc>,
+    /// Tasks the run will go through, 0 if its workflow cannot be read.
+    pub total_tasks: usize,
+    pub status: LiveRunStatus,
+}
+
+impl LiveRun {
+    /// Share of the tasks finished so far, from 0 to 1.
+    pub fn fraction(&self) -> f64 {
+        if self.total_tasks == 0 {
+            return 0.0;
+        }
+        (self.status.finished_tasks as f64 / self.total_tasks as f64).min(1.0)
+    }
+}
+
+/// How many tasks the run of `execution` goes through, after its bounds.
+fn planned_task_count(execution: &WorkflowExecution) -> usize {
+    let Ok(workflow) = s_e_e_engine::parse_workflow(&execution.workflow_snapshot.to_string())
+    else {
+        return 0;
+    };
+    match &execution.bounds {
+        Some(bounds) => {
+            s_e_e_engine::bound_workflow(&workflow, bounds.from.as_deref(), bounds.until.as_deref())
+                .map(|bounded| bounded.workflow.tasks.len())
+                .unwrap_or(0)
+        }
+        None => workflow.tasks.len(),
+    }
 }
 
 pub struct ExecutionService;
@@ -40,12 +119,97 @@ impl ExecutionService {
                 completed_at: exec.completed_at,
                 task_count: exec.tasks.len(),
                 timestamp: exec.timestamp,
+                labels: exec.labels,
             })
             .collect();
 
         Ok(summaries)
     }
 
+    /// The first `limit` executions matching `filter`, newest first.
+    pub async fn fetch_execution_page(
+        filter: ExecutionFilter,
+        limit: usize,
+    ) -> Result<ExecutionPage, ExecutionError> {
+        let store = s_e_e_core::get_global_store()
+            .map_err(|e| ExecutionError::DatabaseUnavailable(e.to_string()))?;
+
+        let executions = store
+            .list_workflow_execution_summaries(&filter, 0, limit)
+            .await
+            .map_err(|e| ExecutionError::FetchExecutionsFailed(e.to_string()))?;
+        let total = store
+            .count_workflow_executions(&filter)
+            .await
+            .map_err(|e| ExecutionError::FetchExecutionsFailed(e.to_string()))?;
+
+        Ok(ExecutionPage { executions, total })
+    }
+
+    pub async fn fetch_execution_labels() -> Result<Vec<String>, ExecutionError> {
+        s_e_e_core::list_execution_labels()
+            .await
+            .map_err(|e| ExecutionError::FetchExecutionsFailed(e.to_string()))
+    }
+
+    pub async fn set_execution_labels(
+        execution_id: &str,
+        labels: Vec<String>,
+    ) -> Result<Vec<String>, ExecutionError> {
+        s_e_e_core::set_execution_labels(execution_id, labels)
+            .await
+            .map_err(|e| ExecutionError::UpdateLabelsFailed(e.to_string()))
+    }
+
+    pub async fn fetch_filter_presets() -> Result<Vec<ExecutionFilterPreset>, ExecutionError> {
+        s_e_e_core::list_execution_filter_presets()
+            .await
+            .map_err(|e| ExecutionError::FetchFilterPresetsFailed(e.to_string()))
+    }
+
+    pub async fn save_filter_preset(
+        name: &str,
+        filter: ExecutionFilter,
+    ) -> Result<ExecutionFilterPreset, ExecutionError> {
+        s_e_e_core::save_execution_filter_preset(name, filter)
+            .await
+            .map_err(|e| ExecutionError::SaveFilterPresetFailed(e.to_string()))
+    }
+
+    pub async fn delete_filter_preset(name: &str) -> Result<(), ExecutionError> {
+        s_e_e_core::delete_execution_filter_preset(name)
+            .await
+            .map_err(|e| ExecutionError::SaveFilterPresetFailed(e.to_string()))
+    }
+
+    pub async fn fetch_daily_stats(
+        since: NaiveDate,
+    ) -> Result<Vec<DailyExecutionStats>, ExecutionError> {
+        let store = s_e_e_core::get_global_store()
+            .map_err(|e| ExecutionError::DatabaseUnavailable(e.to_string()))?;
+
+        store
+            .daily_execution_stats(since)
+            .await
+            .map_err(|e| ExecutionError::FetchCalendarFailed(e.to_string()))
+    }
+
+    pub async fn fetch_executions_on(
+        date: &str,
+    ) -> Result<Vec<WorkflowExecutionSummary>, ExecutionError> {
+        let date = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| ExecutionError::InvalidDate(date.to_string()))?;
+        let store = s_e_e_core::get_global_store()
+            .map_err(|e| ExecutionError::DatabaseUnavailable(e.to_string()))?;
+
+        let executions = store
+            .list_workflow_executions_on(date)
+            .await
+            .map_err(|e| ExecutionError::FetchExecutionsFailed(e.to_string()))?;
+
+        Ok(executions.iter().map(|exec| exec.to_summary()).collect())
+    }
+
     pub async fn fetch_running_workflows(
         _limit: usize,
     ) -> Result<Vec<WorkflowMetadata>, ExecutionError> {
@@ -81,6 +245,59 @@ impl ExecutionService {
         Ok(running)
     }
 
+    /// Executions still running, oldest first, with the progress their live
+    /// output shows. Runs started elsewhere have no output here.
+    pub async fn fetch_live_runs(limit: usize) -> Result<Vec<LiveRun>, ExecutionError> {
+        let store = s_e_e_core::get_global_store()
+            .map_err(|e| ExecutionError::DatabaseUnavailable(e.to_string()))?;
+
+        let filter = ExecutionFilter {
+            status: Some(WorkflowExecutionStatus::Running),
+            ..Default::default()
+        };
+        let summaries = store
+            .list_workflow_execution_summaries(&filter, 0, limit)
+            .await
+            .map_err(|e| ExecutionError::FetchExecutionsFailed(e.to_string()))?;
+
+        let mut runs = Vec::with_capacity(summaries.len());
+        for summary in summaries {
+            let total_tasks = store
+                .get_workflow_execution(&summary.id)
+                .await
+                .map_err(|e| ExecutionError::FetchExecutionsFailed(e.to_string()))?
+                .map(|execution| planned_task_count(&execution))
+                .unwrap_or(0);
+            let events = LiveOutputService::events_for(&summary.id);
+            runs.push(LiveRun {
+                status: LiveOutputService::status_of(&events),
+                id: summary.id,
+                workflow_name: summary.workflow_name,
+                started_at: summary.created_at,
+                total_tasks,
+            });
+        }
+        runs.sort_by_key(|run| run.started_at);
+        Ok(runs)
+    }
+
+    /// Stop a run started from this window. False if it is not running here.
+    pub async fn cancel_run(execution_id: &str) -> Result<bool, ExecutionError> {
+        s_e_e_core::cancel_workflow_execution(execution_id)
+            .await
+            .map_err(|e| ExecutionError::CancelFailed(e.to_string()))
+    }
+
+    /// Output the store kept for a run that never saved its task logs, such
+    /// as one the app quit or crashed during.
+    pub async fn fetch_journaled_output(
+        execution_id: &str,
+    ) -> Result<Vec<OutputEvent>, ExecutionError> {
+        s_e_e_core::get_journaled_output(execution_id)
+            .await
+            .map_err(|e| ExecutionError::FetchJournaledOutputFailed(e.to_string()))
+    }
+
     pub async fn fetch_workflow_execution(
         execution_id: &str,
     ) -> Result<WorkflowExecution, ExecutionError> {
@@ -115,4 +332,69 @@ impl ExecutionService {
             .await
             .map_err(|e| ExecutionError::DeleteExecutionFailed(e.to_string()))
     }
+
+    pub async fn fetch_snapshot_status(
+        execution_id: &str,
+    ) -> Result<SnapshotStatus, ExecutionError> {
+        s_e_e_core::execution_snapshot_status(execution_id)
+            .await
+            .map_err(|e| ExecutionError::FetchSnapshotStatusFailed(e.to_string()))
+    }
+
+    pub async fn fetch_context_at(
+        execution_id: &str,
+        task_id: &str,
+    ) -> Result<TaskContext, ExecutionError> {
+        s_e_e_core::get_context_at(execution_id, task_id)
+            .await
+            .map_err(|e| ExecutionError::FetchContextFailed(e.to_string()))
+    }
+
+    pub async fn compare_with_previous_run(
+        execution_id: &str,
+    ) -> Result<Option<ExecutionComparison>, ExecutionError> {
+        s_e_e_core::compare_with_previous_run(execution_id)
+            .await
+            .map_err(|e| ExecutionError::CompareWithPreviousFailed(e.to_string()))
+    }
+
+    pub async fn fetch_flaky_tasks(limit: usize) -> Result<Vec<FlakyTask>, ExecutionError> {
+        s_e_e_core::flaky_tasks(limit)
+            .await
+            .map_err(|e| ExecutionError::FetchFlakyTasksFailed(e.to_string()))
+    }
+
+    pub async fn fetch_workflow_health(runs: usize) -> Result<Vec<WorkflowHealth>, ExecutionError> {
+        let store = s_e_e_core::get_global_store()
+            .map_err(|e| ExecutionError::DatabaseUnavailable(e.to_string()))?;
+
+        store
+            .list_workflow_health(runs)
+            .await
+            .map_err(|e| ExecutionError::FetchHealthFailed(e.to_string()))
+    }
+
+    pub async fn fetch_execution_flaky_tasks(
+        execution_id: &str,
+    ) -> Result<Vec<FlakyTask>, ExecutionError> {
+        s_e_e_core::execution_flaky_tasks(execution_id)
+            .await
+            .map_err(|e| ExecutionError::FetchFlakyTasksFailed(e.to_string()))
+    }
+
+    pub async fn fetch_archived_executions() -> Result<Vec<ArchivedExecution>, ExecutionError> {
+        s_e_e_core::list_archived_executions()
+            .await
+            .map_err(|e| ExecutionError::FetchArchivedFailed(e.to_string()))
+    }
+
+    pub async fn restore_archived_execution(
+        execution_id: &str,
+    ) -> Result<WorkflowExecution, ExecutionError> {
+        let dir = s_e_e_core::default_archive_dir()
+            .map_err(|e| ExecutionError::RestoreArchivedFailed(e.to_string()))?;
+        s_e_e_core::restore_archived_execution(&dir, execution_id)
+            .await
+            .map_err(|e| ExecutionError::RestoreArchivedFailed(e.to_string()))
+    }
 }
@@ -0,0 +1,161 @@
+use rfd::FileDialog;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error("Database not available: {0}")]
+    DatabaseUnavailable(String),
+    #[error("Failed to export {0}")]
+    ExportFailed(String),
+    #[error("Could not write {0}")]
+    WriteFailed(String),
+}
+
+/// What the context menus can export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportKind {
+    /// As a bundle, the same file `see export-bundle` writes.
+    Workflow,
+    Prompt,
+    Execution,
+}
+
+impl ExportKind {
+    pub fn noun(self, count: usize) -> &'static str {
+        match (self, count) {
+            (ExportKind::Workflow, 1) => "workflow",
+            (ExportKind::Workflow, _) => "workflows",
+            (ExportKind::Prompt, 1) => "prompt",
+            (ExportKind::Prompt, _) => "prompts",
+            (ExportKind::Execution, 1) => "execution",
+            (ExportKind::Execution, _) => "executions",
+        }
+    }
+}
+
+/// One file to save: the name to suggest, without extension, and its JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportFile {
+    pub name: String,
+    pub contents: String,
+}
+
+/// `name` with everything but letters, digits, `-` and `_` replaced by `-`,
+/// or `fallback` if nothing is left.
+pub fn file_stem(name: &str, fallback: &str) -> String {
+    let stem: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    if stem.trim_matches('-').is_empty() {
+        fallback.to_string()
+    } else {
+        stem
+    }
+}
+
+pub struct ExportService;
+
+impl ExportService {
+    pub async fn fetch(kind: ExportKind, id: &str) -> Result<ExportFile, ExportError> {
+        let store = s_e_e_core::get_global_store()
+            .map_err(|e| ExportError::DatabaseUnavailable(e.to_string()))?;
+        let failed = |e: String| ExportError::ExportFailed(format!("{}: {}", id, e));
+
+        match kind {
+            ExportKind::Workflow => {
+                let name = store
+                    .get_workflow(id)
+                    .await
+                    .map_err(failed)?
+                    .map(|workflow| workflow.name)
+                    .unwrap_or_else(|| id.to_string());
+                let contents = s_e_e_core::export_workflow_bundle(id, None)
+                    .await
+                    .map_err(|e| failed(e.to_string()))?;
+                Ok(ExportFile {
+                    name: file_stem(&name, "workflow"),
+                    contents,
+                })
+            }
+            ExportKind::Prompt => {
+                let prompt = store
+                    .list_prompts()
+                    .await
+                    .map_err(failed)?
+                    .into_iter()
+                    .find(|prompt| prompt.id == id)
+                    .ok_or_else(|| failed("not found".to_string()))?;
+                let contents =
+                    serde_json::to_string_pretty(&prompt).map_err(|e| failed(e.to_string()))?;
+                Ok(ExportFile {
+                    name: file_stem(&prompt.id, "prompt"),
+                    contents,
+                })
+            }
+            ExportKind::Execution => {
+                let execution = store.get_workflow_with_tasks(id).await.map_err(failed)?;
+                let contents =
+                    serde_json::to_string_pretty(&execution).map_err(|e| failed(e.to_string()))?;
+                Ok(ExportFile {
+                    name: file_stem(
+                        &format!("{}-{}", execution.workflow_name, execution.id),
+                        "execution",
+                    ),
+                    contents,
+                })
+            }
+        }
+    }
+
+    /// Ask where to save `files`, as a file for one and into a folder for
+    /// several, and write them. `None` if the user cancelled.
+    pub fn save(files: Vec<ExportFile>) -> Result<Option<Vec<PathBuf>>, ExportError> {
+        let paths = match files.as_slice() {
+            [] => return Ok(Some(Vec::new())),
+            [file] => {
+                let Some(path) = FileDialog::new()
+                    .add_filter("JSON", &["json"])
+                    .set_file_name(format!("{}.json", file.name))
+                    .set_title("Export")
+                    .save_file()
+                else {
+                    return Ok(None);
+                };
+                vec![path]
+            }
+            _ => {
+                let Some(folder) = FileDialog::new()
+                    .set_title("Export to Folder")
+                    .pick_folder()
+                else {
+                    return Ok(None);
+                };
+                let mut used = HashSet::new();
+                files
+                    .iter()
+                    .map(|file| {
+                        let name = std::iter::once(file.name.clone())
+                            .chain((2..).map(|n| format!("{}-{}", file.name, n)))
+                            .find(|name| used.insert(name.clone()))
+                            .expect("there is always an unused file name");
+                        folder.join(format!("{}.json", name))
+                    })
+                    .collect()
+            }
+        };
+
+        for (path, file) in paths.iter().zip(&files) {
+            std::fs::write(path, &file.contents)
+                .map_err(|e| ExportError::WriteFailed(format!("{}: {}", path.display(), e)))?;
+        }
+        Ok(Some(paths))
+    }
+}
@@ -0,0 +1,164 @@
+use s_e_e_core::{OutputCallback, OutputEvent, TaskProgress};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::sync::watch;
+
+/// Executions whose output is kept; the oldest is dropped when another starts.
+const MAX_EXECUTIONS: usize = 20;
+/// Lines kept per execution. Older lines are dropped first.
+const MAX_EVENTS_PER_EXECUTION: usize = 10_000;
+
+#[derive(Default)]
+struct LiveOutputBuffer {
+    order: VecDeque<String>,
+    events: HashMap<String, VecDeque<OutputEvent>>,
+}
+
+fn buffer() -> &'static Mutex<LiveOutputBuffer> {
+    static BUFFER: OnceLock<Mutex<LiveOutputBuffer>> = OnceLock::new();
+    BUFFER.get_or_init(Default::default)
+}
+
+/// Counts the events recorded so far, for [`LiveOutputService::subscribe`].
+fn updates() -> &'static watch::Sender<u64> {
+    static UPDATES: OnceLock<watch::Sender<u64>> = OnceLock::new();
+    UPDATES.get_or_init(|| watch::channel(0).0)
+}
+
+/// The lines one task wrote, in the order it wrote them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskOutputGroup {
+    /// `None` for workflow-level messages.
+    pub task_id: Option<String>,
+    pub label: String,
+    pub lines: Vec<String>,
+    /// The latest progress the task reported.
+    pub progress: Option<TaskProgress>,
+}
+
+/// The output to show for an execution, grouped by task.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ExecutionOutput {
+    pub groups: Vec<TaskOutputGroup>,
+    /// Whether it was read back from the store's journal rather than streamed
+    /// to this window, i.e. the run stopped before it saved its task logs.
+    pub from_journal: bool,
+}
+
+/// How far a run has got, as far as its output tells.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LiveRunStatus {
+    /// The task that wrote last among those still going.
+    pub current_task: Option<String>,
+    /// The latest progress `current_task` reported.
+    pub progress: Option<TaskProgress>,
+    pub finished_tasks: usize,
+}
+
+/// Output streamed by workflows started from this window, held in memory so the
+/// execution page can show it while the run is still going.
+pub struct LiveOutputService;
+
+impl LiveOutputService {
+    pub fn callback() -> OutputCallback {
+        Arc::new(Self::record)
+    }
+
+    /// Changes each time an event is recorded, so views can refresh as soon
+    /// as a run moves on instead of on their next poll.
+    pub fn subscribe() -> watch::Receiver<u64> {
+        updates().subscribe()
+    }
+
+    pub fn record(event: OutputEvent) {
+        Self::buffer_event(event);
+        updates().send_modify(|count| *count += 1);
+    }
+
+    fn buffer_event(event: OutputEvent) {
+        let mut buffer = buffer().lock().unwrap_or_else(|e| e.into_inner());
+        if !buffer.events.contains_key(&event.execution_id) {
+            buffer.order.push_back(event.execution_id.clone());
+            if buffer.order.len() > MAX_EXECUTIONS {
+                if let Some(oldest) = buffer.order.pop_front() {
+                    buffer.events.remove(&oldest);
+                }
+            }
+        }
+        let events = buffer.events.entry(event.execution_id.clone()).or_default();
+        if events.len() >= MAX_EVENTS_PER_EXECUTION {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+
+    pub fn events_for(execution_id: &str) -> Vec<OutputEvent> {
+        let buffer = buffer().lock().unwrap_or_else(|e| e.into_inner());
+        buffer
+            .events
+            .get(execution_id)
+            .map(|events| events.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn status_of(events: &[OutputEvent]) -> LiveRunStatus {
+        let finished: HashSet<&str> = events
+            .iter()
+            .filter(|event| event.finished.is_some())
+            .filter_map(|event| event.task_id.as_deref())
+            .collect();
+        let current = events.iter().rev().find(|event| {
+            event
+                .task_id
+                .as_deref()
+                .is_some_and(|id| !finished.contains(id))
+        });
+
+        LiveRunStatus {
+            current_task: current.map(|event| {
+                event
+                    .task_name
+                    .clone()
+                    .or_else(|| event.task_id.clone())
+                    .unwrap_or_default()
+            }),
+            progress: current.and_then(|current| {
+                events
+                    .iter()
+                    .rev()
+                    .filter(|event| event.task_id == current.task_id)
+                    .find_map(|event| event.progress.clone())
+            }),
+            finished_tasks: finished.len(),
+        }
+    }
+
+    /// Groups lines by task, ordered by each task's first line.
+    pub fn group_by_task(events: &[OutputEvent]) -> Vec<TaskOutputGroup> {
+        let mut groups: Vec<TaskOutputGroup> = Vec::new();
+        for event in events {
+            let index = match groups.iter().position(|g| g.task_id == event.task_id) {
+                Some(index) => index,
+                None => {
+                    let label = match (&event.task_name, &event.task_id) {
+                        (Some(name), _) => name.clone(),
+                        (None, Some(id)) => id.clone(),
+                        (None, None) => "Workflow".to_string(),
+                    };
+                    groups.push(TaskOutputGroup {
+                        task_id: event.task_id.clone(),
+                        label,
+                        lines: Vec::new(),
+                        progress: None,
+                    });
+                    groups.len() - 1
+                }
+            };
+            groups[index].lines.push(event.line.clone());
+            if event.progress.is_some() {
+                groups[index].progress = event.progress.clone();
+            }
+        }
+        groups
+    }
+}
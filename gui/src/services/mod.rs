@@ -1,10 +1,22 @@
 pub mod database;
 pub mod execution;
+pub mod export;
+pub mod live_output;
+pub mod notification;
 pub mod prompt;
 pub mod settings;
+pub mod storage;
+pub mod trash;
+pub mod ui_state;
 pub mod workflow;
 
 pub use database::clear_database;
+pub use export::{file_stem, ExportFile, ExportKind, ExportService};
+pub use live_output::{ExecutionOutput, LiveOutputService, LiveRunStatus, TaskOutputGroup};
+pub use notification::NotificationService;
 pub use prompt::UserPromptService;
 pub use settings::SettingsService;
+pub use storage::StorageService;
+pub use trash::TrashService;
+pub use ui_state::UiStateService;
 pub use workflow::{WorkflowError, WorkflowService};
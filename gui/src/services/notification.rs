@@ -0,0 +1,31 @@
+use s_e_e_core::Notification;
+
+/// How many notifications the bell panel shows.
+pub const NOTIFICATION_PANEL_LIMIT: usize = 50;
+
+#[derive(Debug, thiserror::Error)]
+pub enum NotificationError {
+    #[error("Failed to fetch notifications: {0}")]
+    FetchNotificationsFailed(String),
+    #[error("Failed to update notifications: {0}")]
+    UpdateNotificationsFailed(String),
+}
+
+pub struct NotificationService;
+
+impl NotificationService {
+    pub async fn fetch_notifications() -> Result<Vec<Notification>, NotificationError> {
+        s_e_e_core::list_notifications(NOTIFICATION_PANEL_LIMIT)
+            .await
+            .map_err(|e| NotificationError::FetchNotificationsFailed(e.to_string()))
+    }
+
+    /// Mark one notification read, or all of them when `id` is `None`.
+    pub async fn mark_read(id: Option<&str>) -> Result<(), NotificationError> {
+        match id {
+            Some(id) => s_e_e_core::mark_notification_read(id).await,
+            None => s_e_e_core::mark_all_notifications_read().await,
+        }
+        .map_err(|e| NotificationError::UpdateNotificationsFailed(e.to_string()))
+    }
+}
@@ -1,4 +1,4 @@
-use s_e_e_core::Prompt;
+use s_e_e_core::{Prompt, PromptFilter, PromptWithUsage};
 
 #[derive(Debug, thiserror::Error)]
 pub enum UserPromptError {
@@ -12,6 +12,10 @@ pub enum UserPromptError {
     UpdatePromptFailed(String),
     #[error("Failed to delete prompt: {0}")]
     DeletePromptFailed(String),
+    #[error("Failed to duplicate prompt: {0}")]
+    DuplicatePromptFailed(String),
+    #[error("{0}")]
+    RenderPromptFailed(String),
 }
 
 pub struct UserPromptService;
@@ -27,6 +31,30 @@ impl UserPromptService {
             .map_err(|e| UserPromptError::FetchPromptsFailed(e.to_string()))
     }
 
+    pub async fn fetch_prompts_filtered(
+        filter: &PromptFilter,
+    ) -> Result<Vec<PromptWithUsage>, UserPromptError> {
+        s_e_e_core::list_prompts_filtered(filter)
+            .await
+            .map_err(|e| UserPromptError::FetchPromptsFailed(e.to_string()))
+    }
+
+    pub async fn fetch_prompt_tags() -> Result<Vec<String>, UserPromptError> {
+        s_e_e_core::list_prompt_tags()
+            .await
+            .map_err(|e| UserPromptError::FetchPromptsFailed(e.to_string()))
+    }
+
+    /// Editor content with its `{{include:...}}` directives expanded.
+    pub async fn preview_composition(
+        id: Option<String>,
+        content: String,
+    ) -> Result<String, UserPromptError> {
+        s_e_e_core::preview_prompt(id.as_deref(), &content)
+            .await
+            .map_err(|e| UserPromptError::RenderPromptFailed(e.to_string()))
+    }
+
     pub async fn create_prompt(prompt: Prompt) -> Result<(), UserPromptError> {
         let store = s_e_e_core::get_global_store()
             .map_err(|e| UserPromptError::DatabaseUnavailable(e.to_string()))?;
@@ -47,6 +75,12 @@ impl UserPromptService {
             .map_err(|e| UserPromptError::UpdatePromptFailed(e.to_string()))
     }
 
+    pub async fn duplicate_prompt(id: &str) -> Result<Prompt, UserPromptError> {
+        s_e_e_core::duplicate_prompt(id)
+            .await
+            .map_err(|e| UserPromptError::DuplicatePromptFailed(e.to_string()))
+    }
+
     pub async fn delete_prompt(id: &str) -> Result<(), UserPromptError> {
         let store = s_e_e_core::get_global_store()
             .map_err(|e| UserPromptError::DatabaseUnavailable(e.to_string()))?;
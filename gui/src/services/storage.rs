@@ -0,0 +1,25 @@
+use s_e_e_core::MaintenanceReport;
+
+#[derive(Debug, thiserror::Error)]
+pub enum StorageError {
+    #[error("Failed to load the last maintenance run: {0}")]
+    FetchMaintenanceFailed(String),
+    #[error("Storage maintenance failed: {0}")]
+    MaintenanceFailed(String),
+}
+
+pub struct StorageService;
+
+impl StorageService {
+    pub async fn fetch_last_maintenance() -> Result<Option<MaintenanceReport>, StorageError> {
+        s_e_e_core::last_storage_maintenance()
+            .await
+            .map_err(|e| StorageError::FetchMaintenanceFailed(e.to_string()))
+    }
+
+    pub async fn run_maintenance() -> Result<MaintenanceReport, StorageError> {
+        s_e_e_core::run_storage_maintenance()
+            .await
+            .map_err(|e| StorageError::MaintenanceFailed(e.to_string()))
+    }
+}
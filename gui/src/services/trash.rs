@@ -0,0 +1,27 @@
+use s_e_e_core::{DeletionBatch, TrashKind};
+
+#[derive(Debug, thiserror::Error)]
+pub enum TrashError {
+    #[error("Failed to delete: {0}")]
+    DeleteFailed(String),
+    #[error("Failed to undo the deletion: {0}")]
+    UndoFailed(String),
+}
+
+pub struct TrashService;
+
+impl TrashService {
+    pub async fn delete(kind: TrashKind, ids: Vec<String>) -> Result<DeletionBatch, TrashError> {
+        match kind {
+            TrashKind::Workflow => s_e_e_core::delete_workflows(&ids).await,
+            TrashKind::Prompt => s_e_e_core::delete_prompts(&ids).await,
+        }
+        .map_err(|e| TrashError::DeleteFailed(e.to_string()))
+    }
+
+    pub async fn undo(batch_id: &str) -> Result<Vec<String>, TrashError> {
+        s_e_e_core::undo_deletion(batch_id)
+            .await
+            .map_err(|e| TrashError::UndoFailed(e.to_string()))
+    }
+}
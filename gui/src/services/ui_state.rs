@@ -0,0 +1,34 @@
+use s_e_e_core::UiState;
+
+#[derive(Debug, thiserror::Error)]
+pub enum UiStateError {
+    #[error("Database not available: {0}")]
+    DatabaseUnavailable(String),
+    #[error("Failed to fetch UI state: {0}")]
+    FetchFailed(String),
+    #[error("Failed to save UI state: {0}")]
+    SaveFailed(String),
+}
+
+pub struct UiStateService;
+
+impl UiStateService {
+    pub async fn fetch_ui_state() -> Result<UiState, UiStateError> {
+        let store = s_e_e_core::get_global_store()
+            .map_err(|e| UiStateError::DatabaseUnavailable(e.to_string()))?;
+        store
+            .load_ui_state()
+            .await
+            .map(Option::unwrap_or_default)
+            .map_err(UiStateError::FetchFailed)
+    }
+
+    pub async fn save_ui_state(state: UiState) -> Result<(), UiStateError> {
+        let store = s_e_e_core::get_global_store()
+            .map_err(|e| UiStateError::DatabaseUnavailable(e.to_string()))?;
+        store
+            .save_ui_state(&state)
+            .await
+            .map_err(UiStateError::SaveFailed)
+    }
+}
@@ -1,5 +1,9 @@
-use s_e_e_core::WorkflowDefinition;
+use s_e_e_core::{
+    DefaultResetPreview, ExampleRefresh, ExampleWorkflow, TaskSnippet, TrustPrompt,
+    WorkflowDefinition, WorkflowParameter, WorkflowResult, WorkflowTest, WorkflowTestReport,
+};
 use std::fs;
+use std::path::Path;
 
 #[derive(Debug, thiserror::Error)]
 pub enum WorkflowError {
@@ -11,6 +15,26 @@ pub enum WorkflowError {
     FetchWorkflowFailed(String),
     #[error("Failed to create workflow: {0}")]
     CreateWorkflowFailed(String),
+    #[error("Failed to load examples: {0}")]
+    FetchExamplesFailed(String),
+    #[error("Failed to add example: {0}")]
+    AddExampleFailed(String),
+    #[error("Failed to reset workflow: {0}")]
+    ResetWorkflowFailed(String),
+    #[error("Failed to save snapshot: {0}")]
+    SaveSnapshotFailed(String),
+    #[error("Sandbox run failed: {0}")]
+    SandboxRunFailed(String),
+    #[error("Failed to import bundle: {0}")]
+    ImportBundleFailed(String),
+    #[error("Failed to check workflow trust: {0}")]
+    TrustCheckFailed(String),
+    #[error("Failed to load snippets: {0}")]
+    FetchSnippetsFailed(String),
+    #[error("Failed to duplicate workflow: {0}")]
+    DuplicateWorkflowFailed(String),
+    #[error("Workflow test failed to run: {0}")]
+    WorkflowTestFailed(String),
 }
 
 pub struct WorkflowService;
@@ -45,6 +69,138 @@ impl WorkflowService {
             .await
             .map_err(|e| WorkflowError::CreateWorkflowFailed(e.to_string()))
     }
+
+    pub async fn duplicate_workflow(id: &str) -> Result<WorkflowDefinition, WorkflowError> {
+        s_e_e_core::duplicate_workflow(id)
+            .await
+            .map_err(|e| WorkflowError::DuplicateWorkflowFailed(e.to_string()))
+    }
+
+    pub async fn fetch_snippets() -> Result<Vec<TaskSnippet>, WorkflowError> {
+        s_e_e_core::list_snippets()
+            .await
+            .map_err(|e| WorkflowError::FetchSnippetsFailed(e.to_string()))
+    }
+
+    pub fn fetch_examples() -> Result<Vec<ExampleWorkflow>, WorkflowError> {
+        s_e_e_core::list_example_workflows()
+            .map_err(|e| WorkflowError::FetchExamplesFailed(e.to_string()))
+    }
+
+    pub async fn add_example(id: &str) -> Result<WorkflowDefinition, WorkflowError> {
+        s_e_e_core::add_example_workflow(id)
+            .await
+            .map_err(|e| WorkflowError::AddExampleFailed(e.to_string()))
+    }
+
+    pub async fn complete_onboarding(add_examples: bool) -> Result<usize, WorkflowError> {
+        s_e_e_core::complete_onboarding(add_examples)
+            .await
+            .map_err(|e| WorkflowError::AddExampleFailed(e.to_string()))
+    }
+
+    /// Preview a workflow without running its commands or saving anything.
+    pub async fn run_sandboxed(content: &str) -> Result<WorkflowResult, WorkflowError> {
+        s_e_e_core::run_sandboxed(content, Default::default(), None)
+            .await
+            .map_err(|e| WorkflowError::SandboxRunFailed(e.to_string()))
+    }
+
+    /// Import a signed or unsigned bundle file, keeping the file name as its source.
+    pub async fn import_bundle_file(path: &str) -> Result<WorkflowDefinition, WorkflowError> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| WorkflowError::ImportBundleFailed(format!("{}: {}", path, e)))?;
+        let source = Path::new(path)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(path);
+        s_e_e_core::import_workflow_bundle(source, &text)
+            .await
+            .map_err(|e| WorkflowError::ImportBundleFailed(e.to_string()))
+    }
+
+    pub async fn trust_prompt(id: &str) -> Result<Option<TrustPrompt>, WorkflowError> {
+        s_e_e_core::workflow_trust_prompt(id)
+            .await
+            .map_err(|e| WorkflowError::TrustCheckFailed(e.to_string()))
+    }
+
+    pub async fn parameters(id: &str) -> Result<Vec<WorkflowParameter>, WorkflowError> {
+        s_e_e_core::workflow_parameters(id)
+            .await
+            .map_err(|e| WorkflowError::FetchWorkflowFailed(e.to_string()))
+    }
+
+    pub async fn trust(id: &str) -> Result<(), WorkflowError> {
+        s_e_e_core::trust_workflow(id)
+            .await
+            .map_err(|e| WorkflowError::TrustCheckFailed(e.to_string()))
+    }
+
+    pub fn is_example(id: &str) -> bool {
+        s_e_e_core::get_example_workflow(id).is_ok()
+    }
+
+    pub async fn preview_reset(id: &str) -> Result<DefaultResetPreview, WorkflowError> {
+        s_e_e_core::preview_default_reset(id)
+            .await
+            .map_err(|e| WorkflowError::ResetWorkflowFailed(e.to_string()))
+    }
+
+    pub async fn reset_to_default(id: &str) -> Result<WorkflowDefinition, WorkflowError> {
+        s_e_e_core::reset_default_workflow(id)
+            .await
+            .map_err(|e| WorkflowError::ResetWorkflowFailed(e.to_string()))
+    }
+
+    pub async fn refresh_examples() -> Result<ExampleRefresh, WorkflowError> {
+        s_e_e_core::refresh_examples()
+            .await
+            .map_err(|e| WorkflowError::ResetWorkflowFailed(e.to_string()))
+    }
+
+    pub async fn save_execution_snapshot(
+        execution_id: &str,
+    ) -> Result<WorkflowDefinition, WorkflowError> {
+        s_e_e_core::save_snapshot_as_workflow(execution_id, None)
+            .await
+            .map_err(|e| WorkflowError::SaveSnapshotFailed(e.to_string()))
+    }
+
+    pub async fn create_test_from_execution(
+        execution_id: &str,
+    ) -> Result<WorkflowTest, WorkflowError> {
+        s_e_e_core::create_test_from_execution(execution_id, None)
+            .await
+            .map_err(|e| WorkflowError::WorkflowTestFailed(e.to_string()))
+    }
+
+    pub async fn fetch_workflow_tests(
+        workflow_id: &str,
+    ) -> Result<Vec<WorkflowTest>, WorkflowError> {
+        s_e_e_core::list_workflow_tests(workflow_id)
+            .await
+            .map_err(|e| WorkflowError::WorkflowTestFailed(e.to_string()))
+    }
+
+    pub async fn delete_workflow_test(test_id: &str) -> Result<(), WorkflowError> {
+        s_e_e_core::delete_workflow_test(test_id)
+            .await
+            .map_err(|e| WorkflowError::WorkflowTestFailed(e.to_string()))
+    }
+
+    pub async fn run_workflow_tests(
+        workflow_id: &str,
+    ) -> Result<Vec<WorkflowTestReport>, WorkflowError> {
+        s_e_e_core::run_workflow_tests(workflow_id)
+            .await
+            .map_err(|e| WorkflowError::WorkflowTestFailed(e.to_string()))
+    }
+}
+
+/// Whether the file at `path` is a workflow bundle rather than a bare definition.
+pub fn is_bundle_file(path: &str) -> bool {
+    fs::read_to_string(path).is_ok_and(|text| s_e_e_core::is_workflow_bundle(&text))
 }
 
 pub fn read_and_parse_workflow_file(file_path: String) -> Result<WorkflowDefinition, String> {
@@ -5,4 +5,4 @@ pub mod store;
 
 pub use errors::PersistenceError;
 pub use models::*;
-pub use store::Store;
+pub use store::{Store, SCHEMA_VERSION};
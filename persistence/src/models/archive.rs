@@ -0,0 +1,20 @@
+use super::WorkflowExecutionStatus;
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Index entry for an execution moved out of the database into an archive file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ArchivedExecution {
+    pub id: String,
+    pub workflow_name: String,
+    pub status: WorkflowExecutionStatus,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub task_count: usize,
+    pub archived_at: DateTime<Utc>,
+    /// Archive file name, relative to the archive directory.
+    pub file_name: String,
+    /// Compressed size of the archive file.
+    pub size_bytes: u64,
+}
@@ -1,8 +1,9 @@
-use crate::models::AuditStatus;
+use crate::models::{AuditChangeOp, AuditStatus};
 use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct AuditEvent {
     pub id: String,
     pub task_id: String,
@@ -10,6 +11,21 @@ pub struct AuditEvent {
     pub timestamp: DateTime<Utc>,
     pub changes_count: usize,
     pub message: String,
+    #[serde(default)]
+    pub changes: Vec<AuditChange>,
+    #[serde(default)]
+    pub changes_truncated: bool,
+}
+
+/// A context change recorded for a task, addressed by JSON pointer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AuditChange {
+    pub op: AuditChangeOp,
+    pub path: String,
+    #[serde(default)]
+    pub before: Option<serde_json::Value>,
+    #[serde(default)]
+    pub after: Option<serde_json::Value>,
 }
 
 impl Default for AuditEvent {
@@ -21,6 +37,8 @@ impl Default for AuditEvent {
             timestamp: Utc::now(),
             changes_count: 0,
             message: String::new(),
+            changes: Vec::new(),
+            changes_truncated: false,
         }
     }
 }
@@ -48,6 +66,8 @@ impl AuditEvent {
             timestamp: Utc::now(),
             changes_count,
             message,
+            changes: Vec::new(),
+            changes_truncated: false,
         }
     }
 
@@ -59,6 +79,8 @@ impl AuditEvent {
             timestamp: Utc::now(),
             changes_count,
             message,
+            changes: Vec::new(),
+            changes_truncated: false,
         }
     }
 }
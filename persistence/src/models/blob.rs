@@ -0,0 +1,20 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Size of the content-addressed payload storage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct BlobStats {
+    /// Distinct payloads stored.
+    pub blobs: u64,
+    /// Bytes of payload JSON stored.
+    pub bytes: u64,
+    /// Places in executions and tasks that point at a payload.
+    pub references: u64,
+}
+
+/// What a blob vacuum removed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct BlobVacuumStats {
+    pub removed: u64,
+    pub bytes_reclaimed: u64,
+}
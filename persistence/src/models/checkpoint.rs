@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// How far a run got, saved after every round of tasks and deleted when the
+/// run finishes. One left behind belongs to a run that was interrupted and
+/// can be resumed from it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ExecutionCheckpoint {
+    pub execution_id: String,
+    /// Tasks that succeeded or were skipped.
+    pub completed_tasks: Vec<String>,
+    /// The tasks in `completed_tasks` that were skipped.
+    pub skipped_tasks: Vec<String>,
+    /// The run's context data: initial data and task outputs.
+    pub data: Value,
+    /// Transient values the run's handlers keep outside the context data.
+    /// Only saved here, never with the execution.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub scratch: BTreeMap<String, Value>,
+    pub saved_at: DateTime<Utc>,
+}
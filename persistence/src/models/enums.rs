@@ -1,7 +1,8 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub enum WorkflowExecutionStatus {
     #[serde(rename = "pending")]
     Pending,
@@ -13,6 +14,9 @@ pub enum WorkflowExecutionStatus {
     Failed,
     #[serde(rename = "waiting_for_input")]
     WaitingForInput,
+    /// Stopped on request while it was running.
+    #[serde(rename = "cancelled")]
+    Cancelled,
 }
 
 impl WorkflowExecutionStatus {
@@ -23,6 +27,7 @@ impl WorkflowExecutionStatus {
             WorkflowExecutionStatus::Complete => "complete",
             WorkflowExecutionStatus::Failed => "failed",
             WorkflowExecutionStatus::WaitingForInput => "waiting_for_input",
+            WorkflowExecutionStatus::Cancelled => "cancelled",
         }
     }
 }
@@ -33,7 +38,7 @@ impl fmt::Display for WorkflowExecutionStatus {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub enum TaskExecutionStatus {
     #[serde(rename = "pending")]
     Pending,
@@ -45,6 +50,10 @@ pub enum TaskExecutionStatus {
     Failed,
     #[serde(rename = "waiting_for_input")]
     WaitingForInput,
+    /// Not run because its `when` condition, or that of a task before it,
+    /// did not hold.
+    #[serde(rename = "skipped")]
+    Skipped,
 }
 
 impl TaskExecutionStatus {
@@ -55,12 +64,13 @@ impl TaskExecutionStatus {
             TaskExecutionStatus::Complete => "complete",
             TaskExecutionStatus::Failed => "failed",
             TaskExecutionStatus::WaitingForInput => "waiting_for_input",
+            TaskExecutionStatus::Skipped => "skipped",
         }
     }
 }
 
 /// UI theme options
-#[derive(Debug, Clone, PartialEq, Hash, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Hash, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum Theme {
     #[serde(rename = "light")]
     Light,
@@ -70,8 +80,140 @@ pub enum Theme {
     System,
 }
 
+/// Time zone timestamps are shown in
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, Default, Serialize, Deserialize, JsonSchema)]
+pub enum DisplayTimeZone {
+    #[default]
+    #[serde(rename = "local")]
+    Local,
+    #[serde(rename = "utc")]
+    Utc,
+}
+
+/// Regional layout for absolute dates and times
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, Default, Serialize, Deserialize, JsonSchema)]
+pub enum DateStyle {
+    /// 2024-05-01 14:03:09
+    #[default]
+    #[serde(rename = "iso")]
+    Iso,
+    /// 05/01/2024 2:03:09 PM
+    #[serde(rename = "us")]
+    UnitedStates,
+    /// 01.05.2024 14:03:09
+    #[serde(rename = "european")]
+    European,
+}
+
+/// Message broker a connection talks to
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, Default, Serialize, Deserialize, JsonSchema)]
+pub enum MessageBroker {
+    #[default]
+    #[serde(rename = "kafka")]
+    Kafka,
+    #[serde(rename = "nats")]
+    Nats,
+    #[serde(rename = "amqp")]
+    Amqp,
+}
+
+impl MessageBroker {
+    pub const ALL: [MessageBroker; 3] = [
+        MessageBroker::Kafka,
+        MessageBroker::Nats,
+        MessageBroker::Amqp,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageBroker::Kafka => "kafka",
+            MessageBroker::Nats => "nats",
+            MessageBroker::Amqp => "amqp",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MessageBroker::Kafka => "Kafka",
+            MessageBroker::Nats => "NATS",
+            MessageBroker::Amqp => "AMQP",
+        }
+    }
+}
+
+/// Most detailed log events that are kept, from least to most verbose
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Hash,
+    Eq,
+    PartialOrd,
+    Ord,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+pub enum LogLevel {
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "warn")]
+    Warn,
+    #[default]
+    #[serde(rename = "info")]
+    Info,
+    #[serde(rename = "debug")]
+    Debug,
+    #[serde(rename = "trace")]
+    Trace,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 5] = [
+        LogLevel::Error,
+        LogLevel::Warn,
+        LogLevel::Info,
+        LogLevel::Debug,
+        LogLevel::Trace,
+    ];
+
+    /// The level as written in filter directives, such as `debug`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "Error",
+            LogLevel::Warn => "Warning",
+            LogLevel::Info => "Info",
+            LogLevel::Debug => "Debug",
+            LogLevel::Trace => "Trace",
+        }
+    }
+}
+
+impl std::str::FromStr for LogLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LogLevel::ALL
+            .into_iter()
+            .find(|level| level.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("Unknown log level '{}'", s))
+    }
+}
+
 /// Audit entry status (re-exported from engine)
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub enum AuditStatus {
     #[serde(rename = "success")]
     Success,
@@ -88,7 +230,27 @@ impl std::fmt::Display for AuditStatus {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum AuditChangeOp {
+    #[serde(rename = "add")]
+    Add,
+    #[serde(rename = "remove")]
+    Remove,
+    #[serde(rename = "replace")]
+    Replace,
+}
+
+impl AuditChangeOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditChangeOp::Add => "add",
+            AuditChangeOp::Remove => "remove",
+            AuditChangeOp::Replace => "replace",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub enum InputType {
     #[serde(rename = "string")]
     String,
@@ -109,7 +271,7 @@ impl std::fmt::Display for InputType {
 }
 
 /// Input request status
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub enum InputRequestStatus {
     #[serde(rename = "pending")]
     Pending,
@@ -125,3 +287,30 @@ impl std::fmt::Display for InputRequestStatus {
         }
     }
 }
+
+/// Specific cause recorded on a failed task
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum TaskFailureReason {
+    #[serde(rename = "timeout")]
+    Timeout,
+    #[serde(rename = "memory_limit_exceeded")]
+    MemoryLimitExceeded,
+    #[serde(rename = "cpu_limit_exceeded")]
+    CpuLimitExceeded,
+}
+
+impl TaskFailureReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskFailureReason::Timeout => "timeout",
+            TaskFailureReason::MemoryLimitExceeded => "memory_limit_exceeded",
+            TaskFailureReason::CpuLimitExceeded => "cpu_limit_exceeded",
+        }
+    }
+}
+
+impl std::fmt::Display for TaskFailureReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -1,9 +1,10 @@
 use crate::models::{AuditEvent, TaskExecution, WorkflowExecutionStatus};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct WorkflowExecution {
     pub id: String,
     pub workflow_name: String,
@@ -16,9 +17,71 @@ pub struct WorkflowExecution {
     pub audit_trail: Vec<AuditEvent>,
     pub per_task_logs: HashMap<String, Vec<String>>,
     pub errors: Vec<String>,
+    /// Parameter values the execution was started with.
+    #[serde(default)]
+    pub parameters: HashMap<String, serde_json::Value>,
+    /// Execution of the upstream workflow whose success started this one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triggered_by: Option<String>,
+    /// Definition the run was started from. `workflow_snapshot` holds its content
+    /// as it was at the time; the definition itself may have been edited since.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow_id: Option<String>,
+    /// Free-form labels, such as a release or a ticket, for finding the run later.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    /// Set when the run covered only part of the workflow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bounds: Option<RunBounds>,
+    /// Where and with what the run happened, for reproducing it later.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest: Option<ReproducibilityManifest>,
+    /// Values of the run's secret parameters, encrypted; `parameters` holds
+    /// them masked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sealed_parameters: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// The part of a workflow a run is limited to. Unset fields leave that end of
+/// the workflow as it is.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct RunBounds {
+    /// Task the run starts at; tasks before it are skipped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// Task the run stops after; only it and the tasks leading to it run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+    /// Earlier execution that supplies the outputs of the skipped tasks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_from: Option<String>,
+}
+
+/// What an execution ran, where, and with which tools, recorded when it starts.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ReproducibilityManifest {
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+    /// Task function types the workflow uses, with the version providing each.
+    #[serde(default)]
+    pub handlers: BTreeMap<String, String>,
+    /// `sha256:` hash of the workflow content that ran, after snippets were expanded.
+    pub workflow_hash: String,
+    #[serde(default)]
+    pub parameters: BTreeMap<String, serde_json::Value>,
+    /// First line of `--version` output of the tools the workflow calls.
+    #[serde(default)]
+    pub tools: BTreeMap<String, String>,
+}
+
+impl RunBounds {
+    pub fn is_empty(&self) -> bool {
+        self.from.is_none() && self.until.is_none() && self.context_from.is_none()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct WorkflowExecutionSummary {
     pub id: String,
     pub workflow_name: String,
@@ -27,9 +90,111 @@ pub struct WorkflowExecutionSummary {
     pub completed_at: Option<DateTime<Utc>>,
     pub task_count: usize,
     pub timestamp: DateTime<Utc>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+/// Narrowing for the execution history. Unset fields match every execution.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ExecutionFilter {
+    #[serde(default)]
+    pub status: Option<WorkflowExecutionStatus>,
+    /// Executions must carry this label.
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Executions must have been started from this workflow definition.
+    #[serde(default)]
+    pub workflow_id: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+impl ExecutionFilter {
+    pub fn with_status(status: Option<WorkflowExecutionStatus>) -> Self {
+        Self {
+            status,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.status.is_none() && self.label.is_none() && self.workflow_id.is_none()
+    }
+}
+
+/// A named [`ExecutionFilter`] saved for reuse from the executions list.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ExecutionFilterPreset {
+    pub name: String,
+    pub filter: ExecutionFilter,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ExecutionFilterPreset {
+    pub fn new(name: impl Into<String>, filter: ExecutionFilter) -> Self {
+        Self {
+            name: name.into(),
+            filter,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Execution counts for one UTC calendar day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DailyExecutionStats {
+    pub date: NaiveDate,
+    pub total: usize,
+    pub failed: usize,
+}
+
+impl DailyExecutionStats {
+    /// Share of the day's executions that failed, from 0.0 to 1.0.
+    pub fn failure_rate(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.failed as f64 / self.total as f64
+        }
+    }
+}
+
+/// How the latest executions of one workflow definition went.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WorkflowHealth {
+    pub workflow_id: String,
+    /// Statuses of the latest executions, oldest first. Never empty.
+    pub recent: Vec<WorkflowExecutionStatus>,
+    /// When the latest execution started.
+    pub last_run_at: DateTime<Utc>,
+}
+
+impl WorkflowHealth {
+    pub fn last_status(&self) -> Option<&WorkflowExecutionStatus> {
+        self.recent.last()
+    }
+
+    /// Share of the finished recent executions that succeeded, from 0.0 to
+    /// 1.0, or `None` while none has finished. Cancelled runs are left out.
+    pub fn success_rate(&self) -> Option<f64> {
+        let finished = self
+            .recent
+            .iter()
+            .filter(|status| {
+                matches!(
+                    status,
+                    WorkflowExecutionStatus::Complete | WorkflowExecutionStatus::Failed
+                )
+            })
+            .count();
+        let succeeded = self
+            .recent
+            .iter()
+            .filter(|status| **status == WorkflowExecutionStatus::Complete)
+            .count();
+        (finished > 0).then(|| succeeded as f64 / finished as f64)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct WorkflowMetadata {
     pub id: String,
     pub name: String,
@@ -54,6 +219,13 @@ impl Default for WorkflowExecution {
             audit_trail: Vec::new(),
             per_task_logs: HashMap::new(),
             errors: Vec::new(),
+            parameters: HashMap::new(),
+            triggered_by: None,
+            workflow_id: None,
+            labels: Vec::new(),
+            bounds: None,
+            manifest: None,
+            sealed_parameters: None,
         }
     }
 }
@@ -69,6 +241,7 @@ impl Default for WorkflowExecutionSummary {
             completed_at: None,
             task_count: 0,
             timestamp: now,
+            labels: Vec::new(),
         }
     }
 }
@@ -97,7 +270,24 @@ impl WorkflowExecution {
             completed_at: self.completed_at,
             task_count: self.tasks.len(),
             timestamp: self.timestamp,
+            labels: self.labels.clone(),
+        }
+    }
+
+    /// Replace the labels, trimming them and dropping blanks and duplicates.
+    pub fn set_labels<I, S>(&mut self, labels: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut normalized: Vec<String> = Vec::new();
+        for label in labels {
+            let label = label.as_ref().trim();
+            if !label.is_empty() && !normalized.iter().any(|l| l == label) {
+                normalized.push(label.to_string());
+            }
         }
+        self.labels = normalized;
     }
 
     pub fn to_metadata(&self) -> WorkflowMetadata {
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// What one run of [`Store::maintenance`](crate::Store::maintenance) did.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct MaintenanceReport {
+    pub started_at: DateTime<Utc>,
+    pub completed_at: DateTime<Utc>,
+    /// Size of the database and its write-ahead log before maintenance.
+    pub bytes_before: u64,
+    pub bytes_after: u64,
+    /// A full `VACUUM` was needed to switch the database to incremental
+    /// vacuuming. Only happens once for databases created before that setting.
+    #[serde(default)]
+    pub full_vacuum: bool,
+}
+
+impl MaintenanceReport {
+    pub fn reclaimed_bytes(&self) -> u64 {
+        self.bytes_before.saturating_sub(self.bytes_after)
+    }
+}
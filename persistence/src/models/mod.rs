@@ -1,19 +1,55 @@
+pub mod archive;
 pub mod audit;
+pub mod blob;
+pub mod checkpoint;
 pub mod enums;
 pub mod execution;
+pub mod maintenance;
+pub mod notification;
+pub mod output_journal;
 pub mod prompt;
+pub mod provenance;
+pub mod resource_lock;
+pub mod schema;
 pub mod settings;
+pub mod snippet;
 pub mod task;
+pub mod task_cache;
+pub mod trash;
+pub mod ui_state;
 pub mod user_input_request;
 pub mod workflow;
+pub mod workflow_test;
 
-pub use audit::AuditEvent;
+pub use archive::ArchivedExecution;
+pub use audit::{AuditChange, AuditEvent};
+pub use blob::{BlobStats, BlobVacuumStats};
+pub use checkpoint::ExecutionCheckpoint;
 pub use enums::{
-    AuditStatus, InputRequestStatus, InputType, TaskExecutionStatus, Theme, WorkflowExecutionStatus,
+    AuditChangeOp, AuditStatus, DateStyle, DisplayTimeZone, InputRequestStatus, InputType,
+    LogLevel, MessageBroker, TaskExecutionStatus, TaskFailureReason, Theme,
+    WorkflowExecutionStatus,
 };
-pub use execution::{WorkflowExecution, WorkflowExecutionSummary, WorkflowMetadata};
-pub use prompt::Prompt;
-pub use settings::AppSettings;
-pub use task::TaskExecution;
+pub use execution::{
+    DailyExecutionStats, ExecutionFilter, ExecutionFilterPreset, ReproducibilityManifest,
+    RunBounds, WorkflowExecution, WorkflowExecutionSummary, WorkflowHealth, WorkflowMetadata,
+};
+pub use maintenance::MaintenanceReport;
+pub use notification::{Notification, NotificationKind};
+pub use output_journal::OutputChunk;
+pub use prompt::{Prompt, PromptFilter, PromptSort, PromptWithUsage};
+pub use provenance::WorkflowProvenance;
+pub use resource_lock::ResourceLock;
+pub use schema::{persisted_models, ColumnLayout, PersistedModel, TableLayout};
+pub use settings::{
+    AppSettings, DbConnectionSettings, HandlerPolicySettings, HandlerProfile, LogSettings,
+    MessageConnectionSettings, PollingSettings, TimestampSettings, WorkflowLimitSettings,
+};
+pub use snippet::{SnippetParameter, TaskSnippet};
+pub use task::{TaskAttempt, TaskExecution};
+pub use task_cache::CachedTaskResult;
+pub use trash::TrashKind;
+pub use ui_state::{UiState, ViewportState, WindowState};
 pub use user_input_request::UserInputRequest;
 pub use workflow::WorkflowDefinition;
+pub use workflow_test::{RecordedTaskResult, WorkflowTest};
@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// What a notification is about. Each kind can be muted in the settings.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    ExecutionCompleted,
+    ExecutionFailed,
+    /// A run started by another workflow's trigger failed.
+    TriggeredRunFailed,
+    /// A run paused until someone answers a prompt.
+    InputRequested,
+    /// A task matched one of its workflow's `notify` rules.
+    TaskAlert,
+}
+
+impl NotificationKind {
+    pub const ALL: [NotificationKind; 5] = [
+        NotificationKind::ExecutionCompleted,
+        NotificationKind::ExecutionFailed,
+        NotificationKind::TriggeredRunFailed,
+        NotificationKind::InputRequested,
+        NotificationKind::TaskAlert,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            NotificationKind::ExecutionCompleted => "Completed runs",
+            NotificationKind::ExecutionFailed => "Failed runs",
+            NotificationKind::TriggeredRunFailed => "Failed triggered runs",
+            NotificationKind::InputRequested => "Input requests",
+            NotificationKind::TaskAlert => "Task alerts",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Notification {
+    pub id: String,
+    pub kind: NotificationKind,
+    pub title: String,
+    pub message: String,
+    /// Execution the notification links to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_id: Option<String>,
+    #[serde(default)]
+    pub read: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Notification {
+    pub fn new(kind: NotificationKind, title: String, message: String) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            kind,
+            title,
+            message,
+            execution_id: None,
+            read: false,
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// One line of a run's output, saved as soon as it is written so the output
+/// of tasks still running when the process dies is not lost with it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct OutputChunk {
+    pub execution_id: String,
+    /// Position in the run's output stream. A resumed run numbers its output
+    /// from 0 again; chunks are read back in the order they were saved.
+    pub sequence: u64,
+    /// `None` for messages about the workflow itself.
+    pub task_id: Option<String>,
+    pub task_name: Option<String>,
+    pub line: String,
+    /// Set on the line a task ends with: whether it succeeded.
+    pub finished: Option<bool>,
+}
@@ -1,12 +1,16 @@
 use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct Prompt {
     pub id: String,
     pub name: String,
     pub content: String,
     pub created_at: DateTime<Utc>,
+    /// Lowercase labels used to filter the prompt list.
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl Default for Prompt {
@@ -17,6 +21,7 @@ impl Default for Prompt {
             name: String::new(),
             content: String::new(),
             created_at: now,
+            tags: Vec::new(),
         }
     }
 }
@@ -43,4 +48,69 @@ impl Prompt {
     pub fn update_name(&mut self, name: String) {
         self.name = name;
     }
+
+    /// Replace the tags, trimming and lowercasing them and dropping blanks and duplicates.
+    pub fn set_tags<I, S>(&mut self, tags: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut normalized: Vec<String> = Vec::new();
+        for tag in tags {
+            let tag = tag.as_ref().trim().to_lowercase();
+            if !tag.is_empty() && !normalized.contains(&tag) {
+                normalized.push(tag);
+            }
+        }
+        self.tags = normalized;
+    }
+}
+
+/// Order for [`PromptFilter`] results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub enum PromptSort {
+    #[default]
+    Name,
+    Newest,
+    /// Most recent task run that used the prompt first; unused prompts last.
+    RecentlyUsed,
+    /// Most task runs first; ties broken by name.
+    MostUsed,
+}
+
+impl PromptSort {
+    pub const ALL: [PromptSort; 4] = [
+        PromptSort::Name,
+        PromptSort::Newest,
+        PromptSort::RecentlyUsed,
+        PromptSort::MostUsed,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PromptSort::Name => "name",
+            PromptSort::Newest => "newest",
+            PromptSort::RecentlyUsed => "recently_used",
+            PromptSort::MostUsed => "most_used",
+        }
+    }
+}
+
+/// Narrowing and ordering for the prompt list.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct PromptFilter {
+    /// Case-insensitive text matched against name and content.
+    pub search: Option<String>,
+    /// Prompts must carry every one of these tags.
+    pub tags: Vec<String>,
+    pub sort: PromptSort,
+}
+
+/// A prompt with how often task runs have used it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PromptWithUsage {
+    pub prompt: Prompt,
+    /// Task executions linked to the prompt through `prompt_id`.
+    pub use_count: u64,
+    pub last_used_at: Option<DateTime<Utc>>,
 }
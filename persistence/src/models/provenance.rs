@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Where an imported workflow came from and what was verified when it arrived.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WorkflowProvenance {
+    pub workflow_id: String,
+    /// File name or other label of the bundle the workflow was imported from.
+    pub source: String,
+    /// `sha256:<hex>` of the workflow content as it was imported.
+    pub content_hash: String,
+    /// Hex public key whose signature was verified, if the bundle was signed.
+    #[serde(default)]
+    pub signed_by: Option<String>,
+    pub imported_at: DateTime<Utc>,
+    /// Content hash the user last confirmed they trust to run.
+    #[serde(default)]
+    pub trusted_hash: Option<String>,
+}
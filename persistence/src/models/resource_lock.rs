@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A task's ticket in the queue for a named resource. Tickets are served in
+/// order; the one at position 0 holds the resource.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ResourceLock {
+    pub ticket: i64,
+    pub resource: String,
+    pub execution_id: String,
+    pub task_id: String,
+    pub requested_at: DateTime<Utc>,
+    /// Last time the owner reported the ticket still in use.
+    pub heartbeat_at: DateTime<Utc>,
+    /// Tickets ahead of this one for the same resource.
+    pub position: usize,
+}
@@ -0,0 +1,97 @@
+use crate::models::{
+    AppSettings, ArchivedExecution, AuditEvent, CachedTaskResult, ExecutionCheckpoint,
+    ExecutionFilterPreset, MaintenanceReport, Notification, Prompt, TaskExecution, TaskSnippet,
+    UiState, UserInputRequest, WorkflowDefinition, WorkflowExecution, WorkflowProvenance,
+    WorkflowTest,
+};
+use crate::store::blob::{EXECUTION_BLOB_PATHS, TASK_BLOB_PATHS};
+use schemars::schema::RootSchema;
+use schemars::{schema_for, JsonSchema};
+use serde::{Deserialize, Serialize};
+
+/// A model the store keeps, and where.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PersistedModel {
+    pub name: &'static str,
+    pub table: &'static str,
+    /// Set for models kept in a single row of a shared table, such as
+    /// `app_settings` in `settings`.
+    pub row_id: Option<&'static str>,
+    /// JSON pointers (`*` for any key or index) whose large values are
+    /// stored in `blobs` and replaced by `{"$blob": "<id>"}`.
+    pub blob_paths: &'static [&'static str],
+    /// JSON schema of the `data` column.
+    pub schema: RootSchema,
+}
+
+fn model<T: JsonSchema>(
+    name: &'static str,
+    table: &'static str,
+    row_id: Option<&'static str>,
+    blob_paths: &'static [&'static str],
+) -> PersistedModel {
+    PersistedModel {
+        name,
+        table,
+        row_id,
+        blob_paths,
+        schema: schema_for!(T),
+    }
+}
+
+/// Every model the store writes as JSON, in table order. Tables with plain
+/// columns, such as `resource_locks`, are described by [`TableLayout`] only.
+pub fn persisted_models() -> Vec<PersistedModel> {
+    vec![
+        model::<WorkflowDefinition>("WorkflowDefinition", "workflows", None, &[]),
+        model::<WorkflowExecution>(
+            "WorkflowExecution",
+            "workflow_executions",
+            None,
+            EXECUTION_BLOB_PATHS,
+        ),
+        model::<TaskExecution>("TaskExecution", "task_executions", None, TASK_BLOB_PATHS),
+        model::<UserInputRequest>("UserInputRequest", "user_input_requests", None, &[]),
+        model::<Prompt>("Prompt", "prompts", None, &[]),
+        model::<AuditEvent>("AuditEvent", "audit_events", None, &[]),
+        model::<AppSettings>("AppSettings", "settings", Some("app_settings"), &[]),
+        model::<UiState>("UiState", "settings", Some("ui_state"), &[]),
+        model::<MaintenanceReport>(
+            "MaintenanceReport",
+            "settings",
+            Some("storage_maintenance"),
+            &[],
+        ),
+        model::<CachedTaskResult>("CachedTaskResult", "task_result_cache", None, &[]),
+        model::<ExecutionCheckpoint>("ExecutionCheckpoint", "execution_checkpoints", None, &[]),
+        model::<ArchivedExecution>("ArchivedExecution", "archived_executions", None, &[]),
+        model::<WorkflowProvenance>("WorkflowProvenance", "workflow_provenance", None, &[]),
+        model::<Notification>("Notification", "notifications", None, &[]),
+        model::<TaskSnippet>("TaskSnippet", "task_snippets", None, &[]),
+        model::<WorkflowTest>("WorkflowTest", "workflow_tests", None, &[]),
+        model::<ExecutionFilterPreset>(
+            "ExecutionFilterPreset",
+            "execution_filter_presets",
+            None,
+            &[],
+        ),
+    ]
+}
+
+/// One column of a database table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnLayout {
+    pub name: String,
+    pub sql_type: String,
+    pub not_null: bool,
+    pub primary_key: bool,
+}
+
+/// A database table as SQLite reports it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableLayout {
+    pub name: String,
+    pub columns: Vec<ColumnLayout>,
+    /// `CREATE INDEX` statements for the table.
+    pub indexes: Vec<String>,
+}
@@ -1,12 +1,184 @@
-use crate::models::Theme;
+use crate::models::{DateStyle, DisplayTimeZone, LogLevel, MessageBroker, NotificationKind, Theme};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 
-#[derive(Debug, Clone, PartialEq, Hash, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Hash, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct AppSettings {
     pub theme: Theme,
     pub auto_save: bool,
     pub notifications: bool,
     pub default_workflow: Option<String>,
+    #[serde(default)]
+    pub polling: PollingSettings,
+    #[serde(default)]
+    pub timestamps: TimestampSettings,
+    #[serde(default)]
+    pub workflow_limits: WorkflowLimitSettings,
+    /// The first-run choice about adding the example workflows has been made.
+    #[serde(default)]
+    pub onboarding_complete: bool,
+    /// Finished executions older than this many days are moved to archive files
+    /// at startup. `None` keeps every execution in the database.
+    #[serde(default)]
+    pub archive_after_days: Option<u32>,
+    /// Notification kinds that are not recorded. `notifications` turns them
+    /// all off at once.
+    #[serde(default)]
+    pub muted_notifications: BTreeSet<NotificationKind>,
+    /// Databases that `db_query` tasks can reach, by name.
+    #[serde(default)]
+    pub db_connections: Vec<DbConnectionSettings>,
+    /// Message brokers that `publish_message` tasks can reach, by name.
+    #[serde(default)]
+    pub message_connections: Vec<MessageConnectionSettings>,
+    /// Which task handlers may run on this machine.
+    #[serde(default)]
+    pub handler_policy: HandlerPolicySettings,
+    /// How much is logged, overall and per module.
+    #[serde(default)]
+    pub logging: LogSettings,
+}
+
+/// Log levels for the whole app and for single modules, such as
+/// `engine = debug` while everything else stays at `info`.
+#[derive(Debug, Clone, Default, PartialEq, Hash, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct LogSettings {
+    /// Level for everything without one of its own.
+    pub level: LogLevel,
+    /// Levels by module name (`engine`, `persistence`, `gui`, ...) or by
+    /// tracing target.
+    pub modules: BTreeMap<String, LogLevel>,
+}
+
+/// Named sets of task handlers that may or may not run, such as a
+/// "restricted" profile for shared machines, and the one in effect.
+#[derive(Debug, Clone, PartialEq, Hash, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct HandlerPolicySettings {
+    /// `None` lets every handler run.
+    pub active_profile: Option<String>,
+    pub profiles: Vec<HandlerProfile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Hash, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct HandlerProfile {
+    pub name: String,
+    /// Handlers that may run, by `function.name`. `None` allows every
+    /// handler that is not denied.
+    #[serde(default)]
+    pub allowed_handlers: Option<BTreeSet<String>>,
+    /// Handlers that never run, even when also allowed.
+    #[serde(default)]
+    pub denied_handlers: BTreeSet<String>,
+}
+
+impl Default for HandlerPolicySettings {
+    fn default() -> Self {
+        Self {
+            active_profile: None,
+            profiles: vec![HandlerProfile {
+                name: "restricted".to_string(),
+                allowed_handlers: None,
+                denied_handlers: ["cli_command", "cursor_agent", "docker"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+            }],
+        }
+    }
+}
+
+impl HandlerPolicySettings {
+    /// The profile in effect, if one is chosen and exists.
+    pub fn active(&self) -> Option<&HandlerProfile> {
+        let name = self.active_profile.as_deref()?;
+        self.profiles.iter().find(|profile| profile.name == name)
+    }
+}
+
+/// How often the GUI refreshes live data, in milliseconds. Zero turns polling off.
+#[derive(Debug, Clone, PartialEq, Hash, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct PollingSettings {
+    /// Execution lists and running workflows.
+    pub executions_ms: u64,
+    /// A single execution's detail page.
+    pub execution_details_ms: u64,
+    /// The executions calendar heatmap.
+    pub calendar_ms: u64,
+    /// Stop polling while the window is unfocused or minimized.
+    pub pause_when_unfocused: bool,
+}
+
+/// How the GUI displays timestamps.
+#[derive(Debug, Clone, Default, PartialEq, Hash, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct TimestampSettings {
+    pub time_zone: DisplayTimeZone,
+    pub date_style: DateStyle,
+}
+
+/// Largest workflow definition accepted when validating or parsing.
+#[derive(Debug, Clone, PartialEq, Hash, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct WorkflowLimitSettings {
+    pub max_tasks: usize,
+    /// Longest chain of `next_tasks`, root included.
+    pub max_depth: usize,
+    /// Most `next_tasks` on a single task.
+    pub max_fan_out: usize,
+    pub max_parameters: usize,
+    pub max_document_kb: usize,
+}
+
+/// A named database connection. The password is never saved: `password_env`
+/// names the environment variable it is read from when a query runs.
+#[derive(Debug, Clone, Default, PartialEq, Hash, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct DbConnectionSettings {
+    pub name: String,
+    pub url: String,
+    pub password_env: Option<String>,
+    /// Statement keywords allowed, e.g. `select`. Empty allows anything.
+    pub allowed_statements: Vec<String>,
+    pub max_rows: Option<usize>,
+}
+
+/// A named message broker connection. As with databases, only the name of the
+/// environment variable holding the password is saved.
+#[derive(Debug, Clone, Default, PartialEq, Hash, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct MessageConnectionSettings {
+    pub name: String,
+    pub broker: MessageBroker,
+    /// Broker URL, or the bootstrap servers for Kafka.
+    pub url: String,
+    pub password_env: Option<String>,
+}
+
+impl Default for WorkflowLimitSettings {
+    fn default() -> Self {
+        Self {
+            max_tasks: 1000,
+            max_depth: 30,
+            max_fan_out: 100,
+            max_parameters: 100,
+            max_document_kb: 1024,
+        }
+    }
+}
+
+impl Default for PollingSettings {
+    fn default() -> Self {
+        Self {
+            executions_ms: 1000,
+            execution_details_ms: 2000,
+            calendar_ms: 30_000,
+            pause_when_unfocused: true,
+        }
+    }
 }
 
 impl Default for AppSettings {
@@ -16,13 +188,36 @@ impl Default for AppSettings {
             auto_save: true,
             notifications: true,
             default_workflow: None,
+            polling: PollingSettings::default(),
+            timestamps: TimestampSettings::default(),
+            workflow_limits: WorkflowLimitSettings::default(),
+            onboarding_complete: false,
+            archive_after_days: None,
+            muted_notifications: BTreeSet::new(),
+            db_connections: Vec::new(),
+            message_connections: Vec::new(),
+            handler_policy: HandlerPolicySettings::default(),
+            logging: LogSettings::default(),
         }
     }
 }
 
 impl AppSettings {
     pub fn validate(&self) -> Result<(), String> {
-        Ok(())
+        check_connections(
+            "Database",
+            self.db_connections
+                .iter()
+                .map(|c| (c.name.as_str(), c.url.as_str())),
+        )?;
+        check_connections(
+            "Message",
+            self.message_connections
+                .iter()
+                .map(|c| (c.name.as_str(), c.url.as_str())),
+        )?;
+        check_handler_policy(&self.handler_policy)?;
+        check_log_settings(&self.logging)
     }
 
     pub fn set_theme(&mut self, theme: Theme) {
@@ -37,7 +232,109 @@ impl AppSettings {
         self.notifications = notifications;
     }
 
+    /// Whether notifications of `kind` should be recorded.
+    pub fn notifies(&self, kind: NotificationKind) -> bool {
+        self.notifications && !self.muted_notifications.contains(&kind)
+    }
+
+    pub fn set_polling(&mut self, polling: PollingSettings) {
+        self.polling = polling;
+    }
+
+    pub fn set_timestamps(&mut self, timestamps: TimestampSettings) {
+        self.timestamps = timestamps;
+    }
+
+    pub fn set_workflow_limits(&mut self, workflow_limits: WorkflowLimitSettings) {
+        self.workflow_limits = workflow_limits;
+    }
+
+    pub fn set_onboarding_complete(&mut self, onboarding_complete: bool) {
+        self.onboarding_complete = onboarding_complete;
+    }
+
+    pub fn set_db_connections(&mut self, db_connections: Vec<DbConnectionSettings>) {
+        self.db_connections = db_connections;
+    }
+
+    pub fn set_message_connections(&mut self, message_connections: Vec<MessageConnectionSettings>) {
+        self.message_connections = message_connections;
+    }
+
+    pub fn set_handler_policy(&mut self, handler_policy: HandlerPolicySettings) {
+        self.handler_policy = handler_policy;
+    }
+
+    pub fn set_logging(&mut self, logging: LogSettings) {
+        self.logging = logging;
+    }
+
     pub fn set_default_workflow(&mut self, workflow_id: Option<String>) {
         self.default_workflow = workflow_id;
     }
 }
+
+/// Every connection has a name and a URL, and no two share a name.
+fn check_connections<'a>(
+    kind: &str,
+    connections: impl Iterator<Item = (&'a str, &'a str)>,
+) -> Result<(), String> {
+    let mut names = std::collections::HashSet::new();
+    for (name, url) in connections {
+        if name.trim().is_empty() {
+            return Err(format!("{} connection name cannot be empty", kind));
+        }
+        if url.trim().is_empty() {
+            return Err(format!("{} connection '{}' has no URL", kind, name));
+        }
+        if !names.insert(name) {
+            return Err(format!(
+                "{} connection '{}' is defined more than once",
+                kind, name
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Profiles have distinct, non-empty names, and the active one exists.
+fn check_handler_policy(policy: &HandlerPolicySettings) -> Result<(), String> {
+    let mut names = std::collections::HashSet::new();
+    for profile in &policy.profiles {
+        if profile.name.trim().is_empty() {
+            return Err("Handler profile name cannot be empty".to_string());
+        }
+        if !names.insert(profile.name.as_str()) {
+            return Err(format!(
+                "Handler profile '{}' is defined more than once",
+                profile.name
+            ));
+        }
+    }
+    match policy.active_profile.as_deref() {
+        Some(active) if !names.contains(active) => Err(format!(
+            "Active handler profile '{}' does not exist",
+            active
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Module names are usable as tracing targets.
+fn check_log_settings(logging: &LogSettings) -> Result<(), String> {
+    for module in logging.modules.keys() {
+        if module.trim().is_empty() {
+            return Err("Log module name cannot be empty".to_string());
+        }
+        if !module
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+        {
+            return Err(format!(
+                "Log module '{}' may only contain letters, digits, '_' and ':'",
+                module
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A reusable task fragment. Workflows reference it from a task with
+/// `"snippet": "<id>"` and fill its parameters with `"with"`; the reference
+/// is expanded when the workflow runs, so editing the snippet updates every
+/// workflow using it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TaskSnippet {
+    pub id: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub parameters: Vec<SnippetParameter>,
+    /// Task fields other than `id` and `next_tasks`, usually `name` and
+    /// `function`. Strings may contain `{{snippet.NAME}}` placeholders.
+    pub task: Value,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A value a workflow passes to a snippet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SnippetParameter {
+    pub name: String,
+    /// Used when the reference leaves the parameter out. Without one the
+    /// parameter is required.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Default for TaskSnippet {
+    fn default() -> Self {
+        let now = Utc::now();
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: String::new(),
+            description: None,
+            parameters: Vec::new(),
+            task: Value::Object(Default::default()),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+impl TaskSnippet {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("Snippet ID cannot be empty".to_string());
+        }
+        if self.name.trim().is_empty() {
+            return Err("Snippet name cannot be empty".to_string());
+        }
+        let Some(task) = self.task.as_object() else {
+            return Err("Snippet task must be a JSON object".to_string());
+        };
+        if !task.contains_key("function") {
+            return Err("Snippet task needs a function".to_string());
+        }
+        for field in ["id", "next_tasks", "snippet"] {
+            if task.contains_key(field) {
+                return Err(format!(
+                    "Snippet task cannot set '{}'; the referencing task does",
+                    field
+                ));
+            }
+        }
+
+        let mut names = std::collections::HashSet::new();
+        for parameter in &self.parameters {
+            if parameter.name.trim().is_empty() {
+                return Err("Snippet parameter names cannot be empty".to_string());
+            }
+            if !names.insert(parameter.name.as_str()) {
+                return Err(format!("Duplicate snippet parameter '{}'", parameter.name));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn parameter(&self, name: &str) -> Option<&SnippetParameter> {
+        self.parameters.iter().find(|p| p.name == name)
+    }
+}
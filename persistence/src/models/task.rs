@@ -1,8 +1,10 @@
-use crate::models::TaskExecutionStatus;
+use crate::models::{TaskExecutionStatus, TaskFailureReason};
 use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct TaskExecution {
     pub id: String,
     pub workflow_id: String,
@@ -15,6 +17,37 @@ pub struct TaskExecution {
     pub user_input: Option<String>,
     pub input_request_id: Option<String>,
     pub prompt_id: Option<String>,
+    #[serde(default)]
+    pub failure_reason: Option<TaskFailureReason>,
+    /// Bytes the task wrote, including any that were elided from `output`.
+    #[serde(default)]
+    pub output_bytes: Option<u64>,
+    /// `output` keeps only the head and tail of what the task wrote.
+    #[serde(default)]
+    pub output_truncated: bool,
+    /// The result was reused from the task result cache instead of running the task.
+    #[serde(default)]
+    pub cache_hit: bool,
+    /// Environment variables the task's process started with. Values of
+    /// credential-like variables are redacted before they are stored.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub environment: BTreeMap<String, String>,
+    /// Earlier runs of the task within this execution, oldest first. The
+    /// fields above always describe the latest run.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attempts: Vec<TaskAttempt>,
+}
+
+/// A run of a task that a manual re-run has since replaced.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TaskAttempt {
+    pub status: TaskExecutionStatus,
+    pub output: Option<String>,
+    pub error: Option<String>,
+    #[serde(default)]
+    pub failure_reason: Option<TaskFailureReason>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
 }
 
 impl Default for TaskExecution {
@@ -32,6 +65,12 @@ impl Default for TaskExecution {
             user_input: None,
             input_request_id: None,
             prompt_id: None,
+            failure_reason: None,
+            output_bytes: None,
+            output_truncated: false,
+            cache_hit: false,
+            environment: BTreeMap::new(),
+            attempts: Vec::new(),
         }
     }
 }
@@ -49,7 +88,9 @@ impl TaskExecution {
         }
 
         match self.status {
-            TaskExecutionStatus::Complete | TaskExecutionStatus::Failed => {
+            TaskExecutionStatus::Complete
+            | TaskExecutionStatus::Failed
+            | TaskExecutionStatus::Skipped => {
                 if self.completed_at.is_none() {
                     return Err("Completed tasks must have completion timestamp".to_string());
                 }
@@ -68,7 +109,9 @@ impl TaskExecution {
     pub fn is_finished(&self) -> bool {
         matches!(
             self.status,
-            TaskExecutionStatus::Complete | TaskExecutionStatus::Failed
+            TaskExecutionStatus::Complete
+                | TaskExecutionStatus::Failed
+                | TaskExecutionStatus::Skipped
         )
     }
 
@@ -83,4 +126,29 @@ impl TaskExecution {
     pub fn get_input_request_id(&self) -> Option<&str> {
         self.input_request_id.as_deref()
     }
+
+    /// Take on the result of `rerun`, keeping the current result as an attempt.
+    pub fn record_rerun(&mut self, rerun: TaskExecution) {
+        let mut attempts = std::mem::take(&mut self.attempts);
+        attempts.push(TaskAttempt {
+            status: self.status.clone(),
+            output: self.output.take(),
+            error: self.error.take(),
+            failure_reason: self.failure_reason,
+            created_at: self.created_at,
+            completed_at: self.completed_at,
+        });
+        *self = TaskExecution {
+            id: std::mem::take(&mut self.id),
+            workflow_id: std::mem::take(&mut self.workflow_id),
+            prompt_id: self.prompt_id.take(),
+            attempts,
+            ..rerun
+        };
+    }
+
+    /// Which run of the task the fields describe, counting from 1.
+    pub fn attempt_number(&self) -> usize {
+        self.attempts.len() + 1
+    }
 }
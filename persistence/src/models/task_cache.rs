@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A stored task result, reused by later runs of a task with the same cache key
+/// until `expires_at`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CachedTaskResult {
+    pub key: String,
+    pub result: Value,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl CachedTaskResult {
+    pub fn new(key: String, result: Value, ttl: chrono::Duration) -> Self {
+        let now = Utc::now();
+        Self {
+            key,
+            result,
+            created_at: now,
+            expires_at: now + ttl,
+        }
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+}
@@ -0,0 +1,36 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Library items that are moved to the trash instead of being deleted
+/// outright, so a deletion can be undone for a short while.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TrashKind {
+    Workflow,
+    Prompt,
+}
+
+impl TrashKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrashKind::Workflow => "workflow",
+            TrashKind::Prompt => "prompt",
+        }
+    }
+
+    pub(crate) fn from_name(kind: &str) -> Option<Self> {
+        match kind {
+            "workflow" => Some(TrashKind::Workflow),
+            "prompt" => Some(TrashKind::Prompt),
+            _ => None,
+        }
+    }
+
+    /// Table the items live in while not trashed.
+    pub(crate) fn table(&self) -> &'static str {
+        match self {
+            TrashKind::Workflow => "workflows",
+            TrashKind::Prompt => "prompts",
+        }
+    }
+}
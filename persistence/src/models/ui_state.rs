@@ -0,0 +1,42 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Layout and navigation state the GUI restores on the next launch. Unlike
+/// `AppSettings` it is written as the user moves around, not from a form.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct UiState {
+    pub window: Option<WindowState>,
+    pub sidebar_collapsed: bool,
+    /// Path of the last page shown, such as `/executions/list`.
+    pub last_route: Option<String>,
+    /// Filter selections keyed by page, in whatever shape that page uses.
+    pub filters: BTreeMap<String, Value>,
+    /// Split pane divider positions keyed by pane, as the first pane's share
+    /// of the width.
+    pub panes: BTreeMap<String, f64>,
+    /// Where the visual editor was panned and zoomed to, keyed by workflow id.
+    pub viewports: BTreeMap<String, ViewportState>,
+    pub hide_minimap: bool,
+}
+
+/// Main window geometry in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct WindowState {
+    pub width: u32,
+    pub height: u32,
+    pub x: i32,
+    pub y: i32,
+    #[serde(default)]
+    pub maximized: bool,
+}
+
+/// Pan offset and zoom of the visual editor's canvas.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ViewportState {
+    pub x: f64,
+    pub y: f64,
+    pub zoom: f64,
+}
@@ -1,9 +1,10 @@
 use crate::models::enums::{InputRequestStatus, InputType};
 use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct UserInputRequest {
     pub id: String,
     pub task_execution_id: String,
@@ -40,6 +41,29 @@ impl Default for UserInputRequest {
 }
 
 impl UserInputRequest {
+    /// A request standing in for a `manual` task: the instructions are the
+    /// prompt, and it is answered with a done/skip confirmation.
+    pub fn manual(
+        task_execution_id: &str,
+        workflow_execution_id: &str,
+        instructions: &str,
+    ) -> Self {
+        Self {
+            task_execution_id: task_execution_id.to_string(),
+            workflow_execution_id: workflow_execution_id.to_string(),
+            prompt_text: instructions.to_string(),
+            validation_rules: serde_json::json!({ "manual": true }),
+            ..Self::default()
+        }
+    }
+
+    pub fn is_manual(&self) -> bool {
+        self.validation_rules
+            .get("manual")
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+    }
+
     pub fn validate(&self) -> Result<(), String> {
         if self.id.is_empty() {
             return Err("Request ID cannot be empty".to_string());
@@ -0,0 +1,59 @@
+use crate::models::TaskFailureReason;
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// A past run of a workflow kept as a regression test. Running it replays the
+/// recorded task results in place of the handlers and checks that the
+/// workflow, as it is now, still ends with the same context.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WorkflowTest {
+    pub id: String,
+    pub workflow_id: String,
+    pub name: String,
+    /// Execution the results were recorded from.
+    pub source_execution_id: String,
+    /// Parameter values the recorded run was started with.
+    #[serde(default)]
+    pub parameters: HashMap<String, Value>,
+    /// What each task returned in the recorded run, by task id. A task
+    /// without a result fails when the test runs.
+    pub task_results: BTreeMap<String, RecordedTaskResult>,
+    /// Context the recorded run finished with.
+    pub expected_context: Value,
+    /// Whether the recorded run succeeded.
+    pub expected_success: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A task's result as a test replays it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RecordedTaskResult {
+    pub success: bool,
+    #[serde(default)]
+    pub output: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<TaskFailureReason>,
+}
+
+impl WorkflowTest {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("Test ID cannot be empty".to_string());
+        }
+        if self.workflow_id.trim().is_empty() {
+            return Err("Test workflow ID cannot be empty".to_string());
+        }
+        if self.name.trim().is_empty() {
+            return Err("Test name cannot be empty".to_string());
+        }
+        if !self.expected_context.is_object() {
+            return Err("Test expected context must be a JSON object".to_string());
+        }
+        Ok(())
+    }
+}
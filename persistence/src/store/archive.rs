@@ -0,0 +1,170 @@
+use super::Store;
+use crate::logging::{
+    log_db_operation_error, log_db_operation_start, log_db_operation_success, log_deserialization,
+    log_serialization,
+};
+use crate::models::ArchivedExecution;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+
+impl Store {
+    /// Ids of complete or failed executions created before `before`, oldest first.
+    ///
+    /// Pending, running and waiting executions are never returned, however old.
+    pub async fn list_archivable_execution_ids(
+        &self,
+        before: DateTime<Utc>,
+    ) -> Result<Vec<String>, String> {
+        log_db_operation_start("list_archivable_execution_ids", "workflow_executions");
+
+        let ids = sqlx::query_scalar(
+            "SELECT id FROM workflow_executions \
+             WHERE json_extract(data, '$.status') IN ('complete', 'failed') \
+               AND julianday(json_extract(data, '$.created_at')) < julianday(?) \
+             ORDER BY json_extract(data, '$.created_at')",
+        )
+        .bind(before.to_rfc3339())
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error(
+                "list_archivable_execution_ids",
+                "workflow_executions",
+                &e.to_string(),
+            );
+            format!("Database error: {}", e)
+        })?;
+
+        log_db_operation_success("list_archivable_execution_ids", "workflow_executions", 0);
+        Ok(ids)
+    }
+
+    pub async fn save_archived_execution(&self, entry: &ArchivedExecution) -> Result<(), String> {
+        log_db_operation_start("save_archived_execution", "archived_executions");
+
+        let json_data = serde_json::to_string(entry).map_err(|e| {
+            log_db_operation_error(
+                "save_archived_execution",
+                "archived_executions",
+                &e.to_string(),
+            );
+            format!("Serialization error: {}", e)
+        })?;
+
+        log_serialization("ArchivedExecution", json_data.len());
+
+        sqlx::query("INSERT OR REPLACE INTO archived_executions (id, data) VALUES (?, ?)")
+            .bind(&entry.id)
+            .bind(&json_data)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error(
+                    "save_archived_execution",
+                    "archived_executions",
+                    &e.to_string(),
+                );
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success("save_archived_execution", "archived_executions", 0);
+        Ok(())
+    }
+
+    pub async fn get_archived_execution(
+        &self,
+        id: &str,
+    ) -> Result<Option<ArchivedExecution>, String> {
+        log_db_operation_start("get_archived_execution", "archived_executions");
+
+        let row = sqlx::query("SELECT data FROM archived_executions WHERE id = ?")
+            .bind(id)
+            .fetch_optional(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error(
+                    "get_archived_execution",
+                    "archived_executions",
+                    &e.to_string(),
+                );
+                format!("Database error: {}", e)
+            })?;
+
+        let entry = match row {
+            Some(row) => {
+                let json_data: String = row.get("data");
+                log_deserialization("ArchivedExecution", json_data.len());
+                Some(serde_json::from_str(&json_data).map_err(|e| {
+                    log_db_operation_error(
+                        "get_archived_execution",
+                        "archived_executions",
+                        &e.to_string(),
+                    );
+                    format!("Deserialization error: {}", e)
+                })?)
+            }
+            None => None,
+        };
+
+        log_db_operation_success("get_archived_execution", "archived_executions", 0);
+        Ok(entry)
+    }
+
+    /// Every archived execution, newest run first.
+    pub async fn list_archived_executions(&self) -> Result<Vec<ArchivedExecution>, String> {
+        log_db_operation_start("list_archived_executions", "archived_executions");
+
+        let rows = sqlx::query(
+            "SELECT data FROM archived_executions ORDER BY json_extract(data, '$.created_at') DESC",
+        )
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error(
+                "list_archived_executions",
+                "archived_executions",
+                &e.to_string(),
+            );
+            format!("Database error: {}", e)
+        })?;
+
+        let mut entries = Vec::new();
+        for row in rows {
+            let json_data: String = row.get("data");
+            log_deserialization("ArchivedExecution", json_data.len());
+
+            let entry = serde_json::from_str(&json_data).map_err(|e| {
+                log_db_operation_error(
+                    "list_archived_executions",
+                    "archived_executions",
+                    &e.to_string(),
+                );
+                format!("Deserialization error: {}", e)
+            })?;
+            entries.push(entry);
+        }
+
+        log_db_operation_success("list_archived_executions", "archived_executions", 0);
+        Ok(entries)
+    }
+
+    pub async fn delete_archived_execution(&self, id: &str) -> Result<(), String> {
+        log_db_operation_start("delete_archived_execution", "archived_executions");
+
+        sqlx::query("DELETE FROM archived_executions WHERE id = ?")
+            .bind(id)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error(
+                    "delete_archived_execution",
+                    "archived_executions",
+                    &e.to_string(),
+                );
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success("delete_archived_execution", "archived_executions", 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,246 @@
+//! Content-addressed storage for large execution payloads.
+//!
+//! Values at known paths that serialize to at least [`BLOB_THRESHOLD_BYTES`] are
+//! stored once in `blobs`, keyed by the SHA-256 of their JSON, and the row keeps
+//! a `{"$blob": "<hash>"}` reference instead. `blob_refs` records which row points
+//! at which blob, so a blob's reference count is its number of `blob_refs` rows.
+
+use super::Store;
+use crate::logging::{
+    log_db_operation_error, log_db_operation_start, log_db_operation_success, log_deserialization,
+};
+use crate::models::{BlobStats, BlobVacuumStats};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use sqlx::{Row, SqliteConnection};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+/// Payloads whose JSON is at least this many bytes are deduplicated.
+pub const BLOB_THRESHOLD_BYTES: usize = 4096;
+
+const BLOB_REF_KEY: &str = "$blob";
+
+pub(crate) const EXECUTION_BLOB_PATHS: &[&str] = &[
+    "/workflow_snapshot",
+    "/per_task_logs/*",
+    "/tasks/*/output",
+    "/tasks/*/attempts/*/output",
+];
+pub(crate) const TASK_BLOB_PATHS: &[&str] = &["/output", "/attempts/*/output"];
+
+pub(crate) struct Blob {
+    id: String,
+    data: String,
+}
+
+/// Moves the large values at `paths` out of `value`, leaving references behind.
+pub(crate) fn externalize(value: &mut Value, paths: &[&str]) -> Vec<Blob> {
+    let mut blobs = Vec::new();
+    for path in paths {
+        for_each_at_path(value, &segments(path), &mut |v| {
+            let data = v.to_string();
+            if data.len() < BLOB_THRESHOLD_BYTES {
+                return;
+            }
+            let id = content_hash(&data);
+            *v = serde_json::json!({ BLOB_REF_KEY: id });
+            blobs.push(Blob { id, data });
+        });
+    }
+    blobs
+}
+
+/// Points `owner` at exactly `blobs`, storing any that are new. Run inside the
+/// transaction that writes the owning row.
+pub(crate) async fn replace_blob_refs(
+    conn: &mut SqliteConnection,
+    owner: &str,
+    blobs: &[Blob],
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM blob_refs WHERE owner = ?")
+        .bind(owner)
+        .execute(&mut *conn)
+        .await?;
+    for blob in blobs {
+        sqlx::query("INSERT OR IGNORE INTO blobs (id, data) VALUES (?, ?)")
+            .bind(&blob.id)
+            .bind(&blob.data)
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query("INSERT INTO blob_refs (owner, blob_id) VALUES (?, ?)")
+            .bind(owner)
+            .bind(&blob.id)
+            .execute(&mut *conn)
+            .await?;
+    }
+    Ok(())
+}
+
+pub(crate) fn execution_owner(id: &str) -> String {
+    format!("workflow_executions:{}", id)
+}
+
+pub(crate) fn task_owner(id: &str) -> String {
+    format!("task_executions:{}", id)
+}
+
+fn segments(path: &str) -> Vec<&str> {
+    path.trim_start_matches('/').split('/').collect()
+}
+
+/// Calls `f` on every value at `segments`, where `*` matches each child.
+fn for_each_at_path(value: &mut Value, segments: &[&str], f: &mut impl FnMut(&mut Value)) {
+    let Some((first, rest)) = segments.split_first() else {
+        f(value);
+        return;
+    };
+    match (*first, value) {
+        ("*", Value::Object(map)) => {
+            for child in map.values_mut() {
+                for_each_at_path(child, rest, f);
+            }
+        }
+        ("*", Value::Array(items)) => {
+            for child in items {
+                for_each_at_path(child, rest, f);
+            }
+        }
+        (key, Value::Object(map)) => {
+            if let Some(child) = map.get_mut(key) {
+                for_each_at_path(child, rest, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn blob_ref(value: &Value) -> Option<&str> {
+    match value {
+        Value::Object(map) if map.len() == 1 => map.get(BLOB_REF_KEY)?.as_str(),
+        _ => None,
+    }
+}
+
+fn content_hash(data: &str) -> String {
+    Sha256::digest(data.as_bytes())
+        .iter()
+        .fold(String::with_capacity(64), |mut hash, byte| {
+            let _ = write!(hash, "{:02x}", byte);
+            hash
+        })
+}
+
+impl Store {
+    /// Parse a stored row, swapping blob references at `paths` back for their content.
+    pub(crate) async fn decode_with_blobs<T: DeserializeOwned>(
+        &self,
+        json_data: &str,
+        paths: &[&str],
+    ) -> Result<T, String> {
+        let mut value: Value =
+            serde_json::from_str(json_data).map_err(|e| format!("Deserialization error: {}", e))?;
+
+        let mut ids = HashSet::new();
+        for path in paths {
+            for_each_at_path(&mut value, &segments(path), &mut |v| {
+                if let Some(id) = blob_ref(v) {
+                    ids.insert(id.to_string());
+                }
+            });
+        }
+
+        if !ids.is_empty() {
+            let contents = self.load_blobs(&ids).await?;
+            for path in paths {
+                for_each_at_path(&mut value, &segments(path), &mut |v| {
+                    let content = blob_ref(v).and_then(|id| contents.get(id)).cloned();
+                    if let Some(content) = content {
+                        *v = content;
+                    }
+                });
+            }
+        }
+
+        serde_json::from_value(value).map_err(|e| format!("Deserialization error: {}", e))
+    }
+
+    async fn load_blobs(&self, ids: &HashSet<String>) -> Result<HashMap<String, Value>, String> {
+        let placeholders = vec!["?"; ids.len()].join(", ");
+        let sql = format!("SELECT id, data FROM blobs WHERE id IN ({})", placeholders);
+        let mut query = sqlx::query(&sql);
+        for id in ids {
+            query = query.bind(id);
+        }
+        let rows = query
+            .fetch_all(self.pool())
+            .await
+            .map_err(|e| format!("Database error: {}", e))?;
+
+        let mut contents = HashMap::with_capacity(rows.len());
+        for row in rows {
+            let id: String = row.get("id");
+            let data: String = row.get("data");
+            log_deserialization("Blob", data.len());
+            let value =
+                serde_json::from_str(&data).map_err(|e| format!("Deserialization error: {}", e))?;
+            contents.insert(id, value);
+        }
+        Ok(contents)
+    }
+
+    pub async fn blob_stats(&self) -> Result<BlobStats, String> {
+        log_db_operation_start("blob_stats", "blobs");
+
+        let row = sqlx::query(
+            "SELECT (SELECT COUNT(*) FROM blobs) AS blobs, \
+                    (SELECT COALESCE(SUM(length(data)), 0) FROM blobs) AS bytes, \
+                    (SELECT COUNT(*) FROM blob_refs) AS refs",
+        )
+        .fetch_one(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error("blob_stats", "blobs", &e.to_string());
+            format!("Database error: {}", e)
+        })?;
+
+        let blobs: i64 = row.get("blobs");
+        let bytes: i64 = row.get("bytes");
+        let references: i64 = row.get("refs");
+
+        log_db_operation_success("blob_stats", "blobs", 0);
+        Ok(BlobStats {
+            blobs: blobs as u64,
+            bytes: bytes as u64,
+            references: references as u64,
+        })
+    }
+
+    /// Delete blobs nothing refers to any more, such as output of deleted executions.
+    pub async fn vacuum_blobs(&self) -> Result<BlobVacuumStats, String> {
+        log_db_operation_start("vacuum_blobs", "blobs");
+
+        let rows = sqlx::query(
+            "DELETE FROM blobs WHERE id NOT IN (SELECT blob_id FROM blob_refs) \
+             RETURNING length(data) AS size",
+        )
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error("vacuum_blobs", "blobs", &e.to_string());
+            format!("Database error: {}", e)
+        })?;
+
+        let stats = BlobVacuumStats {
+            removed: rows.len() as u64,
+            bytes_reclaimed: rows
+                .iter()
+                .map(|row| row.get::<i64, _>("size") as u64)
+                .sum(),
+        };
+
+        log_db_operation_success("vacuum_blobs", "blobs", 0);
+        Ok(stats)
+    }
+}
@@ -0,0 +1,105 @@
+use super::Store;
+use crate::logging::{
+    log_db_operation_error, log_db_operation_start, log_db_operation_success, log_deserialization,
+    log_serialization,
+};
+use crate::models::ExecutionCheckpoint;
+use sqlx::Row;
+
+impl Store {
+    /// Save `checkpoint`, replacing the one saved earlier for its execution.
+    pub async fn save_execution_checkpoint(
+        &self,
+        checkpoint: &ExecutionCheckpoint,
+    ) -> Result<(), String> {
+        log_db_operation_start("save_execution_checkpoint", "execution_checkpoints");
+
+        let json_data = serde_json::to_string(checkpoint).map_err(|e| {
+            log_db_operation_error(
+                "save_execution_checkpoint",
+                "execution_checkpoints",
+                &e.to_string(),
+            );
+            format!("Serialization error: {}", e)
+        })?;
+
+        log_serialization("ExecutionCheckpoint", json_data.len());
+
+        sqlx::query("INSERT OR REPLACE INTO execution_checkpoints (id, data) VALUES (?, ?)")
+            .bind(&checkpoint.execution_id)
+            .bind(&json_data)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error(
+                    "save_execution_checkpoint",
+                    "execution_checkpoints",
+                    &e.to_string(),
+                );
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success("save_execution_checkpoint", "execution_checkpoints", 0);
+        Ok(())
+    }
+
+    pub async fn get_execution_checkpoint(
+        &self,
+        execution_id: &str,
+    ) -> Result<Option<ExecutionCheckpoint>, String> {
+        log_db_operation_start("get_execution_checkpoint", "execution_checkpoints");
+
+        let row = sqlx::query("SELECT data FROM execution_checkpoints WHERE id = ?")
+            .bind(execution_id)
+            .fetch_optional(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error(
+                    "get_execution_checkpoint",
+                    "execution_checkpoints",
+                    &e.to_string(),
+                );
+                format!("Database error: {}", e)
+            })?;
+
+        let Some(row) = row else {
+            log_db_operation_success("get_execution_checkpoint", "execution_checkpoints", 0);
+            return Ok(None);
+        };
+
+        let json_data: String = row.get("data");
+        log_deserialization("ExecutionCheckpoint", json_data.len());
+        let checkpoint = serde_json::from_str(&json_data).map_err(|e| {
+            log_db_operation_error(
+                "get_execution_checkpoint",
+                "execution_checkpoints",
+                &e.to_string(),
+            );
+            format!("Deserialization error: {}", e)
+        })?;
+
+        log_db_operation_success("get_execution_checkpoint", "execution_checkpoints", 0);
+        Ok(Some(checkpoint))
+    }
+
+    /// Drop the execution's checkpoint, if it has one.
+    pub async fn delete_execution_checkpoint(&self, execution_id: &str) -> Result<(), String> {
+        log_db_operation_start("delete_execution_checkpoint", "execution_checkpoints");
+
+        sqlx::query("DELETE FROM execution_checkpoints WHERE id = ?")
+            .bind(execution_id)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error(
+                    "delete_execution_checkpoint",
+                    "execution_checkpoints",
+                    &e.to_string(),
+                );
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success("delete_execution_checkpoint", "execution_checkpoints", 0);
+        Ok(())
+    }
+}
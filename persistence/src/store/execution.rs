@@ -1,11 +1,26 @@
+use super::blob::{
+    execution_owner, externalize, replace_blob_refs, task_owner, EXECUTION_BLOB_PATHS,
+};
 use super::Store;
 use crate::logging::{
     log_db_operation_error, log_db_operation_start, log_db_operation_success, log_deserialization,
     log_serialization,
 };
-use crate::models::{TaskExecution, WorkflowExecution, WorkflowMetadata};
+use crate::models::{
+    DailyExecutionStats, ExecutionFilter, WorkflowExecution, WorkflowExecutionStatus,
+    WorkflowExecutionSummary, WorkflowHealth, WorkflowMetadata,
+};
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
 use sqlx::Row;
 
+/// `WHERE` clause applying an [`ExecutionFilter`] bound as `?1` (status), `?2`
+/// (label) and `?3` (workflow id).
+const EXECUTION_FILTER_SQL: &str = "WHERE (?1 IS NULL OR json_extract(data, '$.status') = ?1) \
+     AND (?2 IS NULL OR EXISTS ( \
+         SELECT 1 FROM json_each(workflow_executions.data, '$.labels') AS label \
+         WHERE label.value = ?2)) \
+     AND (?3 IS NULL OR json_extract(data, '$.workflow_id') = ?3)";
+
 impl Store {
     pub async fn save_workflow_execution(
         &self,
@@ -13,7 +28,7 @@ impl Store {
     ) -> Result<(), String> {
         log_db_operation_start("save_workflow_execution", "workflow_executions");
 
-        let json_data = serde_json::to_string(&execution).map_err(|e| {
+        let mut value = serde_json::to_value(&execution).map_err(|e| {
             log_db_operation_error(
                 "save_workflow_execution",
                 "workflow_executions",
@@ -21,22 +36,30 @@ impl Store {
             );
             format!("Serialization error: {}", e)
         })?;
+        let blobs = externalize(&mut value, EXECUTION_BLOB_PATHS);
+        let json_data = value.to_string();
 
         log_serialization("WorkflowExecution", json_data.len());
 
+        let db_error = |e: sqlx::Error| {
+            log_db_operation_error(
+                "save_workflow_execution",
+                "workflow_executions",
+                &e.to_string(),
+            );
+            format!("Database error: {}", e)
+        };
+        let mut tx = self.pool().begin().await.map_err(db_error)?;
+        replace_blob_refs(&mut tx, &execution_owner(&execution.id), &blobs)
+            .await
+            .map_err(db_error)?;
         sqlx::query("INSERT OR REPLACE INTO workflow_executions (id, data) VALUES (?, ?)")
             .bind(&execution.id)
             .bind(&json_data)
-            .execute(self.pool())
+            .execute(&mut *tx)
             .await
-            .map_err(|e| {
-                log_db_operation_error(
-                    "save_workflow_execution",
-                    "workflow_executions",
-                    &e.to_string(),
-                );
-                format!("Database error: {}", e)
-            })?;
+            .map_err(db_error)?;
+        tx.commit().await.map_err(db_error)?;
 
         log_db_operation_success("save_workflow_execution", "workflow_executions", 0);
         Ok(())
@@ -66,14 +89,12 @@ impl Store {
                 let json_data: String = row.get("data");
                 log_deserialization("WorkflowExecution", json_data.len());
 
-                let execution = serde_json::from_str(&json_data).map_err(|e| {
-                    log_db_operation_error(
-                        "get_workflow_execution",
-                        "workflow_executions",
-                        &e.to_string(),
-                    );
-                    format!("Deserialization error: {}", e)
-                })?;
+                let execution = self
+                    .decode_with_blobs(&json_data, EXECUTION_BLOB_PATHS)
+                    .await
+                    .inspect_err(|e| {
+                        log_db_operation_error("get_workflow_execution", "workflow_executions", e);
+                    })?;
 
                 log_db_operation_success("get_workflow_execution", "workflow_executions", 0);
                 Ok(Some(execution))
@@ -107,37 +128,440 @@ impl Store {
             let json_data: String = row.get("data");
             log_deserialization("WorkflowExecution", json_data.len());
 
-            let execution = serde_json::from_str(&json_data).map_err(|e| {
+            let execution = self
+                .decode_with_blobs(&json_data, EXECUTION_BLOB_PATHS)
+                .await
+                .inspect_err(|e| {
+                    log_db_operation_error("list_workflow_executions", "workflow_executions", e);
+                })?;
+            executions.push(execution);
+        }
+
+        log_db_operation_success("list_workflow_executions", "workflow_executions", 0);
+        Ok(executions)
+    }
+
+    /// One page of execution summaries matching `filter`, newest first.
+    ///
+    /// Summaries are built inside SQLite so tasks, logs and audit trails are never
+    /// deserialized for list views.
+    pub async fn list_workflow_execution_summaries(
+        &self,
+        filter: &ExecutionFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<WorkflowExecutionSummary>, String> {
+        log_db_operation_start("list_workflow_execution_summaries", "workflow_executions");
+
+        let sql = format!(
+            "SELECT json_object( \
+                 'id', json_extract(data, '$.id'), \
+                 'workflow_name', json_extract(data, '$.workflow_name'), \
+                 'status', json_extract(data, '$.status'), \
+                 'created_at', json_extract(data, '$.created_at'), \
+                 'completed_at', json_extract(data, '$.completed_at'), \
+                 'task_count', json_array_length(data, '$.tasks'), \
+                 'timestamp', json_extract(data, '$.timestamp'), \
+                 'labels', json(COALESCE(json_extract(data, '$.labels'), '[]')) \
+             ) AS summary \
+             FROM workflow_executions \
+             {} \
+             ORDER BY json_extract(data, '$.created_at') DESC \
+             LIMIT ?4 OFFSET ?5",
+            EXECUTION_FILTER_SQL
+        );
+        let rows = sqlx::query(&sql)
+            .bind(filter.status.as_ref().map(WorkflowExecutionStatus::as_str))
+            .bind(filter.label.as_deref())
+            .bind(filter.workflow_id.as_deref())
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(self.pool())
+            .await
+            .map_err(|e| {
                 log_db_operation_error(
-                    "list_workflow_executions",
+                    "list_workflow_execution_summaries",
+                    "workflow_executions",
+                    &e.to_string(),
+                );
+                format!("Database error: {}", e)
+            })?;
+
+        let mut summaries = Vec::new();
+        for row in rows {
+            let json_data: String = row.get("summary");
+            log_deserialization("WorkflowExecutionSummary", json_data.len());
+
+            let summary = serde_json::from_str(&json_data).map_err(|e| {
+                log_db_operation_error(
+                    "list_workflow_execution_summaries",
                     "workflow_executions",
                     &e.to_string(),
                 );
                 format!("Deserialization error: {}", e)
             })?;
-            executions.push(execution);
+            summaries.push(summary);
         }
 
-        log_db_operation_success("list_workflow_executions", "workflow_executions", 0);
-        Ok(executions)
+        log_db_operation_success(
+            "list_workflow_execution_summaries",
+            "workflow_executions",
+            0,
+        );
+        Ok(summaries)
     }
 
-    pub async fn delete_workflow_execution(&self, id: &str) -> Result<(), String> {
-        log_db_operation_start("delete_workflow_execution", "workflow_executions");
+    pub async fn count_workflow_executions(
+        &self,
+        filter: &ExecutionFilter,
+    ) -> Result<usize, String> {
+        log_db_operation_start("count_workflow_executions", "workflow_executions");
 
-        sqlx::query("DELETE FROM workflow_executions WHERE id = ?")
-            .bind(id)
-            .execute(self.pool())
+        let sql = format!(
+            "SELECT COUNT(*) FROM workflow_executions {}",
+            EXECUTION_FILTER_SQL
+        );
+        let count: i64 = sqlx::query_scalar(&sql)
+            .bind(filter.status.as_ref().map(WorkflowExecutionStatus::as_str))
+            .bind(filter.label.as_deref())
+            .bind(filter.workflow_id.as_deref())
+            .fetch_one(self.pool())
             .await
             .map_err(|e| {
                 log_db_operation_error(
-                    "delete_workflow_execution",
+                    "count_workflow_executions",
                     "workflow_executions",
                     &e.to_string(),
                 );
                 format!("Database error: {}", e)
             })?;
 
+        log_db_operation_success("count_workflow_executions", "workflow_executions", 0);
+        Ok(count as usize)
+    }
+
+    /// Every label used on an execution, sorted.
+    pub async fn list_execution_labels(&self) -> Result<Vec<String>, String> {
+        log_db_operation_start("list_execution_labels", "workflow_executions");
+
+        let labels: Vec<String> = sqlx::query_scalar(
+            "SELECT DISTINCT label.value \
+             FROM workflow_executions, json_each(workflow_executions.data, '$.labels') AS label \
+             ORDER BY label.value",
+        )
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error(
+                "list_execution_labels",
+                "workflow_executions",
+                &e.to_string(),
+            );
+            format!("Database error: {}", e)
+        })?;
+
+        log_db_operation_success("list_execution_labels", "workflow_executions", 0);
+        Ok(labels)
+    }
+
+    /// Executions started on `date` (UTC), newest first.
+    pub async fn list_workflow_executions_on(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Vec<WorkflowExecution>, String> {
+        log_db_operation_start("list_workflow_executions_on", "workflow_executions");
+
+        let rows = sqlx::query(
+            "SELECT data FROM workflow_executions \
+             WHERE date(json_extract(data, '$.created_at')) = ? \
+             ORDER BY json_extract(data, '$.created_at') DESC",
+        )
+        .bind(date.to_string())
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error(
+                "list_workflow_executions_on",
+                "workflow_executions",
+                &e.to_string(),
+            );
+            format!("Database error: {}", e)
+        })?;
+
+        let mut executions = Vec::new();
+        for row in rows {
+            let json_data: String = row.get("data");
+            log_deserialization("WorkflowExecution", json_data.len());
+
+            let execution = self
+                .decode_with_blobs(&json_data, EXECUTION_BLOB_PATHS)
+                .await
+                .inspect_err(|e| {
+                    log_db_operation_error("list_workflow_executions_on", "workflow_executions", e);
+                })?;
+            executions.push(execution);
+        }
+
+        log_db_operation_success("list_workflow_executions_on", "workflow_executions", 0);
+        Ok(executions)
+    }
+
+    /// Executions started at or after `from` and before `to`, oldest first.
+    pub async fn list_workflow_executions_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<WorkflowExecution>, String> {
+        log_db_operation_start("list_workflow_executions_between", "workflow_executions");
+
+        // Compared as Julian days: stored timestamps carry varying fractional
+        // digits, so their text does not sort in time order.
+        let rows = sqlx::query(
+            "SELECT data FROM workflow_executions \
+             WHERE julianday(json_extract(data, '$.created_at')) >= julianday(?1) \
+               AND julianday(json_extract(data, '$.created_at')) < julianday(?2) \
+             ORDER BY julianday(json_extract(data, '$.created_at')) ASC",
+        )
+        .bind(from.to_rfc3339_opts(SecondsFormat::Millis, true))
+        .bind(to.to_rfc3339_opts(SecondsFormat::Millis, true))
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error(
+                "list_workflow_executions_between",
+                "workflow_executions",
+                &e.to_string(),
+            );
+            format!("Database error: {}", e)
+        })?;
+
+        let mut executions = Vec::new();
+        for row in rows {
+            let json_data: String = row.get("data");
+            log_deserialization("WorkflowExecution", json_data.len());
+
+            let execution = self
+                .decode_with_blobs(&json_data, EXECUTION_BLOB_PATHS)
+                .await
+                .inspect_err(|e| {
+                    log_db_operation_error(
+                        "list_workflow_executions_between",
+                        "workflow_executions",
+                        e,
+                    );
+                })?;
+            executions.push(execution);
+        }
+
+        log_db_operation_success("list_workflow_executions_between", "workflow_executions", 0);
+        Ok(executions)
+    }
+
+    /// The newest execution of the same workflow that started before
+    /// `execution`. Runs are matched on `workflow_id`, or on the workflow name
+    /// when `execution` does not record which definition it ran.
+    pub async fn previous_workflow_execution(
+        &self,
+        execution: &WorkflowExecution,
+    ) -> Result<Option<WorkflowExecution>, String> {
+        log_db_operation_start("previous_workflow_execution", "workflow_executions");
+
+        let row = sqlx::query(
+            "SELECT data FROM workflow_executions \
+             WHERE id != ?1 \
+               AND json_extract(data, '$.created_at') < ?2 \
+               AND CASE WHEN ?3 IS NULL \
+                   THEN json_extract(data, '$.workflow_name') = ?4 \
+                   ELSE json_extract(data, '$.workflow_id') = ?3 END \
+             ORDER BY json_extract(data, '$.created_at') DESC \
+             LIMIT 1",
+        )
+        .bind(&execution.id)
+        .bind(
+            execution
+                .created_at
+                .to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        )
+        .bind(execution.workflow_id.as_deref())
+        .bind(&execution.workflow_name)
+        .fetch_optional(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error(
+                "previous_workflow_execution",
+                "workflow_executions",
+                &e.to_string(),
+            );
+            format!("Database error: {}", e)
+        })?;
+
+        let previous = match row {
+            Some(row) => {
+                let json_data: String = row.get("data");
+                log_deserialization("WorkflowExecution", json_data.len());
+                Some(
+                    self.decode_with_blobs(&json_data, EXECUTION_BLOB_PATHS)
+                        .await
+                        .inspect_err(|e| {
+                            log_db_operation_error(
+                                "previous_workflow_execution",
+                                "workflow_executions",
+                                e,
+                            );
+                        })?,
+                )
+            }
+            None => None,
+        };
+
+        log_db_operation_success("previous_workflow_execution", "workflow_executions", 0);
+        Ok(previous)
+    }
+
+    /// Execution and failure counts per UTC day, for days on or after `since`.
+    ///
+    /// Days without executions are omitted.
+    pub async fn daily_execution_stats(
+        &self,
+        since: NaiveDate,
+    ) -> Result<Vec<DailyExecutionStats>, String> {
+        log_db_operation_start("daily_execution_stats", "workflow_executions");
+
+        let rows = sqlx::query(
+            "SELECT date(json_extract(data, '$.created_at')) AS day, \
+                    COUNT(*) AS total, \
+                    SUM(CASE WHEN json_extract(data, '$.status') = 'failed' THEN 1 ELSE 0 END) \
+                        AS failed \
+             FROM workflow_executions \
+             WHERE date(json_extract(data, '$.created_at')) >= ? \
+             GROUP BY day \
+             ORDER BY day",
+        )
+        .bind(since.to_string())
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error(
+                "daily_execution_stats",
+                "workflow_executions",
+                &e.to_string(),
+            );
+            format!("Database error: {}", e)
+        })?;
+
+        let mut stats = Vec::new();
+        for row in rows {
+            let day: String = row.get("day");
+            let total: i64 = row.get("total");
+            let failed: i64 = row.get("failed");
+            let date = NaiveDate::parse_from_str(&day, "%Y-%m-%d")
+                .map_err(|e| format!("Invalid execution date '{}': {}", day, e))?;
+            stats.push(DailyExecutionStats {
+                date,
+                total: total as usize,
+                failed: failed as usize,
+            });
+        }
+
+        log_db_operation_success("daily_execution_stats", "workflow_executions", 0);
+        Ok(stats)
+    }
+
+    /// The statuses of the latest `runs` executions of each workflow
+    /// definition, sorted by workflow id. Executions that do not record which
+    /// definition they ran are left out.
+    pub async fn list_workflow_health(&self, runs: usize) -> Result<Vec<WorkflowHealth>, String> {
+        log_db_operation_start("list_workflow_health", "workflow_executions");
+
+        let rows = sqlx::query(
+            "SELECT workflow_id, status, created_at FROM ( \
+                 SELECT json_extract(data, '$.workflow_id') AS workflow_id, \
+                        json_extract(data, '$.status') AS status, \
+                        json_extract(data, '$.created_at') AS created_at, \
+                        ROW_NUMBER() OVER ( \
+                            PARTITION BY json_extract(data, '$.workflow_id') \
+                            ORDER BY julianday(json_extract(data, '$.created_at')) DESC \
+                        ) AS run \
+                 FROM workflow_executions \
+                 WHERE json_extract(data, '$.workflow_id') IS NOT NULL \
+             ) \
+             WHERE run <= ? \
+             ORDER BY workflow_id, run DESC",
+        )
+        .bind(runs as i64)
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error(
+                "list_workflow_health",
+                "workflow_executions",
+                &e.to_string(),
+            );
+            format!("Database error: {}", e)
+        })?;
+
+        let mut health: Vec<WorkflowHealth> = Vec::new();
+        for row in rows {
+            let workflow_id: String = row.get("workflow_id");
+            let status: String = row.get("status");
+            let created_at: String = row.get("created_at");
+            let status: WorkflowExecutionStatus =
+                serde_json::from_value(serde_json::Value::String(status))
+                    .map_err(|e| format!("Deserialization error: {}", e))?;
+            let created_at = created_at
+                .parse::<DateTime<Utc>>()
+                .map_err(|e| format!("Invalid execution time '{}': {}", created_at, e))?;
+
+            // Rows come oldest first within each workflow.
+            match health.last_mut() {
+                Some(entry) if entry.workflow_id == workflow_id => {
+                    entry.recent.push(status);
+                    entry.last_run_at = created_at;
+                }
+                _ => health.push(WorkflowHealth {
+                    workflow_id,
+                    recent: vec![status],
+                    last_run_at: created_at,
+                }),
+            }
+        }
+
+        log_db_operation_success("list_workflow_health", "workflow_executions", 0);
+        Ok(health)
+    }
+
+    pub async fn delete_workflow_execution(&self, id: &str) -> Result<(), String> {
+        log_db_operation_start("delete_workflow_execution", "workflow_executions");
+
+        let db_error = |e: sqlx::Error| {
+            log_db_operation_error(
+                "delete_workflow_execution",
+                "workflow_executions",
+                &e.to_string(),
+            );
+            format!("Database error: {}", e)
+        };
+        let mut tx = self.pool().begin().await.map_err(db_error)?;
+        replace_blob_refs(&mut tx, &execution_owner(id), &[])
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM workflow_executions WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM execution_checkpoints WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM output_journal WHERE execution_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        tx.commit().await.map_err(db_error)?;
+
         log_db_operation_success("delete_workflow_execution", "workflow_executions", 0);
         Ok(())
     }
@@ -167,45 +591,34 @@ impl Store {
 
         self.delete_workflow_execution(id).await?;
 
-        let rows = sqlx::query("SELECT id, data FROM task_executions")
-            .fetch_all(self.pool())
-            .await
-            .map_err(|e| {
-                log_db_operation_error(
-                    "delete_workflow_metadata_and_tasks",
-                    "task_executions",
-                    &e.to_string(),
-                );
-                format!("Database error: {}", e)
-            })?;
-
-        for row in rows {
-            let task_id: String = row.get("id");
-            let json_data: String = row.get("data");
-
-            let task: TaskExecution = serde_json::from_str(&json_data).map_err(|e| {
-                log_db_operation_error(
-                    "delete_workflow_metadata_and_tasks",
-                    "task_executions",
-                    &e.to_string(),
-                );
-                format!("Deserialization error: {}", e)
-            })?;
+        let db_error = |e: sqlx::Error| {
+            log_db_operation_error(
+                "delete_workflow_metadata_and_tasks",
+                "task_executions",
+                &e.to_string(),
+            );
+            format!("Database error: {}", e)
+        };
 
-            if task.workflow_id == id {
-                sqlx::query("DELETE FROM task_executions WHERE id = ?")
-                    .bind(&task_id)
-                    .execute(self.pool())
-                    .await
-                    .map_err(|e| {
-                        log_db_operation_error(
-                            "delete_workflow_metadata_and_tasks",
-                            "task_executions",
-                            &e.to_string(),
-                        );
-                        format!("Database error: {}", e)
-                    })?;
-            }
+        let task_ids: Vec<String> = sqlx::query_scalar(
+            "SELECT id FROM task_executions WHERE json_extract(data, '$.workflow_id') = ?",
+        )
+        .bind(id)
+        .fetch_all(self.pool())
+        .await
+        .map_err(db_error)?;
+
+        for task_id in task_ids {
+            let mut tx = self.pool().begin().await.map_err(db_error)?;
+            replace_blob_refs(&mut tx, &task_owner(&task_id), &[])
+                .await
+                .map_err(db_error)?;
+            sqlx::query("DELETE FROM task_executions WHERE id = ?")
+                .bind(&task_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+            tx.commit().await.map_err(db_error)?;
         }
 
         log_db_operation_success(
@@ -0,0 +1,116 @@
+use super::Store;
+use crate::logging::{
+    log_db_operation_error, log_db_operation_start, log_db_operation_success, log_deserialization,
+    log_serialization,
+};
+use crate::models::ExecutionFilterPreset;
+use sqlx::Row;
+
+impl Store {
+    /// Save a preset, replacing any preset with the same name.
+    pub async fn save_execution_filter_preset(
+        &self,
+        preset: &ExecutionFilterPreset,
+    ) -> Result<(), String> {
+        log_db_operation_start("save_execution_filter_preset", "execution_filter_presets");
+
+        let json_data = serde_json::to_string(preset).map_err(|e| {
+            log_db_operation_error(
+                "save_execution_filter_preset",
+                "execution_filter_presets",
+                &e.to_string(),
+            );
+            format!("Serialization error: {}", e)
+        })?;
+
+        log_serialization("ExecutionFilterPreset", json_data.len());
+
+        sqlx::query("INSERT OR REPLACE INTO execution_filter_presets (name, data) VALUES (?, ?)")
+            .bind(&preset.name)
+            .bind(&json_data)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error(
+                    "save_execution_filter_preset",
+                    "execution_filter_presets",
+                    &e.to_string(),
+                );
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success(
+            "save_execution_filter_preset",
+            "execution_filter_presets",
+            0,
+        );
+        Ok(())
+    }
+
+    /// Every preset, by name.
+    pub async fn list_execution_filter_presets(
+        &self,
+    ) -> Result<Vec<ExecutionFilterPreset>, String> {
+        log_db_operation_start("list_execution_filter_presets", "execution_filter_presets");
+
+        let rows =
+            sqlx::query("SELECT data FROM execution_filter_presets ORDER BY lower(name), name")
+                .fetch_all(self.pool())
+                .await
+                .map_err(|e| {
+                    log_db_operation_error(
+                        "list_execution_filter_presets",
+                        "execution_filter_presets",
+                        &e.to_string(),
+                    );
+                    format!("Database error: {}", e)
+                })?;
+
+        let mut presets = Vec::new();
+        for row in rows {
+            let json_data: String = row.get("data");
+            log_deserialization("ExecutionFilterPreset", json_data.len());
+
+            let preset = serde_json::from_str(&json_data).map_err(|e| {
+                log_db_operation_error(
+                    "list_execution_filter_presets",
+                    "execution_filter_presets",
+                    &e.to_string(),
+                );
+                format!("Deserialization error: {}", e)
+            })?;
+            presets.push(preset);
+        }
+
+        log_db_operation_success(
+            "list_execution_filter_presets",
+            "execution_filter_presets",
+            0,
+        );
+        Ok(presets)
+    }
+
+    pub async fn delete_execution_filter_preset(&self, name: &str) -> Result<(), String> {
+        log_db_operation_start("delete_execution_filter_preset", "execution_filter_presets");
+
+        sqlx::query("DELETE FROM execution_filter_presets WHERE name = ?")
+            .bind(name)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error(
+                    "delete_execution_filter_preset",
+                    "execution_filter_presets",
+                    &e.to_string(),
+                );
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success(
+            "delete_execution_filter_preset",
+            "execution_filter_presets",
+            0,
+        );
+        Ok(())
+    }
+}
@@ -1,10 +1,17 @@
+use super::schema::SCHEMA_VERSION;
 use crate::errors::PersistenceError;
 use crate::logging::{log_db_operation_start, log_db_operation_success};
-use sqlx::SqlitePool;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::{Row, SqlitePool};
 use std::sync::Arc;
+use std::time::Duration;
+
+/// Connections a read-only store keeps for reporting queries.
+const READ_ONLY_CONNECTIONS: u32 = 4;
 
 pub struct Store {
     pool: Arc<SqlitePool>,
+    read_only: bool,
 }
 
 impl Store {
@@ -23,6 +30,13 @@ impl Store {
             PersistenceError::Database(e.to_string())
         })?;
 
+        // Only takes effect on a new, empty database; older ones are switched
+        // over by the first `maintenance()` run.
+        sqlx::query("PRAGMA auto_vacuum=INCREMENTAL")
+            .execute(&pool)
+            .await
+            .map_err(|e| PersistenceError::Database(e.to_string()))?;
+
         sqlx::query("PRAGMA journal_mode=WAL")
             .execute(&pool)
             .await
@@ -34,9 +48,47 @@ impl Store {
 
         Ok(Self {
             pool: Arc::new(pool),
+            read_only: false,
+        })
+    }
+
+    /// Open an existing database for reading only, on connections of its own.
+    ///
+    /// Meant for reporting next to a store that writes, such as exports while
+    /// the app runs workflows: the connections are opened read-only with
+    /// `query_only` set, so every write fails, and with WAL journaling their
+    /// reads never wait on the writer. Nothing is created or migrated.
+    pub async fn open_read_only(db_path: &str) -> Result<Self, PersistenceError> {
+        log_db_operation_start("connect_read_only", "database");
+        tracing::info!("Opening database read-only: {}", db_path);
+
+        let options = SqliteConnectOptions::new()
+            .filename(db_path)
+            .read_only(true)
+            .pragma("query_only", "ON")
+            .busy_timeout(Duration::from_secs(5));
+        let pool = SqlitePoolOptions::new()
+            .max_connections(READ_ONLY_CONNECTIONS)
+            .connect_with(options)
+            .await
+            .map_err(|e| {
+                tracing::error!("Read-only database connection failed: {}", e);
+                PersistenceError::Database(e.to_string())
+            })?;
+
+        log_db_operation_success("connect_read_only", "database", 0);
+
+        Ok(Self {
+            pool: Arc::new(pool),
+            read_only: true,
         })
     }
 
+    /// Whether this store was opened with [`Store::open_read_only`].
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
     async fn create_tables(pool: &SqlitePool) -> Result<(), PersistenceError> {
         log_db_operation_start("create_tables", "all");
 
@@ -48,6 +100,24 @@ impl Store {
             "CREATE TABLE IF NOT EXISTS prompts (id TEXT PRIMARY KEY, data JSON NOT NULL)",
             "CREATE TABLE IF NOT EXISTS audit_events (id TEXT PRIMARY KEY, data JSON NOT NULL)",
             "CREATE TABLE IF NOT EXISTS settings (id TEXT PRIMARY KEY, data JSON NOT NULL)",
+            "CREATE TABLE IF NOT EXISTS task_result_cache (id TEXT PRIMARY KEY, data JSON NOT NULL)",
+            "CREATE TABLE IF NOT EXISTS execution_checkpoints (id TEXT PRIMARY KEY, data JSON NOT NULL)",
+            "CREATE TABLE IF NOT EXISTS archived_executions (id TEXT PRIMARY KEY, data JSON NOT NULL)",
+            "CREATE TABLE IF NOT EXISTS workflow_provenance (id TEXT PRIMARY KEY, data JSON NOT NULL)",
+            "CREATE TABLE IF NOT EXISTS notifications (id TEXT PRIMARY KEY, data JSON NOT NULL)",
+            "CREATE TABLE IF NOT EXISTS task_snippets (id TEXT PRIMARY KEY, data JSON NOT NULL)",
+            "CREATE TABLE IF NOT EXISTS workflow_tests (id TEXT PRIMARY KEY, data JSON NOT NULL)",
+            "CREATE TABLE IF NOT EXISTS execution_filter_presets (name TEXT PRIMARY KEY, data JSON NOT NULL)",
+            "CREATE TABLE IF NOT EXISTS blobs (id TEXT PRIMARY KEY, data TEXT NOT NULL)",
+            "CREATE TABLE IF NOT EXISTS blob_refs (owner TEXT NOT NULL, blob_id TEXT NOT NULL)",
+            "CREATE TABLE IF NOT EXISTS resource_locks (ticket INTEGER PRIMARY KEY AUTOINCREMENT, resource TEXT NOT NULL, execution_id TEXT NOT NULL, task_id TEXT NOT NULL, requested_at INTEGER NOT NULL, heartbeat_at INTEGER NOT NULL)",
+            "CREATE TABLE IF NOT EXISTS output_journal (chunk INTEGER PRIMARY KEY AUTOINCREMENT, execution_id TEXT NOT NULL, sequence INTEGER NOT NULL, task_id TEXT, task_name TEXT, line TEXT NOT NULL, finished INTEGER)",
+            "CREATE TABLE IF NOT EXISTS trash (kind TEXT NOT NULL, id TEXT NOT NULL, data JSON NOT NULL, batch TEXT NOT NULL, deleted_at INTEGER NOT NULL, PRIMARY KEY (kind, id))",
+            "CREATE INDEX IF NOT EXISTS trash_batch ON trash (batch)",
+            "CREATE INDEX IF NOT EXISTS resource_locks_resource ON resource_locks (resource, ticket)",
+            "CREATE INDEX IF NOT EXISTS output_journal_execution ON output_journal (execution_id, chunk)",
+            "CREATE INDEX IF NOT EXISTS blob_refs_owner ON blob_refs (owner)",
+            "CREATE INDEX IF NOT EXISTS blob_refs_blob_id ON blob_refs (blob_id)",
         ];
 
         for table_sql in &tables {
@@ -57,6 +127,19 @@ impl Store {
                 .map_err(|e| PersistenceError::Database(e.to_string()))?;
         }
 
+        // Databases from before versioning report 0 and match version 1.
+        let version: i64 = sqlx::query("PRAGMA user_version")
+            .fetch_one(pool)
+            .await
+            .map_err(|e| PersistenceError::Database(e.to_string()))?
+            .get(0);
+        if version == 0 {
+            sqlx::query(&format!("PRAGMA user_version = {}", SCHEMA_VERSION))
+                .execute(pool)
+                .await
+                .map_err(|e| PersistenceError::Database(e.to_string()))?;
+        }
+
         log_db_operation_success("create_tables", "all", 0);
         Ok(())
     }
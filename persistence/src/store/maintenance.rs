@@ -0,0 +1,159 @@
+use super::Store;
+use crate::logging::{
+    log_db_operation_error, log_db_operation_start, log_db_operation_success, log_deserialization,
+    log_serialization,
+};
+use crate::models::MaintenanceReport;
+use chrono::Utc;
+use sqlx::{Row, SqliteConnection};
+
+/// `PRAGMA auto_vacuum` value for incremental mode.
+const AUTO_VACUUM_INCREMENTAL: i64 = 2;
+
+fn db_error(operation: &str, e: sqlx::Error) -> String {
+    log_db_operation_error(operation, "database", &e.to_string());
+    format!("Database error: {}", e)
+}
+
+/// Bytes the database occupies on disk, counting its write-ahead log. An
+/// in-memory database is measured in pages instead.
+async fn database_size(conn: &mut SqliteConnection) -> Result<u64, sqlx::Error> {
+    let file = sqlx::query("PRAGMA database_list")
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .find(|row| row.get::<String, _>("name") == "main")
+        .map(|row| row.get::<String, _>("file"))
+        .unwrap_or_default();
+
+    if !file.is_empty() {
+        let size_of = |path: &str| std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        return Ok(size_of(&file) + size_of(&format!("{}-wal", file)));
+    }
+
+    let page_count: i64 = sqlx::query_scalar("PRAGMA page_count")
+        .fetch_one(&mut *conn)
+        .await?;
+    let page_size: i64 = sqlx::query_scalar("PRAGMA page_size")
+        .fetch_one(&mut *conn)
+        .await?;
+    Ok((page_count * page_size) as u64)
+}
+
+impl Store {
+    /// Give space freed by deleted rows back to the filesystem and refresh the
+    /// query planner's statistics: checkpoint and truncate the WAL, run an
+    /// incremental vacuum, then `ANALYZE`. The report is kept and can be read
+    /// back with [`Store::last_maintenance`].
+    pub async fn maintenance(&self) -> Result<MaintenanceReport, String> {
+        log_db_operation_start("maintenance", "database");
+        let started_at = Utc::now();
+
+        // Everything runs on one connection: switching the vacuum mode only
+        // applies to the connection that runs the following VACUUM.
+        let mut conn = self
+            .pool()
+            .acquire()
+            .await
+            .map_err(|e| db_error("maintenance", e))?;
+
+        let bytes_before = database_size(&mut conn)
+            .await
+            .map_err(|e| db_error("maintenance", e))?;
+
+        sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| db_error("maintenance", e))?;
+
+        let auto_vacuum: i64 = sqlx::query_scalar("PRAGMA auto_vacuum")
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(|e| db_error("maintenance", e))?;
+        let full_vacuum = auto_vacuum != AUTO_VACUUM_INCREMENTAL;
+        if full_vacuum {
+            tracing::info!("Switching database to incremental vacuum");
+            sqlx::query("PRAGMA auto_vacuum=INCREMENTAL")
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| db_error("maintenance", e))?;
+            sqlx::query("VACUUM")
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| db_error("maintenance", e))?;
+        } else {
+            // Each step of this pragma frees pages, so it has to be read to the end.
+            sqlx::query("PRAGMA incremental_vacuum")
+                .fetch_all(&mut *conn)
+                .await
+                .map_err(|e| db_error("maintenance", e))?;
+        }
+
+        sqlx::query("ANALYZE")
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| db_error("maintenance", e))?;
+
+        // The vacuum itself went through the WAL; fold it back in.
+        sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| db_error("maintenance", e))?;
+
+        let bytes_after = database_size(&mut conn)
+            .await
+            .map_err(|e| db_error("maintenance", e))?;
+        drop(conn);
+
+        let report = MaintenanceReport {
+            started_at,
+            completed_at: Utc::now(),
+            bytes_before,
+            bytes_after,
+            full_vacuum,
+        };
+
+        let json_data = serde_json::to_string(&report).map_err(|e| {
+            log_db_operation_error("maintenance", "settings", &e.to_string());
+            format!("Serialization error: {}", e)
+        })?;
+        log_serialization("MaintenanceReport", json_data.len());
+
+        sqlx::query("INSERT OR REPLACE INTO settings (id, data) VALUES ('storage_maintenance', ?)")
+            .bind(&json_data)
+            .execute(self.pool())
+            .await
+            .map_err(|e| db_error("maintenance", e))?;
+
+        log_db_operation_success("maintenance", "database", 0);
+        Ok(report)
+    }
+
+    /// The report of the most recent [`Store::maintenance`] run, if any.
+    pub async fn last_maintenance(&self) -> Result<Option<MaintenanceReport>, String> {
+        log_db_operation_start("last_maintenance", "settings");
+
+        let row = sqlx::query("SELECT data FROM settings WHERE id = 'storage_maintenance'")
+            .fetch_optional(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error("last_maintenance", "settings", &e.to_string());
+                format!("Database error: {}", e)
+            })?;
+
+        let report = match row {
+            Some(row) => {
+                let json_data: String = row.get("data");
+                log_deserialization("MaintenanceReport", json_data.len());
+                Some(serde_json::from_str(&json_data).map_err(|e| {
+                    log_db_operation_error("last_maintenance", "settings", &e.to_string());
+                    format!("Deserialization error: {}", e)
+                })?)
+            }
+            None => None,
+        };
+
+        log_db_operation_success("last_maintenance", "settings", 0);
+        Ok(report)
+    }
+}
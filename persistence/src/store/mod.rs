@@ -1,11 +1,26 @@
+pub mod archive;
 pub mod audit;
+pub mod blob;
+pub mod checkpoint;
 pub mod execution;
+pub mod execution_filter;
 pub mod lib;
+pub mod maintenance;
+pub mod notification;
+pub mod output_journal;
 pub mod prompt;
+pub mod provenance;
+pub mod resource_lock;
+pub mod schema;
 pub mod settings;
+pub mod snippet;
 pub mod task;
+pub mod task_cache;
+pub mod trash;
 pub mod user_input;
 pub mod utils;
 pub mod workflow;
+pub mod workflow_test;
 
 pub use lib::Store;
+pub use schema::SCHEMA_VERSION;
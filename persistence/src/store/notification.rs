@@ -0,0 +1,130 @@
+use super::Store;
+use crate::logging::{
+    log_db_operation_error, log_db_operation_start, log_db_operation_success, log_deserialization,
+    log_serialization,
+};
+use crate::models::Notification;
+use sqlx::Row;
+
+impl Store {
+    pub async fn save_notification(&self, notification: &Notification) -> Result<(), String> {
+        log_db_operation_start("save_notification", "notifications");
+
+        let json_data = serde_json::to_string(notification).map_err(|e| {
+            log_db_operation_error("save_notification", "notifications", &e.to_string());
+            format!("Serialization error: {}", e)
+        })?;
+
+        log_serialization("Notification", json_data.len());
+
+        sqlx::query("INSERT OR REPLACE INTO notifications (id, data) VALUES (?, ?)")
+            .bind(&notification.id)
+            .bind(&json_data)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error("save_notification", "notifications", &e.to_string());
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success("save_notification", "notifications", 0);
+        Ok(())
+    }
+
+    /// Newest notifications first, at most `limit` of them.
+    pub async fn list_notifications(&self, limit: usize) -> Result<Vec<Notification>, String> {
+        log_db_operation_start("list_notifications", "notifications");
+
+        let rows = sqlx::query(
+            "SELECT data FROM notifications \
+             ORDER BY json_extract(data, '$.created_at') DESC, id \
+             LIMIT ?",
+        )
+        .bind(limit as i64)
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error("list_notifications", "notifications", &e.to_string());
+            format!("Database error: {}", e)
+        })?;
+
+        let mut notifications = Vec::new();
+        for row in rows {
+            let json_data: String = row.get("data");
+            log_deserialization("Notification", json_data.len());
+
+            let notification = serde_json::from_str(&json_data).map_err(|e| {
+                log_db_operation_error("list_notifications", "notifications", &e.to_string());
+                format!("Deserialization error: {}", e)
+            })?;
+            notifications.push(notification);
+        }
+
+        log_db_operation_success("list_notifications", "notifications", 0);
+        Ok(notifications)
+    }
+
+    pub async fn count_unread_notifications(&self) -> Result<usize, String> {
+        log_db_operation_start("count_unread_notifications", "notifications");
+
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM notifications WHERE NOT json_extract(data, '$.read')",
+        )
+        .fetch_one(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error(
+                "count_unread_notifications",
+                "notifications",
+                &e.to_string(),
+            );
+            format!("Database error: {}", e)
+        })?;
+
+        log_db_operation_success("count_unread_notifications", "notifications", 0);
+        Ok(count as usize)
+    }
+
+    /// Mark one notification read, or every notification when `id` is `None`.
+    pub async fn mark_notifications_read(&self, id: Option<&str>) -> Result<(), String> {
+        log_db_operation_start("mark_notifications_read", "notifications");
+
+        sqlx::query(
+            "UPDATE notifications SET data = json_set(data, '$.read', json('true')) \
+             WHERE ?1 IS NULL OR id = ?1",
+        )
+        .bind(id)
+        .execute(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error("mark_notifications_read", "notifications", &e.to_string());
+            format!("Database error: {}", e)
+        })?;
+
+        log_db_operation_success("mark_notifications_read", "notifications", 0);
+        Ok(())
+    }
+
+    /// Delete all but the newest `keep` notifications.
+    pub async fn prune_notifications(&self, keep: usize) -> Result<(), String> {
+        log_db_operation_start("prune_notifications", "notifications");
+
+        sqlx::query(
+            "DELETE FROM notifications WHERE id NOT IN ( \
+                 SELECT id FROM notifications \
+                 ORDER BY json_extract(data, '$.created_at') DESC, id \
+                 LIMIT ? \
+             )",
+        )
+        .bind(keep as i64)
+        .execute(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error("prune_notifications", "notifications", &e.to_string());
+            format!("Database error: {}", e)
+        })?;
+
+        log_db_operation_success("prune_notifications", "notifications", 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use super::Store;
+use crate::logging::{log_db_operation_error, log_db_operation_start, log_db_operation_success};
+use crate::models::OutputChunk;
+use sqlx::Row;
+
+fn db_error(operation: &str, e: sqlx::Error) -> String {
+    log_db_operation_error(operation, "output_journal", &e.to_string());
+    format!("Database error: {}", e)
+}
+
+impl Store {
+    /// Append `chunks` to their executions' output journals, all or none.
+    pub async fn append_output_chunks(&self, chunks: &[OutputChunk]) -> Result<(), String> {
+        log_db_operation_start("append_output_chunks", "output_journal");
+
+        let mut tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| db_error("append_output_chunks", e))?;
+        for chunk in chunks {
+            sqlx::query(
+                "INSERT INTO output_journal (execution_id, sequence, task_id, task_name, line, finished) VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&chunk.execution_id)
+            .bind(chunk.sequence as i64)
+            .bind(&chunk.task_id)
+            .bind(&chunk.task_name)
+            .bind(&chunk.line)
+            .bind(chunk.finished)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| db_error("append_output_chunks", e))?;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| db_error("append_output_chunks", e))?;
+
+        log_db_operation_success("append_output_chunks", "output_journal", 0);
+        Ok(())
+    }
+
+    /// The execution's journaled output, in the order it was saved.
+    pub async fn get_output_journal(&self, execution_id: &str) -> Result<Vec<OutputChunk>, String> {
+        log_db_operation_start("get_output_journal", "output_journal");
+
+        let rows = sqlx::query(
+            "SELECT sequence, task_id, task_name, line, finished FROM output_journal WHERE execution_id = ? ORDER BY chunk",
+        )
+        .bind(execution_id)
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| db_error("get_output_journal", e))?;
+
+        let chunks = rows
+            .into_iter()
+            .map(|row| OutputChunk {
+                execution_id: execution_id.to_string(),
+                sequence: row.get::<i64, _>("sequence") as u64,
+                task_id: row.get("task_id"),
+                task_name: row.get("task_name"),
+                line: row.get("line"),
+                finished: row.get("finished"),
+            })
+            .collect();
+
+        log_db_operation_success("get_output_journal", "output_journal", 0);
+        Ok(chunks)
+    }
+
+    /// Drop the execution's journaled output, once its task logs are saved.
+    /// Returns how many chunks were removed.
+    pub async fn delete_output_journal(&self, execution_id: &str) -> Result<u64, String> {
+        log_db_operation_start("delete_output_journal", "output_journal");
+
+        let result = sqlx::query("DELETE FROM output_journal WHERE execution_id = ?")
+            .bind(execution_id)
+            .execute(self.pool())
+            .await
+            .map_err(|e| db_error("delete_output_journal", e))?;
+
+        log_db_operation_success("delete_output_journal", "output_journal", 0);
+        Ok(result.rows_affected())
+    }
+}
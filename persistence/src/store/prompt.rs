@@ -3,7 +3,8 @@ use crate::logging::{
     log_db_operation_error, log_db_operation_start, log_db_operation_success, log_deserialization,
     log_serialization,
 };
-use crate::models::Prompt;
+use crate::models::{Prompt, PromptFilter, PromptSort, PromptWithUsage};
+use chrono::{DateTime, Utc};
 use sqlx::Row;
 
 impl Store {
@@ -58,6 +59,99 @@ impl Store {
         Ok(prompts)
     }
 
+    /// Prompts matching `filter`, each with its usage counted from task executions
+    /// that link to it through `prompt_id`.
+    pub async fn list_prompts_filtered(
+        &self,
+        filter: &PromptFilter,
+    ) -> Result<Vec<PromptWithUsage>, String> {
+        log_db_operation_start("list_prompts_filtered", "prompts");
+
+        let order_by = match filter.sort {
+            PromptSort::Name => "lower(json_extract(p.data, '$.name')), p.id",
+            PromptSort::Newest => "json_extract(p.data, '$.created_at') DESC, p.id",
+            PromptSort::RecentlyUsed => {
+                "last_used_at IS NULL, last_used_at DESC, lower(json_extract(p.data, '$.name'))"
+            }
+            PromptSort::MostUsed => "use_count DESC, lower(json_extract(p.data, '$.name'))",
+        };
+        let search = filter
+            .search
+            .as_deref()
+            .map(|s| s.trim().to_lowercase())
+            .filter(|s| !s.is_empty());
+
+        let sql = format!(
+            "SELECT p.data AS data, \
+                 COUNT(t.id) AS use_count, \
+                 MAX(json_extract(t.data, '$.created_at')) AS last_used_at \
+             FROM prompts p \
+             LEFT JOIN task_executions t ON json_extract(t.data, '$.prompt_id') = p.id \
+             WHERE ?1 IS NULL \
+                 OR instr(lower(json_extract(p.data, '$.name')), ?1) > 0 \
+                 OR instr(lower(json_extract(p.data, '$.content')), ?1) > 0 \
+             GROUP BY p.id \
+             ORDER BY {}",
+            order_by
+        );
+
+        let rows = sqlx::query(&sql)
+            .bind(search)
+            .fetch_all(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error("list_prompts_filtered", "prompts", &e.to_string());
+                format!("Database error: {}", e)
+            })?;
+
+        let wanted_tags: Vec<String> = filter.tags.iter().map(|t| t.to_lowercase()).collect();
+        let mut prompts = Vec::new();
+        for row in rows {
+            let json_data: String = row.get("data");
+            log_deserialization("Prompt", json_data.len());
+
+            let prompt: Prompt = serde_json::from_str(&json_data).map_err(|e| {
+                log_db_operation_error("list_prompts_filtered", "prompts", &e.to_string());
+                format!("Deserialization error: {}", e)
+            })?;
+            if !wanted_tags.iter().all(|tag| prompt.tags.contains(tag)) {
+                continue;
+            }
+
+            let use_count: i64 = row.get("use_count");
+            let last_used_at: Option<String> = row.get("last_used_at");
+            prompts.push(PromptWithUsage {
+                prompt,
+                use_count: use_count as u64,
+                last_used_at: last_used_at
+                    .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+                    .map(|d| d.with_timezone(&Utc)),
+            });
+        }
+
+        log_db_operation_success("list_prompts_filtered", "prompts", 0);
+        Ok(prompts)
+    }
+
+    /// Every tag used by at least one prompt, sorted.
+    pub async fn list_prompt_tags(&self) -> Result<Vec<String>, String> {
+        log_db_operation_start("list_prompt_tags", "prompts");
+
+        let tags: Vec<String> = sqlx::query_scalar(
+            "SELECT DISTINCT tag.value FROM prompts, json_each(prompts.data, '$.tags') AS tag \
+             ORDER BY tag.value",
+        )
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error("list_prompt_tags", "prompts", &e.to_string());
+            format!("Database error: {}", e)
+        })?;
+
+        log_db_operation_success("list_prompt_tags", "prompts", 0);
+        Ok(tags)
+    }
+
     pub async fn delete_prompt(&self, id: &str) -> Result<(), String> {
         log_db_operation_start("delete_prompt", "prompts");
 
@@ -0,0 +1,83 @@
+use super::Store;
+use crate::logging::{
+    log_db_operation_error, log_db_operation_start, log_db_operation_success, log_deserialization,
+    log_serialization,
+};
+use crate::models::WorkflowProvenance;
+use sqlx::Row;
+
+impl Store {
+    pub async fn get_workflow_provenance(
+        &self,
+        workflow_id: &str,
+    ) -> Result<Option<WorkflowProvenance>, String> {
+        log_db_operation_start("get_workflow_provenance", "workflow_provenance");
+
+        let row = sqlx::query("SELECT data FROM workflow_provenance WHERE id = ?")
+            .bind(workflow_id)
+            .fetch_optional(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error(
+                    "get_workflow_provenance",
+                    "workflow_provenance",
+                    &e.to_string(),
+                );
+                format!("Database error: {}", e)
+            })?;
+
+        let Some(row) = row else {
+            log_db_operation_success("get_workflow_provenance", "workflow_provenance", 0);
+            return Ok(None);
+        };
+
+        let json_data: String = row.get("data");
+        log_deserialization("WorkflowProvenance", json_data.len());
+        let provenance = serde_json::from_str(&json_data).map_err(|e| {
+            log_db_operation_error(
+                "get_workflow_provenance",
+                "workflow_provenance",
+                &e.to_string(),
+            );
+            format!("Deserialization error: {}", e)
+        })?;
+
+        log_db_operation_success("get_workflow_provenance", "workflow_provenance", 0);
+        Ok(Some(provenance))
+    }
+
+    pub async fn save_workflow_provenance(
+        &self,
+        provenance: &WorkflowProvenance,
+    ) -> Result<(), String> {
+        log_db_operation_start("save_workflow_provenance", "workflow_provenance");
+
+        let json_data = serde_json::to_string(provenance).map_err(|e| {
+            log_db_operation_error(
+                "save_workflow_provenance",
+                "workflow_provenance",
+                &e.to_string(),
+            );
+            format!("Serialization error: {}", e)
+        })?;
+
+        log_serialization("WorkflowProvenance", json_data.len());
+
+        sqlx::query("INSERT OR REPLACE INTO workflow_provenance (id, data) VALUES (?, ?)")
+            .bind(&provenance.workflow_id)
+            .bind(&json_data)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error(
+                    "save_workflow_provenance",
+                    "workflow_provenance",
+                    &e.to_string(),
+                );
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success("save_workflow_provenance", "workflow_provenance", 0);
+        Ok(())
+    }
+}
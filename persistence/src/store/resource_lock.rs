@@ -0,0 +1,145 @@
+use super::Store;
+use crate::logging::{log_db_operation_error, log_db_operation_start, log_db_operation_success};
+use crate::models::ResourceLock;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+
+fn db_error(operation: &str, e: sqlx::Error) -> String {
+    log_db_operation_error(operation, "resource_locks", &e.to_string());
+    format!("Database error: {}", e)
+}
+
+fn from_millis(millis: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(millis).unwrap_or_default()
+}
+
+impl Store {
+    /// Queue a ticket for `resource` behind every ticket already taken.
+    pub async fn join_resource_queue(
+        &self,
+        resource: &str,
+        execution_id: &str,
+        task_id: &str,
+    ) -> Result<i64, String> {
+        log_db_operation_start("join_resource_queue", "resource_locks");
+
+        let now = Utc::now().timestamp_millis();
+        let result = sqlx::query(
+            "INSERT INTO resource_locks (resource, execution_id, task_id, requested_at, heartbeat_at) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(resource)
+        .bind(execution_id)
+        .bind(task_id)
+        .bind(now)
+        .bind(now)
+        .execute(self.pool())
+        .await
+        .map_err(|e| db_error("join_resource_queue", e))?;
+
+        log_db_operation_success("join_resource_queue", "resource_locks", 0);
+        Ok(result.last_insert_rowid())
+    }
+
+    /// How many tickets are ahead of `ticket` for its resource, after dropping
+    /// those whose owner has not reported in since `stale_before`. Refreshes
+    /// `ticket`'s own heartbeat. `None` when the ticket is no longer queued.
+    pub async fn resource_queue_position(
+        &self,
+        ticket: i64,
+        stale_before: DateTime<Utc>,
+    ) -> Result<Option<usize>, String> {
+        log_db_operation_start("resource_queue_position", "resource_locks");
+
+        let mut tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| db_error("resource_queue_position", e))?;
+
+        let row = sqlx::query(
+            "UPDATE resource_locks SET heartbeat_at = ? WHERE ticket = ? RETURNING resource",
+        )
+        .bind(Utc::now().timestamp_millis())
+        .bind(ticket)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| db_error("resource_queue_position", e))?;
+        let Some(row) = row else {
+            log_db_operation_success("resource_queue_position", "resource_locks", 0);
+            return Ok(None);
+        };
+        let resource: String = row.get("resource");
+
+        sqlx::query("DELETE FROM resource_locks WHERE resource = ? AND heartbeat_at < ?")
+            .bind(&resource)
+            .bind(stale_before.timestamp_millis())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| db_error("resource_queue_position", e))?;
+
+        let ahead: i64 = sqlx::query(
+            "SELECT COUNT(*) AS ahead FROM resource_locks WHERE resource = ? AND ticket < ?",
+        )
+        .bind(&resource)
+        .bind(ticket)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| db_error("resource_queue_position", e))?
+        .get("ahead");
+
+        tx.commit()
+            .await
+            .map_err(|e| db_error("resource_queue_position", e))?;
+
+        log_db_operation_success("resource_queue_position", "resource_locks", 0);
+        Ok(Some(ahead as usize))
+    }
+
+    /// Give up `ticket`, whether it held its resource or was still waiting.
+    /// Returns whether it was queued.
+    pub async fn leave_resource_queue(&self, ticket: i64) -> Result<bool, String> {
+        log_db_operation_start("leave_resource_queue", "resource_locks");
+
+        let result = sqlx::query("DELETE FROM resource_locks WHERE ticket = ?")
+            .bind(ticket)
+            .execute(self.pool())
+            .await
+            .map_err(|e| db_error("leave_resource_queue", e))?;
+
+        log_db_operation_success("leave_resource_queue", "resource_locks", 0);
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Every queued ticket, grouped by resource in queue order.
+    pub async fn list_resource_locks(&self) -> Result<Vec<ResourceLock>, String> {
+        log_db_operation_start("list_resource_locks", "resource_locks");
+
+        let rows = sqlx::query(
+            "SELECT ticket, resource, execution_id, task_id, requested_at, heartbeat_at FROM resource_locks ORDER BY resource, ticket",
+        )
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| db_error("list_resource_locks", e))?;
+
+        let mut locks: Vec<ResourceLock> = Vec::with_capacity(rows.len());
+        for row in rows {
+            let resource: String = row.get("resource");
+            let position = match locks.last() {
+                Some(previous) if previous.resource == resource => previous.position + 1,
+                _ => 0,
+            };
+            locks.push(ResourceLock {
+                ticket: row.get("ticket"),
+                resource,
+                execution_id: row.get("execution_id"),
+                task_id: row.get("task_id"),
+                requested_at: from_millis(row.get("requested_at")),
+                heartbeat_at: from_millis(row.get("heartbeat_at")),
+                position,
+            });
+        }
+
+        log_db_operation_success("list_resource_locks", "resource_locks", 0);
+        Ok(locks)
+    }
+}
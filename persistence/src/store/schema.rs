@@ -0,0 +1,79 @@
+use super::Store;
+use crate::logging::{log_db_operation_error, log_db_operation_start, log_db_operation_success};
+use crate::models::{ColumnLayout, TableLayout};
+use sqlx::Row;
+
+/// Version of the stored data layout, kept in SQLite's `user_version`.
+/// Raise it whenever a table or a stored model changes in a way older
+/// readers cannot handle.
+pub const SCHEMA_VERSION: i64 = 1;
+
+fn db_error(operation: &str, e: sqlx::Error) -> String {
+    log_db_operation_error(operation, "sqlite_master", &e.to_string());
+    format!("Database error: {}", e)
+}
+
+impl Store {
+    /// The schema version recorded in the database.
+    pub async fn schema_version(&self) -> Result<i64, String> {
+        log_db_operation_start("schema_version", "sqlite_master");
+
+        let version: i64 = sqlx::query("PRAGMA user_version")
+            .fetch_one(self.pool())
+            .await
+            .map_err(|e| db_error("schema_version", e))?
+            .get(0);
+
+        log_db_operation_success("schema_version", "sqlite_master", 0);
+        Ok(version)
+    }
+
+    /// Every table with its columns and indexes, sorted by name.
+    pub async fn table_layout(&self) -> Result<Vec<TableLayout>, String> {
+        log_db_operation_start("table_layout", "sqlite_master");
+
+        let tables = sqlx::query(
+            "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+        )
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| db_error("table_layout", e))?;
+
+        let mut layout = Vec::with_capacity(tables.len());
+        for table in tables {
+            let name: String = table.get("name");
+            let columns =
+                sqlx::query("SELECT name, type, \"notnull\", pk FROM pragma_table_info(?)")
+                    .bind(&name)
+                    .fetch_all(self.pool())
+                    .await
+                    .map_err(|e| db_error("table_layout", e))?
+                    .into_iter()
+                    .map(|column| ColumnLayout {
+                        name: column.get("name"),
+                        sql_type: column.get("type"),
+                        not_null: column.get::<i64, _>("notnull") != 0,
+                        primary_key: column.get::<i64, _>("pk") != 0,
+                    })
+                    .collect();
+            let indexes = sqlx::query(
+                "SELECT sql FROM sqlite_master WHERE type = 'index' AND tbl_name = ? AND sql IS NOT NULL ORDER BY name",
+            )
+            .bind(&name)
+            .fetch_all(self.pool())
+            .await
+            .map_err(|e| db_error("table_layout", e))?
+            .into_iter()
+            .map(|index| index.get("sql"))
+            .collect();
+            layout.push(TableLayout {
+                name,
+                columns,
+                indexes,
+            });
+        }
+
+        log_db_operation_success("table_layout", "sqlite_master", 0);
+        Ok(layout)
+    }
+}
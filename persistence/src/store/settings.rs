@@ -3,7 +3,7 @@ use crate::logging::{
     log_db_operation_error, log_db_operation_start, log_db_operation_success, log_deserialization,
     log_serialization,
 };
-use crate::models::AppSettings;
+use crate::models::{AppSettings, UiState};
 use sqlx::Row;
 
 impl Store {
@@ -60,4 +60,56 @@ impl Store {
         log_db_operation_success("save_settings", "settings", 0);
         Ok(())
     }
+
+    /// Stored next to the app settings as its own row, so the frequent writes
+    /// from window moves never race a settings form save.
+    pub async fn load_ui_state(&self) -> Result<Option<UiState>, String> {
+        log_db_operation_start("load_ui_state", "settings");
+
+        let row = sqlx::query("SELECT data FROM settings WHERE id = 'ui_state'")
+            .fetch_optional(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error("load_ui_state", "settings", &e.to_string());
+                format!("Database error: {}", e)
+            })?;
+
+        let Some(row) = row else {
+            log_db_operation_success("load_ui_state", "settings", 0);
+            return Ok(None);
+        };
+
+        let json_data: String = row.get("data");
+        log_deserialization("UiState", json_data.len());
+        let state = serde_json::from_str(&json_data).map_err(|e| {
+            log_db_operation_error("load_ui_state", "settings", &e.to_string());
+            format!("Deserialization error: {}", e)
+        })?;
+
+        log_db_operation_success("load_ui_state", "settings", 0);
+        Ok(Some(state))
+    }
+
+    pub async fn save_ui_state(&self, state: &UiState) -> Result<(), String> {
+        log_db_operation_start("save_ui_state", "settings");
+
+        let json_data = serde_json::to_string(state).map_err(|e| {
+            log_db_operation_error("save_ui_state", "settings", &e.to_string());
+            format!("Serialization error: {}", e)
+        })?;
+
+        log_serialization("UiState", json_data.len());
+
+        sqlx::query("INSERT OR REPLACE INTO settings (id, data) VALUES ('ui_state', ?)")
+            .bind(&json_data)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error("save_ui_state", "settings", &e.to_string());
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success("save_ui_state", "settings", 0);
+        Ok(())
+    }
 }
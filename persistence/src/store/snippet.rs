@@ -0,0 +1,107 @@
+use super::Store;
+use crate::logging::{
+    log_db_operation_error, log_db_operation_start, log_db_operation_success, log_deserialization,
+    log_serialization,
+};
+use crate::models::TaskSnippet;
+use sqlx::Row;
+
+impl Store {
+    pub async fn save_snippet(&self, snippet: &TaskSnippet) -> Result<(), String> {
+        log_db_operation_start("save_snippet", "task_snippets");
+
+        let json_data = serde_json::to_string(snippet).map_err(|e| {
+            log_db_operation_error("save_snippet", "task_snippets", &e.to_string());
+            format!("Serialization error: {}", e)
+        })?;
+
+        log_serialization("TaskSnippet", json_data.len());
+
+        sqlx::query("INSERT OR REPLACE INTO task_snippets (id, data) VALUES (?, ?)")
+            .bind(&snippet.id)
+            .bind(&json_data)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error("save_snippet", "task_snippets", &e.to_string());
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success("save_snippet", "task_snippets", 0);
+        Ok(())
+    }
+
+    pub async fn get_snippet(&self, id: &str) -> Result<Option<TaskSnippet>, String> {
+        log_db_operation_start("get_snippet", "task_snippets");
+
+        let row = sqlx::query("SELECT data FROM task_snippets WHERE id = ?")
+            .bind(id)
+            .fetch_optional(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error("get_snippet", "task_snippets", &e.to_string());
+                format!("Database error: {}", e)
+            })?;
+
+        let snippet = match row {
+            Some(row) => {
+                let json_data: String = row.get("data");
+                log_deserialization("TaskSnippet", json_data.len());
+                Some(serde_json::from_str(&json_data).map_err(|e| {
+                    log_db_operation_error("get_snippet", "task_snippets", &e.to_string());
+                    format!("Deserialization error: {}", e)
+                })?)
+            }
+            None => None,
+        };
+
+        log_db_operation_success("get_snippet", "task_snippets", 0);
+        Ok(snippet)
+    }
+
+    /// Every snippet, by name.
+    pub async fn list_snippets(&self) -> Result<Vec<TaskSnippet>, String> {
+        log_db_operation_start("list_snippets", "task_snippets");
+
+        let rows = sqlx::query(
+            "SELECT data FROM task_snippets ORDER BY lower(json_extract(data, '$.name')), id",
+        )
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error("list_snippets", "task_snippets", &e.to_string());
+            format!("Database error: {}", e)
+        })?;
+
+        let mut snippets = Vec::new();
+        for row in rows {
+            let json_data: String = row.get("data");
+            log_deserialization("TaskSnippet", json_data.len());
+
+            let snippet = serde_json::from_str(&json_data).map_err(|e| {
+                log_db_operation_error("list_snippets", "task_snippets", &e.to_string());
+                format!("Deserialization error: {}", e)
+            })?;
+            snippets.push(snippet);
+        }
+
+        log_db_operation_success("list_snippets", "task_snippets", 0);
+        Ok(snippets)
+    }
+
+    pub async fn delete_snippet(&self, id: &str) -> Result<(), String> {
+        log_db_operation_start("delete_snippet", "task_snippets");
+
+        sqlx::query("DELETE FROM task_snippets WHERE id = ?")
+            .bind(id)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error("delete_snippet", "task_snippets", &e.to_string());
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success("delete_snippet", "task_snippets", 0);
+        Ok(())
+    }
+}
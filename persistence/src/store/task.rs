@@ -1,31 +1,40 @@
+use super::blob::{externalize, replace_blob_refs, task_owner, TASK_BLOB_PATHS};
 use super::Store;
 use crate::logging::{
     log_db_operation_error, log_db_operation_start, log_db_operation_success, log_deserialization,
     log_serialization,
 };
-use crate::models::TaskExecution;
+use crate::models::{TaskExecution, TaskExecutionStatus};
 use sqlx::Row;
 
 impl Store {
     pub async fn save_task_execution(&self, task: TaskExecution) -> Result<(), String> {
         log_db_operation_start("save_task_execution", "task_executions");
 
-        let json_data = serde_json::to_string(&task).map_err(|e| {
+        let mut value = serde_json::to_value(&task).map_err(|e| {
             log_db_operation_error("save_task_execution", "task_executions", &e.to_string());
             format!("Serialization error: {}", e)
         })?;
+        let blobs = externalize(&mut value, TASK_BLOB_PATHS);
+        let json_data = value.to_string();
 
         log_serialization("TaskExecution", json_data.len());
 
+        let db_error = |e: sqlx::Error| {
+            log_db_operation_error("save_task_execution", "task_executions", &e.to_string());
+            format!("Database error: {}", e)
+        };
+        let mut tx = self.pool().begin().await.map_err(db_error)?;
+        replace_blob_refs(&mut tx, &task_owner(&task.id), &blobs)
+            .await
+            .map_err(db_error)?;
         sqlx::query("INSERT OR REPLACE INTO task_executions (id, data) VALUES (?, ?)")
             .bind(&task.id)
             .bind(&json_data)
-            .execute(self.pool())
+            .execute(&mut *tx)
             .await
-            .map_err(|e| {
-                log_db_operation_error("save_task_execution", "task_executions", &e.to_string());
-                format!("Database error: {}", e)
-            })?;
+            .map_err(db_error)?;
+        tx.commit().await.map_err(db_error)?;
 
         log_db_operation_success("save_task_execution", "task_executions", 0);
         Ok(())
@@ -38,8 +47,10 @@ impl Store {
         log_db_operation_start("get_tasks_for_workflow", "task_executions");
 
         let rows = sqlx::query(
-            "SELECT data FROM task_executions ORDER BY json_extract(data, '$.created_at') ASC",
+            "SELECT data FROM task_executions WHERE json_extract(data, '$.workflow_id') = ? \
+             ORDER BY json_extract(data, '$.created_at') ASC",
         )
+        .bind(workflow_id)
         .fetch_all(self.pool())
         .await
         .map_err(|e| {
@@ -52,10 +63,12 @@ impl Store {
             let json_data: String = row.get("data");
             log_deserialization("TaskExecution", json_data.len());
 
-            let task: TaskExecution = serde_json::from_str(&json_data).map_err(|e| {
-                log_db_operation_error("get_tasks_for_workflow", "task_executions", &e.to_string());
-                format!("Deserialization error: {}", e)
-            })?;
+            let task: TaskExecution = self
+                .decode_with_blobs(&json_data, TASK_BLOB_PATHS)
+                .await
+                .inspect_err(|e| {
+                    log_db_operation_error("get_tasks_for_workflow", "task_executions", e);
+                })?;
 
             if task.workflow_id == workflow_id {
                 tasks.push(task);
@@ -73,31 +86,32 @@ impl Store {
     pub async fn get_tasks_waiting_for_input(&self) -> Result<Vec<TaskExecution>, String> {
         log_db_operation_start("get_tasks_waiting_for_input", "task_executions");
 
-        let rows = sqlx::query("SELECT data FROM task_executions")
-            .fetch_all(self.pool())
-            .await
-            .map_err(|e| {
-                log_db_operation_error(
-                    "get_tasks_waiting_for_input",
-                    "task_executions",
-                    &e.to_string(),
-                );
-                format!("Database error: {}", e)
-            })?;
+        let rows = sqlx::query(
+            "SELECT data FROM task_executions WHERE json_extract(data, '$.status') = ?",
+        )
+        .bind(TaskExecutionStatus::WaitingForInput.as_str())
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error(
+                "get_tasks_waiting_for_input",
+                "task_executions",
+                &e.to_string(),
+            );
+            format!("Database error: {}", e)
+        })?;
 
         let mut tasks = Vec::new();
         for row in rows {
             let json_data: String = row.get("data");
             log_deserialization("TaskExecution", json_data.len());
 
-            let task: TaskExecution = serde_json::from_str(&json_data).map_err(|e| {
-                log_db_operation_error(
-                    "get_tasks_waiting_for_input",
-                    "task_executions",
-                    &e.to_string(),
-                );
-                format!("Deserialization error: {}", e)
-            })?;
+            let task: TaskExecution = self
+                .decode_with_blobs(&json_data, TASK_BLOB_PATHS)
+                .await
+                .inspect_err(|e| {
+                    log_db_operation_error("get_tasks_waiting_for_input", "task_executions", e);
+                })?;
 
             if task.is_waiting_for_input() {
                 tasks.push(task);
@@ -114,31 +128,38 @@ impl Store {
     ) -> Result<Vec<TaskExecution>, String> {
         log_db_operation_start("get_tasks_waiting_for_input_in_workflow", "task_executions");
 
-        let rows = sqlx::query("SELECT data FROM task_executions")
-            .fetch_all(self.pool())
-            .await
-            .map_err(|e| {
-                log_db_operation_error(
-                    "get_tasks_waiting_for_input_in_workflow",
-                    "task_executions",
-                    &e.to_string(),
-                );
-                format!("Database error: {}", e)
-            })?;
+        let rows = sqlx::query(
+            "SELECT data FROM task_executions \
+             WHERE json_extract(data, '$.workflow_id') = ? AND json_extract(data, '$.status') = ?",
+        )
+        .bind(workflow_id)
+        .bind(TaskExecutionStatus::WaitingForInput.as_str())
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error(
+                "get_tasks_waiting_for_input_in_workflow",
+                "task_executions",
+                &e.to_string(),
+            );
+            format!("Database error: {}", e)
+        })?;
 
         let mut tasks = Vec::new();
         for row in rows {
             let json_data: String = row.get("data");
             log_deserialization("TaskExecution", json_data.len());
 
-            let task: TaskExecution = serde_json::from_str(&json_data).map_err(|e| {
-                log_db_operation_error(
-                    "get_tasks_waiting_for_input_in_workflow",
-                    "task_executions",
-                    &e.to_string(),
-                );
-                format!("Deserialization error: {}", e)
-            })?;
+            let task: TaskExecution = self
+                .decode_with_blobs(&json_data, TASK_BLOB_PATHS)
+                .await
+                .inspect_err(|e| {
+                    log_db_operation_error(
+                        "get_tasks_waiting_for_input_in_workflow",
+                        "task_executions",
+                        e,
+                    );
+                })?;
 
             if task.workflow_id == workflow_id && task.is_waiting_for_input() {
                 tasks.push(task);
@@ -180,14 +201,12 @@ impl Store {
         let json_data: String = row.get("data");
         log_deserialization("TaskExecution", json_data.len());
 
-        let task: TaskExecution = serde_json::from_str(&json_data).map_err(|e| {
-            log_db_operation_error(
-                "get_task_with_input_request",
-                "task_executions",
-                &e.to_string(),
-            );
-            format!("Deserialization error: {}", e)
-        })?;
+        let task: TaskExecution = self
+            .decode_with_blobs(&json_data, TASK_BLOB_PATHS)
+            .await
+            .inspect_err(|e| {
+                log_db_operation_error("get_task_with_input_request", "task_executions", e);
+            })?;
 
         log_db_operation_success("get_task_with_input_request", "task_executions", 0);
         Ok(Some(task))
@@ -0,0 +1,118 @@
+use super::Store;
+use crate::logging::{
+    log_db_operation_error, log_db_operation_start, log_db_operation_success, log_deserialization,
+    log_serialization,
+};
+use crate::models::CachedTaskResult;
+use sqlx::Row;
+
+impl Store {
+    /// The cached result for `key`. Expired entries are deleted and reported as missing.
+    pub async fn get_cached_task_result(
+        &self,
+        key: &str,
+    ) -> Result<Option<CachedTaskResult>, String> {
+        log_db_operation_start("get_cached_task_result", "task_result_cache");
+
+        let row = sqlx::query("SELECT data FROM task_result_cache WHERE id = ?")
+            .bind(key)
+            .fetch_optional(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error(
+                    "get_cached_task_result",
+                    "task_result_cache",
+                    &e.to_string(),
+                );
+                format!("Database error: {}", e)
+            })?;
+
+        let Some(row) = row else {
+            log_db_operation_success("get_cached_task_result", "task_result_cache", 0);
+            return Ok(None);
+        };
+
+        let json_data: String = row.get("data");
+        log_deserialization("CachedTaskResult", json_data.len());
+        let cached: CachedTaskResult = serde_json::from_str(&json_data).map_err(|e| {
+            log_db_operation_error(
+                "get_cached_task_result",
+                "task_result_cache",
+                &e.to_string(),
+            );
+            format!("Deserialization error: {}", e)
+        })?;
+
+        if cached.is_expired(chrono::Utc::now()) {
+            sqlx::query("DELETE FROM task_result_cache WHERE id = ?")
+                .bind(key)
+                .execute(self.pool())
+                .await
+                .map_err(|e| {
+                    log_db_operation_error(
+                        "get_cached_task_result",
+                        "task_result_cache",
+                        &e.to_string(),
+                    );
+                    format!("Database error: {}", e)
+                })?;
+            log_db_operation_success("get_cached_task_result", "task_result_cache", 0);
+            return Ok(None);
+        }
+
+        log_db_operation_success("get_cached_task_result", "task_result_cache", 0);
+        Ok(Some(cached))
+    }
+
+    pub async fn save_cached_task_result(&self, cached: &CachedTaskResult) -> Result<(), String> {
+        log_db_operation_start("save_cached_task_result", "task_result_cache");
+
+        let json_data = serde_json::to_string(cached).map_err(|e| {
+            log_db_operation_error(
+                "save_cached_task_result",
+                "task_result_cache",
+                &e.to_string(),
+            );
+            format!("Serialization error: {}", e)
+        })?;
+
+        log_serialization("CachedTaskResult", json_data.len());
+
+        sqlx::query("INSERT OR REPLACE INTO task_result_cache (id, data) VALUES (?, ?)")
+            .bind(&cached.key)
+            .bind(&json_data)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error(
+                    "save_cached_task_result",
+                    "task_result_cache",
+                    &e.to_string(),
+                );
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success("save_cached_task_result", "task_result_cache", 0);
+        Ok(())
+    }
+
+    /// Drop every cached task result. Returns how many were removed.
+    pub async fn clear_task_result_cache(&self) -> Result<u64, String> {
+        log_db_operation_start("clear_task_result_cache", "task_result_cache");
+
+        let result = sqlx::query("DELETE FROM task_result_cache")
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error(
+                    "clear_task_result_cache",
+                    "task_result_cache",
+                    &e.to_string(),
+                );
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success("clear_task_result_cache", "task_result_cache", 0);
+        Ok(result.rows_affected())
+    }
+}
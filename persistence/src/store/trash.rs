@@ -0,0 +1,155 @@
+use super::Store;
+use crate::logging::{log_db_operation_error, log_db_operation_start, log_db_operation_success};
+use crate::models::TrashKind;
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+
+fn db_error(operation: &str, e: sqlx::Error) -> String {
+    log_db_operation_error(operation, "trash", &e.to_string());
+    format!("Database error: {}", e)
+}
+
+impl Store {
+    /// Move the `kind` items with these ids to the trash under `batch`, all in
+    /// one transaction. Ids that don't exist are skipped. Returns how many
+    /// were moved.
+    pub async fn move_to_trash(
+        &self,
+        kind: TrashKind,
+        ids: &[String],
+        batch: &str,
+    ) -> Result<usize, String> {
+        log_db_operation_start("move_to_trash", kind.table());
+
+        let now = Utc::now().timestamp_millis();
+        let mut tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| db_error("move_to_trash", e))?;
+        let mut moved = 0;
+        for id in ids {
+            sqlx::query(&format!(
+                "INSERT OR REPLACE INTO trash (kind, id, data, batch, deleted_at) SELECT ?, id, data, ?, ? FROM {} WHERE id = ?",
+                kind.table()
+            ))
+            .bind(kind.as_str())
+            .bind(batch)
+            .bind(now)
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| db_error("move_to_trash", e))?;
+            let result = sqlx::query(&format!("DELETE FROM {} WHERE id = ?", kind.table()))
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| db_error("move_to_trash", e))?;
+            moved += result.rows_affected() as usize;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| db_error("move_to_trash", e))?;
+
+        log_db_operation_success("move_to_trash", kind.table(), 0);
+        Ok(moved)
+    }
+
+    /// Put back what was trashed under `batch` no earlier than
+    /// `deleted_after`, in one transaction. An item whose id has been taken
+    /// again in the meantime stays in the trash. Returns the restored ids.
+    pub async fn restore_from_trash(
+        &self,
+        batch: &str,
+        deleted_after: DateTime<Utc>,
+    ) -> Result<Vec<String>, String> {
+        log_db_operation_start("restore_from_trash", "trash");
+
+        let mut tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| db_error("restore_from_trash", e))?;
+        let rows = sqlx::query(
+            "SELECT kind, id, data FROM trash WHERE batch = ? AND deleted_at >= ? ORDER BY rowid",
+        )
+        .bind(batch)
+        .bind(deleted_after.timestamp_millis())
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| db_error("restore_from_trash", e))?;
+
+        let mut restored = Vec::new();
+        for row in rows {
+            let kind: String = row.get("kind");
+            let id: String = row.get("id");
+            let data: String = row.get("data");
+            let Some(trash_kind) = TrashKind::from_name(&kind) else {
+                continue;
+            };
+            let result = sqlx::query(&format!(
+                "INSERT OR IGNORE INTO {} (id, data) VALUES (?, ?)",
+                trash_kind.table()
+            ))
+            .bind(&id)
+            .bind(&data)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| db_error("restore_from_trash", e))?;
+            if result.rows_affected() == 0 {
+                continue;
+            }
+            sqlx::query("DELETE FROM trash WHERE kind = ? AND id = ?")
+                .bind(&kind)
+                .bind(&id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| db_error("restore_from_trash", e))?;
+            restored.push(id);
+        }
+        tx.commit()
+            .await
+            .map_err(|e| db_error("restore_from_trash", e))?;
+
+        log_db_operation_success("restore_from_trash", "trash", 0);
+        Ok(restored)
+    }
+
+    /// Delete for good everything trashed before `before`, along with the
+    /// provenance of purged workflows whose id is not in use again. Returns
+    /// how many items were purged.
+    pub async fn purge_trash(&self, before: DateTime<Utc>) -> Result<usize, String> {
+        log_db_operation_start("purge_trash", "trash");
+
+        let mut tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| db_error("purge_trash", e))?;
+        let workflow_ids: Vec<String> =
+            sqlx::query_scalar("SELECT id FROM trash WHERE kind = ? AND deleted_at < ?")
+                .bind(TrashKind::Workflow.as_str())
+                .bind(before.timestamp_millis())
+                .fetch_all(&mut *tx)
+                .await
+                .map_err(|e| db_error("purge_trash", e))?;
+        let result = sqlx::query("DELETE FROM trash WHERE deleted_at < ?")
+            .bind(before.timestamp_millis())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| db_error("purge_trash", e))?;
+        for id in &workflow_ids {
+            sqlx::query(
+                "DELETE FROM workflow_provenance WHERE id = ? AND id NOT IN (SELECT id FROM workflows)",
+            )
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| db_error("purge_trash", e))?;
+        }
+        tx.commit().await.map_err(|e| db_error("purge_trash", e))?;
+
+        log_db_operation_success("purge_trash", "trash", 0);
+        Ok(result.rows_affected() as usize)
+    }
+}
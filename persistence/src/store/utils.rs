@@ -12,6 +12,19 @@ impl Store {
             "prompts",
             "audit_events",
             "settings",
+            "task_result_cache",
+            "execution_checkpoints",
+            "archived_executions",
+            "workflow_provenance",
+            "notifications",
+            "task_snippets",
+            "workflow_tests",
+            "execution_filter_presets",
+            "blob_refs",
+            "blobs",
+            "resource_locks",
+            "output_journal",
+            "trash",
         ];
 
         for table in &tables {
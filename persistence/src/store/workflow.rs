@@ -104,6 +104,15 @@ impl Store {
                 format!("Database error: {}", e)
             })?;
 
+        sqlx::query("DELETE FROM workflow_provenance WHERE id = ?")
+            .bind(id)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error("delete_workflow", "workflow_provenance", &e.to_string());
+                format!("Database error: {}", e)
+            })?;
+
         log_db_operation_success("delete_workflow", "workflows", 0);
         Ok(())
     }
@@ -0,0 +1,111 @@
+use super::Store;
+use crate::logging::{
+    log_db_operation_error, log_db_operation_start, log_db_operation_success, log_deserialization,
+    log_serialization,
+};
+use crate::models::WorkflowTest;
+use sqlx::Row;
+
+impl Store {
+    pub async fn save_workflow_test(&self, test: &WorkflowTest) -> Result<(), String> {
+        log_db_operation_start("save_workflow_test", "workflow_tests");
+
+        let json_data = serde_json::to_string(test).map_err(|e| {
+            log_db_operation_error("save_workflow_test", "workflow_tests", &e.to_string());
+            format!("Serialization error: {}", e)
+        })?;
+
+        log_serialization("WorkflowTest", json_data.len());
+
+        sqlx::query("INSERT OR REPLACE INTO workflow_tests (id, data) VALUES (?, ?)")
+            .bind(&test.id)
+            .bind(&json_data)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error("save_workflow_test", "workflow_tests", &e.to_string());
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success("save_workflow_test", "workflow_tests", 0);
+        Ok(())
+    }
+
+    pub async fn get_workflow_test(&self, id: &str) -> Result<Option<WorkflowTest>, String> {
+        log_db_operation_start("get_workflow_test", "workflow_tests");
+
+        let row = sqlx::query("SELECT data FROM workflow_tests WHERE id = ?")
+            .bind(id)
+            .fetch_optional(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error("get_workflow_test", "workflow_tests", &e.to_string());
+                format!("Database error: {}", e)
+            })?;
+
+        let test = match row {
+            Some(row) => {
+                let json_data: String = row.get("data");
+                log_deserialization("WorkflowTest", json_data.len());
+                Some(serde_json::from_str(&json_data).map_err(|e| {
+                    log_db_operation_error("get_workflow_test", "workflow_tests", &e.to_string());
+                    format!("Deserialization error: {}", e)
+                })?)
+            }
+            None => None,
+        };
+
+        log_db_operation_success("get_workflow_test", "workflow_tests", 0);
+        Ok(test)
+    }
+
+    /// Tests of one workflow, oldest first.
+    pub async fn list_workflow_tests(
+        &self,
+        workflow_id: &str,
+    ) -> Result<Vec<WorkflowTest>, String> {
+        log_db_operation_start("list_workflow_tests", "workflow_tests");
+
+        let rows = sqlx::query(
+            "SELECT data FROM workflow_tests WHERE json_extract(data, '$.workflow_id') = ? \
+             ORDER BY json_extract(data, '$.created_at'), id",
+        )
+        .bind(workflow_id)
+        .fetch_all(self.pool())
+        .await
+        .map_err(|e| {
+            log_db_operation_error("list_workflow_tests", "workflow_tests", &e.to_string());
+            format!("Database error: {}", e)
+        })?;
+
+        let mut tests = Vec::new();
+        for row in rows {
+            let json_data: String = row.get("data");
+            log_deserialization("WorkflowTest", json_data.len());
+            let test = serde_json::from_str(&json_data).map_err(|e| {
+                log_db_operation_error("list_workflow_tests", "workflow_tests", &e.to_string());
+                format!("Deserialization error: {}", e)
+            })?;
+            tests.push(test);
+        }
+
+        log_db_operation_success("list_workflow_tests", "workflow_tests", 0);
+        Ok(tests)
+    }
+
+    pub async fn delete_workflow_test(&self, id: &str) -> Result<(), String> {
+        log_db_operation_start("delete_workflow_test", "workflow_tests");
+
+        sqlx::query("DELETE FROM workflow_tests WHERE id = ?")
+            .bind(id)
+            .execute(self.pool())
+            .await
+            .map_err(|e| {
+                log_db_operation_error("delete_workflow_test", "workflow_tests", &e.to_string());
+                format!("Database error: {}", e)
+            })?;
+
+        log_db_operation_success("delete_workflow_test", "workflow_tests", 0);
+        Ok(())
+    }
+}
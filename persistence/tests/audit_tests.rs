@@ -1,5 +1,5 @@
 use chrono::Utc;
-use s_e_e_persistence::{AuditEvent, AuditStatus, Store};
+use s_e_e_persistence::{AuditChange, AuditChangeOp, AuditEvent, AuditStatus, Store};
 
 async fn create_test_store() -> Store {
     Store::new(":memory:").await.unwrap()
@@ -59,6 +59,8 @@ async fn test_audit_event_serialization() {
         status: AuditStatus::Success,
         timestamp: Utc::now(),
         changes_count: 5,
+        changes: Vec::new(),
+        changes_truncated: false,
         message: "Task completed successfully".to_string(),
     };
 
@@ -74,6 +76,8 @@ async fn test_audit_event_validation_error() {
         status: AuditStatus::Success,
         timestamp: Utc::now(),
         changes_count: 5,
+        changes: Vec::new(),
+        changes_truncated: false,
         message: "Test message".to_string(),
     };
 
@@ -90,6 +94,8 @@ async fn test_audit_event_empty_message() {
         status: AuditStatus::Success,
         timestamp: Utc::now(),
         changes_count: 5,
+        changes: Vec::new(),
+        changes_truncated: false,
         message: "".to_string(),
     };
 
@@ -97,3 +103,34 @@ async fn test_audit_event_empty_message() {
     assert!(result.is_err());
     assert!(result.unwrap_err().contains("message cannot be empty"));
 }
+
+#[test]
+fn test_audit_event_without_changes_deserializes() {
+    let json = r#"{
+        "id": "audit-1",
+        "task_id": "task-1",
+        "status": "success",
+        "timestamp": "2024-01-15T10:30:45Z",
+        "changes_count": 1,
+        "message": "Completed task: Task 1"
+    }"#;
+
+    let event: AuditEvent = serde_json::from_str(json).unwrap();
+    assert!(event.changes.is_empty());
+    assert!(!event.changes_truncated);
+}
+
+#[tokio::test]
+async fn test_log_audit_event_with_changes() {
+    let store = create_test_store().await;
+    let mut event = AuditEvent::success("task-1".to_string(), "Task completed".to_string(), 1);
+    event.changes.push(AuditChange {
+        op: AuditChangeOp::Add,
+        path: "/task-1".to_string(),
+        before: None,
+        after: Some(serde_json::json!("done")),
+    });
+
+    let result = store.log_audit_event(event).await;
+    assert!(result.is_ok());
+}
@@ -0,0 +1,132 @@
+use s_e_e_persistence::{
+    BlobStats, Store, TaskExecution, TaskExecutionStatus, WorkflowExecution,
+    WorkflowExecutionStatus,
+};
+use std::collections::HashMap;
+
+async fn create_test_store() -> Store {
+    Store::new(":memory:").await.unwrap()
+}
+
+fn big_log(seed: &str) -> Vec<String> {
+    (0..500).map(|n| format!("{} line {}", seed, n)).collect()
+}
+
+fn execution(id: &str, log: Vec<String>) -> WorkflowExecution {
+    let mut per_task_logs = HashMap::new();
+    per_task_logs.insert("build".to_string(), log);
+    per_task_logs.insert("lint".to_string(), vec!["ok".to_string()]);
+    WorkflowExecution {
+        id: id.to_string(),
+        workflow_name: "Build".to_string(),
+        status: WorkflowExecutionStatus::Complete,
+        per_task_logs,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_repeated_output_is_stored_once() {
+    let store = create_test_store().await;
+    let first = execution("exec-1", big_log("compile"));
+    let second = execution("exec-2", big_log("compile"));
+
+    store.save_workflow_execution(first.clone()).await.unwrap();
+    store.save_workflow_execution(second.clone()).await.unwrap();
+
+    let stats = store.blob_stats().await.unwrap();
+    assert_eq!(stats.blobs, 1);
+    assert_eq!(stats.references, 2);
+
+    let loaded = store.get_workflow_execution("exec-2").await.unwrap();
+    assert_eq!(loaded, Some(second));
+    let listed = store.list_workflow_executions().await.unwrap();
+    assert!(listed.contains(&first));
+}
+
+#[tokio::test]
+async fn test_small_payloads_stay_inline() {
+    let store = create_test_store().await;
+    store
+        .save_workflow_execution(execution("exec-1", vec!["short".to_string()]))
+        .await
+        .unwrap();
+
+    assert_eq!(store.blob_stats().await.unwrap(), BlobStats::default());
+}
+
+#[tokio::test]
+async fn test_resaving_replaces_references() {
+    let store = create_test_store().await;
+    store
+        .save_workflow_execution(execution("exec-1", big_log("first")))
+        .await
+        .unwrap();
+    store
+        .save_workflow_execution(execution("exec-1", big_log("second")))
+        .await
+        .unwrap();
+
+    let stats = store.blob_stats().await.unwrap();
+    assert_eq!(stats.blobs, 2);
+    assert_eq!(stats.references, 1);
+
+    let vacuum = store.vacuum_blobs().await.unwrap();
+    assert_eq!(vacuum.removed, 1);
+    assert!(vacuum.bytes_reclaimed > 0);
+    assert_eq!(store.blob_stats().await.unwrap().blobs, 1);
+}
+
+#[tokio::test]
+async fn test_vacuum_keeps_blobs_that_are_still_referenced() {
+    let store = create_test_store().await;
+    store
+        .save_workflow_execution(execution("exec-1", big_log("compile")))
+        .await
+        .unwrap();
+    store
+        .save_workflow_execution(execution("exec-2", big_log("compile")))
+        .await
+        .unwrap();
+
+    store.delete_workflow_execution("exec-1").await.unwrap();
+    assert_eq!(store.vacuum_blobs().await.unwrap().removed, 0);
+    assert!(store
+        .get_workflow_execution("exec-2")
+        .await
+        .unwrap()
+        .is_some());
+
+    store.delete_workflow_execution("exec-2").await.unwrap();
+    assert_eq!(store.vacuum_blobs().await.unwrap().removed, 1);
+    assert_eq!(store.blob_stats().await.unwrap(), BlobStats::default());
+}
+
+#[tokio::test]
+async fn test_task_output_is_deduplicated() {
+    let store = create_test_store().await;
+    let output = big_log("test").join("\n");
+    for id in ["task-1", "task-2"] {
+        let task = TaskExecution {
+            id: id.to_string(),
+            workflow_id: "exec-1".to_string(),
+            name: "Test".to_string(),
+            status: TaskExecutionStatus::Complete,
+            output: Some(output.clone()),
+            completed_at: Some(chrono::Utc::now()),
+            ..Default::default()
+        };
+        store.save_task_execution(task).await.unwrap();
+    }
+
+    assert_eq!(store.blob_stats().await.unwrap().blobs, 1);
+    let tasks = store.get_tasks_for_workflow("exec-1").await.unwrap();
+    assert_eq!(tasks.len(), 2);
+    assert!(tasks.iter().all(|t| t.output.as_deref() == Some(&output)));
+
+    store
+        .delete_workflow_metadata_and_tasks("exec-1")
+        .await
+        .unwrap();
+    assert_eq!(store.vacuum_blobs().await.unwrap().removed, 1);
+}
@@ -0,0 +1,75 @@
+use chrono::Utc;
+use s_e_e_persistence::{ExecutionCheckpoint, Store, WorkflowExecution};
+use serde_json::json;
+
+async fn create_test_store() -> Store {
+    Store::new(":memory:").await.unwrap()
+}
+
+fn checkpoint(completed: &[&str]) -> ExecutionCheckpoint {
+    ExecutionCheckpoint {
+        execution_id: "exec-1".to_string(),
+        completed_tasks: completed.iter().map(|id| id.to_string()).collect(),
+        skipped_tasks: Vec::new(),
+        data: json!({ "build": "built" }),
+        scratch: [("cursor".to_string(), json!(3))].into(),
+        saved_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_save_replaces_earlier_checkpoint() {
+    let store = create_test_store().await;
+    store
+        .save_execution_checkpoint(&checkpoint(&["build"]))
+        .await
+        .unwrap();
+    let latest = checkpoint(&["build", "test"]);
+    store.save_execution_checkpoint(&latest).await.unwrap();
+
+    let loaded = store.get_execution_checkpoint("exec-1").await.unwrap();
+    assert_eq!(loaded, Some(latest));
+    assert!(store
+        .get_execution_checkpoint("missing")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_delete_execution_checkpoint() {
+    let store = create_test_store().await;
+    store
+        .save_execution_checkpoint(&checkpoint(&["build"]))
+        .await
+        .unwrap();
+
+    store.delete_execution_checkpoint("exec-1").await.unwrap();
+    store.delete_execution_checkpoint("exec-1").await.unwrap();
+    assert!(store
+        .get_execution_checkpoint("exec-1")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_deleting_execution_deletes_its_checkpoint() {
+    let store = create_test_store().await;
+    let execution = WorkflowExecution {
+        id: "exec-1".to_string(),
+        ..Default::default()
+    };
+    store.save_workflow_execution(execution).await.unwrap();
+    store
+        .save_execution_checkpoint(&checkpoint(&["build"]))
+        .await
+        .unwrap();
+
+    store.delete_workflow_execution("exec-1").await.unwrap();
+    assert!(store
+        .get_execution_checkpoint("exec-1")
+        .await
+        .unwrap()
+        .is_none());
+}
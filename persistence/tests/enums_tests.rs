@@ -10,6 +10,7 @@ fn test_execution_status_variants() {
         WorkflowExecutionStatus::WaitingForInput.to_string(),
         "waiting_for_input"
     );
+    assert_eq!(WorkflowExecutionStatus::Cancelled.to_string(), "cancelled");
 }
 
 #[test]
@@ -26,6 +27,15 @@ fn test_execution_status_waiting_for_input() {
     assert_eq!(deserialized, WorkflowExecutionStatus::WaitingForInput);
 }
 
+#[test]
+fn test_execution_status_cancelled() {
+    let json = serde_json::to_string(&WorkflowExecutionStatus::Cancelled).unwrap();
+    assert_eq!(json, "\"cancelled\"");
+
+    let deserialized: WorkflowExecutionStatus = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, WorkflowExecutionStatus::Cancelled);
+}
+
 #[test]
 fn test_execution_status_serialization() {
     let status = WorkflowExecutionStatus::Running;
@@ -104,6 +114,9 @@ fn test_task_status_variants() {
 
     let waiting_json = serde_json::to_string(&TaskExecutionStatus::WaitingForInput).unwrap();
     assert_eq!(waiting_json, "\"waiting_for_input\"");
+
+    let skipped_json = serde_json::to_string(&TaskExecutionStatus::Skipped).unwrap();
+    assert_eq!(skipped_json, "\"skipped\"");
 }
 
 #[test]
@@ -125,6 +138,7 @@ fn test_task_status_all_variants_serialization() {
         TaskExecutionStatus::Complete,
         TaskExecutionStatus::Failed,
         TaskExecutionStatus::WaitingForInput,
+        TaskExecutionStatus::Skipped,
     ];
 
     for variant in variants {
@@ -1,6 +1,7 @@
 use chrono::Utc;
 use s_e_e_persistence::{
-    Store, TaskExecution, TaskExecutionStatus, WorkflowExecution, WorkflowExecutionStatus,
+    ExecutionFilter, ExecutionFilterPreset, Store, TaskExecution, TaskExecutionStatus,
+    WorkflowExecution, WorkflowExecutionStatus,
 };
 use std::collections::HashMap;
 
@@ -25,6 +26,13 @@ fn create_test_execution() -> WorkflowExecution {
         audit_trail: Vec::new(),
         per_task_logs: HashMap::new(),
         errors: Vec::new(),
+        parameters: std::collections::HashMap::new(),
+        triggered_by: None,
+        workflow_id: None,
+        labels: Vec::new(),
+        bounds: None,
+        manifest: None,
+        sealed_parameters: None,
     }
 }
 
@@ -279,3 +287,375 @@ async fn test_get_workflow_with_tasks_not_found() {
     assert!(result.is_err());
     assert!(result.unwrap_err().contains("not found"));
 }
+
+fn execution_at(id: &str, created_at: &str, status: WorkflowExecutionStatus) -> WorkflowExecution {
+    let created_at = chrono::DateTime::parse_from_rfc3339(created_at)
+        .unwrap()
+        .with_timezone(&Utc);
+    WorkflowExecution {
+        id: id.to_string(),
+        status,
+        created_at,
+        timestamp: created_at,
+        ..create_test_execution()
+    }
+}
+
+#[tokio::test]
+async fn test_daily_execution_stats() {
+    let store = create_test_store().await;
+    for execution in [
+        execution_at(
+            "old",
+            "2024-03-01T10:00:00Z",
+            WorkflowExecutionStatus::Failed,
+        ),
+        execution_at(
+            "a",
+            "2024-03-04T08:00:00Z",
+            WorkflowExecutionStatus::Complete,
+        ),
+        execution_at("b", "2024-03-04T23:59:59Z", WorkflowExecutionStatus::Failed),
+        execution_at(
+            "c",
+            "2024-03-06T00:00:01Z",
+            WorkflowExecutionStatus::Complete,
+        ),
+    ] {
+        store.save_workflow_execution(execution).await.unwrap();
+    }
+
+    let since = chrono::NaiveDate::from_ymd_opt(2024, 3, 2).unwrap();
+    let stats = store.daily_execution_stats(since).await.unwrap();
+
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats[0].date.to_string(), "2024-03-04");
+    assert_eq!((stats[0].total, stats[0].failed), (2, 1));
+    assert_eq!(stats[0].failure_rate(), 0.5);
+    assert_eq!(stats[1].date.to_string(), "2024-03-06");
+    assert_eq!((stats[1].total, stats[1].failed), (1, 0));
+}
+
+#[tokio::test]
+async fn test_list_workflow_health() {
+    let store = create_test_store().await;
+    let run = |id: &str, workflow: Option<&str>, at: &str, status| WorkflowExecution {
+        workflow_id: workflow.map(str::to_string),
+        ..execution_at(id, at, status)
+    };
+    for execution in [
+        run(
+            "deploy-1",
+            Some("deploy"),
+            "2024-03-01T10:00:00Z",
+            WorkflowExecutionStatus::Complete,
+        ),
+        run(
+            "deploy-2",
+            Some("deploy"),
+            "2024-03-02T10:00:00Z",
+            WorkflowExecutionStatus::Failed,
+        ),
+        run(
+            "deploy-3",
+            Some("deploy"),
+            "2024-03-03T10:00:00Z",
+            WorkflowExecutionStatus::Complete,
+        ),
+        run(
+            "deploy-4",
+            Some("deploy"),
+            "2024-03-04T10:00:00Z",
+            WorkflowExecutionStatus::Running,
+        ),
+        run(
+            "build-1",
+            Some("build"),
+            "2024-03-02T12:00:00Z",
+            WorkflowExecutionStatus::Failed,
+        ),
+        run(
+            "adhoc",
+            None,
+            "2024-03-05T10:00:00Z",
+            WorkflowExecutionStatus::Complete,
+        ),
+    ] {
+        store.save_workflow_execution(execution).await.unwrap();
+    }
+
+    let health = store.list_workflow_health(3).await.unwrap();
+
+    assert_eq!(health.len(), 2);
+    assert_eq!(health[0].workflow_id, "build");
+    assert_eq!(health[0].recent, vec![WorkflowExecutionStatus::Failed]);
+    assert_eq!(health[0].success_rate(), Some(0.0));
+    assert_eq!(health[1].workflow_id, "deploy");
+    assert_eq!(
+        health[1].recent,
+        vec![
+            WorkflowExecutionStatus::Failed,
+            WorkflowExecutionStatus::Complete,
+            WorkflowExecutionStatus::Running,
+        ]
+    );
+    assert_eq!(
+        health[1].last_status(),
+        Some(&WorkflowExecutionStatus::Running)
+    );
+    assert_eq!(
+        health[1].last_run_at.to_rfc3339(),
+        "2024-03-04T10:00:00+00:00"
+    );
+    assert_eq!(health[1].success_rate(), Some(0.5));
+}
+
+#[tokio::test]
+async fn test_list_workflow_executions_on() {
+    let store = create_test_store().await;
+    for execution in [
+        execution_at(
+            "a",
+            "2024-03-04T08:00:00Z",
+            WorkflowExecutionStatus::Complete,
+        ),
+        execution_at("b", "2024-03-04T20:00:00Z", WorkflowExecutionStatus::Failed),
+        execution_at(
+            "c",
+            "2024-03-05T08:00:00Z",
+            WorkflowExecutionStatus::Complete,
+        ),
+    ] {
+        store.save_workflow_execution(execution).await.unwrap();
+    }
+
+    let date = chrono::NaiveDate::from_ymd_opt(2024, 3, 4).unwrap();
+    let executions = store.list_workflow_executions_on(date).await.unwrap();
+
+    let ids: Vec<&str> = executions.iter().map(|e| e.id.as_str()).collect();
+    assert_eq!(ids, vec!["b", "a"]);
+}
+
+#[tokio::test]
+async fn test_list_workflow_executions_between() {
+    let store = create_test_store().await;
+    for execution in [
+        execution_at(
+            "before",
+            "2024-03-03T23:59:59.999Z",
+            WorkflowExecutionStatus::Complete,
+        ),
+        execution_at(
+            "b",
+            "2024-03-04T20:00:00.250Z",
+            WorkflowExecutionStatus::Failed,
+        ),
+        execution_at(
+            "a",
+            "2024-03-04T00:00:00Z",
+            WorkflowExecutionStatus::Complete,
+        ),
+        execution_at(
+            "end",
+            "2024-03-05T00:00:00Z",
+            WorkflowExecutionStatus::Complete,
+        ),
+    ] {
+        store.save_workflow_execution(execution).await.unwrap();
+    }
+
+    let at = |s: &str| {
+        chrono::DateTime::parse_from_rfc3339(s)
+            .unwrap()
+            .with_timezone(&Utc)
+    };
+    let executions = store
+        .list_workflow_executions_between(at("2024-03-04T00:00:00Z"), at("2024-03-05T00:00:00Z"))
+        .await
+        .unwrap();
+
+    let ids: Vec<&str> = executions.iter().map(|e| e.id.as_str()).collect();
+    assert_eq!(ids, vec!["a", "b"]);
+}
+
+#[tokio::test]
+async fn test_list_workflow_execution_summaries_pages() {
+    let store = create_test_store().await;
+    for (id, created_at, status) in [
+        (
+            "e1",
+            "2024-03-01T10:00:00Z",
+            WorkflowExecutionStatus::Complete,
+        ),
+        (
+            "e2",
+            "2024-03-02T10:00:00Z",
+            WorkflowExecutionStatus::Failed,
+        ),
+        (
+            "e3",
+            "2024-03-03T10:00:00Z",
+            WorkflowExecutionStatus::Complete,
+        ),
+        (
+            "e4",
+            "2024-03-04T10:00:00Z",
+            WorkflowExecutionStatus::Complete,
+        ),
+    ] {
+        store
+            .save_workflow_execution(execution_at(id, created_at, status))
+            .await
+            .unwrap();
+    }
+
+    let first = store
+        .list_workflow_execution_summaries(&ExecutionFilter::default(), 0, 3)
+        .await
+        .unwrap();
+    let ids: Vec<_> = first.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(ids, vec!["e4", "e3", "e2"]);
+    assert_eq!(first[0].task_count, create_test_execution().tasks.len());
+
+    let rest = store
+        .list_workflow_execution_summaries(&ExecutionFilter::default(), 3, 3)
+        .await
+        .unwrap();
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest[0].id, "e1");
+
+    let complete = store
+        .list_workflow_execution_summaries(
+            &ExecutionFilter::with_status(Some(WorkflowExecutionStatus::Complete)),
+            1,
+            10,
+        )
+        .await
+        .unwrap();
+    let ids: Vec<_> = complete.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(ids, vec!["e3", "e1"]);
+
+    assert_eq!(
+        store
+            .count_workflow_executions(&ExecutionFilter::default())
+            .await
+            .unwrap(),
+        4
+    );
+    assert_eq!(
+        store
+            .count_workflow_executions(&ExecutionFilter::with_status(Some(
+                WorkflowExecutionStatus::Failed
+            )))
+            .await
+            .unwrap(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_execution_summary_matches_full_execution() {
+    let store = create_test_store().await;
+    let execution = create_test_execution();
+    store
+        .save_workflow_execution(execution.clone())
+        .await
+        .unwrap();
+
+    let summaries = store
+        .list_workflow_execution_summaries(&ExecutionFilter::default(), 0, 10)
+        .await
+        .unwrap();
+    assert_eq!(summaries.len(), 1);
+    let summary = &summaries[0];
+    assert_eq!(summary.id, execution.id);
+    assert_eq!(summary.workflow_name, execution.workflow_name);
+    assert_eq!(summary.status, execution.status);
+    assert_eq!(summary.created_at, execution.created_at);
+    assert_eq!(summary.completed_at, execution.completed_at);
+    assert_eq!(summary.timestamp, execution.timestamp);
+}
+
+#[tokio::test]
+async fn test_execution_summaries_filtered_by_label_and_workflow() {
+    let store = create_test_store().await;
+    let labeled = |id: &str, created_at: &str, workflow_id: &str, labels: &[&str]| {
+        let mut execution = execution_at(id, created_at, WorkflowExecutionStatus::Complete);
+        execution.workflow_id = Some(workflow_id.to_string());
+        execution.set_labels(labels);
+        execution
+    };
+    for execution in [
+        labeled("e1", "2024-03-01T10:00:00Z", "deploy", &["release-1.4"]),
+        labeled(
+            "e2",
+            "2024-03-02T10:00:00Z",
+            "deploy",
+            &[" customer-repro ", "release-1.4", ""],
+        ),
+        labeled("e3", "2024-03-03T10:00:00Z", "build", &["release-1.4"]),
+        labeled("e4", "2024-03-04T10:00:00Z", "deploy", &[]),
+    ] {
+        store.save_workflow_execution(execution).await.unwrap();
+    }
+
+    let filter = ExecutionFilter {
+        label: Some("release-1.4".to_string()),
+        workflow_id: Some("deploy".to_string()),
+        ..Default::default()
+    };
+    let summaries = store
+        .list_workflow_execution_summaries(&filter, 0, 10)
+        .await
+        .unwrap();
+    let ids: Vec<_> = summaries.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(ids, vec!["e2", "e1"]);
+    assert_eq!(summaries[0].labels, vec!["customer-repro", "release-1.4"]);
+    assert_eq!(store.count_workflow_executions(&filter).await.unwrap(), 2);
+
+    assert_eq!(
+        store.list_execution_labels().await.unwrap(),
+        vec!["customer-repro", "release-1.4"]
+    );
+}
+
+#[tokio::test]
+async fn test_execution_filter_presets() {
+    let store = create_test_store().await;
+    let failed_releases = ExecutionFilterPreset::new(
+        "Failed releases",
+        ExecutionFilter {
+            status: Some(WorkflowExecutionStatus::Failed),
+            label: Some("release-1.4".to_string()),
+            workflow_id: None,
+        },
+    );
+    store
+        .save_execution_filter_preset(&failed_releases)
+        .await
+        .unwrap();
+    store
+        .save_execution_filter_preset(&ExecutionFilterPreset::new(
+            "deploys",
+            ExecutionFilter {
+                workflow_id: Some("deploy".to_string()),
+                ..Default::default()
+            },
+        ))
+        .await
+        .unwrap();
+
+    let presets = store.list_execution_filter_presets().await.unwrap();
+    let names: Vec<_> = presets.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["deploys", "Failed releases"]);
+    assert_eq!(presets[1], failed_releases);
+
+    store
+        .delete_execution_filter_preset("deploys")
+        .await
+        .unwrap();
+    assert_eq!(
+        store.list_execution_filter_presets().await.unwrap().len(),
+        1
+    );
+}
@@ -0,0 +1,19 @@
+-- A database as written by releases that predate execution parameters, trigger
+-- provenance, task failure reasons, recorded audit diffs, input requests and
+-- the GUI polling and timestamp settings. Tables that did not exist yet are absent.
+
+CREATE TABLE workflows (id TEXT PRIMARY KEY, data JSON NOT NULL);
+CREATE TABLE workflow_executions (id TEXT PRIMARY KEY, data JSON NOT NULL);
+CREATE TABLE task_executions (id TEXT PRIMARY KEY, data JSON NOT NULL);
+CREATE TABLE prompts (id TEXT PRIMARY KEY, data JSON NOT NULL);
+CREATE TABLE settings (id TEXT PRIMARY KEY, data JSON NOT NULL);
+
+INSERT INTO workflows (id, data) VALUES ('legacy-workflow', '{"id":"legacy-workflow","name":"Legacy Workflow","description":null,"content":"{\"id\":\"legacy-workflow\",\"name\":\"Legacy Workflow\",\"tasks\":[]}","is_default":false,"is_edited":true,"created_at":"2024-03-01T09:00:00Z","updated_at":"2024-03-02T10:30:00Z"}');
+
+INSERT INTO workflow_executions (id, data) VALUES ('legacy-execution', '{"id":"legacy-execution","workflow_name":"Legacy Workflow","workflow_snapshot":{"id":"legacy-workflow","name":"Legacy Workflow","tasks":[]},"status":"complete","created_at":"2024-03-03T08:00:00Z","completed_at":"2024-03-03T08:00:05Z","tasks":[{"id":"task-1","workflow_id":"legacy-execution","name":"Task 1","status":"complete","output":"done","error":null,"created_at":"2024-03-03T08:00:01Z","completed_at":"2024-03-03T08:00:04Z","user_input":null,"input_request_id":null,"prompt_id":null}],"timestamp":"2024-03-03T08:00:00Z","audit_trail":[{"id":"audit-1","task_id":"task-1","status":"success","timestamp":"2024-03-03T08:00:04Z","changes_count":1,"message":"Task completed"}],"per_task_logs":{"task-1":["done"]},"errors":[]}');
+
+INSERT INTO task_executions (id, data) VALUES ('task-1', '{"id":"task-1","workflow_id":"legacy-execution","name":"Task 1","status":"complete","output":"done","error":null,"created_at":"2024-03-03T08:00:01Z","completed_at":"2024-03-03T08:00:04Z","user_input":null,"input_request_id":null,"prompt_id":null}');
+
+INSERT INTO prompts (id, data) VALUES ('legacy-prompt', '{"id":"legacy-prompt","name":"Legacy Prompt","content":"Summarize the output","created_at":"2024-02-28T12:00:00Z"}');
+
+INSERT INTO settings (id, data) VALUES ('app_settings', '{"theme":"dark","auto_save":false,"notifications":true,"default_workflow":"legacy-workflow"}');
@@ -1,7 +1,8 @@
 use chrono::Utc;
 use s_e_e_persistence::{
-    AppSettings, AuditEvent, Prompt, Store, TaskExecution, TaskExecutionStatus, Theme,
-    WorkflowDefinition, WorkflowExecution, WorkflowExecutionStatus,
+    AppSettings, AuditEvent, PollingSettings, Prompt, Store, TaskExecution, TaskExecutionStatus,
+    Theme, TimestampSettings, WorkflowDefinition, WorkflowExecution, WorkflowExecutionStatus,
+    WorkflowLimitSettings,
 };
 use std::collections::HashMap;
 
@@ -45,6 +46,13 @@ async fn test_complete_workflow_execution_flow() {
         audit_trail: Vec::new(),
         per_task_logs: HashMap::new(),
         errors: Vec::new(),
+        parameters: std::collections::HashMap::new(),
+        triggered_by: None,
+        workflow_id: None,
+        labels: Vec::new(),
+        bounds: None,
+        manifest: None,
+        sealed_parameters: None,
     };
 
     store
@@ -64,6 +72,12 @@ async fn test_complete_workflow_execution_flow() {
         user_input: None,
         input_request_id: None,
         prompt_id: None,
+        failure_reason: None,
+        output_bytes: None,
+        output_truncated: false,
+        cache_hit: false,
+        environment: Default::default(),
+        attempts: Vec::new(),
     };
 
     let task2 = TaskExecution {
@@ -78,6 +92,12 @@ async fn test_complete_workflow_execution_flow() {
         user_input: None,
         input_request_id: None,
         prompt_id: None,
+        failure_reason: None,
+        output_bytes: None,
+        output_truncated: false,
+        cache_hit: false,
+        environment: Default::default(),
+        attempts: Vec::new(),
     };
 
     store.save_task_execution(task1.clone()).await.unwrap();
@@ -185,6 +205,16 @@ async fn test_multi_table_operations() {
         auto_save: true,
         notifications: false,
         default_workflow: Some("multi-table-workflow".to_string()),
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: Default::default(),
+        logging: Default::default(),
     };
 
     let audit = AuditEvent::success(
@@ -229,6 +259,16 @@ async fn test_workflow_execution_with_prompts_and_settings() {
         auto_save: true,
         notifications: true,
         default_workflow: None,
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: Default::default(),
+        logging: Default::default(),
     };
 
     store.save_settings(&settings).await.unwrap();
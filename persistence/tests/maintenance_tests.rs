@@ -0,0 +1,64 @@
+use s_e_e_persistence::{Store, WorkflowExecution, WorkflowExecutionStatus};
+use std::collections::HashMap;
+use tempfile::TempDir;
+
+fn execution(n: usize) -> WorkflowExecution {
+    let log = (0..200)
+        .map(|line| format!("execution {} output line {}", n, line))
+        .collect();
+    WorkflowExecution {
+        id: format!("exec-{}", n),
+        workflow_name: "Maintenance".to_string(),
+        status: WorkflowExecutionStatus::Complete,
+        per_task_logs: HashMap::from([("build".to_string(), log)]),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_maintenance_reclaims_space_after_deletes() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("maintenance.db");
+    let store = Store::new(&path.to_string_lossy()).await.unwrap();
+
+    for n in 0..100 {
+        store.save_workflow_execution(execution(n)).await.unwrap();
+    }
+    for n in 0..100 {
+        store
+            .delete_workflow_execution(&format!("exec-{}", n))
+            .await
+            .unwrap();
+    }
+    store.vacuum_blobs().await.unwrap();
+
+    let report = store.maintenance().await.unwrap();
+
+    assert!(report.bytes_after < report.bytes_before, "{:?}", report);
+    assert_eq!(
+        report.reclaimed_bytes(),
+        report.bytes_before - report.bytes_after
+    );
+}
+
+#[tokio::test]
+async fn test_maintenance_switches_to_incremental_vacuum_once() {
+    let store = Store::new(":memory:").await.unwrap();
+    store.save_workflow_execution(execution(1)).await.unwrap();
+
+    store.maintenance().await.unwrap();
+    let second = store.maintenance().await.unwrap();
+
+    assert!(!second.full_vacuum);
+}
+
+#[tokio::test]
+async fn test_last_maintenance_returns_latest_report() {
+    let store = Store::new(":memory:").await.unwrap();
+    assert_eq!(store.last_maintenance().await.unwrap(), None);
+
+    store.maintenance().await.unwrap();
+    let latest = store.maintenance().await.unwrap();
+
+    assert_eq!(store.last_maintenance().await.unwrap(), Some(latest));
+}
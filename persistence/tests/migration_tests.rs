@@ -0,0 +1,150 @@
+//! Opening databases written by older releases must keep their data readable.
+
+use s_e_e_persistence::{
+    AuditStatus, PollingSettings, Store, TaskExecutionStatus, Theme, TimestampSettings,
+    WorkflowExecutionStatus, SCHEMA_VERSION,
+};
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{ConnectOptions, Connection};
+use std::path::Path;
+use tempfile::TempDir;
+
+const LEGACY_SCHEMA: &str = include_str!("fixtures/legacy_schema.sql");
+
+/// Build a database file from a fixture script, then open it with the current store.
+async fn open_fixture(dir: &TempDir, script: &str) -> Store {
+    let path = dir.path().join("legacy.db");
+    load_fixture(&path, script).await;
+    Store::new(path.to_str().unwrap()).await.unwrap()
+}
+
+async fn load_fixture(path: &Path, script: &str) {
+    let mut conn = SqliteConnectOptions::new()
+        .filename(path)
+        .create_if_missing(true)
+        .connect()
+        .await
+        .unwrap();
+    let statements = script
+        .split(";\n")
+        .map(|statement| {
+            statement
+                .lines()
+                .filter(|line| !line.trim_start().starts_with("--"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .filter(|statement| !statement.trim().is_empty());
+    for statement in statements {
+        sqlx::query(&statement).execute(&mut conn).await.unwrap();
+    }
+    conn.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_legacy_workflows_and_prompts_load() {
+    let dir = TempDir::new().unwrap();
+    let store = open_fixture(&dir, LEGACY_SCHEMA).await;
+
+    let workflow = store
+        .get_workflow("legacy-workflow")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(workflow.name, "Legacy Workflow");
+    assert!(workflow.is_edited);
+
+    let prompts = store.list_prompts().await.unwrap();
+    assert_eq!(prompts.len(), 1);
+    assert_eq!(prompts[0].content, "Summarize the output");
+}
+
+#[tokio::test]
+async fn test_legacy_execution_fills_new_fields_with_defaults() {
+    let dir = TempDir::new().unwrap();
+    let store = open_fixture(&dir, LEGACY_SCHEMA).await;
+
+    let execution = store
+        .get_workflow_execution("legacy-execution")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(execution.status, WorkflowExecutionStatus::Complete);
+    assert!(execution.parameters.is_empty());
+    assert!(execution.triggered_by.is_none());
+
+    assert_eq!(execution.tasks.len(), 1);
+    assert_eq!(execution.tasks[0].status, TaskExecutionStatus::Complete);
+    assert!(execution.tasks[0].failure_reason.is_none());
+
+    let event = &execution.audit_trail[0];
+    assert_eq!(event.status, AuditStatus::Success);
+    assert!(event.changes.is_empty());
+    assert!(!event.changes_truncated);
+
+    let metadata = store.list_workflow_metadata().await.unwrap();
+    assert_eq!(metadata.len(), 1);
+
+    let since = chrono::NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    let stats = store.daily_execution_stats(since).await.unwrap();
+    assert_eq!(stats.len(), 1);
+    assert_eq!(stats[0].total, 1);
+
+    let tasks = store
+        .get_tasks_for_workflow("legacy-execution")
+        .await
+        .unwrap();
+    assert_eq!(tasks.len(), 1);
+}
+
+#[tokio::test]
+async fn test_legacy_settings_fill_new_fields_with_defaults() {
+    let dir = TempDir::new().unwrap();
+    let store = open_fixture(&dir, LEGACY_SCHEMA).await;
+
+    let settings = store.load_settings().await.unwrap().unwrap();
+    assert_eq!(settings.theme, Theme::Dark);
+    assert!(!settings.auto_save);
+    assert_eq!(
+        settings.default_workflow.as_deref(),
+        Some("legacy-workflow")
+    );
+    assert_eq!(settings.polling, PollingSettings::default());
+    assert_eq!(settings.timestamps, TimestampSettings::default());
+}
+
+#[tokio::test]
+async fn test_legacy_database_gains_missing_tables() {
+    let dir = TempDir::new().unwrap();
+    let store = open_fixture(&dir, LEGACY_SCHEMA).await;
+
+    assert!(store.get_all_pending_inputs().await.unwrap().is_empty());
+    assert!(store.get_input_request("missing").await.unwrap().is_none());
+    assert_eq!(store.schema_version().await.unwrap(), SCHEMA_VERSION);
+}
+
+#[tokio::test]
+async fn test_legacy_data_survives_rewrite() {
+    let dir = TempDir::new().unwrap();
+    let store = open_fixture(&dir, LEGACY_SCHEMA).await;
+
+    let execution = store
+        .get_workflow_execution("legacy-execution")
+        .await
+        .unwrap()
+        .unwrap();
+    store
+        .save_workflow_execution(execution.clone())
+        .await
+        .unwrap();
+    drop(store);
+
+    let reopened = Store::new(dir.path().join("legacy.db").to_str().unwrap())
+        .await
+        .unwrap();
+    let reloaded = reopened
+        .get_workflow_execution("legacy-execution")
+        .await
+        .unwrap();
+    assert_eq!(reloaded, Some(execution));
+}
@@ -25,6 +25,8 @@ fn test_audit_event_validation_success() {
         status: AuditStatus::Success,
         timestamp: Utc::now(),
         changes_count: 5,
+        changes: Vec::new(),
+        changes_truncated: false,
         message: "Task completed successfully".to_string(),
     };
 
@@ -114,6 +116,8 @@ fn test_audit_event_serialization() {
         status: AuditStatus::Success,
         timestamp: Utc::now(),
         changes_count: 5,
+        changes: Vec::new(),
+        changes_truncated: false,
         message: "Task completed successfully".to_string(),
     };
 
@@ -1,9 +1,8 @@
-
-
-
-
-use s_e_e_persistence::{WorkflowExecutionStatus, TaskExecution, TaskStatus, WorkflowExecution, WorkflowExecutionSummary, WorkflowMetadata};
 use chrono::Utc;
+use s_e_e_persistence::{
+    TaskExecution, TaskStatus, WorkflowExecution, WorkflowExecutionStatus,
+    WorkflowExecutionSummary, WorkflowMetadata,
+};
 
 #[test]
 fn test_workflow_execution_default() {
@@ -37,15 +36,16 @@ fn test_workflow_execution_serialization() {
         audit_trail: Vec::new(),
         per_task_logs: std::collections::HashMap::new(),
         errors: Vec::new(),
+        parameters: std::collections::HashMap::new(),
+        triggered_by: None,
+        workflow_id: None,
     };
 
-
     let json = serde_json::to_string(&execution).unwrap();
     assert!(json.contains("exec-1"));
     assert!(json.contains("Test Workflow"));
     assert!(json.contains("complete"));
 
-
     let deserialized: WorkflowExecution = serde_json::from_str(&json).unwrap();
     assert_eq!(deserialized.id, execution.id);
     assert_eq!(deserialized.workflow_name, execution.workflow_name);
@@ -73,6 +73,9 @@ fn test_workflow_execution_serialization_with_snapshot() {
         audit_trail: Vec::new(),
         per_task_logs: std::collections::HashMap::new(),
         errors: Vec::new(),
+        parameters: std::collections::HashMap::new(),
+        triggered_by: None,
+        workflow_id: None,
     };
 
     let json = serde_json::to_string(&execution).unwrap();
@@ -96,14 +99,14 @@ fn test_workflow_execution_to_summary() {
         status: WorkflowExecutionStatus::Complete,
         created_at: Utc::now(),
         completed_at: Some(Utc::now()),
-        tasks: vec![
-            TaskExecution::default(),
-            TaskExecution::default(),
-        ],
+        tasks: vec![TaskExecution::default(), TaskExecution::default()],
         timestamp: Utc::now(),
         audit_trail: Vec::new(),
         per_task_logs: std::collections::HashMap::new(),
         errors: Vec::new(),
+        parameters: std::collections::HashMap::new(),
+        triggered_by: None,
+        workflow_id: None,
     };
 
     let summary = execution.to_summary();
@@ -135,7 +138,6 @@ fn test_workflow_execution_to_metadata() {
 
 #[test]
 fn test_workflow_execution_waiting_for_input_status() {
-
     let execution = WorkflowExecution {
         id: "exec-1".to_string(),
         workflow_name: "Test Workflow".to_string(),
@@ -152,16 +154,17 @@ fn test_workflow_execution_waiting_for_input_status() {
         audit_trail: Vec::new(),
         per_task_logs: std::collections::HashMap::new(),
         errors: Vec::new(),
+        parameters: std::collections::HashMap::new(),
+        triggered_by: None,
+        workflow_id: None,
     };
 
     assert_eq!(execution.status, WorkflowExecutionStatus::WaitingForInput);
     assert_eq!(execution.status.as_str(), "waiting_for_input");
 
-
     let json = serde_json::to_string(&execution).unwrap();
     assert!(json.contains("waiting_for_input"));
 
-
     let summary = execution.to_summary();
     assert_eq!(summary.status, WorkflowExecutionStatus::WaitingForInput);
 }
@@ -186,15 +189,14 @@ fn test_workflow_execution_summary_serialization() {
         completed_at: Some(Utc::now()),
         task_count: 5,
         timestamp: Utc::now(),
+        labels: vec!["release-1.4".to_string()],
     };
 
-
     let json = serde_json::to_string(&summary).unwrap();
     assert!(json.contains("exec-1"));
     assert!(json.contains("failed"));
     assert!(json.contains("task_count"));
 
-
     let deserialized: WorkflowExecutionSummary = serde_json::from_str(&json).unwrap();
     assert_eq!(deserialized.id, summary.id);
     assert_eq!(deserialized.task_count, summary.task_count);
@@ -217,12 +219,10 @@ fn test_workflow_metadata_serialization() {
         status: "running".to_string(),
     };
 
-
     let json = serde_json::to_string(&metadata).unwrap();
     assert!(json.contains("exec-1"));
     assert!(json.contains("running"));
 
-
     let deserialized: WorkflowMetadata = serde_json::from_str(&json).unwrap();
     assert_eq!(deserialized.id, metadata.id);
     assert_eq!(deserialized.name, metadata.name);
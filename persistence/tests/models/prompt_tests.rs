@@ -23,6 +23,7 @@ fn test_prompt_validation_success() {
         content: "This is a test prompt".to_string(),
         created_at: Utc::now(),
         updated_at: Utc::now(),
+        tags: Vec::new(),
     };
 
     let result = prompt.validate();
@@ -78,6 +79,7 @@ fn test_prompt_update_content() {
         name: "Test Prompt".to_string(),
         content: "Old content".to_string(),
         created_at: Utc::now(),
+        tags: Vec::new(),
     };
 
     prompt.update_content("New content".to_string());
@@ -92,6 +94,7 @@ fn test_prompt_update_name() {
         name: "Old Name".to_string(),
         content: "Test content".to_string(),
         created_at: Utc::now(),
+        tags: Vec::new(),
     };
 
     prompt.update_name("New Name".to_string());
@@ -107,6 +110,7 @@ fn test_prompt_serialization() {
         content: "This is a test prompt".to_string(),
         created_at: Utc::now(),
         updated_at: Utc::now(),
+        tags: Vec::new(),
     };
 
 
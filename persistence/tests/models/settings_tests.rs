@@ -2,7 +2,7 @@
 
 
 
-use s_e_e_persistence::{AppSettings, Theme};
+use s_e_e_persistence::{AppSettings, PollingSettings, TimestampSettings, WorkflowLimitSettings, Theme};
 
 #[test]
 fn test_app_settings_default() {
@@ -77,6 +77,16 @@ fn test_app_settings_serialization() {
         auto_save: false,
         notifications: true,
         default_workflow: Some("workflow-1".to_string()),
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: Default::default(),
+        logging: Default::default(),
     };
 
 
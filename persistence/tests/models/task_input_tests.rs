@@ -1,9 +1,5 @@
-
-
-
-
-use s_e_e_persistence::{TaskExecution, TaskStatus};
 use chrono::Utc;
+use s_e_e_persistence::{TaskExecution, TaskStatus};
 
 #[test]
 fn test_task_execution_with_user_input_fields() {
@@ -19,6 +15,12 @@ fn test_task_execution_with_user_input_fields() {
         user_input: Some("user provided value".to_string()),
         input_request_id: Some("request-123".to_string()),
         prompt_id: Some("prompt-456".to_string()),
+        failure_reason: None,
+        output_bytes: None,
+        output_truncated: false,
+        cache_hit: false,
+        environment: Default::default(),
+        attempts: Vec::new(),
     };
 
     assert_eq!(task.user_input, Some("user provided value".to_string()));
@@ -47,7 +49,10 @@ fn test_task_execution_get_input_request_id() {
         input_request_id: Some("request-123".to_string()),
         ..Default::default()
     };
-    assert_eq!(task_with_request_id.get_input_request_id(), Some("request-123"));
+    assert_eq!(
+        task_with_request_id.get_input_request_id(),
+        Some("request-123")
+    );
 
     let task_without_request_id = TaskExecution {
         input_request_id: None,
@@ -70,16 +75,20 @@ fn test_task_execution_with_input_serialization() {
         user_input: Some("input value".to_string()),
         input_request_id: Some("request-id".to_string()),
         prompt_id: Some("prompt-id".to_string()),
+        failure_reason: None,
+        output_bytes: None,
+        output_truncated: false,
+        cache_hit: false,
+        environment: Default::default(),
+        attempts: Vec::new(),
     };
 
-
     let json = serde_json::to_string(&task).unwrap();
     assert!(json.contains("task-1"));
     assert!(json.contains("input value"));
     assert!(json.contains("request-id"));
     assert!(json.contains("prompt-id"));
 
-
     let deserialized: TaskExecution = serde_json::from_str(&json).unwrap();
     assert_eq!(deserialized.user_input, task.user_input);
     assert_eq!(deserialized.input_request_id, task.input_request_id);
@@ -94,4 +103,3 @@ fn test_task_execution_with_input_default() {
     assert!(task.input_request_id.is_none());
     assert!(task.prompt_id.is_none());
 }
-
@@ -1,9 +1,5 @@
-
-
-
-
-use s_e_e_persistence::{TaskExecution, TaskStatus};
 use chrono::Utc;
+use s_e_e_persistence::{TaskExecution, TaskStatus};
 
 #[test]
 fn test_task_execution_default() {
@@ -30,6 +26,11 @@ fn test_task_execution_validation_success() {
         error: None,
         created_at: Utc::now(),
         completed_at: Some(Utc::now()),
+        output_bytes: None,
+        output_truncated: false,
+        cache_hit: false,
+        environment: Default::default(),
+        attempts: Vec::new(),
     };
 
     let result = task.validate();
@@ -163,9 +164,13 @@ fn test_task_execution_serialization() {
         error: None,
         created_at: Utc::now(),
         completed_at: Some(Utc::now()),
+        output_bytes: None,
+        output_truncated: false,
+        cache_hit: false,
+        environment: Default::default(),
+        attempts: Vec::new(),
     };
 
-
     let json = serde_json::to_string(&task).unwrap();
     assert!(json.contains("task-1"));
     assert!(json.contains("workflow-1"));
@@ -173,7 +178,6 @@ fn test_task_execution_serialization() {
     assert!(json.contains("complete"));
     assert!(json.contains("Task output"));
 
-
     let deserialized: TaskExecution = serde_json::from_str(&json).unwrap();
     assert_eq!(deserialized.id, task.id);
     assert_eq!(deserialized.workflow_id, task.workflow_id);
@@ -0,0 +1,80 @@
+use chrono::{Duration, Utc};
+use s_e_e_persistence::{AppSettings, Notification, NotificationKind, Store};
+
+async fn create_test_store() -> Store {
+    Store::new(":memory:").await.unwrap()
+}
+
+fn notification(kind: NotificationKind, minutes_ago: i64) -> Notification {
+    Notification {
+        created_at: Utc::now() - Duration::minutes(minutes_ago),
+        ..Notification::new(kind, "Deploy".to_string(), "Run finished".to_string())
+    }
+}
+
+#[tokio::test]
+async fn test_list_notifications_newest_first() {
+    let store = create_test_store().await;
+    let old = notification(NotificationKind::ExecutionFailed, 10);
+    let new = notification(NotificationKind::ExecutionCompleted, 1);
+    store.save_notification(&old).await.unwrap();
+    store.save_notification(&new).await.unwrap();
+
+    let listed = store.list_notifications(10).await.unwrap();
+    assert_eq!(listed, vec![new.clone(), old]);
+
+    let limited = store.list_notifications(1).await.unwrap();
+    assert_eq!(limited, vec![new]);
+}
+
+#[tokio::test]
+async fn test_mark_notifications_read() {
+    let store = create_test_store().await;
+    let first = notification(NotificationKind::ExecutionCompleted, 2);
+    let second = notification(NotificationKind::InputRequested, 1);
+    store.save_notification(&first).await.unwrap();
+    store.save_notification(&second).await.unwrap();
+    assert_eq!(store.count_unread_notifications().await.unwrap(), 2);
+
+    store
+        .mark_notifications_read(Some(&first.id))
+        .await
+        .unwrap();
+    assert_eq!(store.count_unread_notifications().await.unwrap(), 1);
+    let listed = store.list_notifications(10).await.unwrap();
+    assert!(listed.iter().find(|n| n.id == first.id).unwrap().read);
+    assert!(!listed.iter().find(|n| n.id == second.id).unwrap().read);
+
+    store.mark_notifications_read(None).await.unwrap();
+    assert_eq!(store.count_unread_notifications().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_prune_notifications_keeps_newest() {
+    let store = create_test_store().await;
+    for minutes_ago in 0..5 {
+        let n = notification(NotificationKind::ExecutionCompleted, minutes_ago);
+        store.save_notification(&n).await.unwrap();
+    }
+
+    store.prune_notifications(2).await.unwrap();
+
+    let listed = store.list_notifications(10).await.unwrap();
+    assert_eq!(listed.len(), 2);
+    assert!(listed[0].created_at > listed[1].created_at);
+}
+
+#[test]
+fn test_settings_mute_notification_kinds() {
+    let mut settings = AppSettings::default();
+    assert!(settings.notifies(NotificationKind::ExecutionFailed));
+
+    settings
+        .muted_notifications
+        .insert(NotificationKind::ExecutionFailed);
+    assert!(!settings.notifies(NotificationKind::ExecutionFailed));
+    assert!(settings.notifies(NotificationKind::ExecutionCompleted));
+
+    settings.notifications = false;
+    assert!(!settings.notifies(NotificationKind::ExecutionCompleted));
+}
@@ -0,0 +1,76 @@
+use s_e_e_persistence::{OutputChunk, Store, WorkflowExecution};
+
+async fn create_test_store() -> Store {
+    Store::new(":memory:").await.unwrap()
+}
+
+fn chunk(execution_id: &str, sequence: u64, line: &str) -> OutputChunk {
+    OutputChunk {
+        execution_id: execution_id.to_string(),
+        sequence,
+        task_id: Some("build".to_string()),
+        task_name: Some("Build".to_string()),
+        line: line.to_string(),
+        finished: None,
+    }
+}
+
+#[tokio::test]
+async fn test_journal_reads_back_in_saved_order() {
+    let store = create_test_store().await;
+    store
+        .append_output_chunks(&[chunk("exec-1", 0, "compiling"), chunk("exec-2", 0, "other")])
+        .await
+        .unwrap();
+    // A resumed run numbers its output from 0 again.
+    let mut last = chunk("exec-1", 0, "done");
+    last.finished = Some(true);
+    store
+        .append_output_chunks(&[chunk("exec-1", 1, "linking"), last.clone()])
+        .await
+        .unwrap();
+
+    let journal = store.get_output_journal("exec-1").await.unwrap();
+    let lines: Vec<&str> = journal.iter().map(|c| c.line.as_str()).collect();
+    assert_eq!(lines, vec!["compiling", "linking", "done"]);
+    assert_eq!(journal[2], last);
+    assert!(store
+        .get_output_journal("missing")
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_output_journal() {
+    let store = create_test_store().await;
+    store
+        .append_output_chunks(&[chunk("exec-1", 0, "a"), chunk("exec-1", 1, "b")])
+        .await
+        .unwrap();
+    store
+        .append_output_chunks(&[chunk("exec-2", 0, "c")])
+        .await
+        .unwrap();
+
+    assert_eq!(store.delete_output_journal("exec-1").await.unwrap(), 2);
+    assert!(store.get_output_journal("exec-1").await.unwrap().is_empty());
+    assert_eq!(store.get_output_journal("exec-2").await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_deleting_execution_deletes_its_journal() {
+    let store = create_test_store().await;
+    let execution = WorkflowExecution {
+        id: "exec-1".to_string(),
+        ..Default::default()
+    };
+    store.save_workflow_execution(execution).await.unwrap();
+    store
+        .append_output_chunks(&[chunk("exec-1", 0, "a")])
+        .await
+        .unwrap();
+
+    store.delete_workflow_execution("exec-1").await.unwrap();
+    assert!(store.get_output_journal("exec-1").await.unwrap().is_empty());
+}
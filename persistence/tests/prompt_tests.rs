@@ -1,5 +1,5 @@
 use chrono::Utc;
-use s_e_e_persistence::{Prompt, Store};
+use s_e_e_persistence::{Prompt, PromptFilter, PromptSort, Store, TaskExecution};
 
 async fn create_test_store() -> Store {
     Store::new(":memory:").await.unwrap()
@@ -11,6 +11,7 @@ fn create_test_prompt() -> Prompt {
         name: "Test Prompt".to_string(),
         content: "This is a test prompt".to_string(),
         created_at: Utc::now(),
+        tags: Vec::new(),
     }
 }
 
@@ -112,6 +113,7 @@ async fn test_prompt_serialization() {
         name: "Test Prompt".to_string(),
         content: "This is a test prompt with special characters: !@#$%^&*()".to_string(),
         created_at: Utc::now(),
+        tags: Vec::new(),
     };
 
     store.save_prompt(&prompt).await.unwrap();
@@ -124,3 +126,122 @@ async fn test_prompt_serialization() {
     assert_eq!(retrieved_prompt.name, prompt.name);
     assert_eq!(retrieved_prompt.content, prompt.content);
 }
+
+fn tagged_prompt(id: &str, name: &str, content: &str, tags: &[&str]) -> Prompt {
+    let mut prompt = Prompt {
+        id: id.to_string(),
+        name: name.to_string(),
+        content: content.to_string(),
+        created_at: Utc::now(),
+        tags: Vec::new(),
+    };
+    prompt.set_tags(tags.iter().copied());
+    prompt
+}
+
+async fn record_use(store: &Store, prompt_id: &str, minutes_ago: i64) {
+    let task = TaskExecution {
+        workflow_id: "exec-1".to_string(),
+        name: "Agent".to_string(),
+        prompt_id: Some(prompt_id.to_string()),
+        created_at: Utc::now() - chrono::Duration::minutes(minutes_ago),
+        ..TaskExecution::default()
+    };
+    store.save_task_execution(task).await.unwrap();
+}
+
+async fn seeded_store() -> Store {
+    let store = create_test_store().await;
+    for prompt in [
+        tagged_prompt(
+            "review",
+            "Code Review",
+            "Review this diff",
+            &["code", "review"],
+        ),
+        tagged_prompt("bug", "Bug Fix", "Find the root cause", &["code"]),
+        tagged_prompt("docs", "Docs", "Write a README section", &["Writing "]),
+    ] {
+        store.save_prompt(&prompt).await.unwrap();
+    }
+    record_use(&store, "bug", 30).await;
+    record_use(&store, "bug", 20).await;
+    record_use(&store, "docs", 5).await;
+    store
+}
+
+fn ids(prompts: &[s_e_e_persistence::PromptWithUsage]) -> Vec<&str> {
+    prompts.iter().map(|p| p.prompt.id.as_str()).collect()
+}
+
+#[test]
+fn test_set_tags_normalizes() {
+    let prompt = tagged_prompt("p", "P", "c", &[" Code ", "code", "", "Review"]);
+    assert_eq!(prompt.tags, vec!["code", "review"]);
+}
+
+#[tokio::test]
+async fn test_list_prompts_filtered_by_search_and_tags() {
+    let store = seeded_store().await;
+
+    let filter = PromptFilter {
+        search: Some("ROOT".to_string()),
+        ..PromptFilter::default()
+    };
+    assert_eq!(
+        ids(&store.list_prompts_filtered(&filter).await.unwrap()),
+        vec!["bug"]
+    );
+
+    let filter = PromptFilter {
+        tags: vec!["code".to_string()],
+        ..PromptFilter::default()
+    };
+    assert_eq!(
+        ids(&store.list_prompts_filtered(&filter).await.unwrap()),
+        vec!["bug", "review"]
+    );
+
+    let filter = PromptFilter {
+        tags: vec!["code".to_string(), "review".to_string()],
+        ..PromptFilter::default()
+    };
+    assert_eq!(
+        ids(&store.list_prompts_filtered(&filter).await.unwrap()),
+        vec!["review"]
+    );
+}
+
+#[tokio::test]
+async fn test_list_prompts_filtered_usage_sorts() {
+    let store = seeded_store().await;
+
+    let most_used = store
+        .list_prompts_filtered(&PromptFilter {
+            sort: PromptSort::MostUsed,
+            ..PromptFilter::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(ids(&most_used), vec!["bug", "docs", "review"]);
+    assert_eq!(most_used[0].use_count, 2);
+    assert_eq!(most_used[2].use_count, 0);
+    assert!(most_used[2].last_used_at.is_none());
+
+    let recent = store
+        .list_prompts_filtered(&PromptFilter {
+            sort: PromptSort::RecentlyUsed,
+            ..PromptFilter::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(ids(&recent), vec!["docs", "bug", "review"]);
+}
+
+#[tokio::test]
+async fn test_list_prompt_tags() {
+    let store = seeded_store().await;
+
+    let tags = store.list_prompt_tags().await.unwrap();
+    assert_eq!(tags, vec!["code", "review", "writing"]);
+}
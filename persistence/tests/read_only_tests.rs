@@ -0,0 +1,59 @@
+//! A read-only store opened next to a writable one on the same file.
+
+use s_e_e_persistence::{Store, WorkflowExecution, WorkflowExecutionStatus};
+use tempfile::TempDir;
+
+fn db_path(dir: &TempDir) -> String {
+    dir.path().join("reports.db").to_string_lossy().into_owned()
+}
+
+fn execution(id: &str) -> WorkflowExecution {
+    WorkflowExecution {
+        id: id.to_string(),
+        workflow_name: "Read Only Test".to_string(),
+        status: WorkflowExecutionStatus::Complete,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_read_only_store_sees_writes_but_cannot_write() {
+    let dir = TempDir::new().unwrap();
+    let path = db_path(&dir);
+    let store = Store::new(&path).await.unwrap();
+    store
+        .save_workflow_execution(execution("exec-1"))
+        .await
+        .unwrap();
+
+    let reports = Store::open_read_only(&path).await.unwrap();
+    assert!(reports.is_read_only());
+    assert!(!store.is_read_only());
+    assert!(reports
+        .get_workflow_execution("exec-1")
+        .await
+        .unwrap()
+        .is_some());
+
+    store
+        .save_workflow_execution(execution("exec-2"))
+        .await
+        .unwrap();
+    assert_eq!(reports.list_workflow_executions().await.unwrap().len(), 2);
+
+    assert!(reports
+        .save_workflow_execution(execution("exec-3"))
+        .await
+        .is_err());
+    assert!(reports.delete_workflow_execution("exec-1").await.is_err());
+    assert_eq!(store.list_workflow_executions().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_read_only_store_needs_an_existing_database() {
+    let dir = TempDir::new().unwrap();
+    let path = db_path(&dir);
+
+    assert!(Store::open_read_only(&path).await.is_err());
+    assert!(!std::path::Path::new(&path).exists());
+}
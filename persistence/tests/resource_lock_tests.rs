@@ -0,0 +1,111 @@
+use chrono::{Duration, Utc};
+use s_e_e_persistence::Store;
+
+async fn create_test_store() -> Store {
+    Store::new(":memory:").await.unwrap()
+}
+
+#[tokio::test]
+async fn test_resource_queue_serves_tickets_in_order() {
+    let store = create_test_store().await;
+    let stale_before = Utc::now() - Duration::minutes(1);
+
+    let first = store
+        .join_resource_queue("deploy", "e1", "t1")
+        .await
+        .unwrap();
+    let other = store
+        .join_resource_queue("backup", "e1", "t2")
+        .await
+        .unwrap();
+    let second = store
+        .join_resource_queue("deploy", "e2", "t1")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        store
+            .resource_queue_position(first, stale_before)
+            .await
+            .unwrap(),
+        Some(0)
+    );
+    assert_eq!(
+        store
+            .resource_queue_position(other, stale_before)
+            .await
+            .unwrap(),
+        Some(0)
+    );
+    assert_eq!(
+        store
+            .resource_queue_position(second, stale_before)
+            .await
+            .unwrap(),
+        Some(1)
+    );
+
+    let listed = store.list_resource_locks().await.unwrap();
+    let summary: Vec<_> = listed
+        .iter()
+        .map(|lock| {
+            (
+                lock.resource.as_str(),
+                lock.execution_id.as_str(),
+                lock.position,
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("backup", "e1", 0),
+            ("deploy", "e1", 0),
+            ("deploy", "e2", 1)
+        ]
+    );
+
+    assert!(store.leave_resource_queue(first).await.unwrap());
+    assert!(!store.leave_resource_queue(first).await.unwrap());
+    assert_eq!(
+        store
+            .resource_queue_position(second, stale_before)
+            .await
+            .unwrap(),
+        Some(0)
+    );
+    assert_eq!(
+        store
+            .resource_queue_position(first, stale_before)
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_stale_tickets_are_dropped() {
+    let store = create_test_store().await;
+    let abandoned = store
+        .join_resource_queue("deploy", "e1", "t1")
+        .await
+        .unwrap();
+    let waiting = store
+        .join_resource_queue("deploy", "e2", "t1")
+        .await
+        .unwrap();
+
+    let position = store
+        .resource_queue_position(waiting, Utc::now() + Duration::seconds(1))
+        .await
+        .unwrap();
+
+    assert_eq!(position, Some(0));
+    assert_eq!(
+        store
+            .resource_queue_position(abandoned, Utc::now() - Duration::minutes(1))
+            .await
+            .unwrap(),
+        None
+    );
+}
@@ -0,0 +1,525 @@
+//! Property-based round-trips: whatever a model holds, saving and loading it
+//! through the store must give back an equal value.
+
+use chrono::{DateTime, Utc};
+use proptest::collection::{btree_map, btree_set, hash_map, vec};
+use proptest::option;
+use proptest::prelude::*;
+use s_e_e_persistence::{
+    AppSettings, AuditChange, AuditChangeOp, AuditEvent, AuditStatus, DateStyle, DisplayTimeZone,
+    InputRequestStatus, InputType, NotificationKind, PollingSettings, Prompt,
+    ReproducibilityManifest, RunBounds, Store, TaskAttempt, TaskExecution, TaskExecutionStatus,
+    TaskFailureReason, Theme, TimestampSettings, UserInputRequest, WorkflowDefinition,
+    WorkflowExecution, WorkflowExecutionStatus, WorkflowLimitSettings,
+};
+use serde_json::Value;
+use tempfile::TempDir;
+use tokio::runtime::Runtime;
+
+const CASES: u32 = 48;
+
+struct Harness {
+    runtime: Runtime,
+    store: Store,
+    _dir: TempDir,
+}
+
+impl Harness {
+    fn new() -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("roundtrip.db");
+        let store = runtime
+            .block_on(Store::new(path.to_str().unwrap()))
+            .unwrap();
+        Self {
+            runtime,
+            store,
+            _dir: dir,
+        }
+    }
+}
+
+fn id() -> impl Strategy<Value = String> {
+    "[a-z0-9][a-z0-9-]{0,23}"
+}
+
+fn text() -> impl Strategy<Value = String> {
+    "\\PC{1,40}"
+}
+
+fn timestamp() -> impl Strategy<Value = DateTime<Utc>> {
+    (0i64..4_102_444_800, 0u32..1_000_000_000)
+        .prop_map(|(secs, nanos)| DateTime::from_timestamp(secs, nanos).unwrap())
+}
+
+/// JSON without floats, which do not survive a text round-trip bit for bit.
+fn json_value() -> impl Strategy<Value = Value> {
+    let leaf = prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::Bool),
+        any::<i64>().prop_map(Value::from),
+        "\\PC{0,20}".prop_map(Value::String),
+    ];
+    leaf.prop_recursive(3, 24, 4, |inner| {
+        prop_oneof![
+            vec(inner.clone(), 0..4).prop_map(Value::Array),
+            hash_map("[a-z_]{1,8}", inner, 0..4)
+                .prop_map(|map| Value::Object(map.into_iter().collect())),
+        ]
+    })
+}
+
+/// `Some(Value::Null)` is stored as `null` and reads back as `None`; models
+/// treat the two the same, so only non-null values are generated here.
+fn optional_json() -> impl Strategy<Value = Option<Value>> {
+    option::of(json_value().prop_filter("null reads back as None", |v| !v.is_null()))
+}
+
+fn workflow_definition() -> impl Strategy<Value = WorkflowDefinition> {
+    (
+        id(),
+        text(),
+        option::of(text()),
+        text(),
+        any::<bool>(),
+        any::<bool>(),
+        timestamp(),
+        timestamp(),
+    )
+        .prop_map(
+            |(id, name, description, content, is_default, is_edited, created_at, updated_at)| {
+                WorkflowDefinition {
+                    id,
+                    name,
+                    description,
+                    content,
+                    is_default,
+                    is_edited,
+                    created_at,
+                    updated_at,
+                }
+            },
+        )
+}
+
+fn task_execution(workflow_id: String) -> impl Strategy<Value = TaskExecution> {
+    let status = prop_oneof![
+        Just(TaskExecutionStatus::Pending),
+        Just(TaskExecutionStatus::InProgress),
+        Just(TaskExecutionStatus::Complete),
+        Just(TaskExecutionStatus::Failed),
+        Just(TaskExecutionStatus::WaitingForInput),
+        Just(TaskExecutionStatus::Skipped),
+    ];
+    let failure_reason = option::of(prop_oneof![
+        Just(TaskFailureReason::Timeout),
+        Just(TaskFailureReason::MemoryLimitExceeded),
+        Just(TaskFailureReason::CpuLimitExceeded),
+    ]);
+    let attempt = (
+        status.clone(),
+        option::of(text()),
+        option::of(text()),
+        failure_reason.clone(),
+        timestamp(),
+        option::of(timestamp()),
+    )
+        .prop_map(
+            |(status, output, error, failure_reason, created_at, completed_at)| TaskAttempt {
+                status,
+                output,
+                error,
+                failure_reason,
+                created_at,
+                completed_at,
+            },
+        );
+    (
+        (id(), text(), status, option::of(text()), option::of(text())),
+        (timestamp(), option::of(timestamp()), option::of(text())),
+        (option::of(id()), option::of(id()), failure_reason),
+        (option::of(any::<u64>()), any::<bool>(), any::<bool>()),
+        btree_map("[A-Z_]{1,12}", text(), 0..4),
+        vec(attempt, 0..3),
+    )
+        .prop_map(
+            move |(
+                (id, name, status, output, error),
+                (created_at, completed_at, user_input),
+                (input_request_id, prompt_id, failure_reason),
+                (output_bytes, output_truncated, cache_hit),
+                environment,
+                attempts,
+            )| TaskExecution {
+                id,
+                workflow_id: workflow_id.clone(),
+                name,
+                status,
+                output,
+                error,
+                created_at,
+                completed_at,
+                user_input,
+                input_request_id,
+                prompt_id,
+                failure_reason,
+                output_bytes,
+                output_truncated,
+                cache_hit,
+                environment,
+                attempts,
+            },
+        )
+}
+
+fn audit_event() -> impl Strategy<Value = AuditEvent> {
+    let change = (
+        prop_oneof![
+            Just(AuditChangeOp::Add),
+            Just(AuditChangeOp::Remove),
+            Just(AuditChangeOp::Replace),
+        ],
+        "(/[a-z0-9_]{1,8}){1,3}",
+        optional_json(),
+        optional_json(),
+    )
+        .prop_map(|(op, path, before, after)| AuditChange {
+            op,
+            path,
+            before,
+            after,
+        });
+    (
+        id(),
+        id(),
+        prop_oneof![Just(AuditStatus::Success), Just(AuditStatus::Failure)],
+        timestamp(),
+        text(),
+        vec(change, 0..4),
+        any::<bool>(),
+    )
+        .prop_map(
+            |(id, task_id, status, timestamp, message, changes, changes_truncated)| AuditEvent {
+                id,
+                task_id,
+                status,
+                timestamp,
+                changes_count: changes.len(),
+                message,
+                changes,
+                changes_truncated,
+            },
+        )
+}
+
+fn workflow_execution() -> impl Strategy<Value = WorkflowExecution> {
+    let status = prop_oneof![
+        Just(WorkflowExecutionStatus::Pending),
+        Just(WorkflowExecutionStatus::Running),
+        Just(WorkflowExecutionStatus::Complete),
+        Just(WorkflowExecutionStatus::Failed),
+        Just(WorkflowExecutionStatus::WaitingForInput),
+    ];
+    id().prop_flat_map(move |execution_id| {
+        (
+            (
+                text(),
+                json_value(),
+                status.clone(),
+                timestamp(),
+                option::of(timestamp()),
+            ),
+            (
+                vec(task_execution(execution_id.clone()), 0..4),
+                timestamp(),
+                vec(audit_event(), 0..4),
+                vec(text(), 0..3),
+            ),
+            (
+                hash_map(id(), vec(text(), 0..3), 0..3),
+                vec(text(), 0..3),
+                hash_map("[a-z_]{1,8}", json_value(), 0..3),
+                option::of(id()),
+                option::of(id()),
+            ),
+            option::of((option::of(id()), option::of(id()), option::of(id()))),
+            option::of((
+                (text(), text(), text(), text()),
+                btree_map("[a-z_]{1,8}", text(), 0..3),
+                btree_map("[a-z_]{1,8}", json_value(), 0..3),
+                btree_map("[a-z_]{1,8}", text(), 0..3),
+            )),
+            option::of(text()),
+        )
+            .prop_map(
+                move |(
+                    (workflow_name, workflow_snapshot, status, created_at, completed_at),
+                    (tasks, timestamp, audit_trail, labels),
+                    (per_task_logs, errors, parameters, triggered_by, workflow_id),
+                    bounds,
+                    manifest,
+                    sealed_parameters,
+                )| WorkflowExecution {
+                    id: execution_id.clone(),
+                    workflow_name,
+                    workflow_snapshot,
+                    status,
+                    created_at,
+                    completed_at,
+                    tasks,
+                    timestamp,
+                    audit_trail,
+                    per_task_logs,
+                    errors,
+                    parameters,
+                    triggered_by,
+                    workflow_id,
+                    labels,
+                    bounds: bounds.map(|(from, until, context_from)| RunBounds {
+                        from,
+                        until,
+                        context_from,
+                    }),
+                    manifest: manifest.map(
+                        |((app_version, os, arch, workflow_hash), handlers, parameters, tools)| {
+                            ReproducibilityManifest {
+                                app_version,
+                                os,
+                                arch,
+                                handlers,
+                                workflow_hash,
+                                parameters,
+                                tools,
+                            }
+                        },
+                    ),
+                    sealed_parameters,
+                },
+            )
+    })
+}
+
+fn prompt() -> impl Strategy<Value = Prompt> {
+    (
+        id(),
+        text(),
+        text(),
+        timestamp(),
+        prop::collection::vec(text(), 0..3),
+    )
+        .prop_map(|(id, name, content, created_at, tags)| Prompt {
+            id,
+            name,
+            content,
+            created_at,
+            tags,
+        })
+}
+
+fn app_settings() -> impl Strategy<Value = AppSettings> {
+    let theme = prop_oneof![Just(Theme::Light), Just(Theme::Dark), Just(Theme::System)];
+    let polling = (any::<u64>(), any::<u64>(), any::<u64>(), any::<bool>()).prop_map(
+        |(executions_ms, execution_details_ms, calendar_ms, pause_when_unfocused)| {
+            PollingSettings {
+                executions_ms,
+                execution_details_ms,
+                calendar_ms,
+                pause_when_unfocused,
+            }
+        },
+    );
+    let timestamps = (
+        prop_oneof![Just(DisplayTimeZone::Local), Just(DisplayTimeZone::Utc)],
+        prop_oneof![
+            Just(DateStyle::Iso),
+            Just(DateStyle::UnitedStates),
+            Just(DateStyle::European),
+        ],
+    )
+        .prop_map(|(time_zone, date_style)| TimestampSettings {
+            time_zone,
+            date_style,
+        });
+    let workflow_limits = (
+        any::<usize>(),
+        any::<usize>(),
+        any::<usize>(),
+        any::<usize>(),
+        any::<usize>(),
+    )
+        .prop_map(
+            |(max_tasks, max_depth, max_fan_out, max_parameters, max_document_kb)| {
+                WorkflowLimitSettings {
+                    max_tasks,
+                    max_depth,
+                    max_fan_out,
+                    max_parameters,
+                    max_document_kb,
+                }
+            },
+        );
+    (
+        theme,
+        any::<bool>(),
+        any::<bool>(),
+        option::of(id()),
+        polling,
+        timestamps,
+        workflow_limits,
+        any::<bool>(),
+        option::of(any::<u32>()),
+        btree_set(prop::sample::select(NotificationKind::ALL.to_vec()), 0..4),
+    )
+        .prop_map(
+            |(
+                theme,
+                auto_save,
+                notifications,
+                default_workflow,
+                polling,
+                timestamps,
+                workflow_limits,
+                onboarding_complete,
+                archive_after_days,
+                muted_notifications,
+            )| AppSettings {
+                theme,
+                auto_save,
+                notifications,
+                default_workflow,
+                polling,
+                timestamps,
+                workflow_limits,
+                onboarding_complete,
+                archive_after_days,
+                muted_notifications,
+                db_connections: Vec::new(),
+                message_connections: Vec::new(),
+                handler_policy: Default::default(),
+                logging: Default::default(),
+            },
+        )
+}
+
+fn input_request() -> impl Strategy<Value = UserInputRequest> {
+    (
+        (id(), id(), id(), text()),
+        (
+            prop_oneof![
+                Just(InputType::String),
+                Just(InputType::Number),
+                Just(InputType::Boolean),
+            ],
+            any::<bool>(),
+            optional_json(),
+            json_value(),
+        ),
+        (
+            prop_oneof![
+                Just(InputRequestStatus::Pending),
+                Just(InputRequestStatus::Fulfilled),
+            ],
+            timestamp(),
+            option::of(timestamp()),
+            option::of(text()),
+        ),
+    )
+        .prop_map(
+            |(
+                (id, task_execution_id, workflow_execution_id, prompt_text),
+                (input_type, required, default_value, validation_rules),
+                (status, created_at, fulfilled_at, fulfilled_value),
+            )| UserInputRequest {
+                id,
+                task_execution_id,
+                workflow_execution_id,
+                prompt_text,
+                input_type,
+                required,
+                default_value,
+                validation_rules,
+                status,
+                created_at,
+                fulfilled_at,
+                fulfilled_value,
+            },
+        )
+}
+
+#[test]
+fn test_workflow_definition_round_trip() {
+    let harness = Harness::new();
+    proptest!(ProptestConfig::with_cases(CASES), |(workflow in workflow_definition())| {
+        let loaded = harness.runtime.block_on(async {
+            harness.store.save_workflow(&workflow).await.unwrap();
+            harness.store.get_workflow(&workflow.id).await.unwrap()
+        });
+        prop_assert_eq!(loaded, Some(workflow));
+    });
+}
+
+#[test]
+fn test_workflow_execution_round_trip() {
+    let harness = Harness::new();
+    proptest!(ProptestConfig::with_cases(CASES), |(execution in workflow_execution())| {
+        let loaded = harness.runtime.block_on(async {
+            harness.store.save_workflow_execution(execution.clone()).await.unwrap();
+            harness.store.get_workflow_execution(&execution.id).await.unwrap()
+        });
+        prop_assert_eq!(loaded, Some(execution));
+    });
+}
+
+#[test]
+fn test_task_execution_round_trip() {
+    let harness = Harness::new();
+    let tasks = id().prop_flat_map(task_execution);
+    proptest!(ProptestConfig::with_cases(CASES), |(task in tasks)| {
+        let loaded = harness.runtime.block_on(async {
+            harness.store.save_task_execution(task.clone()).await.unwrap();
+            harness.store.get_tasks_for_workflow(&task.workflow_id).await.unwrap()
+        });
+        prop_assert!(loaded.contains(&task));
+    });
+}
+
+#[test]
+fn test_prompt_round_trip() {
+    let harness = Harness::new();
+    proptest!(ProptestConfig::with_cases(CASES), |(prompt in prompt())| {
+        let loaded = harness.runtime.block_on(async {
+            harness.store.save_prompt(&prompt).await.unwrap();
+            harness.store.list_prompts().await.unwrap()
+        });
+        let loaded = loaded.into_iter().find(|p| p.id == prompt.id);
+        prop_assert_eq!(loaded, Some(prompt));
+    });
+}
+
+#[test]
+fn test_settings_round_trip() {
+    let harness = Harness::new();
+    proptest!(ProptestConfig::with_cases(CASES), |(settings in app_settings())| {
+        let loaded = harness.runtime.block_on(async {
+            harness.store.save_settings(&settings).await.unwrap();
+            harness.store.load_settings().await.unwrap()
+        });
+        prop_assert_eq!(loaded, Some(settings));
+    });
+}
+
+#[test]
+fn test_input_request_round_trip() {
+    let harness = Harness::new();
+    proptest!(ProptestConfig::with_cases(CASES), |(request in input_request())| {
+        let loaded = harness.runtime.block_on(async {
+            harness.store.save_input_request(&request).await.unwrap();
+            harness.store.get_input_request(&request.id).await.unwrap()
+        });
+        prop_assert_eq!(loaded, Some(request));
+    });
+}
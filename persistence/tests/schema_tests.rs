@@ -0,0 +1,84 @@
+use s_e_e_persistence::{persisted_models, Store, SCHEMA_VERSION};
+
+async fn create_test_store() -> Store {
+    Store::new(":memory:").await.unwrap()
+}
+
+#[tokio::test]
+async fn test_new_database_has_current_schema_version() {
+    let store = create_test_store().await;
+
+    assert_eq!(store.schema_version().await.unwrap(), SCHEMA_VERSION);
+}
+
+#[tokio::test]
+async fn test_table_layout_lists_columns_and_indexes() {
+    let store = create_test_store().await;
+
+    let tables = store.table_layout().await.unwrap();
+    let names: Vec<&str> = tables.iter().map(|table| table.name.as_str()).collect();
+    let mut sorted = names.clone();
+    sorted.sort();
+    assert_eq!(names, sorted);
+
+    let workflows = tables
+        .iter()
+        .find(|table| table.name == "workflows")
+        .unwrap();
+    let columns: Vec<(&str, &str, bool)> = workflows
+        .columns
+        .iter()
+        .map(|column| {
+            (
+                column.name.as_str(),
+                column.sql_type.as_str(),
+                column.primary_key,
+            )
+        })
+        .collect();
+    assert_eq!(columns, vec![("id", "TEXT", true), ("data", "JSON", false)]);
+    assert!(workflows.columns[1].not_null);
+
+    let trash = tables.iter().find(|table| table.name == "trash").unwrap();
+    assert_eq!(trash.indexes.len(), 1);
+    assert!(trash.indexes[0].contains("trash_batch"));
+}
+
+#[tokio::test]
+async fn test_persisted_models_live_in_existing_tables() {
+    let store = create_test_store().await;
+    let tables = store.table_layout().await.unwrap();
+
+    let models = persisted_models();
+    for model in &models {
+        assert!(
+            tables.iter().any(|table| table.name == model.table),
+            "{} is kept in missing table {}",
+            model.name,
+            model.table
+        );
+        let title = model
+            .schema
+            .schema
+            .metadata
+            .as_ref()
+            .and_then(|m| m.title.as_deref());
+        assert_eq!(title, Some(model.name));
+    }
+
+    let execution = models
+        .iter()
+        .find(|model| model.name == "WorkflowExecution")
+        .unwrap();
+    let properties = &execution.schema.schema.object.as_ref().unwrap().properties;
+    assert!(properties.contains_key("status"));
+    assert!(properties.contains_key("tasks"));
+    assert!(execution.schema.definitions.contains_key("TaskExecution"));
+    assert!(execution.blob_paths.contains(&"/workflow_snapshot"));
+
+    let settings = models
+        .iter()
+        .find(|model| model.name == "AppSettings")
+        .unwrap();
+    assert_eq!(settings.row_id, Some("app_settings"));
+}
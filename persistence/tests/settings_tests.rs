@@ -1,4 +1,8 @@
-use s_e_e_persistence::{AppSettings, Store, Theme};
+use s_e_e_persistence::{
+    AppSettings, DateStyle, DisplayTimeZone, HandlerPolicySettings, HandlerProfile, LogLevel,
+    LogSettings, PollingSettings, Store, Theme, TimestampSettings, UiState, ViewportState,
+    WindowState, WorkflowLimitSettings,
+};
 
 async fn create_test_store() -> Store {
     Store::new(":memory:").await.unwrap()
@@ -22,6 +26,16 @@ async fn test_save_settings() {
         auto_save: false,
         notifications: true,
         default_workflow: Some("workflow-1".to_string()),
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: HandlerPolicySettings::default(),
+        logging: Default::default(),
     };
 
     let result = store.save_settings(&settings).await;
@@ -37,6 +51,16 @@ async fn test_load_settings_after_save() {
         auto_save: false,
         notifications: false,
         default_workflow: Some("workflow-2".to_string()),
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: HandlerPolicySettings::default(),
+        logging: Default::default(),
     };
 
     store.save_settings(&original_settings).await.unwrap();
@@ -61,6 +85,16 @@ async fn test_save_settings_update() {
         auto_save: true,
         notifications: true,
         default_workflow: None,
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: HandlerPolicySettings::default(),
+        logging: Default::default(),
     };
 
     store.save_settings(&initial_settings).await.unwrap();
@@ -70,6 +104,16 @@ async fn test_save_settings_update() {
         auto_save: false,
         notifications: false,
         default_workflow: Some("workflow-3".to_string()),
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: HandlerPolicySettings::default(),
+        logging: Default::default(),
     };
 
     store.save_settings(&updated_settings).await.unwrap();
@@ -93,6 +137,16 @@ async fn test_settings_serialization() {
         auto_save: false,
         notifications: true,
         default_workflow: Some("workflow-1".to_string()),
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: HandlerPolicySettings::default(),
+        logging: Default::default(),
     };
 
     store.save_settings(&settings).await.unwrap();
@@ -116,6 +170,16 @@ async fn test_settings_all_themes() {
             auto_save: true,
             notifications: true,
             default_workflow: None,
+            polling: PollingSettings::default(),
+            timestamps: TimestampSettings::default(),
+            workflow_limits: WorkflowLimitSettings::default(),
+            onboarding_complete: false,
+            archive_after_days: None,
+            muted_notifications: Default::default(),
+            db_connections: Vec::new(),
+            message_connections: Vec::new(),
+            handler_policy: HandlerPolicySettings::default(),
+            logging: Default::default(),
         };
 
         store.save_settings(&settings).await.unwrap();
@@ -124,3 +188,194 @@ async fn test_settings_all_themes() {
         assert_eq!(loaded_settings.theme, theme);
     }
 }
+
+#[tokio::test]
+async fn test_settings_polling_round_trip() {
+    let store = create_test_store().await;
+
+    let settings = AppSettings {
+        polling: PollingSettings {
+            executions_ms: 5000,
+            execution_details_ms: 0,
+            calendar_ms: 60_000,
+            pause_when_unfocused: false,
+        },
+        ..AppSettings::default()
+    };
+
+    store.save_settings(&settings).await.unwrap();
+
+    let loaded_settings = store.load_settings().await.unwrap().unwrap();
+    assert_eq!(loaded_settings.polling, settings.polling);
+}
+
+#[test]
+fn test_settings_without_polling_use_defaults() {
+    let json = r#"{"theme":"dark","auto_save":true,"notifications":true,"default_workflow":null}"#;
+
+    let settings: AppSettings = serde_json::from_str(json).unwrap();
+    assert_eq!(settings.polling, PollingSettings::default());
+    assert_eq!(settings.workflow_limits, WorkflowLimitSettings::default());
+    assert_eq!(settings.handler_policy, HandlerPolicySettings::default());
+    assert_eq!(settings.logging, LogSettings::default());
+    assert!(!settings.onboarding_complete);
+}
+
+#[tokio::test]
+async fn test_log_settings_round_trip() {
+    let store = create_test_store().await;
+    let mut settings = AppSettings::default();
+    settings.set_logging(LogSettings {
+        level: LogLevel::Warn,
+        modules: [
+            ("engine".to_string(), LogLevel::Debug),
+            ("persistence".to_string(), LogLevel::Error),
+        ]
+        .into_iter()
+        .collect(),
+    });
+    store.save_settings(&settings).await.unwrap();
+
+    let loaded = store.load_settings().await.unwrap().unwrap();
+    assert_eq!(loaded.logging, settings.logging);
+
+    let json = serde_json::to_value(&loaded.logging).unwrap();
+    assert_eq!(json["level"], "warn");
+    assert_eq!(json["modules"]["engine"], "debug");
+}
+
+#[test]
+fn test_log_settings_validation() {
+    let with_module = |module: &str| AppSettings {
+        logging: LogSettings {
+            modules: [(module.to_string(), LogLevel::Debug)]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        },
+        ..AppSettings::default()
+    };
+
+    assert!(with_module("engine").validate().is_ok());
+    assert!(with_module("s_e_e_engine::handlers").validate().is_ok());
+    assert!(with_module(" ").validate().is_err());
+    assert!(with_module("engine=debug").validate().is_err());
+    assert_eq!("DEBUG".parse::<LogLevel>(), Ok(LogLevel::Debug));
+    assert!("loud".parse::<LogLevel>().is_err());
+}
+
+#[tokio::test]
+async fn test_handler_policy_round_trip() {
+    let store = create_test_store().await;
+    let mut settings = AppSettings::default();
+    assert!(settings.handler_policy.active().is_none());
+
+    settings.handler_policy.active_profile = Some("restricted".to_string());
+    store.save_settings(&settings).await.unwrap();
+
+    let loaded = store.load_settings().await.unwrap().unwrap();
+    let active = loaded.handler_policy.active().unwrap();
+    assert_eq!(active.name, "restricted");
+    assert!(active.denied_handlers.contains("cli_command"));
+    assert!(active.denied_handlers.contains("docker"));
+    assert_eq!(active.allowed_handlers, None);
+}
+
+#[test]
+fn test_handler_policy_validation() {
+    let profile = |name: &str| HandlerProfile {
+        name: name.to_string(),
+        allowed_handlers: None,
+        denied_handlers: Default::default(),
+    };
+    let with_policy = |active_profile: Option<&str>, profiles: Vec<HandlerProfile>| AppSettings {
+        handler_policy: HandlerPolicySettings {
+            active_profile: active_profile.map(String::from),
+            profiles,
+        },
+        ..AppSettings::default()
+    };
+
+    assert!(with_policy(Some("ci"), vec![profile("ci")])
+        .validate()
+        .is_ok());
+    assert!(with_policy(Some("ci"), vec![profile("dev")])
+        .validate()
+        .unwrap_err()
+        .contains("'ci' does not exist"));
+    assert!(with_policy(None, vec![profile("ci"), profile("ci")])
+        .validate()
+        .is_err());
+    assert!(with_policy(None, vec![profile(" ")]).validate().is_err());
+}
+
+#[tokio::test]
+async fn test_settings_timestamps_round_trip() {
+    let store = create_test_store().await;
+
+    let settings = AppSettings {
+        timestamps: TimestampSettings {
+            time_zone: DisplayTimeZone::Utc,
+            date_style: DateStyle::European,
+        },
+        ..AppSettings::default()
+    };
+
+    store.save_settings(&settings).await.unwrap();
+
+    let loaded_settings = store.load_settings().await.unwrap().unwrap();
+    assert_eq!(loaded_settings.timestamps, settings.timestamps);
+
+    let json = serde_json::to_value(&settings.timestamps).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "time_zone": "utc", "date_style": "european" })
+    );
+}
+
+#[tokio::test]
+async fn test_ui_state_round_trip_alongside_settings() {
+    let store = create_test_store().await;
+    assert!(store.load_ui_state().await.unwrap().is_none());
+
+    let settings = AppSettings {
+        theme: Theme::Dark,
+        ..AppSettings::default()
+    };
+    store.save_settings(&settings).await.unwrap();
+
+    let mut state = UiState {
+        window: Some(WindowState {
+            width: 1280,
+            height: 800,
+            x: -20,
+            y: 40,
+            maximized: false,
+        }),
+        sidebar_collapsed: true,
+        last_route: Some("/executions/list".to_string()),
+        ..UiState::default()
+    };
+    state
+        .filters
+        .insert("executions".to_string(), serde_json::json!("failed"));
+    state.panes.insert("execution_details".to_string(), 0.35);
+    state.viewports.insert(
+        "deploy".to_string(),
+        ViewportState {
+            x: -120.5,
+            y: 40.0,
+            zoom: 0.6,
+        },
+    );
+    store.save_ui_state(&state).await.unwrap();
+
+    assert_eq!(store.load_ui_state().await.unwrap(), Some(state));
+    assert_eq!(store.load_settings().await.unwrap(), Some(settings));
+
+    let partial: UiState = serde_json::from_str(r#"{"sidebar_collapsed": true}"#).unwrap();
+    assert!(partial.window.is_none());
+    assert!(partial.filters.is_empty());
+    assert!(partial.viewports.is_empty());
+    assert!(!partial.hide_minimap);
+}
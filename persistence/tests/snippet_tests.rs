@@ -0,0 +1,65 @@
+use s_e_e_persistence::{SnippetParameter, Store, TaskSnippet};
+use serde_json::json;
+
+async fn create_test_store() -> Store {
+    Store::new(":memory:").await.unwrap()
+}
+
+fn snippet(id: &str, name: &str) -> TaskSnippet {
+    TaskSnippet {
+        id: id.to_string(),
+        name: name.to_string(),
+        parameters: vec![SnippetParameter {
+            name: "repo".to_string(),
+            default: None,
+            description: None,
+        }],
+        task: json!({
+            "name": "Checkout",
+            "function": {
+                "name": "cli_command",
+                "input": { "command": "git", "args": ["clone", "{{snippet.repo}}"] }
+            }
+        }),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_save_list_and_delete_snippets() {
+    let store = create_test_store().await;
+    let notify = snippet("notify", "notify slack");
+    let checkout = snippet("checkout", "Checkout repo");
+    store.save_snippet(&notify).await.unwrap();
+    store.save_snippet(&checkout).await.unwrap();
+
+    let listed = store.list_snippets().await.unwrap();
+    assert_eq!(listed, vec![checkout.clone(), notify.clone()]);
+    assert_eq!(
+        store.get_snippet("checkout").await.unwrap(),
+        Some(checkout.clone())
+    );
+
+    store.delete_snippet("checkout").await.unwrap();
+    assert_eq!(store.get_snippet("checkout").await.unwrap(), None);
+    assert_eq!(store.list_snippets().await.unwrap(), vec![notify]);
+}
+
+#[test]
+fn test_snippet_validation() {
+    assert!(snippet("checkout", "Checkout").validate().is_ok());
+    assert!(snippet("", "Checkout").validate().is_err());
+    assert!(snippet("checkout", " ").validate().is_err());
+
+    let mut no_function = snippet("checkout", "Checkout");
+    no_function.task = json!({ "name": "Checkout" });
+    assert!(no_function.validate().is_err());
+
+    let mut sets_id = snippet("checkout", "Checkout");
+    sets_id.task["id"] = json!("checkout");
+    assert!(sets_id.validate().unwrap_err().contains("'id'"));
+
+    let mut duplicate = snippet("checkout", "Checkout");
+    duplicate.parameters.push(duplicate.parameters[0].clone());
+    assert!(duplicate.validate().is_err());
+}
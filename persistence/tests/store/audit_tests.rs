@@ -63,6 +63,8 @@ async fn test_audit_event_serialization() {
         status: AuditStatus::Success,
         timestamp: Utc::now(),
         changes_count: 5,
+        changes: Vec::new(),
+        changes_truncated: false,
         message: "Task completed successfully".to_string(),
     };
 
@@ -81,6 +83,8 @@ async fn test_audit_event_validation_error() {
         status: AuditStatus::Success,
         timestamp: Utc::now(),
         changes_count: 5,
+        changes: Vec::new(),
+        changes_truncated: false,
         message: "Test message".to_string(),
     };
 
@@ -101,6 +105,8 @@ async fn test_audit_event_empty_message() {
         status: AuditStatus::Success,
         timestamp: Utc::now(),
         changes_count: 5,
+        changes: Vec::new(),
+        changes_truncated: false,
         message: "".to_string(),
     };
 
@@ -26,6 +26,9 @@ fn create_test_execution() -> WorkflowExecution {
         audit_trail: Vec::new(),
         per_task_logs: std::collections::HashMap::new(),
         errors: Vec::new(),
+        parameters: std::collections::HashMap::new(),
+        triggered_by: None,
+        workflow_id: None,
     }
 }
 
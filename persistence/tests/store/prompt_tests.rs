@@ -16,6 +16,7 @@ fn create_test_prompt() -> Prompt {
         content: "This is a test prompt".to_string(),
         created_at: Utc::now(),
         updated_at: Utc::now(),
+        tags: Vec::new(),
     }
 }
 
@@ -131,6 +132,7 @@ async fn test_prompt_serialization() {
         content: "This is a test prompt with special characters: !@#$%^&*()".to_string(),
         created_at: Utc::now(),
         updated_at: Utc::now(),
+        tags: Vec::new(),
     };
 
 
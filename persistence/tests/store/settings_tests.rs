@@ -2,7 +2,7 @@
 
 
 
-use s_e_e_persistence::{Store, AppSettings, Theme};
+use s_e_e_persistence::{Store, AppSettings, PollingSettings, TimestampSettings, WorkflowLimitSettings, Theme};
 
 async fn create_test_store() -> Store {
     Store::new(":memory:").await.unwrap()
@@ -30,6 +30,16 @@ async fn test_save_settings() {
         auto_save: false,
         notifications: true,
         default_workflow: Some("workflow-1".to_string()),
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: Default::default(),
+        logging: Default::default(),
     };
 
     let result = store.save_settings(&settings).await;
@@ -45,6 +55,16 @@ async fn test_load_settings_after_save() {
         auto_save: false,
         notifications: false,
         default_workflow: Some("workflow-2".to_string()),
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: Default::default(),
+        logging: Default::default(),
     };
 
 
@@ -69,6 +89,16 @@ async fn test_save_settings_update() {
         auto_save: true,
         notifications: true,
         default_workflow: None,
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: Default::default(),
+        logging: Default::default(),
     };
 
     store.save_settings(&initial_settings).await.unwrap();
@@ -79,6 +109,16 @@ async fn test_save_settings_update() {
         auto_save: false,
         notifications: false,
         default_workflow: Some("workflow-3".to_string()),
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: Default::default(),
+        logging: Default::default(),
     };
 
     store.save_settings(&updated_settings).await.unwrap();
@@ -100,6 +140,16 @@ async fn test_settings_serialization() {
         auto_save: false,
         notifications: true,
         default_workflow: Some("workflow-1".to_string()),
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: Default::default(),
+        logging: Default::default(),
     };
 
 
@@ -125,6 +175,16 @@ async fn test_settings_all_themes() {
             auto_save: true,
             notifications: true,
             default_workflow: None,
+            polling: PollingSettings::default(),
+            timestamps: TimestampSettings::default(),
+            workflow_limits: WorkflowLimitSettings::default(),
+            onboarding_complete: false,
+            archive_after_days: None,
+            muted_notifications: Default::default(),
+            db_connections: Vec::new(),
+            message_connections: Vec::new(),
+            handler_policy: Default::default(),
+            logging: Default::default(),
         };
 
 
@@ -1,9 +1,5 @@
-
-
-
-
-use s_e_e_persistence::{Store, TaskExecution, TaskStatus};
 use chrono::Utc;
+use s_e_e_persistence::{Store, TaskExecution, TaskStatus};
 
 async fn create_test_store() -> Store {
     Store::new(":memory:").await.unwrap()
@@ -19,6 +15,11 @@ fn create_test_task() -> TaskExecution {
         error: None,
         created_at: Utc::now(),
         completed_at: Some(Utc::now()),
+        output_bytes: None,
+        output_truncated: false,
+        cache_hit: false,
+        environment: Default::default(),
+        attempts: Vec::new(),
     }
 }
 
@@ -44,10 +45,8 @@ async fn test_get_tasks_for_workflow_single() {
     let store = create_test_store().await;
     let task = create_test_task();
 
-
     store.save_task_execution(task.clone()).await.unwrap();
 
-
     let tasks = store.get_tasks_for_workflow("workflow-1").await.unwrap();
     assert_eq!(tasks.len(), 1);
 
@@ -56,14 +55,16 @@ async fn test_get_tasks_for_workflow_single() {
     assert_eq!(retrieved_task.workflow_id, "workflow-1");
     assert_eq!(retrieved_task.name, "Test Task");
     assert_eq!(retrieved_task.status, TaskExecutionStatus::Complete);
-    assert_eq!(retrieved_task.output, Some("Task completed successfully".to_string()));
+    assert_eq!(
+        retrieved_task.output,
+        Some("Task completed successfully".to_string())
+    );
 }
 
 #[tokio::test]
 async fn test_get_tasks_for_workflow_multiple() {
     let store = create_test_store().await;
 
-
     let task1 = TaskExecution {
         id: "task-1".to_string(),
         workflow_id: "workflow-1".to_string(),
@@ -88,22 +89,18 @@ async fn test_get_tasks_for_workflow_multiple() {
         ..Default::default()
     };
 
-
     store.save_task_execution(task1).await.unwrap();
     store.save_task_execution(task2).await.unwrap();
     store.save_task_execution(task3).await.unwrap();
 
-
     let tasks = store.get_tasks_for_workflow("workflow-1").await.unwrap();
     assert_eq!(tasks.len(), 2);
 
-
     let task_ids: Vec<&str> = tasks.iter().map(|t| t.id.as_str()).collect();
     assert!(task_ids.contains(&"task-1"));
     assert!(task_ids.contains(&"task-2"));
     assert!(!task_ids.contains(&"task-3"));
 
-
     let tasks = store.get_tasks_for_workflow("workflow-2").await.unwrap();
     assert_eq!(tasks.len(), 1);
     assert_eq!(tasks[0].id, "task-3");
@@ -114,24 +111,23 @@ async fn test_save_task_execution_update() {
     let store = create_test_store().await;
     let mut task = create_test_task();
 
-
     store.save_task_execution(task.clone()).await.unwrap();
 
-
     task.status = TaskExecutionStatus::Failed;
     task.error = Some("Task failed with error".to_string());
     task.output = None;
 
-
     store.save_task_execution(task.clone()).await.unwrap();
 
-
     let tasks = store.get_tasks_for_workflow("workflow-1").await.unwrap();
     assert_eq!(tasks.len(), 1);
 
     let retrieved_task = &tasks[0];
     assert_eq!(retrieved_task.status, TaskExecutionStatus::Failed);
-    assert_eq!(retrieved_task.error, Some("Task failed with error".to_string()));
+    assert_eq!(
+        retrieved_task.error,
+        Some("Task failed with error".to_string())
+    );
     assert!(retrieved_task.output.is_none());
 }
 
@@ -147,12 +143,15 @@ async fn test_task_execution_serialization() {
         error: None,
         created_at: Utc::now(),
         completed_at: None,
+        output_bytes: None,
+        output_truncated: false,
+        cache_hit: false,
+        environment: Default::default(),
+        attempts: Vec::new(),
     };
 
-
     store.save_task_execution(task.clone()).await.unwrap();
 
-
     let tasks = store.get_tasks_for_workflow("workflow-1").await.unwrap();
     assert_eq!(tasks.len(), 1);
 
@@ -180,12 +179,17 @@ async fn test_save_task_with_input() {
         user_input: Some("user-input".to_string()),
         input_request_id: Some("request-123".to_string()),
         prompt_id: None,
+        failure_reason: None,
+        output_bytes: None,
+        output_truncated: false,
+        cache_hit: false,
+        environment: Default::default(),
+        attempts: Vec::new(),
     };
 
     let result = store.save_task_with_input(task.clone()).await;
     assert!(result.is_ok());
 
-
     let tasks = store.get_tasks_for_workflow("workflow-1").await.unwrap();
     assert_eq!(tasks.len(), 1);
     assert_eq!(tasks[0].user_input, Some("user-input".to_string()));
@@ -195,7 +199,6 @@ async fn test_save_task_with_input() {
 async fn test_get_tasks_waiting_for_input() {
     let store = create_test_store().await;
 
-
     let task1 = TaskExecution {
         id: "task-1".to_string(),
         workflow_id: "workflow-1".to_string(),
@@ -221,16 +224,13 @@ async fn test_get_tasks_waiting_for_input() {
         ..Default::default()
     };
 
-
     store.save_task_execution(task1).await.unwrap();
     store.save_task_execution(task2).await.unwrap();
     store.save_task_execution(task3).await.unwrap();
 
-
     let waiting_tasks = store.get_tasks_waiting_for_input().await.unwrap();
     assert_eq!(waiting_tasks.len(), 2);
 
-
     let task_ids: Vec<&str> = waiting_tasks.iter().map(|t| t.id.as_str()).collect();
     assert!(task_ids.contains(&"task-1"));
     assert!(task_ids.contains(&"task-3"));
@@ -241,7 +241,6 @@ async fn test_get_tasks_waiting_for_input() {
 async fn test_get_tasks_waiting_for_input_in_workflow() {
     let store = create_test_store().await;
 
-
     let task1 = TaskExecution {
         id: "task-1".to_string(),
         workflow_id: "workflow-1".to_string(),
@@ -270,12 +269,16 @@ async fn test_get_tasks_waiting_for_input_in_workflow() {
     store.save_task_execution(task2).await.unwrap();
     store.save_task_execution(task3).await.unwrap();
 
-
-    let waiting = store.get_tasks_waiting_for_input_in_workflow("workflow-1").await.unwrap();
+    let waiting = store
+        .get_tasks_waiting_for_input_in_workflow("workflow-1")
+        .await
+        .unwrap();
     assert_eq!(waiting.len(), 2);
 
-
-    let waiting = store.get_tasks_waiting_for_input_in_workflow("workflow-2").await.unwrap();
+    let waiting = store
+        .get_tasks_waiting_for_input_in_workflow("workflow-2")
+        .await
+        .unwrap();
     assert_eq!(waiting.len(), 1);
     assert_eq!(waiting[0].id, "task-3");
 }
@@ -293,15 +296,15 @@ async fn test_get_task_with_input_request() {
         ..Default::default()
     };
 
-
     store.save_task_execution(task).await.unwrap();
 
-
     let retrieved = store.get_task_with_input_request("task-1").await.unwrap();
     assert!(retrieved.is_some());
     assert_eq!(retrieved.unwrap().id, "task-1");
 
-
-    let not_found = store.get_task_with_input_request("non-existent").await.unwrap();
+    let not_found = store
+        .get_task_with_input_request("non-existent")
+        .await
+        .unwrap();
     assert!(not_found.is_none());
 }
@@ -2,7 +2,7 @@
 
 
 
-use s_e_e_persistence::{Store, WorkflowDefinition, WorkflowExecution, Prompt, AppSettings, AuditEvent, AuditStatus, WorkflowStatus, TaskExecution, TaskStatus};
+use s_e_e_persistence::{Store, WorkflowDefinition, WorkflowExecution, Prompt, AppSettings, PollingSettings, TimestampSettings, WorkflowLimitSettings, AuditEvent, AuditStatus, WorkflowStatus, TaskExecution, TaskStatus};
 use chrono::Utc;
 
 async fn create_test_store() -> Store {
@@ -58,6 +58,16 @@ async fn test_clear_all_data_with_data() {
         auto_save: false,
         notifications: true,
         default_workflow: Some("workflow-1".to_string()),
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: Default::default(),
+        logging: Default::default(),
     };
 
     let audit_event = AuditEvent::success(
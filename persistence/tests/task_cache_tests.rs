@@ -0,0 +1,50 @@
+use chrono::Duration;
+use s_e_e_persistence::{CachedTaskResult, Store};
+use serde_json::json;
+
+async fn create_test_store() -> Store {
+    Store::new(":memory:").await.unwrap()
+}
+
+#[tokio::test]
+async fn test_save_and_get_cached_task_result() {
+    let store = create_test_store().await;
+    let cached = CachedTaskResult::new(
+        "abc123".to_string(),
+        json!({ "success": true, "output": "hello", "error": null }),
+        Duration::minutes(5),
+    );
+
+    store.save_cached_task_result(&cached).await.unwrap();
+
+    let loaded = store.get_cached_task_result("abc123").await.unwrap();
+    assert_eq!(loaded, Some(cached));
+    assert!(store
+        .get_cached_task_result("missing")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_expired_cached_task_result_is_removed() {
+    let store = create_test_store().await;
+    let mut cached = CachedTaskResult::new("old".to_string(), json!({}), Duration::minutes(5));
+    cached.expires_at = chrono::Utc::now() - Duration::seconds(1);
+    store.save_cached_task_result(&cached).await.unwrap();
+
+    assert!(store.get_cached_task_result("old").await.unwrap().is_none());
+    assert_eq!(store.clear_task_result_cache().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_clear_task_result_cache() {
+    let store = create_test_store().await;
+    for key in ["a", "b"] {
+        let cached = CachedTaskResult::new(key.to_string(), json!({}), Duration::minutes(5));
+        store.save_cached_task_result(&cached).await.unwrap();
+    }
+
+    assert_eq!(store.clear_task_result_cache().await.unwrap(), 2);
+    assert!(store.get_cached_task_result("a").await.unwrap().is_none());
+}
@@ -1,5 +1,5 @@
 use chrono::Utc;
-use s_e_e_persistence::{Store, TaskExecution, TaskExecutionStatus};
+use s_e_e_persistence::{Store, TaskExecution, TaskExecutionStatus, TaskFailureReason};
 
 async fn create_test_store() -> Store {
     Store::new(":memory:").await.unwrap()
@@ -18,6 +18,12 @@ fn create_test_task() -> TaskExecution {
         user_input: None,
         input_request_id: None,
         prompt_id: None,
+        failure_reason: None,
+        output_bytes: None,
+        output_truncated: false,
+        cache_hit: false,
+        environment: Default::default(),
+        attempts: Vec::new(),
     }
 }
 
@@ -144,6 +150,12 @@ async fn test_task_execution_serialization() {
         user_input: None,
         input_request_id: None,
         prompt_id: None,
+        failure_reason: None,
+        output_bytes: None,
+        output_truncated: false,
+        cache_hit: false,
+        environment: Default::default(),
+        attempts: Vec::new(),
     };
 
     store.save_task_execution(task.clone()).await.unwrap();
@@ -159,3 +171,65 @@ async fn test_task_execution_serialization() {
     assert_eq!(retrieved_task.output, task.output);
     assert_eq!(retrieved_task.error, task.error);
 }
+
+#[test]
+fn test_task_execution_without_failure_reason_deserializes() {
+    let json = r#"{
+        "id": "task-1",
+        "workflow_id": "workflow-1",
+        "name": "Legacy Task",
+        "status": "failed",
+        "output": null,
+        "error": "boom",
+        "created_at": "2024-01-15T10:30:45Z",
+        "completed_at": null,
+        "user_input": null,
+        "input_request_id": null,
+        "prompt_id": null
+    }"#;
+
+    let task: TaskExecution = serde_json::from_str(json).unwrap();
+    assert_eq!(task.failure_reason, None);
+    assert_eq!(task.output_bytes, None);
+    assert!(!task.output_truncated);
+
+    let mut task = task;
+    task.failure_reason = Some(TaskFailureReason::CpuLimitExceeded);
+    let round_trip: TaskExecution =
+        serde_json::from_str(&serde_json::to_string(&task).unwrap()).unwrap();
+    assert_eq!(
+        round_trip.failure_reason,
+        Some(TaskFailureReason::CpuLimitExceeded)
+    );
+}
+
+#[test]
+fn test_record_rerun_keeps_earlier_result_as_attempt() {
+    let mut task = create_test_task();
+    task.status = TaskExecutionStatus::Failed;
+    task.output = None;
+    task.error = Some("exit status 1".to_string());
+    task.failure_reason = Some(TaskFailureReason::Timeout);
+
+    task.record_rerun(TaskExecution {
+        id: "task-1".to_string(),
+        workflow_id: "other".to_string(),
+        status: TaskExecutionStatus::Complete,
+        output: Some("passed".to_string()),
+        ..Default::default()
+    });
+
+    assert_eq!(task.workflow_id, "workflow-1");
+    assert_eq!(task.status, TaskExecutionStatus::Complete);
+    assert_eq!(task.output.as_deref(), Some("passed"));
+    assert_eq!(task.error, None);
+    assert_eq!(task.attempt_number(), 2);
+    let attempt = &task.attempts[0];
+    assert_eq!(attempt.status, TaskExecutionStatus::Failed);
+    assert_eq!(attempt.error.as_deref(), Some("exit status 1"));
+    assert_eq!(attempt.failure_reason, Some(TaskFailureReason::Timeout));
+
+    let round_trip: TaskExecution =
+        serde_json::from_str(&serde_json::to_string(&task).unwrap()).unwrap();
+    assert_eq!(round_trip.attempts, task.attempts);
+}
@@ -0,0 +1,143 @@
+use chrono::{Duration, Utc};
+use s_e_e_persistence::{Prompt, Store, TrashKind, WorkflowDefinition, WorkflowProvenance};
+
+async fn create_test_store() -> Store {
+    Store::new(":memory:").await.unwrap()
+}
+
+fn workflow(id: &str) -> WorkflowDefinition {
+    WorkflowDefinition {
+        id: id.to_string(),
+        name: id.to_string(),
+        content: format!(r#"{{"id":"{}","name":"{}","tasks":[]}}"#, id, id),
+        ..Default::default()
+    }
+}
+
+fn prompt(id: &str) -> Prompt {
+    Prompt {
+        id: id.to_string(),
+        name: id.to_string(),
+        content: "Hello".to_string(),
+        created_at: Utc::now(),
+        tags: Vec::new(),
+    }
+}
+
+#[tokio::test]
+async fn test_move_to_trash_and_restore_batch() {
+    let store = create_test_store().await;
+    for id in ["a", "b", "c"] {
+        store.save_workflow(&workflow(id)).await.unwrap();
+    }
+    store.save_prompt(&prompt("p")).await.unwrap();
+
+    let ids = ["a".to_string(), "b".to_string(), "missing".to_string()];
+    let moved = store
+        .move_to_trash(TrashKind::Workflow, &ids, "batch-1")
+        .await
+        .unwrap();
+    assert_eq!(moved, 2);
+    store
+        .move_to_trash(TrashKind::Prompt, &["p".to_string()], "batch-2")
+        .await
+        .unwrap();
+
+    let remaining: Vec<_> = store
+        .list_workflows()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|w| w.id)
+        .collect();
+    assert_eq!(remaining, ["c"]);
+    assert!(store.get_workflow("a").await.unwrap().is_none());
+    assert!(store.list_prompts().await.unwrap().is_empty());
+
+    let since = Utc::now() - Duration::minutes(1);
+    let restored = store.restore_from_trash("batch-1", since).await.unwrap();
+    assert_eq!(restored, ["a", "b"]);
+    assert_eq!(store.get_workflow("a").await.unwrap().unwrap().name, "a");
+    assert!(store.list_prompts().await.unwrap().is_empty());
+    assert!(store
+        .restore_from_trash("batch-1", since)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_restore_skips_expired_and_reused_ids() {
+    let store = create_test_store().await;
+    store.save_workflow(&workflow("a")).await.unwrap();
+    store.save_workflow(&workflow("b")).await.unwrap();
+    let ids = ["a".to_string(), "b".to_string()];
+    store
+        .move_to_trash(TrashKind::Workflow, &ids, "batch")
+        .await
+        .unwrap();
+
+    let later = Utc::now() + Duration::minutes(1);
+    assert!(store
+        .restore_from_trash("batch", later)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let mut replacement = workflow("a");
+    replacement.name = "Replacement".to_string();
+    store.save_workflow(&replacement).await.unwrap();
+    let restored = store
+        .restore_from_trash("batch", Utc::now() - Duration::minutes(1))
+        .await
+        .unwrap();
+    assert_eq!(restored, ["b"]);
+    assert_eq!(
+        store.get_workflow("a").await.unwrap().unwrap().name,
+        "Replacement"
+    );
+}
+
+#[tokio::test]
+async fn test_purge_trash_deletes_old_items_and_their_provenance() {
+    let store = create_test_store().await;
+    store.save_workflow(&workflow("a")).await.unwrap();
+    store
+        .save_workflow_provenance(&WorkflowProvenance {
+            workflow_id: "a".to_string(),
+            source: "a.bundle.json".to_string(),
+            content_hash: "sha256:00".to_string(),
+            signed_by: None,
+            imported_at: Utc::now(),
+            trusted_hash: None,
+        })
+        .await
+        .unwrap();
+    store
+        .move_to_trash(TrashKind::Workflow, &["a".to_string()], "batch")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        store
+            .purge_trash(Utc::now() - Duration::minutes(1))
+            .await
+            .unwrap(),
+        0
+    );
+    assert!(store.get_workflow_provenance("a").await.unwrap().is_some());
+
+    assert_eq!(
+        store
+            .purge_trash(Utc::now() + Duration::minutes(1))
+            .await
+            .unwrap(),
+        1
+    );
+    assert!(store.get_workflow_provenance("a").await.unwrap().is_none());
+    assert!(store
+        .restore_from_trash("batch", Utc::now() - Duration::minutes(1))
+        .await
+        .unwrap()
+        .is_empty());
+}
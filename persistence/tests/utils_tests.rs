@@ -1,6 +1,7 @@
 use s_e_e_persistence::{
-    AppSettings, AuditEvent, Prompt, Store, TaskExecution, TaskExecutionStatus, Theme,
-    WorkflowDefinition, WorkflowExecution, WorkflowExecutionStatus,
+    AppSettings, AuditEvent, PollingSettings, Prompt, Store, TaskExecution, TaskExecutionStatus,
+    Theme, TimestampSettings, WorkflowDefinition, WorkflowExecution, WorkflowExecutionStatus,
+    WorkflowLimitSettings,
 };
 
 async fn create_test_store() -> Store {
@@ -52,6 +53,16 @@ async fn test_clear_all_data_with_data() {
         auto_save: false,
         notifications: true,
         default_workflow: Some("workflow-1".to_string()),
+        polling: PollingSettings::default(),
+        timestamps: TimestampSettings::default(),
+        workflow_limits: WorkflowLimitSettings::default(),
+        onboarding_complete: false,
+        archive_after_days: None,
+        muted_notifications: Default::default(),
+        db_connections: Vec::new(),
+        message_connections: Vec::new(),
+        handler_policy: Default::default(),
+        logging: Default::default(),
     };
 
     let audit_event = AuditEvent::success("task-1".to_string(), "Task completed".to_string(), 3);
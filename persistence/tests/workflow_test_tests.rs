@@ -0,0 +1,76 @@
+use chrono::{Duration, Utc};
+use s_e_e_persistence::{RecordedTaskResult, Store, TaskFailureReason, WorkflowTest};
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+
+async fn create_test_store() -> Store {
+    Store::new(":memory:").await.unwrap()
+}
+
+fn workflow_test(id: &str, workflow_id: &str, age_minutes: i64) -> WorkflowTest {
+    let mut task_results = BTreeMap::new();
+    task_results.insert(
+        "build".to_string(),
+        RecordedTaskResult {
+            success: true,
+            output: json!("built"),
+            error: None,
+            failure_reason: None,
+        },
+    );
+    task_results.insert(
+        "deploy".to_string(),
+        RecordedTaskResult {
+            success: false,
+            output: json!(null),
+            error: Some("Command timed out after 100 ms".to_string()),
+            failure_reason: Some(TaskFailureReason::Timeout),
+        },
+    );
+    WorkflowTest {
+        id: id.to_string(),
+        workflow_id: workflow_id.to_string(),
+        name: format!("Test {}", id),
+        source_execution_id: "exec-1".to_string(),
+        parameters: HashMap::from([("env".to_string(), json!("staging"))]),
+        task_results,
+        expected_context: json!({ "build": "built" }),
+        expected_success: false,
+        created_at: Utc::now() - Duration::minutes(age_minutes),
+    }
+}
+
+#[tokio::test]
+async fn test_save_list_and_delete_workflow_tests() {
+    let store = create_test_store().await;
+    let newer = workflow_test("newer", "release", 1);
+    let older = workflow_test("older", "release", 10);
+    let other = workflow_test("other", "nightly", 5);
+    for test in [&newer, &older, &other] {
+        store.save_workflow_test(test).await.unwrap();
+    }
+
+    assert_eq!(
+        store.list_workflow_tests("release").await.unwrap(),
+        vec![older.clone(), newer.clone()]
+    );
+    assert_eq!(store.get_workflow_test("other").await.unwrap(), Some(other));
+
+    store.delete_workflow_test("older").await.unwrap();
+    assert_eq!(
+        store.list_workflow_tests("release").await.unwrap(),
+        vec![newer]
+    );
+    assert_eq!(store.get_workflow_test("older").await.unwrap(), None);
+}
+
+#[test]
+fn test_workflow_test_validation() {
+    assert!(workflow_test("t", "release", 0).validate().is_ok());
+
+    let mut test = workflow_test("t", "", 0);
+    assert!(test.validate().is_err());
+    test.workflow_id = "release".to_string();
+    test.expected_context = json!([]);
+    assert!(test.validate().is_err());
+}
@@ -1,5 +1,5 @@
 use chrono::Utc;
-use s_e_e_persistence::{Store, WorkflowDefinition};
+use s_e_e_persistence::{Store, WorkflowDefinition, WorkflowProvenance};
 
 async fn create_test_store() -> Store {
     Store::new(":memory:").await.unwrap()
@@ -158,3 +158,31 @@ async fn test_workflow_serialization_error() {
     let retrieved = store.get_workflow("test-workflow").await.unwrap();
     assert!(retrieved.is_some());
 }
+
+#[tokio::test]
+async fn test_workflow_provenance_is_removed_with_workflow() {
+    let store = create_test_store().await;
+    let workflow = create_test_workflow();
+    store.save_workflow(&workflow).await.unwrap();
+
+    let provenance = WorkflowProvenance {
+        workflow_id: workflow.id.clone(),
+        source: "shared.see.json".to_string(),
+        content_hash: "sha256:abc".to_string(),
+        signed_by: None,
+        imported_at: Utc::now(),
+        trusted_hash: None,
+    };
+    store.save_workflow_provenance(&provenance).await.unwrap();
+    assert_eq!(
+        store.get_workflow_provenance(&workflow.id).await.unwrap(),
+        Some(provenance)
+    );
+
+    store.delete_workflow(&workflow.id).await.unwrap();
+    assert!(store
+        .get_workflow_provenance(&workflow.id)
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -1,7 +1,9 @@
-import React, { useState, useCallback, useEffect } from "react";
+import React, { useState, useCallback, useEffect, useRef } from "react";
 import {
   ReactFlow,
+  ReactFlowInstance,
   Controls,
+  MiniMap,
   Background,
   useNodesState,
   useEdgesState,
@@ -25,14 +27,25 @@ import {
   CheckCircleIcon as CheckCircleIconSolid,
   XCircleIcon as XCircleIconSolid,
 } from "@heroicons/react/24/solid";
-import { Workflow, MessageFromParent, WorkflowTask } from "./types";
+import {
+  Workflow,
+  MessageFromParent,
+  NotifyRule,
+  LayoutMode,
+  MessageToParent,
+  ViewportState,
+  WorkflowTask,
+} from "./types";
 import { NodeEditorModal } from "./components/NodeEditorModal";
 import { Input } from "./components/input";
+import { Select } from "./components/select";
 import { CustomEdge } from "./components/CustomEdge";
 import { useWorkflowNodes } from "./hooks/useWorkflowNodes";
 import { useWorkflowEdges } from "./hooks/useWorkflowEdges";
+import { useParentTheme } from "./hooks/useParentTheme";
 import { renderNodeLabel } from "./utils/nodeRenderer";
 import {
+  applyLayoutPositions,
   getLayoutedElements,
   NODE_WIDTH,
   START_NODE_ID,
@@ -42,6 +55,8 @@ import {
 } from "./utils/layout";
 import { createTaskNode } from "./utils/taskFactory";
 import { serializeWorkflow } from "./utils/workflowSerializer";
+import { replaceTaskRules } from "./utils/notifyRules";
+import { exportImage } from "./utils/exportImage";
 import {
   validateWorkflow,
   hasValidationErrors,
@@ -58,6 +73,15 @@ const WorkflowEditor: React.FC = () => {
   const [edges, setEdges, onEdgesChange] = useEdgesState<Edge>([]);
   const [workflow, setWorkflow] = useState<Workflow | null>(null);
   const [isLoaded, setIsLoaded] = useState(false);
+  const colorMode = useParentTheme();
+  const flowRef = useRef<ReactFlowInstance | null>(null);
+  const [initialViewport, setInitialViewport] = useState<ViewportState | null>(
+    null,
+  );
+  const [showMinimap, setShowMinimap] = useState(true);
+  const [layoutMode, setLayoutMode] = useState<LayoutMode>("layered");
+  // Nodes dragged by hand; their positions are saved as overrides.
+  const [pinned, setPinned] = useState<Set<string>>(new Set());
   const [workflowName, setWorkflowName] = useState<string>("");
 
   const [editingNode, setEditingNode] = useState<WorkflowTask | null>(null);
@@ -106,7 +130,19 @@ const WorkflowEditor: React.FC = () => {
   );
 
   const handleSaveNode = useCallback(
-    (updatedNode: WorkflowTask) => {
+    (updatedNode: WorkflowTask, taskNotifyRules: NotifyRule[]) => {
+      setWorkflow((current) =>
+        current
+          ? {
+              ...current,
+              notify: replaceTaskRules(
+                current.notify ?? [],
+                updatedNode.id,
+                taskNotifyRules,
+              ),
+            }
+          : current,
+      );
       setNodes((currentNodes) =>
         currentNodes.map((node) => {
           if (node.id === updatedNode.id) {
@@ -123,7 +159,7 @@ const WorkflowEditor: React.FC = () => {
         }),
       );
     },
-    [setNodes],
+    [setNodes, setWorkflow],
   );
 
   const handleDeleteNode = useCallback(
@@ -153,6 +189,7 @@ const WorkflowEditor: React.FC = () => {
         const updatedWorkflow = {
           ...workflow,
           tasks: removeTaskRecursive(workflow.tasks),
+          notify: replaceTaskRules(workflow.notify ?? [], nodeId, []),
         };
         setWorkflow(updatedWorkflow);
       }
@@ -205,14 +242,39 @@ const WorkflowEditor: React.FC = () => {
     return () => clearInterval(intervalId);
   }, [nodes, edges, workflow]);
 
-  const handleAutoLayout = useCallback(() => {
-    const { nodes: layoutedNodes, edges: layoutedEdges } = getLayoutedElements(
-      nodes,
-      edges,
-    );
-    setNodes(layoutedNodes);
-    setEdges(layoutedEdges);
-  }, [nodes, edges, setNodes, setEdges]);
+  // Layouts are computed by the app so the CLI graph export matches; it
+  // answers with APPLY_LAYOUT.
+  const requestLayout = useCallback(
+    (mode: LayoutMode, keepPinned: boolean) => {
+      if (!workflow) return;
+      const keep = keepPinned ? pinned : new Set<string>();
+      setPinned(keep);
+      window.parent.postMessage(
+        {
+          type: "LAYOUT_REQUESTED",
+          payload: {
+            mode,
+            workflow: serializeWorkflow(
+              nodes,
+              edges,
+              workflowName,
+              workflow.id,
+              mode,
+              keep,
+              workflow.notify,
+            ),
+          },
+        } satisfies MessageToParent,
+        "*",
+      );
+    },
+    [nodes, edges, workflowName, workflow, pinned],
+  );
+
+  const handleAutoLayout = useCallback(
+    () => requestLayout(layoutMode, false),
+    [requestLayout, layoutMode],
+  );
 
   const runValidation = useCallback(() => {
     const errors = validateWorkflow(nodes, edges, workflow);
@@ -251,6 +313,9 @@ const WorkflowEditor: React.FC = () => {
       edges,
       workflowName,
       workflow.id,
+      layoutMode,
+      pinned,
+      workflow.notify,
     );
 
     window.parent.postMessage(
@@ -262,7 +327,7 @@ const WorkflowEditor: React.FC = () => {
     );
 
     console.log("Workflow saved:", serializedWorkflow);
-  }, [nodes, edges, workflowName, workflow, handleValidate]);
+  }, [nodes, edges, workflowName, workflow, layoutMode, pinned, handleValidate]);
 
   const handleAddNode = useCallback(
     (taskData: WorkflowTask) => {
@@ -366,6 +431,10 @@ const WorkflowEditor: React.FC = () => {
       ) {
         const wf = event.data.payload.workflow;
         setWorkflow(wf);
+        setInitialViewport(event.data.payload.viewport ?? null);
+        if (event.data.payload.minimap !== undefined) {
+          setShowMinimap(event.data.payload.minimap);
+        }
 
         if (event.data.payload.workflowName) {
           setWorkflowName(event.data.payload.workflowName);
@@ -435,9 +504,26 @@ const WorkflowEditor: React.FC = () => {
         const initialNodes = [startNode, ...taskNodes];
         const initialEdges = [...startEdges, ...taskEdges];
 
-        const { nodes: newNodes, edges: newEdges } = hasSavedPositions
-          ? { nodes: initialNodes, edges: initialEdges }
-          : getLayoutedElements(initialNodes, initialEdges);
+        const layout = event.data.payload.layout;
+        setLayoutMode(layout?.mode ?? wf.metadata?.layout ?? "layered");
+        setPinned(
+          new Set(
+            Object.keys(savedPositions).filter((id) => id !== START_NODE_ID),
+          ),
+        );
+
+        const { nodes: newNodes, edges: newEdges } = layout
+          ? {
+              nodes: applyLayoutPositions(
+                initialNodes,
+                initialEdges,
+                layout.positions,
+              ),
+              edges: initialEdges,
+            }
+          : hasSavedPositions
+            ? { nodes: initialNodes, edges: initialEdges }
+            : getLayoutedElements(initialNodes, initialEdges);
 
         setNodes(newNodes);
         setEdges(newEdges);
@@ -449,13 +535,70 @@ const WorkflowEditor: React.FC = () => {
     return () => window.removeEventListener("message", handleMessage);
   }, [tasksToNodes, tasksToEdges, setNodes, setEdges, handleDeleteEdge]);
 
+  useEffect(() => {
+    const handleMessage = (event: MessageEvent<MessageFromParent>) => {
+      const format = event.data?.payload?.format;
+      if (event.data?.type !== "EXPORT_IMAGE" || !format) {
+        return;
+      }
+      const reply = (payload: MessageToParent["payload"]) =>
+        window.parent.postMessage(
+          { type: "IMAGE_EXPORTED", payload } satisfies MessageToParent,
+          "*",
+        );
+      exportImage(nodes, format)
+        .then((data) => reply({ format, data }))
+        .catch((error) => reply({ format, error: String(error) }));
+    };
+
+    window.addEventListener("message", handleMessage);
+    return () => window.removeEventListener("message", handleMessage);
+  }, [nodes]);
+
+  useEffect(() => {
+    const handleMessage = (event: MessageEvent<MessageFromParent>) => {
+      const layout = event.data?.payload?.layout;
+      if (event.data?.type !== "APPLY_LAYOUT" || !layout) {
+        return;
+      }
+      setLayoutMode(layout.mode);
+      setNodes((nds) => applyLayoutPositions(nds, edges, layout.positions));
+    };
+
+    window.addEventListener("message", handleMessage);
+    return () => window.removeEventListener("message", handleMessage);
+  }, [edges, setNodes]);
+
+  useEffect(() => {
+    const handleMessage = (event: MessageEvent<MessageFromParent>) => {
+      const flow = flowRef.current;
+      switch (event.data?.type) {
+        case "FIT_VIEW":
+          flow?.fitView({ duration: 200 });
+          break;
+        case "ZOOM_IN":
+          flow?.zoomIn({ duration: 200 });
+          break;
+        case "ZOOM_OUT":
+          flow?.zoomOut({ duration: 200 });
+          break;
+        case "SET_MINIMAP":
+          setShowMinimap(event.data.payload?.minimap ?? true);
+          break;
+      }
+    };
+
+    window.addEventListener("message", handleMessage);
+    return () => window.removeEventListener("message", handleMessage);
+  }, []);
+
   const onConnect = useCallback(
     (params: Connection) => {
       const newEdge = {
         ...params,
         type: "smoothstep",
         animated: true,
-        style: { stroke: "#3b82f6", strokeWidth: 2 },
+        style: { stroke: "var(--see-accent, #3b82f6)", strokeWidth: 2 },
       };
       setEdges((eds) => addEdge(newEdge, eds));
     },
@@ -523,6 +666,18 @@ const WorkflowEditor: React.FC = () => {
                   className="min-w-[300px]"
                 />
 
+                <Select
+                  value={layoutMode}
+                  onChange={(e) =>
+                    requestLayout(e.target.value as LayoutMode, true)
+                  }
+                  className="!w-40 shrink-0"
+                >
+                  <option value="layered">Layered</option>
+                  <option value="force_directed">Force-directed</option>
+                  <option value="stage_grid">Grid by stage</option>
+                </Select>
+
                 <button
                   onClick={handleAutoLayout}
                   className="relative shrink-0 rounded-full p-2 text-gray-400 hover:text-gray-500 focus:outline focus:outline-2 focus:outline-offset-2 focus:outline-indigo-600 dark:hover:text-white dark:focus:outline-indigo-500 group"
@@ -530,7 +685,7 @@ const WorkflowEditor: React.FC = () => {
                 >
                   <ArrowsPointingOutIcon className="w-6 h-6" />
                   <span className="absolute top-full mt-2 left-1/2 -translate-x-1/2 px-2 py-1 bg-gray-900 text-white text-xs rounded whitespace-nowrap opacity-0 group-hover:opacity-100 transition-opacity pointer-events-none z-50">
-                    Auto Layout
+                    Auto Layout (resets dragged nodes)
                   </span>
                 </button>
 
@@ -741,23 +896,50 @@ const WorkflowEditor: React.FC = () => {
           nodes={nodes}
           edges={edges}
           onNodesChange={onNodesChange}
+          onNodeDragStop={(_event, _node, dragged) =>
+            setPinned(
+              (prev) =>
+                new Set([
+                  ...prev,
+                  ...dragged
+                    .map((node) => node.id)
+                    .filter((id) => id !== START_NODE_ID),
+                ]),
+            )
+          }
           onEdgesChange={onEdgesChange}
           onConnect={onConnect}
           onNodeClick={handleNodeClick}
           onNodeDoubleClick={handleNodeDoubleClick}
           onSelectionChange={onSelectionChange}
           edgeTypes={edgeTypes}
-          fitView
+          colorMode={colorMode}
+          onInit={(instance) => {
+            flowRef.current = instance;
+          }}
+          onMoveEnd={(_event, viewport) =>
+            window.parent.postMessage(
+              {
+                type: "VIEWPORT_CHANGED",
+                payload: { viewport },
+              } satisfies MessageToParent,
+              "*",
+            )
+          }
+          fitView={!initialViewport}
+          defaultViewport={initialViewport ?? undefined}
           attributionPosition="bottom-left"
         >
           <Background variant={BackgroundVariant.Dots} gap={16} size={1} />
           <Controls />
+          {showMinimap && <MiniMap pannable zoomable />}
         </ReactFlow>
       </div>
 
       <NodeEditorModal
         isOpen={isModalOpen}
         node={editingNode}
+        notifyRules={workflow?.notify ?? []}
         onSave={handleSaveNode}
         onClose={() => setIsModalOpen(false)}
       />
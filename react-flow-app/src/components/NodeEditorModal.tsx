@@ -5,19 +5,24 @@ import { Input } from "./input";
 import { Select } from "./select";
 import { Button } from "./button";
 import { FunctionFormFields } from "./FunctionFormFields";
-import { WorkflowTask } from "../types";
+import { NotifyRule, WorkflowTask } from "../types";
 import { validateJson, buildTaskFunction } from "../utils/functionBuilder";
+import { describeRule, sharedRules, taskRules } from "../utils/notifyRules";
 
 interface NodeEditorModalProps {
   isOpen: boolean;
   node: WorkflowTask | null;
-  onSave: (updatedNode: WorkflowTask) => void;
+  /** The workflow's `notify` rules. */
+  notifyRules: NotifyRule[];
+  /** Called with the updated task and the rules naming only it. */
+  onSave: (updatedNode: WorkflowTask, taskNotifyRules: NotifyRule[]) => void;
   onClose: () => void;
 }
 
 export function NodeEditorModal({
   isOpen,
   node,
+  notifyRules,
   onSave,
   onClose,
 }: NodeEditorModalProps) {
@@ -44,12 +49,23 @@ export function NodeEditorModal({
   const [customInputJson, setCustomInputJson] = useState("{}");
   const [customInputError, setCustomInputError] = useState("");
 
+  const [notifyOnFailure, setNotifyOnFailure] = useState(false);
+  const [notifyOnSuccess, setNotifyOnSuccess] = useState(false);
+  const [slowSeconds, setSlowSeconds] = useState("");
+
   useEffect(() => {
     if (!node) return;
 
     setName(node.name || "");
     setFunctionType(node.function.name);
 
+    // Rules only change through this dialog, so they are read when it opens.
+    const own = taskRules(notifyRules, node.id);
+    setNotifyOnFailure(own.some((rule) => rule.on === "failure"));
+    setNotifyOnSuccess(own.some((rule) => rule.on === "success"));
+    const slow = own.find((rule) => rule.on === "slow");
+    setSlowSeconds(slow ? String(slow.longer_than_seconds ?? "") : "");
+
     switch (node.function.name) {
       case "cli_command":
         setCommand(node.function.input.command || "");
@@ -118,6 +134,10 @@ export function NodeEditorModal({
       errors.customInput = "Invalid JSON";
     }
 
+    if (slowSeconds.trim() && !/^[1-9][0-9]*$/.test(slowSeconds.trim())) {
+      errors.slowSeconds = "Enter a whole number of seconds";
+    }
+
     setValidationErrors(errors);
     return Object.keys(errors).length === 0;
   };
@@ -150,10 +170,23 @@ export function NodeEditorModal({
       function: updatedFunction,
     };
 
-    onSave(updatedNode);
+    const own: NotifyRule[] = [];
+    if (notifyOnFailure) own.push({ tasks: [node.id], on: "failure" });
+    if (notifyOnSuccess) own.push({ tasks: [node.id], on: "success" });
+    if (slowSeconds.trim()) {
+      own.push({
+        tasks: [node.id],
+        on: "slow",
+        longer_than_seconds: Number(slowSeconds.trim()),
+      });
+    }
+
+    onSave(updatedNode, own);
     onClose();
   };
 
+  const shared = node ? sharedRules(notifyRules, node.id) : [];
+
   const handleCancel = () => {
     setConfigError("");
     setCustomInputError("");
@@ -220,6 +253,48 @@ export function NodeEditorModal({
             onCustomInputBlur={handleCustomInputBlur}
             validationErrors={validationErrors}
           />
+
+          <Field>
+            <Label>Notifications</Label>
+            <p className="text-sm text-zinc-600 dark:text-zinc-400">
+              Send a notification when this task:
+            </p>
+            <label className="flex items-center text-sm text-zinc-900 dark:text-white">
+              <input
+                type="checkbox"
+                checked={notifyOnFailure}
+                onChange={(e) => setNotifyOnFailure(e.target.checked)}
+                className="mr-2"
+              />
+              Fails
+            </label>
+            <label className="flex items-center text-sm text-zinc-900 dark:text-white">
+              <input
+                type="checkbox"
+                checked={notifyOnSuccess}
+                onChange={(e) => setNotifyOnSuccess(e.target.checked)}
+                className="mr-2"
+              />
+              Succeeds
+            </label>
+            <Input
+              value={slowSeconds}
+              onChange={(e) => setSlowSeconds(e.target.value)}
+              placeholder="Runs longer than (seconds)"
+              invalid={!!validationErrors.slowSeconds}
+            />
+            {validationErrors.slowSeconds && (
+              <p className="text-red-600 dark:text-red-400 text-sm mt-1">
+                {validationErrors.slowSeconds}
+              </p>
+            )}
+            {shared.length > 0 && (
+              <p className="text-sm text-zinc-600 dark:text-zinc-400">
+                Also covered by workflow rules:{" "}
+                {shared.map(describeRule).join("; ")}
+              </p>
+            )}
+          </Field>
         </FieldGroup>
       </DialogBody>
       <DialogActions>
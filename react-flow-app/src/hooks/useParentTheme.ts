@@ -0,0 +1,45 @@
+import { useEffect, useState } from "react";
+import { MessageFromParent, ThemeMode, ThemePayload } from "../types";
+
+declare global {
+  interface Window {
+    WORKFLOW_THEME?: ThemePayload;
+  }
+}
+
+const applyTheme = (theme: ThemePayload) => {
+  const root = document.documentElement;
+  root.classList.toggle("dark", theme.mode === "dark");
+  root.classList.toggle("light", theme.mode !== "dark");
+  root.style.colorScheme = theme.mode;
+  Object.entries(theme.variables ?? {}).forEach(([name, value]) =>
+    root.style.setProperty(name, value),
+  );
+};
+
+/**
+ * Follows the host app's theme: the one it started the iframe with, then
+ * every SET_THEME message it sends. Returns the current mode for React Flow.
+ */
+export const useParentTheme = (): ThemeMode => {
+  const [mode, setMode] = useState<ThemeMode>(
+    window.WORKFLOW_THEME?.mode ?? "light",
+  );
+
+  useEffect(() => {
+    if (window.WORKFLOW_THEME) {
+      applyTheme(window.WORKFLOW_THEME);
+    }
+    const handleMessage = (event: MessageEvent<MessageFromParent>) => {
+      const theme = event.data?.payload?.theme;
+      if (event.data?.type === "SET_THEME" && theme) {
+        applyTheme(theme);
+        setMode(theme.mode);
+      }
+    };
+    window.addEventListener("message", handleMessage);
+    return () => window.removeEventListener("message", handleMessage);
+  }, []);
+
+  return mode;
+};
@@ -16,7 +16,7 @@ export const useWorkflowEdges = () => {
               target: nextTask.id,
               type: "smoothstep",
               animated: true,
-              style: { stroke: "#3b82f6", strokeWidth: 2 },
+              style: { stroke: "var(--see-accent, #3b82f6)", strokeWidth: 2 },
             });
             if (nextTask.next_tasks && nextTask.next_tasks.length > 0) {
               generateEdgesRecursive([nextTask]);
@@ -29,20 +29,77 @@ export interface NodePosition {
   y: number;
 }
 
+export type LayoutMode = "layered" | "force_directed" | "stage_grid";
+
 export interface WorkflowVisualizationMetadata {
+  /** Nodes the user dragged; these keep their place whatever the layout. */
   node_positions?: Record<string, NodePosition>;
+  layout?: LayoutMode;
+}
+
+/** Node positions computed by the app for a layout mode. */
+export interface LayoutPayload {
+  mode: LayoutMode;
+  positions: Record<string, NodePosition>;
+}
+
+export type NotifyOn = "failure" | "success" | "slow";
+
+/** A workflow `notify` rule: which tasks to send a notification about, and when. */
+export interface NotifyRule {
+  /** Task ids; `*` and `?` match as in file globs, e.g. `deploy-*`. */
+  tasks: string[];
+  on: NotifyOn;
+  /** With `on: "slow"`: seconds a task may run before it counts as slow. */
+  longer_than_seconds?: number;
 }
 
 export interface Workflow {
   id: string;
   name: string;
   tasks: WorkflowTask[];
+  notify?: NotifyRule[];
   metadata?: WorkflowVisualizationMetadata;
 }
 
+export type ThemeMode = "light" | "dark";
+
+export type ImageFormat = "svg" | "png";
+
+export interface ViewportState {
+  x: number;
+  y: number;
+  zoom: number;
+}
+
+export interface ThemePayload {
+  mode: ThemeMode;
+  /** CSS custom properties to set on the document, e.g. `--see-accent`. */
+  variables?: Record<string, string>;
+}
+
 export interface MessageFromParent {
-  type: "LOAD_WORKFLOW" | "GET_WORKFLOW_STATE" | "UPDATE_NODE" | "DELETE_EDGE";
+  type:
+    | "LOAD_WORKFLOW"
+    | "GET_WORKFLOW_STATE"
+    | "UPDATE_NODE"
+    | "DELETE_EDGE"
+    | "SET_THEME"
+    | "EXPORT_IMAGE"
+    | "FIT_VIEW"
+    | "ZOOM_IN"
+    | "ZOOM_OUT"
+    | "SET_MINIMAP"
+    | "APPLY_LAYOUT";
   payload?: {
+    /** With LOAD_WORKFLOW or APPLY_LAYOUT. */
+    layout?: LayoutPayload;
+    format?: ImageFormat;
+    /** With LOAD_WORKFLOW: where to open the canvas instead of fitting it. */
+    viewport?: ViewportState;
+    /** With LOAD_WORKFLOW or SET_MINIMAP. */
+    minimap?: boolean;
+    theme?: ThemePayload;
     workflow?: Workflow;
     workflowName?: string;
     nodeId?: string;
@@ -56,9 +113,21 @@ export interface MessageFromParent {
 }
 
 export interface MessageToParent {
-  type: "SAVE_WORKFLOW" | "WORKFLOW_STATE" | "VALIDATION_ERROR" | "READY";
+  type:
+    | "SAVE_WORKFLOW"
+    | "WORKFLOW_STATE"
+    | "VALIDATION_ERROR"
+    | "READY"
+    | "IMAGE_EXPORTED"
+    | "VIEWPORT_CHANGED"
+    | "LAYOUT_REQUESTED";
   payload?: {
-    workflow: Workflow;
+    mode?: LayoutMode;
+    viewport?: ViewportState;
+    workflow?: Workflow;
     error?: string;
+    format?: ImageFormat;
+    /** SVG markup, or base64 PNG bytes, for IMAGE_EXPORTED. */
+    data?: string;
   };
 }
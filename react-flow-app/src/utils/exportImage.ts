@@ -0,0 +1,53 @@
+import { getNodesBounds, getViewportForBounds, Node } from "@xyflow/react";
+import { toPng, toSvg } from "html-to-image";
+import { ImageFormat } from "../types";
+
+/** Blank space around the graph, as a fraction of its size. */
+const PADDING = 0.05;
+/** PNGs are rendered at this multiple of the graph's size, for sharp slides. */
+const PNG_PIXEL_RATIO = 2;
+
+/**
+ * Renders every node and edge, not just the part in view, and returns the
+ * SVG markup or the PNG bytes base64 encoded.
+ */
+export const exportImage = async (
+  nodes: Node[],
+  format: ImageFormat,
+): Promise<string> => {
+  const viewportElement = document.querySelector<HTMLElement>(
+    ".react-flow__viewport",
+  );
+  if (!viewportElement || nodes.length === 0) {
+    throw new Error("There is no graph to export");
+  }
+
+  const bounds = getNodesBounds(nodes);
+  const width = Math.ceil(bounds.width * (1 + 2 * PADDING));
+  const height = Math.ceil(bounds.height * (1 + 2 * PADDING));
+  const viewport = getViewportForBounds(bounds, width, height, 1, 1, PADDING);
+  const background =
+    getComputedStyle(document.documentElement)
+      .getPropertyValue("--see-surface")
+      .trim() || "#ffffff";
+  const options = {
+    backgroundColor: background,
+    width,
+    height,
+    style: {
+      width: `${width}px`,
+      height: `${height}px`,
+      transform: `translate(${viewport.x}px, ${viewport.y}px) scale(${viewport.zoom})`,
+    },
+  };
+
+  if (format === "svg") {
+    const dataUrl = await toSvg(viewportElement, options);
+    return decodeURIComponent(dataUrl.slice(dataUrl.indexOf(",") + 1));
+  }
+  const dataUrl = await toPng(viewportElement, {
+    ...options,
+    pixelRatio: PNG_PIXEL_RATIO,
+  });
+  return dataUrl.slice(dataUrl.indexOf(",") + 1);
+};
@@ -1,4 +1,5 @@
 import { Node, Edge } from "@xyflow/react";
+import { NodePosition } from "../types";
 import dagre from "@dagrejs/dagre";
 
 export const NODE_WIDTH = 250;
@@ -52,3 +53,38 @@ export const getLayoutedElements = (
 
   return { nodes: layoutedNodes, edges };
 };
+
+/**
+ * Moves task nodes to the positions the app computed and puts the start node
+ * above the tasks it links to. Nodes missing from `positions` stay put.
+ */
+export const applyLayoutPositions = (
+  nodes: Node[],
+  edges: Edge[],
+  positions: Record<string, NodePosition>,
+): Node[] => {
+  const placed = nodes.map((node) =>
+    positions[node.id] ? { ...node, position: positions[node.id] } : node,
+  );
+  const roots = edges
+    .filter((edge) => edge.source === START_NODE_ID)
+    .map((edge) => placed.find((node) => node.id === edge.target))
+    .filter((node): node is Node => node !== undefined);
+  if (roots.length === 0) {
+    return placed;
+  }
+  const left = Math.min(...roots.map((node) => node.position.x));
+  const right = Math.max(...roots.map((node) => node.position.x));
+  const top = Math.min(...roots.map((node) => node.position.y));
+  return placed.map((node) =>
+    node.id === START_NODE_ID
+      ? {
+          ...node,
+          position: {
+            x: (left + right + NODE_WIDTH - START_NODE_SIZE) / 2,
+            y: top - START_NODE_SIZE - 70,
+          },
+        }
+      : node,
+  );
+};
@@ -0,0 +1,40 @@
+import { describe, it, expect } from "vitest";
+import {
+  patternMatches,
+  replaceTaskRules,
+  sharedRules,
+  taskRules,
+} from "./notifyRules";
+import { NotifyRule } from "../types";
+
+describe("notifyRules", () => {
+  const rules: NotifyRule[] = [
+    { tasks: ["deploy"], on: "failure" },
+    { tasks: ["deploy-*"], on: "failure" },
+    { tasks: ["deploy", "build"], on: "slow", longer_than_seconds: 600 },
+  ];
+
+  it("matches patterns like file globs", () => {
+    expect(patternMatches("deploy-*", "deploy-eu")).toBe(true);
+    expect(patternMatches("deploy-?", "deploy-1")).toBe(true);
+    expect(patternMatches("deploy-*", "deploy")).toBe(false);
+    expect(patternMatches("a.b", "axb")).toBe(false);
+  });
+
+  it("splits a task's own rules from shared ones", () => {
+    expect(taskRules(rules, "deploy")).toEqual([rules[0]]);
+    expect(sharedRules(rules, "deploy")).toEqual([rules[2]]);
+    expect(sharedRules(rules, "deploy-eu")).toEqual([rules[1]]);
+  });
+
+  it("replaces only the task's own rules", () => {
+    const updated = replaceTaskRules(rules, "deploy", [
+      { tasks: ["deploy"], on: "success" },
+    ]);
+    expect(updated).toEqual([
+      rules[1],
+      rules[2],
+      { tasks: ["deploy"], on: "success" },
+    ]);
+  });
+});
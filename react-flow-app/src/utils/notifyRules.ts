@@ -0,0 +1,49 @@
+import { NotifyRule } from "../types";
+
+/** Whether `pattern` matches `taskId`, with `*` and `?` as in file globs. */
+export function patternMatches(pattern: string, taskId: string): boolean {
+  const source = pattern
+    .split("")
+    .map((c) =>
+      c === "*" ? ".*" : c === "?" ? "." : c.replace(/[.+^${}()|[\]\\]/g, "\\$&"),
+    )
+    .join("");
+  return new RegExp(`^${source}$`).test(taskId);
+}
+
+/** Rules naming only this task; the node editor owns and edits these. */
+export function isTaskRule(rule: NotifyRule, taskId: string): boolean {
+  return rule.tasks.length === 1 && rule.tasks[0] === taskId;
+}
+
+export function taskRules(rules: NotifyRule[], taskId: string): NotifyRule[] {
+  return rules.filter((rule) => isTaskRule(rule, taskId));
+}
+
+/** Rules that also cover the task through a pattern or a list of tasks. */
+export function sharedRules(rules: NotifyRule[], taskId: string): NotifyRule[] {
+  return rules.filter(
+    (rule) =>
+      !isTaskRule(rule, taskId) &&
+      rule.tasks.some((pattern) => patternMatches(pattern, taskId)),
+  );
+}
+
+/** `rules` with the task's own rules replaced by `own`. */
+export function replaceTaskRules(
+  rules: NotifyRule[],
+  taskId: string,
+  own: NotifyRule[],
+): NotifyRule[] {
+  return [...rules.filter((rule) => !isTaskRule(rule, taskId)), ...own];
+}
+
+export function describeRule(rule: NotifyRule): string {
+  const when =
+    rule.on === "slow"
+      ? `runs longer than ${rule.longer_than_seconds}s`
+      : rule.on === "failure"
+        ? "fails"
+        : "succeeds";
+  return `${rule.tasks.join(", ")}: when it ${when}`;
+}
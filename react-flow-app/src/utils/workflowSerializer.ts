@@ -1,5 +1,5 @@
 import { Node, Edge } from "@xyflow/react";
-import { Workflow, WorkflowTask } from "../types";
+import { LayoutMode, NotifyRule, Workflow, WorkflowTask } from "../types";
 import { START_NODE_ID } from "./layout";
 
 export function serializeWorkflow(
@@ -7,6 +7,9 @@ export function serializeWorkflow(
   edges: Edge[],
   workflowName: string,
   workflowId: string,
+  layout: LayoutMode,
+  pinned: Set<string>,
+  notify?: NotifyRule[],
 ): Workflow {
   const taskNodes = nodes.filter((n) => n.id !== START_NODE_ID);
 
@@ -54,19 +57,23 @@ export function serializeWorkflow(
     .filter((t): t is WorkflowTask => t !== null);
 
   const node_positions: Record<string, { x: number; y: number }> = {};
-  nodes.forEach((node) => {
-    node_positions[node.id] = {
-      x: node.position.x,
-      y: node.position.y,
-    };
-  });
+  taskNodes
+    .filter((node) => pinned.has(node.id))
+    .forEach((node) => {
+      node_positions[node.id] = {
+        x: node.position.x,
+        y: node.position.y,
+      };
+    });
 
   return {
     id: workflowId,
     name: workflowName,
     tasks,
+    notify: notify && notify.length > 0 ? notify : undefined,
     metadata: {
       node_positions,
+      layout,
     },
   };
 }
@@ -0,0 +1,25 @@
+use s_e_e_core::validation::ValidationErrors;
+use s_e_e_engine::{EngineError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SeeError {
+    #[error("Validation error: {0}")]
+    Validation(#[from] ValidationErrors),
+
+    #[error("Engine error: {0}")]
+    Engine(#[from] EngineError),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl SeeError {
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            SeeError::Validation(_) => ErrorCategory::Validation,
+            SeeError::Engine(e) => e.category(),
+            SeeError::Io(_) => ErrorCategory::Io,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Single entry point for embedding the S.E.E. workflow engine in other Rust programs.
+//!
+//! Persistence-backed APIs are only available with the `persistence` feature.
+
+pub mod errors;
+pub mod workflow;
+
+pub use errors::SeeError;
+pub use workflow::{load_workflow, load_workflow_file, validate_workflow, Runner};
+
+pub use async_trait::async_trait;
+pub use s_e_e_core::validation::{
+    QuickFix, Severity, ValidationCode, ValidationError, ValidationErrors, VALIDATION_CODES_VERSION,
+};
+pub use s_e_e_engine::handlers::{HandlerRegistry, TaskHandler};
+pub use s_e_e_engine::{
+    AuditEntry, AuditStatus, EngineError, EngineTask, EngineWorkflow, ErrorCategory,
+    ExecutionContext, HandlerError, OutputEvent, OutputEvents, OutputSink, TaskFunction, TaskInfo,
+    TaskResult, TaskStatus, WorkflowEngine, WorkflowResult,
+};
+
+#[cfg(feature = "persistence")]
+pub mod persistence {
+    pub use s_e_e_core::{
+        delete_workflow_execution, execute_workflow_by_id, get_global_store, get_pending_inputs,
+        init_global_store, provide_user_input, CoreError, OutputCallback,
+    };
+    pub use s_e_e_persistence::*;
+}
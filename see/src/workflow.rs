@@ -0,0 +1,55 @@
+use crate::errors::SeeError;
+use s_e_e_engine::handlers::{HandlerRegistry, TaskHandler};
+use s_e_e_engine::{parse_workflow, EngineWorkflow, WorkflowEngine, WorkflowResult};
+use std::path::Path;
+
+/// Parse a workflow definition without running schema validation.
+pub fn load_workflow(json: &str) -> Result<EngineWorkflow, SeeError> {
+    parse_workflow(json).map_err(|e| SeeError::Engine(e.into()))
+}
+
+pub fn load_workflow_file(path: impl AsRef<Path>) -> Result<EngineWorkflow, SeeError> {
+    let json = std::fs::read_to_string(path)?;
+    load_workflow(&json)
+}
+
+/// Check a workflow definition against the bundled JSON schema.
+pub fn validate_workflow(json: &str) -> Result<(), SeeError> {
+    Ok(s_e_e_core::validate_workflow_json(json)?)
+}
+
+/// Runs workflows with the built-in handlers plus any registered ones.
+pub struct Runner {
+    handlers: HandlerRegistry,
+}
+
+impl Runner {
+    pub fn new() -> Self {
+        Self {
+            handlers: HandlerRegistry::new(),
+        }
+    }
+
+    /// Register a handler for `custom` functions whose name matches `name`.
+    pub fn with_handler(mut self, name: &str, handler: impl TaskHandler + 'static) -> Self {
+        self.handlers
+            .register_handler(name.to_string(), Box::new(handler));
+        self
+    }
+
+    pub async fn run(self, workflow: EngineWorkflow) -> Result<WorkflowResult, SeeError> {
+        let engine = WorkflowEngine::with_handlers(self.handlers);
+        Ok(engine.execute_workflow(workflow).await?)
+    }
+
+    pub async fn run_json(self, json: &str) -> Result<WorkflowResult, SeeError> {
+        let workflow = load_workflow(json)?;
+        self.run(workflow).await
+    }
+}
+
+impl Default for Runner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,94 @@
+use s_e_e::{
+    async_trait, load_workflow, validate_workflow, EngineTask, ExecutionContext, HandlerError,
+    Runner, SeeError, TaskFunction, TaskHandler, TaskResult, TaskStatus,
+};
+use serde_json::json;
+
+struct UppercaseHandler;
+
+#[async_trait]
+impl TaskHandler for UppercaseHandler {
+    async fn execute(
+        &self,
+        _context: &mut ExecutionContext,
+        task: &EngineTask,
+    ) -> Result<TaskResult, HandlerError> {
+        let TaskFunction::Custom { input, .. } = &task.function else {
+            return Err(HandlerError::InvalidConfiguration(
+                "Expected Custom function".to_string(),
+            ));
+        };
+        let text = input
+            .get("text")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+        Ok(TaskResult {
+            success: true,
+            output: json!(text.to_uppercase()),
+            error: None,
+            failure_reason: None,
+            output_stats: None,
+            exit_code: None,
+            environment: None,
+        })
+    }
+}
+
+const SIMPLE: &str = r#"{
+    "id": "simple",
+    "name": "Simple",
+    "tasks": [
+        {
+            "id": "task1",
+            "name": "Task 1",
+            "function": { "name": "cli_command", "input": { "command": "echo", "args": ["hi"] } }
+        }
+    ]
+}"#;
+
+#[test]
+fn test_load_and_validate_workflow() {
+    validate_workflow(SIMPLE).unwrap();
+    let workflow = load_workflow(SIMPLE).unwrap();
+    assert_eq!(workflow.name, "Simple");
+    assert_eq!(workflow.tasks.len(), 1);
+}
+
+#[test]
+fn test_validate_rejects_invalid_workflow() {
+    let result = validate_workflow(r#"{"id": "x", "name": "x", "tasks": [{"id": "t"}]}"#);
+    assert!(matches!(result, Err(SeeError::Validation(_))));
+}
+
+#[test]
+fn test_load_rejects_malformed_json() {
+    assert!(matches!(load_workflow("{"), Err(SeeError::Engine(_))));
+}
+
+#[tokio::test]
+async fn test_runner_uses_registered_handler() {
+    let json = r#"{
+        "id": "custom",
+        "name": "Custom",
+        "tasks": [
+            {
+                "id": "shout",
+                "name": "Shout",
+                "function": { "name": "uppercase", "input": { "text": "hello" } }
+            }
+        ]
+    }"#;
+
+    let result = Runner::new()
+        .with_handler("uppercase", UppercaseHandler)
+        .run_json(json)
+        .await
+        .unwrap();
+
+    assert!(result.success);
+    assert_eq!(result.tasks[0].status, TaskStatus::Complete);
+    assert_eq!(
+        result.per_task_logs.get("shout"),
+        Some(&vec!["Output: HELLO".to_string()])
+    );
+}